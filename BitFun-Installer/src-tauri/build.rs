@@ -13,6 +13,14 @@ fn main() {
     tauri_build::build()
 }
 
+/// Payload entry compression: `zstd` (default) or `deflate`.
+const PAYLOAD_COMPRESSION_ENV: &str = "BITFUN_INSTALLER_PAYLOAD_COMPRESSION";
+/// zstd level for payload entries (1-22).
+const PAYLOAD_ZSTD_LEVEL_ENV: &str = "BITFUN_INSTALLER_ZSTD_LEVEL";
+const DEFAULT_PAYLOAD_ZSTD_LEVEL: i32 = 19;
+/// Debug builds favor build time over installer size.
+const DEBUG_PAYLOAD_ZSTD_LEVEL: i32 = 3;
+
 fn build_embedded_payload() -> Result<(), Box<dyn std::error::Error>> {
     let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR")?);
     let payload_dir = manifest_dir.join("payload");
@@ -20,10 +28,13 @@ fn build_embedded_payload() -> Result<(), Box<dyn std::error::Error>> {
     let out_zip = out_dir.join("embedded_payload.zip");
 
     println!("cargo:rerun-if-changed={}", payload_dir.display());
+    println!("cargo:rerun-if-env-changed={PAYLOAD_COMPRESSION_ENV}");
+    println!("cargo:rerun-if-env-changed={PAYLOAD_ZSTD_LEVEL_ENV}");
 
+    let options = payload_file_options()?;
     let mut file_count = 0usize;
     if payload_dir.exists() && payload_dir.is_dir() {
-        file_count = create_payload_zip(&payload_dir, &out_zip)?;
+        file_count = create_payload_zip(&payload_dir, &out_zip, options)?;
         emit_rerun_for_files(&payload_dir)?;
     } else {
         create_empty_zip(&out_zip)?;
@@ -36,6 +47,45 @@ fn build_embedded_payload() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Entries stay in a zip container so the manifest lookup, filtered
+/// extraction and repair keep working; only the per-entry method changes.
+/// The extractor reads the method from each entry, so deflate payloads
+/// (external `payload.zip` files, older builds) still install.
+fn payload_file_options() -> Result<FileOptions, Box<dyn std::error::Error>> {
+    let compression = std::env::var(PAYLOAD_COMPRESSION_ENV).unwrap_or_default();
+    let options = FileOptions::default();
+    match compression.trim().to_ascii_lowercase().as_str() {
+        "" | "zstd" => {
+            let level = match std::env::var(PAYLOAD_ZSTD_LEVEL_ENV) {
+                Ok(raw) => raw
+                    .trim()
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|level| (1..=22).contains(level))
+                    .ok_or_else(|| format!("{PAYLOAD_ZSTD_LEVEL_ENV} must be 1-22, got '{raw}'"))?,
+                Err(_) if std::env::var("PROFILE").as_deref() == Ok("debug") => {
+                    DEBUG_PAYLOAD_ZSTD_LEVEL
+                }
+                Err(_) => DEFAULT_PAYLOAD_ZSTD_LEVEL,
+            };
+            println!("cargo:warning=embedded payload compression: zstd level {level}");
+            println!("cargo:rustc-env=EMBEDDED_PAYLOAD_COMPRESSION=zstd");
+            Ok(options
+                .compression_method(CompressionMethod::Zstd)
+                .compression_level(Some(level)))
+        }
+        "deflate" => {
+            println!("cargo:warning=embedded payload compression: deflate");
+            println!("cargo:rustc-env=EMBEDDED_PAYLOAD_COMPRESSION=deflate");
+            Ok(options.compression_method(CompressionMethod::Deflated))
+        }
+        other => Err(format!(
+            "{PAYLOAD_COMPRESSION_ENV} must be 'zstd' or 'deflate', got '{other}'"
+        )
+        .into()),
+    }
+}
+
 fn emit_rerun_for_files(dir: &Path) -> io::Result<()> {
     for entry in fs::read_dir(dir)? {
         let entry = entry?;
@@ -55,10 +105,13 @@ fn create_empty_zip(out_zip: &Path) -> zip::result::ZipResult<()> {
     Ok(())
 }
 
-fn create_payload_zip(payload_dir: &Path, out_zip: &Path) -> zip::result::ZipResult<usize> {
+fn create_payload_zip(
+    payload_dir: &Path,
+    out_zip: &Path,
+    options: FileOptions,
+) -> zip::result::ZipResult<usize> {
     let file = File::create(out_zip)?;
     let mut zip = ZipWriter::new(file);
-    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
 
     let mut file_count = 0usize;
     add_dir_to_zip(&mut zip, payload_dir, payload_dir, options, &mut file_count)?;
@@ -0,0 +1,41 @@
+//! Cooperative cancellation of a running installation.
+//!
+//! Installation runs synchronously, so the cancel command only raises a flag;
+//! extraction checks it before every entry and `run_installation` between
+//! steps, then rolls back like any other failure.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Stable error string for a cancelled install; see `src/hooks/useInstaller.ts`.
+pub(super) const INSTALL_CANCELLED_ERR: &str = "INSTALL_CANCELLED";
+
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug)]
+pub(super) struct InstallCancelled;
+
+impl fmt::Display for InstallCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Installation cancelled")
+    }
+}
+
+impl std::error::Error for InstallCancelled {}
+
+pub(super) fn request() {
+    CANCEL_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Clears a request left over from a previous run.
+pub(super) fn reset() {
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+}
+
+pub(super) fn check() -> Result<(), InstallCancelled> {
+    if CANCEL_REQUESTED.load(Ordering::SeqCst) {
+        Err(InstallCancelled)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! Silent installs driven from the command line.
+//!
+//! `--silent` installs without opening the installer window. Every progress
+//! update is written to stdout as one JSON object per line with the same
+//! fields as the `install-progress` event (`step`, `percent`, `message`), so
+//! orchestration tools and accessibility wrappers can follow the install.
+//! A failed install ends with a `failed` line and exit code 1.
+
+use std::cell::Cell;
+use std::io::Write;
+
+use super::commands;
+use super::types::{InstallOptions, InstallProgress};
+
+const SILENT_FLAG: &str = "--silent";
+/// Terminal step reported when the installation fails.
+const FAILED_STEP: &str = "failed";
+/// Matches `DEFAULT_OPTIONS.appLanguage` in `src/types/installer.ts`.
+const DEFAULT_APP_LANGUAGE: &str = "zh-CN";
+
+pub(crate) fn silent_install_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == SILENT_FLAG)
+}
+
+/// Runs a silent install and returns the process exit code.
+pub(crate) fn run_silent_install() -> i32 {
+    let mut options = InstallOptions {
+        install_path: commands::get_initial_install_path(),
+        desktop_shortcut: true,
+        start_menu: true,
+        start_menu_folder: None,
+        desktop_shortcut_name: None,
+        start_menu_shortcut_name: None,
+        launch_after_install: false,
+        app_language: commands::read_saved_app_language()
+            .unwrap_or_else(|| DEFAULT_APP_LANGUAGE.to_string()),
+        theme_preference: "system".to_string(),
+        model_config: None,
+    };
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let last_percent = Cell::new(0);
+    let report = |progress: &InstallProgress| {
+        last_percent.set(progress.percent);
+        write_progress_line(progress);
+    };
+    let result = apply_silent_install_args(&mut options, &args)
+        .and_then(|()| commands::run_installation(options, None, &report));
+    match result {
+        Ok(()) => 0,
+        Err(error) => {
+            log::error!("Silent installation failed: {}", error);
+            write_progress_line(&InstallProgress {
+                step: FAILED_STEP.to_string(),
+                percent: last_percent.get(),
+                message: error,
+            });
+            1
+        }
+    }
+}
+
+/// Applies `--install-path <dir>`, `--language <code>`,
+/// `--no-desktop-shortcut` and `--no-start-menu`.
+fn apply_silent_install_args(options: &mut InstallOptions, args: &[String]) -> Result<(), String> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            SILENT_FLAG => {}
+            "--install-path" => options.install_path = value()?,
+            "--language" => options.app_language = value()?,
+            "--no-desktop-shortcut" => options.desktop_shortcut = false,
+            "--no-start-menu" => options.start_menu = false,
+            other => return Err(format!("Unknown silent install argument: {}", other)),
+        }
+    }
+    Ok(())
+}
+
+fn write_progress_line(progress: &InstallProgress) {
+    let Ok(line) = serde_json::to_string(progress) else {
+        return;
+    };
+    let mut stdout = std::io::stdout().lock();
+    // A closed pipe must not abort the installation.
+    let _ = writeln!(stdout, "{}", line).and_then(|()| stdout.flush());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_silent_install_args;
+    use crate::installer::types::InstallOptions;
+
+    fn options() -> InstallOptions {
+        InstallOptions {
+            install_path: "default".to_string(),
+            desktop_shortcut: true,
+            start_menu: true,
+            start_menu_folder: None,
+            desktop_shortcut_name: None,
+            start_menu_shortcut_name: None,
+            launch_after_install: false,
+            app_language: "zh-CN".to_string(),
+            theme_preference: "system".to_string(),
+            model_config: None,
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn silent_args_override_defaults() {
+        let mut parsed = options();
+        apply_silent_install_args(
+            &mut parsed,
+            &args(&[
+                "--silent",
+                "--install-path",
+                r"D:\Apps",
+                "--language",
+                "en-US",
+                "--no-start-menu",
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(parsed.install_path, r"D:\Apps");
+        assert_eq!(parsed.app_language, "en-US");
+        assert!(parsed.desktop_shortcut);
+        assert!(!parsed.start_menu);
+    }
+
+    #[test]
+    fn silent_args_reject_unknown_and_missing_values() {
+        assert!(apply_silent_install_args(&mut options(), &args(&["--install-path"])).is_err());
+        assert!(apply_silent_install_args(&mut options(), &args(&["--bogus"])).is_err());
+    }
+}
@@ -1,12 +1,22 @@
 //! Tauri commands exposed to the frontend installer UI.
 
 use super::MAIN_APP_EXE;
+use super::cancel::{self, InstallCancelled, INSTALL_CANCELLED_ERR};
+use super::disk_space::{self, LowDiskSpace};
 use super::extract::{self, ESTIMATED_INSTALL_SIZE};
+use super::leftovers;
+use super::repair;
 use super::generated_locale_contract::INSTALLER_GENERATED_LOCALES;
 use super::types::{
-    ConnectionTestResult, DiskSpaceInfo, InstallOptions, InstallProgress, ModelConfig,
-    RemoteModelInfo,
+    ConnectionTestResult, DiskSpaceInfo, FailedRemoval, InstallConflict, InstallOptions,
+    InstallProgress, ModelConfig, PayloadManifest, PayloadManifestFile, RemoteModelInfo,
+    RepairReport, ShortcutNames, UninstallReport,
 };
+use bitfun_services_core::first_launch::{
+    FirstLaunchHandoff, FIRST_LAUNCH_HANDOFF_FILE, FIRST_LAUNCH_HANDOFF_SCHEMA_VERSION,
+};
+use bitfun_services_core::format::LocaleFormatter;
+use bitfun_services_core::hashing::{hash_reader, HashAlgorithm};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::fs::File;
@@ -20,11 +30,12 @@ use tauri::{Emitter, Manager, Window};
 struct WindowsInstallState {
     manufacturer_registered: bool,
     uninstall_registered: bool,
+    url_protocol_registered: bool,
     desktop_shortcut_created: bool,
     start_menu_shortcut_created: bool,
+    shortcut_names: ShortcutNames,
 }
 
-const MIN_WINDOWS_APP_EXE_BYTES: u64 = 5 * 1024 * 1024;
 const PAYLOAD_MANIFEST_FILE: &str = "payload-manifest.json";
 const INSTALLER_STATE_FILE: &str = "installer-state.json";
 const DEFAULT_MODEL_CONTEXT_WINDOW: u64 = 200_000;
@@ -58,22 +69,14 @@ static INSTALLER_APP_LANGUAGE_ALIASES_BY_PRIORITY: LazyLock<Vec<(&'static str, &
         aliases
     });
 
-#[derive(Debug, Clone, Deserialize)]
-struct PayloadManifest {
-    files: Vec<PayloadManifestFile>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-struct PayloadManifestFile {
-    path: String,
-}
-
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct LaunchContext {
     pub mode: String,
     pub uninstall_path: Option<String>,
     pub app_language: Option<String>,
+    /// Other BitFun installations found in install mode.
+    pub conflicts: Vec<InstallConflict>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -98,6 +101,19 @@ pub(crate) struct ExistingInstallationResponse {
 #[serde(rename_all = "camelCase")]
 struct InstallerState {
     last_install_path: String,
+    /// Shortcut choices of the last install, re-applied by repair. Missing
+    /// for installs made before repair existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shortcuts: Option<RecordedShortcutOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedShortcutOptions {
+    desktop_shortcut: bool,
+    start_menu: bool,
+    #[serde(default)]
+    names: ShortcutNames,
 }
 
 /// Get the default installation path.
@@ -337,43 +353,13 @@ pub(crate) fn get_disk_space(path: String) -> Result<DiskSpaceInfo, String> {
     // Walk up to find an existing ancestor directory
     let check_path = find_existing_ancestor(&path);
 
-    // Use std::fs metadata as a basic check. For actual disk space,
-    // platform-specific APIs are needed.
-    #[cfg(target_os = "windows")]
-    {
-        use std::ffi::OsStr;
-        use std::os::windows::ffi::OsStrExt;
-
-        let fallback_windows_root = format!("{}{}", "C:", std::path::MAIN_SEPARATOR);
-        let wide_path: Vec<u16> = OsStr::new(
-            check_path
-                .to_str()
-                .unwrap_or(fallback_windows_root.as_str()),
-        )
-            .encode_wide()
-            .chain(std::iter::once(0))
-            .collect();
-
-        let mut free_bytes_available: u64 = 0;
-        let mut total_bytes: u64 = 0;
-        let mut total_free_bytes: u64 = 0;
-
-        unsafe {
-            let result = windows_sys_get_disk_free_space(
-                wide_path.as_ptr(),
-                &mut free_bytes_available,
-                &mut total_bytes,
-                &mut total_free_bytes,
-            );
-            if result != 0 {
-                return Ok(DiskSpaceInfo {
-                    total: total_bytes,
-                    available: free_bytes_available,
-                    required: ESTIMATED_INSTALL_SIZE,
-                    sufficient: free_bytes_available >= ESTIMATED_INSTALL_SIZE,
-                });
-            }
-        }
+    if let Some(space) = disk_space::query_disk_space(&check_path) {
+        return Ok(DiskSpaceInfo {
+            total: space.total,
+            available: space.available,
+            required: ESTIMATED_INSTALL_SIZE,
+            sufficient: space.available >= ESTIMATED_INSTALL_SIZE,
+        });
     }
 
     // Fallback: assume sufficient space
@@ -385,26 +371,6 @@ pub(crate) fn get_disk_space(path: String) -> Result<DiskSpaceInfo, String> {
     })
 }
 
-#[cfg(target_os = "windows")]
-unsafe fn windows_sys_get_disk_free_space(
-    path: *const u16,
-    free_bytes_available: *mut u64,
-    total_bytes: *mut u64,
-    total_free_bytes: *mut u64,
-) -> i32 {
-    // Link to kernel32.dll GetDiskFreeSpaceExW
-    #[link(name = "kernel32")]
-    extern "system" {
-        fn GetDiskFreeSpaceExW(
-            lpDirectoryName: *const u16,
-            lpFreeBytesAvailableToCaller: *mut u64,
-            lpTotalNumberOfBytes: *mut u64,
-            lpTotalNumberOfFreeBytes: *mut u64,
-        ) -> i32;
-    }
-    GetDiskFreeSpaceExW(path, free_bytes_available, total_bytes, total_free_bytes)
-}
-
 #[tauri::command]
 pub(crate) fn get_launch_context() -> LaunchContext {
     let args: Vec<String> = std::env::args().collect();
@@ -418,6 +384,26 @@ pub(crate) fn get_launch_context() -> LaunchContext {
             mode: "uninstall".to_string(),
             uninstall_path,
             app_language,
+            conflicts: Vec::new(),
+        };
+    }
+
+    if let Some(idx) = args.iter().position(|arg| arg == "--repair") {
+        let uninstall_path = args
+            .get(idx + 1)
+            .filter(|p| !p.starts_with("--"))
+            .map(|p| p.to_string())
+            .or_else(|| {
+                is_running_as_uninstall_binary()
+                    .then(guess_uninstall_path_from_exe)
+                    .flatten()
+            })
+            .or_else(read_last_install_path);
+        return LaunchContext {
+            mode: "repair".to_string(),
+            uninstall_path,
+            app_language,
+            conflicts: Vec::new(),
         };
     }
 
@@ -426,6 +412,7 @@ pub(crate) fn get_launch_context() -> LaunchContext {
             mode: "uninstall".to_string(),
             uninstall_path: guess_uninstall_path_from_exe(),
             app_language,
+            conflicts: Vec::new(),
         };
     }
 
@@ -433,6 +420,7 @@ pub(crate) fn get_launch_context() -> LaunchContext {
         mode: "install".to_string(),
         uninstall_path: None,
         app_language,
+        conflicts: detect_install_conflicts(Path::new(&get_initial_install_path())),
     }
 }
 
@@ -452,37 +440,86 @@ pub(crate) async fn start_installation(
     window: Window,
     options: InstallOptions,
 ) -> Result<(), String> {
+    let resource_dir = window.app_handle().path().resource_dir().ok();
+    run_installation(options, resource_dir.as_deref(), &|progress| {
+        let _ = window.emit("install-progress", progress);
+    })
+}
+
+/// Stops a running `start_installation` at its next file or step; it then
+/// rolls back and fails with `INSTALL_CANCELLED`.
+#[tauri::command]
+pub(crate) fn cancel_installation() {
+    log::info!("Installation cancellation requested");
+    cancel::request();
+}
+
+/// Installs into `options.install_path`, reporting each step to `report`.
+/// Shared by the UI command and silent installs. `resource_dir` adds the
+/// bundle resource folder to the external payload locations.
+pub(super) fn run_installation(
+    options: InstallOptions,
+    resource_dir: Option<&Path>,
+    report: ProgressSink<'_>,
+) -> Result<(), String> {
+    cancel::reset();
     let install_path = prepare_install_target(Path::new(&options.install_path))?;
+    if let Some(conflict) = detect_install_conflicts(&install_path)
+        .into_iter()
+        .find(|conflict| conflict.blocking)
+    {
+        log::warn!(
+            "Installation blocked by a per-machine install: location={}",
+            conflict.location
+        );
+        return Err(format!(
+            "{}per_machine_install_exists",
+            INSTALL_PATH_ERR_PREFIX
+        ));
+    }
+    let shortcut_names = ShortcutNames::from_options(&options)?;
     let install_dir_was_absent = !install_path.exists();
     #[cfg(target_os = "windows")]
-    let mut windows_state = WindowsInstallState::default();
+    let mut windows_state = WindowsInstallState {
+        shortcut_names: shortcut_names.clone(),
+        ..Default::default()
+    };
 
     let result: Result<(), String> = (|| {
         // Step 1: Create target directory
-        emit_progress(&window, "prepare", 5, "Creating installation directory...");
+        emit_progress(report, "prepare", 5, "Creating installation directory...");
         std::fs::create_dir_all(&install_path)
             .map_err(|e| format!("Failed to create directory: {}", e))?;
 
         // Step 2: Extract / copy application files
-        emit_progress(&window, "extract", 15, "Extracting application files...");
+        check_cancelled()?;
+        emit_progress(report, "extract", 15, "Extracting application files...");
 
         let mut extracted = false;
-        let mut used_debug_placeholder = false;
+        let mut payload_manifest: Option<PayloadManifest> = None;
         let mut checked_locations: Vec<String> = Vec::new();
 
         if embedded_payload_available() {
             checked_locations.push("embedded payload zip".to_string());
-            preflight_validate_payload_zip_bytes(EMBEDDED_PAYLOAD_ZIP, "embedded payload zip")?;
-            let _ =
+            let manifest =
                 read_payload_manifest_from_zip_bytes(EMBEDDED_PAYLOAD_ZIP, "embedded payload zip")?;
+            preflight_validate_payload_zip_bytes(
+                EMBEDDED_PAYLOAD_ZIP,
+                &manifest,
+                "embedded payload zip",
+            )?;
             extract::extract_zip_bytes_with_filter(
                 EMBEDDED_PAYLOAD_ZIP,
                 &install_path,
                 should_install_payload_path,
             )
-            .map_err(|e| format!("Embedded payload extraction failed: {}", e))?;
+            .map_err(|e| extraction_error("Embedded payload extraction failed", e))?;
+            payload_manifest = Some(manifest);
             extracted = true;
-            log::info!("Extracted payload from embedded installer archive");
+            log::info!(
+                "Extracted payload from embedded installer archive: compression={}",
+                option_env!("EMBEDDED_PAYLOAD_COMPRESSION").unwrap_or("deflate")
+            );
         }
 
         // Fallback to external payload locations for compatibility and local debug.
@@ -493,20 +530,28 @@ pub(crate) async fn start_installation(
             .to_path_buf();
 
         if !extracted {
-            for candidate in build_payload_candidates(&window, &exe_dir) {
+            for candidate in build_payload_candidates(resource_dir, &exe_dir) {
                 if candidate.is_zip {
                     checked_locations.push(format!("zip: {}", candidate.path.display()));
                     if !candidate.path.exists() {
                         continue;
                     }
-                    preflight_validate_payload_zip_file(&candidate.path, &candidate.label)?;
-                    let _ = read_payload_manifest_from_zip_file(&candidate.path, &candidate.label)?;
+                    let manifest =
+                        read_payload_manifest_from_zip_file(&candidate.path, &candidate.label)?;
+                    preflight_validate_payload_zip_file(
+                        &candidate.path,
+                        &manifest,
+                        &candidate.label,
+                    )?;
                     extract::extract_zip_with_filter(
                         &candidate.path,
                         &install_path,
                         should_install_payload_path,
                     )
-                    .map_err(|e| format!("Extraction failed from {}: {}", candidate.label, e))?;
+                    .map_err(|e| {
+                        extraction_error(&format!("Extraction failed from {}", candidate.label), e)
+                    })?;
+                    payload_manifest = Some(manifest);
                     extracted = true;
                     log::info!("Extracted payload from {}", candidate.label);
                     break;
@@ -516,14 +561,17 @@ pub(crate) async fn start_installation(
                 if !candidate.path.exists() {
                     continue;
                 }
-                preflight_validate_payload_dir(&candidate.path, &candidate.label)?;
-                let _ = read_payload_manifest_from_dir(&candidate.path, &candidate.label)?;
+                let manifest = read_payload_manifest_from_dir(&candidate.path, &candidate.label)?;
+                preflight_validate_payload_dir(&candidate.path, &manifest, &candidate.label)?;
                 extract::copy_directory_with_filter(
                     &candidate.path,
                     &install_path,
                     should_install_payload_path,
                 )
-                .map_err(|e| format!("File copy failed from {}: {}", candidate.label, e))?;
+                .map_err(|e| {
+                    extraction_error(&format!("File copy failed from {}", candidate.label), e)
+                })?;
+                payload_manifest = Some(manifest);
                 extracted = true;
                 log::info!("Copied payload from {}", candidate.label);
                 break;
@@ -539,7 +587,6 @@ pub(crate) async fn start_installation(
                     std::fs::write(&placeholder, "placeholder")
                         .map_err(|e| format!("Failed to write placeholder: {}", e))?;
                 }
-                used_debug_placeholder = true;
             } else {
                 return Err(format!(
                     "Installer payload is missing. Checked: {}",
@@ -548,11 +595,25 @@ pub(crate) async fn start_installation(
             }
         }
 
-        if !used_debug_placeholder {
-            verify_installed_payload(&install_path)?;
+        // The development placeholder has no manifest to verify against.
+        if let Some(manifest) = &payload_manifest {
+            verify_installed_payload(&install_path, manifest)?;
         }
 
-        emit_progress(&window, "extract", 50, "Files extracted successfully");
+        let extracted_message = match &payload_manifest {
+            Some(manifest) => {
+                let formatter = LocaleFormatter::new(&options.app_language);
+                let total_bytes: u64 = manifest.files.iter().filter_map(|file| file.size).sum();
+                format!(
+                    "Files extracted successfully ({} files, {})",
+                    formatter.number(manifest.files.len() as u64),
+                    formatter.bytes(total_bytes)
+                )
+            }
+            None => "Files extracted successfully".to_string(),
+        };
+        emit_progress(report, "extract", 50, &extracted_message);
+        check_cancelled()?;
 
         // Step 3: Windows-specific operations
         #[cfg(target_os = "windows")]
@@ -566,7 +627,7 @@ pub(crate) async fn start_installation(
                 .map_err(|e| format!("Failed to create uninstaller executable: {}", e))?;
             let uninstall_command = format!("\"{}\"", uninstaller_path.display());
 
-            emit_progress(&window, "registry", 60, "Registering application...");
+            emit_progress(report, "registry", 60, "Registering application...");
             registry::register_tauri_install_location(&install_path)
                 .map_err(|e| format!("Registry error: {}", e))?;
             windows_state.manufacturer_registered = true;
@@ -574,33 +635,38 @@ pub(crate) async fn start_installation(
                 &install_path,
                 env!("CARGO_PKG_VERSION"),
                 &uninstall_command,
+                &shortcut_names,
             )
             .map_err(|e| format!("Registry error: {}", e))?;
             windows_state.uninstall_registered = true;
+            registry::register_url_protocol(&install_path)
+                .map_err(|e| format!("Registry error: {}", e))?;
+            windows_state.url_protocol_registered = true;
 
             // Desktop shortcut
             if options.desktop_shortcut {
-                emit_progress(&window, "shortcuts", 70, "Creating desktop shortcut...");
-                shortcut::create_desktop_shortcut(&install_path)
+                emit_progress(report, "shortcuts", 70, "Creating desktop shortcut...");
+                shortcut::create_desktop_shortcut(&install_path, &shortcut_names)
                     .map_err(|e| format!("Shortcut error: {}", e))?;
                 windows_state.desktop_shortcut_created = true;
             }
 
             // Start Menu
             if options.start_menu {
-                emit_progress(&window, "shortcuts", 75, "Creating Start Menu entry...");
-                shortcut::create_start_menu_shortcut(&install_path)
+                emit_progress(report, "shortcuts", 75, "Creating Start Menu entry...");
+                shortcut::create_start_menu_shortcut(&install_path, &shortcut_names)
                     .map_err(|e| format!("Start Menu error: {}", e))?;
                 windows_state.start_menu_shortcut_created = true;
             }
         }
 
         // Step 4: Save first-launch language preference for BitFun app.
-        emit_progress(&window, "config", 92, "Applying startup preferences...");
+        check_cancelled()?;
+        emit_progress(report, "config", 92, "Applying startup preferences...");
         apply_first_launch_language(&options.app_language)
             .map_err(|e| format!("Failed to apply startup preferences: {}", e))?;
         // Step 5: Done
-        emit_progress(&window, "complete", 100, "Installation complete!");
+        emit_progress(report, "complete", 100, "Installation complete!");
         Ok(())
     })();
 
@@ -612,29 +678,195 @@ pub(crate) async fn start_installation(
         return Err(err);
     }
 
-    persist_last_install_path(&install_path);
+    persist_install_state(&install_path, &options, shortcut_names);
+    write_first_launch_handoff(&FirstLaunchHandoff {
+        schema_version: FIRST_LAUNCH_HANDOFF_SCHEMA_VERSION,
+        installer_version: env!("CARGO_PKG_VERSION").to_string(),
+        installed_at_ms: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|elapsed| elapsed.as_millis() as u64),
+        install_path: install_path.to_string_lossy().to_string(),
+        components: installed_components(&options),
+        app_language: normalize_app_language(&options.app_language).map(str::to_string),
+        theme_preference: None,
+        model_configured: false,
+    });
 
     Ok(())
 }
 
+/// Re-verify an installation against the payload manifest, restore missing or
+/// damaged files from the embedded payload, and re-create registry entries and
+/// shortcuts recorded at install time.
+#[tauri::command]
+pub(crate) async fn repair_installation(install_path: String) -> Result<RepairReport, String> {
+    let install_path = PathBuf::from(install_path.trim());
+    if !install_path.is_dir() {
+        return Err(format!(
+            "Install directory not found: {}",
+            install_path.display()
+        ));
+    }
+    if !embedded_payload_available() {
+        return Err("This installer has no embedded payload to repair from".to_string());
+    }
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(EMBEDDED_PAYLOAD_ZIP))
+        .map_err(|e| format!("Invalid zip from embedded payload zip: {e}"))?;
+    let manifest = read_payload_manifest_from_zip_archive(&mut archive, "embedded payload zip")?;
+    let files = manifest
+        .files
+        .iter()
+        .map(|file| Ok((sanitize_manifest_relative_path(&file.path)?, file)))
+        .collect::<Result<Vec<_>, String>>()?;
+    let outcome = repair::repair_payload_files(&mut archive, &files, &install_path)?;
+    verify_installed_payload(&install_path, &manifest)?;
+
+    #[allow(unused_mut)]
+    let mut restored_integrations = Vec::new();
+    #[cfg(target_os = "windows")]
+    repair_windows_integrations(&install_path, &mut restored_integrations)?;
+
+    log::info!(
+        "Repair finished: install_path={}, verified_files={}, restored_files={}, restored_integrations={}",
+        install_path.display(),
+        outcome.verified,
+        outcome.restored.len(),
+        restored_integrations.join(",")
+    );
+    Ok(RepairReport {
+        install_path: install_path.to_string_lossy().to_string(),
+        verified_files: outcome.verified,
+        restored_files: outcome.restored,
+        restored_integrations,
+    })
+}
+
+/// Re-creates whatever `start_installation` registered that is now missing or
+/// points at another directory. Shortcuts follow the recorded install options
+/// and names, and are left alone for installs without them.
+#[cfg(target_os = "windows")]
+fn repair_windows_integrations(
+    install_path: &Path,
+    restored: &mut Vec<String>,
+) -> Result<(), String> {
+    use super::registry;
+    use super::shortcut;
+
+    let points_here = |location: Option<String>| {
+        location
+            .map(|location| windows_path_eq_case_insensitive(Path::new(&location), install_path))
+            .unwrap_or(false)
+    };
+
+    let uninstaller_path = install_path.join("uninstall.exe");
+    if !uninstaller_path.is_file() {
+        let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
+        std::fs::copy(&current_exe, &uninstaller_path)
+            .map_err(|e| format!("Failed to restore uninstaller executable: {}", e))?;
+        restored.push("uninstaller".to_string());
+    }
+
+    if !points_here(registry::read_tauri_install_location()) {
+        registry::register_tauri_install_location(install_path)
+            .map_err(|e| format!("Registry error: {}", e))?;
+        restored.push("installLocation".to_string());
+    }
+
+    let recorded = read_installer_state().and_then(|state| state.shortcuts);
+    let registered_names = registry::read_registered_shortcut_names();
+    let shortcut_names = registered_names
+        .clone()
+        .or_else(|| recorded.as_ref().map(|recorded| recorded.names.clone()))
+        .unwrap_or_default();
+
+    let uninstall_entry = registry::read_existing_install_from_uninstall_registry();
+    let uninstall_entry_ok = registered_names.is_some()
+        && uninstall_entry
+            .map(|entry| {
+                entry.uninstall_string.is_some() && points_here(Some(entry.install_location))
+            })
+            .unwrap_or(false);
+    if !uninstall_entry_ok {
+        registry::register_uninstall_entry(
+            install_path,
+            env!("CARGO_PKG_VERSION"),
+            &format!("\"{}\"", uninstaller_path.display()),
+            &shortcut_names,
+        )
+        .map_err(|e| format!("Registry error: {}", e))?;
+        restored.push("uninstallEntry".to_string());
+    }
+
+    if !registry::url_protocol_points_to(install_path) {
+        registry::register_url_protocol(install_path)
+            .map_err(|e| format!("Registry error: {}", e))?;
+        restored.push("urlProtocol".to_string());
+    }
+
+    let Some(shortcuts) = recorded else {
+        log::info!("No recorded shortcut options, skipping shortcut repair");
+        return Ok(());
+    };
+    if shortcuts.desktop_shortcut && !shortcut::desktop_shortcut_exists(&shortcut_names) {
+        shortcut::create_desktop_shortcut(install_path, &shortcut_names)
+            .map_err(|e| format!("Shortcut error: {}", e))?;
+        restored.push("desktopShortcut".to_string());
+    }
+    if shortcuts.start_menu && !shortcut::start_menu_shortcut_exists(&shortcut_names) {
+        shortcut::create_start_menu_shortcut(install_path, &shortcut_names)
+            .map_err(|e| format!("Start Menu error: {}", e))?;
+        restored.push("startMenuShortcut".to_string());
+    }
+    Ok(())
+}
+
 /// Uninstall BitFun (for the uninstaller companion).
+///
+/// Returns what was kept and what could not be removed instead of failing on
+/// the first locked file.
 #[tauri::command]
-pub(crate) async fn uninstall(install_path: String) -> Result<(), String> {
+pub(crate) async fn uninstall(install_path: String) -> Result<UninstallReport, String> {
     let install_path = PathBuf::from(&install_path);
     let uninstall_targets = collect_uninstall_targets(&install_path)?;
+    let mut failed_removals = Vec::new();
 
     #[cfg(target_os = "windows")]
     {
         use super::registry;
         use super::shortcut;
 
-        let _ = shortcut::remove_desktop_shortcut();
-        let _ = shortcut::remove_start_menu_shortcut();
-        let _ = registry::remove_context_menu();
-        let _ = registry::remove_from_path(&install_path);
-        let _ = registry::remove_autostart_run_entry();
-        let _ = registry::remove_tauri_install_location();
-        let _ = registry::remove_uninstall_entry();
+        // Read before the uninstall entry holding the names is removed.
+        let shortcut_names = registry::read_registered_shortcut_names()
+            .or_else(|| {
+                read_installer_state()
+                    .and_then(|state| state.shortcuts)
+                    .map(|shortcuts| shortcuts.names)
+            })
+            .unwrap_or_default();
+        let integrations = [
+            (
+                "desktopShortcut",
+                shortcut::remove_desktop_shortcut(&shortcut_names),
+            ),
+            (
+                "startMenuShortcut",
+                shortcut::remove_start_menu_shortcut(&shortcut_names),
+            ),
+            ("contextMenu", registry::remove_context_menu()),
+            ("urlProtocol", registry::remove_url_protocol()),
+            ("path", registry::remove_from_path(&install_path)),
+            ("autostart", registry::remove_autostart_run_entry()),
+            ("installLocation", registry::remove_tauri_install_location()),
+            ("uninstallEntry", registry::remove_uninstall_entry()),
+        ];
+        for (item, result) in integrations {
+            if let Err(e) = result {
+                append_uninstall_runtime_log(&format!("failed to remove {}: {}", item, e));
+                failed_removals.push(leftovers::failed_integration_removal(item, e));
+            }
+        }
     }
 
     #[cfg(target_os = "windows")]
@@ -666,7 +898,11 @@ pub(crate) async fn uninstall(install_path: String) -> Result<(), String> {
         ));
 
         let current_exe_path = current_exe.as_deref();
-        remove_installed_targets(&install_path, &uninstall_targets, current_exe_path)?;
+        failed_removals.extend(remove_installed_targets(
+            &install_path,
+            &uninstall_targets,
+            current_exe_path,
+        ));
 
         if (running_uninstall_binary || running_from_install_dir)
             && current_exe_path
@@ -680,8 +916,30 @@ pub(crate) async fn uninstall(install_path: String) -> Result<(), String> {
     }
 
     #[cfg(not(target_os = "windows"))]
-    remove_installed_targets(&install_path, &uninstall_targets, None)?;
-    Ok(())
+    failed_removals.extend(remove_installed_targets(
+        &install_path,
+        &uninstall_targets,
+        None,
+    ));
+
+    // Integrations are reported by name, files by their absolute path.
+    let leftover_files = failed_removals
+        .iter()
+        .any(|failure| Path::new(&failure.item).is_absolute());
+    let report = UninstallReport {
+        install_path: install_path.display().to_string(),
+        kept_data: leftovers::collect_kept_data(),
+        cleanup_command: leftover_files
+            .then(|| leftovers::cleanup_command(std::slice::from_ref(&install_path)))
+            .flatten(),
+        failed_removals,
+    };
+    log::info!(
+        "Uninstall finished: kept_data={}, failed_removals={}",
+        report.kept_data.len(),
+        report.failed_removals.len()
+    );
+    Ok(report)
 }
 
 #[cfg(target_os = "windows")]
@@ -805,20 +1063,13 @@ pub(crate) fn close_installer(window: Window) {
 }
 
 /// Save theme preference for first launch (called after installation).
+///
+/// The app's theme registry owns the list of available themes (built-in plus
+/// user theme files) and falls back to the default for ids it cannot resolve,
+/// so the installer only checks that the id is well-formed.
 #[tauri::command]
 pub(crate) fn set_theme_preference(theme_preference: String) -> Result<(), String> {
-    let allowed = [
-        "system",
-        "bitfun-dark",
-        "bitfun-light",
-        "bitfun-midnight",
-        "bitfun-china-style",
-        "bitfun-china-night",
-        "bitfun-cyber",
-        "bitfun-slate",
-        "bitfun-tokyo-night",
-    ];
-    if !allowed.contains(&theme_preference.as_str()) {
+    if !is_valid_theme_id(&theme_preference) {
         return Err("Unsupported theme preference".to_string());
     }
 
@@ -834,15 +1085,33 @@ pub(crate) fn set_theme_preference(theme_preference: String) -> Result<(), Strin
         .or_insert_with(|| Value::Object(Map::new()))
         .as_object_mut()
         .ok_or_else(|| "Invalid themes config object".to_string())?;
-    themes_obj.insert("current".to_string(), Value::String(theme_preference));
+    themes_obj.insert(
+        "current".to_string(),
+        Value::String(theme_preference.clone()),
+    );
 
-    write_root_config(&app_config_file, &root)
+    write_root_config(&app_config_file, &root)?;
+    update_first_launch_handoff(|handoff| handoff.theme_preference = Some(theme_preference));
+    Ok(())
+}
+
+/// Mirrors `is_valid_theme_id` in `bitfun_core::service::theme`.
+fn is_valid_theme_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && !id.starts_with('-')
+        && !id.ends_with('-')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
 }
 
 /// Save default model configuration for first launch (called after installation).
 #[tauri::command]
 pub(crate) fn set_model_config(model_config: ModelConfig) -> Result<(), String> {
-    apply_first_launch_model(&model_config)
+    apply_first_launch_model(&model_config)?;
+    update_first_launch_handoff(|handoff| handoff.model_configured = true);
+    Ok(())
 }
 
 /// Validate model configuration connectivity from installer (same stack as desktop `test_ai_config_connection`).
@@ -1042,13 +1311,16 @@ fn parse_custom_request_body(raw: &Option<String>) -> Result<Option<Map<String,
     Ok(Some(obj.clone()))
 }
 
-fn emit_progress(window: &Window, step: &str, percent: u32, message: &str) {
+/// Receives every `install-progress` payload of an installation.
+pub(super) type ProgressSink<'a> = &'a dyn Fn(&InstallProgress);
+
+fn emit_progress(report: ProgressSink<'_>, step: &str, percent: u32, message: &str) {
     let progress = InstallProgress {
         step: step.to_string(),
         percent,
         message: message.to_string(),
     };
-    let _ = window.emit("install-progress", &progress);
+    report(&progress);
     log::info!("[{}%] {}: {}", percent, step, message);
 }
 
@@ -1080,10 +1352,10 @@ struct PayloadCandidate {
     is_zip: bool,
 }
 
-fn build_payload_candidates(window: &Window, exe_dir: &Path) -> Vec<PayloadCandidate> {
+fn build_payload_candidates(resource_dir: Option<&Path>, exe_dir: &Path) -> Vec<PayloadCandidate> {
     let mut candidates = Vec::new();
 
-    if let Ok(resource_dir) = window.app_handle().path().resource_dir() {
+    if let Some(resource_dir) = resource_dir {
         candidates.push(PayloadCandidate {
             label: "resource_dir/payload.zip".to_string(),
             path: resource_dir.join("payload.zip"),
@@ -1131,6 +1403,37 @@ fn build_payload_candidates(window: &Window, exe_dir: &Path) -> Vec<PayloadCandi
     candidates
 }
 
+/// Other BitFun installations that clash with a per-user install into
+/// `install_path`. Only Windows has machine-wide installs to detect.
+fn detect_install_conflicts(install_path: &Path) -> Vec<InstallConflict> {
+    #[cfg(target_os = "windows")]
+    {
+        super::conflicts::detect_install_conflicts(install_path)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = install_path;
+        Vec::new()
+    }
+}
+
+/// Low disk space keeps its stable `INSTALL_DISK_SPACE::` form so the
+/// frontend can show the volume and shortfall, and cancellation stays
+/// `INSTALL_CANCELLED`; other failures get `context`.
+fn extraction_error(context: &str, error: anyhow::Error) -> String {
+    if error.is::<InstallCancelled>() {
+        return INSTALL_CANCELLED_ERR.to_string();
+    }
+    match error.downcast_ref::<LowDiskSpace>() {
+        Some(low_space) => low_space.to_install_error(),
+        None => format!("{}: {}", context, error),
+    }
+}
+
+fn check_cancelled() -> Result<(), String> {
+    cancel::check().map_err(|_| INSTALL_CANCELLED_ERR.to_string())
+}
+
 fn find_existing_ancestor(path: &Path) -> PathBuf {
     let mut current = path.to_path_buf();
     while !current.exists() {
@@ -1234,13 +1537,17 @@ fn installer_state_path() -> Result<PathBuf, String> {
     Ok(parent.join(INSTALLER_STATE_FILE))
 }
 
-fn read_last_install_path() -> Option<String> {
+fn read_installer_state() -> Option<InstallerState> {
     let state_path = installer_state_path().ok()?;
     if !state_path.exists() {
         return None;
     }
     let content = std::fs::read_to_string(&state_path).ok()?;
-    let state: InstallerState = serde_json::from_str(&content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn read_last_install_path() -> Option<String> {
+    let state = read_installer_state()?;
     let trimmed = state.last_install_path.trim();
     if trimmed.is_empty() {
         return None;
@@ -1248,13 +1555,18 @@ fn read_last_install_path() -> Option<String> {
     Some(trimmed.to_string())
 }
 
-fn persist_last_install_path(install_path: &Path) {
+fn persist_install_state(install_path: &Path, options: &InstallOptions, names: ShortcutNames) {
     let Ok(state_path) = installer_state_path() else {
         log::warn!("Could not resolve installer state path");
         return;
     };
     let state = InstallerState {
         last_install_path: install_path.to_string_lossy().to_string(),
+        shortcuts: Some(RecordedShortcutOptions {
+            desktop_shortcut: options.desktop_shortcut,
+            start_menu: options.start_menu,
+            names,
+        }),
     };
     let body = match serde_json::to_string_pretty(&state) {
         Ok(b) => b,
@@ -1268,7 +1580,68 @@ fn persist_last_install_path(install_path: &Path) {
     }
 }
 
-fn read_saved_app_language() -> Option<String> {
+fn first_launch_handoff_path() -> Result<PathBuf, String> {
+    let app_config_file = ensure_app_config_path()?;
+    let parent = app_config_file
+        .parent()
+        .ok_or_else(|| "Invalid app config path".to_string())?;
+    Ok(parent.join(FIRST_LAUNCH_HANDOFF_FILE))
+}
+
+fn write_first_launch_handoff(handoff: &FirstLaunchHandoff) {
+    let Ok(handoff_path) = first_launch_handoff_path() else {
+        log::warn!("Could not resolve first-launch handoff path");
+        return;
+    };
+    let body = match serde_json::to_string_pretty(handoff) {
+        Ok(b) => b,
+        Err(e) => {
+            log::warn!("Failed to serialize first-launch handoff: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = std::fs::write(&handoff_path, body) {
+        log::warn!("Failed to write first-launch handoff: {}", e);
+    }
+}
+
+/// Records a choice made after the files were installed. Does nothing when
+/// the app has already consumed the handoff.
+fn update_first_launch_handoff(update: impl FnOnce(&mut FirstLaunchHandoff)) {
+    let Some(mut handoff) = first_launch_handoff_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str::<FirstLaunchHandoff>(&content).ok())
+    else {
+        return;
+    };
+    update(&mut handoff);
+    write_first_launch_handoff(&handoff);
+}
+
+/// Integrations created by a successful install; names match
+/// `RepairReport::restored_integrations`. Always empty outside Windows, where
+/// the installer only copies files.
+fn installed_components(options: &InstallOptions) -> Vec<String> {
+    if !cfg!(target_os = "windows") {
+        return Vec::new();
+    }
+    let mut components = vec![
+        "uninstaller".to_string(),
+        "installLocation".to_string(),
+        "uninstallEntry".to_string(),
+        "urlProtocol".to_string(),
+    ];
+    if options.desktop_shortcut {
+        components.push("desktopShortcut".to_string());
+    }
+    if options.start_menu {
+        components.push("startMenuShortcut".to_string());
+    }
+    components
+}
+
+pub(super) fn read_saved_app_language() -> Option<String> {
     let app_config_file = ensure_app_config_path().ok()?;
     if !app_config_file.exists() {
         return None;
@@ -1486,27 +1859,58 @@ fn apply_first_launch_model(model: &ModelConfig) -> Result<(), String> {
 
 fn preflight_validate_payload_zip_bytes(
     zip_bytes: &[u8],
+    manifest: &PayloadManifest,
     source_label: &str,
 ) -> Result<(), String> {
     let reader = Cursor::new(zip_bytes);
     let mut archive = zip::ZipArchive::new(reader)
         .map_err(|e| format!("Invalid zip from {source_label}: {e}"))?;
-    preflight_validate_payload_zip_archive(&mut archive, source_label)
+    preflight_validate_payload_zip_archive(&mut archive, manifest, source_label)
 }
 
-fn preflight_validate_payload_zip_file(path: &Path, source_label: &str) -> Result<(), String> {
+fn preflight_validate_payload_zip_file(
+    path: &Path,
+    manifest: &PayloadManifest,
+    source_label: &str,
+) -> Result<(), String> {
     let file = File::open(path)
         .map_err(|e| format!("Failed to open payload zip ({source_label}): {e}"))?;
     let mut archive = zip::ZipArchive::new(file)
         .map_err(|e| format!("Invalid payload zip ({source_label}): {e}"))?;
-    preflight_validate_payload_zip_archive(&mut archive, source_label)
+    preflight_validate_payload_zip_archive(&mut archive, manifest, source_label)
+}
+
+/// The manifest entry for the main executable, which must carry a hash so
+/// a truncated or tampered payload is caught before anything is extracted.
+fn main_app_manifest_entry<'a>(
+    manifest: &'a PayloadManifest,
+    source_label: &str,
+) -> Result<&'a PayloadManifestFile, String> {
+    let entry = manifest
+        .files
+        .iter()
+        .find(|file| file.path.eq_ignore_ascii_case(MAIN_APP_EXE))
+        .ok_or_else(|| {
+            format!(
+                "Payload manifest from {source_label} does not list {}",
+                MAIN_APP_EXE
+            )
+        })?;
+    if entry.sha256.is_none() {
+        return Err(format!(
+            "Payload manifest from {source_label} has no SHA-256 for {}",
+            MAIN_APP_EXE
+        ));
+    }
+    Ok(entry)
 }
 
 fn preflight_validate_payload_zip_archive<R: std::io::Read + std::io::Seek>(
     archive: &mut zip::ZipArchive<R>,
+    manifest: &PayloadManifest,
     source_label: &str,
 ) -> Result<(), String> {
-    let mut exe_size: Option<u64> = None;
+    let expected = main_app_manifest_entry(manifest, source_label)?;
     for i in 0..archive.len() {
         let file = archive
             .by_index(i)
@@ -1515,40 +1919,50 @@ fn preflight_validate_payload_zip_archive<R: std::io::Read + std::io::Seek>(
             continue;
         }
         let file_name = zip_entry_file_name(file.name());
-        if file_name.eq_ignore_ascii_case(MAIN_APP_EXE) {
-            exe_size = Some(file.size());
-            break;
+        if !file_name.eq_ignore_ascii_case(MAIN_APP_EXE) {
+            continue;
+        }
+
+        let actual = hash_reader(file, HashAlgorithm::Sha256, |_| {})
+            .map_err(|e| format!("Failed to read payload entry ({source_label}): {e}"))?;
+        let size_matches = expected.size.is_none_or(|size| size == actual.size_bytes);
+        let hash_matches = expected
+            .sha256
+            .as_deref()
+            .is_some_and(|hash| actual.hex.eq_ignore_ascii_case(hash.trim()));
+        if !size_matches || !hash_matches {
+            return Err(format!(
+                "Payload {} from {source_label} does not match its manifest",
+                MAIN_APP_EXE
+            ));
         }
+        return Ok(());
     }
 
-    let size = exe_size.ok_or_else(|| {
-        format!(
-            "Payload from {source_label} does not contain {}",
-            MAIN_APP_EXE
-        )
-    })?;
-    validate_payload_exe_size(size, source_label)
+    Err(format!(
+        "Payload from {source_label} does not contain {}",
+        MAIN_APP_EXE
+    ))
 }
 
-fn preflight_validate_payload_dir(path: &Path, source_label: &str) -> Result<(), String> {
+fn preflight_validate_payload_dir(
+    path: &Path,
+    manifest: &PayloadManifest,
+    source_label: &str,
+) -> Result<(), String> {
+    let expected = main_app_manifest_entry(manifest, source_label)?;
     let app_exe = path.join(MAIN_APP_EXE);
-    let meta = std::fs::metadata(&app_exe).map_err(|_| {
-        format!(
+    match repair::inspect_installed_file(&app_exe, expected)? {
+        None => Ok(()),
+        Some(repair::PayloadFileDamage::Missing) => Err(format!(
             "Payload directory from {source_label} does not contain {}",
             app_exe.display()
-        )
-    })?;
-    validate_payload_exe_size(meta.len(), source_label)
-}
-
-fn validate_payload_exe_size(size: u64, source_label: &str) -> Result<(), String> {
-    if size < MIN_WINDOWS_APP_EXE_BYTES {
-        return Err(format!(
-            "Payload {} from {source_label} is too small ({size} bytes)",
+        )),
+        Some(_) => Err(format!(
+            "Payload {} from {source_label} does not match its manifest",
             MAIN_APP_EXE
-        ));
+        )),
     }
-    Ok(())
 }
 
 fn read_payload_manifest_from_zip_bytes(
@@ -1669,7 +2083,8 @@ fn remove_installed_targets(
     install_path: &Path,
     targets: &[PathBuf],
     skip_file: Option<&Path>,
-) -> Result<(), String> {
+) -> Vec<FailedRemoval> {
+    let mut failed = Vec::new();
     for path in targets {
         if skip_file
             .map(|skip| paths_equal_for_platform(path, skip))
@@ -1678,23 +2093,23 @@ fn remove_installed_targets(
             continue;
         }
 
-        if !path.exists() {
+        if !path.is_file() {
             continue;
         }
 
-        if path.is_file() {
-            std::fs::remove_file(path).map_err(|e| {
-                format!("Failed to remove installed file {}: {}", path.display(), e)
-            })?;
+        if let Err(e) = std::fs::remove_file(path) {
+            log::warn!("Failed to remove installed file {}: {}", path.display(), e);
+            failed.push(leftovers::failed_file_removal(path, &e));
         }
     }
 
+    // Folders that still hold user files are kept on purpose.
     for dir in collect_parent_directories(install_path, targets) {
         let _ = std::fs::remove_dir(&dir);
     }
     let _ = std::fs::remove_dir(install_path);
 
-    Ok(())
+    failed
 }
 
 fn collect_parent_directories(root: &Path, paths: &[PathBuf]) -> Vec<PathBuf> {
@@ -1738,23 +2153,19 @@ fn sanitize_manifest_relative_path(raw: &str) -> Result<PathBuf, String> {
     Ok(path)
 }
 
-fn verify_installed_payload(install_path: &Path) -> Result<(), String> {
-    let app_exe = install_path.join(MAIN_APP_EXE);
-    let app_meta = std::fs::metadata(&app_exe).map_err(|_| {
-        format!(
+fn verify_installed_payload(install_path: &Path, manifest: &PayloadManifest) -> Result<(), String> {
+    let expected = main_app_manifest_entry(manifest, "installed payload")?;
+    match repair::inspect_installed_file(&install_path.join(MAIN_APP_EXE), expected)? {
+        None => Ok(()),
+        Some(repair::PayloadFileDamage::Missing) => Err(format!(
             "Installed {} is missing after extraction",
             MAIN_APP_EXE
-        )
-    })?;
-    if app_meta.len() < MIN_WINDOWS_APP_EXE_BYTES {
-        return Err(format!(
-            "Installed {} is too small ({} bytes). Payload is likely invalid.",
-            MAIN_APP_EXE,
-            app_meta.len()
-        ));
+        )),
+        Some(_) => Err(format!(
+            "Installed {} does not match the payload manifest. Payload is likely invalid.",
+            MAIN_APP_EXE
+        )),
     }
-
-    Ok(())
 }
 
 fn paths_equal_for_platform(a: &Path, b: &Path) -> bool {
@@ -1784,14 +2195,17 @@ fn rollback_installation(
         let _ = registry::remove_tauri_install_location();
     }
     if windows_state.start_menu_shortcut_created {
-        let _ = shortcut::remove_start_menu_shortcut();
+        let _ = shortcut::remove_start_menu_shortcut(&windows_state.shortcut_names);
     }
     if windows_state.desktop_shortcut_created {
-        let _ = shortcut::remove_desktop_shortcut();
+        let _ = shortcut::remove_desktop_shortcut(&windows_state.shortcut_names);
     }
     if windows_state.uninstall_registered {
         let _ = registry::remove_uninstall_entry();
     }
+    if windows_state.url_protocol_registered {
+        let _ = registry::remove_url_protocol();
+    }
 
     if install_dir_was_absent && install_path.exists() {
         let _ = std::fs::remove_dir_all(install_path);
@@ -1808,7 +2222,9 @@ fn rollback_installation(install_path: &Path, install_dir_was_absent: bool) {
 
 #[cfg(test)]
 mod tests {
-    use super::{normalize_app_language, INSTALLER_APP_LANGUAGE_ALIASES_BY_PRIORITY};
+    use super::{
+        is_valid_theme_id, normalize_app_language, INSTALLER_APP_LANGUAGE_ALIASES_BY_PRIORITY,
+    };
 
     #[test]
     fn language_alias_priority_is_descending_and_stable_for_equal_lengths() {
@@ -1850,4 +2266,15 @@ mod tests {
         assert_eq!(normalize_app_language("fr-FR"), None);
         assert_eq!(normalize_app_language(""), None);
     }
+
+    #[test]
+    fn theme_preference_accepts_builtin_and_user_theme_ids() {
+        assert!(is_valid_theme_id("system"));
+        assert!(is_valid_theme_id("bitfun-tokyo-night"));
+        assert!(is_valid_theme_id("my-ocean-2"));
+        assert!(!is_valid_theme_id(""));
+        assert!(!is_valid_theme_id("../app"));
+        assert!(!is_valid_theme_id("Ocean"));
+        assert!(!is_valid_theme_id("-ocean"));
+    }
 }
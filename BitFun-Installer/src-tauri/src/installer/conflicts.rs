@@ -0,0 +1,157 @@
+//! Detection of other BitFun installations on this machine.
+//!
+//! This installer installs per user (`HKCU`). A per-machine install, or
+//! another user's install referenced from `PATH`, is reported before
+//! installing so the machine does not end up with two Add/Remove Programs
+//! entries or several `bitfun-desktop.exe` folders on `PATH`.
+
+use std::path::Path;
+
+use super::registry;
+use super::types::{InstallConflict, InstallConflictKind};
+use super::MAIN_APP_EXE;
+
+/// Conflicts for a per-user install into `install_path`.
+pub(super) fn detect_install_conflicts(install_path: &Path) -> Vec<InstallConflict> {
+    let install_path = install_path.to_string_lossy();
+    let mut conflicts = Vec::new();
+    if let Some(data) = registry::read_per_machine_install() {
+        conflicts.push(InstallConflict {
+            kind: InstallConflictKind::PerMachineInstall,
+            location: data.install_location,
+            blocking: true,
+        });
+    }
+
+    let user_profile = std::env::var("USERPROFILE").ok();
+    for entry in registry::read_path_entries() {
+        let entry = expand_env_vars(&entry);
+        if !Path::new(&entry).join(MAIN_APP_EXE).is_file()
+            || conflicts
+                .iter()
+                .any(|conflict| same_windows_path(&conflict.location, &entry))
+        {
+            continue;
+        }
+        if let Some(kind) = classify_path_entry(&entry, &install_path, user_profile.as_deref()) {
+            conflicts.push(InstallConflict {
+                kind,
+                location: entry,
+                blocking: false,
+            });
+        }
+    }
+
+    for conflict in &conflicts {
+        log::info!(
+            "Detected other BitFun installation: kind={:?}, location={}, blocking={}",
+            conflict.kind,
+            conflict.location,
+            conflict.blocking
+        );
+    }
+    conflicts
+}
+
+/// Classifies a `PATH` folder containing BitFun. The folder being installed
+/// into is not a conflict.
+fn classify_path_entry(
+    entry: &str,
+    install_path: &str,
+    user_profile: Option<&str>,
+) -> Option<InstallConflictKind> {
+    if same_windows_path(entry, install_path) {
+        return None;
+    }
+    let other_user = user_profile.is_some_and(|profile| {
+        let profile = normalize_windows_path(profile);
+        let profiles_root = profile.rsplit_once('\\').map(|(root, _)| root);
+        !is_under(entry, &profile) && profiles_root.is_some_and(|root| is_under(entry, root))
+    });
+    Some(if other_user {
+        InstallConflictKind::OtherUserInstall
+    } else {
+        InstallConflictKind::PathEntry
+    })
+}
+
+/// Lowercase, backslash-separated, without quotes or trailing separators.
+fn normalize_windows_path(path: &str) -> String {
+    path.trim()
+        .trim_matches('"')
+        .replace('/', "\\")
+        .trim_end_matches('\\')
+        .to_lowercase()
+}
+
+fn same_windows_path(a: &str, b: &str) -> bool {
+    normalize_windows_path(a) == normalize_windows_path(b)
+}
+
+fn is_under(path: &str, root: &str) -> bool {
+    let path = normalize_windows_path(path);
+    let root = normalize_windows_path(root);
+    !root.is_empty() && (path == root || path.starts_with(&format!("{}\\", root)))
+}
+
+/// Expands `%NAME%` references the way `REG_EXPAND_SZ` `PATH` values do.
+/// Unknown variables are kept as written.
+fn expand_env_vars(value: &str) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('%') {
+        let Some(len) = rest[start + 1..].find('%') else {
+            break;
+        };
+        let name = &rest[start + 1..start + 1 + len];
+        expanded.push_str(&rest[..start]);
+        match std::env::var(name) {
+            Ok(value) if !name.is_empty() => expanded.push_str(&value),
+            _ => expanded.push_str(&rest[start..start + len + 2]),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_path_entry, expand_env_vars};
+    use crate::installer::types::InstallConflictKind;
+
+    #[test]
+    fn path_entries_are_classified_by_owner() {
+        let install = r"C:\Users\alice\AppData\Local\Programs\BitFun";
+        let profile = Some(r"C:\Users\alice");
+
+        assert_eq!(
+            classify_path_entry(
+                r"c:\users\alice\appdata\local\programs\bitfun\",
+                install,
+                profile
+            ),
+            None
+        );
+        assert_eq!(
+            classify_path_entry(r"C:\Users\bob\AppData\Local\BitFun", install, profile),
+            Some(InstallConflictKind::OtherUserInstall)
+        );
+        assert_eq!(
+            classify_path_entry(r"C:\Users\alice\Tools\BitFun", install, profile),
+            Some(InstallConflictKind::PathEntry)
+        );
+        assert_eq!(
+            classify_path_entry(r"D:\Apps\BitFun", install, profile),
+            Some(InstallConflictKind::PathEntry)
+        );
+    }
+
+    #[test]
+    fn unknown_env_vars_are_kept() {
+        assert_eq!(
+            expand_env_vars(r"%BITFUN_TEST_UNSET_VAR%\BitFun;50%"),
+            r"%BITFUN_TEST_UNSET_VAR%\BitFun;50%"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Free-space checks for the install volume.
+//!
+//! `get_disk_space` is only a pre-check; other programs can keep filling the
+//! volume while the payload is extracted. Extraction re-checks free space
+//! against the bytes it still has to write and stops before the disk is full.
+
+use serde_json::json;
+use std::fmt;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+/// Matches `INSTALL_DISK_SPACE_ERROR_PREFIX` in `src/utils/diskSpaceErrors.ts`.
+/// The rest of the message is a JSON object with the volume and byte counts.
+pub(super) const INSTALL_DISK_SPACE_ERR_PREFIX: &str = "INSTALL_DISK_SPACE::";
+
+/// Free space left over after the payload, so the volume is never filled to
+/// the last byte.
+const FREE_SPACE_MARGIN: u64 = 32 * 1024 * 1024;
+
+/// Bytes written between two free-space queries.
+const CHECK_INTERVAL_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct DiskSpace {
+    pub total: u64,
+    pub available: u64,
+}
+
+/// Queries the volume holding `path`, which must exist. Returns `None` where
+/// no query is implemented or the query fails.
+#[cfg(target_os = "windows")]
+pub(super) fn query_disk_space(path: &Path) -> Option<DiskSpace> {
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetDiskFreeSpaceExW(
+            lpDirectoryName: *const u16,
+            lpFreeBytesAvailableToCaller: *mut u64,
+            lpTotalNumberOfBytes: *mut u64,
+            lpTotalNumberOfFreeBytes: *mut u64,
+        ) -> i32;
+    }
+
+    let wide_path: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut free_bytes_available: u64 = 0;
+    let mut total_bytes: u64 = 0;
+    let mut total_free_bytes: u64 = 0;
+    let result = unsafe {
+        GetDiskFreeSpaceExW(
+            wide_path.as_ptr(),
+            &mut free_bytes_available,
+            &mut total_bytes,
+            &mut total_free_bytes,
+        )
+    };
+    (result != 0).then_some(DiskSpace {
+        total: total_bytes,
+        available: free_bytes_available,
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+pub(super) fn query_disk_space(_path: &Path) -> Option<DiskSpace> {
+    None
+}
+
+fn available_space(path: &Path) -> Option<u64> {
+    query_disk_space(path).map(|space| space.available)
+}
+
+/// Drive or share root of `path`, e.g. `C:\` or `\\server\share\`.
+pub(super) fn volume_root(path: &Path) -> PathBuf {
+    let mut root = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => root.push(component.as_os_str()),
+            _ => break,
+        }
+    }
+    if root.as_os_str().is_empty() {
+        path.to_path_buf()
+    } else {
+        root
+    }
+}
+
+/// The install volume cannot hold the rest of the payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct LowDiskSpace {
+    pub volume: String,
+    pub required: u64,
+    pub available: u64,
+}
+
+impl LowDiskSpace {
+    pub fn shortfall(&self) -> u64 {
+        self.required.saturating_sub(self.available)
+    }
+
+    /// Stable error string the frontend turns into a localized message.
+    pub fn to_install_error(&self) -> String {
+        format!(
+            "{}{}",
+            INSTALL_DISK_SPACE_ERR_PREFIX,
+            json!({
+                "volume": self.volume,
+                "requiredBytes": self.required,
+                "availableBytes": self.available,
+                "shortfallBytes": self.shortfall(),
+            })
+        )
+    }
+}
+
+impl fmt::Display for LowDiskSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Not enough disk space on {}: {} more bytes needed ({} required, {} available)",
+            self.volume,
+            self.shortfall(),
+            self.required,
+            self.available
+        )
+    }
+}
+
+impl std::error::Error for LowDiskSpace {}
+
+/// Tracks the bytes still to be written and checks them against the free
+/// space of the target volume.
+pub(super) struct DiskSpaceMonitor {
+    target: PathBuf,
+    remaining: u64,
+    since_check: Option<u64>,
+    probe: fn(&Path) -> Option<u64>,
+}
+
+impl DiskSpaceMonitor {
+    /// `planned_bytes` is the net growth of the target: new files count in
+    /// full, overwritten files only by how much they grow.
+    pub fn new(target: &Path, planned_bytes: u64) -> Self {
+        Self::with_probe(target, planned_bytes, available_space)
+    }
+
+    fn with_probe(target: &Path, planned_bytes: u64, probe: fn(&Path) -> Option<u64>) -> Self {
+        Self {
+            target: target.to_path_buf(),
+            remaining: planned_bytes,
+            since_check: None,
+            probe,
+        }
+    }
+
+    /// Called before writing an entry that grows the target by `bytes`. Free
+    /// space is queried before the first entry and then once per
+    /// `CHECK_INTERVAL_BYTES`.
+    pub fn before_write(&mut self, bytes: u64) -> Result<(), LowDiskSpace> {
+        let due = self.since_check.map_or(true, |written| {
+            written.saturating_add(bytes) >= CHECK_INTERVAL_BYTES
+        });
+        if !due {
+            return Ok(());
+        }
+        self.since_check = Some(0);
+        let Some(available) = (self.probe)(&self.target) else {
+            return Ok(());
+        };
+        let low_space = self.low_space(available);
+        if available < low_space.required {
+            log::warn!("Stopping extraction: {}", low_space);
+            return Err(low_space);
+        }
+        Ok(())
+    }
+
+    pub fn after_write(&mut self, bytes: u64) {
+        self.remaining = self.remaining.saturating_sub(bytes);
+        self.since_check = self
+            .since_check
+            .map(|written| written.saturating_add(bytes));
+    }
+
+    /// Error for a write that failed because the volume filled up.
+    pub fn disk_full(&self) -> LowDiskSpace {
+        let low_space = self.low_space((self.probe)(&self.target).unwrap_or(0));
+        log::warn!("Extraction hit a full disk: {}", low_space);
+        low_space
+    }
+
+    fn low_space(&self, available: u64) -> LowDiskSpace {
+        LowDiskSpace {
+            volume: volume_root(&self.target).display().to_string(),
+            required: self.remaining.saturating_add(FREE_SPACE_MARGIN),
+            available,
+        }
+    }
+}
+
+/// `ERROR_DISK_FULL` / `ERROR_HANDLE_DISK_FULL` on Windows, `ENOSPC` elsewhere.
+pub(super) fn is_disk_full_error(error: &io::Error) -> bool {
+    #[cfg(target_os = "windows")]
+    let codes: &[i32] = &[112, 39];
+    #[cfg(not(target_os = "windows"))]
+    let codes: &[i32] = &[28];
+    error
+        .raw_os_error()
+        .is_some_and(|code| codes.contains(&code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiskSpaceMonitor, CHECK_INTERVAL_BYTES, FREE_SPACE_MARGIN};
+    use std::path::Path;
+
+    const MIB: u64 = 1024 * 1024;
+
+    #[test]
+    fn monitor_reports_shortfall_for_remaining_bytes() {
+        let available = |_: &Path| Some(300 * MIB);
+        let mut monitor = DiskSpaceMonitor::with_probe(Path::new("/install"), 400 * MIB, available);
+
+        let low_space = monitor.before_write(10 * MIB).unwrap_err();
+        assert_eq!(low_space.required, 400 * MIB + FREE_SPACE_MARGIN);
+        assert_eq!(low_space.shortfall(), 100 * MIB + FREE_SPACE_MARGIN);
+
+        let mut monitor = DiskSpaceMonitor::with_probe(Path::new("/install"), 400 * MIB, available);
+        monitor.after_write(200 * MIB);
+        assert!(monitor.before_write(10 * MIB).is_ok());
+    }
+
+    #[test]
+    fn monitor_queries_once_per_interval() {
+        let exhausted = |_: &Path| Some(0);
+        let mut monitor = DiskSpaceMonitor::with_probe(Path::new("/install"), 0, exhausted);
+        monitor.since_check = Some(0);
+
+        assert!(monitor.before_write(CHECK_INTERVAL_BYTES / 2).is_ok());
+        monitor.after_write(CHECK_INTERVAL_BYTES / 2);
+        assert!(monitor.before_write(CHECK_INTERVAL_BYTES / 2).is_err());
+    }
+}
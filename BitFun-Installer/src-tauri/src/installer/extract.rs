@@ -1,12 +1,19 @@
 use anyhow::{Context, Result};
 use std::fs;
 use std::io;
-use std::io::Cursor;
+use std::io::{BufWriter, Cursor, Write};
 use std::path::{Path, PathBuf};
 
+use super::cancel::{self, InstallCancelled};
+use super::disk_space::{is_disk_full_error, DiskSpaceMonitor, LowDiskSpace};
+
 /// Estimated install size in bytes (~200MB for typical Tauri app with WebView)
 pub(super) const ESTIMATED_INSTALL_SIZE: u64 = 200 * 1024 * 1024;
 
+/// Large writes keep extraction of big payload files disk-bound rather than
+/// syscall-bound.
+const EXTRACT_WRITE_BUFFER_BYTES: usize = 1024 * 1024;
+
 /// Extract a zip archive to the target directory with an entry filter.
 pub(super) fn extract_zip_with_filter(
     archive_path: &Path,
@@ -20,7 +27,8 @@ pub(super) fn extract_zip_with_filter(
     extract_zip_archive(archive, target_dir, should_extract)
 }
 
-/// Extract a zip archive from in-memory bytes with an entry filter.
+/// Extract a zip archive from in-memory bytes with an entry filter. Entries
+/// may be zstd or deflate compressed; each one is decoded as a stream.
 pub(super) fn extract_zip_bytes_with_filter(
     archive_bytes: &[u8],
     target_dir: &Path,
@@ -36,26 +44,98 @@ fn extract_zip_archive<R: io::Read + io::Seek>(
     target_dir: &Path,
     should_extract: fn(&Path) -> bool,
 ) -> Result<()> {
+    let mut planned_bytes: u64 = 0;
     for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        let rel_path: PathBuf = file.mangled_name();
-        if !should_extract(&rel_path) {
-            continue;
+        let file = archive.by_index_raw(i)?;
+        let rel_path = file.mangled_name();
+        if !file.is_dir() && should_extract(&rel_path) {
+            planned_bytes += growth_bytes(&target_dir.join(&rel_path), file.size());
         }
-        let out_path = target_dir.join(&rel_path);
+    }
 
-        if file.name().ends_with('/') {
-            fs::create_dir_all(&out_path)?;
-        } else {
-            if let Some(parent) = out_path.parent() {
-                fs::create_dir_all(parent)?;
+    let mut monitor = DiskSpaceMonitor::new(target_dir, planned_bytes);
+    let mut created_files = Vec::new();
+    let result = (|| -> Result<()> {
+        for i in 0..archive.len() {
+            cancel::check()?;
+            let mut file = archive.by_index(i)?;
+            let rel_path: PathBuf = file.mangled_name();
+            if !should_extract(&rel_path) {
+                continue;
+            }
+            let out_path = target_dir.join(&rel_path);
+
+            if file.name().ends_with('/') {
+                fs::create_dir_all(&out_path)?;
+            } else {
+                if let Some(parent) = out_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let growth = growth_bytes(&out_path, file.size());
+                monitor.before_write(growth)?;
+                if !out_path.exists() {
+                    created_files.push(out_path.clone());
+                }
+                write_file(&out_path, &mut file, &monitor)?;
+                monitor.after_write(growth);
             }
-            let mut outfile = fs::File::create(&out_path)?;
-            io::copy(&mut file, &mut outfile)?;
         }
+        Ok(())
+    })();
+    if let Err(e) = &result {
+        remove_files_after_abort(e, &created_files);
     }
+    result
+}
 
-    Ok(())
+/// Streams `reader` into `out_path`. A full volume removes the partial file
+/// and becomes a [`LowDiskSpace`] error.
+fn write_file(
+    out_path: &Path,
+    reader: &mut impl io::Read,
+    monitor: &DiskSpaceMonitor,
+) -> Result<()> {
+    let written = fs::File::create(out_path).and_then(|file| {
+        let mut outfile = BufWriter::with_capacity(EXTRACT_WRITE_BUFFER_BYTES, file);
+        io::copy(reader, &mut outfile)?;
+        outfile.flush()
+    });
+    match written {
+        Ok(()) => Ok(()),
+        Err(e) if is_disk_full_error(&e) => {
+            let _ = fs::remove_file(out_path);
+            Err(monitor.disk_full().into())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// How much writing `size` bytes to `path` grows the volume; overwritten
+/// files only count the difference.
+fn growth_bytes(path: &Path, size: u64) -> u64 {
+    let existing = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+    size.saturating_sub(existing)
+}
+
+/// Removes files this extraction created when it stopped for lack of space
+/// or was cancelled, so an aborted install into an existing folder does not
+/// leave them behind. Other failures are cleaned up by the installer rollback.
+fn remove_files_after_abort(error: &anyhow::Error, created_files: &[PathBuf]) {
+    let reason = if error.is::<LowDiskSpace>() {
+        "running out of disk space"
+    } else if error.is::<InstallCancelled>() {
+        "cancellation"
+    } else {
+        return;
+    };
+    for path in created_files {
+        let _ = fs::remove_file(path);
+    }
+    log::info!(
+        "Removed {} partially installed file(s) after {}",
+        created_files.len(),
+        reason
+    );
 }
 
 /// Copy files from source to target with a relative-path file filter.
@@ -64,7 +144,42 @@ pub(super) fn copy_directory_with_filter(
     target: &Path,
     should_copy_file: fn(&Path) -> bool,
 ) -> Result<u64> {
-    copy_directory_internal(source, target, Path::new(""), should_copy_file)
+    let planned_bytes = planned_copy_bytes(source, target, Path::new(""), should_copy_file)?;
+    let mut monitor = DiskSpaceMonitor::new(target, planned_bytes);
+    let mut created_files = Vec::new();
+    let result = copy_directory_internal(
+        source,
+        target,
+        Path::new(""),
+        should_copy_file,
+        &mut monitor,
+        &mut created_files,
+    );
+    if let Err(e) = &result {
+        remove_files_after_abort(e, &created_files);
+    }
+    result
+}
+
+fn planned_copy_bytes(
+    source: &Path,
+    target: &Path,
+    relative_prefix: &Path,
+    should_copy_file: fn(&Path) -> bool,
+) -> Result<u64> {
+    let mut planned_bytes: u64 = 0;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let rel = relative_prefix.join(&name);
+        let dest = target.join(&name);
+        if entry.file_type()?.is_dir() {
+            planned_bytes += planned_copy_bytes(&entry.path(), &dest, &rel, should_copy_file)?;
+        } else if should_copy_file(&rel) {
+            planned_bytes += growth_bytes(&dest, entry.metadata()?.len());
+        }
+    }
+    Ok(planned_bytes)
 }
 
 fn copy_directory_internal(
@@ -72,6 +187,8 @@ fn copy_directory_internal(
     target: &Path,
     relative_prefix: &Path,
     should_copy_file: fn(&Path) -> bool,
+    monitor: &mut DiskSpaceMonitor,
+    created_files: &mut Vec<PathBuf>,
 ) -> Result<u64> {
     let mut bytes_copied: u64 = 0;
 
@@ -80,6 +197,7 @@ fn copy_directory_internal(
     }
 
     for entry in fs::read_dir(source)? {
+        cancel::check()?;
         let entry = entry?;
         let file_type = entry.file_type()?;
         let name = entry.file_name();
@@ -87,16 +205,84 @@ fn copy_directory_internal(
         let dest = target.join(&name);
 
         if file_type.is_dir() {
-            bytes_copied += copy_directory_internal(&entry.path(), &dest, &rel, should_copy_file)?;
+            bytes_copied += copy_directory_internal(
+                &entry.path(),
+                &dest,
+                &rel,
+                should_copy_file,
+                monitor,
+                created_files,
+            )?;
         } else {
             if !should_copy_file(&rel) {
                 continue;
             }
             let size = entry.metadata()?.len();
-            fs::copy(entry.path(), &dest)?;
+            let growth = growth_bytes(&dest, size);
+            monitor.before_write(growth)?;
+            if !dest.exists() {
+                created_files.push(dest.clone());
+            }
+            if let Err(e) = fs::copy(entry.path(), &dest) {
+                if is_disk_full_error(&e) {
+                    let _ = fs::remove_file(&dest);
+                    return Err(monitor.disk_full().into());
+                }
+                return Err(e.into());
+            }
+            monitor.after_write(growth);
             bytes_copied += size;
         }
     }
 
     Ok(bytes_copied)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::extract_zip_bytes_with_filter;
+    use std::io::{Cursor, Write};
+    use std::path::Path;
+    use zip::write::FileOptions;
+    use zip::CompressionMethod;
+
+    #[test]
+    fn extracts_zstd_and_deflate_entries_from_one_archive() {
+        let mut zip_bytes = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(Cursor::new(&mut zip_bytes));
+            for (name, method) in [
+                ("app.bin", CompressionMethod::Zstd),
+                ("resources/data.txt", CompressionMethod::Deflated),
+                ("payload-manifest.json", CompressionMethod::Zstd),
+            ] {
+                writer
+                    .start_file(name, FileOptions::default().compression_method(method))
+                    .unwrap();
+                writer.write_all(name.repeat(64).as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let target = std::env::temp_dir().join(format!(
+            "bitfun-installer-extract-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&target);
+        extract_zip_bytes_with_filter(&zip_bytes, &target, |path: &Path| {
+            path != Path::new("payload-manifest.json")
+        })
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(target.join("app.bin")).unwrap(),
+            "app.bin".repeat(64)
+        );
+        assert_eq!(
+            std::fs::read_to_string(target.join("resources/data.txt")).unwrap(),
+            "resources/data.txt".repeat(64)
+        );
+        assert!(!target.join("payload-manifest.json").exists());
+        let _ = std::fs::remove_dir_all(&target);
+    }
+}
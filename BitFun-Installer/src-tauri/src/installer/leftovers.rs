@@ -0,0 +1,125 @@
+//! What an uninstall leaves behind.
+//!
+//! User data is kept on purpose so a reinstall picks up where the user left
+//! off. Files that cannot be deleted, usually because BitFun or a tool it
+//! started is still running, are reported with a command the user can run
+//! later instead of being skipped silently.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::types::{FailedRemoval, KeptData, KeptDataKind};
+
+/// User data directories that exist and are kept. Paths mirror the app's
+/// `PathManager` defaults (`user_root_dir`, `user_skills_dir`,
+/// `managed_runtimes_dir`, `bitfun_home_dir`).
+pub(super) fn collect_kept_data() -> Vec<KeptData> {
+    let user_root = dirs::config_dir().map(|dir| dir.join("bitfun"));
+    let skills = if cfg!(target_os = "windows") {
+        dirs::data_dir()
+    } else if cfg!(target_os = "macos") {
+        dirs::home_dir().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        dirs::data_local_dir()
+    }
+    .map(|dir| dir.join("BitFun").join("skills"));
+
+    [
+        (KeptDataKind::Config, user_root.clone()),
+        (KeptDataKind::Skills, skills),
+        (
+            KeptDataKind::Runtimes,
+            user_root.map(|root| root.join("runtimes")),
+        ),
+        (
+            KeptDataKind::AssistantHome,
+            dirs::home_dir().map(|home| home.join(".bitfun")),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(kind, path)| {
+        let path = path.filter(|path| path.is_dir())?;
+        Some(KeptData {
+            kind,
+            path: path.display().to_string(),
+        })
+    })
+    .collect()
+}
+
+/// `ERROR_SHARING_VIOLATION` / `ERROR_LOCK_VIOLATION` on Windows, `ETXTBSY`
+/// elsewhere. Windows reports `ERROR_ACCESS_DENIED` for running executables
+/// as well, so that counts as in use too.
+fn is_in_use_error(error: &io::Error) -> bool {
+    #[cfg(target_os = "windows")]
+    let codes: &[i32] = &[32, 33, 5];
+    #[cfg(not(target_os = "windows"))]
+    let codes: &[i32] = &[26];
+    error
+        .raw_os_error()
+        .is_some_and(|code| codes.contains(&code))
+}
+
+pub(super) fn failed_file_removal(path: &Path, error: &io::Error) -> FailedRemoval {
+    FailedRemoval {
+        item: path.display().to_string(),
+        reason: error.to_string(),
+        in_use: is_in_use_error(error),
+    }
+}
+
+/// A shortcut or registry entry that could not be removed.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub(super) fn failed_integration_removal(item: &str, error: impl ToString) -> FailedRemoval {
+    FailedRemoval {
+        item: item.to_string(),
+        reason: error.to_string(),
+        in_use: false,
+    }
+}
+
+/// Command that deletes `paths` (directories recursively) in the platform
+/// shell.
+pub(super) fn cleanup_command(paths: &[PathBuf]) -> Option<String> {
+    if paths.is_empty() {
+        return None;
+    }
+    let commands = paths
+        .iter()
+        .map(|path| {
+            let path = path.display();
+            if cfg!(target_os = "windows") {
+                format!("rmdir /s /q \"{}\"", path)
+            } else {
+                format!("rm -rf '{}'", path.to_string().replace('\'', r"'\''"))
+            }
+        })
+        .collect::<Vec<_>>();
+    Some(if cfg!(target_os = "windows") {
+        format!("cmd /c \"{}\"", commands.join(" & "))
+    } else {
+        commands.join(" && ")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cleanup_command;
+    use std::path::PathBuf;
+
+    #[test]
+    fn cleanup_command_covers_every_path() {
+        assert_eq!(cleanup_command(&[]), None);
+
+        let command =
+            cleanup_command(&[PathBuf::from("install dir"), PathBuf::from("it's here")]).unwrap();
+        if cfg!(target_os = "windows") {
+            assert_eq!(
+                command,
+                r#"cmd /c "rmdir /s /q "install dir" & rmdir /s /q "it's here"""#
+            );
+        } else {
+            assert_eq!(command, r"rm -rf 'install dir' && rm -rf 'it'\''s here'");
+        }
+    }
+}
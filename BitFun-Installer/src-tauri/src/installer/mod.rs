@@ -1,12 +1,19 @@
 mod ai_config;
+mod cancel;
+pub(super) mod cli;
 pub(super) mod commands;
+mod disk_space;
 mod extract;
 mod generated_locale_contract;
+mod leftovers;
+mod repair;
 mod types;
 
 /// Windows main binary file name — must match `src/apps/desktop` `[[bin]]` and Tauri NSIS output.
 const MAIN_APP_EXE: &str = "bitfun-desktop.exe";
 
+#[cfg(target_os = "windows")]
+mod conflicts;
 #[cfg(target_os = "windows")]
 mod registry;
 #[cfg(target_os = "windows")]
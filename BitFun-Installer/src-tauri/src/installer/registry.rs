@@ -3,6 +3,7 @@
 //! Handles:
 //! - Uninstall registry entries (Add/Remove Programs)
 //! - Install location under `Software\{publisher}\{productName}` (matches Tauri NSIS `MANUPRODUCTKEY`)
+//! - `bitfun://` URL protocol handler (deep links from "Add to BitFun" buttons)
 //!
 //! Must stay in sync with `src/apps/desktop/tauri.conf.json`: `bundle.publisher` + `productName`.
 //! Main exe name must match `super::MAIN_APP_EXE` (same as Tauri NSIS).
@@ -12,10 +13,19 @@ use std::path::Path;
 use winreg::enums::*;
 use winreg::RegKey;
 
+use super::types::ShortcutNames;
 use super::MAIN_APP_EXE;
 
 const APP_NAME: &str = "BitFun";
 const UNINSTALL_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Uninstall\BitFun";
+/// Deep link scheme — must match `DEEP_LINK_SCHEME` in `src/apps/desktop/src/deep_link.rs`.
+const URL_PROTOCOL_SCHEME: &str = "bitfun";
+const MACHINE_ENVIRONMENT_KEY: &str =
+    r"SYSTEM\CurrentControlSet\Control\Session Manager\Environment";
+/// Shortcut names recorded in the uninstall entry so uninstall removes the right items.
+const SHORTCUT_START_MENU_FOLDER_VALUE: &str = "BitFunStartMenuFolder";
+const SHORTCUT_DESKTOP_NAME_VALUE: &str = "BitFunDesktopShortcutName";
+const SHORTCUT_START_MENU_NAME_VALUE: &str = "BitFunStartMenuShortcutName";
 
 /// Matches Tauri NSIS `MANUFACTURER` (`bundle.publisher`).
 const TAURI_MANUFACTURER: &str = "BitFun Team";
@@ -50,6 +60,7 @@ pub(super) fn register_uninstall_entry(
     install_path: &Path,
     version: &str,
     uninstall_command: &str,
+    shortcuts: &ShortcutNames,
 ) -> Result<()> {
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
     let (key, _) = hkcu
@@ -68,11 +79,34 @@ pub(super) fn register_uninstall_entry(
     key.set_value("QuietUninstallString", &uninstall_command)?;
     key.set_value("NoModify", &1u32)?;
     key.set_value("NoRepair", &1u32)?;
+    key.set_value(SHORTCUT_DESKTOP_NAME_VALUE, &shortcuts.desktop_name)?;
+    key.set_value(SHORTCUT_START_MENU_NAME_VALUE, &shortcuts.start_menu_name)?;
+    match &shortcuts.start_menu_folder {
+        Some(folder) => key.set_value(SHORTCUT_START_MENU_FOLDER_VALUE, folder)?,
+        None => {
+            let _ = key.delete_value(SHORTCUT_START_MENU_FOLDER_VALUE);
+        }
+    }
 
     log::info!("Registered uninstall entry at {}", UNINSTALL_KEY);
     Ok(())
 }
 
+/// Shortcut names stored by `register_uninstall_entry`. `None` for entries
+/// written by Tauri NSIS or older installers, which use the default names.
+pub(super) fn read_registered_shortcut_names() -> Option<ShortcutNames> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let key = hkcu.open_subkey(UNINSTALL_KEY).ok()?;
+    Some(ShortcutNames {
+        start_menu_folder: key
+            .get_value::<String, _>(SHORTCUT_START_MENU_FOLDER_VALUE)
+            .ok()
+            .filter(|folder| !folder.trim().is_empty()),
+        desktop_name: key.get_value(SHORTCUT_DESKTOP_NAME_VALUE).ok()?,
+        start_menu_name: key.get_value(SHORTCUT_START_MENU_NAME_VALUE).ok()?,
+    })
+}
+
 /// Same as Tauri NSIS `WriteRegStr SHCTX "${MANUPRODUCTKEY}" "" $INSTDIR` — used for default install dir / upgrades.
 pub(super) fn register_tauri_install_location(install_path: &Path) -> Result<()> {
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
@@ -164,6 +198,80 @@ pub(super) fn read_existing_install_from_uninstall_registry() -> Option<Uninstal
         .or_else(|| read_uninstall_key(RegKey::predef(HKEY_LOCAL_MACHINE), "hklm"))
 }
 
+/// Install registered for all users (e.g. Tauri NSIS per-machine mode).
+pub(super) fn read_per_machine_install() -> Option<UninstallRegistryData> {
+    read_uninstall_key(RegKey::predef(HKEY_LOCAL_MACHINE), "hklm")
+}
+
+/// Folders on the user and machine `PATH`, unexpanded.
+pub(super) fn read_path_entries() -> Vec<String> {
+    let mut entries = Vec::new();
+    for (root, key) in [
+        (HKEY_CURRENT_USER, "Environment"),
+        (HKEY_LOCAL_MACHINE, MACHINE_ENVIRONMENT_KEY),
+    ] {
+        let Ok(env_key) = RegKey::predef(root).open_subkey(key) else {
+            continue;
+        };
+        let path: String = env_key.get_value("Path").unwrap_or_default();
+        entries.extend(path.split(';').filter_map(normalize_registry_path));
+    }
+    entries
+}
+
+fn url_protocol_key() -> String {
+    format!(r"Software\Classes\{}", URL_PROTOCOL_SCHEME)
+}
+
+/// Register `bitfun://` so the shell launches the app with the URL as its first argument.
+pub(super) fn register_url_protocol(install_path: &Path) -> Result<()> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let path = url_protocol_key();
+    let (key, _) = hkcu
+        .create_subkey(&path)
+        .with_context(|| format!("Failed to create registry key {}", path))?;
+    let exe_path = install_path.join(MAIN_APP_EXE);
+
+    key.set_value("", &format!("URL:{} Protocol", APP_NAME))?;
+    key.set_value("URL Protocol", &"")?;
+
+    let (icon_key, _) = key.create_subkey("DefaultIcon")?;
+    icon_key.set_value("", &format!("{},0", quote_windows_path(&exe_path)))?;
+
+    let (command_key, _) = key.create_subkey(r"shell\open\command")?;
+    command_key.set_value("", &format!("{} \"%1\"", quote_windows_path(&exe_path)))?;
+
+    log::info!("Registered URL protocol at {}", path);
+    Ok(())
+}
+
+/// Whether `bitfun://` is registered and opens the main exe under `install_path`.
+pub(super) fn url_protocol_points_to(install_path: &Path) -> bool {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let path = format!(r"{}\shell\open\command", url_protocol_key());
+    let Ok(key) = hkcu.open_subkey(&path) else {
+        return false;
+    };
+    let Ok(command) = key.get_value::<String, _>("") else {
+        return false;
+    };
+    let expected = format!(
+        "{} \"%1\"",
+        quote_windows_path(&install_path.join(MAIN_APP_EXE))
+    );
+    command.eq_ignore_ascii_case(&expected)
+}
+
+/// Remove the `bitfun://` protocol handler (HKCU only; the installer never writes HKLM).
+pub(super) fn remove_url_protocol() -> Result<()> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let path = url_protocol_key();
+    if hkcu.delete_subkey_all(&path).is_ok() {
+        log::info!("Removed URL protocol key {}", path);
+    }
+    Ok(())
+}
+
 /// Remove legacy context menu entries from older installer builds (no longer registered on install).
 pub(super) fn remove_context_menu() -> Result<()> {
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
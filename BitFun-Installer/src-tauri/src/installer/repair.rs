@@ -0,0 +1,213 @@
+//! Payload verification and restore for repair mode.
+//!
+//! Installed files are checked against the payload manifest (size and
+//! SHA-256). Missing or damaged files are re-extracted from the payload
+//! archive; intact files are left untouched.
+
+use bitfun_services_core::hashing::{hash_reader, HashAlgorithm};
+use std::fs;
+use std::io::{self, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use super::types::PayloadManifestFile;
+
+/// Why an installed payload file has to be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PayloadFileDamage {
+    Missing,
+    SizeMismatch,
+    ContentMismatch,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct PayloadRepairOutcome {
+    pub verified: usize,
+    pub restored: Vec<String>,
+}
+
+/// Checks one installed file against its manifest entry.
+pub(super) fn inspect_installed_file(
+    path: &Path,
+    expected: &PayloadManifestFile,
+) -> Result<Option<PayloadFileDamage>, String> {
+    let meta = match fs::metadata(path) {
+        Ok(meta) if meta.is_file() => meta,
+        Ok(_) => return Ok(Some(PayloadFileDamage::Missing)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(Some(PayloadFileDamage::Missing))
+        }
+        Err(e) => return Err(format!("Failed to inspect {}: {}", path.display(), e)),
+    };
+    if expected.size.is_some_and(|size| size != meta.len()) {
+        return Ok(Some(PayloadFileDamage::SizeMismatch));
+    }
+    if let Some(expected_hash) = &expected.sha256 {
+        let actual_hash = sha256_file(path)?;
+        if !actual_hash.eq_ignore_ascii_case(expected_hash.trim()) {
+            return Ok(Some(PayloadFileDamage::ContentMismatch));
+        }
+    }
+    Ok(None)
+}
+
+/// Verifies every manifest file under `install_path` and restores damaged
+/// ones from `archive`. `files` pairs each sanitized relative path with its
+/// manifest entry.
+pub(super) fn repair_payload_files<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    files: &[(PathBuf, &PayloadManifestFile)],
+    install_path: &Path,
+) -> Result<PayloadRepairOutcome, String> {
+    let mut outcome = PayloadRepairOutcome::default();
+    for (relative_path, expected) in files {
+        let target = install_path.join(relative_path);
+        outcome.verified += 1;
+        let Some(damage) = inspect_installed_file(&target, expected)? else {
+            continue;
+        };
+        log::warn!(
+            "Repairing payload file: path={}, damage={:?}",
+            expected.path,
+            damage
+        );
+        restore_payload_file(archive, &expected.path, &target)?;
+        if let Some(damage) = inspect_installed_file(&target, expected)? {
+            return Err(format!(
+                "Restored file {} still does not match the payload manifest ({:?})",
+                expected.path, damage
+            ));
+        }
+        outcome.restored.push(expected.path.clone());
+    }
+    Ok(outcome)
+}
+
+/// Extracts one archive entry next to `target`, then moves it into place so
+/// a failed write never leaves a truncated file behind.
+fn restore_payload_file<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    entry_name: &str,
+    target: &Path,
+) -> Result<(), String> {
+    let mut entry = archive
+        .by_name(entry_name)
+        .map_err(|e| format!("Payload does not contain {}: {}", entry_name, e))?;
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let mut staging = target.as_os_str().to_owned();
+    staging.push(".repair");
+    let staging = PathBuf::from(staging);
+    let written = fs::File::create(&staging)
+        .and_then(|mut out| io::copy(&mut entry, &mut out))
+        .and_then(|_| fs::rename(&staging, target));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&staging);
+        return Err(format!(
+            "Failed to restore {}: {}. Close BitFun and try again.",
+            target.display(),
+            e
+        ));
+    }
+    Ok(())
+}
+
+fn sha256_file(path: &Path) -> Result<String, String> {
+    let file =
+        fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    hash_reader(file, HashAlgorithm::Sha256, |_| {})
+        .map(|hash| hash.hex)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inspect_installed_file, repair_payload_files, PayloadFileDamage};
+    use crate::installer::types::PayloadManifestFile;
+    use sha2::{Digest, Sha256};
+    use std::io::{Cursor, Write};
+    use std::path::PathBuf;
+
+    fn manifest_file(path: &str, content: &[u8]) -> PayloadManifestFile {
+        PayloadManifestFile {
+            path: path.to_string(),
+            size: Some(content.len() as u64),
+            sha256: Some(
+                Sha256::digest(content)
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn repair_restores_missing_and_damaged_files_only() {
+        let files = [
+            ("bitfun-desktop.exe", b"app binary".as_slice()),
+            ("resources/app.dat", b"resource data".as_slice()),
+            ("locales/en.pak", b"locale".as_slice()),
+        ];
+        let mut zip_bytes = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(Cursor::new(&mut zip_bytes));
+            for (name, content) in files {
+                writer
+                    .start_file(name, zip::write::FileOptions::default())
+                    .unwrap();
+                writer.write_all(content).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        let manifest = files
+            .iter()
+            .map(|(name, content)| manifest_file(name, content))
+            .collect::<Vec<_>>();
+
+        let install_path = std::env::temp_dir().join(format!(
+            "bitfun-installer-repair-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&install_path);
+        std::fs::create_dir_all(install_path.join("resources")).unwrap();
+        std::fs::create_dir_all(install_path.join("locales")).unwrap();
+        std::fs::write(install_path.join("bitfun-desktop.exe"), b"app binary").unwrap();
+        // Same size, different content.
+        std::fs::write(install_path.join("resources/app.dat"), b"resource-data").unwrap();
+
+        assert_eq!(
+            inspect_installed_file(&install_path.join("locales/en.pak"), &manifest[2]).unwrap(),
+            Some(PayloadFileDamage::Missing)
+        );
+        assert_eq!(
+            inspect_installed_file(&install_path.join("resources/app.dat"), &manifest[1]).unwrap(),
+            Some(PayloadFileDamage::ContentMismatch)
+        );
+
+        let entries = manifest
+            .iter()
+            .map(|file| (PathBuf::from(&file.path), file))
+            .collect::<Vec<_>>();
+        let mut archive = zip::ZipArchive::new(Cursor::new(zip_bytes)).unwrap();
+        let outcome = repair_payload_files(&mut archive, &entries, &install_path).unwrap();
+
+        assert_eq!(outcome.verified, 3);
+        assert_eq!(
+            outcome.restored,
+            vec!["resources/app.dat", "locales/en.pak"]
+        );
+        assert_eq!(
+            std::fs::read(install_path.join("resources/app.dat")).unwrap(),
+            b"resource data"
+        );
+        assert!(entries.iter().all(|(path, file)| inspect_installed_file(
+            &install_path.join(path),
+            file
+        )
+        .unwrap()
+        .is_none()));
+
+        let _ = std::fs::remove_dir_all(&install_path);
+    }
+}
@@ -3,15 +3,30 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
+use super::types::ShortcutNames;
 use super::MAIN_APP_EXE;
 
-const SHORTCUT_NAME: &str = "BitFun.lnk";
 const LEGACY_START_MENU_DIR: &str = "BitFun";
 
-/// Create a desktop shortcut for BitFun.
-pub(super) fn create_desktop_shortcut(install_path: &Path) -> Result<()> {
+fn lnk_file_name(display_name: &str) -> String {
+    format!("{}.lnk", display_name)
+}
+
+fn desktop_shortcut_path(names: &ShortcutNames) -> Result<PathBuf> {
     let desktop = dirs::desktop_dir().with_context(|| "Cannot find Desktop directory")?;
-    let shortcut_path = desktop.join(SHORTCUT_NAME);
+    Ok(desktop.join(lnk_file_name(&names.desktop_name)))
+}
+
+fn start_menu_shortcut_dir(start_menu: &Path, names: &ShortcutNames) -> PathBuf {
+    match &names.start_menu_folder {
+        Some(folder) => start_menu.join(folder),
+        None => start_menu.to_path_buf(),
+    }
+}
+
+/// Create a desktop shortcut for BitFun.
+pub(super) fn create_desktop_shortcut(install_path: &Path, names: &ShortcutNames) -> Result<()> {
+    let shortcut_path = desktop_shortcut_path(names)?;
     let exe_path = install_path.join(MAIN_APP_EXE);
 
     create_lnk(&shortcut_path, &exe_path, install_path)?;
@@ -19,11 +34,18 @@ pub(super) fn create_desktop_shortcut(install_path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Create a Start Menu shortcut for BitFun.
-pub(super) fn create_start_menu_shortcut(install_path: &Path) -> Result<()> {
+/// Create a Start Menu shortcut for BitFun, inside the configured folder if any.
+pub(super) fn create_start_menu_shortcut(install_path: &Path, names: &ShortcutNames) -> Result<()> {
     let start_menu = get_start_menu_dir()?;
-    remove_legacy_start_menu_shortcut(&start_menu)?;
-    let shortcut_path = start_menu.join(SHORTCUT_NAME);
+    remove_legacy_start_menu_shortcut(&start_menu, names)?;
+    let shortcut_dir = start_menu_shortcut_dir(&start_menu, names);
+    std::fs::create_dir_all(&shortcut_dir).with_context(|| {
+        format!(
+            "Failed to create Start Menu folder {}",
+            shortcut_dir.display()
+        )
+    })?;
+    let shortcut_path = shortcut_dir.join(lnk_file_name(&names.start_menu_name));
     let exe_path = install_path.join(MAIN_APP_EXE);
 
     create_lnk(&shortcut_path, &exe_path, install_path)?;
@@ -32,9 +54,8 @@ pub(super) fn create_start_menu_shortcut(install_path: &Path) -> Result<()> {
 }
 
 /// Remove desktop shortcut.
-pub(super) fn remove_desktop_shortcut() -> Result<()> {
-    if let Some(desktop) = dirs::desktop_dir() {
-        let shortcut_path = desktop.join(SHORTCUT_NAME);
+pub(super) fn remove_desktop_shortcut(names: &ShortcutNames) -> Result<()> {
+    if let Ok(shortcut_path) = desktop_shortcut_path(names) {
         if shortcut_path.exists() {
             std::fs::remove_file(&shortcut_path)?;
         }
@@ -42,17 +63,41 @@ pub(super) fn remove_desktop_shortcut() -> Result<()> {
     Ok(())
 }
 
-/// Remove Start Menu shortcut, including the legacy folder layout.
-pub(super) fn remove_start_menu_shortcut() -> Result<()> {
+/// Remove Start Menu shortcut and its folder once empty, including the legacy
+/// folder layout.
+pub(super) fn remove_start_menu_shortcut(names: &ShortcutNames) -> Result<()> {
     let start_menu = get_start_menu_dir()?;
-    let shortcut_path = start_menu.join(SHORTCUT_NAME);
+    let shortcut_dir = start_menu_shortcut_dir(&start_menu, names);
+    let shortcut_path = shortcut_dir.join(lnk_file_name(&names.start_menu_name));
     if shortcut_path.exists() {
         std::fs::remove_file(&shortcut_path)?;
     }
-    remove_legacy_start_menu_shortcut(&start_menu)?;
+    if names.start_menu_folder.is_some() {
+        // Fails while other items remain in the folder, which is intended.
+        let _ = std::fs::remove_dir(&shortcut_dir);
+    }
+    remove_legacy_start_menu_shortcut(&start_menu, names)?;
     Ok(())
 }
 
+/// Whether the desktop shortcut exists.
+pub(super) fn desktop_shortcut_exists(names: &ShortcutNames) -> bool {
+    desktop_shortcut_path(names)
+        .map(|path| path.is_file())
+        .unwrap_or(false)
+}
+
+/// Whether the Start Menu shortcut exists.
+pub(super) fn start_menu_shortcut_exists(names: &ShortcutNames) -> bool {
+    get_start_menu_dir()
+        .map(|start_menu| {
+            start_menu_shortcut_dir(&start_menu, names)
+                .join(lnk_file_name(&names.start_menu_name))
+                .is_file()
+        })
+        .unwrap_or(false)
+}
+
 /// Get the current user's Start Menu Programs directory.
 fn get_start_menu_dir() -> Result<PathBuf> {
     let appdata =
@@ -64,7 +109,15 @@ fn get_start_menu_dir() -> Result<PathBuf> {
         .join("Programs"))
 }
 
-fn remove_legacy_start_menu_shortcut(start_menu: &Path) -> Result<()> {
+fn remove_legacy_start_menu_shortcut(start_menu: &Path, names: &ShortcutNames) -> Result<()> {
+    // A custom folder may reuse the legacy name; never delete it wholesale.
+    if names
+        .start_menu_folder
+        .as_deref()
+        .is_some_and(|folder| folder.eq_ignore_ascii_case(LEGACY_START_MENU_DIR))
+    {
+        return Ok(());
+    }
     let legacy_dir = start_menu.join(LEGACY_START_MENU_DIR);
     if legacy_dir.exists() {
         std::fs::remove_dir_all(&legacy_dir)?;
@@ -11,6 +11,15 @@ pub(crate) struct InstallOptions {
     pub desktop_shortcut: bool,
     /// Add to Start Menu
     pub start_menu: bool,
+    /// Start Menu subfolder for the shortcut; unset places it directly under Programs.
+    #[serde(default)]
+    pub start_menu_folder: Option<String>,
+    /// Desktop shortcut display name (default `BitFun`).
+    #[serde(default)]
+    pub desktop_shortcut_name: Option<String>,
+    /// Start Menu shortcut display name (default `BitFun`).
+    #[serde(default)]
+    pub start_menu_shortcut_name: Option<String>,
     /// Launch after installation
     pub launch_after_install: bool,
     /// First-launch app language (zh-CN / en-US)
@@ -132,6 +141,188 @@ pub(super) struct InstallProgress {
     pub message: String,
 }
 
+/// Default display name of the desktop and Start Menu shortcuts.
+pub(super) const DEFAULT_SHORTCUT_NAME: &str = "BitFun";
+
+/// Resolved shortcut location and names. Recorded at install time so
+/// uninstall and repair touch the same items.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ShortcutNames {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_menu_folder: Option<String>,
+    pub desktop_name: String,
+    pub start_menu_name: String,
+}
+
+impl Default for ShortcutNames {
+    fn default() -> Self {
+        Self {
+            start_menu_folder: None,
+            desktop_name: DEFAULT_SHORTCUT_NAME.to_string(),
+            start_menu_name: DEFAULT_SHORTCUT_NAME.to_string(),
+        }
+    }
+}
+
+impl ShortcutNames {
+    /// Validates the custom names in `options`; blank values use the defaults.
+    pub(super) fn from_options(options: &InstallOptions) -> Result<Self, String> {
+        let defaults = Self::default();
+        Ok(Self {
+            start_menu_folder: validate_shortcut_component(
+                "Start Menu folder",
+                options.start_menu_folder.as_deref(),
+            )?,
+            desktop_name: validate_shortcut_component(
+                "desktop shortcut name",
+                options.desktop_shortcut_name.as_deref(),
+            )?
+            .unwrap_or(defaults.desktop_name),
+            start_menu_name: validate_shortcut_component(
+                "Start Menu shortcut name",
+                options.start_menu_shortcut_name.as_deref(),
+            )?
+            .unwrap_or(defaults.start_menu_name),
+        })
+    }
+}
+
+const MAX_SHORTCUT_COMPONENT_CHARS: usize = 80;
+const WINDOWS_RESERVED_FILE_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// A shortcut or folder name must be a single valid Windows file name.
+fn validate_shortcut_component(field: &str, value: Option<&str>) -> Result<Option<String>, String> {
+    let Some(value) = value.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    if value.chars().count() > MAX_SHORTCUT_COMPONENT_CHARS {
+        return Err(format!(
+            "The {} must be at most {} characters",
+            field, MAX_SHORTCUT_COMPONENT_CHARS
+        ));
+    }
+    if value.chars().any(|c| {
+        c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*')
+    }) {
+        return Err(format!(
+            "The {} contains characters that are not allowed in file names: {}",
+            field, value
+        ));
+    }
+    let stem = value.split('.').next().unwrap_or(value);
+    if value.ends_with('.')
+        || WINDOWS_RESERVED_FILE_NAMES
+            .iter()
+            .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        return Err(format!("The {} is not a valid file name: {}", field, value));
+    }
+    Ok(Some(value.to_string()))
+}
+
+/// `payload-manifest.json` written by `scripts/build-installer.cjs`.
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct PayloadManifest {
+    pub files: Vec<PayloadManifestFile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct PayloadManifestFile {
+    /// Path relative to the install root, `/`-separated.
+    pub path: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Lowercase hex SHA-256 of the file contents.
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+/// Result of `repair_installation`, shown by the repair UI.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RepairReport {
+    pub install_path: String,
+    /// Payload files checked against the manifest.
+    pub verified_files: usize,
+    /// Payload files restored because they were missing or damaged.
+    pub restored_files: Vec<String>,
+    /// Re-created integrations: `uninstaller`, `installLocation`,
+    /// `uninstallEntry`, `urlProtocol`, `desktopShortcut`, `startMenuShortcut`.
+    pub restored_integrations: Vec<String>,
+}
+
+/// Result of `uninstall`: what was left behind on purpose and what could not
+/// be removed.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UninstallReport {
+    pub install_path: String,
+    /// User data kept so a reinstall picks up where the user left off.
+    pub kept_data: Vec<KeptData>,
+    pub failed_removals: Vec<FailedRemoval>,
+    /// Shell command that removes the leftover install files once they are
+    /// no longer in use. Only set when something failed to be removed.
+    pub cleanup_command: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum KeptDataKind {
+    /// Settings, sessions and other app data.
+    Config,
+    Skills,
+    /// Managed runtimes downloaded by the app.
+    Runtimes,
+    /// Assistant workspaces and memories under `~/.bitfun`.
+    AssistantHome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KeptData {
+    pub kind: KeptDataKind,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FailedRemoval {
+    /// File path, or the integration name for shortcuts and registry entries.
+    pub item: String,
+    pub reason: String,
+    /// The file is locked by a running process.
+    pub in_use: bool,
+}
+
+/// Another BitFun installation that would clash with a per-user install.
+/// Only detected on Windows.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum InstallConflictKind {
+    /// Registered for all users under `HKLM`. A per-user install next to it
+    /// would add a second Add/Remove Programs entry.
+    PerMachineInstall,
+    /// Another user's per-user install referenced from the machine `PATH`.
+    OtherUserInstall,
+    /// A different BitFun folder already on `PATH`.
+    PathEntry,
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InstallConflict {
+    pub kind: InstallConflictKind,
+    pub location: String,
+    /// Installation cannot continue until this conflict is resolved.
+    pub blocking: bool,
+}
+
 /// Disk space information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -152,6 +343,9 @@ impl Default for InstallOptions {
             install_path: String::new(),
             desktop_shortcut: true,
             start_menu: true,
+            start_menu_folder: None,
+            desktop_shortcut_name: None,
+            start_menu_shortcut_name: None,
             launch_after_install: true,
             app_language: "zh-CN".to_string(),
             theme_preference: "system".to_string(),
@@ -159,3 +353,37 @@ impl Default for InstallOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InstallOptions, ShortcutNames};
+
+    #[test]
+    fn shortcut_names_default_blank_values_and_reject_invalid_file_names() {
+        let options = InstallOptions {
+            start_menu_folder: Some("  Contoso Tools ".to_string()),
+            desktop_shortcut_name: Some(" ".to_string()),
+            start_menu_shortcut_name: Some("BitFun (Contoso)".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            ShortcutNames::from_options(&options).unwrap(),
+            ShortcutNames {
+                start_menu_folder: Some("Contoso Tools".to_string()),
+                desktop_name: "BitFun".to_string(),
+                start_menu_name: "BitFun (Contoso)".to_string(),
+            }
+        );
+
+        for invalid in ["Tools\\BitFun", "a:b", "BitFun.", "nul", "COM1.lnk"] {
+            let options = InstallOptions {
+                start_menu_folder: Some(invalid.to_string()),
+                ..Default::default()
+            };
+            assert!(
+                ShortcutNames::from_options(&options).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+}
@@ -4,6 +4,10 @@ use installer::commands;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    if installer::cli::silent_install_requested() {
+        std::process::exit(installer::cli::run_silent_install());
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .invoke_handler(tauri::generate_handler![
@@ -15,10 +19,12 @@ pub fn run() {
             commands::get_disk_space,
             commands::validate_install_path,
             commands::start_installation,
+            commands::cancel_installation,
             commands::set_model_config,
             commands::test_model_config_connection,
             commands::list_model_config_models,
             commands::set_theme_preference,
+            commands::repair_installation,
             commands::uninstall,
             commands::launch_application,
             commands::close_installer,
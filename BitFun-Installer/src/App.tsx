@@ -37,7 +37,7 @@ function App() {
     model: t('model.title'),
     progress: t('progress.title'),
     theme: t('themeSetup.title'),
-    uninstall: t('uninstall.title'),
+    uninstall: t(installer.isRepairMode ? 'uninstall.repairTitle' : 'uninstall.title'),
   };
 
   const renderPage = () => {
@@ -53,6 +53,7 @@ function App() {
             error={installer.error}
             refreshDiskSpace={installer.refreshDiskSpace}
             existingInstall={installer.existingInstall}
+            installConflicts={installer.installConflicts}
             onLaunchRegisteredUninstaller={installer.launchRegisteredUninstaller}
             onBack={installer.back}
             onInstall={installer.install}
@@ -79,7 +80,10 @@ function App() {
             progress={installer.progress}
             error={installer.error}
             canConfirmProgress={installer.canConfirmProgress}
+            isInstalling={installer.isInstalling}
+            isCancelling={installer.isCancellingInstall}
             onConfirmProgress={installer.confirmProgress}
+            onCancel={installer.cancelInstall}
             onRetry={installer.retryInstall}
             onBackToOptions={installer.backToOptions}
           />
@@ -100,8 +104,14 @@ function App() {
             isUninstalling={installer.isUninstalling}
             uninstallCompleted={installer.uninstallCompleted}
             uninstallError={installer.uninstallError}
+            uninstallReport={installer.uninstallReport}
             uninstallProgress={installer.uninstallProgress}
             onUninstall={installer.startUninstall}
+            repairMode={installer.isRepairMode}
+            isRepairing={installer.isRepairing}
+            repairReport={installer.repairReport}
+            repairError={installer.repairError}
+            onRepair={installer.startRepair}
             onClose={installer.closeInstaller}
           />
         );
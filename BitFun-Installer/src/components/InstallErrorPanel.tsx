@@ -1,4 +1,5 @@
 import { useTranslation } from 'react-i18next';
+import { formatInstallDiskSpaceError } from '../utils/diskSpaceErrors';
 import {
   formatInstallPathError,
   installPathErrorShowsAdminHint,
@@ -13,7 +14,7 @@ interface InstallErrorPanelProps {
 
 export function InstallErrorPanel({ message, variant = 'options' }: InstallErrorPanelProps) {
   const { t } = useTranslation();
-  const text = formatInstallPathError(message, t);
+  const text = formatInstallDiskSpaceError(message, t) ?? formatInstallPathError(message, t);
   const code = parseInstallPathErrorCode(message);
   const showAdmin = installPathErrorShowsAdminHint(code);
 
@@ -13,6 +13,9 @@ import type {
   LaunchContext,
   InstallPathValidation,
   ExistingInstallation,
+  InstallConflict,
+  RepairReport,
+  UninstallReport,
 } from '../types/installer';
 import { DEFAULT_OPTIONS } from '../types/installer';
 
@@ -29,11 +32,14 @@ export interface UseInstallerReturn {
   error: string | null;
   diskSpace: DiskSpaceInfo | null;
   existingInstall: ExistingInstallation | null;
+  installConflicts: InstallConflict[];
   launchRegisteredUninstaller: () => Promise<void>;
   install: () => Promise<void>;
   canConfirmProgress: boolean;
   confirmProgress: () => void;
   retryInstall: () => Promise<void>;
+  cancelInstall: () => Promise<void>;
+  isCancellingInstall: boolean;
   backToOptions: () => void;
   saveModelConfig: () => Promise<void>;
   testModelConnection: (modelConfig: ModelConfig) => Promise<ConnectionTestResult>;
@@ -45,11 +51,19 @@ export interface UseInstallerReturn {
   isUninstalling: boolean;
   uninstallCompleted: boolean;
   uninstallError: string | null;
+  uninstallReport: UninstallReport | null;
   uninstallProgress: number;
   startUninstall: () => Promise<void>;
+  isRepairMode: boolean;
+  isRepairing: boolean;
+  repairReport: RepairReport | null;
+  repairError: string | null;
+  startRepair: () => Promise<void>;
 }
 
 const STEPS: InstallStep[] = ['lang', 'options', 'progress', 'model', 'theme'];
+/** Matches Rust `INSTALL_CANCELLED_ERR` in `cancel.rs`. */
+const INSTALL_CANCELLED_ERROR = 'INSTALL_CANCELLED';
 const MOCK_INSTALL_FOR_DEBUG = import.meta.env.DEV && import.meta.env.VITE_MOCK_INSTALL === 'true';
 
 export function useInstaller(): UseInstallerReturn {
@@ -63,14 +77,21 @@ export function useInstaller(): UseInstallerReturn {
   const [isInstalling, setIsInstalling] = useState(false);
   const [installationCompleted, setInstallationCompleted] = useState(false);
   const [canConfirmProgress, setCanConfirmProgress] = useState(false);
+  const [isCancellingInstall, setIsCancellingInstall] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [diskSpace, setDiskSpace] = useState<DiskSpaceInfo | null>(null);
   const [existingInstall, setExistingInstall] = useState<ExistingInstallation | null>(null);
+  const [installConflicts, setInstallConflicts] = useState<InstallConflict[]>([]);
   const [isUninstallMode, setIsUninstallMode] = useState(false);
   const [isUninstalling, setIsUninstalling] = useState(false);
   const [uninstallCompleted, setUninstallCompleted] = useState(false);
   const [uninstallError, setUninstallError] = useState<string | null>(null);
+  const [uninstallReport, setUninstallReport] = useState<UninstallReport | null>(null);
   const [uninstallProgress, setUninstallProgress] = useState(0);
+  const [isRepairMode, setIsRepairMode] = useState(false);
+  const [isRepairing, setIsRepairing] = useState(false);
+  const [repairReport, setRepairReport] = useState<RepairReport | null>(null);
+  const [repairError, setRepairError] = useState<string | null>(null);
 
   const emptyExistingInstall: ExistingInstallation = {
     detected: false,
@@ -94,8 +115,9 @@ export function useInstaller(): UseInstallerReturn {
           ...prev,
           appLanguage: mapUiLanguageToAppLanguage(uiLanguage),
         }));
-        if (context.mode === 'uninstall') {
+        if (context.mode === 'uninstall' || context.mode === 'repair') {
           setIsUninstallMode(true);
+          setIsRepairMode(context.mode === 'repair');
           setStep('uninstall');
           const uninstallPath = context.uninstallPath;
           if (uninstallPath) {
@@ -103,6 +125,7 @@ export function useInstaller(): UseInstallerReturn {
           }
           return;
         }
+        setInstallConflicts(context.conflicts ?? []);
       } catch (err) {
         console.warn('Failed to detect launch context:', err);
       }
@@ -296,9 +319,15 @@ export function useInstaller(): UseInstallerReturn {
       }
     } catch (err: any) {
       const raw = typeof err === 'string' ? err : err?.message;
-      setError((raw && String(raw).trim()) ? String(raw) : i18n.t('errors.install.failed'));
+      if (raw === INSTALL_CANCELLED_ERROR) {
+        // The backend already rolled back; return to the options page.
+        setStep('options');
+      } else {
+        setError((raw && String(raw).trim()) ? String(raw) : i18n.t('errors.install.failed'));
+      }
     } finally {
       setIsInstalling(false);
+      setIsCancellingInstall(false);
     }
   }, [options, readExistingInstall]);
 
@@ -313,6 +342,16 @@ export function useInstaller(): UseInstallerReturn {
     await install();
   }, [install, isInstalling]);
 
+  const cancelInstall = useCallback(async () => {
+    if (!isInstalling || isCancellingInstall) return;
+    setIsCancellingInstall(true);
+    try {
+      await invoke('cancel_installation');
+    } catch {
+      setIsCancellingInstall(false);
+    }
+  }, [isInstalling, isCancellingInstall]);
+
   const backToOptions = useCallback(() => {
     if (isInstalling) return;
     setError(null);
@@ -340,6 +379,7 @@ export function useInstaller(): UseInstallerReturn {
   const startUninstall = useCallback(async () => {
     if (isUninstalling) return;
     setUninstallError(null);
+    setUninstallReport(null);
     setUninstallCompleted(false);
     setIsUninstalling(true);
     setUninstallProgress(0);
@@ -359,12 +399,16 @@ export function useInstaller(): UseInstallerReturn {
         }, 80);
       });
 
-      await invoke('uninstall', { installPath: options.installPath });
+      const report = await invoke<UninstallReport>('uninstall', { installPath: options.installPath });
+      setUninstallReport(report);
       setUninstallProgress(100);
       setUninstallCompleted(true);
-      window.setTimeout(() => {
-        closeInstaller();
-      }, 600);
+      // Stay open so the user can read what was left behind.
+      if (report.failedRemovals.length === 0 && report.keptData.length === 0) {
+        window.setTimeout(() => {
+          closeInstaller();
+        }, 600);
+      }
     } catch (err: any) {
       setUninstallError(typeof err === 'string' ? err : err.message || 'Uninstall failed');
       setUninstallProgress(0);
@@ -373,13 +417,33 @@ export function useInstaller(): UseInstallerReturn {
     }
   }, [closeInstaller, isUninstalling, options.installPath]);
 
+  const startRepair = useCallback(async () => {
+    if (isRepairing || isUninstalling) return;
+    setRepairError(null);
+    setRepairReport(null);
+    setIsRepairing(true);
+    try {
+      const report = await invoke<RepairReport>('repair_installation', {
+        installPath: options.installPath,
+      });
+      setRepairReport(report);
+    } catch (err: any) {
+      setRepairError(typeof err === 'string' ? err : err?.message || 'Repair failed');
+    } finally {
+      setIsRepairing(false);
+    }
+  }, [isRepairing, isUninstalling, options.installPath]);
+
   return {
     step, goTo, next, back,
     options, setOptions,
     progress, isInstalling, installationCompleted, error, diskSpace,
-    existingInstall, launchRegisteredUninstaller,
-    install, canConfirmProgress, confirmProgress, retryInstall, backToOptions,
+    existingInstall, installConflicts, launchRegisteredUninstaller,
+    install, canConfirmProgress, confirmProgress, retryInstall, cancelInstall, isCancellingInstall,
+    backToOptions,
     saveModelConfig, testModelConnection, launchApp, closeInstaller, refreshDiskSpace, clearInstallError,
-    isUninstallMode, isUninstalling, uninstallCompleted, uninstallError, uninstallProgress, startUninstall,
+    isUninstallMode, isUninstalling, uninstallCompleted, uninstallError, uninstallReport, uninstallProgress,
+    startUninstall,
+    isRepairMode, isRepairing, repairReport, repairError, startRepair,
   };
 }
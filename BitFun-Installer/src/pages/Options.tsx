@@ -4,11 +4,13 @@ import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-dialog';
 import { Checkbox } from '../components/Checkbox';
 import { InstallErrorPanel } from '../components/InstallErrorPanel';
+import { formatBytes } from '../utils/formatBytes';
 import type {
   InstallOptions,
   DiskSpaceInfo,
   InstallPathValidation,
   ExistingInstallation,
+  InstallConflict,
 } from '../types/installer';
 
 interface OptionsProps {
@@ -18,6 +20,7 @@ interface OptionsProps {
   error: string | null;
   refreshDiskSpace: (path: string) => Promise<void>;
   existingInstall: ExistingInstallation | null;
+  installConflicts: InstallConflict[];
   onLaunchRegisteredUninstaller: () => void | Promise<void>;
   onBack: () => void;
   onInstall: () => Promise<void>;
@@ -32,6 +35,7 @@ export function Options({
   error,
   refreshDiskSpace,
   existingInstall,
+  installConflicts,
   onLaunchRegisteredUninstaller,
   onBack,
   onInstall,
@@ -63,14 +67,6 @@ export function Options({
     }
   };
 
-  const formatBytes = (bytes: number): string => {
-    if (bytes === 0) return '0 B';
-    const k = 1024;
-    const sizes = ['B', 'KB', 'MB', 'GB', 'TB'];
-    const i = Math.floor(Math.log(bytes) / Math.log(k));
-    return `${parseFloat((bytes / Math.pow(k, i)).toFixed(1))} ${sizes[i]}`;
-  };
-
   const update = (key: keyof InstallOptions, value: boolean) => {
     setOptions((prev) => ({ ...prev, [key]: value }));
   };
@@ -128,6 +124,32 @@ export function Options({
               </div>
             </div>
           ) : null}
+          {installConflicts.length > 0 ? (
+            <div
+              style={{
+                marginBottom: 16,
+                padding: '12px 14px',
+                borderRadius: 10,
+                border: '1px solid color-mix(in srgb, var(--color-warning) 45%, transparent)',
+                background: 'color-mix(in srgb, var(--color-warning) 8%, transparent)',
+                fontSize: 12,
+                lineHeight: 1.55,
+                color: 'var(--color-text-primary)',
+              }}
+            >
+              <div style={{ fontWeight: 600, marginBottom: 8 }}>{t('options.conflictsTitle')}</div>
+              {installConflicts.map((conflict) => (
+                <div key={`${conflict.kind}:${conflict.location}`} style={{ marginBottom: 8, wordBreak: 'break-all' }}>
+                  {t(`options.conflicts.${conflict.kind}`, { path: conflict.location })}
+                </div>
+              ))}
+              <p style={{ margin: 0, opacity: 0.88 }}>
+                {installConflicts.some((conflict) => conflict.blocking)
+                  ? t('options.conflictsBlockingHint')
+                  : t('options.conflictsHint')}
+              </p>
+            </div>
+          ) : null}
           <div style={{ marginBottom: 20 }}>
             <div className="section-label">
               <svg
@@ -7,7 +7,10 @@ interface ProgressProps {
   progress: InstallProgress;
   error: string | null;
   canConfirmProgress: boolean;
+  isInstalling: boolean;
+  isCancelling: boolean;
   onConfirmProgress: () => void;
+  onCancel: () => Promise<void>;
   onRetry: () => Promise<void>;
   onBackToOptions: () => void;
 }
@@ -16,7 +19,10 @@ export function ProgressPage({
   progress,
   error,
   canConfirmProgress,
+  isInstalling,
+  isCancelling,
   onConfirmProgress,
+  onCancel,
   onRetry,
   onBackToOptions,
 }: ProgressProps) {
@@ -92,7 +98,7 @@ export function ProgressPage({
       </div>
 
       {!error ? (
-        canConfirmProgress && (
+        canConfirmProgress ? (
           <div className="page-footer page-footer--center">
             <button
               type="button"
@@ -103,6 +109,19 @@ export function ProgressPage({
               {t('progress.confirmContinue')}
             </button>
           </div>
+        ) : (
+          isInstalling && !isCompleted && (
+            <div className="page-footer page-footer--center">
+              <button
+                type="button"
+                className="btn btn-ghost"
+                disabled={isCancelling}
+                onClick={() => { void onCancel(); }}
+              >
+                {isCancelling ? t('progress.cancelling') : t('progress.cancel')}
+              </button>
+            </div>
+          )
         )
       ) : (
         <div className="page-footer page-footer--center">
@@ -1,13 +1,21 @@
 import { useTranslation } from 'react-i18next';
 import { ProgressBar } from '../components/ProgressBar';
+import type { RepairReport, UninstallReport } from '../types/installer';
 
 interface UninstallPageProps {
   installPath: string;
   isUninstalling: boolean;
   uninstallCompleted: boolean;
   uninstallError: string | null;
+  uninstallReport: UninstallReport | null;
   uninstallProgress: number;
   onUninstall: () => Promise<void>;
+  /** Launched with `--repair`: repair is the primary action. */
+  repairMode: boolean;
+  isRepairing: boolean;
+  repairReport: RepairReport | null;
+  repairError: string | null;
+  onRepair: () => Promise<void>;
   onClose: () => void;
 }
 
@@ -16,11 +24,21 @@ export function UninstallPage({
   isUninstalling,
   uninstallCompleted,
   uninstallError,
+  uninstallReport,
   uninstallProgress,
   onUninstall,
+  repairMode,
+  isRepairing,
+  repairReport,
+  repairError,
+  onRepair,
   onClose,
 }: UninstallPageProps) {
   const { t } = useTranslation();
+  const busy = isUninstalling || isRepairing;
+  const restoredCount = repairReport
+    ? repairReport.restoredFiles.length + repairReport.restoredIntegrations.length
+    : 0;
 
   return (
     <div className="uninstall-page">
@@ -28,10 +46,10 @@ export function UninstallPage({
         <div className="page-container page-container--center">
           <div className="uninstall-card">
             <div className="uninstall-title">
-              {t('uninstall.title')}
+              {t(repairMode ? 'uninstall.repairTitle' : 'uninstall.title')}
             </div>
             <div className="uninstall-subtitle">
-              {t('uninstall.subtitle')}
+              {t(repairMode ? 'uninstall.repairSubtitle' : 'uninstall.subtitle')}
             </div>
 
             <div className="uninstall-inline-meta" title={installPath || t('uninstall.pathUnknown')}>
@@ -45,9 +63,72 @@ export function UninstallPage({
               </div>
             )}
 
+            {repairError && (
+              <div className="uninstall-error">
+                {repairError}
+              </div>
+            )}
+
+            {repairReport && (
+              <div className="uninstall-success">
+                {restoredCount === 0
+                  ? t('uninstall.repairNothingFound', { count: repairReport.verifiedFiles })
+                  : t('uninstall.repairCompleted', {
+                    files: repairReport.restoredFiles.length,
+                    integrations: repairReport.restoredIntegrations.length,
+                  })}
+                {restoredCount > 0 && (
+                  <ul className="uninstall-repair-list">
+                    {repairReport.restoredIntegrations.map((id) => (
+                      <li key={id}>{t(`uninstall.repairIntegrations.${id}`, { defaultValue: id })}</li>
+                    ))}
+                    {repairReport.restoredFiles.map((path) => (
+                      <li key={path}>{path}</li>
+                    ))}
+                  </ul>
+                )}
+              </div>
+            )}
+
             {uninstallCompleted && (
               <div className="uninstall-success">
-                {t('uninstall.completed')}
+                {t(uninstallReport?.failedRemovals.length
+                  ? 'uninstall.completedWithLeftovers'
+                  : 'uninstall.completed')}
+              </div>
+            )}
+
+            {uninstallReport && uninstallReport.failedRemovals.length > 0 && (
+              <div className="uninstall-error">
+                {t('uninstall.report.failedTitle')}
+                <ul className="uninstall-repair-list">
+                  {uninstallReport.failedRemovals.map((failure) => (
+                    <li key={failure.item}>
+                      {t(`uninstall.repairIntegrations.${failure.item}`, { defaultValue: failure.item })}
+                      {' — '}
+                      {failure.inUse ? t('uninstall.report.inUse') : failure.reason}
+                    </li>
+                  ))}
+                </ul>
+                {uninstallReport.cleanupCommand && (
+                  <>
+                    <div>{t('uninstall.report.cleanupHint')}</div>
+                    <code className="uninstall-cleanup-command">{uninstallReport.cleanupCommand}</code>
+                  </>
+                )}
+              </div>
+            )}
+
+            {uninstallReport && uninstallReport.keptData.length > 0 && (
+              <div className="uninstall-kept-data">
+                {t('uninstall.report.keptTitle')}
+                <ul className="uninstall-repair-list">
+                  {uninstallReport.keptData.map((entry) => (
+                    <li key={entry.kind} title={entry.path}>
+                      {t(`uninstall.report.kept.${entry.kind}`)}: {entry.path}
+                    </li>
+                  ))}
+                </ul>
               </div>
             )}
 
@@ -64,10 +145,19 @@ export function UninstallPage({
       <div className="page-footer">
         <div className="uninstall-actions" style={{ width: '100%' }}>
           <button className="btn btn-ghost" onClick={onClose}>
-            {t(uninstallCompleted ? 'uninstall.close' : 'uninstall.cancel')}
+            {t(uninstallCompleted || repairReport ? 'uninstall.close' : 'uninstall.cancel')}
           </button>
           {!uninstallCompleted && (
-            <button className="btn btn-primary" disabled={isUninstalling} onClick={() => { void onUninstall(); }}>
+            <button
+              className={repairMode ? 'btn btn-primary' : 'btn btn-ghost'}
+              disabled={busy}
+              onClick={() => { void onRepair(); }}
+            >
+              {isRepairing ? t('uninstall.repairing') : t('uninstall.repair')}
+            </button>
+          )}
+          {!uninstallCompleted && !repairMode && (
+            <button className="btn btn-primary" disabled={busy} onClick={() => { void onUninstall(); }}>
               {isUninstalling ? t('uninstall.uninstalling') : t('uninstall.confirm')}
             </button>
           )}
@@ -4,15 +4,55 @@ import type { AppLanguage } from '../i18n/languages';
 export type InstallStep = 'lang' | 'options' | 'model' | 'progress' | 'theme' | 'uninstall';
 
 export interface LaunchContext {
-  mode: 'install' | 'uninstall';
+  mode: 'install' | 'uninstall' | 'repair';
   uninstallPath: string | null;
   appLanguage?: AppLanguage | null;
+  /** Other BitFun installations found in install mode. */
+  conflicts?: InstallConflict[];
+}
+
+/** Matches Rust `InstallConflict` (camelCase). */
+export interface InstallConflict {
+  kind: 'perMachineInstall' | 'otherUserInstall' | 'pathEntry';
+  location: string;
+  /** Installation cannot continue until this is resolved. */
+  blocking: boolean;
 }
 
 export interface InstallPathValidation {
   installPath: string;
 }
 
+/** Matches `repair_installation` / `RepairReport` (camelCase). */
+export interface RepairReport {
+  installPath: string;
+  verifiedFiles: number;
+  restoredFiles: string[];
+  restoredIntegrations: string[];
+}
+
+/** Matches `uninstall` / `UninstallReport` (camelCase). */
+export interface UninstallReport {
+  installPath: string;
+  keptData: KeptData[];
+  failedRemovals: FailedRemoval[];
+  cleanupCommand: string | null;
+}
+
+export type KeptDataKind = 'config' | 'skills' | 'runtimes' | 'assistantHome';
+
+export interface KeptData {
+  kind: KeptDataKind;
+  path: string;
+}
+
+export interface FailedRemoval {
+  /** File path, or an integration id such as `desktopShortcut`. */
+  item: string;
+  reason: string;
+  inUse: boolean;
+}
+
 /** Matches `get_existing_installation` / `ExistingInstallationResponse` (camelCase). */
 export interface ExistingInstallation {
   detected: boolean;
@@ -82,6 +122,12 @@ export interface InstallOptions {
   installPath: string;
   desktopShortcut: boolean;
   startMenu: boolean;
+  /** Start Menu subfolder for the shortcut; unset places it directly under Programs. */
+  startMenuFolder?: string;
+  /** Desktop shortcut display name (default `BitFun`). */
+  desktopShortcutName?: string;
+  /** Start Menu shortcut display name (default `BitFun`). */
+  startMenuShortcutName?: string;
   launchAfterInstall: boolean;
   appLanguage: AppLanguage;
   themePreference: ThemePreferenceId;
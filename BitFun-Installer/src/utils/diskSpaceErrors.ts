@@ -0,0 +1,37 @@
+import type { TFunction } from 'i18next';
+import { formatBytes } from './formatBytes';
+
+/** Matches Rust `INSTALL_DISK_SPACE_ERR_PREFIX` in `disk_space.rs`. */
+export const INSTALL_DISK_SPACE_ERROR_PREFIX = 'INSTALL_DISK_SPACE::';
+
+export interface InstallDiskSpaceError {
+  volume: string;
+  requiredBytes: number;
+  availableBytes: number;
+  shortfallBytes: number;
+}
+
+export function parseInstallDiskSpaceError(
+  message: string | null | undefined,
+): InstallDiskSpaceError | null {
+  if (!message || !message.startsWith(INSTALL_DISK_SPACE_ERROR_PREFIX)) return null;
+  try {
+    return JSON.parse(message.slice(INSTALL_DISK_SPACE_ERROR_PREFIX.length)) as InstallDiskSpaceError;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Maps backend `INSTALL_DISK_SPACE::{json}` to a localized message naming the
+ * volume and how much more space is needed. Returns null for other errors.
+ */
+export function formatInstallDiskSpaceError(message: string, t: TFunction): string | null {
+  const error = parseInstallDiskSpaceError(message);
+  if (!error) return null;
+  return t('errors.diskSpace.insufficient', {
+    volume: error.volume,
+    shortfall: formatBytes(error.shortfallBytes),
+    available: formatBytes(error.availableBytes),
+  });
+}
@@ -24,6 +24,7 @@ mod product_assembly;
 mod prompts;
 mod root_handlers;
 mod runtime;
+mod serve;
 mod ui;
 
 use anyhow::{anyhow, Result};
@@ -84,6 +85,14 @@ struct Cli {
     /// Enable verbose logging
     #[arg(short, long, global = true)]
     verbose: bool,
+
+    /// Run headless: serve the automation HTTP/WebSocket API instead of the UI
+    #[arg(long, conflicts_with = "command")]
+    serve: bool,
+
+    /// Localhost port for `--serve`
+    #[arg(long, default_value_t = serve::DEFAULT_SERVE_PORT, requires = "serve")]
+    serve_port: u16,
 }
 
 #[derive(Subcommand)]
@@ -805,7 +814,7 @@ async fn run_cli() -> Result<()> {
         Err(error) => error.exit(),
     };
 
-    let is_tui_mode = matches!(cli.command, None | Some(Commands::Chat { .. }));
+    let is_tui_mode = !cli.serve && matches!(cli.command, None | Some(Commands::Chat { .. }));
     let is_exec_mode = matches!(cli.command, Some(Commands::Exec { .. }));
     let is_daemon_run = matches!(
         cli.command,
@@ -820,7 +829,7 @@ async fn run_cli() -> Result<()> {
         tracing::Level::ERROR
     };
 
-    if is_tui_mode || is_exec_mode || is_daemon_run {
+    if is_tui_mode || is_exec_mode || is_daemon_run || cli.serve {
         logging::init_file_logging(file_log_level);
     } else {
         tracing_subscriber::fmt()
@@ -839,6 +848,10 @@ async fn run_cli() -> Result<()> {
         CliConfig::default()
     });
 
+    if cli.serve {
+        return serve::run_serve(cli.serve_port).await;
+    }
+
     match cli.command {
         Some(Commands::Chat { agent }) => {
             // Interactive mode with startup page, scoped to the current directory.
@@ -150,7 +150,7 @@ impl ChatMode {
         let result: Result<(), String> = tokio::task::block_in_place(|| {
             rt_handle.block_on(async {
                 match skill.level.as_str() {
-                    "user" => {
+                    "user" | "builtin" => {
                         set_user_mode_skill_state(
                             &mode_id,
                             &skill.key,
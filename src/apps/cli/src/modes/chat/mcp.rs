@@ -756,8 +756,17 @@ impl ChatMode {
                 env,
                 working_directory: None,
                 inherit_parent_environment: None,
+                isolation: None,
+                framing: config_obj
+                    .get("framing")
+                    .cloned()
+                    .and_then(|value| serde_json::from_value(value).ok()),
                 headers,
                 url,
+                tls: config_obj
+                    .get("tls")
+                    .cloned()
+                    .and_then(|value| serde_json::from_value(value).ok()),
                 auto_start,
                 enabled,
                 location: bitfun_core::service::mcp::ConfigLocation::User,
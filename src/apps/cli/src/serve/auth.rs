@@ -0,0 +1,150 @@
+//! Bearer token handling for the headless server.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::CliConfig;
+
+/// Overrides the persisted token, e.g. for CI where the token is a secret.
+pub(crate) const SERVE_TOKEN_ENV: &str = "BITFUN_SERVE_TOKEN";
+
+const SERVE_TOKEN_FILE: &str = "serve-token";
+const MIN_TOKEN_LEN: usize = 16;
+
+/// Where the token came from, so startup output can tell the user where to find it.
+pub(crate) enum TokenSource {
+    Environment,
+    File(PathBuf),
+}
+
+/// Resolves the API token: `BITFUN_SERVE_TOKEN` when set, otherwise the token
+/// persisted in the CLI config directory, generating it on first use.
+pub(crate) fn resolve_serve_token() -> Result<(String, TokenSource)> {
+    if let Ok(token) = std::env::var(SERVE_TOKEN_ENV) {
+        let token = token.trim().to_string();
+        if token.len() < MIN_TOKEN_LEN {
+            anyhow::bail!("{SERVE_TOKEN_ENV} must be at least {MIN_TOKEN_LEN} characters");
+        }
+        return Ok((token, TokenSource::Environment));
+    }
+
+    let path = CliConfig::config_dir()?.join(SERVE_TOKEN_FILE);
+    let token = load_or_create_token(&path)?;
+    Ok((token, TokenSource::File(path)))
+}
+
+fn load_or_create_token(path: &Path) -> Result<String> {
+    if let Ok(existing) = std::fs::read_to_string(path) {
+        let existing = existing.trim();
+        if existing.len() >= MIN_TOKEN_LEN {
+            return Ok(existing.to_string());
+        }
+    }
+
+    let token = generate_token();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, &token)
+        .with_context(|| format!("Failed to write serve token to {}", path.display()))?;
+    restrict_permissions(path)?;
+    Ok(token)
+}
+
+pub(super) fn generate_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+#[cfg(unix)]
+pub(super) fn restrict_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+        .with_context(|| format!("Failed to restrict permissions on {}", path.display()))
+}
+
+#[cfg(not(unix))]
+pub(super) fn restrict_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Extracts the token from an `Authorization: Bearer <token>` header value.
+pub(crate) fn bearer_token(header: Option<&str>) -> Option<&str> {
+    header
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
+/// Constant-time comparison so response timing does not leak token prefixes.
+pub(crate) fn token_matches(presented: &str, token: &str) -> bool {
+    let presented = presented.as_bytes();
+    let token = token.as_bytes();
+    if presented.len() != token.len() {
+        return false;
+    }
+    presented
+        .iter()
+        .zip(token)
+        .fold(0u8, |diff, (left, right)| diff | (left ^ right))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authorization_matches(header: Option<&str>, token: &str) -> bool {
+        bearer_token(header).is_some_and(|presented| token_matches(presented, token))
+    }
+
+    #[test]
+    fn authorization_requires_bearer_scheme_and_exact_token() {
+        let token = "0123456789abcdef0123";
+        assert!(authorization_matches(
+            Some("Bearer 0123456789abcdef0123"),
+            token
+        ));
+        assert!(!authorization_matches(None, token));
+        assert!(!authorization_matches(Some("0123456789abcdef0123"), token));
+        assert!(!authorization_matches(
+            Some("Basic 0123456789abcdef0123"),
+            token
+        ));
+        assert!(!authorization_matches(
+            Some("Bearer 0123456789abcdef012"),
+            token
+        ));
+        assert!(!authorization_matches(
+            Some("Bearer 0123456789abcdef0124"),
+            token
+        ));
+    }
+
+    #[test]
+    fn generated_token_is_persisted_and_reused() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("nested").join(SERVE_TOKEN_FILE);
+
+        let first = load_or_create_token(&path).unwrap();
+        let second = load_or_create_token(&path).unwrap();
+
+        assert_eq!(first, second);
+        assert!(first.len() >= MIN_TOKEN_LEN);
+    }
+
+    #[test]
+    fn short_persisted_tokens_are_replaced() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join(SERVE_TOKEN_FILE);
+        std::fs::write(&path, "short").unwrap();
+
+        let token = load_or_create_token(&path).unwrap();
+
+        assert_ne!(token, "short");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), token);
+    }
+}
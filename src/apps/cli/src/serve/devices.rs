@@ -0,0 +1,600 @@
+//! Paired device registry for the headless server.
+//!
+//! The owner (holder of the serve token) issues short-lived pairing codes.
+//! A device redeems a code with its id and public key and receives its own
+//! bearer token; only a hash of that token is stored. Each device carries a
+//! permission scope, can be revoked, and every binding change is audited.
+
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::auth::{generate_token, restrict_permissions};
+
+const DEVICE_REGISTRY_VERSION: u32 = 1;
+const DEVICE_REGISTRY_FILE: &str = "serve-devices.json";
+const PAIRING_CODE_DIGITS: u32 = 8;
+pub(crate) const DEFAULT_PAIRING_TTL_SECS: i64 = 300;
+const MAX_PAIRING_TTL_SECS: i64 = 3600;
+const MAX_PENDING_PAIRINGS: usize = 5;
+/// Failed redemptions tolerated before all outstanding codes are invalidated.
+const MAX_FAILED_REDEMPTIONS: u32 = 5;
+const MAX_AUDIT_ENTRIES: usize = 500;
+/// `last_seen_at` updates are only persisted this often.
+const LAST_SEEN_PERSIST_INTERVAL_MS: i64 = 60_000;
+
+/// What a paired device may do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DeviceScope {
+    /// Read status, tasks and the event stream.
+    #[default]
+    ViewOnly,
+    /// Additionally start and manage tasks and answer permission prompts.
+    Control,
+}
+
+impl DeviceScope {
+    pub(crate) fn allows_control(self) -> bool {
+        matches!(self, Self::Control)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceRecord {
+    device_id: String,
+    device_name: String,
+    /// Base64 raw 32-byte public key; pinned at first pairing.
+    public_key: String,
+    scope: DeviceScope,
+    token_hash: String,
+    paired_at: i64,
+    last_seen_at: Option<i64>,
+    revoked_at: Option<i64>,
+}
+
+/// Device as reported by the API; never includes token material.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeviceInfo {
+    pub device_id: String,
+    pub device_name: String,
+    pub public_key: String,
+    pub scope: DeviceScope,
+    pub paired_at: i64,
+    pub last_seen_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+}
+
+impl From<&DeviceRecord> for DeviceInfo {
+    fn from(record: &DeviceRecord) -> Self {
+        Self {
+            device_id: record.device_id.clone(),
+            device_name: record.device_name.clone(),
+            public_key: record.public_key.clone(),
+            scope: record.scope,
+            paired_at: record.paired_at,
+            last_seen_at: record.last_seen_at,
+            revoked_at: record.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DeviceAuditAction {
+    Paired,
+    Repaired,
+    ScopeChanged,
+    Revoked,
+    PairingRejected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeviceAuditEntry {
+    pub at: i64,
+    pub action: DeviceAuditAction,
+    pub device_id: Option<String>,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceRegistryFile {
+    version: u32,
+    devices: Vec<DeviceRecord>,
+    audit: Vec<DeviceAuditEntry>,
+}
+
+impl Default for DeviceRegistryFile {
+    fn default() -> Self {
+        Self {
+            version: DEVICE_REGISTRY_VERSION,
+            devices: Vec::new(),
+            audit: Vec::new(),
+        }
+    }
+}
+
+/// A code the owner can hand to a device (typically rendered as a QR code).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PairingOffer {
+    pub code: String,
+    pub scope: DeviceScope,
+    pub expires_at: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PairingRequest {
+    pub code: String,
+    pub device_id: String,
+    pub device_name: String,
+    pub public_key: String,
+}
+
+/// Result of a successful pairing. `token` is only ever returned here.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PairingGrant {
+    pub device: DeviceInfo,
+    pub token: String,
+}
+
+struct RegistryState {
+    file: DeviceRegistryFile,
+    pending: Vec<PairingOffer>,
+    failed_redemptions: u32,
+    last_persisted_seen: i64,
+}
+
+pub(crate) struct DeviceRegistry {
+    path: PathBuf,
+    state: Mutex<RegistryState>,
+}
+
+impl DeviceRegistry {
+    pub(crate) fn default_path(config_dir: &Path) -> PathBuf {
+        config_dir.join(DEVICE_REGISTRY_FILE)
+    }
+
+    pub(crate) fn load(path: PathBuf) -> Result<Self> {
+        let file = match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                let file: DeviceRegistryFile = serde_json::from_str(&content)
+                    .with_context(|| format!("Failed to parse {}", path.display()))?;
+                if file.version != DEVICE_REGISTRY_VERSION {
+                    anyhow::bail!(
+                        "Unsupported device registry version {} in {}",
+                        file.version,
+                        path.display()
+                    );
+                }
+                file
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                DeviceRegistryFile::default()
+            }
+            Err(error) => {
+                return Err(error).with_context(|| format!("Failed to read {}", path.display()))
+            }
+        };
+
+        Ok(Self {
+            path,
+            state: Mutex::new(RegistryState {
+                file,
+                pending: Vec::new(),
+                failed_redemptions: 0,
+                last_persisted_seen: 0,
+            }),
+        })
+    }
+
+    /// Issues a one-time pairing code valid for `ttl_secs`.
+    pub(crate) fn create_pairing(&self, scope: DeviceScope, ttl_secs: i64) -> PairingOffer {
+        let now = now_ms();
+        let ttl_secs = ttl_secs.clamp(1, MAX_PAIRING_TTL_SECS);
+        let mut state = self.lock();
+        state.pending.retain(|offer| offer.expires_at > now);
+        if state.pending.len() >= MAX_PENDING_PAIRINGS {
+            state.pending.remove(0);
+        }
+
+        let offer = PairingOffer {
+            code: generate_pairing_code(),
+            scope,
+            expires_at: now + ttl_secs * 1000,
+        };
+        state.pending.push(offer.clone());
+        offer
+    }
+
+    /// Redeems a pairing code and binds the device.
+    ///
+    /// A device id that is already bound must present the same public key;
+    /// re-pairing rotates its token. Revoked devices may pair again.
+    pub(crate) fn complete_pairing(&self, request: PairingRequest) -> Result<PairingGrant> {
+        let public_key = validate_pairing_request(&request)?;
+
+        let now = now_ms();
+        let mut state = self.lock();
+        state.pending.retain(|offer| offer.expires_at > now);
+
+        let Some(index) = state
+            .pending
+            .iter()
+            .position(|offer| super::auth::token_matches(request.code.trim(), &offer.code))
+        else {
+            state.failed_redemptions += 1;
+            if state.failed_redemptions >= MAX_FAILED_REDEMPTIONS {
+                state.pending.clear();
+                state.failed_redemptions = 0;
+            }
+            push_audit(
+                &mut state.file,
+                DeviceAuditAction::PairingRejected,
+                Some(&request.device_id),
+                Some("invalid or expired code".to_string()),
+            );
+            self.persist(&state.file)?;
+            return Err(anyhow!("Invalid or expired pairing code"));
+        };
+
+        let existing = state
+            .file
+            .devices
+            .iter()
+            .position(|device| device.device_id == request.device_id);
+        if let Some(existing) = existing {
+            let device = &state.file.devices[existing];
+            if device.revoked_at.is_none() && device.public_key != public_key {
+                push_audit(
+                    &mut state.file,
+                    DeviceAuditAction::PairingRejected,
+                    Some(&request.device_id),
+                    Some("public key mismatch".to_string()),
+                );
+                self.persist(&state.file)?;
+                return Err(anyhow!(
+                    "Device is already paired with a different key; revoke it first"
+                ));
+            }
+        }
+
+        let offer = state.pending.remove(index);
+        state.failed_redemptions = 0;
+        let token = generate_token();
+        let record = DeviceRecord {
+            device_id: request.device_id.clone(),
+            device_name: request.device_name.trim().to_string(),
+            public_key,
+            scope: offer.scope,
+            token_hash: hash_token(&token),
+            paired_at: now,
+            last_seen_at: None,
+            revoked_at: None,
+        };
+        let device = DeviceInfo::from(&record);
+        let action = match existing {
+            Some(index) => {
+                state.file.devices[index] = record;
+                DeviceAuditAction::Repaired
+            }
+            None => {
+                state.file.devices.push(record);
+                DeviceAuditAction::Paired
+            }
+        };
+        push_audit(
+            &mut state.file,
+            action,
+            Some(&device.device_id),
+            Some(format!("scope={:?}", device.scope)),
+        );
+        self.persist(&state.file)?;
+
+        Ok(PairingGrant { device, token })
+    }
+
+    /// Resolves a device bearer token to an active device.
+    pub(crate) fn authenticate(&self, token: &str) -> Option<DeviceInfo> {
+        let token_hash = hash_token(token);
+        let now = now_ms();
+        let mut state = self.lock();
+        let device = state.file.devices.iter_mut().find(|device| {
+            device.revoked_at.is_none()
+                && super::auth::token_matches(&token_hash, &device.token_hash)
+        })?;
+        device.last_seen_at = Some(now);
+        let info = DeviceInfo::from(&*device);
+
+        if now - state.last_persisted_seen >= LAST_SEEN_PERSIST_INTERVAL_MS {
+            state.last_persisted_seen = now;
+            if let Err(error) = self.persist(&state.file) {
+                tracing::warn!("Failed to persist device last-seen time: {error}");
+            }
+        }
+        Some(info)
+    }
+
+    pub(crate) fn list_devices(&self) -> Vec<DeviceInfo> {
+        self.lock()
+            .file
+            .devices
+            .iter()
+            .map(DeviceInfo::from)
+            .collect()
+    }
+
+    pub(crate) fn audit_log(&self) -> Vec<DeviceAuditEntry> {
+        self.lock().file.audit.clone()
+    }
+
+    pub(crate) fn set_scope(&self, device_id: &str, scope: DeviceScope) -> Result<DeviceInfo> {
+        let mut state = self.lock();
+        let device = active_device_mut(&mut state.file, device_id)?;
+        let previous = device.scope;
+        device.scope = scope;
+        let info = DeviceInfo::from(&*device);
+        push_audit(
+            &mut state.file,
+            DeviceAuditAction::ScopeChanged,
+            Some(device_id),
+            Some(format!("{previous:?} -> {scope:?}")),
+        );
+        self.persist(&state.file)?;
+        Ok(info)
+    }
+
+    pub(crate) fn revoke(&self, device_id: &str) -> Result<DeviceInfo> {
+        let mut state = self.lock();
+        let device = active_device_mut(&mut state.file, device_id)?;
+        device.revoked_at = Some(now_ms());
+        let info = DeviceInfo::from(&*device);
+        push_audit(
+            &mut state.file,
+            DeviceAuditAction::Revoked,
+            Some(device_id),
+            None,
+        );
+        self.persist(&state.file)?;
+        Ok(info)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RegistryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn persist(&self, file: &DeviceRegistryFile) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(file)?;
+        let temp = self.path.with_extension("json.tmp");
+        std::fs::write(&temp, content)
+            .with_context(|| format!("Failed to write {}", temp.display()))?;
+        // Holds token hashes, so keep it as private as the serve token.
+        restrict_permissions(&temp)?;
+        std::fs::rename(&temp, &self.path)
+            .with_context(|| format!("Failed to replace {}", self.path.display()))
+    }
+}
+
+fn active_device_mut<'a>(
+    file: &'a mut DeviceRegistryFile,
+    device_id: &str,
+) -> Result<&'a mut DeviceRecord> {
+    file.devices
+        .iter_mut()
+        .find(|device| device.device_id == device_id && device.revoked_at.is_none())
+        .ok_or_else(|| anyhow!("No active device with id {device_id}"))
+}
+
+fn push_audit(
+    file: &mut DeviceRegistryFile,
+    action: DeviceAuditAction,
+    device_id: Option<&str>,
+    detail: Option<String>,
+) {
+    file.audit.push(DeviceAuditEntry {
+        at: now_ms(),
+        action,
+        device_id: device_id.map(str::to_string),
+        detail,
+    });
+    if file.audit.len() > MAX_AUDIT_ENTRIES {
+        let overflow = file.audit.len() - MAX_AUDIT_ENTRIES;
+        file.audit.drain(..overflow);
+    }
+}
+
+/// Returns the public key re-encoded in canonical base64 so it compares
+/// reliably against the pinned key.
+fn validate_pairing_request(request: &PairingRequest) -> Result<String> {
+    let device_id = request.device_id.as_str();
+    if device_id.is_empty()
+        || device_id.len() > 128
+        || !device_id
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'))
+    {
+        return Err(anyhow!("Invalid device id"));
+    }
+    let device_name = request.device_name.trim();
+    if device_name.is_empty()
+        || device_name.len() > 256
+        || device_name.chars().any(char::is_control)
+    {
+        return Err(anyhow!("Invalid device name"));
+    }
+    let key = BASE64
+        .decode(request.public_key.trim())
+        .map_err(|_| anyhow!("Public key must be base64"))?;
+    if key.len() != 32 {
+        return Err(anyhow!("Public key must be 32 bytes"));
+    }
+    Ok(BASE64.encode(key))
+}
+
+fn generate_pairing_code() -> String {
+    let upper = 10u32.pow(PAIRING_CODE_DIGITS);
+    format!(
+        "{:0width$}",
+        rand::thread_rng().gen_range(0..upper),
+        width = PAIRING_CODE_DIGITS as usize
+    )
+}
+
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> (tempfile::TempDir, DeviceRegistry) {
+        let temp = tempfile::tempdir().unwrap();
+        let registry = DeviceRegistry::load(DeviceRegistry::default_path(temp.path())).unwrap();
+        (temp, registry)
+    }
+
+    fn request(code: &str, device_id: &str, key_byte: u8) -> PairingRequest {
+        PairingRequest {
+            code: code.to_string(),
+            device_id: device_id.to_string(),
+            device_name: "Phone".to_string(),
+            public_key: BASE64.encode([key_byte; 32]),
+        }
+    }
+
+    #[test]
+    fn pairing_code_is_single_use_and_token_authenticates() {
+        let (_temp, registry) = registry();
+        let offer = registry.create_pairing(DeviceScope::Control, DEFAULT_PAIRING_TTL_SECS);
+
+        let grant = registry
+            .complete_pairing(request(&offer.code, "phone-1", 1))
+            .unwrap();
+        assert_eq!(grant.device.scope, DeviceScope::Control);
+        assert!(registry
+            .complete_pairing(request(&offer.code, "phone-2", 2))
+            .is_err());
+
+        let device = registry.authenticate(&grant.token).unwrap();
+        assert_eq!(device.device_id, "phone-1");
+        assert!(registry.authenticate("not-a-device-token").is_none());
+    }
+
+    #[test]
+    fn revoked_device_loses_access_and_registry_survives_reload() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = DeviceRegistry::default_path(temp.path());
+        let registry = DeviceRegistry::load(path.clone()).unwrap();
+        let offer = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        let grant = registry
+            .complete_pairing(request(&offer.code, "tablet", 3))
+            .unwrap();
+
+        registry.revoke("tablet").unwrap();
+        assert!(registry.authenticate(&grant.token).is_none());
+
+        let reloaded = DeviceRegistry::load(path).unwrap();
+        let devices = reloaded.list_devices();
+        assert_eq!(devices.len(), 1);
+        assert!(devices[0].revoked_at.is_some());
+        let actions: Vec<_> = reloaded
+            .audit_log()
+            .into_iter()
+            .map(|entry| entry.action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![DeviceAuditAction::Paired, DeviceAuditAction::Revoked]
+        );
+    }
+
+    #[test]
+    fn active_device_cannot_rebind_with_different_key() {
+        let (_temp, registry) = registry();
+        let first = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        registry
+            .complete_pairing(request(&first.code, "phone", 1))
+            .unwrap();
+
+        let second = registry.create_pairing(DeviceScope::Control, DEFAULT_PAIRING_TTL_SECS);
+        assert!(registry
+            .complete_pairing(request(&second.code, "phone", 9))
+            .is_err());
+        let grant = registry
+            .complete_pairing(request(&second.code, "phone", 1))
+            .unwrap();
+        assert_eq!(grant.device.scope, DeviceScope::Control);
+    }
+
+    #[test]
+    fn pinned_key_ignores_surrounding_whitespace() {
+        let (_temp, registry) = registry();
+        let first = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        let mut padded = request(&first.code, "phone", 1);
+        padded.public_key = format!(" {}\n", padded.public_key);
+        let grant = registry.complete_pairing(padded).unwrap();
+        assert_eq!(grant.device.public_key, BASE64.encode([1u8; 32]));
+
+        let second = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        assert!(registry
+            .complete_pairing(request(&second.code, "phone", 1))
+            .is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn registry_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp = tempfile::tempdir().unwrap();
+        let path = DeviceRegistry::default_path(temp.path());
+        let registry = DeviceRegistry::load(path.clone()).unwrap();
+        let offer = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        registry
+            .complete_pairing(request(&offer.code, "phone", 1))
+            .unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn repeated_bad_codes_invalidate_outstanding_offers() {
+        let (_temp, registry) = registry();
+        let offer = registry.create_pairing(DeviceScope::ViewOnly, DEFAULT_PAIRING_TTL_SECS);
+        let wrong = if offer.code == "00000000" {
+            "00000001"
+        } else {
+            "00000000"
+        };
+
+        for _ in 0..MAX_FAILED_REDEMPTIONS {
+            assert!(registry
+                .complete_pairing(request(wrong, "phone", 1))
+                .is_err());
+        }
+        assert!(registry
+            .complete_pairing(request(&offer.code, "phone", 1))
+            .is_err());
+    }
+}
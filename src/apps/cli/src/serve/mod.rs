@@ -0,0 +1,241 @@
+//! Headless automation server (`bitfun --serve`).
+//!
+//! Runs the core services without a UI and exposes a token-protected
+//! localhost API for CI jobs and remote clients:
+//! - `GET  /api/v1/status`
+//! - `GET  /api/v1/tasks`, `POST /api/v1/tasks`, `GET /api/v1/tasks/{id}`
+//! - `POST /api/v1/tasks/{id}/{pause|resume|retry|cancel}`
+//! - `POST /api/v1/permissions/{request_id}`
+//! - `GET  /api/v1/events` (WebSocket stream of agent, task and permission events)
+//! - `POST /api/v1/pairing`, `POST /api/v1/pairing/complete`
+//! - `GET  /api/v1/devices`, `GET /api/v1/devices/audit`
+//! - `POST /api/v1/devices/{id}/{revoke|scope}`
+//! - `GET  /api/v1/approvals`, `POST /api/v1/approvals/{id}`
+//!
+//! The serve token grants full access, including device management. Paired
+//! devices authenticate with their own token and are limited to their scope;
+//! their control actions additionally need owner approval unless remembered.
+
+mod auth;
+mod devices;
+mod routes;
+
+use anyhow::{anyhow, Context, Result};
+use bitfun_agent_runtime::sdk::PermissionRequestEvent;
+use bitfun_core::agentic::events::EventSubscriber;
+use bitfun_core::agentic::task_queue::{
+    set_global_agent_task_service, AgentTaskEvent, AgentTaskEventSubscriber, AgentTaskService,
+};
+use bitfun_core::service::approvals::{
+    set_global_approval_service, spawn_permission_approval_bridge, ApprovalEvent, ApprovalService,
+};
+use bitfun_events::AgenticEvent;
+use serde::Serialize;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::broadcast;
+
+use crate::runtime::approval::CliApprovalPolicy;
+use crate::runtime::CliRuntimeContext;
+use auth::{resolve_serve_token, TokenSource};
+use devices::DeviceRegistry;
+
+pub(crate) const DEFAULT_SERVE_PORT: u16 = 7870;
+
+const SERVE_EVENT_BUFFER: usize = 1024;
+
+/// Message pushed to `/api/v1/events` subscribers.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "event", rename_all = "camelCase")]
+pub(crate) enum ServeEvent {
+    Agentic(AgenticEvent),
+    Task(AgentTaskEvent),
+    Permission(PermissionRequestEvent),
+    Approval(ApprovalEvent),
+}
+
+impl ServeEvent {
+    fn session_id(&self) -> Option<&str> {
+        match self {
+            Self::Agentic(event) => event.session_id(),
+            Self::Task(event) => Some(event.task.session_id.as_str()),
+            Self::Permission(PermissionRequestEvent::Asked { request }) => {
+                Some(request.session_id.as_str())
+            }
+            Self::Permission(_) => None,
+            Self::Approval(event) => event.request().session_id.as_deref(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct ServeState {
+    runtime: Arc<CliRuntimeContext>,
+    tasks: Arc<AgentTaskService>,
+    approvals: Arc<ApprovalService>,
+    token: Arc<str>,
+    devices: Arc<DeviceRegistry>,
+    events: broadcast::Sender<ServeEvent>,
+    addr: SocketAddr,
+    started_at: Instant,
+}
+
+/// Starts core services and serves the automation API until Ctrl+C.
+pub(crate) async fn run_serve(port: u16) -> Result<()> {
+    let workspace_root = std::env::current_dir().context("Failed to resolve workspace")?;
+    let (token, token_source) = resolve_serve_token()?;
+    let devices = DeviceRegistry::load(DeviceRegistry::default_path(
+        &crate::config::CliConfig::config_dir()?,
+    ))?;
+
+    // Permission requests are surfaced to API clients instead of being
+    // rejected, so remote clients can approve tools like the desktop UI does.
+    let runtime = crate::initialize_core_services(
+        &workspace_root,
+        CliApprovalPolicy::Ask,
+        crate::BootstrapProfile::Execution,
+    )
+    .await?;
+
+    let path_manager = bitfun_core::infrastructure::try_get_path_manager_arc()
+        .map_err(|error| anyhow!("Failed to resolve BitFun paths: {error}"))?;
+    let scheduler = bitfun_core::agentic::coordination::get_global_scheduler()
+        .ok_or_else(|| anyhow!("Dialog scheduler is not initialized"))?;
+    let tasks = AgentTaskService::new(path_manager.clone(), scheduler)
+        .await
+        .map_err(|error| anyhow!("Failed to initialize agent task service: {error}"))?;
+    set_global_agent_task_service(tasks.clone());
+    let approvals = ApprovalService::new(path_manager)
+        .await
+        .map_err(|error| anyhow!("Failed to initialize approval service: {error}"))?;
+    set_global_approval_service(approvals.clone());
+    spawn_permission_approval_bridge(
+        bitfun_core::product_runtime::core_permission_request_manager()
+            .map_err(|error| anyhow!(error))?,
+        approvals.clone(),
+    );
+
+    let (events, _) = broadcast::channel(SERVE_EVENT_BUFFER);
+    spawn_event_pumps(&runtime, &tasks, &approvals, &events)?;
+    tasks.start();
+
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind {addr}"))?;
+
+    let state = ServeState {
+        runtime,
+        tasks,
+        approvals,
+        token: Arc::from(token),
+        devices: Arc::new(devices),
+        events,
+        addr,
+        started_at: Instant::now(),
+    };
+
+    println!("BitFun headless server listening on http://{addr}");
+    println!("Workspace: {}", workspace_root.display());
+    match token_source {
+        TokenSource::Environment => {
+            println!("Auth: bearer token from {}", auth::SERVE_TOKEN_ENV)
+        }
+        TokenSource::File(path) => println!("Auth: bearer token in {}", path.display()),
+    }
+    tracing::info!("Headless server started: addr={addr}");
+
+    let served = axum::serve(listener, routes::router(state))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+            tracing::info!("Headless server shutting down");
+        })
+        .await;
+
+    crate::shutdown_mcp_servers().await;
+    served.context("Headless server failed")
+}
+
+/// Fans agent, task, permission and approval events into the server broadcast channel.
+/// Agent events also drive the task queue, since the CLI has no event router
+/// subscription for it.
+fn spawn_event_pumps(
+    runtime: &CliRuntimeContext,
+    tasks: &Arc<AgentTaskService>,
+    approvals: &ApprovalService,
+    events: &broadcast::Sender<ServeEvent>,
+) -> Result<()> {
+    let mut agent_rx = runtime.agent_events().subscribe();
+    let task_subscriber = AgentTaskEventSubscriber::new(tasks.clone());
+    let sender = events.clone();
+    tokio::spawn(async move {
+        loop {
+            match agent_rx.recv().await {
+                Ok(envelope) => {
+                    if let Err(error) = task_subscriber.on_event(&envelope.event).await {
+                        tracing::warn!("Failed to update agent task from event: {error}");
+                    }
+                    let _ = sender.send(ServeEvent::Agentic(envelope.event));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Headless server agent event pump lagged by {skipped}");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    let mut task_rx = tasks.subscribe();
+    let sender = events.clone();
+    tokio::spawn(async move {
+        loop {
+            match task_rx.recv().await {
+                Ok(event) => {
+                    let _ = sender.send(ServeEvent::Task(event));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Headless server task event pump lagged by {skipped}");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    let mut permission_rx = runtime
+        .agent_runtime()
+        .subscribe_permission_requests()
+        .map_err(|error| anyhow!(error.into_message()))?;
+    let sender = events.clone();
+    tokio::spawn(async move {
+        loop {
+            match permission_rx.recv().await {
+                Ok(event) => {
+                    let _ = sender.send(ServeEvent::Permission(event));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Headless server permission event pump lagged by {skipped}");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    let mut approval_rx = approvals.subscribe();
+    let sender = events.clone();
+    tokio::spawn(async move {
+        loop {
+            match approval_rx.recv().await {
+                Ok(event) => {
+                    let _ = sender.send(ServeEvent::Approval(event));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Headless server approval event pump lagged by {skipped}");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,550 @@
+//! HTTP and WebSocket routes for the headless server.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use bitfun_agent_runtime::sdk::{
+    AgentSessionCreateRequest, PermissionReply, PermissionReplySource,
+};
+use bitfun_core::agentic::task_queue::{AgentTask, AgentTaskPriority, EnqueueAgentTaskRequest};
+use bitfun_core::service::approvals::{
+    ApprovalKind, ApprovalRequest, ApprovalResolution, ApprovalResponder, ApprovalResponse,
+    NewApprovalRequest,
+};
+use bitfun_core::util::errors::BitFunError;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use super::auth::{bearer_token, token_matches};
+use super::devices::{
+    DeviceAuditEntry, DeviceInfo, DeviceScope, PairingGrant, PairingOffer, PairingRequest,
+    DEFAULT_PAIRING_TTL_SECS,
+};
+use super::{ServeEvent, ServeState};
+
+pub(super) fn router(state: ServeState) -> Router {
+    Router::new()
+        .route("/api/v1/status", get(status))
+        .route("/api/v1/tasks", get(list_tasks).post(create_task))
+        .route("/api/v1/tasks/{task_id}", get(get_task))
+        .route("/api/v1/tasks/{task_id}/{action}", post(task_action))
+        .route("/api/v1/permissions/{request_id}", post(reply_permission))
+        .route("/api/v1/events", get(events))
+        .route("/api/v1/pairing", post(create_pairing))
+        .route("/api/v1/pairing/complete", post(complete_pairing))
+        .route("/api/v1/devices", get(list_devices))
+        .route("/api/v1/devices/audit", get(device_audit))
+        .route("/api/v1/devices/{device_id}/revoke", post(revoke_device))
+        .route("/api/v1/devices/{device_id}/scope", post(set_device_scope))
+        .route("/api/v1/approvals", get(list_approvals))
+        .route("/api/v1/approvals/{request_id}", post(respond_approval))
+        .layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+/// JSON error body: `{ "error": "..." }`.
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<BitFunError> for ApiError {
+    fn from(error: BitFunError) -> Self {
+        let status = match error {
+            BitFunError::NotFound(_) => StatusCode::NOT_FOUND,
+            BitFunError::Validation(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self::new(status, error.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            Json(serde_json::json!({ "error": self.message })),
+        )
+            .into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthQuery {
+    token: Option<String>,
+}
+
+/// Access level a route requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    /// Pairing redemption: the pairing code is the credential.
+    Public,
+    View,
+    Control,
+    /// Device management; only the serve token qualifies.
+    Owner,
+}
+
+fn required_access(method: &Method, path: &str) -> Access {
+    if path == "/api/v1/pairing/complete" {
+        Access::Public
+    } else if path.starts_with("/api/v1/pairing")
+        || path.starts_with("/api/v1/devices")
+        || path.starts_with("/api/v1/approvals")
+    {
+        Access::Owner
+    } else if method == Method::GET {
+        Access::View
+    } else {
+        Access::Control
+    }
+}
+
+/// Describes a device control request for approval prompts and remembered
+/// choices, e.g. `task:cancel` for `POST /api/v1/tasks/{id}/cancel`.
+fn device_action(path: &str) -> String {
+    let segments: Vec<_> = path
+        .trim_start_matches("/api/v1/")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    match segments.as_slice() {
+        ["tasks"] => "task:create".to_string(),
+        ["tasks", _, action] => format!("task:{action}"),
+        ["permissions", _] => "permission:reply".to_string(),
+        _ => path.to_string(),
+    }
+}
+
+/// Requires `Authorization: Bearer <token>` with either the serve token or a
+/// paired device token whose scope covers the route. WebSocket clients that
+/// cannot set headers (browsers) may pass `?token=` on `/api/v1/events` instead.
+///
+/// Control actions from devices go through the approval workflow so the owner
+/// can confirm them; the request is held until the approval resolves.
+async fn require_token(
+    State(state): State<ServeState>,
+    Query(query): Query<AuthQuery>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let access = required_access(request.method(), request.uri().path());
+    if access == Access::Public {
+        return next.run(request).await;
+    }
+
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let presented = bearer_token(header).or_else(|| {
+        (request.uri().path() == "/api/v1/events")
+            .then_some(query.token.as_deref())
+            .flatten()
+    });
+
+    let is_owner = presented.is_some_and(|token| token_matches(token, &state.token));
+    let allowed = if is_owner {
+        true
+    } else {
+        match presented.and_then(|token| state.devices.authenticate(token)) {
+            Some(device) => match access {
+                Access::Public | Access::View => true,
+                Access::Control => {
+                    device.scope.allows_control()
+                        && approve_device_action(&state, &device, request.uri().path()).await
+                }
+                Access::Owner => false,
+            },
+            None => {
+                return ApiError::new(StatusCode::UNAUTHORIZED, "Missing or invalid API token")
+                    .into_response();
+            }
+        }
+    };
+
+    if !allowed {
+        return ApiError::new(
+            StatusCode::FORBIDDEN,
+            "Device is not permitted to perform this action",
+        )
+        .into_response();
+    }
+    next.run(request).await
+}
+
+async fn approve_device_action(state: &ServeState, device: &DeviceInfo, path: &str) -> bool {
+    let action = device_action(path);
+    let resolution = state
+        .approvals
+        .request_approval(NewApprovalRequest {
+            id: None,
+            kind: ApprovalKind::RemoteDeviceAction,
+            title: format!("{} wants to perform {action}", device.device_name),
+            detail: Some(path.to_string()),
+            subject: format!("{}:{action}", device.device_id),
+            session_id: None,
+            requester: Some(device.device_id.clone()),
+        })
+        .await;
+    match resolution {
+        Ok(resolution) => resolution.decision.is_approved(),
+        Err(error) => {
+            tracing::warn!("Device action approval failed: {error}");
+            false
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusResponse {
+    version: &'static str,
+    uptime_seconds: u64,
+    workspace: String,
+    mcp: String,
+    queued_tasks: usize,
+    running_tasks: usize,
+}
+
+async fn status(State(state): State<ServeState>) -> ApiResult<StatusResponse> {
+    let tasks = state.tasks.list_tasks(None).await;
+    let count = |status| tasks.iter().filter(|task| task.status == status).count();
+    Ok(Json(StatusResponse {
+        version: env!("CARGO_PKG_VERSION"),
+        uptime_seconds: state.started_at.elapsed().as_secs(),
+        workspace: state.runtime.workspace_root().to_string_lossy().to_string(),
+        mcp: crate::get_mcp_status_text(),
+        queued_tasks: count(bitfun_core::agentic::task_queue::AgentTaskStatus::Queued),
+        running_tasks: count(bitfun_core::agentic::task_queue::AgentTaskStatus::Running),
+    }))
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionFilter {
+    session_id: Option<String>,
+}
+
+async fn list_tasks(
+    State(state): State<ServeState>,
+    Query(filter): Query<SessionFilter>,
+) -> ApiResult<Vec<AgentTask>> {
+    Ok(Json(
+        state.tasks.list_tasks(filter.session_id.as_deref()).await,
+    ))
+}
+
+async fn get_task(
+    State(state): State<ServeState>,
+    Path(task_id): Path<String>,
+) -> ApiResult<AgentTask> {
+    state
+        .tasks
+        .get_task(&task_id)
+        .await
+        .map(Json)
+        .ok_or_else(|| ApiError::new(StatusCode::NOT_FOUND, "Task not found"))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateTaskRequest {
+    prompt: String,
+    /// Existing session to run in; a new session is created when omitted.
+    session_id: Option<String>,
+    #[serde(default = "default_agent_type")]
+    agent_type: String,
+    title: Option<String>,
+    #[serde(default)]
+    priority: AgentTaskPriority,
+    max_attempts: Option<u32>,
+}
+
+fn default_agent_type() -> String {
+    "agentic".to_string()
+}
+
+async fn create_task(
+    State(state): State<ServeState>,
+    Json(request): Json<CreateTaskRequest>,
+) -> Result<(StatusCode, Json<AgentTask>), ApiError> {
+    let workspace_path = state.runtime.workspace_root().to_string_lossy().to_string();
+    let session_id = match request.session_id {
+        Some(session_id) => session_id,
+        None => {
+            state
+                .runtime
+                .agent_runtime()
+                .create_session(AgentSessionCreateRequest {
+                    session_name: request
+                        .title
+                        .clone()
+                        .unwrap_or_else(|| "Headless task".to_string()),
+                    agent_type: request.agent_type.clone(),
+                    workspace_path: Some(workspace_path.clone()),
+                    workspace_id: None,
+                    remote_connection_id: None,
+                    remote_ssh_host: None,
+                    model_id: None,
+                    metadata: serde_json::Map::new(),
+                })
+                .await
+                .map_err(|error| {
+                    ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into_message())
+                })?
+                .session_id
+        }
+    };
+
+    let task = state
+        .tasks
+        .enqueue_task(EnqueueAgentTaskRequest {
+            session_id,
+            title: request.title,
+            prompt: request.prompt,
+            agent_type: request.agent_type,
+            workspace_path: Some(workspace_path),
+            remote_connection_id: None,
+            remote_ssh_host: None,
+            priority: request.priority,
+            max_attempts: request.max_attempts,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+async fn task_action(
+    State(state): State<ServeState>,
+    Path((task_id, action)): Path<(String, String)>,
+) -> ApiResult<AgentTask> {
+    let task = match action.as_str() {
+        "pause" => state.tasks.pause_task(&task_id).await?,
+        "resume" => state.tasks.resume_task(&task_id).await?,
+        "retry" => state.tasks.retry_task(&task_id).await?,
+        "cancel" => state.tasks.cancel_task(&task_id).await?,
+        other => {
+            return Err(ApiError::new(
+                StatusCode::NOT_FOUND,
+                format!("Unknown task action: {other}"),
+            ))
+        }
+    };
+    Ok(Json(task))
+}
+
+async fn reply_permission(
+    State(state): State<ServeState>,
+    Path(request_id): Path<String>,
+    Json(reply): Json<PermissionReply>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .runtime
+        .agent_runtime()
+        .respond_permission_with_source(&request_id, reply, PermissionReplySource::User)
+        .await
+        .map_err(|error| ApiError::new(StatusCode::BAD_REQUEST, error.into_message()))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn events(
+    State(state): State<ServeState>,
+    Query(filter): Query<SessionFilter>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let receiver = state.events.subscribe();
+    upgrade.on_upgrade(move |socket| stream_events(socket, receiver, filter.session_id))
+}
+
+/// Streams server events to one client, optionally limited to one session.
+async fn stream_events(
+    mut socket: WebSocket,
+    mut receiver: broadcast::Receiver<ServeEvent>,
+    session_id: Option<String>,
+) {
+    loop {
+        tokio::select! {
+            event = receiver.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Headless server client lagged by {skipped} events");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if session_id
+                    .as_deref()
+                    .is_some_and(|session_id| event.session_id().is_some_and(|id| id != session_id))
+                {
+                    continue;
+                }
+                let Ok(payload) = serde_json::to_string(&event) else {
+                    continue;
+                };
+                if socket.send(Message::Text(payload.into())).await.is_err() {
+                    break;
+                }
+            }
+            incoming = socket.recv() => {
+                match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreatePairingRequest {
+    #[serde(default)]
+    scope: DeviceScope,
+    ttl_seconds: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreatePairingResponse {
+    #[serde(flatten)]
+    offer: PairingOffer,
+    /// JSON payload to render as a QR code for the device to scan.
+    qr_payload: String,
+}
+
+async fn create_pairing(
+    State(state): State<ServeState>,
+    Json(request): Json<CreatePairingRequest>,
+) -> ApiResult<CreatePairingResponse> {
+    let offer = state.devices.create_pairing(
+        request.scope,
+        request.ttl_seconds.unwrap_or(DEFAULT_PAIRING_TTL_SECS),
+    );
+    let qr_payload = serde_json::json!({
+        "version": 1,
+        "url": format!("http://{}", state.addr),
+        "code": offer.code,
+        "scope": offer.scope,
+        "expiresAt": offer.expires_at,
+    })
+    .to_string();
+    Ok(Json(CreatePairingResponse { offer, qr_payload }))
+}
+
+async fn complete_pairing(
+    State(state): State<ServeState>,
+    Json(request): Json<PairingRequest>,
+) -> ApiResult<PairingGrant> {
+    state
+        .devices
+        .complete_pairing(request)
+        .map(Json)
+        .map_err(|error| ApiError::new(StatusCode::FORBIDDEN, error.to_string()))
+}
+
+async fn list_devices(State(state): State<ServeState>) -> ApiResult<Vec<DeviceInfo>> {
+    Ok(Json(state.devices.list_devices()))
+}
+
+async fn device_audit(State(state): State<ServeState>) -> ApiResult<Vec<DeviceAuditEntry>> {
+    Ok(Json(state.devices.audit_log()))
+}
+
+async fn revoke_device(
+    State(state): State<ServeState>,
+    Path(device_id): Path<String>,
+) -> ApiResult<DeviceInfo> {
+    state
+        .devices
+        .revoke(&device_id)
+        .map(Json)
+        .map_err(|error| ApiError::new(StatusCode::NOT_FOUND, error.to_string()))
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceScopeRequest {
+    scope: DeviceScope,
+}
+
+async fn set_device_scope(
+    State(state): State<ServeState>,
+    Path(device_id): Path<String>,
+    Json(request): Json<DeviceScopeRequest>,
+) -> ApiResult<DeviceInfo> {
+    state
+        .devices
+        .set_scope(&device_id, request.scope)
+        .map(Json)
+        .map_err(|error| ApiError::new(StatusCode::NOT_FOUND, error.to_string()))
+}
+
+async fn list_approvals(State(state): State<ServeState>) -> ApiResult<Vec<ApprovalRequest>> {
+    Ok(Json(state.approvals.list_pending().await))
+}
+
+async fn respond_approval(
+    State(state): State<ServeState>,
+    Path(request_id): Path<String>,
+    Json(response): Json<ApprovalResponse>,
+) -> ApiResult<ApprovalResolution> {
+    Ok(Json(
+        state
+            .approvals
+            .respond(&request_id, response, ApprovalResponder::RemoteClient)
+            .await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_map_to_expected_access_levels() {
+        assert_eq!(
+            required_access(&Method::POST, "/api/v1/pairing/complete"),
+            Access::Public
+        );
+        assert_eq!(
+            required_access(&Method::POST, "/api/v1/pairing"),
+            Access::Owner
+        );
+        assert_eq!(
+            required_access(&Method::GET, "/api/v1/devices"),
+            Access::Owner
+        );
+        assert_eq!(required_access(&Method::GET, "/api/v1/tasks"), Access::View);
+        assert_eq!(
+            required_access(&Method::POST, "/api/v1/permissions/abc"),
+            Access::Control
+        );
+        assert_eq!(
+            required_access(&Method::POST, "/api/v1/approvals/abc"),
+            Access::Owner
+        );
+    }
+
+    #[test]
+    fn device_actions_ignore_resource_ids() {
+        assert_eq!(device_action("/api/v1/tasks"), "task:create");
+        assert_eq!(device_action("/api/v1/tasks/t-1/cancel"), "task:cancel");
+        assert_eq!(device_action("/api/v1/permissions/p-9"), "permission:reply");
+    }
+}
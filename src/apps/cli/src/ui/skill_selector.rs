@@ -23,7 +23,7 @@ pub(crate) struct SkillItem {
     pub key: String,
     pub name: String,
     pub description: String,
-    pub level: String, // "project" or "user"
+    pub level: String, // "project", "user" or "builtin"
     pub source_slot: String,
     pub source_label: String,
     pub enabled: bool,
@@ -424,6 +424,7 @@ impl SkillSelectorState {
         let level_marker = match skill.level.as_str() {
             "project" => "P",
             "user" => "U",
+            "builtin" => "B",
             _ => "?",
         };
         let level_style = match skill.level.as_str() {
@@ -488,6 +489,7 @@ impl SkillSelectorState {
             let scope = match skill.level.as_str() {
                 "project" => "project",
                 "user" => "user",
+                "builtin" => "built-in",
                 _ => "other",
             };
             return ListItem::new(vec![
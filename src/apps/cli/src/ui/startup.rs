@@ -1957,7 +1957,7 @@ impl StartupPage {
         let result: Result<(), String> = tokio::task::block_in_place(|| {
             tokio::runtime::Handle::current().block_on(async {
                 match skill.level.as_str() {
-                    "user" => {
+                    "user" | "builtin" => {
                         set_user_mode_skill_state(
                             &mode_id,
                             &skill.key,
@@ -1,3 +1,55 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
 fn main() {
+    embed_build_hash();
+    embed_changelog();
     tauri_build::build();
 }
+
+/// Exposes `BITFUN_BUILD_HASH` to `env!`. Release CI sets it explicitly;
+/// local builds fall back to the checked-out commit, or an empty string
+/// outside a git checkout.
+fn embed_build_hash() {
+    println!("cargo:rerun-if-env-changed=BITFUN_BUILD_HASH");
+    let hash = std::env::var("BITFUN_BUILD_HASH")
+        .ok()
+        .filter(|hash| !hash.trim().is_empty())
+        .or_else(git_head_hash)
+        .unwrap_or_default();
+    println!("cargo:rustc-env=BITFUN_BUILD_HASH={}", hash.trim());
+
+    let git_dir = repo_root().join(".git");
+    if git_dir.is_dir() {
+        println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+        println!("cargo:rerun-if-changed={}", git_dir.join("refs").display());
+    }
+}
+
+fn git_head_hash() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .current_dir(repo_root())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// Copies the release changelog into `OUT_DIR` so `include_str!` works even
+/// when the file has not been generated yet.
+fn embed_changelog() {
+    let source = repo_root().join("CHANGELOG.md");
+    if source.is_file() {
+        println!("cargo:rerun-if-changed={}", source.display());
+    }
+    let content = std::fs::read_to_string(&source).unwrap_or_default();
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    std::fs::write(out_dir.join("CHANGELOG.md"), content).expect("failed to write CHANGELOG.md");
+}
+
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..")
+}
@@ -16,6 +16,11 @@ for src_dir in \
   fi
 done
 
+# Register the bitfun:// scheme handler declared in the .desktop file
+if command -v update-desktop-database &>/dev/null; then
+  update-desktop-database -q /usr/share/applications 2>/dev/null || true
+fi
+
 if [ ! -d "$ICON_SRC" ]; then
   exit 0
 fi
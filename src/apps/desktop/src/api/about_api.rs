@@ -0,0 +1,169 @@
+//! About dialog API: version, build and license details.
+
+use bitfun_core::service::mcp::protocol::default_protocol_version;
+use bitfun_core::service::runtime::provisioning::managed_runtime_versions;
+use serde::Serialize;
+use tauri::AppHandle;
+
+const BUILD_HASH: &str = env!("BITFUN_BUILD_HASH");
+const CHANGELOG: &str = include_str!(concat!(env!("OUT_DIR"), "/CHANGELOG.md"));
+const LICENSE_TEXT: &str = include_str!("../../../../../LICENSE");
+const LICENSE_SPDX_ID: &str = "MIT";
+
+/// Which release stream this build belongs to, derived from its version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    Stable,
+    /// `-alpha`, `-beta` and `-rc` prereleases.
+    Beta,
+    Nightly,
+    /// Debug builds, which the updater never replaces.
+    Dev,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentVersion {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseDetails {
+    pub spdx_id: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppInfo {
+    pub name: String,
+    pub version: String,
+    /// Short commit hash; `None` for builds made outside a git checkout.
+    pub build_hash: Option<String>,
+    pub channel: UpdateChannel,
+    pub components: Vec<ComponentVersion>,
+    pub license: LicenseDetails,
+    /// Release notes of the installed version, as markdown.
+    pub changelog: Option<String>,
+}
+
+#[tauri::command]
+pub async fn get_app_info(app: AppHandle) -> Result<AppInfo, String> {
+    let package = app.package_info();
+    let version = package.version.to_string();
+
+    let mut components = vec![
+        ComponentVersion {
+            id: "tauri".to_string(),
+            name: "Tauri".to_string(),
+            version: tauri::VERSION.to_string(),
+        },
+        ComponentVersion {
+            id: "mcp-protocol".to_string(),
+            name: "MCP protocol".to_string(),
+            version: default_protocol_version(),
+        },
+    ];
+    components.extend(
+        managed_runtime_versions()
+            .into_iter()
+            .map(|runtime| ComponentVersion {
+                id: runtime.component,
+                name: runtime.display_name,
+                version: runtime.version,
+            }),
+    );
+
+    Ok(AppInfo {
+        name: package.name.clone(),
+        channel: update_channel(&version, cfg!(debug_assertions)),
+        changelog: changelog_section(CHANGELOG, &version),
+        version,
+        build_hash: Some(BUILD_HASH.to_string()).filter(|hash| !hash.is_empty()),
+        components,
+        license: LicenseDetails {
+            spdx_id: LICENSE_SPDX_ID.to_string(),
+            text: LICENSE_TEXT.to_string(),
+        },
+    })
+}
+
+fn update_channel(version: &str, debug_build: bool) -> UpdateChannel {
+    if debug_build {
+        return UpdateChannel::Dev;
+    }
+    let Some((_, prerelease)) = version.split_once('-') else {
+        return UpdateChannel::Stable;
+    };
+    let prerelease = prerelease.to_ascii_lowercase();
+    if prerelease.starts_with("nightly") {
+        UpdateChannel::Nightly
+    } else {
+        UpdateChannel::Beta
+    }
+}
+
+/// The section for `version` of a release-please changelog, whose headings
+/// look like `## [0.2.13](https://…) (2026-01-05)` or `## 0.2.13 (…)`.
+fn changelog_section(changelog: &str, version: &str) -> Option<String> {
+    let mut section: Option<Vec<&str>> = None;
+    for line in changelog.lines() {
+        let heading = line
+            .strip_prefix("## ")
+            .or_else(|| line.strip_prefix("# "))
+            .map(str::trim_start);
+        match (heading, &mut section) {
+            (Some(_), Some(_)) => break,
+            (Some(heading), None) if heading_version(heading) == Some(version) => {
+                section = Some(Vec::new())
+            }
+            (None, Some(lines)) => lines.push(line),
+            _ => {}
+        }
+    }
+    let text = section?.join("\n").trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn heading_version(heading: &str) -> Option<&str> {
+    let heading = heading.strip_prefix('[').unwrap_or(heading);
+    let version = heading
+        .split(|c: char| c == ']' || c.is_whitespace())
+        .next()?;
+    Some(version.strip_prefix('v').unwrap_or(version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog\n\n\
+        ## [0.2.13](https://example.com/compare/v0.2.12...v0.2.13) (2026-01-05)\n\n\
+        ### Features\n\n* add about info\n\n\
+        ## [0.2.12](https://example.com/compare/v0.2.11...v0.2.12) (2025-12-20)\n\n\
+        ### Bug Fixes\n\n* older fix\n";
+
+    #[test]
+    fn extracts_only_the_installed_version() {
+        let section = changelog_section(CHANGELOG, "0.2.13").unwrap();
+        assert!(section.starts_with("### Features"));
+        assert!(!section.contains("older fix"));
+        assert!(changelog_section(CHANGELOG, "0.2.14").is_none());
+        assert!(changelog_section("", "0.2.13").is_none());
+    }
+
+    #[test]
+    fn channel_follows_prerelease_tag() {
+        assert_eq!(update_channel("0.2.13", false), UpdateChannel::Stable);
+        assert_eq!(update_channel("0.3.0-beta.1", false), UpdateChannel::Beta);
+        assert_eq!(
+            update_channel("0.3.0-nightly.20260105", false),
+            UpdateChannel::Nightly
+        );
+        assert_eq!(update_channel("0.2.13", true), UpdateChannel::Dev);
+    }
+}
@@ -0,0 +1,129 @@
+//! Agent task queue API.
+
+use bitfun_core::agentic::task_queue::{
+    get_global_agent_task_service, AgentTask, AgentTaskService, EnqueueAgentTaskRequest,
+};
+use bitfun_transport::emit_journaled;
+use log::{debug, error};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Emitted with an `AgentTaskEvent` payload whenever a task changes state.
+pub const AGENT_TASK_UPDATED_EVENT: &str = "agent_task_updated";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAgentTasksRequest {
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentTaskIdRequest {
+    pub task_id: String,
+}
+
+fn agent_task_service() -> Result<Arc<AgentTaskService>, String> {
+    get_global_agent_task_service()
+        .ok_or_else(|| "Agent task service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_agent_tasks(request: ListAgentTasksRequest) -> Result<Vec<AgentTask>, String> {
+    debug!("Listing agent tasks: session_id={:?}", request.session_id);
+
+    let service = agent_task_service()?;
+    Ok(service.list_tasks(request.session_id.as_deref()).await)
+}
+
+#[tauri::command]
+pub async fn enqueue_agent_task(request: EnqueueAgentTaskRequest) -> Result<AgentTask, String> {
+    debug!(
+        "Enqueuing agent task: session_id={}, priority={:?}",
+        request.session_id, request.priority
+    );
+
+    let service = agent_task_service()?;
+    service.enqueue_task(request).await.map_err(|error| {
+        error!("Failed to enqueue agent task: {}", error);
+        format!("Failed to enqueue agent task: {}", error)
+    })
+}
+
+#[tauri::command]
+pub async fn pause_agent_task(request: AgentTaskIdRequest) -> Result<AgentTask, String> {
+    debug!("Pausing agent task: task_id={}", request.task_id);
+
+    let service = agent_task_service()?;
+    service
+        .pause_task(&request.task_id)
+        .await
+        .map_err(|error| format!("Failed to pause agent task: {}", error))
+}
+
+#[tauri::command]
+pub async fn resume_agent_task(request: AgentTaskIdRequest) -> Result<AgentTask, String> {
+    debug!("Resuming agent task: task_id={}", request.task_id);
+
+    let service = agent_task_service()?;
+    service
+        .resume_task(&request.task_id)
+        .await
+        .map_err(|error| format!("Failed to resume agent task: {}", error))
+}
+
+#[tauri::command]
+pub async fn retry_agent_task(request: AgentTaskIdRequest) -> Result<AgentTask, String> {
+    debug!("Retrying agent task: task_id={}", request.task_id);
+
+    let service = agent_task_service()?;
+    service
+        .retry_task(&request.task_id)
+        .await
+        .map_err(|error| format!("Failed to retry agent task: {}", error))
+}
+
+#[tauri::command]
+pub async fn cancel_agent_task(request: AgentTaskIdRequest) -> Result<AgentTask, String> {
+    debug!("Cancelling agent task: task_id={}", request.task_id);
+
+    let service = agent_task_service()?;
+    service
+        .cancel_task(&request.task_id)
+        .await
+        .map_err(|error| format!("Failed to cancel agent task: {}", error))
+}
+
+/// Forwards task lifecycle events to every window.
+pub fn spawn_agent_task_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_agent_task_service() else {
+        log::warn!("Agent task service unavailable; task events will not be forwarded");
+        return;
+    };
+    let mut receiver = service.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    let payload = serde_json::to_value(&event).unwrap_or_default();
+                    if let Err(e) = emit_journaled(
+                        &app_handle,
+                        AGENT_TASK_UPDATED_EVENT,
+                        Some(&event.task.session_id),
+                        payload,
+                    ) {
+                        log::warn!("Failed to emit agent task event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    log::warn!("Agent task event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    log::warn!("Agent task event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
@@ -217,6 +217,9 @@ pub struct StartDialogTurnRequest {
     pub image_contexts: Option<Vec<ImageContextData>>,
     #[serde(default)]
     pub user_message_metadata: Option<serde_json::Value>,
+    /// Ids of session attachments sent with this message.
+    #[serde(default)]
+    pub attachment_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1456,16 +1459,24 @@ fn desktop_dialog_turn_request(
         turn_id,
         image_contexts,
         user_message_metadata,
+        attachment_ids,
     } = request;
 
     let policy = DialogSubmissionPolicy::for_source(DialogTriggerSource::DesktopUi);
-    let attachments = match image_contexts.filter(|images| !images.is_empty()) {
-        Some(images) => resolve_missing_image_payloads(images)?
+    let mut attachments: Vec<AgentInputAttachment> =
+        match image_contexts.filter(|images| !images.is_empty()) {
+            Some(images) => resolve_missing_image_payloads(images)?
+                .into_iter()
+                .map(desktop_image_attachment)
+                .collect(),
+            None => Vec::new(),
+        };
+    attachments.extend(
+        attachment_ids
+            .unwrap_or_default()
             .into_iter()
-            .map(desktop_image_attachment)
-            .collect(),
-        None => Vec::new(),
-    };
+            .map(AgentInputAttachment::session_attachment),
+    );
     let metadata = desktop_user_message_metadata(user_message_metadata);
 
     Ok(AgentDialogTurnRequest {
@@ -2437,6 +2448,7 @@ pub async fn delete_session(
     runtime: State<'_, DesktopRuntimeContext>,
     request: DeleteSessionRequest,
 ) -> Result<(), String> {
+    let session_id = request.session_id.clone();
     runtime
         .session_application()
         .delete_session(
@@ -2448,7 +2460,10 @@ pub async fn delete_session(
             request.session_id,
         )
         .await
-        .map_err(|error| format!("Failed to delete session: {error}"))
+        .map_err(|error| format!("Failed to delete session: {error}"))?;
+    crate::api::attachment_api::clear_session_attachments(&session_id).await;
+    crate::api::artifact_api::remove_session_artifacts(&session_id).await;
+    Ok(())
 }
 
 #[tauri::command]
@@ -2893,7 +2908,8 @@ mod tests {
             "userMessageMetadata": {
                 "surface": "flow_chat",
                 "requestId": "request-1"
-            }
+            },
+            "attachmentIds": ["attachment-1"]
         }))
         .expect("current Tauri request shape");
 
@@ -2927,7 +2943,11 @@ mod tests {
         );
         assert!(runtime_request.reply_route.is_none());
         assert!(runtime_request.prepended_reminders.is_empty());
-        assert_eq!(runtime_request.attachments.len(), 1);
+        assert_eq!(runtime_request.attachments.len(), 2);
+        assert_eq!(
+            runtime_request.attachments[1],
+            AgentInputAttachment::session_attachment("attachment-1")
+        );
         let attachment = &runtime_request.attachments[0];
         assert_eq!(attachment.kind, "remote_image");
         assert_eq!(attachment.id, "image-1");
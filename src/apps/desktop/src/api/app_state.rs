@@ -10,9 +10,12 @@ use bitfun_core::miniapp::{
 use bitfun_core::service::remote_ssh::{
     init_remote_workspace_manager, RemoteFileService, RemoteTerminalManager, SSHConnectionManager,
 };
+use bitfun_core::service::service_health::{service_health_registry, ServiceKind, ServiceStatus};
 use bitfun_core::service::{announcement, config, filesystem, mcp, search, token_usage, workspace};
 use bitfun_core::util::errors::*;
-use bitfun_services_integrations::speech::{SpeechService, SpeechStoragePaths};
+use bitfun_services_integrations::speech::{
+    SpeechService, SpeechStoragePaths, TextToSpeechService,
+};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -71,6 +74,7 @@ pub struct AppState {
     pub filesystem_service: Arc<filesystem::FileSystemService>,
     pub workspace_search_service: Arc<search::WorkspaceSearchService>,
     pub speech_service: Arc<SpeechService>,
+    pub text_to_speech_service: Arc<TextToSpeechService>,
     pub agent_registry: Arc<agents::AgentRegistry>,
     pub mcp_service: Option<Arc<mcp::MCPService>>,
     pub acp_client_service: Option<Arc<bitfun_acp::AcpClientService>>,
@@ -126,13 +130,13 @@ impl AppState {
 
         let mcp_service = match mcp::MCPService::new(config_service.clone()) {
             Ok(service) => {
-                log::info!("MCP service initialized successfully");
                 let service = Arc::new(service);
                 mcp::set_global_mcp_service(service.clone());
+                service_health_registry().report_initialized(ServiceKind::Mcp);
                 Some(service)
             }
             Err(e) => {
-                log::warn!("Failed to initialize MCP service: {}", e);
+                service_health_registry().report_failed(ServiceKind::Mcp, e.to_string());
                 None
             }
         };
@@ -193,11 +197,17 @@ impl AppState {
             path_manager.speech_model_downloads_dir(),
             path_manager.speech_input_temp_dir(),
         )));
+        let text_to_speech_service = Arc::new(TextToSpeechService::new());
         let js_worker_pool = JsWorkerPool::new(path_manager, worker_host_path)
             .ok()
             .map(Arc::new);
-        if js_worker_pool.is_none() {
-            log::warn!("JsWorkerPool not initialized (missing worker_host.js or no Bun/Node)");
+        if js_worker_pool.is_some() {
+            service_health_registry().report_initialized(ServiceKind::Runtime);
+        } else {
+            service_health_registry().report_degraded(
+                ServiceKind::Runtime,
+                "worker_host.js or a Bun/Node runtime is missing; MiniApp workers are disabled",
+            );
         }
 
         let statistics = Arc::new(RwLock::new(AppStatistics {
@@ -298,6 +308,7 @@ impl AppState {
             filesystem_service,
             workspace_search_service,
             speech_service,
+            text_to_speech_service,
             agent_registry,
             mcp_service,
             acp_client_service,
@@ -335,6 +346,12 @@ impl AppState {
         services.insert("config_service".to_string(), true);
         services.insert("filesystem_service".to_string(), true);
         services.insert("workspace_search_service".to_string(), true);
+        for entry in service_health_registry().snapshot().services {
+            services.insert(
+                format!("{}_service", entry.service.id()),
+                entry.status != ServiceStatus::Failed,
+            );
+        }
 
         let all_healthy = services.values().all(|&status| status);
 
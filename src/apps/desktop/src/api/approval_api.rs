@@ -0,0 +1,155 @@
+//! Approval workflow API.
+
+use bitfun_core::service::approvals::{
+    get_global_approval_service, ApprovalKind, ApprovalPolicy, ApprovalRequest, ApprovalResolution,
+    ApprovalResponder, ApprovalResponse, ApprovalService, RememberedApproval,
+};
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with an `ApprovalEvent` payload when an approval is requested or resolved.
+pub const APPROVAL_EVENT: &str = "approval_event";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondApprovalRequest {
+    pub request_id: String,
+    #[serde(flatten)]
+    pub response: ApprovalResponse,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForgetRememberedApprovalRequest {
+    pub kind: ApprovalKind,
+    pub subject: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetApprovalPolicyRequest {
+    pub kind: ApprovalKind,
+    pub policy: ApprovalPolicy,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalPolicyEntry {
+    pub kind: ApprovalKind,
+    pub policy: ApprovalPolicy,
+}
+
+fn approval_service() -> Result<Arc<ApprovalService>, String> {
+    get_global_approval_service().ok_or_else(|| "Approval service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_pending_approvals() -> Result<Vec<ApprovalRequest>, String> {
+    let service = approval_service()?;
+    Ok(service.list_pending().await)
+}
+
+#[tauri::command]
+pub async fn respond_approval(
+    request: RespondApprovalRequest,
+) -> Result<ApprovalResolution, String> {
+    debug!(
+        "Responding to approval: request_id={}, decision={:?}, remember={}",
+        request.request_id, request.response.decision, request.response.remember
+    );
+
+    let service = approval_service()?;
+    service
+        .respond(
+            &request.request_id,
+            request.response,
+            ApprovalResponder::Desktop,
+        )
+        .await
+        .map_err(|error| {
+            error!("Failed to respond to approval: {}", error);
+            format!("Failed to respond to approval: {}", error)
+        })
+}
+
+#[tauri::command]
+pub async fn list_remembered_approvals() -> Result<Vec<RememberedApproval>, String> {
+    let service = approval_service()?;
+    Ok(service.list_remembered().await)
+}
+
+#[tauri::command]
+pub async fn forget_remembered_approval(
+    request: ForgetRememberedApprovalRequest,
+) -> Result<bool, String> {
+    let service = approval_service()?;
+    service
+        .forget_remembered(request.kind, &request.subject)
+        .await
+        .map_err(|error| format!("Failed to forget remembered approval: {}", error))
+}
+
+#[tauri::command]
+pub async fn clear_remembered_approvals() -> Result<usize, String> {
+    let service = approval_service()?;
+    service
+        .clear_remembered()
+        .await
+        .map_err(|error| format!("Failed to clear remembered approvals: {}", error))
+}
+
+#[tauri::command]
+pub async fn get_approval_policies() -> Result<Vec<ApprovalPolicyEntry>, String> {
+    let service = approval_service()?;
+    let policies = service.policies().await;
+    Ok(ApprovalKind::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            policies.get(&kind).map(|policy| ApprovalPolicyEntry {
+                kind,
+                policy: *policy,
+            })
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn set_approval_policy(request: SetApprovalPolicyRequest) -> Result<(), String> {
+    debug!("Setting approval policy: kind={:?}", request.kind);
+
+    let service = approval_service()?;
+    service
+        .set_policy(request.kind, request.policy)
+        .await
+        .map_err(|error| format!("Failed to set approval policy: {}", error))
+}
+
+/// Forwards approval requests and resolutions to every window.
+pub fn spawn_approval_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_approval_service() else {
+        log::warn!("Approval service unavailable; approval events will not be forwarded");
+        return;
+    };
+    let mut receiver = service.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if let Err(e) = app_handle.emit(APPROVAL_EVENT, &event) {
+                        log::warn!("Failed to emit approval event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    log::warn!("Approval event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    log::warn!("Approval event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
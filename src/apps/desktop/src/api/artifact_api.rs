@@ -0,0 +1,108 @@
+//! Agent artifact API
+//!
+//! Lists, previews, opens, exports and deletes files registered by agent
+//! tasks in the managed artifact store.
+
+use bitfun_core::service::artifacts::{
+    get_global_artifact_service, ArtifactPreview, ArtifactRecord, ArtifactService,
+    RegisterArtifactRequest,
+};
+use log::warn;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_opener::OpenerExt;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListArtifactsRequest {
+    /// All sessions when omitted.
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactIdRequest {
+    pub artifact_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportArtifactRequest {
+    pub artifact_id: String,
+    /// Destination file, or a directory to keep the artifact's name.
+    pub target_path: String,
+}
+
+fn artifact_service() -> Result<Arc<ArtifactService>, String> {
+    get_global_artifact_service().ok_or_else(|| "Artifact service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_artifacts(request: ListArtifactsRequest) -> Result<Vec<ArtifactRecord>, String> {
+    artifact_service()?
+        .list(request.session_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to list artifacts: {}", e))
+}
+
+#[tauri::command]
+pub async fn register_artifact(request: RegisterArtifactRequest) -> Result<ArtifactRecord, String> {
+    artifact_service()?
+        .register(request)
+        .await
+        .map_err(|e| format!("Failed to register artifact: {}", e))
+}
+
+#[tauri::command]
+pub async fn preview_artifact(request: ArtifactIdRequest) -> Result<ArtifactPreview, String> {
+    artifact_service()?
+        .preview(&request.artifact_id)
+        .await
+        .map_err(|e| format!("Failed to preview artifact: {}", e))
+}
+
+/// Opens the managed copy with the system's default application.
+#[tauri::command]
+pub async fn open_artifact(app: AppHandle, request: ArtifactIdRequest) -> Result<(), String> {
+    let record = artifact_service()?
+        .get(&request.artifact_id)
+        .await
+        .map_err(|e| format!("Failed to open artifact: {}", e))?;
+    app.opener()
+        .open_path(&record.path, None::<&str>)
+        .map_err(|e| format!("Failed to open artifact: {}", e))
+}
+
+/// Returns the path the artifact was written to.
+#[tauri::command]
+pub async fn export_artifact(request: ExportArtifactRequest) -> Result<String, String> {
+    artifact_service()?
+        .export(&request.artifact_id, Path::new(&request.target_path))
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|e| format!("Failed to export artifact: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_artifact(request: ArtifactIdRequest) -> Result<bool, String> {
+    artifact_service()?
+        .delete(&request.artifact_id)
+        .await
+        .map_err(|e| format!("Failed to delete artifact: {}", e))
+}
+
+/// Removes a deleted session's artifacts; failures are only logged.
+pub(crate) async fn remove_session_artifacts(session_id: &str) {
+    let Some(service) = get_global_artifact_service() else {
+        return;
+    };
+    if let Err(e) = service.remove_session(session_id).await {
+        warn!(
+            "Failed to remove session artifacts: session_id={}, error={}",
+            session_id, e
+        );
+    }
+}
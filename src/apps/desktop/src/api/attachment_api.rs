@@ -0,0 +1,213 @@
+//! Session attachment ingestion API
+//!
+//! Drops and pastes are stored on the backend and referenced by handle, so
+//! large files never travel through invoke payloads as base64.
+
+use crate::api::clipboard_file_api::get_clipboard_files_internal;
+use bitfun_core::service::attachments::{
+    get_global_attachment_service, AttachmentHandle, AttachmentOrigin, AttachmentService,
+};
+use image::codecs::png::PngEncoder;
+use image::{ColorType, ImageEncoder};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestDroppedFilesRequest {
+    pub session_id: String,
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestClipboardRequest {
+    pub session_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionAttachmentsRequest {
+    pub session_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveSessionAttachmentRequest {
+    pub session_id: String,
+    pub attachment_id: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestAttachmentsResponse {
+    pub attachments: Vec<AttachmentHandle>,
+    /// Inputs that failed validation; the rest are still attached.
+    pub rejected: Vec<RejectedAttachment>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectedAttachment {
+    pub source: String,
+    pub error: String,
+}
+
+enum ClipboardContent {
+    Files(Vec<String>),
+    Image(Vec<u8>),
+    Text(String),
+    Empty,
+}
+
+fn attachment_service() -> Result<Arc<AttachmentService>, String> {
+    get_global_attachment_service()
+        .ok_or_else(|| "Attachment service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn ingest_dropped_files(
+    request: IngestDroppedFilesRequest,
+) -> Result<IngestAttachmentsResponse, String> {
+    let service = attachment_service()?;
+    Ok(ingest_paths(
+        &service,
+        &request.session_id,
+        &request.paths,
+        AttachmentOrigin::Drop,
+    )
+    .await)
+}
+
+/// Attaches whatever the clipboard holds, preferring copied files over an
+/// image and an image over text.
+#[tauri::command]
+pub async fn ingest_clipboard(
+    request: IngestClipboardRequest,
+) -> Result<IngestAttachmentsResponse, String> {
+    let service = attachment_service()?;
+    let content = tokio::task::spawn_blocking(read_clipboard_content)
+        .await
+        .map_err(|e| format!("Failed to read clipboard: {}", e))??;
+
+    let session_id = request.session_id.as_str();
+    let single = match content {
+        ClipboardContent::Files(paths) => {
+            return Ok(
+                ingest_paths(&service, session_id, &paths, AttachmentOrigin::Clipboard).await,
+            );
+        }
+        ClipboardContent::Image(png) => {
+            let name = format!(
+                "pasted-image-{}.png",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            );
+            service
+                .ingest_bytes(session_id, &name, &png, AttachmentOrigin::Clipboard)
+                .await
+        }
+        ClipboardContent::Text(text) => {
+            service
+                .ingest_text(session_id, &text, AttachmentOrigin::Clipboard)
+                .await
+        }
+        ClipboardContent::Empty => return Ok(IngestAttachmentsResponse::default()),
+    };
+
+    let mut response = IngestAttachmentsResponse::default();
+    match single {
+        Ok(handle) => response.attachments.push(handle),
+        Err(e) => response.rejected.push(RejectedAttachment {
+            source: "clipboard".to_string(),
+            error: e.to_string(),
+        }),
+    }
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn list_session_attachments(
+    request: SessionAttachmentsRequest,
+) -> Result<Vec<AttachmentHandle>, String> {
+    attachment_service()?
+        .list(&request.session_id)
+        .await
+        .map_err(|e| format!("Failed to list attachments: {}", e))
+}
+
+#[tauri::command]
+pub async fn remove_session_attachment(
+    request: RemoveSessionAttachmentRequest,
+) -> Result<bool, String> {
+    attachment_service()?
+        .remove(&request.session_id, &request.attachment_id)
+        .await
+        .map_err(|e| format!("Failed to remove attachment: {}", e))
+}
+
+/// Removes a deleted session's attachments; failures are only logged.
+pub(crate) async fn clear_session_attachments(session_id: &str) {
+    let Some(service) = get_global_attachment_service() else {
+        return;
+    };
+    if let Err(e) = service.clear_session(session_id).await {
+        warn!(
+            "Failed to clear session attachments: session_id={}, error={}",
+            session_id, e
+        );
+    }
+}
+
+async fn ingest_paths(
+    service: &AttachmentService,
+    session_id: &str,
+    paths: &[String],
+    origin: AttachmentOrigin,
+) -> IngestAttachmentsResponse {
+    let mut response = IngestAttachmentsResponse::default();
+    for path in paths {
+        match service
+            .ingest_file(session_id, Path::new(path), origin)
+            .await
+        {
+            Ok(handle) => response.attachments.push(handle),
+            Err(e) => {
+                debug!("Attachment rejected: path={}, error={}", path, e);
+                response.rejected.push(RejectedAttachment {
+                    source: path.clone(),
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+    response
+}
+
+fn read_clipboard_content() -> Result<ClipboardContent, String> {
+    match get_clipboard_files_internal() {
+        Ok(files) if !files.is_empty() => return Ok(ClipboardContent::Files(files)),
+        Ok(_) => {}
+        Err(e) => debug!("No file list on clipboard: {}", e),
+    }
+
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("Failed to open clipboard: {}", e))?;
+    if let Ok(image) = clipboard.get_image() {
+        return encode_png(image.width as u32, image.height as u32, &image.bytes)
+            .map(ClipboardContent::Image);
+    }
+    match clipboard.get_text() {
+        Ok(text) if !text.trim().is_empty() => Ok(ClipboardContent::Text(text)),
+        _ => Ok(ClipboardContent::Empty),
+    }
+}
+
+fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>, String> {
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(rgba, width, height, ColorType::Rgba8)
+        .map_err(|e| format!("Failed to encode clipboard image: {}", e))?;
+    Ok(png)
+}
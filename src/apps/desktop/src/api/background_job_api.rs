@@ -0,0 +1,156 @@
+//! Background job scheduler API.
+
+use bitfun_core::service::background_jobs::{
+    get_global_background_job_service, BackgroundJob, BackgroundJobAction, BackgroundJobHandler,
+    BackgroundJobKind, BackgroundJobRun, BackgroundJobService, CreateBackgroundJobRequest,
+    UpdateBackgroundJobRequest,
+};
+use bitfun_core::service::connectivity::get_global_connectivity_service;
+use bitfun_core::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_updater::UpdaterExt;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundJobIdRequest {
+    pub job_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBackgroundJobCommandRequest {
+    pub job_id: String,
+    #[serde(flatten)]
+    pub update: UpdateBackgroundJobRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListBackgroundJobRunsRequest {
+    /// All jobs when omitted.
+    #[serde(default)]
+    pub job_id: Option<String>,
+    #[serde(default = "default_run_history_limit")]
+    pub limit: usize,
+}
+
+fn default_run_history_limit() -> usize {
+    50
+}
+
+fn background_job_service() -> Result<Arc<BackgroundJobService>, String> {
+    get_global_background_job_service()
+        .ok_or_else(|| "Background job service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_background_jobs() -> Result<Vec<BackgroundJob>, String> {
+    Ok(background_job_service()?.list_jobs().await)
+}
+
+#[tauri::command]
+pub async fn create_background_job(
+    request: CreateBackgroundJobRequest,
+) -> Result<BackgroundJob, String> {
+    background_job_service()?
+        .create_job(request)
+        .await
+        .map_err(|e| format!("Failed to create background job: {}", e))
+}
+
+#[tauri::command]
+pub async fn update_background_job(
+    request: UpdateBackgroundJobCommandRequest,
+) -> Result<BackgroundJob, String> {
+    background_job_service()?
+        .update_job(&request.job_id, request.update)
+        .await
+        .map_err(|e| format!("Failed to update background job: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_background_job(request: BackgroundJobIdRequest) -> Result<bool, String> {
+    background_job_service()?
+        .delete_job(&request.job_id)
+        .await
+        .map_err(|e| format!("Failed to delete background job: {}", e))
+}
+
+#[tauri::command]
+pub async fn run_background_job_now(
+    request: BackgroundJobIdRequest,
+) -> Result<BackgroundJobRun, String> {
+    background_job_service()?
+        .run_job_now(&request.job_id)
+        .await
+        .map_err(|e| format!("Failed to run background job: {}", e))
+}
+
+#[tauri::command]
+pub async fn list_background_job_runs(
+    request: ListBackgroundJobRunsRequest,
+) -> Result<Vec<BackgroundJobRun>, String> {
+    background_job_service()?
+        .list_runs(request.job_id.as_deref(), request.limit)
+        .await
+        .map_err(|e| format!("Failed to list background job runs: {}", e))
+}
+
+/// Registers handlers for job kinds that depend on desktop-only services.
+pub fn register_background_job_handlers(app_handle: AppHandle) {
+    let Some(service) = get_global_background_job_service() else {
+        warn!("Background job service unavailable; desktop job handlers not registered");
+        return;
+    };
+    service.register_handler(
+        BackgroundJobKind::RefreshSkillMarketCache,
+        Arc::new(SkillMarketRefreshHandler),
+    );
+    service.register_handler(
+        BackgroundJobKind::CheckUpdates,
+        Arc::new(UpdateCheckHandler { app_handle }),
+    );
+}
+
+struct SkillMarketRefreshHandler;
+
+#[async_trait::async_trait]
+impl BackgroundJobHandler for SkillMarketRefreshHandler {
+    async fn run(&self, _action: &BackgroundJobAction) -> BitFunResult<Option<String>> {
+        let count = crate::api::skill_api::refresh_skill_market_cache()
+            .await
+            .map_err(BitFunError::service)?;
+        Ok(Some(format!("Refreshed {} market skills", count)))
+    }
+}
+
+struct UpdateCheckHandler {
+    app_handle: AppHandle,
+}
+
+#[async_trait::async_trait]
+impl BackgroundJobHandler for UpdateCheckHandler {
+    async fn run(&self, _action: &BackgroundJobAction) -> BitFunResult<Option<String>> {
+        if let Some(connectivity) = get_global_connectivity_service() {
+            connectivity.ensure_online("Update check").await?;
+        }
+        let updater = self
+            .app_handle
+            .updater()
+            .map_err(|e| BitFunError::service(e.to_string()))?;
+        let update = updater
+            .check()
+            .await
+            .map_err(|e| BitFunError::service(e.to_string()))?;
+        Ok(Some(match update {
+            Some(update) => {
+                info!("Scheduled update check found version {}", update.version);
+                format!("Update available: {}", update.version)
+            }
+            None => "Up to date".to_string(),
+        }))
+    }
+}
@@ -0,0 +1,80 @@
+//! User data backup API
+//!
+//! Creates timestamped backup archives and restores them, fully or by
+//! section. Restored data is picked up after the app restarts.
+
+use bitfun_core::infrastructure::try_get_path_manager_arc;
+use bitfun_core::service::backup::{
+    BackupRoots, BackupSection, BackupService, BackupSummary, RestoreBackupResult,
+};
+use log::{info, warn};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBackupRequest {
+    /// The managed backups directory when omitted.
+    #[serde(default)]
+    pub destination_dir: Option<String>,
+    /// Every section when omitted or empty.
+    #[serde(default)]
+    pub sections: Vec<BackupSection>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupRequest {
+    pub archive_path: String,
+    /// Every archived section when omitted.
+    #[serde(default)]
+    pub sections: Option<Vec<BackupSection>>,
+}
+
+#[tauri::command]
+pub async fn create_backup(request: CreateBackupRequest) -> Result<BackupSummary, String> {
+    let path_manager = try_get_path_manager_arc().map_err(|e| e.to_string())?;
+    let destination = request
+        .destination_dir
+        .map(PathBuf::from)
+        .unwrap_or_else(|| path_manager.user_backups_dir());
+    let service = BackupService::new(BackupRoots::from_path_manager(&path_manager));
+    tokio::task::spawn_blocking(move || service.create_backup(&destination, &request.sections))
+        .await
+        .map_err(|e| format!("Backup task failed: {}", e))?
+        .map_err(|e| format!("Failed to create backup: {}", e))
+}
+
+#[tauri::command]
+pub async fn restore_backup(request: RestoreBackupRequest) -> Result<RestoreBackupResult, String> {
+    let path_manager = try_get_path_manager_arc().map_err(|e| e.to_string())?;
+    let service = BackupService::new(BackupRoots::from_path_manager(&path_manager));
+    let archive = PathBuf::from(request.archive_path);
+    tokio::task::spawn_blocking(move || {
+        service.restore_backup(&archive, request.sections.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))?
+    .map_err(|e| format!("Failed to restore backup: {}", e))
+}
+
+/// Backs up config, skills and credential references before an update is
+/// installed. Session history is left out to keep updates fast; failures only
+/// log, since a missing safety copy should not block the update.
+pub(crate) async fn create_pre_update_backup() {
+    let request = CreateBackupRequest {
+        destination_dir: None,
+        sections: vec![
+            BackupSection::Config,
+            BackupSection::Skills,
+            BackupSection::Credentials,
+        ],
+    };
+    match create_backup(request).await {
+        Ok(summary) => info!(
+            "Pre-update backup created: path={}",
+            summary.archive_path.display()
+        ),
+        Err(error) => warn!("Pre-update backup failed: {}", error),
+    }
+}
@@ -263,7 +263,7 @@ mod linux_clipboard {
     }
 }
 
-fn get_clipboard_files_internal() -> Result<Vec<String>, String> {
+pub(crate) fn get_clipboard_files_internal() -> Result<Vec<String>, String> {
     #[cfg(target_os = "windows")]
     {
         windows_clipboard::get_clipboard_files()
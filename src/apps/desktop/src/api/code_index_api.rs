@@ -0,0 +1,51 @@
+//! Code index API - symbol lookup and file outlines.
+
+use bitfun_core::service::code_index::{
+    get_code_index_service, FileOutline, SymbolQuery, SymbolSearchResult,
+};
+use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindSymbolRequest {
+    pub workspace_path: String,
+    #[serde(flatten)]
+    pub query: SymbolQuery,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFileOutlineRequest {
+    pub workspace_path: String,
+    pub file_path: String,
+}
+
+#[tauri::command]
+pub async fn find_symbol(request: FindSymbolRequest) -> Result<SymbolSearchResult, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_code_index_service()
+        .find_symbol(Path::new(&request.workspace_path), request.query)
+        .await
+        .map_err(|e| format!("Failed to find symbol: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_file_outline(request: GetFileOutlineRequest) -> Result<FileOutline, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_code_index_service()
+        .get_file_outline(
+            Path::new(&request.workspace_path),
+            Path::new(&request.file_path),
+        )
+        .await
+        .map_err(|e| format!("Failed to outline file: {}", e))
+}
+
+async fn ensure_local_workspace(workspace_path: &str) -> Result<(), String> {
+    if is_remote_path(workspace_path).await {
+        return Err("Code index is only available for local workspaces".to_string());
+    }
+    Ok(())
+}
@@ -10,7 +10,7 @@ use crate::api::path_target::{
 };
 use crate::api::search_api::{
     build_content_search_request, group_search_results, prepare_content_search_runner,
-    search_file_contents_via_workspace_search, search_metadata_from_content_result,
+    search_file_contents_via_workspace_search, search_globs, search_metadata_from_content_result,
     should_use_workspace_search, SearchMetadataResponse,
 };
 use crate::api::workspace_activation::spawn_workspace_background_warmup;
@@ -25,9 +25,11 @@ use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
 use bitfun_core::service::remote_ssh::{
     shell_quote_posix, RemoteDirEntry, RemoteFileService, RemoteWorkspaceEntry,
 };
+use bitfun_core::service::service_health::{service_health_registry, ServiceKind};
 use bitfun_core::service::workspace::{
     ScanOptions, WorkspaceInfo, WorkspaceKind, WorkspaceOpenOptions,
 };
+use bitfun_core::util::errors::ApiError;
 use log::{debug, error, info, warn};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -599,6 +601,12 @@ pub struct SearchFileContentsRequest {
     pub whole_word: bool,
     #[serde(default)]
     pub max_results: Option<usize>,
+    /// Only search files matching these globs (relative to `root_path`).
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    /// Skip files matching these globs, on top of `.gitignore` rules.
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -844,6 +852,8 @@ pub struct DeleteFileRequest {
     pub path: String,
     #[serde(default, rename = "remoteConnectionId")]
     pub remote_connection_id: Option<String>,
+    /// Delete for good when the system trash is unavailable.
+    pub permanent: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -852,6 +862,8 @@ pub struct DeleteDirectoryRequest {
     pub recursive: Option<bool>,
     #[serde(default, rename = "remoteConnectionId")]
     pub remote_connection_id: Option<String>,
+    /// Delete for good when the system trash is unavailable.
+    pub permanent: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1364,6 +1376,7 @@ pub async fn open_workspace(
     {
         Ok(workspace_info) => {
             apply_active_workspace_context(&state, &app, &workspace_info, None).await;
+            super::workspace_trust_api::request_trust_if_undecided(&app, &workspace_info.root_path);
 
             if let Err(e) = state
                 .workspace_identity_watch_service
@@ -3016,10 +3029,11 @@ pub async fn export_local_file_to_path(request: ExportLocalFileRequest) -> Resul
 pub async fn delete_file(
     state: State<'_, AppState>,
     request: DeleteFileRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     delete_desktop_file(
         &state,
         &request.path,
+        request.permanent.unwrap_or(false),
         request.remote_connection_id.as_deref(),
     )
     .await
@@ -3029,12 +3043,13 @@ pub async fn delete_file(
 pub async fn delete_directory(
     state: State<'_, AppState>,
     request: DeleteDirectoryRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let recursive = request.recursive.unwrap_or(false);
     delete_desktop_directory(
         &state,
         &request.path,
         recursive,
+        request.permanent.unwrap_or(false),
         request.remote_connection_id.as_deref(),
     )
     .await
@@ -3295,7 +3310,7 @@ fn create_local_zip_archive(
 }
 
 /// Recursively add a directory's children to a zip archive.
-fn add_dir_contents_to_zip(
+pub(crate) fn add_dir_contents_to_zip(
     zip: &mut zip::ZipWriter<std::fs::File>,
     dir: &Path,
     archive_prefix: &str,
@@ -3325,7 +3340,7 @@ fn add_dir_contents_to_zip(
 }
 
 /// Add a single file to a zip archive.
-fn add_file_to_zip(
+pub(crate) fn add_file_to_zip(
     zip: &mut zip::ZipWriter<std::fs::File>,
     source_path: &Path,
     archive_path: &str,
@@ -4418,6 +4433,7 @@ pub async fn search_files(
         max_results: Some(max_results),
         file_extensions: None,
         include_directories: request.include_directories,
+        globs: Vec::new(),
     };
 
     let use_workspace_search =
@@ -4435,6 +4451,7 @@ pub async fn search_files(
                     request.use_regex,
                     request.whole_word,
                     max_results,
+                    Vec::new(),
                 )
                 .await
                 .map(|result| result.outcome.results)
@@ -4468,6 +4485,7 @@ pub async fn search_files(
                         request.use_regex,
                         request.whole_word,
                         remaining,
+                        Vec::new(),
                     )
                     .await
                     .map(|result| result.outcome)?
@@ -4549,6 +4567,7 @@ pub async fn search_filenames(
         max_results: Some(limit),
         file_extensions: None,
         include_directories: request.include_directories,
+        globs: Vec::new(),
     };
 
     let result = match resolve_desktop_path_target(&state, &request.root_path, None).await {
@@ -4618,6 +4637,7 @@ pub async fn search_file_contents(
     let search_id = request.search_id.clone();
     let cancel_flag = register_search(&state, search_id.as_deref());
     let limit = resolve_search_limit(request.max_results, DEFAULT_CONTENT_SEARCH_RESULTS);
+    let globs = search_globs(&request.include_globs, &request.exclude_globs);
     let options = FileSearchOptions {
         include_content: true,
         case_sensitive: request.case_sensitive,
@@ -4626,6 +4646,7 @@ pub async fn search_file_contents(
         max_results: Some(limit),
         file_extensions: None,
         include_directories: false,
+        globs: globs.clone(),
     };
 
     let result = if should_use_workspace_search(&state, &request.root_path).await {
@@ -4637,6 +4658,7 @@ pub async fn search_file_contents(
             request.use_regex,
             request.whole_word,
             limit,
+            globs,
         )
         .await
         .map(|result| {
@@ -4694,6 +4716,7 @@ pub async fn start_search_filenames_stream(
         max_results: Some(limit),
         file_extensions: None,
         include_directories: request.include_directories,
+        globs: Vec::new(),
     };
 
     let remote_search_target =
@@ -4827,6 +4850,7 @@ pub async fn start_search_file_contents_stream(
     let search_id = ensure_search_id(request.search_id.clone(), "content-stream");
     let cancel_flag = register_search(&state, Some(&search_id));
     let limit = resolve_search_limit(request.max_results, DEFAULT_CONTENT_SEARCH_RESULTS);
+    let globs = search_globs(&request.include_globs, &request.exclude_globs);
     let options = FileSearchOptions {
         include_content: true,
         case_sensitive: request.case_sensitive,
@@ -4835,6 +4859,7 @@ pub async fn start_search_file_contents_stream(
         max_results: Some(limit),
         file_extensions: None,
         include_directories: false,
+        globs: globs.clone(),
     };
 
     let filesystem_service = state.filesystem_service.clone();
@@ -4882,6 +4907,7 @@ pub async fn start_search_file_contents_stream(
                     use_regex,
                     whole_word,
                     limit,
+                    globs,
                 ))
                 .await
                 .map(|result| {
@@ -5064,7 +5090,15 @@ pub async fn report_ide_control_result(request: IdeControlResultRequest) -> Resu
 
 #[tauri::command]
 pub async fn start_file_watch(path: String, recursive: Option<bool>) -> Result<(), String> {
-    file_watch::start_file_watch(path, recursive).await
+    file_watch::start_file_watch(path.clone(), recursive)
+        .await
+        .inspect_err(|e| {
+            // Usually the OS watch limit; later edits on disk go unnoticed.
+            service_health_registry().report_degraded(
+                ServiceKind::FileWatcher,
+                format!("Failed to watch {}: {}", path, e),
+            );
+        })
 }
 
 #[tauri::command]
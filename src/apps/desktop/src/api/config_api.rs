@@ -1,8 +1,12 @@
 //! Configuration API
 
 use crate::api::app_state::AppState;
+use crate::crash_diagnostics::DiagnosticsBundleInfo;
 use crate::startup_trace::DesktopStartupTrace;
+use bitfun_core::service::i18n::{current_locale_formatter, localize, TranslationArgs};
+use bitfun_core::service::runtime::RuntimeManager;
 use bitfun_core::util::errors::BitFunError;
+use bitfun_core::util::secret_redaction::strip_secrets;
 use log::{error, info};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -169,6 +173,37 @@ mod tests {
             Some("ai.review_team_rate_limit_status"),
         ));
     }
+
+    #[test]
+    fn diagnostics_reports_drop_secrets_and_url_queries() {
+        let report = report_or_error(Ok(serde_json::json!({
+            "ai": { "models": [{
+                "api_key": "sk-live",
+                "base_url": "https://api.example.com/v1?key=abc",
+            }] },
+            "mcpServers": { "remote": { "url": "wss://mcp.example.com/ws?token=xyz" } },
+            "note": "what?",
+        })));
+
+        let model = &report["ai"]["models"][0];
+        assert_eq!(model["api_key"], "");
+        assert_eq!(model["base_url"], "https://api.example.com/v1");
+        assert_eq!(
+            report["mcpServers"]["remote"]["url"],
+            "wss://mcp.example.com/ws"
+        );
+        assert_eq!(report["note"], "what?");
+    }
+
+    #[test]
+    fn diagnostics_reports_keep_collection_errors() {
+        let report = report_or_error(Err("system info unavailable".to_string()));
+
+        assert_eq!(
+            report,
+            serde_json::json!({ "error": "system info unavailable" })
+        );
+    }
 }
 
 #[tauri::command]
@@ -370,7 +405,88 @@ pub async fn export_diagnostics_bundle(
     _request: ExportDiagnosticsBundleRequest,
 ) -> Result<Value, String> {
     let bundle_info = crate::crash_diagnostics::export_diagnostics_bundle()?;
-    to_json_value(bundle_info, "diagnostics bundle info")
+    to_json_value(
+        with_bundle_summary(bundle_info).await,
+        "diagnostics bundle info",
+    )
+}
+
+/// Builds one support archive for bug reports: versions, OS, runtime
+/// capabilities, MCP server statuses, redacted logs and the config with
+/// secrets blanked. Parts that cannot be collected record their error.
+#[tauri::command]
+pub async fn generate_diagnostics_bundle(state: State<'_, AppState>) -> Result<Value, String> {
+    let system = crate::api::system_api::get_system_info()
+        .await
+        .and_then(|info| to_json_value(info, "system info"));
+    let runtime_capabilities = RuntimeManager::new()
+        .map_err(|e| e.to_string())
+        .and_then(|manager| to_json_value(manager.get_capabilities(), "runtime capabilities"));
+    let config = state
+        .config_service
+        .get_config::<Value>(None)
+        .await
+        .map_err(|e| e.to_string());
+    let mcp_servers = crate::api::mcp_api::get_mcp_servers(state)
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|servers| to_json_value(servers, "MCP servers"));
+
+    let reports: Vec<(&str, Value)> = vec![
+        ("system.json", report_or_error(system)),
+        (
+            "runtime-capabilities.json",
+            report_or_error(runtime_capabilities),
+        ),
+        ("mcp-servers.json", report_or_error(mcp_servers)),
+        ("config.json", report_or_error(config)),
+    ];
+    let bundle_info = crate::crash_diagnostics::export_support_bundle(&reports)?;
+    info!("Diagnostics bundle generated: {}", bundle_info.bundle_path);
+    to_json_value(
+        with_bundle_summary(bundle_info).await,
+        "diagnostics bundle info",
+    )
+}
+
+async fn with_bundle_summary(mut info: DiagnosticsBundleInfo) -> DiagnosticsBundleInfo {
+    let formatter = current_locale_formatter().await;
+    let args = TranslationArgs::new()
+        .with_string("size", formatter.bytes(info.size_bytes))
+        .with_string("sessions", formatter.number(info.log_session_count as u64))
+        .with_string("exportedAt", formatter.date_time(&chrono::Local::now()));
+    info.summary = Some(
+        localize("diagnostics-bundle-summary", Some(args))
+            .await
+            .text,
+    );
+    info
+}
+
+/// Secrets are blanked and URL query strings, which often carry tokens, are
+/// dropped.
+fn report_or_error(report: Result<Value, String>) -> Value {
+    match report {
+        Ok(mut value) => {
+            strip_secrets(&mut value);
+            strip_url_queries(&mut value);
+            value
+        }
+        Err(error) => serde_json::json!({ "error": error }),
+    }
+}
+
+fn strip_url_queries(value: &mut Value) {
+    match value {
+        Value::String(text) if text.contains("://") => {
+            if let Some(index) = text.find('?') {
+                text.truncate(index);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_url_queries),
+        Value::Object(fields) => fields.values_mut().for_each(strip_url_queries),
+        _ => {}
+    }
 }
 
 #[tauri::command]
@@ -0,0 +1,83 @@
+//! Network connectivity API.
+
+use bitfun_core::service::config::{subscribe_config_updates, ConfigUpdateEvent};
+use bitfun_core::service::connectivity::{
+    get_global_connectivity_service, ConnectivityService, ConnectivitySnapshot, ConnectivityTarget,
+    ConnectivityTargetKind,
+};
+use log::warn;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `ConnectivitySnapshot` payload whenever reachability changes.
+pub const CONNECTIVITY_CHANGED_EVENT: &str = "connectivity_changed";
+
+fn connectivity_service() -> Result<Arc<ConnectivityService>, String> {
+    get_global_connectivity_service()
+        .ok_or_else(|| "Connectivity service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn get_connectivity_status() -> Result<ConnectivitySnapshot, String> {
+    Ok(connectivity_service()?.snapshot().await)
+}
+
+#[tauri::command]
+pub async fn refresh_connectivity() -> Result<ConnectivitySnapshot, String> {
+    Ok(connectivity_service()?.refresh().await)
+}
+
+/// Registers desktop-owned endpoints, starts the probe loop and forwards
+/// reachability changes to the frontend.
+pub fn start_connectivity_monitor(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_connectivity_service() else {
+        warn!("Connectivity service unavailable; network status will not be monitored");
+        return;
+    };
+    service.register_target(ConnectivityTarget {
+        id: "skill-registry".to_string(),
+        label: "Skill registry".to_string(),
+        kind: ConnectivityTargetKind::SkillRegistry,
+        url: crate::api::skill_api::skill_market_api_base(),
+    });
+
+    let mut receiver = service.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(snapshot) => {
+                    if let Err(e) = app_handle.emit(CONNECTIVITY_CHANGED_EVENT, &snapshot) {
+                        warn!("Failed to emit connectivity event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    warn!("Connectivity event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Connectivity event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+
+    // Re-probe right away when model endpoints change instead of waiting for
+    // the next interval.
+    if let Some(mut config_updates) = subscribe_config_updates() {
+        let service = service.clone();
+        tokio::spawn(async move {
+            loop {
+                match config_updates.recv().await {
+                    Ok(
+                        ConfigUpdateEvent::ModelConfigurationUpdated
+                        | ConfigUpdateEvent::AIModelUpdated { .. },
+                    ) => service.request_refresh(),
+                    Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    service.start();
+}
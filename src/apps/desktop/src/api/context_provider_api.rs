@@ -0,0 +1,19 @@
+//! Agent prompt context provider API.
+
+use bitfun_core::agentic::context_providers::{set_open_editor_files, OpenEditorFile};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetContextOpenFilesRequest {
+    pub workspace_path: String,
+    #[serde(default)]
+    pub files: Vec<OpenEditorFile>,
+}
+
+/// Records the editor tabs open in a workspace for the `open_files` provider.
+#[tauri::command]
+pub async fn set_context_open_files(request: SetContextOpenFilesRequest) -> Result<(), String> {
+    set_open_editor_files(&request.workspace_path, request.files);
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! API key health API.
+
+use bitfun_core::service::config::{subscribe_config_updates, ConfigUpdateEvent};
+use bitfun_core::service::credential_health::{
+    get_global_credential_health_service, CredentialHealthService, CredentialHealthSnapshot,
+};
+use log::warn;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `CredentialHealthSnapshot` payload whenever a model's
+/// credential state changes.
+pub const CREDENTIAL_HEALTH_CHANGED_EVENT: &str = "credential_health_changed";
+
+fn credential_health_service() -> Result<Arc<CredentialHealthService>, String> {
+    get_global_credential_health_service()
+        .ok_or_else(|| "Credential health service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn get_credential_health_status() -> Result<CredentialHealthSnapshot, String> {
+    Ok(credential_health_service()?.snapshot().await)
+}
+
+#[tauri::command]
+pub async fn refresh_credential_health() -> Result<CredentialHealthSnapshot, String> {
+    Ok(credential_health_service()?.refresh().await)
+}
+
+/// Starts the check loop and forwards credential state changes to the
+/// frontend.
+pub fn start_credential_health_monitor(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_credential_health_service() else {
+        warn!("Credential health service unavailable; API keys will not be monitored");
+        return;
+    };
+
+    let mut receiver = service.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(snapshot) => {
+                    if let Err(e) = app_handle.emit(CREDENTIAL_HEALTH_CHANGED_EVENT, &snapshot) {
+                        warn!("Failed to emit credential health event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    warn!("Credential health event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Credential health event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+
+    // Check edited keys right away so a fixed key clears its warning.
+    if let Some(mut config_updates) = subscribe_config_updates() {
+        let service = service.clone();
+        tokio::spawn(async move {
+            loop {
+                match config_updates.recv().await {
+                    Ok(
+                        ConfigUpdateEvent::ModelConfigurationUpdated
+                        | ConfigUpdateEvent::AIModelUpdated { .. },
+                    ) => service.request_refresh(),
+                    Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    service.start();
+}
@@ -1,5 +1,6 @@
 //! Scheduled jobs API.
 
+use bitfun_core::agentic::task_queue::get_global_agent_task_service;
 use bitfun_core::service::cron::{
     get_global_cron_service, CreateCronJobRequest, CronJob, CronJobTargetKind, UpdateCronJobRequest,
 };
@@ -110,5 +111,9 @@ pub async fn notify_cron_host_ready() -> Result<(), String> {
     // `start` is idempotent, so the frontend readiness signal can safely race
     // with the desktop fallback timer.
     service.start();
+    // Queued agent tasks wait for the same host readiness before dispatching.
+    if let Some(task_service) = get_global_agent_task_service() {
+        task_service.start();
+    }
     Ok(())
 }
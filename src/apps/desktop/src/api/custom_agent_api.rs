@@ -2,8 +2,9 @@ use crate::api::app_state::AppState;
 use bitfun_core::agentic::agents::{
     custom_agent_model_or_default, custom_agent_review_writable_tools, default_custom_agent_tools,
     default_custom_agent_user_context_policy, CustomAgentDetail, CustomAgentKind, CustomAgentLevel,
-    CustomMode, CustomSubagent, UserContextPolicy, UserContextSection,
+    CustomAgentLoadIssue, CustomMode, CustomSubagent, UserContextPolicy, UserContextSection,
 };
+use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
 use log::{debug, warn};
 use serde::Deserialize;
 use std::collections::HashSet;
@@ -454,3 +455,29 @@ pub async fn reload_custom_agents(
         .await;
     Ok(())
 }
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCustomAgentLoadIssuesRequest {
+    pub workspace_path: Option<String>,
+}
+
+/// Files under `.bitfun/agents` that were skipped or partially ignored on the
+/// last load, so the agents page can show why a definition is missing.
+#[tauri::command]
+pub async fn get_custom_agent_load_issues(
+    state: State<'_, AppState>,
+    request: GetCustomAgentLoadIssuesRequest,
+) -> Result<Vec<CustomAgentLoadIssue>, String> {
+    if let Some(workspace_path) = request.workspace_path.as_deref() {
+        if is_remote_path(workspace_path).await {
+            return Err(
+                "Custom agent load issues are only available for local workspaces".to_string(),
+            );
+        }
+    }
+    let workspace = workspace_root_from_request(request.workspace_path.as_deref());
+    Ok(state
+        .agent_registry
+        .custom_agent_load_issues(workspace.as_deref()))
+}
@@ -1,7 +1,10 @@
 //! Diff API - Tauri commands for diff comparison
 
+use bitfun_core::service::patch::{PatchReport, PatchService};
+use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComputeDiffRequest {
@@ -49,6 +52,13 @@ pub struct ApplyPatchRequest {
     pub patch: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspacePatchRequest {
+    #[serde(rename = "workspacePath")]
+    pub workspace_path: String,
+    pub patch: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaveMergedContentRequest {
     #[serde(rename = "filePath")]
@@ -194,7 +204,43 @@ pub async fn compute_diff(request: ComputeDiffRequest) -> Result<DiffResult, Str
 
 #[tauri::command]
 pub async fn apply_patch(request: ApplyPatchRequest) -> Result<String, String> {
-    Ok(request.content)
+    PatchService::apply_to_text(&request.content, &request.patch).map_err(|e| e.to_string())
+}
+
+/// Dry run: reports where each hunk lands and any conflicts.
+#[tauri::command]
+pub async fn preview_workspace_patch(
+    request: WorkspacePatchRequest,
+) -> Result<PatchReport, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    PatchService::preview(Path::new(&request.workspace_path), &request.patch)
+        .await
+        .map_err(|e| format!("Failed to preview patch: {}", e))
+}
+
+/// Applies a multi-file patch atomically; with conflicts nothing is written.
+#[tauri::command]
+pub async fn apply_workspace_patch(request: WorkspacePatchRequest) -> Result<PatchReport, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    let report = PatchService::apply(Path::new(&request.workspace_path), &request.patch)
+        .await
+        .map_err(|e| format!("Failed to apply patch: {}", e))?;
+    if report.applied {
+        info!("Workspace patch applied: files={}", report.files.len());
+    } else {
+        warn!(
+            "Workspace patch not applied due to conflicts: files={}",
+            report.files.len()
+        );
+    }
+    Ok(report)
+}
+
+async fn ensure_local_workspace(workspace_path: &str) -> Result<(), String> {
+    if is_remote_path(workspace_path).await {
+        return Err("Patches can only be applied to local workspaces".to_string());
+    }
+    Ok(())
 }
 
 #[tauri::command]
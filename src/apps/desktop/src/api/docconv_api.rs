@@ -0,0 +1,72 @@
+//! Document conversion API.
+
+use bitfun_core::service::docconv::{
+    get_global_document_conversion_service, DocumentConversionCapability,
+    DocumentConversionRequest, DocumentConversionResult, DocumentConversionService,
+};
+use bitfun_transport::emit_journaled;
+use log::{error, warn};
+use std::sync::Arc;
+
+/// Emitted with a `DocumentConversionEvent` payload as conversion jobs progress.
+pub const DOCUMENT_CONVERSION_EVENT: &str = "document_conversion_event";
+
+fn document_conversion_service() -> Result<Arc<DocumentConversionService>, String> {
+    get_global_document_conversion_service()
+        .ok_or_else(|| "Document conversion service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn get_document_conversion_capabilities(
+) -> Result<Vec<DocumentConversionCapability>, String> {
+    let service = document_conversion_service()?;
+    Ok(service.capabilities())
+}
+
+#[tauri::command]
+pub async fn convert_document(
+    request: DocumentConversionRequest,
+) -> Result<DocumentConversionResult, String> {
+    let service = document_conversion_service()?;
+    service.convert(request).await.map_err(|e| {
+        error!("Failed to convert document: {}", e);
+        format!("Failed to convert document: {}", e)
+    })
+}
+
+/// Forwards conversion job progress to every window.
+pub fn spawn_document_conversion_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_document_conversion_service() else {
+        warn!("Document conversion service unavailable; conversion events will not be forwarded");
+        return;
+    };
+    let mut receiver = service.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    let payload = serde_json::to_value(&event).unwrap_or_default();
+                    if let Err(e) = emit_journaled(
+                        &app_handle,
+                        DOCUMENT_CONVERSION_EVENT,
+                        Some(event.job_id()),
+                        payload,
+                    ) {
+                        warn!("Failed to emit document conversion event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    warn!("Document conversion event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(
+                        "Document conversion event forwarder lagged by {} messages",
+                        n
+                    );
+                }
+            }
+        }
+    });
+}
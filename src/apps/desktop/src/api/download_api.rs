@@ -0,0 +1,40 @@
+//! Download speed limit API.
+
+use bitfun_core::service::downloads::{
+    download_speed_limits, set_download_speed_limit as apply_download_speed_limit,
+    DownloadSpeedLimits,
+};
+use bitfun_core::util::errors::ApiError;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDownloadSpeedLimitRequest {
+    /// Changes only this running download; the global cap when omitted.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+    /// `0` removes the cap.
+    pub max_bytes_per_sec: u64,
+}
+
+#[tauri::command]
+pub async fn get_download_speed_limits() -> Result<DownloadSpeedLimits, ApiError> {
+    Ok(download_speed_limits())
+}
+
+/// Applies to downloads already in progress. The global cap is also saved
+/// to `app.downloads`.
+#[tauri::command]
+pub async fn set_download_speed_limit(
+    request: SetDownloadSpeedLimitRequest,
+) -> Result<DownloadSpeedLimits, ApiError> {
+    let operation_id = request
+        .operation_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    apply_download_speed_limit(operation_id, request.max_bytes_per_sec)
+        .await
+        .map_err(|e| ApiError::from(e).with_context("Failed to set download speed limit"))?;
+    Ok(download_speed_limits())
+}
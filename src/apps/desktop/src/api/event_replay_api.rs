@@ -0,0 +1,28 @@
+//! Event replay API for reloaded webviews.
+
+use bitfun_transport::{event_journal, EventReplay, ReplayFilter};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayEventsRequest {
+    /// Last `eventSeq` the caller has seen; 0 for everything retained.
+    #[serde(default)]
+    pub since: u64,
+    /// Session, operation or job ID to limit the replay to.
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub event_names: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn replay_events(request: ReplayEventsRequest) -> Result<EventReplay, String> {
+    Ok(event_journal().replay(
+        request.since,
+        &ReplayFilter {
+            scope: request.scope.as_deref(),
+            event_names: &request.event_names,
+        },
+    ))
+}
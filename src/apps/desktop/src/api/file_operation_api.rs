@@ -0,0 +1,78 @@
+//! File operation journal API.
+
+use bitfun_core::service::file_ops::{
+    get_global_file_operation_service, FileOperationRecord, FileOperationService,
+};
+use log::{debug, error, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `FileOperationEvent` payload when an operation is applied or undone.
+pub const FILE_OPERATION_EVENT: &str = "file_operation_event";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoFileOperationRequest {
+    /// Undo this operation; the most recent one when omitted.
+    pub operation_id: Option<String>,
+}
+
+fn file_operation_service() -> Result<Arc<FileOperationService>, String> {
+    get_global_file_operation_service()
+        .ok_or_else(|| "File operation service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn list_file_operations() -> Result<Vec<FileOperationRecord>, String> {
+    let service = file_operation_service()?;
+    Ok(service.recent_operations().await)
+}
+
+#[tauri::command]
+pub async fn undo_file_operation(
+    request: UndoFileOperationRequest,
+) -> Result<FileOperationRecord, String> {
+    debug!(
+        "Undoing file operation: operation_id={}",
+        request.operation_id.as_deref().unwrap_or("latest")
+    );
+
+    let service = file_operation_service()?;
+    let result = match request.operation_id.as_deref() {
+        Some(operation_id) => service.undo(operation_id).await,
+        None => service.undo_last().await,
+    };
+    result.map_err(|e| {
+        error!("Failed to undo file operation: {}", e);
+        format!("Failed to undo file operation: {}", e)
+    })
+}
+
+/// Forwards applied and undone file operations to every window.
+pub fn spawn_file_operation_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_file_operation_service() else {
+        warn!("File operation service unavailable; file operation events will not be forwarded");
+        return;
+    };
+    let mut receiver = service.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if let Err(e) = app_handle.emit(FILE_OPERATION_EVENT, &event) {
+                        warn!("Failed to emit file operation event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    warn!("File operation event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("File operation event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
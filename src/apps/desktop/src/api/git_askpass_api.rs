@@ -0,0 +1,123 @@
+//! Git credential prompt API.
+
+use bitfun_core::infrastructure::PathManager;
+use bitfun_core::service::git::askpass::{
+    get_global_git_askpass_bridge, set_global_git_askpass_bridge, GitAskpassBridge,
+    GitCredentialPrompt,
+};
+use bitfun_core::service::git::{configure_git_processes, GitProcessConfig};
+use bitfun_core::service::runtime::RuntimeManager;
+use log::{debug, error};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `GitAskpassEvent` payload when Git asks for credentials or
+/// a prompt is resolved.
+pub const GIT_ASKPASS_EVENT: &str = "git_askpass_event";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondGitCredentialPromptRequest {
+    pub prompt_id: String,
+    /// `None` cancels the prompt.
+    #[serde(default)]
+    pub answer: Option<String>,
+}
+
+fn askpass_bridge() -> Result<Arc<GitAskpassBridge>, String> {
+    get_global_git_askpass_bridge()
+        .ok_or_else(|| "Git credential prompts are not available".to_string())
+}
+
+#[tauri::command]
+pub async fn list_pending_git_credential_prompts() -> Result<Vec<GitCredentialPrompt>, String> {
+    let bridge = askpass_bridge()?;
+    Ok(bridge.list_pending().await)
+}
+
+#[tauri::command]
+pub async fn respond_git_credential_prompt(
+    request: RespondGitCredentialPromptRequest,
+) -> Result<(), String> {
+    debug!(
+        "Responding to Git credential prompt: prompt_id={}, answered={}",
+        request.prompt_id,
+        request.answer.is_some()
+    );
+
+    let bridge = askpass_bridge()?;
+    bridge
+        .respond(&request.prompt_id, request.answer)
+        .await
+        .map_err(|error| {
+            error!("Failed to respond to Git credential prompt: {}", error);
+            format!("Failed to respond to Git credential prompt: {}", error)
+        })
+}
+
+/// Starts the askpass bridge and points the app's Git commands at the
+/// resolved Git (system first, then the managed portable Git) with the
+/// managed Git defaults and the bridge environment.
+pub async fn configure_git_credentials(path_manager: &PathManager) {
+    let runtime_manager = RuntimeManager::new().ok();
+    let mut env = runtime_manager
+        .as_ref()
+        .map(RuntimeManager::managed_git_config_env)
+        .unwrap_or_default();
+
+    let bridge = match std::env::current_exe() {
+        Ok(host_program) => {
+            GitAskpassBridge::start(&host_program, &path_manager.temp_dir().join("askpass"))
+                .await
+                .map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    };
+    match bridge {
+        Ok(bridge) => {
+            env.extend(bridge.child_env());
+            set_global_git_askpass_bridge(bridge);
+        }
+        Err(e) => log::warn!(
+            "Git credential prompts unavailable; Git commands needing credentials will fail: {}",
+            e
+        ),
+    }
+
+    configure_git_processes(GitProcessConfig {
+        program: runtime_manager
+            .and_then(|manager| manager.resolve_command("git"))
+            .map(|resolved| PathBuf::from(resolved.command)),
+        env,
+    });
+}
+
+/// Forwards Git credential prompts and their resolutions to every window.
+pub fn spawn_git_askpass_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(bridge) = get_global_git_askpass_bridge() else {
+        log::warn!("Git askpass bridge unavailable; credential prompts will not be forwarded");
+        return;
+    };
+    let mut receiver = bridge.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if let Err(e) = app_handle.emit(GIT_ASKPASS_EVENT, &event) {
+                        log::warn!("Failed to emit Git askpass event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    log::warn!("Git askpass event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    log::warn!("Git askpass event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,32 @@
+//! File checksum API.
+
+use bitfun_core::util::errors::ApiError;
+use bitfun_core::util::hashing::{self, FileVerification};
+use serde::Deserialize;
+use std::io::ErrorKind;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyFileRequest {
+    pub path: String,
+    /// `sha256:<hex>`, `blake3:<hex>`, or bare hex for SHA-256.
+    pub expected: String,
+}
+
+/// Hashes a local file and compares it with `expected`. A mismatch is
+/// reported in the result rather than as an error.
+#[tauri::command]
+pub async fn verify_file(request: VerifyFileRequest) -> Result<FileVerification, ApiError> {
+    let path = request.path.trim();
+    if path.is_empty() {
+        return Err(ApiError::invalid_input("File path is required"));
+    }
+    hashing::verify_file(Path::new(path), &request.expected, |_| {})
+        .await
+        .map_err(|e| match e.kind() {
+            ErrorKind::InvalidInput => ApiError::invalid_input(e.to_string()),
+            ErrorKind::NotFound => ApiError::not_found(format!("File not found: {}", path)),
+            _ => ApiError::internal(format!("Failed to hash {}: {}", path, e)),
+        })
+}
@@ -1,7 +1,10 @@
 //! I18n API
 
 use crate::api::app_state::AppState;
-use bitfun_core::service::i18n::{sync_global_i18n_service_locale, LocaleId, LocaleMetadata};
+use bitfun_core::service::i18n::{
+    get_global_i18n_service, sync_global_i18n_service_locale, I18nService, LocaleId,
+    LocaleMetadata, LocalizedMessage,
+};
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -29,6 +32,20 @@ pub struct TranslateRequest {
     pub args: Option<Value>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MessageRef {
+    pub id: String,
+    #[serde(default)]
+    pub params: std::collections::BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LocalizeMessagesRequest {
+    pub messages: Vec<MessageRef>,
+    /// Render in this language instead of the configured one.
+    pub language: Option<String>,
+}
+
 #[tauri::command]
 pub async fn i18n_get_current_language(state: State<'_, AppState>) -> Result<String, String> {
     let config_service = &state.config_service;
@@ -191,3 +208,39 @@ pub async fn i18n_set_config(state: State<'_, AppState>, config: Value) -> Resul
         Ok("i18n config saved (no language change)".to_string())
     }
 }
+
+/// Re-render backend catalog messages (e.g. a `LocalizedMessage` received
+/// before a language switch) from their ids and params.
+#[tauri::command]
+pub async fn i18n_localize_messages(
+    request: LocalizeMessagesRequest,
+) -> Result<Vec<LocalizedMessage>, String> {
+    let service = match get_global_i18n_service().await {
+        Some(service) => service,
+        None => {
+            let service = I18nService::new();
+            service
+                .initialize()
+                .await
+                .map_err(|e| format!("Failed to initialize i18n service: {}", e))?;
+            std::sync::Arc::new(service)
+        }
+    };
+
+    let locale = match request.language.as_deref() {
+        Some(language) => LocaleId::from_str(language)
+            .ok_or_else(|| format!("Unsupported language: {}", language))?,
+        None => service.get_current_locale().await,
+    };
+
+    let mut localized = Vec::with_capacity(request.messages.len());
+    for message in request.messages {
+        let args = bitfun_core::service::i18n::params_to_translation_args(&message.params);
+        localized.push(
+            service
+                .localize_with_locale(&locale, &message.id, Some(args))
+                .await,
+        );
+    }
+    Ok(localized)
+}
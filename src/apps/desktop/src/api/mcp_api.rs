@@ -2,19 +2,61 @@
 
 use crate::api::app_state::AppState;
 use crate::startup_trace::DesktopStartupTrace;
+use bitfun_core::service::i18n::{localize, TranslationArgs};
+use bitfun_core::service::mcp::adapter::{
+    attach_mcp_resources, MCPResourceAttachment, MCPResourceAttachmentOptions,
+    DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET,
+};
+use bitfun_core::service::mcp::app_files::{
+    check_file_size, McpAppFileEncoding, MAX_MCP_APP_FILE_BYTES, MAX_MCP_APP_SCRATCH_BYTES,
+};
+use bitfun_core::service::mcp::app_instances::{
+    get_global_mcp_app_instances, McpAppInstance, McpAppInstanceRegistry, OpenMcpAppInstance,
+};
 use bitfun_core::service::mcp::auth::{
     has_stored_oauth_credentials, MCPRemoteOAuthSessionSnapshot,
 };
-use bitfun_core::service::mcp::config::MCPConfigService;
+use bitfun_core::service::mcp::config::{
+    render_mcp_server_preset, MCPConfigService, MCPPresetFieldError, MCPServerPresetCatalog,
+};
 use bitfun_core::service::mcp::protocol::{
-    MCPPrompt, MCPResource, PromptsGetResult, ResourcesReadResult,
+    MCPPrompt, MCPResource, MCPTraceSnapshot, PromptsGetResult, ResourcesReadResult,
+};
+use bitfun_core::service::mcp::server::{
+    MCPCapabilityChange, MCPInitializationSummary, MCPServerReadiness,
 };
-use bitfun_core::service::mcp::MCPServerType;
+use bitfun_core::service::mcp::{MCPFailure, MCPServerType};
 use bitfun_core::service::runtime::{RuntimeManager, RuntimeSource};
+use bitfun_core::service::service_health::{service_health_registry, ServiceKind};
+use bitfun_core::util::errors::{ApiError, BitFunError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use std::time::Instant;
-use tauri::State;
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::DialogExt;
+
+fn mcp_service_unavailable() -> ApiError {
+    ApiError::service_unavailable(service_health_registry().unavailable_message(ServiceKind::Mcp))
+}
+
+/// Classifies a failed start and renders it from the message catalog.
+async fn mcp_start_error(server_id: &str, error: BitFunError) -> ApiError {
+    let failure = MCPFailure::from_error(&error);
+    let (id, args) = match &failure {
+        MCPFailure::NotFound { .. } => (
+            "mcp-server-not-found",
+            TranslationArgs::new().with_string("server", server_id),
+        ),
+        _ => (
+            "mcp-server-start-failed",
+            TranslationArgs::new()
+                .with_string("server", server_id)
+                .with_string("reason", failure.message()),
+        ),
+    };
+    ApiError::from(failure).with_localized(localize(id, Some(args)).await)
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -38,6 +80,9 @@ pub struct MCPServerInfo {
     pub oauth_enabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xaa_enabled: Option<bool>,
+    /// `custom`, `pinned` or `insecure` when the server has a `tls` block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_mode: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub command: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,6 +111,18 @@ pub struct ReadMCPResourceRequest {
     pub resource_uri: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachMCPResourcesRequest {
+    pub server_id: String,
+    pub resource_uris: Vec<String>,
+    /// Defaults to `DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET`.
+    #[serde(default)]
+    pub token_budget: Option<usize>,
+    #[serde(default)]
+    pub include_images: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListMCPPromptsRequest {
@@ -87,16 +144,13 @@ async fn load_mcp_resources(
     mcp_service: &bitfun_core::service::mcp::MCPService,
     server_id: &str,
     refresh: bool,
-) -> Result<Vec<MCPResource>, String> {
+) -> Result<Vec<MCPResource>, ApiError> {
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, server_id).await?;
     let mut resources = manager.get_cached_resources(server_id).await;
 
     if refresh || resources.is_empty() {
-        manager
-            .refresh_server_resource_catalog(server_id)
-            .await
-            .map_err(|e| e.to_string())?;
+        manager.refresh_server_resource_catalog(server_id).await?;
         resources = manager.get_cached_resources(server_id).await;
     }
 
@@ -107,16 +161,13 @@ async fn load_mcp_prompts(
     mcp_service: &bitfun_core::service::mcp::MCPService,
     server_id: &str,
     refresh: bool,
-) -> Result<Vec<MCPPrompt>, String> {
+) -> Result<Vec<MCPPrompt>, ApiError> {
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, server_id).await?;
     let mut prompts = manager.get_cached_prompts(server_id).await;
 
     if refresh || prompts.is_empty() {
-        manager
-            .refresh_server_prompt_catalog(server_id)
-            .await
-            .map_err(|e| e.to_string())?;
+        manager.refresh_server_prompt_catalog(server_id).await?;
         prompts = manager.get_cached_prompts(server_id).await;
     }
 
@@ -126,58 +177,63 @@ async fn load_mcp_prompts(
 async fn ensure_unscoped_host_mcp_access(
     manager: &bitfun_core::service::mcp::MCPServerManager,
     server_id: &str,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     manager
         .server_available_for_context(server_id, None, false)
         .await
         .then_some(())
-        .ok_or_else(|| "MCP server is unavailable in this product surface".to_string())
+        .ok_or_else(|| "MCP server is unavailable in this product surface".into())
 }
 
 #[tauri::command]
 pub async fn initialize_mcp_servers(
     state: State<'_, AppState>,
     startup_trace: State<'_, DesktopStartupTrace>,
-) -> Result<(), String> {
+) -> Result<MCPInitializationSummary, ApiError> {
     let trace_started = Instant::now();
     let result = async {
         let mcp_service = state
             .mcp_service
             .as_ref()
-            .ok_or_else(|| "MCP service not initialized".to_string())?;
+            .ok_or_else(mcp_service_unavailable)?;
 
-        mcp_service
-            .server_manager()
-            .initialize_all()
-            .await
-            .map_err(|e| e.to_string())?;
-
-        Ok(())
+        Ok::<_, ApiError>(mcp_service.server_manager().initialize_all().await?)
     }
     .await;
     startup_trace.record_tauri_command_elapsed("initialize_mcp_servers", None, trace_started);
     result
 }
 
+#[tauri::command]
+pub async fn get_mcp_startup_readiness(
+    state: State<'_, AppState>,
+) -> Result<BTreeMap<String, MCPServerReadiness>, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    Ok(mcp_service.server_manager().get_startup_readiness())
+}
+
 #[tauri::command]
 pub async fn initialize_mcp_servers_non_destructive(
     state: State<'_, AppState>,
     startup_trace: State<'_, DesktopStartupTrace>,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let trace_started = Instant::now();
     let result = async {
         let mcp_service = state
             .mcp_service
             .as_ref()
-            .ok_or_else(|| "MCP service not initialized".to_string())?;
+            .ok_or_else(mcp_service_unavailable)?;
 
         mcp_service
             .server_manager()
             .initialize_non_destructive()
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
 
-        Ok(())
+        Ok::<(), ApiError>(())
     }
     .await;
     startup_trace.record_tauri_command_elapsed(
@@ -189,17 +245,13 @@ pub async fn initialize_mcp_servers_non_destructive(
 }
 
 #[tauri::command]
-pub async fn get_mcp_servers(state: State<'_, AppState>) -> Result<Vec<MCPServerInfo>, String> {
+pub async fn get_mcp_servers(state: State<'_, AppState>) -> Result<Vec<MCPServerInfo>, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
-    let configs = mcp_service
-        .config_service()
-        .load_all_configs()
-        .await
-        .map_err(|e| e.to_string())?;
+    let configs = mcp_service.config_service().load_all_configs().await?;
 
     let mut infos = Vec::new();
     let runtime_manager = RuntimeManager::new().ok();
@@ -316,6 +368,16 @@ pub async fn get_mcp_servers(state: State<'_, AppState>) -> Result<Vec<MCPServer
             } else {
                 None
             },
+            tls_mode: config.tls.as_ref().map(|tls| {
+                if !tls.pinned_spki_sha256.is_empty() {
+                    "pinned"
+                } else if tls.insecure_skip_verify {
+                    "insecure"
+                } else {
+                    "custom"
+                }
+                .to_string()
+            }),
             command,
             command_available,
             command_source,
@@ -332,11 +394,11 @@ pub async fn get_mcp_servers(state: State<'_, AppState>) -> Result<Vec<MCPServer
 pub async fn list_mcp_resources(
     state: State<'_, AppState>,
     request: ListMCPResourcesRequest,
-) -> Result<Vec<MCPResource>, String> {
+) -> Result<Vec<MCPResource>, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     load_mcp_resources(mcp_service.as_ref(), &request.server_id, request.refresh).await
 }
@@ -345,34 +407,72 @@ pub async fn list_mcp_resources(
 pub async fn read_mcp_resource(
     state: State<'_, AppState>,
     request: ReadMCPResourceRequest,
-) -> Result<ResourcesReadResult, String> {
+) -> Result<ResourcesReadResult, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
     let connection = manager
         .get_connection(&request.server_id)
         .await
-        .ok_or_else(|| format!("MCP server not connected: {}", request.server_id))?;
+        .ok_or_else(|| MCPFailure::Transport {
+            message: format!("MCP server not connected: {}", request.server_id),
+        })?;
 
     connection
         .read_resource(&request.resource_uri)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
+}
+
+#[tauri::command]
+pub async fn attach_mcp_resources(
+    state: State<'_, AppState>,
+    request: AttachMCPResourcesRequest,
+) -> Result<Vec<MCPResourceAttachment>, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
+    let connection = manager
+        .get_connection(&request.server_id)
+        .await
+        .ok_or_else(|| MCPFailure::Transport {
+            message: format!("MCP server not connected: {}", request.server_id),
+        })?;
+    let options = MCPResourceAttachmentOptions {
+        token_budget: request
+            .token_budget
+            .unwrap_or(DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET),
+        include_images: request.include_images,
+        image_provider: None,
+    };
+
+    attach_mcp_resources(
+        &connection,
+        &request.server_id,
+        &request.resource_uris,
+        &options,
+    )
+    .await
+    .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
 
 #[tauri::command]
 pub async fn list_mcp_prompts(
     state: State<'_, AppState>,
     request: ListMCPPromptsRequest,
-) -> Result<Vec<MCPPrompt>, String> {
+) -> Result<Vec<MCPPrompt>, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     load_mcp_prompts(mcp_service.as_ref(), &request.server_id, request.refresh).await
 }
@@ -381,55 +481,59 @@ pub async fn list_mcp_prompts(
 pub async fn get_mcp_prompt(
     state: State<'_, AppState>,
     request: GetMCPPromptRequest,
-) -> Result<PromptsGetResult, String> {
+) -> Result<PromptsGetResult, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
     let connection = manager
         .get_connection(&request.server_id)
         .await
-        .ok_or_else(|| format!("MCP server not connected: {}", request.server_id))?;
+        .ok_or_else(|| MCPFailure::Transport {
+            message: format!("MCP server not connected: {}", request.server_id),
+        })?;
 
     connection
         .get_prompt(&request.prompt_name, request.arguments)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
 
 #[tauri::command]
-pub async fn start_mcp_server(state: State<'_, AppState>, server_id: String) -> Result<(), String> {
+pub async fn start_mcp_server(
+    state: State<'_, AppState>,
+    server_id: String,
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &server_id).await?;
-    manager
-        .start_server(&server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    if let Err(error) = manager.start_server(&server_id).await {
+        return Err(mcp_start_error(&server_id, error).await);
+    }
 
     Ok(())
 }
 
 #[tauri::command]
-pub async fn stop_mcp_server(state: State<'_, AppState>, server_id: String) -> Result<(), String> {
+pub async fn stop_mcp_server(
+    state: State<'_, AppState>,
+    server_id: String,
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &server_id).await?;
-    manager
-        .stop_server(&server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    manager.stop_server(&server_id).await?;
 
     Ok(())
 }
@@ -438,18 +542,17 @@ pub async fn stop_mcp_server(state: State<'_, AppState>, server_id: String) -> R
 pub async fn restart_mcp_server(
     state: State<'_, AppState>,
     server_id: String,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &server_id).await?;
-    manager
-        .restart_server(&server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    if let Err(error) = manager.restart_server(&server_id).await {
+        return Err(mcp_start_error(&server_id, error).await);
+    }
 
     Ok(())
 }
@@ -458,51 +561,48 @@ pub async fn restart_mcp_server(
 pub async fn get_mcp_server_status(
     state: State<'_, AppState>,
     server_id: String,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &server_id).await?;
-    let status = manager
-        .get_server_status(&server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    let status = manager.get_server_status(&server_id).await?;
 
     Ok(format!("{:?}", status))
 }
 
 #[tauri::command]
-pub async fn load_mcp_json_config(state: State<'_, AppState>) -> Result<String, String> {
+pub async fn load_mcp_json_config(state: State<'_, AppState>) -> Result<String, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     mcp_service
         .config_service()
         .load_mcp_json_config()
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
 
 #[tauri::command]
 pub async fn save_mcp_json_config(
     state: State<'_, AppState>,
     json_config: String,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     mcp_service
         .config_service()
         .save_mcp_json_config(&json_config)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
 
 /// Content Security Policy configuration for MCP App UI (aligned with VSCode/MCP Apps spec).
@@ -582,7 +682,7 @@ pub struct MCPAppResourceContent {
 pub async fn get_mcp_tool_ui_uri(
     _state: State<'_, AppState>,
     tool_name: String,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, ApiError> {
     let registry = bitfun_core::agentic::tools::registry::get_global_tool_registry();
     let guard = registry.read().await;
     let is_mcp_tool = guard
@@ -600,14 +700,16 @@ pub async fn get_mcp_tool_ui_uri(
 pub async fn fetch_mcp_app_resource(
     state: State<'_, AppState>,
     request: FetchMCPAppResourceRequest,
-) -> Result<FetchMCPAppResourceResponse, String> {
+) -> Result<FetchMCPAppResourceResponse, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     if !request.resource_uri.starts_with("ui://") {
-        return Err("Resource URI must use ui:// scheme".to_string());
+        return Err(ApiError::invalid_input(
+            "Resource URI must use ui:// scheme",
+        ));
     }
 
     let manager = mcp_service.server_manager();
@@ -615,12 +717,11 @@ pub async fn fetch_mcp_app_resource(
     let connection = manager
         .get_connection(&request.server_id)
         .await
-        .ok_or_else(|| format!("MCP server not connected: {}", request.server_id))?;
+        .ok_or_else(|| MCPFailure::Transport {
+            message: format!("MCP server not connected: {}", request.server_id),
+        })?;
 
-    let result = connection
-        .read_resource(&request.resource_uri)
-        .await
-        .map_err(|e| e.to_string())?;
+    let result = connection.read_resource(&request.resource_uri).await?;
 
     let contents = result
         .contents
@@ -669,6 +770,9 @@ pub async fn fetch_mcp_app_resource(
 #[serde(rename_all = "camelCase")]
 pub struct SendMCPAppMessageRequest {
     pub server_id: String,
+    /// Widget instance sending the message; required for host-handled `bitfun/*` methods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
     /// JSON-RPC 2.0 request: { "jsonrpc": "2.0", "method": "...", "params": {...}, "id": ... }
     #[serde(flatten)]
     pub message: serde_json::Value,
@@ -742,60 +846,147 @@ pub struct CancelMCPRemoteOAuthRequest {
     pub server_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproveMCPCapabilityChangesRequest {
+    pub server_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMCPTraceEnabledRequest {
+    pub server_id: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMCPTraceRequest {
+    pub server_id: String,
+    pub target_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMCPTraceResponse {
+    pub path: String,
+    pub frames: usize,
+}
+
+/// Exported inspector file: the server's identity plus its redacted frames.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MCPTraceExport {
+    server_id: String,
+    server_name: Option<String>,
+    transport: Option<String>,
+    app_version: &'static str,
+    exported_at: String,
+    #[serde(flatten)]
+    trace: MCPTraceSnapshot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerPresetRequest {
+    pub preset_id: String,
+    #[serde(default)]
+    pub values: HashMap<String, String>,
+}
+
+/// Result of checking an install form before the preset is added.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerPresetValidation {
+    pub valid: bool,
+    pub errors: Vec<MCPPresetFieldError>,
+    /// The `mcp.json` entry that would be added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_available: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_resolved_path: Option<String>,
+}
+
 #[tauri::command]
 pub async fn send_mcp_app_message(
+    app: AppHandle,
     state: State<'_, AppState>,
     request: SendMCPAppMessageRequest,
-) -> Result<SendMCPAppMessageResponse, String> {
-    let mcp_service = state
-        .mcp_service
-        .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
-
-    let manager = mcp_service.server_manager();
-    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
-    let connection = manager
-        .get_connection(&request.server_id)
-        .await
-        .ok_or_else(|| format!("MCP server not connected: {}", request.server_id))?;
-
+) -> Result<SendMCPAppMessageResponse, ApiError> {
     let msg = &request.message;
     let method = msg
         .get("method")
         .and_then(|m| m.as_str())
-        .ok_or_else(|| "Missing method".to_string())?;
+        .ok_or_else(|| ApiError::invalid_input("Missing method"))?;
     let id = msg.get("id").cloned();
     let params = msg
         .get("params")
         .cloned()
         .unwrap_or(serde_json::Value::Null);
 
+    if method.starts_with(MCP_APP_HOST_METHOD_PREFIX) {
+        let response = match handle_mcp_app_host_method(&app, &request, method, params).await {
+            Ok(result) => serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": error.0, "message": error.1 }
+            }),
+        };
+        return Ok(SendMCPAppMessageResponse { response });
+    }
+
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
+    let connection = manager
+        .get_connection(&request.server_id)
+        .await
+        .ok_or_else(|| MCPFailure::Transport {
+            message: format!("MCP server not connected: {}", request.server_id),
+        })?;
+
     let result_value: serde_json::Value = match method {
         "tools/call" => {
             let name = params
                 .get("name")
                 .and_then(|n| n.as_str())
-                .ok_or_else(|| "tools/call: missing name".to_string())?;
+                .ok_or_else(|| ApiError::invalid_input("tools/call: missing name"))?;
             let arguments = params.get("arguments").cloned();
-            let result = connection
-                .call_tool(name, arguments)
-                .await
-                .map_err(|e| e.to_string())?;
+            let result = match connection.call_tool(name, arguments).await {
+                Ok(result) => result,
+                Err(error) => {
+                    let failure = MCPFailure::from(error);
+                    let args = TranslationArgs::new()
+                        .with_string("tool", name)
+                        .with_string("server", request.server_id.as_str())
+                        .with_string("reason", failure.message());
+                    return Err(ApiError::from(failure)
+                        .with_localized(localize("mcp-tool-call-failed", Some(args)).await));
+                }
+            };
             serde_json::to_value(result).map_err(|e| e.to_string())?
         }
         "resources/read" => {
             let uri = params
                 .get("uri")
                 .and_then(|u| u.as_str())
-                .ok_or_else(|| "resources/read: missing uri".to_string())?;
-            let result = connection
-                .read_resource(uri)
-                .await
-                .map_err(|e| e.to_string())?;
+                .ok_or_else(|| ApiError::invalid_input("resources/read: missing uri"))?;
+            let result = connection.read_resource(uri).await?;
             serde_json::to_value(result).map_err(|e| e.to_string())?
         }
         "ping" => {
-            connection.ping().await.map_err(|e| e.to_string())?;
+            connection.ping().await?;
             serde_json::json!({})
         }
         _ => {
@@ -818,15 +1009,322 @@ pub async fn send_mcp_app_message(
     Ok(SendMCPAppMessageResponse { response })
 }
 
+/// Methods answered by BitFun itself rather than forwarded to the MCP server.
+const MCP_APP_HOST_METHOD_PREFIX: &str = "bitfun/";
+
+/// JSON-RPC error code and message returned to the widget.
+type MCPAppHostError = (i32, String);
+
+/// Resolves the sending widget's instance; host methods never act on another
+/// widget's instance, even one of the same server.
+async fn mcp_app_instance_for(
+    request: &SendMCPAppMessageRequest,
+) -> Result<(Arc<McpAppInstanceRegistry>, McpAppInstance), MCPAppHostError> {
+    let registry = get_global_mcp_app_instances()
+        .ok_or_else(|| (-32000, "MCP App instances are not available".to_string()))?;
+    let instance_id = request
+        .instance_id
+        .as_deref()
+        .ok_or_else(|| (-32602, "Missing instanceId".to_string()))?;
+    let instance = registry
+        .get(instance_id)
+        .await
+        .filter(|instance| instance.server_id == request.server_id)
+        .ok_or_else(|| (-32602, format!("Unknown MCP App instance: {}", instance_id)))?;
+    Ok((registry, instance))
+}
+
+async fn handle_mcp_app_host_method(
+    app: &AppHandle,
+    request: &SendMCPAppMessageRequest,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<serde_json::Value, MCPAppHostError> {
+    let (registry, instance) = mcp_app_instance_for(request).await?;
+    let invalid_params = |error: BitFunError| (-32602, error.to_string());
+    match method {
+        "bitfun/widgetState/get" => Ok(serde_json::json!({ "state": instance.state })),
+        "bitfun/widgetState/set" => {
+            let state = params.get("state").cloned().filter(|v| !v.is_null());
+            registry
+                .set_state(&instance.instance_id, state)
+                .await
+                .map_err(invalid_params)?;
+            Ok(serde_json::json!({}))
+        }
+        "bitfun/files/read" => {
+            let params: MCPAppFileParams = parse_host_params(params)?;
+            let path = params.require_path()?;
+            let bytes = registry
+                .scratch_dir(&instance.instance_id)
+                .read(path)
+                .await
+                .map_err(invalid_params)?;
+            let size = bytes.len();
+            let content = params.encoding.encode(bytes).map_err(invalid_params)?;
+            Ok(serde_json::json!({
+                "path": path,
+                "content": content,
+                "encoding": params.encoding,
+                "size": size,
+            }))
+        }
+        "bitfun/files/write" => {
+            let params: MCPAppFileParams = parse_host_params(params)?;
+            let path = params.require_path()?;
+            let bytes = params.decode_content()?;
+            registry
+                .scratch_dir(&instance.instance_id)
+                .write(path, &bytes)
+                .await
+                .map_err(invalid_params)?;
+            Ok(serde_json::json!({ "path": path, "size": bytes.len() }))
+        }
+        "bitfun/files/delete" => {
+            let params: MCPAppFileParams = parse_host_params(params)?;
+            let deleted = registry
+                .scratch_dir(&instance.instance_id)
+                .delete(params.require_path()?)
+                .await
+                .map_err(invalid_params)?;
+            Ok(serde_json::json!({ "deleted": deleted }))
+        }
+        "bitfun/files/list" => {
+            let files = registry
+                .scratch_dir(&instance.instance_id)
+                .list()
+                .await
+                .map_err(invalid_params)?;
+            let used_bytes: u64 = files.iter().map(|file| file.size).sum();
+            Ok(serde_json::json!({
+                "files": files,
+                "usedBytes": used_bytes,
+                "quotaBytes": MAX_MCP_APP_SCRATCH_BYTES,
+                "maxFileBytes": MAX_MCP_APP_FILE_BYTES,
+            }))
+        }
+        "bitfun/files/pickOpen" => {
+            let params: MCPAppFileParams = parse_host_params(params)?;
+            let Some(path) = pick_file_for_mcp_app(app, &instance, &params).await? else {
+                return Ok(serde_json::json!({ "cancelled": true }));
+            };
+            let size = tokio::fs::metadata(&path)
+                .await
+                .map_err(|error| (-32000, format!("Failed to read file: {}", error)))?
+                .len();
+            check_file_size(size).map_err(invalid_params)?;
+            let bytes = tokio::fs::read(&path)
+                .await
+                .map_err(|error| (-32000, format!("Failed to read file: {}", error)))?;
+            let content = params.encoding.encode(bytes).map_err(invalid_params)?;
+            // Only the file name goes back; the widget never learns host paths.
+            Ok(serde_json::json!({
+                "cancelled": false,
+                "name": file_name_of(&path),
+                "content": content,
+                "encoding": params.encoding,
+                "size": size,
+            }))
+        }
+        "bitfun/files/pickSave" => {
+            let params: MCPAppFileParams = parse_host_params(params)?;
+            let bytes = params.decode_content()?;
+            let Some(path) = save_file_for_mcp_app(app, &instance, &params).await? else {
+                return Ok(serde_json::json!({ "cancelled": true }));
+            };
+            tokio::fs::write(&path, &bytes)
+                .await
+                .map_err(|error| (-32000, format!("Failed to write file: {}", error)))?;
+            Ok(serde_json::json!({
+                "cancelled": false,
+                "name": file_name_of(&path),
+                "size": bytes.len(),
+            }))
+        }
+        _ => Err((-32601, format!("Method not found: {}", method))),
+    }
+}
+
+/// Params shared by the `bitfun/files/*` host methods.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MCPAppFileParams {
+    path: Option<String>,
+    content: Option<String>,
+    encoding: McpAppFileEncoding,
+    /// Dialog title for pickers.
+    title: Option<String>,
+    /// File name proposed in the save dialog.
+    suggested_name: Option<String>,
+    filters: Vec<MCPAppFileFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MCPAppFileFilter {
+    name: String,
+    extensions: Vec<String>,
+}
+
+impl MCPAppFileParams {
+    fn require_path(&self) -> Result<&str, MCPAppHostError> {
+        self.path
+            .as_deref()
+            .ok_or_else(|| (-32602, "Missing path".to_string()))
+    }
+
+    fn decode_content(&self) -> Result<Vec<u8>, MCPAppHostError> {
+        let content = self
+            .content
+            .as_deref()
+            .ok_or_else(|| (-32602, "Missing content".to_string()))?;
+        let bytes = self
+            .encoding
+            .decode(content)
+            .map_err(|error| (-32602, error.to_string()))?;
+        check_file_size(bytes.len() as u64).map_err(|error| (-32602, error.to_string()))?;
+        Ok(bytes)
+    }
+}
+
+fn parse_host_params<T: serde::de::DeserializeOwned + Default>(
+    params: serde_json::Value,
+) -> Result<T, MCPAppHostError> {
+    if params.is_null() {
+        return Ok(T::default());
+    }
+    serde_json::from_value(params).map_err(|error| (-32602, format!("Invalid params: {}", error)))
+}
+
+/// Dialog title naming the server, so the user can tell which widget is asking.
+fn picker_title(instance: &McpAppInstance, params: &MCPAppFileParams, fallback: &str) -> String {
+    let title = params
+        .title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .unwrap_or(fallback);
+    format!("{} — {}", instance.server_id, title)
+}
+
+async fn pick_file_for_mcp_app(
+    app: &AppHandle,
+    instance: &McpAppInstance,
+    params: &MCPAppFileParams,
+) -> Result<Option<std::path::PathBuf>, MCPAppHostError> {
+    let mut dialog = app
+        .dialog()
+        .file()
+        .set_title(picker_title(instance, params, "Open File"));
+    for filter in &params.filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(String::as_str).collect();
+        dialog = dialog.add_filter(&filter.name, &extensions);
+    }
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    dialog.pick_file(move |file| {
+        let _ = tx.send(file);
+    });
+    resolve_picked_path(rx.await.ok().flatten())
+}
+
+async fn save_file_for_mcp_app(
+    app: &AppHandle,
+    instance: &McpAppInstance,
+    params: &MCPAppFileParams,
+) -> Result<Option<std::path::PathBuf>, MCPAppHostError> {
+    let mut dialog = app
+        .dialog()
+        .file()
+        .set_title(picker_title(instance, params, "Save File"));
+    if let Some(name) = params
+        .suggested_name
+        .as_deref()
+        .and_then(|name| std::path::Path::new(name).file_name())
+    {
+        dialog = dialog.set_file_name(name.to_string_lossy());
+    }
+    for filter in &params.filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(String::as_str).collect();
+        dialog = dialog.add_filter(&filter.name, &extensions);
+    }
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    dialog.save_file(move |file| {
+        let _ = tx.send(file);
+    });
+    resolve_picked_path(rx.await.ok().flatten())
+}
+
+fn resolve_picked_path(
+    file: Option<tauri_plugin_dialog::FilePath>,
+) -> Result<Option<std::path::PathBuf>, MCPAppHostError> {
+    file.map(|file| {
+        file.into_path()
+            .map_err(|error| (-32000, format!("Unsupported file location: {}", error)))
+    })
+    .transpose()
+}
+
+fn file_name_of(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMCPAppStateRequest {
+    pub instance_id: String,
+    #[serde(default)]
+    pub state: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseMCPAppInstanceRequest {
+    pub instance_id: String,
+}
+
+fn mcp_app_instances() -> Result<Arc<McpAppInstanceRegistry>, ApiError> {
+    get_global_mcp_app_instances()
+        .ok_or_else(|| ApiError::service_unavailable("MCP App instances are not available"))
+}
+
+/// Registers a rendered widget, or returns the saved instance for the same tool call.
+#[tauri::command]
+pub async fn open_mcp_app_instance(
+    request: OpenMcpAppInstance,
+) -> Result<McpAppInstance, ApiError> {
+    Ok(mcp_app_instances()?.open(request).await?)
+}
+
+#[tauri::command]
+pub async fn update_mcp_app_state(
+    request: UpdateMCPAppStateRequest,
+) -> Result<McpAppInstance, ApiError> {
+    Ok(mcp_app_instances()?
+        .set_state(&request.instance_id, request.state)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn close_mcp_app_instance(request: CloseMCPAppInstanceRequest) -> Result<bool, ApiError> {
+    Ok(mcp_app_instances()?.close(&request.instance_id).await?)
+}
+
+/// Widgets open in this or the previous run, most recently used first.
+#[tauri::command]
+pub async fn list_active_mcp_apps() -> Result<Vec<McpAppInstance>, ApiError> {
+    Ok(mcp_app_instances()?.list().await)
+}
+
 #[tauri::command]
 pub async fn submit_mcp_interaction_response(
     state: State<'_, AppState>,
     request: SubmitMCPInteractionResponseRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let error_message = request.error.as_ref().and_then(|e| e.message.clone());
     let error_code = request.error.as_ref().and_then(|e| e.code);
@@ -842,8 +1340,7 @@ pub async fn submit_mcp_interaction_response(
             error_code,
             error_data,
         )
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     Ok(())
 }
@@ -852,18 +1349,17 @@ pub async fn submit_mcp_interaction_response(
 pub async fn update_mcp_remote_auth(
     state: State<'_, AppState>,
     request: UpdateMCPRemoteAuthRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
     manager
         .reauthenticate_remote_server(&request.server_id, &request.authorization_value)
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     Ok(())
 }
@@ -872,18 +1368,15 @@ pub async fn update_mcp_remote_auth(
 pub async fn clear_mcp_remote_auth(
     state: State<'_, AppState>,
     request: ClearMCPRemoteAuthRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
-    manager
-        .clear_remote_server_auth(&request.server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    manager.clear_remote_server_auth(&request.server_id).await?;
 
     Ok(())
 }
@@ -892,18 +1385,15 @@ pub async fn clear_mcp_remote_auth(
 pub async fn delete_mcp_server(
     state: State<'_, AppState>,
     request: DeleteMCPServerRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
-    manager
-        .remove_server(&request.server_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    manager.remove_server(&request.server_id).await?;
 
     Ok(())
 }
@@ -912,29 +1402,29 @@ pub async fn delete_mcp_server(
 pub async fn start_mcp_remote_oauth(
     state: State<'_, AppState>,
     request: StartMCPRemoteOAuthRequest,
-) -> Result<MCPRemoteOAuthSessionSnapshot, String> {
+) -> Result<MCPRemoteOAuthSessionSnapshot, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
     manager
         .start_remote_oauth_authorization(&request.server_id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
 
 #[tauri::command]
 pub async fn get_mcp_remote_oauth_session(
     state: State<'_, AppState>,
     request: GetMCPRemoteOAuthSessionRequest,
-) -> Result<Option<MCPRemoteOAuthSessionSnapshot>, String> {
+) -> Result<Option<MCPRemoteOAuthSessionSnapshot>, ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
@@ -945,16 +1435,207 @@ pub async fn get_mcp_remote_oauth_session(
 pub async fn cancel_mcp_remote_oauth(
     state: State<'_, AppState>,
     request: CancelMCPRemoteOAuthRequest,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     let mcp_service = state
         .mcp_service
         .as_ref()
-        .ok_or_else(|| "MCP service not initialized".to_string())?;
+        .ok_or_else(mcp_service_unavailable)?;
 
     let manager = mcp_service.server_manager();
     ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
     manager
         .cancel_remote_oauth_authorization(&request.server_id)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
+}
+
+#[tauri::command]
+pub async fn get_mcp_capability_changes(
+    state: State<'_, AppState>,
+) -> Result<Vec<MCPCapabilityChange>, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    Ok(mcp_service
+        .server_manager()
+        .get_pending_capability_changes()
+        .await)
+}
+
+#[tauri::command]
+pub async fn approve_mcp_capability_changes(
+    state: State<'_, AppState>,
+    request: ApproveMCPCapabilityChangesRequest,
+) -> Result<(), ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
+    manager
+        .approve_capability_changes(&request.server_id)
+        .await
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
+}
+
+#[tauri::command]
+pub async fn set_mcp_trace_enabled(
+    state: State<'_, AppState>,
+    request: SetMCPTraceEnabledRequest,
+) -> Result<(), ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
+    manager
+        .set_protocol_trace_enabled(&request.server_id, request.enabled)
+        .await
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
+}
+
+#[tauri::command]
+pub async fn get_mcp_trace(
+    state: State<'_, AppState>,
+    server_id: String,
+) -> Result<MCPTraceSnapshot, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &server_id).await?;
+    manager
+        .get_protocol_trace(&server_id)
+        .await
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
+}
+
+/// Writes a server's inspector trace to a JSON file that can be attached to
+/// a bug report.
+#[tauri::command]
+pub async fn export_mcp_trace(
+    state: State<'_, AppState>,
+    request: ExportMCPTraceRequest,
+) -> Result<ExportMCPTraceResponse, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let manager = mcp_service.server_manager();
+    ensure_unscoped_host_mcp_access(&manager, &request.server_id).await?;
+    let trace = manager.get_protocol_trace(&request.server_id).await?;
+    let config = mcp_service
+        .config_service()
+        .get_server_config(&request.server_id)
+        .await?;
+    let frames = trace.frames.len();
+    let export = MCPTraceExport {
+        server_id: request.server_id,
+        server_name: config.as_ref().map(|config| config.name.clone()),
+        transport: config
+            .as_ref()
+            .map(|config| config.resolved_transport().as_str().to_string()),
+        app_version: env!("CARGO_PKG_VERSION"),
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        trace,
+    };
+    let content = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize MCP trace: {}", e))?;
+
+    let mut target = std::path::PathBuf::from(&request.target_path);
+    if target.extension().is_none() {
+        target.set_extension("json");
+    }
+    tokio::fs::write(&target, content.as_bytes())
+        .await
+        .map_err(|e| format!("Failed to write MCP trace: {}", e))?;
+
+    Ok(ExportMCPTraceResponse {
+        path: target.to_string_lossy().to_string(),
+        frames,
+    })
+}
+
+#[tauri::command]
+pub async fn get_mcp_server_presets(
+    state: State<'_, AppState>,
+) -> Result<MCPServerPresetCatalog, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    Ok(mcp_service.config_service().load_preset_catalog().await)
+}
+
+#[tauri::command]
+pub async fn validate_mcp_server_preset(
+    state: State<'_, AppState>,
+    request: MCPServerPresetRequest,
+) -> Result<MCPServerPresetValidation, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    let catalog = mcp_service.config_service().load_preset_catalog().await;
+    let preset = catalog
+        .get(&request.preset_id)
+        .ok_or_else(|| MCPFailure::NotFound {
+            message: format!("MCP server preset not found: {}", request.preset_id),
+        })?;
+    let (config, errors) = match render_mcp_server_preset(preset, &request.values) {
+        Ok(config) => (Some(config), Vec::new()),
+        Err(errors) => (None, errors),
+    };
+
+    let mut validation = MCPServerPresetValidation {
+        valid: errors.is_empty(),
+        errors,
+        config,
+        command: preset.command.clone(),
+        command_available: None,
+        command_source: None,
+        command_resolved_path: None,
+    };
+    if let Some(command) = preset.command.as_deref() {
+        if let Ok(runtime_manager) = RuntimeManager::new() {
+            let capability = runtime_manager.get_command_capability(command);
+            validation.command_available = Some(capability.available);
+            validation.command_source = capability.source.map(|source| match source {
+                RuntimeSource::System => "system".to_string(),
+                RuntimeSource::Managed => "managed".to_string(),
+            });
+            validation.command_resolved_path = runtime_manager
+                .resolve_command(command)
+                .and_then(|resolved| resolved.resolved_path);
+        }
+    }
+    Ok(validation)
+}
+
+#[tauri::command]
+pub async fn install_mcp_server_preset(
+    state: State<'_, AppState>,
+    request: MCPServerPresetRequest,
+) -> Result<String, ApiError> {
+    let mcp_service = state
+        .mcp_service
+        .as_ref()
+        .ok_or_else(mcp_service_unavailable)?;
+
+    mcp_service
+        .config_service()
+        .install_preset(&request.preset_id, &request.values)
+        .await
+        .map_err(|e| ApiError::from(MCPFailure::from(e)))
 }
@@ -22,6 +22,7 @@ use bitfun_core::miniapp::{
     MiniAppPermissionDiff, MiniAppPermissions, MiniAppSource,
 };
 use bitfun_core::service::config::types::GlobalConfig;
+use bitfun_core::service::service_health::{service_health_registry, ServiceKind};
 use bitfun_core::util::types::Message;
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
@@ -301,7 +302,7 @@ async fn ensure_worker_dependencies(
     let pool = state
         .js_worker_pool
         .as_ref()
-        .ok_or_else(|| "JS Worker pool not initialized".to_string())?;
+        .ok_or_else(|| service_health_registry().unavailable_message(ServiceKind::Runtime))?;
 
     let needs_install = !app.source.npm_dependencies.is_empty()
         && (app.runtime.deps_dirty || !pool.has_installed_deps(app_id));
@@ -580,7 +581,7 @@ pub async fn miniapp_worker_call(
     let pool = state
         .js_worker_pool
         .as_ref()
-        .ok_or_else(|| "JS Worker pool not initialized".to_string())?;
+        .ok_or_else(|| service_health_registry().unavailable_message(ServiceKind::Runtime))?;
     let was_running = pool.is_running(&request.app_id).await;
     let mut app = state
         .miniapp_manager
@@ -709,7 +710,7 @@ pub async fn miniapp_install_deps(
     let pool = state
         .js_worker_pool
         .as_ref()
-        .ok_or_else(|| "JS Worker pool not initialized".to_string())?;
+        .ok_or_else(|| service_health_registry().unavailable_message(ServiceKind::Runtime))?;
     let app = state
         .miniapp_manager
         .get(&app_id)
@@ -998,7 +999,7 @@ pub async fn miniapp_draft_worker_call(
     let pool = state
         .js_worker_pool
         .as_ref()
-        .ok_or_else(|| "JS Worker pool not initialized".to_string())?;
+        .ok_or_else(|| service_health_registry().unavailable_message(ServiceKind::Runtime))?;
     let draft = state
         .miniapp_manager
         .get_draft(&request.app_id, &request.draft_id)
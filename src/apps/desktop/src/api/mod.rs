@@ -1,28 +1,45 @@
 //! API layer module
 
+pub mod about_api;
 pub mod acp_client_api;
+pub mod agent_task_api;
 pub mod agentic_api;
 pub mod announcement_api;
 pub mod app_state;
+pub mod approval_api;
+pub mod artifact_api;
+pub mod attachment_api;
+pub mod background_job_api;
+pub mod backup_api;
 pub mod browser_api;
 pub mod browser_control_api;
 pub mod btw_api;
 pub mod canvas_api;
 pub mod clipboard_file_api;
+pub mod code_index_api;
 pub mod commands;
 pub mod computer_use_api;
 pub mod config_api;
+pub mod connectivity_api;
+pub mod context_provider_api;
 pub mod context_upload_api;
+pub mod credential_health_api;
 pub mod cron_api;
 pub mod custom_agent_api;
 pub mod debug_api;
 pub mod diff_api;
+pub mod docconv_api;
+pub mod download_api;
 pub mod dto;
 pub mod editor_ai_api;
+pub mod event_replay_api;
 pub mod external_hooks_api;
 pub mod external_sources_api;
+pub mod file_operation_api;
 pub mod git_agent_api;
 pub mod git_api;
+pub mod git_askpass_api;
+pub mod hashing_api;
 pub mod i18n_api;
 pub mod insights_api;
 pub mod lsp_api;
@@ -31,18 +48,26 @@ pub mod mcp_api;
 pub mod miniapp_agent_api;
 pub mod miniapp_api;
 pub mod miniapp_export_api;
+pub mod operation_api;
 pub mod pages_api;
 pub mod path_target;
 pub mod peer_host_invoke;
+pub mod power_api;
+pub mod project_template_api;
 pub mod relay_deploy_api;
 pub mod remote_connect_api;
 pub mod remote_workspace_policy;
 pub mod review_platform_api;
 pub mod runtime_api;
+pub mod screen_capture_api;
 pub mod search_api;
+pub mod semantic_search_api;
+pub mod service_health_api;
 pub mod session_api;
 pub mod session_storage_path;
+pub mod sidecar_plugin_api;
 pub mod skill_api;
+pub mod skill_install_journal;
 pub mod snapshot_service;
 pub mod speech_api;
 pub mod ssh_api;
@@ -51,7 +76,10 @@ pub mod storage_commands;
 pub mod subagent_api;
 pub mod system_api;
 pub mod terminal_api;
+pub mod theme_api;
 pub mod tool_api;
+pub mod window_api;
 pub mod workspace_activation;
+pub mod workspace_trust_api;
 
 pub use app_state::{AppState, AppStatistics, HealthStatus, RemoteWorkspace};
@@ -0,0 +1,15 @@
+//! Cancellable operation API.
+
+use bitfun_core::service::operations::{operation_registry, OperationInfo};
+
+/// Cancels a running operation started with an `operationId`. Returns `false`
+/// when the operation already finished or never existed.
+#[tauri::command]
+pub async fn cancel_operation(operation_id: String) -> Result<bool, String> {
+    Ok(operation_registry().cancel(operation_id.trim()))
+}
+
+#[tauri::command]
+pub async fn list_operations() -> Result<Vec<OperationInfo>, String> {
+    Ok(operation_registry().list())
+}
@@ -7,13 +7,16 @@ use bitfun_core::agentic::tools::workspace_paths::{
 };
 use bitfun_core::infrastructure::get_path_manager_arc;
 use bitfun_core::infrastructure::FileOperationOptions;
+use bitfun_core::service::file_ops::{get_global_file_operation_service, FileOperationService};
 use bitfun_core::service::remote_ssh::workspace_state::remote_workspace_runtime_root;
 use bitfun_core::service::remote_ssh::{
     get_remote_workspace_manager, normalize_remote_workspace_path, RemoteWorkspaceEntry,
 };
 use bitfun_core::service::workspace::{WorkspaceInfo, WorkspaceKind};
+use bitfun_core::util::errors::ApiError;
 use serde::Serialize;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
@@ -126,6 +129,39 @@ async fn lookup_remote_entry_for_path(
     manager.lookup_connection(path, preferred.as_deref()).await
 }
 
+/// Workspace root that confines file operations on a local path: the owning
+/// open workspace, or the projects runtime root for runtime artifacts.
+async fn local_operation_root(
+    app_state: &AppState,
+    resolved_path: &Path,
+    is_runtime_artifact: bool,
+) -> Result<PathBuf, String> {
+    if is_runtime_artifact {
+        return Ok(get_path_manager_arc().projects_root());
+    }
+
+    app_state
+        .workspace_service
+        .list_workspace_infos()
+        .await
+        .into_iter()
+        .filter(|workspace| workspace.workspace_kind != WorkspaceKind::Remote)
+        .map(|workspace| workspace.root_path)
+        .filter(|root| resolved_path.starts_with(root))
+        .max_by_key(|root| root.components().count())
+        .ok_or_else(|| {
+            format!(
+                "Path is outside every open workspace: {}",
+                resolved_path.display()
+            )
+        })
+}
+
+fn file_operation_service() -> Result<Arc<FileOperationService>, String> {
+    get_global_file_operation_service()
+        .ok_or_else(|| "File operation service is not initialized".to_string())
+}
+
 pub async fn resolve_desktop_path_target(
     app_state: &AppState,
     raw_path: &str,
@@ -427,6 +463,7 @@ pub async fn rename_path(
     match resolve_desktop_path_target(app_state, old_path, preferred_remote_connection_id).await? {
         DesktopPathTarget::Local {
             resolved_path: old_resolved_path,
+            is_runtime_artifact,
             ..
         } => {
             let new_resolved_path = match resolve_desktop_path_target(
@@ -445,13 +482,12 @@ pub async fn rename_path(
                 }
             };
 
-            app_state
-                .filesystem_service
-                .move_file(
-                    &old_resolved_path.to_string_lossy(),
-                    &new_resolved_path.to_string_lossy(),
-                )
+            let root =
+                local_operation_root(app_state, &old_resolved_path, is_runtime_artifact).await?;
+            file_operation_service()?
+                .rename(&root, &old_resolved_path, &new_resolved_path)
                 .await
+                .map(|_| ())
                 .map_err(|e| format!("Failed to rename file: {}", e))
         }
         DesktopPathTarget::Remote { entry, .. } => {
@@ -467,17 +503,28 @@ pub async fn rename_path(
     }
 }
 
+/// Deletes a file, moving local ones to the system trash. Fails with
+/// `ApiErrorCode::NotImplemented` when no trash is available, unless
+/// `permanent` is set.
 pub async fn delete_file(
     app_state: &AppState,
     raw_path: &str,
+    permanent: bool,
     preferred_remote_connection_id: Option<&str>,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     match resolve_desktop_path_target(app_state, raw_path, preferred_remote_connection_id).await? {
-        DesktopPathTarget::Local { resolved_path, .. } => app_state
-            .filesystem_service
-            .delete_file(&resolved_path.to_string_lossy())
-            .await
-            .map_err(|e| format!("Failed to delete file: {}", e)),
+        DesktopPathTarget::Local {
+            resolved_path,
+            is_runtime_artifact,
+            ..
+        } => {
+            let root = local_operation_root(app_state, &resolved_path, is_runtime_artifact).await?;
+            file_operation_service()?
+                .delete(&root, &resolved_path, false, permanent)
+                .await
+                .map(|_| ())
+                .map_err(|e| ApiError::from(e).with_context("Failed to delete file"))
+        }
         DesktopPathTarget::Remote {
             requested_path,
             entry,
@@ -489,23 +536,32 @@ pub async fn delete_file(
             remote_fs
                 .remove_file(&entry.connection_id, &requested_path)
                 .await
-                .map_err(|e| format!("Failed to delete remote file: {}", e))
+                .map_err(|e| ApiError::from(format!("Failed to delete remote file: {}", e)))
         }
     }
 }
 
+/// Deletes a directory; see [`delete_file`] for the trash behavior.
 pub async fn delete_directory(
     app_state: &AppState,
     raw_path: &str,
     recursive: bool,
+    permanent: bool,
     preferred_remote_connection_id: Option<&str>,
-) -> Result<(), String> {
+) -> Result<(), ApiError> {
     match resolve_desktop_path_target(app_state, raw_path, preferred_remote_connection_id).await? {
-        DesktopPathTarget::Local { resolved_path, .. } => app_state
-            .filesystem_service
-            .delete_directory(&resolved_path.to_string_lossy(), recursive)
-            .await
-            .map_err(|e| format!("Failed to delete directory: {}", e)),
+        DesktopPathTarget::Local {
+            resolved_path,
+            is_runtime_artifact,
+            ..
+        } => {
+            let root = local_operation_root(app_state, &resolved_path, is_runtime_artifact).await?;
+            file_operation_service()?
+                .delete(&root, &resolved_path, recursive, permanent)
+                .await
+                .map(|_| ())
+                .map_err(|e| ApiError::from(e).with_context("Failed to delete directory"))
+        }
         DesktopPathTarget::Remote {
             requested_path,
             entry,
@@ -518,12 +574,16 @@ pub async fn delete_directory(
                 remote_fs
                     .remove_dir_all(&entry.connection_id, &requested_path)
                     .await
-                    .map_err(|e| format!("Failed to delete remote directory: {}", e))
+                    .map_err(|e| {
+                        ApiError::from(format!("Failed to delete remote directory: {}", e))
+                    })
             } else {
                 remote_fs
                     .remove_dir(&entry.connection_id, &requested_path)
                     .await
-                    .map_err(|e| format!("Failed to delete remote directory: {}", e))
+                    .map_err(|e| {
+                        ApiError::from(format!("Failed to delete remote directory: {}", e))
+                    })
             }
         }
     }
@@ -535,11 +595,14 @@ pub async fn create_empty_file(
     preferred_remote_connection_id: Option<&str>,
 ) -> Result<(), String> {
     match resolve_desktop_path_target(app_state, raw_path, preferred_remote_connection_id).await? {
-        DesktopPathTarget::Local { resolved_path, .. } => {
-            let options = FileOperationOptions::default();
-            app_state
-                .filesystem_service
-                .write_file_with_options(&resolved_path.to_string_lossy(), "", options)
+        DesktopPathTarget::Local {
+            resolved_path,
+            is_runtime_artifact,
+            ..
+        } => {
+            let root = local_operation_root(app_state, &resolved_path, is_runtime_artifact).await?;
+            file_operation_service()?
+                .create_file(&root, &resolved_path, b"")
                 .await
                 .map(|_| ())
                 .map_err(|e| format!("Failed to create file: {}", e))
@@ -566,11 +629,23 @@ pub async fn create_directory(
     preferred_remote_connection_id: Option<&str>,
 ) -> Result<(), String> {
     match resolve_desktop_path_target(app_state, raw_path, preferred_remote_connection_id).await? {
-        DesktopPathTarget::Local { resolved_path, .. } => app_state
-            .filesystem_service
-            .create_directory(&resolved_path.to_string_lossy())
-            .await
-            .map_err(|e| format!("Failed to create directory: {}", e)),
+        DesktopPathTarget::Local {
+            resolved_path,
+            is_runtime_artifact,
+            ..
+        } => {
+            // Creating an existing directory stays a no-op, and is not
+            // journaled so undo cannot remove a folder it did not create.
+            if resolved_path.is_dir() {
+                return Ok(());
+            }
+            let root = local_operation_root(app_state, &resolved_path, is_runtime_artifact).await?;
+            file_operation_service()?
+                .create_directory(&root, &resolved_path)
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("Failed to create directory: {}", e))
+        }
         DesktopPathTarget::Remote {
             requested_path,
             entry,
@@ -38,6 +38,8 @@ static LOCAL_ONLY_COMMANDS: &[&str] = &[
     "startup_window_control",
     "toggle_main_window_fullscreen",
     "restart_app",
+    "get_data_location",
+    "relocate_data_dir",
     "check_for_updates",
     "install_update",
     // Account identity / peer mode control (stay on controller)
@@ -0,0 +1,45 @@
+//! Power and idle state API.
+
+use bitfun_core::service::power::{get_global_power_service, PowerService, PowerSnapshot};
+use bitfun_core::util::errors::ApiError;
+use log::warn;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `PowerSnapshot` payload whenever the power source, battery
+/// saver, idle state or the set of deferred background work changes.
+pub const POWER_STATE_CHANGED_EVENT: &str = "power_state_changed";
+
+fn power_service() -> Result<Arc<PowerService>, ApiError> {
+    get_global_power_service()
+        .ok_or_else(|| ApiError::service_unavailable("Power service is not initialized"))
+}
+
+#[tauri::command]
+pub async fn get_power_status() -> Result<PowerSnapshot, ApiError> {
+    Ok(power_service()?.snapshot())
+}
+
+#[tauri::command]
+pub async fn refresh_power_status() -> Result<PowerSnapshot, ApiError> {
+    Ok(power_service()?.refresh().await)
+}
+
+/// Starts the probe loop and forwards power state changes to the frontend.
+pub fn start_power_monitor(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_power_service() else {
+        warn!("Power service unavailable; background work will ignore battery state");
+        return;
+    };
+    service.start();
+
+    let mut receiver = service.subscribe();
+    tokio::spawn(async move {
+        while receiver.changed().await.is_ok() {
+            let snapshot = receiver.borrow_and_update().clone();
+            if let Err(e) = app_handle.emit(POWER_STATE_CHANGED_EVENT, &snapshot) {
+                warn!("Failed to emit power state event: {}", e);
+            }
+        }
+    });
+}
@@ -0,0 +1,80 @@
+//! Project template API for "New Project…"
+
+use bitfun_core::infrastructure::get_path_manager_arc;
+use bitfun_core::service::operations::{operation_registry, OperationKind};
+use bitfun_core::service::project_templates::{
+    create_project, ProjectTemplateCatalog, ProjectTemplateRegistry, ScaffoldProgress,
+    ScaffoldResult,
+};
+use bitfun_core::util::errors::ApiError;
+use bitfun_transport::emit_journaled;
+use serde::Deserialize;
+use std::path::Path;
+use tauri::AppHandle;
+
+pub const PROJECT_SCAFFOLD_PROGRESS_EVENT: &str = "project://scaffold-progress";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewProjectFromTemplateRequest {
+    pub parent_path: String,
+    pub project_name: String,
+    pub template_id: String,
+    /// Lets the caller cancel through `cancel_operation` while files are
+    /// written or post-create commands run.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+}
+
+fn template_registry() -> ProjectTemplateRegistry {
+    ProjectTemplateRegistry::from_path_manager(&get_path_manager_arc())
+}
+
+/// List built-in and user templates, plus invalid user template directories.
+#[tauri::command]
+pub async fn list_project_templates() -> Result<ProjectTemplateCatalog, ApiError> {
+    Ok(template_registry().list())
+}
+
+/// Create `<parentPath>/<projectName>` from a template. Progress is emitted
+/// as `PROJECT_SCAFFOLD_PROGRESS_EVENT`; failed post-create commands are
+/// listed in the result rather than failing the call.
+#[tauri::command]
+pub async fn new_project_from_template(
+    app: AppHandle,
+    request: NewProjectFromTemplateRequest,
+) -> Result<ScaffoldResult, ApiError> {
+    let parent_path = request.parent_path.trim();
+    if parent_path.is_empty() {
+        return Err(ApiError::invalid_input("Parent directory is required"));
+    }
+    let template = template_registry()
+        .get(request.template_id.trim())
+        .map_err(|e| ApiError::from(e).with_context("Failed to load project template"))?;
+    let operation = operation_registry().begin(
+        request.operation_id,
+        OperationKind::ProjectScaffold,
+        request.project_name.clone(),
+    )?;
+    let operation_id = operation.id().to_string();
+
+    create_project(
+        &template,
+        Path::new(parent_path),
+        &request.project_name,
+        operation.token(),
+        move |progress: ScaffoldProgress| {
+            let payload = serde_json::to_value(&progress).unwrap_or_default();
+            if let Err(error) = emit_journaled(
+                &app,
+                PROJECT_SCAFFOLD_PROGRESS_EVENT,
+                Some(&operation_id),
+                payload,
+            ) {
+                log::warn!("Failed to emit project scaffold progress event: {error}");
+            }
+        },
+    )
+    .await
+    .map_err(|e| ApiError::from(e).with_context("Failed to create project"))
+}
@@ -158,11 +158,27 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::RemoteUnsupported,
     ),
     ("apply_patch", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "apply_session_retention",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
+    (
+        "apply_workspace_patch",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    (
+        "approve_mcp_capability_changes",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "archive_all_sessions",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
     ("archive_session", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "attach_mcp_resources",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "browser_control_create_launcher",
         RemoteWorkspacePolicy::LocalOnly,
@@ -195,6 +211,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "cancel_acp_dialog_turn",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "cancel_agent_task",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("cancel_dialog_turn", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "cancel_insights_generation",
@@ -204,6 +224,11 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "cancel_mcp_remote_oauth",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("cancel_operation", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    (
+        "cancel_runtime_provisioning",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     ("cancel_search", RemoteWorkspacePolicy::LegacyUnaudited),
     ("cancel_session", RemoteWorkspacePolicy::LegacyUnaudited),
     (
@@ -216,6 +241,8 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "canonicalize_agent_profile_configs",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("capture_region", RemoteWorkspacePolicy::LocalOnly),
+    ("capture_window", RemoteWorkspacePolicy::LocalOnly),
     (
         "check_command_exists",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -254,10 +281,20 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "clear_mcp_remote_auth",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "clear_remembered_approvals",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "clear_semantic_index",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
     (
         "clear_session_thread_goal",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("close_mcp_app_instance", RemoteWorkspacePolicy::LocalOnly),
+    ("close_secondary_window", RemoteWorkspacePolicy::LocalOnly),
     ("close_workspace", RemoteWorkspacePolicy::LegacyUnaudited),
     ("compact_session", RemoteWorkspacePolicy::LegacyUnaudited),
     ("compress_path", RemoteWorkspacePolicy::RemoteRouted),
@@ -279,6 +316,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "control_deep_review_queue",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("convert_document", RemoteWorkspacePolicy::LocalOnly),
     (
         "create_acp_flow_session",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -287,6 +325,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "create_assistant_workspace",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "create_background_job",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("create_cron_job", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "create_custom_agent",
@@ -310,10 +352,15 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "delete_all_archived_sessions",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("delete_artifact", RemoteWorkspacePolicy::LocalOnly),
     (
         "delete_assistant_workspace",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "delete_background_job",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("delete_cron_job", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "delete_custom_agent",
@@ -330,6 +377,11 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
     ("delete_session", RemoteWorkspacePolicy::LegacyUnaudited),
     ("delete_skill", RemoteWorkspacePolicy::LegacyUnaudited),
     ("delete_subagent", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "delete_user_theme",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("diagnose_runtime_command", RemoteWorkspacePolicy::LocalOnly),
     (
         "dismiss_announcement",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
@@ -338,8 +390,16 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "download_skill_market",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "duplicate_skill_to_user",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("editor_ai_cancel", RemoteWorkspacePolicy::LegacyUnaudited),
     ("editor_ai_stream", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "enqueue_agent_task",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "ensure_assistant_bootstrap",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -361,23 +421,42 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "explorer_get_file_tree",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("export_artifact", RemoteWorkspacePolicy::LocalOnly),
     ("export_config", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "export_diagnostics_bundle",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "generate_diagnostics_bundle",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     (
         "export_local_file_to_path",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("export_mcp_trace", RemoteWorkspacePolicy::LocalOnly),
+    ("export_replay_bundle", RemoteWorkspacePolicy::RemoteRouted),
+    ("export_session", RemoteWorkspacePolicy::RemoteRouted),
+    ("export_session_report", RemoteWorkspacePolicy::RemoteRouted),
     (
         "export_session_transcript",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "export_skill_bundle",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
     (
         "fetch_mcp_app_resource",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("find_symbol", RemoteWorkspacePolicy::RemoteUnsupported),
+    (
+        "forget_remembered_approval",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("forget_workspace_trust", RemoteWorkspacePolicy::LocalOnly),
     ("fork_session", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "generate_commit_message",
@@ -418,8 +497,13 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_announcement_tips",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
+    ("get_app_info", RemoteWorkspacePolicy::WorkspaceAgnostic),
     ("get_app_state", RemoteWorkspacePolicy::WorkspaceAgnostic),
     ("get_app_version", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    (
+        "get_approval_policies",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "get_available_modes",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -438,6 +522,18 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
     ),
     ("get_config", RemoteWorkspacePolicy::LegacyUnaudited),
     ("get_configs", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "get_connectivity_status",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "get_credential_health_status",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "get_current_theme",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "get_current_workspace",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -446,6 +542,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_custom_agent_detail",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "get_custom_agent_load_issues",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
     (
         "get_default_review_team_definition",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -458,6 +558,14 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_directory_children_paginated",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "get_document_conversion_capabilities",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "get_download_speed_limits",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     (
         "get_external_hook_catalog",
         RemoteWorkspacePolicy::RemoteUnsupported,
@@ -466,6 +574,97 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_external_source_snapshot",
         RemoteWorkspacePolicy::RemoteUnsupported,
     ),
+    ("get_file_outline", RemoteWorkspacePolicy::RemoteUnsupported),
+    (
+        "get_semantic_index_status",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    (
+        "get_service_health",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("get_user_theme", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("get_workspace_trust", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "i18n_localize_messages",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("ingest_clipboard", RemoteWorkspacePolicy::LocalOnly),
+    ("ingest_dropped_files", RemoteWorkspacePolicy::LocalOnly),
+    ("list_active_mcp_apps", RemoteWorkspacePolicy::LocalOnly),
+    ("list_agent_tasks", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("list_artifacts", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "list_background_job_runs",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "list_background_jobs",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("list_capture_displays", RemoteWorkspacePolicy::LocalOnly),
+    ("list_checkpoints", RemoteWorkspacePolicy::RemoteUnsupported),
+    ("list_file_operations", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "list_pending_approvals",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "list_pending_git_credential_prompts",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
+    (
+        "list_project_templates",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "list_remembered_approvals",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("list_secondary_windows", RemoteWorkspacePolicy::LocalOnly),
+    ("list_session_attachments", RemoteWorkspacePolicy::LocalOnly),
+    ("list_themes", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("list_workspace_trust", RemoteWorkspacePolicy::LocalOnly),
+    ("open_artifact", RemoteWorkspacePolicy::LocalOnly),
+    ("open_mcp_app_instance", RemoteWorkspacePolicy::LocalOnly),
+    ("pause_agent_task", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("preview_artifact", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "preview_skill_market_install",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    (
+        "preview_workspace_patch",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    (
+        "refresh_connectivity",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "refresh_credential_health",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("register_artifact", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "remove_session_attachment",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
+    ("replay_events", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("respond_approval", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    (
+        "respond_git_credential_prompt",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
+    (
+        "restore_checkpoint",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    (
+        "resume_agent_task",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("retry_agent_task", RemoteWorkspacePolicy::WorkspaceAgnostic),
     (
         "reveal_external_source_location",
         RemoteWorkspacePolicy::RemoteUnsupported,
@@ -485,6 +684,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
     ),
     ("get_file_metadata", RemoteWorkspacePolicy::LegacyUnaudited),
     ("get_file_tree", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "get_first_launch_context",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "get_global_config_health",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -498,20 +701,33 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
     ("get_latest_insights", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "get_mcp_capability_changes",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("get_mcp_prompt", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "get_mcp_remote_oauth_session",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "get_mcp_server_presets",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "get_mcp_server_status",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
     ("get_mcp_servers", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "get_mcp_startup_readiness",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "get_mcp_tool_ui_uri",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("get_mcp_trace", RemoteWorkspacePolicy::WorkspaceAgnostic),
     ("get_memory_paths", RemoteWorkspacePolicy::LegacyUnaudited),
     ("get_miniapp", RemoteWorkspacePolicy::LegacyUnaudited),
     (
@@ -544,6 +760,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_pending_announcements",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
+    ("get_power_status", RemoteWorkspacePolicy::LocalOnly),
     (
         "get_project_storage_paths",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -564,6 +781,11 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_runtime_logging_info",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "get_runtime_provisioning_plan",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
+    ("get_runtime_usage", RemoteWorkspacePolicy::LocalOnly),
     (
         "get_session_file_diff_stats",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -602,6 +824,8 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_storage_statistics",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("get_cache_usage", RemoteWorkspacePolicy::LocalOnly),
+    ("clear_cache", RemoteWorkspacePolicy::LocalOnly),
     (
         "get_subagent_detail",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -726,7 +950,19 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "install_acp_client_cli",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "install_mcp_server_preset",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    (
+        "install_runtime_components",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     ("install_update", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    (
+        "invoke_sidecar_plugin_command",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     (
         "list_agent_companion_pets",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -763,6 +999,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "get_project_permission_rules",
         RemoteWorkspacePolicy::RemoteRouted,
     ),
+    (
+        "run_background_job_now",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "save_project_permission_rules",
         RemoteWorkspacePolicy::RemoteRouted,
@@ -779,6 +1019,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
     ("list_mcp_prompts", RemoteWorkspacePolicy::LegacyUnaudited),
     ("list_mcp_resources", RemoteWorkspacePolicy::LegacyUnaudited),
     ("list_miniapps", RemoteWorkspacePolicy::LegacyUnaudited),
+    ("list_operations", RemoteWorkspacePolicy::WorkspaceAgnostic),
     (
         "list_persisted_sessions",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -788,6 +1029,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
     ("list_sessions", RemoteWorkspacePolicy::LegacyUnaudited),
+    ("list_sidecar_plugins", RemoteWorkspacePolicy::LocalOnly),
     ("list_skill_market", RemoteWorkspacePolicy::LegacyUnaudited),
     ("list_subagents", RemoteWorkspacePolicy::RemoteRouted),
     (
@@ -1067,6 +1309,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "never_show_announcement",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
+    (
+        "new_project_from_template",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     (
         "notify_cron_host_ready",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1076,6 +1322,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LocalOnly,
     ),
     ("open_remote_workspace", RemoteWorkspacePolicy::RemoteRouted),
+    ("open_secondary_window", RemoteWorkspacePolicy::LocalOnly),
     ("open_workspace", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "page_create_open_link",
@@ -1146,6 +1393,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "refresh_model_client",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("refresh_power_status", RemoteWorkspacePolicy::LocalOnly),
     (
         "refresh_subscription_account",
         RemoteWorkspacePolicy::LocalOnly,
@@ -1177,6 +1425,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "reload_global_config",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("reload_sidecar_plugins", RemoteWorkspacePolicy::LocalOnly),
     ("reload_subagents", RemoteWorkspacePolicy::LegacyUnaudited),
     // One-click self-hosted relay (SSH to user host). WorkspaceAgnostic: uses
     // an SSH connection id, not the open project workspace. See
@@ -1338,6 +1587,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "reset_mode_skill_selection",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("reset_window_layout", RemoteWorkspacePolicy::LocalOnly),
     (
         "reset_workspace_persona_files",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1347,6 +1597,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LocalOnly,
     ),
     ("restart_app", RemoteWorkspacePolicy::LocalOnly),
+    ("create_backup", RemoteWorkspacePolicy::LocalOnly),
+    ("restore_backup", RemoteWorkspacePolicy::LocalOnly),
+    ("get_data_location", RemoteWorkspacePolicy::LocalOnly),
+    ("relocate_data_dir", RemoteWorkspacePolicy::LocalOnly),
     ("restart_mcp_server", RemoteWorkspacePolicy::LegacyUnaudited),
     ("restore_session", RemoteWorkspacePolicy::LegacyUnaudited),
     (
@@ -1362,6 +1616,14 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "review_platform_clear_auth_token",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
+    (
+        "review_platform_create_branch",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
+    (
+        "review_platform_create_pull_request",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
     (
         "review_platform_get_issue",
         RemoteWorkspacePolicy::RemoteRouted,
@@ -1386,6 +1648,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "review_platform_get_pull_request_review_target_by_identity",
         RemoteWorkspacePolicy::RemoteRouted,
     ),
+    (
+        "review_platform_get_review_comments",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
     (
         "review_platform_get_workspace_context",
         RemoteWorkspacePolicy::RemoteRouted,
@@ -1394,6 +1660,14 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "review_platform_get_workspace_snapshot",
         RemoteWorkspacePolicy::RemoteRouted,
     ),
+    (
+        "review_platform_list_issues",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
+    (
+        "review_platform_push_branch",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
     (
         "review_platform_update_auth_token",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
@@ -1425,6 +1699,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
     ("save_session_turn", RemoteWorkspacePolicy::LegacyUnaudited),
+    ("save_user_theme", RemoteWorkspacePolicy::WorkspaceAgnostic),
     (
         "scan_workspace_info",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1445,10 +1720,20 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::RemoteRouted,
     ),
     ("search_rebuild_index", RemoteWorkspacePolicy::RemoteRouted),
+    (
+        "search_session_history",
+        RemoteWorkspacePolicy::RemoteRouted,
+    ),
     (
         "search_skill_market",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("search_tools", RemoteWorkspacePolicy::RemoteRouted),
+    (
+        "semantic_index_workspace",
+        RemoteWorkspacePolicy::RemoteUnsupported,
+    ),
+    ("semantic_search", RemoteWorkspacePolicy::RemoteUnsupported),
     (
         "send_background_command_input",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1474,7 +1759,16 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "set_agent_profile_config",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "set_approval_policy",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("set_config", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "set_context_open_files",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
+    ("set_download_speed_limit", RemoteWorkspacePolicy::LocalOnly),
     (
         "set_external_mcp_server_decision_command",
         RemoteWorkspacePolicy::RemoteUnsupported,
@@ -1500,6 +1794,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::RemoteUnsupported,
     ),
     ("set_macos_edit_menu_mode", RemoteWorkspacePolicy::LocalOnly),
+    (
+        "set_mcp_trace_enabled",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "set_miniapp_draft_storage",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1520,10 +1818,16 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "set_session_thread_goal_status",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "set_sidecar_plugin_enabled",
+        RemoteWorkspacePolicy::LocalOnly,
+    ),
     (
         "set_subagent_timeout",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("set_theme", RemoteWorkspacePolicy::WorkspaceAgnostic),
+    ("set_workspace_trust", RemoteWorkspacePolicy::LocalOnly),
     (
         "show_agent_companion_desktop_pet",
         RemoteWorkspacePolicy::LocalOnly,
@@ -1542,19 +1846,23 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LocalOnly,
     ),
     ("speech_delete_model", RemoteWorkspacePolicy::LocalOnly),
+    ("speech_download_model", RemoteWorkspacePolicy::LocalOnly),
     (
-        "speech_download_model",
+        "speech_finish_input_session",
         RemoteWorkspacePolicy::LocalOnly,
     ),
     (
-        "speech_finish_input_session",
+        "speech_get_whisper_cpp_status",
         RemoteWorkspacePolicy::LocalOnly,
     ),
     ("speech_list_models", RemoteWorkspacePolicy::LocalOnly),
+    ("speech_list_voices", RemoteWorkspacePolicy::LocalOnly),
+    ("speech_speak", RemoteWorkspacePolicy::LocalOnly),
     (
         "speech_start_input_session",
         RemoteWorkspacePolicy::LocalOnly,
     ),
+    ("speech_stop_speaking", RemoteWorkspacePolicy::LocalOnly),
     ("speech_verify_model", RemoteWorkspacePolicy::LocalOnly),
     ("ssh_connect", RemoteWorkspacePolicy::WorkspaceAgnostic),
     (
@@ -1611,12 +1919,17 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "start_search_filenames_stream",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    (
+        "start_tool_replay",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("start_subscription_login", RemoteWorkspacePolicy::LocalOnly),
     ("startup_window_control", RemoteWorkspacePolicy::LocalOnly),
     ("steer_dialog_turn", RemoteWorkspacePolicy::LegacyUnaudited),
     ("stop_acp_client", RemoteWorkspacePolicy::LegacyUnaudited),
     ("stop_file_watch", RemoteWorkspacePolicy::LegacyUnaudited),
     ("stop_mcp_server", RemoteWorkspacePolicy::LegacyUnaudited),
+    ("stop_tool_replay", RemoteWorkspacePolicy::WorkspaceAgnostic),
     (
         "submit_acp_permission_response",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1637,6 +1950,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "sync_config_to_global",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("take_pending_deep_links", RemoteWorkspacePolicy::LocalOnly),
     ("terminal_ack", RemoteWorkspacePolicy::RemoteRouted),
     ("terminal_close", RemoteWorkspacePolicy::RemoteRouted),
     ("terminal_create", RemoteWorkspacePolicy::RemoteRouted),
@@ -1678,10 +1992,16 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
     ("unarchive_session", RemoteWorkspacePolicy::LegacyUnaudited),
+    ("undo_file_operation", RemoteWorkspacePolicy::LocalOnly),
+    ("undo_skill_install", RemoteWorkspacePolicy::LocalOnly),
     (
         "update_app_status",
         RemoteWorkspacePolicy::WorkspaceAgnostic,
     ),
+    (
+        "update_background_job",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     ("update_cron_job", RemoteWorkspacePolicy::LegacyUnaudited),
     (
         "update_custom_agent",
@@ -1691,6 +2011,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "update_external_integration_policy_command",
         RemoteWorkspacePolicy::RemoteUnsupported,
     ),
+    ("update_mcp_app_state", RemoteWorkspacePolicy::LocalOnly),
     (
         "update_mcp_remote_auth",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1722,6 +2043,10 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
     ("validate_config", RemoteWorkspacePolicy::LegacyUnaudited),
+    (
+        "validate_mcp_server_preset",
+        RemoteWorkspacePolicy::WorkspaceAgnostic,
+    ),
     (
         "validate_skill_path",
         RemoteWorkspacePolicy::LegacyUnaudited,
@@ -1730,6 +2055,7 @@ pub const REMOTE_WORKSPACE_COMMAND_POLICIES: &[(&str, RemoteWorkspacePolicy)] =
         "validate_tool_input",
         RemoteWorkspacePolicy::LegacyUnaudited,
     ),
+    ("verify_file", RemoteWorkspacePolicy::LocalOnly),
     (
         "webdriver_bridge_result",
         RemoteWorkspacePolicy::LegacyUnaudited,
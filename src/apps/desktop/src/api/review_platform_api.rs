@@ -2,10 +2,13 @@
 
 use crate::api::app_state::AppState;
 use bitfun_core::service::review_platform::{
-    ReviewPlatformCiLog, ReviewPlatformDetailSection, ReviewPlatformError,
-    ReviewPlatformIssueEvidence, ReviewPlatformKind, ReviewPlatformPullRequestDetail,
+    ReviewIssueStateFilter, ReviewPlatformActionResult, ReviewPlatformBranchResult,
+    ReviewPlatformCiLog, ReviewPlatformCreateBranchRequest, ReviewPlatformCreatePullRequestRequest,
+    ReviewPlatformDetailSection, ReviewPlatformError, ReviewPlatformIssueEvidence,
+    ReviewPlatformIssuePage, ReviewPlatformKind, ReviewPlatformPullRequestDetail,
     ReviewPlatformPullRequestDetailPage, ReviewPlatformPullRequestReviewTarget,
-    ReviewPlatformService, ReviewPlatformWorkspaceSnapshot,
+    ReviewPlatformPushBranchRequest, ReviewPlatformReviewComments, ReviewPlatformService,
+    ReviewPlatformWorkspaceSnapshot,
 };
 use log::error;
 use serde::Deserialize;
@@ -56,6 +59,27 @@ pub struct ReviewPlatformPullRequestCiLogRequest {
     pub ci_item_name: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformListIssuesRequest {
+    pub repository_path: String,
+    pub remote_id: Option<String>,
+    #[serde(default)]
+    pub state: ReviewIssueStateFilter,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformReviewCommentsRequest {
+    pub repository_path: String,
+    pub remote_id: String,
+    pub pull_request_id: String,
+    #[serde(default)]
+    pub include_resolved: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewPlatformUpdateAuthTokenRequest {
@@ -292,6 +316,107 @@ pub async fn review_platform_get_pull_request_ci_log(
     })
 }
 
+#[tauri::command]
+pub async fn review_platform_list_issues(
+    _state: State<'_, AppState>,
+    request: ReviewPlatformListIssuesRequest,
+) -> Result<ReviewPlatformIssuePage, String> {
+    ReviewPlatformService::list_issues(
+        &request.repository_path,
+        request.remote_id.as_deref(),
+        request.state,
+        request.page,
+        request.per_page,
+    )
+    .await
+    .map_err(|error| {
+        error!(
+            "Failed to list review platform issues: path={}, remote_id={:?}, state={:?}, error={}",
+            request.repository_path, request.remote_id, request.state, error
+        );
+        format!("Failed to list review platform issues: {}", error)
+    })
+}
+
+#[tauri::command]
+pub async fn review_platform_get_review_comments(
+    _state: State<'_, AppState>,
+    request: ReviewPlatformReviewCommentsRequest,
+) -> Result<ReviewPlatformReviewComments, String> {
+    ReviewPlatformService::pull_request_review_comments(
+        &request.repository_path,
+        &request.remote_id,
+        &request.pull_request_id,
+        request.include_resolved,
+    )
+    .await
+    .map_err(|error| {
+        error!(
+            "Failed to get review platform review comments: path={}, remote_id={}, pull_request_id={}, error={}",
+            request.repository_path,
+            request.remote_id,
+            request.pull_request_id,
+            error
+        );
+        format!("Failed to get review platform review comments: {}", error)
+    })
+}
+
+#[tauri::command]
+pub async fn review_platform_create_branch(
+    _state: State<'_, AppState>,
+    request: ReviewPlatformCreateBranchRequest,
+) -> Result<ReviewPlatformBranchResult, String> {
+    let repository_path = request.repository_path.clone();
+    let branch_name = request.branch_name.clone();
+    ReviewPlatformService::create_branch(request)
+        .await
+        .map_err(|error| {
+            error!(
+                "Failed to create review platform branch: path={}, branch={}, error={}",
+                repository_path, branch_name, error
+            );
+            format!("Failed to create branch: {}", error)
+        })
+}
+
+#[tauri::command]
+pub async fn review_platform_push_branch(
+    _state: State<'_, AppState>,
+    request: ReviewPlatformPushBranchRequest,
+) -> Result<ReviewPlatformBranchResult, String> {
+    let repository_path = request.repository_path.clone();
+    let remote_id = request.remote_id.clone();
+    let branch_name = request.branch_name.clone();
+    ReviewPlatformService::push_branch(request)
+        .await
+        .map_err(|error| {
+            error!(
+                "Failed to push review platform branch: path={}, remote_id={:?}, branch={:?}, error={}",
+                repository_path, remote_id, branch_name, error
+            );
+            format!("Failed to push branch: {}", error)
+        })
+}
+
+#[tauri::command]
+pub async fn review_platform_create_pull_request(
+    _state: State<'_, AppState>,
+    request: ReviewPlatformCreatePullRequestRequest,
+) -> Result<ReviewPlatformActionResult, String> {
+    let repository_path = request.repository_path.clone();
+    let remote_id = request.remote_id.clone();
+    ReviewPlatformService::create_pull_request(request)
+        .await
+        .map_err(|error| {
+            error!(
+                "Failed to create review platform pull request: path={}, remote_id={:?}, error={}",
+                repository_path, remote_id, error
+            );
+            format!("Failed to create pull request: {}", error)
+        })
+}
+
 #[tauri::command]
 pub async fn review_platform_update_auth_token(
     _state: State<'_, AppState>,
@@ -376,6 +501,19 @@ mod tests {
         assert_eq!(request.per_page, Some(100));
     }
 
+    #[test]
+    fn review_platform_list_issues_wire_defaults_to_open_issues() {
+        let request: ReviewPlatformListIssuesRequest = serde_json::from_value(json!({
+            "repositoryPath": "D:/workspace/example",
+            "perPage": 20
+        }))
+        .expect("list issues wire should deserialize");
+
+        assert_eq!(request.state, ReviewIssueStateFilter::Open);
+        assert_eq!(request.remote_id, None);
+        assert_eq!(request.per_page, Some(20));
+    }
+
     #[test]
     fn review_platform_request_wire_deserializes_pull_request_identity_fields() {
         let request: ReviewPlatformPullRequestIdentityRequest = serde_json::from_value(json!({
@@ -1,13 +1,145 @@
 //! Runtime capability API
 
 use crate::api::app_state::AppState;
-use bitfun_core::service::runtime::{RuntimeCommandCapability, RuntimeManager};
-use tauri::State;
+use bitfun_core::service::i18n::{localized_error, TranslationArgs};
+use bitfun_core::service::operations::{operation_registry, OperationKind};
+use bitfun_core::service::runtime::provisioning::{
+    get_global_runtime_provisioner, RuntimeInstallOptions, RuntimeProvisioningPlan,
+    RuntimeProvisioningProgress, RuntimeProvisioningResult,
+};
+use bitfun_core::service::runtime::{
+    CommandDiagnosis, RuntimeCommandCapability, RuntimeCommandUsage, RuntimeManager,
+};
+use bitfun_core::util::errors::{ApiError, ApiErrorCode, BitFunError};
+use bitfun_transport::emit_journaled;
+use serde::Deserialize;
+use tauri::{AppHandle, State};
+
+pub const RUNTIME_PROVISIONING_PROGRESS_EVENT: &str = "runtime://provisioning-progress";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnoseRuntimeCommandRequest {
+    pub command: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallRuntimeComponentsRequest {
+    pub components: Vec<String>,
+    /// Lets the caller cancel through `cancel_operation` while the install
+    /// is still running.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+    /// Waits for the user to go idle before downloading, per the power settings.
+    #[serde(default)]
+    pub defer_until_idle: bool,
+    /// Speed cap for this install, adjustable later with `set_download_speed_limit`.
+    #[serde(default)]
+    pub max_bytes_per_sec: Option<u64>,
+}
+
+fn runtime_unavailable(error: BitFunError) -> ApiError {
+    ApiError::service_unavailable(error.to_string())
+}
 
 #[tauri::command]
 pub async fn get_runtime_capabilities(
     _state: State<'_, AppState>,
-) -> Result<Vec<RuntimeCommandCapability>, String> {
-    let manager = RuntimeManager::new().map_err(|e| e.to_string())?;
+) -> Result<Vec<RuntimeCommandCapability>, ApiError> {
+    let manager = RuntimeManager::new().map_err(runtime_unavailable)?;
     Ok(manager.get_capabilities())
 }
+
+#[tauri::command]
+pub async fn diagnose_runtime_command(
+    _state: State<'_, AppState>,
+    request: DiagnoseRuntimeCommandRequest,
+) -> Result<CommandDiagnosis, ApiError> {
+    let command = request.command.trim();
+    if command.is_empty() {
+        return Err(ApiError::invalid_input("Command name is required"));
+    }
+    let manager = RuntimeManager::new().map_err(runtime_unavailable)?;
+    Ok(manager.diagnose_command(command).await)
+}
+
+#[tauri::command]
+pub async fn get_runtime_usage(
+    _state: State<'_, AppState>,
+) -> Result<Vec<RuntimeCommandUsage>, ApiError> {
+    let manager = RuntimeManager::new().map_err(runtime_unavailable)?;
+    Ok(manager.get_usage())
+}
+
+#[tauri::command]
+pub async fn get_runtime_provisioning_plan(
+    _state: State<'_, AppState>,
+) -> Result<RuntimeProvisioningPlan, ApiError> {
+    let provisioner = get_global_runtime_provisioner().map_err(runtime_unavailable)?;
+    provisioner
+        .plan()
+        .await
+        .map_err(|e| ApiError::from(e).with_context("Failed to build runtime provisioning plan"))
+}
+
+#[tauri::command]
+pub async fn install_runtime_components(
+    _state: State<'_, AppState>,
+    app: AppHandle,
+    request: InstallRuntimeComponentsRequest,
+) -> Result<RuntimeProvisioningResult, ApiError> {
+    let provisioner = get_global_runtime_provisioner().map_err(runtime_unavailable)?;
+    let operation = operation_registry().begin(
+        request.operation_id,
+        OperationKind::RuntimeInstall,
+        request.components.join(", "),
+    )?;
+    let operation_id = operation.id().to_string();
+    let options = RuntimeInstallOptions {
+        defer_until_idle: request.defer_until_idle,
+        operation_id: Some(operation_id.clone()),
+        max_bytes_per_sec: request.max_bytes_per_sec,
+    };
+    let result = provisioner
+        .install(
+            &request.components,
+            operation.token().clone(),
+            options,
+            move |progress: RuntimeProvisioningProgress| {
+                let payload = serde_json::to_value(&progress).unwrap_or_default();
+                if let Err(error) = emit_journaled(
+                    &app,
+                    RUNTIME_PROVISIONING_PROGRESS_EVENT,
+                    Some(&operation_id),
+                    payload,
+                ) {
+                    log::warn!("Failed to emit runtime provisioning progress event: {error}");
+                }
+            },
+        )
+        .await;
+    match result {
+        Ok(result) => Ok(result),
+        Err(error) => {
+            let error = ApiError::from(error);
+            if error.code == ApiErrorCode::Cancelled {
+                return Err(error.with_context("Failed to install runtime components"));
+            }
+            Err(localized_error(
+                error.code,
+                "runtime-install-failed",
+                TranslationArgs::new()
+                    .with_string("component", request.components.join(", "))
+                    .with_string("reason", error.message),
+            )
+            .await)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn cancel_runtime_provisioning(_state: State<'_, AppState>) -> Result<bool, ApiError> {
+    let provisioner = get_global_runtime_provisioner().map_err(runtime_unavailable)?;
+    Ok(provisioner.cancel())
+}
@@ -0,0 +1,434 @@
+//! Screen capture API
+//!
+//! Captures a BitFun window or an area of a display after the user confirms a
+//! native prompt, and stores the PNG in the artifact store so the UI can
+//! attach it to the session as image context.
+
+use crate::computer_use::DesktopComputerUseHost;
+use bitfun_core::agentic::tools::computer_use_host::ComputerUseHost;
+use bitfun_core::infrastructure::try_get_path_manager_arc;
+use bitfun_core::service::artifacts::{
+    get_global_artifact_service, ArtifactRecord, RegisterArtifactRequest,
+};
+use bitfun_core::service::i18n::{localize, TranslationArgs};
+use image::{ImageFormat, RgbaImage};
+use log::{info, warn};
+use screenshots::Screen;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+const DEFAULT_WINDOW_LABEL: &str = "main";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureDisplay {
+    pub id: u32,
+    /// 1-based position in the system display list, used in prompts.
+    pub index: usize,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f32,
+    pub is_primary: bool,
+}
+
+/// Area in global logical coordinates (points), as reported by
+/// [`list_capture_displays`].
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureWindowRequest {
+    pub session_id: String,
+    #[serde(default)]
+    pub task_id: Option<String>,
+    /// BitFun window label; the main window when omitted.
+    #[serde(default)]
+    pub window_label: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureRegionRequest {
+    pub session_id: String,
+    #[serde(default)]
+    pub task_id: Option<String>,
+    /// Display to capture; the one showing the main window when omitted.
+    #[serde(default)]
+    pub display_id: Option<u32>,
+    /// Area to capture; the whole display when omitted.
+    #[serde(default)]
+    pub region: Option<CaptureRect>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenCaptureResult {
+    /// `None` when the user declined the prompt.
+    pub artifact: Option<ArtifactRecord>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScreenCaptureResult {
+    fn declined() -> Self {
+        Self {
+            artifact: None,
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn list_capture_displays() -> Result<Vec<CaptureDisplay>, String> {
+    tokio::task::spawn_blocking(all_displays)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Captures a BitFun window. Other applications are captured through
+/// [`capture_region`].
+#[tauri::command]
+pub async fn capture_window(
+    app: AppHandle,
+    request: CaptureWindowRequest,
+) -> Result<ScreenCaptureResult, String> {
+    let label = request
+        .window_label
+        .as_deref()
+        .unwrap_or(DEFAULT_WINDOW_LABEL);
+    let window = app
+        .get_webview_window(label)
+        .ok_or_else(|| format!("Window not found: {}", label))?;
+    let rect = window_rect(&window)?;
+    let title = window.title().unwrap_or_else(|_| label.to_string());
+
+    let message = localize(
+        "screen-capture-confirm-window",
+        Some(TranslationArgs::new().with_string("target", title)),
+    )
+    .await
+    .text;
+    if !confirm_capture(&app, message).await {
+        return Ok(ScreenCaptureResult::declined());
+    }
+    ensure_screen_capture_permission().await?;
+
+    let image = tokio::task::spawn_blocking(move || capture_rect(None, rect))
+        .await
+        .map_err(|e| e.to_string())??;
+    store_capture(image, &request.session_id, request.task_id, "window").await
+}
+
+#[tauri::command]
+pub async fn capture_region(
+    app: AppHandle,
+    request: CaptureRegionRequest,
+) -> Result<ScreenCaptureResult, String> {
+    let displays = tokio::task::spawn_blocking(all_displays)
+        .await
+        .map_err(|e| e.to_string())??;
+    let display = match request.display_id {
+        Some(id) => displays.iter().find(|display| display.id == id),
+        None => app
+            .get_webview_window(DEFAULT_WINDOW_LABEL)
+            .and_then(|window| window_rect(&window).ok())
+            .and_then(|rect| display_containing(&displays, rect))
+            .or_else(|| displays.iter().find(|display| display.is_primary)),
+    }
+    .or_else(|| displays.first())
+    .cloned()
+    .ok_or_else(|| "No display available to capture".to_string())?;
+
+    let display_rect = CaptureRect {
+        x: display.x,
+        y: display.y,
+        width: display.width,
+        height: display.height,
+    };
+    let rect = match request.region {
+        Some(region) => intersect(region, display_rect)
+            .ok_or_else(|| "Capture region lies outside the display".to_string())?,
+        None => display_rect,
+    };
+
+    let args = TranslationArgs::new().with_number("index", display.index as f64);
+    let target = if request.region.is_some() {
+        localize(
+            "screen-capture-target-region",
+            Some(
+                args.with_number("width", rect.width as f64)
+                    .with_number("height", rect.height as f64),
+            ),
+        )
+        .await
+    } else {
+        localize("screen-capture-target-display", Some(args)).await
+    };
+    let message = localize(
+        "screen-capture-confirm-region",
+        Some(TranslationArgs::new().with_string("target", target.text)),
+    )
+    .await
+    .text;
+    if !confirm_capture(&app, message).await {
+        return Ok(ScreenCaptureResult::declined());
+    }
+    ensure_screen_capture_permission().await?;
+
+    let display_id = display.id;
+    let image = tokio::task::spawn_blocking(move || capture_rect(Some(display_id), rect))
+        .await
+        .map_err(|e| e.to_string())??;
+    store_capture(image, &request.session_id, request.task_id, "screen").await
+}
+
+fn all_displays() -> Result<Vec<CaptureDisplay>, String> {
+    let screens = Screen::all().map_err(|e| format!("Failed to list displays: {}", e))?;
+    Ok(screens
+        .into_iter()
+        .enumerate()
+        .map(|(index, screen)| {
+            let info = screen.display_info;
+            CaptureDisplay {
+                id: info.id,
+                index: index + 1,
+                x: info.x,
+                y: info.y,
+                width: info.width,
+                height: info.height,
+                scale_factor: info.scale_factor,
+                is_primary: info.is_primary,
+            }
+        })
+        .collect())
+}
+
+fn window_rect(window: &tauri::WebviewWindow) -> Result<CaptureRect, String> {
+    let scale = window.scale_factor().map_err(|e| e.to_string())?;
+    let position = window
+        .outer_position()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale);
+    let size = window
+        .outer_size()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale);
+    Ok(CaptureRect {
+        x: position.x.round() as i32,
+        y: position.y.round() as i32,
+        width: size.width.round().max(1.0) as u32,
+        height: size.height.round().max(1.0) as u32,
+    })
+}
+
+/// Display holding the centre of `rect`.
+fn display_containing(displays: &[CaptureDisplay], rect: CaptureRect) -> Option<&CaptureDisplay> {
+    let cx = rect.x as i64 + rect.width as i64 / 2;
+    let cy = rect.y as i64 + rect.height as i64 / 2;
+    displays.iter().find(|display| {
+        let x = display.x as i64;
+        let y = display.y as i64;
+        cx >= x && cx < x + display.width as i64 && cy >= y && cy < y + display.height as i64
+    })
+}
+
+fn intersect(a: CaptureRect, b: CaptureRect) -> Option<CaptureRect> {
+    let x0 = a.x.max(b.x);
+    let y0 = a.y.max(b.y);
+    let x1 = (a.x as i64 + a.width as i64).min(b.x as i64 + b.width as i64);
+    let y1 = (a.y as i64 + a.height as i64).min(b.y as i64 + b.height as i64);
+    if x1 <= x0 as i64 || y1 <= y0 as i64 {
+        return None;
+    }
+    Some(CaptureRect {
+        x: x0,
+        y: y0,
+        width: (x1 - x0 as i64) as u32,
+        height: (y1 - y0 as i64) as u32,
+    })
+}
+
+/// Captures `rect` (global points) from `display_id`, or from the display
+/// under the rect's centre. The part outside that display is dropped.
+fn capture_rect(display_id: Option<u32>, rect: CaptureRect) -> Result<RgbaImage, String> {
+    let screens = Screen::all().map_err(|e| format!("Failed to list displays: {}", e))?;
+    let cx = rect.x + rect.width as i32 / 2;
+    let cy = rect.y + rect.height as i32 / 2;
+    let screen = match display_id {
+        Some(id) => screens
+            .into_iter()
+            .find(|screen| screen.display_info.id == id),
+        None => Screen::from_point(cx, cy).ok(),
+    }
+    .ok_or_else(|| "Display not found for capture".to_string())?;
+
+    let info = screen.display_info;
+    let display_rect = CaptureRect {
+        x: info.x,
+        y: info.y,
+        width: info.width,
+        height: info.height,
+    };
+    let area = intersect(rect, display_rect)
+        .ok_or_else(|| "Capture area lies outside the display".to_string())?;
+    screen
+        .capture_area(area.x - info.x, area.y - info.y, area.width, area.height)
+        .map_err(|e| format!("Screen capture failed: {}", e))
+}
+
+async fn confirm_capture(app: &AppHandle, message: String) -> bool {
+    let title = localize("screen-capture-confirm-title", None).await.text;
+    let allow = localize("screen-capture-allow", None).await.text;
+    let deny = localize("screen-capture-deny", None).await.text;
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .message(message)
+        .title(title)
+        .kind(MessageDialogKind::Info)
+        .buttons(MessageDialogButtons::OkCancelCustom(allow, deny))
+        .show(move |confirmed| {
+            let _ = tx.send(confirmed);
+        });
+    let confirmed = rx.await.unwrap_or(false);
+    if !confirmed {
+        info!("Screen capture declined by user");
+    }
+    confirmed
+}
+
+/// On macOS a capture without Screen Recording permission silently returns
+/// only the desktop wallpaper, so check first and surface the system prompt.
+async fn ensure_screen_capture_permission() -> Result<(), String> {
+    let host = DesktopComputerUseHost::new();
+    let granted = match host.permission_snapshot().await {
+        Ok(snapshot) => snapshot.screen_capture_granted,
+        Err(e) => {
+            warn!("Failed to check screen capture permission: {}", e);
+            true
+        }
+    };
+    if granted {
+        return Ok(());
+    }
+    host.prompt_for_missing_permissions();
+    Err(localize("screen-capture-permission-missing", None)
+        .await
+        .text)
+}
+
+async fn store_capture(
+    image: RgbaImage,
+    session_id: &str,
+    task_id: Option<String>,
+    kind: &str,
+) -> Result<ScreenCaptureResult, String> {
+    let service = get_global_artifact_service()
+        .ok_or_else(|| "Artifact service is not initialized".to_string())?;
+    let path_manager = try_get_path_manager_arc().map_err(|e| e.to_string())?;
+    let (width, height) = image.dimensions();
+    let name = format!(
+        "{}-{}.png",
+        kind,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+
+    let temp_dir = path_manager.temp_dir().join("screen-capture");
+    tokio::fs::create_dir_all(&temp_dir)
+        .await
+        .map_err(|e| format!("Failed to prepare capture directory: {}", e))?;
+    let temp_path = temp_dir.join(format!("{}.png", uuid::Uuid::new_v4()));
+    let encode_path = temp_path.clone();
+    tokio::task::spawn_blocking(move || image.save_with_format(&encode_path, ImageFormat::Png))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to encode capture: {}", e))?;
+
+    let result = service
+        .register(RegisterArtifactRequest {
+            session_id: session_id.to_string(),
+            task_id,
+            source_path: temp_path.to_string_lossy().to_string(),
+            name: Some(name),
+        })
+        .await;
+    if let Err(e) = tokio::fs::remove_file(&temp_path).await {
+        warn!(
+            "Failed to remove temporary capture: path={}, error={}",
+            temp_path.display(),
+            e
+        );
+    }
+    let artifact = result.map_err(|e| format!("Failed to store capture: {}", e))?;
+    info!(
+        "Screen capture stored: artifact_id={}, session_id={}, size={}x{}",
+        artifact.id, artifact.session_id, width, height
+    );
+    Ok(ScreenCaptureResult {
+        artifact: Some(artifact),
+        width,
+        height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> CaptureRect {
+        CaptureRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn intersect_clips_to_display() {
+        let display = rect(0, 0, 1920, 1080);
+        let clipped = intersect(rect(1800, 1000, 400, 400), display).unwrap();
+        assert_eq!((clipped.x, clipped.y), (1800, 1000));
+        assert_eq!((clipped.width, clipped.height), (120, 80));
+        assert!(intersect(rect(-500, 0, 400, 100), display).is_none());
+    }
+
+    #[test]
+    fn display_containing_uses_rect_centre() {
+        let displays = vec![
+            CaptureDisplay {
+                id: 1,
+                index: 1,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                scale_factor: 1.0,
+                is_primary: true,
+            },
+            CaptureDisplay {
+                id: 2,
+                index: 2,
+                x: 1920,
+                y: 0,
+                width: 1280,
+                height: 1024,
+                scale_factor: 2.0,
+                is_primary: false,
+            },
+        ];
+        let found = display_containing(&displays, rect(1800, 100, 600, 400)).unwrap();
+        assert_eq!(found.id, 2);
+        assert!(display_containing(&displays, rect(5000, 0, 10, 10)).is_none());
+    }
+}
@@ -137,6 +137,7 @@ pub(crate) async fn search_file_contents_via_workspace_search(
     use_regex: bool,
     whole_word: bool,
     max_results: usize,
+    globs: Vec<String>,
 ) -> Result<bitfun_core::service::search::ContentSearchResult, String> {
     search_content_request_via_workspace_search(
         state,
@@ -147,11 +148,27 @@ pub(crate) async fn search_file_contents_via_workspace_search(
             use_regex,
             whole_word,
             max_results,
+            globs,
         ),
     )
     .await
 }
 
+/// Merges include/exclude globs into ripgrep-style globs (`!` excludes).
+pub(crate) fn search_globs(include_globs: &[String], exclude_globs: &[String]) -> Vec<String> {
+    let include = include_globs
+        .iter()
+        .map(|glob| glob.trim())
+        .filter(|glob| !glob.is_empty())
+        .map(str::to_string);
+    let exclude = exclude_globs
+        .iter()
+        .map(|glob| glob.trim().trim_start_matches('!'))
+        .filter(|glob| !glob.is_empty())
+        .map(|glob| format!("!{}", glob));
+    include.chain(exclude).collect()
+}
+
 pub(crate) fn build_content_search_request(
     root_path: &str,
     pattern: &str,
@@ -159,6 +176,7 @@ pub(crate) fn build_content_search_request(
     use_regex: bool,
     whole_word: bool,
     max_results: usize,
+    globs: Vec<String>,
 ) -> ContentSearchRequest {
     ContentSearchRequest {
         repo_root: root_path.into(),
@@ -172,7 +190,7 @@ pub(crate) fn build_content_search_request(
         before_context: 0,
         after_context: 0,
         max_results: Some(max_results),
-        globs: Vec::new(),
+        globs,
         file_types: Vec::new(),
         exclude_file_types: Vec::new(),
     }
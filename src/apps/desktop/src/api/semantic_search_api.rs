@@ -0,0 +1,84 @@
+//! Semantic search API - embeddings-backed workspace retrieval.
+
+use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
+use bitfun_core::service::semantic_search::{
+    get_semantic_search_service, SemanticIndexReport, SemanticIndexStatus, SemanticSearchResult,
+};
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchRequest {
+    pub workspace_path: String,
+    pub query: String,
+    #[serde(default)]
+    pub k: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticIndexRequest {
+    pub workspace_path: String,
+}
+
+#[tauri::command]
+pub async fn semantic_search(
+    request: SemanticSearchRequest,
+) -> Result<SemanticSearchResult, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_semantic_search_service()
+        .await
+        .map_err(|e| e.to_string())?
+        .semantic_search(
+            Path::new(&request.workspace_path),
+            &request.query,
+            request.k.unwrap_or_default(),
+        )
+        .await
+        .map_err(|e| format!("Semantic search failed: {}", e))
+}
+
+#[tauri::command]
+pub async fn semantic_index_workspace(
+    request: SemanticIndexRequest,
+) -> Result<SemanticIndexReport, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_semantic_search_service()
+        .await
+        .map_err(|e| e.to_string())?
+        .index_workspace(Path::new(&request.workspace_path))
+        .await
+        .map_err(|e| format!("Failed to index workspace: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_semantic_index_status(
+    request: SemanticIndexRequest,
+) -> Result<SemanticIndexStatus, String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_semantic_search_service()
+        .await
+        .map_err(|e| e.to_string())?
+        .status(Path::new(&request.workspace_path))
+        .await
+        .map_err(|e| format!("Failed to read semantic index status: {}", e))
+}
+
+#[tauri::command]
+pub async fn clear_semantic_index(request: SemanticIndexRequest) -> Result<(), String> {
+    ensure_local_workspace(&request.workspace_path).await?;
+    get_semantic_search_service()
+        .await
+        .map_err(|e| e.to_string())?
+        .clear(Path::new(&request.workspace_path))
+        .await
+        .map_err(|e| format!("Failed to clear semantic index: {}", e))
+}
+
+async fn ensure_local_workspace(workspace_path: &str) -> Result<(), String> {
+    if is_remote_path(workspace_path).await {
+        return Err("Semantic search is only available for local workspaces".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! Service health API.
+
+use bitfun_core::agentic::tools::implementations::skills::SkillRegistry;
+use bitfun_core::infrastructure::get_path_manager_arc;
+use bitfun_core::service::service_health::{
+    service_health_registry, ServiceHealthSnapshot, ServiceKind,
+};
+use log::warn;
+use tauri::Emitter;
+
+/// Emitted with a `ServiceHealthSnapshot` payload whenever a service reports a
+/// new status.
+pub const SERVICE_HEALTH_CHANGED_EVENT: &str = "service_health_changed";
+
+#[tauri::command]
+pub async fn get_service_health() -> Result<ServiceHealthSnapshot, String> {
+    Ok(service_health_registry().snapshot())
+}
+
+/// Forwards status changes to the frontend and loads skills so an unreadable
+/// skills directory shows up at startup rather than on first use.
+pub fn start_service_health_monitor(app_handle: tauri::AppHandle) {
+    let mut receiver = service_health_registry().subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(snapshot) => {
+                    if let Err(e) = app_handle.emit(SERVICE_HEALTH_CHANGED_EVENT, &snapshot) {
+                        warn!("Failed to emit service health event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Service health event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+
+    tokio::spawn(async {
+        SkillRegistry::global().refresh().await;
+        let skills_dir = get_path_manager_arc().user_skills_dir();
+        match tokio::fs::read_dir(&skills_dir).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                service_health_registry().report_degraded(
+                    ServiceKind::Skills,
+                    format!(
+                        "User skills directory {} is unreadable: {}",
+                        skills_dir.display(),
+                        e
+                    ),
+                );
+            }
+            _ => service_health_registry().report_initialized(ServiceKind::Skills),
+        }
+    });
+}
@@ -7,18 +7,27 @@ use crate::runtime::{
     UiSessionMetadataField,
 };
 use crate::startup_trace::DesktopStartupTrace;
+use bitfun_agent_tools::{ToolReplayBundle, ToolReplayStatus, TOOL_REPLAY_BUNDLE_SCHEMA_VERSION};
 use bitfun_core::agentic::coordination::get_global_scheduler;
 use bitfun_core::agentic::persistence::{
     PersistenceManager, SessionBranchResult, SessionMetadataPage,
 };
+use bitfun_core::agentic::tools::pipeline;
 use bitfun_core::infrastructure::PathManager;
+use bitfun_core::service::artifacts::get_global_artifact_service;
+use bitfun_core::service::docconv::{
+    get_global_document_conversion_service, DocumentConversionKind, DocumentConversionRequest,
+};
 use bitfun_core::service::remote_ssh::normalize_remote_workspace_path;
 use bitfun_core::service::session::{
-    DialogTurnData, SessionKind, SessionMetadata, SessionStatus, SessionTranscriptExport,
+    render_session_report_html, DialogTurnData, SessionExportFormat, SessionKind, SessionMetadata,
+    SessionReportArtifact, SessionReportFormat, SessionReportOptions, SessionRetentionPlan,
+    SessionRetentionPolicy, SessionSearchHit, SessionStatus, SessionTranscriptExport,
     SessionTranscriptExportOptions,
 };
 use bitfun_core::service::session_usage::SessionUsageReport;
 use bitfun_core::service::workspace::WorkspaceKind;
+use log::warn;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Instant;
@@ -238,6 +247,106 @@ pub struct DeleteAllArchivedSessionsRequest {
     pub remote_ssh_host: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SearchSessionHistoryRequest {
+    pub workspace_path: String,
+    pub query: String,
+    #[serde(default = "default_session_history_search_limit")]
+    pub limit: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+}
+
+fn default_session_history_search_limit() -> usize {
+    50
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportSessionRequest {
+    pub session_id: String,
+    pub workspace_path: String,
+    pub format: SessionExportFormat,
+    /// Destination file chosen by the user in the save dialog.
+    pub target_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSessionResponse {
+    pub path: String,
+    pub bytes: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportSessionReportRequest {
+    pub session_id: String,
+    pub workspace_path: String,
+    pub format: SessionReportFormat,
+    #[serde(default)]
+    pub options: SessionReportOptions,
+    /// Destination file chosen by the user in the save dialog.
+    pub target_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSessionReportResponse {
+    pub path: String,
+    pub bytes: u64,
+    /// Secrets replaced with placeholders in the written report.
+    pub redaction_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportReplayBundleRequest {
+    pub session_id: String,
+    pub workspace_path: String,
+    /// Destination file chosen by the user in the save dialog.
+    pub target_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReplayBundleResponse {
+    pub path: String,
+    pub invocations: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StartToolReplayRequest {
+    pub session_id: String,
+    /// Replay bundle previously written by `export_replay_bundle`.
+    pub bundle_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StopToolReplayRequest {
+    pub session_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApplySessionRetentionRequest {
+    pub workspace_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+}
+
 #[tauri::command]
 pub async fn list_persisted_sessions(
     request: ListPersistedSessionsRequest,
@@ -762,3 +871,305 @@ pub async fn delete_all_archived_sessions(
 
     Ok(deleted_count)
 }
+
+/// Full-text search over titles, user messages, assistant text and tool calls.
+#[tauri::command]
+pub async fn search_session_history(
+    request: SearchSessionHistoryRequest,
+    app_state: State<'_, AppState>,
+    path_manager: State<'_, Arc<PathManager>>,
+) -> Result<Vec<SessionSearchHit>, String> {
+    if request.query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let workspace_path = desktop_effective_session_storage_path(
+        &app_state,
+        &request.workspace_path,
+        request.remote_connection_id.as_deref(),
+        request.remote_ssh_host.as_deref(),
+    )
+    .await;
+    let manager = PersistenceManager::new(path_manager.inner().clone())
+        .map_err(|e| format!("Failed to create persistence manager: {}", e))?;
+
+    manager
+        .search_sessions(&workspace_path, &request.query, request.limit)
+        .await
+        .map_err(|e| format!("Failed to search session history: {}", e))
+}
+
+#[tauri::command]
+pub async fn export_session(
+    request: ExportSessionRequest,
+    app_state: State<'_, AppState>,
+    path_manager: State<'_, Arc<PathManager>>,
+) -> Result<ExportSessionResponse, String> {
+    let workspace_path = desktop_effective_session_storage_path(
+        &app_state,
+        &request.workspace_path,
+        request.remote_connection_id.as_deref(),
+        request.remote_ssh_host.as_deref(),
+    )
+    .await;
+    let manager = PersistenceManager::new(path_manager.inner().clone())
+        .map_err(|e| format!("Failed to create persistence manager: {}", e))?;
+
+    let content = manager
+        .export_session(&workspace_path, &request.session_id, request.format)
+        .await
+        .map_err(|e| format!("Failed to export session: {}", e))?;
+
+    let mut target = std::path::PathBuf::from(&request.target_path);
+    if target.extension().is_none() {
+        target.set_extension(request.format.file_extension());
+    }
+    tokio::fs::write(&target, content.as_bytes())
+        .await
+        .map_err(|e| format!("Failed to write session export: {}", e))?;
+
+    Ok(ExportSessionResponse {
+        path: target.to_string_lossy().to_string(),
+        bytes: content.len(),
+    })
+}
+
+/// Writes a shareable report of a session or one of its tasks. PDF reports
+/// are rendered as HTML first and converted by LibreOffice.
+#[tauri::command]
+pub async fn export_session_report(
+    request: ExportSessionReportRequest,
+    app_state: State<'_, AppState>,
+    path_manager: State<'_, Arc<PathManager>>,
+) -> Result<ExportSessionReportResponse, String> {
+    let workspace_path = desktop_effective_session_storage_path(
+        &app_state,
+        &request.workspace_path,
+        request.remote_connection_id.as_deref(),
+        request.remote_ssh_host.as_deref(),
+    )
+    .await;
+    let manager = PersistenceManager::new(path_manager.inner().clone())
+        .map_err(|e| format!("Failed to create persistence manager: {}", e))?;
+
+    let artifacts = if request.options.include_artifacts {
+        session_report_artifacts(&request.session_id).await
+    } else {
+        Vec::new()
+    };
+    let report = manager
+        .render_session_report(
+            &workspace_path,
+            &request.session_id,
+            &artifacts,
+            &request.options,
+        )
+        .await
+        .map_err(|e| format!("Failed to render session report: {}", e))?;
+
+    let mut target = std::path::PathBuf::from(&request.target_path);
+    if target.extension().is_none() {
+        target.set_extension(request.format.file_extension());
+    }
+    match request.format {
+        SessionReportFormat::Markdown => tokio::fs::write(&target, report.markdown.as_bytes())
+            .await
+            .map_err(|e| format!("Failed to write session report: {}", e))?,
+        SessionReportFormat::Html => {
+            let html = render_session_report_html(&report.title, &report.markdown);
+            tokio::fs::write(&target, html.as_bytes())
+                .await
+                .map_err(|e| format!("Failed to write session report: {}", e))?
+        }
+        SessionReportFormat::Pdf => {
+            let html = render_session_report_html(&report.title, &report.markdown);
+            target = convert_session_report_to_pdf(&path_manager, &html, &target).await?;
+        }
+    }
+
+    let bytes = tokio::fs::metadata(&target)
+        .await
+        .map(|metadata| metadata.len())
+        .unwrap_or_default();
+    Ok(ExportSessionReportResponse {
+        path: target.to_string_lossy().to_string(),
+        bytes,
+        redaction_count: report.redaction_count,
+    })
+}
+
+async fn session_report_artifacts(session_id: &str) -> Vec<SessionReportArtifact> {
+    let Some(service) = get_global_artifact_service() else {
+        return Vec::new();
+    };
+    match service.list(Some(session_id)).await {
+        Ok(records) => records
+            .into_iter()
+            .map(|record| SessionReportArtifact {
+                name: record.name,
+                kind: record.kind.as_str().to_string(),
+                mime_type: record.mime_type,
+                size_bytes: record.size_bytes,
+                task_id: record.task_id,
+            })
+            .collect(),
+        Err(e) => {
+            warn!(
+                "Failed to list artifacts for session report: session_id={}, error={}",
+                session_id, e
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// Converts the report HTML to a PDF at `target` and returns the PDF's path.
+async fn convert_session_report_to_pdf(
+    path_manager: &PathManager,
+    html: &str,
+    target: &std::path::Path,
+) -> Result<std::path::PathBuf, String> {
+    let service = get_global_document_conversion_service()
+        .ok_or_else(|| "Document conversion service is not initialized".to_string())?;
+    let output_dir = target
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .unwrap_or_default();
+    let stem = target
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "report".to_string());
+
+    // The converter names its output after the input, so the HTML gets the
+    // target's stem in a directory of its own.
+    let staging_dir = path_manager
+        .document_conversion_temp_dir()
+        .join(format!("report-{}", uuid::Uuid::new_v4()));
+    tokio::fs::create_dir_all(&staging_dir)
+        .await
+        .map_err(|e| format!("Failed to prepare session report: {}", e))?;
+    let html_path = staging_dir.join(format!("{}.html", stem));
+    let result = match tokio::fs::write(&html_path, html.as_bytes()).await {
+        Ok(()) => {
+            let mut conversion =
+                DocumentConversionRequest::new(DocumentConversionKind::HtmlToPdf, &html_path);
+            conversion.output_dir = Some(output_dir);
+            service
+                .convert(conversion)
+                .await
+                .map_err(|e| format!("Failed to convert session report to PDF: {}", e))
+        }
+        Err(e) => Err(format!("Failed to prepare session report: {}", e)),
+    };
+    if let Err(e) = tokio::fs::remove_dir_all(&staging_dir).await {
+        warn!(
+            "Failed to remove session report staging directory: path={}, error={}",
+            staging_dir.display(),
+            e
+        );
+    }
+
+    result?
+        .outputs
+        .into_iter()
+        .next()
+        .ok_or_else(|| "PDF conversion produced no output".to_string())
+}
+
+/// Writes the session's recorded tool invocations to a replay bundle file
+/// that can be attached to a bug report.
+#[tauri::command]
+pub async fn export_replay_bundle(
+    request: ExportReplayBundleRequest,
+    app_state: State<'_, AppState>,
+    path_manager: State<'_, Arc<PathManager>>,
+) -> Result<ExportReplayBundleResponse, String> {
+    let workspace_path = desktop_effective_session_storage_path(
+        &app_state,
+        &request.workspace_path,
+        request.remote_connection_id.as_deref(),
+        request.remote_ssh_host.as_deref(),
+    )
+    .await;
+    let manager = PersistenceManager::new(path_manager.inner().clone())
+        .map_err(|e| format!("Failed to create persistence manager: {}", e))?;
+
+    let bundle = manager
+        .export_tool_replay_bundle(&workspace_path, &request.session_id)
+        .await
+        .map_err(|e| format!("Failed to export replay bundle: {}", e))?;
+    let content = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| format!("Failed to serialize replay bundle: {}", e))?;
+
+    let mut target = std::path::PathBuf::from(&request.target_path);
+    if target.extension().is_none() {
+        target.set_extension("json");
+    }
+    tokio::fs::write(&target, content.as_bytes())
+        .await
+        .map_err(|e| format!("Failed to write replay bundle: {}", e))?;
+
+    Ok(ExportReplayBundleResponse {
+        path: target.to_string_lossy().to_string(),
+        invocations: bundle.invocations.len(),
+    })
+}
+
+/// Puts a session into replay mode: tool calls are answered from the bundle
+/// instead of being executed.
+#[tauri::command]
+pub async fn start_tool_replay(
+    request: StartToolReplayRequest,
+) -> Result<ToolReplayStatus, String> {
+    let content = tokio::fs::read_to_string(&request.bundle_path)
+        .await
+        .map_err(|e| format!("Failed to read replay bundle: {}", e))?;
+    let bundle: ToolReplayBundle =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid replay bundle: {}", e))?;
+    if bundle.schema_version > TOOL_REPLAY_BUNDLE_SCHEMA_VERSION {
+        return Err(format!(
+            "Unsupported replay bundle schema version: {}",
+            bundle.schema_version
+        ));
+    }
+    Ok(pipeline::start_tool_replay(&request.session_id, bundle))
+}
+
+#[tauri::command]
+pub async fn stop_tool_replay(
+    request: StopToolReplayRequest,
+) -> Result<Option<ToolReplayStatus>, String> {
+    Ok(pipeline::stop_tool_replay(&request.session_id))
+}
+
+/// Applies the `app.session_retention` policy to one workspace. A disabled
+/// policy returns an empty plan without touching any session.
+#[tauri::command]
+pub async fn apply_session_retention(
+    request: ApplySessionRetentionRequest,
+    app_state: State<'_, AppState>,
+    path_manager: State<'_, Arc<PathManager>>,
+) -> Result<SessionRetentionPlan, String> {
+    let policy = app_state
+        .config_service
+        .get_config::<SessionRetentionPolicy>(Some("app.session_retention"))
+        .await
+        .unwrap_or_default();
+    if !policy.is_enabled() {
+        return Ok(SessionRetentionPlan::default());
+    }
+
+    let workspace_path = desktop_effective_session_storage_path(
+        &app_state,
+        &request.workspace_path,
+        request.remote_connection_id.as_deref(),
+        request.remote_ssh_host.as_deref(),
+    )
+    .await;
+    let manager = PersistenceManager::new(path_manager.inner().clone())
+        .map_err(|e| format!("Failed to create persistence manager: {}", e))?;
+
+    manager
+        .apply_session_retention(&workspace_path, &policy)
+        .await
+        .map_err(|e| format!("Failed to apply session retention: {}", e))
+}
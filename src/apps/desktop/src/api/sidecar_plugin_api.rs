@@ -0,0 +1,64 @@
+//! Sidecar plugin API.
+
+use bitfun_core::service::sidecar_plugins::{
+    get_global_sidecar_plugin_service, SidecarPluginInfo, SidecarPluginList, SidecarPluginService,
+};
+use bitfun_core::util::errors::ApiError;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSidecarPluginEnabledRequest {
+    pub plugin_id: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvokeSidecarPluginCommandRequest {
+    pub plugin_id: String,
+    pub command: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+fn sidecar_plugin_service() -> Result<Arc<SidecarPluginService>, ApiError> {
+    get_global_sidecar_plugin_service()
+        .ok_or_else(|| ApiError::service_unavailable("Sidecar plugin service is not initialized"))
+}
+
+#[tauri::command]
+pub async fn list_sidecar_plugins() -> Result<SidecarPluginList, ApiError> {
+    Ok(sidecar_plugin_service()?.list().await)
+}
+
+/// Rescans the plugins directory and restarts enabled plugins.
+#[tauri::command]
+pub async fn reload_sidecar_plugins() -> Result<SidecarPluginList, ApiError> {
+    Ok(sidecar_plugin_service()?.reload().await)
+}
+
+/// Resolves after the user answers the permission prompt when enabling needs one.
+#[tauri::command]
+pub async fn set_sidecar_plugin_enabled(
+    request: SetSidecarPluginEnabledRequest,
+) -> Result<SidecarPluginInfo, ApiError> {
+    Ok(sidecar_plugin_service()?
+        .set_enabled(request.plugin_id.trim(), request.enabled)
+        .await?)
+}
+
+#[tauri::command]
+pub async fn invoke_sidecar_plugin_command(
+    request: InvokeSidecarPluginCommandRequest,
+) -> Result<Value, ApiError> {
+    Ok(sidecar_plugin_service()?
+        .invoke_command(
+            request.plugin_id.trim(),
+            request.command.trim(),
+            request.arguments,
+        )
+        .await?)
+}
@@ -16,6 +16,12 @@ use tokio::task::JoinSet;
 use tokio::time::{timeout, Duration};
 
 use crate::api::app_state::AppState;
+use crate::api::commands::{add_dir_contents_to_zip, add_file_to_zip};
+use crate::api::skill_install_journal::{
+    record_install, remove_created_paths, take_install, watched_skill_dirs, SkillRootSnapshot,
+    SKILL_INSTALL_LOCK,
+};
+use bitfun_agent_runtime::skills::{check_skill_compatibility, current_skill_platform};
 use bitfun_core::agentic::tools::implementations::skills::mode_overrides::{
     clear_user_mode_skill_overrides, load_project_mode_skills_document_local,
     project_mode_skills_path_for_remote, save_project_mode_skills_document_local,
@@ -31,10 +37,15 @@ use bitfun_core::infrastructure::get_path_manager_arc;
 use bitfun_core::service::config::agent_profile_project_store::{
     deserialize_project_agent_profiles_document, serialize_project_agent_profiles_document,
 };
+use bitfun_core::service::connectivity::get_global_connectivity_service;
+use bitfun_core::service::i18n::{localized_error, TranslationArgs};
+use bitfun_core::service::operations::{operation_registry, CancellationToken, OperationKind};
 use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
 use bitfun_core::service::remote_ssh::{get_remote_workspace_manager, RemoteWorkspaceEntry};
 use bitfun_core::service::runtime::RuntimeManager;
+use bitfun_core::util::errors::{ApiError, ApiErrorCode};
 use bitfun_core::util::process_manager;
+use bitfun_core::util::process_tree::ProcessTreeChild;
 
 const SKILLS_SEARCH_API_BASE: &str = "https://skills.sh";
 const DEFAULT_MARKET_QUERY: &str = "skill";
@@ -44,6 +55,11 @@ const MAX_OUTPUT_PREVIEW_CHARS: usize = 2000;
 const MARKET_DESC_FETCH_TIMEOUT_SECS: u64 = 4;
 const MARKET_DESC_FETCH_CONCURRENCY: usize = 6;
 const MARKET_DESC_MAX_LEN: usize = 220;
+const INSTALLER_CANCEL_GRACE: Duration = Duration::from_secs(2);
+/// Extensions the install preview flags as executable scripts.
+const SKILL_SCRIPT_EXTENSIONS: &[&str] = &[
+    "sh", "bash", "zsh", "fish", "py", "js", "mjs", "cjs", "ts", "rb", "pl", "ps1", "bat", "cmd",
+];
 
 static MARKET_DESCRIPTION_CACHE: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
 
@@ -62,6 +78,12 @@ fn can_delete_owned_skill(source_id: &str, source_slot: &str, is_builtin: bool)
 }
 
 fn ensure_skill_can_be_deleted(skill: &SkillInfo) -> Result<(), String> {
+    if skill.level.is_read_only() {
+        return Err(format!(
+            "Built-in Skill '{}' is read-only; duplicate it to the user level to change it",
+            skill.name
+        ));
+    }
     if can_delete_owned_skill(&skill.source_id, &skill.source_slot, skill.is_builtin) {
         Ok(())
     } else {
@@ -97,6 +119,10 @@ pub struct SkillMarketDownloadRequest {
     pub package: String,
     pub level: Option<SkillLocation>,
     pub workspace_path: Option<String>,
+    /// Lets the caller cancel through `cancel_operation` while the installer
+    /// is still running.
+    #[serde(default)]
+    pub operation_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,6 +132,74 @@ pub struct SkillMarketDownloadResponse {
     pub level: SkillLocation,
     pub installed_skills: Vec<String>,
     pub output: String,
+    /// Pass to `undo_skill_install` to remove what this install created;
+    /// absent when nothing new was written.
+    pub install_id: Option<String>,
+    /// Installed skills that target a newer BitFun or another OS; they are
+    /// disabled until that changes.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillInstallUndoResponse {
+    pub install_id: String,
+    pub package: String,
+    pub removed_paths: Vec<String>,
+}
+
+/// What `download_skill_market` would install, resolved without touching the
+/// workspace or the user's skill folders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillMarketInstallPreview {
+    pub package: String,
+    pub level: SkillLocation,
+    pub skills: Vec<SkillMarketPreviewSkill>,
+    pub total_size_bytes: u64,
+    pub includes_scripts: bool,
+    /// True when a skill targets a newer BitFun or another OS; the caller
+    /// should confirm before installing.
+    pub has_incompatible_skills: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillMarketPreviewSkill {
+    pub name: String,
+    pub description: Option<String>,
+    /// Folder the skill would be written to.
+    pub target_path: String,
+    /// Whether `target_path` already exists and would be overwritten.
+    pub exists: bool,
+    pub file_count: usize,
+    pub size_bytes: u64,
+    /// Script files, relative to the skill folder.
+    pub scripts: Vec<String>,
+    /// Why the skill would be disabled after installing, if it would be.
+    pub incompatible_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillBundleExportRequest {
+    pub skill_key: String,
+    pub workspace_path: Option<String>,
+    /// Zip file to write.
+    pub target_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillBundleExportResponse {
+    pub path: String,
+    pub skill_name: String,
+    /// Referenced files from next to the skill folder that were added to the
+    /// bundle at the same relative location.
+    pub external_assets: Vec<String>,
+    /// References that do not resolve to a file; the bundle is written without
+    /// them.
+    pub missing_assets: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,6 +247,24 @@ struct SkillSearchApiItem {
     installs: u64,
 }
 
+async fn skill_not_found(name: &str) -> ApiError {
+    localized_error(
+        ApiErrorCode::NotFound,
+        "skill-not-found",
+        TranslationArgs::new().with_string("name", name),
+    )
+    .await
+}
+
+async fn skill_already_installed(name: &str) -> ApiError {
+    localized_error(
+        ApiErrorCode::Conflict,
+        "skill-already-installed",
+        TranslationArgs::new().with_string("name", name),
+    )
+    .await
+}
+
 fn workspace_root_from_input(workspace_path: Option<&str>) -> Option<PathBuf> {
     workspace_path
         .filter(|path| !path.is_empty())
@@ -276,7 +388,7 @@ async fn persist_user_mode_skill_selection(
 
     for skill in all_skills
         .iter()
-        .filter(|skill| skill.level == SkillLocation::User)
+        .filter(|skill| matches!(skill.level, SkillLocation::User | SkillLocation::Builtin))
     {
         let should_enable = enabled_keys.contains(&skill.key);
         let default_enabled = resolve_skill_default_enabled_for_mode(skill, mode_id);
@@ -483,7 +595,7 @@ pub async fn get_skill_configs(
     state: State<'_, AppState>,
     force_refresh: Option<bool>,
     workspace_path: Option<String>,
-) -> Result<Value, String> {
+) -> Result<Value, ApiError> {
     let registry = SkillRegistry::global();
 
     if force_refresh.unwrap_or(false) {
@@ -494,7 +606,7 @@ pub async fn get_skill_configs(
         get_all_skills_for_workspace_input(&state, registry, workspace_path.as_deref()).await?;
 
     serde_json::to_value(all_skills)
-        .map_err(|e| format!("Failed to serialize skill configs: {}", e))
+        .map_err(|e| ApiError::internal(format!("Failed to serialize skill configs: {}", e)))
 }
 
 #[tauri::command]
@@ -503,7 +615,7 @@ pub async fn get_mode_skill_configs(
     mode_id: String,
     force_refresh: Option<bool>,
     workspace_path: Option<String>,
-) -> Result<Value, String> {
+) -> Result<Value, ApiError> {
     let registry = SkillRegistry::global();
 
     if force_refresh.unwrap_or(false) {
@@ -519,7 +631,7 @@ pub async fn get_mode_skill_configs(
     .await?;
 
     serde_json::to_value(mode_skill_infos)
-        .map_err(|e| format!("Failed to serialize mode skill configs: {}", e))
+        .map_err(|e| ApiError::internal(format!("Failed to serialize mode skill configs: {}", e)))
 }
 
 #[tauri::command]
@@ -529,10 +641,10 @@ pub async fn set_mode_skill_disabled(
     skill_key: String,
     disabled: bool,
     workspace_path: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     if skill_key.starts_with("user::") {
         let registry = SkillRegistry::global();
-        let skill_info = if let Some((remote_root, entry)) =
+        let found = if let Some((remote_root, entry)) =
             resolve_remote_workspace(&state, workspace_path.as_deref()).await?
         {
             let remote_fs = state
@@ -554,8 +666,10 @@ pub async fn set_mode_skill_disabled(
                     workspace_root_from_input(workspace_path.as_deref()).as_deref(),
                 )
                 .await
-        }
-        .ok_or_else(|| format!("Skill '{}' not found", skill_key))?;
+        };
+        let Some(skill_info) = found else {
+            return Err(skill_not_found(&skill_key).await);
+        };
 
         let default_enabled = resolve_skill_default_enabled_for_mode(&skill_info, &mode_id);
         set_user_mode_skill_state(&mode_id, &skill_key, !disabled, default_enabled)
@@ -576,7 +690,10 @@ pub async fn set_mode_skill_disabled(
     }
 
     if !skill_key.starts_with("project::") {
-        return Err(format!("Unsupported skill key '{}'", skill_key));
+        return Err(ApiError::invalid_input(format!(
+            "Unsupported skill key '{}'",
+            skill_key
+        )));
     }
 
     if let Some((remote_root, entry)) =
@@ -635,8 +752,10 @@ pub async fn set_mode_skill_disabled(
             .await
             .map_err(|e| format!("Failed to write remote project skill overrides: {}", e))?;
     } else {
-        let workspace_root = workspace_root_from_input(workspace_path.as_deref())
-            .ok_or_else(|| "Project-level skill overrides require an open workspace".to_string())?;
+        let workspace_root =
+            workspace_root_from_input(workspace_path.as_deref()).ok_or_else(|| {
+                ApiError::invalid_input("Project-level skill overrides require an open workspace")
+            })?;
         let mut document = load_project_mode_skills_document_local(&workspace_root)
             .await
             .map_err(|e| format!("Failed to load project mode skills: {}", e))?;
@@ -657,7 +776,7 @@ pub async fn set_mode_skill_disabled(
 pub async fn replace_mode_skill_selection(
     state: State<'_, AppState>,
     request: ReplaceModeSkillSelectionRequest,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     let registry = SkillRegistry::global();
     let all_skills =
         get_all_skills_for_workspace_input(&state, registry, request.workspace_path.as_deref())
@@ -672,11 +791,11 @@ pub async fn replace_mode_skill_selection(
         .cloned()
         .collect();
     if !unknown_keys.is_empty() {
-        return Err(format!(
+        return Err(ApiError::invalid_input(format!(
             "Unknown skill keys for mode '{}': {}",
             request.mode_id,
             unknown_keys.join(", ")
-        ));
+        )));
     }
 
     persist_user_mode_skill_selection(&request.mode_id, &all_skills, &enabled_keys).await?;
@@ -726,7 +845,7 @@ pub async fn replace_mode_skill_selection(
 pub async fn reset_mode_skill_selection(
     state: State<'_, AppState>,
     request: ResetModeSkillSelectionRequest,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     clear_user_mode_skill_overrides(&request.mode_id)
         .await
         .map_err(|e| format!("Failed to reset user skill overrides: {}", e))?;
@@ -757,7 +876,7 @@ pub async fn reset_mode_skill_selection(
 }
 
 #[tauri::command]
-pub async fn validate_skill_path(path: String) -> Result<SkillValidationResult, String> {
+pub async fn validate_skill_path(path: String) -> Result<SkillValidationResult, ApiError> {
     use std::path::Path;
 
     let skill_path = Path::new(&path);
@@ -822,10 +941,22 @@ pub async fn add_skill(
     source_path: String,
     level: String,
     workspace_path: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     let validation = validate_skill_path(source_path.clone()).await?;
     if !validation.valid {
-        return Err(validation.error.unwrap_or("Invalid skill path".to_string()));
+        return Err(localized_error(
+            ApiErrorCode::InvalidInput,
+            "skill-invalid-manifest",
+            TranslationArgs::new()
+                .with_string("path", source_path.as_str())
+                .with_string(
+                    "reason",
+                    validation
+                        .error
+                        .unwrap_or_else(|| "Invalid skill path".to_string()),
+                ),
+        )
+        .await);
     }
 
     let skill_name = validation
@@ -837,21 +968,26 @@ pub async fn add_skill(
     let target_dir = if level == "project" {
         if let Some(workspace_root) = workspace_root_from_input(workspace_path.as_deref()) {
             if is_remote_path(&workspace_root.to_string_lossy()).await {
-                return Err(
-                    "Installing project skills into remote workspaces is not supported yet"
-                        .to_string(),
-                );
+                return Err(ApiError::new(
+                    ApiErrorCode::NotImplemented,
+                    "Installing project skills into remote workspaces is not supported yet",
+                ));
             }
             workspace_root.join(".bitfun").join("skills")
         } else {
-            return Err("No workspace open, cannot add project-level Skill".to_string());
+            return Err(ApiError::invalid_input(
+                "No workspace open, cannot add project-level Skill",
+            ));
         }
     } else {
         get_path_manager_arc().user_skills_dir()
     };
 
     if let Err(e) = tokio::fs::create_dir_all(&target_dir).await {
-        return Err(format!("Failed to create skills directory: {}", e));
+        return Err(ApiError::new(
+            ApiErrorCode::Io,
+            format!("Failed to create skills directory: {}", e),
+        ));
     }
 
     let folder_name = source
@@ -862,19 +998,14 @@ pub async fn add_skill(
     let target_path = target_dir.join(folder_name);
 
     if target_path.exists() {
-        return Err(format!(
-            "Skill '{}' already exists in {} level directory",
-            folder_name,
-            if level == "project" {
-                "project"
-            } else {
-                "user"
-            }
-        ));
+        return Err(skill_already_installed(folder_name).await);
     }
 
     if let Err(e) = copy_dir_all(source, &target_path).await {
-        return Err(format!("Failed to copy skill folder: {}", e));
+        return Err(ApiError::new(
+            ApiErrorCode::Io,
+            format!("Failed to copy skill folder: {}", e),
+        ));
     }
 
     SkillRegistry::global()
@@ -914,7 +1045,7 @@ pub async fn delete_skill(
     state: State<'_, AppState>,
     skill_key: String,
     workspace_path: Option<String>,
-) -> Result<String, String> {
+) -> Result<String, ApiError> {
     let registry = SkillRegistry::global();
     if let Some((remote_root, entry)) =
         resolve_remote_workspace(&state, workspace_path.as_deref()).await?
@@ -925,10 +1056,12 @@ pub async fn delete_skill(
             .map_err(|e| format!("Remote file service not available: {}", e))?;
         let remote_workspace_fs =
             RemoteWorkspaceFs::new(entry.connection_id.clone(), remote_fs.clone());
-        let skill_info = registry
+        let Some(skill_info) = registry
             .find_skill_by_key_for_remote_workspace(&remote_workspace_fs, &remote_root, &skill_key)
             .await
-            .ok_or_else(|| format!("Skill '{}' not found", skill_key))?;
+        else {
+            return Err(skill_not_found(&skill_key).await);
+        };
         ensure_skill_can_be_deleted(&skill_info)?;
 
         match skill_info.level {
@@ -942,7 +1075,7 @@ pub async fn delete_skill(
                     skill_key, skill_info.path
                 );
             }
-            SkillLocation::User => {
+            SkillLocation::User | SkillLocation::Builtin => {
                 let skill_path = std::path::PathBuf::from(&skill_info.path);
                 if skill_path.exists() {
                     tokio::fs::remove_dir_all(&skill_path)
@@ -963,17 +1096,22 @@ pub async fn delete_skill(
     }
 
     let workspace_root = workspace_root_from_input(workspace_path.as_deref());
-    let skill_info = registry
+    let Some(skill_info) = registry
         .find_skill_by_key_for_workspace(&skill_key, workspace_root.as_deref())
         .await
-        .ok_or_else(|| format!("Skill '{}' not found", skill_key))?;
+    else {
+        return Err(skill_not_found(&skill_key).await);
+    };
     ensure_skill_can_be_deleted(&skill_info)?;
 
     let skill_path = std::path::PathBuf::from(&skill_info.path);
 
     if skill_path.exists() {
         if let Err(e) = tokio::fs::remove_dir_all(&skill_path).await {
-            return Err(format!("Failed to delete skill folder: {}", e));
+            return Err(ApiError::new(
+                ApiErrorCode::Io,
+                format!("Failed to delete skill folder: {}", e),
+            ));
         }
     }
 
@@ -989,6 +1127,72 @@ pub async fn delete_skill(
     Ok(format!("Skill '{}' deleted successfully", skill_info.name))
 }
 
+/// Copies a built-in skill into the BitFun user skills folder so it can be
+/// edited. The copy shadows the built-in skill of the same name.
+#[tauri::command]
+pub async fn duplicate_skill_to_user(
+    _state: State<'_, AppState>,
+    skill_name: String,
+) -> Result<SkillInfo, ApiError> {
+    let skill_name = skill_name.trim();
+    let registry = SkillRegistry::global();
+    let Some(builtin) = registry.get_all_skills().await.into_iter().find(|skill| {
+        skill.level == SkillLocation::Builtin
+            && (skill.name == skill_name || skill.dir_name == skill_name || skill.key == skill_name)
+    }) else {
+        return Err(skill_not_found(skill_name).await);
+    };
+
+    let target_path = get_path_manager_arc()
+        .user_skills_dir()
+        .join(&builtin.dir_name);
+    if tokio::fs::symlink_metadata(&target_path).await.is_ok() {
+        return Err(skill_already_installed(&builtin.dir_name).await);
+    }
+    if let Err(e) = copy_dir_all(Path::new(&builtin.path), &target_path).await {
+        return Err(ApiError::new(
+            ApiErrorCode::Io,
+            format!("Failed to copy skill folder: {}", e),
+        ));
+    }
+
+    registry.refresh().await;
+    let copy_path = target_path.to_string_lossy().to_string();
+    let copy = registry
+        .get_all_skills()
+        .await
+        .into_iter()
+        .find(|skill| skill.path == copy_path)
+        .ok_or_else(|| {
+            format!(
+                "Copied Skill '{}' was not found after refresh",
+                builtin.name
+            )
+        })?;
+
+    info!(
+        "Built-in skill duplicated to user level: name={}, key={}, path={}",
+        builtin.name, copy.key, copy_path
+    );
+    Ok(copy)
+}
+
+#[cfg(test)]
+mod skill_preview_tests {
+    use super::is_skill_script;
+    use std::path::Path;
+
+    #[test]
+    fn scripts_are_detected_by_folder_or_extension() {
+        assert!(is_skill_script(Path::new("scripts/fetch")));
+        assert!(is_skill_script(Path::new("tools/build.SH")));
+        assert!(is_skill_script(Path::new("helper.py")));
+        assert!(!is_skill_script(Path::new("SKILL.md")));
+        assert!(!is_skill_script(Path::new("references/scripts.md")));
+        assert!(!is_skill_script(Path::new("scripts")));
+    }
+}
+
 #[cfg(test)]
 mod skill_delete_policy_tests {
     use super::can_delete_owned_skill;
@@ -1015,11 +1219,22 @@ mod skill_delete_policy_tests {
     }
 }
 
+/// Market installs go to the user or project level; the built-in level only
+/// holds skills shipped with BitFun.
+fn market_install_level(level: Option<SkillLocation>) -> Result<SkillLocation, ApiError> {
+    match level.unwrap_or(SkillLocation::Project) {
+        SkillLocation::Builtin => Err(ApiError::invalid_input(
+            "Skills cannot be installed into the built-in level",
+        )),
+        level => Ok(level),
+    }
+}
+
 #[tauri::command]
 pub async fn list_skill_market(
     _state: State<'_, AppState>,
     request: SkillMarketListRequest,
-) -> Result<Vec<SkillMarketItem>, String> {
+) -> Result<Vec<SkillMarketItem>, ApiError> {
     let query = request
         .query
         .as_deref()
@@ -1034,7 +1249,7 @@ pub async fn list_skill_market(
 pub async fn search_skill_market(
     _state: State<'_, AppState>,
     request: SkillMarketSearchRequest,
-) -> Result<Vec<SkillMarketItem>, String> {
+) -> Result<Vec<SkillMarketItem>, ApiError> {
     let query = request.query.trim();
     if query.is_empty() {
         return Ok(Vec::new());
@@ -1047,27 +1262,33 @@ pub async fn search_skill_market(
 pub async fn download_skill_market(
     _state: State<'_, AppState>,
     request: SkillMarketDownloadRequest,
-) -> Result<SkillMarketDownloadResponse, String> {
+) -> Result<SkillMarketDownloadResponse, ApiError> {
     let package = request.package.trim().to_string();
     if package.is_empty() {
-        return Err("Skill package cannot be empty".to_string());
+        return Err(ApiError::invalid_input("Skill package cannot be empty"));
     }
 
-    let level = request.level.unwrap_or(SkillLocation::Project);
+    let level = market_install_level(request.level)?;
     let workspace_path = if level == SkillLocation::Project {
-        let path = trim_workspace_path(request.workspace_path.as_deref())
-            .ok_or_else(|| "No workspace open, cannot add project-level Skill".to_string())?;
+        let path = trim_workspace_path(request.workspace_path.as_deref()).ok_or_else(|| {
+            ApiError::invalid_input("No workspace open, cannot add project-level Skill")
+        })?;
         if is_remote_path(&path).await {
-            return Err(
-                "Downloading project skills into remote workspaces is not supported yet"
-                    .to_string(),
-            );
+            return Err(ApiError::new(
+                ApiErrorCode::NotImplemented,
+                "Downloading project skills into remote workspaces is not supported yet",
+            ));
         }
         Some(PathBuf::from(path))
     } else {
         None
     };
 
+    let operation = operation_registry().begin(
+        request.operation_id,
+        OperationKind::SkillMarketDownload,
+        package.clone(),
+    )?;
     let registry = SkillRegistry::global();
     let before_names: HashSet<String> = registry
         .get_all_skills_for_workspace(workspace_path.as_deref())
@@ -1076,6 +1297,219 @@ pub async fn download_skill_market(
         .map(|skill| skill.name)
         .collect();
 
+    let _install_guard = SKILL_INSTALL_LOCK.lock().await;
+    let snapshot =
+        SkillRootSnapshot::capture(watched_skill_dirs(level, workspace_path.as_deref())).await;
+
+    let mut command = skills_installer_command(&package, level)?;
+    if let Some(path) = workspace_path.as_ref() {
+        command.current_dir(path);
+    }
+    let (stdout, stderr) = match run_skills_installer(command, &package, operation.token()).await {
+        Ok(output) => output,
+        Err(error) => {
+            // The installer can fail halfway through; do not leave its
+            // partial output behind.
+            let removed = remove_created_paths(&snapshot.created_since().await).await;
+            if !removed.is_empty() {
+                info!(
+                    "Rolled back failed skill install: package={}, removed_count={}",
+                    package,
+                    removed.len()
+                );
+                registry
+                    .refresh_for_workspace(workspace_path.as_deref())
+                    .await;
+            }
+            if error.code == ApiErrorCode::Cancelled {
+                return Err(error);
+            }
+            return Err(localized_error(
+                error.code,
+                "skill-install-failed",
+                TranslationArgs::new()
+                    .with_string("name", package.as_str())
+                    .with_string("reason", error.message),
+            )
+            .await);
+        }
+    };
+
+    let created_paths = snapshot.created_since().await;
+    let install_id = if created_paths.is_empty() {
+        None
+    } else {
+        Some(record_install(&package, level, workspace_path.as_deref(), &created_paths).await?)
+    };
+
+    registry
+        .refresh_for_workspace(workspace_path.as_deref())
+        .await;
+    let new_skills: Vec<SkillInfo> = registry
+        .get_all_skills_for_workspace(workspace_path.as_deref())
+        .await
+        .into_iter()
+        .filter(|skill| !before_names.contains(&skill.name))
+        .collect();
+    let mut warnings: Vec<String> = new_skills
+        .iter()
+        .filter_map(|skill| {
+            let reason = skill.incompatible_reason.as_deref()?;
+            Some(format!("Skill '{}' is disabled: {}", skill.name, reason))
+        })
+        .collect();
+    warnings.sort();
+    warnings.dedup();
+    for warning in &warnings {
+        log::warn!(
+            "Installed skill is incompatible: package={}, warning={}",
+            package,
+            warning
+        );
+    }
+    let mut installed_skills: Vec<String> =
+        new_skills.into_iter().map(|skill| skill.name).collect();
+    installed_skills.sort();
+    installed_skills.dedup();
+
+    info!(
+        "Skill market download completed: package={}, level={}, installed_count={}",
+        package,
+        level.as_str(),
+        installed_skills.len()
+    );
+
+    Ok(SkillMarketDownloadResponse {
+        package,
+        level,
+        installed_skills,
+        output: summarize_command_output(&stdout, &stderr),
+        install_id,
+        warnings,
+    })
+}
+
+/// Removes everything a previous `download_skill_market` call created.
+#[tauri::command]
+pub async fn undo_skill_install(
+    _state: State<'_, AppState>,
+    install_id: String,
+) -> Result<SkillInstallUndoResponse, ApiError> {
+    let _install_guard = SKILL_INSTALL_LOCK.lock().await;
+    let record = take_install(install_id.trim()).await?;
+    let created_paths: Vec<PathBuf> = record.created_paths.iter().map(PathBuf::from).collect();
+    let removed_paths = remove_created_paths(&created_paths).await;
+
+    let workspace_path = record.workspace_path.as_deref().map(Path::new);
+    SkillRegistry::global()
+        .refresh_for_workspace(workspace_path)
+        .await;
+
+    info!(
+        "Skill install undone: install_id={}, package={}, removed_count={}",
+        record.install_id,
+        record.package,
+        removed_paths.len()
+    );
+
+    Ok(SkillInstallUndoResponse {
+        install_id: record.install_id,
+        package: record.package,
+        removed_paths: removed_paths
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+    })
+}
+
+/// Resolves what `download_skill_market` would install for the same request.
+///
+/// The installer runs against a throwaway directory (standing in for the
+/// workspace, or for the home directory at user level) and the resulting
+/// skill folders are inspected and mapped onto their real destinations.
+#[tauri::command]
+pub async fn preview_skill_market_install(
+    _state: State<'_, AppState>,
+    request: SkillMarketDownloadRequest,
+) -> Result<SkillMarketInstallPreview, ApiError> {
+    let package = request.package.trim().to_string();
+    if package.is_empty() {
+        return Err(ApiError::invalid_input("Skill package cannot be empty"));
+    }
+
+    let level = market_install_level(request.level)?;
+    let destination_root = if level == SkillLocation::Project {
+        let path = trim_workspace_path(request.workspace_path.as_deref()).ok_or_else(|| {
+            ApiError::invalid_input("No workspace open, cannot add project-level Skill")
+        })?;
+        if is_remote_path(&path).await {
+            return Err(ApiError::new(
+                ApiErrorCode::NotImplemented,
+                "Downloading project skills into remote workspaces is not supported yet",
+            ));
+        }
+        PathBuf::from(path)
+    } else {
+        dirs::home_dir().ok_or_else(|| "Failed to resolve the home directory".to_string())?
+    };
+
+    let operation = operation_registry().begin(
+        request.operation_id,
+        OperationKind::SkillMarketPreview,
+        package.clone(),
+    )?;
+    let staging_root =
+        std::env::temp_dir().join(format!("bitfun-skill-preview-{}", uuid::Uuid::new_v4()));
+    tokio::fs::create_dir_all(&staging_root)
+        .await
+        .map_err(|e| format!("Failed to create preview directory: {}", e))?;
+
+    let result: Result<_, ApiError> = async {
+        let mut command = skills_installer_command(&package, level)?;
+        command.current_dir(&staging_root);
+        if level == SkillLocation::User {
+            // Redirect the global install into the staging directory.
+            command.env("HOME", &staging_root);
+            command.env("USERPROFILE", &staging_root);
+            command.env("XDG_CONFIG_HOME", staging_root.join(".config"));
+        }
+        run_skills_installer(command, &package, operation.token()).await?;
+        Ok(collect_staged_skills(&staging_root, &destination_root, level).await?)
+    }
+    .await;
+
+    if let Err(e) = tokio::fs::remove_dir_all(&staging_root).await {
+        log::warn!(
+            "Failed to remove skill preview directory: path={}, error={}",
+            staging_root.display(),
+            e
+        );
+    }
+
+    let skills = result?;
+    info!(
+        "Skill market install preview resolved: package={}, level={}, skill_count={}",
+        package,
+        level.as_str(),
+        skills.len()
+    );
+
+    Ok(SkillMarketInstallPreview {
+        package,
+        level,
+        total_size_bytes: skills.iter().map(|skill| skill.size_bytes).sum(),
+        includes_scripts: skills.iter().any(|skill| !skill.scripts.is_empty()),
+        has_incompatible_skills: skills
+            .iter()
+            .any(|skill| skill.incompatible_reason.is_some()),
+        skills,
+    })
+}
+
+fn skills_installer_command(
+    package: &str,
+    level: SkillLocation,
+) -> Result<tokio::process::Command, String> {
     let runtime_manager = RuntimeManager::new()
         .map_err(|e| format!("Failed to initialize runtime manager: {}", e))?;
     let resolved_npx = runtime_manager.resolve_command("npx").ok_or_else(|| {
@@ -1087,7 +1521,7 @@ pub async fn download_skill_market(
         .arg("-y")
         .arg("skills")
         .arg("add")
-        .arg(&package)
+        .arg(package)
         .arg("-y")
         .arg("-a")
         .arg("universal");
@@ -1096,10 +1530,6 @@ pub async fn download_skill_market(
         command.arg("-g");
     }
 
-    if let Some(path) = workspace_path.as_ref() {
-        command.current_dir(path);
-    }
-
     let current_path = std::env::var("PATH").ok();
     if let Some(merged_path) = runtime_manager.merged_path_env(current_path.as_deref()) {
         command.env("PATH", &merged_path);
@@ -1111,17 +1541,40 @@ pub async fn download_skill_market(
 
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
+    Ok(command)
+}
 
-    let output = command
-        .output()
+/// Runs the installer and returns its `(stdout, stderr)`. Cancelling
+/// `cancel` kills the installer together with the npm processes it spawned.
+async fn run_skills_installer(
+    mut command: tokio::process::Command,
+    package: &str,
+    cancel: &CancellationToken,
+) -> Result<(String, String), ApiError> {
+    let mut child = ProcessTreeChild::spawn(&mut command)
         .await
-        .map_err(|e| format!("Failed to execute skills installer: {}", e))?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        .map_err(|e| ApiError::from(format!("Failed to execute skills installer: {}", e)))?;
+    let stdout_reader = read_pipe(child.take_stdout());
+    let stderr_reader = read_pipe(child.take_stderr());
+
+    let status = tokio::select! {
+        status = child.wait() => status
+            .map_err(|e| ApiError::from(format!("Failed to execute skills installer: {}", e)))?,
+        _ = cancel.cancelled() => {
+            if let Err(e) = child.terminate(INSTALLER_CANCEL_GRACE).await {
+                log::warn!("Failed to stop skills installer: package={}, error={}", package, e);
+            }
+            return Err(ApiError::new(
+                ApiErrorCode::Cancelled,
+                format!("Installing skill package '{}' was cancelled", package),
+            ));
+        }
+    };
+    let stdout = stdout_reader.await.unwrap_or_default();
+    let stderr = stderr_reader.await.unwrap_or_default();
 
-    if !output.status.success() {
-        let exit_code = output.status.code().unwrap_or(-1);
+    if !status.success() {
+        let exit_code = status.code().unwrap_or(-1);
         let detail = if !stderr.trim().is_empty() {
             truncate_preview(stderr.trim())
         } else if !stdout.trim().is_empty() {
@@ -1132,68 +1585,349 @@ pub async fn download_skill_market(
         return Err(format!(
             "Failed to download skill package '{}' (exit code {}): {}",
             package, exit_code, detail
-        ));
+        )
+        .into());
     }
 
-    registry
-        .refresh_for_workspace(workspace_path.as_deref())
-        .await;
-    let mut installed_skills: Vec<String> = registry
-        .get_all_skills_for_workspace(workspace_path.as_deref())
-        .await
-        .into_iter()
-        .map(|skill| skill.name)
-        .filter(|name| !before_names.contains(name))
-        .collect();
-    installed_skills.sort();
-    installed_skills.dedup();
+    Ok((stdout, stderr))
+}
+
+/// Drains a child pipe in the background so a chatty installer cannot block
+/// on a full pipe buffer.
+fn read_pipe<R>(pipe: Option<R>) -> tokio::task::JoinHandle<String>
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = tokio::io::AsyncReadExt::read_to_end(&mut pipe, &mut buffer).await;
+        }
+        String::from_utf8_lossy(&buffer).to_string()
+    })
+}
+
+/// Finds every skill folder (a directory holding `SKILL.md`) the installer
+/// wrote under `staging_root` and describes it at its real destination.
+async fn collect_staged_skills(
+    staging_root: &Path,
+    destination_root: &Path,
+    level: SkillLocation,
+) -> Result<Vec<SkillMarketPreviewSkill>, String> {
+    let mut skills = Vec::new();
+    let mut pending = vec![staging_root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        if tokio::fs::metadata(dir.join("SKILL.md")).await.is_ok() {
+            skills.push(describe_staged_skill(&dir, staging_root, destination_root, level).await?);
+            continue;
+        }
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .map_err(|e| format!("Failed to inspect preview directory: {}", e))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("Failed to inspect preview directory: {}", e))?
+        {
+            let is_dir = entry
+                .file_type()
+                .await
+                .map(|ty| ty.is_dir())
+                .unwrap_or(false);
+            if is_dir && entry.file_name() != "node_modules" {
+                pending.push(entry.path());
+            }
+        }
+    }
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(skills)
+}
+
+async fn describe_staged_skill(
+    skill_dir: &Path,
+    staging_root: &Path,
+    destination_root: &Path,
+    level: SkillLocation,
+) -> Result<SkillMarketPreviewSkill, String> {
+    let relative = skill_dir.strip_prefix(staging_root).unwrap_or(skill_dir);
+    let target_path = destination_root.join(relative);
+    let fallback_name = skill_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let (name, description, incompatible_reason) =
+        match tokio::fs::read_to_string(skill_dir.join("SKILL.md")).await {
+            Ok(content) => match SkillData::from_markdown(
+                target_path.to_string_lossy().to_string(),
+                &content,
+                level,
+                false,
+            ) {
+                Ok(data) => (
+                    data.name,
+                    Some(data.description),
+                    check_skill_compatibility(
+                        &data.compatibility,
+                        bitfun_core::VERSION,
+                        Some(current_skill_platform()),
+                    ),
+                ),
+                Err(_) => (fallback_name, None, None),
+            },
+            Err(_) => (fallback_name, None, None),
+        };
+
+    let mut file_count = 0;
+    let mut size_bytes = 0;
+    let mut scripts = Vec::new();
+    let mut pending = vec![skill_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .map_err(|e| format!("Failed to inspect skill folder: {}", e))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("Failed to inspect skill folder: {}", e))?
+        {
+            let metadata = match entry.metadata().await {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+            let path = entry.path();
+            if metadata.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            file_count += 1;
+            size_bytes += metadata.len();
+            let relative = path.strip_prefix(skill_dir).unwrap_or(&path);
+            if is_skill_script(relative) {
+                scripts.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    scripts.sort();
+
+    Ok(SkillMarketPreviewSkill {
+        name,
+        description,
+        exists: tokio::fs::metadata(&target_path).await.is_ok(),
+        target_path: target_path.to_string_lossy().to_string(),
+        file_count,
+        size_bytes,
+        scripts,
+        incompatible_reason,
+    })
+}
+
+/// Anything under a top-level `scripts/` folder, or a file with a script
+/// extension, counts as a script.
+fn is_skill_script(relative: &Path) -> bool {
+    if relative
+        .components()
+        .next()
+        .is_some_and(|first| first.as_os_str() == "scripts")
+        && relative.components().count() > 1
+    {
+        return true;
+    }
+    relative
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            SKILL_SCRIPT_EXTENSIONS
+                .iter()
+                .any(|candidate| ext.eq_ignore_ascii_case(candidate))
+        })
+}
+
+/// Packages a skill as a zip holding its folder plus every companion file the
+/// skill references, so it works the same on another machine.
+#[tauri::command]
+pub async fn export_skill_bundle(
+    _state: State<'_, AppState>,
+    request: SkillBundleExportRequest,
+) -> Result<SkillBundleExportResponse, ApiError> {
+    let target_path = request.target_path.trim();
+    if target_path.is_empty() {
+        return Err(ApiError::invalid_input("Export path cannot be empty"));
+    }
+    let target_path = PathBuf::from(target_path);
+
+    let workspace_path = trim_workspace_path(request.workspace_path.as_deref());
+    if let Some(path) = workspace_path.as_deref() {
+        if is_remote_path(path).await {
+            return Err(ApiError::new(
+                ApiErrorCode::NotImplemented,
+                "Exporting skills from remote workspaces is not supported yet",
+            ));
+        }
+    }
+    let skill = SkillRegistry::global()
+        .find_and_load_skill_by_key_for_workspace(
+            request.skill_key.trim(),
+            workspace_root_from_input(workspace_path.as_deref()).as_deref(),
+            None,
+        )
+        .await?;
+
+    let mut missing_assets = Vec::new();
+    let mut external_assets = Vec::new();
+    let mut unbundleable = Vec::new();
+    for asset in &skill.assets {
+        if !asset.exists {
+            missing_assets.push(asset.reference.clone());
+            continue;
+        }
+        if asset.inside_skill_dir {
+            continue;
+        }
+        // The bundle root holds the skill folder, so one `../` level can be
+        // reproduced next to it; anything further up cannot.
+        match asset.reference.strip_prefix("../") {
+            Some(rest) if rest.starts_with(&format!("{}/", skill.dir_name)) => {}
+            Some(rest) if !rest.starts_with("../") => {
+                external_assets.push((PathBuf::from(&asset.resolved_path), rest.to_string()))
+            }
+            _ => unbundleable.push(asset.reference.clone()),
+        }
+    }
+    if !unbundleable.is_empty() {
+        return Err(ApiError::invalid_input(format!(
+            "Skill '{}' references files that cannot be bundled: {}",
+            skill.name,
+            unbundleable.join(", ")
+        )));
+    }
+
+    let skill_dir = PathBuf::from(&skill.path);
+    let dir_name = skill.dir_name.clone();
+    let bundle_path = target_path.clone();
+    let bundled = external_assets.clone();
+    tokio::task::spawn_blocking(move || {
+        write_skill_bundle(&skill_dir, &dir_name, &bundled, &bundle_path)
+    })
+    .await
+    .map_err(|e| format!("Skill export task failed: {}", e))??;
 
     info!(
-        "Skill market download completed: package={}, level={}, installed_count={}",
-        package,
-        level.as_str(),
-        installed_skills.len()
+        "Skill bundle exported: key={}, path={}, external_assets={}, missing_assets={}",
+        skill.key,
+        target_path.display(),
+        external_assets.len(),
+        missing_assets.len()
     );
 
-    Ok(SkillMarketDownloadResponse {
-        package,
-        level,
-        installed_skills,
-        output: summarize_command_output(&stdout, &stderr),
+    Ok(SkillBundleExportResponse {
+        path: target_path.to_string_lossy().to_string(),
+        skill_name: skill.name,
+        external_assets: external_assets
+            .into_iter()
+            .map(|(_, archive_path)| archive_path)
+            .collect(),
+        missing_assets,
     })
 }
 
+fn write_skill_bundle(
+    skill_dir: &Path,
+    dir_name: &str,
+    external_assets: &[(PathBuf, String)],
+    target_path: &Path,
+) -> Result<(), String> {
+    let result = (|| {
+        if let Some(parent) = target_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create '{}': {}", parent.display(), e))?;
+        }
+        let file = std::fs::File::create(target_path)
+            .map_err(|e| format!("Failed to create '{}': {}", target_path.display(), e))?;
+        let mut zip_writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        zip_writer
+            .add_directory(format!("{}/", dir_name), options)
+            .map_err(|e| format!("Failed to add '{}' to zip: {}", dir_name, e))?;
+        add_dir_contents_to_zip(&mut zip_writer, skill_dir, dir_name, options)?;
+        for (source_path, archive_path) in external_assets {
+            add_file_to_zip(&mut zip_writer, source_path, archive_path, options)?;
+        }
+
+        zip_writer
+            .finish()
+            .map_err(|e| format!("Failed to finalize zip archive: {}", e))?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(target_path);
+    }
+    result
+}
+
+/// Re-fetches the default market listing so its description cache stays
+/// warm; used by the scheduled refresh job.
+pub(crate) async fn refresh_skill_market_cache() -> Result<usize, String> {
+    fetch_skill_market(DEFAULT_MARKET_QUERY, DEFAULT_MARKET_LIMIT)
+        .await
+        .map(|items| items.len())
+        .map_err(|e| e.to_string())
+}
+
 fn normalize_market_limit(value: Option<u32>) -> u32 {
     value
         .unwrap_or(DEFAULT_MARKET_LIMIT)
         .clamp(1, MAX_MARKET_LIMIT)
 }
 
-async fn fetch_skill_market(query: &str, limit: u32) -> Result<Vec<SkillMarketItem>, String> {
-    let api_base =
-        std::env::var("SKILLS_API_URL").unwrap_or_else(|_| SKILLS_SEARCH_API_BASE.into());
+pub(crate) fn skill_market_api_base() -> String {
+    std::env::var("SKILLS_API_URL").unwrap_or_else(|_| SKILLS_SEARCH_API_BASE.into())
+}
+
+async fn fetch_skill_market(query: &str, limit: u32) -> Result<Vec<SkillMarketItem>, ApiError> {
+    let api_base = skill_market_api_base();
     let base_url = api_base.trim_end_matches('/');
     let endpoint = format!("{}/api/search", base_url);
 
+    if let Some(connectivity) = get_global_connectivity_service() {
+        connectivity
+            .ensure_reachable(&endpoint, "Skill market")
+            .await?;
+    }
+
     let client = Client::new();
     let response = client
         .get(&endpoint)
         .query(&[("q", query), ("limit", &limit.to_string())])
         .send()
         .await
-        .map_err(|e| format!("Failed to query skill market: {}", e))?;
+        .map_err(|e| {
+            ApiError::new(
+                ApiErrorCode::Network,
+                format!("Failed to query skill market: {}", e),
+            )
+        })?;
 
     if !response.status().is_success() {
-        return Err(format!(
-            "Skill market request failed with status {}",
-            response.status()
-        ));
+        return Err(ApiError::new(
+            ApiErrorCode::Network,
+            format!(
+                "Skill market request failed with status {}",
+                response.status()
+            ),
+        )
+        .with_retryable(response.status().is_server_error()));
     }
 
-    let payload: SkillSearchApiResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to decode skill market response: {}", e))?;
+    let payload: SkillSearchApiResponse = response.json().await.map_err(|e| {
+        ApiError::new(
+            ApiErrorCode::Protocol,
+            format!("Failed to decode skill market response: {}", e),
+        )
+    })?;
 
     let mut seen_install_ids: HashSet<String> = HashSet::new();
     let mut items = Vec::new();
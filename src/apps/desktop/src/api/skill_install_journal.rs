@@ -0,0 +1,247 @@
+//! Journal of paths created by market skill installs.
+//!
+//! The npx-based installer gives no account of what it wrote, so installs
+//! snapshot the skill roots before and after running it. Whatever appeared in
+//! between is recorded under an install id and can be removed again, either
+//! right away when the installer fails or later on request.
+
+use bitfun_agent_runtime::skills::{
+    resolve_user_config_skill_root, SkillRootSpec, PROJECT_SKILL_ROOTS, USER_CONFIG_SKILL_ROOTS,
+    USER_HOME_SKILL_ROOTS,
+};
+use bitfun_core::agentic::tools::implementations::skills::SkillLocation;
+use bitfun_core::infrastructure::get_path_manager_arc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+const JOURNAL_FILE_NAME: &str = "skill_installs.json";
+
+/// Serializes installs and undos so snapshots are not attributed to the wrong
+/// install and the journal file is not written concurrently.
+pub(crate) static SKILL_INSTALL_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillInstallRecord {
+    pub install_id: String,
+    pub package: String,
+    pub level: SkillLocation,
+    pub workspace_path: Option<String>,
+    /// Topmost paths the install created; removing them undoes it.
+    pub created_paths: Vec<String>,
+    pub installed_at_ms: u64,
+}
+
+/// Entries of the directories an install may write to, taken before and after
+/// the installer runs.
+#[derive(Debug, Default)]
+pub(crate) struct SkillRootSnapshot {
+    watched_dirs: Vec<PathBuf>,
+    entries: BTreeSet<PathBuf>,
+}
+
+impl SkillRootSnapshot {
+    pub(crate) async fn capture(watched_dirs: Vec<PathBuf>) -> Self {
+        let mut entries = BTreeSet::new();
+        for dir in &watched_dirs {
+            if tokio::fs::metadata(dir).await.is_ok() {
+                entries.insert(dir.clone());
+            }
+            let Ok(mut read_dir) = tokio::fs::read_dir(dir).await else {
+                continue;
+            };
+            while let Ok(Some(entry)) = read_dir.next_entry().await {
+                entries.insert(entry.path());
+            }
+        }
+        Self {
+            watched_dirs,
+            entries,
+        }
+    }
+
+    /// Paths present now but not in `self`, without paths nested in another
+    /// created path.
+    pub(crate) async fn created_since(&self) -> Vec<PathBuf> {
+        let after = Self::capture(self.watched_dirs.clone()).await;
+        let mut created: Vec<PathBuf> = Vec::new();
+        for path in after.entries.difference(&self.entries) {
+            if !created.iter().any(|parent| path.starts_with(parent)) {
+                created.push(path.clone());
+            }
+        }
+        created
+    }
+}
+
+/// Directories an install at `level` may create entries in: each skill root
+/// and its parent (e.g. `.agents` and `.agents/skills`).
+pub(crate) fn watched_skill_dirs(level: SkillLocation, workspace: Option<&Path>) -> Vec<PathBuf> {
+    fn push_spec(watched: &mut Vec<PathBuf>, root: &Path, spec: &SkillRootSpec) {
+        watched.push(root.join(spec.parent));
+        watched.push(root.join(spec.parent).join(spec.subdir));
+    }
+
+    let mut watched = Vec::new();
+    match level {
+        SkillLocation::Project => {
+            if let Some(workspace) = workspace {
+                for spec in PROJECT_SKILL_ROOTS {
+                    push_spec(&mut watched, workspace, spec);
+                }
+            }
+        }
+        SkillLocation::User => {
+            let home = dirs::home_dir();
+            if let Some(home) = home.as_deref() {
+                for spec in USER_HOME_SKILL_ROOTS {
+                    push_spec(&mut watched, home, spec);
+                }
+            }
+            if let Some(config_dir) = dirs::config_dir() {
+                for spec in USER_CONFIG_SKILL_ROOTS {
+                    let root = resolve_user_config_skill_root(spec, &config_dir, home.as_deref());
+                    if let Some(parent) = root.parent() {
+                        watched.push(parent.to_path_buf());
+                    }
+                    watched.push(root);
+                }
+            }
+            watched.push(get_path_manager_arc().user_skills_dir());
+        }
+        SkillLocation::Builtin => {}
+    }
+    watched
+}
+
+/// Removes `paths`, returning the ones that were actually deleted.
+pub(crate) async fn remove_created_paths(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut removed = Vec::new();
+    for path in paths {
+        let Ok(metadata) = tokio::fs::symlink_metadata(path).await else {
+            continue;
+        };
+        let result = if metadata.is_dir() {
+            tokio::fs::remove_dir_all(path).await
+        } else {
+            tokio::fs::remove_file(path).await
+        };
+        match result {
+            Ok(()) => removed.push(path.clone()),
+            Err(e) => log::warn!(
+                "Failed to remove skill install path: path={}, error={}",
+                path.display(),
+                e
+            ),
+        }
+    }
+    removed
+}
+
+fn journal_path() -> PathBuf {
+    get_path_manager_arc()
+        .user_data_dir()
+        .join(JOURNAL_FILE_NAME)
+}
+
+async fn load_journal() -> Vec<SkillInstallRecord> {
+    match tokio::fs::read_to_string(journal_path()).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("Ignoring unreadable skill install journal: {}", e);
+            Vec::new()
+        }),
+        Err(_) => Vec::new(),
+    }
+}
+
+async fn save_journal(records: &[SkillInstallRecord]) -> Result<(), String> {
+    let path = journal_path();
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create skill install journal directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(records)
+        .map_err(|e| format!("Failed to serialize skill install journal: {}", e))?;
+    tokio::fs::write(&path, content)
+        .await
+        .map_err(|e| format!("Failed to write skill install journal: {}", e))
+}
+
+/// Records an install and returns its id. Callers hold [`SKILL_INSTALL_LOCK`].
+pub(crate) async fn record_install(
+    package: &str,
+    level: SkillLocation,
+    workspace_path: Option<&Path>,
+    created_paths: &[PathBuf],
+) -> Result<String, String> {
+    let record = SkillInstallRecord {
+        install_id: uuid::Uuid::new_v4().to_string(),
+        package: package.to_string(),
+        level,
+        workspace_path: workspace_path.map(|path| path.to_string_lossy().to_string()),
+        created_paths: created_paths
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+        installed_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default(),
+    };
+    let install_id = record.install_id.clone();
+    let mut records = load_journal().await;
+    records.push(record);
+    save_journal(&records).await?;
+    Ok(install_id)
+}
+
+/// Removes and returns the record for `install_id`. Callers hold
+/// [`SKILL_INSTALL_LOCK`].
+pub(crate) async fn take_install(install_id: &str) -> Result<SkillInstallRecord, String> {
+    let mut records = load_journal().await;
+    let index = records
+        .iter()
+        .position(|record| record.install_id == install_id)
+        .ok_or_else(|| format!("No recorded skill install with id '{}'", install_id))?;
+    let record = records.remove(index);
+    save_journal(&records).await?;
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn created_paths_are_collapsed_to_their_topmost_entry() {
+        let workspace = tempfile::tempdir().unwrap();
+        let existing = workspace.path().join(".claude/skills/kept");
+        std::fs::create_dir_all(&existing).unwrap();
+
+        let watched = watched_skill_dirs(SkillLocation::Project, Some(workspace.path()));
+        let before = SkillRootSnapshot::capture(watched).await;
+
+        let new_root = workspace.path().join(".agents/skills/review");
+        std::fs::create_dir_all(&new_root).unwrap();
+        std::fs::write(new_root.join("SKILL.md"), "---\nname: review\n---\n").unwrap();
+        let new_skill = workspace.path().join(".claude/skills/lint");
+        std::fs::create_dir_all(&new_skill).unwrap();
+
+        let created = before.created_since().await;
+        assert_eq!(
+            created,
+            vec![workspace.path().join(".agents"), new_skill.clone()]
+        );
+
+        let removed = remove_created_paths(&created).await;
+        assert_eq!(removed.len(), 2);
+        assert!(!workspace.path().join(".agents").exists());
+        assert!(!new_skill.exists());
+        assert!(existing.exists());
+    }
+}
@@ -5,6 +5,7 @@ use bitfun_core::service::remote_ssh::workspace_state::is_remote_path;
 use bitfun_core::service::snapshot::{
     ensure_snapshot_manager_for_workspace, get_snapshot_manager_for_workspace,
     initialize_snapshot_manager_for_workspace, OperationType, SnapshotConfig, SnapshotManager,
+    TurnCheckpoint,
 };
 use bitfun_runtime_ports::{
     LocalWorkspaceSnapshotPort, LocalWorkspaceSnapshotSessionRequest,
@@ -64,6 +65,24 @@ pub struct RollbackTurnRequest {
     pub workspace_path: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListCheckpointsRequest {
+    #[serde(alias = "sessionId")]
+    pub session_id: String,
+    #[serde(alias = "workspacePath")]
+    pub workspace_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreCheckpointRequest {
+    #[serde(alias = "sessionId")]
+    pub session_id: String,
+    #[serde(alias = "turnIndex")]
+    pub turn_index: usize,
+    #[serde(alias = "workspacePath")]
+    pub workspace_path: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcceptSessionRequest {
     #[serde(alias = "sessionId")]
@@ -622,6 +641,54 @@ pub async fn rollback_to_turn(
     Ok(restored_files_str)
 }
 
+#[tauri::command]
+pub async fn list_checkpoints(
+    request: ListCheckpointsRequest,
+) -> Result<Vec<TurnCheckpoint>, String> {
+    if is_remote_path(&request.workspace_path).await {
+        return Err("Checkpoints are not available for remote workspaces".to_string());
+    }
+    let manager = ensure_snapshot_manager_ready(&request.workspace_path).await?;
+
+    manager
+        .list_checkpoints(&request.session_id)
+        .await
+        .map_err(|e| format!("Failed to list checkpoints: {}", e))
+}
+
+/// Restores the workspace files to the checkpoint taken before `turn_index`,
+/// undoing that turn and every later one. The conversation itself is kept.
+#[tauri::command]
+pub async fn restore_checkpoint(
+    app_handle: AppHandle,
+    runtime: State<'_, DesktopRuntimeContext>,
+    request: RestoreCheckpointRequest,
+) -> Result<Vec<String>, String> {
+    if is_remote_path(&request.workspace_path).await {
+        return Err("Checkpoints are not available for remote workspaces".to_string());
+    }
+
+    let restored_files = rollback_to_turn(
+        app_handle,
+        runtime,
+        RollbackTurnRequest {
+            session_id: request.session_id.clone(),
+            turn_index: request.turn_index,
+            delete_turns: false,
+            workspace_path: request.workspace_path,
+        },
+    )
+    .await?;
+    info!(
+        "Checkpoint restored: session_id={}, turn_index={}, files_count={}",
+        request.session_id,
+        request.turn_index,
+        restored_files.len()
+    );
+
+    Ok(restored_files)
+}
+
 #[tauri::command]
 pub async fn accept_session(
     app_handle: AppHandle,
@@ -1,14 +1,26 @@
-//! Desktop adapter for local speech input.
+//! Desktop adapter for speech input and read-aloud.
 
 use crate::api::AppState;
+use bitfun_core::service::config::{get_global_config_service, GlobalConfig, TextToSpeechConfig};
+use bitfun_core::service::runtime::RuntimeManager;
 use bitfun_core_types::speech::{
     SpeechAppendAudioChunkRequest, SpeechAppendAudioChunkResponse, SpeechCancelInputSessionRequest,
     SpeechCancelModelDownloadRequest, SpeechDeleteModelRequest, SpeechDownloadModelRequest,
-    SpeechFinishInputSessionRequest, SpeechInputSession, SpeechListModelsResponse,
-    SpeechModelProgressEvent, SpeechModelStatus, SpeechStartInputSessionRequest,
-    SpeechTranscriptionResult, SpeechVerifyModelRequest,
+    SpeechFinishInputSessionRequest, SpeechInputProvider, SpeechInputSession,
+    SpeechListModelsResponse, SpeechModelProgressEvent, SpeechModelStatus,
+    SpeechPartialTranscriptEvent, SpeechStartInputSessionRequest, SpeechTranscriptionResult,
+    SpeechVerifyModelRequest, SpeechWhisperCppStatus, TextToSpeechListVoicesResponse,
+    TextToSpeechSpeakRequest, TextToSpeechSpeakResult, TextToSpeechStateEvent,
 };
-use bitfun_events::{SPEECH_MODEL_PROGRESS_EVENT, SPEECH_MODEL_STATUS_CHANGED_EVENT};
+use bitfun_events::{
+    SPEECH_MODEL_PROGRESS_EVENT, SPEECH_MODEL_STATUS_CHANGED_EVENT,
+    SPEECH_PARTIAL_TRANSCRIPT_EVENT, SPEECH_TTS_STATE_EVENT,
+};
+use bitfun_services_integrations::speech::{
+    list_whisper_cpp_models, whisper_cpp_models_dir, SpeechCloudEndpoint, SpeechInputBackend,
+    TextToSpeechBackend, WHISPER_CPP_COMMAND,
+};
+use std::path::PathBuf;
 use tauri::{AppHandle, Emitter, State};
 
 #[tauri::command]
@@ -87,14 +99,32 @@ pub async fn speech_verify_model(
     Ok(status)
 }
 
+#[tauri::command]
+pub async fn speech_get_whisper_cpp_status() -> Result<SpeechWhisperCppStatus, String> {
+    let runtime_manager = RuntimeManager::new()
+        .map_err(|error| format!("Failed to resolve managed runtimes: {error}"))?;
+    let models_dir = whisper_cpp_models_dir(runtime_manager.runtime_root());
+    let models = list_whisper_cpp_models(&models_dir)
+        .await
+        .map_err(|error| format!("Failed to list whisper.cpp models: {error}"))?;
+    Ok(SpeechWhisperCppStatus {
+        binary_path: resolve_whisper_cpp_binary(&runtime_manager),
+        models_dir,
+        models,
+    })
+}
+
 #[tauri::command]
 pub async fn speech_start_input_session(
     state: State<'_, AppState>,
     request: SpeechStartInputSessionRequest,
 ) -> Result<SpeechInputSession, String> {
+    let backend = resolve_input_backend(&request)
+        .await
+        .map_err(|error| format!("Failed to start speech input session: {error}"))?;
     state
         .speech_service
-        .start_input_session(request)
+        .start_input_session(request, backend)
         .await
         .map_err(|error| format!("Failed to start speech input session: {error}"))
 }
@@ -102,11 +132,16 @@ pub async fn speech_start_input_session(
 #[tauri::command]
 pub async fn speech_append_audio_chunk(
     state: State<'_, AppState>,
+    app: AppHandle,
     request: SpeechAppendAudioChunkRequest,
 ) -> Result<SpeechAppendAudioChunkResponse, String> {
     state
         .speech_service
-        .append_audio_chunk(request)
+        .append_audio_chunk(request, move |event: SpeechPartialTranscriptEvent| {
+            if let Err(error) = app.emit(SPEECH_PARTIAL_TRANSCRIPT_EVENT, &event) {
+                log::warn!("Failed to emit partial transcript event: {error}");
+            }
+        })
         .await
         .map_err(|error| format!("Failed to append speech audio chunk: {error}"))
 }
@@ -135,6 +170,177 @@ pub async fn speech_cancel_input_session(
         .map_err(|error| format!("Failed to cancel speech input session: {error}"))
 }
 
+#[tauri::command]
+pub async fn speech_list_voices(
+    state: State<'_, AppState>,
+) -> Result<TextToSpeechListVoicesResponse, String> {
+    let config = load_text_to_speech_config()
+        .await
+        .map_err(|error| format!("Failed to list voices: {error}"))?;
+    let backend = resolve_speech_output_backend(&config)
+        .await
+        .map_err(|error| format!("Failed to list voices: {error}"))?;
+    state
+        .text_to_speech_service
+        .list_voices(&backend)
+        .await
+        .map_err(|error| format!("Failed to list voices: {error}"))
+}
+
+#[tauri::command]
+pub async fn speech_speak(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    request: TextToSpeechSpeakRequest,
+) -> Result<TextToSpeechSpeakResult, String> {
+    let config = load_text_to_speech_config()
+        .await
+        .map_err(|error| format!("Failed to speak text: {error}"))?;
+    let backend = resolve_speech_output_backend(&config)
+        .await
+        .map_err(|error| format!("Failed to speak text: {error}"))?;
+    let voice = request
+        .voice
+        .filter(|voice| !voice.trim().is_empty())
+        .or_else(|| configured_voice(&config, request.language.as_deref()));
+    state
+        .text_to_speech_service
+        .speak(
+            &request.text,
+            voice,
+            config.rate,
+            backend,
+            move |event: TextToSpeechStateEvent| {
+                if let Err(error) = app.emit(SPEECH_TTS_STATE_EVENT, &event) {
+                    log::warn!("Failed to emit text-to-speech state event: {error}");
+                }
+            },
+        )
+        .await
+        .map_err(|error| format!("Failed to speak text: {error}"))
+}
+
+#[tauri::command]
+pub async fn speech_stop_speaking(state: State<'_, AppState>) -> Result<Option<String>, String> {
+    Ok(state.text_to_speech_service.stop_speaking().await)
+}
+
+async fn resolve_input_backend(
+    request: &SpeechStartInputSessionRequest,
+) -> Result<SpeechInputBackend, String> {
+    match request.provider {
+        SpeechInputProvider::Local => Ok(SpeechInputBackend::LocalModel),
+        SpeechInputProvider::WhisperCpp => {
+            let runtime_manager = RuntimeManager::new().map_err(|error| error.to_string())?;
+            let binary = resolve_whisper_cpp_binary(&runtime_manager).ok_or_else(|| {
+                format!(
+                    "{WHISPER_CPP_COMMAND} was not found; install the whisper.cpp runtime component"
+                )
+            })?;
+            Ok(SpeechInputBackend::WhisperCpp {
+                binary,
+                models_dir: whisper_cpp_models_dir(runtime_manager.runtime_root()),
+            })
+        }
+        SpeechInputProvider::Cloud => {
+            let model_id = request
+                .model_id
+                .as_deref()
+                .ok_or_else(|| "No cloud speech model is selected".to_string())?;
+            let config = get_global_config_service()
+                .await
+                .map_err(|error| error.to_string())?
+                .get_config::<GlobalConfig>(None)
+                .await
+                .map_err(|error| error.to_string())?;
+            let model = config
+                .ai
+                .models
+                .into_iter()
+                .find(|model| model.id == model_id)
+                .ok_or_else(|| format!("Cloud speech model not found: {model_id}"))?;
+            let request_url = model
+                .request_url
+                .filter(|url| !url.trim().is_empty())
+                .unwrap_or_else(|| {
+                    format!(
+                        "{}/audio/transcriptions",
+                        model.base_url.trim_end_matches('/')
+                    )
+                });
+            Ok(SpeechInputBackend::Cloud(SpeechCloudEndpoint {
+                request_url,
+                api_key: model.api_key,
+                model_name: model.model_name,
+            }))
+        }
+    }
+}
+
+async fn load_text_to_speech_config() -> Result<TextToSpeechConfig, String> {
+    get_global_config_service()
+        .await
+        .map_err(|error| error.to_string())?
+        .get_config::<TextToSpeechConfig>(Some("app.ai_experience.text_to_speech"))
+        .await
+        .map_err(|error| error.to_string())
+}
+
+async fn resolve_speech_output_backend(
+    config: &TextToSpeechConfig,
+) -> Result<TextToSpeechBackend, String> {
+    if config.provider != "cloud" {
+        return Ok(TextToSpeechBackend::System);
+    }
+    let model_id = config.cloud_model_config_id.as_str();
+    if model_id.is_empty() {
+        return Err("No cloud speech model is selected".to_string());
+    }
+    let global = get_global_config_service()
+        .await
+        .map_err(|error| error.to_string())?
+        .get_config::<GlobalConfig>(None)
+        .await
+        .map_err(|error| error.to_string())?;
+    let model = global
+        .ai
+        .models
+        .into_iter()
+        .find(|model| model.id == model_id)
+        .ok_or_else(|| format!("Cloud speech model not found: {model_id}"))?;
+    // The model entry supplies the provider endpoint and key; its own request
+    // URL points at chat completions, so the speech route is derived instead.
+    Ok(TextToSpeechBackend::Cloud(SpeechCloudEndpoint {
+        request_url: format!("{}/audio/speech", model.base_url.trim_end_matches('/')),
+        api_key: model.api_key,
+        model_name: config.cloud_model_name.clone(),
+    }))
+}
+
+/// Picks the configured voice for `language`, trying the exact tag, then its
+/// primary subtag, then the `default` entry.
+fn configured_voice(config: &TextToSpeechConfig, language: Option<&str>) -> Option<String> {
+    let mut keys = Vec::new();
+    if let Some(language) = language {
+        keys.push(language);
+        if let Some((primary, _)) = language.split_once(['-', '_']) {
+            keys.push(primary);
+        }
+    }
+    keys.push("default");
+    keys.into_iter()
+        .filter_map(|key| config.voices.get(key))
+        .find(|voice| !voice.trim().is_empty())
+        .cloned()
+}
+
+fn resolve_whisper_cpp_binary(runtime_manager: &RuntimeManager) -> Option<PathBuf> {
+    runtime_manager
+        .resolve_command(WHISPER_CPP_COMMAND)
+        .and_then(|resolved| resolved.resolved_path)
+        .map(PathBuf::from)
+}
+
 fn emit_status(app: &AppHandle, status: &SpeechModelStatus) {
     if let Err(error) = app.emit(SPEECH_MODEL_STATUS_CHANGED_EVENT, status) {
         log::warn!("Failed to emit speech model status event: {error}");
@@ -1,7 +1,10 @@
 //! Storage Management API
 
 use crate::api::AppState;
-use bitfun_core::infrastructure::storage::{CleanupPolicy, CleanupResult, CleanupService};
+use bitfun_core::infrastructure::storage::{
+    get_disk_cache, CacheCategory, CacheEvictionResult, CacheUsageReport, CleanupPolicy,
+    CleanupResult, CleanupService,
+};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::State;
@@ -120,6 +123,24 @@ pub async fn get_storage_statistics(state: State<'_, AppState>) -> Result<Storag
     })
 }
 
+/// Disk usage of each cache category against its quota.
+#[tauri::command]
+pub async fn get_cache_usage() -> Result<CacheUsageReport, String> {
+    tokio::task::spawn_blocking(|| get_disk_cache().usage())
+        .await
+        .map_err(|e| format!("Cache usage task failed: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
+/// Empties `category`, or every cache category when it is omitted.
+#[tauri::command]
+pub async fn clear_cache(category: Option<CacheCategory>) -> Result<CacheEvictionResult, String> {
+    tokio::task::spawn_blocking(move || get_disk_cache().clear(category))
+        .await
+        .map_err(|e| format!("Cache clear task failed: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn initialize_project_storage(
     state: State<'_, AppState>,
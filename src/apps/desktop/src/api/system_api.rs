@@ -5,6 +5,10 @@ use std::sync::{Arc, Mutex, OnceLock};
 
 use crate::api::app_state::AppState;
 use crate::startup_trace::DesktopStartupTrace;
+use bitfun_core::infrastructure::app_paths::DataLocation;
+use bitfun_core::infrastructure::try_get_path_manager_arc;
+use bitfun_core::service::connectivity::get_global_connectivity_service;
+use bitfun_core::service::first_launch::FirstLaunchHandoff;
 use bitfun_core::service::system;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter, Manager, Position, Size, State};
@@ -54,6 +58,12 @@ pub async fn get_app_version(
     Ok(app.package_info().version.to_string())
 }
 
+/// Installer handoff for the first launch after an install; `None` otherwise.
+#[tauri::command]
+pub async fn get_first_launch_context() -> Result<Option<FirstLaunchHandoff>, String> {
+    Ok(crate::first_launch::installer_handoff())
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckForUpdatesRequest {}
@@ -75,6 +85,12 @@ pub async fn check_for_updates(
     request: CheckForUpdatesRequest,
 ) -> Result<CheckForUpdatesResponse, String> {
     let _ = request;
+    if let Some(connectivity) = get_global_connectivity_service() {
+        connectivity
+            .ensure_online("Update check")
+            .await
+            .map_err(|e| e.to_string())?;
+    }
     let updater = app.updater().map_err(|e| e.to_string())?;
     let update = updater.check().await.map_err(|e| e.to_string())?;
     match update {
@@ -103,11 +119,18 @@ pub struct InstallUpdateRequest {}
 #[tauri::command]
 pub async fn install_update(app: AppHandle, request: InstallUpdateRequest) -> Result<(), String> {
     let _ = request;
+    if let Some(connectivity) = get_global_connectivity_service() {
+        connectivity
+            .ensure_online("Update check")
+            .await
+            .map_err(|e| e.to_string())?;
+    }
     let updater = app.updater().map_err(|e| e.to_string())?;
     let update = updater.check().await.map_err(|e| e.to_string())?;
     let Some(update) = update else {
         return Err("No update available".to_string());
     };
+    crate::api::backup_api::create_pre_update_backup().await;
     let app_handle = app.clone();
     let progress = Arc::new(Mutex::new((0u64, None::<u64>)));
     let progress_chunk = Arc::clone(&progress);
@@ -202,6 +225,31 @@ pub async fn restart_app(app: AppHandle, request: RestartAppRequest) -> Result<(
     Ok(())
 }
 
+/// Current user data directory and any relocation waiting for restart.
+#[tauri::command]
+pub async fn get_data_location() -> Result<DataLocation, String> {
+    try_get_path_manager_arc()
+        .and_then(|manager| manager.data_location())
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelocateDataDirRequest {
+    pub path: String,
+}
+
+/// Schedules moving user data to `path`; the move happens when the app
+/// restarts, which the frontend triggers with `restart_app`.
+#[tauri::command]
+pub async fn relocate_data_dir(request: RelocateDataDirRequest) -> Result<DataLocation, String> {
+    let manager = try_get_path_manager_arc().map_err(|e| e.to_string())?;
+    manager
+        .relocate_data_dir(Path::new(request.path.trim()))
+        .and_then(|()| manager.data_location())
+        .map_err(|e| e.to_string())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckCommandResponse {
@@ -77,6 +77,9 @@ impl TerminalState {
                         config.env.insert("Path".to_string(), merged_path);
                     }
                 }
+                // Managed Git defaults only; credential prompts stay in the
+                // terminal where the user typed the command.
+                config.env.extend(runtime_manager.managed_git_config_env());
             }
 
             let api = TerminalApi::new(config).await;
@@ -0,0 +1,118 @@
+//! Theme registry Tauri commands.
+
+use bitfun_core::infrastructure::get_path_manager_arc;
+use bitfun_core::service::theme::{ThemeCatalog, ThemeDefinition, ThemeRegistry};
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+/// Emitted to every window after `themes.current` changes.
+pub const THEME_UPDATED_EVENT: &str = "bitfun_theme_updated";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeIdRequest {
+    pub theme_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveUserThemeRequest {
+    pub theme: ThemeDefinition,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeUpdatedPayload {
+    pub theme_id: String,
+    /// Full token set when the active theme is a user theme.
+    pub user_theme: Option<ThemeDefinition>,
+}
+
+fn theme_registry() -> ThemeRegistry {
+    ThemeRegistry::from_path_manager(&get_path_manager_arc())
+}
+
+/// List `system`, built-in and user themes, plus invalid user theme files.
+#[tauri::command]
+pub async fn list_themes() -> Result<ThemeCatalog, String> {
+    Ok(theme_registry().list())
+}
+
+/// Load a user theme definition. Returns `None` for built-in ids.
+#[tauri::command]
+pub async fn get_user_theme(request: ThemeIdRequest) -> Result<Option<ThemeDefinition>, String> {
+    theme_registry()
+        .get_user_theme(&request.theme_id)
+        .map_err(|e| format!("Failed to load theme: {}", e))
+}
+
+/// Validate and write a user theme file.
+#[tauri::command]
+pub async fn save_user_theme(request: SaveUserThemeRequest) -> Result<(), String> {
+    theme_registry()
+        .save_user_theme(&request.theme)
+        .map_err(|e| format!("Failed to save theme: {}", e))
+}
+
+/// Delete a user theme file. Returns `false` when it did not exist.
+#[tauri::command]
+pub async fn delete_user_theme(request: ThemeIdRequest) -> Result<bool, String> {
+    theme_registry()
+        .delete_user_theme(&request.theme_id)
+        .map_err(|e| format!("Failed to delete theme: {}", e))
+}
+
+/// The active theme id, falling back to the default when it no longer resolves.
+#[tauri::command]
+pub async fn get_current_theme() -> Result<String, String> {
+    theme_registry()
+        .current_theme_id()
+        .await
+        .map_err(|e| format!("Failed to get current theme: {}", e))
+}
+
+/// Validate and persist the active theme. Windows are updated through
+/// `THEME_UPDATED_EVENT` once the config bus reports the change.
+#[tauri::command]
+pub async fn set_theme(request: ThemeIdRequest) -> Result<(), String> {
+    theme_registry()
+        .set_current_theme(&request.theme_id)
+        .await
+        .map_err(|e| format!("Failed to set theme: {}", e))
+}
+
+/// Forward `ConfigUpdateEvent::ThemeUpdated` to the frontend so theme changes
+/// made from any surface (settings, remote, installer re-run) apply live.
+pub fn spawn_theme_update_listener(app_handle: tauri::AppHandle) {
+    use bitfun_core::service::config::{subscribe_config_updates, ConfigUpdateEvent};
+
+    tokio::spawn(async move {
+        let Some(mut receiver) = subscribe_config_updates() else {
+            log::warn!("Config update subscription unavailable for theme listener");
+            return;
+        };
+
+        loop {
+            match receiver.recv().await {
+                Ok(ConfigUpdateEvent::ThemeUpdated { theme_id }) => {
+                    let user_theme = theme_registry().get_user_theme(&theme_id).ok().flatten();
+                    let payload = ThemeUpdatedPayload {
+                        theme_id,
+                        user_theme,
+                    };
+                    if let Err(e) = app_handle.emit(THEME_UPDATED_EVENT, &payload) {
+                        log::warn!("Failed to emit theme update event: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    log::warn!("Theme listener channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    log::warn!("Theme listener lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
@@ -8,8 +8,10 @@ use std::sync::Arc;
 use tauri::State;
 
 use bitfun_agent_runtime::sdk::AgentUserAnswersRequest;
+use bitfun_agent_tools::{ToolSearchMatch, DEFAULT_TOOL_SEARCH_LIMIT};
 use bitfun_core::agentic::{
     tools::framework::ToolUseContext,
+    tools::implementations::search_available_tools,
     tools::{get_all_tools, get_readonly_tools},
     workspace::{local_workspace_services, remote_workspace_services},
     WorkspaceBinding,
@@ -38,6 +40,14 @@ pub struct GetToolInfoRequest {
     pub tool_name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchToolsRequest {
+    pub query: String,
+    pub limit: Option<usize>,
+    pub workspace_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DynamicMcpToolInfo {
@@ -292,6 +302,17 @@ pub async fn get_tool_info(request: GetToolInfoRequest) -> Result<Option<ToolInf
     Ok(None)
 }
 
+#[tauri::command]
+pub async fn search_tools(request: SearchToolsRequest) -> Result<Vec<ToolSearchMatch>, String> {
+    let context = match request.workspace_path.as_deref() {
+        Some(path) if !path.trim().is_empty() => Some(build_tool_context(Some(path)).await),
+        _ => None,
+    };
+    let limit = request.limit.unwrap_or(DEFAULT_TOOL_SEARCH_LIMIT);
+
+    Ok(search_available_tools(&request.query, limit, context.as_ref()).await)
+}
+
 #[tauri::command]
 pub async fn validate_tool_input(
     request: ToolValidationRequest,
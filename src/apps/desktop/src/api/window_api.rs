@@ -0,0 +1,230 @@
+//! Secondary window API.
+//!
+//! Log viewer and terminal windows are single-instance per kind and keep
+//! their own geometry in the window layout, like the main window.
+
+use crate::window_layout::{self, WindowGeometry};
+use bitfun_core::util::errors::ApiError;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{AppHandle, Manager};
+
+const SECONDARY_WINDOW_MIN_WIDTH: f64 = 480.0;
+const SECONDARY_WINDOW_MIN_HEIGHT: f64 = 320.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecondaryWindowKind {
+    LogViewer,
+    Terminal,
+}
+
+impl SecondaryWindowKind {
+    pub const ALL: [SecondaryWindowKind; 2] = [Self::LogViewer, Self::Terminal];
+
+    /// Tauri window label; also listed in `capabilities/default.json`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::LogViewer => "secondary-log-viewer",
+            Self::Terminal => "secondary-terminal",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.label() == label)
+    }
+
+    /// `bitfunWindow` query value the frontend renders for this window.
+    fn route(self) -> &'static str {
+        match self {
+            Self::LogViewer => "log-viewer",
+            Self::Terminal => "terminal",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::LogViewer => "BitFun Logs",
+            Self::Terminal => "BitFun Terminal",
+        }
+    }
+
+    fn default_size(self) -> (f64, f64) {
+        match self {
+            Self::LogViewer => (960.0, 640.0),
+            Self::Terminal => (900.0, 560.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecondaryWindowInfo {
+    pub kind: SecondaryWindowKind,
+    pub label: String,
+    pub visible: bool,
+    pub focused: bool,
+    pub geometry: Option<WindowGeometry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenSecondaryWindowRequest {
+    pub kind: SecondaryWindowKind,
+    /// Extra query parameters for the window's page, e.g. a terminal session id.
+    /// Ignored when the window is already open.
+    #[serde(default)]
+    pub query: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseSecondaryWindowRequest {
+    pub kind: SecondaryWindowKind,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetWindowLayoutRequest {
+    /// Resets every window, including the main one, when omitted.
+    #[serde(default)]
+    pub kind: Option<SecondaryWindowKind>,
+}
+
+fn window_url_path(kind: SecondaryWindowKind, query: &BTreeMap<String, String>) -> String {
+    let mut path = format!("?bitfunWindow={}", kind.route());
+    for (key, value) in query {
+        if key == "bitfunWindow" {
+            continue;
+        }
+        path.push('&');
+        path.push_str(&urlencoding::encode(key));
+        path.push('=');
+        path.push_str(&urlencoding::encode(value));
+    }
+    path
+}
+
+fn window_info(
+    app: &AppHandle,
+    kind: SecondaryWindowKind,
+    window: &tauri::WebviewWindow,
+) -> SecondaryWindowInfo {
+    SecondaryWindowInfo {
+        kind,
+        label: kind.label().to_string(),
+        visible: window.is_visible().unwrap_or(false),
+        focused: window.is_focused().unwrap_or(false),
+        geometry: window_layout::saved_geometry(app, kind.label()),
+    }
+}
+
+fn window_error(kind: SecondaryWindowKind, action: &str, error: tauri::Error) -> ApiError {
+    ApiError::internal(format!(
+        "Failed to {} {} window: {}",
+        action,
+        kind.route(),
+        error
+    ))
+}
+
+/// Opens the window for `kind`, or focuses it when it is already open.
+#[tauri::command]
+pub async fn open_secondary_window(
+    app: AppHandle,
+    request: OpenSecondaryWindowRequest,
+) -> Result<SecondaryWindowInfo, ApiError> {
+    let kind = request.kind;
+    if let Some(window) = app.get_webview_window(kind.label()) {
+        if let Err(e) = window.unminimize() {
+            warn!(
+                "Failed to unminimize secondary window: kind={:?}, error={}",
+                kind, e
+            );
+        }
+        window.show().map_err(|e| window_error(kind, "show", e))?;
+        window
+            .set_focus()
+            .map_err(|e| window_error(kind, "focus", e))?;
+        return Ok(window_info(&app, kind, &window));
+    }
+
+    let (width, height) = kind.default_size();
+    let url = crate::theme::app_url(&window_url_path(kind, &request.query));
+    let window = tauri::WebviewWindowBuilder::new(&app, kind.label(), url)
+        .title(kind.title())
+        .inner_size(width, height)
+        .min_inner_size(SECONDARY_WINDOW_MIN_WIDTH, SECONDARY_WINDOW_MIN_HEIGHT)
+        .center()
+        .resizable(true)
+        .visible(false)
+        .disable_drag_drop_handler()
+        .build()
+        .map_err(|e| window_error(kind, "create", e))?;
+    window_layout::restore_window(&window);
+    window.show().map_err(|e| window_error(kind, "show", e))?;
+    if let Err(e) = window.set_focus() {
+        warn!(
+            "Failed to focus secondary window: kind={:?}, error={}",
+            kind, e
+        );
+    }
+    debug!("Secondary window opened: kind={:?}", kind);
+    Ok(window_info(&app, kind, &window))
+}
+
+/// Closes the window for `kind`; its geometry is saved on the way out.
+#[tauri::command]
+pub async fn close_secondary_window(
+    app: AppHandle,
+    request: CloseSecondaryWindowRequest,
+) -> Result<(), ApiError> {
+    if let Some(window) = app.get_webview_window(request.kind.label()) {
+        window
+            .close()
+            .map_err(|e| window_error(request.kind, "close", e))?;
+    }
+    Ok(())
+}
+
+/// Secondary windows that are currently open.
+#[tauri::command]
+pub async fn list_secondary_windows(app: AppHandle) -> Result<Vec<SecondaryWindowInfo>, ApiError> {
+    Ok(SecondaryWindowKind::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            app.get_webview_window(kind.label())
+                .map(|window| window_info(&app, kind, &window))
+        })
+        .collect())
+}
+
+/// Forgets saved geometry so windows open at their default placement next time.
+#[tauri::command]
+pub async fn reset_window_layout(request: ResetWindowLayoutRequest) -> Result<(), ApiError> {
+    window_layout::reset(request.kind.map(SecondaryWindowKind::label));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_url_path_encodes_query_and_keeps_route() {
+        let query = BTreeMap::from([
+            ("bitfunWindow".to_string(), "main".to_string()),
+            ("sessionId".to_string(), "a b&c".to_string()),
+        ]);
+        assert_eq!(
+            window_url_path(SecondaryWindowKind::Terminal, &query),
+            "?bitfunWindow=terminal&sessionId=a%20b%26c"
+        );
+        assert_eq!(
+            SecondaryWindowKind::from_label("secondary-log-viewer"),
+            Some(SecondaryWindowKind::LogViewer)
+        );
+        assert_eq!(SecondaryWindowKind::from_label("main"), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Workspace trust API.
+
+use bitfun_core::service::workspace_trust::{
+    get_global_workspace_trust_service, WorkspaceTrustDecision, WorkspaceTrustInfo,
+    WorkspaceTrustRecord, WorkspaceTrustService, WorkspaceTrustState,
+};
+use log::{debug, error, warn};
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
+use tauri::Emitter;
+
+/// Emitted with a `WorkspaceTrustInfo` payload when a folder without a trust
+/// decision is opened and the user should be asked.
+pub const WORKSPACE_TRUST_REQUIRED_EVENT: &str = "workspace_trust_required";
+
+/// Emitted with a `WorkspaceTrustInfo` payload after a trust decision changes.
+pub const WORKSPACE_TRUST_CHANGED_EVENT: &str = "workspace_trust_changed";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTrustPathRequest {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWorkspaceTrustRequest {
+    pub path: String,
+    pub decision: WorkspaceTrustDecision,
+}
+
+fn workspace_trust_service() -> Result<Arc<WorkspaceTrustService>, String> {
+    get_global_workspace_trust_service()
+        .ok_or_else(|| "Workspace trust service is not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn get_workspace_trust(
+    request: WorkspaceTrustPathRequest,
+) -> Result<WorkspaceTrustInfo, String> {
+    let service = workspace_trust_service()?;
+    Ok(service.trust_info(Path::new(&request.path)))
+}
+
+#[tauri::command]
+pub async fn set_workspace_trust(
+    request: SetWorkspaceTrustRequest,
+) -> Result<WorkspaceTrustInfo, String> {
+    debug!(
+        "Setting workspace trust: path={}, decision={:?}",
+        request.path, request.decision
+    );
+
+    let service = workspace_trust_service()?;
+    service
+        .set_decision(Path::new(&request.path), request.decision)
+        .await
+        .map_err(|error| {
+            error!("Failed to set workspace trust: {}", error);
+            format!("Failed to set workspace trust: {}", error)
+        })
+}
+
+#[tauri::command]
+pub async fn list_workspace_trust() -> Result<Vec<WorkspaceTrustRecord>, String> {
+    let service = workspace_trust_service()?;
+    Ok(service.list_decisions())
+}
+
+#[tauri::command]
+pub async fn forget_workspace_trust(request: WorkspaceTrustPathRequest) -> Result<bool, String> {
+    let service = workspace_trust_service()?;
+    service
+        .forget_decision(Path::new(&request.path))
+        .await
+        .map_err(|error| format!("Failed to forget workspace trust: {}", error))
+}
+
+/// Asks the frontend for a trust decision when `workspace_root` has none yet.
+pub fn request_trust_if_undecided(app_handle: &tauri::AppHandle, workspace_root: &Path) {
+    let Some(service) = get_global_workspace_trust_service() else {
+        return;
+    };
+    let info = service.trust_info(workspace_root);
+    if info.state != WorkspaceTrustState::Unknown {
+        return;
+    }
+    if let Err(e) = app_handle.emit(WORKSPACE_TRUST_REQUIRED_EVENT, &info) {
+        warn!("Failed to emit workspace trust prompt: {}", e);
+    }
+}
+
+/// Forwards trust decision changes to every window.
+pub fn spawn_workspace_trust_event_forwarder(app_handle: tauri::AppHandle) {
+    let Some(service) = get_global_workspace_trust_service() else {
+        warn!("Workspace trust service unavailable; trust events will not be forwarded");
+        return;
+    };
+    let mut receiver = service.subscribe();
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(info) => {
+                    if let Err(e) = app_handle.emit(WORKSPACE_TRUST_CHANGED_EVENT, &info) {
+                        warn!("Failed to emit workspace trust event: {}", e);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                    warn!("Workspace trust event channel closed");
+                    break;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("Workspace trust event forwarder lagged by {} messages", n);
+                }
+            }
+        }
+    });
+}
@@ -78,6 +78,11 @@ struct CrashReport {
 #[serde(rename_all = "camelCase")]
 pub struct DiagnosticsBundleInfo {
     pub bundle_path: String,
+    pub size_bytes: u64,
+    pub log_session_count: usize,
+    /// One-line description in the configured locale, filled in by the command layer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -211,6 +216,22 @@ pub fn write_panic_report(
 }
 
 pub fn export_diagnostics_bundle() -> Result<DiagnosticsBundleInfo, String> {
+    write_bundle("bitfun-diagnostics", &[], false)
+}
+
+/// Bundle for bug reports: the crash diagnostics plus the host's `reports`,
+/// with logs run through the diagnostic redaction rules.
+pub fn export_support_bundle(
+    reports: &[(&str, serde_json::Value)],
+) -> Result<DiagnosticsBundleInfo, String> {
+    write_bundle("bitfun-support", reports, true)
+}
+
+fn write_bundle(
+    file_prefix: &str,
+    reports: &[(&str, serde_json::Value)],
+    redact_logs: bool,
+) -> Result<DiagnosticsBundleInfo, String> {
     let context = CURRENT_RUN_CONTEXT
         .get()
         .cloned()
@@ -221,7 +242,8 @@ pub fn export_diagnostics_bundle() -> Result<DiagnosticsBundleInfo, String> {
         .map_err(|error| format!("Failed to create diagnostics directory: {}", error))?;
 
     let filename = format!(
-        "bitfun-diagnostics-{}.zip",
+        "{}-{}.zip",
+        file_prefix,
         Local::now().format("%Y%m%dT%H%M%S")
     );
     let bundle_path = diagnostics_dir.join(filename);
@@ -242,12 +264,23 @@ pub fn export_diagnostics_bundle() -> Result<DiagnosticsBundleInfo, String> {
         platform_crash_report_hints: platform_crash_report_hints(),
     };
     add_json_entry(&mut zip, DIAGNOSTIC_METADATA_FILE, &metadata, options)?;
+    for (archive_path, report) in reports {
+        add_json_entry(&mut zip, archive_path, report, options)?;
+    }
 
     if context.run_state_path.exists() {
-        add_file_entry(&mut zip, &context.run_state_path, RUN_STATE_FILE, options)?;
+        add_file_entry(
+            &mut zip,
+            &context.run_state_path,
+            RUN_STATE_FILE,
+            options,
+            redact_logs,
+        )?;
     }
 
-    for session_dir in recent_session_dirs(&context.logs_root, MAX_BUNDLED_LOG_SESSIONS)? {
+    let session_dirs = recent_session_dirs(&context.logs_root, MAX_BUNDLED_LOG_SESSIONS)?;
+    let log_session_count = session_dirs.len();
+    for session_dir in session_dirs {
         let name = session_dir
             .file_name()
             .and_then(|value| value.to_str())
@@ -257,14 +290,21 @@ pub fn export_diagnostics_bundle() -> Result<DiagnosticsBundleInfo, String> {
             &session_dir,
             &format!("sessions/{}", name),
             options,
+            redact_logs,
         )?;
     }
 
     zip.finish()
         .map_err(|error| format!("Failed to finish diagnostics bundle: {}", error))?;
+    let size_bytes = fs::metadata(&bundle_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or_default();
 
     Ok(DiagnosticsBundleInfo {
         bundle_path: bundle_path.to_string_lossy().to_string(),
+        size_bytes,
+        log_session_count,
+        summary: None,
     })
 }
 
@@ -344,6 +384,7 @@ fn add_directory_entries(
     dir: &Path,
     archive_prefix: &str,
     options: SimpleFileOptions,
+    redact: bool,
 ) -> Result<(), String> {
     for entry in fs::read_dir(dir)
         .map_err(|error| format!("Failed to read directory {}: {}", dir.display(), error))?
@@ -354,9 +395,9 @@ fn add_directory_entries(
         let archive_path = format!("{}/{}", archive_prefix, name);
 
         if path.is_dir() {
-            add_directory_entries(zip, &path, &archive_path, options)?;
+            add_directory_entries(zip, &path, &archive_path, options, redact)?;
         } else if path.is_file() {
-            add_file_entry(zip, &path, &archive_path, options)?;
+            add_file_entry(zip, &path, &archive_path, options, redact)?;
         }
     }
 
@@ -391,7 +432,11 @@ fn add_file_entry(
     source_path: &Path,
     archive_path: &str,
     options: SimpleFileOptions,
+    redact: bool,
 ) -> Result<(), String> {
+    if redact {
+        return add_redacted_file_entry(zip, source_path, archive_path, options);
+    }
     let mut file = File::open(source_path)
         .map_err(|error| format!("Failed to open {}: {}", source_path.display(), error))?;
     zip.start_file(normalize_archive_path(archive_path), options)
@@ -419,6 +464,32 @@ fn add_file_entry(
     Ok(())
 }
 
+fn add_redacted_file_entry(
+    zip: &mut zip::ZipWriter<File>,
+    source_path: &Path,
+    archive_path: &str,
+    options: SimpleFileOptions,
+) -> Result<(), String> {
+    let content = fs::read(source_path)
+        .map_err(|error| format!("Failed to read {}: {}", source_path.display(), error))?;
+    let redacted = bitfun_core::service::diagnostics::redact_diagnostic_log_text(
+        &String::from_utf8_lossy(&content),
+    );
+    zip.start_file(normalize_archive_path(archive_path), options)
+        .map_err(|error| {
+            format!(
+                "Failed to add {} to diagnostics bundle: {}",
+                archive_path, error
+            )
+        })?;
+    zip.write_all(redacted.as_bytes()).map_err(|error| {
+        format!(
+            "Failed to write {} to diagnostics bundle: {}",
+            archive_path, error
+        )
+    })
+}
+
 fn normalize_archive_path(path: &str) -> String {
     path.replace('\\', "/")
 }
@@ -567,4 +638,38 @@ mod tests {
 
         let _ = fs::remove_dir_all(temp_dir);
     }
+
+    #[test]
+    fn redacted_log_entries_leave_secrets_out_of_the_bundle() {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let log_path = temp.path().join("app.log");
+        fs::write(
+            &log_path,
+            "[INFO][ai::openai] connecting\nAuthorization: Bearer live-provider-token\n",
+        )
+        .expect("write test log");
+        let bundle_path = temp.path().join("bundle.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&bundle_path).expect("create bundle"));
+
+        add_file_entry(
+            &mut zip,
+            &log_path,
+            "logs\\app.log",
+            SimpleFileOptions::default(),
+            true,
+        )
+        .expect("add redacted log entry");
+        zip.finish().expect("finish bundle");
+
+        let mut archive = zip::ZipArchive::new(File::open(&bundle_path).expect("open bundle"))
+            .expect("read bundle");
+        let mut content = String::new();
+        archive
+            .by_name("logs/app.log")
+            .expect("log entry should use forward slashes")
+            .read_to_string(&mut content)
+            .expect("read log entry");
+        assert!(content.contains("[ai::openai] connecting"));
+        assert!(!content.contains("live-provider-token"));
+    }
 }
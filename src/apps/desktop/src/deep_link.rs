@@ -0,0 +1,293 @@
+//! `bitfun://` deep link handling.
+//!
+//! Supported actions:
+//! - `bitfun://open?path=<workspace path>`
+//! - `bitfun://install-skill?id=<market skill id>`
+//! - `bitfun://add-mcp-server?config=<url-encoded JSON server config>`
+//!
+//! Links arrive as process arguments, either on cold start or forwarded by the
+//! single-instance plugin to the running instance (and via `RunEvent::Opened`
+//! on macOS). Every link is confirmed with a native dialog before it reaches the
+//! frontend, because links can be triggered by any web page.
+//!
+//! The scheme is registered by `Info.plist` on macOS, the installer's registry
+//! entries on Windows and the `MimeType` of `linux/bitfun-desktop.desktop` on
+//! Linux.
+
+use log::{info, warn};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tauri::{Emitter, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+pub const DEEP_LINK_SCHEME: &str = "bitfun";
+pub const DEEP_LINK_RECEIVED_EVENT: &str = "bitfun_deep_link_received";
+
+/// Upper bound for a single link; protects the confirmation dialog and parser
+/// from oversized payloads pasted into a browser address bar.
+const MAX_DEEP_LINK_LEN: usize = 16 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum DeepLinkAction {
+    Open {
+        path: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    InstallSkill {
+        skill_id: String,
+    },
+    AddMcpServer {
+        config: serde_json::Value,
+    },
+}
+
+impl DeepLinkAction {
+    fn confirmation_message(&self) -> String {
+        match self {
+            Self::Open { path } => {
+                format!("A link wants to open this folder in BitFun:\n\n{}", path)
+            }
+            Self::InstallSkill { skill_id } => format!(
+                "A link wants to install the skill \"{}\" from the skill market.",
+                skill_id
+            ),
+            Self::AddMcpServer { config } => {
+                let summary =
+                    serde_json::to_string_pretty(config).unwrap_or_else(|_| config.to_string());
+                format!(
+                    "A link wants to add the following MCP server. Only continue if you trust its source.\n\n{}",
+                    summary
+                )
+            }
+        }
+    }
+
+    fn confirm_label(&self) -> &'static str {
+        match self {
+            Self::Open { .. } => "Open",
+            Self::InstallSkill { .. } => "Install",
+            Self::AddMcpServer { .. } => "Add Server",
+        }
+    }
+}
+
+/// Links confirmed by the user but not yet consumed by the frontend.
+///
+/// `DEEP_LINK_RECEIVED_EVENT` carries no payload; the frontend drains this queue
+/// on that event and once on startup, since a cold-start link can be confirmed
+/// before the webview has registered its listener.
+#[derive(Default)]
+pub struct DeepLinkState {
+    pending: Mutex<VecDeque<DeepLinkAction>>,
+}
+
+impl DeepLinkState {
+    fn push(&self, action: DeepLinkAction) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.push_back(action);
+        }
+    }
+
+    fn drain(&self) -> Vec<DeepLinkAction> {
+        self.pending
+            .lock()
+            .map(|mut pending| pending.drain(..).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the first `bitfun://` URL found in process arguments.
+pub fn find_deep_link_arg<S: AsRef<str>>(args: &[S]) -> Option<String> {
+    args.iter()
+        .map(|arg| arg.as_ref().trim())
+        .find(|arg| is_deep_link(arg))
+        .map(str::to_string)
+}
+
+pub fn is_deep_link(value: &str) -> bool {
+    value
+        .get(..DEEP_LINK_SCHEME.len() + 3)
+        .map(|prefix| prefix.eq_ignore_ascii_case(&format!("{}://", DEEP_LINK_SCHEME)))
+        .unwrap_or(false)
+}
+
+pub fn parse_deep_link(url: &str) -> Result<DeepLinkAction, String> {
+    if url.len() > MAX_DEEP_LINK_LEN {
+        return Err(format!(
+            "Deep link exceeds {} bytes and was ignored",
+            MAX_DEEP_LINK_LEN
+        ));
+    }
+    if !is_deep_link(url) {
+        return Err(format!("Not a {}:// link", DEEP_LINK_SCHEME));
+    }
+
+    let rest = &url[DEEP_LINK_SCHEME.len() + 3..];
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (action, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let action = action.trim_end_matches('/').to_ascii_lowercase();
+
+    match action.as_str() {
+        "open" => {
+            let path = required_query_param(query, "path")?;
+            Ok(DeepLinkAction::Open { path })
+        }
+        "install-skill" => {
+            let skill_id = required_query_param(query, "id")?;
+            if !skill_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '@'))
+            {
+                return Err(format!("Invalid skill id: {}", skill_id));
+            }
+            Ok(DeepLinkAction::InstallSkill { skill_id })
+        }
+        "add-mcp-server" => {
+            let raw = required_query_param(query, "config")?;
+            let config: serde_json::Value = serde_json::from_str(&raw)
+                .map_err(|e| format!("Invalid MCP server config JSON: {}", e))?;
+            if !config.is_object() {
+                return Err("MCP server config must be a JSON object".to_string());
+            }
+            Ok(DeepLinkAction::AddMcpServer { config })
+        }
+        "" => Err("Deep link is missing an action".to_string()),
+        other => Err(format!("Unsupported deep link action: {}", other)),
+    }
+}
+
+fn required_query_param(query: &str, name: &str) -> Result<String, String> {
+    for pair in query.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if key != name {
+            continue;
+        }
+        // `+` is a space in form encoding, which is what browsers produce for
+        // `<form method="get">` based "Add to BitFun" buttons.
+        let value = value.replace('+', " ");
+        let decoded = urlencoding::decode(&value)
+            .map_err(|e| format!("Invalid encoding for '{}': {}", name, e))?
+            .trim()
+            .to_string();
+        if decoded.is_empty() {
+            break;
+        }
+        return Ok(decoded);
+    }
+    Err(format!(
+        "Deep link is missing required parameter '{}'",
+        name
+    ))
+}
+
+/// Parses `url`, asks the user to confirm, then queues the action and notifies
+/// the main window.
+pub fn handle_deep_link(app: &tauri::AppHandle, url: &str) {
+    let action = match parse_deep_link(url) {
+        Ok(action) => action,
+        Err(error) => {
+            warn!("Ignored deep link: error={}", error);
+            return;
+        }
+    };
+    info!("Deep link received: action={}", action_name(&action));
+
+    let app_handle = app.clone();
+    app.dialog()
+        .message(action.confirmation_message())
+        .title("BitFun")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            action.confirm_label().to_string(),
+            "Cancel".to_string(),
+        ))
+        .show(move |confirmed| {
+            if !confirmed {
+                info!(
+                    "Deep link rejected by user: action={}",
+                    action_name(&action)
+                );
+                return;
+            }
+            let state: tauri::State<'_, DeepLinkState> = app_handle.state();
+            state.push(action);
+            if let Err(error) = app_handle.emit_to("main", DEEP_LINK_RECEIVED_EVENT, ()) {
+                warn!("Failed to emit deep link event: {}", error);
+            }
+        });
+}
+
+fn action_name(action: &DeepLinkAction) -> &'static str {
+    match action {
+        DeepLinkAction::Open { .. } => "open",
+        DeepLinkAction::InstallSkill { .. } => "install-skill",
+        DeepLinkAction::AddMcpServer { .. } => "add-mcp-server",
+    }
+}
+
+/// Returns and clears confirmed deep link actions that the frontend has not handled yet.
+#[tauri::command]
+pub fn take_pending_deep_links(state: tauri::State<'_, DeepLinkState>) -> Vec<DeepLinkAction> {
+    state.drain()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_open_link_with_encoded_path() {
+        let action = parse_deep_link("bitfun://open?path=%2Fhome%2Fme%2Fmy%20project").unwrap();
+        assert_eq!(
+            action,
+            DeepLinkAction::Open {
+                path: "/home/me/my project".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parses_install_skill_and_rejects_unsafe_ids() {
+        assert_eq!(
+            parse_deep_link("BITFUN://install-skill/?id=acme/pdf-tools").unwrap(),
+            DeepLinkAction::InstallSkill {
+                skill_id: "acme/pdf-tools".to_string()
+            }
+        );
+        assert!(parse_deep_link("bitfun://install-skill?id=a%20b;rm").is_err());
+    }
+
+    #[test]
+    fn parses_add_mcp_server_config_object_only() {
+        let link = format!(
+            "bitfun://add-mcp-server?config={}",
+            urlencoding::encode(r#"{"name":"demo","url":"https://example.com/mcp"}"#)
+        );
+        let DeepLinkAction::AddMcpServer { config } = parse_deep_link(&link).unwrap() else {
+            panic!("expected add-mcp-server action");
+        };
+        assert_eq!(config["name"], "demo");
+
+        assert!(parse_deep_link("bitfun://add-mcp-server?config=%5B1%5D").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_actions_and_missing_params() {
+        assert!(parse_deep_link("bitfun://delete-everything").is_err());
+        assert!(parse_deep_link("bitfun://open").is_err());
+        assert!(parse_deep_link("bitfun://open?path=").is_err());
+        assert!(parse_deep_link("https://open?path=/tmp").is_err());
+    }
+
+    #[test]
+    fn finds_deep_link_among_process_args() {
+        let args = ["bitfun-desktop.exe", "--flag", "bitfun://open?path=x"];
+        assert_eq!(
+            find_deep_link_arg(&args),
+            Some("bitfun://open?path=x".to_string())
+        );
+        assert_eq!(find_deep_link_arg(&["bitfun-desktop.exe"]), None);
+    }
+}
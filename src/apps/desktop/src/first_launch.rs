@@ -0,0 +1,104 @@
+//! One-time handoff from the Windows installer.
+//!
+//! The installer writes `first-launch.json` into the user config directory.
+//! The app reads and deletes it on first start, logs the installer version
+//! for install attribution, and keeps the content for the rest of the
+//! process so the UI can tailor onboarding.
+
+use bitfun_core::service::first_launch::{FirstLaunchHandoff, FIRST_LAUNCH_HANDOFF_FILE};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static INSTALLER_HANDOFF: OnceLock<Option<FirstLaunchHandoff>> = OnceLock::new();
+
+/// Reads and deletes the handoff file in `config_dir`. Only the first call
+/// per process touches the file.
+pub fn consume_installer_handoff(config_dir: &Path) {
+    INSTALLER_HANDOFF.get_or_init(|| take_handoff_file(config_dir));
+}
+
+fn take_handoff_file(config_dir: &Path) -> Option<FirstLaunchHandoff> {
+    let path = config_dir.join(FIRST_LAUNCH_HANDOFF_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return None,
+        Err(error) => {
+            log::warn!(
+                "Failed to read installer handoff: path={}, error={}",
+                path.display(),
+                error
+            );
+            return None;
+        }
+    };
+    // Delete before parsing so a malformed file is not retried forever.
+    if let Err(error) = fs::remove_file(&path) {
+        log::warn!(
+            "Failed to delete installer handoff: path={}, error={}",
+            path.display(),
+            error
+        );
+    }
+    match serde_json::from_str::<FirstLaunchHandoff>(&content) {
+        Ok(handoff) => {
+            log::info!(
+                    "First launch after install: installer_version={}, components={}, model_configured={}",
+                    handoff.installer_version,
+                    handoff.components.join(","),
+                    handoff.model_configured
+                );
+            Some(handoff)
+        }
+        Err(error) => {
+            log::warn!("Ignoring invalid installer handoff: {}", error);
+            None
+        }
+    }
+}
+
+/// The handoff consumed at startup, if this is the first launch after an
+/// install.
+pub fn installer_handoff() -> Option<FirstLaunchHandoff> {
+    INSTALLER_HANDOFF.get().cloned().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handoff_file_is_read_and_deleted_once() {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let path = temp.path().join(FIRST_LAUNCH_HANDOFF_FILE);
+        fs::write(
+            &path,
+            r#"{"schemaVersion":1,"installerVersion":"0.2.13","installPath":"C:\\BitFun","modelConfigured":true}"#,
+        )
+        .expect("write handoff");
+
+        let handoff = take_handoff_file(temp.path()).expect("handoff should parse");
+        assert_eq!(handoff.installer_version, "0.2.13");
+        assert!(handoff.model_configured);
+        assert!(!path.exists());
+        assert!(take_handoff_file(temp.path()).is_none());
+    }
+
+    #[test]
+    fn missing_handoff_file_is_not_an_error() {
+        let temp = tempfile::tempdir().expect("create temp dir");
+
+        assert!(take_handoff_file(temp.path()).is_none());
+    }
+
+    #[test]
+    fn malformed_handoff_file_is_deleted_and_ignored() {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let path = temp.path().join(FIRST_LAUNCH_HANDOFF_FILE);
+        fs::write(&path, "{not json").expect("write handoff");
+
+        assert!(take_handoff_file(temp.path()).is_none());
+        assert!(!path.exists());
+    }
+}
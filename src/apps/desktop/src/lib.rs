@@ -4,19 +4,23 @@
 pub mod api;
 pub mod computer_use;
 pub mod crash_diagnostics;
+pub mod deep_link;
 mod embedded_relay_host;
+pub mod first_launch;
 pub mod logging;
 pub mod macos_menubar;
 pub mod runtime;
 pub mod startup_trace;
 pub mod theme;
 pub mod tray;
+pub mod window_layout;
 
 use bitfun_core::agentic::tools::computer_use_capability::set_computer_use_desktop_available;
 use bitfun_core::agentic::tools::computer_use_host::ComputerUseHostRef;
 use bitfun_core::infrastructure::ai::AIClientFactory;
 use bitfun_core::infrastructure::{get_path_manager_arc, try_get_path_manager_arc};
 use bitfun_core::service::search::get_global_workspace_search_service;
+use bitfun_core::service::service_health::{service_health_registry, ServiceKind};
 use bitfun_core::service::workspace::get_global_workspace_service;
 use bitfun_core::util::{elapsed_ms, TimingCollector};
 use bitfun_transport::{TauriTransportAdapter, TransportAdapter};
@@ -28,7 +32,6 @@ use std::sync::{
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::Emitter;
 use tauri::Manager;
-use tauri_plugin_window_state::{AppHandleExt, StateFlags};
 
 // Re-export API
 pub use api::*;
@@ -40,8 +43,8 @@ use api::computer_use_api::*;
 use api::config_api::*;
 use api::cron_api::*;
 use api::custom_agent_api::{
-    create_custom_agent, delete_custom_agent, get_custom_agent_detail, reload_custom_agents,
-    update_custom_agent,
+    create_custom_agent, delete_custom_agent, get_custom_agent_detail,
+    get_custom_agent_load_issues, reload_custom_agents, update_custom_agent,
 };
 use api::diff_api::*;
 use api::external_hooks_api::*;
@@ -253,13 +256,9 @@ fn handle_secondary_launch(app: &tauri::AppHandle) {
     }
 }
 
-fn main_window_state_flags() -> StateFlags {
-    StateFlags::SIZE | StateFlags::POSITION | StateFlags::MAXIMIZED | StateFlags::FULLSCREEN
-}
-
 pub(crate) fn save_main_window_state(app: &tauri::AppHandle) {
-    if let Err(error) = app.save_window_state(main_window_state_flags()) {
-        log::warn!("Failed to save main window state: {}", error);
+    if let Some(window) = app.get_window(window_layout::MAIN_WINDOW_LABEL) {
+        window_layout::save_window(&window);
     }
 }
 
@@ -279,6 +278,21 @@ fn get_startup_native_trace(
 /// Tauri application entry point
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub async fn run() {
+    // Git re-runs this binary as its askpass helper; answer and exit before
+    // any app startup work.
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some(bitfun_core::service::git::askpass::GIT_ASKPASS_ARG) {
+        let prompt = args.next().unwrap_or_default();
+        std::process::exit(bitfun_core::service::git::askpass::run_askpass_client(
+            &prompt,
+        ));
+    }
+
+    // A data directory move requested in settings must finish before
+    // logging or any service opens files under the old root.
+    let data_relocation_result =
+        bitfun_core::infrastructure::app_paths::apply_pending_data_relocation();
+
     let startup_started = Instant::now();
     let startup_trace_id = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -300,6 +314,14 @@ pub async fn run() {
     bitfun_core::service::remote_connect::ensure_rustls_crypto_provider();
 
     eprintln!("=== BitFun Desktop Starting ===");
+    match data_relocation_result {
+        Ok(Some(root)) => eprintln!("Data directory relocated to {}", root.display()),
+        Ok(None) => {}
+        Err(e) => eprintln!(
+            "Data directory relocation failed, keeping current root: {}",
+            e
+        ),
+    }
 
     let step_started = Instant::now();
     if let Err(e) = bitfun_core::service::config::initialize_global_config().await {
@@ -345,6 +367,7 @@ pub async fn run() {
         log::error!("Failed to initialize global AIClientFactory: {}", e);
         return;
     }
+    service_health_registry().report_initialized(ServiceKind::Ai);
     startup_timings.record_elapsed("initialize_global_ai_client_factory", step_started);
     startup_trace.record_elapsed_step(
         "native_pre_tauri",
@@ -444,6 +467,9 @@ pub async fn run() {
                 cwd
             );
             handle_secondary_launch(app);
+            if let Some(url) = deep_link::find_deep_link_arg(&args) {
+                deep_link::handle_deep_link(app, &url);
+            }
         }));
     }
 
@@ -459,12 +485,6 @@ pub async fn run() {
         )
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
-        .plugin(
-            tauri_plugin_window_state::Builder::default()
-                .with_state_flags(main_window_state_flags())
-                .with_filter(|label| label == "main")
-                .build(),
-        )
         .manage(app_state)
         .manage(desktop_runtime)
         .manage(coordinator_state)
@@ -474,6 +494,7 @@ pub async fn run() {
         .manage(scheduler)
         .manage(terminal_state)
         .manage(startup_trace.clone())
+        .manage(deep_link::DeepLinkState::default())
         .on_page_load(|webview, payload| {
             let label = webview.label();
             if label.starts_with("embedded-browser-view-")
@@ -518,6 +539,10 @@ pub async fn run() {
                 step_started,
             );
 
+            if let Ok(path_manager) = try_get_path_manager_arc() {
+                first_launch::consume_installer_handoff(&path_manager.user_config_dir());
+            }
+
             // Ensure the Tauri NSIS registry install-location key points to the
             // actual install directory, so that auto-updates respect the custom
             // install path chosen during initial installation.
@@ -829,6 +854,11 @@ pub async fn run() {
             api::remote_connect_api::set_account_app_handle(app_handle.clone());
             startup_trace.record_elapsed_step("native_setup", "init_services", step_started);
 
+            let launch_args: Vec<String> = std::env::args().collect();
+            if let Some(url) = deep_link::find_deep_link_arg(&launch_args) {
+                deep_link::handle_deep_link(&app_handle, &url);
+            }
+
             let step_started = Instant::now();
             logging::spawn_log_cleanup_task();
             startup_trace.record_elapsed_step("native_setup", "spawn_log_cleanup_task", step_started);
@@ -855,10 +885,18 @@ pub async fn run() {
         })
         .on_window_event({
             move |window, event| {
-                if window.label() == "main"
-                    && matches!(event, tauri::WindowEvent::CloseRequested { .. })
-                {
-                    save_main_window_state(window.app_handle());
+                let secondary_window =
+                    api::window_api::SecondaryWindowKind::from_label(window.label()).is_some();
+                if window.label() == window_layout::MAIN_WINDOW_LABEL || secondary_window {
+                    match event {
+                        tauri::WindowEvent::Moved(_) | tauri::WindowEvent::Resized(_) => {
+                            window_layout::remember_window(window);
+                        }
+                        tauri::WindowEvent::CloseRequested { .. } => {
+                            window_layout::save_window(window);
+                        }
+                        _ => {}
+                    }
                 }
 
                 if let tauri::WindowEvent::CloseRequested { api: _api, .. } = event {
@@ -949,6 +987,13 @@ pub async fn run() {
             api::agentic_api::set_session_memory_mode,
             webdriver_bridge_result,
             get_startup_native_trace,
+            deep_link::take_pending_deep_links,
+            api::theme_api::list_themes,
+            api::theme_api::get_user_theme,
+            api::theme_api::set_theme,
+            api::theme_api::get_current_theme,
+            api::theme_api::save_user_theme,
+            api::theme_api::delete_user_theme,
             api::agentic_api::list_sessions,
             api::agentic_api::list_pending_permission_requests,
             api::agentic_api::subscribe_permission_requests,
@@ -986,6 +1031,7 @@ pub async fn run() {
             get_all_tools_info,
             get_readonly_tools_info,
             get_tool_info,
+            search_tools,
             validate_tool_input,
             execute_tool,
             submit_user_answers,
@@ -1065,16 +1111,26 @@ pub async fn run() {
             get_global_config_health,
             get_runtime_logging_info,
             export_diagnostics_bundle,
+            generate_diagnostics_bundle,
             get_runtime_capabilities,
+            get_runtime_usage,
+            diagnose_runtime_command,
+            get_runtime_provisioning_plan,
+            install_runtime_components,
+            cancel_runtime_provisioning,
             speech_list_models,
             speech_download_model,
             speech_cancel_model_download,
             speech_delete_model,
             speech_verify_model,
+            speech_get_whisper_cpp_status,
             speech_start_input_session,
             speech_append_audio_chunk,
             speech_finish_input_session,
             speech_cancel_input_session,
+            speech_list_voices,
+            speech_speak,
+            speech_stop_speaking,
             get_agent_profile_configs,
             get_agent_profile_config,
             set_agent_profile_config,
@@ -1087,6 +1143,7 @@ pub async fn run() {
             update_custom_agent,
             delete_custom_agent,
             reload_custom_agents,
+            get_custom_agent_load_issues,
             get_subagent_detail,
             delete_subagent,
             create_subagent,
@@ -1099,12 +1156,16 @@ pub async fn run() {
             list_skill_market,
             search_skill_market,
             download_skill_market,
+            preview_skill_market_install,
+            undo_skill_install,
+            export_skill_bundle,
             set_mode_skill_disabled,
             replace_mode_skill_selection,
             reset_mode_skill_selection,
             validate_skill_path,
             add_skill,
             delete_skill,
+            duplicate_skill_to_user,
             git_is_repository,
             git_get_repository_basic,
             git_resolve_revision,
@@ -1117,6 +1178,11 @@ pub async fn run() {
             review_platform_get_pull_request_review_target_by_identity,
             review_platform_get_pull_request_detail_page,
             review_platform_get_pull_request_ci_log,
+            review_platform_list_issues,
+            review_platform_get_review_comments,
+            review_platform_create_branch,
+            review_platform_push_branch,
+            review_platform_create_pull_request,
             review_platform_update_auth_token,
             review_platform_clear_auth_token,
             git_get_status,
@@ -1153,11 +1219,15 @@ pub async fn run() {
             get_work_state_summary,
             compute_diff,
             apply_patch,
+            preview_workspace_patch,
+            apply_workspace_patch,
             save_merged_diff_content,
             initialize_snapshot,
             record_file_change,
             rollback_session,
             rollback_to_turn,
+            list_checkpoints,
+            restore_checkpoint,
             accept_session,
             accept_file,
             reject_file,
@@ -1183,6 +1253,8 @@ pub async fn run() {
             cleanup_storage,
             cleanup_storage_with_policy,
             get_storage_statistics,
+            get_cache_usage,
+            clear_cache,
             initialize_project_storage,
             // Session persistence API
             list_persisted_sessions,
@@ -1202,11 +1274,20 @@ pub async fn run() {
             archive_all_sessions,
             list_archived_sessions,
             delete_all_archived_sessions,
+            search_session_history,
+            export_session,
+            export_session_report,
+            export_replay_bundle,
+            start_tool_replay,
+            stop_tool_replay,
+            apply_session_retention,
             initialize_mcp_servers,
             api::mcp_api::initialize_mcp_servers_non_destructive,
+            api::mcp_api::get_mcp_startup_readiness,
             get_mcp_servers,
             api::mcp_api::list_mcp_resources,
             api::mcp_api::read_mcp_resource,
+            api::mcp_api::attach_mcp_resources,
             api::mcp_api::list_mcp_prompts,
             api::mcp_api::get_mcp_prompt,
             start_mcp_server,
@@ -1218,6 +1299,10 @@ pub async fn run() {
             get_mcp_tool_ui_uri,
             fetch_mcp_app_resource,
             send_mcp_app_message,
+            open_mcp_app_instance,
+            update_mcp_app_state,
+            close_mcp_app_instance,
+            list_active_mcp_apps,
             submit_mcp_interaction_response,
             update_mcp_remote_auth,
             clear_mcp_remote_auth,
@@ -1225,6 +1310,14 @@ pub async fn run() {
             api::mcp_api::start_mcp_remote_oauth,
             api::mcp_api::get_mcp_remote_oauth_session,
             api::mcp_api::cancel_mcp_remote_oauth,
+            api::mcp_api::get_mcp_capability_changes,
+            api::mcp_api::approve_mcp_capability_changes,
+            api::mcp_api::set_mcp_trace_enabled,
+            api::mcp_api::get_mcp_trace,
+            api::mcp_api::export_mcp_trace,
+            api::mcp_api::get_mcp_server_presets,
+            api::mcp_api::validate_mcp_server_preset,
+            api::mcp_api::install_mcp_server_preset,
             initialize_acp_clients,
             get_acp_clients,
             probe_acp_client_requirements,
@@ -1307,6 +1400,80 @@ pub async fn run() {
             update_cron_job,
             delete_cron_job,
             notify_cron_host_ready,
+            api::agent_task_api::list_agent_tasks,
+            api::agent_task_api::enqueue_agent_task,
+            api::agent_task_api::pause_agent_task,
+            api::agent_task_api::resume_agent_task,
+            api::agent_task_api::retry_agent_task,
+            api::agent_task_api::cancel_agent_task,
+            api::approval_api::list_pending_approvals,
+            api::approval_api::respond_approval,
+            api::approval_api::list_remembered_approvals,
+            api::approval_api::forget_remembered_approval,
+            api::approval_api::clear_remembered_approvals,
+            api::approval_api::get_approval_policies,
+            api::approval_api::set_approval_policy,
+            api::git_askpass_api::list_pending_git_credential_prompts,
+            api::git_askpass_api::respond_git_credential_prompt,
+            api::workspace_trust_api::get_workspace_trust,
+            api::workspace_trust_api::set_workspace_trust,
+            api::workspace_trust_api::list_workspace_trust,
+            api::workspace_trust_api::forget_workspace_trust,
+            api::file_operation_api::list_file_operations,
+            api::file_operation_api::undo_file_operation,
+            api::docconv_api::get_document_conversion_capabilities,
+            api::docconv_api::convert_document,
+            api::code_index_api::find_symbol,
+            api::code_index_api::get_file_outline,
+            api::semantic_search_api::semantic_search,
+            api::semantic_search_api::semantic_index_workspace,
+            api::semantic_search_api::get_semantic_index_status,
+            api::semantic_search_api::clear_semantic_index,
+            api::attachment_api::ingest_dropped_files,
+            api::attachment_api::ingest_clipboard,
+            api::attachment_api::list_session_attachments,
+            api::attachment_api::remove_session_attachment,
+            api::backup_api::create_backup,
+            api::backup_api::restore_backup,
+            api::artifact_api::list_artifacts,
+            api::artifact_api::register_artifact,
+            api::artifact_api::preview_artifact,
+            api::artifact_api::open_artifact,
+            api::artifact_api::export_artifact,
+            api::artifact_api::delete_artifact,
+            api::screen_capture_api::list_capture_displays,
+            api::screen_capture_api::capture_window,
+            api::screen_capture_api::capture_region,
+            api::background_job_api::list_background_jobs,
+            api::background_job_api::create_background_job,
+            api::background_job_api::update_background_job,
+            api::background_job_api::delete_background_job,
+            api::background_job_api::run_background_job_now,
+            api::background_job_api::list_background_job_runs,
+            api::connectivity_api::get_connectivity_status,
+            api::connectivity_api::refresh_connectivity,
+            api::download_api::get_download_speed_limits,
+            api::download_api::set_download_speed_limit,
+            api::hashing_api::verify_file,
+            api::power_api::get_power_status,
+            api::power_api::refresh_power_status,
+            api::project_template_api::list_project_templates,
+            api::project_template_api::new_project_from_template,
+            api::credential_health_api::get_credential_health_status,
+            api::credential_health_api::refresh_credential_health,
+            api::service_health_api::get_service_health,
+            api::sidecar_plugin_api::list_sidecar_plugins,
+            api::sidecar_plugin_api::reload_sidecar_plugins,
+            api::sidecar_plugin_api::set_sidecar_plugin_enabled,
+            api::sidecar_plugin_api::invoke_sidecar_plugin_command,
+            api::operation_api::cancel_operation,
+            api::operation_api::list_operations,
+            api::event_replay_api::replay_events,
+            api::window_api::open_secondary_window,
+            api::window_api::close_secondary_window,
+            api::window_api::list_secondary_windows,
+            api::window_api::reset_window_layout,
+            api::context_provider_api::set_context_open_files,
             api::config_api::canonicalize_agent_profile_configs,
             api::terminal_api::terminal_get_shells,
             api::terminal_api::terminal_create,
@@ -1324,10 +1491,14 @@ pub async fn run() {
             api::terminal_api::terminal_get_history,
             get_system_info,
             get_app_version,
+            api::about_api::get_app_info,
+            get_first_launch_context,
             check_for_updates,
             install_update,
             api::system_api::open_html_file_in_browser,
             restart_app,
+            api::system_api::get_data_location,
+            api::system_api::relocate_data_dir,
             send_system_notification,
             api::system_api::quit_app,
             api::system_api::minimize_to_tray,
@@ -1343,6 +1514,7 @@ pub async fn run() {
             i18n_get_supported_languages,
             i18n_get_config,
             i18n_set_config,
+            i18n_localize_messages,
             // Remote Connect
             api::remote_connect_api::remote_connect_get_device_info,
             api::remote_connect_api::remote_connect_get_lan_ip,
@@ -1540,6 +1712,16 @@ pub async fn run() {
                     };
                     show_main_window_on_macos(_app_handle, reason);
                 }
+                #[cfg(target_os = "macos")]
+                tauri::RunEvent::Opened { urls } => {
+                    for url in urls
+                        .iter()
+                        .map(|url| url.as_str())
+                        .filter(|url| deep_link::is_deep_link(url))
+                    {
+                        deep_link::handle_deep_link(_app_handle, url);
+                    }
+                }
                 _ => {}
             });
         }
@@ -1567,6 +1749,56 @@ async fn init_agentic_system() -> anyhow::Result<(
     let path_manager = try_get_path_manager_arc()?;
     let persistence_manager = Arc::new(persistence::PersistenceManager::new(path_manager.clone())?);
 
+    // Installed before any workspace restores so project skills and MCP
+    // servers of untrusted folders are never loaded.
+    let workspace_trust_service =
+        bitfun_core::service::workspace_trust::WorkspaceTrustService::new(path_manager.clone())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize workspace trust service: {}", e))?;
+    bitfun_core::service::workspace_trust::set_global_workspace_trust_service(
+        workspace_trust_service,
+    );
+    bitfun_core::service::file_ops::set_global_file_operation_service(
+        bitfun_core::service::file_ops::FileOperationService::new(),
+    );
+    bitfun_core::service::attachments::set_global_attachment_service(
+        bitfun_core::service::attachments::AttachmentService::new(&path_manager),
+    );
+    match bitfun_core::service::docconv::DocumentConversionService::new(&path_manager) {
+        Ok(service) => {
+            bitfun_core::service::docconv::set_global_document_conversion_service(service)
+        }
+        Err(e) => log::warn!("Document conversion service unavailable: {}", e),
+    }
+    match bitfun_core::infrastructure::storage::get_global_sqlite_storage() {
+        Ok(storage) => {
+            match bitfun_core::service::artifacts::ArtifactService::new(&path_manager, storage)
+                .await
+            {
+                Ok(service) => {
+                    bitfun_core::service::artifacts::set_global_artifact_service(service.clone());
+                    tokio::spawn(async move {
+                        if let Err(e) = service.collect_garbage(None).await {
+                            log::warn!("Artifact garbage collection failed: {}", e);
+                        }
+                    });
+                }
+                Err(e) => log::warn!("Artifact service unavailable: {}", e),
+            }
+        }
+        Err(e) => log::warn!("Artifact service unavailable: {}", e),
+    }
+    match bitfun_core::service::connectivity::ConnectivityService::new() {
+        Ok(service) => bitfun_core::service::connectivity::set_global_connectivity_service(service),
+        Err(e) => log::warn!("Connectivity service unavailable: {}", e),
+    }
+    bitfun_core::service::power::set_global_power_service(
+        bitfun_core::service::power::PowerService::new(),
+    );
+    bitfun_core::service::credential_health::set_global_credential_health_service(
+        bitfun_core::service::credential_health::CredentialHealthService::new(),
+    );
+
     let context_store = Arc::new(session::SessionContextStore::new());
     let context_compressor = Arc::new(session::ContextCompressor::new(Default::default()));
 
@@ -1680,11 +1912,78 @@ async fn init_agentic_system() -> anyhow::Result<(
         cron_service.clone(),
     ));
     event_router.subscribe_internal("cron_jobs".to_string(), cron_subscriber);
+
+    let agent_task_service = bitfun_core::agentic::task_queue::AgentTaskService::new(
+        path_manager.clone(),
+        scheduler.clone(),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to initialize agent task service: {}", e))?;
+    bitfun_core::agentic::task_queue::set_global_agent_task_service(agent_task_service.clone());
+    let agent_task_subscriber = Arc::new(
+        bitfun_core::agentic::task_queue::AgentTaskEventSubscriber::new(
+            agent_task_service.clone(),
+        ),
+    );
+    event_router.subscribe_internal("agent_tasks".to_string(), agent_task_subscriber);
+
+    let approval_service =
+        bitfun_core::service::approvals::ApprovalService::new(path_manager.clone())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize approval service: {}", e))?;
+    bitfun_core::service::approvals::spawn_permission_approval_bridge(
+        bitfun_core::product_runtime::core_permission_request_manager()
+            .map_err(anyhow::Error::msg)?,
+        approval_service.clone(),
+    );
+    bitfun_core::service::approvals::set_global_approval_service(approval_service);
+    bitfun_core::service::mcp::app_instances::set_global_mcp_app_instances(
+        bitfun_core::service::mcp::app_instances::McpAppInstanceRegistry::new(path_manager.clone())
+            .await,
+    );
+    match bitfun_core::service::sidecar_plugins::SidecarPluginService::new(path_manager.clone())
+        .await
+    {
+        Ok(service) => {
+            bitfun_core::service::sidecar_plugins::set_global_sidecar_plugin_service(
+                service.clone(),
+            );
+            tokio::spawn(async move {
+                let plugins = service.reload().await;
+                log::info!(
+                    "Sidecar plugins loaded: plugins={}, issues={}",
+                    plugins.plugins.len(),
+                    plugins.issues.len()
+                );
+            });
+        }
+        Err(e) => log::warn!("Sidecar plugin service unavailable: {}", e),
+    }
+    api::git_askpass_api::configure_git_credentials(&path_manager).await;
+    match bitfun_core::infrastructure::storage::get_global_sqlite_storage() {
+        Ok(storage) => match bitfun_core::service::background_jobs::BackgroundJobService::new(
+            path_manager.clone(),
+            storage,
+        )
+        .await
+        {
+            Ok(service) => {
+                bitfun_core::service::background_jobs::set_global_background_job_service(
+                    service.clone(),
+                );
+                service.start();
+            }
+            Err(e) => log::warn!("Background job service unavailable: {}", e),
+        },
+        Err(e) => log::warn!("Background job service unavailable: {}", e),
+    }
     {
         let cron_service_for_fallback = cron_service.clone();
-        // Desktop cron runs can emit FlowChat events immediately. Prefer the
-        // frontend readiness handshake, but keep a fallback so cron is not left
-        // disabled if the web host never reaches the ready path.
+        let agent_task_service_for_fallback = agent_task_service.clone();
+        // Desktop cron runs and queued agent tasks can emit FlowChat events
+        // immediately. Prefer the frontend readiness handshake, but keep a
+        // fallback so they are not left disabled if the web host never reaches
+        // the ready path.
         tokio::spawn(async move {
             tokio::time::sleep(CRON_DESKTOP_START_FALLBACK_DELAY).await;
             log::info!(
@@ -1692,6 +1991,7 @@ async fn init_agentic_system() -> anyhow::Result<(
                 CRON_DESKTOP_START_FALLBACK_DELAY.as_secs()
             );
             cron_service_for_fallback.start();
+            agent_task_service_for_fallback.start();
         });
     }
 
@@ -1826,6 +2126,7 @@ pub(crate) fn perform_process_exit_cleanup() -> bool {
     }
     bitfun_core::util::process_manager::cleanup_all_processes();
     api::remote_connect_api::cleanup_on_exit();
+    window_layout::persist();
     true
 }
 
@@ -1885,6 +2186,19 @@ fn init_services(app_handle: tauri::AppHandle, default_log_level: log::LevelFilt
     spawn_ingest_server_with_config_listener();
     spawn_runtime_log_level_listener(default_log_level);
     spawn_workspace_search_feature_listener(app_handle.clone());
+    api::theme_api::spawn_theme_update_listener(app_handle.clone());
+    api::agent_task_api::spawn_agent_task_event_forwarder(app_handle.clone());
+    api::approval_api::spawn_approval_event_forwarder(app_handle.clone());
+    api::git_askpass_api::spawn_git_askpass_event_forwarder(app_handle.clone());
+    api::workspace_trust_api::spawn_workspace_trust_event_forwarder(app_handle.clone());
+    api::file_operation_api::spawn_file_operation_event_forwarder(app_handle.clone());
+    api::docconv_api::spawn_document_conversion_event_forwarder(app_handle.clone());
+    api::background_job_api::register_background_job_handlers(app_handle.clone());
+    api::connectivity_api::start_connectivity_monitor(app_handle.clone());
+    api::power_api::start_power_monitor(app_handle.clone());
+    bitfun_core::service::downloads::start_download_settings_listener();
+    api::credential_health_api::start_credential_health_monitor(app_handle.clone());
+    api::service_health_api::start_service_health_monitor(app_handle.clone());
 
     tokio::spawn(async move {
         let transport = Arc::new(TauriTransportAdapter::new(app_handle.clone()));
@@ -1897,6 +2211,7 @@ fn init_services(app_handle: tauri::AppHandle, default_log_level: log::LevelFilt
         service::snapshot::initialize_snapshot_event_emitter(emitter.clone());
 
         bitfun_core::service::initialize_file_watch_service(emitter.clone());
+        service_health_registry().report_initialized(ServiceKind::FileWatcher);
 
         if let Err(e) = workspace_identity_watch_service
             .set_event_emitter(emitter.clone())
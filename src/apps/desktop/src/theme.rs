@@ -5,6 +5,7 @@ use std::time::Instant;
 
 use bitfun_core::infrastructure::try_get_path_manager_arc;
 use bitfun_core::service::config::types::GlobalConfig;
+use bitfun_core::service::theme::{ThemeKind, ThemeRegistry};
 use dark_light::Mode;
 use log::{debug, error, warn};
 use tauri::webview::PageLoadEvent;
@@ -183,6 +184,33 @@ impl ThemeConfig {
             .map(|theme| theme.to_theme_config(Some(theme_id.to_string())))
     }
 
+    /// Resolves splash colors from a user theme file in the config directory.
+    fn get_user_theme(
+        path_manager: &bitfun_core::infrastructure::PathManager,
+        theme_id: &str,
+    ) -> Option<Self> {
+        let registry = ThemeRegistry::from_path_manager(path_manager);
+        let theme = match registry.get_user_theme(theme_id) {
+            Ok(theme) => theme?,
+            Err(e) => {
+                warn!("Failed to load user theme {}: {}", theme_id, e);
+                return None;
+            }
+        };
+        let token = |name: &str| theme.token(name).unwrap_or_default().to_string();
+        Some(Self {
+            id: theme.id.clone(),
+            selection_id: Some(theme.id.clone()),
+            bg_primary: token("bgPrimary"),
+            bg_secondary: token("bgSecondary"),
+            bg_scene: token("bgScene"),
+            is_light: theme.kind == ThemeKind::Light,
+            text_primary: token("textPrimary"),
+            text_muted: token("textMuted"),
+            accent_color: token("accentColor"),
+        })
+    }
+
     fn startup_theme_bootstrap_manifest() -> &'static StartupThemeBootstrapManifest {
         STARTUP_THEME_BOOTSTRAP_MANIFEST.get_or_init(|| {
             let manifest: StartupThemeBootstrapManifest =
@@ -259,7 +287,9 @@ impl ThemeConfig {
 
         let resolved_id = Self::resolve_builtin_theme_id(theme_id);
 
-        let theme = match Self::get_builtin_theme(resolved_id) {
+        let theme = match Self::get_builtin_theme(resolved_id)
+            .or_else(|| Self::get_user_theme(&path_manager, resolved_id))
+        {
             Some(mut config) => {
                 config.selection_id = Some(theme_id.to_string());
                 config
@@ -422,11 +452,15 @@ impl ThemeConfig {
     }
 
     pub fn to_tauri_color(&self) -> tauri::window::Color {
+        // User themes may use short hex or `rgb()` values; only full hex maps
+        // directly, anything else falls back to the default dark chrome.
         let hex = self.bg_primary.trim_start_matches('#');
-        let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(18);
-        let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(18);
-        let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(20);
-        tauri::window::Color(r, g, b, 255)
+        let channel = |range: std::ops::Range<usize>, fallback: u8| {
+            hex.get(range)
+                .and_then(|value| u8::from_str_radix(value, 16).ok())
+                .unwrap_or(fallback)
+        };
+        tauri::window::Color(channel(0..2, 18), channel(2..4, 18), channel(4..6, 20), 255)
     }
 }
 
@@ -546,6 +580,7 @@ pub fn create_main_window(
                 }
             }
 
+            crate::window_layout::restore_window(&window);
             show_main_window_for_startup(&window, total_started_at, startup_trace);
         }
         Err(e) => {
@@ -588,7 +623,7 @@ fn show_main_window_for_startup(
     );
 }
 
-fn app_url(path: &str) -> WebviewUrl {
+pub(crate) fn app_url(path: &str) -> WebviewUrl {
     if cfg!(debug_assertions) {
         match format!("http://localhost:1422/{}", path).parse() {
             Ok(url) => WebviewUrl::External(url),
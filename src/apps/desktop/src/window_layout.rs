@@ -0,0 +1,657 @@
+//! Window geometry persisted per monitor configuration.
+//!
+//! Docking a laptop or unplugging a display changes where a window should
+//! open, so geometry is remembered separately for each set of connected
+//! monitors. A layout saved for another configuration is only used as a
+//! fallback, and any restored window that would not have a grabbable title
+//! bar on a current monitor is moved back onto the primary one.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bitfun_core::infrastructure::try_get_path_manager_arc;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+pub const MAIN_WINDOW_LABEL: &str = "main";
+const WINDOW_LAYOUT_VERSION: u32 = 1;
+/// Layouts kept for monitor configurations other than the current one.
+const MAX_MONITOR_CONFIGURATIONS: usize = 16;
+/// Part of the window top that must stay on a monitor, in physical pixels.
+const GRAB_STRIP_HEIGHT: u32 = 32;
+const GRAB_MIN_VISIBLE_WIDTH: u32 = 120;
+
+/// A rectangle in physical screen pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScreenRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScreenRect {
+    fn right(&self) -> i64 {
+        i64::from(self.x) + i64::from(self.width)
+    }
+
+    fn bottom(&self) -> i64 {
+        i64::from(self.y) + i64::from(self.height)
+    }
+
+    /// Width and height of the overlap with `other`.
+    fn overlap(&self, other: &ScreenRect) -> (u32, u32) {
+        let width = self.right().min(other.right()) - i64::from(self.x.max(other.x));
+        let height = self.bottom().min(other.bottom()) - i64::from(self.y.max(other.y));
+        (width.max(0) as u32, height.max(0) as u32)
+    }
+
+    fn overlap_area(&self, other: &ScreenRect) -> u64 {
+        let (width, height) = self.overlap(other);
+        u64::from(width) * u64::from(height)
+    }
+}
+
+/// Saved placement of one window. Position is the outer frame, size the
+/// inner content area, both in physical pixels. For a maximized or
+/// fullscreen window the bounds are those it returns to when restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub maximized: bool,
+    #[serde(default)]
+    pub fullscreen: bool,
+}
+
+impl WindowGeometry {
+    fn bounds(&self) -> ScreenRect {
+        ScreenRect {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// One connected monitor, reduced to what placement needs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorSnapshot {
+    pub name: Option<String>,
+    pub bounds: ScreenRect,
+    pub work_area: ScreenRect,
+    pub scale_factor: f64,
+    pub primary: bool,
+}
+
+/// Stable key for a set of monitors, independent of enumeration order.
+pub fn monitor_configuration_key(monitors: &[MonitorSnapshot]) -> String {
+    let mut entries = monitors
+        .iter()
+        .map(|monitor| {
+            format!(
+                "{}@{},{}:{}x{}*{:.2}",
+                monitor.name.as_deref().unwrap_or("unknown"),
+                monitor.bounds.x,
+                monitor.bounds.y,
+                monitor.bounds.width,
+                monitor.bounds.height,
+                monitor.scale_factor
+            )
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries.join(";")
+}
+
+/// Whether the top of the window can still be grabbed on `work_area`.
+fn is_grabbable(bounds: &ScreenRect, work_area: &ScreenRect) -> bool {
+    let strip = ScreenRect {
+        height: bounds.height.min(GRAB_STRIP_HEIGHT),
+        ..*bounds
+    };
+    let (width, height) = strip.overlap(work_area);
+    width >= bounds.width.min(GRAB_MIN_VISIBLE_WIDTH) && height >= strip.height.div_ceil(2)
+}
+
+/// Adjusts saved geometry to the current monitors: an off-screen window is
+/// centered on the primary monitor and a window larger than its monitor is
+/// shrunk to fit. Returns `None` when no monitor is known.
+pub fn fit_to_monitors(
+    geometry: WindowGeometry,
+    monitors: &[MonitorSnapshot],
+) -> Option<WindowGeometry> {
+    let bounds = geometry.bounds();
+    let visible_on = monitors
+        .iter()
+        .filter(|monitor| is_grabbable(&bounds, &monitor.work_area))
+        .max_by_key(|monitor| bounds.overlap_area(&monitor.work_area));
+    let (area, recenter) = match visible_on {
+        Some(monitor) => (monitor.work_area, false),
+        None => {
+            let fallback = monitors
+                .iter()
+                .find(|monitor| monitor.primary)
+                .or_else(|| monitors.first())?;
+            (fallback.work_area, true)
+        }
+    };
+
+    let mut fitted = geometry;
+    fitted.width = geometry.width.min(area.width).max(1);
+    fitted.height = geometry.height.min(area.height).max(1);
+    let shrunk = fitted.width != geometry.width || fitted.height != geometry.height;
+    if recenter {
+        fitted.x = area.x + ((area.width - fitted.width) / 2) as i32;
+        fitted.y = area.y + ((area.height - fitted.height) / 2) as i32;
+    } else if shrunk {
+        fitted.x = fitted
+            .x
+            .clamp(area.x, area.x + (area.width - fitted.width) as i32);
+        fitted.y = fitted
+            .y
+            .clamp(area.y, area.y + (area.height - fitted.height) as i32);
+    }
+    Some(fitted)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorConfigurationLayout {
+    pub updated_at_ms: u64,
+    #[serde(default)]
+    pub windows: BTreeMap<String, WindowGeometry>,
+}
+
+/// `window_layout.json` in the user config directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowLayoutFile {
+    pub version: u32,
+    /// Keyed by [`monitor_configuration_key`].
+    #[serde(default)]
+    pub configurations: BTreeMap<String, MonitorConfigurationLayout>,
+}
+
+impl Default for WindowLayoutFile {
+    fn default() -> Self {
+        Self {
+            version: WINDOW_LAYOUT_VERSION,
+            configurations: BTreeMap::new(),
+        }
+    }
+}
+
+impl WindowLayoutFile {
+    /// Geometry saved for this configuration, else the most recently saved
+    /// geometry of the window under any configuration.
+    pub fn geometry(&self, configuration: &str, label: &str) -> Option<WindowGeometry> {
+        if let Some(geometry) = self
+            .configurations
+            .get(configuration)
+            .and_then(|layout| layout.windows.get(label))
+        {
+            return Some(*geometry);
+        }
+        self.configurations
+            .values()
+            .filter_map(|layout| {
+                layout
+                    .windows
+                    .get(label)
+                    .map(|geometry| (layout.updated_at_ms, *geometry))
+            })
+            .max_by_key(|(updated_at_ms, _)| *updated_at_ms)
+            .map(|(_, geometry)| geometry)
+    }
+
+    /// Returns whether anything changed.
+    pub fn record(
+        &mut self,
+        configuration: &str,
+        label: &str,
+        geometry: WindowGeometry,
+        now_ms: u64,
+    ) -> bool {
+        let layout = self
+            .configurations
+            .entry(configuration.to_string())
+            .or_default();
+        if layout.windows.get(label) == Some(&geometry) {
+            return false;
+        }
+        layout.windows.insert(label.to_string(), geometry);
+        layout.updated_at_ms = now_ms;
+
+        while self.configurations.len() > MAX_MONITOR_CONFIGURATIONS {
+            let oldest = self
+                .configurations
+                .iter()
+                .filter(|(key, _)| key.as_str() != configuration)
+                .min_by_key(|(_, layout)| layout.updated_at_ms)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(key) => self.configurations.remove(&key),
+                None => break,
+            };
+        }
+        true
+    }
+
+    /// Forgets a window under every configuration, or everything when
+    /// `label` is `None`. Returns whether anything was removed.
+    pub fn forget(&mut self, label: Option<&str>) -> bool {
+        let Some(label) = label else {
+            let changed = !self.configurations.is_empty();
+            self.configurations.clear();
+            return changed;
+        };
+        let mut changed = false;
+        for layout in self.configurations.values_mut() {
+            changed |= layout.windows.remove(label).is_some();
+        }
+        self.configurations
+            .retain(|_, layout| !layout.windows.is_empty());
+        changed
+    }
+}
+
+struct WindowLayoutStore {
+    path: Option<PathBuf>,
+    file: Mutex<WindowLayoutFile>,
+    dirty: AtomicBool,
+}
+
+static WINDOW_LAYOUT_STORE: OnceLock<WindowLayoutStore> = OnceLock::new();
+
+fn store() -> &'static WindowLayoutStore {
+    WINDOW_LAYOUT_STORE.get_or_init(|| {
+        let path = match try_get_path_manager_arc() {
+            Ok(path_manager) => Some(path_manager.window_layout_file()),
+            Err(e) => {
+                warn!("Window layout will not be persisted: {}", e);
+                None
+            }
+        };
+        let file = path.as_deref().map(load_layout_file).unwrap_or_default();
+        WindowLayoutStore {
+            path,
+            file: Mutex::new(file),
+            dirty: AtomicBool::new(false),
+        }
+    })
+}
+
+fn load_layout_file(path: &Path) -> WindowLayoutFile {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return WindowLayoutFile::default(),
+        Err(e) => {
+            warn!(
+                "Failed to read window layout: path={}, error={}",
+                path.display(),
+                e
+            );
+            return WindowLayoutFile::default();
+        }
+    };
+    match serde_json::from_str::<WindowLayoutFile>(&content) {
+        Ok(file) if file.version == WINDOW_LAYOUT_VERSION => file,
+        Ok(file) => {
+            warn!(
+                "Ignoring window layout with unsupported version: path={}, version={}",
+                path.display(),
+                file.version
+            );
+            WindowLayoutFile::default()
+        }
+        Err(e) => {
+            warn!(
+                "Ignoring unreadable window layout: path={}, error={}",
+                path.display(),
+                e
+            );
+            WindowLayoutFile::default()
+        }
+    }
+}
+
+fn with_layout<T>(f: impl FnOnce(&mut WindowLayoutFile) -> T) -> T {
+    let mut file = match store().file.lock() {
+        Ok(file) => file,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    f(&mut file)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Writes pending changes to disk. Cheap when nothing changed.
+pub fn persist() {
+    let store = store();
+    let Some(path) = store.path.as_deref() else {
+        return;
+    };
+    if !store.dirty.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    let content = match with_layout(|file| serde_json::to_string_pretty(file)) {
+        Ok(content) => content,
+        Err(e) => {
+            warn!("Failed to serialize window layout: {}", e);
+            return;
+        }
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| {
+            let temp_path = path.with_extension("json.tmp");
+            std::fs::write(&temp_path, content)?;
+            std::fs::rename(&temp_path, path)
+        });
+    if let Err(e) = result {
+        store.dirty.store(true, Ordering::SeqCst);
+        warn!(
+            "Failed to save window layout: path={}, error={}",
+            path.display(),
+            e
+        );
+    }
+}
+
+/// Forgets saved geometry for one window or, with `None`, for all windows.
+pub fn reset(label: Option<&str>) {
+    if with_layout(|file| file.forget(label)) {
+        store().dirty.store(true, Ordering::SeqCst);
+        persist();
+    }
+}
+
+pub fn current_monitors(app: &tauri::AppHandle) -> Vec<MonitorSnapshot> {
+    let primary = app.primary_monitor().ok().flatten();
+    let monitors = match app.available_monitors() {
+        Ok(monitors) => monitors,
+        Err(e) => {
+            warn!("Failed to list monitors: {}", e);
+            return Vec::new();
+        }
+    };
+    monitors
+        .iter()
+        .map(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+            let work_area = monitor.work_area();
+            MonitorSnapshot {
+                name: monitor.name().cloned(),
+                bounds: ScreenRect {
+                    x: position.x,
+                    y: position.y,
+                    width: size.width,
+                    height: size.height,
+                },
+                work_area: ScreenRect {
+                    x: work_area.position.x,
+                    y: work_area.position.y,
+                    width: work_area.size.width,
+                    height: work_area.size.height,
+                },
+                scale_factor: monitor.scale_factor(),
+                primary: primary.as_ref().is_some_and(|primary| {
+                    primary.name() == monitor.name() && primary.position() == position
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Geometry saved for `label` under the current monitors, already fitted to
+/// them.
+pub fn saved_geometry(app: &tauri::AppHandle, label: &str) -> Option<WindowGeometry> {
+    let monitors = current_monitors(app);
+    let configuration = monitor_configuration_key(&monitors);
+    let geometry = with_layout(|file| file.geometry(&configuration, label))?;
+    fit_to_monitors(geometry, &monitors)
+}
+
+/// Applies the saved geometry to a window that has not been shown yet.
+/// Leaves the builder's default placement when nothing was saved.
+pub fn restore_window(window: &tauri::WebviewWindow) {
+    let Some(geometry) = saved_geometry(window.app_handle(), window.label()) else {
+        return;
+    };
+    debug!(
+        "Restoring window geometry: label={}, geometry={:?}",
+        window.label(),
+        geometry
+    );
+    if let Err(e) = window.set_size(tauri::PhysicalSize::new(geometry.width, geometry.height)) {
+        warn!(
+            "Failed to restore window size: label={}, error={}",
+            window.label(),
+            e
+        );
+    }
+    if let Err(e) = window.set_position(tauri::PhysicalPosition::new(geometry.x, geometry.y)) {
+        warn!(
+            "Failed to restore window position: label={}, error={}",
+            window.label(),
+            e
+        );
+    }
+    if geometry.fullscreen {
+        if let Err(e) = window.set_fullscreen(true) {
+            warn!(
+                "Failed to restore fullscreen: label={}, error={}",
+                window.label(),
+                e
+            );
+        }
+    } else if geometry.maximized {
+        if let Err(e) = window.maximize() {
+            warn!(
+                "Failed to restore maximized window: label={}, error={}",
+                window.label(),
+                e
+            );
+        }
+    }
+}
+
+fn capture_geometry(
+    window: &tauri::Window,
+    previous: Option<WindowGeometry>,
+) -> Option<WindowGeometry> {
+    if window.is_minimized().unwrap_or(false) {
+        return None;
+    }
+    let maximized = window.is_maximized().unwrap_or(false);
+    let fullscreen = window.is_fullscreen().unwrap_or(false);
+    // A maximized window reports the monitor's bounds; keep the bounds it
+    // should return to.
+    if let Some(previous) = previous.filter(|_| maximized || fullscreen) {
+        return Some(WindowGeometry {
+            maximized,
+            fullscreen,
+            ..previous
+        });
+    }
+    let position = window.outer_position().ok()?;
+    let size = window.inner_size().ok()?;
+    if size.width == 0 || size.height == 0 {
+        return None;
+    }
+    Some(WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized,
+        fullscreen,
+    })
+}
+
+/// Records the window's current geometry in memory; see [`persist`].
+pub fn remember_window(window: &tauri::Window) {
+    let monitors = current_monitors(window.app_handle());
+    if monitors.is_empty() {
+        return;
+    }
+    let configuration = monitor_configuration_key(&monitors);
+    let label = window.label();
+    let previous = with_layout(|file| {
+        file.configurations
+            .get(&configuration)
+            .and_then(|layout| layout.windows.get(label))
+            .copied()
+    });
+    let Some(geometry) = capture_geometry(window, previous) else {
+        return;
+    };
+    if with_layout(|file| file.record(&configuration, label, geometry, now_ms())) {
+        store().dirty.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Records the window's geometry and writes the layout file.
+pub fn save_window(window: &tauri::Window) {
+    remember_window(window);
+    persist();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, primary: bool) -> MonitorSnapshot {
+        let bounds = ScreenRect {
+            x,
+            y: 0,
+            width: 1920,
+            height: 1080,
+        };
+        MonitorSnapshot {
+            name: Some(name.to_string()),
+            bounds,
+            work_area: ScreenRect {
+                height: 1040,
+                ..bounds
+            },
+            scale_factor: 1.0,
+            primary,
+        }
+    }
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            x,
+            y,
+            width,
+            height,
+            maximized: false,
+            fullscreen: false,
+        }
+    }
+
+    #[test]
+    fn configuration_key_ignores_monitor_order() {
+        let laptop = monitor("laptop", 0, true);
+        let external = monitor("external", 1920, false);
+        assert_eq!(
+            monitor_configuration_key(&[laptop.clone(), external.clone()]),
+            monitor_configuration_key(&[external, laptop.clone()])
+        );
+        assert_ne!(
+            monitor_configuration_key(&[laptop.clone()]),
+            monitor_configuration_key(&[MonitorSnapshot {
+                scale_factor: 2.0,
+                ..laptop
+            }])
+        );
+    }
+
+    #[test]
+    fn fit_keeps_visible_windows_and_recenters_off_screen_ones() {
+        let monitors = [monitor("laptop", 0, true), monitor("external", 1920, false)];
+
+        let on_external = geometry(2000, 100, 1200, 800);
+        assert_eq!(fit_to_monitors(on_external, &monitors), Some(on_external));
+
+        // Saved while a third monitor was attached to the right.
+        let off_screen = geometry(4000, 100, 1200, 800);
+        assert_eq!(
+            fit_to_monitors(off_screen, &monitors),
+            Some(geometry(360, 120, 1200, 800))
+        );
+
+        // Title bar above the top edge cannot be grabbed.
+        let above = geometry(100, -500, 1200, 800);
+        assert_eq!(
+            fit_to_monitors(above, &monitors),
+            Some(geometry(360, 120, 1200, 800))
+        );
+
+        assert_eq!(fit_to_monitors(on_external, &[]), None);
+    }
+
+    #[test]
+    fn fit_shrinks_windows_larger_than_their_monitor() {
+        let monitors = [monitor("laptop", 0, true)];
+        assert_eq!(
+            fit_to_monitors(geometry(100, 50, 2560, 1440), &monitors),
+            Some(geometry(0, 0, 1920, 1040))
+        );
+    }
+
+    #[test]
+    fn layout_prefers_exact_configuration_then_latest() {
+        let mut file = WindowLayoutFile::default();
+        assert!(file.record("docked", "main", geometry(2000, 0, 1600, 900), 10));
+        assert!(file.record("laptop", "main", geometry(0, 0, 1200, 800), 20));
+        assert!(!file.record("laptop", "main", geometry(0, 0, 1200, 800), 30));
+
+        assert_eq!(
+            file.geometry("docked", "main"),
+            Some(geometry(2000, 0, 1600, 900))
+        );
+        assert_eq!(
+            file.geometry("projector", "main"),
+            Some(geometry(0, 0, 1200, 800))
+        );
+        assert_eq!(file.geometry("docked", "secondary-terminal"), None);
+
+        assert!(file.forget(Some("main")));
+        assert!(file.configurations.is_empty());
+        assert!(!file.forget(None));
+    }
+
+    #[test]
+    fn layout_drops_oldest_configurations() {
+        let mut file = WindowLayoutFile::default();
+        for index in 0..=MAX_MONITOR_CONFIGURATIONS as u64 {
+            file.record(
+                &format!("config-{}", index),
+                "main",
+                geometry(0, 0, 800, 600),
+                index,
+            );
+        }
+        assert_eq!(file.configurations.len(), MAX_MONITOR_CONFIGURATIONS);
+        assert!(!file.configurations.contains_key("config-0"));
+    }
+}
@@ -140,6 +140,21 @@ pub async fn initialize(workspace: Option<String>) -> anyhow::Result<Arc<ServerA
     event_router.subscribe_internal("cron_jobs".to_string(), cron_subscriber);
     cron_service.start();
 
+    // Agent task queue
+    let agent_task_service = bitfun_core::agentic::task_queue::AgentTaskService::new(
+        path_manager.clone(),
+        scheduler.clone(),
+    )
+    .await?;
+    bitfun_core::agentic::task_queue::set_global_agent_task_service(agent_task_service.clone());
+    let agent_task_subscriber = Arc::new(
+        bitfun_core::agentic::task_queue::AgentTaskEventSubscriber::new(
+            agent_task_service.clone(),
+        ),
+    );
+    event_router.subscribe_internal("agent_tasks".to_string(), agent_task_subscriber);
+    agent_task_service.start();
+
     // Function agents
     let _ = bitfun_core::function_agents::git_func_agent::GitFunctionAgent::new(
         ai_client_factory.clone(),
@@ -8,23 +8,35 @@ pub(crate) mod format;
 pub(crate) mod healthcheck;
 pub(crate) mod http;
 pub(crate) mod quirks;
+pub(crate) mod rate_limit;
 pub(crate) mod response_aggregator;
+pub(crate) mod response_cache;
 pub(crate) mod sse;
+pub(crate) mod structured_output;
 pub(crate) mod utils;
 
 use crate::providers::{anthropic, gemini, openai};
+use crate::tokenizer;
 use crate::trace::{
     ModelExchangeRequestTraceHandle, ModelExchangeResponseTrace, ModelExchangeTraceConfig,
 };
 use crate::types::ProxyConfig;
 use crate::types::*;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use format::ApiFormat;
-use log::warn;
+use log::{debug, warn};
 use reqwest::Client;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
+pub use rate_limit::{
+    RequestClassMetrics, RequestGovernor, RequestGovernorMetrics, RequestLimits, RequestPermit,
+    RequestPriority,
+};
+pub use response_cache::{ResponseCache, ResponseCacheLimits, ResponseCacheMode};
+pub use structured_output::{validate_json_schema, StructuredOutput};
+
 const SEND_MESSAGE_STREAM_ATTEMPTS: usize = 10;
 const TEST_CONNECTION_STREAM_ATTEMPTS: usize = 5;
 const SEND_MESSAGE_RETRY_BASE_DELAY_MS: u64 = 500;
@@ -33,6 +45,9 @@ const SEND_MESSAGE_MAX_EXPONENTIAL_DELAY_MS: u64 = 30_000;
 const SEND_MESSAGE_MAX_RATE_LIMIT_DELAY_MS: u64 = 60_000;
 const SEND_MESSAGE_MAX_RETRY_EXPONENT_SHIFT: u32 = 6;
 
+type UnifiedResponseStream =
+    std::pin::Pin<Box<dyn futures::Stream<Item = Result<crate::stream::UnifiedResponse>> + Send>>;
+
 /// Streamed response result with the parsed stream and optional raw SSE receiver.
 pub struct StreamResponse {
     pub stream: std::pin::Pin<
@@ -53,11 +68,35 @@ pub struct StreamOptions {
     pub ttft_timeout: Option<Duration>,
 }
 
+/// Throttling applied before each model request is sent.
+#[derive(Clone, Default)]
+pub(crate) struct RequestScheduling {
+    /// Acquired in order; typically the model governor, then the app-wide one.
+    governors: Vec<Arc<RequestGovernor>>,
+    /// Requests sharing a key queue behind each other; distinct keys are
+    /// admitted round-robin.
+    fairness_key: String,
+    priority: RequestPriority,
+}
+
+impl std::fmt::Debug for RequestScheduling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestScheduling")
+            .field("governors", &self.governors.len())
+            .field("fairness_key", &self.fairness_key)
+            .field("priority", &self.priority)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AIClient {
     pub(crate) client: Client,
     pub config: AIConfig,
     pub(crate) stream_options: StreamOptions,
+    pub(crate) scheduling: RequestScheduling,
+    /// Shared store used by [`AIClient::send_message_cached`].
+    pub(crate) response_cache: Option<Arc<ResponseCache>>,
 }
 
 impl AIClient {
@@ -89,9 +128,45 @@ impl AIClient {
             client,
             config,
             stream_options,
+            scheduling: RequestScheduling::default(),
+            response_cache: None,
         }
     }
 
+    /// Throttle requests from this client through `governors`, acquired in order.
+    pub fn with_request_governors(mut self, governors: Vec<Arc<RequestGovernor>>) -> Self {
+        self.scheduling.governors = governors;
+        self
+    }
+
+    /// Let [`AIClient::send_message_cached`] reuse responses stored in `cache`.
+    pub fn with_response_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Clone this client so its requests queue fairly against other keys
+    /// (usually one per session) while reusing the HTTP client.
+    pub fn with_fairness_key(&self, fairness_key: impl Into<String>) -> Self {
+        let mut client = self.clone();
+        client.scheduling.fairness_key = fairness_key.into();
+        client
+    }
+
+    /// Clone this client so its requests are admitted with `priority` when
+    /// the request governors are contended.
+    pub fn with_request_priority(&self, priority: RequestPriority) -> Self {
+        let mut client = self.clone();
+        client.scheduling.priority = priority;
+        client
+    }
+
+    /// Key that groups this client's requests for provider prompt caching:
+    /// the fairness key (usually the session id), when one is set.
+    pub(crate) fn prompt_cache_key(&self) -> Option<&str> {
+        Some(self.scheduling.fairness_key.as_str()).filter(|key| !key.is_empty())
+    }
+
     /// Returns the configured idle timeout between streamed chunks, if any.
     pub fn stream_idle_timeout(&self) -> Option<Duration> {
         self.stream_options.idle_timeout
@@ -110,6 +185,8 @@ impl AIClient {
             client: self.client.clone(),
             config,
             stream_options: self.stream_options.clone(),
+            scheduling: self.scheduling.clone(),
+            response_cache: self.response_cache.clone(),
         }
     }
 
@@ -122,6 +199,8 @@ impl AIClient {
             client: self.client.clone(),
             config,
             stream_options: self.stream_options.clone(),
+            scheduling: self.scheduling.clone(),
+            response_cache: self.response_cache.clone(),
         }
     }
 
@@ -143,30 +222,14 @@ impl AIClient {
         extra_body: Option<serde_json::Value>,
         trace: Option<ModelExchangeTraceConfig>,
     ) -> Result<StreamResponse> {
-        let max_tries = SEND_MESSAGE_STREAM_ATTEMPTS;
-        match ApiFormat::parse(&self.config.format)? {
-            ApiFormat::OpenAIChat => {
-                openai::chat::send_stream(self, messages, tools, extra_body, max_tries, trace).await
-            }
-            ApiFormat::OpenAIResponses => {
-                openai::responses::send_stream(self, messages, tools, extra_body, max_tries, trace)
-                    .await
-            }
-            ApiFormat::Anthropic => {
-                anthropic::request::send_stream(self, messages, tools, extra_body, max_tries, trace)
-                    .await
-            }
-            ApiFormat::Gemini => {
-                gemini::request::send_stream(self, messages, tools, extra_body, max_tries, trace)
-                    .await
-            }
-            ApiFormat::GeminiCodeAssist => {
-                gemini::code_assist::send_stream(
-                    self, messages, tools, extra_body, max_tries, trace,
-                )
-                .await
-            }
-        }
+        self.send_message_stream_with_extra_body_and_max_attempts(
+            messages,
+            tools,
+            extra_body,
+            SEND_MESSAGE_STREAM_ATTEMPTS,
+            trace,
+        )
+        .await
     }
 
     pub async fn send_message(
@@ -179,6 +242,121 @@ impl AIClient {
             .await
     }
 
+    /// Like [`AIClient::send_message`], but answers repeated requests from the
+    /// response cache when one is attached. Meant for utility calls whose
+    /// answer may be reused (titles, summaries, classifications); `Bypass`
+    /// skips the lookup and stores the fresh response. Cache hits carry no
+    /// usage.
+    pub async fn send_message_cached(
+        &self,
+        messages: Vec<Message>,
+        tools: Option<Vec<ToolDefinition>>,
+        mode: ResponseCacheMode,
+    ) -> Result<GeminiResponse> {
+        let custom_body = self.config.custom_request_body.clone();
+        let cached = self.response_cache.as_ref().and_then(|cache| {
+            response_cache::response_cache_key(
+                &self.config,
+                &messages,
+                tools.as_deref(),
+                custom_body.as_ref(),
+            )
+            .map(|key| (cache, key))
+        });
+        let Some((cache, key)) = cached else {
+            return self
+                .send_message_with_extra_body(messages, tools, custom_body)
+                .await;
+        };
+
+        if mode == ResponseCacheMode::Reuse {
+            if let Some(response) = cache.get(&key) {
+                debug!("AI response served from cache: model={}", self.config.model);
+                // Nothing was spent on this call; keep usage trackers honest.
+                return Ok(GeminiResponse {
+                    usage: None,
+                    ..response
+                });
+            }
+        }
+
+        let response = self
+            .send_message_with_extra_body(messages, tools, custom_body)
+            .await?;
+        if !response.text.trim().is_empty() || response.tool_calls.is_some() {
+            cache.insert(key, response.clone());
+        }
+        Ok(response)
+    }
+
+    /// Sends `messages` asking for a JSON value matching `output.schema`, and
+    /// returns that value once it passes local validation. Invalid responses
+    /// are sent back with the validation error up to
+    /// `output.max_repair_attempts` times.
+    pub async fn send_message_structured(
+        &self,
+        mut messages: Vec<Message>,
+        output: &StructuredOutput,
+    ) -> Result<serde_json::Value> {
+        let format = ApiFormat::parse(&self.config.format)?;
+        // Anthropic rejects a forced tool choice while extended thinking is on.
+        let forced_tool_client;
+        let client = if format == ApiFormat::Anthropic {
+            forced_tool_client = self.with_reasoning_mode(ReasoningMode::Disabled);
+            &forced_tool_client
+        } else {
+            self
+        };
+        let (tools, fields) = structured_output::request_parts(format, output);
+        let mut extra_body = match self.config.custom_request_body.clone() {
+            Some(serde_json::Value::Object(custom)) => custom,
+            _ => serde_json::Map::new(),
+        };
+        extra_body.extend(fields);
+        let extra_body = serde_json::Value::Object(extra_body);
+
+        let mut last_error = String::new();
+        for attempt in 0..=output.max_repair_attempts {
+            let response = client
+                .send_message_with_extra_body(
+                    messages.clone(),
+                    tools.clone(),
+                    Some(extra_body.clone()),
+                )
+                .await?;
+            match structured_output::parse_response(output, &response) {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    warn!(
+                        "Structured output did not match schema: name={}, attempt={}/{}, error={}",
+                        output.name,
+                        attempt + 1,
+                        output.max_repair_attempts + 1,
+                        error
+                    );
+                    let previous = response
+                        .tool_calls
+                        .as_ref()
+                        .and_then(|calls| calls.first())
+                        .map(|call| call.serialized_arguments())
+                        .unwrap_or(response.text);
+                    messages.push(Message::assistant(previous));
+                    messages.push(Message::user(structured_output::repair_prompt(
+                        output, &error,
+                    )));
+                    last_error = error;
+                }
+            }
+        }
+
+        Err(anyhow!(
+            "Model output did not match schema '{}' after {} attempt(s): {}",
+            output.name,
+            output.max_repair_attempts + 1,
+            last_error
+        ))
+    }
+
     pub async fn send_message_with_extra_body(
         &self,
         messages: Vec<Message>,
@@ -286,7 +464,11 @@ impl AIClient {
         max_tries: usize,
         trace: Option<ModelExchangeTraceConfig>,
     ) -> Result<StreamResponse> {
-        match ApiFormat::parse(&self.config.format)? {
+        let format = ApiFormat::parse(&self.config.format)?;
+        let permits = self
+            .acquire_request_permits(&messages, tools.as_deref())
+            .await?;
+        let mut response = match format {
             ApiFormat::OpenAIChat => {
                 openai::chat::send_stream(self, messages, tools, extra_body, max_tries, trace).await
             }
@@ -308,13 +490,47 @@ impl AIClient {
                 )
                 .await
             }
+        }?;
+        if !permits.is_empty() {
+            response.stream = hold_permits_until_stream_ends(response.stream, permits);
         }
+        Ok(response)
+    }
+
+    async fn acquire_request_permits(
+        &self,
+        messages: &[Message],
+        tools: Option<&[ToolDefinition]>,
+    ) -> Result<Vec<RequestPermit>> {
+        if self.scheduling.governors.is_empty() {
+            return Ok(Vec::new());
+        }
+        let estimated_tokens = estimate_request_tokens(&self.config.model, messages, tools);
+        let mut permits = Vec::with_capacity(self.scheduling.governors.len());
+        for governor in &self.scheduling.governors {
+            permits.push(
+                governor
+                    .acquire(
+                        &self.scheduling.fairness_key,
+                        self.scheduling.priority,
+                        estimated_tokens,
+                    )
+                    .await?,
+            );
+        }
+        Ok(permits)
     }
 
     pub async fn test_connection(&self) -> Result<ConnectionTestResult> {
         healthcheck::test_connection(self, TEST_CONNECTION_STREAM_ATTEMPTS).await
     }
 
+    /// Minimal authenticated request used to watch for expired or revoked
+    /// credentials; see [`ConnectionTestMessageCode::AuthenticationFailed`].
+    pub async fn check_credentials(&self) -> Result<ConnectionTestResult> {
+        healthcheck::check_credentials(self).await
+    }
+
     pub async fn test_image_input_connection(&self) -> Result<ConnectionTestResult> {
         healthcheck::test_image_input_connection(self, TEST_CONNECTION_STREAM_ATTEMPTS).await
     }
@@ -348,6 +564,59 @@ impl AIClient {
     }
 }
 
+/// Input size charged against tokens-per-minute budgets before the provider
+/// reports real usage, counted with the model's tokenizer.
+fn estimate_request_tokens(
+    model: &str,
+    messages: &[Message],
+    tools: Option<&[ToolDefinition]>,
+) -> u32 {
+    let tokenizer = tokenizer::tokenizer_for_model(model);
+    let message_tokens: usize = messages
+        .iter()
+        .map(|message| {
+            message
+                .content
+                .as_deref()
+                .map_or(0, |content| tokenizer.count_tokens(content))
+                + message
+                    .reasoning_content
+                    .as_deref()
+                    .map_or(0, |reasoning| tokenizer.count_tokens(reasoning))
+                + message.tool_calls.as_ref().map_or(0, |tool_calls| {
+                    tool_calls
+                        .iter()
+                        .map(|tool_call| tokenizer.count_tokens(&tool_call.serialized_arguments()))
+                        .sum()
+                })
+        })
+        .sum();
+    let tool_tokens: usize = tools.map_or(0, |tools| {
+        tools
+            .iter()
+            .map(|tool| {
+                tokenizer.count_tokens(&tool.description)
+                    + tokenizer.count_tokens(&tool.parameters.to_string())
+            })
+            .sum()
+    });
+    u32::try_from(message_tokens + tool_tokens).unwrap_or(u32::MAX)
+}
+
+/// Keeps the concurrency slots taken until the stream is fully consumed or
+/// dropped, not just until it opens.
+fn hold_permits_until_stream_ends(
+    stream: UnifiedResponseStream,
+    permits: Vec<RequestPermit>,
+) -> UnifiedResponseStream {
+    use futures::StreamExt;
+
+    Box::pin(stream.map(move |item| {
+        let _held = &permits;
+        item
+    }))
+}
+
 fn send_message_retry_delay_ms(attempt_index: usize, error_message: &str) -> u64 {
     let shift = u32::try_from(attempt_index)
         .unwrap_or(u32::MAX)
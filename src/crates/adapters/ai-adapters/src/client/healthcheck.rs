@@ -4,6 +4,8 @@ use crate::types::{ConnectionTestMessageCode, ConnectionTestResult, Message, Too
 use anyhow::Result;
 use log::debug;
 
+const CREDENTIAL_CHECK_MAX_TOKENS: u32 = 16;
+
 pub(crate) fn image_test_response_matches_expected(response: &str) -> bool {
     let upper = response.to_ascii_uppercase();
 
@@ -61,6 +63,24 @@ pub(crate) fn image_test_response_matches_expected(response: &str) -> bool {
 fn connection_error_message_code(error_msg: &str) -> Option<ConnectionTestMessageCode> {
     let msg = error_msg.to_ascii_lowercase();
 
+    let auth_keywords = [
+        "client error 401",
+        "client error 403",
+        "unauthorized",
+        "invalid api key",
+        "invalid_api_key",
+        "invalid x-api-key",
+        "incorrect api key",
+        "authentication_error",
+        "authentication failed",
+        "api key expired",
+        "token expired",
+        "permission_denied",
+    ];
+    if auth_keywords.iter().any(|keyword| msg.contains(keyword)) {
+        return Some(ConnectionTestMessageCode::AuthenticationFailed);
+    }
+
     let tls_keywords = [
         "certificate",
         "cert",
@@ -171,6 +191,35 @@ pub(crate) async fn test_connection(
     }
 }
 
+/// Cheapest authenticated request: a one-word prompt with a small output cap,
+/// no tools and no retries. Only `success` and `message_code` matter.
+pub(crate) async fn check_credentials(client: &AIClient) -> Result<ConnectionTestResult> {
+    let start_time = std::time::Instant::now();
+    let client = client.with_max_tokens(Some(CREDENTIAL_CHECK_MAX_TOKENS));
+    let messages = vec![Message::user("ping".to_string())];
+
+    match client.send_test_message(messages, None, 1).await {
+        Ok(_) => Ok(ConnectionTestResult {
+            success: true,
+            response_time_ms: elapsed_ms_u64(start_time),
+            model_response: None,
+            message_code: None,
+            error_details: None,
+        }),
+        Err(e) => {
+            let error_msg = format!("{}", e);
+            debug!("credential check failed: {}", error_msg);
+            Ok(ConnectionTestResult {
+                success: false,
+                response_time_ms: elapsed_ms_u64(start_time),
+                model_response: None,
+                message_code: connection_error_message_code(&error_msg),
+                error_details: Some(error_msg),
+            })
+        }
+    }
+}
+
 pub(crate) async fn test_image_input_connection(
     client: &AIClient,
     max_attempts: usize,
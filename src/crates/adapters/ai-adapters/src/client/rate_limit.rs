@@ -0,0 +1,602 @@
+//! Client-side request throttling.
+//!
+//! A [`RequestGovernor`] combines token buckets for requests and tokens per
+//! minute with a cap on in-flight requests. Waiters are queued per fairness
+//! key (usually a session id) and admitted round-robin across keys, so one
+//! busy session cannot starve the others sharing a model.
+//!
+//! Requests also carry a [`RequestPriority`]. Interactive waiters are admitted
+//! before background ones, except that a background waiter is let through
+//! after every few interactive admissions so summaries and scheduled jobs
+//! still make progress under sustained chat load.
+
+use anyhow::{anyhow, Result};
+use log::debug;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+const QUEUED_WAIT_LOG_THRESHOLD: Duration = Duration::from_secs(1);
+/// Interactive admissions in a row, while background waiters are queued,
+/// after which one background waiter goes first.
+const BACKGROUND_ADMISSION_INTERVAL: u32 = 4;
+
+/// Scheduling class of a request. When a governor is contended, interactive
+/// requests are admitted ahead of background ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RequestPriority {
+    /// A user is waiting on the response, e.g. a chat turn.
+    #[default]
+    Interactive,
+    /// Summaries, titles, indexing and scheduled jobs.
+    Background,
+}
+
+impl RequestPriority {
+    fn lane(self) -> usize {
+        match self {
+            Self::Interactive => 0,
+            Self::Background => 1,
+        }
+    }
+}
+
+/// Admission counters of one [`RequestPriority`] class.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestClassMetrics {
+    /// Requests currently waiting for admission.
+    pub queued: usize,
+    /// Requests admitted since the governor was created.
+    pub admitted: u64,
+    /// Summed queueing time of the admitted requests.
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
+impl RequestClassMetrics {
+    pub fn average_wait(&self) -> Duration {
+        if self.admitted == 0 {
+            return Duration::ZERO;
+        }
+        self.total_wait.div_f64(self.admitted as f64)
+    }
+
+    fn record_admission(&mut self, waited: Duration) {
+        self.admitted += 1;
+        self.total_wait += waited;
+        self.max_wait = self.max_wait.max(waited);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestGovernorMetrics {
+    pub interactive: RequestClassMetrics,
+    pub background: RequestClassMetrics,
+}
+
+/// Limits enforced by a [`RequestGovernor`]. `None` leaves that dimension
+/// unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestLimits {
+    pub requests_per_minute: Option<u32>,
+    pub tokens_per_minute: Option<u32>,
+    pub max_concurrent: Option<u32>,
+}
+
+impl RequestLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.requests_per_minute.is_none()
+            && self.tokens_per_minute.is_none()
+            && self.max_concurrent.is_none()
+    }
+}
+
+pub struct RequestGovernor {
+    name: String,
+    state: Mutex<GovernorState>,
+}
+
+/// Held for the lifetime of a request; dropping it frees the concurrency slot.
+pub struct RequestPermit {
+    governor: Arc<RequestGovernor>,
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        self.governor.release();
+    }
+}
+
+struct GovernorState {
+    limits: RequestLimits,
+    in_flight: u32,
+    request_bucket: Option<TokenBucket>,
+    token_bucket: Option<TokenBucket>,
+    /// Indexed by [`RequestPriority::lane`].
+    lanes: [Lane; 2],
+    /// Interactive admissions since a background waiter was last admitted,
+    /// counted only while background waiters are queued.
+    interactive_streak: u32,
+    /// Indexed by [`RequestPriority::lane`]; `queued` is filled on read.
+    metrics: [RequestClassMetrics; 2],
+    next_waiter_id: u64,
+    wake_scheduled: bool,
+}
+
+#[derive(Default)]
+struct Lane {
+    queues: HashMap<String, VecDeque<Waiter>>,
+    /// Keys with queued waiters, in round-robin order.
+    rotation: VecDeque<String>,
+}
+
+struct Waiter {
+    id: u64,
+    tokens: u32,
+    enqueued_at: Instant,
+    sender: oneshot::Sender<RequestPermit>,
+}
+
+impl RequestGovernor {
+    pub fn new(name: impl Into<String>, limits: RequestLimits) -> Arc<Self> {
+        let now = Instant::now();
+        Arc::new(Self {
+            name: name.into(),
+            state: Mutex::new(GovernorState {
+                limits,
+                in_flight: 0,
+                request_bucket: TokenBucket::new(limits.requests_per_minute, now),
+                token_bucket: TokenBucket::new(limits.tokens_per_minute, now),
+                lanes: Default::default(),
+                interactive_streak: 0,
+                metrics: Default::default(),
+                next_waiter_id: 0,
+                wake_scheduled: false,
+            }),
+        })
+    }
+
+    pub fn limits(&self) -> RequestLimits {
+        self.lock().limits
+    }
+
+    pub fn metrics(&self) -> RequestGovernorMetrics {
+        let state = self.lock();
+        let class = |priority: RequestPriority| {
+            let lane = priority.lane();
+            RequestClassMetrics {
+                queued: state.lanes[lane].queues.values().map(VecDeque::len).sum(),
+                ..state.metrics[lane]
+            }
+        };
+        RequestGovernorMetrics {
+            interactive: class(RequestPriority::Interactive),
+            background: class(RequestPriority::Background),
+        }
+    }
+
+    /// Applies new limits to in-flight and queued requests. Buckets are only
+    /// reset when their rate actually changes.
+    pub fn set_limits(self: &Arc<Self>, limits: RequestLimits) {
+        {
+            let mut state = self.lock();
+            if state.limits == limits {
+                return;
+            }
+            let now = Instant::now();
+            if state.limits.requests_per_minute != limits.requests_per_minute {
+                state.request_bucket = TokenBucket::new(limits.requests_per_minute, now);
+            }
+            if state.limits.tokens_per_minute != limits.tokens_per_minute {
+                state.token_bucket = TokenBucket::new(limits.tokens_per_minute, now);
+            }
+            state.limits = limits;
+        }
+        self.dispatch();
+    }
+
+    /// Waits until a request of roughly `estimated_tokens` may start.
+    pub async fn acquire(
+        self: &Arc<Self>,
+        fairness_key: &str,
+        priority: RequestPriority,
+        estimated_tokens: u32,
+    ) -> Result<RequestPermit> {
+        let started = Instant::now();
+        let (waiter_id, receiver) = self.enqueue(fairness_key, priority, estimated_tokens);
+        let _queued = QueuedWaiter {
+            governor: self,
+            fairness_key,
+            priority,
+            waiter_id,
+        };
+        self.dispatch();
+
+        let permit = receiver
+            .await
+            .map_err(|_| anyhow!("Request governor '{}' dropped a queued request", self.name))?;
+        let waited = started.elapsed();
+        if waited >= QUEUED_WAIT_LOG_THRESHOLD {
+            debug!(
+                "AI request throttled: governor={}, key={}, priority={:?}, waited_ms={}",
+                self.name,
+                fairness_key,
+                priority,
+                waited.as_millis()
+            );
+        }
+        Ok(permit)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, GovernorState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn enqueue(
+        &self,
+        fairness_key: &str,
+        priority: RequestPriority,
+        estimated_tokens: u32,
+    ) -> (u64, oneshot::Receiver<RequestPermit>) {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.lock();
+        let id = state.next_waiter_id;
+        state.next_waiter_id += 1;
+        let lane = &mut state.lanes[priority.lane()];
+        let queue = lane.queues.entry(fairness_key.to_string()).or_default();
+        let was_idle = queue.is_empty();
+        queue.push_back(Waiter {
+            id,
+            tokens: estimated_tokens,
+            enqueued_at: Instant::now(),
+            sender,
+        });
+        if was_idle {
+            lane.rotation.push_back(fairness_key.to_string());
+        }
+        (id, receiver)
+    }
+
+    /// Removes a waiter whose `acquire` future was dropped before admission.
+    fn cancel(self: &Arc<Self>, fairness_key: &str, priority: RequestPriority, waiter_id: u64) {
+        let removed = {
+            let mut state = self.lock();
+            let lane = &mut state.lanes[priority.lane()];
+            let Some(queue) = lane.queues.get_mut(fairness_key) else {
+                return;
+            };
+            let Some(index) = queue.iter().position(|waiter| waiter.id == waiter_id) else {
+                return;
+            };
+            queue.remove(index);
+            if queue.is_empty() {
+                lane.queues.remove(fairness_key);
+                lane.rotation.retain(|key| key != fairness_key);
+            }
+            index == 0
+        };
+        // A cancelled head may have been blocking smaller requests behind it.
+        if removed {
+            self.dispatch();
+        }
+    }
+
+    fn release(self: &Arc<Self>) {
+        {
+            let mut state = self.lock();
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        self.dispatch();
+    }
+
+    /// Admits queued waiters until a limit is hit: interactive before
+    /// background, round-robin across keys within a class. When a bucket is
+    /// empty, a timer re-runs dispatch once it has refilled.
+    fn dispatch(self: &Arc<Self>) {
+        let mut admitted = Vec::new();
+        let mut wake_after = None;
+        {
+            let mut state = self.lock();
+            let now = Instant::now();
+            while let Some(lane_index) = state.next_lane() {
+                if let Some(max_concurrent) = state.limits.max_concurrent {
+                    if state.in_flight >= max_concurrent.max(1) {
+                        break;
+                    }
+                }
+                let lane = &mut state.lanes[lane_index];
+                let Some(key) = lane.rotation.front().cloned() else {
+                    break;
+                };
+                let Some(tokens) = lane
+                    .queues
+                    .get(&key)
+                    .and_then(|queue| queue.front())
+                    .map(|waiter| waiter.tokens)
+                else {
+                    lane.rotation.pop_front();
+                    continue;
+                };
+                if let Some(delay) = state.admission_delay(tokens, now) {
+                    wake_after = Some(delay);
+                    break;
+                }
+
+                let lane = &mut state.lanes[lane_index];
+                lane.rotation.pop_front();
+                let (waiter, drained) = match lane.queues.get_mut(&key) {
+                    Some(queue) => (queue.pop_front(), queue.is_empty()),
+                    None => (None, true),
+                };
+                if drained {
+                    lane.queues.remove(&key);
+                } else {
+                    lane.rotation.push_back(key);
+                }
+                if let Some(waiter) = waiter {
+                    state.consume(tokens);
+                    state.in_flight += 1;
+                    state.record_admission(
+                        lane_index,
+                        now.saturating_duration_since(waiter.enqueued_at),
+                    );
+                    admitted.push(waiter.sender);
+                }
+            }
+
+            if wake_after.is_some() {
+                if state.wake_scheduled {
+                    wake_after = None;
+                } else {
+                    state.wake_scheduled = true;
+                }
+            }
+        }
+
+        // Sent outside the lock: a failed send drops the permit, which
+        // re-enters `release`.
+        for sender in admitted {
+            let _ = sender.send(RequestPermit {
+                governor: Arc::clone(self),
+            });
+        }
+
+        if let Some(delay) = wake_after {
+            let governor = Arc::clone(self);
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                governor.lock().wake_scheduled = false;
+                governor.dispatch();
+            });
+        }
+    }
+}
+
+impl GovernorState {
+    /// Lane to admit from next: interactive first, unless background waiters
+    /// have been passed over [`BACKGROUND_ADMISSION_INTERVAL`] times in a row.
+    fn next_lane(&self) -> Option<usize> {
+        let interactive = RequestPriority::Interactive.lane();
+        let background = RequestPriority::Background.lane();
+        match (
+            self.lanes[interactive].rotation.is_empty(),
+            self.lanes[background].rotation.is_empty(),
+        ) {
+            (true, true) => None,
+            (false, true) => Some(interactive),
+            (true, false) => Some(background),
+            (false, false) if self.interactive_streak >= BACKGROUND_ADMISSION_INTERVAL => {
+                Some(background)
+            }
+            (false, false) => Some(interactive),
+        }
+    }
+
+    fn record_admission(&mut self, lane: usize, waited: Duration) {
+        self.metrics[lane].record_admission(waited);
+        let background = RequestPriority::Background.lane();
+        if lane == background || self.lanes[background].rotation.is_empty() {
+            self.interactive_streak = 0;
+        } else {
+            self.interactive_streak += 1;
+        }
+    }
+
+    fn admission_delay(&mut self, tokens: u32, now: Instant) -> Option<Duration> {
+        let request_delay = self
+            .request_bucket
+            .as_mut()
+            .and_then(|bucket| bucket.delay_for(1, now));
+        let token_delay = self
+            .token_bucket
+            .as_mut()
+            .and_then(|bucket| bucket.delay_for(tokens, now));
+        request_delay.max(token_delay)
+    }
+
+    fn consume(&mut self, tokens: u32) {
+        if let Some(bucket) = self.request_bucket.as_mut() {
+            bucket.take(1);
+        }
+        if let Some(bucket) = self.token_bucket.as_mut() {
+            bucket.take(tokens);
+        }
+    }
+}
+
+struct QueuedWaiter<'a> {
+    governor: &'a Arc<RequestGovernor>,
+    fairness_key: &'a str,
+    priority: RequestPriority,
+    waiter_id: u64,
+}
+
+impl Drop for QueuedWaiter<'_> {
+    fn drop(&mut self) {
+        self.governor
+            .cancel(self.fairness_key, self.priority, self.waiter_id);
+    }
+}
+
+/// Continuously refilling bucket holding up to one minute of budget.
+struct TokenBucket {
+    capacity: f64,
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(per_minute: Option<u32>, now: Instant) -> Option<Self> {
+        let capacity = f64::from(per_minute?.max(1));
+        Some(Self {
+            capacity,
+            available: capacity,
+            refilled_at: now,
+        })
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.available = (self.available + elapsed * self.capacity / 60.0).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    /// Requests larger than the whole bucket are clamped so they can still
+    /// run once it is full.
+    fn delay_for(&mut self, amount: u32, now: Instant) -> Option<Duration> {
+        self.refill(now);
+        let amount = f64::from(amount).min(self.capacity);
+        if self.available >= amount {
+            return None;
+        }
+        let seconds = (amount - self.available) * 60.0 / self.capacity;
+        Some(Duration::from_secs_f64(seconds).max(Duration::from_millis(10)))
+    }
+
+    fn take(&mut self, amount: u32) {
+        let amount = f64::from(amount).min(self.capacity);
+        self.available = (self.available - amount).max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERACTIVE: RequestPriority = RequestPriority::Interactive;
+    const BACKGROUND: RequestPriority = RequestPriority::Background;
+
+    #[tokio::test]
+    async fn concurrency_slots_rotate_across_fairness_keys() {
+        let governor = RequestGovernor::new(
+            "test",
+            RequestLimits {
+                max_concurrent: Some(1),
+                ..Default::default()
+            },
+        );
+        let running = governor.acquire("a", INTERACTIVE, 0).await.unwrap();
+
+        let (_, mut a1) = governor.enqueue("a", INTERACTIVE, 0);
+        let (_, mut a2) = governor.enqueue("a", INTERACTIVE, 0);
+        let (_, mut b1) = governor.enqueue("b", INTERACTIVE, 0);
+        governor.dispatch();
+        assert!(a1.try_recv().is_err());
+
+        drop(running);
+        let first = a1.try_recv().expect("a1 admitted first");
+        assert!(b1.try_recv().is_err());
+
+        drop(first);
+        let second = b1.try_recv().expect("b1 admitted before a2");
+        assert!(a2.try_recv().is_err());
+
+        drop(second);
+        assert!(a2.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn cancelled_waiters_leave_the_queue() {
+        let governor = RequestGovernor::new(
+            "test",
+            RequestLimits {
+                max_concurrent: Some(1),
+                ..Default::default()
+            },
+        );
+        let running = governor.acquire("a", INTERACTIVE, 0).await.unwrap();
+        let (waiter_id, receiver) = governor.enqueue("b", INTERACTIVE, 0);
+        drop(receiver);
+        governor.cancel("b", INTERACTIVE, waiter_id);
+        drop(running);
+
+        let state = governor.lock();
+        assert!(state.lanes.iter().all(|lane| lane.rotation.is_empty()));
+        assert_eq!(state.in_flight, 0);
+    }
+
+    #[tokio::test]
+    async fn interactive_requests_go_first_without_starving_background() {
+        let governor = RequestGovernor::new(
+            "test",
+            RequestLimits {
+                max_concurrent: Some(1),
+                ..Default::default()
+            },
+        );
+        let mut running = governor.acquire("chat", INTERACTIVE, 0).await.unwrap();
+
+        let (_, mut background) = governor.enqueue("titles", BACKGROUND, 0);
+        let mut interactive: VecDeque<_> = (0..=BACKGROUND_ADMISSION_INTERVAL)
+            .map(|_| governor.enqueue("chat", INTERACTIVE, 0).1)
+            .collect();
+
+        for _ in 0..BACKGROUND_ADMISSION_INTERVAL {
+            drop(running);
+            let mut next = interactive.pop_front().unwrap();
+            running = next.try_recv().expect("interactive admitted first");
+            assert!(background.try_recv().is_err());
+        }
+
+        drop(running);
+        let running = background
+            .try_recv()
+            .expect("background admitted after the streak");
+        assert!(interactive[0].try_recv().is_err());
+
+        let metrics = governor.metrics();
+        assert_eq!(
+            metrics.interactive.admitted,
+            1 + u64::from(BACKGROUND_ADMISSION_INTERVAL)
+        );
+        assert_eq!(metrics.interactive.queued, 1);
+        assert_eq!(metrics.background.admitted, 1);
+        assert_eq!(metrics.background.queued, 0);
+
+        drop(running);
+        assert!(interactive[0].try_recv().is_ok());
+    }
+
+    #[test]
+    fn token_bucket_reports_refill_delay() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(Some(60), now).unwrap();
+        assert_eq!(bucket.delay_for(60, now), None);
+        bucket.take(60);
+
+        let delay = bucket.delay_for(30, now).unwrap();
+        assert_eq!(delay.as_secs(), 30);
+        assert_eq!(bucket.delay_for(30, now + Duration::from_secs(30)), None);
+
+        // Oversized requests wait for a full bucket instead of forever.
+        assert!(bucket
+            .delay_for(1_000, now + Duration::from_secs(30))
+            .is_some());
+        assert_eq!(bucket.delay_for(1_000, now + Duration::from_secs(60)), None);
+    }
+}
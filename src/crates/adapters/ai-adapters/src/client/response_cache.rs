@@ -0,0 +1,236 @@
+//! Reuse of model responses for repeated utility requests.
+//!
+//! A [`ResponseCache`] maps a normalized request (model settings, messages,
+//! tools and extra body) to the aggregated response it produced. Only calls
+//! made through [`AIClient::send_message_cached`](super::AIClient::send_message_cached)
+//! read or fill it, so callers opt in where a repeated answer is acceptable:
+//! titles, summaries, classifications and other temperature-0 style requests.
+
+use crate::types::{AIConfig, GeminiResponse, Message, ToolDefinition};
+use log::debug;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Limits of a [`ResponseCache`]. `max_entries == 0` disables the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseCacheLimits {
+    /// How long a stored response may be reused.
+    pub ttl: Duration,
+    /// Least recently used entries are evicted beyond this count.
+    pub max_entries: usize,
+}
+
+impl Default for ResponseCacheLimits {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(10 * 60),
+            max_entries: 256,
+        }
+    }
+}
+
+/// How a cached send treats the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseCacheMode {
+    /// Return a fresh stored response when there is one.
+    #[default]
+    Reuse,
+    /// Always ask the model, then replace the stored response.
+    Bypass,
+}
+
+pub struct ResponseCache {
+    state: Mutex<CacheState>,
+}
+
+struct CacheState {
+    limits: ResponseCacheLimits,
+    entries: HashMap<String, CacheEntry>,
+}
+
+struct CacheEntry {
+    response: GeminiResponse,
+    stored_at: Instant,
+    last_used: Instant,
+}
+
+impl ResponseCache {
+    pub fn new(limits: ResponseCacheLimits) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(CacheState {
+                limits,
+                entries: HashMap::new(),
+            }),
+        })
+    }
+
+    pub fn limits(&self) -> ResponseCacheLimits {
+        self.lock().limits
+    }
+
+    /// Applies new limits, dropping entries that no longer fit.
+    pub fn set_limits(&self, limits: ResponseCacheLimits) {
+        let mut state = self.lock();
+        if state.limits == limits {
+            return;
+        }
+        state.limits = limits;
+        let now = Instant::now();
+        state.evict(now);
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<GeminiResponse> {
+        let mut state = self.lock();
+        let ttl = state.limits.ttl;
+        let now = Instant::now();
+        let entry = state.entries.get_mut(key)?;
+        if now.duration_since(entry.stored_at) >= ttl {
+            state.entries.remove(key);
+            return None;
+        }
+        entry.last_used = now;
+        Some(entry.response.clone())
+    }
+
+    pub(crate) fn insert(&self, key: String, response: GeminiResponse) {
+        let mut state = self.lock();
+        if state.limits.max_entries == 0 {
+            return;
+        }
+        let now = Instant::now();
+        state.entries.insert(
+            key,
+            CacheEntry {
+                response,
+                stored_at: now,
+                last_used: now,
+            },
+        );
+        state.evict(now);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CacheState {
+    /// Drops expired entries, then the least recently used ones over the cap.
+    fn evict(&mut self, now: Instant) {
+        let ttl = self.limits.ttl;
+        self.entries
+            .retain(|_, entry| now.duration_since(entry.stored_at) < ttl);
+        while self.entries.len() > self.limits.max_entries {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+            debug!(
+                "Evicted AI response cache entry: remaining={}",
+                self.entries.len()
+            );
+        }
+    }
+}
+
+/// Cache key for a request: every setting that changes what the provider is
+/// asked, plus the messages, tools and extra body. Credentials and headers
+/// are left out; two clients for the same endpoint and model share entries.
+pub(crate) fn response_cache_key(
+    config: &AIConfig,
+    messages: &[Message],
+    tools: Option<&[ToolDefinition]>,
+    extra_body: Option<&Value>,
+) -> Option<String> {
+    let key = json!({
+        "request_url": config.request_url,
+        "model": config.model,
+        "format": config.format,
+        "max_tokens": config.max_tokens,
+        "temperature": config.temperature,
+        "top_p": config.top_p,
+        "reasoning_mode": config.reasoning_mode,
+        "reasoning_effort": config.reasoning_effort,
+        "thinking_budget_tokens": config.thinking_budget_tokens,
+        "messages": messages.iter().map(normalize_message).collect::<Option<Vec<_>>>()?,
+        "tools": tools,
+        "extra_body": extra_body,
+    });
+    serde_json::to_string(&key).ok()
+}
+
+/// Message content with surrounding whitespace trimmed, so prompts that only
+/// differ in template padding share an entry.
+fn normalize_message(message: &Message) -> Option<Value> {
+    let mut value = serde_json::to_value(message).ok()?;
+    if let Some(content) = message.content.as_deref() {
+        value["content"] = json!(content.trim());
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(text: &str) -> GeminiResponse {
+        GeminiResponse {
+            text: text.to_string(),
+            reasoning_content: None,
+            tool_calls: None,
+            usage: None,
+            finish_reason: Some("stop".to_string()),
+            provider_metadata: None,
+        }
+    }
+
+    #[test]
+    fn entries_expire_and_least_recently_used_is_evicted() {
+        let cache = ResponseCache::new(ResponseCacheLimits {
+            ttl: Duration::from_secs(60),
+            max_entries: 2,
+        });
+        cache.insert("a".to_string(), response("A"));
+        cache.insert("b".to_string(), response("B"));
+        assert_eq!(cache.get("a").map(|hit| hit.text).as_deref(), Some("A"));
+
+        cache.insert("c".to_string(), response("C"));
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+
+        cache.set_limits(ResponseCacheLimits {
+            ttl: Duration::ZERO,
+            max_entries: 2,
+        });
+        assert!(cache.is_empty());
+
+        cache.set_limits(ResponseCacheLimits {
+            ttl: Duration::from_secs(60),
+            max_entries: 0,
+        });
+        cache.insert("d".to_string(), response("D"));
+        assert!(cache.get("d").is_none());
+    }
+}
@@ -0,0 +1,332 @@
+//! JSON schema constrained responses.
+//!
+//! [`AIClient::send_message_structured`](super::AIClient::send_message_structured)
+//! asks the provider for output matching a [`StructuredOutput`] schema:
+//! `response_format` for OpenAI Chat and Gemini, `text.format` for the
+//! Responses API, and a forced tool call for Anthropic. The result is checked
+//! locally with [`validate_json_schema`] and invalid output is sent back to
+//! the model for a bounded number of repairs.
+
+use super::format::ApiFormat;
+use crate::types::{GeminiResponse, ToolDefinition};
+use serde_json::{json, Map, Value};
+
+const DEFAULT_MAX_REPAIR_ATTEMPTS: usize = 2;
+
+/// Schema a structured request must satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredOutput {
+    /// Schema name sent to the provider; also the forced tool's name.
+    pub name: String,
+    /// JSON schema of the expected object.
+    pub schema: Value,
+    /// Ask providers for strict schema adherence. OpenAI's strict mode needs
+    /// every property listed in `required` and `additionalProperties: false`.
+    pub strict: bool,
+    /// Extra requests allowed after an invalid response.
+    pub max_repair_attempts: usize,
+}
+
+impl StructuredOutput {
+    pub fn new(name: impl Into<String>, schema: Value) -> Self {
+        Self {
+            name: name.into(),
+            schema,
+            strict: false,
+            max_repair_attempts: DEFAULT_MAX_REPAIR_ATTEMPTS,
+        }
+    }
+}
+
+/// Tools and request body fields that ask `format` for structured output.
+pub(crate) fn request_parts(
+    format: ApiFormat,
+    output: &StructuredOutput,
+) -> (Option<Vec<ToolDefinition>>, Map<String, Value>) {
+    let mut fields = Map::new();
+    match format {
+        ApiFormat::OpenAIChat | ApiFormat::Gemini | ApiFormat::GeminiCodeAssist => {
+            // Gemini translates `response_format` into its generation config.
+            fields.insert(
+                "response_format".to_string(),
+                json!({
+                    "type": "json_schema",
+                    "json_schema": {
+                        "name": output.name,
+                        "schema": output.schema,
+                        "strict": output.strict,
+                    }
+                }),
+            );
+            (None, fields)
+        }
+        ApiFormat::OpenAIResponses => {
+            fields.insert(
+                "text".to_string(),
+                json!({
+                    "format": {
+                        "type": "json_schema",
+                        "name": output.name,
+                        "schema": output.schema,
+                        "strict": output.strict,
+                    }
+                }),
+            );
+            (None, fields)
+        }
+        ApiFormat::Anthropic => {
+            fields.insert(
+                "tool_choice".to_string(),
+                json!({ "type": "tool", "name": output.name }),
+            );
+            let tool = ToolDefinition {
+                name: output.name.clone(),
+                description: "Return the answer as this tool's input.".to_string(),
+                parameters: output.schema.clone(),
+            };
+            (Some(vec![tool]), fields)
+        }
+    }
+}
+
+/// The structured value in `response`: the forced tool's input when there is
+/// one, otherwise the JSON in the response text.
+pub(crate) fn parse_response(
+    output: &StructuredOutput,
+    response: &GeminiResponse,
+) -> Result<Value, String> {
+    let value = match response
+        .tool_calls
+        .iter()
+        .flatten()
+        .find(|call| call.name == output.name)
+    {
+        Some(call) => match &call.arguments {
+            Value::String(raw) => serde_json::from_str(raw)
+                .map_err(|e| format!("Tool input is not valid JSON: {}", e))?,
+            arguments => arguments.clone(),
+        },
+        None => {
+            let text = extract_json_text(&response.text)
+                .ok_or_else(|| "The response contains no JSON object".to_string())?;
+            serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {}", e))?
+        }
+    };
+    validate_json_schema(&value, &output.schema)?;
+    Ok(value)
+}
+
+/// The outermost JSON object or array in `text`, ignoring code fences and
+/// surrounding prose.
+fn extract_json_text(text: &str) -> Option<&str> {
+    let start = text.find(['{', '['])?;
+    let closing = if text[start..].starts_with('{') {
+        '}'
+    } else {
+        ']'
+    };
+    let end = text.rfind(closing)?;
+    (end > start).then(|| &text[start..=end])
+}
+
+pub(crate) fn repair_prompt(output: &StructuredOutput, error: &str) -> String {
+    format!(
+        "Your previous response did not match the required JSON schema: {}\n\
+         Reply again with only a JSON value that matches this schema:\n{}",
+        error, output.schema
+    )
+}
+
+/// Checks `value` against the commonly used subset of JSON schema: `type`
+/// (single or list), `enum`, `properties`, `required`,
+/// `additionalProperties: false`, `items` and numeric `minimum`/`maximum`.
+/// Other keywords are ignored.
+pub fn validate_json_schema(value: &Value, schema: &Value) -> Result<(), String> {
+    validate_at(value, schema, "$")
+}
+
+fn validate_at(value: &Value, schema: &Value, path: &str) -> Result<(), String> {
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(kind) => vec![kind.as_str()],
+            Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|kind| matches_type(value, kind)) {
+            return Err(format!("{} must be of type {}", path, types.join(" or ")));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return Err(format!(
+                "{} must be one of {}",
+                path,
+                Value::Array(allowed.clone())
+            ));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < minimum {
+                return Err(format!("{} must be at least {}", path, minimum));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > maximum {
+                return Err(format!("{} must be at most {}", path, maximum));
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for name in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !object.contains_key(name) {
+                return Err(format!("{} is missing required property '{}'", path, name));
+            }
+        }
+        for (name, item) in object {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(property_schema) => {
+                    validate_at(item, property_schema, &format!("{}.{}", path, name))?
+                }
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return Err(format!("{} has unexpected property '{}'", path, name));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Some(items), Some(item_schema)) = (value.as_array(), schema.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, &format!("{}[{}]", path, index))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_type(value: &Value, kind: &str) -> bool {
+    match kind {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ToolCall;
+
+    fn output() -> StructuredOutput {
+        StructuredOutput::new(
+            "commit",
+            json!({
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string" },
+                    "scope": { "type": ["string", "null"] },
+                    "confidence": { "type": "number", "minimum": 0, "maximum": 1 },
+                    "kind": { "enum": ["feat", "fix"] }
+                },
+                "required": ["title"],
+                "additionalProperties": false
+            }),
+        )
+    }
+
+    fn response(text: &str, tool_calls: Option<Vec<ToolCall>>) -> GeminiResponse {
+        GeminiResponse {
+            text: text.to_string(),
+            reasoning_content: None,
+            tool_calls,
+            usage: None,
+            finish_reason: None,
+            provider_metadata: None,
+        }
+    }
+
+    #[test]
+    fn schema_subset_is_enforced() {
+        let schema = output().schema;
+        assert_eq!(
+            validate_json_schema(
+                &json!({ "title": "x", "scope": null, "kind": "fix" }),
+                &schema
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate_json_schema(&json!({ "scope": "core" }), &schema),
+            Err("$ is missing required property 'title'".to_string())
+        );
+        assert_eq!(
+            validate_json_schema(&json!({ "title": "x", "confidence": 2 }), &schema),
+            Err("$.confidence must be at most 1".to_string())
+        );
+        assert_eq!(
+            validate_json_schema(&json!({ "title": "x", "extra": 1 }), &schema),
+            Err("$ has unexpected property 'extra'".to_string())
+        );
+        assert!(validate_json_schema(&json!({ "title": "x", "kind": "docs" }), &schema).is_err());
+    }
+
+    #[test]
+    fn responses_are_read_from_text_or_the_forced_tool() {
+        let output = output();
+        assert_eq!(
+            parse_response(
+                &output,
+                &response(
+                    "Here you go:\n```json\n{\"title\": \"Add cache\"}\n```",
+                    None
+                )
+            ),
+            Ok(json!({ "title": "Add cache" }))
+        );
+        let tool_call = ToolCall {
+            id: "call_1".to_string(),
+            name: "commit".to_string(),
+            arguments: json!({ "title": "Add cache" }),
+            raw_arguments: None,
+        };
+        assert_eq!(
+            parse_response(&output, &response("", Some(vec![tool_call]))),
+            Ok(json!({ "title": "Add cache" }))
+        );
+        assert!(parse_response(&output, &response("no json here", None)).is_err());
+    }
+
+    #[test]
+    fn each_format_gets_its_structured_output_fields() {
+        let output = output();
+        let (tools, fields) = request_parts(ApiFormat::OpenAIChat, &output);
+        assert!(tools.is_none());
+        assert_eq!(fields["response_format"]["json_schema"]["name"], "commit");
+
+        let (_, fields) = request_parts(ApiFormat::OpenAIResponses, &output);
+        assert_eq!(fields["text"]["format"]["type"], "json_schema");
+
+        let (tools, fields) = request_parts(ApiFormat::Anthropic, &output);
+        assert_eq!(tools.expect("forced tool")[0].name, "commit");
+        assert_eq!(
+            fields["tool_choice"],
+            json!({ "type": "tool", "name": "commit" })
+        );
+    }
+}
@@ -7,11 +7,16 @@ pub mod providers;
 pub mod stream;
 #[cfg(feature = "subscription-auth")]
 pub mod subscription_auth;
+pub mod tokenizer;
 pub mod tool_call_accumulator;
 pub mod trace;
 pub mod types;
 
-pub use client::{AIClient, StreamOptions, StreamResponse};
+pub use client::{
+    validate_json_schema, AIClient, RequestClassMetrics, RequestGovernor, RequestGovernorMetrics,
+    RequestLimits, RequestPermit, RequestPriority, ResponseCache, ResponseCacheLimits,
+    ResponseCacheMode, StreamOptions, StreamResponse, StructuredOutput,
+};
 pub use model_selector::{
     classify_model_selector, resolve_cache_model_selector, resolve_required_model_selector,
     ModelSelectorError, ModelSelectorKind,
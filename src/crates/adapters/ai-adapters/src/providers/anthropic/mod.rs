@@ -4,6 +4,7 @@
 
 pub mod discovery;
 pub mod message_converter;
+pub(crate) mod prompt_cache;
 pub mod request;
 
 pub use message_converter::AnthropicMessageConverter;
@@ -0,0 +1,136 @@
+//! Prompt caching breakpoints for Anthropic requests.
+//!
+//! Anthropic caches a prompt prefix up to each block marked with
+//! `cache_control`. The prefix is tools, then system, then messages, so the
+//! last tool and the system prompt cover the parts that stay fixed for a
+//! session, and the last message lets the next turn reuse the conversation so
+//! far. That is three of the four breakpoints a request may carry.
+
+use serde_json::{json, Value};
+
+/// Claude models behind any Anthropic-format endpoint accept `cache_control`;
+/// other vendors' Anthropic-compatible endpoints may reject it.
+pub(crate) fn supports_prompt_caching(url: &str, model_name: &str) -> bool {
+    model_name.contains("claude") && !url.contains("bigmodel.cn")
+}
+
+/// Marks the last tool, the system prompt and the last message block as
+/// cache breakpoints. Bodies that already carry `cache_control` anywhere are
+/// left alone so custom request bodies keep control.
+pub(crate) fn apply_cache_breakpoints(request_body: &mut Value) {
+    if has_cache_control(request_body) {
+        return;
+    }
+
+    if let Some(last_tool) = request_body
+        .get_mut("tools")
+        .and_then(Value::as_array_mut)
+        .and_then(|tools| tools.last_mut())
+    {
+        mark(last_tool);
+    }
+
+    if let Some(system) = request_body.get_mut("system") {
+        mark_last_block(system);
+    }
+
+    if let Some(content) = request_body
+        .get_mut("messages")
+        .and_then(Value::as_array_mut)
+        .and_then(|messages| messages.last_mut())
+        .and_then(|message| message.get_mut("content"))
+    {
+        mark_last_block(content);
+    }
+}
+
+fn has_cache_control(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => {
+            map.contains_key("cache_control") || map.values().any(has_cache_control)
+        }
+        Value::Array(items) => items.iter().any(has_cache_control),
+        _ => false,
+    }
+}
+
+fn mark(block: &mut Value) {
+    if let Some(block) = block.as_object_mut() {
+        block.insert("cache_control".to_string(), json!({ "type": "ephemeral" }));
+    }
+}
+
+/// Marks the last block of `content`, turning plain text into a text block
+/// first. Empty text and thinking blocks cannot carry a breakpoint.
+fn mark_last_block(content: &mut Value) {
+    if let Some(text) = content.as_str() {
+        if text.is_empty() {
+            return;
+        }
+        *content = json!([{ "type": "text", "text": text }]);
+    }
+    let Some(last) = content.as_array_mut().and_then(|blocks| blocks.last_mut()) else {
+        return;
+    };
+    if matches!(
+        last.get("type").and_then(Value::as_str),
+        Some("thinking" | "redacted_thinking")
+    ) {
+        return;
+    }
+    mark(last);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_last_tool_system_and_last_message() {
+        let mut body = json!({
+            "system": "You are BitFun.",
+            "tools": [{ "name": "Read" }, { "name": "Write" }],
+            "messages": [
+                { "role": "user", "content": "hi" },
+                { "role": "assistant", "content": [{ "type": "text", "text": "hello" }] },
+                { "role": "user", "content": [
+                    { "type": "tool_result", "tool_use_id": "t1", "content": "ok" }
+                ] }
+            ]
+        });
+
+        apply_cache_breakpoints(&mut body);
+
+        let ephemeral = json!({ "type": "ephemeral" });
+        assert!(body["tools"][0].get("cache_control").is_none());
+        assert_eq!(body["tools"][1]["cache_control"], ephemeral);
+        assert_eq!(body["system"][0]["text"], "You are BitFun.");
+        assert_eq!(body["system"][0]["cache_control"], ephemeral);
+        assert_eq!(body["messages"][0]["content"], "hi");
+        assert_eq!(
+            body["messages"][2]["content"][0]["cache_control"],
+            ephemeral
+        );
+    }
+
+    #[test]
+    fn leaves_bodies_with_custom_breakpoints_alone() {
+        let mut body = json!({
+            "system": [{ "type": "text", "text": "s", "cache_control": { "type": "ephemeral" } }],
+            "messages": [{ "role": "user", "content": "hi" }]
+        });
+        let original = body.clone();
+
+        apply_cache_breakpoints(&mut body);
+
+        assert_eq!(body, original);
+        assert!(supports_prompt_caching(
+            "https://api.anthropic.com/v1/messages",
+            "claude-sonnet-4-6"
+        ));
+        assert!(!supports_prompt_caching(
+            "https://api.deepseek.com/anthropic",
+            "deepseek-v4-pro"
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use super::AnthropicMessageConverter;
+use super::{prompt_cache, AnthropicMessageConverter};
 use crate::client::quirks::{
     is_deepseek_reasoning_effort_model, is_deepseek_url, normalize_deepseek_reasoning_effort,
     should_append_tool_stream,
@@ -310,6 +310,10 @@ pub(crate) fn build_request_body(
         }
     }
 
+    if prompt_cache::supports_prompt_caching(url, &model_name) {
+        prompt_cache::apply_cache_breakpoints(&mut request_body);
+    }
+
     request_body
 }
 
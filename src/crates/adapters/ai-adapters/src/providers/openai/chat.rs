@@ -29,6 +29,7 @@ pub(crate) fn build_request_body(
     }
 
     common::apply_reasoning_fields(&mut request_body, client, url);
+    common::apply_prompt_cache_key(&mut request_body, client, url);
 
     if let Some(max_tokens) = client.config.max_tokens {
         request_body["max_tokens"] = serde_json::json!(max_tokens);
@@ -47,6 +47,22 @@ pub(crate) fn apply_reasoning_fields(
     );
 }
 
+/// OpenAI caches prompt prefixes on its own; `prompt_cache_key` keeps a
+/// session's requests on the same cache. Only OpenAI's API is known to accept
+/// the field, and a custom request body may still override it.
+pub(crate) fn apply_prompt_cache_key(
+    request_body: &mut serde_json::Value,
+    client: &AIClient,
+    url: &str,
+) {
+    if !url.contains("api.openai.com") {
+        return;
+    }
+    if let Some(key) = client.prompt_cache_key() {
+        request_body["prompt_cache_key"] = serde_json::json!(key);
+    }
+}
+
 pub(crate) fn resolve_models_url(client: &AIClient) -> String {
     let mut base = normalize_base_url_for_discovery(&client.config.base_url);
 
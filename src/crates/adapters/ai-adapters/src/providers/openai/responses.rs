@@ -30,6 +30,8 @@ pub(crate) fn build_request_body(
         request_body["max_output_tokens"] = serde_json::json!(max_tokens);
     }
 
+    common::apply_prompt_cache_key(&mut request_body, client, &client.config.request_url);
+
     let responses_effort = client
         .config
         .reasoning_effort
@@ -200,4 +202,22 @@ mod tests {
         assert_eq!(request_body["tools"][0]["type"], json!("function"));
         assert!(request_body["tools"][0].get("function").is_none());
     }
+
+    #[test]
+    fn sends_session_prompt_cache_key_to_openai() {
+        let client = test_client();
+        let input = vec![json!({ "type": "message", "role": "user", "content": "hello" })];
+
+        let unkeyed = build_request_body(&client, None, input.clone(), None, None);
+        assert!(unkeyed.get("prompt_cache_key").is_none());
+
+        let keyed = build_request_body(
+            &client.with_fairness_key("session-1"),
+            None,
+            input,
+            None,
+            None,
+        );
+        assert_eq!(keyed["prompt_cache_key"], json!("session-1"));
+    }
 }
@@ -0,0 +1,299 @@
+//! Local token counting.
+//!
+//! [`tokenizer_for_model`] picks a tokenizer from the model name: OpenAI's
+//! BPE vocabularies for GPT models, a padded cl100k count for Claude (whose
+//! tokenizer is not published), and o200k for everything else. o200k tracks
+//! current vocabularies far better than per-character heuristics, especially
+//! on CJK text. Hosts can register their own tokenizer for a model prefix,
+//! e.g. a sentencepiece model loaded with the `sentencepiece-tokenizer`
+//! feature.
+
+use std::sync::{Arc, OnceLock, RwLock};
+use tiktoken_rs::CoreBPE;
+
+pub trait Tokenizer: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn count_tokens(&self, text: &str) -> usize;
+
+    /// Longest prefix of `text` within `max_tokens`, cut on a char boundary.
+    fn truncate_to_tokens<'a>(&self, text: &'a str, max_tokens: usize) -> &'a str {
+        if self.count_tokens(text) <= max_tokens {
+            return text;
+        }
+        let ends: Vec<usize> = text
+            .char_indices()
+            .map(|(index, ch)| index + ch.len_utf8())
+            .collect();
+        let fitting = ends.partition_point(|&end| self.count_tokens(&text[..end]) <= max_tokens);
+        match fitting.checked_sub(1) {
+            Some(last) => &text[..ends[last]],
+            None => "",
+        }
+    }
+}
+
+/// Byte-pair encoding with one of OpenAI's published vocabularies.
+pub struct BpeTokenizer {
+    name: &'static str,
+    bpe: &'static CoreBPE,
+}
+
+impl BpeTokenizer {
+    /// GPT-4o and later.
+    pub fn o200k() -> Self {
+        Self {
+            name: "o200k_base",
+            bpe: tiktoken_rs::o200k_base_singleton(),
+        }
+    }
+
+    /// GPT-3.5 and GPT-4.
+    pub fn cl100k() -> Self {
+        Self {
+            name: "cl100k_base",
+            bpe: tiktoken_rs::cl100k_base_singleton(),
+        }
+    }
+}
+
+impl Tokenizer for BpeTokenizer {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        self.bpe.encode_ordinary(text).len()
+    }
+
+    fn truncate_to_tokens<'a>(&self, text: &'a str, max_tokens: usize) -> &'a str {
+        let tokens = self.bpe.encode_ordinary(text);
+        if tokens.len() <= max_tokens {
+            return text;
+        }
+        // Ordinary tokens decode to the exact input bytes, so the kept
+        // tokens' byte lengths locate the cut; a token may end mid-char.
+        let mut end: usize = self
+            .bpe
+            ._decode_native_and_split(tokens[..max_tokens].to_vec())
+            .map(|bytes| bytes.len())
+            .sum();
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        &text[..end]
+    }
+}
+
+/// Claude's tokenizer is not published; cl100k counts padded by a tenth
+/// land at or slightly above what the API reports for typical text.
+pub struct AnthropicApproxTokenizer {
+    inner: BpeTokenizer,
+}
+
+impl AnthropicApproxTokenizer {
+    pub fn new() -> Self {
+        Self {
+            inner: BpeTokenizer::cl100k(),
+        }
+    }
+}
+
+impl Default for AnthropicApproxTokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tokenizer for AnthropicApproxTokenizer {
+    fn name(&self) -> &str {
+        "anthropic_approx"
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        let tokens = self.inner.count_tokens(text);
+        tokens + tokens.div_ceil(10)
+    }
+
+    fn truncate_to_tokens<'a>(&self, text: &'a str, max_tokens: usize) -> &'a str {
+        self.inner
+            .truncate_to_tokens(text, max_tokens.saturating_mul(10) / 11)
+    }
+}
+
+/// Tokenizer stored in a Hugging Face `tokenizer.json`, the format
+/// sentencepiece-based models such as Llama, Qwen and Gemma ship with.
+#[cfg(feature = "sentencepiece-tokenizer")]
+pub struct SentencePieceTokenizer {
+    name: String,
+    inner: tokenizers::Tokenizer,
+}
+
+#[cfg(feature = "sentencepiece-tokenizer")]
+impl SentencePieceTokenizer {
+    pub fn from_file(
+        name: impl Into<String>,
+        path: impl AsRef<std::path::Path>,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let inner = tokenizers::Tokenizer::from_file(path).map_err(|error| {
+            anyhow::anyhow!("Failed to load tokenizer '{}': {}", path.display(), error)
+        })?;
+        Ok(Self {
+            name: name.into(),
+            inner,
+        })
+    }
+}
+
+#[cfg(feature = "sentencepiece-tokenizer")]
+impl Tokenizer for SentencePieceTokenizer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        match self.inner.encode(text, false) {
+            Ok(encoding) => encoding.len(),
+            Err(error) => {
+                log::debug!(
+                    "Tokenizer '{}' failed, falling back to o200k: {}",
+                    self.name,
+                    error
+                );
+                default_tokenizer().count_tokens(text)
+            }
+        }
+    }
+}
+
+/// Tokenizers registered by the host, matched by model name prefix.
+static REGISTERED_TOKENIZERS: RwLock<Vec<(String, Arc<dyn Tokenizer>)>> = RwLock::new(Vec::new());
+
+/// Uses `tokenizer` for models whose name starts with `model_prefix`
+/// (case-insensitive). The longest matching prefix wins; registering a prefix
+/// again replaces its tokenizer.
+pub fn register_tokenizer(model_prefix: impl Into<String>, tokenizer: Arc<dyn Tokenizer>) {
+    let model_prefix = model_prefix.into().to_ascii_lowercase();
+    let mut registered = REGISTERED_TOKENIZERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    registered.retain(|(prefix, _)| *prefix != model_prefix);
+    registered.push((model_prefix, tokenizer));
+}
+
+/// Tokenizer used when the model is unknown.
+pub fn default_tokenizer() -> Arc<dyn Tokenizer> {
+    static O200K: OnceLock<Arc<dyn Tokenizer>> = OnceLock::new();
+    O200K
+        .get_or_init(|| Arc::new(BpeTokenizer::o200k()))
+        .clone()
+}
+
+pub fn tokenizer_for_model(model: &str) -> Arc<dyn Tokenizer> {
+    static CL100K: OnceLock<Arc<dyn Tokenizer>> = OnceLock::new();
+    static ANTHROPIC: OnceLock<Arc<dyn Tokenizer>> = OnceLock::new();
+
+    let model = model.to_ascii_lowercase();
+    if let Some(tokenizer) = registered_tokenizer(&model) {
+        return tokenizer;
+    }
+    // Gateways often prefix the vendor, e.g. `openai/gpt-4`.
+    let model = model.rsplit('/').next().unwrap_or(&model);
+    if model.contains("claude") {
+        ANTHROPIC
+            .get_or_init(|| Arc::new(AnthropicApproxTokenizer::new()))
+            .clone()
+    } else if uses_cl100k(model) {
+        CL100K
+            .get_or_init(|| Arc::new(BpeTokenizer::cl100k()))
+            .clone()
+    } else {
+        default_tokenizer()
+    }
+}
+
+pub fn count_tokens(model: &str, text: &str) -> usize {
+    tokenizer_for_model(model).count_tokens(text)
+}
+
+fn registered_tokenizer(model: &str) -> Option<Arc<dyn Tokenizer>> {
+    REGISTERED_TOKENIZERS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, tokenizer)| Arc::clone(tokenizer))
+}
+
+fn uses_cl100k(model: &str) -> bool {
+    model.starts_with("gpt-3.5")
+        || model.starts_with("text-embedding")
+        || (model.starts_with("gpt-4")
+            && !model.starts_with("gpt-4o")
+            && !model.starts_with("gpt-4."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CharTokenizer;
+
+    impl Tokenizer for CharTokenizer {
+        fn name(&self) -> &str {
+            "chars"
+        }
+
+        fn count_tokens(&self, text: &str) -> usize {
+            text.chars().count()
+        }
+    }
+
+    #[test]
+    fn models_map_to_their_vocabulary() {
+        assert_eq!(tokenizer_for_model("gpt-4o-mini").name(), "o200k_base");
+        assert_eq!(tokenizer_for_model("gpt-4.1").name(), "o200k_base");
+        assert_eq!(
+            tokenizer_for_model("openai/gpt-4-turbo").name(),
+            "cl100k_base"
+        );
+        assert_eq!(
+            tokenizer_for_model("claude-sonnet-4-5").name(),
+            "anthropic_approx"
+        );
+        assert_eq!(tokenizer_for_model("deepseek-chat").name(), "o200k_base");
+
+        register_tokenizer("Test-Local-", Arc::new(CharTokenizer));
+        assert_eq!(tokenizer_for_model("test-local-llama").name(), "chars");
+        assert_eq!(count_tokens("test-local-llama", "数据"), 2);
+    }
+
+    #[test]
+    fn cjk_text_is_counted_by_vocabulary() {
+        let text = "上下文管理需要准确的令牌计数";
+        assert_eq!(BpeTokenizer::o200k().count_tokens(text), 10);
+        assert_eq!(BpeTokenizer::cl100k().count_tokens(text), 15);
+        assert_eq!(
+            AnthropicApproxTokenizer::new().count_tokens("hello world"),
+            3
+        );
+    }
+
+    #[test]
+    fn truncation_keeps_whole_tokens_and_chars() {
+        let tokenizer = BpeTokenizer::o200k();
+        assert_eq!(
+            tokenizer.truncate_to_tokens("数据数据数据数据", 2),
+            "数据数据"
+        );
+        assert_eq!(
+            tokenizer.truncate_to_tokens("hello world", 5),
+            "hello world"
+        );
+        assert_eq!(tokenizer.truncate_to_tokens("hello world", 0), "");
+
+        assert_eq!(CharTokenizer.truncate_to_tokens("数据数据", 3), "数据数");
+    }
+}
@@ -2,4 +2,4 @@
 pub mod tauri;
 
 #[cfg(feature = "tauri-adapter")]
-pub use tauri::TauriTransportAdapter;
+pub use tauri::{emit_journaled, TauriTransportAdapter};
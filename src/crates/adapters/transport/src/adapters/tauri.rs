@@ -3,16 +3,42 @@
 //! This adapter owns only Tauri delivery. Agentic event names and payload
 //! shapes are projected by `bitfun-events`.
 
+#[cfg(feature = "tauri-adapter")]
+use crate::journal::event_journal;
 #[cfg(feature = "tauri-adapter")]
 use crate::traits::TransportAdapter;
 use async_trait::async_trait;
 use bitfun_events::{project_agentic_frontend_event, AgenticEvent};
 use log::warn;
+use serde_json::Value;
 use std::fmt;
 
 #[cfg(feature = "tauri-adapter")]
 use tauri::{AppHandle, Emitter};
 
+/// Streamed deltas are rebuilt from persisted session history on reload, so
+/// replaying them would only duplicate text.
+const UNJOURNALED_EVENTS: &[&str] = &["agentic://text-chunk"];
+
+/// Scope of a projected agentic event: the session it belongs to.
+fn agentic_event_scope(payload: &Value) -> Option<&str> {
+    payload.get("sessionId").and_then(Value::as_str)
+}
+
+/// Journals `payload` under `scope` for replay and emits it with its
+/// sequence number.
+#[cfg(feature = "tauri-adapter")]
+pub fn emit_journaled(
+    app_handle: &AppHandle,
+    event_name: &str,
+    scope: Option<&str>,
+    mut payload: Value,
+) -> anyhow::Result<()> {
+    event_journal().record(scope, event_name, &mut payload);
+    app_handle.emit(event_name, payload)?;
+    Ok(())
+}
+
 #[cfg(feature = "tauri-adapter")]
 pub struct TauriTransportAdapter {
     app_handle: AppHandle,
@@ -42,9 +68,18 @@ impl TransportAdapter for TauriTransportAdapter {
             warn!("Unhandled AgenticEvent type in TauriAdapter");
             return Ok(());
         };
-        self.app_handle
-            .emit(projected.event_name.as_str(), projected.payload)?;
-        Ok(())
+        let event_name = projected.event_name.as_str();
+        if UNJOURNALED_EVENTS.contains(&event_name) {
+            self.app_handle.emit(event_name, projected.payload)?;
+            return Ok(());
+        }
+        let scope = agentic_event_scope(&projected.payload).map(str::to_string);
+        emit_journaled(
+            &self.app_handle,
+            event_name,
+            scope.as_deref(),
+            projected.payload,
+        )
     }
 
     async fn emit_generic(
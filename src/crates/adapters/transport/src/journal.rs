@@ -0,0 +1,236 @@
+//! Replay journal for emitted frontend events.
+//!
+//! A reloaded webview misses every event emitted while it was gone. Emitted
+//! events get a process-wide sequence number and the recent ones are kept per
+//! scope (session, operation, job), so the frontend can ask for everything
+//! after the last sequence it saw and rebuild progress and task state.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Payload field carrying the sequence number of a journaled event.
+pub const EVENT_SEQ_FIELD: &str = "eventSeq";
+
+const MAX_EVENTS_PER_SCOPE: usize = 256;
+const MAX_SCOPES: usize = 128;
+/// Bucket for events that do not belong to a session or operation.
+const UNSCOPED: &str = "";
+
+static EVENT_JOURNAL: LazyLock<EventJournal> = LazyLock::new(EventJournal::default);
+
+pub fn event_journal() -> &'static EventJournal {
+    &EVENT_JOURNAL
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournaledEvent {
+    pub seq: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    pub event_name: String,
+    pub payload: Value,
+    pub emitted_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventReplay {
+    /// Matching events after `since`, oldest first.
+    pub events: Vec<JournaledEvent>,
+    /// Sequence of the newest journaled event, matched or not.
+    pub latest_seq: u64,
+    /// False when matching events after `since` were already evicted; the
+    /// caller should reload full state instead of relying on the replay.
+    pub complete: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReplayFilter<'a> {
+    pub scope: Option<&'a str>,
+    /// Only these event names; all events when empty.
+    pub event_names: &'a [String],
+}
+
+impl ReplayFilter<'_> {
+    fn matches_name(&self, event_name: &str) -> bool {
+        self.event_names.is_empty() || self.event_names.iter().any(|name| name == event_name)
+    }
+}
+
+#[derive(Default)]
+struct ScopeLog {
+    events: VecDeque<JournaledEvent>,
+    /// Highest sequence dropped from this scope.
+    evicted_through: u64,
+}
+
+#[derive(Default)]
+struct JournalState {
+    last_seq: u64,
+    scopes: HashMap<String, ScopeLog>,
+    /// Highest sequence held by a scope that was dropped as a whole.
+    evicted_scopes_through: u64,
+}
+
+#[derive(Default)]
+pub struct EventJournal {
+    state: Mutex<JournalState>,
+}
+
+impl EventJournal {
+    /// Assigns the next sequence number, stamps it into an object `payload`
+    /// as [`EVENT_SEQ_FIELD`] and keeps a copy for replay.
+    pub fn record(&self, scope: Option<&str>, event_name: &str, payload: &mut Value) -> u64 {
+        let mut state = self.lock();
+        state.last_seq += 1;
+        let seq = state.last_seq;
+        if let Value::Object(fields) = payload {
+            fields.insert(EVENT_SEQ_FIELD.to_string(), Value::from(seq));
+        }
+
+        let key = scope.unwrap_or(UNSCOPED);
+        if !state.scopes.contains_key(key) && state.scopes.len() >= MAX_SCOPES {
+            Self::evict_oldest_scope(&mut state);
+        }
+        let log = state.scopes.entry(key.to_string()).or_default();
+        if log.events.len() >= MAX_EVENTS_PER_SCOPE {
+            if let Some(dropped) = log.events.pop_front() {
+                log.evicted_through = dropped.seq;
+            }
+        }
+        log.events.push_back(JournaledEvent {
+            seq,
+            scope: scope.map(str::to_string),
+            event_name: event_name.to_string(),
+            payload: payload.clone(),
+            emitted_at_ms: now_ms(),
+        });
+        seq
+    }
+
+    /// Journaled events with a sequence greater than `since`.
+    pub fn replay(&self, since: u64, filter: &ReplayFilter<'_>) -> EventReplay {
+        let state = self.lock();
+        let logs: Vec<&ScopeLog> = match filter.scope {
+            Some(scope) => state.scopes.get(scope).into_iter().collect(),
+            None => state.scopes.values().collect(),
+        };
+
+        // A dropped scope may have held events for an unscoped or missing
+        // scope request.
+        let may_include_dropped_scope = match filter.scope {
+            Some(scope) => !state.scopes.contains_key(scope),
+            None => true,
+        };
+        let complete = logs.iter().all(|log| log.evicted_through <= since)
+            && (!may_include_dropped_scope || state.evicted_scopes_through <= since);
+
+        let mut events: Vec<JournaledEvent> = logs
+            .into_iter()
+            .flat_map(|log| log.events.iter())
+            .filter(|event| event.seq > since && filter.matches_name(&event.event_name))
+            .cloned()
+            .collect();
+        events.sort_by_key(|event| event.seq);
+
+        EventReplay {
+            events,
+            latest_seq: state.last_seq,
+            complete,
+        }
+    }
+
+    pub fn latest_seq(&self) -> u64 {
+        self.lock().last_seq
+    }
+
+    /// Drops the scope whose newest event is the oldest.
+    fn evict_oldest_scope(state: &mut JournalState) {
+        let oldest = state
+            .scopes
+            .iter()
+            .min_by_key(|(_, log)| log.events.back().map_or(0, |event| event.seq))
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            if let Some(log) = state.scopes.remove(&key) {
+                let newest = log.events.back().map_or(0, |event| event.seq);
+                state.evicted_scopes_through = state.evicted_scopes_through.max(newest);
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, JournalState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn replay_returns_events_after_since_with_stamped_sequence() {
+        let journal = EventJournal::default();
+        let mut first = json!({ "percent": 10 });
+        let mut second = json!({ "percent": 50 });
+        journal.record(Some("op-1"), "progress", &mut first);
+        let seq = journal.record(Some("op-1"), "progress", &mut second);
+        journal.record(None, "other", &mut json!({}));
+
+        assert_eq!(second[EVENT_SEQ_FIELD], json!(seq));
+
+        let replay = journal.replay(
+            1,
+            &ReplayFilter {
+                scope: Some("op-1"),
+                event_names: &[],
+            },
+        );
+        assert!(replay.complete);
+        assert_eq!(replay.latest_seq, 3);
+        assert_eq!(replay.events.len(), 1);
+        assert_eq!(replay.events[0].payload["percent"], json!(50));
+
+        let names = ["other".to_string()];
+        let replay = journal.replay(
+            0,
+            &ReplayFilter {
+                scope: None,
+                event_names: &names,
+            },
+        );
+        assert_eq!(replay.events.len(), 1);
+        assert_eq!(replay.events[0].scope, None);
+    }
+
+    #[test]
+    fn evicted_events_mark_replay_incomplete() {
+        let journal = EventJournal::default();
+        for _ in 0..MAX_EVENTS_PER_SCOPE + 1 {
+            journal.record(Some("session"), "tick", &mut json!({}));
+        }
+        let filter = ReplayFilter {
+            scope: Some("session"),
+            event_names: &[],
+        };
+
+        let replay = journal.replay(0, &filter);
+        assert!(!replay.complete);
+        assert_eq!(replay.events.len(), MAX_EVENTS_PER_SCOPE);
+        assert!(journal.replay(1, &filter).complete);
+    }
+}
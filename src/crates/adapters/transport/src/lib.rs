@@ -1,12 +1,14 @@
 pub mod adapters;
 pub mod emitter;
+pub mod journal;
 /// BitFun Transport Layer
 ///
 /// Event delivery abstraction used by current product hosts.
 pub mod traits;
 
 pub use emitter::TransportEmitter;
+pub use journal::{event_journal, EventJournal, EventReplay, JournaledEvent, ReplayFilter};
 pub use traits::TransportAdapter;
 
 #[cfg(feature = "tauri-adapter")]
-pub use adapters::TauriTransportAdapter;
+pub use adapters::{emit_journaled, TauriTransportAdapter};
@@ -126,6 +126,36 @@ error-server = Server error
 error-unauthorized = Unauthorized
 error-forbidden = Access forbidden
 
+# ==================== Skills ====================
+skill-not-found = Skill not found: { $name }
+skill-install-failed = Failed to install skill { $name }: { $reason }
+skill-invalid-manifest = Invalid SKILL.md in { $path }: { $reason }
+skill-already-installed = Skill is already installed: { $name }
+
+# ==================== MCP ====================
+mcp-server-not-found = MCP server not found: { $server }
+mcp-server-start-failed = Failed to start MCP server { $server }: { $reason }
+mcp-tool-call-failed = MCP tool { $tool } on { $server } failed: { $reason }
+
+# ==================== Runtime ====================
+runtime-install-failed = Failed to install runtime { $component }: { $reason }
+
+# ==================== Storage ====================
+cleanup-job-summary = Removed { $files } files, freed { $size }
+
+# ==================== Diagnostics ====================
+diagnostics-bundle-summary = Diagnostics bundle ({ $size }) with { $sessions } log sessions, exported { $exportedAt }
+
+# ==================== Screen Capture ====================
+screen-capture-confirm-title = Allow screen capture?
+screen-capture-confirm-window = BitFun wants to capture the window "{ $target }" and attach the image to the current session.
+screen-capture-confirm-region = BitFun wants to capture { $target } and attach the image to the current session.
+screen-capture-target-display = display { $index }
+screen-capture-target-region = a { $width }×{ $height } area of display { $index }
+screen-capture-allow = Capture
+screen-capture-deny = Cancel
+screen-capture-permission-missing = Screen Recording permission is required. Grant it in System Settings, then try again.
+
 # ==================== Time ====================
 time-just-now = just now
 time-seconds-ago = { $count } { $count ->
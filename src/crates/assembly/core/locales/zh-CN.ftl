@@ -126,6 +126,36 @@ error-server = 服务器错误
 error-unauthorized = 未授权
 error-forbidden = 禁止访问
 
+# ==================== 技能 ====================
+skill-not-found = 未找到技能：{ $name }
+skill-install-failed = 安装技能 { $name } 失败：{ $reason }
+skill-invalid-manifest = { $path } 中的 SKILL.md 无效：{ $reason }
+skill-already-installed = 技能已安装：{ $name }
+
+# ==================== MCP ====================
+mcp-server-not-found = 未找到 MCP 服务器：{ $server }
+mcp-server-start-failed = 启动 MCP 服务器 { $server } 失败：{ $reason }
+mcp-tool-call-failed = MCP 服务器 { $server } 的工具 { $tool } 调用失败：{ $reason }
+
+# ==================== 运行时 ====================
+runtime-install-failed = 安装运行时 { $component } 失败：{ $reason }
+
+# ==================== 存储 ====================
+cleanup-job-summary = 已删除 { $files } 个文件，释放 { $size }
+
+# ==================== 诊断 ====================
+diagnostics-bundle-summary = 诊断包（{ $size }），包含 { $sessions } 个日志会话，导出于 { $exportedAt }
+
+# ==================== 屏幕截图 ====================
+screen-capture-confirm-title = 允许截取屏幕？
+screen-capture-confirm-window = BitFun 将截取窗口“{ $target }”，并把图片附加到当前会话。
+screen-capture-confirm-region = BitFun 将截取{ $target }，并把图片附加到当前会话。
+screen-capture-target-display = 显示器 { $index }
+screen-capture-target-region = 显示器 { $index } 上 { $width }×{ $height } 的区域
+screen-capture-allow = 截取
+screen-capture-deny = 取消
+screen-capture-permission-missing = 需要“屏幕录制”权限。请在系统设置中授予后重试。
+
 # ==================== 时间 ====================
 time-just-now = 刚刚
 time-seconds-ago = { $count } 秒前
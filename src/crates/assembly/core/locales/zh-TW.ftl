@@ -126,6 +126,36 @@ error-server = 伺服器錯誤
 error-unauthorized = 未授權
 error-forbidden = 禁止訪問
 
+# ==================== 技能 ====================
+skill-not-found = 未找到技能：{ $name }
+skill-install-failed = 安裝技能 { $name } 失敗：{ $reason }
+skill-invalid-manifest = { $path } 中的 SKILL.md 無效：{ $reason }
+skill-already-installed = 技能已安裝：{ $name }
+
+# ==================== MCP ====================
+mcp-server-not-found = 未找到 MCP 伺服器：{ $server }
+mcp-server-start-failed = 啟動 MCP 伺服器 { $server } 失敗：{ $reason }
+mcp-tool-call-failed = MCP 伺服器 { $server } 的工具 { $tool } 呼叫失敗：{ $reason }
+
+# ==================== 執行時 ====================
+runtime-install-failed = 安裝執行時 { $component } 失敗：{ $reason }
+
+# ==================== 儲存 ====================
+cleanup-job-summary = 已刪除 { $files } 個檔案，釋放 { $size }
+
+# ==================== 診斷 ====================
+diagnostics-bundle-summary = 診斷包（{ $size }），包含 { $sessions } 個日誌工作階段，匯出於 { $exportedAt }
+
+# ==================== 螢幕擷取 ====================
+screen-capture-confirm-title = 允許擷取螢幕？
+screen-capture-confirm-window = BitFun 將擷取視窗「{ $target }」，並將圖片附加到目前的工作階段。
+screen-capture-confirm-region = BitFun 將擷取{ $target }，並將圖片附加到目前的工作階段。
+screen-capture-target-display = 顯示器 { $index }
+screen-capture-target-region = 顯示器 { $index } 上 { $width }×{ $height } 的區域
+screen-capture-allow = 擷取
+screen-capture-deny = 取消
+screen-capture-permission-missing = 需要「螢幕錄製」權限。請在系統設定中授予後重試。
+
 # ==================== 時間 ====================
 time-just-now = 剛剛
 time-seconds-ago = { $count } 秒前
@@ -50,9 +50,10 @@ pub use registry::visibility::{
     BuiltinSubagentExposure, SubagentVisibilityPolicy, SubagentVisibilitySummary,
 };
 pub use registry::{
-    get_agent_registry, AgentRegistry, CustomAgentDetail, CustomSubagentDetail,
-    ExternalSubagentGenerationLease, ExternalSubagentInvocationBinding,
-    ExternalSubagentModelBinding, ExternalSubagentRegistration, ExternalSubagentRoute,
+    get_agent_registry, AgentRegistry, CustomAgentDetail, CustomAgentLoadIssue,
+    CustomAgentLoadIssueSeverity, CustomSubagentDetail, ExternalSubagentGenerationLease,
+    ExternalSubagentInvocationBinding, ExternalSubagentModelBinding, ExternalSubagentRegistration,
+    ExternalSubagentRoute,
 };
 use std::any::Any;
 
@@ -106,16 +107,22 @@ pub fn shared_coding_mode_tools() -> Vec<String> {
         "ListModels".to_string(),
         "AgentWait".to_string(),
         "Read".to_string(),
+        "ReadMore".to_string(),
+        "SearchTools".to_string(),
         "view_image".to_string(),
         "analyze_image".to_string(),
         "Write".to_string(),
         "Edit".to_string(),
+        "EditTransaction".to_string(),
         "Delete".to_string(),
         "ExecCommand".to_string(),
         "WriteStdin".to_string(),
         "ExecControl".to_string(),
         "Grep".to_string(),
         "Glob".to_string(),
+        "FindSymbol".to_string(),
+        "FileOutline".to_string(),
+        "LSP".to_string(),
         "WebSearch".to_string(),
         "WebFetch".to_string(),
         "TodoWrite".to_string(),
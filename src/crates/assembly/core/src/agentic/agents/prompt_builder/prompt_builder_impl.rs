@@ -1,4 +1,5 @@
 //! System prompts module providing main dialogue and agent dialogue prompts
+use crate::agentic::context_providers::{get_global_context_composer, ContextProviderRequest};
 use crate::agentic::memories::build_memory_read_path_reminder;
 use crate::agentic::memories::workspace::memory_root_dir;
 use crate::agentic::tools::implementations::ExecCommandTool;
@@ -8,6 +9,7 @@ use crate::agentic::WorkspaceBinding;
 use crate::infrastructure::try_get_path_manager_arc;
 use crate::service::bootstrap::build_workspace_persona_prompt;
 use crate::service::config::global::GlobalConfigManager;
+use crate::service::config::types::ContextProviderSettings;
 use crate::service::config::{get_app_language_code, get_global_config_service};
 use crate::service::filesystem::get_formatted_directory_listing;
 use crate::service::i18n::LocaleId;
@@ -24,6 +26,7 @@ use bitfun_agent_runtime::prompt::{
 };
 use bitfun_agent_runtime::remote_file_delivery::user_workspace_relative_file_link;
 use log::{debug, info, warn};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Placeholder constants
@@ -386,6 +389,48 @@ impl PromptBuilder {
         render_user_context_reminder(additional_sections)
     }
 
+    /// Runs the enabled context providers for this turn.
+    ///
+    /// Kept out of the system prompt and the cached user context: provider
+    /// output changes between turns and would otherwise invalidate the
+    /// provider-side prompt cache. `query` is the user's message, for
+    /// providers that retrieve by relevance.
+    pub async fn build_provided_context_reminder(&self, query: Option<&str>) -> Option<String> {
+        let settings = match GlobalConfigManager::get_service().await {
+            Ok(service) => service
+                .get_config::<HashMap<String, ContextProviderSettings>>(Some(
+                    "ai.context_providers",
+                ))
+                .await
+                .unwrap_or_default(),
+            Err(e) => {
+                debug!("Failed to read context provider config: {}", e);
+                HashMap::new()
+            }
+        };
+        let request = ContextProviderRequest {
+            session_id: self.context.session_id.clone(),
+            workspace_path: self.context.workspace_path.clone(),
+            remote: self.context.remote_execution.is_some(),
+            query: query.map(str::to_string),
+        };
+
+        let composed = get_global_context_composer()
+            .compose(&request, &settings)
+            .await;
+        for report in composed.truncated_reports() {
+            info!(
+                "Context provider output truncated: session_id={:?}, provider={}, original_tokens={}, budget={}",
+                request.session_id, report.provider_id, report.original_tokens, report.token_budget
+            );
+        }
+        debug!(
+            "Context providers composed: session_id={:?}, reports={:?}",
+            request.session_id, composed.reports
+        );
+        composed.text
+    }
+
     pub async fn build_prepended_reminders(
         &self,
         user_context_policy: &UserContextPolicy,
@@ -396,6 +441,7 @@ impl PromptBuilder {
             agent_listing: self.build_agent_listing_reminder(),
             runtime_context: self.build_runtime_context_reminder().await,
             user_context: self.build_user_context_reminder(user_context_policy).await,
+            provided_context: self.build_provided_context_reminder(None).await,
         }
     }
 
@@ -63,6 +63,7 @@ impl AgentRegistry {
             agents: std::sync::RwLock::new(Self::build_builtin_agents()),
             project_subagents: std::sync::RwLock::new(HashMap::new()),
             user_custom_agents_loaded: std::sync::RwLock::new(false),
+            custom_agent_load_issues: std::sync::RwLock::new(HashMap::new()),
             external_subagents: std::sync::Arc::new(
                 super::external::ExternalSubagentRegistryState::new(),
             ),
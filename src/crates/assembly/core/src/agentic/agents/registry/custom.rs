@@ -6,7 +6,7 @@ use super::support::{
 use super::types::{
     agent_source_from_custom_level, subagent_key_for, AgentEntry, AgentSource, CustomAgentConfig,
 };
-use super::{AgentRegistry, CustomAgentDetail};
+use super::{AgentRegistry, CustomAgentDetail, CustomAgentLoadIssue, CustomAgentLoadIssueSeverity};
 use crate::agentic::agents::definitions::custom::{CustomAgentData, CustomMode, CustomSubagent};
 use crate::agentic::agents::registry::visibility::SubagentVisibilityPolicy;
 use crate::agentic::agents::{
@@ -26,7 +26,7 @@ use bitfun_agent_runtime::custom_agent::{
 };
 use log::{debug, error, warn};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 impl AgentRegistry {
@@ -66,6 +66,7 @@ impl AgentRegistry {
         let valid_models = Self::get_valid_model_ids().await;
 
         let custom = load_custom_agent_definitions(roots);
+        let mut issues = Vec::new();
         for load_error in custom.errors {
             if load_error.error == "Project-scoped custom modes are not supported" {
                 warn!(
@@ -74,13 +75,18 @@ impl AgentRegistry {
                     load_error.error
                 );
             } else {
-                let error = BitFunError::Agent(load_error.error);
                 error!(
                     "Failed to load custom agent from {}: {}",
                     load_error.path.display(),
-                    error
+                    BitFunError::Agent(load_error.error.clone())
                 );
             }
+            issues.push(CustomAgentLoadIssue {
+                path: load_error.path.to_string_lossy().to_string(),
+                agent_id: None,
+                severity: CustomAgentLoadIssueSeverity::Error,
+                message: load_error.error,
+            });
         }
 
         let mut user_entries = HashMap::new();
@@ -88,13 +94,15 @@ impl AgentRegistry {
 
         for loaded in custom.definitions {
             let mut definition = loaded.definition;
-            Self::validate_custom_agent(
+            let report = Self::validate_custom_agent(
                 &mut definition,
                 &loaded.metadata,
                 &valid_tools,
                 &readonly_tools,
                 &valid_models,
             );
+            let path = loaded.path.to_string_lossy().to_string();
+            issues.extend(validation_report_issues(&path, &definition.id, &report));
 
             let id = definition.id.clone();
             let source = agent_source_from_custom_level(definition.level);
@@ -111,10 +119,7 @@ impl AgentRegistry {
                 },
                 source,
                 subagent_source,
-                agent: custom_agent_from_definition(
-                    loaded.path.to_string_lossy().to_string(),
-                    definition,
-                ),
+                agent: custom_agent_from_definition(path, definition),
                 visibility_policy: SubagentVisibilityPolicy::public(),
                 custom_config: Some(custom_config),
             };
@@ -139,6 +144,7 @@ impl AgentRegistry {
             for (id, entry) in user_entries {
                 if map.contains_key(&id) {
                     warn!("Custom agent {} conflicts with existing entry, skip", id);
+                    issues.push(conflict_issue(&id, &entry));
                     continue;
                 }
                 map.insert(id, entry);
@@ -149,12 +155,13 @@ impl AgentRegistry {
             let map = self.read_agents();
             let filtered_project_entries = project_entries
                 .into_iter()
-                .filter(|(id, _)| {
+                .filter(|(id, entry)| {
                     if map.contains_key(id) {
                         warn!(
                             "Custom project agent {} conflicts with global entry, skip",
                             id
                         );
+                        issues.push(conflict_issue(id, entry));
                         return false;
                     }
                     true
@@ -165,9 +172,43 @@ impl AgentRegistry {
                 .insert(root.to_path_buf(), filtered_project_entries);
         }
 
+        self.set_custom_agent_load_issues(workspace_root, issues);
         self.set_user_custom_agents_loaded(true);
     }
 
+    /// Issues from the last load for `workspace_root` (or the last user-only
+    /// load when `None`).
+    pub fn custom_agent_load_issues(
+        &self,
+        workspace_root: Option<&Path>,
+    ) -> Vec<CustomAgentLoadIssue> {
+        let key = workspace_root.map(Path::to_path_buf);
+        match self.custom_agent_load_issues.read() {
+            Ok(guard) => guard.get(&key).cloned().unwrap_or_default(),
+            Err(poisoned) => {
+                warn!("Custom agent load issues read lock poisoned, recovering");
+                poisoned.into_inner().get(&key).cloned().unwrap_or_default()
+            }
+        }
+    }
+
+    fn set_custom_agent_load_issues(
+        &self,
+        workspace_root: Option<&Path>,
+        issues: Vec<CustomAgentLoadIssue>,
+    ) {
+        let key: Option<PathBuf> = workspace_root.map(Path::to_path_buf);
+        match self.custom_agent_load_issues.write() {
+            Ok(mut guard) => {
+                guard.insert(key, issues);
+            }
+            Err(poisoned) => {
+                warn!("Custom agent load issues write lock poisoned, recovering");
+                poisoned.into_inner().insert(key, issues);
+            }
+        }
+    }
+
     /// Compatibility wrapper for existing project-subagent callers.
     pub async fn load_custom_subagents(&self, workspace_root: &Path) {
         self.load_custom_agents(Some(workspace_root)).await;
@@ -199,7 +240,7 @@ impl AgentRegistry {
         valid_tools: &[String],
         readonly_tools: &[String],
         valid_models: &[String],
-    ) {
+    ) -> CustomAgentValidationReport {
         let agent_id = definition.id.clone();
         let report = validate_custom_agent_definition(
             definition,
@@ -212,6 +253,7 @@ impl AgentRegistry {
         );
 
         Self::log_custom_agent_validation_report(&agent_id, &report);
+        report
     }
 
     fn log_custom_agent_validation_report(agent_id: &str, report: &CustomAgentValidationReport) {
@@ -876,6 +918,50 @@ fn custom_agent_discovery_roots(workspace_root: Option<&Path>) -> CustomAgentDis
     }
 }
 
+fn validation_report_issues(
+    path: &str,
+    agent_id: &str,
+    report: &CustomAgentValidationReport,
+) -> Vec<CustomAgentLoadIssue> {
+    let mut messages = Vec::new();
+    if !report.invalid_tools.is_empty() {
+        messages.push(format!(
+            "Unknown tools ignored: {}",
+            report.invalid_tools.join(", ")
+        ));
+    }
+    if !report.writable_review_tools.is_empty() {
+        messages.push(format!(
+            "Writable tools removed from review subagent: {}",
+            report.writable_review_tools.join(", ")
+        ));
+    }
+    if let Some(model_fallback) = &report.model_fallback {
+        messages.push(format!(
+            "Unknown model '{}' replaced with '{}'",
+            model_fallback.original, model_fallback.fallback
+        ));
+    }
+    messages
+        .into_iter()
+        .map(|message| CustomAgentLoadIssue {
+            path: path.to_string(),
+            agent_id: Some(agent_id.to_string()),
+            severity: CustomAgentLoadIssueSeverity::Warning,
+            message,
+        })
+        .collect()
+}
+
+fn conflict_issue(agent_id: &str, entry: &AgentEntry) -> CustomAgentLoadIssue {
+    CustomAgentLoadIssue {
+        path: custom_agent_path(entry.agent.as_ref()).unwrap_or_default(),
+        agent_id: Some(agent_id.to_string()),
+        severity: CustomAgentLoadIssueSeverity::Error,
+        message: format!("Id '{}' is already used by another agent", agent_id),
+    }
+}
+
 fn custom_agent_from_definition(path: String, definition: CustomAgentDefinition) -> Arc<dyn Agent> {
     match definition.kind {
         CustomAgentKind::Mode => Arc::new(CustomMode::from_definition(path, definition)),
@@ -49,6 +49,27 @@ pub struct CustomAgentDetail {
 
 pub type CustomSubagentDetail = CustomAgentDetail;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomAgentLoadIssueSeverity {
+    /// The file was skipped.
+    Error,
+    /// The agent loaded, but part of its definition was ignored or replaced.
+    Warning,
+}
+
+/// A problem found while loading `.bitfun/agents/*.md` files, kept so the
+/// agents page can explain why a definition is missing or behaves differently.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomAgentLoadIssue {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_id: Option<String>,
+    pub severity: CustomAgentLoadIssueSeverity,
+    pub message: String,
+}
+
 /// Registry for managing all available agents
 pub struct AgentRegistry {
     /// id -> agent_entry
@@ -56,6 +77,8 @@ pub struct AgentRegistry {
     /// workspace root -> (project subagent id -> agent_entry)
     project_subagents: RwLock<HashMap<PathBuf, HashMap<String, AgentEntry>>>,
     user_custom_agents_loaded: RwLock<bool>,
+    /// workspace root (`None` for user-only loads) -> issues from the last load
+    custom_agent_load_issues: RwLock<HashMap<Option<PathBuf>, Vec<CustomAgentLoadIssue>>>,
     external_subagents: Arc<external::ExternalSubagentRegistryState>,
 }
 
@@ -1,5 +1,8 @@
 use super::support::merge_dynamic_mcp_tools;
-use super::{AgentRegistry, ExternalSubagentRegistration, ExternalSubagentRoute};
+use super::{
+    AgentRegistry, CustomAgentLoadIssueSeverity, ExternalSubagentRegistration,
+    ExternalSubagentRoute,
+};
 use crate::agentic::agents::definitions::custom::{CustomMode, CustomSubagent, CustomSubagentKind};
 use crate::agentic::agents::registry::builtin::default_model_id_for_builtin_agent;
 use crate::agentic::agents::registry::types::{
@@ -800,6 +803,36 @@ async fn project_scoped_custom_mode_is_skipped_while_project_subagent_loads() {
     assert!(subagents.iter().any(|agent| agent.id == "ProjectHelper"));
 }
 
+#[tokio::test]
+async fn custom_agent_load_issues_report_skipped_files_per_workspace() {
+    let env = CustomAgentTestEnv::new("bitfun-custom-agent-load-issues");
+    let registry = AgentRegistry::new();
+    let workspace_root = env.workspace_root.clone();
+    let broken_path = env.workspace_agents_dir.join("broken.md");
+    std::fs::write(&broken_path, "---\nname: Broken\n---\nNo description.\n")
+        .expect("write broken agent");
+    write_project_custom_subagent(
+        &env.workspace_agents_dir.join("project-helper.md"),
+        "ProjectHelper",
+    );
+
+    registry
+        .load_custom_agents_from_test_roots(
+            Some(&workspace_root),
+            &env.discovery_roots(Some(workspace_root.clone())),
+        )
+        .await;
+
+    let issues = registry.custom_agent_load_issues(Some(&workspace_root));
+    let broken = issues
+        .iter()
+        .find(|issue| issue.path == broken_path.to_string_lossy())
+        .expect("broken agent should be reported");
+    assert_eq!(broken.severity, CustomAgentLoadIssueSeverity::Error);
+    assert!(broken.message.contains("description"), "{}", broken.message);
+    assert!(registry.custom_agent_load_issues(None).is_empty());
+}
+
 #[tokio::test]
 async fn custom_mode_detail_reports_kind_level_model_path_and_policy() {
     let env = CustomAgentTestEnv::new("bitfun-custom-mode-registry-detail");
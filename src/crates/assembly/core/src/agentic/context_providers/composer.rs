@@ -0,0 +1,343 @@
+//! Runs context providers and assembles their output within budget.
+
+use super::providers::{
+    GitDiffProvider, McpResourcesProvider, OpenFilesProvider, SemanticSearchProvider,
+    TerminalOutputProvider, WorkspaceTreeProvider,
+};
+use super::types::{
+    ComposedContext, ContextFragment, ContextProvider, ContextProviderOutcome,
+    ContextProviderReport, ContextProviderRequest,
+};
+use crate::service::config::types::ContextProviderSettings;
+use crate::util::token_counter::TokenCounter;
+use bitfun_agent_runtime::prompt::render_provided_context_reminder;
+use futures::future::join_all;
+use log::warn;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+
+/// A slow provider must not hold up the turn.
+const PROVIDER_TIMEOUT: Duration = Duration::from_secs(3);
+
+static GLOBAL_CONTEXT_COMPOSER: OnceLock<Arc<ContextComposer>> = OnceLock::new();
+
+/// Returns the process-wide composer with the built-in providers registered.
+pub fn get_global_context_composer() -> Arc<ContextComposer> {
+    GLOBAL_CONTEXT_COMPOSER
+        .get_or_init(|| Arc::new(ContextComposer::with_builtin_providers()))
+        .clone()
+}
+
+#[derive(Default)]
+pub struct ContextComposer {
+    providers: RwLock<Vec<Arc<dyn ContextProvider>>>,
+}
+
+impl ContextComposer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_builtin_providers() -> Self {
+        let composer = Self::new();
+        composer.register(Arc::new(OpenFilesProvider));
+        composer.register(Arc::new(GitDiffProvider));
+        composer.register(Arc::new(TerminalOutputProvider));
+        composer.register(Arc::new(McpResourcesProvider));
+        composer.register(Arc::new(WorkspaceTreeProvider));
+        composer.register(Arc::new(SemanticSearchProvider));
+        composer
+    }
+
+    /// Adds a provider, replacing any provider registered under the same id.
+    pub fn register(&self, provider: Arc<dyn ContextProvider>) {
+        let mut providers = match self.providers.write() {
+            Ok(providers) => providers,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        providers.retain(|existing| existing.id() != provider.id());
+        providers.push(provider);
+        providers.sort_by(|a, b| a.order().cmp(&b.order()).then_with(|| a.id().cmp(b.id())));
+    }
+
+    /// Removes every provider whose id starts with `prefix`.
+    pub fn unregister_by_prefix(&self, prefix: &str) -> usize {
+        let mut providers = match self.providers.write() {
+            Ok(providers) => providers,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let before = providers.len();
+        providers.retain(|provider| !provider.id().starts_with(prefix));
+        before - providers.len()
+    }
+
+    /// Provider ids in composition order.
+    pub fn provider_ids(&self) -> Vec<String> {
+        self.providers_snapshot()
+            .iter()
+            .map(|provider| provider.id().to_string())
+            .collect()
+    }
+
+    /// Runs the enabled providers concurrently and joins their sections in
+    /// composition order. Failures are reported, never propagated.
+    pub async fn compose(
+        &self,
+        request: &ContextProviderRequest,
+        settings: &HashMap<String, ContextProviderSettings>,
+    ) -> ComposedContext {
+        let providers = self.providers_snapshot();
+        let results = join_all(providers.iter().map(|provider| {
+            let settings = settings.get(provider.id()).copied().unwrap_or_default();
+            run_provider(provider.as_ref(), request, settings)
+        }))
+        .await;
+
+        let mut sections = Vec::new();
+        let mut reports = Vec::with_capacity(results.len());
+        for (section, report) in results {
+            sections.extend(section);
+            reports.push(report);
+        }
+
+        ComposedContext {
+            text: render_provided_context_reminder(sections),
+            reports,
+        }
+    }
+
+    fn providers_snapshot(&self) -> Vec<Arc<dyn ContextProvider>> {
+        match self.providers.read() {
+            Ok(providers) => providers.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+}
+
+async fn run_provider(
+    provider: &dyn ContextProvider,
+    request: &ContextProviderRequest,
+    settings: ContextProviderSettings,
+) -> (Option<String>, ContextProviderReport) {
+    let token_budget = settings
+        .token_budget
+        .unwrap_or_else(|| provider.default_token_budget());
+    let mut report = ContextProviderReport {
+        provider_id: provider.id().to_string(),
+        outcome: ContextProviderOutcome::Disabled,
+        token_budget,
+        original_tokens: 0,
+        included_tokens: 0,
+        error: None,
+    };
+    if !settings
+        .enabled
+        .unwrap_or_else(|| provider.default_enabled())
+        || token_budget == 0
+    {
+        return (None, report);
+    }
+
+    let fragment = match tokio::time::timeout(PROVIDER_TIMEOUT, provider.provide(request)).await {
+        Ok(Ok(Some(fragment))) if !fragment.body.trim().is_empty() => fragment,
+        Ok(Ok(_)) => {
+            report.outcome = ContextProviderOutcome::Empty;
+            return (None, report);
+        }
+        Ok(Err(e)) => {
+            warn!("Context provider failed: id={}, error={}", provider.id(), e);
+            report.outcome = ContextProviderOutcome::Failed;
+            report.error = Some(e.to_string());
+            return (None, report);
+        }
+        Err(_) => {
+            warn!(
+                "Context provider timed out: id={}, timeout_ms={}",
+                provider.id(),
+                PROVIDER_TIMEOUT.as_millis()
+            );
+            report.outcome = ContextProviderOutcome::TimedOut;
+            return (None, report);
+        }
+    };
+
+    let ContextFragment { title, body } = fragment;
+    let body = body.trim();
+    report.original_tokens = TokenCounter::estimate_tokens(body);
+    let (body, truncated) = truncate_to_budget(body, token_budget);
+    let body = if truncated {
+        report.outcome = ContextProviderOutcome::Truncated;
+        format!(
+            "{}\n[... truncated to fit a {}-token budget; about {} tokens omitted]",
+            body,
+            token_budget,
+            report
+                .original_tokens
+                .saturating_sub(TokenCounter::estimate_tokens(body))
+        )
+    } else {
+        report.outcome = ContextProviderOutcome::Included;
+        body.to_string()
+    };
+    report.included_tokens = TokenCounter::estimate_tokens(&body);
+
+    (Some(format!("## {}\n{}", title.trim(), body)), report)
+}
+
+/// Keeps the longest prefix within `token_budget`, preferring to cut at a line
+/// boundary so partial lines do not reach the model.
+fn truncate_to_budget(text: &str, token_budget: usize) -> (&str, bool) {
+    let prefix = TokenCounter::truncate_to_tokens(text, token_budget);
+    let cut = prefix.len();
+    if cut == text.len() {
+        return (text, false);
+    }
+    let prefix = match prefix.rfind('\n') {
+        _ if text[cut..].starts_with('\n') => prefix,
+        Some(newline) if newline >= cut / 2 => &prefix[..newline],
+        _ => prefix,
+    };
+    (prefix.trim_end(), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::errors::{BitFunError, BitFunResult};
+    use async_trait::async_trait;
+
+    struct StaticProvider {
+        id: &'static str,
+        order: u32,
+        result: Result<&'static str, &'static str>,
+    }
+
+    #[async_trait]
+    impl ContextProvider for StaticProvider {
+        fn id(&self) -> &str {
+            self.id
+        }
+
+        fn order(&self) -> u32 {
+            self.order
+        }
+
+        fn default_enabled(&self) -> bool {
+            true
+        }
+
+        fn default_token_budget(&self) -> usize {
+            1_000
+        }
+
+        async fn provide(
+            &self,
+            _request: &ContextProviderRequest,
+        ) -> BitFunResult<Option<ContextFragment>> {
+            match self.result {
+                Ok(body) => Ok(Some(ContextFragment {
+                    title: self.id.to_string(),
+                    body: body.to_string(),
+                })),
+                Err(message) => Err(BitFunError::service(message)),
+            }
+        }
+    }
+
+    fn request() -> ContextProviderRequest {
+        ContextProviderRequest {
+            session_id: None,
+            workspace_path: "/workspace".to_string(),
+            remote: false,
+            query: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn compose_orders_sections_by_order_then_id() {
+        let composer = ContextComposer::new();
+        for (id, order) in [("zeta", 10), ("beta", 20), ("alpha", 20)] {
+            composer.register(Arc::new(StaticProvider {
+                id,
+                order,
+                result: Ok("body"),
+            }));
+        }
+
+        let composed = composer.compose(&request(), &HashMap::new()).await;
+        let text = composed.text.expect("sections should render");
+
+        let zeta = text.find("## zeta").expect("zeta section");
+        let alpha = text.find("## alpha").expect("alpha section");
+        let beta = text.find("## beta").expect("beta section");
+        assert!(zeta < alpha && alpha < beta);
+        assert_eq!(composer.provider_ids(), vec!["zeta", "alpha", "beta"]);
+    }
+
+    #[tokio::test]
+    async fn compose_applies_settings_and_reports_outcomes() {
+        let composer = ContextComposer::new();
+        composer.register(Arc::new(StaticProvider {
+            id: "long",
+            order: 1,
+            result: Ok("line one\nline two\nline three\nline four"),
+        }));
+        composer.register(Arc::new(StaticProvider {
+            id: "off",
+            order: 2,
+            result: Ok("hidden"),
+        }));
+        composer.register(Arc::new(StaticProvider {
+            id: "broken",
+            order: 3,
+            result: Err("boom"),
+        }));
+        let settings = HashMap::from([
+            (
+                "long".to_string(),
+                ContextProviderSettings {
+                    enabled: None,
+                    token_budget: Some(5),
+                },
+            ),
+            (
+                "off".to_string(),
+                ContextProviderSettings {
+                    enabled: Some(false),
+                    token_budget: None,
+                },
+            ),
+        ]);
+
+        let composed = composer.compose(&request(), &settings).await;
+        let text = composed.text.clone().expect("truncated section renders");
+
+        assert!(text.contains("line one\nline two\n[... truncated"));
+        assert!(!text.contains("line four"));
+        assert!(!text.contains("hidden"));
+        let outcomes = composed
+            .reports
+            .iter()
+            .map(|report| (report.provider_id.as_str(), report.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("long", ContextProviderOutcome::Truncated),
+                ("off", ContextProviderOutcome::Disabled),
+                ("broken", ContextProviderOutcome::Failed),
+            ]
+        );
+        assert_eq!(composed.truncated_reports().count(), 1);
+    }
+
+    #[test]
+    fn truncate_to_budget_respects_char_boundaries() {
+        let input = "数据数据数据数据";
+        let (text, truncated) = truncate_to_budget(input, 2);
+        assert!(truncated);
+        assert!(!text.is_empty() && text.len() < input.len());
+        assert!(input.starts_with(text));
+    }
+}
@@ -0,0 +1,23 @@
+//! Pluggable context providers for agent prompts.
+//!
+//! Providers contribute short sections about the user's environment (open
+//! editors, uncommitted changes, terminal output, code relevant to the
+//! request, ...). The composer runs the enabled ones, trims each section to
+//! its token budget and joins them in a fixed order, so the same inputs always
+//! produce the same prompt text.
+
+mod composer;
+mod open_files;
+mod providers;
+mod types;
+
+pub use composer::{get_global_context_composer, ContextComposer};
+pub use open_files::{open_editor_files, set_open_editor_files, OpenEditorFile};
+pub use providers::{
+    GitDiffProvider, McpResourcesProvider, OpenFilesProvider, SemanticSearchProvider,
+    TerminalOutputProvider, WorkspaceTreeProvider,
+};
+pub use types::{
+    ComposedContext, ContextFragment, ContextProvider, ContextProviderOutcome,
+    ContextProviderReport, ContextProviderRequest,
+};
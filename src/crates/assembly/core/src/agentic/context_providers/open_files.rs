@@ -0,0 +1,52 @@
+//! Open editor files reported by the frontend.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenEditorFile {
+    pub path: String,
+    /// The tab currently focused in the editor.
+    #[serde(default)]
+    pub active: bool,
+    /// Has unsaved edits, so the file on disk may differ from what the user sees.
+    #[serde(default)]
+    pub dirty: bool,
+}
+
+static OPEN_EDITOR_FILES: LazyLock<RwLock<HashMap<String, Vec<OpenEditorFile>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn workspace_key(workspace_path: &str) -> String {
+    workspace_path
+        .replace('\\', "/")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Replaces the open file list for a workspace. An empty list clears it.
+pub fn set_open_editor_files(workspace_path: &str, files: Vec<OpenEditorFile>) {
+    let mut registry = match OPEN_EDITOR_FILES.write() {
+        Ok(registry) => registry,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let key = workspace_key(workspace_path);
+    if files.is_empty() {
+        registry.remove(&key);
+    } else {
+        registry.insert(key, files);
+    }
+}
+
+pub fn open_editor_files(workspace_path: &str) -> Vec<OpenEditorFile> {
+    let registry = match OPEN_EDITOR_FILES.read() {
+        Ok(registry) => registry,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    registry
+        .get(&workspace_key(workspace_path))
+        .cloned()
+        .unwrap_or_default()
+}
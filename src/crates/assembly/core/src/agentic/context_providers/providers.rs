@@ -0,0 +1,393 @@
+//! Built-in context providers.
+
+use super::open_files::open_editor_files;
+use super::types::{ContextFragment, ContextProvider, ContextProviderRequest};
+use crate::service::filesystem::get_formatted_directory_listing;
+use crate::service::git::{GitDiffParams, GitService};
+use crate::service::mcp::get_global_mcp_service;
+use crate::service::semantic_search::get_semantic_search_service;
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use std::path::Path;
+use terminal_core::api::{GetHistoryRequest, TerminalApi};
+use terminal_core::session::SessionSource;
+use tool_runtime::util::ansi_cleaner::strip_ansi;
+
+/// Lines kept from the end of each terminal's scrollback.
+const TERMINAL_TAIL_LINES: usize = 40;
+const WORKSPACE_TREE_MAX_ENTRIES: usize = 200;
+const SEMANTIC_SEARCH_HITS: usize = 5;
+
+fn is_git_workspace(workspace_path: &str) -> bool {
+    Path::new(workspace_path).join(".git").exists()
+}
+
+fn display_path<'a>(path: &'a str, workspace_path: &str) -> &'a str {
+    let workspace_path = workspace_path.trim_end_matches(['/', '\\']);
+    path.strip_prefix(workspace_path)
+        .filter(|relative| relative.starts_with(['/', '\\']))
+        .map(|relative| relative.trim_start_matches(['/', '\\']))
+        .filter(|relative| !relative.is_empty())
+        .unwrap_or(path)
+}
+
+/// Files open in the editor, with the focused tab first.
+pub struct OpenFilesProvider;
+
+#[async_trait]
+impl ContextProvider for OpenFilesProvider {
+    fn id(&self) -> &str {
+        "open_files"
+    }
+
+    fn order(&self) -> u32 {
+        10
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    fn default_token_budget(&self) -> usize {
+        400
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        let mut files = open_editor_files(&request.workspace_path);
+        if files.is_empty() {
+            return Ok(None);
+        }
+        files.sort_by_key(|file| !file.active);
+
+        let body = files
+            .iter()
+            .map(|file| {
+                let mut line = format!("- {}", display_path(&file.path, &request.workspace_path));
+                if file.active {
+                    line.push_str(" (active)");
+                }
+                if file.dirty {
+                    line.push_str(" (unsaved changes)");
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Some(ContextFragment {
+            title: "Open Editor Files".to_string(),
+            body,
+        }))
+    }
+}
+
+/// Uncommitted changes in the workspace repository.
+pub struct GitDiffProvider;
+
+#[async_trait]
+impl ContextProvider for GitDiffProvider {
+    fn id(&self) -> &str {
+        "git_diff"
+    }
+
+    fn order(&self) -> u32 {
+        20
+    }
+
+    fn default_token_budget(&self) -> usize {
+        2_000
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        if request.remote || !is_git_workspace(&request.workspace_path) {
+            return Ok(None);
+        }
+
+        let stat = GitService::get_diff(
+            &request.workspace_path,
+            &GitDiffParams {
+                stat: Some(true),
+                review_safe: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| BitFunError::service(format!("Failed to read git diff stat: {}", e)))?;
+        if stat.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let diff = GitService::get_diff(
+            &request.workspace_path,
+            &GitDiffParams {
+                review_safe: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| BitFunError::service(format!("Failed to read git diff: {}", e)))?;
+
+        Ok(Some(ContextFragment {
+            title: "Uncommitted Changes".to_string(),
+            body: format!("{}\n\n{}", stat.trim_end(), diff.trim()),
+        }))
+    }
+}
+
+/// Recent output from terminals the user opened in this workspace.
+pub struct TerminalOutputProvider;
+
+#[async_trait]
+impl ContextProvider for TerminalOutputProvider {
+    fn id(&self) -> &str {
+        "terminal_output"
+    }
+
+    fn order(&self) -> u32 {
+        30
+    }
+
+    fn default_token_budget(&self) -> usize {
+        1_500
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        if request.remote {
+            return Ok(None);
+        }
+        // The terminal subsystem is not running in every host.
+        let Ok(terminal_api) = TerminalApi::from_singleton() else {
+            return Ok(None);
+        };
+
+        let workspace_path = request.workspace_path.replace('\\', "/");
+        let mut sessions = terminal_api
+            .list_sessions()
+            .await
+            .map_err(|e| BitFunError::service(format!("Failed to list terminals: {}", e)))?;
+        sessions.retain(|session| {
+            session.source == SessionSource::Manual
+                && session.cwd.replace('\\', "/").starts_with(&workspace_path)
+        });
+        sessions.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+        let mut sections = Vec::new();
+        for session in sessions {
+            let history = match terminal_api
+                .get_history(GetHistoryRequest {
+                    session_id: session.id.clone(),
+                })
+                .await
+            {
+                Ok(history) => history,
+                Err(_) => continue,
+            };
+            let output = strip_ansi(&history.data);
+            let lines = output
+                .lines()
+                .map(str::trim_end)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>();
+            if lines.is_empty() {
+                continue;
+            }
+            let tail = &lines[lines.len().saturating_sub(TERMINAL_TAIL_LINES)..];
+            sections.push(format!(
+                "### {} ({})\n{}",
+                session.name,
+                display_path(&session.cwd, &request.workspace_path),
+                tail.join("\n")
+            ));
+        }
+
+        if sections.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ContextFragment {
+            title: "Recent Terminal Output".to_string(),
+            body: sections.join("\n\n"),
+        }))
+    }
+}
+
+/// Resources advertised by connected MCP servers.
+pub struct McpResourcesProvider;
+
+#[async_trait]
+impl ContextProvider for McpResourcesProvider {
+    fn id(&self) -> &str {
+        "mcp_resources"
+    }
+
+    fn order(&self) -> u32 {
+        40
+    }
+
+    fn default_token_budget(&self) -> usize {
+        800
+    }
+
+    async fn provide(
+        &self,
+        _request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        let Some(mcp_service) = get_global_mcp_service() else {
+            return Ok(None);
+        };
+        let server_manager = mcp_service.server_manager();
+        let mut server_ids = server_manager.get_all_server_ids().await;
+        server_ids.sort();
+
+        let mut lines = Vec::new();
+        for server_id in server_ids {
+            let mut resources = server_manager.get_cached_resources(&server_id).await;
+            resources.sort_by(|a, b| a.uri.cmp(&b.uri));
+            for resource in resources {
+                let label = resource.title.as_deref().unwrap_or(&resource.name);
+                let mut line = format!("- [{}] {} <{}>", server_id, label, resource.uri);
+                if let Some(description) = resource
+                    .description
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|description| !description.is_empty())
+                {
+                    line.push_str(": ");
+                    line.push_str(description);
+                }
+                lines.push(line);
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ContextFragment {
+            title: "MCP Resources".to_string(),
+            body: lines.join("\n"),
+        }))
+    }
+}
+
+/// A compact listing of the workspace directory tree.
+pub struct WorkspaceTreeProvider;
+
+#[async_trait]
+impl ContextProvider for WorkspaceTreeProvider {
+    fn id(&self) -> &str {
+        "workspace_tree"
+    }
+
+    fn order(&self) -> u32 {
+        50
+    }
+
+    fn default_token_budget(&self) -> usize {
+        1_000
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        if request.remote {
+            return Ok(None);
+        }
+        let workspace_path = request.workspace_path.clone();
+        let listing = tokio::task::spawn_blocking(move || {
+            get_formatted_directory_listing(&workspace_path, WORKSPACE_TREE_MAX_ENTRIES)
+        })
+        .await
+        .map_err(|e| BitFunError::service(format!("Workspace listing task failed: {}", e)))?
+        .map_err(|e| BitFunError::service(format!("Failed to list workspace: {}", e)))?;
+
+        let mut body = listing.text;
+        if listing.reached_limit {
+            body.push_str(&format!(
+                "\n(showing the first {} entries)",
+                WORKSPACE_TREE_MAX_ENTRIES
+            ));
+        }
+        Ok(Some(ContextFragment {
+            title: "Workspace Tree".to_string(),
+            body,
+        }))
+    }
+}
+
+/// Workspace code closest in meaning to the user's message.
+pub struct SemanticSearchProvider;
+
+#[async_trait]
+impl ContextProvider for SemanticSearchProvider {
+    fn id(&self) -> &str {
+        "semantic_search"
+    }
+
+    fn order(&self) -> u32 {
+        60
+    }
+
+    fn default_token_budget(&self) -> usize {
+        2_000
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        let Some(query) = request
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|query| !query.is_empty())
+        else {
+            return Ok(None);
+        };
+        if request.remote {
+            return Ok(None);
+        }
+
+        let service = get_semantic_search_service().await?;
+        let Some(result) = service
+            .semantic_search_indexed(
+                Path::new(&request.workspace_path),
+                query,
+                SEMANTIC_SEARCH_HITS,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        if result.hits.is_empty() {
+            return Ok(None);
+        }
+
+        let body = result
+            .hits
+            .iter()
+            .map(|hit| {
+                format!(
+                    "### {}:{}-{}\n```\n{}\n```",
+                    hit.path,
+                    hit.start_line,
+                    hit.end_line,
+                    hit.content.trim_end()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        Ok(Some(ContextFragment {
+            title: "Relevant Code".to_string(),
+            body,
+        }))
+    }
+}
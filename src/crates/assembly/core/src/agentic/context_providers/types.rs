@@ -0,0 +1,87 @@
+//! Context provider contracts.
+
+use crate::util::errors::BitFunResult;
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Inputs shared by every provider for one prompt assembly.
+#[derive(Debug, Clone)]
+pub struct ContextProviderRequest {
+    pub session_id: Option<String>,
+    pub workspace_path: String,
+    /// Set when the workspace lives on a remote host. Providers that read the
+    /// local disk or local processes return nothing in that case.
+    pub remote: bool,
+    /// The user's message for this turn, when known.
+    pub query: Option<String>,
+}
+
+/// A titled section produced by a provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextFragment {
+    pub title: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait ContextProvider: Send + Sync {
+    /// Stable identifier, also used as the key in `ai.context_providers`.
+    fn id(&self) -> &str;
+
+    /// Position in the composed output. Ties are broken by id.
+    fn order(&self) -> u32;
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn default_token_budget(&self) -> usize;
+
+    /// Returns `None` when there is nothing worth adding for this request.
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextProviderOutcome {
+    Included,
+    Truncated,
+    Empty,
+    Disabled,
+    Failed,
+    TimedOut,
+}
+
+/// What one provider contributed to a composed context.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextProviderReport {
+    pub provider_id: String,
+    pub outcome: ContextProviderOutcome,
+    pub token_budget: usize,
+    /// Estimated size of the section before truncation.
+    pub original_tokens: usize,
+    /// Estimated size of the section as included in the prompt.
+    pub included_tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ComposedContext {
+    /// Rendered reminder, or `None` when no provider contributed.
+    pub text: Option<String>,
+    /// One report per registered provider, in composition order.
+    pub reports: Vec<ContextProviderReport>,
+}
+
+impl ComposedContext {
+    pub fn truncated_reports(&self) -> impl Iterator<Item = &ContextProviderReport> {
+        self.reports
+            .iter()
+            .filter(|report| report.outcome == ContextProviderOutcome::Truncated)
+    }
+}
@@ -24,6 +24,7 @@ use crate::agentic::events::{
 };
 use crate::agentic::execution::{
     ContextCompactionOutcome, ExecutionContext, ExecutionEngine, ExecutionResult,
+    BACKGROUND_TURN_CONTEXT_KEY,
 };
 use crate::agentic::fork_agent::ForkAgentContextSnapshot;
 use crate::agentic::goal_mode::{
@@ -56,6 +57,7 @@ use crate::service::config::global::GlobalConfigManager;
 use crate::service::config::{
     get_global_config_service, AgentModelDefaultsConfig, SubagentModelSelection,
 };
+use crate::service::hooks::{spawn_notification_hooks, HookEvent, HookInput};
 use crate::service::remote_ssh::normalize_remote_workspace_path;
 use crate::service::session::{
     SessionMemoryMode, SessionRelationship, SessionRelationshipKind, SessionStatus,
@@ -283,6 +285,27 @@ pub(crate) struct SubagentExecutionRequest {
     /// queued or running invocation reaches a terminal state.
     pub(crate) external_generation_lease:
         Option<crate::agentic::agents::ExternalSubagentGenerationLease>,
+    pub(crate) scope: SubagentScope,
+}
+
+/// Limits a parent places on one delegated subagent run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SubagentScope {
+    /// Narrows the child's tools to this list; empty keeps the agent's tools.
+    pub(crate) allowed_tools: Vec<String>,
+    /// Caps the child's context window below its model's window.
+    pub(crate) context_token_budget: Option<usize>,
+}
+
+fn scoped_subagent_tool_restrictions(
+    delegation_policy: DelegationPolicy,
+    scope: &SubagentScope,
+) -> ToolRuntimeRestrictions {
+    let mut restrictions = runtime_tool_restrictions_for_delegation_policy(delegation_policy);
+    restrictions
+        .allowed_tool_names
+        .extend(scope.allowed_tools.iter().cloned());
+    restrictions
 }
 
 #[derive(Debug, Clone)]
@@ -500,6 +523,7 @@ pub(crate) struct HiddenSubagentExecutionRequest {
     /// prepared until the final execution/cleanup owner releases every clone.
     execution_lease: Option<Arc<SessionExecutionLease>>,
     external_generation_lease: Option<crate::agentic::agents::ExternalSubagentGenerationLease>,
+    context_token_budget: Option<usize>,
 }
 
 fn ensure_hidden_subagent_dialog_turn_id(dialog_turn_id: &mut Option<String>) -> String {
@@ -1773,6 +1797,16 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
         // resolve to a different effective storage path and double-writing can leave
         // metadata/turn files split across two locations.
 
+        let mut hook_input = HookInput::new(HookEvent::SessionStart, session.session_id.clone());
+        hook_input.workspace_path = Some(workspace_path.clone());
+        hook_input.agent_type = Some(session.agent_type.clone());
+        let hook_cwd = session
+            .config
+            .remote_connection_id
+            .is_none()
+            .then(|| PathBuf::from(&workspace_path));
+        spawn_notification_hooks(hook_input, hook_cwd);
+
         self.emit_event(AgenticEvent::SessionCreated {
             session_id: session.session_id.clone(),
             session_name: session.session_name.clone(),
@@ -2522,7 +2556,10 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                 }
             };
 
-        if let Err(error) = ai_client_factory.get_client_resolved(&model_id).await {
+        if let Err(error) = ai_client_factory
+            .get_client_resolved_for_workspace(&model_id, workspace_binding.local_root_path())
+            .await
+        {
             return Ok(AssistantBootstrapEnsureOutcome::Blocked {
                 session_id,
                 reason: AssistantBootstrapBlockReason::ModelUnavailable,
@@ -3980,6 +4017,12 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
             "edit_constraint_revocation_authorized".to_string(),
             revocation_authorized.to_string(),
         );
+        // Nobody is waiting on those turns either, so their model requests
+        // yield to interactive turns on a contended model.
+        context_vars.insert(
+            BACKGROUND_TURN_CONTEXT_KEY.to_string(),
+            (!revocation_authorized).to_string(),
+        );
 
         // Pass model_id for token usage tracking
         if let Some(model_id) = &session.config.model_id {
@@ -4648,6 +4691,14 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
         self.background_subagent_outcomes
             .delete_session_references(session_id)
             .await?;
+        let mut hook_input = HookInput::new(HookEvent::SessionEnd, session_id);
+        hook_input.workspace_path = Some(workspace_path.to_string_lossy().into_owned());
+        spawn_notification_hooks(
+            hook_input,
+            workspace_path
+                .is_dir()
+                .then(|| workspace_path.to_path_buf()),
+        );
         self.emit_event(AgenticEvent::SessionDeleted {
             session_id: session_id.to_string(),
         })
@@ -5396,6 +5447,7 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
             prepared_session_created,
             execution_lease,
             external_generation_lease: _external_generation_lease,
+            context_token_budget,
         } = request;
         let prepared_target_session_id = target_session_id.clone();
         let continuation_policy = session_config.continuation_policy;
@@ -5615,6 +5667,10 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
             .await;
             return Err(error);
         }
+        if let Some(budget) = context_token_budget {
+            self.session_manager
+                .cap_session_context_window(&session_id, budget);
+        }
         if let Some(source_session_id) = prompt_cache_source_session_id.as_deref() {
             self.session_manager
                 .seed_forked_edit_constraints(source_session_id, &session_id)
@@ -6992,8 +7048,9 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                         permission_runtime_ceiling: Some(request.permission_runtime_ceiling),
                         delegation_policy: request.delegation_policy,
                         runtime_tool_restrictions: runtime_tool_restrictions_for_session_lifetime(
-                            runtime_tool_restrictions_for_delegation_policy(
+                            scoped_subagent_tool_restrictions(
                                 request.delegation_policy,
+                                &request.scope,
                             ),
                             transient,
                         ),
@@ -7004,6 +7061,7 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                         prepared_session_created: false,
                         execution_lease: None,
                         external_generation_lease: request.external_generation_lease,
+                        context_token_budget: request.scope.context_token_budget,
                     });
                 }
 
@@ -7076,7 +7134,10 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                     permission_runtime_ceiling: Some(request.permission_runtime_ceiling),
                     delegation_policy: request.delegation_policy,
                     runtime_tool_restrictions: runtime_tool_restrictions_for_session_lifetime(
-                        runtime_tool_restrictions_for_delegation_policy(request.delegation_policy),
+                        scoped_subagent_tool_restrictions(
+                            request.delegation_policy,
+                            &request.scope,
+                        ),
                         parent_transient,
                     ),
                     prompt_cache_source_session_id: None,
@@ -7086,6 +7147,7 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                     prepared_session_created: false,
                     execution_lease: None,
                     external_generation_lease: request.external_generation_lease,
+                    context_token_budget: request.scope.context_token_budget,
                 })
             }
             SubagentContextMode::Fork => {
@@ -7163,7 +7225,10 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                     permission_runtime_ceiling: Some(request.permission_runtime_ceiling),
                     delegation_policy: request.delegation_policy,
                     runtime_tool_restrictions: runtime_tool_restrictions_for_session_lifetime(
-                        runtime_tool_restrictions_for_delegation_policy(request.delegation_policy),
+                        scoped_subagent_tool_restrictions(
+                            request.delegation_policy,
+                            &request.scope,
+                        ),
                         parent_transient,
                     ),
                     prompt_cache_source_session_id: Some(snapshot.parent_session_id),
@@ -7173,6 +7238,7 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
                     prepared_session_created: false,
                     execution_lease: None,
                     external_generation_lease: request.external_generation_lease,
+                    context_token_budget: request.scope.context_token_budget,
                 })
             }
         }
@@ -7581,6 +7647,7 @@ Update the persona files and delete BOOTSTRAP.md as soon as bootstrap is complet
             prepared_session_created: false,
             execution_lease: None,
             external_generation_lease: None,
+            context_token_budget: None,
         };
 
         self.execute_hidden_subagent_internal(hidden_request, cancel_token, timeout_seconds)
@@ -9010,9 +9077,10 @@ mod tests {
         normalize_subagent_max_concurrency, resolve_agent_session_create_created_by,
         resolve_agent_submission_turn_id, resolve_subagent_model_selection,
         runtime_port_error_preserving_message, runtime_tool_restrictions_for_session_lifetime,
-        turn_review_manifest_for_agent, BackgroundSubagentWaitMode, ConversationCoordinator,
-        SessionMemoryMode, SessionReferenceLocator, SessionRelationshipKind,
-        SubagentExecutionRequest, TEST_AGENT_MODEL_DEFAULTS,
+        scoped_subagent_tool_restrictions, turn_review_manifest_for_agent,
+        BackgroundSubagentWaitMode, ConversationCoordinator, SessionMemoryMode,
+        SessionReferenceLocator, SessionRelationshipKind, SubagentExecutionRequest, SubagentScope,
+        TEST_AGENT_MODEL_DEFAULTS,
     };
     use crate::agentic::coordination::coordination_store::{
         BackgroundTaskRegistration, RegisteredBackgroundTask,
@@ -9106,6 +9174,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn subagent_scope_narrows_tools_without_reopening_delegation() {
+        let scope = SubagentScope {
+            allowed_tools: vec!["Read".to_string(), "Task".to_string()],
+            context_token_budget: None,
+        };
+        let restrictions =
+            scoped_subagent_tool_restrictions(DelegationPolicy::top_level().spawn_child(), &scope);
+
+        assert!(restrictions.is_tool_allowed("Read"));
+        assert!(!restrictions.is_tool_allowed("Bash"));
+        assert!(!restrictions.is_tool_allowed("Task"));
+
+        let unscoped = scoped_subagent_tool_restrictions(
+            DelegationPolicy::top_level().spawn_child_within(2),
+            &SubagentScope::default(),
+        );
+        assert!(unscoped.is_tool_allowed("Bash"));
+        assert!(unscoped.is_tool_allowed("Task"));
+    }
+
     #[test]
     fn transient_session_runtime_restrictions_deny_out_of_band_session_tools() {
         let mut base = crate::agentic::tools::ToolRuntimeRestrictions::default();
@@ -10932,6 +11021,7 @@ mod tests {
                 permission_runtime_ceiling: PermissionRuntimeCeiling::default(),
                 delegation_policy: DelegationPolicy::top_level().spawn_child(),
                 external_generation_lease: None,
+                scope: SubagentScope::default(),
             })
             .await
             .expect("fresh subagent request should resolve");
@@ -10995,6 +11085,7 @@ mod tests {
                 permission_runtime_ceiling: PermissionRuntimeCeiling::default(),
                 delegation_policy: DelegationPolicy::top_level().spawn_child(),
                 external_generation_lease: None,
+                scope: SubagentScope::default(),
             })
             .await
             .expect("fresh-only transient child should resolve");
@@ -11091,6 +11182,7 @@ mod tests {
             .expect("test ceiling should be valid"),
             delegation_policy: DelegationPolicy::top_level().spawn_child(),
             external_generation_lease: None,
+            scope: SubagentScope::default(),
         };
 
         let prepared = coordinator
@@ -11148,6 +11240,7 @@ mod tests {
             permission_runtime_ceiling: PermissionRuntimeCeiling::default(),
             delegation_policy: DelegationPolicy::top_level().spawn_child(),
             external_generation_lease: None,
+            scope: SubagentScope::default(),
         };
 
         let prepared = coordinator
@@ -11220,6 +11313,7 @@ mod tests {
             permission_runtime_ceiling: PermissionRuntimeCeiling::default(),
             delegation_policy: DelegationPolicy::top_level().spawn_child(),
             external_generation_lease: None,
+            scope: SubagentScope::default(),
         };
 
         let prepared = coordinator
@@ -11263,6 +11357,7 @@ mod tests {
             permission_runtime_ceiling: PermissionRuntimeCeiling::default(),
             delegation_policy: DelegationPolicy::top_level().spawn_child(),
             external_generation_lease: None,
+            scope: SubagentScope::default(),
         };
 
         let prepared = coordinator
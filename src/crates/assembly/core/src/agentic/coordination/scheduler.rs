@@ -27,6 +27,9 @@ use crate::agentic::keyed_lock::{KeyedAsyncLock, KeyedAsyncLockGuard};
 use crate::agentic::round_preempt::{DialogRoundInjectionSource, SessionRoundInjectionBuffer};
 use crate::agentic::session::session_store_port::CoreSessionStorePort;
 use crate::agentic::session::SessionManager;
+use crate::service::attachments::{
+    get_global_attachment_service, AttachmentKind, AttachmentService,
+};
 use crate::util::errors::{BitFunError, BitFunResult};
 use bitfun_runtime_ports::{ThreadGoal, MAX_THREAD_GOAL_AUTO_CONTINUATIONS};
 use log::{debug, info, warn};
@@ -2090,6 +2093,77 @@ fn agent_dialog_turn_image_contexts(
     Ok(Some(image_contexts))
 }
 
+/// Resolves `session_attachment` entries through the attachment service.
+///
+/// Stored images become image attachments; text and other files are listed in
+/// a reminder with their stored paths so the agent reads them with its tools.
+async fn resolve_session_attachments(
+    service: Option<&AttachmentService>,
+    session_id: &str,
+    attachments: Vec<AgentInputAttachment>,
+) -> PortResult<(Vec<AgentInputAttachment>, Option<Message>)> {
+    let mut resolved = Vec::with_capacity(attachments.len());
+    let mut file_lines = Vec::new();
+    for attachment in attachments {
+        if attachment.kind != "session_attachment" {
+            resolved.push(attachment);
+            continue;
+        }
+
+        let service = service.ok_or_else(|| {
+            PortError::new(
+                PortErrorKind::NotAvailable,
+                "session attachment service is not available",
+            )
+        })?;
+        let handle = service
+            .get(session_id, &attachment.id)
+            .await
+            .map_err(|error| {
+                let kind = match error {
+                    BitFunError::NotFound(_) => PortErrorKind::NotFound,
+                    BitFunError::Validation(_) => PortErrorKind::InvalidRequest,
+                    _ => PortErrorKind::Backend,
+                };
+                PortError::new(kind, error.to_string())
+            })?;
+
+        if handle.kind == AttachmentKind::Image {
+            let mut metadata = serde_json::Map::new();
+            metadata.insert("name".to_string(), serde_json::Value::String(handle.name));
+            metadata.insert(
+                "imagePath".to_string(),
+                serde_json::Value::String(handle.path),
+            );
+            metadata.insert(
+                "mimeType".to_string(),
+                serde_json::Value::String(handle.mime_type),
+            );
+            resolved.push(AgentInputAttachment {
+                kind: "remote_image".to_string(),
+                id: handle.id,
+                metadata,
+            });
+        } else {
+            file_lines.push(format!(
+                "- {} ({}, {} bytes): {}",
+                handle.name, handle.mime_type, handle.size_bytes, handle.path
+            ));
+        }
+    }
+
+    let reminder = (!file_lines.is_empty()).then(|| {
+        Message::internal_reminder(
+            InternalReminderKind::Generic,
+            format!(
+                "The user attached these files to this message. Read them from the stored paths when they are relevant:\n{}",
+                file_lines.join("\n")
+            ),
+        )
+    });
+    Ok((resolved, reminder))
+}
+
 fn agent_dialog_turn_prepended_messages(
     reminders: &[AgentDialogPrependedReminder],
 ) -> PortResult<Vec<Message>> {
@@ -2125,9 +2199,17 @@ impl DialogScheduler {
         request: AgentDialogTurnRequest,
         reject_if_busy: bool,
     ) -> PortResult<DialogSubmitOutcome> {
-        let image_contexts = agent_dialog_turn_image_contexts(&request.attachments)?;
-        let prepended_messages =
+        let attachment_service = get_global_attachment_service();
+        let (attachments, attachment_reminder) = resolve_session_attachments(
+            attachment_service.as_deref(),
+            &request.session_id,
+            request.attachments,
+        )
+        .await?;
+        let image_contexts = agent_dialog_turn_image_contexts(&attachments)?;
+        let mut prepended_messages =
             agent_dialog_turn_prepended_messages(&request.prepended_reminders)?;
+        prepended_messages.extend(attachment_reminder);
         let user_message_metadata = if request.metadata.is_empty() {
             None
         } else {
@@ -2379,6 +2461,7 @@ mod tests {
     use crate::agentic::tools::registry::ToolRegistry;
     use crate::agentic::tools::{ToolPipeline, ToolStateManager};
     use crate::infrastructure::PathManager;
+    use crate::service::attachments::AttachmentOrigin;
     use bitfun_runtime_ports::{AgentDialogPrependedReminder, AgentInputAttachment, PortErrorKind};
     use tokio::sync::RwLock as TokioRwLock;
 
@@ -3259,6 +3342,79 @@ mod tests {
             .contains("unsupported agent dialog attachment kind"));
     }
 
+    #[tokio::test]
+    async fn agent_dialog_turn_session_attachments_resolve_to_images_and_file_reminder() {
+        let root = std::env::temp_dir().join(format!(
+            "bitfun-scheduler-attachments-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let service = AttachmentService::with_root(root.clone());
+        let image = service
+            .ingest_bytes(
+                "session-1",
+                "shot.png",
+                b"\x89PNG\r\n\x1a\n0000",
+                AttachmentOrigin::Drop,
+            )
+            .await
+            .unwrap();
+        let text = service
+            .ingest_text("session-1", "notes", AttachmentOrigin::Clipboard)
+            .await
+            .unwrap();
+
+        let (attachments, reminder) = resolve_session_attachments(
+            Some(&service),
+            "session-1",
+            vec![
+                AgentInputAttachment::remote_image(
+                    "ctx-1",
+                    "clip.png",
+                    "data:image/png;base64,abc",
+                ),
+                AgentInputAttachment::session_attachment(&image.id),
+                AgentInputAttachment::session_attachment(&text.id),
+            ],
+        )
+        .await
+        .expect("stored attachments should resolve");
+
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].id, "ctx-1");
+        let contexts = agent_dialog_turn_image_contexts(&attachments)
+            .unwrap()
+            .unwrap();
+        assert_eq!(contexts[1].id, image.id);
+        assert_eq!(contexts[1].image_path.as_deref(), Some(image.path.as_str()));
+        assert_eq!(contexts[1].mime_type, "image/png");
+
+        let reminder = reminder.expect("text attachments are listed in a reminder");
+        assert_eq!(
+            reminder.internal_reminder_kind(),
+            Some(InternalReminderKind::Generic)
+        );
+
+        let err = resolve_session_attachments(
+            Some(&service),
+            "session-1",
+            vec![AgentInputAttachment::session_attachment("missing")],
+        )
+        .await
+        .expect_err("unknown attachment ids must fail the submission");
+        assert_eq!(err.kind, PortErrorKind::NotFound);
+
+        let err = resolve_session_attachments(
+            None,
+            "session-1",
+            vec![AgentInputAttachment::session_attachment(&text.id)],
+        )
+        .await
+        .expect_err("attachments need the service");
+        assert_eq!(err.kind, PortErrorKind::NotAvailable);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[test]
     fn agent_dialog_turn_prepended_reminders_preserve_session_message_kind() {
         let messages = agent_dialog_turn_prepended_messages(&[AgentDialogPrependedReminder {
@@ -6,6 +6,7 @@ use super::model_exchange_trace::{
     prepare_model_exchange_trace_for_workspace, ModelExchangeTraceOperation,
 };
 use super::round_executor::RoundExecutor;
+use super::secret_redaction::MessageRedactor;
 use super::types::{ExecutionContext, ExecutionResult, RoundContext, RoundResult};
 use crate::agentic::agents::{
     build_prompt_context_for_workspace, get_agent_registry, PrependedPromptReminders,
@@ -33,14 +34,17 @@ use crate::agentic::tools::product_runtime::{
     collect_product_loaded_deferred_tool_specs, GetToolSpecTool,
 };
 use crate::agentic::tools::{
-    resolve_tool_manifest, tool_context_runtime, ResolvedToolManifest, ToolRuntimeRestrictions,
+    exposure_budget, resolve_tool_manifest, tool_context_runtime, ResolvedToolManifest,
+    ToolRuntimeRestrictions,
 };
 use crate::agentic::WorkspaceBinding;
 use crate::infrastructure::ai::get_global_ai_client_factory;
 use crate::service::config::get_global_config_service;
+use crate::service::config::project_model_store::load_validated_project_model_config;
 use crate::service::config::types::{
     automatic_max_output_tokens, model_runtime_binding_fingerprint, ModelCapability, ModelCategory,
 };
+use crate::service::credential_health::get_global_credential_health_service;
 use crate::util::errors::{BitFunError, BitFunResult};
 use crate::util::token_counter::TokenCounter;
 use crate::util::types::Message as AIMessage;
@@ -327,6 +331,7 @@ struct CompressionModelSummaryInput<'a> {
     prepended_prompt_reminders: &'a PrependedPromptReminders,
     tool_definitions: &'a Option<Vec<ToolDefinition>>,
     workspace: Option<&'a WorkspaceBinding>,
+    session_id: &'a str,
     dialog_turn_id: &'a str,
     runtime_messages: &'a [Message],
     ai_client: Arc<crate::infrastructure::ai::AIClient>,
@@ -997,7 +1002,7 @@ impl ExecutionEngine {
         session_id: &str,
         current_agent: &dyn crate::agentic::agents::Agent,
         prompt_context: Option<&PromptBuilderContext>,
-        _context_vars: &HashMap<String, String>,
+        context_vars: &HashMap<String, String>,
     ) -> PrependedPromptReminders {
         let Some(prompt_context) = prompt_context.cloned() else {
             return PrependedPromptReminders::default();
@@ -1072,6 +1077,11 @@ impl ExecutionEngine {
             built_user_context
         };
         let runtime_context = prompt_builder.build_runtime_context_reminder().await;
+        let provided_context = prompt_builder
+            .build_provided_context_reminder(
+                context_vars.get("original_user_input").map(String::as_str),
+            )
+            .await;
 
         PrependedPromptReminders {
             deferred_tool_listing: prompt_builder.build_deferred_tool_listing_reminder(),
@@ -1083,6 +1093,7 @@ impl ExecutionEngine {
                 .and_then(|sections| sections.render_agent_listing_reminder()),
             runtime_context,
             user_context,
+            provided_context,
         }
     }
 
@@ -1180,7 +1191,7 @@ impl ExecutionEngine {
         prepended_prompt_reminders: &PrependedPromptReminders,
     ) {
         debug!(
-            "Turn prompt scaffold resolved: session_id={}, turn_id={}, stage={}, system_prompt_len={} bytes, skill_listing_len={}, agent_listing_len={}, deferred_tool_listing_len={}, user_context_len={}, runtime_context_len={}",
+            "Turn prompt scaffold resolved: session_id={}, turn_id={}, stage={}, system_prompt_len={} bytes, skill_listing_len={}, agent_listing_len={}, deferred_tool_listing_len={}, user_context_len={}, runtime_context_len={}, provided_context_len={}",
             session_id,
             turn_id,
             stage,
@@ -1209,6 +1220,11 @@ impl ExecutionEngine {
                 .runtime_context
                 .as_ref()
                 .map(|text| text.len())
+                .unwrap_or(0),
+            prepended_prompt_reminders
+                .provided_context
+                .as_ref()
+                .map(|text| text.len())
                 .unwrap_or(0)
         );
     }
@@ -1288,6 +1304,13 @@ impl ExecutionEngine {
             return Ok(model_id.to_string());
         }
 
+        let ai_config = load_validated_project_model_config(
+            &ai_config,
+            workspace.and_then(WorkspaceBinding::local_root_path),
+        )
+        .await?
+        .apply_to(&ai_config);
+
         let agent_registry = get_agent_registry();
         let fallback_model_id = agent_registry
             .get_model_id_for_agent(agent_type, workspace.map(|binding| binding.root_path()))
@@ -1308,6 +1331,27 @@ impl ExecutionEngine {
             || configured_model_id == "default"
             || resolved_configured_model_id == "auto"
         {
+            // A model the user picked for the session wins, so skills only
+            // steer sessions left on the auto strategy.
+            if let Some(preference) = self
+                .session_manager
+                .skill_model_preference(&session.session_id)
+            {
+                if let Some(skill_model_id) = ai_config
+                    .resolve_skill_model_preference(&preference.skill_name, &preference.model)
+                {
+                    info!(
+                        "Skill model preference applied: session_id={}, turn_index={}, skill={}, requested={}, resolved_model_id={}",
+                        session.session_id,
+                        turn_index,
+                        preference.skill_name,
+                        preference.model,
+                        skill_model_id
+                    );
+                    return Ok(skill_model_id);
+                }
+            }
+
             let fallback_model = "primary";
             let resolved_model_id = ai_config.resolve_model_selection(fallback_model);
 
@@ -1754,7 +1798,7 @@ impl ExecutionEngine {
         &self,
         input: CompressionModelSummaryInput<'_>,
     ) -> BitFunResult<Option<String>> {
-        let request_messages = self
+        let mut request_messages = self
             .build_compression_request_messages(
                 input.runtime_messages,
                 input.dialog_turn_id,
@@ -1764,6 +1808,18 @@ impl ExecutionEngine {
                 input.prepended_prompt_reminders,
             )
             .await?;
+        let global_config: crate::service::config::types::GlobalConfig =
+            match get_global_config_service().await {
+                Ok(service) => service.get_config(None).await.unwrap_or_default(),
+                Err(_) => Default::default(),
+            };
+        // Remote project overrides need workspace services, which compaction
+        // does not carry; user-level settings still apply there.
+        if let Some(redactor) =
+            MessageRedactor::for_workspace(&global_config, input.workspace, None).await
+        {
+            redactor.redact_messages(Some(input.session_id), &mut request_messages);
+        }
 
         let raw_summary = self
             .request_compression_summary_with_retry(
@@ -1844,7 +1900,15 @@ impl ExecutionEngine {
                 )
                 .await
         } else {
-            ai_client_factory.get_client_resolved(&model_id).await
+            ai_client_factory
+                .get_client_resolved_for_workspace(
+                    &model_id,
+                    context
+                        .workspace
+                        .as_ref()
+                        .and_then(WorkspaceBinding::local_root_path),
+                )
+                .await
         };
         let ai_client = ai_client_result.map_err(|e| {
             BitFunError::AIClient(format!(
@@ -2029,6 +2093,7 @@ impl ExecutionEngine {
             .generate_compression_model_summary(CompressionModelSummaryInput {
                 ai_client,
                 runtime_messages: &runtime_messages,
+                session_id,
                 dialog_turn_id,
                 workspace,
                 tool_definitions,
@@ -2341,6 +2406,7 @@ impl ExecutionEngine {
             .generate_compression_model_summary(CompressionModelSummaryInput {
                 ai_client: scaffold.ai_client.clone(),
                 runtime_messages: &runtime_messages,
+                session_id: &session_id,
                 dialog_turn_id: &dialog_turn_id,
                 workspace: context.workspace.as_ref(),
                 tool_definitions: &scaffold.tool_definitions,
@@ -2691,7 +2757,15 @@ impl ExecutionEngine {
                 )
                 .await
         } else {
-            ai_client_factory.get_client_resolved(&model_id).await
+            ai_client_factory
+                .get_client_resolved_for_workspace(
+                    &model_id,
+                    context
+                        .workspace
+                        .as_ref()
+                        .and_then(WorkspaceBinding::local_root_path),
+                )
+                .await
         };
         let ai_client = ai_client_result.map_err(|e| {
             BitFunError::AIClient(format!(
@@ -2699,6 +2773,9 @@ impl ExecutionEngine {
                 model_id, e
             ))
         })?;
+        if let Some(credential_health) = get_global_credential_health_service() {
+            credential_health.ensure_usable(&model_id).await?;
+        }
 
         // Primary model vision capability (tools + system prompt appendix; also used below for API message stripping).
         let primary_model_facts = Self::resolve_primary_model_context(
@@ -2786,20 +2863,57 @@ impl ExecutionEngine {
             &context.runtime_tool_restrictions,
         );
 
+        // The exposure budget only demotes tools to deferred exposure, so it
+        // needs GetToolSpec to keep them reachable.
+        let exposure_budget = if enable_tools && deferred_tool_loading_enabled {
+            match exposure_budget::load_enabled_tool_exposure_budget().await {
+                Some(settings) => {
+                    let signals = exposure_budget::collect_tool_exposure_signals(
+                        &initial_messages,
+                        &original_user_input,
+                        context
+                            .workspace
+                            .as_ref()
+                            .map(|workspace| workspace.root_path()),
+                    )
+                    .await;
+                    Some((settings, signals))
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+        let mut omitted_deferred_tools = 0;
         let tool_manifest = if enable_tools {
             debug!(
                 "Agent tools: agent={}, tool_count={}",
                 agent_type,
                 allowed_tools.len()
             );
-            Some(
-                resolve_tool_manifest(
-                    &allowed_tools,
-                    &tool_policy.exposure_overrides,
-                    &tool_description_context,
-                )
-                .await,
+            let exposure_overrides = match exposure_budget.as_ref() {
+                Some((settings, signals)) => {
+                    exposure_budget::apply_tool_exposure_budget(
+                        &allowed_tools,
+                        &tool_policy.exposure_overrides,
+                        settings,
+                        signals,
+                    )
+                    .await
+                }
+                None => tool_policy.exposure_overrides.clone(),
+            };
+            let mut manifest = resolve_tool_manifest(
+                &allowed_tools,
+                &exposure_overrides,
+                &tool_description_context,
             )
+            .await;
+            if let Some((settings, signals)) = exposure_budget.as_ref() {
+                omitted_deferred_tools =
+                    exposure_budget::trim_deferred_tool_listing(&mut manifest, settings, signals);
+            }
+            Some(manifest)
         } else {
             None
         };
@@ -2807,11 +2921,19 @@ impl ExecutionEngine {
             .as_ref()
             .map(|manifest| manifest.deferred_tool_names.clone())
             .unwrap_or_default();
-        let tool_listing_sections = if let Some(manifest) = tool_manifest.as_ref() {
+        let mut tool_listing_sections = if let Some(manifest) = tool_manifest.as_ref() {
             Self::build_tool_listing_sections(manifest, &tool_description_context).await
         } else {
             ToolListingSections::default()
         };
+        if omitted_deferred_tools > 0 {
+            if let Some(listing) = tool_listing_sections.deferred_tool_listing.as_mut() {
+                listing.push_str("\n\n");
+                listing.push_str(&exposure_budget::omitted_deferred_tools_note(
+                    omitted_deferred_tools,
+                ));
+            }
+        }
         let runtime_context_needs = tool_manifest
             .as_ref()
             .map(|manifest| {
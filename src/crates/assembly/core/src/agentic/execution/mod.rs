@@ -5,7 +5,9 @@
 pub mod edit_constraint_guard;
 pub mod execution_engine;
 pub(crate) mod model_exchange_trace;
+pub(crate) mod round_artifacts;
 pub mod round_executor;
+pub(crate) mod secret_redaction;
 pub mod stream_processor;
 pub mod types;
 pub mod write_content_sanitizer;
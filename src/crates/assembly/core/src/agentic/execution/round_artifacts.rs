@@ -0,0 +1,107 @@
+//! Round artifact registration
+//!
+//! Tools report files they produced in an `artifact_path` field of their
+//! result data. When a round completes those files are copied into the
+//! managed artifact store. Subagent rounds register under the parent session
+//! with the parent's Task call as the task id, so task outputs stay with the
+//! session that asked for them.
+
+use super::types::RoundContext;
+use crate::agentic::core::ToolResult;
+use crate::service::artifacts::{get_global_artifact_service, RegisterArtifactRequest};
+use log::{debug, warn};
+use std::collections::HashSet;
+
+pub(crate) fn round_artifact_requests(
+    session_id: &str,
+    task_id: &str,
+    tool_results: &[ToolResult],
+) -> Vec<RegisterArtifactRequest> {
+    let mut seen = HashSet::new();
+    tool_results
+        .iter()
+        .filter(|result| !result.is_error)
+        .filter_map(|result| result.result.get("artifact_path")?.as_str())
+        .filter(|path| seen.insert(path.to_string()))
+        .map(|path| RegisterArtifactRequest {
+            session_id: session_id.to_string(),
+            task_id: Some(task_id.to_string()),
+            source_path: path.to_string(),
+            name: None,
+        })
+        .collect()
+}
+
+/// Registers the round's outputs in the background; failures are only logged.
+pub(crate) fn register_round_artifacts(context: &RoundContext, tool_results: &[ToolResult]) {
+    let (session_id, task_id) = match &context.subagent_parent_info {
+        Some(parent) => (parent.session_id.as_str(), parent.tool_call_id.as_str()),
+        None => (context.session_id.as_str(), context.dialog_turn_id.as_str()),
+    };
+    let requests = round_artifact_requests(session_id, task_id, tool_results);
+    if requests.is_empty() {
+        return;
+    }
+    let Some(service) = get_global_artifact_service() else {
+        debug!(
+            "Artifact service unavailable, skipping round outputs: session_id={}, count={}",
+            session_id,
+            requests.len()
+        );
+        return;
+    };
+
+    tokio::spawn(async move {
+        for request in requests {
+            let source_path = request.source_path.clone();
+            if let Err(e) = service.register(request).await {
+                warn!(
+                    "Failed to register round artifact: source_path={}, error={}",
+                    source_path, e
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_artifact_requests;
+    use crate::agentic::core::ToolResult;
+    use serde_json::json;
+
+    fn tool_result(tool_id: &str, result: serde_json::Value, is_error: bool) -> ToolResult {
+        ToolResult {
+            tool_id: tool_id.to_string(),
+            tool_name: "Write".to_string(),
+            effective_tool_name: None,
+            result,
+            result_for_assistant: None,
+            is_error,
+            duration_ms: None,
+            image_attachments: None,
+        }
+    }
+
+    #[test]
+    fn only_successful_results_with_an_artifact_path_are_registered_once() {
+        let results = vec![
+            tool_result("call-1", json!({ "artifact_path": "/ws/out.md" }), false),
+            tool_result("call-2", json!({ "artifact_path": "/ws/out.md" }), false),
+            tool_result("call-3", json!({ "artifact_path": "/ws/failed.md" }), true),
+            tool_result("call-4", json!({ "file_path": "notes.md" }), false),
+            tool_result("call-5", json!({ "artifact_path": "/ws/chart.png" }), false),
+        ];
+
+        let requests = round_artifact_requests("session-1", "turn-1", &results);
+
+        let paths: Vec<_> = requests
+            .iter()
+            .map(|request| request.source_path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["/ws/out.md", "/ws/chart.png"]);
+        assert!(requests.iter().all(|request| {
+            request.session_id == "session-1" && request.task_id.as_deref() == Some("turn-1")
+        }));
+    }
+}
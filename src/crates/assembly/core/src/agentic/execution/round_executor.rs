@@ -3,6 +3,8 @@
 //! Executes a single model round: calls AI, processes streaming responses, executes tools
 
 use super::model_exchange_trace::prepare_model_exchange_trace;
+use super::round_artifacts::register_round_artifacts;
+use super::secret_redaction::{restore_tool_call_secrets, MessageRedactor};
 use super::stream_processor::{StreamProcessOptions, StreamProcessor, StreamResult};
 use super::types::{FinishReason, RoundContext, RoundResult};
 use crate::agentic::core::{Message, ToolCall};
@@ -20,10 +22,11 @@ use crate::agentic::tools::pipeline::{
     SubagentBatchExecutionPolicy as PipelineSubagentBatchExecutionPolicy, ToolExecutionContext,
     ToolExecutionOptions, ToolPipeline,
 };
+use crate::agentic::tools::restrictions::{ToolWriteSandbox, SENSITIVE_FILE_PERMISSION_ACTION};
 use crate::agentic::tools::tool_context_runtime;
 use crate::agentic::tools::tool_result_storage;
 use crate::agentic::MessageContent;
-use crate::infrastructure::ai::AIClient;
+use crate::infrastructure::ai::{AIClient, RequestPriority};
 use crate::service::config::project_permission_store::{
     load_project_permission_config_local, load_project_permission_config_remote,
 };
@@ -39,12 +42,16 @@ use bitfun_agent_runtime::turn_cancellation::DialogTurnCancellationTokenStore;
 use bitfun_ai_adapters::{
     ModelExchangeRequestTraceHandle, ModelExchangeResponseTrace, ModelExchangeTraceConfig,
 };
-use bitfun_runtime_ports::PermissionRule;
+use bitfun_runtime_ports::{PermissionEffect, PermissionRule};
 use log::{debug, error, warn};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
 
+/// Context variable marking turns no user is waiting on, e.g. scheduled jobs;
+/// their model requests yield to interactive turns when a model is contended.
+pub const BACKGROUND_TURN_CONTEXT_KEY: &str = "background_turn";
+
 /// Round executor
 pub struct RoundExecutor {
     stream_processor: Arc<StreamProcessor>,
@@ -151,16 +158,52 @@ impl RoundExecutor {
         project_rules: &[PermissionRule],
         agent_profile: Option<&AgentProfileConfig>,
         parent_runtime_ceiling: Option<&bitfun_runtime_ports::PermissionRuntimeCeiling>,
+        write_sandbox: Option<&ToolWriteSandbox>,
     ) -> Vec<PermissionRule> {
+        // Enforced last so full access and project rules cannot waive it.
+        let enforced: Vec<PermissionRule> = write_sandbox
+            .filter(|sandbox| sandbox.approve_sensitive_files)
+            .map(|_| {
+                PermissionRule::new(SENSITIVE_FILE_PERMISSION_ACTION, "*", PermissionEffect::Ask)
+            })
+            .into_iter()
+            .collect();
         resolve_effective_permission_rules(
             global,
             project_rules,
             agent_profile,
             parent_runtime_ceiling,
-            &[],
+            &enforced,
         )
     }
 
+    /// Write sandbox for this round's tools, or `None` when disabled.
+    fn resolve_write_sandbox(
+        global: &crate::service::config::types::GlobalConfig,
+        workspace_restricted: bool,
+    ) -> Option<ToolWriteSandbox> {
+        let settings = &global.ai.write_sandbox;
+        if !settings.enabled {
+            return None;
+        }
+        let level = if workspace_restricted {
+            settings.restricted
+        } else {
+            settings.trusted
+        };
+        Some(ToolWriteSandbox {
+            extra_roots: settings
+                .extra_write_paths
+                .iter()
+                .map(|path| path.trim())
+                .filter(|path| !path.is_empty())
+                .map(str::to_string)
+                .collect(),
+            confine_to_workspace: level.confine_to_workspace,
+            approve_sensitive_files: level.approve_sensitive_files,
+        })
+    }
+
     fn resolve_auto_approve_ask(
         global: &crate::service::config::types::GlobalConfig,
         context_vars: &std::collections::HashMap<String, String>,
@@ -205,7 +248,7 @@ impl RoundExecutor {
         &self,
         ai_client: Arc<AIClient>,
         context: RoundContext,
-        ai_messages: Vec<AIMessage>,
+        mut ai_messages: Vec<AIMessage>,
         tool_definitions: Option<Vec<ToolDefinition>>,
         context_window: Option<usize>,
     ) -> BitFunResult<RoundResult> {
@@ -246,6 +289,29 @@ impl RoundExecutor {
                 Err(_) => Default::default(),
             };
         let allow_normal_tool_json_repair = global_config.ai.allow_tool_json_repair;
+        if let Some(redactor) = MessageRedactor::for_workspace(
+            &global_config,
+            context.workspace.as_ref(),
+            context.workspace_services.as_ref(),
+        )
+        .await
+        {
+            redactor.redact_messages(Some(&context.session_id), &mut ai_messages);
+        }
+        // Rate-limited models admit queued requests round-robin per session,
+        // interactive turns ahead of background ones.
+        let request_priority = if context
+            .context_vars
+            .get(BACKGROUND_TURN_CONTEXT_KEY)
+            .is_some_and(|value| value == "true")
+        {
+            RequestPriority::Background
+        } else {
+            RequestPriority::Interactive
+        };
+        let scheduled_client = ai_client
+            .with_fairness_key(context.session_id.clone())
+            .with_request_priority(request_priority);
         let max_attempts = Self::MAX_STREAM_ATTEMPTS;
         let mut attempt_index = 0usize;
         let (stream_result, send_to_stream_ms, stream_processing_ms, final_trace_handle) = loop {
@@ -271,7 +337,7 @@ impl RoundExecutor {
                 max_attempts
             );
             // Use dynamically obtained client for call
-            let send_future = ai_client.send_message_stream(
+            let send_future = scheduled_client.send_message_stream(
                 ai_messages.clone(),
                 tool_definitions.clone(),
                 trace_config.clone(),
@@ -875,6 +941,20 @@ impl RoundExecutor {
                     .as_ref()
                     .map(|parent| parent.permission_delegation_context(&context.agent_type))
             });
+            // Untrusted local folders run restricted: no automatic approval of
+            // prompts, no project-granted allow rules and the stricter write
+            // sandbox level.
+            let workspace_restricted = context.workspace.as_ref().is_some_and(|workspace| {
+                !workspace.is_remote()
+                    && crate::service::workspace_trust::is_workspace_restricted(
+                        workspace.root_path(),
+                    )
+            });
+            let write_sandbox = Self::resolve_write_sandbox(&global_config, workspace_restricted);
+            let mut runtime_tool_restrictions = context.runtime_tool_restrictions.clone();
+            if write_sandbox.is_some() {
+                runtime_tool_restrictions.path_policy.sandbox = write_sandbox.clone();
+            }
             let tool_context = ToolExecutionContext {
                 session_id: context.session_id.clone(),
                 dialog_turn_id: context.dialog_turn_id.clone(),
@@ -891,7 +971,7 @@ impl RoundExecutor {
                 deferred_tools: context.deferred_tools.clone(),
                 loaded_deferred_tool_specs: context.loaded_deferred_tool_specs.clone(),
                 allowed_tools,
-                runtime_tool_restrictions: context.runtime_tool_restrictions.clone(),
+                runtime_tool_restrictions,
                 steering_interrupt: context.steering_interrupt.clone(),
                 workspace_services: context.workspace_services.clone(),
                 terminal_port: context.terminal_port.clone(),
@@ -904,10 +984,10 @@ impl RoundExecutor {
             let subagent_batch_execution_policy = Self::map_subagent_batch_execution_policy(
                 global_config.ai.subagent_batch_execution_policy,
             );
-            let auto_approve_ask =
-                Self::resolve_auto_approve_ask(&global_config, &context.context_vars);
+            let auto_approve_ask = !workspace_restricted
+                && Self::resolve_auto_approve_ask(&global_config, &context.context_vars);
 
-            let project_rules = match context.workspace.as_ref() {
+            let mut project_rules = match context.workspace.as_ref() {
                 Some(workspace) if workspace.is_remote() => {
                     match context.workspace_services.as_ref() {
                         Some(services) => {
@@ -928,6 +1008,9 @@ impl RoundExecutor {
                 }
                 None => Vec::new(),
             };
+            if workspace_restricted {
+                project_rules.retain(|rule| rule.effect != PermissionEffect::Allow);
+            }
 
             let agent_profile_id =
                 crate::agentic::agents::resolve_mode_config_profile_id(&context.agent_type);
@@ -940,10 +1023,12 @@ impl RoundExecutor {
                 &project_rules,
                 agent_profile,
                 context.permission_runtime_ceiling.as_ref(),
+                write_sandbox.as_ref(),
             );
 
             // Create tool execution options (use configured timeout values)
             let tool_options = ToolExecutionOptions {
+                max_parallel_tools: global_config.ai.max_parallel_tool_calls.max(1),
                 timeout_secs: tool_execution_timeout,
                 subagent_batch_execution_policy,
                 permission_rules,
@@ -959,10 +1044,15 @@ impl RoundExecutor {
                     CancellationToken::new(),
                 );
 
+            // The model only saw placeholders for redacted secrets; tools get
+            // the real values back.
+            let mut executable_tool_calls = tool_calls.clone();
+            restore_tool_call_secrets(&context.session_id, &mut executable_tool_calls);
+
             // Execute tools — convert pipeline-level Err into per-tool error results
             // so the model always receives a tool_result for every tool_call.
             let execution_results = match tool_pipeline
-                .execute_tools(tool_calls.clone(), tool_context, tool_options)
+                .execute_tools(executable_tool_calls, tool_context, tool_options)
                 .await
             {
                 Ok(results) => results,
@@ -1013,6 +1103,7 @@ impl RoundExecutor {
             vec![]
         };
         let tool_phase_ms = elapsed_ms_u64(tool_phase_started_at);
+        register_round_artifacts(&context, &tool_results);
 
         // Create assistant message (includes tool calls and thinking content, supports interleaved thinking mode)
         let reasoning = if stream_result.full_thinking.is_empty() {
@@ -1488,6 +1579,7 @@ mod tests {
     use crate::agentic::events::{EventQueue, EventQueueConfig};
     use crate::agentic::execution::stream_processor::StreamResult;
     use crate::agentic::execution::types::RoundContext;
+    use crate::agentic::tools::restrictions::SENSITIVE_FILE_PERMISSION_ACTION;
     use crate::agentic::tools::ToolRuntimeRestrictions;
     use crate::service::config::types::{AgentProfileConfig, GlobalConfig};
     use crate::util::errors::BitFunError;
@@ -1567,8 +1659,13 @@ mod tests {
             ..AgentProfileConfig::default()
         };
 
-        let resolved =
-            RoundExecutor::resolve_permission_rules(&global, &project_rules, Some(&agent), None);
+        let resolved = RoundExecutor::resolve_permission_rules(
+            &global,
+            &project_rules,
+            Some(&agent),
+            None,
+            None,
+        );
         let evaluator = PermissionEvaluator::case_sensitive();
 
         assert_eq!(
@@ -1589,6 +1686,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_sandbox_follows_trust_level_and_keeps_sensitive_files_gated() {
+        let mut global = GlobalConfig::default();
+        global.tool_permissions.policy.preset = PermissionPolicyPreset::FullAccess;
+        global.ai.write_sandbox.extra_write_paths = vec![" /tmp/scratch ".to_string()];
+
+        let trusted = RoundExecutor::resolve_write_sandbox(&global, false).expect("sandbox");
+        let restricted = RoundExecutor::resolve_write_sandbox(&global, true).expect("sandbox");
+        assert!(!trusted.confine_to_workspace);
+        assert!(restricted.confine_to_workspace);
+        assert_eq!(restricted.extra_roots, vec!["/tmp/scratch".to_string()]);
+
+        let project_rules = vec![PermissionRule::new(
+            SENSITIVE_FILE_PERMISSION_ACTION,
+            "*",
+            PermissionEffect::Allow,
+        )];
+        let resolved = RoundExecutor::resolve_permission_rules(
+            &global,
+            &project_rules,
+            None,
+            None,
+            Some(&trusted),
+        );
+        assert_eq!(
+            PermissionEvaluator::case_sensitive().evaluate_resource(
+                SENSITIVE_FILE_PERMISSION_ACTION,
+                "/repo/.git/config",
+                &resolved
+            ),
+            PermissionEffect::Ask
+        );
+
+        global.ai.write_sandbox.enabled = false;
+        assert!(RoundExecutor::resolve_write_sandbox(&global, true).is_none());
+    }
+
     #[test]
     fn auto_approve_context_overrides_persisted_interaction_preference() {
         let mut global = GlobalConfig::default();
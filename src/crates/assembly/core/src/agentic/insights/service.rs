@@ -7,7 +7,7 @@ use crate::agentic::insights::prompt_context::{
 };
 use crate::agentic::insights::types::*;
 use crate::infrastructure::ai::get_global_ai_client_factory;
-use crate::infrastructure::ai::AIClient;
+use crate::infrastructure::ai::{AIClient, RequestPriority};
 use crate::infrastructure::events::{emit_global_event, BackendEvent};
 use crate::infrastructure::get_path_manager_arc;
 use crate::service::config::get_global_config_service;
@@ -241,6 +241,10 @@ impl InsightsService {
             };
             (fast, primary)
         };
+        let ai_client_fast =
+            Arc::new(ai_client_fast.with_request_priority(RequestPriority::Background));
+        let ai_client_primary =
+            Arc::new(ai_client_primary.with_request_priority(RequestPriority::Background));
 
         let mut generation_models = vec![ai_client_fast.config.model.clone()];
         if ai_client_primary.config.model != ai_client_fast.config.model {
@@ -760,6 +760,7 @@ fn memory_phase2_tool_restrictions(memory_root: &std::path::Path) -> ToolRuntime
             write_roots: vec![root.clone()],
             edit_roots: vec![root.clone()],
             delete_roots: vec![root],
+            sandbox: None,
         },
     }
 }
@@ -9,7 +9,7 @@ use crate::agentic::memories::types::{
 };
 use crate::agentic::persistence::PersistenceManager;
 use crate::agentic::SessionKind;
-use crate::infrastructure::ai::get_global_ai_client_factory;
+use crate::infrastructure::ai::{get_global_ai_client_factory, RequestPriority};
 use crate::infrastructure::get_path_manager_arc;
 use crate::service::config::get_global_config_service;
 use crate::service::config::types::{GlobalConfig, MemoryExternalContextPolicy};
@@ -160,9 +160,12 @@ impl MemoryPhase1Service {
         let ai_factory = get_global_ai_client_factory().await.map_err(|error| {
             BitFunError::service(format!("Failed to get AI client factory: {}", error))
         })?;
-        let ai_client = ai_factory
-            .get_client_resolved(&config.extract_model_selector)
-            .await?;
+        let ai_client = Arc::new(
+            ai_factory
+                .get_client_resolved(&config.extract_model_selector)
+                .await?
+                .with_request_priority(RequestPriority::Background),
+        );
         info!(
             "Memory phase1 model resolved: selector={}, name={}, model={}, format={}, context_window={}, max_tokens={:?}",
             config.extract_model_selector,
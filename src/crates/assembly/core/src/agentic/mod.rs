@@ -20,11 +20,13 @@ pub mod tools;
 
 // Coordination module
 pub mod context_profile;
+pub mod context_providers;
 pub mod coordination;
 pub mod deep_review;
 pub mod deep_review_policy;
 pub mod harness;
 pub(crate) mod subagent_runtime;
+pub mod task_queue;
 
 // Shared-context fork-agent execution module
 pub mod fork_agent;
@@ -23,12 +23,16 @@ use crate::service::remote_ssh::workspace_state::{
     resolve_workspace_session_identity, LOCAL_WORKSPACE_SSH_HOST,
 };
 use crate::service::session::{
-    DialogTurnData, SessionMetadata, SessionTranscriptExport, SessionTranscriptExportOptions,
+    plan_session_retention, render_session_export, render_session_report, search_session_history,
+    DialogTurnData, RenderedSessionReport, SessionExportFormat, SessionKind, SessionMetadata,
+    SessionReportArtifact, SessionReportOptions, SessionRetentionPlan, SessionRetentionPolicy,
+    SessionSearchHit, SessionStatus, SessionTranscriptExport, SessionTranscriptExportOptions,
     TranscriptLineRange, SESSION_STORAGE_SCHEMA_VERSION,
 };
 use crate::service::workspace_runtime::WorkspaceRuntimeService;
 use crate::util::errors::{BitFunError, BitFunResult};
 use crate::util::timing::elapsed_ms_u64;
+use bitfun_agent_tools::{ToolReplayBundle, TOOL_INVOCATION_LOG_FILE_NAME};
 use bitfun_runtime_ports::{SessionTurnLoadRequest, SessionTurnLoadTiming};
 use bitfun_services_core::{
     json_store::{JsonFileStore, JsonFileStoreError},
@@ -3037,6 +3041,160 @@ impl PersistenceManager {
         .await
         .map(|_| ())
     }
+
+    /// Full-text search over a workspace's standard sessions, most recently
+    /// active first. Sessions whose turns fail to load are skipped.
+    pub async fn search_sessions(
+        &self,
+        workspace_path: &Path,
+        query: &str,
+        limit: usize,
+    ) -> BitFunResult<Vec<SessionSearchHit>> {
+        let mut sessions = self.list_session_metadata(workspace_path).await?;
+        sessions.retain(|metadata| metadata.session_kind == SessionKind::Standard);
+        sessions.sort_by_key(|metadata| std::cmp::Reverse(metadata.last_active_at));
+
+        let mut hits = Vec::new();
+        for metadata in sessions {
+            if hits.len() >= limit {
+                break;
+            }
+            let turns = match self
+                .load_session_turns(workspace_path, &metadata.session_id)
+                .await
+            {
+                Ok(turns) => turns,
+                Err(error) => {
+                    warn!(
+                        "Skipping session in history search: session_id={}, error={}",
+                        metadata.session_id, error
+                    );
+                    Vec::new()
+                }
+            };
+            hits.extend(search_session_history(
+                &metadata,
+                &turns,
+                query,
+                limit - hits.len(),
+            ));
+        }
+        Ok(hits)
+    }
+
+    /// Renders a whole session as Markdown or JSON for the user to save.
+    pub async fn export_session(
+        &self,
+        workspace_path: &Path,
+        session_id: &str,
+        format: SessionExportFormat,
+    ) -> BitFunResult<String> {
+        Self::validate_session_id(session_id)?;
+        let metadata = self
+            .load_session_metadata(workspace_path, session_id)
+            .await?
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!("Session metadata not found: {}", session_id))
+            })?;
+        let turns = self.load_session_turns(workspace_path, session_id).await?;
+        Ok(render_session_export(&metadata, &turns, format)?)
+    }
+
+    /// Renders a session, or one of its turns, as a shareable Markdown report.
+    pub async fn render_session_report(
+        &self,
+        workspace_path: &Path,
+        session_id: &str,
+        artifacts: &[SessionReportArtifact],
+        options: &SessionReportOptions,
+    ) -> BitFunResult<RenderedSessionReport> {
+        Self::validate_session_id(session_id)?;
+        let metadata = self
+            .load_session_metadata(workspace_path, session_id)
+            .await?
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!("Session metadata not found: {}", session_id))
+            })?;
+        let turns = self.load_session_turns(workspace_path, session_id).await?;
+        if let Some(turn_index) = options.turn_index {
+            if !turns.iter().any(|turn| turn.turn_index == turn_index) {
+                return Err(BitFunError::NotFound(format!(
+                    "Dialog turn {} not found in session {}",
+                    turn_index, session_id
+                )));
+            }
+        }
+        Ok(render_session_report(&metadata, &turns, artifacts, options))
+    }
+
+    /// Collects the session's recorded tool invocations into a replay bundle.
+    /// A session that never ran a tool yields an empty bundle.
+    pub async fn export_tool_replay_bundle(
+        &self,
+        workspace_path: &Path,
+        session_id: &str,
+    ) -> BitFunResult<ToolReplayBundle> {
+        Self::validate_session_id(session_id)?;
+        let log_path = self
+            .session_layout(workspace_path)
+            .session_dir(session_id)
+            .join(TOOL_INVOCATION_LOG_FILE_NAME);
+        let log = match fs::read_to_string(&log_path).await {
+            Ok(log) => log,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(BitFunError::io(format!(
+                    "Failed to read tool invocation log for session {}: {}",
+                    session_id, error
+                )))
+            }
+        };
+        let (bundle, skipped) = ToolReplayBundle::from_invocation_log(
+            session_id,
+            Self::system_time_to_unix_ms(SystemTime::now()),
+            &log,
+        );
+        if skipped > 0 {
+            warn!(
+                "Skipped unreadable tool invocation records: session_id={}, skipped={}",
+                session_id, skipped
+            );
+        }
+        Ok(bundle)
+    }
+
+    /// Archives idle sessions and deletes old archived ones per `policy`.
+    pub async fn apply_session_retention(
+        &self,
+        workspace_path: &Path,
+        policy: &SessionRetentionPolicy,
+    ) -> BitFunResult<SessionRetentionPlan> {
+        if !policy.is_enabled() {
+            return Ok(SessionRetentionPlan::default());
+        }
+        let sessions = self.list_session_metadata(workspace_path).await?;
+        let now_ms = Self::system_time_to_unix_ms(SystemTime::now());
+        let plan = plan_session_retention(&sessions, policy, now_ms);
+
+        for session_id in &plan.archive {
+            self.update_session_metadata(workspace_path, session_id, |metadata| {
+                metadata.status = SessionStatus::Archived;
+            })
+            .await?;
+        }
+        for session_id in &plan.delete {
+            self.delete_session(workspace_path, session_id).await?;
+        }
+        if !plan.archive.is_empty() || !plan.delete.is_empty() {
+            info!(
+                "Session retention applied: workspace={}, archived={}, deleted={}",
+                workspace_path.display(),
+                plan.archive.len(),
+                plan.delete.len()
+            );
+        }
+        Ok(plan)
+    }
 }
 
 #[cfg(test)]
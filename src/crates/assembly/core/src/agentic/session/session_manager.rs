@@ -24,9 +24,10 @@ use crate::agentic::session::{
     UserContextCacheIdentity,
 };
 use crate::agentic::skill_agent_snapshot::TurnSkillAgentSnapshot;
+use crate::agentic::tools::implementations::skills::types::SkillModelPreference;
 use crate::agentic::workspace::WorkspaceBinding;
 use crate::agentic::ConversationCoordinator;
-use crate::infrastructure::ai::get_global_ai_client_factory;
+use crate::infrastructure::ai::{get_global_ai_client_factory, RequestPriority, ResponseCacheMode};
 use crate::service::config::{
     get_app_language_code, get_global_config_service, short_model_user_language_instruction,
     subscribe_config_updates, ConfigUpdateEvent,
@@ -199,6 +200,9 @@ pub struct SessionManager {
     token_anchor_store: Arc<TokenAnchorStore>,
     turn_skill_agent_snapshot_store: Arc<TurnSkillAgentSnapshotStore>,
     skill_agent_baseline_override_snapshot_store: Arc<DashMap<String, TurnSkillAgentSnapshot>>,
+    /// Model preference of the skill most recently loaded in each session.
+    /// Process-local: a restored session starts on its own model again.
+    skill_model_preference_store: Arc<DashMap<String, SkillModelPreference>>,
     /// Session-scoped edit-constraint state. The in-memory copy serves the hot
     /// tool-validation path; the same state is persisted in session metadata so
     /// restore and fork paths preserve both constraints and extraction evidence.
@@ -220,6 +224,7 @@ fn clear_session_runtime_stores(
     token_anchor_store: &TokenAnchorStore,
     turn_skill_agent_snapshot_store: &TurnSkillAgentSnapshotStore,
     skill_agent_baseline_override_snapshot_store: &DashMap<String, TurnSkillAgentSnapshot>,
+    skill_model_preference_store: &DashMap<String, SkillModelPreference>,
     file_read_state_store: &FileReadStateStore,
     evidence_ledger: &SessionEvidenceLedger,
 ) {
@@ -228,6 +233,7 @@ fn clear_session_runtime_stores(
     token_anchor_store.delete_session(session_id);
     turn_skill_agent_snapshot_store.delete_session(session_id);
     skill_agent_baseline_override_snapshot_store.remove(session_id);
+    skill_model_preference_store.remove(session_id);
     file_read_state_store.delete_session(session_id);
     evidence_ledger.delete_session(session_id);
 }
@@ -1727,6 +1733,7 @@ impl SessionManager {
             token_anchor_store: Arc::new(TokenAnchorStore::new()),
             turn_skill_agent_snapshot_store: Arc::new(TurnSkillAgentSnapshotStore::new()),
             skill_agent_baseline_override_snapshot_store: Arc::new(DashMap::new()),
+            skill_model_preference_store: Arc::new(DashMap::new()),
             edit_constraints_store: Arc::new(DashMap::new()),
             file_read_state_store: Arc::new(FileReadStateStore::new()),
             evidence_ledger: Arc::new(SessionEvidenceLedger::new()),
@@ -1935,6 +1942,7 @@ impl SessionManager {
         let turn_skill_agent_snapshot_store = self.turn_skill_agent_snapshot_store.clone();
         let skill_agent_baseline_override_snapshot_store =
             self.skill_agent_baseline_override_snapshot_store.clone();
+        let skill_model_preference_store = self.skill_model_preference_store.clone();
         let edit_constraints_store = self.edit_constraints_store.clone();
         let file_read_state_store = self.file_read_state_store.clone();
         let evidence_ledger = self.evidence_ledger.clone();
@@ -1965,6 +1973,7 @@ impl SessionManager {
                 token_anchor_store,
                 turn_skill_agent_snapshot_store,
                 skill_agent_baseline_override_snapshot_store,
+                skill_model_preference_store,
                 edit_constraints_store,
                 file_read_state_store,
                 evidence_ledger,
@@ -2746,6 +2755,30 @@ impl SessionManager {
             .map(|value| value.clone())
     }
 
+    /// Records the model preference of a skill just loaded in the session, or
+    /// clears it when the skill has none, so the newest skill decides.
+    pub fn set_skill_model_preference(
+        &self,
+        session_id: &str,
+        preference: Option<SkillModelPreference>,
+    ) {
+        match preference {
+            Some(preference) => {
+                self.skill_model_preference_store
+                    .insert(session_id.to_string(), preference);
+            }
+            None => {
+                self.skill_model_preference_store.remove(session_id);
+            }
+        }
+    }
+
+    pub fn skill_model_preference(&self, session_id: &str) -> Option<SkillModelPreference> {
+        self.skill_model_preference_store
+            .get(session_id)
+            .map(|value| value.clone())
+    }
+
     fn edit_constraint_state_from_metadata(
         metadata: Option<&SessionMetadata>,
     ) -> Option<crate::agentic::execution::edit_constraint_guard::EditConstraintState> {
@@ -3496,6 +3529,20 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Lowers the session's context window to `budget` for the next run.
+    /// Call after `refresh_session_context_window`, which resets it.
+    pub fn cap_session_context_window(&self, session_id: &str, budget: usize) {
+        if let Some(mut session) = self.sessions.get_mut(session_id) {
+            if budget < session.config.max_context_tokens {
+                debug!(
+                    "Capped session context window: session_id={}, previous={}, budget={}",
+                    session_id, session.config.max_context_tokens, budget
+                );
+                session.config.max_context_tokens = budget;
+            }
+        }
+    }
+
     /// Update session activity time
     pub fn touch_session(&self, session_id: &str) {
         if let Some(mut session) = self.sessions.get_mut(session_id) {
@@ -3841,6 +3888,7 @@ impl SessionManager {
             self.token_anchor_store.as_ref(),
             self.turn_skill_agent_snapshot_store.as_ref(),
             self.skill_agent_baseline_override_snapshot_store.as_ref(),
+            self.skill_model_preference_store.as_ref(),
             self.file_read_state_store.as_ref(),
             self.evidence_ledger.as_ref(),
         );
@@ -3882,6 +3930,7 @@ impl SessionManager {
             self.token_anchor_store.as_ref(),
             self.turn_skill_agent_snapshot_store.as_ref(),
             self.skill_agent_baseline_override_snapshot_store.as_ref(),
+            self.skill_model_preference_store.as_ref(),
             self.file_read_state_store.as_ref(),
             self.evidence_ledger.as_ref(),
         );
@@ -3905,6 +3954,12 @@ impl SessionManager {
             }
         }
 
+        // Killing the process closes its CDP sockets; the browser session
+        // registry prunes those entries on next use.
+        crate::agentic::tools::browser_control::headless_browser_pool()
+            .shutdown(session_id)
+            .await;
+
         if let Some(error) = required_error {
             return Err(error);
         }
@@ -4812,6 +4867,7 @@ impl SessionManager {
                 self.token_anchor_store.as_ref(),
                 self.turn_skill_agent_snapshot_store.as_ref(),
                 self.skill_agent_baseline_override_snapshot_store.as_ref(),
+                self.skill_model_preference_store.as_ref(),
                 self.file_read_state_store.as_ref(),
                 self.evidence_ledger.as_ref(),
             );
@@ -6513,10 +6569,11 @@ impl SessionManager {
         let ai_client = ai_client_factory
             .get_client_by_func_agent("session-title-func-agent")
             .await
-            .map_err(|e| BitFunError::AIClient(format!("Failed to get AI client: {}", e)))?;
+            .map_err(|e| BitFunError::AIClient(format!("Failed to get AI client: {}", e)))?
+            .with_request_priority(RequestPriority::Background);
 
         let response = ai_client
-            .send_message(messages, None)
+            .send_message_cached(messages, None, ResponseCacheMode::Reuse)
             .await
             .map_err(|e| BitFunError::ai(format!("AI call failed: {}", e)))?;
 
@@ -6648,6 +6705,7 @@ impl SessionManager {
         let turn_skill_agent_snapshot_store = self.turn_skill_agent_snapshot_store.clone();
         let skill_agent_baseline_override_snapshot_store =
             self.skill_agent_baseline_override_snapshot_store.clone();
+        let skill_model_preference_store = self.skill_model_preference_store.clone();
         let edit_constraints_store = self.edit_constraints_store.clone();
         let file_read_state_store = self.file_read_state_store.clone();
         let evidence_ledger = self.evidence_ledger.clone();
@@ -6729,6 +6787,7 @@ impl SessionManager {
                             token_anchor_store.as_ref(),
                             turn_skill_agent_snapshot_store.as_ref(),
                             skill_agent_baseline_override_snapshot_store.as_ref(),
+                            skill_model_preference_store.as_ref(),
                             file_read_state_store.as_ref(),
                             evidence_ledger.as_ref(),
                         );
@@ -0,0 +1,16 @@
+//! Agent task orchestration.
+//!
+//! A persistent, prioritized queue of agent prompts that can be paused,
+//! resumed, retried and cancelled, layered on top of the dialog scheduler.
+
+mod service;
+mod store;
+mod subscriber;
+mod types;
+
+pub use service::{get_global_agent_task_service, set_global_agent_task_service, AgentTaskService};
+pub use subscriber::AgentTaskEventSubscriber;
+pub use types::{
+    AgentTask, AgentTaskEvent, AgentTaskLifecycle, AgentTaskPriority, AgentTaskStatus,
+    AgentTasksFile, EnqueueAgentTaskRequest, AGENT_TASKS_VERSION, DEFAULT_MAX_ATTEMPTS,
+};
@@ -0,0 +1,547 @@
+//! Agent task orchestration service.
+//!
+//! Tasks wait in this queue until their session is idle, then are handed to the
+//! `DialogScheduler` one at a time per session. Turn lifecycle events drive the
+//! task state machine, and every change is persisted so an app restart picks up
+//! where it left off.
+
+use super::store::AgentTaskStore;
+use super::types::{
+    AgentTask, AgentTaskEvent, AgentTaskLifecycle, AgentTaskStatus, EnqueueAgentTaskRequest,
+    DEFAULT_MAX_ATTEMPTS,
+};
+use crate::agentic::coordination::{DialogScheduler, DialogSubmissionPolicy, DialogTriggerSource};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{broadcast, Mutex, Notify, RwLock};
+use tokio::time::Duration;
+use uuid::Uuid;
+
+static GLOBAL_AGENT_TASK_SERVICE: OnceLock<Arc<AgentTaskService>> = OnceLock::new();
+
+/// Upper bound accepted for `EnqueueAgentTaskRequest::max_attempts`.
+const MAX_ATTEMPTS_LIMIT: u32 = 10;
+
+/// Sessions can become idle without a turn event we observe (e.g. a turn that
+/// was never started), so queued tasks are also re-checked periodically.
+const DISPATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+const EVENT_CHANNEL_CAPACITY: usize = 128;
+
+pub struct AgentTaskService {
+    scheduler: Arc<DialogScheduler>,
+    store: Arc<AgentTaskStore>,
+    tasks: Arc<RwLock<HashMap<String, AgentTask>>>,
+    mutation_lock: Arc<Mutex<()>>,
+    wakeup: Arc<Notify>,
+    events: broadcast::Sender<AgentTaskEvent>,
+    runner_started: AtomicBool,
+}
+
+impl AgentTaskService {
+    pub async fn new(
+        path_manager: Arc<PathManager>,
+        scheduler: Arc<DialogScheduler>,
+    ) -> BitFunResult<Arc<Self>> {
+        let store = Arc::new(AgentTaskStore::new(path_manager).await?);
+        let loaded = store.load().await?;
+        let current_ms = now_ms();
+
+        let mut tasks = HashMap::new();
+        let mut needs_save = false;
+        for mut task in loaded.tasks {
+            if task.reconcile_after_restart(current_ms) {
+                info!(
+                    "Re-queued agent task interrupted by restart: task_id={}, session_id={}",
+                    task.id, task.session_id
+                );
+                needs_save = true;
+            }
+            tasks.insert(task.id.clone(), task);
+        }
+
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let service = Arc::new(Self {
+            scheduler,
+            store,
+            tasks: Arc::new(RwLock::new(tasks)),
+            mutation_lock: Arc::new(Mutex::new(())),
+            wakeup: Arc::new(Notify::new()),
+            events,
+            runner_started: AtomicBool::new(false),
+        });
+
+        if needs_save {
+            let tasks = service.tasks.read().await;
+            service.persist_tasks_locked(&tasks).await?;
+        }
+
+        Ok(service)
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self
+            .runner_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            service.run_loop().await;
+        });
+    }
+
+    /// Subscribes to task lifecycle events.
+    pub fn subscribe(&self) -> broadcast::Receiver<AgentTaskEvent> {
+        self.events.subscribe()
+    }
+
+    /// Lists tasks in dispatch order, optionally restricted to one session.
+    pub async fn list_tasks(&self, session_id: Option<&str>) -> Vec<AgentTask> {
+        let tasks = self.tasks.read().await;
+        let mut tasks = tasks
+            .values()
+            .filter(|task| session_id.is_none_or(|session_id| task.session_id == session_id))
+            .cloned()
+            .collect::<Vec<_>>();
+        tasks.sort_by(AgentTask::dispatch_order);
+        tasks
+    }
+
+    pub async fn get_task(&self, task_id: &str) -> Option<AgentTask> {
+        self.tasks.read().await.get(task_id).cloned()
+    }
+
+    pub async fn enqueue_task(&self, request: EnqueueAgentTaskRequest) -> BitFunResult<AgentTask> {
+        let session_id = request.session_id.trim().to_string();
+        if session_id.is_empty() {
+            return Err(BitFunError::validation("Task session id must not be empty"));
+        }
+        if request.prompt.trim().is_empty() {
+            return Err(BitFunError::validation("Task prompt must not be empty"));
+        }
+        let max_attempts = request.max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS);
+        if !(1..=MAX_ATTEMPTS_LIMIT).contains(&max_attempts) {
+            return Err(BitFunError::validation(format!(
+                "Task max attempts must be between 1 and {}",
+                MAX_ATTEMPTS_LIMIT
+            )));
+        }
+
+        let _guard = self.mutation_lock.lock().await;
+        let mut tasks = self.tasks.write().await;
+        let current_ms = now_ms();
+        let task = AgentTask {
+            id: generate_task_id(&tasks),
+            session_id,
+            title: request
+                .title
+                .map(|title| title.trim().to_string())
+                .filter(|title| !title.is_empty()),
+            prompt: request.prompt,
+            agent_type: request.agent_type,
+            workspace_path: request.workspace_path,
+            remote_connection_id: request.remote_connection_id,
+            remote_ssh_host: request.remote_ssh_host,
+            priority: request.priority,
+            status: AgentTaskStatus::Queued,
+            attempts: 0,
+            max_attempts,
+            active_turn_id: None,
+            last_error: None,
+            created_at_ms: current_ms,
+            updated_at_ms: current_ms,
+            started_at_ms: None,
+            finished_at_ms: None,
+        };
+        tasks.insert(task.id.clone(), task.clone());
+        self.persist_tasks_locked(&tasks).await?;
+        drop(tasks);
+
+        debug!(
+            "Agent task enqueued: task_id={}, session_id={}, priority={:?}",
+            task.id, task.session_id, task.priority
+        );
+        self.emit(AgentTaskLifecycle::Enqueued, &task);
+        self.wakeup.notify_one();
+        Ok(task)
+    }
+
+    /// Pauses a queued or running task. A running task's turn is cancelled and
+    /// the task starts over when resumed.
+    pub async fn pause_task(&self, task_id: &str) -> BitFunResult<AgentTask> {
+        let (task, interrupted_turn) = self
+            .transition(task_id, AgentTask::can_pause, "paused", |task, now_ms| {
+                task.mark_paused(now_ms)
+            })
+            .await?;
+        self.cancel_interrupted_turn(&task, interrupted_turn).await;
+        self.emit(AgentTaskLifecycle::Paused, &task);
+        Ok(task)
+    }
+
+    pub async fn resume_task(&self, task_id: &str) -> BitFunResult<AgentTask> {
+        let (task, _) = self
+            .transition(task_id, AgentTask::can_resume, "resumed", |task, now_ms| {
+                task.mark_requeued(false, now_ms)
+            })
+            .await?;
+        self.emit(AgentTaskLifecycle::Resumed, &task);
+        self.wakeup.notify_one();
+        Ok(task)
+    }
+
+    /// Re-queues a failed or cancelled task with a fresh attempt budget.
+    pub async fn retry_task(&self, task_id: &str) -> BitFunResult<AgentTask> {
+        let (task, _) = self
+            .transition(task_id, AgentTask::can_retry, "retried", |task, now_ms| {
+                task.mark_requeued(true, now_ms)
+            })
+            .await?;
+        self.emit(AgentTaskLifecycle::Retried, &task);
+        self.wakeup.notify_one();
+        Ok(task)
+    }
+
+    pub async fn cancel_task(&self, task_id: &str) -> BitFunResult<AgentTask> {
+        let (task, interrupted_turn) = self
+            .transition(
+                task_id,
+                AgentTask::can_cancel,
+                "cancelled",
+                |task, now_ms| task.mark_cancelled(now_ms),
+            )
+            .await?;
+        self.cancel_interrupted_turn(&task, interrupted_turn).await;
+        self.emit(AgentTaskLifecycle::Cancelled, &task);
+        Ok(task)
+    }
+
+    pub async fn handle_turn_completed(&self, turn_id: &str) -> BitFunResult<()> {
+        self.handle_turn_state_change(turn_id, |task, now_ms| {
+            task.mark_completed(now_ms);
+            AgentTaskLifecycle::Completed
+        })
+        .await
+    }
+
+    pub async fn handle_turn_failed(&self, turn_id: &str, error: &str) -> BitFunResult<()> {
+        self.handle_turn_state_change(turn_id, |task, now_ms| {
+            task.mark_failed(error.to_string(), now_ms);
+            failure_lifecycle(task)
+        })
+        .await
+    }
+
+    pub async fn handle_turn_cancelled(&self, turn_id: &str) -> BitFunResult<()> {
+        self.handle_turn_state_change(turn_id, |task, now_ms| {
+            task.mark_cancelled(now_ms);
+            AgentTaskLifecycle::Cancelled
+        })
+        .await
+    }
+
+    /// Applies a user-requested transition. Returns the updated task and the
+    /// turn that was active before the transition, if any.
+    async fn transition<G, F>(
+        &self,
+        task_id: &str,
+        allowed: G,
+        action: &str,
+        update: F,
+    ) -> BitFunResult<(AgentTask, Option<String>)>
+    where
+        G: FnOnce(&AgentTask) -> bool,
+        F: FnOnce(&mut AgentTask, i64),
+    {
+        let _guard = self.mutation_lock.lock().await;
+        let mut tasks = self.tasks.write().await;
+        let task = tasks
+            .get_mut(task_id)
+            .ok_or_else(|| BitFunError::NotFound(format!("Agent task not found: {}", task_id)))?;
+        if !allowed(task) {
+            return Err(BitFunError::validation(format!(
+                "Agent task {} cannot be {} while {}",
+                task_id,
+                action,
+                task.status.as_str()
+            )));
+        }
+
+        let interrupted_turn = task.active_turn_id.clone();
+        update(task, now_ms());
+        let updated = task.clone();
+        self.persist_tasks_locked(&tasks).await?;
+        Ok((updated, interrupted_turn))
+    }
+
+    /// Cancels the dialog turn of a task that was paused or cancelled while
+    /// running. Called after the task lock is released, because cancellation
+    /// emits a turn event that is routed back to this service.
+    async fn cancel_interrupted_turn(&self, task: &AgentTask, turn_id: Option<String>) {
+        let Some(turn_id) = turn_id else {
+            return;
+        };
+        if let Err(error) = self
+            .scheduler
+            .cancel_queued_or_active_turn(&task.session_id, &turn_id)
+            .await
+        {
+            warn!(
+                "Failed to cancel agent task turn: task_id={}, turn_id={}, error={}",
+                task.id, turn_id, error
+            );
+        }
+    }
+
+    async fn handle_turn_state_change<F>(&self, turn_id: &str, update: F) -> BitFunResult<()>
+    where
+        F: FnOnce(&mut AgentTask, i64) -> AgentTaskLifecycle,
+    {
+        let updated = {
+            let _guard = self.mutation_lock.lock().await;
+            let mut tasks = self.tasks.write().await;
+            match tasks
+                .values_mut()
+                .find(|task| task.active_turn_id.as_deref() == Some(turn_id))
+            {
+                Some(task) => {
+                    let lifecycle = update(task, now_ms());
+                    let updated = task.clone();
+                    self.persist_tasks_locked(&tasks).await?;
+                    Some((lifecycle, updated))
+                }
+                None => None,
+            }
+        };
+
+        if let Some((lifecycle, task)) = updated {
+            self.emit(lifecycle, &task);
+        }
+        // Any finished turn may free a session that queued tasks are waiting on.
+        self.wakeup.notify_one();
+        Ok(())
+    }
+
+    async fn run_loop(self: Arc<Self>) {
+        info!("Agent task service loop started");
+
+        loop {
+            if let Err(error) = self.dispatch_ready_tasks().await {
+                warn!("Failed to dispatch agent tasks: {}", error);
+            }
+
+            tokio::select! {
+                _ = self.wakeup.notified() => {}
+                _ = tokio::time::sleep(DISPATCH_POLL_INTERVAL) => {}
+            }
+        }
+    }
+
+    async fn dispatch_ready_tasks(&self) -> BitFunResult<()> {
+        let dispatched = {
+            let _guard = self.mutation_lock.lock().await;
+            let mut tasks = self.tasks.write().await;
+            let ready = select_ready_tasks(tasks.values(), |session_id| {
+                self.scheduler.is_session_busy_or_queued(session_id)
+            });
+            if ready.is_empty() {
+                return Ok(());
+            }
+
+            let current_ms = now_ms();
+            let mut dispatched = Vec::with_capacity(ready.len());
+            for task_id in ready {
+                if let Some(task) = tasks.get_mut(&task_id) {
+                    let turn_id = format!("agenttask_{}_{}", task.id, task.attempts + 1);
+                    task.mark_dispatched(turn_id, current_ms);
+                    dispatched.push(task.clone());
+                }
+            }
+            self.persist_tasks_locked(&tasks).await?;
+            dispatched
+        };
+
+        for task in dispatched {
+            self.emit(AgentTaskLifecycle::Started, &task);
+            if let Err(error) = self.submit_task(&task).await {
+                warn!(
+                    "Failed to submit agent task: task_id={}, session_id={}, error={}",
+                    task.id, task.session_id, error
+                );
+                if let Some(turn_id) = task.active_turn_id.as_deref() {
+                    self.handle_turn_failed(turn_id, &error).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn submit_task(&self, task: &AgentTask) -> Result<(), String> {
+        self.scheduler
+            .submit(
+                task.session_id.clone(),
+                task.prompt.clone(),
+                Some(task.prompt.clone()),
+                task.active_turn_id.clone(),
+                task.agent_type.clone(),
+                task.workspace_path.clone(),
+                task.remote_connection_id.clone(),
+                task.remote_ssh_host.clone(),
+                DialogSubmissionPolicy::new(
+                    DialogTriggerSource::DesktopApi,
+                    task.priority.queue_priority(),
+                ),
+                None,
+                None,
+                None,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    fn emit(&self, lifecycle: AgentTaskLifecycle, task: &AgentTask) {
+        // No receivers is fine; the frontend subscribes lazily.
+        let _ = self.events.send(AgentTaskEvent {
+            lifecycle,
+            task: task.clone(),
+        });
+    }
+
+    async fn persist_tasks_locked(&self, tasks: &HashMap<String, AgentTask>) -> BitFunResult<()> {
+        self.store
+            .save_tasks(tasks.values().cloned().collect::<Vec<_>>())
+            .await
+    }
+}
+
+pub fn get_global_agent_task_service() -> Option<Arc<AgentTaskService>> {
+    GLOBAL_AGENT_TASK_SERVICE.get().cloned()
+}
+
+pub fn set_global_agent_task_service(service: Arc<AgentTaskService>) {
+    let _ = GLOBAL_AGENT_TASK_SERVICE.set(service);
+}
+
+/// Picks at most one queued task per session whose session has no running task
+/// and is not busy with other turns, in dispatch order.
+fn select_ready_tasks<'a, I, B>(tasks: I, is_session_busy: B) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a AgentTask>,
+    B: Fn(&str) -> bool,
+{
+    let mut queued = Vec::new();
+    let mut claimed_sessions = HashSet::new();
+    for task in tasks {
+        match task.status {
+            AgentTaskStatus::Running => {
+                claimed_sessions.insert(task.session_id.clone());
+            }
+            AgentTaskStatus::Queued => queued.push(task),
+            _ => {}
+        }
+    }
+    queued.sort_by(|left, right| left.dispatch_order(right));
+
+    queued
+        .into_iter()
+        .filter(|task| {
+            !claimed_sessions.contains(&task.session_id)
+                && !is_session_busy(&task.session_id)
+                && claimed_sessions.insert(task.session_id.clone())
+        })
+        .map(|task| task.id.clone())
+        .collect()
+}
+
+fn failure_lifecycle(task: &AgentTask) -> AgentTaskLifecycle {
+    if task.status == AgentTaskStatus::Failed {
+        AgentTaskLifecycle::Failed
+    } else {
+        AgentTaskLifecycle::Retried
+    }
+}
+
+fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+fn generate_task_id(tasks: &HashMap<String, AgentTask>) -> String {
+    loop {
+        let uuid = Uuid::new_v4().simple().to_string();
+        let id = format!("task_{}", &uuid[..8]);
+        if !tasks.contains_key(&id) {
+            return id;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agentic::task_queue::types::AgentTaskPriority;
+
+    fn task(id: &str, session_id: &str, priority: AgentTaskPriority) -> AgentTask {
+        AgentTask {
+            id: id.to_string(),
+            session_id: session_id.to_string(),
+            title: None,
+            prompt: "do it".to_string(),
+            agent_type: "agentic".to_string(),
+            workspace_path: None,
+            remote_connection_id: None,
+            remote_ssh_host: None,
+            priority,
+            status: AgentTaskStatus::Queued,
+            attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            active_turn_id: None,
+            last_error: None,
+            created_at_ms: 0,
+            updated_at_ms: 0,
+            started_at_ms: None,
+            finished_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn selects_highest_priority_task_per_idle_session() {
+        let tasks = [
+            task("a", "s1", AgentTaskPriority::Normal),
+            task("b", "s1", AgentTaskPriority::High),
+            task("c", "s2", AgentTaskPriority::Low),
+            task("d", "s3", AgentTaskPriority::High),
+        ];
+
+        let ready = select_ready_tasks(tasks.iter(), |session_id| session_id == "s3");
+
+        assert_eq!(ready, ["b", "c"]);
+    }
+
+    #[test]
+    fn skips_sessions_with_a_running_task_and_non_queued_tasks() {
+        let mut running = task("a", "s1", AgentTaskPriority::Low);
+        running.status = AgentTaskStatus::Running;
+        let mut paused = task("c", "s2", AgentTaskPriority::High);
+        paused.status = AgentTaskStatus::Paused;
+        let tasks = [
+            running,
+            task("b", "s1", AgentTaskPriority::High),
+            paused,
+            task("d", "s2", AgentTaskPriority::Low),
+        ];
+
+        let ready = select_ready_tasks(tasks.iter(), |_| false);
+
+        assert_eq!(ready, ["d"]);
+    }
+}
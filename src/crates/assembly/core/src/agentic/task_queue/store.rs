@@ -0,0 +1,126 @@
+//! tasks.json persistence wrapper.
+
+use super::types::{AgentTask, AgentTasksFile, AGENT_TASKS_VERSION};
+use crate::infrastructure::storage::{PersistenceService, StorageOptions};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+
+pub(super) struct AgentTaskStore {
+    persistence: PersistenceService,
+    path_manager: Arc<PathManager>,
+}
+
+impl AgentTaskStore {
+    pub(super) async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Self> {
+        let tasks_dir = path_manager.user_agent_tasks_dir();
+        path_manager.ensure_dir(&tasks_dir).await?;
+
+        let persistence = PersistenceService::new(tasks_dir).await?;
+
+        Ok(Self {
+            persistence,
+            path_manager,
+        })
+    }
+
+    fn tasks_file_path(&self) -> PathBuf {
+        self.path_manager.agent_tasks_file()
+    }
+
+    pub(super) async fn load(&self) -> BitFunResult<AgentTasksFile> {
+        let tasks_file_path = self.tasks_file_path();
+        if !tasks_file_path.exists() {
+            return Ok(AgentTasksFile::default());
+        }
+
+        let content = fs::read_to_string(&tasks_file_path)
+            .await
+            .map_err(|error| BitFunError::service(format!("Failed to read file: {}", error)))?;
+
+        match parse_tasks_file_content(&content, &tasks_file_path) {
+            Ok(file) => Ok(file),
+            Err(error) => {
+                warn!(
+                    "Failed to load agent tasks file; backing up and resetting to empty state: path={}, error={}",
+                    tasks_file_path.display(),
+                    error
+                );
+                self.backup_incompatible_tasks_file(&tasks_file_path)
+                    .await?;
+                self.save_tasks(Vec::new()).await?;
+                Ok(AgentTasksFile::default())
+            }
+        }
+    }
+
+    pub(super) async fn save_tasks(&self, tasks: Vec<AgentTask>) -> BitFunResult<()> {
+        let mut tasks = tasks;
+        tasks.sort_by(|left, right| {
+            left.created_at_ms
+                .cmp(&right.created_at_ms)
+                .then_with(|| left.id.cmp(&right.id))
+        });
+
+        let data = AgentTasksFile {
+            version: AGENT_TASKS_VERSION,
+            tasks,
+        };
+
+        self.persistence
+            .save_json("tasks", &data, StorageOptions::default())
+            .await
+    }
+
+    async fn backup_incompatible_tasks_file(&self, tasks_file_path: &Path) -> BitFunResult<()> {
+        let backup_path = incompatible_backup_path(tasks_file_path);
+        fs::rename(tasks_file_path, &backup_path)
+            .await
+            .map_err(|error| {
+                BitFunError::service(format!(
+                    "Failed to back up incompatible agent tasks file {} to {}: {}",
+                    tasks_file_path.display(),
+                    backup_path.display(),
+                    error
+                ))
+            })?;
+        info!(
+            "Backed up incompatible agent tasks file: source={}, backup={}",
+            tasks_file_path.display(),
+            backup_path.display()
+        );
+        Ok(())
+    }
+}
+
+fn incompatible_backup_path(tasks_file_path: &Path) -> PathBuf {
+    let timestamp_ms = chrono::Utc::now().timestamp_millis();
+    let file_name = tasks_file_path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .unwrap_or("tasks.json");
+    tasks_file_path.with_file_name(format!("{}.incompatible.{}.bak", file_name, timestamp_ms))
+}
+
+fn parse_tasks_file_content(content: &str, tasks_file_path: &Path) -> BitFunResult<AgentTasksFile> {
+    let file: AgentTasksFile = serde_json::from_str(content).map_err(|error| {
+        BitFunError::service(format!(
+            "Failed to parse agent tasks file {}: {}",
+            tasks_file_path.display(),
+            error
+        ))
+    })?;
+
+    if file.version != AGENT_TASKS_VERSION {
+        return Err(BitFunError::service(format!(
+            "Unsupported agent tasks file version {} in {}",
+            file.version,
+            tasks_file_path.display()
+        )));
+    }
+
+    Ok(file)
+}
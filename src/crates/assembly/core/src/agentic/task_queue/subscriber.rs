@@ -0,0 +1,41 @@
+//! Agent task event subscriber.
+
+use super::service::AgentTaskService;
+use crate::agentic::events::{AgenticEvent, EventSubscriber};
+use bitfun_agent_runtime::event_bus::{EventBusError, EventSubscriberResult};
+use log::error;
+use std::sync::Arc;
+
+pub struct AgentTaskEventSubscriber {
+    task_service: Arc<AgentTaskService>,
+}
+
+impl AgentTaskEventSubscriber {
+    pub fn new(task_service: Arc<AgentTaskService>) -> Self {
+        Self { task_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSubscriber for AgentTaskEventSubscriber {
+    async fn on_event(&self, event: &AgenticEvent) -> EventSubscriberResult {
+        let result = match event {
+            AgenticEvent::DialogTurnCompleted { turn_id, .. } => {
+                self.task_service.handle_turn_completed(turn_id).await
+            }
+            AgenticEvent::DialogTurnFailed { turn_id, error, .. } => {
+                self.task_service.handle_turn_failed(turn_id, error).await
+            }
+            AgenticEvent::DialogTurnCancelled { turn_id, .. } => {
+                self.task_service.handle_turn_cancelled(turn_id).await
+            }
+            _ => Ok(()),
+        };
+
+        if let Err(error) = &result {
+            error!("Failed to update agent task state from event: {}", error);
+        }
+
+        result.map_err(EventBusError::subscriber)
+    }
+}
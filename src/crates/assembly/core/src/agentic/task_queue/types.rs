@@ -0,0 +1,327 @@
+//! Agent task data types.
+
+use crate::agentic::coordination::DialogQueuePriority;
+use serde::{Deserialize, Serialize};
+
+pub const AGENT_TASKS_VERSION: u32 = 1;
+
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentTasksFile {
+    pub version: u32,
+    pub tasks: Vec<AgentTask>,
+}
+
+impl Default for AgentTasksFile {
+    fn default() -> Self {
+        Self {
+            version: AGENT_TASKS_VERSION,
+            tasks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentTaskPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl AgentTaskPriority {
+    pub fn queue_priority(self) -> DialogQueuePriority {
+        match self {
+            Self::Low => DialogQueuePriority::Low,
+            Self::Normal => DialogQueuePriority::Normal,
+            Self::High => DialogQueuePriority::High,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentTaskStatus {
+    Queued,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl AgentTaskStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentTask {
+    pub id: String,
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub prompt: String,
+    pub agent_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_connection_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_ssh_host: Option<String>,
+    pub priority: AgentTaskPriority,
+    pub status: AgentTaskStatus,
+    /// Number of times the task has been handed to the dialog scheduler.
+    #[serde(default)]
+    pub attempts: u32,
+    pub max_attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_turn_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at_ms: Option<i64>,
+}
+
+impl AgentTask {
+    /// Dispatch order: higher priority first, then FIFO.
+    pub fn dispatch_order(&self, other: &Self) -> std::cmp::Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.created_at_ms.cmp(&other.created_at_ms))
+            .then_with(|| self.id.cmp(&other.id))
+    }
+
+    pub fn can_pause(&self) -> bool {
+        matches!(
+            self.status,
+            AgentTaskStatus::Queued | AgentTaskStatus::Running
+        )
+    }
+
+    pub fn can_resume(&self) -> bool {
+        self.status == AgentTaskStatus::Paused
+    }
+
+    pub fn can_retry(&self) -> bool {
+        matches!(
+            self.status,
+            AgentTaskStatus::Failed | AgentTaskStatus::Cancelled
+        )
+    }
+
+    pub fn can_cancel(&self) -> bool {
+        !self.status.is_terminal()
+    }
+
+    pub fn mark_dispatched(&mut self, turn_id: String, now_ms: i64) {
+        self.status = AgentTaskStatus::Running;
+        self.attempts = self.attempts.saturating_add(1);
+        self.active_turn_id = Some(turn_id);
+        self.last_error = None;
+        self.started_at_ms = Some(now_ms);
+        self.finished_at_ms = None;
+        self.updated_at_ms = now_ms;
+    }
+
+    pub fn mark_completed(&mut self, now_ms: i64) {
+        self.status = AgentTaskStatus::Completed;
+        self.active_turn_id = None;
+        self.last_error = None;
+        self.finished_at_ms = Some(now_ms);
+        self.updated_at_ms = now_ms;
+    }
+
+    /// Records a failed attempt. The task is re-queued while attempts remain.
+    pub fn mark_failed(&mut self, error: String, now_ms: i64) {
+        self.active_turn_id = None;
+        self.last_error = Some(error);
+        self.updated_at_ms = now_ms;
+        if self.attempts < self.max_attempts {
+            self.status = AgentTaskStatus::Queued;
+        } else {
+            self.status = AgentTaskStatus::Failed;
+            self.finished_at_ms = Some(now_ms);
+        }
+    }
+
+    pub fn mark_cancelled(&mut self, now_ms: i64) {
+        self.status = AgentTaskStatus::Cancelled;
+        self.active_turn_id = None;
+        self.finished_at_ms = Some(now_ms);
+        self.updated_at_ms = now_ms;
+    }
+
+    pub fn mark_paused(&mut self, now_ms: i64) {
+        self.status = AgentTaskStatus::Paused;
+        self.active_turn_id = None;
+        self.updated_at_ms = now_ms;
+    }
+
+    /// Puts a paused, failed or cancelled task back in the queue.
+    pub fn mark_requeued(&mut self, reset_attempts: bool, now_ms: i64) {
+        self.status = AgentTaskStatus::Queued;
+        self.active_turn_id = None;
+        self.finished_at_ms = None;
+        if reset_attempts {
+            self.attempts = 0;
+            self.last_error = None;
+        }
+        self.updated_at_ms = now_ms;
+    }
+
+    /// Reconciles a task loaded from disk. A task that was running when the app
+    /// exited lost its turn, so it goes back to the queue without counting the
+    /// interrupted attempt. Returns whether the task changed.
+    pub fn reconcile_after_restart(&mut self, now_ms: i64) -> bool {
+        if self.status != AgentTaskStatus::Running {
+            return false;
+        }
+        self.status = AgentTaskStatus::Queued;
+        self.active_turn_id = None;
+        self.attempts = self.attempts.saturating_sub(1);
+        self.updated_at_ms = now_ms;
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnqueueAgentTaskRequest {
+    pub session_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub prompt: String,
+    #[serde(default = "default_agent_type")]
+    pub agent_type: String,
+    #[serde(default)]
+    pub workspace_path: Option<String>,
+    #[serde(default)]
+    pub remote_connection_id: Option<String>,
+    #[serde(default)]
+    pub remote_ssh_host: Option<String>,
+    #[serde(default)]
+    pub priority: AgentTaskPriority,
+    #[serde(default)]
+    pub max_attempts: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentTaskLifecycle {
+    Enqueued,
+    Started,
+    Paused,
+    Resumed,
+    Retried,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// Broadcast whenever a task changes state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentTaskEvent {
+    pub lifecycle: AgentTaskLifecycle,
+    pub task: AgentTask,
+}
+
+fn default_agent_type() -> String {
+    "agentic".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, priority: AgentTaskPriority, created_at_ms: i64) -> AgentTask {
+        AgentTask {
+            id: id.to_string(),
+            session_id: "session_1".to_string(),
+            title: None,
+            prompt: "do it".to_string(),
+            agent_type: "agentic".to_string(),
+            workspace_path: None,
+            remote_connection_id: None,
+            remote_ssh_host: None,
+            priority,
+            status: AgentTaskStatus::Queued,
+            attempts: 0,
+            max_attempts: 2,
+            active_turn_id: None,
+            last_error: None,
+            created_at_ms,
+            updated_at_ms: created_at_ms,
+            started_at_ms: None,
+            finished_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn dispatch_order_prefers_priority_then_age() {
+        let mut tasks = vec![
+            task("a", AgentTaskPriority::Normal, 2),
+            task("b", AgentTaskPriority::High, 3),
+            task("c", AgentTaskPriority::Normal, 1),
+            task("d", AgentTaskPriority::Low, 0),
+        ];
+        tasks.sort_by(AgentTask::dispatch_order);
+        let ids: Vec<_> = tasks.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["b", "c", "a", "d"]);
+    }
+
+    #[test]
+    fn failed_attempts_requeue_until_max_attempts() {
+        let mut task = task("a", AgentTaskPriority::Normal, 0);
+        task.mark_dispatched("turn_1".to_string(), 10);
+        task.mark_failed("boom".to_string(), 11);
+        assert_eq!(task.status, AgentTaskStatus::Queued);
+        assert_eq!(task.last_error.as_deref(), Some("boom"));
+
+        task.mark_dispatched("turn_2".to_string(), 12);
+        task.mark_failed("boom again".to_string(), 13);
+        assert_eq!(task.status, AgentTaskStatus::Failed);
+        assert!(task.can_retry());
+
+        task.mark_requeued(true, 14);
+        assert_eq!(task.status, AgentTaskStatus::Queued);
+        assert_eq!(task.attempts, 0);
+        assert!(task.last_error.is_none());
+    }
+
+    #[test]
+    fn running_tasks_are_requeued_after_restart() {
+        let mut task = task("a", AgentTaskPriority::Normal, 0);
+        task.mark_dispatched("turn_1".to_string(), 10);
+        assert!(task.reconcile_after_restart(20));
+        assert_eq!(task.status, AgentTaskStatus::Queued);
+        assert_eq!(task.attempts, 0);
+        assert!(task.active_turn_id.is_none());
+
+        task.mark_paused(21);
+        assert!(!task.reconcile_after_restart(22));
+        assert_eq!(task.status, AgentTaskStatus::Paused);
+    }
+}
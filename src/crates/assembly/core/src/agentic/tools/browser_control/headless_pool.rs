@@ -0,0 +1,223 @@
+//! Per-session headless browsers for `connect { mode: "headless" }`.
+//!
+//! Each agent session gets its own Chromium process, loopback debug port and
+//! throwaway profile, so cookies, storage and open pages never cross
+//! sessions. ControlHub refuses to drive a headless browser from any session
+//! other than the one that launched it.
+//!
+//! The executable comes from the managed runtime (`chromium` component) or
+//! PATH, falling back to an installed Chrome/Chromium/Edge.
+
+use super::browser_launcher::{BrowserKind, BrowserLauncher};
+use crate::infrastructure::app_paths::get_path_manager_arc;
+use crate::service::runtime::{RuntimeManager, RuntimeSource};
+use crate::util::errors::{BitFunError, BitFunResult};
+use bitfun_services_integrations::browser_control::{
+    pick_free_port, HeadlessBrowserProcess, HeadlessLaunchOptions,
+};
+use log::info;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+const HEADLESS_WINDOW_SIZE: (u32, u32) = (1280, 800);
+const HEADLESS_STARTUP_TIMEOUT: Duration = Duration::from_secs(20);
+/// Owner used when a tool call carries no session id.
+const UNSCOPED_OWNER: &str = "unscoped";
+
+static HEADLESS_BROWSERS: OnceLock<Arc<HeadlessBrowserPool>> = OnceLock::new();
+
+pub fn headless_browser_pool() -> Arc<HeadlessBrowserPool> {
+    HEADLESS_BROWSERS
+        .get_or_init(|| Arc::new(HeadlessBrowserPool::default()))
+        .clone()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeadlessBrowserInfo {
+    pub port: u16,
+    pub pid: u32,
+    pub executable: String,
+    pub source: RuntimeSource,
+    /// False when the session's existing browser was reused.
+    pub launched: bool,
+}
+
+struct PooledBrowser {
+    process: HeadlessBrowserProcess,
+    executable: String,
+    source: RuntimeSource,
+}
+
+impl PooledBrowser {
+    fn info(&self, launched: bool) -> HeadlessBrowserInfo {
+        HeadlessBrowserInfo {
+            port: self.process.port(),
+            pid: self.process.pid(),
+            executable: self.executable.clone(),
+            source: self.source,
+            launched,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct HeadlessBrowserPool {
+    browsers: Mutex<HashMap<String, PooledBrowser>>,
+}
+
+impl HeadlessBrowserPool {
+    pub fn owner_key(session_id: Option<&str>) -> String {
+        session_id
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .unwrap_or(UNSCOPED_OWNER)
+            .to_string()
+    }
+
+    /// Returns the owner's running browser, launching one if needed.
+    pub async fn ensure(&self, owner: &str) -> BitFunResult<HeadlessBrowserInfo> {
+        {
+            let mut browsers = self.browsers.lock().await;
+            if let Some(browser) = browsers.get_mut(owner) {
+                if browser.process.is_running() {
+                    return Ok(browser.info(false));
+                }
+                browsers.remove(owner);
+            }
+        }
+
+        // Launch without holding the lock so other sessions are not blocked
+        // for the browser's startup time.
+        let (executable, source) = resolve_headless_executable()?;
+        let port = pick_free_port()?;
+        let process = HeadlessBrowserProcess::launch(HeadlessLaunchOptions {
+            executable: PathBuf::from(&executable),
+            port,
+            user_data_dir: profile_dir(owner),
+            window_size: HEADLESS_WINDOW_SIZE,
+            startup_timeout: HEADLESS_STARTUP_TIMEOUT,
+        })
+        .await?;
+        info!(
+            "Headless browser started: owner={}, port={}, executable={}",
+            owner, port, executable
+        );
+
+        let mut browsers = self.browsers.lock().await;
+        // A concurrent call for the same owner may have won the race; keep
+        // its browser and let this one drop.
+        if let Some(existing) = browsers.get_mut(owner) {
+            if existing.process.is_running() {
+                return Ok(existing.info(false));
+            }
+        }
+        let browser = PooledBrowser {
+            process,
+            executable,
+            source,
+        };
+        let info = browser.info(true);
+        browsers.insert(owner.to_string(), browser);
+        Ok(info)
+    }
+
+    /// The session that owns the headless browser on `port`, if any.
+    pub async fn owner_of_port(&self, port: u16) -> Option<String> {
+        self.browsers
+            .lock()
+            .await
+            .iter()
+            .find(|(_, browser)| browser.process.port() == port)
+            .map(|(owner, _)| owner.clone())
+    }
+
+    /// Stops the owner's browser and deletes its profile. Returns the port
+    /// it was using.
+    pub async fn shutdown(&self, owner: &str) -> Option<u16> {
+        let browser = self.browsers.lock().await.remove(owner)?;
+        let port = browser.process.port();
+        info!("Headless browser stopped: owner={}, port={}", owner, port);
+        Some(port)
+    }
+}
+
+fn resolve_headless_executable() -> BitFunResult<(String, RuntimeSource)> {
+    if let Some(resolved) = RuntimeManager::new()?.resolve_command("chromium") {
+        let path = resolved.resolved_path.unwrap_or(resolved.command);
+        return Ok((path, resolved.source));
+    }
+    [BrowserKind::Chrome, BrowserKind::Chromium, BrowserKind::Edge]
+        .iter()
+        .find(|kind| BrowserLauncher::is_browser_installed(kind))
+        .map(|kind| {
+            (
+                BrowserLauncher::browser_executable(kind),
+                RuntimeSource::System,
+            )
+        })
+        .ok_or_else(|| {
+            BitFunError::tool(
+                "No Chromium-based browser found for headless automation. Install Chrome or Chromium, or add the managed `chromium` runtime component.",
+            )
+        })
+}
+
+/// A fresh directory per launch so a crashed run never leaves state behind
+/// for the next one.
+fn profile_dir(owner: &str) -> PathBuf {
+    let safe_owner: String = owner
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    get_path_manager_arc()
+        .headless_browser_profiles_dir()
+        .join(format!("{}-{}", safe_owner, uuid::Uuid::new_v4().simple()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owner_key_falls_back_for_missing_session() {
+        assert_eq!(HeadlessBrowserPool::owner_key(Some("s-1")), "s-1");
+        assert_eq!(HeadlessBrowserPool::owner_key(Some("  ")), UNSCOPED_OWNER);
+        assert_eq!(HeadlessBrowserPool::owner_key(None), UNSCOPED_OWNER);
+    }
+
+    #[test]
+    fn profile_dirs_are_unique_and_path_safe() {
+        let first = profile_dir("../session a");
+        let second = profile_dir("../session a");
+
+        assert_ne!(first, second);
+        let name = first.file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("___session_a-"));
+        assert_eq!(
+            first.parent(),
+            Some(
+                get_path_manager_arc()
+                    .headless_browser_profiles_dir()
+                    .as_path()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown_of_unknown_owner_is_a_no_op() {
+        let pool = HeadlessBrowserPool::default();
+
+        assert_eq!(pool.shutdown("missing").await, None);
+        assert_eq!(pool.owner_of_port(9222).await, None);
+    }
+}
@@ -3,16 +3,19 @@
 //! Connects to the user's default browser (Chrome, Edge, etc.) over a
 //! CDP WebSocket, enabling page navigation, DOM interaction, screenshots,
 //! JS evaluation and more — all while preserving the user's existing
-//! cookies, extensions, and login sessions.
+//! cookies, extensions, and login sessions. `headless_pool` runs isolated
+//! per-session headless browsers for automation that needs no login state.
 
 pub mod actions;
 pub mod browser_launcher;
 pub mod cdp_client;
+pub mod headless_pool;
 pub mod session_registry;
 
 pub use actions::BrowserActions;
 pub use browser_launcher::BrowserLauncher;
 pub use cdp_client::CdpClient;
+pub use headless_pool::{headless_browser_pool, HeadlessBrowserInfo, HeadlessBrowserPool};
 pub use session_registry::{
     BrowserSession, BrowserSessionRegistry, BrowserSessionState, DialogHandler,
 };
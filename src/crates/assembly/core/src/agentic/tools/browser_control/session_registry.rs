@@ -563,6 +563,26 @@ impl BrowserSessionRegistry {
         }
     }
 
+    /// Remove every session attached to the browser on `port`.
+    pub async fn remove_port(&self, port: u16) {
+        let mut g = self.inner.write().await;
+        g.sessions.retain(|_, session| session.port != port);
+        if let Some(default_id) = g.default_id.clone() {
+            if !g.sessions.contains_key(&default_id) {
+                g.default_id = None;
+            }
+        }
+    }
+
+    /// Debug port of a session (or the default), without pruning.
+    pub async fn port_of(&self, session_id: Option<&str>) -> Option<u16> {
+        let g = self.inner.read().await;
+        let id = session_id
+            .map(str::to_string)
+            .or_else(|| g.default_id.clone())?;
+        g.sessions.get(&id).map(|session| session.port)
+    }
+
     /// Snapshot of registered session ids — used by `list_sessions` actions.
     pub async fn list(&self) -> Vec<String> {
         let g = self.inner.read().await;
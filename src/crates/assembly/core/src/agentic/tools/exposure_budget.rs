@@ -0,0 +1,222 @@
+//! Per-turn tool exposure budget.
+//!
+//! Applies `ai.tool_exposure_budget` on top of the agent tool policy: direct
+//! tools outside the budget are demoted to deferred exposure, and the
+//! deferred-tool listing in the prompt is capped. Selection itself lives in
+//! `bitfun_agent_tools::tool_exposure_budget`.
+
+use crate::agentic::agents::AgentToolPolicyOverrides;
+use crate::agentic::core::{Message, MessageContent};
+use crate::agentic::tools::registry::get_global_tool_registry;
+use crate::agentic::tools::ResolvedToolManifest;
+use crate::service::config::get_global_config_service;
+use crate::service::config::types::ToolExposureBudgetSettings;
+use crate::service::workspace::get_global_workspace_service;
+use bitfun_agent_tools::{
+    select_budgeted_tools, ToolExposure, ToolExposureBudget, ToolExposureCandidate,
+    ToolExposureSignals,
+};
+use log::{info, warn};
+use std::path::Path;
+
+/// Returns the budget settings when the budget is enabled.
+pub(crate) async fn load_enabled_tool_exposure_budget() -> Option<ToolExposureBudgetSettings> {
+    let service = get_global_config_service().await.ok()?;
+    let settings = service
+        .get_config::<ToolExposureBudgetSettings>(Some("ai.tool_exposure_budget"))
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to read tool exposure budget, using defaults: {}", e);
+            ToolExposureBudgetSettings::default()
+        });
+    settings.enabled.then_some(settings)
+}
+
+/// Collects recency, request and workspace-language signals for one turn.
+pub(crate) async fn collect_tool_exposure_signals(
+    messages: &[Message],
+    request_text: &str,
+    workspace_root: Option<&Path>,
+) -> ToolExposureSignals {
+    let mut recent_tool_names = Vec::<String>::new();
+    for message in messages.iter().rev() {
+        let names = match &message.content {
+            MessageContent::ToolResult {
+                tool_name,
+                effective_tool_name,
+                ..
+            } => vec![effective_tool_name.as_deref().unwrap_or(tool_name)],
+            MessageContent::Mixed { tool_calls, .. } => tool_calls
+                .iter()
+                .map(|call| call.tool_name.as_str())
+                .collect(),
+            _ => continue,
+        };
+        for name in names {
+            if !recent_tool_names.iter().any(|recent| recent == name) {
+                recent_tool_names.push(name.to_string());
+            }
+        }
+    }
+
+    let workspace_languages = match (workspace_root, get_global_workspace_service()) {
+        (Some(root), Some(service)) => service
+            .get_workspace_by_path(root)
+            .await
+            .map(|workspace| workspace.languages)
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    ToolExposureSignals {
+        recent_tool_names,
+        request_text: request_text.to_string(),
+        workspace_languages,
+    }
+}
+
+/// Returns exposure overrides that demote direct tools outside the budget.
+pub(crate) async fn apply_tool_exposure_budget(
+    allowed_tools: &[String],
+    exposure_overrides: &AgentToolPolicyOverrides,
+    settings: &ToolExposureBudgetSettings,
+    signals: &ToolExposureSignals,
+) -> AgentToolPolicyOverrides {
+    let candidates = {
+        let registry = get_global_tool_registry();
+        let registry = registry.read().await;
+        allowed_tools
+            .iter()
+            .filter_map(|name| {
+                let tool = registry.get_tool(name)?;
+                let exposure = exposure_overrides
+                    .get(name)
+                    .copied()
+                    .unwrap_or_else(|| tool.default_exposure());
+                (exposure == ToolExposure::Direct).then(|| ToolExposureCandidate {
+                    name: name.clone(),
+                    description: tool.short_description(),
+                    is_dynamic: tool.dynamic_tool_info().is_some(),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let selection = select_budgeted_tools(
+        &candidates,
+        signals,
+        &ToolExposureBudget {
+            max_tools: settings.max_direct_tools,
+            pinned_tools: settings.pinned_tools.clone(),
+        },
+    );
+    let metrics = &selection.metrics;
+    info!(
+        "Tool exposure budget applied: candidates={}, exposed={}, demoted={}, pinned={}, signal_matched={}, demoted_description_chars={}",
+        metrics.candidate_count,
+        metrics.exposed_count,
+        metrics.demoted_count,
+        metrics.pinned_count,
+        metrics.signal_matched_count,
+        metrics.demoted_description_chars
+    );
+
+    let mut overrides = exposure_overrides.clone();
+    for name in selection.demoted {
+        overrides.insert(name, ToolExposure::Deferred);
+    }
+    overrides
+}
+
+/// Keeps the most relevant deferred tools in the prompt listing and returns
+/// how many were left out. Omitted tools stay loadable with GetToolSpec.
+pub(crate) fn trim_deferred_tool_listing(
+    manifest: &mut ResolvedToolManifest,
+    settings: &ToolExposureBudgetSettings,
+    signals: &ToolExposureSignals,
+) -> usize {
+    if manifest.deferred_tool_summaries.len() <= settings.max_listed_deferred_tools {
+        return 0;
+    }
+    let candidates = manifest
+        .deferred_tool_summaries
+        .iter()
+        .map(|summary| ToolExposureCandidate {
+            name: summary.name.clone(),
+            description: summary.short_description.clone().unwrap_or_default(),
+            is_dynamic: true,
+        })
+        .collect::<Vec<_>>();
+    let selection = select_budgeted_tools(
+        &candidates,
+        signals,
+        &ToolExposureBudget {
+            max_tools: settings.max_listed_deferred_tools,
+            pinned_tools: settings.pinned_tools.clone(),
+        },
+    );
+    manifest
+        .deferred_tool_summaries
+        .retain(|summary| selection.exposed.contains(&summary.name));
+    info!(
+        "Deferred tool listing trimmed: listed={}, omitted={}",
+        selection.metrics.exposed_count, selection.metrics.demoted_count
+    );
+    selection.metrics.demoted_count
+}
+
+/// Note appended to the deferred-tool listing when tools were omitted.
+pub(crate) fn omitted_deferred_tools_note(omitted: usize) -> String {
+    format!(
+        "{} more deferred tools are not listed here. Use SearchTools to find them by keyword.",
+        omitted
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collect_tool_exposure_signals;
+    use crate::agentic::core::{Message, MessageContent, ToolCall};
+
+    #[tokio::test]
+    async fn recent_tool_names_are_most_recent_first_and_unique() {
+        let call = |name: &str| ToolCall {
+            tool_id: format!("call-{}", name),
+            tool_name: name.to_string(),
+            arguments: serde_json::json!({}),
+            ..Default::default()
+        };
+        let mut first = Message::user("fix the build".to_string());
+        first.content = MessageContent::Mixed {
+            reasoning_content: None,
+            text: String::new(),
+            tool_calls: vec![call("Read"), call("Grep")],
+        };
+        let mut second = Message::user(String::new());
+        second.content = MessageContent::ToolResult {
+            tool_id: "call-deferred".to_string(),
+            tool_name: "CallDeferredTool".to_string(),
+            effective_tool_name: Some("mcp__github__create_issue".to_string()),
+            result: serde_json::json!({}),
+            result_for_assistant: None,
+            is_error: false,
+            image_attachments: None,
+        };
+        let mut third = Message::user(String::new());
+        third.content = MessageContent::Mixed {
+            reasoning_content: None,
+            text: String::new(),
+            tool_calls: vec![call("Read")],
+        };
+
+        let signals =
+            collect_tool_exposure_signals(&[first, second, third], "open an issue", None).await;
+
+        assert_eq!(
+            signals.recent_tool_names,
+            vec!["Read", "mcp__github__create_issue", "Grep"]
+        );
+        assert_eq!(signals.request_text, "open an issue");
+        assert!(signals.workspace_languages.is_empty());
+    }
+}
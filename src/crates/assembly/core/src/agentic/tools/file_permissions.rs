@@ -1,6 +1,9 @@
-use crate::agentic::tools::framework::{PermissionIntent, ToolPathResolution, ToolUseContext};
+use crate::agentic::tools::framework::{
+    PermissionIntent, ToolPathResolution, ToolResource, ToolResourceAccess, ToolUseContext,
+};
 use crate::agentic::tools::restrictions::{
-    canonicalize_local_path_best_effort, is_local_path_within_root,
+    canonicalize_local_path_best_effort, is_local_path_within_root, is_sensitive_write_path,
+    SENSITIVE_FILE_PERMISSION_ACTION,
 };
 use crate::util::errors::{BitFunError, BitFunResult};
 use std::collections::HashSet;
@@ -13,13 +16,24 @@ pub(crate) fn file_permission_intents<'a>(
 ) -> BitFunResult<Vec<PermissionIntent>> {
     let mut resources = Vec::new();
     let mut external_directories = Vec::new();
+    let mut sensitive_files = Vec::new();
     let mut seen_resources = HashSet::new();
     let mut seen_external_directories = HashSet::new();
+    let approve_sensitive_files = action != "read"
+        && context
+            .runtime_tool_restrictions
+            .path_policy
+            .sandbox
+            .as_ref()
+            .is_some_and(|sandbox| sandbox.approve_sensitive_files);
 
     for path in paths {
         let resolved = context.resolve_tool_path(path)?;
         let resource = normalized_permission_resource(&resolved)?;
         if seen_resources.insert(resource.clone()) {
+            if approve_sensitive_files && is_sensitive_workspace_file(context, &resource) {
+                sensitive_files.push(resource.clone());
+            }
             resources.push(resource);
         }
 
@@ -43,9 +57,56 @@ pub(crate) fn file_permission_intents<'a>(
             external_directories,
         ));
     }
+    if !sensitive_files.is_empty() {
+        intents.push(PermissionIntent::new(
+            SENSITIVE_FILE_PERMISSION_ACTION,
+            sensitive_files,
+        ));
+    }
     Ok(intents)
 }
 
+/// Sensitive patterns are workspace-relative, so files outside the workspace
+/// never match.
+fn is_sensitive_workspace_file(context: &ToolUseContext, resource: &str) -> bool {
+    let Some(workspace) = context.workspace.as_ref() else {
+        return false;
+    };
+    let root = if workspace.is_remote() {
+        workspace.root_path_string().replace('\\', "/")
+    } else {
+        match canonicalize_local_path_best_effort(workspace.root_path()) {
+            Ok(root) => root.to_string_lossy().replace('\\', "/"),
+            Err(_) => return false,
+        }
+    };
+    resource
+        .strip_prefix(root.trim_end_matches('/'))
+        .and_then(|relative| relative.strip_prefix('/'))
+        .is_some_and(is_sensitive_write_path)
+}
+
+/// Declares file accesses for scheduling, keyed like permission resources so
+/// different spellings of the same path collide.
+pub(crate) fn file_resource_accesses<'a>(
+    write: bool,
+    paths: impl IntoIterator<Item = &'a str>,
+    context: &ToolUseContext,
+) -> Option<Vec<ToolResourceAccess>> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let resolved = context.resolve_tool_path(path).ok()?;
+            let resource = ToolResource::File(normalized_permission_resource(&resolved).ok()?);
+            Some(if write {
+                ToolResourceAccess::Write(resource)
+            } else {
+                ToolResourceAccess::Read(resource)
+            })
+        })
+        .collect()
+}
+
 fn normalized_permission_resource(resolved: &ToolPathResolution) -> BitFunResult<String> {
     if resolved.uses_remote_workspace_backend() || resolved.runtime_scope.is_some() {
         return Ok(resolved.resolved_path.replace('\\', "/"));
@@ -152,6 +213,41 @@ mod tests {
         assert_eq!(intents[0].action, "read");
     }
 
+    #[test]
+    fn sandboxed_writes_to_sensitive_files_add_sensitive_file_intent() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let workspace = temp.path().join("workspace");
+        fs::create_dir_all(workspace.join(".git")).expect("git dir");
+        let git_config = workspace.join(".git").join("config");
+        let source = workspace.join("main.rs");
+        let mut context =
+            ToolUseContext::for_tool_listing(Some(WorkspaceBinding::new(None, workspace)), None);
+        context.runtime_tool_restrictions.path_policy.sandbox =
+            Some(crate::agentic::tools::ToolWriteSandbox {
+                approve_sensitive_files: true,
+                ..Default::default()
+            });
+
+        let intents = file_permission_intents(
+            "edit",
+            [
+                git_config.to_string_lossy().as_ref(),
+                source.to_string_lossy().as_ref(),
+            ],
+            &context,
+        )
+        .expect("permission intents");
+        assert_eq!(intents.len(), 2);
+        assert_eq!(intents[1].action, SENSITIVE_FILE_PERMISSION_ACTION);
+        assert_eq!(intents[1].resources.len(), 1);
+        assert!(intents[1].resources[0].ends_with("/.git/config"));
+
+        let reads =
+            file_permission_intents("read", [git_config.to_string_lossy().as_ref()], &context)
+                .expect("read intents");
+        assert_eq!(reads.len(), 1);
+    }
+
     #[test]
     fn multi_file_edit_keeps_patch_targets_in_one_atomic_intent() {
         let temp = tempfile::tempdir().expect("temp dir");
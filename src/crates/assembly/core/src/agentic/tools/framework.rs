@@ -11,6 +11,7 @@ pub use bitfun_agent_tools::{
     ToolPathResolution, ToolRenderOptions, ToolResult, ToolWorkspaceKind, ValidationResult,
 };
 use serde_json::Value;
+pub use tool_runtime::pipeline::{ToolResource, ToolResourceAccess};
 
 /// Tool trait
 #[async_trait]
@@ -111,6 +112,17 @@ pub trait Tool: Send + Sync {
         self.is_readonly()
     }
 
+    /// Resources this call reads or writes. Declaring them lets the pipeline
+    /// overlap calls that touch different resources; `None` falls back to
+    /// `is_concurrency_safe`.
+    fn resource_accesses(
+        &self,
+        _input: &Value,
+        _context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        None
+    }
+
     /// Describe permission actions and resources without performing side effects.
     fn permission_intents(
         &self,
@@ -0,0 +1,409 @@
+//! FindSymbol and FileOutline tools
+//!
+//! Syntax-aware code navigation backed by the workspace code index: look up
+//! where a function or type is defined, or list what a file declares,
+//! without reading whole files or grepping for text.
+
+use crate::agentic::tools::framework::{
+    Tool, ToolExposure, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+};
+use crate::service::code_index::{get_code_index_service, CodeSymbol, SymbolKind, SymbolQuery};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::path::Path;
+
+const KIND_NAMES: &[&str] = &[
+    "function",
+    "method",
+    "struct",
+    "class",
+    "interface",
+    "trait",
+    "enum",
+    "type_alias",
+    "constant",
+    "module",
+    "import",
+];
+
+fn invalid(message: impl Into<String>) -> ValidationResult {
+    ValidationResult {
+        result: false,
+        message: Some(message.into()),
+        error_code: Some(400),
+        meta: None,
+    }
+}
+
+fn valid() -> ValidationResult {
+    ValidationResult {
+        result: true,
+        message: None,
+        error_code: None,
+        meta: None,
+    }
+}
+
+fn local_workspace_root(context: &ToolUseContext) -> BitFunResult<&Path> {
+    if context.is_remote() {
+        return Err(BitFunError::tool(
+            "Code index is not available for remote workspaces; use Grep instead",
+        ));
+    }
+    context
+        .workspace_root()
+        .ok_or_else(|| BitFunError::tool("No workspace is open"))
+}
+
+fn parse_kinds(input: &Value) -> Result<Vec<SymbolKind>, String> {
+    let Some(kinds) = input.get("kinds") else {
+        return Ok(Vec::new());
+    };
+    let kinds = kinds
+        .as_array()
+        .ok_or_else(|| "kinds must be an array".to_string())?;
+    kinds
+        .iter()
+        .map(|kind| {
+            kind.as_str().and_then(SymbolKind::parse).ok_or_else(|| {
+                format!(
+                    "Unknown symbol kind {}; expected one of {}",
+                    kind,
+                    KIND_NAMES.join(", ")
+                )
+            })
+        })
+        .collect()
+}
+
+fn format_symbol_line(symbol: &CodeSymbol, with_path: bool) -> String {
+    let location = if with_path {
+        format!("{}:{}-{}", symbol.path, symbol.start_line, symbol.end_line)
+    } else {
+        format!("{}-{}", symbol.start_line, symbol.end_line)
+    };
+    let name = match &symbol.container {
+        Some(container) => format!("{}::{}", container, symbol.name),
+        None => symbol.name.clone(),
+    };
+    format!(
+        "{} {} {} | {}",
+        location,
+        symbol.kind.as_str(),
+        name,
+        symbol.signature
+    )
+}
+
+/// FindSymbol tool - locate definitions by name
+pub struct FindSymbolTool;
+
+impl Default for FindSymbolTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FindSymbolTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Tool for FindSymbolTool {
+    fn name(&self) -> &str {
+        "FindSymbol"
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(r#"Finds where functions, methods, types and other symbols are defined in the current workspace, using a syntax-aware index instead of text search.
+
+Usage:
+- `name` is matched case-insensitively; exact names rank first, then prefixes, then substrings
+- Qualify with the enclosing type to narrow results: `Parser::parse` or `Parser.parse`
+- Filter with `kinds` (function, method, struct, class, interface, trait, enum, type_alias, constant, module, import) and `path` (a workspace-relative directory)
+- Imports are only returned when `kinds` includes "import"
+- Supports Rust, TypeScript/TSX, JavaScript, Python, Go, Java, C and C++; use Grep for other files, string literals or usages
+- Results give `path:start-end`, so follow up with Read on just that range"#
+            .to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Find symbol definitions by name across the workspace.".to_string()
+    }
+
+    fn default_exposure(&self) -> ToolExposure {
+        ToolExposure::Deferred
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Symbol name, optionally qualified as Type::member or Type.member."
+                },
+                "kinds": {
+                    "type": "array",
+                    "items": { "type": "string", "enum": KIND_NAMES },
+                    "description": "Only return symbols of these kinds."
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Only search files under this workspace-relative directory."
+                },
+                "limit": {
+                    "type": "number",
+                    "description": "Maximum number of results. Defaults to 50."
+                }
+            },
+            "required": ["name"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    async fn is_available_in_context(&self, context: Option<&ToolUseContext>) -> bool {
+        !context.map(|ctx| ctx.is_remote()).unwrap_or(false)
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        match input.get("name").and_then(|v| v.as_str()) {
+            Some(name) if !name.trim().is_empty() => {}
+            _ => return invalid("name is required"),
+        }
+        if let Err(message) = parse_kinds(input) {
+            return invalid(message);
+        }
+        valid()
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        match input.get("name").and_then(|v| v.as_str()) {
+            Some(name) => format!("Find symbol {}", name),
+            None => "Finding symbol".to_string(),
+        }
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        let name = input
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| BitFunError::tool("name is required"))?;
+        let kinds = parse_kinds(input).map_err(BitFunError::tool)?;
+        let root = local_workspace_root(context)?;
+        let query = SymbolQuery {
+            name: name.to_string(),
+            kinds,
+            path_prefix: input
+                .get("path")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            limit: input
+                .get("limit")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as usize),
+        };
+
+        let result = get_code_index_service().find_symbol(root, query).await?;
+
+        let mut text = if result.symbols.is_empty() {
+            format!(
+                "No symbols matching \"{}\" in {} indexed files.",
+                name, result.files_indexed
+            )
+        } else {
+            result
+                .symbols
+                .iter()
+                .map(|symbol| format_symbol_line(symbol, true))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        if result.total_matches > result.symbols.len() {
+            text.push_str(&format!(
+                "\n(showing {} of {} matches; narrow with kinds or path)",
+                result.symbols.len(),
+                result.total_matches
+            ));
+        }
+
+        Ok(vec![ToolResult::Result {
+            data: serde_json::to_value(&result)?,
+            result_for_assistant: Some(text),
+            image_attachments: None,
+        }])
+    }
+}
+
+/// FileOutline tool - list the symbols a file declares
+pub struct FileOutlineTool;
+
+impl Default for FileOutlineTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileOutlineTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Tool for FileOutlineTool {
+    fn name(&self) -> &str {
+        "FileOutline"
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(r#"Lists the imports, types, functions and methods declared in a source file with their line ranges, without returning the file body.
+
+Usage:
+- The path must be relative to the current workspace or an absolute path inside it
+- Use it to understand a large file's structure before reading specific ranges with Read
+- Supports Rust, TypeScript/TSX, JavaScript, Python, Go, Java, C and C++"#
+            .to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "List the symbols declared in a source file.".to_string()
+    }
+
+    fn default_exposure(&self) -> ToolExposure {
+        ToolExposure::Deferred
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Source file to outline. Use a workspace-relative path or an absolute path inside the current workspace."
+                }
+            },
+            "required": ["path"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    async fn is_available_in_context(&self, context: Option<&ToolUseContext>) -> bool {
+        !context.map(|ctx| ctx.is_remote()).unwrap_or(false)
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        match input.get("path").and_then(|v| v.as_str()) {
+            Some(path) if !path.trim().is_empty() => valid(),
+            _ => invalid("path is required"),
+        }
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        match input.get("path").and_then(|v| v.as_str()) {
+            Some(path) => format!("Outline {}", path),
+            None => "Outlining file".to_string(),
+        }
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        let path = input
+            .get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| BitFunError::tool("path is required"))?;
+        let root = local_workspace_root(context)?;
+
+        let outline = get_code_index_service()
+            .get_file_outline(root, Path::new(path))
+            .await?;
+
+        let text = if outline.symbols.is_empty() {
+            format!("{}: no symbols found", outline.path)
+        } else {
+            let lines = outline
+                .symbols
+                .iter()
+                .map(|symbol| {
+                    let indent = if symbol.container.is_some() { "  " } else { "" };
+                    format!("{}{}", indent, format_symbol_line(symbol, false))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}\n{}", outline.path, lines)
+        };
+
+        Ok(vec![ToolResult::Result {
+            data: serde_json::to_value(&outline)?,
+            result_for_assistant: Some(text),
+            image_attachments: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_are_parsed_and_unknown_kinds_rejected() {
+        assert_eq!(
+            parse_kinds(&json!({ "kinds": ["struct", "Method"] })).unwrap(),
+            vec![SymbolKind::Struct, SymbolKind::Method]
+        );
+        assert!(parse_kinds(&json!({})).unwrap().is_empty());
+        assert!(parse_kinds(&json!({ "kinds": ["macro"] })).is_err());
+    }
+
+    #[test]
+    fn symbol_lines_include_container_and_range() {
+        let symbol = CodeSymbol {
+            name: "parse".to_string(),
+            kind: SymbolKind::Method,
+            path: "src/lib.rs".to_string(),
+            start_line: 3,
+            end_line: 9,
+            container: Some("Parser".to_string()),
+            signature: "pub fn parse(&self) {".to_string(),
+        };
+
+        assert_eq!(
+            format_symbol_line(&symbol, true),
+            "src/lib.rs:3-9 method Parser::parse | pub fn parse(&self) {"
+        );
+    }
+}
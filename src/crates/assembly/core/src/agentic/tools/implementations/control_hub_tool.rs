@@ -13,6 +13,9 @@ use crate::agentic::tools::browser_control::browser_launcher::{
     BrowserKind, BrowserLauncher, LaunchResult, DEFAULT_CDP_PORT,
 };
 use crate::agentic::tools::browser_control::cdp_client::CdpClient;
+use crate::agentic::tools::browser_control::headless_pool::{
+    headless_browser_pool, HeadlessBrowserPool,
+};
 use crate::agentic::tools::browser_control::session_registry::{
     BrowserSession, BrowserSessionRegistry, BrowserSessionState, DialogHandler,
 };
@@ -82,7 +85,7 @@ impl ControlHubTool {
         vec![
             "For project Web UI testing that does not depend on user login state, use the dedicated headless browser flow instead of the user's browser.".to_string(),
             format!(
-                "Start or attach a headless test browser on the test port {} and then drive it through browser DOM actions only.",
+                "Call connect {{ mode: \"headless\" }} without a port to start this session's own sandboxed headless browser; pass a port (e.g. {}) only to attach to a headless test browser that is already running.",
                 port
             ),
             "Do not switch to desktop mouse/keyboard browser control in headless mode.".to_string(),
@@ -134,8 +137,8 @@ Use this tool via `{ domain, action, params }` for browser automation, terminal
   * `open_builtin { url, title?, replace_existing? }` — open an http(s) URL in BitFun's built-in right-side browser panel. This changes the BitFun UI only; it does not fetch page text for reasoning.
 - Automation modes (external managed browser):
   * `connect { mode: "default" }` (default) — start or attach the stable managed browser profile with CDP enabled.
-  * `connect { mode: "headless" }` — start or attach the stable managed headless browser profile for project Web UI testing that does not depend on user login state.
-- Actions: open_builtin, connect, tab_new, navigate, back, forward, reload, snapshot, click, hover, fill, type, check, uncheck, select, press_key, scroll, auto_scroll, wait, get, get_text, get_url, get_title, get_html, screenshot, evaluate, fetch, cookies, set_cookies, set_file_input_files, cdp, network, console, errors, trace, dialog, frame, frame_main, read_article, close, list_pages, tab_query, switch_page, list_sessions.
+  * `connect { mode: "headless" }` — start this session's own sandboxed headless browser (private throwaway profile, loopback-only port) for project Web UI testing that does not depend on user login state. Pass `port` only to attach to a headless test browser that is already running. `close_headless` stops the session's browser; it is also stopped when the session is deleted.
+- Actions: open_builtin, connect, tab_new, navigate, back, forward, reload, snapshot, click, hover, fill, type, check, uncheck, select, press_key, scroll, auto_scroll, wait, get, get_text, get_url, get_title, get_html, screenshot, evaluate, fetch, cookies, set_cookies, set_file_input_files, cdp, network, console, errors, trace, dialog, frame, frame_main, read_article, close, close_headless, list_pages, tab_query, switch_page, list_sessions.
 - Automation workflow: connect -> navigate -> snapshot (returns @e1, @e2 ... refs) -> click/fill using refs.
 - Take a fresh snapshot after any DOM mutation; stale refs return `error.code = STALE_REF`.
 
@@ -185,7 +188,7 @@ Branch on `ok` and `error.code`, not on English messages.
                     .with_hint(hint),
                 ))
             }
-            "browser" => self.handle_browser(action, params).await,
+            "browser" => self.handle_browser(action, params, context).await,
             "terminal" => self.handle_terminal(action, params, context).await,
             "system" => {
                 let hint = if context.is_remote() {
@@ -479,7 +482,54 @@ Branch on `ok` and `error.code`, not on English messages.
         )
     }
 
-    async fn handle_browser(&self, action: &str, params: &Value) -> BitFunResult<Vec<ToolResult>> {
+    /// Headless browsers started by the pool belong to one agent session.
+    /// Returns an error when the call addresses another session's browser,
+    /// either by `port` or through a CDP session attached to it.
+    async fn headless_sandbox_violation(
+        action: &str,
+        params: &Value,
+        port: u16,
+        session_id: Option<&str>,
+        owner: &str,
+    ) -> Option<ControlHubError> {
+        let mut target_ports = Vec::new();
+        if params.get("port").is_some() {
+            target_ports.push(port);
+        }
+        // `connect` attaches a new session rather than using the default one.
+        if !matches!(action, "connect" | "open_builtin" | "close_headless") {
+            target_ports.extend(browser_sessions().port_of(session_id).await);
+        }
+
+        let pool = headless_browser_pool();
+        for target in target_ports {
+            let Some(other) = pool.owner_of_port(target).await else {
+                continue;
+            };
+            if other != owner {
+                return Some(
+                    ControlHubError::new(
+                        ErrorCode::GuardRejected,
+                        format!(
+                            "The headless browser on port {} belongs to another agent session.",
+                            target
+                        ),
+                    )
+                    .with_hint(
+                        "Call browser.connect { mode: \"headless\" } without a port to start this session's own headless browser.",
+                    ),
+                );
+            }
+        }
+        None
+    }
+
+    async fn handle_browser(
+        &self,
+        action: &str,
+        params: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
         let port = params
             .get("port")
             .and_then(|v| v.as_u64())
@@ -491,6 +541,19 @@ Branch on `ok` and `error.code`, not on English messages.
             .and_then(|v| v.as_str())
             .map(str::to_string);
 
+        let owner = HeadlessBrowserPool::owner_key(context.session_id.as_deref());
+        if let Some(error) = Self::headless_sandbox_violation(
+            action,
+            params,
+            port,
+            session_id_param.as_deref(),
+            &owner,
+        )
+        .await
+        {
+            return Ok(err_response("browser", action, error));
+        }
+
         match action {
             "open_builtin" => {
                 let raw_url = params.get("url").and_then(Value::as_str).unwrap_or("");
@@ -539,6 +602,25 @@ Branch on `ok` and `error.code`, not on English messages.
             "connect" => {
                 let mode = Self::browser_connect_mode_from_params(params);
 
+                // Without an explicit port, headless mode runs this session's
+                // own sandboxed browser instead of attaching to a shared one.
+                let sandboxed = if mode == "headless" && params.get("port").is_none() {
+                    match headless_browser_pool().ensure(&owner).await {
+                        Ok(info) => Some(info),
+                        Err(error) => {
+                            return Ok(err_response(
+                                "browser",
+                                "connect",
+                                ControlHubError::new(ErrorCode::NotAvailable, error.to_string())
+                                    .with_hints(Self::headless_browser_connect_hints(port)),
+                            ));
+                        }
+                    }
+                } else {
+                    None
+                };
+                let port = sandboxed.as_ref().map_or(port, |info| info.port);
+
                 if mode == "headless" && !BrowserLauncher::is_cdp_available(port).await {
                     return Ok(err_response(
                         "browser",
@@ -710,7 +792,9 @@ Branch on `ok` and `error.code`, not on English messages.
                             "page_title": page.title,
                             "matched_by_target": targeted,
                             "activated": activated,
-                            "status": if mode == "headless" {
+                            "status": if sandboxed.as_ref().is_some_and(|info| info.launched) {
+                                "launched"
+                            } else if mode == "headless" {
                                 "attached"
                             } else if matches!(launch_result, LaunchResult::AlreadyConnected) {
                                 "already_connected"
@@ -721,6 +805,14 @@ Branch on `ok` and `error.code`, not on English messages.
                         if let Some(w) = activate_warning {
                             result["warning"] = json!(w);
                         }
+                        if let Some(info) = &sandboxed {
+                            result["sandbox"] = json!({
+                                "owner_session": owner,
+                                "pid": info.pid,
+                                "executable": info.executable,
+                                "runtime_source": info.source,
+                            });
+                        }
                         let summary = if targeted {
                             format!(
                                 "Connected to {} via DOM/CDP (session {}, page '{}')",
@@ -753,6 +845,24 @@ Branch on `ok` and `error.code`, not on English messages.
                 }
             }
 
+            "close_headless" => {
+                let stopped = headless_browser_pool().shutdown(&owner).await;
+                if let Some(stopped_port) = stopped {
+                    browser_sessions().remove_port(stopped_port).await;
+                }
+                let summary = match stopped {
+                    Some(stopped_port) => format!(
+                        "Stopped this session's headless browser on port {}",
+                        stopped_port
+                    ),
+                    None => "This session has no running headless browser".to_string(),
+                };
+                Ok(vec![ToolResult::ok(
+                    json!({ "success": true, "stopped": stopped.is_some(), "port": stopped }),
+                    Some(summary),
+                )])
+            }
+
             "list_pages" => {
                 let pages = CdpClient::list_pages(port).await?;
                 let default_id = browser_sessions().default_id().await;
@@ -1704,7 +1814,7 @@ Branch on `ok` and `error.code`, not on English messages.
                         Ok(vec![ToolResult::ok(result, Some("Page closed".to_string()))])
                     }
                     other => Err(BitFunError::tool(format!(
-                        "Unknown browser action: '{}'. Valid: connect, tab_new, navigate, back, forward, reload, snapshot, click, hover, fill, type, check, uncheck, select, press_key, scroll, auto_scroll, wait, get, get_text, get_url, get_title, get_html, screenshot, evaluate, fetch, cookies, set_cookies, set_file_input_files, cdp, network, console, errors, trace, dialog, frame, frame_main, read_article, close, list_pages, tab_query, switch_page, list_sessions",
+                        "Unknown browser action: '{}'. Valid: connect, tab_new, navigate, back, forward, reload, snapshot, click, hover, fill, type, check, uncheck, select, press_key, scroll, auto_scroll, wait, get, get_text, get_url, get_title, get_html, screenshot, evaluate, fetch, cookies, set_cookies, set_file_input_files, cdp, network, console, errors, trace, dialog, frame, frame_main, read_article, close, close_headless, list_pages, tab_query, switch_page, list_sessions",
                         other
                     ))),
                 }
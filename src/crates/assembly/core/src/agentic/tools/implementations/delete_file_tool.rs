@@ -1,9 +1,11 @@
-use crate::agentic::tools::file_permissions::file_permission_intents;
+use crate::agentic::tools::file_permissions::{file_permission_intents, file_resource_accesses};
 use crate::agentic::tools::framework::{
-    PermissionIntent, Tool, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+    PermissionIntent, Tool, ToolRenderOptions, ToolResourceAccess, ToolResult, ToolUseContext,
+    ValidationResult,
 };
 use crate::agentic::tools::workspace_paths::is_bitfun_tool_uri;
 use crate::agentic::tools::ToolPathOperation;
+use crate::service::file_ops::FileOperationKind;
 use crate::util::errors::{BitFunError, BitFunResult};
 use async_trait::async_trait;
 use serde_json::{json, Value};
@@ -128,6 +130,15 @@ Important notes:
         file_permission_intents("edit", [path], context)
     }
 
+    fn resource_accesses(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        let path = input.get("path").and_then(Value::as_str)?;
+        file_resource_accesses(true, [path], context)
+    }
+
     async fn validate_input(
         &self,
         input: &Value,
@@ -309,7 +320,15 @@ Important notes:
                 .and_then(|v| v.as_bool())
                 .unwrap_or(false);
 
-            delete_path_success_message(path, is_directory)
+            let message = delete_path_success_message(path, is_directory);
+            if output.get("moved_to_trash").and_then(|v| v.as_bool()) == Some(false) {
+                format!(
+                    "{}. The system trash was unavailable, so it was deleted permanently.",
+                    message
+                )
+            } else {
+                message
+            }
         } else {
             "Deletion completed".to_string()
         }
@@ -390,22 +409,47 @@ Important notes:
             }]);
         }
 
-        let delete_request = DeleteLocalPathRequest {
-            logical_path: resolved.logical_path.clone(),
-            resolved_path: Path::new(&resolved.resolved_path).to_path_buf(),
-            recursive,
+        let resolved_path = Path::new(&resolved.resolved_path).to_path_buf();
+        // Workspace files go through the file operation service so the user
+        // can restore them from the trash; runtime artifacts are removed.
+        // Where no trash exists the deletion the user already approved is
+        // made permanent and journaled as such.
+        let file_operation_service = crate::service::file_ops::get_global_file_operation_service();
+        let workspace_root = context
+            .workspace_root()
+            .filter(|root| resolved_path.starts_with(root));
+        let result_data = if let (Some(service), Some(root)) =
+            (file_operation_service, workspace_root)
+        {
+            let operation = service
+                .delete(root, &resolved_path, recursive, true)
+                .await
+                .map_err(|error| BitFunError::tool(error.to_string()))?;
+            json!({
+                "success": true,
+                "path": resolved.logical_path,
+                "is_directory": operation.is_directory,
+                "recursive": recursive,
+                "moved_to_trash": operation.kind == FileOperationKind::Delete,
+                "operation_id": operation.id
+            })
+        } else {
+            let delete_request = DeleteLocalPathRequest {
+                logical_path: resolved.logical_path.clone(),
+                resolved_path,
+                recursive,
+            };
+            let outcome = tokio::task::spawn_blocking(move || delete_local_path(delete_request))
+                .await
+                .map_err(|error| BitFunError::tool(format!("Delete task failed: {}", error)))?
+                .map_err(BitFunError::tool)?;
+            json!({
+                "success": true,
+                "path": outcome.logical_path,
+                "is_directory": outcome.is_directory,
+                "recursive": outcome.recursive
+            })
         };
-        let outcome = tokio::task::spawn_blocking(move || delete_local_path(delete_request))
-            .await
-            .map_err(|error| BitFunError::tool(format!("Delete task failed: {}", error)))?
-            .map_err(BitFunError::tool)?;
-
-        let result_data = json!({
-            "success": true,
-            "path": outcome.logical_path,
-            "is_directory": outcome.is_directory,
-            "recursive": outcome.recursive
-        });
 
         let result_text = self.render_result_for_assistant(&result_data);
         crate::agentic::execution::edit_constraint_guard::record_mutation_applied(
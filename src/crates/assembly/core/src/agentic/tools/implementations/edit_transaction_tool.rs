@@ -0,0 +1,542 @@
+//! EditTransaction tool implementation.
+//!
+//! Stages edits across many files and keeps them only if they all apply, the
+//! result parses, and the configured validation command passes. Planning,
+//! checks and rollback live in `service::edit_transaction`; this tool adds
+//! the per-file path policy, read-state guardrails and checkpoints that the
+//! single-file Edit and Write tools apply.
+
+use super::file_write_tool::FileWriteTool;
+use crate::agentic::execution::edit_constraint_guard;
+use crate::agentic::tools::file_permissions::{file_permission_intents, file_resource_accesses};
+use crate::agentic::tools::file_read_state_runtime::{
+    file_mutation_timestamp_ms, update_file_read_state_after_mutation,
+    validate_edit_against_read_state, validate_edit_has_prior_read,
+};
+use crate::agentic::tools::file_tool_guidance::file_tool_guidance_message;
+use crate::agentic::tools::framework::{
+    PermissionIntent, Tool, ToolPathResolution, ToolRenderOptions, ToolResourceAccess, ToolResult,
+    ToolUseContext, ValidationResult,
+};
+use crate::agentic::tools::ToolPathOperation;
+use crate::service::config::get_global_config_service;
+use crate::service::config::types::EditTransactionSettings;
+use crate::service::edit_transaction::{
+    EditTransactionOptions, EditTransactionReport, EditTransactionService, EditTransactionStatus,
+    StagedEdit, ValidationCommand,
+};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use log::debug;
+use serde_json::{json, Value};
+
+pub const EDIT_TRANSACTION_TOOL_NAME: &str = "EditTransaction";
+
+const EDIT_TRANSACTION_TOOL_PROMPT: &str = r#"Applies a set of edits across one or more files as a single transaction: either every edit is kept or none is.
+
+Use this for refactors that must land together, such as renaming a symbol and updating its call sites, where a half-applied change would leave the workspace broken.
+
+Each entry in `edits` is one of:
+- `{ "file_path", "old_string", "new_string", "replace_all"? }`: exact string replacement, with the same rules as the Edit tool. You must Read a file before replacing text in it.
+- `{ "file_path", "content" }`: full file content, with the same rules as the Write tool. Creates the file if it does not exist.
+
+Edits to the same file apply in order, each to the result of the previous one.
+
+Before anything is written:
+- Every edit must apply; an `old_string` that is missing or ambiguous fails the whole transaction.
+- Files in supported languages are parsed, and edits that introduce syntax errors are rejected.
+
+After the files are written, the user's configured validation command (for example a build or test run) runs from the workspace root. If it fails, every file is restored and the command output is returned.
+
+Set `dry_run` to true to see the per-file diffs without writing anything.
+
+The result lists each changed file with its diff statistics. Diffs are shown to the user for review."#;
+
+/// EditTransaction tool - all-or-nothing multi-file edits.
+pub struct EditTransactionTool;
+
+/// A staged edit with its path checked against the workspace.
+struct ResolvedEdit {
+    edit: StagedEdit,
+    resolved: ToolPathResolution,
+}
+
+impl Default for EditTransactionTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditTransactionTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn parse_edits(input: &Value) -> Result<Vec<StagedEdit>, String> {
+        let entries = input
+            .get("edits")
+            .and_then(Value::as_array)
+            .filter(|entries| !entries.is_empty())
+            .ok_or_else(|| "edits must be a non-empty array".to_string())?;
+
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let field = |name: &str| entry.get(name).and_then(Value::as_str);
+                let path = field("file_path")
+                    .filter(|path| !path.is_empty())
+                    .ok_or_else(|| format!("edits[{}].file_path is required", index))?
+                    .to_string();
+                if let Some(content) = field("content") {
+                    if entry.get("old_string").is_some() || entry.get("new_string").is_some() {
+                        return Err(format!(
+                            "edits[{}] must set either content or old_string/new_string, not both",
+                            index
+                        ));
+                    }
+                    return Ok(StagedEdit::Write {
+                        path,
+                        content: content.to_string(),
+                    });
+                }
+
+                let old_string = field("old_string")
+                    .filter(|old_string| !old_string.is_empty())
+                    .ok_or_else(|| format!("edits[{}].old_string is required", index))?;
+                let new_string = field("new_string")
+                    .ok_or_else(|| format!("edits[{}].new_string is required", index))?;
+                if old_string == new_string {
+                    return Err(format!(
+                        "edits[{}].new_string must be different from old_string",
+                        index
+                    ));
+                }
+                Ok(StagedEdit::Replace {
+                    path,
+                    old_string: old_string.to_string(),
+                    new_string: new_string.to_string(),
+                    replace_all: entry
+                        .get("replace_all")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                })
+            })
+            .collect()
+    }
+
+    fn operation(edit: &StagedEdit) -> ToolPathOperation {
+        match edit {
+            StagedEdit::Replace { .. } => ToolPathOperation::Edit,
+            StagedEdit::Write { .. } => ToolPathOperation::Write,
+        }
+    }
+
+    fn resolve_edits(
+        edits: Vec<StagedEdit>,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ResolvedEdit>> {
+        edits
+            .into_iter()
+            .map(|edit| {
+                let resolved = context.resolve_tool_path(edit.path())?;
+                context.enforce_path_operation(Self::operation(&edit), &resolved)?;
+                Ok(ResolvedEdit { edit, resolved })
+            })
+            .collect()
+    }
+
+    /// The same read-before-change checks Edit and Write apply per file.
+    async fn guardrail_error(edit: &ResolvedEdit, context: &ToolUseContext) -> Option<String> {
+        match &edit.edit {
+            StagedEdit::Replace { .. } => {
+                if let Some(message) = validate_edit_has_prior_read(context, &edit.resolved) {
+                    return Some(file_tool_guidance_message(message));
+                }
+                validate_edit_against_read_state(context, &edit.resolved)
+                    .await
+                    .map(file_tool_guidance_message)
+            }
+            StagedEdit::Write { path, .. } => {
+                FileWriteTool::preflight_write_error(context, path).await
+            }
+        }
+    }
+
+    async fn load_settings() -> EditTransactionSettings {
+        let Ok(service) = get_global_config_service().await else {
+            return EditTransactionSettings::default();
+        };
+        service
+            .get_config::<EditTransactionSettings>(Some("ai.edit_transaction"))
+            .await
+            .unwrap_or_else(|e| {
+                debug!("Edit transaction config unavailable, using defaults: {}", e);
+                EditTransactionSettings::default()
+            })
+    }
+
+    fn options(settings: &EditTransactionSettings, dry_run: bool) -> EditTransactionOptions {
+        let program = settings.validation_command.trim();
+        EditTransactionOptions {
+            check_syntax: settings.check_syntax,
+            validation: (!program.is_empty()).then(|| ValidationCommand {
+                program: program.to_string(),
+                args: settings.validation_args.clone(),
+                timeout_secs: settings.validation_timeout_secs,
+            }),
+            dry_run,
+        }
+    }
+
+    fn assistant_summary(report: &EditTransactionReport) -> String {
+        let mut lines = vec![match report.status {
+            EditTransactionStatus::Previewed => {
+                "Dry run: the edits apply cleanly. Nothing was written.".to_string()
+            }
+            EditTransactionStatus::Committed => "Edit transaction committed.".to_string(),
+            EditTransactionStatus::Rejected => format!(
+                "Edit transaction rejected; nothing was written. {}",
+                report.reason.as_deref().unwrap_or_default()
+            ),
+            EditTransactionStatus::RolledBack => format!(
+                "Edit transaction rolled back; every file was restored. {}",
+                report.reason.as_deref().unwrap_or_default()
+            ),
+        }];
+
+        for file in &report.files {
+            lines.push(format!(
+                "- {} ({:?}, {} edit(s), +{} -{})",
+                file.path, file.change, file.edit_count, file.additions, file.deletions
+            ));
+            for error in &file.syntax_errors {
+                lines.push(format!(
+                    "  syntax error at {}:{}: {}",
+                    error.line, error.column, error.message
+                ));
+            }
+        }
+
+        if let Some(run) = &report.validation {
+            let outcome = if run.passed {
+                "passed".to_string()
+            } else if run.timed_out {
+                "timed out".to_string()
+            } else {
+                match run.exit_code {
+                    Some(code) => format!("failed with exit code {}", code),
+                    None => "failed".to_string(),
+                }
+            };
+            lines.push(format!("Validation `{}` {}.", run.command, outcome));
+            if !run.passed && !run.output.is_empty() {
+                lines.push(format!(
+                    "<validation_output>\n{}\n</validation_output>",
+                    run.output
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+#[async_trait]
+impl Tool for EditTransactionTool {
+    fn name(&self) -> &str {
+        EDIT_TRANSACTION_TOOL_NAME
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(EDIT_TRANSACTION_TOOL_PROMPT.to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Apply edits across several files all at once, or not at all".to_string()
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "edits": {
+                    "type": "array",
+                    "minItems": 1,
+                    "description": "Edits in the order they apply. Each is either a replacement (old_string/new_string) or full file content.",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "file_path": {
+                                "type": "string",
+                                "description": "The path to the file to change"
+                            },
+                            "old_string": {
+                                "type": "string",
+                                "description": "Exact text to replace, copied verbatim from your latest Read of this file"
+                            },
+                            "new_string": {
+                                "type": "string",
+                                "description": "Replacement text (must be different from old_string)"
+                            },
+                            "replace_all": {
+                                "type": "boolean",
+                                "default": false,
+                                "description": "Replace all occurrences of old_string (default false)"
+                            },
+                            "content": {
+                                "type": "string",
+                                "description": "Full file content; use instead of old_string/new_string to create or overwrite the file"
+                            }
+                        },
+                        "required": ["file_path"],
+                        "additionalProperties": false
+                    }
+                },
+                "dry_run": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Check the edits and return the diffs without writing (default false)"
+                }
+            },
+            "required": ["edits"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        false
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        false
+    }
+
+    fn permission_intents(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<PermissionIntent>> {
+        let edits = Self::parse_edits(input).map_err(BitFunError::validation)?;
+        file_permission_intents("edit", edits.iter().map(StagedEdit::path), context)
+    }
+
+    fn resource_accesses(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        let edits = Self::parse_edits(input).ok()?;
+        file_resource_accesses(true, edits.iter().map(StagedEdit::path), context)
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let failure = |message: String| ValidationResult {
+            result: false,
+            message: Some(message),
+            error_code: Some(400),
+            meta: None,
+        };
+        let edits = match Self::parse_edits(input) {
+            Ok(edits) => edits,
+            Err(message) => return failure(message),
+        };
+        for edit in &edits {
+            let operation = Self::operation(edit).verb();
+            if let Some(rejection) = edit_constraint_guard::check_edit(
+                context,
+                EDIT_TRANSACTION_TOOL_NAME,
+                operation,
+                edit.path(),
+                false,
+            ) {
+                return rejection;
+            }
+        }
+
+        let Some(context) = context else {
+            return ValidationResult::default();
+        };
+        if context.is_remote() {
+            return failure(
+                "EditTransaction is only available in local workspaces; use Edit and Write instead"
+                    .to_string(),
+            );
+        }
+        let edits = match Self::resolve_edits(edits, context) {
+            Ok(edits) => edits,
+            Err(error) => return failure(error.to_string()),
+        };
+        for edit in &edits {
+            if let Some(message) = Self::guardrail_error(edit, context).await {
+                return ValidationResult {
+                    result: false,
+                    message: Some(message),
+                    error_code: Some(400),
+                    meta: Some(json!({ "failure_kind": "guidance" })),
+                };
+            }
+        }
+        ValidationResult::default()
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        let edits = Self::parse_edits(input).unwrap_or_default();
+        let paths = edits
+            .iter()
+            .map(StagedEdit::path)
+            .collect::<std::collections::HashSet<_>>();
+        format!("Editing {} file(s) as one transaction", paths.len())
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        if context.is_remote() {
+            return Err(BitFunError::tool(
+                "EditTransaction is only available in local workspaces".to_string(),
+            ));
+        }
+        let workspace_root = context
+            .workspace_root()
+            .ok_or_else(|| BitFunError::tool("A workspace is required".to_string()))?
+            .to_path_buf();
+        let dry_run = input
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let edits = Self::parse_edits(input).map_err(BitFunError::tool)?;
+        let edits = Self::resolve_edits(edits, context)?;
+
+        let mut touched = Vec::new();
+        for edit in &edits {
+            if !touched.contains(&edit.resolved.logical_path) {
+                touched.push(edit.resolved.logical_path.clone());
+            }
+        }
+        if !dry_run {
+            context
+                .record_light_checkpoint(EDIT_TRANSACTION_TOOL_NAME, &touched[0], touched.clone())
+                .await;
+        }
+
+        let staged = edits
+            .iter()
+            .map(|edit| match &edit.edit {
+                StagedEdit::Replace {
+                    old_string,
+                    new_string,
+                    replace_all,
+                    ..
+                } => StagedEdit::Replace {
+                    path: edit.resolved.resolved_path.clone(),
+                    old_string: old_string.clone(),
+                    new_string: new_string.clone(),
+                    replace_all: *replace_all,
+                },
+                StagedEdit::Write { content, .. } => StagedEdit::Write {
+                    path: edit.resolved.resolved_path.clone(),
+                    content: content.clone(),
+                },
+            })
+            .collect::<Vec<_>>();
+        let settings = Self::load_settings().await;
+        let report = EditTransactionService::run(
+            &workspace_root,
+            &staged,
+            &Self::options(&settings, dry_run),
+        )
+        .await
+        .map_err(|error| BitFunError::tool(error.to_string()))?;
+
+        if report.status == EditTransactionStatus::Committed {
+            let mut recorded = Vec::new();
+            for edit in &edits {
+                if recorded.contains(&edit.resolved.resolved_path) {
+                    continue;
+                }
+                recorded.push(edit.resolved.resolved_path.clone());
+                if let Ok(content) = tokio::fs::read_to_string(&edit.resolved.resolved_path).await {
+                    let timestamp_ms = file_mutation_timestamp_ms(context, &edit.resolved).await;
+                    update_file_read_state_after_mutation(
+                        context,
+                        &edit.resolved,
+                        &content,
+                        timestamp_ms,
+                    );
+                }
+                edit_constraint_guard::record_mutation_applied(
+                    context,
+                    EDIT_TRANSACTION_TOOL_NAME,
+                    Self::operation(&edit.edit).verb(),
+                    &edit.resolved.logical_path,
+                );
+            }
+        }
+
+        let result_for_assistant = Self::assistant_summary(&report);
+        let mut data = serde_json::to_value(&report)?;
+        data["success"] = json!(matches!(
+            report.status,
+            EditTransactionStatus::Committed | EditTransactionStatus::Previewed
+        ));
+        data["file_paths"] = json!(touched);
+        Ok(vec![ToolResult::Result {
+            data,
+            result_for_assistant: Some(result_for_assistant),
+            image_attachments: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EditTransactionTool;
+    use crate::service::edit_transaction::StagedEdit;
+    use serde_json::json;
+
+    #[test]
+    fn parses_replacements_and_writes_in_order() {
+        let edits = EditTransactionTool::parse_edits(&json!({
+            "edits": [
+                { "file_path": "src/a.rs", "old_string": "foo", "new_string": "bar" },
+                { "file_path": "src/b.rs", "content": "fn b() {}\n" }
+            ]
+        }))
+        .expect("valid edits");
+
+        assert_eq!(
+            edits,
+            vec![
+                StagedEdit::Replace {
+                    path: "src/a.rs".to_string(),
+                    old_string: "foo".to_string(),
+                    new_string: "bar".to_string(),
+                    replace_all: false,
+                },
+                StagedEdit::Write {
+                    path: "src/b.rs".to_string(),
+                    content: "fn b() {}\n".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_ambiguous_or_incomplete_entries() {
+        assert!(EditTransactionTool::parse_edits(&json!({ "edits": [] })).is_err());
+        assert!(EditTransactionTool::parse_edits(&json!({
+            "edits": [{ "file_path": "a.rs", "old_string": "x" }]
+        }))
+        .is_err());
+        assert!(EditTransactionTool::parse_edits(&json!({
+            "edits": [{ "file_path": "a.rs", "content": "", "old_string": "x", "new_string": "y" }]
+        }))
+        .is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::agentic::tools::file_permissions::file_permission_intents;
+use crate::agentic::tools::file_permissions::{file_permission_intents, file_resource_accesses};
 use crate::agentic::tools::file_read_state_runtime::{
     assert_file_not_unexpectedly_modified, file_mutation_timestamp_ms, get_stored_file_read_state,
     local_file_modification_time_ms, read_current_file_content, read_state_tracking_enabled,
@@ -7,7 +7,8 @@ use crate::agentic::tools::file_read_state_runtime::{
 };
 use crate::agentic::tools::file_tool_guidance::file_tool_guidance_message;
 use crate::agentic::tools::framework::{
-    PermissionIntent, Tool, ToolPathResolution, ToolResult, ToolUseContext, ValidationResult,
+    PermissionIntent, Tool, ToolPathResolution, ToolResourceAccess, ToolResult, ToolUseContext,
+    ValidationResult,
 };
 use crate::agentic::tools::ToolPathOperation;
 use crate::util::errors::{BitFunError, BitFunResult};
@@ -170,6 +171,15 @@ impl Tool for FileEditTool {
         file_permission_intents("edit", [file_path], context)
     }
 
+    fn resource_accesses(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        let file_path = input.get("file_path").and_then(Value::as_str)?;
+        file_resource_accesses(true, [file_path], context)
+    }
+
     async fn validate_input(
         &self,
         input: &Value,
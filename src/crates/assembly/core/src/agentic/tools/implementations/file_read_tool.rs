@@ -1,10 +1,11 @@
-use crate::agentic::tools::file_permissions::file_permission_intents;
+use crate::agentic::tools::file_permissions::{file_permission_intents, file_resource_accesses};
 use crate::agentic::tools::file_read_state_runtime::{
     get_review_read_coverage, local_file_modification_time_ms, local_file_revision,
     record_file_read_state, record_review_read_receipt, review_read_receipts_enabled,
 };
 use crate::agentic::tools::framework::{
-    PermissionIntent, Tool, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+    PermissionIntent, Tool, ToolRenderOptions, ToolResourceAccess, ToolResult, ToolUseContext,
+    ValidationResult,
 };
 use crate::agentic::tools::workspace_paths::is_bitfun_tool_uri;
 use crate::util::errors::{BitFunError, BitFunResult};
@@ -342,6 +343,15 @@ Usage:
         file_permission_intents("read", [file_path], context)
     }
 
+    fn resource_accesses(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        let file_path = input.get("file_path").and_then(Value::as_str)?;
+        file_resource_accesses(false, [file_path], context)
+    }
+
     async fn validate_input(
         &self,
         input: &Value,
@@ -1,4 +1,4 @@
-use crate::agentic::tools::file_permissions::file_permission_intents;
+use crate::agentic::tools::file_permissions::{file_permission_intents, file_resource_accesses};
 use crate::agentic::tools::file_read_state_runtime::{
     assert_file_not_unexpectedly_modified, file_mutation_timestamp_ms, get_stored_file_read_state,
     local_file_modification_time_ms, read_current_file_content, read_state_tracking_enabled,
@@ -9,8 +9,8 @@ use crate::agentic::tools::file_tool_guidance::{
     file_tool_guidance_message, is_file_tool_guidance_message,
 };
 use crate::agentic::tools::framework::{
-    PermissionIntent, Tool, ToolPathResolution, ToolRenderOptions, ToolResult, ToolUseContext,
-    ValidationResult,
+    PermissionIntent, Tool, ToolPathResolution, ToolRenderOptions, ToolResourceAccess, ToolResult,
+    ToolUseContext, ValidationResult,
 };
 use crate::agentic::tools::ToolPathOperation;
 use crate::util::errors::{BitFunError, BitFunResult};
@@ -233,11 +233,14 @@ impl FileWriteTool {
         parameter_names
     }
 
+    /// `artifact_path` is the local file this call produced, registered as a
+    /// session artifact when the round completes.
     fn write_success_result(
         logical_path: &str,
         outcome: WriteLocalFileOutcome,
         missing_path_fallback: bool,
         ignored_parameter_names: &[String],
+        artifact_path: Option<&str>,
     ) -> ToolResult {
         let mut assistant_message = if missing_path_fallback {
             format!(
@@ -258,17 +261,21 @@ impl FileWriteTool {
                 formatted_names
             ));
         }
+        let mut data = json!({
+            "file_path": logical_path,
+            "bytes_written": outcome.bytes_written,
+            "lines_written": outcome.lines_written,
+            "success": true,
+            "status": outcome.status.as_str(),
+            "missing_path_fallback": missing_path_fallback,
+            "rename_required": missing_path_fallback,
+            "message": assistant_message,
+        });
+        if let Some(artifact_path) = artifact_path {
+            data["artifact_path"] = json!(artifact_path);
+        }
         ToolResult::Result {
-            data: json!({
-                "file_path": logical_path,
-                "bytes_written": outcome.bytes_written,
-                "lines_written": outcome.lines_written,
-                "success": true,
-                "status": outcome.status.as_str(),
-                "missing_path_fallback": missing_path_fallback,
-                "rename_required": missing_path_fallback,
-                "message": assistant_message,
-            }),
+            data,
             result_for_assistant: Some(assistant_message),
             image_attachments: None,
         }
@@ -391,6 +398,18 @@ impl Tool for FileWriteTool {
         file_permission_intents("edit", [file_path.as_str()], context)
     }
 
+    fn resource_accesses(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> Option<Vec<ToolResourceAccess>> {
+        let file_path = match Self::parse_payload(input).ok()? {
+            ParsedWritePayload::Target { file_path, .. } => file_path.to_string(),
+            ParsedWritePayload::MissingPath { .. } => Self::fallback_file_path(context),
+        };
+        file_resource_accesses(true, [file_path.as_str()], context)
+    }
+
     async fn validate_input(
         &self,
         input: &Value,
@@ -540,6 +559,7 @@ impl Tool for FileWriteTool {
                 write_same_content_outcome(&resolved.logical_path),
                 missing_path_fallback,
                 &ignored_parameter_names,
+                None,
             );
             return Ok(vec![result]);
         }
@@ -575,6 +595,7 @@ impl Tool for FileWriteTool {
                 write_file_success_outcome(&resolved.logical_path, file_already_exists, &content),
                 missing_path_fallback,
                 &ignored_parameter_names,
+                None,
             );
             return Ok(vec![result]);
         }
@@ -610,6 +631,7 @@ impl Tool for FileWriteTool {
             outcome,
             missing_path_fallback,
             &ignored_parameter_names,
+            (!missing_path_fallback).then_some(resolved.resolved_path.as_str()),
         );
 
         Ok(vec![result])
@@ -711,6 +733,7 @@ mod tests {
         assert_eq!(data["bytes_written"], 0);
         assert_eq!(data["lines_written"], 0);
         assert_eq!(data["status"], "already_exists_same_content");
+        assert!(data.get("artifact_path").is_none());
         assert!(result_for_assistant
             .as_deref()
             .unwrap_or_default()
@@ -743,6 +766,10 @@ mod tests {
         assert_eq!(data["status"], "overwritten");
         assert_eq!(data["bytes_written"], "new content".len());
         assert_eq!(data["lines_written"], 1);
+        assert_eq!(
+            data["artifact_path"].as_str().map(std::path::PathBuf::from),
+            Some(root.join("existing.md"))
+        );
     }
 
     #[tokio::test]
@@ -967,6 +994,7 @@ mod tests {
         );
         assert_eq!(data["missing_path_fallback"], true);
         assert_eq!(data["rename_required"], true);
+        assert!(data.get("artifact_path").is_none());
         assert!(result_for_assistant
             .as_deref()
             .unwrap_or_default()
@@ -0,0 +1,483 @@
+//! LSP tool
+//!
+//! Asks the workspace language server for hover information, definitions,
+//! references or diagnostics at a position, so the agent can follow types
+//! and call sites the same way the editor does.
+
+use crate::agentic::tools::framework::{
+    Tool, ToolExposure, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+};
+use crate::service::lsp::{get_global_lsp_manager, open_workspace, WorkspaceLspManager};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+const ACTIONS: &[&str] = &["hover", "definition", "references", "diagnostics"];
+const MAX_LOCATIONS: usize = 100;
+/// How long to wait for the first diagnostics of a freshly opened file.
+const DIAGNOSTICS_WAIT: Duration = Duration::from_secs(3);
+
+fn invalid(message: impl Into<String>) -> ValidationResult {
+    ValidationResult {
+        result: false,
+        message: Some(message.into()),
+        error_code: Some(400),
+        meta: None,
+    }
+}
+
+fn valid() -> ValidationResult {
+    ValidationResult {
+        result: true,
+        message: None,
+        error_code: None,
+        meta: None,
+    }
+}
+
+fn file_uri(path: &Path) -> String {
+    format!("file://{}", path.display())
+}
+
+fn uri_to_path(uri: &str) -> PathBuf {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    let decoded = urlencoding::decode(path)
+        .map(|p| p.into_owned())
+        .unwrap_or_else(|_| path.to_string());
+    PathBuf::from(decoded)
+}
+
+fn display_path(uri: &str, workspace_root: &Path) -> String {
+    let path = uri_to_path(uri);
+    path.strip_prefix(workspace_root)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Flattens `Location`, `Location[]` and `LocationLink[]` results into
+/// `path:line:character` strings (1-based).
+fn format_locations(result: &Value, workspace_root: &Path) -> Vec<String> {
+    let items = match result {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(_) => vec![result],
+        _ => Vec::new(),
+    };
+
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let uri = item
+                .get("uri")
+                .or_else(|| item.get("targetUri"))
+                .and_then(|v| v.as_str())?;
+            let start = item
+                .get("range")
+                .or_else(|| item.get("targetSelectionRange"))
+                .or_else(|| item.get("targetRange"))
+                .and_then(|range| range.get("start"))?;
+            let line = start.get("line").and_then(|v| v.as_u64())? + 1;
+            let character = start.get("character").and_then(|v| v.as_u64())? + 1;
+            Some(format!(
+                "{}:{}:{}",
+                display_path(uri, workspace_root),
+                line,
+                character
+            ))
+        })
+        .collect()
+}
+
+/// Extracts text from `MarkupContent`, `MarkedString` or an array of them.
+fn hover_text(result: &Value) -> Option<String> {
+    fn marked(value: &Value) -> Option<String> {
+        match value {
+            Value::String(text) => Some(text.clone()),
+            Value::Object(object) => {
+                let text = object.get("value").and_then(|v| v.as_str())?;
+                match object.get("language").and_then(|v| v.as_str()) {
+                    Some(language) => Some(format!("```{}\n{}\n```", language, text)),
+                    None => Some(text.to_string()),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    let contents = result.get("contents")?;
+    let text = match contents {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(marked)
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        other => marked(other)?,
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn format_diagnostic(diagnostic: &Value) -> Option<String> {
+    let start = diagnostic.get("range")?.get("start")?;
+    let line = start.get("line").and_then(|v| v.as_u64())? + 1;
+    let character = start.get("character").and_then(|v| v.as_u64())? + 1;
+    let severity = match diagnostic.get("severity").and_then(|v| v.as_u64()) {
+        Some(1) => "error",
+        Some(2) => "warning",
+        Some(3) => "info",
+        Some(4) => "hint",
+        _ => "diagnostic",
+    };
+    let message = diagnostic.get("message").and_then(|v| v.as_str())?;
+    let source = diagnostic
+        .get("source")
+        .and_then(|v| v.as_str())
+        .map(|source| format!(" [{}]", source))
+        .unwrap_or_default();
+    Some(format!(
+        "{}:{} {}{}: {}",
+        line, character, severity, source, message
+    ))
+}
+
+/// Reads a 1-based position field and converts it to the 0-based LSP form.
+fn position_field(input: &Value, field: &str) -> Result<u32, String> {
+    let value = input
+        .get(field)
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| format!("{} is required for this action", field))?;
+    if value == 0 {
+        return Err(format!("{} is 1-based", field));
+    }
+    u32::try_from(value - 1).map_err(|_| format!("{} is out of range", field))
+}
+
+/// LSP tool - language server queries for a file position
+pub struct LspTool;
+
+impl Default for LspTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LspTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Starts the file's language server if needed and opens the document.
+    /// Returns the workspace manager, the language id, the document URI and
+    /// whether the document was opened by this call.
+    async fn prepare_document(
+        workspace_root: &Path,
+        file_path: &Path,
+    ) -> BitFunResult<(Arc<WorkspaceLspManager>, String, String, bool)> {
+        // Opening the workspace also initializes the global LSP manager.
+        let workspace = open_workspace(workspace_root.to_path_buf())
+            .await
+            .map_err(|e| BitFunError::tool(e.to_string()))?;
+
+        let file_key = file_path.to_string_lossy().to_string();
+        let plugin = {
+            let manager = get_global_lsp_manager().map_err(|e| BitFunError::tool(e.to_string()))?;
+            let manager = manager.read().await;
+            manager.find_plugin_by_file(&file_key).await
+        };
+        let language = plugin
+            .and_then(|plugin| plugin.languages.first().cloned())
+            .ok_or_else(|| {
+                BitFunError::tool(format!(
+                    "No language server is configured for {}. Install an LSP plugin or add one under workspace.language_servers in settings.",
+                    file_path.display()
+                ))
+            })?;
+
+        workspace
+            .ensure_server_running(&language)
+            .await
+            .map_err(|e| {
+                BitFunError::tool(format!(
+                    "Failed to start {} language server: {}",
+                    language, e
+                ))
+            })?;
+
+        let uri = file_uri(file_path);
+        let newly_opened = !workspace.is_document_opened(&uri).await;
+        if newly_opened {
+            let content = tokio::fs::read_to_string(file_path).await?;
+            workspace
+                .open_document(uri.clone(), language.clone(), content)
+                .await
+                .map_err(|e| BitFunError::tool(e.to_string()))?;
+        }
+
+        Ok((workspace, language, uri, newly_opened))
+    }
+}
+
+#[async_trait]
+impl Tool for LspTool {
+    fn name(&self) -> &str {
+        "LSP"
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(r#"Queries the workspace language server about a position in a source file.
+
+Actions:
+- hover: type signature and documentation for the symbol at line/character
+- definition: where the symbol at line/character is defined
+- references: every usage of the symbol at line/character, including its declaration
+- diagnostics: compiler errors and warnings for the whole file (line/character not needed)
+
+Usage:
+- `line` and `character` are 1-based, matching Read output; point at any character inside the identifier
+- Requires a language server for the file type, from an installed LSP plugin or from workspace.language_servers in settings
+- The first request for a language may take a while as the server starts and indexes the project
+- Use FindSymbol to locate a definition by name when you do not yet have a position"#
+            .to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Hover, definitions, references and diagnostics from the language server.".to_string()
+    }
+
+    fn default_exposure(&self) -> ToolExposure {
+        ToolExposure::Deferred
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ACTIONS,
+                    "description": "What to ask the language server."
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Source file. Use a workspace-relative path or an absolute path inside the current workspace."
+                },
+                "line": {
+                    "type": "number",
+                    "description": "1-based line number. Required except for diagnostics."
+                },
+                "character": {
+                    "type": "number",
+                    "description": "1-based column within the line. Required except for diagnostics."
+                }
+            },
+            "required": ["action", "path"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    async fn is_available_in_context(&self, context: Option<&ToolUseContext>) -> bool {
+        !context.map(|ctx| ctx.is_remote()).unwrap_or(false)
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let action = match input.get("action").and_then(|v| v.as_str()) {
+            Some(action) if ACTIONS.contains(&action) => action,
+            _ => return invalid(format!("action must be one of {}", ACTIONS.join(", "))),
+        };
+        match input.get("path").and_then(|v| v.as_str()) {
+            Some(path) if !path.trim().is_empty() => {}
+            _ => return invalid("path is required"),
+        }
+        if action != "diagnostics" {
+            for field in ["line", "character"] {
+                if let Err(message) = position_field(input, field) {
+                    return invalid(message);
+                }
+            }
+        }
+        valid()
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        let action = input
+            .get("action")
+            .and_then(|v| v.as_str())
+            .unwrap_or("query");
+        match input.get("path").and_then(|v| v.as_str()) {
+            Some(path) => match input.get("line").and_then(|v| v.as_u64()) {
+                Some(line) => format!("LSP {} {}:{}", action, path, line),
+                None => format!("LSP {} {}", action, path),
+            },
+            None => format!("LSP {}", action),
+        }
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        if context.is_remote() {
+            return Err(BitFunError::tool(
+                "Language servers are not available for remote workspaces",
+            ));
+        }
+        let action = input
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| BitFunError::tool("action is required"))?;
+        let path = input
+            .get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| BitFunError::tool("path is required"))?;
+        let workspace_root = context
+            .workspace_root()
+            .ok_or_else(|| BitFunError::tool("No workspace is open"))?
+            .to_path_buf();
+        let file_path = PathBuf::from(context.resolve_tool_path(path)?.resolved_path);
+        if !file_path.is_file() {
+            return Err(BitFunError::tool(format!(
+                "File not found: {}",
+                file_path.display()
+            )));
+        }
+
+        let (workspace, language, uri, newly_opened) =
+            Self::prepare_document(&workspace_root, &file_path).await?;
+        let relative = display_path(&uri, &workspace_root);
+
+        let (data, text) = if action == "diagnostics" {
+            let mut diagnostics = workspace
+                .get_diagnostics(&uri)
+                .await
+                .map_err(|e| BitFunError::tool(e.to_string()))?;
+            if newly_opened && diagnostics.is_empty() {
+                let deadline = tokio::time::Instant::now() + DIAGNOSTICS_WAIT;
+                while diagnostics.is_empty() && tokio::time::Instant::now() < deadline {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    diagnostics = workspace
+                        .get_diagnostics(&uri)
+                        .await
+                        .map_err(|e| BitFunError::tool(e.to_string()))?;
+                }
+            }
+            let lines: Vec<String> = diagnostics.iter().filter_map(format_diagnostic).collect();
+            let text = if lines.is_empty() {
+                format!("{}: no diagnostics", relative)
+            } else {
+                format!("{}\n{}", relative, lines.join("\n"))
+            };
+            (json!({ "diagnostics": diagnostics }), text)
+        } else {
+            let line = position_field(input, "line").map_err(BitFunError::tool)?;
+            let character = position_field(input, "character").map_err(BitFunError::tool)?;
+            let result = match action {
+                "hover" => workspace.get_hover(&language, &uri, line, character).await,
+                "definition" => {
+                    workspace
+                        .goto_definition(&language, &uri, line, character)
+                        .await
+                }
+                _ => {
+                    workspace
+                        .find_references(&language, &uri, line, character)
+                        .await
+                }
+            }
+            .map_err(|e| BitFunError::tool(e.to_string()))?;
+
+            let text = if action == "hover" {
+                hover_text(&result)
+                    .unwrap_or_else(|| format!("No hover information at {}:{}", relative, line + 1))
+            } else {
+                let mut locations = format_locations(&result, &workspace_root);
+                if locations.is_empty() {
+                    format!("No {} found at {}:{}", action, relative, line + 1)
+                } else {
+                    let total = locations.len();
+                    locations.truncate(MAX_LOCATIONS);
+                    let mut text = locations.join("\n");
+                    if total > MAX_LOCATIONS {
+                        text.push_str(&format!(
+                            "\n(showing {} of {} locations)",
+                            MAX_LOCATIONS, total
+                        ));
+                    }
+                    text
+                }
+            };
+            (result, text)
+        };
+
+        Ok(vec![ToolResult::Result {
+            data,
+            result_for_assistant: Some(text),
+            image_attachments: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locations_and_location_links_are_flattened() {
+        let root = Path::new("/work");
+        let result = json!([
+            {
+                "uri": "file:///work/src/lib.rs",
+                "range": { "start": { "line": 9, "character": 4 }, "end": { "line": 9, "character": 9 } }
+            },
+            {
+                "targetUri": "file:///work/src/my%20mod.rs",
+                "targetRange": { "start": { "line": 0, "character": 0 }, "end": { "line": 5, "character": 1 } },
+                "targetSelectionRange": { "start": { "line": 2, "character": 7 }, "end": { "line": 2, "character": 12 } }
+            }
+        ]);
+
+        assert_eq!(
+            format_locations(&result, root),
+            vec![
+                "src/lib.rs:10:5".to_string(),
+                "src/my mod.rs:3:8".to_string()
+            ]
+        );
+        assert!(format_locations(&Value::Null, root).is_empty());
+    }
+
+    #[test]
+    fn hover_text_handles_markup_and_marked_strings() {
+        assert_eq!(
+            hover_text(&json!({ "contents": { "kind": "markdown", "value": "fn parse()" } })),
+            Some("fn parse()".to_string())
+        );
+        assert_eq!(
+            hover_text(&json!({ "contents": [{ "language": "rust", "value": "u32" }, "docs"] })),
+            Some("```rust\nu32\n```\n\ndocs".to_string())
+        );
+        assert_eq!(hover_text(&json!({ "contents": "" })), None);
+    }
+
+    #[test]
+    fn positions_are_one_based() {
+        assert_eq!(position_field(&json!({ "line": 1 }), "line"), Ok(0));
+        assert!(position_field(&json!({ "line": 0 }), "line").is_err());
+        assert!(position_field(&json!({}), "character").is_err());
+    }
+}
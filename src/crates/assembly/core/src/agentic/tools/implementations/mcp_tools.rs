@@ -4,17 +4,22 @@ use crate::agentic::tools::framework::{
     PermissionIntent, Tool, ToolExposure, ToolRenderOptions, ToolResult, ToolUseContext,
     ValidationResult,
 };
-use crate::service::mcp::adapter::PromptAdapter;
+use crate::service::mcp::adapter::{
+    attach_mcp_resources, MCPResourceAttachment, MCPResourceAttachmentOptions,
+    MCPResourceAttachmentPart, PromptAdapter, DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET,
+};
 use crate::service::mcp::get_global_mcp_service;
 use crate::service::mcp::protocol::{MCPPrompt, MCPResource, MCPResourceContent};
 use crate::service::mcp::MCPServerManager;
 use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::types::ToolImageAttachment;
 use async_trait::async_trait;
 use serde_json::{json, Value};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 const DEFAULT_RENDER_CHAR_LIMIT: usize = 32_000;
+const MAX_ATTACHMENT_TOKEN_BUDGET: usize = 64_000;
 
 fn tool_error(message: impl Into<String>) -> BitFunError {
     BitFunError::tool(message.into())
@@ -188,6 +193,94 @@ fn render_resource_contents(contents: &[MCPResourceContent], max_chars: usize) -
     rendered
 }
 
+fn render_resource_attachments(attachments: &[MCPResourceAttachment]) -> String {
+    attachments
+        .iter()
+        .flat_map(|attachment| &attachment.parts)
+        .map(|part| match part {
+            MCPResourceAttachmentPart::Text {
+                uri,
+                mime_type,
+                text,
+                truncated,
+                ..
+            } => {
+                let mut rendered = format!("Resource URI: {}", uri);
+                if let Some(mime_type) = mime_type {
+                    rendered.push_str(&format!("\nMIME type: {}", mime_type));
+                }
+                rendered.push_str("\n\n");
+                rendered.push_str(text);
+                if *truncated {
+                    rendered.push_str("\n\n[Truncated to fit the attachment token budget.]");
+                }
+                rendered
+            }
+            MCPResourceAttachmentPart::Image { uri, mime_type, .. } => {
+                format!(
+                    "Resource URI: {}\n\n[Attached as image: {}]",
+                    uri, mime_type
+                )
+            }
+            MCPResourceAttachmentPart::Omitted { uri, reason, .. } => {
+                format!("Resource URI: {}\n\n[Not attached: {}]", uri, reason)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Attachment metadata for the tool result; image data travels separately as
+/// image attachments.
+fn summarize_resource_attachment(attachment: &MCPResourceAttachment) -> Value {
+    let parts = attachment
+        .parts
+        .iter()
+        .map(|part| match part {
+            MCPResourceAttachmentPart::Text {
+                uri,
+                mime_type,
+                estimated_tokens,
+                truncated,
+                ..
+            } => json!({
+                "kind": "text",
+                "uri": uri,
+                "mime_type": mime_type,
+                "estimated_tokens": estimated_tokens,
+                "truncated": truncated,
+            }),
+            MCPResourceAttachmentPart::Image {
+                uri,
+                mime_type,
+                estimated_tokens,
+                ..
+            } => json!({
+                "kind": "image",
+                "uri": uri,
+                "mime_type": mime_type,
+                "estimated_tokens": estimated_tokens,
+            }),
+            MCPResourceAttachmentPart::Omitted {
+                uri,
+                mime_type,
+                reason,
+            } => json!({
+                "kind": "omitted",
+                "uri": uri,
+                "mime_type": mime_type,
+                "reason": reason,
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "uri": attachment.uri,
+        "estimated_tokens": attachment.estimated_tokens,
+        "parts": parts,
+    })
+}
+
 fn render_prompt_catalog(prompts: &[MCPPrompt]) -> String {
     if prompts.is_empty() {
         return "No MCP prompts available.".to_string();
@@ -489,6 +582,238 @@ impl Tool for ReadMCPResourceTool {
     }
 }
 
+pub struct AttachMCPResourcesTool;
+
+impl Default for AttachMCPResourcesTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttachMCPResourcesTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn uris_from_input(input: &Value) -> Vec<String> {
+        input
+            .get("uris")
+            .and_then(Value::as_array)
+            .map(|uris| {
+                uris.iter()
+                    .filter_map(Value::as_str)
+                    .map(str::trim)
+                    .filter(|uri| !uri.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Tool for AttachMCPResourcesTool {
+    fn name(&self) -> &str {
+        "AttachMCPResources"
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok("Attaches one or more MCP resources to the conversation as context. Markdown and other text are passed through, PDFs are converted to text, and images are attached for vision-capable models. All resources share one token budget; content that does not fit is truncated or reported as not attached. Use ListMCPResources first to find resource URIs.".to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Attach MCP resources to the conversation as context.".to_string()
+    }
+
+    fn default_exposure(&self) -> ToolExposure {
+        ToolExposure::Deferred
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "server_id": {
+                    "type": "string",
+                    "description": "The MCP server ID that owns the resources."
+                },
+                "uris": {
+                    "type": "array",
+                    "description": "Resource URIs to attach, in priority order. Later resources are cut first when the budget runs out.",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1
+                },
+                "token_budget": {
+                    "type": "integer",
+                    "description": format!(
+                        "Estimated tokens shared by all attached resources (default {}, max {}).",
+                        DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET, MAX_ATTACHMENT_TOKEN_BUDGET
+                    ),
+                    "minimum": 1,
+                    "maximum": MAX_ATTACHMENT_TOKEN_BUDGET
+                }
+            },
+            "required": ["server_id", "uris"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    fn permission_intents(
+        &self,
+        input: &Value,
+        _context: &ToolUseContext,
+    ) -> BitFunResult<Vec<PermissionIntent>> {
+        let server_id = mcp_input_string(input, "server_id");
+        let resources = Self::uris_from_input(input)
+            .into_iter()
+            .map(|uri| format!("{}:{}", server_id, uri))
+            .collect();
+        Ok(vec![PermissionIntent::new("mcp", resources)])
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let server_validation = validate_required_string(input, "server_id");
+        if !server_validation.result {
+            return server_validation;
+        }
+
+        let invalid = |message: &str| ValidationResult {
+            result: false,
+            message: Some(message.to_string()),
+            error_code: Some(400),
+            meta: None,
+        };
+        let Some(uris) = input.get("uris").and_then(Value::as_array) else {
+            return invalid("uris is required");
+        };
+        if uris.is_empty() {
+            return invalid("uris cannot be empty");
+        }
+        if uris
+            .iter()
+            .any(|uri| uri.as_str().is_none_or(|uri| uri.trim().is_empty()))
+        {
+            return invalid("uris must be non-empty strings");
+        }
+        if let Some(budget) = input.get("token_budget") {
+            let in_range = budget
+                .as_u64()
+                .is_some_and(|budget| (1..=MAX_ATTACHMENT_TOKEN_BUDGET as u64).contains(&budget));
+            if !in_range {
+                return invalid(&format!(
+                    "token_budget must be an integer between 1 and {}",
+                    MAX_ATTACHMENT_TOKEN_BUDGET
+                ));
+            }
+        }
+
+        ValidationResult::default()
+    }
+
+    fn render_tool_use_message(&self, input: &Value, options: &ToolRenderOptions) -> String {
+        let uris = Self::uris_from_input(input);
+        let target = match uris.as_slice() {
+            [uri] => uri.clone(),
+            uris => format!("{} resources", uris.len()),
+        };
+        if options.verbose {
+            format!("Attaching MCP resource context: {}", target)
+        } else {
+            format!("Attach MCP {}", target)
+        }
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        let server_id = input
+            .get("server_id")
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| tool_error("server_id is required"))?;
+        let uris = Self::uris_from_input(input);
+        if uris.is_empty() {
+            return Err(tool_error("uris is required"));
+        }
+        let token_budget = input
+            .get("token_budget")
+            .and_then(Value::as_u64)
+            .map(|budget| (budget as usize).min(MAX_ATTACHMENT_TOKEN_BUDGET))
+            .unwrap_or(DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET);
+        let facts = context.primary_model_facts();
+        let include_images = context.primary_model_supports_image_understanding()
+            && facts.multimodal_tool_output_supported();
+        let options = MCPResourceAttachmentOptions {
+            token_budget,
+            include_images,
+            image_provider: include_images.then(|| facts.api_format.to_lowercase()),
+        };
+
+        let manager = get_mcp_server_manager().await?;
+        ensure_mcp_server_available_for_context(&manager, server_id, context).await?;
+        let connection = manager
+            .get_connection(server_id)
+            .await
+            .ok_or_else(|| tool_error(format!("MCP server not connected: {}", server_id)))?;
+        let attachments = attach_mcp_resources(&connection, server_id, &uris, &options).await?;
+
+        let rendered = render_resource_attachments(&attachments);
+        let estimated_tokens = attachments
+            .iter()
+            .map(|attachment| attachment.estimated_tokens)
+            .sum::<usize>();
+        let images = attachments
+            .iter()
+            .flat_map(|attachment| &attachment.parts)
+            .filter_map(|part| match part {
+                MCPResourceAttachmentPart::Image {
+                    mime_type,
+                    data_base64,
+                    ..
+                } => Some(ToolImageAttachment {
+                    mime_type: mime_type.clone(),
+                    data_base64: data_base64.clone(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let data = json!({
+            "server_id": server_id,
+            "token_budget": token_budget,
+            "estimated_tokens": estimated_tokens,
+            "attachments": attachments
+                .iter()
+                .map(summarize_resource_attachment)
+                .collect::<Vec<_>>(),
+        });
+
+        if images.is_empty() {
+            Ok(vec![ToolResult::ok(data, Some(rendered))])
+        } else {
+            Ok(vec![ToolResult::ok_with_images(
+                data,
+                Some(rendered),
+                images,
+            )])
+        }
+    }
+}
+
 pub struct ListMCPPromptsTool;
 
 impl Default for ListMCPPromptsTool {
@@ -810,7 +1135,10 @@ impl Tool for GetMCPPromptTool {
 
 #[cfg(test)]
 mod tests {
-    use super::{GetMCPPromptTool, ListMCPPromptsTool, ListMCPResourcesTool, ReadMCPResourceTool};
+    use super::{
+        AttachMCPResourcesTool, GetMCPPromptTool, ListMCPPromptsTool, ListMCPResourcesTool,
+        ReadMCPResourceTool,
+    };
     use crate::agentic::tools::framework::{Tool, ToolUseContext};
     use serde_json::json;
 
@@ -819,6 +1147,7 @@ mod tests {
         let context = ToolUseContext::for_tool_listing(None, None);
         let list_resources = ListMCPResourcesTool::new();
         let read_resource = ReadMCPResourceTool::new();
+        let attach_resources = AttachMCPResourcesTool::new();
         let list_prompts = ListMCPPromptsTool::new();
         let get_prompt = GetMCPPromptTool::new();
         let cases: Vec<(&dyn Tool, serde_json::Value, &str)> = vec![
@@ -832,6 +1161,11 @@ mod tests {
                 json!({ "server_id": "docs", "uri": "docs://permission/v2" }),
                 "docs:docs://permission/v2",
             ),
+            (
+                &attach_resources,
+                json!({ "server_id": "docs", "uris": ["docs://permission/v2"] }),
+                "docs:docs://permission/v2",
+            ),
             (
                 &list_prompts,
                 json!({ "server_id": "docs" }),
@@ -6,6 +6,7 @@ pub mod ask_user_question_tool;
 pub mod bash_tool;
 #[cfg(feature = "canvas-runtime")]
 pub mod canvas_tools;
+pub mod code_index_tools;
 pub mod code_review_tool;
 pub mod computer_use_actions;
 pub mod computer_use_input;
@@ -17,6 +18,7 @@ pub mod control_hub_tool;
 pub mod create_plan_tool;
 pub mod cron_tool;
 pub mod delete_file_tool;
+pub mod edit_transaction_tool;
 pub mod exec_command;
 pub mod file_edit_tool;
 pub mod file_read_tool;
@@ -29,12 +31,15 @@ pub mod glob_tool;
 pub mod grep_tool;
 pub mod list_models_tool;
 pub mod ls_tool;
+pub mod lsp_tool;
 pub mod mcp_tools;
 pub mod miniapp_init_tool;
 pub mod page_deploy_tool;
 pub mod page_publish_tool;
 pub mod playbook_tool;
+pub mod read_more_tool;
 pub mod review_platform_tool;
+pub mod search_tools_tool;
 pub mod session_control_tool;
 pub mod session_history_tool;
 pub mod session_message_tool;
@@ -56,12 +61,14 @@ pub use ask_user_question_tool::AskUserQuestionTool;
 pub use bash_tool::BashTool;
 #[cfg(feature = "canvas-runtime")]
 pub use canvas_tools::{CreateCanvasTool, PatchCanvasTool, ReadCanvasTool, UpdateCanvasTool};
+pub use code_index_tools::{FileOutlineTool, FindSymbolTool};
 pub use code_review_tool::CodeReviewTool;
 pub use computer_use_tool::ComputerUseTool;
 pub use control_hub_tool::ControlHubTool;
 pub use create_plan_tool::CreatePlanTool;
 pub use cron_tool::CronTool;
 pub use delete_file_tool::DeleteFileTool;
+pub use edit_transaction_tool::EditTransactionTool;
 pub use exec_command::{ExecCommandTool, ExecControlTool, WriteStdinTool};
 pub use file_edit_tool::FileEditTool;
 pub use file_read_tool::FileReadTool;
@@ -74,14 +81,18 @@ pub use glob_tool::GlobTool;
 pub use grep_tool::GrepTool;
 pub use list_models_tool::ListModelsTool;
 pub use ls_tool::LSTool;
+pub use lsp_tool::LspTool;
 pub use mcp_tools::{
-    GetMCPPromptTool, ListMCPPromptsTool, ListMCPResourcesTool, ReadMCPResourceTool,
+    AttachMCPResourcesTool, GetMCPPromptTool, ListMCPPromptsTool, ListMCPResourcesTool,
+    ReadMCPResourceTool,
 };
 pub use miniapp_init_tool::InitMiniAppTool;
 pub use page_deploy_tool::PageDeployTool;
 pub use page_publish_tool::PagePublishTool;
 pub use playbook_tool::PlaybookTool;
+pub use read_more_tool::ReadMoreTool;
 pub use review_platform_tool::ReviewPlatformTool;
+pub use search_tools_tool::{search_available_tools, SearchToolsTool};
 pub use session_control_tool::SessionControlTool;
 pub use session_history_tool::SessionHistoryTool;
 pub use session_message_tool::SessionMessageTool;
@@ -0,0 +1,252 @@
+//! ReadMore tool implementation.
+//!
+//! Pages through tool output that was too large to send to the model and was
+//! stored for the session instead (see `tool_result_storage`).
+
+use crate::agentic::tools::framework::{
+    Tool, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+};
+use crate::agentic::tools::tool_result_storage::read_persisted_tool_output;
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use bitfun_agent_tools::READ_MORE_TOOL_NAME;
+use serde_json::{json, Value};
+
+const DEFAULT_READ_MORE_LINES: usize = 400;
+const MAX_READ_MORE_LINES: usize = 2_000;
+/// A single page stays well under the per-result storage limit so it is
+/// never persisted again.
+const MAX_READ_MORE_CHARS: usize = 30_000;
+
+/// ReadMore tool - returns a line range of stored tool output.
+pub struct ReadMoreTool;
+
+impl Default for ReadMoreTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReadMoreTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn line_range(input: &Value) -> BitFunResult<(usize, usize)> {
+        let offset = match input.get("offset") {
+            None => 1,
+            Some(value) => value
+                .as_u64()
+                .filter(|offset| *offset >= 1)
+                .ok_or_else(|| {
+                    BitFunError::tool("offset must be a positive line number".to_string())
+                })? as usize,
+        };
+        let limit =
+            match input.get("limit") {
+                None => DEFAULT_READ_MORE_LINES,
+                Some(value) => value.as_u64().filter(|limit| *limit >= 1).ok_or_else(|| {
+                    BitFunError::tool("limit must be a positive integer".to_string())
+                })? as usize,
+            };
+        Ok((offset, limit.min(MAX_READ_MORE_LINES)))
+    }
+}
+
+/// Returns the requested lines, stopping early at `MAX_READ_MORE_CHARS`, and
+/// the number of the last line included.
+fn page_lines(content: &str, offset: usize, limit: usize) -> (String, usize) {
+    let mut page = String::new();
+    let mut last_line = offset.saturating_sub(1);
+    for line in content.lines().skip(offset - 1).take(limit) {
+        if !page.is_empty() && page.len() + line.len() + 1 > MAX_READ_MORE_CHARS {
+            break;
+        }
+        page.push_str(line);
+        page.push('\n');
+        last_line += 1;
+    }
+    (page, last_line)
+}
+
+#[async_trait]
+impl Tool for ReadMoreTool {
+    fn name(&self) -> &str {
+        READ_MORE_TOOL_NAME
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(r#"Read a line range of tool output that was too large to return in full.
+
+When a tool result starts with <persisted-output>, it shows only a preview together with an `Output id`. Call this tool with that id to read further lines instead of re-running the original command.
+
+Parameters:
+- output_id: the id from the <persisted-output> block
+- offset: first line to return, 1-based (default 1)
+- limit: number of lines to return (default 400, max 2000)
+
+Long lines may end a page early; continue from the `next_offset` in the result."#
+            .to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Read a line range of stored oversized tool output.".to_string()
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "output_id": {
+                    "type": "string",
+                    "description": "Output id from a <persisted-output> block."
+                },
+                "offset": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "First line to return, 1-based. Defaults to 1."
+                },
+                "limit": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": MAX_READ_MORE_LINES,
+                    "description": "Number of lines to return. Defaults to 400."
+                }
+            },
+            "required": ["output_id"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let message = if input
+            .get("output_id")
+            .and_then(Value::as_str)
+            .is_none_or(|id| id.trim().is_empty())
+        {
+            Some("output_id is required".to_string())
+        } else {
+            Self::line_range(input).err().map(|error| error.to_string())
+        };
+        ValidationResult {
+            result: message.is_none(),
+            error_code: message.as_ref().map(|_| 400),
+            message,
+            meta: None,
+        }
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        let output_id = input
+            .get("output_id")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        format!("Read more of {}", output_id)
+    }
+
+    fn render_tool_result_message(&self, output: &Value) -> String {
+        match (
+            output.get("start_line").and_then(Value::as_u64),
+            output.get("end_line").and_then(Value::as_u64),
+            output.get("total_lines").and_then(Value::as_u64),
+        ) {
+            (Some(start), Some(end), Some(total)) => {
+                format!("Lines {}-{} of {}", start, end, total)
+            }
+            _ => "Stored output read".to_string(),
+        }
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        let output_id = input
+            .get("output_id")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| BitFunError::tool("output_id is required".to_string()))?;
+        let (offset, limit) = Self::line_range(input)?;
+
+        let content = read_persisted_tool_output(output_id, context).await?;
+        let total_lines = content.lines().count();
+        if offset > total_lines.max(1) {
+            return Err(BitFunError::tool(format!(
+                "offset {} is past the end of output '{}' ({} lines)",
+                offset, output_id, total_lines
+            )));
+        }
+
+        let (page, end_line) = page_lines(&content, offset, limit);
+        let next_offset = (end_line < total_lines).then_some(end_line + 1);
+        let mut result_for_assistant = format!(
+            "Output {} lines {}-{} of {}:\n{}",
+            output_id, offset, end_line, total_lines, page
+        );
+        if let Some(next_offset) = next_offset {
+            result_for_assistant.push_str(&format!(
+                "... (more lines remain; continue with offset {})",
+                next_offset
+            ));
+        }
+
+        Ok(vec![ToolResult::Result {
+            data: json!({
+                "output_id": output_id,
+                "start_line": offset,
+                "end_line": end_line,
+                "total_lines": total_lines,
+                "next_offset": next_offset,
+            }),
+            result_for_assistant: Some(result_for_assistant),
+            image_attachments: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_lines, ReadMoreTool, MAX_READ_MORE_CHARS, MAX_READ_MORE_LINES};
+    use serde_json::json;
+
+    #[test]
+    fn line_range_defaults_and_caps_the_limit() {
+        assert_eq!(ReadMoreTool::line_range(&json!({})).unwrap(), (1, 400));
+        assert_eq!(
+            ReadMoreTool::line_range(&json!({ "offset": 10, "limit": 100_000 })).unwrap(),
+            (10, MAX_READ_MORE_LINES)
+        );
+        assert!(ReadMoreTool::line_range(&json!({ "offset": 0 })).is_err());
+    }
+
+    #[test]
+    fn pages_stop_at_the_line_limit_or_char_budget() {
+        let content = (1..=10)
+            .map(|n| format!("line {n}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (page, end_line) = page_lines(&content, 3, 2);
+        assert_eq!(page, "line 3\nline 4\n");
+        assert_eq!(end_line, 4);
+
+        let long_line = "x".repeat(MAX_READ_MORE_CHARS / 2 + 1);
+        let content = format!("{long_line}\n{long_line}\n{long_line}");
+        let (page, end_line) = page_lines(&content, 1, 3);
+        assert_eq!(end_line, 1);
+        assert!(page.len() <= MAX_READ_MORE_CHARS + 1);
+    }
+}
@@ -7,8 +7,9 @@ use crate::agentic::tools::framework::{
     Tool, ToolExposure, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
 };
 use crate::service::review_platform::{
-    ReviewPlatformApprovalRequest, ReviewPlatformCreatePullRequestRequest,
-    ReviewPlatformDetailSection, ReviewPlatformError, ReviewPlatformKind, ReviewPlatformRemote,
+    ReviewIssueStateFilter, ReviewPlatformApprovalRequest, ReviewPlatformCreateBranchRequest,
+    ReviewPlatformCreatePullRequestRequest, ReviewPlatformDetailSection, ReviewPlatformError,
+    ReviewPlatformKind, ReviewPlatformPushBranchRequest, ReviewPlatformRemote,
     ReviewPlatformReplyToThreadRequest, ReviewPlatformRequestChangesRequest,
     ReviewPlatformResolveThreadRequest, ReviewPlatformService, ReviewPlatformSubmitReviewRequest,
     ReviewSubmitEvent,
@@ -20,11 +21,15 @@ use std::collections::BTreeMap;
 
 const ACTION_WORKSPACE_SNAPSHOT: &str = "get_workspace_snapshot";
 const ACTION_LIST_REMOTES: &str = "list_remotes";
+const ACTION_LIST_ISSUES: &str = "list_issues";
 const ACTION_LIST: &str = "list_pull_requests";
 const ACTION_COUNT: &str = "count_pull_requests";
 const ACTION_GET: &str = "get_pull_request";
 const ACTION_GET_DETAIL_PAGE: &str = "get_pull_request_detail_page";
 const ACTION_GET_CI_LOG: &str = "get_pull_request_ci_log";
+const ACTION_GET_REVIEW_COMMENTS: &str = "get_review_comments";
+const ACTION_CREATE_BRANCH: &str = "create_branch";
+const ACTION_PUSH_BRANCH: &str = "push_branch";
 const ACTION_CREATE: &str = "create_pull_request";
 const ACTION_REPLY: &str = "reply_to_thread";
 const ACTION_SUBMIT_REVIEW: &str = "submit_review";
@@ -36,6 +41,8 @@ const ACTION_UPDATE_AUTH_TOKEN: &str = "update_auth_token";
 const ACTION_CLEAR_AUTH_TOKEN: &str = "clear_auth_token";
 
 const WRITE_ACTIONS: &[&str] = &[
+    ACTION_CREATE_BRANCH,
+    ACTION_PUSH_BRANCH,
     ACTION_CREATE,
     ACTION_REPLY,
     ACTION_SUBMIT_REVIEW,
@@ -125,6 +132,18 @@ impl ReviewPlatformTool {
         }
     }
 
+    fn issue_state(input: &Value) -> BitFunResult<ReviewIssueStateFilter> {
+        match input.get("state").and_then(Value::as_str).unwrap_or("open") {
+            "open" => Ok(ReviewIssueStateFilter::Open),
+            "closed" => Ok(ReviewIssueStateFilter::Closed),
+            "all" => Ok(ReviewIssueStateFilter::All),
+            other => Err(BitFunError::tool(format!(
+                "Unsupported issue state: {}",
+                other
+            ))),
+        }
+    }
+
     fn platform_kind(input: &Value) -> BitFunResult<ReviewPlatformKind> {
         match Self::string_field(input, "platform")?.as_str() {
             "github" => Ok(ReviewPlatformKind::Github),
@@ -240,7 +259,9 @@ impl Tool for ReviewPlatformTool {
     async fn description(&self) -> BitFunResult<String> {
         Ok(r#"Read and operate on hosted pull requests / merge requests.
 
-Use this for remote review-platform operations such as discovering remotes, loading the workspace PR snapshot, counting pull requests, listing pull requests, opening full or paginated pull request detail, loading CI logs, creating a pull request, replying to review threads, submitting a comment review, approving, revoking approval, requesting changes, or resolving a review thread. Use the Git tool for local repository state and branch/commit/push operations.
+Use this for remote review-platform operations such as discovering remotes, listing issues, loading the workspace PR snapshot, counting pull requests, listing pull requests, opening full or paginated pull request detail, loading CI logs, loading review comments as Markdown context, creating a pull request, replying to review threads, submitting a comment review, approving, revoking approval, requesting changes, or resolving a review thread.
+
+To go from an issue to a pull request: list_issues to pick the issue, create_branch, make and commit the changes with the Git tool, push_branch, then create_pull_request. push_branch never force-pushes. Use get_review_comments to load reviewer feedback before addressing it. Use the Git tool for other local repository state and commit operations.
 
 GitHub authentication is owned by the local `gh` CLI and must never use token actions. Authentication-token actions are only for GitLab and GitCode when the user explicitly provides a token or asks to clear a stored token. Never guess or expose token values.
 
@@ -264,11 +285,15 @@ When returning pull request results to the user, include the provider web URL so
                     "enum": [
                         ACTION_WORKSPACE_SNAPSHOT,
                         ACTION_LIST_REMOTES,
+                        ACTION_LIST_ISSUES,
                         ACTION_LIST,
                         ACTION_COUNT,
                         ACTION_GET,
                         ACTION_GET_DETAIL_PAGE,
                         ACTION_GET_CI_LOG,
+                        ACTION_GET_REVIEW_COMMENTS,
+                        ACTION_CREATE_BRANCH,
+                        ACTION_PUSH_BRANCH,
                         ACTION_CREATE,
                         ACTION_REPLY,
                         ACTION_SUBMIT_REVIEW,
@@ -295,11 +320,36 @@ When returning pull request results to the user, include the provider web URL so
                 },
                 "page": {
                     "type": "integer",
-                    "description": "Page number for list_pull_requests, get_workspace_snapshot, or get_pull_request_detail_page."
+                    "description": "Page number for list_issues, list_pull_requests, get_workspace_snapshot, or get_pull_request_detail_page."
                 },
                 "per_page": {
                     "type": "integer",
-                    "description": "Page size for list_pull_requests, get_workspace_snapshot, or get_pull_request_detail_page."
+                    "description": "Page size for list_issues, list_pull_requests, get_workspace_snapshot, or get_pull_request_detail_page."
+                },
+                "state": {
+                    "type": "string",
+                    "enum": ["open", "closed", "all"],
+                    "description": "Issue state for list_issues. Defaults to open."
+                },
+                "branch_name": {
+                    "type": "string",
+                    "description": "Local branch for create_branch (required) or push_branch (defaults to the checked-out branch)."
+                },
+                "start_point": {
+                    "type": "string",
+                    "description": "Commit, tag, or branch create_branch starts from. Defaults to HEAD."
+                },
+                "checkout": {
+                    "type": "boolean",
+                    "description": "Whether create_branch switches to the new branch. Defaults to true."
+                },
+                "set_upstream": {
+                    "type": "boolean",
+                    "description": "Whether push_branch records the remote branch as upstream. Defaults to true."
+                },
+                "include_resolved": {
+                    "type": "boolean",
+                    "description": "Whether get_review_comments includes resolved threads. Defaults to false."
                 },
                 "section": {
                     "type": "string",
@@ -392,11 +442,15 @@ When returning pull request results to the user, include the provider web URL so
         let valid = [
             ACTION_WORKSPACE_SNAPSHOT,
             ACTION_LIST_REMOTES,
+            ACTION_LIST_ISSUES,
             ACTION_LIST,
             ACTION_COUNT,
             ACTION_GET,
             ACTION_GET_DETAIL_PAGE,
             ACTION_GET_CI_LOG,
+            ACTION_GET_REVIEW_COMMENTS,
+            ACTION_CREATE_BRANCH,
+            ACTION_PUSH_BRANCH,
             ACTION_CREATE,
             ACTION_REPLY,
             ACTION_SUBMIT_REVIEW,
@@ -428,6 +482,7 @@ When returning pull request results to the user, include the provider web URL so
         match action {
             ACTION_WORKSPACE_SNAPSHOT => "Load review platform workspace snapshot".to_string(),
             ACTION_LIST_REMOTES => "List review platform remotes".to_string(),
+            ACTION_LIST_ISSUES => "List issues".to_string(),
             ACTION_LIST => "List pull requests".to_string(),
             ACTION_COUNT => "Count pull requests".to_string(),
             ACTION_GET => format!(
@@ -439,6 +494,15 @@ When returning pull request results to the user, include the provider web URL so
             ),
             ACTION_GET_DETAIL_PAGE => "Load pull request detail page".to_string(),
             ACTION_GET_CI_LOG => "Load pull request CI log".to_string(),
+            ACTION_GET_REVIEW_COMMENTS => "Load pull request review comments".to_string(),
+            ACTION_CREATE_BRANCH => format!(
+                "Create branch {}",
+                input
+                    .get("branch_name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("")
+            ),
+            ACTION_PUSH_BRANCH => "Push branch".to_string(),
             ACTION_CREATE => "Create pull request".to_string(),
             ACTION_REPLY => "Reply to pull request thread".to_string(),
             ACTION_SUBMIT_REVIEW => "Submit pull request review".to_string(),
@@ -718,6 +782,51 @@ When returning pull request results to the user, include the provider web URL so
                     }
                 )
             }
+            ACTION_LIST_ISSUES => {
+                let issues = output
+                    .get("issues")
+                    .and_then(Value::as_array)
+                    .map(|items| items.as_slice())
+                    .unwrap_or(&[]);
+                let remote_id = output.get("remoteId").and_then(Value::as_str).unwrap_or("");
+                let has_next = output
+                    .pointer("/pagination/hasNext")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false);
+                let mut lines = vec![format!(
+                    "Remote {} returned {} issues.{}",
+                    remote_id,
+                    issues.len(),
+                    if has_next {
+                        " More pages are available."
+                    } else {
+                        ""
+                    }
+                )];
+                lines.extend(issues.iter().map(|issue| {
+                    let id = issue.get("issueId").and_then(Value::as_str).unwrap_or("");
+                    let title = issue
+                        .get("title")
+                        .and_then(Value::as_str)
+                        .unwrap_or("Untitled");
+                    let state = issue
+                        .get("state")
+                        .and_then(Value::as_str)
+                        .unwrap_or("unknown");
+                    let url = issue.get("webUrl").and_then(Value::as_str).unwrap_or("");
+                    if url.is_empty() {
+                        format!("#{} {} ({})", id, title, state)
+                    } else {
+                        format!("[#{} {}]({}) ({})", id, title, url, state)
+                    }
+                }));
+                lines.join("\n")
+            }
+            ACTION_GET_REVIEW_COMMENTS => output
+                .get("context")
+                .and_then(Value::as_str)
+                .unwrap_or("Pull request review comments loaded.")
+                .to_string(),
             ACTION_UPDATE_AUTH_TOKEN => "Review platform auth token updated.".to_string(),
             ACTION_CLEAR_AUTH_TOKEN => "Review platform auth token cleared.".to_string(),
             _ => "Review platform action completed.".to_string(),
@@ -772,6 +881,48 @@ When returning pull request results to the user, include the provider web URL so
                     "remotes": remotes,
                 })
             }
+            ACTION_LIST_ISSUES => {
+                let remote_id = resolved_remote_id
+                    .clone()
+                    .expect("remote-bound action should resolve a remote");
+                let page = input
+                    .get("page")
+                    .and_then(Value::as_u64)
+                    .map(|value| value as u32);
+                let per_page = input
+                    .get("per_page")
+                    .and_then(Value::as_u64)
+                    .map(|value| value as u32);
+                match ReviewPlatformService::list_issues(
+                    &repository_path,
+                    Some(remote_id.as_str()),
+                    Self::issue_state(input)?,
+                    page,
+                    per_page,
+                )
+                .await
+                {
+                    Ok(issues) => json!({
+                        "action": action,
+                        "repositoryPath": repository_path,
+                        "remoteId": issues.remote_id,
+                        "issues": issues.items,
+                        "pagination": issues.pagination,
+                    }),
+                    Err(error) => {
+                        if let Some(result) = Self::auth_required_result(
+                            &action,
+                            &repository_path,
+                            &remote_id,
+                            &error,
+                        ) {
+                            result
+                        } else {
+                            return Err(BitFunError::tool(error.to_string()));
+                        }
+                    }
+                }
+            }
             ACTION_WORKSPACE_SNAPSHOT => {
                 let page = input
                     .get("page")
@@ -1030,6 +1181,73 @@ When returning pull request results to the user, include the provider web URL so
                     }
                 }
             }
+            ACTION_GET_REVIEW_COMMENTS => {
+                let pull_request_id = Self::string_field(input, "pull_request_id")?;
+                let remote_id = resolved_remote_id
+                    .clone()
+                    .expect("remote-bound action should resolve a remote");
+                let include_resolved = input
+                    .get("include_resolved")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false);
+                match ReviewPlatformService::pull_request_review_comments(
+                    &repository_path,
+                    &remote_id,
+                    &pull_request_id,
+                    include_resolved,
+                )
+                .await
+                {
+                    Ok(comments) => json!({
+                        "action": action,
+                        "repositoryPath": repository_path,
+                        "remoteId": remote_id,
+                        "pullRequest": comments.pull_request,
+                        "threads": comments.threads,
+                        "context": comments.context,
+                        "truncated": comments.truncated,
+                    }),
+                    Err(error) => {
+                        if let Some(result) = Self::auth_required_result(
+                            &action,
+                            &repository_path,
+                            &remote_id,
+                            &error,
+                        ) {
+                            result
+                        } else {
+                            return Err(BitFunError::tool(error.to_string()));
+                        }
+                    }
+                }
+            }
+            ACTION_CREATE_BRANCH => {
+                let request = ReviewPlatformCreateBranchRequest {
+                    repository_path: repository_path.clone(),
+                    branch_name: Self::string_field(input, "branch_name")?,
+                    start_point: Self::optional_string_field(input, "start_point"),
+                    checkout: input.get("checkout").and_then(Value::as_bool),
+                };
+                let result = ReviewPlatformService::create_branch(request)
+                    .await
+                    .map_err(|error| BitFunError::tool(error.to_string()))?;
+                json!({ "action": action, "result": result })
+            }
+            ACTION_PUSH_BRANCH => {
+                let remote_id = resolved_remote_id
+                    .clone()
+                    .expect("remote-bound action should resolve a remote");
+                let request = ReviewPlatformPushBranchRequest {
+                    repository_path: repository_path.clone(),
+                    remote_id: Some(remote_id),
+                    branch_name: Self::optional_string_field(input, "branch_name"),
+                    set_upstream: input.get("set_upstream").and_then(Value::as_bool),
+                };
+                let result = ReviewPlatformService::push_branch(request)
+                    .await
+                    .map_err(|error| BitFunError::tool(error.to_string()))?;
+                json!({ "action": action, "result": result })
+            }
             ACTION_CREATE => {
                 let remote_id = resolved_remote_id
                     .clone()
@@ -1194,11 +1412,14 @@ impl Default for ReviewPlatformTool {
 fn action_requires_remote(action: &str) -> bool {
     matches!(
         action,
-        ACTION_LIST
+        ACTION_LIST_ISSUES
+            | ACTION_LIST
             | ACTION_COUNT
             | ACTION_GET
             | ACTION_GET_DETAIL_PAGE
             | ACTION_GET_CI_LOG
+            | ACTION_GET_REVIEW_COMMENTS
+            | ACTION_PUSH_BRANCH
             | ACTION_CREATE
             | ACTION_REPLY
             | ACTION_SUBMIT_REVIEW
@@ -1312,4 +1533,15 @@ mod tests {
         assert_eq!(selection["action"], ACTION_GET);
         assert_eq!(selection["candidateRemotes"].as_array().unwrap().len(), 2);
     }
+
+    #[test]
+    fn branch_actions_are_not_concurrency_safe() {
+        let tool = ReviewPlatformTool::new();
+
+        assert!(!tool.is_concurrency_safe(Some(&json!({ "action": ACTION_CREATE_BRANCH }))));
+        assert!(!tool.is_concurrency_safe(Some(&json!({ "action": ACTION_PUSH_BRANCH }))));
+        assert!(tool.is_concurrency_safe(Some(&json!({ "action": ACTION_LIST_ISSUES }))));
+        assert!(action_requires_remote(ACTION_PUSH_BRANCH));
+        assert!(!action_requires_remote(ACTION_CREATE_BRANCH));
+    }
 }
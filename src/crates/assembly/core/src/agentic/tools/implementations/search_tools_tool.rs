@@ -0,0 +1,328 @@
+//! SearchTools tool implementation.
+//!
+//! Lets the model find built-in, MCP and skill tools by keyword instead of
+//! relying on the full tool list. Ranking lives in `bitfun_agent_tools`.
+
+use super::skills::{get_skill_registry, SkillInfo};
+use crate::agentic::tools::framework::{
+    Tool, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
+};
+use crate::agentic::tools::registry::get_global_tool_registry;
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use bitfun_agent_tools::{
+    search_tool_catalog, ToolSearchEntry, ToolSearchMatch, ToolSearchSource,
+    DEFAULT_TOOL_SEARCH_LIMIT, MAX_TOOL_SEARCH_LIMIT, SEARCH_TOOLS_TOOL_NAME,
+};
+use serde_json::{json, Value};
+
+/// SearchTools tool - ranked keyword search over every available tool.
+pub struct SearchToolsTool;
+
+impl Default for SearchToolsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchToolsTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn limit(input: &Value) -> BitFunResult<usize> {
+        match input.get("limit") {
+            None => Ok(DEFAULT_TOOL_SEARCH_LIMIT),
+            Some(value) => value
+                .as_u64()
+                .filter(|limit| *limit >= 1)
+                .map(|limit| (limit as usize).min(MAX_TOOL_SEARCH_LIMIT))
+                .ok_or_else(|| BitFunError::tool("limit must be a positive integer".to_string())),
+        }
+    }
+}
+
+/// Searches the tools and skills available in `context`. Without a context
+/// (desktop settings search) every enabled tool and user-level skill is
+/// searched.
+pub async fn search_available_tools(
+    query: &str,
+    limit: usize,
+    context: Option<&ToolUseContext>,
+) -> Vec<ToolSearchMatch> {
+    search_tool_catalog(query, collect_tool_search_entries(context).await, limit)
+}
+
+async fn collect_tool_search_entries(context: Option<&ToolUseContext>) -> Vec<ToolSearchEntry> {
+    let is_allowed = |name: &str| {
+        context.is_none_or(|context| context.runtime_tool_restrictions.is_tool_allowed(name))
+    };
+
+    let (tools, deferred) = {
+        let registry = get_global_tool_registry();
+        let registry = registry.read().await;
+        (registry.get_all_tools(), registry.get_deferred_tool_names())
+    };
+    let mut entries = Vec::with_capacity(tools.len());
+    for tool in tools {
+        let name = tool.name();
+        if name == SEARCH_TOOLS_TOOL_NAME
+            || !is_allowed(name)
+            || !tool.is_enabled().await
+            || !tool.is_available_in_context(context).await
+        {
+            continue;
+        }
+        let mcp = tool.dynamic_tool_info().and_then(|info| info.mcp);
+        let title = tool.user_facing_name();
+        entries.push(ToolSearchEntry {
+            name: name.to_string(),
+            title: (title != name).then_some(title),
+            description: tool.short_description(),
+            source: if mcp.is_some() {
+                ToolSearchSource::Mcp
+            } else {
+                ToolSearchSource::Builtin
+            },
+            namespace: mcp.map(|mcp| mcp.server_name),
+            deferred: deferred.iter().any(|deferred| deferred == name),
+        });
+    }
+
+    if is_allowed("Skill") {
+        entries.extend(
+            resolved_skills(context)
+                .await
+                .into_iter()
+                .map(|skill| ToolSearchEntry {
+                    name: skill.name,
+                    title: None,
+                    description: skill.description,
+                    source: ToolSearchSource::Skill,
+                    namespace: Some(skill.source_label).filter(|label| !label.is_empty()),
+                    deferred: false,
+                }),
+        );
+    }
+    entries
+}
+
+async fn resolved_skills(context: Option<&ToolUseContext>) -> Vec<SkillInfo> {
+    let registry = get_skill_registry();
+    let agent_type = context.and_then(|context| context.agent_type.as_deref());
+    match context {
+        Some(context) if context.is_remote() => match context.ws_fs() {
+            Some(fs) => {
+                let root = context
+                    .workspace
+                    .as_ref()
+                    .map(|workspace| workspace.root_path_string())
+                    .unwrap_or_default();
+                registry
+                    .get_resolved_skills_for_remote_workspace(fs, &root, agent_type)
+                    .await
+            }
+            None => {
+                registry
+                    .get_resolved_skills_for_workspace(None, agent_type)
+                    .await
+            }
+        },
+        Some(context) => {
+            registry
+                .get_resolved_skills_for_workspace(context.workspace_root(), agent_type)
+                .await
+        }
+        None => registry.get_resolved_skills_for_workspace(None, None).await,
+    }
+}
+
+fn render_matches(query: &str, matches: &[ToolSearchMatch]) -> String {
+    if matches.is_empty() {
+        return format!(
+            "No tools match \"{}\". Try fewer or broader keywords.",
+            query
+        );
+    }
+    let mut rendered = format!("Tools matching \"{}\":\n", query);
+    for found in matches {
+        let hint = match (found.entry.source, found.entry.deferred) {
+            (ToolSearchSource::Skill, _) => " (invoke with the Skill tool)",
+            (_, true) => " (load its schema with GetToolSpec first)",
+            _ => "",
+        };
+        rendered.push_str(&format!(
+            "- {} [{}]{}: {}\n",
+            found.qualified_name(),
+            found.entry.source.as_str(),
+            hint,
+            found.entry.description.lines().next().unwrap_or_default()
+        ));
+    }
+    rendered
+}
+
+#[async_trait]
+impl Tool for SearchToolsTool {
+    fn name(&self) -> &str {
+        SEARCH_TOOLS_TOOL_NAME
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(r#"Search the tools available to you by keyword.
+
+Use this when you need a capability that is not in your current tool list, for example a tool from an MCP server or a skill. It searches tool names, titles, descriptions, MCP server names and skills, and returns the best matches first.
+
+Parameters:
+- query: keywords describing the capability, e.g. "github pull request" or "create_issue"
+- limit: maximum number of results (default 10, max 50)
+
+Results are shown as `namespace/name`, where the namespace is the MCP server or skill source. Call a result by its name (without the namespace). Deferred tools must be loaded with GetToolSpec first; skills are invoked with the Skill tool."#
+            .to_string())
+    }
+
+    fn short_description(&self) -> String {
+        "Search available built-in, MCP and skill tools by keyword.".to_string()
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Keywords describing the tool you need."
+                },
+                "limit": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": MAX_TOOL_SEARCH_LIMIT,
+                    "description": "Maximum number of results. Defaults to 10."
+                }
+            },
+            "required": ["query"],
+            "additionalProperties": false
+        })
+    }
+
+    fn is_readonly(&self) -> bool {
+        true
+    }
+
+    fn is_concurrency_safe(&self, _input: Option<&Value>) -> bool {
+        true
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        _context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let message = if input
+            .get("query")
+            .and_then(Value::as_str)
+            .is_none_or(|query| query.trim().is_empty())
+        {
+            Some("query is required".to_string())
+        } else {
+            Self::limit(input).err().map(|error| error.to_string())
+        };
+        ValidationResult {
+            result: message.is_none(),
+            error_code: message.as_ref().map(|_| 400),
+            message,
+            meta: None,
+        }
+    }
+
+    fn render_tool_use_message(&self, input: &Value, _options: &ToolRenderOptions) -> String {
+        let query = input
+            .get("query")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        format!("Search tools for \"{}\"", query)
+    }
+
+    fn render_tool_result_message(&self, output: &Value) -> String {
+        let count = output
+            .get("matches")
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len);
+        format!("Found {} tools", count)
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        let query = input
+            .get("query")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|query| !query.is_empty())
+            .ok_or_else(|| BitFunError::tool("query is required".to_string()))?;
+        let limit = Self::limit(input)?;
+
+        let matches = search_available_tools(query, limit, Some(context)).await;
+        Ok(vec![ToolResult::Result {
+            data: json!({
+                "query": query,
+                "matches": matches,
+            }),
+            result_for_assistant: Some(render_matches(query, &matches)),
+            image_attachments: None,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_matches, SearchToolsTool};
+    use bitfun_agent_tools::{ToolSearchEntry, ToolSearchMatch, ToolSearchSource};
+    use serde_json::json;
+
+    #[test]
+    fn limit_defaults_and_caps() {
+        assert_eq!(SearchToolsTool::limit(&json!({})).unwrap(), 10);
+        assert_eq!(
+            SearchToolsTool::limit(&json!({ "limit": 500 })).unwrap(),
+            50
+        );
+        assert!(SearchToolsTool::limit(&json!({ "limit": 0 })).is_err());
+    }
+
+    #[test]
+    fn rendered_matches_explain_how_to_call_each_result() {
+        let found = |name: &str, source, namespace: Option<&str>, deferred| ToolSearchMatch {
+            entry: ToolSearchEntry {
+                name: name.to_string(),
+                title: None,
+                description: "First line\nSecond line".to_string(),
+                source,
+                namespace: namespace.map(str::to_string),
+                deferred,
+            },
+            score: 1,
+        };
+        let rendered = render_matches(
+            "issue",
+            &[
+                found(
+                    "mcp__github__create_issue",
+                    ToolSearchSource::Mcp,
+                    Some("GitHub"),
+                    true,
+                ),
+                found("triage", ToolSearchSource::Skill, None, false),
+            ],
+        );
+
+        assert!(rendered.contains(
+            "- GitHub/mcp__github__create_issue [mcp] (load its schema with GetToolSpec first): First line"
+        ));
+        assert!(rendered.contains("- triage [skill] (invoke with the Skill tool)"));
+        assert!(!rendered.contains("Second line"));
+        assert!(render_matches("nothing", &[]).starts_with("No tools match"));
+    }
+}
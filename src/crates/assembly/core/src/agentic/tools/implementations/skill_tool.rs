@@ -3,15 +3,18 @@
 //! Supports loading and executing skills from user-level and project-level directories
 //! Manages skill enabled/disabled status through SkillRegistry
 
+use crate::agentic::coordination::get_global_coordinator;
 use crate::agentic::tools::framework::{
     PermissionIntent, Tool, ToolRenderOptions, ToolResult, ToolUseContext, ValidationResult,
 };
 use crate::util::errors::{BitFunError, BitFunResult};
 use async_trait::async_trait;
+use bitfun_agent_runtime::skills::validate_skill_arguments;
 use log::debug;
-use serde_json::{json, Value};
+use serde_json::{json, Map, Value};
 
 // Use skills module
+use super::skills::types::SkillModelPreference;
 use super::skills::{get_skill_registry, render_loaded_skill_for_assistant};
 
 /// Skill tool
@@ -29,12 +32,14 @@ impl SkillTool {
 When users ask you to perform tasks, check whether any skills listed in the current skill listing can help complete the task more effectively. Skills provide specialized capabilities and domain knowledge.
 
 How to use skills:
-- Invoke skills using this tool with the listed skill name or stable key (no arguments)
+- Invoke skills using this tool with the listed skill name or stable key
+- If the listing shows `<parameters>` for a skill, pass values matching that JSON schema in `arguments`; otherwise omit `arguments`
 - The skill's prompt will expand and provide detailed instructions on how to complete the task
 - Examples:
   - `command: "pdf"` - invoke the pdf skill
   - `command: "xlsx"` - invoke the xlsx skill
   - `command: "user::bitfun-system::ppt-design"` - invoke a specific built-in skill by stable key
+  - `command: "fill-form", arguments: {"file": "intake.pdf"}` - invoke a skill that declares a `file` parameter
 
 Important:
 - Only use skills listed in the current skill listing's <available_skills> section, unless a trusted host task explicitly supplies an exact stable key
@@ -134,7 +139,11 @@ impl Tool for SkillTool {
             "properties": {
                 "command": {
                     "type": "string",
-                    "description": "The skill name (no arguments). E.g., \"pdf\" or \"xlsx\""
+                    "description": "The skill name or stable key. E.g., \"pdf\" or \"xlsx\""
+                },
+                "arguments": {
+                    "type": "object",
+                    "description": "Values for the parameters the skill declares in the listing's <parameters> schema. Omit for skills without parameters."
                 }
             },
             "required": ["command"],
@@ -184,6 +193,17 @@ impl Tool for SkillTool {
                 meta: None,
             };
         }
+        if input
+            .get("arguments")
+            .is_some_and(|arguments| !arguments.is_object() && !arguments.is_null())
+        {
+            return ValidationResult {
+                result: false,
+                message: Some("arguments must be an object".to_string()),
+                error_code: Some(400),
+                meta: None,
+            };
+        }
 
         ValidationResult {
             result: true,
@@ -210,6 +230,11 @@ impl Tool for SkillTool {
             .get("command")
             .and_then(|v| v.as_str())
             .ok_or_else(|| BitFunError::tool("command is required".to_string()))?;
+        let arguments: Map<String, Value> = match input.get("arguments") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(arguments)) => arguments.clone(),
+            Some(_) => return Err(BitFunError::tool("arguments must be an object".to_string())),
+        };
 
         debug!("Skill tool executing skill: {}", skill_name);
 
@@ -281,8 +306,32 @@ impl Tool for SkillTool {
             }
         };
 
+        validate_skill_arguments(&skill_data.parameters, &arguments).map_err(|error| {
+            BitFunError::tool(format!(
+                "Invalid arguments for skill '{}': {}",
+                skill_data.name, error
+            ))
+        })?;
+
+        if let (Some(session_id), Some(coordinator)) =
+            (context.session_id.as_deref(), get_global_coordinator())
+        {
+            // The model of the running turn is fixed; the preference applies
+            // from the next turn on.
+            coordinator
+                .get_session_manager()
+                .set_skill_model_preference(
+                    session_id,
+                    skill_data.model.clone().map(|model| SkillModelPreference {
+                        skill_name: skill_data.name.clone(),
+                        model,
+                    }),
+                );
+        }
+
         let location_str = skill_data.location.as_str();
-        let result_for_assistant = render_loaded_skill_for_assistant(&skill_data, use_stable_key);
+        let result_for_assistant =
+            render_loaded_skill_for_assistant(&skill_data, use_stable_key, &arguments);
 
         let result = ToolResult::Result {
             data: json!({
@@ -292,6 +341,10 @@ impl Tool for SkillTool {
                 "description": skill_data.description,
                 "location": location_str,
                 "content": skill_data.content,
+                "assets": skill_data.assets,
+                "model": skill_data.model,
+                "parameters": skill_data.parameters,
+                "arguments": arguments,
                 "success": true
             }),
             result_for_assistant: Some(result_for_assistant),
@@ -490,7 +543,7 @@ Use the remote project skill.
             panic!("expected result payload");
         };
         assert_eq!(data["skill_name"], "cso");
-        assert_eq!(data["location"], "user");
+        assert_eq!(data["location"], "builtin");
         assert!(data["content"]
             .as_str()
             .unwrap_or_default()
@@ -548,6 +601,38 @@ Use the remote project skill.
         assert!(assistant.contains("references/editable-pptx.md"));
     }
 
+    #[tokio::test]
+    async fn undeclared_arguments_are_rejected() {
+        let context = crate::agentic::tools::framework::ToolUseContext {
+            tool_call_id: None,
+            agent_type: Some("Cowork".to_string()),
+            session_id: None,
+            dialog_turn_id: None,
+            workspace: None,
+            loaded_deferred_tool_specs: Vec::new(),
+            primary_model_facts: tool_runtime::context::PrimaryModelFacts::default(),
+            custom_data: Default::default(),
+            computer_use_host: None,
+            runtime_tool_restrictions: Default::default(),
+            runtime_handles: bitfun_runtime_ports::ToolRuntimeHandles::new(None, None),
+        };
+
+        let error = SkillTool::new()
+            .call_impl(
+                &json!({
+                    "command": "user::bitfun-system::ppt-design",
+                    "arguments": { "slides": 3 }
+                }),
+                &context,
+            )
+            .await
+            .expect_err("ppt-design declares no parameters");
+
+        assert!(error
+            .to_string()
+            .contains("Unknown argument 'slides': the skill takes no arguments"));
+    }
+
     struct OrderingRemoteFs;
 
     #[async_trait]
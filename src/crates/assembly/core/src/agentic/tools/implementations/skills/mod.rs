@@ -12,8 +12,8 @@ pub mod types;
 
 pub use registry::SkillRegistry;
 pub use types::{
-    render_loaded_skill_for_assistant, ModeSkillInfo, ModeSkillStateReason, SkillData, SkillInfo,
-    SkillLocation,
+    render_loaded_skill_for_assistant, ModeSkillInfo, ModeSkillStateReason, SkillAssetReference,
+    SkillData, SkillInfo, SkillLocation,
 };
 
 /// Get global Skill registry instance
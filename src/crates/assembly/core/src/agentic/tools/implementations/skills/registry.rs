@@ -10,18 +10,21 @@ use super::mode_overrides::{
 use super::types::{ModeSkillInfo, SkillData, SkillInfo, SkillLocation};
 use crate::agentic::workspace::WorkspaceFileSystem;
 use crate::infrastructure::get_path_manager_arc;
+use crate::service::config::get_app_language_code;
 use crate::util::errors::{BitFunError, BitFunResult};
 use bitfun_agent_runtime::skills::{
-    annotate_shadowed_skills, build_mode_skill_infos, filter_candidates_for_mode,
+    annotate_shadowed_skills, build_mode_skill_infos, check_skill_compatibility,
+    current_skill_platform, extract_skill_asset_references, filter_candidates_for_mode,
     normalize_local_skill_dir_name, normalize_remote_skill_dir_name, normalize_skill_keys,
     resolve_default_hidden_builtin_for_explicit_invocation, resolve_user_config_skill_root,
-    resolve_visible_skills, sort_skill_candidates_by_dir, sort_skills,
-    ExplicitSkillInvocationResolution, SkillCandidate, BITFUN_SKILL_SOURCE_ID,
+    resolve_visible_skills, select_skill_md_language, skill_md_file_name,
+    skill_md_variant_language, sort_skill_candidates_by_dir, sort_skills,
+    ExplicitSkillInvocationResolution, SkillAssetReference, SkillCandidate, BITFUN_SKILL_SOURCE_ID,
     BITFUN_SKILL_SOURCE_LABEL, BITFUN_SYSTEM_SKILL_DIR, BITFUN_SYSTEM_SKILL_SLOT,
-    BITFUN_USER_SKILL_SLOT, PROJECT_SKILL_KEY_PREFIX, PROJECT_SKILL_ROOTS, USER_CONFIG_SKILL_ROOTS,
-    USER_HOME_SKILL_ROOTS, USER_SKILL_KEY_PREFIX,
+    BITFUN_USER_SKILL_SLOT, PROJECT_SKILL_KEY_PREFIX, PROJECT_SKILL_ROOTS, SKILL_MD_FILE_NAME,
+    USER_CONFIG_SKILL_ROOTS, USER_HOME_SKILL_ROOTS, USER_SKILL_KEY_PREFIX,
 };
-use log::{debug, error};
+use log::{debug, error, warn};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
@@ -61,6 +64,74 @@ fn sort_remote_dir_entries(entries: &mut [crate::agentic::workspace::WorkspaceDi
     });
 }
 
+fn collect_skill_md_languages<'a>(file_names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut languages: Vec<String> = file_names
+        .filter_map(skill_md_variant_language)
+        .map(str::to_string)
+        .collect();
+    languages.sort();
+    languages.dedup();
+    languages
+}
+
+async fn local_skill_md_languages(skill_dir: &Path) -> Vec<String> {
+    let mut file_names = Vec::new();
+    if let Ok(mut read_dir) = fs::read_dir(skill_dir).await {
+        while let Ok(Some(item)) = read_dir.next_entry().await {
+            if let Some(name) = item.file_name().to_str() {
+                file_names.push(name.to_string());
+            }
+        }
+    }
+    collect_skill_md_languages(file_names.iter().map(String::as_str))
+}
+
+/// Applies the localized variant on top of the default definition. The
+/// default file's `name` stays authoritative so invocation by name does not
+/// depend on the app language.
+fn apply_skill_md_variant(data: &mut SkillData, variant: SkillData) {
+    data.description = variant.description;
+    if !variant.content.is_empty() {
+        data.content = variant.content;
+    }
+}
+
+/// Resolves a folded asset reference (which may start with `../`) against the
+/// skill folder, returning the path and whether it stays inside the folder.
+fn resolve_skill_asset_reference(skill_dir: &Path, reference: &str) -> (PathBuf, bool) {
+    let mut base = skill_dir.to_path_buf();
+    let mut rest = reference;
+    let mut inside = true;
+    while let Some(stripped) = rest.strip_prefix("../") {
+        base.pop();
+        rest = stripped;
+        inside = false;
+    }
+    (base.join(rest), inside)
+}
+
+fn warn_missing_skill_assets(data: &SkillData) {
+    let missing: Vec<&str> = data
+        .assets
+        .iter()
+        .filter(|asset| !asset.exists)
+        .map(|asset| asset.reference.as_str())
+        .collect();
+    if !missing.is_empty() {
+        warn!(
+            "Skill references missing assets: skill={}, path={}, missing={:?}",
+            data.name, data.path, missing
+        );
+    }
+}
+
+/// Without a mode there are no mode states to disable incompatible skills,
+/// so they are dropped here instead.
+fn retain_compatible_candidates(mut candidates: Vec<SkillCandidate>) -> Vec<SkillCandidate> {
+    candidates.retain(|candidate| candidate.info.incompatible_reason.is_none());
+    candidates
+}
+
 /// Skill registry
 pub struct SkillRegistry {
     /// Cached raw user-level skills (no workspace-specific project skills).
@@ -83,6 +154,11 @@ impl SkillRegistry {
         let mut priority = 0usize;
         let mut deferred_home_entries = Vec::new();
 
+        // Project skills can run arbitrary instructions, so untrusted folders
+        // only see user and built-in skills.
+        let workspace_root = workspace_root
+            .filter(|root| !crate::service::workspace_trust::is_workspace_restricted(root));
+
         if let Some(workspace_path) = workspace_root {
             for spec in PROJECT_SKILL_ROOTS {
                 let path = workspace_path.join(spec.parent).join(spec.subdir);
@@ -150,7 +226,7 @@ impl SkillRegistry {
         if builtin_skills.exists() && builtin_skills.is_dir() {
             entries.push(SkillRootEntry {
                 path: builtin_skills,
-                level: SkillLocation::User,
+                level: SkillLocation::Builtin,
                 slot: BITFUN_SYSTEM_SKILL_SLOT,
                 source_id: BITFUN_SKILL_SOURCE_ID,
                 source_label: BITFUN_SKILL_SOURCE_LABEL,
@@ -196,7 +272,7 @@ impl SkillRegistry {
         entries
     }
 
-    async fn scan_skills_in_dir(entry: &SkillRootEntry) -> Vec<SkillCandidate> {
+    async fn scan_skills_in_dir(entry: &SkillRootEntry, app_language: &str) -> Vec<SkillCandidate> {
         let mut skills = Vec::new();
         if !entry.path.exists() {
             return skills;
@@ -220,10 +296,11 @@ impl SkillRegistry {
                 continue;
             }
 
-            let skill_md_path = path.join("SKILL.md");
+            let skill_md_path = path.join(SKILL_MD_FILE_NAME);
             if !skill_md_path.exists() {
                 continue;
             }
+            let languages = local_skill_md_languages(&path).await;
 
             match fs::read_to_string(&skill_md_path).await {
                 Ok(content) => match SkillData::from_markdown(
@@ -233,12 +310,26 @@ impl SkillRegistry {
                     false,
                 ) {
                     Ok(mut skill_data) => {
+                        if let Some(language) = select_skill_md_language(app_language, &languages) {
+                            let variant_path = path.join(skill_md_file_name(Some(language)));
+                            match Self::read_local_skill_md(&variant_path, entry.level, false).await
+                            {
+                                Ok(variant) => apply_skill_md_variant(&mut skill_data, variant),
+                                Err(error) => debug!(
+                                    "Ignoring skill variant {}: {}",
+                                    variant_path.display(),
+                                    error
+                                ),
+                            }
+                        }
                         skill_data.dir_name = dir_name;
                         let key_prefix = match entry.level {
-                            SkillLocation::User => USER_SKILL_KEY_PREFIX,
+                            // Built-in keys keep the `user::` prefix so stored
+                            // mode overrides still match them.
+                            SkillLocation::User | SkillLocation::Builtin => USER_SKILL_KEY_PREFIX,
                             SkillLocation::Project => PROJECT_SKILL_KEY_PREFIX,
                         };
-                        skills.push(SkillCandidate::from_data(
+                        let mut candidate = SkillCandidate::from_data(
                             skill_data,
                             entry.slot,
                             entry.source_id,
@@ -246,7 +337,14 @@ impl SkillRegistry {
                             key_prefix,
                             entry.priority,
                             entry.is_builtin,
-                        ));
+                        );
+                        candidate.info.languages = languages;
+                        candidate.info.incompatible_reason = check_skill_compatibility(
+                            &candidate.info.compatibility,
+                            crate::VERSION,
+                            Some(current_skill_platform()),
+                        );
+                        skills.push(candidate);
                     }
                     Err(error) => {
                         error!("Failed to parse SKILL.md in {}: {}", path.display(), error);
@@ -269,9 +367,10 @@ impl SkillRegistry {
             debug!("Failed to install built-in skills: {}", error);
         }
 
+        let app_language = get_app_language_code().await;
         let mut skills = Vec::new();
         for entry in Self::get_possible_paths_for_workspace(workspace_root) {
-            let mut part = Self::scan_skills_in_dir(&entry).await;
+            let mut part = Self::scan_skills_in_dir(&entry, &app_language).await;
             skills.append(&mut part);
         }
         skills
@@ -280,6 +379,7 @@ impl SkillRegistry {
     async fn scan_remote_project_skills(
         fs: &dyn WorkspaceFileSystem,
         remote_root: &str,
+        app_language: &str,
     ) -> Vec<SkillCandidate> {
         let mut roots = Vec::new();
         let root = remote_root.trim_end_matches('/');
@@ -312,10 +412,20 @@ impl SkillRegistry {
                 let Some(dir_name) = normalize_remote_skill_dir_name(&item.path) else {
                     continue;
                 };
-                let skill_md_path = format!("{}/SKILL.md", item.path.trim_end_matches('/'));
+                let skill_dir = item.path.trim_end_matches('/');
+                let skill_md_path = format!("{}/{}", skill_dir, SKILL_MD_FILE_NAME);
                 if !fs.is_file(&skill_md_path).await.unwrap_or(false) {
                     continue;
                 }
+                let languages = match fs.read_dir(&item.path).await {
+                    Ok(children) => collect_skill_md_languages(
+                        children
+                            .iter()
+                            .filter(|child| !child.is_dir)
+                            .map(|child| child.name.as_str()),
+                    ),
+                    Err(_) => Vec::new(),
+                };
 
                 match fs.read_file_text(&skill_md_path).await {
                     Ok(content) => match SkillData::from_markdown(
@@ -325,8 +435,33 @@ impl SkillRegistry {
                         false,
                     ) {
                         Ok(mut skill_data) => {
+                            if let Some(language) =
+                                select_skill_md_language(app_language, &languages)
+                            {
+                                let variant_path =
+                                    format!("{}/{}", skill_dir, skill_md_file_name(Some(language)));
+                                let variant = fs
+                                    .read_file_text(&variant_path)
+                                    .await
+                                    .map_err(|error| error.to_string())
+                                    .and_then(|content| {
+                                        SkillData::from_markdown(
+                                            item.path.clone(),
+                                            &content,
+                                            SkillLocation::Project,
+                                            false,
+                                        )
+                                        .map_err(|error| error.to_string())
+                                    });
+                                match variant {
+                                    Ok(variant) => apply_skill_md_variant(&mut skill_data, variant),
+                                    Err(error) => {
+                                        debug!("Ignoring skill variant {}: {}", variant_path, error)
+                                    }
+                                }
+                            }
                             skill_data.dir_name = dir_name;
-                            skills.push(SkillCandidate::from_data(
+                            let mut candidate = SkillCandidate::from_data(
                                 skill_data,
                                 entry.slot,
                                 entry.source_id,
@@ -334,7 +469,16 @@ impl SkillRegistry {
                                 PROJECT_SKILL_KEY_PREFIX,
                                 entry.priority,
                                 false,
-                            ));
+                            );
+                            candidate.info.languages = languages;
+                            // The skill runs on the remote host, whose
+                            // platform is not known here.
+                            candidate.info.incompatible_reason = check_skill_compatibility(
+                                &candidate.info.compatibility,
+                                crate::VERSION,
+                                None,
+                            );
+                            skills.push(candidate);
                         }
                         Err(error) => {
                             error!("Failed to parse SKILL.md in {}: {}", item.path, error);
@@ -356,7 +500,8 @@ impl SkillRegistry {
         remote_root: &str,
     ) -> Vec<SkillCandidate> {
         let mut skills = self.scan_skill_candidates_for_workspace(None).await;
-        skills.extend(Self::scan_remote_project_skills(fs, remote_root).await);
+        let app_language = get_app_language_code().await;
+        skills.extend(Self::scan_remote_project_skills(fs, remote_root, &app_language).await);
         skills
     }
 
@@ -367,7 +512,7 @@ impl SkillRegistry {
         agent_type: Option<&str>,
     ) -> Vec<SkillCandidate> {
         let Some(mode_id) = agent_type.map(str::trim).filter(|value| !value.is_empty()) else {
-            return candidates;
+            return retain_compatible_candidates(candidates);
         };
 
         let user_overrides = load_user_mode_skill_overrides(mode_id)
@@ -394,7 +539,7 @@ impl SkillRegistry {
         agent_type: Option<&str>,
     ) -> Vec<SkillCandidate> {
         let Some(mode_id) = agent_type.map(str::trim).filter(|value| !value.is_empty()) else {
-            return candidates;
+            return retain_compatible_candidates(candidates);
         };
 
         let user_overrides = load_user_mode_skill_overrides(mode_id)
@@ -429,6 +574,9 @@ impl SkillRegistry {
                     skill_name, mode_id
                 )))
             }
+            ExplicitSkillInvocationResolution::Incompatible { reason } => Err(BitFunError::tool(
+                format!("Skill '{}' is not available: {}", skill_name, reason),
+            )),
         }
     }
 
@@ -663,13 +811,7 @@ impl SkillRegistry {
             )
             .await?;
 
-        let skill_md_path = PathBuf::from(&info.path).join("SKILL.md");
-        let content = fs::read_to_string(&skill_md_path)
-            .await
-            .map_err(|error| BitFunError::tool(format!("Failed to read skill file: {}", error)))?;
-
-        let mut data = SkillData::from_markdown(info.path.clone(), &content, info.level, true)
-            .map_err(|error| BitFunError::tool(error.to_string()))?;
+        let mut data = Self::load_local_skill_data(&info).await?;
         data.key = info.key;
         data.source_slot = info.source_slot;
         data.dir_name = info.dir_name;
@@ -699,13 +841,7 @@ impl SkillRegistry {
                 ))
             })?;
 
-        let skill_md_path = PathBuf::from(&info.path).join("SKILL.md");
-        let content = fs::read_to_string(&skill_md_path)
-            .await
-            .map_err(|error| BitFunError::tool(format!("Failed to read skill file: {}", error)))?;
-
-        let mut data = SkillData::from_markdown(info.path.clone(), &content, info.level, true)
-            .map_err(|error| BitFunError::tool(error.to_string()))?;
+        let mut data = Self::load_local_skill_data(&info).await?;
         data.key = info.key;
         data.source_slot = info.source_slot;
         data.dir_name = info.dir_name;
@@ -728,9 +864,7 @@ impl SkillRegistry {
             )
             .await?;
 
-        let content = Self::read_skill_md_for_remote_merge(&info, fs).await?;
-        let mut data = SkillData::from_markdown(info.path.clone(), &content, info.level, true)
-            .map_err(|error| BitFunError::tool(error.to_string()))?;
+        let mut data = Self::load_skill_data_for_remote_merge(&info, fs).await?;
         data.key = info.key;
         data.source_slot = info.source_slot;
         data.dir_name = info.dir_name;
@@ -761,9 +895,7 @@ impl SkillRegistry {
                 ))
             })?;
 
-        let content = Self::read_skill_md_for_remote_merge(&info, fs).await?;
-        let mut data = SkillData::from_markdown(info.path.clone(), &content, info.level, true)
-            .map_err(|error| BitFunError::tool(error.to_string()))?;
+        let mut data = Self::load_skill_data_for_remote_merge(&info, fs).await?;
         data.key = info.key;
         data.source_slot = info.source_slot;
         data.dir_name = info.dir_name;
@@ -795,26 +927,114 @@ impl SkillRegistry {
             .collect()
     }
 
-    async fn read_skill_md_for_remote_merge(
+    async fn read_local_skill_md(
+        skill_md_path: &Path,
+        level: SkillLocation,
+        with_content: bool,
+    ) -> BitFunResult<SkillData> {
+        let content = fs::read_to_string(skill_md_path)
+            .await
+            .map_err(|error| BitFunError::tool(format!("Failed to read skill file: {}", error)))?;
+        let skill_dir = skill_md_path.parent().unwrap_or(skill_md_path);
+        SkillData::from_markdown(
+            skill_dir.to_string_lossy().to_string(),
+            &content,
+            level,
+            with_content,
+        )
+        .map_err(|error| BitFunError::tool(error.to_string()))
+    }
+
+    /// Loads the full skill, using the `SKILL.<lang>.md` variant for the app
+    /// language when the skill provides one.
+    async fn load_local_skill_data(info: &SkillInfo) -> BitFunResult<SkillData> {
+        let skill_dir = PathBuf::from(&info.path);
+        let mut data =
+            Self::read_local_skill_md(&skill_dir.join(SKILL_MD_FILE_NAME), info.level, true)
+                .await?;
+        data.path = info.path.clone();
+        let app_language = get_app_language_code().await;
+        if let Some(language) = select_skill_md_language(&app_language, &info.languages) {
+            let variant_path = skill_dir.join(skill_md_file_name(Some(language)));
+            match Self::read_local_skill_md(&variant_path, info.level, true).await {
+                Ok(variant) => apply_skill_md_variant(&mut data, variant),
+                Err(error) => debug!(
+                    "Ignoring skill variant {}: {}",
+                    variant_path.display(),
+                    error
+                ),
+            }
+        }
+        data.assets = Self::resolve_local_skill_assets(&skill_dir, &data.content).await;
+        warn_missing_skill_assets(&data);
+        Ok(data)
+    }
+
+    /// Resolves the companion files the skill body references.
+    pub async fn resolve_local_skill_assets(
+        skill_dir: &Path,
+        content: &str,
+    ) -> Vec<SkillAssetReference> {
+        let mut assets = Vec::new();
+        for reference in extract_skill_asset_references(content) {
+            let (resolved, inside_skill_dir) = resolve_skill_asset_reference(skill_dir, &reference);
+            assets.push(SkillAssetReference {
+                exists: fs::metadata(&resolved).await.is_ok(),
+                resolved_path: resolved.to_string_lossy().to_string(),
+                reference,
+                inside_skill_dir,
+            });
+        }
+        assets
+    }
+
+    async fn load_skill_data_for_remote_merge(
         info: &SkillInfo,
         remote_fs: &dyn WorkspaceFileSystem,
-    ) -> BitFunResult<String> {
-        match info.level {
-            SkillLocation::User => {
-                let skill_md_path = PathBuf::from(&info.path).join("SKILL.md");
-                fs::read_to_string(&skill_md_path).await.map_err(|error| {
+    ) -> BitFunResult<SkillData> {
+        if info.level != SkillLocation::Project {
+            return Self::load_local_skill_data(info).await;
+        }
+
+        let skill_dir = info.path.trim_end_matches('/');
+        let read = |file_name: String| async move {
+            let content = remote_fs
+                .read_file_text(&format!("{}/{}", skill_dir, file_name))
+                .await
+                .map_err(|error| {
                     BitFunError::tool(format!("Failed to read skill file: {}", error))
-                })
+                })?;
+            SkillData::from_markdown(info.path.clone(), &content, info.level, true)
+                .map_err(|error| BitFunError::tool(error.to_string()))
+        };
+
+        let mut data = read(SKILL_MD_FILE_NAME.to_string()).await?;
+        let app_language = get_app_language_code().await;
+        if let Some(language) = select_skill_md_language(&app_language, &info.languages) {
+            match read(skill_md_file_name(Some(language))).await {
+                Ok(variant) => apply_skill_md_variant(&mut data, variant),
+                Err(error) => debug!("Ignoring skill variant for {}: {}", info.path, error),
             }
-            SkillLocation::Project => {
-                let skill_md_path = format!("{}/SKILL.md", info.path.trim_end_matches('/'));
-                remote_fs
-                    .read_file_text(&skill_md_path)
-                    .await
-                    .map_err(|error| {
-                        BitFunError::tool(format!("Failed to read skill file: {}", error))
-                    })
+        }
+
+        for reference in extract_skill_asset_references(&data.content) {
+            let mut base = skill_dir.to_string();
+            let mut rest = reference.as_str();
+            let mut inside_skill_dir = true;
+            while let Some(stripped) = rest.strip_prefix("../") {
+                base.truncate(base.rfind('/').unwrap_or(0));
+                rest = stripped;
+                inside_skill_dir = false;
             }
+            let resolved_path = format!("{}/{}", base, rest);
+            data.assets.push(SkillAssetReference {
+                exists: remote_fs.exists(&resolved_path).await.unwrap_or(false),
+                resolved_path,
+                reference,
+                inside_skill_dir,
+            });
         }
+        warn_missing_skill_assets(&data);
+        Ok(data)
     }
 }
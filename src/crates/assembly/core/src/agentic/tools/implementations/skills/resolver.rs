@@ -22,7 +22,7 @@ mod tests {
             name: dir_name.to_string(),
             description: String::new(),
             path: format!("/tmp/{}", dir_name),
-            level: SkillLocation::User,
+            level: SkillLocation::Builtin,
             source_slot: "bitfun-system".to_string(),
             source_id: "bitfun".to_string(),
             source_label: "BitFun".to_string(),
@@ -31,6 +31,10 @@ mod tests {
             group_key: None,
             is_shadowed: false,
             shadowed_by_key: None,
+            languages: Vec::new(),
+            compatibility: Default::default(),
+            incompatible_reason: None,
+            parameters: Vec::new(),
         }
     }
 
@@ -49,6 +53,10 @@ mod tests {
             group_key: None,
             is_shadowed: false,
             shadowed_by_key: None,
+            languages: Vec::new(),
+            compatibility: Default::default(),
+            incompatible_reason: None,
+            parameters: Vec::new(),
         }
     }
 
@@ -3,6 +3,6 @@
 //! The provider-neutral owner lives in `bitfun-agent-runtime`.
 
 pub use bitfun_agent_runtime::skills::{
-    render_loaded_skill_for_assistant, ModeSkillInfo, ModeSkillStateReason, SkillData, SkillInfo,
-    SkillLocation, SkillParseError,
+    render_loaded_skill_for_assistant, ModeSkillInfo, ModeSkillStateReason, SkillAssetReference,
+    SkillCompatibility, SkillData, SkillInfo, SkillLocation, SkillModelPreference, SkillParseError,
 };
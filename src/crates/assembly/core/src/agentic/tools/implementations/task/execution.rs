@@ -55,7 +55,6 @@ fn forward_subagent_invocation_context(
 
 struct BackgroundTaskStartRequest<'a> {
     coordinator: &'a std::sync::Arc<crate::agentic::coordination::ConversationCoordinator>,
-    context: &'a ToolUseContext,
     context_mode: SubagentContextMode,
     target_session_id: Option<String>,
     subagent_type: Option<String>,
@@ -69,6 +68,8 @@ struct BackgroundTaskStartRequest<'a> {
     subagent_context: Option<HashMap<String, String>>,
     prepared_prompt: String,
     timeout_seconds: Option<u64>,
+    delegation_policy: DelegationPolicy,
+    scope: SubagentScope,
     tool_call_id: String,
     session_id: String,
     dialog_turn_id: String,
@@ -99,6 +100,17 @@ impl TaskTool {
             .filter(|seconds| *seconds > 0)
     }
 
+    /// How many levels of subagents may be nested under a top-level session.
+    pub(super) async fn load_configured_subagent_max_depth() -> u8 {
+        let Ok(service) = GlobalConfigManager::get_service().await else {
+            return 1;
+        };
+        match service.get_config::<AIConfig>(Some("ai")).await {
+            Ok(ai_config) => ai_config.subagent_max_depth.max(1),
+            Err(_) => 1,
+        }
+    }
+
     pub(super) fn resolve_subagent_timeout_seconds(
         requested_timeout_seconds: Option<u64>,
         configured_execution_timeout_secs: Option<u64>,
@@ -214,6 +226,8 @@ impl TaskTool {
         let run_in_background = invocation.run_in_background;
         let is_retry = invocation.is_retry;
         let requested_auto_retry = invocation.requested_auto_retry;
+        let scope = invocation.scope.clone();
+        let output_schema = invocation.output_schema.clone();
         let is_auto_retry = is_retry && requested_auto_retry;
         let is_deep_review_parent = Self::is_deep_review_context(Some(context));
 
@@ -614,11 +628,16 @@ impl TaskTool {
         let subagent_context = (!subagent_context.is_empty()).then_some(subagent_context);
         let permission_runtime_ceiling =
             Self::derive_parent_permission_runtime_ceiling(context).await;
+        if let Some(schema) = output_schema.as_ref() {
+            prompt = structured_result::prompt_with_output_schema(&prompt, schema);
+        }
+        let delegation_policy = context
+            .delegation_policy()
+            .spawn_child_within(Self::load_configured_subagent_max_depth().await);
         let prepared_prompt = prompt;
         if run_in_background {
             return Self::start_background_task(BackgroundTaskStartRequest {
                 coordinator: &coordinator,
-                context,
                 context_mode,
                 target_session_id,
                 subagent_type,
@@ -632,6 +651,8 @@ impl TaskTool {
                 subagent_context,
                 prepared_prompt,
                 timeout_seconds,
+                delegation_policy,
+                scope,
                 tool_call_id,
                 session_id,
                 dialog_turn_id,
@@ -656,6 +677,9 @@ impl TaskTool {
             subagent_context,
             prepared_prompt,
             timeout_seconds,
+            delegation_policy,
+            scope,
+            output_schema,
             tool_call_id,
             session_id,
             dialog_turn_id,
@@ -680,7 +704,6 @@ impl TaskTool {
     ) -> BitFunResult<Vec<ToolResult>> {
         let BackgroundTaskStartRequest {
             coordinator,
-            context,
             context_mode,
             target_session_id,
             subagent_type,
@@ -694,6 +717,8 @@ impl TaskTool {
             subagent_context,
             prepared_prompt,
             timeout_seconds,
+            delegation_policy,
+            scope,
             tool_call_id,
             session_id,
             dialog_turn_id,
@@ -720,7 +745,8 @@ impl TaskTool {
                     subagent_parent_info: parent_info,
                     context: subagent_context.unwrap_or_default(),
                     permission_runtime_ceiling,
-                    delegation_policy: context.delegation_policy().spawn_child(),
+                    delegation_policy,
+                    scope,
                     external_generation_lease,
                 },
                 timeout_seconds,
@@ -760,6 +786,9 @@ impl TaskTool {
         subagent_context: Option<HashMap<String, String>>,
         prepared_prompt: String,
         timeout_seconds: Option<u64>,
+        delegation_policy: DelegationPolicy,
+        scope: SubagentScope,
+        output_schema: Option<Value>,
         tool_call_id: String,
         session_id: String,
         dialog_turn_id: String,
@@ -815,7 +844,8 @@ impl TaskTool {
                         subagent_parent_info: parent_info,
                         context: subagent_context.clone().unwrap_or_default(),
                         permission_runtime_ceiling: permission_runtime_ceiling.clone(),
-                        delegation_policy: context.delegation_policy().spawn_child(),
+                        delegation_policy,
+                        scope: scope.clone(),
                         external_generation_lease: external_generation_lease.clone(),
                     },
                     context.cancellation_token(),
@@ -1089,6 +1119,18 @@ impl TaskTool {
                 result.ledger_event_id(),
                 &retry_hint,
             );
+        if let Some(schema) = output_schema.as_ref() {
+            match structured_result::extract_structured_result(&result.text, schema) {
+                Ok(structured) => data["structured_result"] = structured,
+                Err(reason) => {
+                    data["structured_result_error"] = json!(reason.clone());
+                    result_for_assistant.push_str(&format!(
+                        "\n<structured_result_error>{}</structured_result_error>",
+                        reason
+                    ));
+                }
+            }
+        }
         if supports_follow_up {
             if let Some(subagent_session_id) = result.session_id() {
                 let agent_id = coordinator
@@ -77,6 +77,8 @@ pub(super) struct TaskInvocation {
     pub(super) run_in_background: bool,
     pub(super) is_retry: bool,
     pub(super) requested_auto_retry: bool,
+    pub(super) scope: SubagentScope,
+    pub(super) output_schema: Option<Value>,
 }
 
 impl TaskTool {
@@ -97,7 +99,14 @@ impl TaskTool {
                     "action is not supported for DeepReview Task calls".to_string(),
                 ));
             }
-            for field in ["fork_context", "agent_id", "run_in_background"] {
+            for field in [
+                "fork_context",
+                "agent_id",
+                "run_in_background",
+                "allowed_tools",
+                "max_context_tokens",
+                "output_schema",
+            ] {
                 if input.get(field).is_some() {
                     return Err(BitFunError::tool(format!(
                         "{field} is not allowed for DeepReview Task calls"
@@ -123,6 +132,8 @@ impl TaskTool {
                     .get("auto_retry")
                     .and_then(Value::as_bool)
                     .unwrap_or(false),
+                scope: SubagentScope::default(),
+                output_schema: None,
             });
         }
 
@@ -188,6 +199,8 @@ impl TaskTool {
                     run_in_background,
                     is_retry: false,
                     requested_auto_retry: false,
+                    scope: Self::subagent_scope(input)?,
+                    output_schema: Self::optional_output_schema(input)?,
                 })
             }
             TaskAction::SendInput => {
@@ -202,6 +215,9 @@ impl TaskTool {
                         "retry",
                         "auto_retry",
                         "retry_coverage",
+                        "allowed_tools",
+                        "max_context_tokens",
+                        "output_schema",
                     ],
                     action,
                 )?;
@@ -221,6 +237,8 @@ impl TaskTool {
                     run_in_background,
                     is_retry: false,
                     requested_auto_retry: false,
+                    scope: SubagentScope::default(),
+                    output_schema: None,
                 })
             }
             TaskAction::Cancel => {
@@ -236,6 +254,9 @@ impl TaskTool {
                         "retry",
                         "auto_retry",
                         "retry_coverage",
+                        "allowed_tools",
+                        "max_context_tokens",
+                        "output_schema",
                     ],
                     action,
                 )?;
@@ -253,6 +274,8 @@ impl TaskTool {
                     run_in_background: false,
                     is_retry: false,
                     requested_auto_retry: false,
+                    scope: SubagentScope::default(),
+                    output_schema: None,
                 })
             }
         }
@@ -361,6 +384,69 @@ impl TaskTool {
         }
     }
 
+    fn subagent_scope(input: &Value) -> BitFunResult<SubagentScope> {
+        let allowed_tools = match input.get("allowed_tools") {
+            None => Vec::new(),
+            Some(value) => {
+                let names = value.as_array().ok_or_else(|| {
+                    BitFunError::tool("allowed_tools must be an array of tool names".to_string())
+                })?;
+                let mut allowed_tools = Vec::with_capacity(names.len());
+                for name in names {
+                    let name = name.as_str().map(str::trim).ok_or_else(|| {
+                        BitFunError::tool(
+                            "allowed_tools must be an array of tool names".to_string(),
+                        )
+                    })?;
+                    if !name.is_empty() && !allowed_tools.iter().any(|tool| tool == name) {
+                        allowed_tools.push(name.to_string());
+                    }
+                }
+                if allowed_tools.is_empty() {
+                    return Err(BitFunError::tool(
+                        "allowed_tools must name at least one tool; omit it to keep the agent's tools"
+                            .to_string(),
+                    ));
+                }
+                allowed_tools
+            }
+        };
+        let context_token_budget = match input.get("max_context_tokens") {
+            None => None,
+            Some(value) => {
+                let budget = value
+                    .as_u64()
+                    .and_then(|budget| usize::try_from(budget).ok())
+                    .ok_or_else(|| {
+                        BitFunError::tool(
+                            "max_context_tokens must be a non-negative integer".to_string(),
+                        )
+                    })?;
+                if budget < MIN_SUBAGENT_CONTEXT_TOKENS {
+                    return Err(BitFunError::tool(format!(
+                        "max_context_tokens must be at least {}",
+                        MIN_SUBAGENT_CONTEXT_TOKENS
+                    )));
+                }
+                Some(budget)
+            }
+        };
+        Ok(SubagentScope {
+            allowed_tools,
+            context_token_budget,
+        })
+    }
+
+    fn optional_output_schema(input: &Value) -> BitFunResult<Option<Value>> {
+        match input.get("output_schema") {
+            None => Ok(None),
+            Some(schema) if schema.is_object() => Ok(Some(schema.clone())),
+            Some(_) => Err(BitFunError::tool(
+                "output_schema must be a JSON Schema object".to_string(),
+            )),
+        }
+    }
+
     fn ensure_fields_absent(
         input: &Value,
         fields: &[&str],
@@ -1,7 +1,9 @@
 use crate::agentic::agents::{
     get_agent_registry, AgentInfo, SubagentListScope, SubagentQueryContext,
 };
-use crate::agentic::coordination::{get_global_coordinator, SubagentExecutionRequest};
+use crate::agentic::coordination::{
+    get_global_coordinator, SubagentExecutionRequest, SubagentScope,
+};
 use crate::agentic::deep_review::task_adapter::{
     self as deep_review_task_adapter, DeepReviewLaunchBatchInfo,
     DeepReviewProviderQueueWaitOutcome, DeepReviewQueueWaitOutcome, DeepReviewQueueWaitSkipReason,
@@ -26,7 +28,7 @@ use crate::service::config::types::{AIConfig, GlobalConfig};
 use crate::util::errors::{BitFunError, BitFunResult};
 use crate::util::timing::elapsed_ms_u64;
 use async_trait::async_trait;
-use bitfun_runtime_ports::{PermissionRuntimeCeiling, SubagentContextMode};
+use bitfun_runtime_ports::{DelegationPolicy, PermissionRuntimeCeiling, SubagentContextMode};
 use input::{TaskAction, TaskInvocation};
 use log::{debug, warn};
 use serde_json::{json, Map, Value};
@@ -39,6 +41,7 @@ mod execution;
 mod input;
 mod launch_review_agent;
 mod schema;
+mod structured_result;
 mod validation;
 
 pub use launch_review_agent::LaunchReviewAgentTool;
@@ -47,6 +50,8 @@ pub struct TaskTool;
 
 const LARGE_TASK_PROMPT_SOFT_LINE_LIMIT: usize = 180;
 const LARGE_TASK_PROMPT_SOFT_BYTE_LIMIT: usize = 16 * 1024;
+/// Smallest context budget a Task call may request for a subagent.
+const MIN_SUBAGENT_CONTEXT_TOKENS: usize = 8 * 1024;
 
 impl Default for TaskTool {
     fn default() -> Self {
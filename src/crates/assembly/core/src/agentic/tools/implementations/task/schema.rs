@@ -70,6 +70,29 @@ impl TaskTool {
                 "description": "Optional for action='spawn' and action='send_input'. Defaults to false."
             }),
         );
+        properties.insert(
+            "allowed_tools".to_string(),
+            json!({
+                "type": "array",
+                "items": { "type": "string" },
+                "description": "Optional for action='spawn'. Restricts the subagent to these tool names; it can never gain tools its agent type lacks."
+            }),
+        );
+        properties.insert(
+            "max_context_tokens".to_string(),
+            json!({
+                "type": "integer",
+                "minimum": MIN_SUBAGENT_CONTEXT_TOKENS,
+                "description": "Optional for action='spawn'. Caps the subagent's context window; older turns are compressed past this budget."
+            }),
+        );
+        properties.insert(
+            "output_schema".to_string(),
+            json!({
+                "type": "object",
+                "description": "Optional for action='spawn'. JSON Schema the subagent's final answer must follow; the parsed object is returned as `structured_result`."
+            }),
+        );
         json!({
             "type": "object",
             "properties": properties,
@@ -112,6 +135,12 @@ The two modes are mutually exclusive: do not provide `subagent_type` when `fork_
 - false: Wait for the agent to finish and return its result to you.
 - true: Run the agent in the background without blocking you. The response includes a `bg_task_id`; use AgentWait when you need the results.
 
+Scoping a spawned subagent (all optional):
+- `allowed_tools`: narrow the subagent to the tools the task needs, e.g. `["Read", "Grep", "Glob"]` for research.
+- `max_context_tokens`: give a small task a smaller context budget.
+- `output_schema`: ask for a JSON object instead of prose when you will process the result programmatically. Only foreground results are parsed.
+- Subagents can spawn their own subagents only up to the nesting depth the user configured.
+
 `model_id` usage:
 - Set it only when the user requests a particular model.
 - Omit it to use the subagent's configured model, which may differ from your model.
@@ -0,0 +1,49 @@
+//! Structured result hand-back for Task calls that pass `output_schema`.
+//!
+//! The subagent is asked to end with a single JSON object. Only top-level
+//! shape is checked here (object-ness and `required` keys); the parent still
+//! decides what to do with the values.
+
+use serde_json::Value;
+
+pub(super) fn prompt_with_output_schema(prompt: &str, schema: &Value) -> String {
+    let schema_text = serde_json::to_string_pretty(schema).unwrap_or_else(|_| schema.to_string());
+    format!(
+        "{prompt}\n\n<output_format>\nEnd your final answer with one JSON object in a ```json fenced block that conforms to this JSON Schema. Do not add commentary after the block.\n{schema_text}\n</output_format>"
+    )
+}
+
+/// Extracts the JSON answer from a subagent's final text: the last ```json
+/// fenced block if there is one, otherwise the whole text.
+pub(super) fn extract_structured_result(text: &str, schema: &Value) -> Result<Value, String> {
+    let candidate = last_json_fence(text).unwrap_or_else(|| text.trim());
+    let value: Value = serde_json::from_str(candidate)
+        .map_err(|e| format!("final answer is not valid JSON: {}", e))?;
+
+    let expects_object = schema.get("type").and_then(Value::as_str) == Some("object")
+        || schema.get("properties").is_some();
+    if expects_object && !value.is_object() {
+        return Err("final answer is not a JSON object".to_string());
+    }
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        let missing = required
+            .iter()
+            .filter_map(Value::as_str)
+            .filter(|key| value.get(key).is_none())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(format!(
+                "final answer is missing required fields: {}",
+                missing.join(", ")
+            ));
+        }
+    }
+    Ok(value)
+}
+
+fn last_json_fence(text: &str) -> Option<&str> {
+    let start = text.rfind("```json")? + "```json".len();
+    let body = &text[start..];
+    let end = body.find("```")?;
+    Some(body[..end].trim())
+}
@@ -1,3 +1,4 @@
+use super::structured_result::{extract_structured_result, prompt_with_output_schema};
 use super::{LaunchReviewAgentTool, TaskTool};
 use crate::agentic::agents::CustomSubagentConfig;
 use crate::agentic::agents::{
@@ -148,6 +149,99 @@ fn task_model_id_inherit_requests_parent_model_inheritance() {
     assert!(invocation.inherit_parent_model);
 }
 
+#[test]
+fn task_spawn_parses_scoped_tools_budget_and_output_schema() {
+    let invocation = TaskTool::parse_invocation(
+        &json!({
+            "action": "spawn",
+            "description": "List parser entry points",
+            "prompt": "List the public parser entry points.",
+            "subagent_type": "Explore",
+            "allowed_tools": ["Read", "Grep", "Read"],
+            "max_context_tokens": 32000,
+            "output_schema": {
+                "type": "object",
+                "required": ["entry_points"]
+            }
+        }),
+        false,
+    )
+    .expect("scoped spawn should parse");
+
+    assert_eq!(invocation.scope.allowed_tools, vec!["Read", "Grep"]);
+    assert_eq!(invocation.scope.context_token_budget, Some(32000));
+    assert!(invocation.output_schema.is_some());
+}
+
+#[test]
+fn task_scope_fields_are_validated_and_spawn_only() {
+    let too_small = TaskTool::parse_invocation(
+        &json!({
+            "action": "spawn",
+            "description": "Inspect parser",
+            "prompt": "Inspect the parser flow.",
+            "subagent_type": "Explore",
+            "max_context_tokens": 100
+        }),
+        false,
+    )
+    .expect_err("tiny context budgets should be rejected");
+    assert!(too_small
+        .to_string()
+        .contains("max_context_tokens must be at least"));
+
+    let empty_tools = TaskTool::parse_invocation(
+        &json!({
+            "action": "spawn",
+            "description": "Inspect parser",
+            "prompt": "Inspect the parser flow.",
+            "subagent_type": "Explore",
+            "allowed_tools": []
+        }),
+        false,
+    )
+    .expect_err("an empty allowlist should be rejected");
+    assert!(empty_tools.to_string().contains("allowed_tools must name"));
+
+    let send_input = TaskTool::parse_invocation(
+        &json!({
+            "action": "send_input",
+            "description": "Continue",
+            "prompt": "Continue.",
+            "agent_id": "a1",
+            "allowed_tools": ["Read"]
+        }),
+        false,
+    )
+    .expect_err("scope cannot change on send_input");
+    assert!(send_input
+        .to_string()
+        .contains("allowed_tools is not allowed when action is send_input"));
+}
+
+#[test]
+fn structured_results_are_extracted_from_fenced_or_bare_json() {
+    let schema = json!({
+        "type": "object",
+        "required": ["files"]
+    });
+    assert!(prompt_with_output_schema("Find files.", &schema).contains("\"required\""));
+
+    let fenced = "Found two.\n```json\n{\"files\": [\"a.rs\", \"b.rs\"]}\n```";
+    assert_eq!(
+        extract_structured_result(fenced, &schema).unwrap(),
+        json!({ "files": ["a.rs", "b.rs"] })
+    );
+    assert_eq!(
+        extract_structured_result(" {\"files\": []} ", &schema).unwrap(),
+        json!({ "files": [] })
+    );
+
+    let missing = extract_structured_result("{\"count\": 2}", &schema).unwrap_err();
+    assert!(missing.contains("files"));
+    assert!(extract_structured_result("no json here", &schema).is_err());
+}
+
 #[tokio::test]
 async fn validate_input_accepts_review_background_for_agent_wait() {
     let validation = TaskTool::new()
@@ -5,10 +5,14 @@ use super::readable::{
 use crate::agentic::tools::framework::{
     PermissionIntent, Tool, ToolExposure, ToolResult, ToolUseContext, ValidationResult,
 };
+use crate::service::config::{get_global_config_service, GlobalConfig};
 use crate::util::errors::{BitFunError, BitFunResult};
 use async_trait::async_trait;
-use bitfun_services_integrations::web_tools::WebToolNetworkProvider;
+use bitfun_services_integrations::web_tools::{
+    ensure_url_allowed, WebFetchOptions, WebToolNetworkProvider,
+};
 use serde_json::{json, Value};
+use std::time::Duration;
 
 /// WebFetch tool
 pub struct WebFetchTool;
@@ -23,6 +27,27 @@ impl WebFetchTool {
     pub fn new() -> Self {
         Self
     }
+
+    /// Builds fetch limits from `ai.web_fetch` and the global proxy, falling
+    /// back to defaults when config is unavailable.
+    async fn fetch_options() -> WebFetchOptions {
+        let config = match get_global_config_service().await {
+            Ok(service) => service.get_config::<GlobalConfig>(None).await.ok(),
+            Err(_) => None,
+        };
+        let Some(config) = config else {
+            return WebFetchOptions::default();
+        };
+        let settings = config.ai.web_fetch;
+        WebFetchOptions {
+            timeout: Duration::from_secs(settings.timeout_secs.max(1)),
+            max_bytes: settings.max_response_bytes.max(1),
+            allowed_domains: settings.allowed_domains,
+            respect_robots_txt: settings.respect_robots_txt,
+            cache_ttl: Duration::from_secs(settings.cache_ttl_secs),
+            proxy: Some(config.ai.proxy),
+        }
+    }
 }
 
 #[async_trait]
@@ -40,6 +65,8 @@ Use this tool to:
 - Download readable content from web pages
 - Access online resources
 
+Pages are cached for a short time, large responses are truncated, robots.txt is respected, and only domains in the configured allowlist can be fetched when one is set.
+
 Supports different output formats:
 - raw: Raw response content (original HTML or text)
 - markdown: Readable content mode. For HTML pages, BitFun extracts the main content and returns markdown when possible, automatically falling back to plain text when markdown conversion is not reliable.
@@ -127,6 +154,16 @@ Example usage:
                     meta: None,
                 };
             }
+
+            let allowed_domains = Self::fetch_options().await.allowed_domains;
+            if let Err(error) = ensure_url_allowed(url, &allowed_domains) {
+                return ValidationResult {
+                    result: false,
+                    message: Some(error.to_string()),
+                    error_code: Some(403),
+                    meta: None,
+                };
+            }
         } else {
             return ValidationResult {
                 result: false,
@@ -157,9 +194,13 @@ Example usage:
         let requested_format =
             normalize_requested_format(input.get("format").and_then(|v| v.as_str()))?;
 
-        let response = WebToolNetworkProvider::fetch_text(url)
+        let options = Self::fetch_options().await;
+        let response = WebToolNetworkProvider::fetch_text(url, &options)
             .await
             .map_err(|error| BitFunError::tool(error.to_string()))?;
+        let final_url = response.url;
+        let truncated = response.truncated;
+        let from_cache = response.from_cache;
         let content_type = response.content_type;
         let content = response.content;
 
@@ -173,13 +214,19 @@ Example usage:
         let (processed_content, content_representation, extractor, title) = match requested_format {
             RequestedFormat::Raw => (content, "raw", "raw", fallback_title),
             RequestedFormat::Json => {
+                if truncated {
+                    return Err(BitFunError::tool(format!(
+                        "JSON response exceeds the {} byte web fetch limit",
+                        options.max_bytes
+                    )));
+                }
                 serde_json::from_str::<Value>(&content)
                     .map_err(|e| BitFunError::tool(format!("Invalid JSON response: {}", e)))?;
                 (content, "json", "json", None)
             }
             RequestedFormat::Markdown => {
                 if is_html_response {
-                    let readable = extract_markdown_with_text_fallback(&content, url)?;
+                    let readable = extract_markdown_with_text_fallback(&content, &final_url)?;
                     (
                         readable.content,
                         readable.content_representation,
@@ -192,9 +239,19 @@ Example usage:
             }
         };
 
+        let result_for_assistant = if truncated {
+            format!(
+                "{}\n\n[Response truncated at {} bytes]",
+                processed_content, options.max_bytes
+            )
+        } else {
+            processed_content.clone()
+        };
+
         let result = ToolResult::Result {
             data: json!({
                 "url": url,
+                "final_url": final_url,
                 "title": title,
                 "format": match requested_format {
                     RequestedFormat::Raw => "raw",
@@ -204,9 +261,11 @@ Example usage:
                 "content_representation": content_representation,
                 "extractor": extractor,
                 "content": processed_content,
-                "content_length": processed_content.len()
+                "content_length": processed_content.len(),
+                "truncated": truncated,
+                "cached": from_cache
             }),
-            result_for_assistant: Some(processed_content),
+            result_for_assistant: Some(result_for_assistant),
             image_attachments: None,
         };
 
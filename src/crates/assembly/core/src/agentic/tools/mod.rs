@@ -6,6 +6,7 @@ pub mod computer_use_capability;
 pub mod computer_use_host;
 pub mod computer_use_optimizer;
 pub mod computer_use_verification;
+pub(crate) mod exposure_budget;
 pub(crate) mod file_permissions;
 pub mod file_read_state_runtime;
 pub mod file_tool_guidance;
@@ -29,8 +30,8 @@ pub mod workspace_paths;
 pub use bitfun_agent_tools::input_validator;
 
 pub use framework::{
-    PortableToolContextProvider, Tool, ToolContextFacts, ToolResult, ToolUseContext,
-    ToolWorkspaceKind, ValidationResult,
+    PortableToolContextProvider, Tool, ToolContextFacts, ToolResource, ToolResourceAccess,
+    ToolResult, ToolUseContext, ToolWorkspaceKind, ValidationResult,
 };
 pub use image_context::{ImageContextData, ImageContextProvider, ImageContextProviderRef};
 pub use input_validator::InputValidator;
@@ -45,5 +46,5 @@ pub use registry::{
 pub use restrictions::{
     is_miniapp_headless_agent_run, miniapp_headless_agent_tool_restrictions,
     tool_restrictions_for_delegation_policy, ToolPathOperation, ToolPathPolicy,
-    ToolRuntimeRestrictions,
+    ToolRuntimeRestrictions, ToolWriteSandbox,
 };
@@ -0,0 +1,90 @@
+//! Tool-call lifecycle hooks
+//!
+//! Bridges the pipeline to the user hook runner. `pre_tool_call` hooks run
+//! before permission planning, so the user approves the rewritten arguments
+//! and blocked calls never prompt.
+
+use super::types::ToolExecutionContext;
+use crate::agentic::core::{ToolCall, ToolResult as ModelToolResult};
+use crate::service::hooks::{HookEvent, HookInput, HookRunner};
+use bitfun_agent_tools::ResolvedToolInvocation;
+use serde_json::Value;
+use std::path::Path;
+
+const EDIT_TOOL_NAMES: &[&str] = &["Write", "Edit", "EditTransaction", "Delete"];
+
+pub(crate) async fn run_pre_tool_call_hooks(
+    resolved_tool_calls: &mut [(ToolCall, ResolvedToolInvocation, Option<String>)],
+    context: &ToolExecutionContext,
+) {
+    let Some(runner) = HookRunner::load().await else {
+        return;
+    };
+    for (_, invocation, resolution_error) in resolved_tool_calls.iter_mut() {
+        let tool_name = invocation.effective_tool_name.clone();
+        if resolution_error.is_some() || !runner.handles(HookEvent::PreToolCall, Some(&tool_name)) {
+            continue;
+        }
+        let mut input = tool_hook_input(HookEvent::PreToolCall, context, &tool_name);
+        input.tool_input = Some(invocation.effective_arguments.clone());
+        let outcome = runner.run(input, local_workspace_root(context)).await;
+        if let Some(reason) = outcome.blocked {
+            *resolution_error = Some(format!("Tool call blocked by hook: {}", reason));
+        } else if let Some(updated_input) = outcome.updated_input {
+            invocation.effective_arguments = updated_input;
+        }
+    }
+}
+
+/// Runs `post_tool_call` hooks, plus `post_edit` hooks after a successful
+/// file edit. Returns text to append to the result the model sees.
+pub(crate) async fn run_post_tool_call_hooks(
+    context: &ToolExecutionContext,
+    tool_name: &str,
+    tool_input: &Value,
+    tool_result: &ModelToolResult,
+) -> Option<String> {
+    let runner = HookRunner::load().await?;
+    let mut events = vec![HookEvent::PostToolCall];
+    if !tool_result.is_error && EDIT_TOOL_NAMES.contains(&tool_name) {
+        events.push(HookEvent::PostEdit);
+    }
+
+    let mut feedback = Vec::new();
+    for event in events {
+        if !runner.handles(event, Some(tool_name)) {
+            continue;
+        }
+        let mut input = tool_hook_input(event, context, tool_name);
+        input.tool_input = Some(tool_input.clone());
+        input.tool_result = tool_result.result_for_assistant.clone();
+        input.is_error = Some(tool_result.is_error);
+        if let Some(text) = runner
+            .run(input, local_workspace_root(context))
+            .await
+            .feedback()
+        {
+            feedback.push(text);
+        }
+    }
+    (!feedback.is_empty()).then(|| feedback.join("\n"))
+}
+
+fn tool_hook_input(event: HookEvent, context: &ToolExecutionContext, tool_name: &str) -> HookInput {
+    let mut input = HookInput::new(event, context.session_id.clone());
+    input.workspace_path = context
+        .workspace
+        .as_ref()
+        .map(|workspace| workspace.root_path_string());
+    input.agent_type = Some(context.agent_type.clone());
+    input.tool_name = Some(tool_name.to_string());
+    input
+}
+
+fn local_workspace_root(context: &ToolExecutionContext) -> Option<&Path> {
+    context
+        .workspace
+        .as_ref()
+        .filter(|workspace| !workspace.is_remote())
+        .map(|workspace| workspace.root_path())
+}
@@ -2,10 +2,13 @@
 //!
 //! Provides complete lifecycle management for tool execution
 
+mod lifecycle_hooks;
+mod replay;
 pub mod state_manager;
 pub mod tool_pipeline;
 pub mod types;
 
+pub use replay::{start_tool_replay, stop_tool_replay, tool_replay_status};
 pub use state_manager::*;
 pub use tool_pipeline::*;
 pub use types::*;
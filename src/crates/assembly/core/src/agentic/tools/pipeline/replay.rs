@@ -0,0 +1,192 @@
+//! Tool invocation recording and replay mode
+//!
+//! Executed tool calls are appended to the session's invocation log. A session
+//! in replay mode answers tool calls from a loaded bundle instead of executing
+//! them; see `bitfun_agent_tools::tool_replay` for the matching rules.
+
+use super::types::{ToolExecutionResult, ToolTask};
+use crate::agentic::core::ToolResult as ModelToolResult;
+use crate::agentic::tools::tool_context_runtime::ToolUseContext;
+use crate::util::errors::BitFunResult;
+use bitfun_agent_tools::{
+    ToolInvocationRecord, ToolReplayBundle, ToolReplayCursor, ToolReplayStatus,
+    TOOL_INVOCATION_LOG_FILE_NAME,
+};
+use log::{info, warn};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+
+static TOOL_REPLAY_SESSIONS: OnceLock<Mutex<HashMap<String, ToolReplayCursor>>> = OnceLock::new();
+
+fn replay_sessions() -> std::sync::MutexGuard<'static, HashMap<String, ToolReplayCursor>> {
+    TOOL_REPLAY_SESSIONS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Puts a session into replay mode. Replaces any replay already running.
+pub fn start_tool_replay(session_id: &str, bundle: ToolReplayBundle) -> ToolReplayStatus {
+    let cursor = ToolReplayCursor::new(bundle);
+    let status = cursor.status();
+    info!(
+        "Tool replay started: session_id={}, recorded={}",
+        session_id, status.recorded
+    );
+    replay_sessions().insert(session_id.to_string(), cursor);
+    status
+}
+
+/// Leaves replay mode and returns the final replay status.
+pub fn stop_tool_replay(session_id: &str) -> Option<ToolReplayStatus> {
+    let status = replay_sessions()
+        .remove(session_id)
+        .map(|cursor| cursor.status());
+    if let Some(status) = status {
+        info!(
+            "Tool replay stopped: session_id={}, recorded={}, replayed={}, diverged={}",
+            session_id, status.recorded, status.replayed, status.diverged
+        );
+    }
+    status
+}
+
+pub fn tool_replay_status(session_id: &str) -> Option<ToolReplayStatus> {
+    replay_sessions()
+        .get(session_id)
+        .map(|cursor| cursor.status())
+}
+
+pub(crate) fn is_tool_replay_active(session_id: &str) -> bool {
+    replay_sessions().contains_key(session_id)
+}
+
+/// Answers one call from the session's replay bundle. A call without a
+/// matching recording becomes an error result; it is never executed.
+pub(crate) fn replay_tool_task(task: &ToolTask) -> ToolExecutionResult {
+    let tool_id = task.tool_call.tool_id.clone();
+    let wire_tool_name = task.tool_call.tool_name.clone();
+    let effective_tool_name = task.invocation.effective_tool_name.clone();
+    let persisted_effective_tool_name =
+        (wire_tool_name != effective_tool_name).then(|| effective_tool_name.clone());
+
+    let replayed = replay_sessions()
+        .get_mut(&task.context.session_id)
+        .map(|cursor| cursor.next_result(&effective_tool_name, task.effective_arguments()));
+    let result = match replayed {
+        Some(Ok(record)) => ModelToolResult {
+            tool_id: tool_id.clone(),
+            tool_name: wire_tool_name.clone(),
+            effective_tool_name: persisted_effective_tool_name,
+            result: record.result,
+            result_for_assistant: record.result_for_assistant,
+            is_error: record.is_error,
+            duration_ms: Some(record.duration_ms),
+            image_attachments: None,
+        },
+        replayed => {
+            let message = match replayed {
+                Some(Err(divergence)) => divergence.message(),
+                _ => "Replay mode ended before this call. The tool was not executed.".to_string(),
+            };
+            warn!(
+                "Tool replay diverged: session_id={}, tool_name={}",
+                task.context.session_id, effective_tool_name
+            );
+            ModelToolResult {
+                tool_id: tool_id.clone(),
+                tool_name: wire_tool_name.clone(),
+                effective_tool_name: persisted_effective_tool_name,
+                result: serde_json::json!({ "error": message }),
+                result_for_assistant: Some(message),
+                is_error: true,
+                duration_ms: Some(0),
+                image_attachments: None,
+            }
+        }
+    };
+    let execution_time_ms = result.duration_ms.unwrap_or_default();
+    ToolExecutionResult {
+        tool_id,
+        tool_name: wire_tool_name,
+        effective_tool_name,
+        result,
+        execution_time_ms,
+    }
+}
+
+/// Appends the round's results to the session invocation log. Failures are
+/// logged and never affect the tool results.
+pub(crate) async fn record_tool_invocations(
+    tasks: &[ToolTask],
+    results: &[ToolExecutionResult],
+    context: &ToolUseContext,
+) {
+    let Some(session_id) = context.session_id.as_deref() else {
+        return;
+    };
+    let mut lines = String::new();
+    for execution in results {
+        let Some(task) = tasks
+            .iter()
+            .find(|task| task.tool_call.tool_id == execution.tool_id)
+        else {
+            continue;
+        };
+        let record = ToolInvocationRecord {
+            tool_id: execution.tool_id.clone(),
+            tool_name: execution.tool_name.clone(),
+            effective_tool_name: execution.effective_tool_name.clone(),
+            arguments: task.effective_arguments().clone(),
+            result: execution.result.result.clone(),
+            result_for_assistant: execution.result.result_for_assistant.clone(),
+            is_error: execution.result.is_error,
+            dialog_turn_id: Some(task.context.dialog_turn_id.clone()),
+            started_at_ms: unix_millis(task.created_at),
+            duration_ms: execution.execution_time_ms,
+        };
+        match serde_json::to_string(&record) {
+            Ok(line) => {
+                lines.push_str(&line);
+                lines.push('\n');
+            }
+            Err(error) => warn!(
+                "Failed to serialize tool invocation record: tool_id={}, error={}",
+                record.tool_id, error
+            ),
+        }
+    }
+    if lines.is_empty() {
+        return;
+    }
+    if let Err(error) = append_to_invocation_log(context, session_id, &lines).await {
+        warn!(
+            "Failed to record tool invocations: session_id={}, error={}",
+            session_id, error
+        );
+    }
+}
+
+async fn append_to_invocation_log(
+    context: &ToolUseContext,
+    session_id: &str,
+    lines: &str,
+) -> BitFunResult<()> {
+    let session_dir = context.current_workspace_session_dir(session_id)?;
+    tokio::fs::create_dir_all(&session_dir).await?;
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(session_dir.join(TOOL_INVOCATION_LOG_FILE_NAME))
+        .await?;
+    file.write_all(lines.as_bytes()).await?;
+    Ok(())
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -3,6 +3,8 @@
 //! Manages the complete lifecycle of tools:
 //! permission authorization, execution, caching, retries, etc.
 
+use super::lifecycle_hooks::{run_post_tool_call_hooks, run_pre_tool_call_hooks};
+use super::replay;
 use super::state_manager::{tool_task_state_kind, ToolStateManager};
 use super::types::*;
 use crate::agentic::core::{ToolCall, ToolExecutionState, ToolResult as ModelToolResult};
@@ -35,7 +37,7 @@ use bitfun_runtime_ports::{
     PermissionRequestSource, PermissionRequestSourceKind, PermissionResourceCaseSensitivity,
     PermissionRule, RoundInjectionToolPreemption,
 };
-use futures::future::join_all;
+use futures::stream::{FuturesUnordered, StreamExt};
 use log::{debug, error, info, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -44,8 +46,8 @@ use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
 use tokio::time::{timeout, Duration};
 use tokio_util::sync::CancellationToken;
 use tool_runtime::pipeline::{
-    partition_tool_batches, retry_delay_ms, should_cancel_tool_state, should_retry_tool_attempt,
-    summarize_dialog_turn_cancellation, tool_call_concurrency_safe_for_batch,
+    plan_tool_dependencies, retry_delay_ms, should_cancel_tool_state, should_retry_tool_attempt,
+    summarize_dialog_turn_cancellation, tool_call_concurrency_safe_for_batch, ToolCallFootprint,
     ToolCancellationTokenStore, ToolExecutionErrorClass, ToolRetryAttemptFacts,
 };
 
@@ -179,6 +181,32 @@ fn convert_to_framework_result(model_result: &ModelToolResult) -> FrameworkToolR
     }
 }
 
+/// Logs where a round's tool time went: wall-clock time for the whole set
+/// next to each call's own duration.
+fn log_tool_round_timing(results: &[ToolExecutionResult], wall_ms: u64) {
+    if results.len() < 2 {
+        return;
+    }
+    let summed_ms: u64 = results.iter().map(|result| result.execution_time_ms).sum();
+    let calls = results
+        .iter()
+        .map(|result| {
+            format!(
+                "{}({})={}ms",
+                result.effective_tool_name, result.tool_id, result.execution_time_ms
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    info!(
+        "Tool round timing: tools={}, wall_ms={}, summed_ms={}, calls=[{}]",
+        results.len(),
+        wall_ms,
+        summed_ms,
+        calls
+    );
+}
+
 fn elapsed_ms_since(time: SystemTime) -> u64 {
     time.elapsed()
         .map(|duration| duration.as_millis().min(u128::from(u64::MAX)) as u64)
@@ -1182,12 +1210,12 @@ impl ToolPipeline {
         }))
     }
 
-    /// Execute multiple tool calls using partitioned mixed scheduling.
+    /// Execute multiple tool calls using dependency-aware scheduling.
     ///
-    /// Consecutive concurrency-safe calls are grouped into a single batch and
-    /// run in parallel; each non-safe call forms its own batch and runs serially.
-    /// Batches are executed in order so that write-after-read dependencies are
-    /// respected while reads still benefit from parallelism.
+    /// Each call waits only for earlier calls whose declared resources it
+    /// conflicts with, so writes to different files overlap while a read of a
+    /// file still follows an earlier write to it. Calls that declare nothing
+    /// keep the batch ordering implied by their concurrency safety.
     pub async fn execute_tools(
         &self,
         tool_calls: Vec<ToolCall>,
@@ -1199,7 +1227,7 @@ impl ToolPipeline {
         }
 
         info!("Executing tools: count={}", tool_calls.len());
-        let resolved_tool_calls = tool_calls
+        let mut resolved_tool_calls = tool_calls
             .iter()
             .map(|tool_call| {
                 let (invocation, resolution_error) =
@@ -1207,6 +1235,7 @@ impl ToolPipeline {
                 (tool_call.clone(), invocation, resolution_error)
             })
             .collect::<Vec<_>>();
+        run_pre_tool_call_hooks(&mut resolved_tool_calls, &context).await;
         let tool_names = resolved_tool_calls
             .iter()
             .map(|(_, invocation, _)| invocation.effective_tool_name.clone())
@@ -1275,6 +1304,10 @@ impl ToolPipeline {
             task_ids.push(tool_id);
         }
 
+        if replay::is_tool_replay_active(&context.session_id) {
+            return Ok(self.replay_tools(&task_ids).await);
+        }
+
         if let Err(error) = self.prepare_permission_plans(&task_ids).await {
             self.cleanup_permission_plans(&task_ids, "Permission planning failed".to_string())
                 .await;
@@ -1290,101 +1323,218 @@ impl ToolPipeline {
                 tool_names.join(", ")
             );
             let result = self.execute_sequential(task_ids.clone()).await;
+            if let Ok(results) = &result {
+                self.record_tool_round(&task_ids, results).await;
+            }
             self.cleanup_permission_plans(&task_ids, "Tool execution finished".to_string())
                 .await;
             return result;
         }
 
-        // Partition into batches of consecutive same-safety tool calls
-        let batches = partition_tool_batches(&task_ids, &concurrency_flags);
+        let footprints = self
+            .resolve_tool_footprints(&task_ids, &concurrency_flags)
+            .await;
+        let dependencies = plan_tool_dependencies(&footprints);
         debug!(
-            "Tool execution plan: total_tools={}, batches={}, concurrency_safe={}, non_concurrency_safe={}, allow_parallel=true, tools={}",
+            "Tool execution plan: total_tools={}, independent={}, concurrency_safe={}, non_concurrency_safe={}, max_parallel={}, allow_parallel=true, tools={}",
             task_ids.len(),
-            batches.len(),
+            dependencies.iter().filter(|deps| deps.is_empty()).count(),
             concurrency_safe_count,
             task_ids.len().saturating_sub(concurrency_safe_count),
+            options.max_parallel_tools,
             tool_names.join(", ")
         );
 
-        debug!(
-            "Partitioned {} tools into {} batches for mixed execution",
-            task_ids.len(),
-            batches.len()
-        );
+        let started_at = Instant::now();
+        let all_results = self
+            .execute_scheduled(&task_ids, &dependencies, options.max_parallel_tools)
+            .await;
+        log_tool_round_timing(&all_results, elapsed_ms_u64(started_at));
+        self.record_tool_round(&task_ids, &all_results).await;
 
-        let mut all_results = Vec::with_capacity(task_ids.len());
-        let mut batch_iter = batches.into_iter().enumerate().peekable();
-        while let Some((batch_idx, batch)) = batch_iter.next() {
-            let batch_context = batch
-                .task_ids
-                .first()
-                .and_then(|task_id| self.state_manager.get_task(task_id))
-                .map(|task| task.context);
-            if batch_context
-                .as_ref()
-                .is_some_and(|context| self.should_interrupt_for_round_injection(context))
-            {
-                let remaining_task_ids = batch
-                    .task_ids
-                    .into_iter()
-                    .chain(batch_iter.flat_map(|(_, batch)| batch.task_ids.into_iter()));
-                all_results.extend(
-                    self.build_steering_interrupted_results(remaining_task_ids)
-                        .await,
-                );
-                break;
+        self.cleanup_permission_plans(&task_ids, "Tool execution finished".to_string())
+            .await;
+        Ok(all_results)
+    }
+
+    /// Answers every call of the round from the session's replay bundle
+    /// without running permission checks or the tools themselves.
+    async fn replay_tools(&self, task_ids: &[String]) -> Vec<ToolExecutionResult> {
+        let mut results = Vec::with_capacity(task_ids.len());
+        for task_id in task_ids {
+            let Some(task) = self.state_manager.get_task(task_id) else {
+                continue;
+            };
+            let replayed = replay::replay_tool_task(&task);
+            self.state_manager
+                .update_state(
+                    task_id,
+                    ToolExecutionState::Completed {
+                        result: convert_to_framework_result(&replayed.result),
+                        duration_ms: replayed.execution_time_ms,
+                        queue_wait_ms: Some(0),
+                        preflight_ms: Some(0),
+                        confirmation_wait_ms: Some(0),
+                        execution_ms: Some(0),
+                    },
+                )
+                .await;
+            results.push(replayed);
+        }
+        results
+    }
+
+    async fn record_tool_round(&self, task_ids: &[String], results: &[ToolExecutionResult]) {
+        let tasks = task_ids
+            .iter()
+            .filter_map(|task_id| self.state_manager.get_task(task_id))
+            .collect::<Vec<_>>();
+        let Some(first_task) = tasks.first() else {
+            return;
+        };
+        let tool_context = self.build_tool_use_context(first_task, CancellationToken::new());
+        replay::record_tool_invocations(&tasks, results, &tool_context).await;
+    }
+
+    /// Resolves which resources each call touches so independent calls can
+    /// overlap. Calls whose tool declares nothing fall back to their
+    /// concurrency-safety flag.
+    async fn resolve_tool_footprints(
+        &self,
+        task_ids: &[String],
+        concurrency_flags: &[bool],
+    ) -> Vec<ToolCallFootprint> {
+        let registry = self.tool_registry.read().await;
+        task_ids
+            .iter()
+            .zip(concurrency_flags)
+            .map(|(task_id, &concurrency_safe)| {
+                let accesses = self
+                    .state_manager
+                    .get_task(task_id)
+                    .filter(|task| task.invocation_resolution_error.is_none())
+                    .and_then(|task| {
+                        let route_root = crate::external_tools::external_tool_route_root(
+                            task.context
+                                .workspace
+                                .as_ref()
+                                .map(|workspace| workspace.root_path()),
+                            task.context
+                                .workspace
+                                .as_ref()
+                                .is_some_and(|workspace| workspace.is_remote()),
+                        );
+                        let tool =
+                            registry
+                                .get_tool(task.effective_tool_name())
+                                .and_then(|tool| {
+                                    crate::external_tools::resolve_external_tool_for_workspace(
+                                        tool, route_root,
+                                    )
+                                })?;
+                        let tool_context =
+                            self.build_tool_use_context(&task, CancellationToken::new());
+                        tool.resource_accesses(task.effective_arguments(), &tool_context)
+                    });
+                ToolCallFootprint::from_declaration(concurrency_safe, accesses)
+            })
+            .collect()
+    }
+
+    /// Starts each call once the calls it depends on have finished, keeping at
+    /// most `max_parallel` in flight. Results follow the model's call order.
+    async fn execute_scheduled(
+        &self,
+        task_ids: &[String],
+        dependencies: &[Vec<usize>],
+        max_parallel: usize,
+    ) -> Vec<ToolExecutionResult> {
+        let max_parallel = max_parallel.max(1);
+        for (task_id, deps) in task_ids.iter().zip(dependencies) {
+            if deps.is_empty() {
+                continue;
             }
+            self.state_manager
+                .update_state(
+                    task_id,
+                    ToolExecutionState::Waiting {
+                        dependencies: deps.iter().map(|&dep| task_ids[dep].clone()).collect(),
+                    },
+                )
+                .await;
+        }
 
-            debug!(
-                "Executing batch {}: {} tool(s), concurrent={}",
-                batch_idx,
-                batch.task_ids.len(),
-                batch.is_concurrent
-            );
-            let batch_results = if batch.is_concurrent {
-                self.execute_parallel(batch.task_ids).await?
-            } else {
-                self.execute_sequential(batch.task_ids).await?
+        let mut results: Vec<Option<ToolExecutionResult>> = vec![None; task_ids.len()];
+        let mut started = vec![false; task_ids.len()];
+        let mut finished = vec![false; task_ids.len()];
+        let mut interrupted = false;
+        let mut running = FuturesUnordered::new();
+
+        loop {
+            if !interrupted {
+                for index in 0..task_ids.len() {
+                    if running.len() >= max_parallel {
+                        break;
+                    }
+                    if started[index] || !dependencies[index].iter().all(|&dep| finished[dep]) {
+                        continue;
+                    }
+                    let task = self.state_manager.get_task(&task_ids[index]);
+                    if task.as_ref().is_some_and(|task| {
+                        self.should_interrupt_for_round_injection(&task.context)
+                    }) {
+                        interrupted = true;
+                        break;
+                    }
+                    started[index] = true;
+                    let interrupt = task.and_then(|task| task.context.steering_interrupt.clone());
+                    running.push(self.execute_watched_tool(
+                        index,
+                        task_ids[index].clone(),
+                        interrupt,
+                    ));
+                }
+            }
+
+            let Some((index, result)) = running.next().await else {
+                break;
             };
-            all_results.extend(batch_results);
+            finished[index] = true;
+            let mut appended = Vec::with_capacity(1);
+            self.append_execution_result(&task_ids[index], result, &mut appended);
+            results[index] = appended.pop();
         }
 
-        self.cleanup_permission_plans(&task_ids, "Tool execution finished".to_string())
+        // Calls that never started because a round injection is waiting.
+        let unstarted = (0..task_ids.len())
+            .filter(|&index| !started[index])
+            .collect::<Vec<_>>();
+        let interrupted_results = self
+            .build_steering_interrupted_results(
+                unstarted.iter().map(|&index| task_ids[index].clone()),
+            )
             .await;
-        Ok(all_results)
+        for (index, result) in unstarted.into_iter().zip(interrupted_results) {
+            results[index] = Some(result);
+        }
+
+        results.into_iter().flatten().collect()
     }
 
-    /// Execute tools in parallel
-    async fn execute_parallel(
+    async fn execute_watched_tool(
         &self,
-        task_ids: Vec<String>,
-    ) -> BitFunResult<Vec<ToolExecutionResult>> {
-        let batch_interrupt = task_ids
-            .first()
-            .and_then(|task_id| self.state_manager.get_task(task_id))
-            .and_then(|task| task.context.steering_interrupt.clone());
+        index: usize,
+        task_id: String,
+        interrupt: Option<crate::agentic::round_preempt::DialogRoundInjectionInterrupt>,
+    ) -> (usize, BitFunResult<ToolExecutionResult>) {
         let watch_handle =
-            self.spawn_round_injection_cancellation_watch(task_ids.clone(), batch_interrupt);
-
-        let futures: Vec<_> = task_ids
-            .iter()
-            .map(|id| self.execute_single_tool(id.clone()))
-            .collect();
-
-        let results = join_all(futures).await;
+            self.spawn_round_injection_cancellation_watch(vec![task_id.clone()], interrupt);
+        let result = self.execute_single_tool(task_id).await;
         if let Some(handle) = watch_handle {
             handle.abort();
             let _ = handle.await;
         }
-
-        // Collect results, including failed results
-        let mut all_results = Vec::new();
-        for (idx, result) in results.into_iter().enumerate() {
-            let task_id = &task_ids[idx];
-            self.append_execution_result(task_id, result, &mut all_results);
-        }
-
-        Ok(all_results)
+        (index, result)
     }
 
     /// Execute tools sequentially
@@ -1753,6 +1903,18 @@ impl ToolPipeline {
                     });
                 }
 
+                if let Some(feedback) =
+                    run_post_tool_call_hooks(&task.context, &tool_name, &tool_args, &tool_result)
+                        .await
+                {
+                    let original = tool_result.result_for_assistant.unwrap_or_default();
+                    tool_result.result_for_assistant = Some(if original.is_empty() {
+                        feedback
+                    } else {
+                        format!("{original}\n\n{feedback}")
+                    });
+                }
+
                 self.state_manager
                     .update_state(
                         &tool_id,
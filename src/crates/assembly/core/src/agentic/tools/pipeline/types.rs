@@ -6,6 +6,7 @@ use crate::agentic::round_preempt::DialogRoundInjectionInterrupt;
 use crate::agentic::tools::ToolRuntimeRestrictions;
 use crate::agentic::workspace::WorkspaceServices;
 use crate::agentic::WorkspaceBinding;
+use crate::service::config::types::DEFAULT_MAX_PARALLEL_TOOL_CALLS;
 use bitfun_agent_tools::ResolvedToolInvocation;
 use bitfun_runtime_ports::{
     DelegationPolicy, PermissionDelegationContext, PermissionRule, RemoteExecPort, TerminalPort,
@@ -20,6 +21,8 @@ pub use tool_runtime::pipeline::SubagentBatchExecutionPolicy;
 #[derive(Debug, Clone)]
 pub struct ToolExecutionOptions {
     pub allow_parallel: bool,
+    /// Cap on calls running at once when `allow_parallel` is set.
+    pub max_parallel_tools: usize,
     pub subagent_batch_execution_policy: SubagentBatchExecutionPolicy,
     pub max_retries: usize,
     /// Tool execution timeout (seconds), None means infinite waiting
@@ -34,6 +37,7 @@ impl Default for ToolExecutionOptions {
     fn default() -> Self {
         Self {
             allow_parallel: true,
+            max_parallel_tools: DEFAULT_MAX_PARALLEL_TOOL_CALLS,
             subagent_batch_execution_policy: SubagentBatchExecutionPolicy::default(),
             max_retries: 0,
             timeout_secs: None, // Default no timeout (infinite waiting)
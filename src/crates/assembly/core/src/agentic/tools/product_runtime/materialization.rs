@@ -22,14 +22,20 @@ impl StaticToolProviderFactory<dyn Tool> for ProductConcreteToolFactory {
             "analyze_image" => Some(Arc::new(AnalyzeImageTool::new())),
             "Glob" => Some(Arc::new(GlobTool::new())),
             "Grep" => Some(Arc::new(GrepTool::new())),
+            "FindSymbol" => Some(Arc::new(FindSymbolTool::new())),
+            "FileOutline" => Some(Arc::new(FileOutlineTool::new())),
+            "LSP" => Some(Arc::new(LspTool::new())),
             "Write" => Some(Arc::new(FileWriteTool::new())),
             "Edit" => Some(Arc::new(FileEditTool::new())),
+            "EditTransaction" => Some(Arc::new(EditTransactionTool::new())),
             "Delete" => Some(Arc::new(DeleteFileTool::new())),
             "ExecCommand" => Some(Arc::new(ExecCommandTool::new())),
             "WriteStdin" => Some(Arc::new(WriteStdinTool::new())),
             "ExecControl" => Some(Arc::new(ExecControlTool::new())),
             "GetTime" => Some(Arc::new(GetTimeTool::new())),
             "ListModels" => Some(Arc::new(ListModelsTool::new())),
+            "ReadMore" => Some(Arc::new(ReadMoreTool::new())),
+            "SearchTools" => Some(Arc::new(SearchToolsTool::new())),
             "Task" => Some(Arc::new(TaskTool::new())),
             "AgentWait" => Some(Arc::new(AgentWaitTool::new())),
             "LaunchReviewAgent" => Some(Arc::new(LaunchReviewAgentTool::new())),
@@ -60,6 +66,7 @@ impl StaticToolProviderFactory<dyn Tool> for ProductConcreteToolFactory {
             "WebFetch" => Some(Arc::new(WebFetchTool::new())),
             "ListMCPResources" => Some(Arc::new(ListMCPResourcesTool::new())),
             "ReadMCPResource" => Some(Arc::new(ReadMCPResourceTool::new())),
+            "AttachMCPResources" => Some(Arc::new(AttachMCPResourcesTool::new())),
             "ListMCPPrompts" => Some(Arc::new(ListMCPPromptsTool::new())),
             "GetMCPPrompt" => Some(Arc::new(GetMCPPromptTool::new())),
             "GenerativeUI" => Some(Arc::new(GenerativeUITool::new())),
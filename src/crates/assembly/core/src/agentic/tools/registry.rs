@@ -124,6 +124,50 @@ impl ToolRegistry {
         }
     }
 
+    /// Incrementally sync one MCP server's tools after its catalog changed.
+    ///
+    /// Registered tools of the server that are not in `published` are removed,
+    /// `upserts` are (re)registered, and every other tool keeps its existing
+    /// instance. Returns the number of removed tools.
+    pub fn sync_mcp_server_tools(
+        &mut self,
+        server_id: &str,
+        published: &std::collections::BTreeSet<String>,
+        upserts: Vec<ToolRef>,
+    ) -> usize {
+        let stale_tool_names = self
+            .get_tool_names()
+            .into_iter()
+            .filter(|name| !published.contains(name))
+            .filter(|name| {
+                self.get_dynamic_tool_info(name)
+                    .and_then(|info| info.mcp)
+                    .is_some_and(|mcp| mcp.server_id == server_id)
+            })
+            .collect::<Vec<_>>();
+
+        let mut removed = 0;
+        for name in &stale_tool_names {
+            removed += usize::from(self.inner.unregister_tool(name).is_some());
+            if let Some(mux) = crate::external_tools::retain_external_tool_mux(name) {
+                self.register_tool_without_external_source_notification(mux);
+            }
+            info!("Unregistering dynamic tool: tool_name={}", name);
+        }
+
+        let changed = removed > 0 || !upserts.is_empty();
+        for tool in upserts {
+            debug!("Upserting MCP tool: tool_name={}", tool.name());
+            let routed = crate::external_tools::intercept_external_tool_registry_registration(tool);
+            self.inner.register_tool(routed);
+        }
+
+        if changed {
+            crate::external_sources::notify_external_tool_registry_changed();
+        }
+        removed
+    }
+
     /// Remove all tools whose registry name starts with the given prefix.
     pub fn unregister_tools_by_prefix(&mut self, prefix: &str) -> usize {
         let retained_muxes = crate::external_tools::retain_external_tool_muxes_for_prefix(prefix);
@@ -503,14 +547,20 @@ mod tests {
             "analyze_image",
             "Glob",
             "Grep",
+            "FindSymbol",
+            "FileOutline",
+            "LSP",
             "Write",
             "Edit",
+            "EditTransaction",
             "Delete",
             "ExecCommand",
             "WriteStdin",
             "ExecControl",
             "GetTime",
             "ListModels",
+            "ReadMore",
+            "SearchTools",
             "Task",
             "AgentWait",
             "LaunchReviewAgent",
@@ -537,6 +587,7 @@ mod tests {
             "WebFetch",
             "ListMCPResources",
             "ReadMCPResource",
+            "AttachMCPResources",
             "ListMCPPrompts",
             "GetMCPPrompt",
             "GenerativeUI",
@@ -717,6 +768,7 @@ mod tests {
                 "WebFetch",
                 "ListMCPResources",
                 "ReadMCPResource",
+                "AttachMCPResources",
                 "ListMCPPrompts",
                 "GetMCPPrompt",
                 "GenerativeUI",
@@ -748,8 +800,13 @@ mod tests {
                 "analyze_image",
                 "Glob",
                 "Grep",
+                "FindSymbol",
+                "FileOutline",
+                "LSP",
                 "GetTime",
                 "ListModels",
+                "ReadMore",
+                "SearchTools",
                 "Skill",
                 "AskUserQuestion",
                 "TodoWrite",
@@ -764,6 +821,7 @@ mod tests {
                 "WebFetch",
                 "ListMCPResources",
                 "ReadMCPResource",
+                "AttachMCPResources",
                 "ListMCPPrompts",
                 "GetMCPPrompt",
                 "GenerativeUI",
@@ -985,7 +1043,7 @@ mod tests {
     #[test]
     fn registry_wraps_file_modification_tools_for_snapshot_tracking() {
         let registry = create_tool_registry();
-        for tool_name in ["Write", "Edit", "Delete"] {
+        for tool_name in ["Write", "Edit", "EditTransaction", "Delete"] {
             let tool = registry
                 .get_tool(tool_name)
                 .unwrap_or_else(|| panic!("{tool_name} tool should be registered"));
@@ -1,8 +1,9 @@
 use crate::util::errors::{BitFunError, BitFunResult};
 pub use bitfun_agent_tools::{
-    is_miniapp_headless_agent_run, is_remote_posix_path_within_root,
+    is_miniapp_headless_agent_run, is_remote_posix_path_within_root, is_sensitive_write_path,
     miniapp_headless_agent_tool_restrictions, tool_restrictions_for_delegation_policy,
     ToolPathOperation, ToolPathPolicy, ToolRestrictionError, ToolRuntimeRestrictions,
+    ToolWriteSandbox, SENSITIVE_FILE_PERMISSION_ACTION,
 };
 use std::path::{Path, PathBuf};
 
@@ -80,8 +80,10 @@ impl ToolRegistryItem for dyn Tool {
 #[async_trait::async_trait]
 impl ContextualToolManifestItem<ToolUseContext> for dyn Tool {
     async fn is_available_in_context(&self, context: &ToolUseContext) -> bool {
-        if matches!(Tool::name(self), "Read" | "Grep" | "Glob" | "LS")
-            && !live_repository_context_allowed(context)
+        if matches!(
+            Tool::name(self),
+            "Read" | "Grep" | "Glob" | "LS" | "FindSymbol" | "FileOutline" | "LSP"
+        ) && !live_repository_context_allowed(context)
         {
             return false;
         }
@@ -19,6 +19,7 @@ use crate::agentic::tools::pipeline::{ToolExecutionContext, ToolTask};
 use crate::agentic::tools::post_call_hooks;
 use crate::agentic::tools::restrictions::{
     is_local_path_within_root, is_remote_posix_path_within_root, ToolPathOperation,
+    ToolWriteSandbox,
 };
 use crate::agentic::tools::workspace_paths::{
     build_bitfun_runtime_uri, is_bitfun_tool_uri, normalize_runtime_relative_path,
@@ -467,10 +468,24 @@ impl ToolUseContext {
         operation: ToolPathOperation,
         resolution: &ToolPathResolution,
     ) -> BitFunResult<()> {
-        let allowed_roots = self
-            .runtime_tool_restrictions
-            .path_policy
-            .roots_for(operation);
+        if matches!(resolution.backend, ToolPathBackend::Local)
+            && is_local_path_within_root(
+                Path::new(&resolution.resolved_path),
+                &get_path_manager_arc().builtin_skills_dir(),
+            )?
+        {
+            return Err(BitFunError::validation(format!(
+                "Path '{}' is in the read-only built-in skills folder; duplicate the skill to the user level to change it",
+                resolution.logical_path
+            )));
+        }
+
+        let path_policy = &self.runtime_tool_restrictions.path_policy;
+        if let Some(sandbox) = path_policy.sandbox.as_ref() {
+            self.enforce_write_sandbox(sandbox, operation, resolution)?;
+        }
+
+        let allowed_roots = path_policy.roots_for(operation);
         if allowed_roots.is_empty() {
             return Ok(());
         }
@@ -510,6 +525,64 @@ impl ToolUseContext {
         ))
     }
 
+    /// Local writes outside the workspace root and the sandbox's extra roots
+    /// are rejected when reached through `..` or a relative path, and also
+    /// for absolute paths when the sandbox confines writes to the workspace.
+    fn enforce_write_sandbox(
+        &self,
+        sandbox: &ToolWriteSandbox,
+        operation: ToolPathOperation,
+        resolution: &ToolPathResolution,
+    ) -> BitFunResult<()> {
+        if resolution.uses_remote_workspace_backend()
+            || resolution.runtime_scope.is_some()
+            || resolution.is_runtime_artifact()
+        {
+            return Ok(());
+        }
+        let Some(workspace_root) = self.workspace_root() else {
+            return Ok(());
+        };
+
+        let path = Path::new(&resolution.resolved_path);
+        if is_local_path_within_root(path, workspace_root)? {
+            return Ok(());
+        }
+        for root in &sandbox.extra_roots {
+            if is_local_path_within_root(path, Path::new(root))? {
+                return Ok(());
+            }
+        }
+
+        let requested = resolution.requested_path.trim();
+        let escapes_by_traversal = !self.workspace_path_is_effectively_absolute(requested)
+            || Path::new(requested)
+                .components()
+                .any(|component| matches!(component, std::path::Component::ParentDir));
+        if escapes_by_traversal {
+            return Err(BitFunError::validation(format!(
+                "Path '{}' resolves outside workspace '{}' and cannot be used for {}",
+                requested,
+                workspace_root.display(),
+                operation.verb()
+            )));
+        }
+        if sandbox.confine_to_workspace {
+            return Err(BitFunError::validation(format!(
+                "Path '{}' is outside workspace '{}'; this workspace only allows {} inside it{}",
+                resolution.logical_path,
+                workspace_root.display(),
+                operation.verb(),
+                if sandbox.extra_roots.is_empty() {
+                    String::new()
+                } else {
+                    format!(" and in {}", sandbox.extra_roots.join(", "))
+                }
+            )));
+        }
+        Ok(())
+    }
+
     /// Resolve a user or model-supplied path for file/shell tools. Uses POSIX semantics when the
     /// workspace is remote SSH so Windows-hosted clients still resolve `/home/...` correctly.
     pub fn resolve_workspace_tool_path(&self, path: &str) -> BitFunResult<String> {
@@ -916,8 +989,11 @@ mod context_facts_tests {
 #[cfg(test)]
 mod path_resolution_tests {
     use super::ToolUseContext;
-    use crate::agentic::tools::{ToolPathOperation, ToolPathPolicy, ToolRuntimeRestrictions};
+    use crate::agentic::tools::{
+        ToolPathOperation, ToolPathPolicy, ToolRuntimeRestrictions, ToolWriteSandbox,
+    };
     use crate::agentic::WorkspaceBinding;
+    use crate::infrastructure::get_path_manager_arc;
     use crate::service::remote_ssh::workspace_state::workspace_session_identity;
     use std::collections::HashMap;
     use std::path::PathBuf;
@@ -1195,6 +1271,79 @@ mod path_resolution_tests {
 
         let _ = std::fs::remove_dir_all(&temp_root);
     }
+
+    #[test]
+    fn builtin_skill_files_are_read_only() {
+        let workspace = std::env::temp_dir().join(format!(
+            "bitfun-tool-context-builtin-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let context = context_with_restrictions(
+            workspace.to_string_lossy().as_ref(),
+            ToolRuntimeRestrictions::default(),
+        );
+        let skill_md = get_path_manager_arc()
+            .builtin_skills_dir()
+            .join("pdf")
+            .join("SKILL.md");
+        let resolved = context
+            .resolve_tool_path(&skill_md.to_string_lossy())
+            .expect("path resolves");
+
+        let err = context
+            .enforce_path_operation(ToolPathOperation::Edit, &resolved)
+            .expect_err("built-in skill files are not editable");
+        assert!(err.to_string().contains("read-only built-in skills folder"));
+    }
+
+    #[test]
+    fn write_sandbox_rejects_traversal_and_confines_absolute_paths() {
+        let temp_root = std::env::temp_dir().join(format!(
+            "bitfun-tool-context-sandbox-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let workspace = temp_root.join("workspace");
+        let scratch = temp_root.join("scratch");
+        std::fs::create_dir_all(&workspace).expect("create workspace");
+        std::fs::create_dir_all(&scratch).expect("create scratch");
+        let sandboxed = |confine_to_workspace| {
+            context_with_restrictions(
+                workspace.to_string_lossy().as_ref(),
+                ToolRuntimeRestrictions {
+                    path_policy: ToolPathPolicy {
+                        sandbox: Some(ToolWriteSandbox {
+                            extra_roots: vec![scratch.to_string_lossy().to_string()],
+                            confine_to_workspace,
+                            approve_sensitive_files: false,
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+        };
+        let enforce = |context: &ToolUseContext, path: &str| {
+            let resolved = context.resolve_tool_path(path).expect("path resolves");
+            context.enforce_path_operation(ToolPathOperation::Write, &resolved)
+        };
+        let outside = temp_root.join("outside.txt").to_string_lossy().to_string();
+
+        let open = sandboxed(false);
+        enforce(&open, "src/main.rs").expect("workspace path is writable");
+        enforce(&open, &scratch.join("notes.md").to_string_lossy())
+            .expect("extra root is writable");
+        enforce(&open, &outside).expect("absolute path is left to external_directory");
+        let err = enforce(&open, "../outside.txt").expect_err("traversal is rejected");
+        assert!(err.to_string().contains("resolves outside workspace"));
+
+        let confined = sandboxed(true);
+        enforce(&confined, &scratch.join("notes.md").to_string_lossy())
+            .expect("extra root is writable");
+        let err = enforce(&confined, &outside).expect_err("outside path is rejected");
+        assert!(err.to_string().contains("only allows write inside it"));
+
+        let _ = std::fs::remove_dir_all(&temp_root);
+    }
 }
 
 #[cfg(test)]
@@ -2,16 +2,18 @@
 //!
 //! The model should not receive unbounded tool output. Large outputs are stored
 //! under the session runtime directory and replaced, for the assistant only, by
-//! a small preview plus a stable reference to the full content.
+//! a small preview plus a stable reference to the full content. The `ReadMore`
+//! tool reads the stored content back by output id.
 
 use crate::agentic::core::ToolResult;
 use crate::agentic::tools::tool_context_runtime::ToolUseContext;
+use crate::service::config::global::GlobalConfigManager;
 use crate::util::errors::{BitFunError, BitFunResult};
 use bitfun_agent_tools::{
     build_persisted_tool_output_message, count_tool_result_lines, generate_tool_result_preview,
     sanitize_tool_result_file_component, select_tool_result_indices_for_persistence,
     tool_result_is_persisted_output, PersistedToolOutput, ToolResultPersistenceCandidate,
-    ToolResultStoragePolicy, GET_TOOL_SPEC_TOOL_NAME,
+    ToolResultStoragePolicy, GET_TOOL_SPEC_TOOL_NAME, READ_MORE_TOOL_NAME,
 };
 #[cfg(test)]
 use bitfun_agent_tools::{DEFAULT_MAX_TOOL_RESULT_CHARS, PERSISTED_OUTPUT_TAG};
@@ -54,7 +56,10 @@ pub(crate) async fn maybe_persist_large_tool_result_for_tool(
         return result;
     }
 
-    let per_tool_limit = effective_per_tool_limit(effective_tool_name, policy);
+    let per_tool_limit = ToolResultStoragePolicy::per_tool_limit_for_budget(
+        effective_per_tool_limit(effective_tool_name, policy),
+        load_configured_tool_result_max_tokens().await,
+    );
     let visible_chars = result_visible_content(&result).chars().count();
     let content_override =
         content_override_if_oversized(&result, effective_tool_name, per_tool_limit);
@@ -135,8 +140,55 @@ pub(crate) async fn apply_round_tool_result_budget(
     results
 }
 
+/// Reads output stored by an earlier oversized tool result in this session.
+pub(crate) async fn read_persisted_tool_output(
+    output_id: &str,
+    context: &ToolUseContext,
+) -> BitFunResult<String> {
+    let session_id = context.session_id.as_deref().ok_or_else(|| {
+        BitFunError::tool("A session id is required to read stored tool output".to_string())
+    })?;
+    let safe_id = sanitize_tool_result_file_component(output_id.trim(), "");
+    if safe_id.is_empty() || safe_id != output_id.trim() {
+        return Err(BitFunError::tool(format!(
+            "Invalid output id '{}'",
+            output_id
+        )));
+    }
+
+    for is_json in [false, true] {
+        let file_name = tool_result_file_name(&safe_id, is_json);
+        let path = context.current_workspace_session_tool_result_path(session_id, &file_name)?;
+        match tokio::fs::read_to_string(&path).await {
+            Ok(content) => return Ok(content),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => {
+                return Err(BitFunError::io(format!(
+                    "Failed to read stored tool output {}: {}",
+                    path.display(),
+                    error
+                )))
+            }
+        }
+    }
+    Err(BitFunError::tool(format!(
+        "No stored output with id '{}' in this session",
+        output_id
+    )))
+}
+
+async fn load_configured_tool_result_max_tokens() -> Option<usize> {
+    let service = GlobalConfigManager::get_service().await.ok()?;
+    service
+        .get_config::<Option<usize>>(Some("ai.tool_result_max_tokens"))
+        .await
+        .ok()
+        .flatten()
+}
+
 fn should_skip_tool_result(result: &ToolResult, effective_tool_name: &str) -> bool {
     effective_tool_name == GET_TOOL_SPEC_TOOL_NAME
+        || effective_tool_name == READ_MORE_TOOL_NAME
         || result
             .image_attachments
             .as_ref()
@@ -185,7 +237,8 @@ async fn persist_tool_result(
     } else {
         serialize_tool_result_content(result)?
     };
-    let file_name = tool_result_file_name(&result.tool_id, is_json);
+    let output_id = tool_result_output_id(&result.tool_id);
+    let file_name = tool_result_file_name(&output_id, is_json);
     let path = context.current_workspace_session_tool_result_path(session_id, &file_name)?;
 
     if let Some(parent) = path.parent() {
@@ -217,6 +270,7 @@ async fn persist_tool_result(
     );
 
     Ok(PersistedToolOutput {
+        output_id,
         reference,
         original_chars: serialized.chars().count(),
         line_count: count_tool_result_lines(&serialized),
@@ -333,11 +387,14 @@ fn visible_content_is_compacted(result: &ToolResult) -> bool {
         .is_some_and(tool_result_is_persisted_output)
 }
 
-fn tool_result_file_name(tool_id: &str, is_json: bool) -> String {
+fn tool_result_output_id(tool_id: &str) -> String {
     let fallback = uuid::Uuid::new_v4().to_string();
-    let safe_id = sanitize_tool_result_file_component(tool_id, &fallback);
+    sanitize_tool_result_file_component(tool_id, &fallback)
+}
+
+fn tool_result_file_name(output_id: &str, is_json: bool) -> String {
     let ext = if is_json { "json" } else { "txt" };
-    format!("{}.{}", safe_id, ext)
+    format!("{}.{}", output_id, ext)
 }
 
 fn tool_result_metadata(result: &ToolResult) -> Vec<(String, String)> {
@@ -462,6 +519,31 @@ mod tests {
         let _ = std::fs::remove_dir_all(root);
     }
 
+    #[tokio::test]
+    async fn persisted_output_is_readable_by_its_output_id() {
+        let root = temp_workspace("read-more");
+        let context = test_context(root.clone());
+        let text = "line\n".repeat(DEFAULT_MAX_TOOL_RESULT_CHARS / 4);
+        let result = tool_result("tool/two", "Grep", text.clone());
+
+        let processed = maybe_persist_large_tool_result(result, &context).await;
+        let assistant = processed.result_for_assistant.unwrap_or_default();
+        assert!(assistant.contains("Output id: tool_two"));
+
+        let stored = read_persisted_tool_output("tool_two", &context)
+            .await
+            .expect("stored output should be readable");
+        assert_eq!(stored, text);
+        assert!(read_persisted_tool_output("../tool_two", &context)
+            .await
+            .is_err());
+        assert!(read_persisted_tool_output("missing", &context)
+            .await
+            .is_err());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[tokio::test]
     async fn read_result_is_persisted_when_over_read_limit() {
         let root = temp_workspace("read");
@@ -100,6 +100,11 @@ impl WorkspaceBinding {
         matches!(self.backend, WorkspaceBackend::Remote { .. })
     }
 
+    /// Root path on this machine; `None` for remote workspaces.
+    pub fn local_root_path(&self) -> Option<&Path> {
+        (!self.is_remote()).then_some(self.root_path.as_path())
+    }
+
     pub fn connection_id(&self) -> Option<&str> {
         match &self.backend {
             WorkspaceBackend::Remote { connection_id, .. } => Some(connection_id),
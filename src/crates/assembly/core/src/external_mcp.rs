@@ -238,8 +238,11 @@ pub(super) fn prepared_mcp_config(
         env,
         working_directory,
         inherit_parent_environment: matches!(server_type, MCPServerType::Local).then_some(false),
+        isolation: None,
+        framing: None,
         headers,
         url,
+        tls: None,
         auto_start: true,
         enabled: true,
         location: ConfigLocation::BuiltIn,
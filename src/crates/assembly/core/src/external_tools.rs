@@ -863,6 +863,18 @@ impl ExternalToolRouter {
         retained_muxes
     }
 
+    fn retain_mux(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        let mux = self
+            .muxes
+            .lock()
+            .expect("external tool router lock poisoned")
+            .get(name)
+            .cloned()?;
+        mux.replace_original(None);
+        let routed: Arc<dyn Tool> = mux;
+        Some(routed)
+    }
+
     fn retain_muxes_for_prefix(&self, prefix: &str) -> Vec<Arc<dyn Tool>> {
         self.muxes
             .lock()
@@ -919,6 +931,10 @@ pub(crate) fn detach_external_tool_mcp_server(server_id: &str) -> Vec<Arc<dyn To
     router().detach_mcp_server(server_id)
 }
 
+pub(crate) fn retain_external_tool_mux(name: &str) -> Option<Arc<dyn Tool>> {
+    router().retain_mux(name)
+}
+
 pub(crate) fn retain_external_tool_muxes_for_prefix(prefix: &str) -> Vec<Arc<dyn Tool>> {
     router().retain_muxes_for_prefix(prefix)
 }
@@ -4,8 +4,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use bitfun_product_domains::function_agents::git_func_agent::{
-    parse_commit_ai_response, prepare_commit_ai_prompt, AICommitAnalysis, CommitMessageOptions,
-    ProjectContext,
+    commit_analysis_json_schema, parse_commit_ai_response, parse_commit_analysis_value,
+    prepare_commit_ai_prompt, AICommitAnalysis, CommitMessageOptions, ProjectContext,
 };
 use bitfun_product_domains::function_agents::ports::{
     CommitAiAnalysisRequest, FunctionAgentAiPort, FunctionAgentFuture, FunctionAgentGitPort,
@@ -19,7 +19,7 @@ use bitfun_services_integrations::function_agents::FunctionAgentGitService;
 use log::{debug, error, warn};
 
 use crate::function_agents::common::{AgentError, AgentResult, Language};
-use crate::infrastructure::ai::{AIClient, AIClientFactory};
+use crate::infrastructure::ai::{AIClient, AIClientFactory, ResponseCacheMode, StructuredOutput};
 use crate::util::types::Message;
 
 pub struct CoreCommitAiAnalysisService {
@@ -64,9 +64,33 @@ impl CoreCommitAiAnalysisService {
             );
         }
 
-        let ai_response = self.call_ai(&prepared_prompt.prompt).await?;
+        match self.call_ai_structured(&prepared_prompt.prompt).await {
+            Ok(analysis) => {
+                parse_commit_analysis_value(&analysis).map_err(AgentError::analysis_error)
+            }
+            Err(e) => {
+                // Some OpenAI-compatible endpoints reject `response_format`;
+                // fall back to extracting JSON from a plain reply.
+                warn!("Structured commit analysis failed, retrying as text: {}", e);
+                let ai_response = self.call_ai(&prepared_prompt.prompt).await?;
+                self.parse_commit_response(&ai_response)
+            }
+        }
+    }
+
+    /// Asks for the commit analysis as schema-checked JSON; the client sends
+    /// invalid output back to the model for repair before giving up.
+    async fn call_ai_structured(&self, prompt: &str) -> anyhow::Result<serde_json::Value> {
+        debug!(
+            "Sending structured request to AI: prompt_length={}",
+            prompt.len()
+        );
 
-        self.parse_commit_response(&ai_response)
+        let messages = vec![Message::user(prompt.to_string())];
+        let output = StructuredOutput::new("commit_analysis", commit_analysis_json_schema());
+        self.ai_client
+            .send_message_structured(messages, &output)
+            .await
     }
 
     async fn call_ai(&self, prompt: &str) -> AgentResult<String> {
@@ -149,7 +173,7 @@ impl CoreWorkStateAiAnalysisService {
         let messages = vec![Message::user(prompt.to_string())];
         let response = self
             .ai_client
-            .send_message(messages, None)
+            .send_message_cached(messages, None, ResponseCacheMode::Reuse)
             .await
             .map_err(|e| {
                 error!("AI call failed: {}", e);
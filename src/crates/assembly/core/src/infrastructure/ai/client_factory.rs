@@ -8,6 +8,9 @@
 
 use crate::infrastructure::ai::{build_stream_options_for_model, AIClient};
 use crate::infrastructure::subscription_auth::{self, SubscriptionProvider as AdapterProvider};
+use crate::service::config::project_model_store::{
+    load_validated_project_model_config, ProjectModelConfig,
+};
 use crate::service::config::types::{
     model_runtime_binding_fingerprint, AuthConfig, SubscriptionProvider,
 };
@@ -15,10 +18,14 @@ use crate::service::config::{get_global_config_service, ConfigService};
 use crate::util::errors::{BitFunError, BitFunResult};
 use crate::util::types::AIConfig;
 use anyhow::{anyhow, Result};
-use bitfun_ai_adapters::resolve_required_model_selector;
+use bitfun_ai_adapters::{
+    resolve_required_model_selector, RequestGovernor, RequestLimits, ResponseCache,
+    ResponseCacheLimits,
+};
 use log::{debug, info, warn};
 use std::collections::HashMap;
-use std::sync::{Arc, OnceLock, RwLock};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 pub struct AIClientFactory {
     config_service: Arc<ConfigService>,
@@ -55,6 +62,74 @@ fn subscription_credential_stale(auth: &AuthConfig, cached: &CachedAIClient) ->
     })
 }
 
+/// Separates the model id from the override fingerprint in cache keys of
+/// workspace-adjusted clients.
+const WORKSPACE_KEY_SEPARATOR: char = '@';
+
+/// Governors outlive cached clients (and factory reloads) so queued and
+/// in-flight requests keep counting against the same budget after a config
+/// change. Limits are refreshed on every client lookup.
+static MODEL_REQUEST_GOVERNORS: OnceLock<Mutex<HashMap<String, Arc<RequestGovernor>>>> =
+    OnceLock::new();
+static GLOBAL_REQUEST_GOVERNOR: OnceLock<Arc<RequestGovernor>> = OnceLock::new();
+
+fn request_governors_for(
+    ai_config: &crate::service::config::types::AIConfig,
+    model_config: &crate::service::config::types::AIModelConfig,
+) -> Vec<Arc<RequestGovernor>> {
+    let model_limits = model_config
+        .rate_limit
+        .map(|rate_limit| RequestLimits {
+            requests_per_minute: rate_limit.requests_per_minute,
+            tokens_per_minute: rate_limit.tokens_per_minute,
+            max_concurrent: rate_limit.max_concurrent_requests,
+        })
+        .unwrap_or_default();
+    let model_governor = {
+        let mut governors = MODEL_REQUEST_GOVERNORS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        governors
+            .entry(model_config.id.clone())
+            .or_insert_with(|| RequestGovernor::new(model_config.id.clone(), model_limits))
+            .clone()
+    };
+    model_governor.set_limits(model_limits);
+
+    let global_limits = RequestLimits {
+        max_concurrent: ai_config.max_concurrent_requests,
+        ..Default::default()
+    };
+    let global_governor = GLOBAL_REQUEST_GOVERNOR
+        .get_or_init(|| RequestGovernor::new("global", global_limits))
+        .clone();
+    global_governor.set_limits(global_limits);
+
+    vec![model_governor, global_governor]
+}
+
+/// Like the governors, one response cache is shared by every client and
+/// survives factory reloads; its limits follow the current settings.
+static RESPONSE_CACHE: OnceLock<Arc<ResponseCache>> = OnceLock::new();
+
+fn response_cache_for(ai_config: &crate::service::config::types::AIConfig) -> Arc<ResponseCache> {
+    let settings = &ai_config.response_cache;
+    let limits = ResponseCacheLimits {
+        ttl: std::time::Duration::from_secs(settings.ttl_secs),
+        max_entries: if settings.enabled {
+            settings.max_entries
+        } else {
+            0
+        },
+    };
+    let cache = RESPONSE_CACHE
+        .get_or_init(|| ResponseCache::new(limits))
+        .clone();
+    cache.set_limits(limits);
+    cache
+}
+
 fn functional_agent_model_selector<'a>(
     ai_config: &'a crate::service::config::types::AIConfig,
     func_agent_name: &str,
@@ -85,7 +160,7 @@ impl AIClientFactory {
     }
 
     pub async fn get_client_by_id(&self, model_id: &str) -> Result<Arc<AIClient>> {
-        self.get_or_create_client(model_id, None).await
+        self.get_or_create_client(model_id, None, None).await
     }
 
     /// Resolves an immutable concrete model id only when its current runtime
@@ -95,7 +170,7 @@ impl AIClientFactory {
         model_id: &str,
         configuration_fingerprint: &str,
     ) -> Result<Arc<AIClient>> {
-        self.get_or_create_client(model_id, Some(configuration_fingerprint))
+        self.get_or_create_client(model_id, Some(configuration_fingerprint), None)
             .await
     }
 
@@ -110,7 +185,33 @@ impl AIClientFactory {
         )
         .map_err(|error| anyhow!(error.to_string()))?;
 
-        self.get_or_create_client(&resolved_model_id, None).await
+        self.get_or_create_client(&resolved_model_id, None, None)
+            .await
+    }
+
+    /// Like [`Self::get_client_resolved`], with the workspace's
+    /// `.bitfun/models.json` overrides applied to selector resolution and to
+    /// the chosen model. Invalid overrides fail the lookup instead of silently
+    /// falling back to the user-level models.
+    pub async fn get_client_resolved_for_workspace(
+        &self,
+        model_id: &str,
+        workspace_root: Option<&Path>,
+    ) -> Result<Arc<AIClient>> {
+        let global_config: crate::service::config::GlobalConfig =
+            self.config_service.get_config(None).await?;
+        let project_config =
+            load_validated_project_model_config(&global_config.ai, workspace_root).await?;
+        let ai_config = project_config.apply_to(&global_config.ai);
+        let resolved_model_id = resolve_required_model_selector(
+            model_id,
+            |selector| ai_config.resolve_model_selection(selector),
+            |model_ref| ai_config.resolve_model_reference(model_ref),
+        )
+        .map_err(|error| anyhow!(error.to_string()))?;
+
+        self.get_or_create_client(&resolved_model_id, None, Some(&project_config))
+            .await
     }
 
     pub fn invalidate_cache(&self) {
@@ -145,7 +246,10 @@ impl AIClientFactory {
                 poisoned.into_inner()
             }
         };
-        if cache.remove(model_id).is_some() {
+        let workspace_prefix = format!("{}{}", model_id, WORKSPACE_KEY_SEPARATOR);
+        let count = cache.len();
+        cache.retain(|key, _| key != model_id && !key.starts_with(&workspace_prefix));
+        if cache.len() != count {
             debug!("Client cache cleared for model: {}", model_id);
         }
     }
@@ -154,6 +258,7 @@ impl AIClientFactory {
         &self,
         model_id: &str,
         expected_configuration_fingerprint: Option<&str>,
+        project_config: Option<&ProjectModelConfig>,
     ) -> Result<Arc<AIClient>> {
         let global_config: crate::service::config::GlobalConfig =
             self.config_service.get_config(None).await?;
@@ -199,9 +304,9 @@ impl AIClientFactory {
             ));
         }
 
-        let configuration_fingerprint = model_runtime_binding_fingerprint(model_config);
+        let approved_fingerprint = model_runtime_binding_fingerprint(model_config);
         if expected_configuration_fingerprint
-            .is_some_and(|expected| expected != configuration_fingerprint)
+            .is_some_and(|expected| expected != approved_fingerprint)
         {
             return Err(anyhow!(
                 "Approved model binding changed for configuration id: {}",
@@ -209,6 +314,27 @@ impl AIClientFactory {
             ));
         }
 
+        // Workspace-adjusted clients are cached beside the user-level one so
+        // switching between workspaces does not rebuild them every turn.
+        let model_override = project_config.and_then(|config| config.models.get(&model_config.id));
+        let overridden_model;
+        let (model_config, cache_key) = match model_override {
+            Some(model_override) => {
+                overridden_model = model_override.apply_to(model_config);
+                let cache_key = format!(
+                    "{}{}{}",
+                    normalized_model_id,
+                    WORKSPACE_KEY_SEPARATOR,
+                    model_runtime_binding_fingerprint(&overridden_model)
+                );
+                (&overridden_model, cache_key)
+            }
+            None => (model_config, normalized_model_id.clone()),
+        };
+        let configuration_fingerprint = model_runtime_binding_fingerprint(model_config);
+        let request_governors = request_governors_for(&global_config.ai, model_config);
+        let response_cache = response_cache_for(&global_config.ai);
+
         {
             let cache = match self.client_cache.read() {
                 Ok(cache) => cache,
@@ -219,7 +345,7 @@ impl AIClientFactory {
                     poisoned.into_inner()
                 }
             };
-            if let Some(cached) = cache.get(&normalized_model_id) {
+            if let Some(cached) = cache.get(&cache_key) {
                 if cached.configuration_fingerprint == configuration_fingerprint
                     && !subscription_credential_stale(&model_config.auth, cached)
                 {
@@ -240,11 +366,11 @@ impl AIClientFactory {
         };
 
         let stream_options = build_stream_options_for_model(&global_config.ai, Some(model_config));
-        let client = Arc::new(AIClient::new_with_runtime_options(
-            ai_config,
-            proxy_config,
-            stream_options,
-        ));
+        let client = Arc::new(
+            AIClient::new_with_runtime_options(ai_config, proxy_config, stream_options)
+                .with_request_governors(request_governors)
+                .with_response_cache(response_cache),
+        );
 
         {
             let mut cache = match self.client_cache.write() {
@@ -257,7 +383,7 @@ impl AIClientFactory {
                 }
             };
             cache.insert(
-                model_config.id.clone(),
+                cache_key,
                 CachedAIClient {
                     configuration_fingerprint,
                     client: client.clone(),
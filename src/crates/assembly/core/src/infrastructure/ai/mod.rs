@@ -10,7 +10,9 @@ use std::time::Duration;
 pub use bitfun_ai_adapters::providers;
 pub use bitfun_ai_adapters::stream as ai_stream_handlers;
 
-pub use bitfun_ai_adapters::{AIClient, StreamOptions, StreamResponse};
+pub use bitfun_ai_adapters::{
+    AIClient, RequestPriority, ResponseCacheMode, StreamOptions, StreamResponse, StructuredOutput,
+};
 pub use client_factory::{
     get_global_ai_client_factory, initialize_global_ai_client_factory, AIClientFactory,
 };
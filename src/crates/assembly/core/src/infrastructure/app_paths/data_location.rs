@@ -0,0 +1,387 @@
+//! Relocatable user data root.
+//!
+//! User-level storage defaults to `{config_dir}/bitfun`. A pointer file kept
+//! there, `data-location.json`, can move it elsewhere, e.g. to a bigger drive.
+//! Relocation happens in two steps: [`PathManager::relocate_data_dir`]
+//! records the request, and [`apply_pending_data_relocation`] copies the data
+//! on the next start, before any service opens files under the old root.
+//!
+//! [`PathManager::relocate_data_dir`]: super::PathManager::relocate_data_dir
+
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+pub const DATA_LOCATION_FILE: &str = "data-location.json";
+
+/// Entries of the user root that move with it; `temp` is recreated instead.
+const RELOCATED_ENTRIES: &[&str] = &["config", "agents", "cache", "runtimes", "data", "logs"];
+
+/// Where user-level storage lives. Stored in the default root, which is the
+/// only location known before the pointer is read.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DataLocation {
+    /// Root in use, when it is not the default one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_root: Option<PathBuf>,
+    /// Root requested by the user, moved to on the next start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_root: Option<PathBuf>,
+    /// Why the last relocation failed; cleared by the next request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl DataLocation {
+    /// A missing or unreadable pointer means the default root.
+    pub fn load(default_root: &Path) -> Self {
+        let path = default_root.join(DATA_LOCATION_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Self::default(),
+            Err(error) => {
+                warn!(
+                    "Failed to read data location file, using default root: path={}, error={}",
+                    path.display(),
+                    error
+                );
+                return Self::default();
+            }
+        };
+        serde_json::from_str(&content).unwrap_or_else(|error| {
+            warn!(
+                "Invalid data location file, using default root: path={}, error={}",
+                path.display(),
+                error
+            );
+            Self::default()
+        })
+    }
+
+    pub(crate) fn save(&self, default_root: &Path) -> BitFunResult<()> {
+        let path = default_root.join(DATA_LOCATION_FILE);
+        let temp_path = default_root.join(format!("{DATA_LOCATION_FILE}.tmp"));
+        let content = serde_json::to_vec_pretty(self)?;
+        fs::create_dir_all(default_root)
+            .and_then(|()| fs::write(&temp_path, content))
+            .and_then(|()| fs::rename(&temp_path, &path))
+            .map_err(|error| {
+                BitFunError::io(format!(
+                    "Failed to write data location file {}: {}",
+                    path.display(),
+                    error
+                ))
+            })
+    }
+}
+
+/// `{config_dir}/bitfun`, where the pointer file lives.
+pub(crate) fn default_user_root() -> BitFunResult<PathBuf> {
+    dirs::config_dir()
+        .map(|config_dir| config_dir.join("bitfun"))
+        .ok_or_else(|| BitFunError::config("Failed to get config directory".to_string()))
+}
+
+/// Platform skills directory used while the data root is not relocated:
+/// - Windows: C:\Users\xxx\AppData\Roaming\BitFun\skills\
+/// - macOS: ~/Library/Application Support/BitFun/skills/
+/// - Linux: ~/.local/share/BitFun/skills/
+pub(crate) fn platform_skills_dir() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("C:\\ProgramData"))
+            .join("BitFun")
+            .join("skills")
+    } else if cfg!(target_os = "macos") {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("Library")
+            .join("Application Support")
+            .join("BitFun")
+            .join("skills")
+    } else {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join("BitFun")
+            .join("skills")
+    }
+}
+
+/// Skills follow a relocated root; at the default root they stay in the
+/// platform data directory.
+pub(crate) fn skills_dir_for_root(root: &Path, default_root: &Path) -> PathBuf {
+    if root == default_root {
+        platform_skills_dir()
+    } else {
+        root.join("skills")
+    }
+}
+
+/// Checks that `target` can receive the data currently under `current`.
+pub(crate) fn validate_relocation_target(current: &Path, target: &Path) -> BitFunResult<()> {
+    if !target.is_absolute()
+        || target
+            .components()
+            .any(|component| matches!(component, Component::ParentDir))
+    {
+        return Err(BitFunError::validation(format!(
+            "Data directory must be an absolute path without '..': {}",
+            target.display()
+        )));
+    }
+    if target.starts_with(current) || current.starts_with(target) {
+        return Err(BitFunError::validation(format!(
+            "Data directory {} cannot contain or be inside the current one ({})",
+            target.display(),
+            current.display()
+        )));
+    }
+    if target.exists() {
+        if !target.is_dir() {
+            return Err(BitFunError::validation(format!(
+                "Data directory is not a directory: {}",
+                target.display()
+            )));
+        }
+        let occupied = fs::read_dir(target)
+            .map_err(|error| {
+                BitFunError::io(format!("Failed to read {}: {}", target.display(), error))
+            })?
+            .filter_map(Result::ok)
+            .any(|entry| entry.file_name() != DATA_LOCATION_FILE);
+        if occupied {
+            return Err(BitFunError::validation(format!(
+                "Data directory must be empty: {}",
+                target.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Moves the user data root if a relocation was requested. Must run before
+/// anything opens files under the root, so the desktop app calls it first
+/// thing on startup. On failure the old root stays in use and the error is
+/// kept in the pointer file for settings to show.
+pub fn apply_pending_data_relocation() -> BitFunResult<Option<PathBuf>> {
+    if super::PathManager::user_root_from_env().is_some() {
+        return Ok(None);
+    }
+    let default_root = default_user_root()?;
+    let mut location = DataLocation::load(&default_root);
+    let Some(target) = location.pending_root.take() else {
+        return Ok(None);
+    };
+    let source = location
+        .user_root
+        .clone()
+        .unwrap_or_else(|| default_root.clone());
+    let source_skills = skills_dir_for_root(&source, &default_root);
+    let target_skills = skills_dir_for_root(&target, &default_root);
+
+    info!(
+        "Relocating data directory: from={}, to={}",
+        source.display(),
+        target.display()
+    );
+    let result = validate_relocation_target(&source, &target).and_then(|()| {
+        copy_data_root(&source, &source_skills, &target, &target_skills).map_err(|error| {
+            BitFunError::io(format!(
+                "Failed to copy data to {}: {}",
+                target.display(),
+                error
+            ))
+        })
+    });
+    if let Err(error) = result {
+        warn!("Data directory relocation failed: {}", error);
+        location.last_error = Some(error.to_string());
+        location.save(&default_root)?;
+        return Err(error);
+    }
+
+    location.user_root = (target != default_root).then(|| target.clone());
+    location.last_error = None;
+    location.save(&default_root)?;
+    remove_old_data_root(&source, &source_skills);
+    info!("Data directory relocated: root={}", target.display());
+    Ok(Some(target))
+}
+
+fn copy_data_root(
+    source: &Path,
+    source_skills: &Path,
+    target: &Path,
+    target_skills: &Path,
+) -> io::Result<()> {
+    let mut copied = Vec::new();
+    let result = copy_data_entries(source, source_skills, target, target_skills, &mut copied);
+    if result.is_err() {
+        for path in copied {
+            let _ = fs::remove_dir_all(path);
+        }
+    }
+    result
+}
+
+fn copy_data_entries(
+    source: &Path,
+    source_skills: &Path,
+    target: &Path,
+    target_skills: &Path,
+    copied: &mut Vec<PathBuf>,
+) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+    let entries = RELOCATED_ENTRIES
+        .iter()
+        .map(|entry| (source.join(entry), target.join(entry)))
+        .chain(std::iter::once((
+            source_skills.to_path_buf(),
+            target_skills.to_path_buf(),
+        )));
+    for (from, to) in entries {
+        if !from.is_dir() {
+            continue;
+        }
+        copied.push(to.clone());
+        copy_tree(&from, &to)?;
+    }
+    Ok(())
+}
+
+/// Best effort: leftovers only waste space once the pointer has switched.
+fn remove_old_data_root(source: &Path, source_skills: &Path) {
+    let entries = RELOCATED_ENTRIES
+        .iter()
+        .chain(std::iter::once(&"temp"))
+        .map(|entry| source.join(entry))
+        .chain(std::iter::once(source_skills.to_path_buf()));
+    for path in entries {
+        if let Err(error) = fs::remove_dir_all(&path) {
+            if error.kind() != io::ErrorKind::NotFound {
+                warn!(
+                    "Failed to remove relocated data: path={}, error={}",
+                    path.display(),
+                    error
+                );
+            }
+        }
+    }
+}
+
+/// Copies a directory tree, recreating symlinks rather than following them so
+/// managed runtimes keep their layout.
+fn copy_tree(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_tree(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(from)?, to)
+}
+
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    let link = fs::read_link(from)?;
+    if fs::metadata(from).is_ok_and(|metadata| metadata.is_dir()) {
+        std::os::windows::fs::symlink_dir(link, to)
+    } else {
+        std::os::windows::fs::symlink_file(link, to)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    fs::copy(from, to).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "bitfun-data-location-{}-{}",
+            name,
+            uuid::Uuid::new_v4()
+        ));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        dir
+    }
+
+    #[test]
+    fn relocation_targets_must_be_empty_and_separate() {
+        let base = temp_dir("validate");
+        let current = base.join("current");
+        fs::create_dir_all(&current).unwrap();
+
+        assert!(validate_relocation_target(&current, &base.join("new")).is_ok());
+        assert!(validate_relocation_target(&current, &current.join("nested")).is_err());
+        assert!(validate_relocation_target(&current, &base).is_err());
+        assert!(validate_relocation_target(&current, Path::new("relative")).is_err());
+
+        let occupied = base.join("occupied");
+        fs::create_dir_all(occupied.join("something")).unwrap();
+        assert!(validate_relocation_target(&current, &occupied).is_err());
+
+        let pointer_only = base.join("pointer-only");
+        fs::create_dir_all(&pointer_only).unwrap();
+        fs::write(pointer_only.join(DATA_LOCATION_FILE), "{}").unwrap();
+        assert!(validate_relocation_target(&current, &pointer_only).is_ok());
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn data_root_copy_moves_known_entries_and_skills() {
+        let base = temp_dir("copy");
+        let source = base.join("source");
+        let source_skills = base.join("skills");
+        let target = base.join("target");
+        fs::create_dir_all(source.join("config")).unwrap();
+        fs::write(source.join("config").join("app.json"), "{}").unwrap();
+        fs::create_dir_all(source.join("runtimes").join("node").join("bin")).unwrap();
+        fs::write(source.join("runtimes/node/bin/node"), "bin").unwrap();
+        fs::create_dir_all(source.join("temp")).unwrap();
+        fs::create_dir_all(source_skills.join("demo")).unwrap();
+        fs::write(source_skills.join("demo").join("SKILL.md"), "# Demo").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("bin/node", source.join("runtimes/node/current")).unwrap();
+
+        copy_data_root(&source, &source_skills, &target, &target.join("skills")).unwrap();
+        remove_old_data_root(&source, &source_skills);
+
+        assert_eq!(
+            fs::read_to_string(target.join("config").join("app.json")).unwrap(),
+            "{}"
+        );
+        assert!(target.join("skills/demo/SKILL.md").is_file());
+        assert!(!target.join("temp").exists());
+        #[cfg(unix)]
+        assert_eq!(
+            fs::read_link(target.join("runtimes/node/current")).unwrap(),
+            Path::new("bin/node")
+        );
+        assert!(!source.join("config").exists());
+        assert!(!source_skills.exists());
+
+        let _ = fs::remove_dir_all(base);
+    }
+}
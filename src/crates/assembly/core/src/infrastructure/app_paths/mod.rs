@@ -2,6 +2,8 @@
 //!
 //! Centralizes path policy for user data, caches, sessions, and workspace-adjacent storage.
 
+pub mod data_location;
 pub mod path_manager;
 
+pub use data_location::{apply_pending_data_relocation, DataLocation};
 pub use path_manager::{get_path_manager_arc, try_get_path_manager_arc, PathManager, StorageLevel};
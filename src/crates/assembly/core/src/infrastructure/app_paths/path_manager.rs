@@ -2,6 +2,7 @@
 //!
 //! Provides unified management for all app storage paths, supporting user, project, and temporary levels
 
+use super::data_location::{self, DataLocation};
 use crate::util::errors::*;
 use log::{debug, error};
 use serde::{Deserialize, Serialize};
@@ -33,6 +34,9 @@ pub enum StorageLevel {
 pub struct PathManager {
     /// User config root directory
     user_root: PathBuf,
+    /// Whether `user_root` was moved away from the default location with
+    /// [`PathManager::relocate_data_dir`].
+    user_root_relocated: bool,
     /// Optional override for the BitFun home directory, used by tests to avoid
     /// touching the real user home.
     bitfun_home_override: Option<PathBuf>,
@@ -44,11 +48,12 @@ impl PathManager {
     /// Create a new path manager
     pub fn new() -> BitFunResult<Self> {
         Self::validate_e2e_storage_guard()?;
-        let user_root = Self::get_user_config_root()?;
+        let (user_root, user_root_relocated) = Self::get_user_config_root()?;
         let bitfun_home_override = Self::get_bitfun_home_override();
 
         Ok(Self {
             user_root,
+            user_root_relocated,
             bitfun_home_override,
             project_runtime_slug_cache: Arc::new(Mutex::new(HashMap::new())),
         })
@@ -91,17 +96,52 @@ impl PathManager {
     /// - Windows: %APPDATA%\BitFun\
     /// - macOS: ~/Library/Application Support/BitFun/
     /// - Linux: ~/.config/bitfun/
-    fn get_user_config_root() -> BitFunResult<PathBuf> {
-        if let Some(path) =
-            Self::env_path("BITFUN_USER_ROOT").or_else(|| Self::env_path("BITFUN_E2E_USER_ROOT"))
-        {
-            return Ok(path);
+    ///
+    /// unless relocated through the `data-location.json` pointer kept there.
+    /// Also returns whether the root was relocated.
+    fn get_user_config_root() -> BitFunResult<(PathBuf, bool)> {
+        if let Some(path) = Self::user_root_from_env() {
+            return Ok((path, false));
         }
 
-        let config_dir = dirs::config_dir()
-            .ok_or_else(|| BitFunError::config("Failed to get config directory".to_string()))?;
+        let default_root = data_location::default_user_root()?;
+        match DataLocation::load(&default_root).user_root {
+            Some(user_root) => Ok((user_root, true)),
+            None => Ok((default_root, false)),
+        }
+    }
 
-        Ok(config_dir.join("bitfun"))
+    pub(crate) fn user_root_from_env() -> Option<PathBuf> {
+        Self::env_path("BITFUN_USER_ROOT").or_else(|| Self::env_path("BITFUN_E2E_USER_ROOT"))
+    }
+
+    /// Current data location, including a relocation waiting for restart.
+    pub fn data_location(&self) -> BitFunResult<DataLocation> {
+        let mut location = DataLocation::load(&data_location::default_user_root()?);
+        location.user_root = Some(self.user_root.clone());
+        Ok(location)
+    }
+
+    /// Schedules moving user data (config, agents, caches, managed runtimes,
+    /// logs and skills) to `new_root`. Services hold files open under the
+    /// current root, so the move happens on the next start; callers restart
+    /// the app afterwards.
+    pub fn relocate_data_dir(&self, new_root: &Path) -> BitFunResult<()> {
+        if Self::user_root_from_env().is_some() {
+            return Err(BitFunError::config(
+                "Data directory is set by BITFUN_USER_ROOT and cannot be relocated",
+            ));
+        }
+        let default_root = data_location::default_user_root()?;
+        let mut location = DataLocation::load(&default_root);
+        if new_root == self.user_root {
+            location.pending_root = None;
+        } else {
+            data_location::validate_relocation_target(&self.user_root, new_root)?;
+            location.pending_root = Some(new_root.to_path_buf());
+        }
+        location.last_error = None;
+        location.save(&default_root)
     }
 
     fn get_bitfun_home_override() -> Option<PathBuf> {
@@ -225,6 +265,21 @@ impl PathManager {
         self.user_config_dir().join("app.json")
     }
 
+    /// Get window layout file path: ~/.config/bitfun/config/window_layout.json
+    pub fn window_layout_file(&self) -> PathBuf {
+        self.user_config_dir().join("window_layout.json")
+    }
+
+    /// Get user theme directory: ~/.config/bitfun/config/themes/
+    pub fn user_themes_dir(&self) -> PathBuf {
+        self.user_config_dir().join("themes")
+    }
+
+    /// Get user project template directory: ~/.config/bitfun/config/project_templates/
+    pub fn user_project_templates_dir(&self) -> PathBuf {
+        self.user_config_dir().join("project_templates")
+    }
+
     /// Get user agent directory: ~/.config/bitfun/agents/
     pub fn user_agents_dir(&self) -> PathBuf {
         self.user_root.join("agents")
@@ -234,24 +289,13 @@ impl PathManager {
     /// - Windows: C:\Users\xxx\AppData\Roaming\BitFun\skills\
     /// - macOS: ~/Library/Application Support/BitFun/skills/
     /// - Linux: ~/.local/share/BitFun/skills/
+    ///
+    /// A relocated data root keeps skills under `{user_root}/skills/`.
     pub fn user_skills_dir(&self) -> PathBuf {
-        if cfg!(target_os = "windows") {
-            dirs::data_dir()
-                .unwrap_or_else(|| PathBuf::from("C:\\ProgramData"))
-                .join("BitFun")
-                .join("skills")
-        } else if cfg!(target_os = "macos") {
-            dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("/tmp"))
-                .join("Library")
-                .join("Application Support")
-                .join("BitFun")
-                .join("skills")
+        if self.user_root_relocated {
+            self.user_root.join("skills")
         } else {
-            dirs::data_local_dir()
-                .unwrap_or_else(|| PathBuf::from("/tmp"))
-                .join("BitFun")
-                .join("skills")
+            data_location::platform_skills_dir()
         }
     }
 
@@ -277,6 +321,11 @@ impl PathManager {
         self.user_root.join("data")
     }
 
+    /// User data backup archives: ~/.config/bitfun/data/backups/
+    pub fn user_backups_dir(&self) -> PathBuf {
+        self.user_data_dir().join("backups")
+    }
+
     /// User-level managed model resources shared across workspaces.
     pub fn user_models_dir(&self) -> PathBuf {
         self.user_data_dir().join("models")
@@ -302,6 +351,26 @@ impl PathManager {
         self.temp_dir().join("speech-input")
     }
 
+    /// Per-session attachments ingested from drag-drop and the clipboard.
+    pub fn session_attachments_dir(&self) -> PathBuf {
+        self.temp_dir().join("attachments").join("sessions")
+    }
+
+    /// Working directories for document conversion jobs.
+    pub fn document_conversion_temp_dir(&self) -> PathBuf {
+        self.temp_dir().join("docconv")
+    }
+
+    /// Throwaway profiles for per-session headless browsers.
+    pub fn headless_browser_profiles_dir(&self) -> PathBuf {
+        self.temp_dir().join("headless-browser")
+    }
+
+    /// Managed copies of files produced by agent tasks.
+    pub fn artifacts_dir(&self) -> PathBuf {
+        self.user_data_dir().join("artifacts")
+    }
+
     /// Get user memory database file: ~/.config/bitfun/data/memories/memories.sqlite
     pub fn memories_database_file(&self) -> PathBuf {
         self.user_data_dir()
@@ -309,6 +378,11 @@ impl PathManager {
             .join("memories.sqlite")
     }
 
+    /// Shared SQLite database used by core services for structured storage.
+    pub fn storage_database_file(&self) -> PathBuf {
+        self.user_data_dir().join("storage").join("bitfun.sqlite")
+    }
+
     /// Get the durable agent coordination database file.
     pub fn agent_coordination_database_file(&self) -> PathBuf {
         self.user_data_dir()
@@ -352,6 +426,46 @@ impl PathManager {
         self.user_cron_dir().join("jobs.json")
     }
 
+    /// Get agent task queue directory: ~/.config/bitfun/data/agent_tasks/
+    pub fn user_agent_tasks_dir(&self) -> PathBuf {
+        self.user_data_dir().join("agent_tasks")
+    }
+
+    /// Get agent task queue persistence file: ~/.config/bitfun/data/agent_tasks/tasks.json
+    pub fn agent_tasks_file(&self) -> PathBuf {
+        self.user_agent_tasks_dir().join("tasks.json")
+    }
+
+    /// Get approvals directory: ~/.config/bitfun/data/approvals/
+    pub fn user_approvals_dir(&self) -> PathBuf {
+        self.user_data_dir().join("approvals")
+    }
+
+    /// Get approvals persistence file: ~/.config/bitfun/data/approvals/approvals.json
+    pub fn approvals_file(&self) -> PathBuf {
+        self.user_approvals_dir().join("approvals.json")
+    }
+
+    /// Get workspace trust directory: ~/.config/bitfun/data/workspace_trust/
+    pub fn user_workspace_trust_dir(&self) -> PathBuf {
+        self.user_data_dir().join("workspace_trust")
+    }
+
+    /// Get workspace trust decisions file: ~/.config/bitfun/data/workspace_trust/workspace_trust.json
+    pub fn workspace_trust_file(&self) -> PathBuf {
+        self.user_workspace_trust_dir().join("workspace_trust.json")
+    }
+
+    /// Get MCP App widget instances file: ~/.config/bitfun/data/mcp_apps/instances.json
+    pub fn mcp_app_instances_file(&self) -> PathBuf {
+        self.user_data_dir().join("mcp_apps").join("instances.json")
+    }
+
+    /// Get MCP App widget scratch root: ~/.config/bitfun/data/mcp_apps/scratch/
+    pub fn mcp_app_scratch_dir(&self) -> PathBuf {
+        self.user_data_dir().join("mcp_apps").join("scratch")
+    }
+
     /// Get miniapps root directory: ~/.config/bitfun/data/miniapps/
     pub fn miniapps_dir(&self) -> PathBuf {
         self.user_data_dir().join("miniapps")
@@ -372,6 +486,11 @@ impl PathManager {
         self.user_data_dir().join("plugins")
     }
 
+    /// Get sidecar plugins directory: ~/.config/bitfun/data/sidecar_plugins/
+    pub fn user_sidecar_plugins_dir(&self) -> PathBuf {
+        self.user_data_dir().join("sidecar_plugins")
+    }
+
     /// Get logs directory: ~/.config/bitfun/logs/
     pub fn logs_dir(&self) -> PathBuf {
         self.user_root.join("logs")
@@ -415,6 +534,17 @@ impl PathManager {
             .join("tool_permissions.json")
     }
 
+    /// Get project secret redaction overrides file: {project}/.bitfun/config/secret_redaction.json
+    pub fn project_secret_redaction_file(&self, workspace_path: &Path) -> PathBuf {
+        self.project_internal_config_dir(workspace_path)
+            .join("secret_redaction.json")
+    }
+
+    /// Get project model overrides file: {project}/.bitfun/models.json
+    pub fn project_models_file(&self, workspace_path: &Path) -> PathBuf {
+        self.project_root(workspace_path).join("models.json")
+    }
+
     /// Get project mode skills file: {project}/.bitfun/config/mode_skills.json
     pub fn project_mode_skills_file(&self, workspace_path: &Path) -> PathBuf {
         self.project_internal_config_dir(workspace_path)
@@ -581,11 +711,17 @@ impl PathManager {
             self.speech_models_dir(),
             self.speech_model_downloads_dir(),
             self.user_cron_dir(),
+            self.user_agent_tasks_dir(),
+            self.user_approvals_dir(),
+            self.user_workspace_trust_dir(),
             self.user_rules_dir(),
             self.miniapps_dir(),
             self.logs_dir(),
             self.temp_dir(),
             self.speech_input_temp_dir(),
+            self.document_conversion_temp_dir(),
+            self.session_attachments_dir(),
+            self.artifacts_dir(),
         ];
 
         for dir in dirs {
@@ -608,6 +744,7 @@ impl Default for PathManager {
                 );
                 Self {
                     user_root: std::env::temp_dir().join("bitfun"),
+                    user_root_relocated: false,
                     bitfun_home_override: Self::get_bitfun_home_override(),
                     project_runtime_slug_cache: Arc::new(Mutex::new(HashMap::new())),
                 }
@@ -625,6 +762,7 @@ impl PathManager {
             .unwrap_or_else(|| user_root.clone());
         Self {
             user_root,
+            user_root_relocated: false,
             bitfun_home_override: Some(base.join("home").join(".bitfun")),
             project_runtime_slug_cache: Arc::new(Mutex::new(HashMap::new())),
         }
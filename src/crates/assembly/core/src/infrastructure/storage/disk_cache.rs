@@ -0,0 +1,454 @@
+//! Shared disk cache with quotas.
+//!
+//! Features cache files under `{cache_root}/{category}/`. Every category has a
+//! byte quota and the cache root as a whole has another; beyond them the least
+//! recently used files go first. Modification time doubles as the access
+//! stamp, so readers call [`DiskCache::touch`] on a hit.
+//!
+//! Directories that belong to no category, such as in-flight model downloads,
+//! count toward the total but are never evicted here.
+
+use crate::infrastructure::get_path_manager_arc;
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::SystemTime;
+
+const MIB: u64 = 1_048_576;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheCategory {
+    /// Skill and MCP market listings and descriptions.
+    MarketDescriptions,
+    /// npm packages fetched for managed runtimes and MCP servers.
+    NpmPackages,
+    /// Model responses kept across restarts.
+    ModelResponses,
+    /// Rendered or downloaded artifacts that can be produced again.
+    Artifacts,
+}
+
+impl CacheCategory {
+    pub const ALL: [Self; 4] = [
+        Self::MarketDescriptions,
+        Self::NpmPackages,
+        Self::ModelResponses,
+        Self::Artifacts,
+    ];
+
+    pub fn dir_name(self) -> &'static str {
+        match self {
+            Self::MarketDescriptions => "market",
+            Self::NpmPackages => "npm",
+            Self::ModelResponses => "model-responses",
+            Self::Artifacts => "artifacts",
+        }
+    }
+
+    fn default_quota_bytes(self) -> u64 {
+        match self {
+            Self::MarketDescriptions => 64 * MIB,
+            Self::NpmPackages => 1024 * MIB,
+            Self::ModelResponses => 128 * MIB,
+            Self::Artifacts => 512 * MIB,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DiskCacheQuotas {
+    /// Limit for the whole cache root, uncategorized entries included.
+    pub total_bytes: u64,
+    /// Per-category limits; categories left out use their default.
+    pub categories: HashMap<CacheCategory, u64>,
+}
+
+impl Default for DiskCacheQuotas {
+    fn default() -> Self {
+        Self {
+            total_bytes: 2048 * MIB,
+            categories: HashMap::new(),
+        }
+    }
+}
+
+impl DiskCacheQuotas {
+    pub fn category_bytes(&self, category: CacheCategory) -> u64 {
+        self.categories
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| category.default_quota_bytes())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheCategoryUsage {
+    pub category: CacheCategory,
+    pub bytes: u64,
+    pub files: usize,
+    pub quota_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheUsageReport {
+    pub root: PathBuf,
+    pub categories: Vec<CacheCategoryUsage>,
+    /// Entries outside any category.
+    pub other_bytes: u64,
+    pub total_bytes: u64,
+    pub quota_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEvictionResult {
+    pub files_deleted: usize,
+    pub bytes_freed: u64,
+}
+
+impl CacheEvictionResult {
+    fn merge(&mut self, other: CacheEvictionResult) {
+        self.files_deleted += other.files_deleted;
+        self.bytes_freed += other.bytes_freed;
+    }
+}
+
+struct CachedFile {
+    path: PathBuf,
+    last_used: SystemTime,
+    size: u64,
+}
+
+pub struct DiskCache {
+    root: PathBuf,
+    quotas: RwLock<DiskCacheQuotas>,
+    /// Serializes scans that delete files.
+    eviction_lock: Mutex<()>,
+}
+
+static GLOBAL_DISK_CACHE: OnceLock<Arc<DiskCache>> = OnceLock::new();
+
+/// Cache rooted at the path manager's cache directory.
+pub fn get_disk_cache() -> Arc<DiskCache> {
+    GLOBAL_DISK_CACHE
+        .get_or_init(|| {
+            Arc::new(DiskCache::new(
+                get_path_manager_arc().cache_root(),
+                DiskCacheQuotas::default(),
+            ))
+        })
+        .clone()
+}
+
+impl DiskCache {
+    pub fn new(root: PathBuf, quotas: DiskCacheQuotas) -> Self {
+        Self {
+            root,
+            quotas: RwLock::new(quotas),
+            eviction_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn quotas(&self) -> DiskCacheQuotas {
+        self.quotas
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Takes effect at the next [`DiskCache::enforce_quotas`].
+    pub fn set_quotas(&self, quotas: DiskCacheQuotas) {
+        *self
+            .quotas
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = quotas;
+    }
+
+    /// Directory owned by `category`, created on first use.
+    pub fn category_dir(&self, category: CacheCategory) -> BitFunResult<PathBuf> {
+        let dir = self.root.join(category.dir_name());
+        fs::create_dir_all(&dir).map_err(|error| {
+            BitFunError::io(format!(
+                "Failed to create cache directory {}: {}",
+                dir.display(),
+                error
+            ))
+        })?;
+        Ok(dir)
+    }
+
+    /// Marks a cached file as used so eviction keeps it longer.
+    pub fn touch(&self, path: &Path) {
+        let result = fs::File::options()
+            .write(true)
+            .open(path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+        if let Err(error) = result {
+            debug!(
+                "Failed to refresh cache entry: path={}, error={}",
+                path.display(),
+                error
+            );
+        }
+    }
+
+    pub fn usage(&self) -> BitFunResult<CacheUsageReport> {
+        let quotas = self.quotas();
+        let mut categories = Vec::with_capacity(CacheCategory::ALL.len());
+        for category in CacheCategory::ALL {
+            let files = self.category_files(category)?;
+            categories.push(CacheCategoryUsage {
+                category,
+                bytes: files.iter().map(|file| file.size).sum(),
+                files: files.len(),
+                quota_bytes: quotas.category_bytes(category),
+            });
+        }
+        let total_bytes = dir_size(&self.root).map_err(|error| self.io_error(error))?;
+        let categorized: u64 = categories.iter().map(|usage| usage.bytes).sum();
+        Ok(CacheUsageReport {
+            root: self.root.clone(),
+            categories,
+            other_bytes: total_bytes.saturating_sub(categorized),
+            total_bytes,
+            quota_bytes: quotas.total_bytes,
+        })
+    }
+
+    /// Trims every category to its quota, then the whole cache to the total
+    /// quota, least recently used files first.
+    pub fn enforce_quotas(&self) -> BitFunResult<CacheEvictionResult> {
+        let _guard = self
+            .eviction_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let quotas = self.quotas();
+        let mut result = CacheEvictionResult::default();
+        let mut remaining = Vec::new();
+        for category in CacheCategory::ALL {
+            let files = self.category_files(category)?;
+            let (evicted, kept) = evict_lru(files, quotas.category_bytes(category));
+            result.merge(evicted);
+            remaining.extend(kept);
+        }
+
+        let total = dir_size(&self.root).map_err(|error| self.io_error(error))?;
+        let evictable: u64 = remaining.iter().map(|file| file.size).sum();
+        let other = total.saturating_sub(evictable);
+        let budget = quotas.total_bytes.saturating_sub(other);
+        let (evicted, _) = evict_lru(remaining, budget);
+        result.merge(evicted);
+
+        if result.files_deleted > 0 {
+            debug!(
+                "Disk cache trimmed: files={}, bytes={}",
+                result.files_deleted, result.bytes_freed
+            );
+        }
+        Ok(result)
+    }
+
+    /// Empties one category, or every category when `category` is `None`.
+    pub fn clear(&self, category: Option<CacheCategory>) -> BitFunResult<CacheEvictionResult> {
+        let _guard = self
+            .eviction_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let categories = match category {
+            Some(category) => vec![category],
+            None => CacheCategory::ALL.to_vec(),
+        };
+        let mut result = CacheEvictionResult::default();
+        for category in categories {
+            let (evicted, _) = evict_lru(self.category_files(category)?, 0);
+            result.merge(evicted);
+            remove_empty_dirs(&self.root.join(category.dir_name()));
+        }
+        Ok(result)
+    }
+
+    fn category_files(&self, category: CacheCategory) -> BitFunResult<Vec<CachedFile>> {
+        let mut files = Vec::new();
+        collect_files(&self.root.join(category.dir_name()), &mut files)
+            .map_err(|error| self.io_error(error))?;
+        Ok(files)
+    }
+
+    fn io_error(&self, error: io::Error) -> BitFunError {
+        BitFunError::io(format!(
+            "Failed to scan cache directory {}: {}",
+            self.root.display(),
+            error
+        ))
+    }
+}
+
+/// Deletes the least recently used files until the rest fit in `budget`.
+/// Returns what was deleted and the files that were kept.
+fn evict_lru(mut files: Vec<CachedFile>, budget: u64) -> (CacheEvictionResult, Vec<CachedFile>) {
+    files.sort_by_key(|file| std::cmp::Reverse(file.last_used));
+    let mut result = CacheEvictionResult::default();
+    let mut kept = Vec::with_capacity(files.len());
+    let mut kept_bytes = 0u64;
+    for file in files {
+        if kept_bytes + file.size <= budget {
+            kept_bytes += file.size;
+            kept.push(file);
+            continue;
+        }
+        match fs::remove_file(&file.path) {
+            Ok(()) => {
+                result.files_deleted += 1;
+                result.bytes_freed += file.size;
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                warn!(
+                    "Failed to evict cache entry: path={}, error={}",
+                    file.path.display(),
+                    error
+                );
+                kept_bytes += file.size;
+                kept.push(file);
+            }
+        }
+    }
+    (result, kept)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<CachedFile>) -> io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        if metadata.is_dir() {
+            collect_files(&entry.path(), files)?;
+        } else if metadata.is_file() {
+            files.push(CachedFile {
+                path: entry.path(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                size: metadata.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn dir_size(dir: &Path) -> io::Result<u64> {
+    let mut files = Vec::new();
+    collect_files(dir, &mut files)?;
+    Ok(files.iter().map(|file| file.size).sum())
+}
+
+fn remove_empty_dirs(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            let path = entry.path();
+            remove_empty_dirs(&path);
+            let _ = fs::remove_dir(&path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn write_aged(path: &Path, size: usize, age_secs: u64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; size]).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+            .unwrap();
+    }
+
+    fn test_cache(name: &str, quotas: DiskCacheQuotas) -> DiskCache {
+        let root = std::env::temp_dir().join(format!(
+            "bitfun-disk-cache-{}-{}",
+            name,
+            uuid::Uuid::new_v4()
+        ));
+        DiskCache::new(root, quotas)
+    }
+
+    #[test]
+    fn quotas_evict_least_recently_used_files_first() {
+        let cache = test_cache(
+            "quota",
+            DiskCacheQuotas {
+                total_bytes: 250,
+                categories: HashMap::from([(CacheCategory::MarketDescriptions, 200)]),
+            },
+        );
+        let market = cache
+            .category_dir(CacheCategory::MarketDescriptions)
+            .unwrap();
+        write_aged(&market.join("old.json"), 100, 300);
+        write_aged(&market.join("used.json"), 100, 200);
+        write_aged(&market.join("new.json"), 100, 100);
+        cache.touch(&market.join("used.json"));
+        let artifacts = cache.category_dir(CacheCategory::Artifacts).unwrap();
+        write_aged(&artifacts.join("a.bin"), 100, 50);
+        write_aged(&cache.root.join("model-downloads").join("part"), 50, 400);
+
+        let result = cache.enforce_quotas().unwrap();
+
+        assert_eq!(result.files_deleted, 2);
+        assert!(!market.join("old.json").exists());
+        assert!(!market.join("new.json").exists());
+        assert!(market.join("used.json").exists());
+        assert!(artifacts.join("a.bin").exists());
+        assert!(cache.root.join("model-downloads").join("part").exists());
+        let usage = cache.usage().unwrap();
+        assert_eq!(usage.total_bytes, 250);
+        assert_eq!(usage.other_bytes, 50);
+
+        let _ = fs::remove_dir_all(&cache.root);
+    }
+
+    #[test]
+    fn clear_empties_only_the_requested_category() {
+        let cache = test_cache("clear", DiskCacheQuotas::default());
+        let npm = cache.category_dir(CacheCategory::NpmPackages).unwrap();
+        write_aged(&npm.join("pkg").join("index.js"), 10, 0);
+        let artifacts = cache.category_dir(CacheCategory::Artifacts).unwrap();
+        write_aged(&artifacts.join("a.bin"), 10, 0);
+
+        let result = cache.clear(Some(CacheCategory::NpmPackages)).unwrap();
+
+        assert_eq!(
+            result,
+            CacheEvictionResult {
+                files_deleted: 1,
+                bytes_freed: 10,
+            }
+        );
+        assert!(!npm.join("pkg").exists());
+        assert!(artifacts.join("a.bin").exists());
+
+        let _ = fs::remove_dir_all(&cache.root);
+    }
+}
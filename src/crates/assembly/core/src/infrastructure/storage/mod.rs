@@ -1,9 +1,16 @@
 //! Storage system
 //!
-//! Data persistence, cleanup, and storage policies.
+//! Data persistence, cleanup, storage policies, and the shared SQLite database.
 
 pub mod cleanup;
+pub mod disk_cache;
 pub mod persistence;
+pub mod sqlite;
 pub use cleanup::{CleanupCategory, CleanupPolicy, CleanupResult, CleanupService};
+pub use disk_cache::{
+    get_disk_cache, CacheCategory, CacheCategoryUsage, CacheEvictionResult, CacheUsageReport,
+    DiskCache, DiskCacheQuotas,
+};
 
 pub use persistence::{PersistenceService, StorageOptions};
+pub use sqlite::{get_global_sqlite_storage, SqliteMigration, SqliteStorage};
@@ -0,0 +1,406 @@
+//! Shared SQLite storage
+//!
+//! One WAL-mode database that services share instead of hand-rolled JSON
+//! files. Each component registers its own ordered migrations; applied
+//! versions are tracked per component so components evolve independently.
+//! A small key/value table with optional expiry is provided for caches.
+
+use crate::infrastructure::try_get_path_manager_arc;
+use crate::util::errors::{BitFunError, BitFunResult};
+use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::OnceCell;
+use tokio::task;
+
+static GLOBAL_SQLITE_STORAGE: OnceLock<Arc<SqliteStorage>> = OnceLock::new();
+
+const STORAGE_COMPONENT: &str = "storage";
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const STORAGE_MIGRATIONS: &[SqliteMigration] = &[SqliteMigration {
+    version: 1,
+    description: "key/value cache",
+    sql: r#"
+CREATE TABLE kv_cache (
+    namespace TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    updated_at_ms INTEGER NOT NULL,
+    expires_at_ms INTEGER,
+    PRIMARY KEY (namespace, key)
+);
+CREATE INDEX kv_cache_expiry ON kv_cache(expires_at_ms) WHERE expires_at_ms IS NOT NULL;
+"#,
+}];
+
+/// One schema step for a component. Versions start at 1 and must be
+/// strictly increasing; applied steps are never edited afterwards.
+#[derive(Debug, Clone, Copy)]
+pub struct SqliteMigration {
+    pub version: u32,
+    pub description: &'static str,
+    pub sql: &'static str,
+}
+
+pub struct SqliteStorage {
+    db_path: PathBuf,
+    connection: OnceCell<Arc<Mutex<Connection>>>,
+}
+
+impl SqliteStorage {
+    /// The database is opened lazily on first use.
+    pub fn new(db_path: PathBuf) -> Self {
+        Self {
+            db_path,
+            connection: OnceCell::new(),
+        }
+    }
+
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+
+    async fn connection(&self) -> BitFunResult<Arc<Mutex<Connection>>> {
+        let db_path = self.db_path.clone();
+        self.connection
+            .get_or_try_init(|| async move {
+                task::spawn_blocking(move || open_connection(db_path))
+                    .await
+                    .map_err(|error| {
+                        BitFunError::service(format!(
+                            "Storage database initialization task failed: {error}"
+                        ))
+                    })?
+            })
+            .await
+            .cloned()
+    }
+
+    /// Runs `operation` on the blocking pool with exclusive access to the
+    /// connection.
+    pub async fn with_connection<T, F>(&self, operation: F) -> BitFunResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> BitFunResult<T> + Send + 'static,
+    {
+        let connection = self.connection().await?;
+        task::spawn_blocking(move || {
+            let mut connection = connection.lock().map_err(|_| {
+                BitFunError::service("Storage database lock was poisoned".to_string())
+            })?;
+            operation(&mut connection)
+        })
+        .await
+        .map_err(|error| BitFunError::service(format!("Storage database task failed: {error}")))?
+    }
+
+    /// Like [`Self::with_connection`], inside an immediate transaction that is
+    /// committed when `operation` succeeds and rolled back otherwise.
+    pub async fn with_transaction<T, F>(&self, operation: F) -> BitFunResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Transaction<'_>) -> BitFunResult<T> + Send + 'static,
+    {
+        self.with_connection(move |connection| {
+            let transaction = connection
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(db_error)?;
+            let value = operation(&transaction)?;
+            transaction.commit().map_err(db_error)?;
+            Ok(value)
+        })
+        .await
+    }
+
+    /// Applies any of `migrations` not yet recorded for `component` and
+    /// returns the component's schema version afterwards.
+    pub async fn migrate(
+        &self,
+        component: &'static str,
+        migrations: &'static [SqliteMigration],
+    ) -> BitFunResult<u32> {
+        self.with_connection(move |connection| apply_migrations(connection, component, migrations))
+            .await
+    }
+
+    pub async fn cache_get<T>(&self, namespace: &str, key: &str) -> BitFunResult<Option<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let namespace = namespace.to_string();
+        let key = key.to_string();
+        let raw = self
+            .with_connection(move |connection| {
+                connection
+                    .query_row(
+                        "SELECT value FROM kv_cache
+                         WHERE namespace = ?1 AND key = ?2
+                           AND (expires_at_ms IS NULL OR expires_at_ms > ?3)",
+                        params![namespace, key, now_ms()],
+                        |row| row.get::<_, String>(0),
+                    )
+                    .optional()
+                    .map_err(db_error)
+            })
+            .await?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(BitFunError::from))
+            .transpose()
+    }
+
+    /// Stores `value` as JSON, replacing any previous entry. A `ttl` of
+    /// `None` keeps the entry until it is deleted.
+    pub async fn cache_put<T>(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: &T,
+        ttl: Option<Duration>,
+    ) -> BitFunResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let raw = serde_json::to_string(value)?;
+        let namespace = namespace.to_string();
+        let key = key.to_string();
+        self.with_connection(move |connection| {
+            let now = now_ms();
+            let expires_at = ttl.map(|ttl| now.saturating_add(ttl.as_millis() as i64));
+            connection
+                .execute(
+                    "INSERT INTO kv_cache (namespace, key, value, updated_at_ms, expires_at_ms)
+                     VALUES (?1, ?2, ?3, ?4, ?5)
+                     ON CONFLICT(namespace, key) DO UPDATE SET
+                         value = excluded.value,
+                         updated_at_ms = excluded.updated_at_ms,
+                         expires_at_ms = excluded.expires_at_ms",
+                    params![namespace, key, raw, now, expires_at],
+                )
+                .map_err(db_error)?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn cache_delete(&self, namespace: &str, key: &str) -> BitFunResult<bool> {
+        let namespace = namespace.to_string();
+        let key = key.to_string();
+        self.with_connection(move |connection| {
+            let removed = connection
+                .execute(
+                    "DELETE FROM kv_cache WHERE namespace = ?1 AND key = ?2",
+                    params![namespace, key],
+                )
+                .map_err(db_error)?;
+            Ok(removed > 0)
+        })
+        .await
+    }
+
+    /// Drops expired cache entries and returns how many were removed.
+    pub async fn cache_purge_expired(&self) -> BitFunResult<usize> {
+        self.with_connection(|connection| {
+            connection
+                .execute(
+                    "DELETE FROM kv_cache WHERE expires_at_ms IS NOT NULL AND expires_at_ms <= ?1",
+                    params![now_ms()],
+                )
+                .map_err(db_error)
+        })
+        .await
+    }
+}
+
+/// Shared storage at the user-level database path, created on first use.
+pub fn get_global_sqlite_storage() -> BitFunResult<Arc<SqliteStorage>> {
+    if let Some(storage) = GLOBAL_SQLITE_STORAGE.get() {
+        return Ok(storage.clone());
+    }
+    let path_manager = try_get_path_manager_arc()?;
+    Ok(GLOBAL_SQLITE_STORAGE
+        .get_or_init(|| Arc::new(SqliteStorage::new(path_manager.storage_database_file())))
+        .clone())
+}
+
+fn open_connection(db_path: PathBuf) -> BitFunResult<Arc<Mutex<Connection>>> {
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| {
+            BitFunError::io(format!(
+                "Failed to create storage database directory {}: {error}",
+                parent.display()
+            ))
+        })?;
+    }
+    let mut connection = Connection::open(&db_path).map_err(|error| {
+        BitFunError::io(format!(
+            "Failed to open storage database {}: {error}",
+            db_path.display()
+        ))
+    })?;
+    connection.busy_timeout(BUSY_TIMEOUT).map_err(db_error)?;
+    connection
+        .execute_batch(
+            r#"
+PRAGMA journal_mode = WAL;
+PRAGMA foreign_keys = ON;
+PRAGMA synchronous = NORMAL;
+CREATE TABLE IF NOT EXISTS storage_migrations (
+    component TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    description TEXT NOT NULL,
+    applied_at_ms INTEGER NOT NULL,
+    PRIMARY KEY (component, version)
+);
+            "#,
+        )
+        .map_err(db_error)?;
+    apply_migrations(&mut connection, STORAGE_COMPONENT, STORAGE_MIGRATIONS)?;
+    Ok(Arc::new(Mutex::new(connection)))
+}
+
+fn apply_migrations(
+    connection: &mut Connection,
+    component: &str,
+    migrations: &[SqliteMigration],
+) -> BitFunResult<u32> {
+    let latest = migrations.last().map_or(0, |migration| migration.version);
+    if migrations
+        .windows(2)
+        .any(|pair| pair[0].version >= pair[1].version)
+        || migrations.first().is_some_and(|first| first.version == 0)
+    {
+        return Err(BitFunError::service(format!(
+            "Storage migrations for {component} must have strictly increasing versions starting above 0"
+        )));
+    }
+
+    let transaction = connection
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(db_error)?;
+    let current = transaction
+        .query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM storage_migrations WHERE component = ?1",
+            params![component],
+            |row| row.get::<_, u32>(0),
+        )
+        .map_err(db_error)?;
+    if current > latest {
+        return Err(BitFunError::service(format!(
+            "Storage schema for {component} is at version {current}, newer than supported version {latest}"
+        )));
+    }
+
+    for migration in migrations.iter().filter(|m| m.version > current) {
+        transaction.execute_batch(migration.sql).map_err(|error| {
+            BitFunError::service(format!(
+                "Storage migration {component} v{} ({}) failed: {error}",
+                migration.version, migration.description
+            ))
+        })?;
+        transaction
+            .execute(
+                "INSERT INTO storage_migrations (component, version, description, applied_at_ms)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    component,
+                    migration.version,
+                    migration.description,
+                    now_ms()
+                ],
+            )
+            .map_err(db_error)?;
+    }
+    transaction.commit().map_err(db_error)?;
+    Ok(current.max(latest))
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn db_error(error: rusqlite::Error) -> BitFunError {
+    BitFunError::service(format!("Storage database error: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES_V1: &[SqliteMigration] = &[SqliteMigration {
+        version: 1,
+        description: "notes",
+        sql: "CREATE TABLE notes (id INTEGER PRIMARY KEY, body TEXT NOT NULL);",
+    }];
+
+    const NOTES_V2: &[SqliteMigration] = &[
+        NOTES_V1[0],
+        SqliteMigration {
+            version: 2,
+            description: "note tags",
+            sql: "ALTER TABLE notes ADD COLUMN tag TEXT;",
+        },
+    ];
+
+    #[tokio::test]
+    async fn migrations_apply_once_per_component_and_reject_downgrades() {
+        let root = tempfile::tempdir().unwrap();
+        let db_path = root.path().join("storage.sqlite");
+
+        let storage = SqliteStorage::new(db_path.clone());
+        assert_eq!(storage.migrate("notes", NOTES_V1).await.unwrap(), 1);
+        assert_eq!(storage.migrate("notes", NOTES_V1).await.unwrap(), 1);
+        assert_eq!(storage.migrate("notes", NOTES_V2).await.unwrap(), 2);
+        storage
+            .with_transaction(|tx| {
+                tx.execute("INSERT INTO notes (body, tag) VALUES ('a', 'x')", [])
+                    .map_err(db_error)?;
+                Ok(())
+            })
+            .await
+            .unwrap();
+        drop(storage);
+
+        let reopened = SqliteStorage::new(db_path);
+        assert!(reopened.migrate("notes", NOTES_V1).await.is_err());
+        let count = reopened
+            .with_connection(|connection| {
+                connection
+                    .query_row("SELECT COUNT(*) FROM notes", [], |row| row.get::<_, i64>(0))
+                    .map_err(db_error)
+            })
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn cache_entries_round_trip_and_expire() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = SqliteStorage::new(root.path().join("storage.sqlite"));
+
+        storage
+            .cache_put("models", "list", &vec!["a", "b"], None)
+            .await
+            .unwrap();
+        storage
+            .cache_put("models", "stale", &1_u32, Some(Duration::ZERO))
+            .await
+            .unwrap();
+
+        let list: Option<Vec<String>> = storage.cache_get("models", "list").await.unwrap();
+        assert_eq!(list, Some(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(
+            storage.cache_get::<u32>("models", "stale").await.unwrap(),
+            None
+        );
+        assert_eq!(storage.cache_purge_expired().await.unwrap(), 1);
+        assert!(storage.cache_delete("models", "list").await.unwrap());
+        assert!(!storage.cache_delete("models", "list").await.unwrap());
+    }
+}
@@ -0,0 +1,17 @@
+//! Approval workflow for sensitive actions.
+//!
+//! One pending-approval queue for every "ask the user" flow, with per-kind
+//! timeout policies and remembered choices.
+
+mod permission_bridge;
+mod service;
+mod store;
+mod types;
+
+pub use permission_bridge::{permission_approval_id, spawn_permission_approval_bridge};
+pub use service::{get_global_approval_service, set_global_approval_service, ApprovalService};
+pub use types::{
+    ApprovalDecision, ApprovalEvent, ApprovalKind, ApprovalPolicy, ApprovalRequest,
+    ApprovalResolution, ApprovalResponder, ApprovalResponse, ApprovalsFile, NewApprovalRequest,
+    RememberedApproval, APPROVALS_VERSION,
+};
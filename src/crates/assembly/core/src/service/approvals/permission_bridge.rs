@@ -0,0 +1,349 @@
+//! Mirrors sensitive tool permission asks into the approval queue.
+//!
+//! Shell commands, MCP tools not declared read-only, and file deletions that
+//! need the user's confirmation become approvals, so remembered choices,
+//! timeout policies and remote clients apply to them. Whichever prompt is
+//! answered first settles both: an approval resolution replies to the
+//! permission request, and a permission reply resolves the approval.
+
+use super::service::ApprovalService;
+use super::types::{
+    ApprovalDecision, ApprovalKind, ApprovalResolution, ApprovalResponder, ApprovalResponse,
+    NewApprovalRequest,
+};
+use crate::service::mcp::adapter::MCP_READ_ONLY_METADATA_KEY;
+use bitfun_agent_runtime::permission::{PermissionRequestManager, PermissionRequestManagerError};
+use bitfun_runtime_ports::{
+    PermissionReply, PermissionReplySource, PermissionRequest, PermissionRequestEvent,
+};
+use log::{debug, warn};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+
+const DELETE_TOOL_NAME: &str = "Delete";
+
+/// Approval id mirroring a permission request.
+pub fn permission_approval_id(permission_request_id: &str) -> String {
+    format!("approval_permission_{}", permission_request_id)
+}
+
+/// Starts mirroring interactive permission asks until the manager goes away.
+pub fn spawn_permission_approval_bridge(
+    permissions: Arc<PermissionRequestManager>,
+    approvals: Arc<ApprovalService>,
+) -> JoinHandle<()> {
+    let mut events = permissions.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(PermissionRequestEvent::Asked { request }) => {
+                    let Some(approval) = approval_for(&request) else {
+                        continue;
+                    };
+                    let permissions = Arc::clone(&permissions);
+                    let approvals = Arc::clone(&approvals);
+                    tokio::spawn(async move {
+                        let request_id = request.request_id;
+                        match approvals.request_approval(approval).await {
+                            Ok(resolution) => {
+                                reply_to_permission(&permissions, &request_id, &resolution).await
+                            }
+                            Err(error) => warn!(
+                                "Failed to mirror permission request as an approval: request_id={}, error={}",
+                                request_id, error
+                            ),
+                        }
+                    });
+                }
+                Ok(PermissionRequestEvent::Replied {
+                    request_id, reply, ..
+                }) => {
+                    let decision = match reply {
+                        PermissionReply::Once | PermissionReply::Always => {
+                            ApprovalDecision::Approve
+                        }
+                        PermissionReply::Reject { .. } => ApprovalDecision::Deny,
+                    };
+                    settle_approval(&approvals, &request_id, decision).await;
+                }
+                Ok(PermissionRequestEvent::Cancelled { request_id, .. }) => {
+                    settle_approval(&approvals, &request_id, ApprovalDecision::Deny).await;
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "Permission approval bridge lagged behind: skipped_events={}",
+                        skipped
+                    );
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+/// The approval a permission ask maps to, if it is one of the sensitive kinds.
+fn approval_for(request: &PermissionRequest) -> Option<NewApprovalRequest> {
+    let subject = request.resources.first()?.clone();
+    let tool = request.source.identity.as_str();
+    let (kind, title) = if request.action == "bash" {
+        (
+            ApprovalKind::DangerousCommand,
+            "Run shell command".to_string(),
+        )
+    } else if request.action == "mcp"
+        && request
+            .display_metadata
+            .get(MCP_READ_ONLY_METADATA_KEY)
+            .and_then(|value| value.as_bool())
+            != Some(true)
+    {
+        (
+            ApprovalKind::DestructiveMcpTool,
+            format!("Run MCP tool {}", tool),
+        )
+    } else if tool == DELETE_TOOL_NAME {
+        (ApprovalKind::FileDeletion, "Delete file".to_string())
+    } else {
+        return None;
+    };
+
+    Some(NewApprovalRequest {
+        id: Some(permission_approval_id(&request.request_id)),
+        kind,
+        title,
+        detail: request.project_path.clone(),
+        subject,
+        session_id: Some(request.session_id.clone()),
+        requester: None,
+    })
+}
+
+async fn reply_to_permission(
+    permissions: &PermissionRequestManager,
+    request_id: &str,
+    resolution: &ApprovalResolution,
+) {
+    // The permission prompt answered first; nothing left to settle.
+    if resolution.responder == ApprovalResponder::ToolPermission {
+        return;
+    }
+    let (reply, source) = match (resolution.decision, resolution.responder) {
+        (ApprovalDecision::Approve, ApprovalResponder::Remembered) => {
+            (PermissionReply::Once, PermissionReplySource::AutoApprove)
+        }
+        (ApprovalDecision::Approve, _) => (PermissionReply::Once, PermissionReplySource::User),
+        (ApprovalDecision::Deny, ApprovalResponder::Timeout) => (
+            PermissionReply::Reject {
+                feedback: Some("No one answered the approval before it timed out".to_string()),
+            },
+            PermissionReplySource::System,
+        ),
+        (ApprovalDecision::Deny, _) => (
+            PermissionReply::Reject { feedback: None },
+            PermissionReplySource::User,
+        ),
+    };
+    match permissions.reply(request_id, reply, source).await {
+        Ok(_) | Err(PermissionRequestManagerError::RequestNotFound(_)) => {}
+        Err(error) => warn!(
+            "Failed to reply to permission request from approval: request_id={}, error={}",
+            request_id, error
+        ),
+    }
+}
+
+async fn settle_approval(
+    approvals: &ApprovalService,
+    permission_request_id: &str,
+    decision: ApprovalDecision,
+) {
+    let response = ApprovalResponse {
+        decision,
+        remember: false,
+    };
+    if approvals
+        .respond(
+            &permission_approval_id(permission_request_id),
+            response,
+            ApprovalResponder::ToolPermission,
+        )
+        .await
+        .is_err()
+    {
+        debug!(
+            "No pending approval mirrors permission request: request_id={}",
+            permission_request_id
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::PathManager;
+    use crate::service::approvals::ApprovalEvent;
+    use bitfun_agent_runtime::permission::PermissionWaitOutcome;
+    use bitfun_runtime_ports::{
+        ClockPort, PermissionRequestSource, PermissionRequestSourceKind, RuntimeServiceCapability,
+        RuntimeServicePort,
+    };
+    use bitfun_services_core::permission_store::ProjectPermissionSqliteStore;
+    use serde_json::{json, Map};
+    use tokio::time::{sleep, Duration};
+
+    struct TestClock;
+
+    impl RuntimeServicePort for TestClock {
+        fn capability(&self) -> RuntimeServiceCapability {
+            RuntimeServiceCapability::Clock
+        }
+    }
+
+    impl ClockPort for TestClock {
+        fn now_unix_millis(&self) -> i64 {
+            1_780_000_000_000
+        }
+    }
+
+    async fn fixture(
+        root: &std::path::Path,
+    ) -> (Arc<PermissionRequestManager>, Arc<ApprovalService>) {
+        let store = Arc::new(ProjectPermissionSqliteStore::new(root.join("permissions")));
+        let permissions = Arc::new(PermissionRequestManager::new(
+            store.clone(),
+            store,
+            Arc::new(TestClock),
+        ));
+        let path_manager = Arc::new(PathManager::with_user_root_for_tests(root.join("user")));
+        let approvals = ApprovalService::new(path_manager).await.unwrap();
+        spawn_permission_approval_bridge(Arc::clone(&permissions), Arc::clone(&approvals));
+        (permissions, approvals)
+    }
+
+    fn permission_request(
+        request_id: &str,
+        action: &str,
+        tool: &str,
+        resource: &str,
+    ) -> PermissionRequest {
+        PermissionRequest {
+            request_id: request_id.to_string(),
+            round_id: "round-1".to_string(),
+            order: 0,
+            tool_call_id: None,
+            project_path: Some("/work/demo".to_string()),
+            project_id: "project-1".to_string(),
+            session_id: "session-1".to_string(),
+            agent_id: "agent".to_string(),
+            action: action.to_string(),
+            resources: vec![resource.to_string()],
+            save_resources: Vec::new(),
+            source: PermissionRequestSource {
+                kind: PermissionRequestSourceKind::ToolCall,
+                identity: tool.to_string(),
+            },
+            delegation: None,
+            display_metadata: Map::new(),
+        }
+    }
+
+    async fn wait_for_pending_approval(approvals: &ApprovalService) -> Vec<String> {
+        for _ in 0..100 {
+            let pending = approvals.list_pending().await;
+            if !pending.is_empty() {
+                return pending.into_iter().map(|request| request.id).collect();
+            }
+            sleep(Duration::from_millis(5)).await;
+        }
+        panic!("approval was not requested");
+    }
+
+    #[test]
+    fn only_sensitive_asks_become_approvals() {
+        let command = permission_request("p1", "bash", "Bash", "rm -rf build");
+        let approval = approval_for(&command).expect("command approval");
+        assert_eq!(approval.kind, ApprovalKind::DangerousCommand);
+        assert_eq!(approval.subject, "rm -rf build");
+        assert_eq!(approval.id.as_deref(), Some("approval_permission_p1"));
+
+        let mut read_only_mcp =
+            permission_request("p2", "mcp", "mcp__gh__search", "mcp__gh__search");
+        read_only_mcp
+            .display_metadata
+            .insert(MCP_READ_ONLY_METADATA_KEY.to_string(), json!(true));
+        assert!(approval_for(&read_only_mcp).is_none());
+        let writing_mcp = permission_request("p3", "mcp", "mcp__gh__merge", "mcp__gh__merge");
+        assert_eq!(
+            approval_for(&writing_mcp).map(|approval| approval.kind),
+            Some(ApprovalKind::DestructiveMcpTool)
+        );
+
+        let deletion = permission_request("p4", "edit", "Delete", "src/old.rs");
+        assert_eq!(
+            approval_for(&deletion).map(|approval| approval.kind),
+            Some(ApprovalKind::FileDeletion)
+        );
+        let edit = permission_request("p5", "edit", "Edit", "src/main.rs");
+        assert!(approval_for(&edit).is_none());
+    }
+
+    #[tokio::test]
+    async fn approving_the_mirrored_approval_answers_the_permission_request() {
+        let temp = tempfile::tempdir().unwrap();
+        let (permissions, approvals) = fixture(temp.path()).await;
+
+        let receiver = permissions
+            .register(permission_request("p1", "bash", "Bash", "cargo clean"))
+            .await
+            .unwrap();
+        let pending = wait_for_pending_approval(&approvals).await;
+        assert_eq!(pending, ["approval_permission_p1"]);
+
+        approvals
+            .respond(
+                "approval_permission_p1",
+                ApprovalResponse {
+                    decision: ApprovalDecision::Approve,
+                    remember: false,
+                },
+                ApprovalResponder::Desktop,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            receiver.wait().await,
+            PermissionWaitOutcome::Replied(PermissionReply::Once)
+        );
+    }
+
+    #[tokio::test]
+    async fn answering_the_permission_prompt_withdraws_the_approval() {
+        let temp = tempfile::tempdir().unwrap();
+        let (permissions, approvals) = fixture(temp.path()).await;
+        let mut events = approvals.subscribe();
+
+        let _receiver = permissions
+            .register(permission_request("p1", "edit", "Delete", "notes.txt"))
+            .await
+            .unwrap();
+        wait_for_pending_approval(&approvals).await;
+        permissions
+            .reply(
+                "p1",
+                PermissionReply::Reject { feedback: None },
+                PermissionReplySource::User,
+            )
+            .await
+            .unwrap();
+
+        loop {
+            if let ApprovalEvent::Resolved { resolution, .. } = events.recv().await.unwrap() {
+                assert_eq!(resolution.responder, ApprovalResponder::ToolPermission);
+                assert_eq!(resolution.decision, ApprovalDecision::Deny);
+                break;
+            }
+        }
+        assert!(approvals.list_pending().await.is_empty());
+    }
+}
@@ -0,0 +1,443 @@
+//! Approval workflow service.
+//!
+//! Callers await `request_approval`; the request sits in the pending queue and
+//! is broadcast to the desktop UI and remote clients until someone answers,
+//! a remembered choice applies, or the kind's policy timeout elapses.
+
+use super::store::ApprovalStore;
+use super::types::{
+    ApprovalDecision, ApprovalEvent, ApprovalKind, ApprovalPolicy, ApprovalRequest,
+    ApprovalResolution, ApprovalResponder, ApprovalResponse, ApprovalsFile, NewApprovalRequest,
+    RememberedApproval,
+};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{broadcast, oneshot, Mutex};
+use tokio::time::Duration;
+use uuid::Uuid;
+
+static GLOBAL_APPROVAL_SERVICE: OnceLock<Arc<ApprovalService>> = OnceLock::new();
+
+const EVENT_CHANNEL_CAPACITY: usize = 128;
+
+struct PendingApproval {
+    request: ApprovalRequest,
+    sender: oneshot::Sender<ApprovalResolution>,
+}
+
+pub struct ApprovalService {
+    store: ApprovalStore,
+    pending: Mutex<HashMap<String, PendingApproval>>,
+    settings: Mutex<ApprovalsFile>,
+    events: broadcast::Sender<ApprovalEvent>,
+}
+
+impl ApprovalService {
+    pub async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Arc<Self>> {
+        let store = ApprovalStore::new(path_manager).await?;
+        let settings = store.load().await?;
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        Ok(Arc::new(Self {
+            store,
+            pending: Mutex::new(HashMap::new()),
+            settings: Mutex::new(settings),
+            events,
+        }))
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ApprovalEvent> {
+        self.events.subscribe()
+    }
+
+    /// Asks the user to confirm a sensitive action and waits for the outcome.
+    pub async fn request_approval(
+        &self,
+        request: NewApprovalRequest,
+    ) -> BitFunResult<ApprovalResolution> {
+        if request.subject.trim().is_empty() {
+            return Err(BitFunError::validation("Approval subject cannot be empty"));
+        }
+
+        let (policy, remembered) = {
+            let settings = self.settings.lock().await;
+            let policy = policy_for(&settings, request.kind);
+            let remembered = policy
+                .allow_remember
+                .then(|| remembered_decision(&settings, request.kind, &request.subject))
+                .flatten();
+            (policy, remembered)
+        };
+
+        let created_at_ms = now_ms();
+        let request = ApprovalRequest {
+            id: request
+                .id
+                .unwrap_or_else(|| format!("approval_{}", Uuid::new_v4())),
+            kind: request.kind,
+            title: request.title,
+            detail: request.detail,
+            subject: request.subject,
+            session_id: request.session_id,
+            requester: request.requester,
+            created_at_ms,
+            expires_at_ms: policy
+                .timeout_secs
+                .map(|secs| created_at_ms + (secs as i64) * 1000),
+            on_timeout: policy.on_timeout,
+            allow_remember: policy.allow_remember,
+        };
+
+        if let Some(decision) = remembered {
+            debug!(
+                "Applying remembered approval: kind={:?}, subject={}, decision={:?}",
+                request.kind, request.subject, decision
+            );
+            let resolution =
+                resolution(&request.id, decision, ApprovalResponder::Remembered, false);
+            self.emit(ApprovalEvent::Resolved {
+                request,
+                resolution: resolution.clone(),
+            });
+            return Ok(resolution);
+        }
+
+        let (sender, mut receiver) = oneshot::channel();
+        {
+            let mut pending = self.pending.lock().await;
+            if pending.contains_key(&request.id) {
+                return Err(BitFunError::validation(format!(
+                    "Approval request {} is already pending",
+                    request.id
+                )));
+            }
+            pending.insert(
+                request.id.clone(),
+                PendingApproval {
+                    request: request.clone(),
+                    sender,
+                },
+            );
+        }
+        info!(
+            "Approval requested: id={}, kind={:?}, subject={}",
+            request.id, request.kind, request.subject
+        );
+        self.emit(ApprovalEvent::Requested {
+            request: request.clone(),
+        });
+
+        let outcome = match policy.timeout_secs {
+            Some(secs) => tokio::time::timeout(Duration::from_secs(secs), &mut receiver)
+                .await
+                .ok(),
+            None => Some((&mut receiver).await),
+        };
+
+        match outcome {
+            Some(Ok(resolution)) => Ok(resolution),
+            Some(Err(_)) => Err(BitFunError::service(format!(
+                "Approval request {} was dropped",
+                request.id
+            ))),
+            None => {
+                // A response may race the timeout; whoever removes the entry wins.
+                if self.pending.lock().await.remove(&request.id).is_none() {
+                    return receiver.await.map_err(|_| {
+                        BitFunError::service(format!("Approval request {} was dropped", request.id))
+                    });
+                }
+                let resolution = resolution(
+                    &request.id,
+                    policy.on_timeout,
+                    ApprovalResponder::Timeout,
+                    false,
+                );
+                info!(
+                    "Approval timed out: id={}, decision={:?}",
+                    request.id, policy.on_timeout
+                );
+                self.emit(ApprovalEvent::Resolved {
+                    request,
+                    resolution: resolution.clone(),
+                });
+                Ok(resolution)
+            }
+        }
+    }
+
+    /// Answers a pending approval. `remember` is ignored for kinds whose
+    /// policy does not allow remembering.
+    pub async fn respond(
+        &self,
+        request_id: &str,
+        response: ApprovalResponse,
+        responder: ApprovalResponder,
+    ) -> BitFunResult<ApprovalResolution> {
+        let pending = self
+            .pending
+            .lock()
+            .await
+            .remove(request_id)
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!("Pending approval not found: {}", request_id))
+            })?;
+
+        let remember = response.remember && pending.request.allow_remember;
+        if remember {
+            self.remember(
+                pending.request.kind,
+                &pending.request.subject,
+                response.decision,
+            )
+            .await?;
+        }
+
+        let resolution = resolution(request_id, response.decision, responder, remember);
+        if pending.sender.send(resolution.clone()).is_err() {
+            warn!(
+                "Approval requester went away before the response arrived: id={}",
+                request_id
+            );
+        }
+        info!(
+            "Approval resolved: id={}, decision={:?}, responder={:?}",
+            request_id, response.decision, responder
+        );
+        self.emit(ApprovalEvent::Resolved {
+            request: pending.request,
+            resolution: resolution.clone(),
+        });
+        Ok(resolution)
+    }
+
+    /// Pending approvals, oldest first. Requests whose caller stopped waiting
+    /// are dropped from the queue.
+    pub async fn list_pending(&self) -> Vec<ApprovalRequest> {
+        let mut pending = self.pending.lock().await;
+        pending.retain(|_, approval| !approval.sender.is_closed());
+        let mut requests: Vec<_> = pending
+            .values()
+            .map(|approval| approval.request.clone())
+            .collect();
+        requests.sort_by(|left, right| {
+            left.created_at_ms
+                .cmp(&right.created_at_ms)
+                .then_with(|| left.id.cmp(&right.id))
+        });
+        requests
+    }
+
+    pub async fn list_remembered(&self) -> Vec<RememberedApproval> {
+        self.settings.lock().await.remembered.clone()
+    }
+
+    pub async fn forget_remembered(&self, kind: ApprovalKind, subject: &str) -> BitFunResult<bool> {
+        let mut settings = self.settings.lock().await;
+        let before = settings.remembered.len();
+        settings
+            .remembered
+            .retain(|entry| !(entry.kind == kind && entry.subject == subject));
+        let removed = settings.remembered.len() != before;
+        if removed {
+            self.store.save(&settings).await?;
+        }
+        Ok(removed)
+    }
+
+    pub async fn clear_remembered(&self) -> BitFunResult<usize> {
+        let mut settings = self.settings.lock().await;
+        let removed = settings.remembered.len();
+        settings.remembered.clear();
+        if removed > 0 {
+            self.store.save(&settings).await?;
+        }
+        Ok(removed)
+    }
+
+    /// Effective policy for every kind, with user overrides applied.
+    pub async fn policies(&self) -> HashMap<ApprovalKind, ApprovalPolicy> {
+        let settings = self.settings.lock().await;
+        ApprovalKind::ALL
+            .into_iter()
+            .map(|kind| (kind, policy_for(&settings, kind)))
+            .collect()
+    }
+
+    pub async fn set_policy(&self, kind: ApprovalKind, policy: ApprovalPolicy) -> BitFunResult<()> {
+        let mut settings = self.settings.lock().await;
+        if policy == kind.default_policy() {
+            settings.policies.remove(&kind);
+        } else {
+            settings.policies.insert(kind, policy);
+        }
+        if !policy.allow_remember {
+            settings.remembered.retain(|entry| entry.kind != kind);
+        }
+        self.store.save(&settings).await
+    }
+
+    async fn remember(
+        &self,
+        kind: ApprovalKind,
+        subject: &str,
+        decision: ApprovalDecision,
+    ) -> BitFunResult<()> {
+        let mut settings = self.settings.lock().await;
+        settings
+            .remembered
+            .retain(|entry| !(entry.kind == kind && entry.subject == subject));
+        settings.remembered.push(RememberedApproval {
+            kind,
+            subject: subject.to_string(),
+            decision,
+            remembered_at_ms: now_ms(),
+        });
+        self.store.save(&settings).await
+    }
+
+    fn emit(&self, event: ApprovalEvent) {
+        let _ = self.events.send(event);
+    }
+}
+
+fn policy_for(settings: &ApprovalsFile, kind: ApprovalKind) -> ApprovalPolicy {
+    settings
+        .policies
+        .get(&kind)
+        .copied()
+        .unwrap_or_else(|| kind.default_policy())
+}
+
+fn remembered_decision(
+    settings: &ApprovalsFile,
+    kind: ApprovalKind,
+    subject: &str,
+) -> Option<ApprovalDecision> {
+    settings
+        .remembered
+        .iter()
+        .find(|entry| entry.kind == kind && entry.subject == subject)
+        .map(|entry| entry.decision)
+}
+
+fn resolution(
+    request_id: &str,
+    decision: ApprovalDecision,
+    responder: ApprovalResponder,
+    remembered: bool,
+) -> ApprovalResolution {
+    ApprovalResolution {
+        request_id: request_id.to_string(),
+        decision,
+        responder,
+        remembered,
+        resolved_at_ms: now_ms(),
+    }
+}
+
+fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+pub fn get_global_approval_service() -> Option<Arc<ApprovalService>> {
+    GLOBAL_APPROVAL_SERVICE.get().cloned()
+}
+
+pub fn set_global_approval_service(service: Arc<ApprovalService>) {
+    let _ = GLOBAL_APPROVAL_SERVICE.set(service);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn service(root: &std::path::Path) -> Arc<ApprovalService> {
+        let path_manager = Arc::new(PathManager::with_user_root_for_tests(root.join("user")));
+        ApprovalService::new(path_manager).await.unwrap()
+    }
+
+    fn device_action(subject: &str) -> NewApprovalRequest {
+        NewApprovalRequest {
+            id: None,
+            kind: ApprovalKind::RemoteDeviceAction,
+            title: "Start task".to_string(),
+            detail: None,
+            subject: subject.to_string(),
+            session_id: None,
+            requester: Some("phone".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn response_resolves_waiter_and_remembered_choice_skips_the_queue() {
+        let temp = tempfile::tempdir().unwrap();
+        let service = service(temp.path()).await;
+        let mut events = service.subscribe();
+
+        let waiter = {
+            let service = service.clone();
+            tokio::spawn(
+                async move { service.request_approval(device_action("phone:start")).await },
+            )
+        };
+        let ApprovalEvent::Requested { request } = events.recv().await.unwrap() else {
+            panic!("expected a requested event");
+        };
+        assert_eq!(service.list_pending().await.len(), 1);
+
+        service
+            .respond(
+                &request.id,
+                ApprovalResponse {
+                    decision: ApprovalDecision::Approve,
+                    remember: true,
+                },
+                ApprovalResponder::RemoteClient,
+            )
+            .await
+            .unwrap();
+        let resolution = waiter.await.unwrap().unwrap();
+        assert!(resolution.decision.is_approved());
+        assert!(resolution.remembered);
+        assert!(service.list_pending().await.is_empty());
+
+        let reloaded = self::service(temp.path()).await;
+        let again = reloaded
+            .request_approval(device_action("phone:start"))
+            .await
+            .unwrap();
+        assert_eq!(again.responder, ApprovalResponder::Remembered);
+        assert!(again.decision.is_approved());
+    }
+
+    #[tokio::test]
+    async fn unanswered_request_applies_timeout_default() {
+        let temp = tempfile::tempdir().unwrap();
+        let service = service(temp.path()).await;
+        service
+            .set_policy(
+                ApprovalKind::RemoteDeviceAction,
+                ApprovalPolicy {
+                    timeout_secs: Some(1),
+                    on_timeout: ApprovalDecision::Deny,
+                    allow_remember: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        let resolution = service
+            .request_approval(device_action("phone:cancel"))
+            .await
+            .unwrap();
+
+        assert_eq!(resolution.responder, ApprovalResponder::Timeout);
+        assert_eq!(resolution.decision, ApprovalDecision::Deny);
+        assert!(service.list_pending().await.is_empty());
+    }
+}
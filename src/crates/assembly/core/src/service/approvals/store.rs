@@ -0,0 +1,113 @@
+//! approvals.json persistence wrapper.
+
+use super::types::{ApprovalsFile, APPROVALS_VERSION};
+use crate::infrastructure::storage::{PersistenceService, StorageOptions};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+
+pub(super) struct ApprovalStore {
+    persistence: PersistenceService,
+    path_manager: Arc<PathManager>,
+}
+
+impl ApprovalStore {
+    pub(super) async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Self> {
+        let approvals_dir = path_manager.user_approvals_dir();
+        path_manager.ensure_dir(&approvals_dir).await?;
+
+        let persistence = PersistenceService::new(approvals_dir).await?;
+
+        Ok(Self {
+            persistence,
+            path_manager,
+        })
+    }
+
+    pub(super) async fn load(&self) -> BitFunResult<ApprovalsFile> {
+        let approvals_file_path = self.path_manager.approvals_file();
+        if !approvals_file_path.exists() {
+            return Ok(ApprovalsFile::default());
+        }
+
+        let content = fs::read_to_string(&approvals_file_path)
+            .await
+            .map_err(|error| BitFunError::service(format!("Failed to read file: {}", error)))?;
+
+        match parse_approvals_file_content(&content, &approvals_file_path) {
+            Ok(file) => Ok(file),
+            Err(error) => {
+                warn!(
+                    "Failed to load approvals file; backing up and resetting to defaults: path={}, error={}",
+                    approvals_file_path.display(),
+                    error
+                );
+                self.backup_incompatible_file(&approvals_file_path).await?;
+                let file = ApprovalsFile::default();
+                self.save(&file).await?;
+                Ok(file)
+            }
+        }
+    }
+
+    pub(super) async fn save(&self, file: &ApprovalsFile) -> BitFunResult<()> {
+        self.persistence
+            .save_json("approvals", file, StorageOptions::default())
+            .await
+    }
+
+    async fn backup_incompatible_file(&self, approvals_file_path: &Path) -> BitFunResult<()> {
+        let backup_path = incompatible_backup_path(approvals_file_path);
+        fs::rename(approvals_file_path, &backup_path)
+            .await
+            .map_err(|error| {
+                BitFunError::service(format!(
+                    "Failed to back up incompatible approvals file {} to {}: {}",
+                    approvals_file_path.display(),
+                    backup_path.display(),
+                    error
+                ))
+            })?;
+        info!(
+            "Backed up incompatible approvals file: source={}, backup={}",
+            approvals_file_path.display(),
+            backup_path.display()
+        );
+        Ok(())
+    }
+}
+
+fn incompatible_backup_path(approvals_file_path: &Path) -> PathBuf {
+    let timestamp_ms = chrono::Utc::now().timestamp_millis();
+    let file_name = approvals_file_path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .unwrap_or("approvals.json");
+    approvals_file_path.with_file_name(format!("{}.incompatible.{}.bak", file_name, timestamp_ms))
+}
+
+fn parse_approvals_file_content(
+    content: &str,
+    approvals_file_path: &Path,
+) -> BitFunResult<ApprovalsFile> {
+    let file: ApprovalsFile = serde_json::from_str(content).map_err(|error| {
+        BitFunError::service(format!(
+            "Failed to parse approvals file {}: {}",
+            approvals_file_path.display(),
+            error
+        ))
+    })?;
+
+    if file.version != APPROVALS_VERSION {
+        return Err(BitFunError::service(format!(
+            "Unsupported approvals file version {} in {}",
+            file.version,
+            approvals_file_path.display()
+        )));
+    }
+
+    Ok(file)
+}
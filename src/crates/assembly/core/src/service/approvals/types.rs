@@ -0,0 +1,223 @@
+//! Approval request, policy and persistence types.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const APPROVALS_VERSION: u32 = 1;
+
+/// Category of sensitive action awaiting user confirmation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalKind {
+    DangerousCommand,
+    DestructiveMcpTool,
+    FileDeletion,
+    RemoteDeviceAction,
+    /// Granting a sidecar plugin the permissions its manifest declares.
+    PluginPermission,
+}
+
+impl ApprovalKind {
+    pub const ALL: [ApprovalKind; 5] = [
+        Self::DangerousCommand,
+        Self::DestructiveMcpTool,
+        Self::FileDeletion,
+        Self::RemoteDeviceAction,
+        Self::PluginPermission,
+    ];
+
+    pub fn default_policy(self) -> ApprovalPolicy {
+        match self {
+            Self::DangerousCommand | Self::DestructiveMcpTool => ApprovalPolicy {
+                timeout_secs: Some(600),
+                on_timeout: ApprovalDecision::Deny,
+                allow_remember: true,
+            },
+            Self::FileDeletion => ApprovalPolicy {
+                timeout_secs: Some(300),
+                on_timeout: ApprovalDecision::Deny,
+                allow_remember: false,
+            },
+            Self::RemoteDeviceAction => ApprovalPolicy {
+                timeout_secs: Some(120),
+                on_timeout: ApprovalDecision::Deny,
+                allow_remember: true,
+            },
+            // The grant itself is persisted by the plugin service.
+            Self::PluginPermission => ApprovalPolicy {
+                timeout_secs: Some(600),
+                on_timeout: ApprovalDecision::Deny,
+                allow_remember: false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    Approve,
+    Deny,
+}
+
+impl ApprovalDecision {
+    pub fn is_approved(self) -> bool {
+        matches!(self, Self::Approve)
+    }
+}
+
+/// How an approval kind is handled when nobody answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalPolicy {
+    /// `None` waits until someone answers.
+    pub timeout_secs: Option<u64>,
+    pub on_timeout: ApprovalDecision,
+    /// Whether "remember my choice" is honored for this kind.
+    pub allow_remember: bool,
+}
+
+/// Who resolved an approval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalResponder {
+    Desktop,
+    RemoteClient,
+    Remembered,
+    Timeout,
+    /// Answered through the chat's tool permission prompt that this approval
+    /// mirrors.
+    ToolPermission,
+}
+
+/// Input for `ApprovalService::request_approval`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewApprovalRequest {
+    /// Caller-chosen id, for callers that mirror another prompt and must be
+    /// able to answer this one when that prompt is answered. Generated when
+    /// absent.
+    #[serde(default)]
+    pub id: Option<String>,
+    pub kind: ApprovalKind,
+    pub title: String,
+    #[serde(default)]
+    pub detail: Option<String>,
+    /// Stable key the remembered choice is stored under, such as the command,
+    /// the MCP tool id, or `device_id:action`.
+    pub subject: String,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Remote device or client that triggered the action, if any.
+    #[serde(default)]
+    pub requester: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalRequest {
+    pub id: String,
+    pub kind: ApprovalKind,
+    pub title: String,
+    pub detail: Option<String>,
+    pub subject: String,
+    pub session_id: Option<String>,
+    pub requester: Option<String>,
+    pub created_at_ms: i64,
+    pub expires_at_ms: Option<i64>,
+    /// Decision applied if the request expires unanswered.
+    pub on_timeout: ApprovalDecision,
+    pub allow_remember: bool,
+}
+
+/// Answer submitted by the desktop UI or a remote client.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalResponse {
+    pub decision: ApprovalDecision,
+    #[serde(default)]
+    pub remember: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalResolution {
+    pub request_id: String,
+    pub decision: ApprovalDecision,
+    pub responder: ApprovalResponder,
+    pub remembered: bool,
+    pub resolved_at_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RememberedApproval {
+    pub kind: ApprovalKind,
+    pub subject: String,
+    pub decision: ApprovalDecision,
+    pub remembered_at_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApprovalEvent {
+    Requested {
+        request: ApprovalRequest,
+    },
+    Resolved {
+        request: ApprovalRequest,
+        resolution: ApprovalResolution,
+    },
+}
+
+impl ApprovalEvent {
+    pub fn request(&self) -> &ApprovalRequest {
+        match self {
+            Self::Requested { request } | Self::Resolved { request, .. } => request,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalsFile {
+    pub version: u32,
+    #[serde(default)]
+    pub remembered: Vec<RememberedApproval>,
+    /// Per-kind overrides of `ApprovalKind::default_policy`.
+    #[serde(default)]
+    pub policies: BTreeMap<ApprovalKind, ApprovalPolicy>,
+}
+
+impl Default for ApprovalsFile {
+    fn default() -> Self {
+        Self {
+            version: APPROVALS_VERSION,
+            remembered: Vec::new(),
+            policies: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approvals_file_round_trips_policy_overrides() {
+        let mut file = ApprovalsFile::default();
+        file.policies.insert(
+            ApprovalKind::FileDeletion,
+            ApprovalPolicy {
+                timeout_secs: None,
+                on_timeout: ApprovalDecision::Deny,
+                allow_remember: true,
+            },
+        );
+
+        let json = serde_json::to_string(&file).unwrap();
+        assert!(json.contains("\"file_deletion\""));
+        let parsed: ApprovalsFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.policies, file.policies);
+    }
+}
@@ -0,0 +1,14 @@
+//! Agent artifact service
+//!
+//! Managed storage for files produced by agent tasks, with previews, export,
+//! and garbage collection of orphaned entries.
+
+mod service;
+mod types;
+
+pub use service::{
+    get_global_artifact_service, set_global_artifact_service, ArtifactService, MAX_ARTIFACT_BYTES,
+};
+pub use types::{
+    ArtifactGcReport, ArtifactKind, ArtifactPreview, ArtifactRecord, RegisterArtifactRequest,
+};
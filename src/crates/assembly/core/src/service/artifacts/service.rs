@@ -0,0 +1,545 @@
+//! Artifact store.
+//!
+//! Registered files are copied to `<artifacts_dir>/<artifact_id>/<name>` and
+//! described by a row in the shared SQLite database, so artifacts survive
+//! workspace clean-ups and can be listed per session or task.
+
+use super::types::{
+    ArtifactGcReport, ArtifactKind, ArtifactPreview, ArtifactRecord, RegisterArtifactRequest,
+};
+use crate::infrastructure::storage::{SqliteMigration, SqliteStorage};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use base64::Engine;
+use chrono::Utc;
+use log::{debug, warn};
+use rusqlite::{params, OptionalExtension, Row};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::io::AsyncReadExt;
+
+static GLOBAL_ARTIFACT_SERVICE: OnceLock<Arc<ArtifactService>> = OnceLock::new();
+
+pub const MAX_ARTIFACT_BYTES: u64 = 200 * 1024 * 1024;
+
+const STORAGE_COMPONENT: &str = "artifacts";
+const MIGRATIONS: &[SqliteMigration] = &[SqliteMigration {
+    version: 1,
+    description: "artifact records",
+    sql: r#"
+CREATE TABLE artifacts (
+    id TEXT PRIMARY KEY,
+    session_id TEXT NOT NULL,
+    task_id TEXT,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    path TEXT NOT NULL,
+    source_path TEXT,
+    created_at_ms INTEGER NOT NULL
+);
+CREATE INDEX artifacts_session ON artifacts(session_id, created_at_ms);
+"#,
+}];
+const SELECT_COLUMNS: &str =
+    "id, session_id, task_id, name, kind, mime_type, size_bytes, path, source_path, created_at_ms";
+
+const PREVIEW_TEXT_BYTES: u64 = 256 * 1024;
+const PREVIEW_IMAGE_BYTES: u64 = 10 * 1024 * 1024;
+const MAX_NAME_CHARS: usize = 128;
+
+pub struct ArtifactService {
+    root: PathBuf,
+    storage: Arc<SqliteStorage>,
+}
+
+impl ArtifactService {
+    pub async fn new(
+        path_manager: &PathManager,
+        storage: Arc<SqliteStorage>,
+    ) -> BitFunResult<Arc<Self>> {
+        Self::with_root(path_manager.artifacts_dir(), storage).await
+    }
+
+    pub async fn with_root(root: PathBuf, storage: Arc<SqliteStorage>) -> BitFunResult<Arc<Self>> {
+        storage.migrate(STORAGE_COMPONENT, MIGRATIONS).await?;
+        Ok(Arc::new(Self { root, storage }))
+    }
+
+    /// Copies a file produced by an agent task into managed storage.
+    pub async fn register(&self, request: RegisterArtifactRequest) -> BitFunResult<ArtifactRecord> {
+        validate_id(&request.session_id, "session id")?;
+        let source = PathBuf::from(&request.source_path);
+        let metadata = tokio::fs::metadata(&source).await.map_err(|e| {
+            BitFunError::NotFound(format!("Cannot read {}: {}", source.display(), e))
+        })?;
+        if !metadata.is_file() {
+            return Err(BitFunError::validation(format!(
+                "Only files can be registered as artifacts: {}",
+                source.display()
+            )));
+        }
+        if metadata.len() > MAX_ARTIFACT_BYTES {
+            return Err(BitFunError::validation(format!(
+                "{} is {} bytes; artifacts are limited to {} bytes",
+                source.display(),
+                metadata.len(),
+                MAX_ARTIFACT_BYTES
+            )));
+        }
+
+        let name = sanitize_file_name(
+            request
+                .name
+                .as_deref()
+                .or_else(|| source.file_name().and_then(|name| name.to_str()))
+                .unwrap_or_default(),
+        );
+        let (kind, mime_type) = classify(&name);
+        let id = uuid::Uuid::new_v4().to_string();
+        let dir = self.root.join(&id);
+        let stored = dir.join(&name);
+        tokio::fs::create_dir_all(&dir).await?;
+
+        let record = ArtifactRecord {
+            id,
+            session_id: request.session_id,
+            task_id: request.task_id,
+            name,
+            kind,
+            mime_type,
+            size_bytes: metadata.len(),
+            path: stored.to_string_lossy().to_string(),
+            source_path: Some(request.source_path),
+            created_at_ms: Utc::now().timestamp_millis(),
+        };
+        let result = match tokio::fs::copy(&source, &stored).await {
+            Ok(_) => self.insert(record.clone()).await,
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = result {
+            let _ = tokio::fs::remove_dir_all(&dir).await;
+            return Err(e);
+        }
+
+        debug!(
+            "Artifact registered: id={}, session_id={}, kind={:?}, size={}",
+            record.id, record.session_id, record.kind, record.size_bytes
+        );
+        Ok(record)
+    }
+
+    /// Newest first; all sessions when `session_id` is `None`.
+    pub async fn list(&self, session_id: Option<&str>) -> BitFunResult<Vec<ArtifactRecord>> {
+        let session_id = session_id.map(str::to_string);
+        self.storage
+            .with_connection(move |connection| {
+                let sql = format!(
+                    "SELECT {SELECT_COLUMNS} FROM artifacts
+                     WHERE ?1 IS NULL OR session_id = ?1
+                     ORDER BY created_at_ms DESC"
+                );
+                let mut statement = connection.prepare(&sql).map_err(db_error)?;
+                let rows = statement
+                    .query_map(params![session_id], record_from_row)
+                    .map_err(db_error)?;
+                rows.collect::<Result<Vec<_>, _>>().map_err(db_error)
+            })
+            .await
+    }
+
+    pub async fn get(&self, id: &str) -> BitFunResult<ArtifactRecord> {
+        validate_id(id, "artifact id")?;
+        let lookup_id = id.to_string();
+        self.storage
+            .with_connection(move |connection| {
+                connection
+                    .query_row(
+                        &format!("SELECT {SELECT_COLUMNS} FROM artifacts WHERE id = ?1"),
+                        params![lookup_id],
+                        record_from_row,
+                    )
+                    .optional()
+                    .map_err(db_error)
+            })
+            .await?
+            .ok_or_else(|| BitFunError::NotFound(format!("Artifact not found: {}", id)))
+    }
+
+    pub async fn preview(&self, id: &str) -> BitFunResult<ArtifactPreview> {
+        let record = self.get(id).await?;
+        let path = Path::new(&record.path);
+
+        if record.kind == ArtifactKind::Image {
+            if record.size_bytes > PREVIEW_IMAGE_BYTES {
+                return Ok(ArtifactPreview::Unavailable);
+            }
+            let bytes = tokio::fs::read(path).await?;
+            return Ok(ArtifactPreview::Image {
+                data_url: format!(
+                    "data:{};base64,{}",
+                    record.mime_type,
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                ),
+            });
+        }
+        if !is_text_mime(&record.mime_type) {
+            return Ok(ArtifactPreview::Unavailable);
+        }
+
+        let mut head = Vec::new();
+        tokio::fs::File::open(path)
+            .await?
+            .take(PREVIEW_TEXT_BYTES)
+            .read_to_end(&mut head)
+            .await?;
+        let content = match std::str::from_utf8(&head) {
+            Ok(text) => text.to_string(),
+            // The preview window may cut a multi-byte character in half.
+            Err(e) if e.error_len().is_none() => {
+                String::from_utf8_lossy(&head[..e.valid_up_to()]).to_string()
+            }
+            Err(_) => return Ok(ArtifactPreview::Unavailable),
+        };
+        Ok(ArtifactPreview::Text {
+            content,
+            truncated: record.size_bytes > PREVIEW_TEXT_BYTES,
+        })
+    }
+
+    /// Copies the artifact to `target`. A directory target keeps the
+    /// artifact's file name.
+    pub async fn export(&self, id: &str, target: &Path) -> BitFunResult<PathBuf> {
+        let record = self.get(id).await?;
+        let destination = if tokio::fs::metadata(target)
+            .await
+            .is_ok_and(|metadata| metadata.is_dir())
+        {
+            target.join(&record.name)
+        } else {
+            target.to_path_buf()
+        };
+        tokio::fs::copy(&record.path, &destination).await?;
+        Ok(destination)
+    }
+
+    pub async fn delete(&self, id: &str) -> BitFunResult<bool> {
+        validate_id(id, "artifact id")?;
+        let removed = self.delete_records(vec![id.to_string()]).await?;
+        self.remove_dir(id).await;
+        Ok(removed > 0)
+    }
+
+    /// Drops every artifact of a deleted session and returns how many were
+    /// removed.
+    pub async fn remove_session(&self, session_id: &str) -> BitFunResult<usize> {
+        let ids: Vec<String> = self
+            .list(Some(session_id))
+            .await?
+            .into_iter()
+            .map(|record| record.id)
+            .collect();
+        let removed = self.delete_records(ids.clone()).await?;
+        for id in &ids {
+            self.remove_dir(id).await;
+        }
+        Ok(removed)
+    }
+
+    /// Removes records whose managed copy is gone, records of sessions not in
+    /// `live_sessions` (when given), and directories without a record.
+    pub async fn collect_garbage(
+        &self,
+        live_sessions: Option<&HashSet<String>>,
+    ) -> BitFunResult<ArtifactGcReport> {
+        let records = self.list(None).await?;
+        let mut known = HashSet::new();
+        let mut orphaned = Vec::new();
+        for record in records {
+            let session_gone = live_sessions.is_some_and(|live| !live.contains(&record.session_id));
+            let file_missing = !tokio::fs::try_exists(&record.path).await.unwrap_or(false);
+            if session_gone || file_missing {
+                orphaned.push(record.id);
+            } else {
+                known.insert(record.id);
+            }
+        }
+
+        let mut report = ArtifactGcReport {
+            removed_records: self.delete_records(orphaned).await?,
+            removed_directories: 0,
+        };
+        let mut entries = match tokio::fs::read_dir(&self.root).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if known.contains(&name) {
+                continue;
+            }
+            match tokio::fs::remove_dir_all(entry.path()).await {
+                Ok(()) => report.removed_directories += 1,
+                Err(e) => warn!(
+                    "Failed to remove orphaned artifact directory: path={}, error={}",
+                    entry.path().display(),
+                    e
+                ),
+            }
+        }
+        Ok(report)
+    }
+
+    async fn insert(&self, record: ArtifactRecord) -> BitFunResult<()> {
+        self.storage
+            .with_connection(move |connection| {
+                connection
+                    .execute(
+                        "INSERT INTO artifacts (id, session_id, task_id, name, kind, mime_type, size_bytes, path, source_path, created_at_ms)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                        params![
+                            record.id,
+                            record.session_id,
+                            record.task_id,
+                            record.name,
+                            record.kind.as_str(),
+                            record.mime_type,
+                            record.size_bytes as i64,
+                            record.path,
+                            record.source_path,
+                            record.created_at_ms
+                        ],
+                    )
+                    .map_err(db_error)?;
+                Ok(())
+            })
+            .await
+    }
+
+    async fn delete_records(&self, ids: Vec<String>) -> BitFunResult<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        self.storage
+            .with_transaction(move |transaction| {
+                let mut removed = 0;
+                for id in &ids {
+                    removed += transaction
+                        .execute("DELETE FROM artifacts WHERE id = ?1", params![id])
+                        .map_err(db_error)?;
+                }
+                Ok(removed)
+            })
+            .await
+    }
+
+    async fn remove_dir(&self, id: &str) {
+        let dir = self.root.join(id);
+        match tokio::fs::remove_dir_all(&dir).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!(
+                "Failed to remove artifact directory: path={}, error={}",
+                dir.display(),
+                e
+            ),
+        }
+    }
+}
+
+pub fn get_global_artifact_service() -> Option<Arc<ArtifactService>> {
+    GLOBAL_ARTIFACT_SERVICE.get().cloned()
+}
+
+pub fn set_global_artifact_service(service: Arc<ArtifactService>) {
+    let _ = GLOBAL_ARTIFACT_SERVICE.set(service);
+}
+
+fn record_from_row(row: &Row<'_>) -> rusqlite::Result<ArtifactRecord> {
+    Ok(ArtifactRecord {
+        id: row.get(0)?,
+        session_id: row.get(1)?,
+        task_id: row.get(2)?,
+        name: row.get(3)?,
+        kind: ArtifactKind::parse(&row.get::<_, String>(4)?),
+        mime_type: row.get(5)?,
+        size_bytes: row.get::<_, i64>(6)?.max(0) as u64,
+        path: row.get(7)?,
+        source_path: row.get(8)?,
+        created_at_ms: row.get(9)?,
+    })
+}
+
+fn db_error(error: rusqlite::Error) -> BitFunError {
+    BitFunError::service(format!("Artifact database error: {error}"))
+}
+
+/// Ids become directory names, so only plain identifier characters pass.
+fn validate_id(id: &str, label: &str) -> BitFunResult<()> {
+    let valid = !id.is_empty()
+        && id.len() <= 128
+        && id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BitFunError::validation(format!(
+            "Invalid {}: {}",
+            label, id
+        )))
+    }
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .trim()
+        .chars()
+        .map(|ch| match ch {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            ch if ch.is_control() => '_',
+            ch => ch,
+        })
+        .take(MAX_NAME_CHARS)
+        .collect();
+    let cleaned = cleaned.trim_matches(['.', ' ']);
+    if cleaned.is_empty() {
+        "artifact".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+fn classify(name: &str) -> (ArtifactKind, String) {
+    let extension = Path::new(name)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let (kind, mime_type) = match extension.as_str() {
+        "png" => (ArtifactKind::Image, "image/png"),
+        "jpg" | "jpeg" => (ArtifactKind::Image, "image/jpeg"),
+        "gif" => (ArtifactKind::Image, "image/gif"),
+        "webp" => (ArtifactKind::Image, "image/webp"),
+        "svg" => (ArtifactKind::Image, "image/svg+xml"),
+        "md" | "markdown" => (ArtifactKind::Document, "text/markdown"),
+        "txt" | "log" => (ArtifactKind::Document, "text/plain"),
+        "csv" => (ArtifactKind::Document, "text/csv"),
+        "html" | "htm" => (ArtifactKind::Document, "text/html"),
+        "pdf" => (ArtifactKind::Document, "application/pdf"),
+        "docx" => (
+            ArtifactKind::Document,
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        ),
+        "pptx" => (
+            ArtifactKind::Document,
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        ),
+        "xlsx" => (
+            ArtifactKind::Document,
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        ),
+        "json" => (ArtifactKind::Code, "application/json"),
+        "xml" => (ArtifactKind::Code, "application/xml"),
+        "rs" | "ts" | "tsx" | "js" | "jsx" | "mjs" | "py" | "go" | "java" | "kt" | "c" | "h"
+        | "cc" | "cpp" | "hpp" | "cs" | "rb" | "php" | "swift" | "sh" | "ps1" | "sql" | "css"
+        | "scss" | "vue" | "yaml" | "yml" | "toml" => (ArtifactKind::Code, "text/plain"),
+        _ => (ArtifactKind::Other, "application/octet-stream"),
+    };
+    (kind, mime_type.to_string())
+}
+
+fn is_text_mime(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || mime_type == "application/json"
+        || mime_type == "application/xml"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn service(root: &Path) -> Arc<ArtifactService> {
+        let storage = Arc::new(SqliteStorage::new(root.join("storage.sqlite")));
+        ArtifactService::with_root(root.join("artifacts"), storage)
+            .await
+            .unwrap()
+    }
+
+    fn request(session_id: &str, source: &Path) -> RegisterArtifactRequest {
+        RegisterArtifactRequest {
+            session_id: session_id.to_string(),
+            task_id: Some("turn-1".to_string()),
+            source_path: source.to_string_lossy().to_string(),
+            name: None,
+        }
+    }
+
+    #[test]
+    fn classify_maps_extensions_to_kinds() {
+        assert_eq!(classify("main.rs").0, ArtifactKind::Code);
+        assert_eq!(classify("report.PDF").0, ArtifactKind::Document);
+        assert_eq!(
+            classify("chart.png"),
+            (ArtifactKind::Image, "image/png".to_string())
+        );
+        assert_eq!(classify("blob.bin").0, ArtifactKind::Other);
+        assert_eq!(sanitize_file_name("../x/y.md"), "_x_y.md");
+    }
+
+    #[tokio::test]
+    async fn registered_artifacts_round_trip_and_preview() {
+        let root = tempfile::tempdir().unwrap();
+        let service = service(root.path()).await;
+        let source = root.path().join("notes.md");
+        tokio::fs::write(&source, "# Title").await.unwrap();
+
+        let record = service.register(request("s1", &source)).await.unwrap();
+        assert_eq!(record.kind, ArtifactKind::Document);
+        assert_eq!(record.task_id.as_deref(), Some("turn-1"));
+        assert!(Path::new(&record.path).starts_with(root.path().join("artifacts")));
+
+        assert_eq!(
+            service.list(Some("s1")).await.unwrap(),
+            vec![record.clone()]
+        );
+        assert!(service.list(Some("s2")).await.unwrap().is_empty());
+        assert_eq!(
+            service.preview(&record.id).await.unwrap(),
+            ArtifactPreview::Text {
+                content: "# Title".to_string(),
+                truncated: false,
+            }
+        );
+
+        let exported = service.export(&record.id, root.path()).await.unwrap();
+        assert_eq!(exported, root.path().join("notes.md"));
+        assert!(service.delete(&record.id).await.unwrap());
+        assert!(service.get(&record.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn garbage_collection_drops_orphans() {
+        let root = tempfile::tempdir().unwrap();
+        let service = service(root.path()).await;
+        let source = root.path().join("out.txt");
+        tokio::fs::write(&source, "data").await.unwrap();
+
+        let live = service.register(request("live", &source)).await.unwrap();
+        service.register(request("gone", &source)).await.unwrap();
+        let missing = service.register(request("live", &source)).await.unwrap();
+        tokio::fs::remove_file(&missing.path).await.unwrap();
+        tokio::fs::create_dir_all(root.path().join("artifacts").join("stray"))
+            .await
+            .unwrap();
+
+        let live_sessions = HashSet::from(["live".to_string()]);
+        let report = service.collect_garbage(Some(&live_sessions)).await.unwrap();
+
+        assert_eq!(report.removed_records, 2);
+        assert_eq!(report.removed_directories, 3);
+        assert_eq!(service.list(None).await.unwrap(), vec![live]);
+    }
+}
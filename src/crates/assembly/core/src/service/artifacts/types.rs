@@ -0,0 +1,88 @@
+//! Artifact record types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    Code,
+    Document,
+    Image,
+    Other,
+}
+
+impl ArtifactKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::Document => "document",
+            Self::Image => "image",
+            Self::Other => "other",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Self {
+        match value {
+            "code" => Self::Code,
+            "document" => Self::Document,
+            "image" => Self::Image,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A file registered by an agent task and stored under the managed
+/// artifacts directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactRecord {
+    pub id: String,
+    pub session_id: String,
+    /// Dialog turn or tool call that produced the file, when known.
+    pub task_id: Option<String>,
+    pub name: String,
+    pub kind: ArtifactKind,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    /// Absolute path of the managed copy.
+    pub path: String,
+    /// Where the file was produced before it was registered.
+    pub source_path: Option<String>,
+    pub created_at_ms: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterArtifactRequest {
+    pub session_id: String,
+    #[serde(default)]
+    pub task_id: Option<String>,
+    pub source_path: String,
+    /// Display name; defaults to the source file name.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ArtifactPreview {
+    Text {
+        content: String,
+        truncated: bool,
+    },
+    Image {
+        #[serde(rename = "dataUrl")]
+        data_url: String,
+    },
+    /// Binary or oversized content; open the file instead.
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactGcReport {
+    /// Records whose session is gone or whose managed copy is missing.
+    pub removed_records: usize,
+    /// Directories left on disk without a record.
+    pub removed_directories: usize,
+}
@@ -0,0 +1,12 @@
+//! Session attachment service
+//!
+//! Validated, session-scoped storage for dropped files and clipboard content.
+
+mod service;
+mod types;
+
+pub use service::{
+    get_global_attachment_service, set_global_attachment_service, AttachmentService,
+    MAX_FILE_ATTACHMENT_BYTES, MAX_IMAGE_ATTACHMENT_BYTES, MAX_TEXT_ATTACHMENT_BYTES,
+};
+pub use types::{AttachmentHandle, AttachmentKind, AttachmentOrigin};
@@ -0,0 +1,482 @@
+//! Session attachment store.
+//!
+//! Dropped files and clipboard content are copied into
+//! `<session_attachments_dir>/<session_id>/<attachment_id>/` next to an
+//! `attachment.json` handle, so the frontend passes ids instead of base64
+//! payloads and the agent reads the stored copy from disk.
+
+use super::types::{AttachmentHandle, AttachmentKind, AttachmentOrigin};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, warn};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::io::AsyncReadExt;
+
+static GLOBAL_ATTACHMENT_SERVICE: OnceLock<Arc<AttachmentService>> = OnceLock::new();
+
+pub const MAX_IMAGE_ATTACHMENT_BYTES: u64 = 20 * 1024 * 1024;
+pub const MAX_TEXT_ATTACHMENT_BYTES: u64 = 5 * 1024 * 1024;
+pub const MAX_FILE_ATTACHMENT_BYTES: u64 = 50 * 1024 * 1024;
+
+const METADATA_FILE: &str = "attachment.json";
+const SNIFF_BYTES: usize = 8 * 1024;
+const MAX_NAME_CHARS: usize = 128;
+
+pub struct AttachmentService {
+    root: PathBuf,
+}
+
+impl AttachmentService {
+    pub fn new(path_manager: &PathManager) -> Arc<Self> {
+        Self::with_root(path_manager.session_attachments_dir())
+    }
+
+    pub fn with_root(root: PathBuf) -> Arc<Self> {
+        Arc::new(Self { root })
+    }
+
+    /// Copies a dropped or pasted file into the session's attachment area.
+    pub async fn ingest_file(
+        &self,
+        session_id: &str,
+        source: &Path,
+        origin: AttachmentOrigin,
+    ) -> BitFunResult<AttachmentHandle> {
+        let metadata = tokio::fs::metadata(source).await.map_err(|e| {
+            BitFunError::NotFound(format!("Cannot read {}: {}", source.display(), e))
+        })?;
+        if metadata.is_dir() {
+            return Err(BitFunError::validation(format!(
+                "Folders cannot be attached: {}",
+                source.display()
+            )));
+        }
+
+        let name = source
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut head = Vec::with_capacity(SNIFF_BYTES);
+        tokio::fs::File::open(source)
+            .await?
+            .take(SNIFF_BYTES as u64)
+            .read_to_end(&mut head)
+            .await?;
+        let (kind, mime_type) = classify(&name, &head, metadata.len())?;
+
+        let (dir, stored) = self.prepare_slot(session_id, &name).await?;
+        let handle = AttachmentHandle {
+            id: slot_id(&dir),
+            session_id: session_id.to_string(),
+            kind,
+            origin,
+            name: file_name_of(&stored),
+            mime_type,
+            size_bytes: metadata.len(),
+            path: stored.to_string_lossy().to_string(),
+            source_path: Some(source.to_string_lossy().to_string()),
+            created_at_ms: Utc::now().timestamp_millis(),
+        };
+        let copied = tokio::fs::copy(source, &stored)
+            .await
+            .map_err(BitFunError::from);
+        self.commit_slot(&dir, handle, copied.map(|_| ())).await
+    }
+
+    /// Stores in-memory content such as a clipboard image.
+    pub async fn ingest_bytes(
+        &self,
+        session_id: &str,
+        name: &str,
+        bytes: &[u8],
+        origin: AttachmentOrigin,
+    ) -> BitFunResult<AttachmentHandle> {
+        let head = &bytes[..bytes.len().min(SNIFF_BYTES)];
+        let (kind, mime_type) = classify(name, head, bytes.len() as u64)?;
+
+        let (dir, stored) = self.prepare_slot(session_id, name).await?;
+        let handle = AttachmentHandle {
+            id: slot_id(&dir),
+            session_id: session_id.to_string(),
+            kind,
+            origin,
+            name: file_name_of(&stored),
+            mime_type,
+            size_bytes: bytes.len() as u64,
+            path: stored.to_string_lossy().to_string(),
+            source_path: None,
+            created_at_ms: Utc::now().timestamp_millis(),
+        };
+        let written = tokio::fs::write(&stored, bytes)
+            .await
+            .map_err(BitFunError::from);
+        self.commit_slot(&dir, handle, written).await
+    }
+
+    pub async fn ingest_text(
+        &self,
+        session_id: &str,
+        text: &str,
+        origin: AttachmentOrigin,
+    ) -> BitFunResult<AttachmentHandle> {
+        if text.trim().is_empty() {
+            return Err(BitFunError::validation("Cannot attach empty text"));
+        }
+        let name = format!("pasted-text-{}.txt", Utc::now().format("%Y%m%d-%H%M%S"));
+        self.ingest_bytes(session_id, &name, text.as_bytes(), origin)
+            .await
+    }
+
+    /// Oldest first.
+    pub async fn list(&self, session_id: &str) -> BitFunResult<Vec<AttachmentHandle>> {
+        let session_dir = self.session_dir(session_id)?;
+        let mut handles = Vec::new();
+        let mut entries = match tokio::fs::read_dir(&session_dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(handles),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            match read_handle(&entry.path()).await {
+                Ok(handle) => handles.push(handle),
+                Err(e) => warn!(
+                    "Skipping unreadable attachment: path={}, error={}",
+                    entry.path().display(),
+                    e
+                ),
+            }
+        }
+        handles.sort_by_key(|handle| handle.created_at_ms);
+        Ok(handles)
+    }
+
+    pub async fn get(
+        &self,
+        session_id: &str,
+        attachment_id: &str,
+    ) -> BitFunResult<AttachmentHandle> {
+        let dir = self.attachment_dir(session_id, attachment_id)?;
+        read_handle(&dir)
+            .await
+            .map_err(|_| BitFunError::NotFound(format!("Attachment not found: {}", attachment_id)))
+    }
+
+    pub async fn remove(&self, session_id: &str, attachment_id: &str) -> BitFunResult<bool> {
+        let dir = self.attachment_dir(session_id, attachment_id)?;
+        match tokio::fs::remove_dir_all(&dir).await {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Drops every attachment of a deleted session.
+    pub async fn clear_session(&self, session_id: &str) -> BitFunResult<()> {
+        let session_dir = self.session_dir(session_id)?;
+        match tokio::fs::remove_dir_all(&session_dir).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn session_dir(&self, session_id: &str) -> BitFunResult<PathBuf> {
+        validate_id(session_id, "session id")?;
+        Ok(self.root.join(session_id))
+    }
+
+    fn attachment_dir(&self, session_id: &str, attachment_id: &str) -> BitFunResult<PathBuf> {
+        validate_id(attachment_id, "attachment id")?;
+        Ok(self.session_dir(session_id)?.join(attachment_id))
+    }
+
+    async fn prepare_slot(&self, session_id: &str, name: &str) -> BitFunResult<(PathBuf, PathBuf)> {
+        let dir = self
+            .session_dir(session_id)?
+            .join(uuid::Uuid::new_v4().to_string());
+        tokio::fs::create_dir_all(&dir).await?;
+        let stored = dir.join(sanitize_file_name(name));
+        Ok((dir, stored))
+    }
+
+    async fn commit_slot(
+        &self,
+        dir: &Path,
+        handle: AttachmentHandle,
+        stored: BitFunResult<()>,
+    ) -> BitFunResult<AttachmentHandle> {
+        let result = match stored {
+            Ok(()) => match serde_json::to_vec_pretty(&handle) {
+                Ok(json) => tokio::fs::write(dir.join(METADATA_FILE), json)
+                    .await
+                    .map_err(BitFunError::from),
+                Err(e) => Err(e.into()),
+            },
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            let _ = tokio::fs::remove_dir_all(dir).await;
+            return Err(e);
+        }
+
+        debug!(
+            "Attachment stored: session_id={}, id={}, kind={:?}, mime={}, size={}",
+            handle.session_id, handle.id, handle.kind, handle.mime_type, handle.size_bytes
+        );
+        Ok(handle)
+    }
+}
+
+pub fn get_global_attachment_service() -> Option<Arc<AttachmentService>> {
+    GLOBAL_ATTACHMENT_SERVICE.get().cloned()
+}
+
+pub fn set_global_attachment_service(service: Arc<AttachmentService>) {
+    let _ = GLOBAL_ATTACHMENT_SERVICE.set(service);
+}
+
+async fn read_handle(dir: &Path) -> BitFunResult<AttachmentHandle> {
+    let json = tokio::fs::read(dir.join(METADATA_FILE)).await?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+fn slot_id(dir: &Path) -> String {
+    file_name_of(dir)
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Ids become directory names, so only plain identifier characters pass.
+fn validate_id(id: &str, label: &str) -> BitFunResult<()> {
+    let valid = !id.is_empty()
+        && id.len() <= 128
+        && id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BitFunError::validation(format!(
+            "Invalid {}: {}",
+            label, id
+        )))
+    }
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .trim()
+        .chars()
+        .map(|ch| match ch {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            ch if ch.is_control() => '_',
+            ch => ch,
+        })
+        .take(MAX_NAME_CHARS)
+        .collect();
+    let cleaned = cleaned.trim_matches(['.', ' ']);
+    if cleaned.is_empty() || cleaned == METADATA_FILE {
+        "attachment".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Determines kind and MIME type from content first and the name second, and
+/// enforces the per-kind size limit.
+fn classify(name: &str, head: &[u8], size: u64) -> BitFunResult<(AttachmentKind, String)> {
+    let extension = Path::new(name)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let mime_type = match sniff_binary_mime(head) {
+        Some(sniffed) => {
+            if let Some(expected) = image_mime_for_extension(&extension) {
+                if expected != sniffed {
+                    return Err(BitFunError::validation(format!(
+                        "{} does not contain {} data",
+                        name, expected
+                    )));
+                }
+            }
+            container_mime(sniffed, &extension).to_string()
+        }
+        None => {
+            if image_mime_for_extension(&extension).is_some() {
+                return Err(BitFunError::validation(format!(
+                    "{} is not a valid image",
+                    name
+                )));
+            }
+            if !looks_like_text(head) {
+                return Err(BitFunError::validation(format!(
+                    "Unsupported attachment type: {}",
+                    name
+                )));
+            }
+            text_mime_for_extension(&extension).to_string()
+        }
+    };
+
+    let kind = if mime_type.starts_with("image/") {
+        AttachmentKind::Image
+    } else if mime_type.starts_with("text/")
+        || mime_type == "application/json"
+        || mime_type == "application/xml"
+    {
+        AttachmentKind::Text
+    } else {
+        AttachmentKind::File
+    };
+    let limit = match kind {
+        AttachmentKind::Image => MAX_IMAGE_ATTACHMENT_BYTES,
+        AttachmentKind::Text => MAX_TEXT_ATTACHMENT_BYTES,
+        AttachmentKind::File => MAX_FILE_ATTACHMENT_BYTES,
+    };
+    if size > limit {
+        return Err(BitFunError::validation(format!(
+            "{} is {} bytes; {:?} attachments are limited to {} bytes",
+            name, size, kind, limit
+        )));
+    }
+    if size == 0 {
+        return Err(BitFunError::validation(format!("{} is empty", name)));
+    }
+
+    Ok((kind, mime_type))
+}
+
+fn sniff_binary_mime(head: &[u8]) -> Option<&'static str> {
+    if head.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if head.len() >= 12 && head.starts_with(b"RIFF") && &head[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if head.starts_with(b"BM") && head.len() >= 14 {
+        Some("image/bmp")
+    } else if head.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else if head.starts_with(b"PK\x03\x04") {
+        Some("application/zip")
+    } else {
+        None
+    }
+}
+
+/// Office documents are zip containers; the extension tells them apart.
+fn container_mime(sniffed: &'static str, extension: &str) -> &'static str {
+    if sniffed != "application/zip" {
+        return sniffed;
+    }
+    match extension {
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        _ => sniffed,
+    }
+}
+
+fn image_mime_for_extension(extension: &str) -> Option<&'static str> {
+    match extension {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "bmp" => Some("image/bmp"),
+        _ => None,
+    }
+}
+
+fn text_mime_for_extension(extension: &str) -> &'static str {
+    match extension {
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        _ => "text/plain",
+    }
+}
+
+fn looks_like_text(head: &[u8]) -> bool {
+    if head.contains(&0) {
+        return false;
+    }
+    match std::str::from_utf8(head) {
+        Ok(_) => true,
+        // The sniff window may cut a multi-byte character in half.
+        Err(e) => e.error_len().is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_trusts_content_over_extension() {
+        let png = b"\x89PNG\r\n\x1a\n0000";
+        assert_eq!(
+            classify("shot.png", png, 12).unwrap(),
+            (AttachmentKind::Image, "image/png".to_string())
+        );
+        assert!(classify("shot.jpg", png, 12).is_err());
+        assert!(classify("fake.png", b"hello", 5).is_err());
+
+        assert_eq!(
+            classify("notes.md", "# 标题".as_bytes(), 8).unwrap(),
+            (AttachmentKind::Text, "text/markdown".to_string())
+        );
+        assert!(classify("tool.exe", b"MZ\x90\x00\x03\x00", 6).is_err());
+    }
+
+    #[test]
+    fn classify_enforces_per_kind_limits() {
+        let too_big = MAX_TEXT_ATTACHMENT_BYTES + 1;
+        assert!(classify("log.txt", b"line", too_big).is_err());
+        assert!(classify("report.pdf", b"%PDF-1.7", too_big).is_ok());
+    }
+
+    #[test]
+    fn file_names_and_ids_cannot_escape_the_attachment_dir() {
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "_.._etc_passwd");
+        assert_eq!(sanitize_file_name(".."), "attachment");
+        assert_eq!(sanitize_file_name(METADATA_FILE), "attachment");
+        assert!(validate_id("../other", "session id").is_err());
+        assert!(validate_id("session_1-a", "session id").is_ok());
+    }
+
+    #[tokio::test]
+    async fn stored_attachments_round_trip_through_handles() {
+        let root =
+            std::env::temp_dir().join(format!("bitfun-attachments-test-{}", uuid::Uuid::new_v4()));
+        let service = AttachmentService::with_root(root.clone());
+
+        let text = service
+            .ingest_text("session-1", "hello", AttachmentOrigin::Clipboard)
+            .await
+            .unwrap();
+        assert_eq!(text.kind, AttachmentKind::Text);
+        assert_eq!(std::fs::read_to_string(&text.path).unwrap(), "hello");
+
+        let listed = service.list("session-1").await.unwrap();
+        assert_eq!(listed, vec![text.clone()]);
+        assert_eq!(service.get("session-1", &text.id).await.unwrap(), text);
+
+        assert!(service.remove("session-1", &text.id).await.unwrap());
+        assert!(service.list("session-1").await.unwrap().is_empty());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,37 @@
+//! Attachment handle types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentKind {
+    Image,
+    Text,
+    File,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentOrigin {
+    Drop,
+    Clipboard,
+}
+
+/// A stored attachment the chat and agent layers reference by `id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentHandle {
+    pub id: String,
+    pub session_id: String,
+    pub kind: AttachmentKind,
+    pub origin: AttachmentOrigin,
+    /// Display name, usually the original file name.
+    pub name: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    /// Absolute path of the stored copy.
+    pub path: String,
+    /// Where a dropped or pasted file came from.
+    pub source_path: Option<String>,
+    pub created_at_ms: i64,
+}
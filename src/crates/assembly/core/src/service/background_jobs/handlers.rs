@@ -0,0 +1,67 @@
+//! Job handlers.
+//!
+//! Core handles clean-up and agent tasks itself; hosts register handlers for
+//! kinds that depend on product services, such as the skill market.
+
+use super::types::BackgroundJobAction;
+use crate::agentic::task_queue::get_global_agent_task_service;
+use crate::infrastructure::storage::{get_disk_cache, CleanupPolicy, CleanupService};
+use crate::infrastructure::PathManager;
+use crate::service::i18n::{current_locale_formatter, localize, TranslationArgs};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait BackgroundJobHandler: Send + Sync {
+    /// Runs one occurrence of `action`. The returned summary is stored in the
+    /// job history.
+    async fn run(&self, action: &BackgroundJobAction) -> BitFunResult<Option<String>>;
+}
+
+pub(super) struct CleanupTempDirsHandler {
+    pub(super) path_manager: Arc<PathManager>,
+}
+
+#[async_trait]
+impl BackgroundJobHandler for CleanupTempDirsHandler {
+    async fn run(&self, action: &BackgroundJobAction) -> BitFunResult<Option<String>> {
+        let mut policy = CleanupPolicy::default();
+        if let BackgroundJobAction::CleanupTempDirs {
+            retention_days: Some(days),
+        } = action
+        {
+            policy.temp_retention_days = *days;
+        }
+        let result = CleanupService::new((*self.path_manager).clone(), policy)
+            .cleanup_all()
+            .await?;
+        let evicted = tokio::task::spawn_blocking(|| get_disk_cache().enforce_quotas())
+            .await
+            .map_err(|e| BitFunError::service(format!("Cache eviction task failed: {}", e)))??;
+        let formatter = current_locale_formatter().await;
+        let files = (result.files_deleted + evicted.files_deleted) as u64;
+        let args = TranslationArgs::new()
+            .with_string("files", formatter.number(files))
+            .with_string(
+                "size",
+                formatter.bytes(result.bytes_freed + evicted.bytes_freed),
+            );
+        Ok(Some(localize("cleanup-job-summary", Some(args)).await.text))
+    }
+}
+
+pub(super) struct RunAgentTaskHandler;
+
+#[async_trait]
+impl BackgroundJobHandler for RunAgentTaskHandler {
+    async fn run(&self, action: &BackgroundJobAction) -> BitFunResult<Option<String>> {
+        let BackgroundJobAction::RunAgentTask { task } = action else {
+            return Err(BitFunError::validation("Expected an agent task action"));
+        };
+        let service = get_global_agent_task_service()
+            .ok_or_else(|| BitFunError::service("Agent task service is not initialized"))?;
+        let queued = service.enqueue_task(task.clone()).await?;
+        Ok(Some(format!("Queued agent task {}", queued.id)))
+    }
+}
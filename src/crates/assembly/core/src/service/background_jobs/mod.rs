@@ -0,0 +1,18 @@
+//! Background job scheduler
+//!
+//! Recurring maintenance jobs (cache refreshes, update checks, temp clean-up,
+//! saved agent tasks) on cron-like schedules, persisted with their run
+//! history in the shared SQLite database.
+
+mod handlers;
+mod service;
+mod types;
+
+pub use handlers::BackgroundJobHandler;
+pub use service::{
+    get_global_background_job_service, set_global_background_job_service, BackgroundJobService,
+};
+pub use types::{
+    BackgroundJob, BackgroundJobAction, BackgroundJobKind, BackgroundJobRun,
+    BackgroundJobRunStatus, CreateBackgroundJobRequest, UpdateBackgroundJobRequest,
+};
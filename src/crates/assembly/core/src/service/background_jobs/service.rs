@@ -0,0 +1,644 @@
+//! Background job service.
+
+use super::handlers::{BackgroundJobHandler, CleanupTempDirsHandler, RunAgentTaskHandler};
+use super::types::{
+    BackgroundJob, BackgroundJobKind, BackgroundJobRun, BackgroundJobRunStatus,
+    CreateBackgroundJobRequest, UpdateBackgroundJobRequest,
+};
+use crate::infrastructure::storage::{SqliteMigration, SqliteStorage};
+use crate::infrastructure::PathManager;
+use crate::service::cron::{compute_next_run_after_ms, validate_schedule, CronSchedule};
+use crate::service::power::{wait_for_background_allowance, BackgroundWork};
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info, warn};
+use rusqlite::params;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{Mutex, Notify, RwLock};
+use tokio::time::Duration;
+
+static GLOBAL_BACKGROUND_JOB_SERVICE: OnceLock<Arc<BackgroundJobService>> = OnceLock::new();
+
+const STORAGE_COMPONENT: &str = "background_jobs";
+const MIGRATIONS: &[SqliteMigration] = &[SqliteMigration {
+    version: 1,
+    description: "background jobs and run history",
+    sql: r#"
+CREATE TABLE background_jobs (
+    id TEXT PRIMARY KEY,
+    definition TEXT NOT NULL,
+    updated_at_ms INTEGER NOT NULL
+);
+CREATE TABLE background_job_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_id TEXT NOT NULL,
+    manual INTEGER NOT NULL,
+    started_at_ms INTEGER NOT NULL,
+    finished_at_ms INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    message TEXT
+);
+CREATE INDEX background_job_runs_job ON background_job_runs(job_id, started_at_ms);
+"#,
+}];
+
+const JOB_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const MAX_RUNS_PER_JOB: i64 = 50;
+const MAX_JITTER_MS: u64 = 60 * 60 * 1000;
+const MESSAGE_MAX_CHARS: usize = 2000;
+
+pub struct BackgroundJobService {
+    storage: Arc<SqliteStorage>,
+    jobs: RwLock<HashMap<String, BackgroundJob>>,
+    handlers: std::sync::RwLock<HashMap<BackgroundJobKind, Arc<dyn BackgroundJobHandler>>>,
+    running: Mutex<HashSet<String>>,
+    mutation_lock: Mutex<()>,
+    wakeup: Notify,
+    runner_started: AtomicBool,
+}
+
+impl BackgroundJobService {
+    /// Loads persisted jobs and registers the built-in clean-up and agent
+    /// task handlers. Runs missed while the app was closed fire once on start.
+    pub async fn new(
+        path_manager: Arc<PathManager>,
+        storage: Arc<SqliteStorage>,
+    ) -> BitFunResult<Arc<Self>> {
+        storage.migrate(STORAGE_COMPONENT, MIGRATIONS).await?;
+        let definitions = storage
+            .with_connection(|connection| {
+                let mut statement = connection
+                    .prepare("SELECT definition FROM background_jobs")
+                    .map_err(db_error)?;
+                let rows = statement
+                    .query_map([], |row| row.get::<_, String>(0))
+                    .map_err(db_error)?;
+                rows.collect::<Result<Vec<_>, _>>().map_err(db_error)
+            })
+            .await?;
+
+        let mut jobs = HashMap::new();
+        for definition in definitions {
+            match serde_json::from_str::<BackgroundJob>(&definition) {
+                Ok(job) => {
+                    jobs.insert(job.id.clone(), job);
+                }
+                Err(e) => warn!("Skipping unreadable background job: {}", e),
+            }
+        }
+
+        let service = Arc::new(Self {
+            storage,
+            jobs: RwLock::new(jobs),
+            handlers: std::sync::RwLock::new(HashMap::new()),
+            running: Mutex::new(HashSet::new()),
+            mutation_lock: Mutex::new(()),
+            wakeup: Notify::new(),
+            runner_started: AtomicBool::new(false),
+        });
+        service.register_handler(
+            BackgroundJobKind::CleanupTempDirs,
+            Arc::new(CleanupTempDirsHandler { path_manager }),
+        );
+        service.register_handler(
+            BackgroundJobKind::RunAgentTask,
+            Arc::new(RunAgentTaskHandler),
+        );
+        Ok(service)
+    }
+
+    /// Replaces the handler for `kind`.
+    pub fn register_handler(
+        &self,
+        kind: BackgroundJobKind,
+        handler: Arc<dyn BackgroundJobHandler>,
+    ) {
+        if let Ok(mut handlers) = self.handlers.write() {
+            handlers.insert(kind, handler);
+        }
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self
+            .runner_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            service.run_loop().await;
+        });
+    }
+
+    /// Sorted by name.
+    pub async fn list_jobs(&self) -> Vec<BackgroundJob> {
+        let mut jobs = self.jobs.read().await.values().cloned().collect::<Vec<_>>();
+        jobs.sort_by(|left, right| left.name.cmp(&right.name).then(left.id.cmp(&right.id)));
+        jobs
+    }
+
+    pub async fn get_job(&self, job_id: &str) -> Option<BackgroundJob> {
+        self.jobs.read().await.get(job_id).cloned()
+    }
+
+    pub async fn create_job(
+        &self,
+        request: CreateBackgroundJobRequest,
+    ) -> BitFunResult<BackgroundJob> {
+        let _guard = self.mutation_lock.lock().await;
+        let current_ms = now_ms();
+        let mut job = BackgroundJob {
+            id: format!("bgjob_{}", uuid::Uuid::new_v4().simple()),
+            name: request.name.trim().to_string(),
+            action: request.action,
+            schedule: materialize_schedule(request.schedule, current_ms),
+            jitter_ms: request.jitter_ms,
+            enabled: request.enabled,
+            created_at_ms: current_ms,
+            updated_at_ms: current_ms,
+            next_run_at_ms: None,
+            last_run_at_ms: None,
+        };
+        validate_job(&job)?;
+        if job.enabled {
+            job.next_run_at_ms = next_run_after(&job, current_ms)?;
+        }
+
+        self.save_job(&job).await?;
+        self.jobs.write().await.insert(job.id.clone(), job.clone());
+        self.wakeup.notify_one();
+        Ok(job)
+    }
+
+    pub async fn update_job(
+        &self,
+        job_id: &str,
+        request: UpdateBackgroundJobRequest,
+    ) -> BitFunResult<BackgroundJob> {
+        let _guard = self.mutation_lock.lock().await;
+        let current_ms = now_ms();
+        let mut job = self.get_job(job_id).await.ok_or_else(|| {
+            BitFunError::NotFound(format!("Background job not found: {}", job_id))
+        })?;
+
+        if let Some(name) = request.name {
+            job.name = name.trim().to_string();
+        }
+        if let Some(action) = request.action {
+            job.action = action;
+        }
+        if let Some(schedule) = request.schedule {
+            job.schedule = materialize_schedule(schedule, current_ms);
+        }
+        if let Some(jitter_ms) = request.jitter_ms {
+            job.jitter_ms = jitter_ms;
+        }
+        if let Some(enabled) = request.enabled {
+            job.enabled = enabled;
+        }
+        validate_job(&job)?;
+        job.updated_at_ms = current_ms;
+        job.next_run_at_ms = if job.enabled {
+            next_run_after(&job, current_ms)?
+        } else {
+            None
+        };
+
+        self.save_job(&job).await?;
+        self.jobs.write().await.insert(job.id.clone(), job.clone());
+        self.wakeup.notify_one();
+        Ok(job)
+    }
+
+    /// Deletes the job and its run history.
+    pub async fn delete_job(&self, job_id: &str) -> BitFunResult<bool> {
+        let _guard = self.mutation_lock.lock().await;
+        let lookup_id = job_id.to_string();
+        self.storage
+            .with_transaction(move |transaction| {
+                transaction
+                    .execute(
+                        "DELETE FROM background_job_runs WHERE job_id = ?1",
+                        params![lookup_id],
+                    )
+                    .map_err(db_error)?;
+                transaction
+                    .execute(
+                        "DELETE FROM background_jobs WHERE id = ?1",
+                        params![lookup_id],
+                    )
+                    .map_err(db_error)?;
+                Ok(())
+            })
+            .await?;
+        let existed = self.jobs.write().await.remove(job_id).is_some();
+        self.wakeup.notify_one();
+        Ok(existed)
+    }
+
+    /// Runs the job immediately without moving its schedule.
+    pub async fn run_job_now(&self, job_id: &str) -> BitFunResult<BackgroundJobRun> {
+        let job = self.get_job(job_id).await.ok_or_else(|| {
+            BitFunError::NotFound(format!("Background job not found: {}", job_id))
+        })?;
+        self.execute(job, true).await
+    }
+
+    /// Most recent first; all jobs when `job_id` is `None`.
+    pub async fn list_runs(
+        &self,
+        job_id: Option<&str>,
+        limit: usize,
+    ) -> BitFunResult<Vec<BackgroundJobRun>> {
+        let job_id = job_id.map(str::to_string);
+        let limit = limit.clamp(1, 500) as i64;
+        self.storage
+            .with_connection(move |connection| {
+                let mut statement = connection
+                    .prepare(
+                        "SELECT id, job_id, manual, started_at_ms, finished_at_ms, status, message
+                         FROM background_job_runs
+                         WHERE ?1 IS NULL OR job_id = ?1
+                         ORDER BY started_at_ms DESC, id DESC
+                         LIMIT ?2",
+                    )
+                    .map_err(db_error)?;
+                let rows = statement
+                    .query_map(params![job_id, limit], |row| {
+                        Ok(BackgroundJobRun {
+                            id: row.get(0)?,
+                            job_id: row.get(1)?,
+                            manual: row.get(2)?,
+                            started_at_ms: row.get(3)?,
+                            finished_at_ms: row.get(4)?,
+                            status: BackgroundJobRunStatus::parse(&row.get::<_, String>(5)?),
+                            message: row.get(6)?,
+                        })
+                    })
+                    .map_err(db_error)?;
+                rows.collect::<Result<Vec<_>, _>>().map_err(db_error)
+            })
+            .await
+    }
+
+    async fn run_loop(self: Arc<Self>) {
+        info!("Background job scheduler started");
+
+        loop {
+            let next_wakeup = {
+                let jobs = self.jobs.read().await;
+                jobs.values()
+                    .filter(|job| job.enabled)
+                    .filter_map(|job| job.next_run_at_ms)
+                    .min()
+            };
+            match next_wakeup {
+                Some(next_wakeup_ms) => {
+                    let current_ms = now_ms();
+                    if next_wakeup_ms > current_ms {
+                        let sleep_ms = (next_wakeup_ms - current_ms) as u64;
+                        tokio::select! {
+                            _ = tokio::time::sleep(Duration::from_millis(sleep_ms)) => {}
+                            _ = self.wakeup.notified() => continue,
+                        }
+                    }
+                }
+                None => {
+                    self.wakeup.notified().await;
+                    continue;
+                }
+            }
+
+            wait_for_background_allowance(BackgroundWork::ScheduledJobs).await;
+            if let Err(e) = self.dispatch_due_jobs().await {
+                warn!("Failed to dispatch background jobs: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+
+    /// Advances each due job to its next occurrence before running it, so a
+    /// slow or failing job never fires twice for the same slot.
+    async fn dispatch_due_jobs(self: &Arc<Self>) -> BitFunResult<()> {
+        let _guard = self.mutation_lock.lock().await;
+        let current_ms = now_ms();
+        let due = {
+            let jobs = self.jobs.read().await;
+            jobs.values()
+                .filter(|job| job.enabled && job.next_run_at_ms.is_some_and(|at| at <= current_ms))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        for mut job in due {
+            job.next_run_at_ms = next_run_after(&job, current_ms)?;
+            if job.next_run_at_ms.is_none() {
+                // One-shot schedules run once.
+                job.enabled = false;
+            }
+            job.updated_at_ms = current_ms;
+            self.save_job(&job).await?;
+            self.jobs.write().await.insert(job.id.clone(), job.clone());
+
+            let service = Arc::clone(self);
+            tokio::spawn(async move {
+                if let Err(e) = service.execute(job, false).await {
+                    warn!("Background job run could not be recorded: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, job: BackgroundJob, manual: bool) -> BitFunResult<BackgroundJobRun> {
+        let started_at_ms = now_ms();
+        if !self.running.lock().await.insert(job.id.clone()) {
+            return self
+                .record_run(
+                    &job.id,
+                    manual,
+                    started_at_ms,
+                    BackgroundJobRunStatus::Skipped,
+                    Some("Previous run is still in progress".to_string()),
+                )
+                .await;
+        }
+
+        let handler = self
+            .handlers
+            .read()
+            .ok()
+            .and_then(|handlers| handlers.get(&job.action.kind()).cloned());
+        let (status, message) = match handler {
+            None => (
+                BackgroundJobRunStatus::Skipped,
+                Some(format!("No handler registered for {:?}", job.action.kind())),
+            ),
+            Some(handler) => {
+                debug!("Running background job: id={}, name={}", job.id, job.name);
+                match tokio::time::timeout(JOB_TIMEOUT, handler.run(&job.action)).await {
+                    Ok(Ok(summary)) => (BackgroundJobRunStatus::Succeeded, summary),
+                    Ok(Err(e)) => (BackgroundJobRunStatus::Failed, Some(e.to_string())),
+                    Err(_) => (
+                        BackgroundJobRunStatus::Failed,
+                        Some(format!("Timed out after {}s", JOB_TIMEOUT.as_secs())),
+                    ),
+                }
+            }
+        };
+        self.running.lock().await.remove(&job.id);
+
+        if status == BackgroundJobRunStatus::Failed {
+            warn!(
+                "Background job failed: id={}, name={}, error={}",
+                job.id,
+                job.name,
+                message.as_deref().unwrap_or_default()
+            );
+        }
+        let run = self
+            .record_run(&job.id, manual, started_at_ms, status, message)
+            .await?;
+
+        let _guard = self.mutation_lock.lock().await;
+        let updated = {
+            let mut jobs = self.jobs.write().await;
+            jobs.get_mut(&job.id).map(|stored| {
+                stored.last_run_at_ms = Some(started_at_ms);
+                stored.clone()
+            })
+        };
+        if let Some(updated) = updated {
+            self.save_job(&updated).await?;
+        }
+        Ok(run)
+    }
+
+    async fn record_run(
+        &self,
+        job_id: &str,
+        manual: bool,
+        started_at_ms: i64,
+        status: BackgroundJobRunStatus,
+        message: Option<String>,
+    ) -> BitFunResult<BackgroundJobRun> {
+        let mut run = BackgroundJobRun {
+            id: 0,
+            job_id: job_id.to_string(),
+            manual,
+            started_at_ms,
+            finished_at_ms: now_ms(),
+            status,
+            message: message.map(|message| message.chars().take(MESSAGE_MAX_CHARS).collect()),
+        };
+        let row = run.clone();
+        run.id = self
+            .storage
+            .with_transaction(move |transaction| {
+                transaction
+                    .execute(
+                        "INSERT INTO background_job_runs (job_id, manual, started_at_ms, finished_at_ms, status, message)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                        params![
+                            row.job_id,
+                            row.manual,
+                            row.started_at_ms,
+                            row.finished_at_ms,
+                            row.status.as_str(),
+                            row.message
+                        ],
+                    )
+                    .map_err(db_error)?;
+                let id = transaction.last_insert_rowid();
+                transaction
+                    .execute(
+                        "DELETE FROM background_job_runs
+                         WHERE job_id = ?1 AND id NOT IN (
+                             SELECT id FROM background_job_runs WHERE job_id = ?1
+                             ORDER BY started_at_ms DESC, id DESC LIMIT ?2
+                         )",
+                        params![row.job_id, MAX_RUNS_PER_JOB],
+                    )
+                    .map_err(db_error)?;
+                Ok(id)
+            })
+            .await?;
+        Ok(run)
+    }
+
+    async fn save_job(&self, job: &BackgroundJob) -> BitFunResult<()> {
+        let definition = serde_json::to_string(job)?;
+        let id = job.id.clone();
+        let updated_at_ms = job.updated_at_ms;
+        self.storage
+            .with_connection(move |connection| {
+                connection
+                    .execute(
+                        "INSERT INTO background_jobs (id, definition, updated_at_ms)
+                         VALUES (?1, ?2, ?3)
+                         ON CONFLICT(id) DO UPDATE SET
+                             definition = excluded.definition,
+                             updated_at_ms = excluded.updated_at_ms",
+                        params![id, definition, updated_at_ms],
+                    )
+                    .map_err(db_error)?;
+                Ok(())
+            })
+            .await
+    }
+}
+
+pub fn get_global_background_job_service() -> Option<Arc<BackgroundJobService>> {
+    GLOBAL_BACKGROUND_JOB_SERVICE.get().cloned()
+}
+
+pub fn set_global_background_job_service(service: Arc<BackgroundJobService>) {
+    let _ = GLOBAL_BACKGROUND_JOB_SERVICE.set(service);
+}
+
+fn validate_job(job: &BackgroundJob) -> BitFunResult<()> {
+    if job.name.is_empty() {
+        return Err(BitFunError::validation(
+            "Background job name must not be empty",
+        ));
+    }
+    if job.jitter_ms > MAX_JITTER_MS {
+        return Err(BitFunError::validation(format!(
+            "Background job jitter must not exceed {} ms",
+            MAX_JITTER_MS
+        )));
+    }
+    validate_schedule(&job.schedule, job.created_at_ms)
+}
+
+/// Next scheduled occurrence after `after_ms`, delayed by a random jitter.
+fn next_run_after(job: &BackgroundJob, after_ms: i64) -> BitFunResult<Option<i64>> {
+    let next = compute_next_run_after_ms(&job.schedule, job.created_at_ms, after_ms)?;
+    Ok(next.map(|at| at.saturating_add(jitter_offset_ms(job.jitter_ms))))
+}
+
+fn jitter_offset_ms(jitter_ms: u64) -> i64 {
+    if jitter_ms == 0 {
+        return 0;
+    }
+    (rand::random::<u64>() % (jitter_ms + 1)) as i64
+}
+
+fn materialize_schedule(schedule: CronSchedule, anchor_ms: i64) -> CronSchedule {
+    match schedule {
+        CronSchedule::Every {
+            every_ms,
+            anchor_ms: None,
+        } => CronSchedule::Every {
+            every_ms,
+            anchor_ms: Some(anchor_ms),
+        },
+        other => other,
+    }
+}
+
+fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+fn db_error(error: rusqlite::Error) -> BitFunError {
+    BitFunError::service(format!("Background job database error: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::background_jobs::BackgroundJobAction;
+    use async_trait::async_trait;
+
+    struct CountingHandler;
+
+    #[async_trait]
+    impl BackgroundJobHandler for CountingHandler {
+        async fn run(&self, _action: &BackgroundJobAction) -> BitFunResult<Option<String>> {
+            Ok(Some("refreshed".to_string()))
+        }
+    }
+
+    async fn service(root: &std::path::Path) -> Arc<BackgroundJobService> {
+        let storage = Arc::new(SqliteStorage::new(root.join("storage.sqlite")));
+        let path_manager = Arc::new(PathManager::with_user_root_for_tests(root.join("user")));
+        BackgroundJobService::new(path_manager, storage)
+            .await
+            .unwrap()
+    }
+
+    fn hourly(jitter_ms: u64) -> CreateBackgroundJobRequest {
+        CreateBackgroundJobRequest {
+            name: "Refresh market".to_string(),
+            action: BackgroundJobAction::RefreshSkillMarketCache,
+            schedule: CronSchedule::Every {
+                every_ms: 60 * 60 * 1000,
+                anchor_ms: None,
+            },
+            jitter_ms,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        assert_eq!(jitter_offset_ms(0), 0);
+        for _ in 0..100 {
+            assert!((0..=500).contains(&jitter_offset_ms(500)));
+        }
+    }
+
+    #[tokio::test]
+    async fn jobs_persist_across_restarts() {
+        let root = tempfile::tempdir().unwrap();
+        let created = service(root.path())
+            .await
+            .create_job(hourly(1_000))
+            .await
+            .unwrap();
+        let next = created.next_run_at_ms.unwrap();
+        assert!(next >= created.created_at_ms + 60 * 60 * 1000);
+        assert!(next <= created.created_at_ms + 60 * 60 * 1000 + 1_000);
+
+        let reloaded = service(root.path()).await;
+        let job = reloaded.get_job(&created.id).await.unwrap();
+        assert_eq!(job.next_run_at_ms, Some(next));
+        assert!(reloaded.delete_job(&created.id).await.unwrap());
+        assert!(service(root.path()).await.list_jobs().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn manual_runs_are_recorded_in_history() {
+        let root = tempfile::tempdir().unwrap();
+        let service = service(root.path()).await;
+        let job = service.create_job(hourly(0)).await.unwrap();
+
+        let skipped = service.run_job_now(&job.id).await.unwrap();
+        assert_eq!(skipped.status, BackgroundJobRunStatus::Skipped);
+
+        service.register_handler(
+            BackgroundJobKind::RefreshSkillMarketCache,
+            Arc::new(CountingHandler),
+        );
+        let succeeded = service.run_job_now(&job.id).await.unwrap();
+        assert_eq!(succeeded.status, BackgroundJobRunStatus::Succeeded);
+        assert_eq!(succeeded.message.as_deref(), Some("refreshed"));
+
+        let runs = service.list_runs(Some(&job.id), 10).await.unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].id, succeeded.id);
+        assert!(runs.iter().all(|run| run.manual));
+        assert!(service
+            .get_job(&job.id)
+            .await
+            .unwrap()
+            .last_run_at_ms
+            .is_some());
+    }
+}
@@ -0,0 +1,130 @@
+//! Background job data types.
+
+use crate::agentic::task_queue::EnqueueAgentTaskRequest;
+use crate::service::cron::CronSchedule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundJobKind {
+    RefreshSkillMarketCache,
+    CheckUpdates,
+    CleanupTempDirs,
+    RunAgentTask,
+}
+
+/// What a job does when it fires. Kinds without a registered handler are
+/// recorded as skipped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackgroundJobAction {
+    RefreshSkillMarketCache,
+    CheckUpdates,
+    CleanupTempDirs {
+        /// Overrides the default temp retention.
+        #[serde(
+            default,
+            rename = "retentionDays",
+            skip_serializing_if = "Option::is_none"
+        )]
+        retention_days: Option<u64>,
+    },
+    RunAgentTask {
+        task: EnqueueAgentTaskRequest,
+    },
+}
+
+impl BackgroundJobAction {
+    pub fn kind(&self) -> BackgroundJobKind {
+        match self {
+            Self::RefreshSkillMarketCache => BackgroundJobKind::RefreshSkillMarketCache,
+            Self::CheckUpdates => BackgroundJobKind::CheckUpdates,
+            Self::CleanupTempDirs { .. } => BackgroundJobKind::CleanupTempDirs,
+            Self::RunAgentTask { .. } => BackgroundJobKind::RunAgentTask,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundJob {
+    pub id: String,
+    pub name: String,
+    pub action: BackgroundJobAction,
+    pub schedule: CronSchedule,
+    /// Each run is delayed by a random amount up to this many milliseconds.
+    #[serde(default)]
+    pub jitter_ms: u64,
+    pub enabled: bool,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+    #[serde(default)]
+    pub next_run_at_ms: Option<i64>,
+    #[serde(default)]
+    pub last_run_at_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundJobRunStatus {
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+impl BackgroundJobRunStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Self {
+        match value {
+            "succeeded" => Self::Succeeded,
+            "failed" => Self::Failed,
+            _ => Self::Skipped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundJobRun {
+    pub id: i64,
+    pub job_id: String,
+    /// `true` when started from `run_job_now` rather than the schedule.
+    pub manual: bool,
+    pub started_at_ms: i64,
+    pub finished_at_ms: i64,
+    pub status: BackgroundJobRunStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBackgroundJobRequest {
+    pub name: String,
+    pub action: BackgroundJobAction,
+    pub schedule: CronSchedule,
+    #[serde(default)]
+    pub jitter_ms: u64,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBackgroundJobRequest {
+    pub name: Option<String>,
+    pub action: Option<BackgroundJobAction>,
+    pub schedule: Option<CronSchedule>,
+    pub jitter_ms: Option<u64>,
+    pub enabled: Option<bool>,
+}
+
+const fn default_enabled() -> bool {
+    true
+}
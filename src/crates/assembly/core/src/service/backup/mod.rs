@@ -0,0 +1,16 @@
+//! User data backup
+//!
+//! Timestamped zip archives of config, skills, session history and the
+//! subscription keychain references, for moving to another machine or as a
+//! safety net before updates. Secrets never enter an archive: JSON values that
+//! look like credentials are blanked and listed in the manifest, and restore
+//! keeps whatever the current install holds for them.
+
+mod service;
+mod types;
+
+pub use service::{BackupRoots, BackupService};
+pub use types::{
+    BackupFileEntry, BackupManifest, BackupSection, BackupSummary, RestoreBackupResult,
+    SecretReference,
+};
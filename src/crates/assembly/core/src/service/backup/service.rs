@@ -0,0 +1,552 @@
+//! Backup archive creation and restore.
+
+use super::types::{
+    BackupFileEntry, BackupManifest, BackupSection, BackupSummary, RestoreBackupResult,
+    SecretReference,
+};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::secret_redaction::strip_secrets;
+use chrono::{Local, Utc};
+use log::{info, warn};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use zip::write::SimpleFileOptions;
+
+const FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+const FILES_PREFIX: &str = "files";
+/// The subscription store keeps only metadata and keychain references.
+const SUBSCRIPTION_AUTH_FILE: &str = "subscription_auth.json";
+
+/// Directories a backup reads from and restores into.
+#[derive(Debug, Clone)]
+pub struct BackupRoots {
+    pub config_dir: PathBuf,
+    pub agents_dir: PathBuf,
+    pub rules_dir: PathBuf,
+    pub skills_dir: PathBuf,
+    pub projects_dir: PathBuf,
+    pub data_dir: PathBuf,
+}
+
+impl BackupRoots {
+    pub fn from_path_manager(path_manager: &PathManager) -> Self {
+        Self {
+            config_dir: path_manager.user_config_dir(),
+            agents_dir: path_manager.user_agents_dir(),
+            rules_dir: path_manager.user_rules_dir(),
+            skills_dir: path_manager.user_skills_dir(),
+            projects_dir: path_manager.projects_root(),
+            data_dir: path_manager.user_data_dir(),
+        }
+    }
+
+    /// Archive prefix and directory of every source that makes up `section`.
+    fn sources(&self, section: BackupSection) -> Vec<(&'static str, &Path)> {
+        match section {
+            BackupSection::Config => vec![
+                ("config", self.config_dir.as_path()),
+                ("agents", self.agents_dir.as_path()),
+                ("rules", self.rules_dir.as_path()),
+            ],
+            BackupSection::Skills => vec![("skills", self.skills_dir.as_path())],
+            BackupSection::Sessions => vec![("projects", self.projects_dir.as_path())],
+            BackupSection::Credentials => vec![("credentials", self.data_dir.as_path())],
+        }
+    }
+}
+
+/// Whether a file under a section source belongs in the backup.
+fn includes(section: BackupSection, relative: &[&str]) -> bool {
+    match section {
+        // Config backups hold API keys in plain text.
+        BackupSection::Config => relative.first() != Some(&"backups"),
+        BackupSection::Skills => true,
+        // `<workspace-slug>/sessions/...` and `<workspace-slug>/plans/...`;
+        // snapshots and indexes can be rebuilt.
+        BackupSection::Sessions => {
+            relative.len() > 2 && matches!(relative[1], "sessions" | "plans")
+        }
+        BackupSection::Credentials => relative == [SUBSCRIPTION_AUTH_FILE],
+    }
+}
+
+pub struct BackupService {
+    roots: BackupRoots,
+}
+
+impl BackupService {
+    pub fn new(roots: BackupRoots) -> Self {
+        Self { roots }
+    }
+
+    /// Writes `bitfun-backup-<timestamp>.zip` into `destination_dir`. Secrets
+    /// found in JSON files are blanked and listed in the manifest instead.
+    pub fn create_backup(
+        &self,
+        destination_dir: &Path,
+        sections: &[BackupSection],
+    ) -> BitFunResult<BackupSummary> {
+        let sections: Vec<BackupSection> = normalized_sections(sections);
+        fs::create_dir_all(destination_dir)?;
+        let created_at_ms = Utc::now().timestamp_millis();
+        let archive_path = destination_dir.join(format!(
+            "bitfun-backup-{}.zip",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let partial_path = archive_path.with_extension("zip.partial");
+
+        let result = self.write_archive(&partial_path, &sections, created_at_ms);
+        let manifest = match result {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                let _ = fs::remove_file(&partial_path);
+                return Err(error);
+            }
+        };
+        fs::rename(&partial_path, &archive_path)?;
+
+        info!(
+            "Backup created: path={}, files={}",
+            archive_path.display(),
+            manifest.files.len()
+        );
+        Ok(BackupSummary {
+            archive_path,
+            created_at_ms,
+            sections,
+            file_count: manifest.files.len(),
+            total_bytes: manifest.files.iter().map(|file| file.size).sum(),
+            redacted_secrets: manifest.secret_references.len(),
+        })
+    }
+
+    /// Reads the manifest without verifying file contents.
+    pub fn read_manifest(archive_path: &Path) -> BitFunResult<BackupManifest> {
+        let mut archive = open_archive(archive_path)?;
+        read_manifest_entry(&mut archive)
+    }
+
+    /// Restores `sections` (all archived ones when `None`) over the current
+    /// data. Every file is checked against the manifest before anything is
+    /// written. Secrets blanked in the archive keep their current values.
+    pub fn restore_backup(
+        &self,
+        archive_path: &Path,
+        sections: Option<&[BackupSection]>,
+    ) -> BitFunResult<RestoreBackupResult> {
+        let mut archive = open_archive(archive_path)?;
+        let manifest = read_manifest_entry(&mut archive)?;
+        if manifest.format_version > FORMAT_VERSION {
+            return Err(BitFunError::validation(format!(
+                "Backup format {} is newer than this version supports",
+                manifest.format_version
+            )));
+        }
+        let sections = match sections {
+            Some(requested) => {
+                let requested = normalized_sections(requested);
+                if let Some(missing) = requested
+                    .iter()
+                    .find(|section| !manifest.sections.contains(section))
+                {
+                    return Err(BitFunError::validation(format!(
+                        "Backup does not contain section {:?}",
+                        missing
+                    )));
+                }
+                requested
+            }
+            None => manifest.sections.clone(),
+        };
+
+        let files: Vec<&BackupFileEntry> = manifest
+            .files
+            .iter()
+            .filter(|file| sections.contains(&file.section))
+            .collect();
+        let mut targets = Vec::with_capacity(files.len());
+        for file in &files {
+            targets.push(self.restore_target(file)?);
+            let bytes = read_entry(&mut archive, &file.path)?;
+            if bytes.len() as u64 != file.size || sha256_hex(&bytes) != file.sha256 {
+                return Err(BitFunError::validation(format!(
+                    "Backup is corrupted: {} does not match its checksum",
+                    file.path
+                )));
+            }
+        }
+
+        let mut secrets_by_path: HashMap<&str, Vec<&SecretReference>> = HashMap::new();
+        for reference in &manifest.secret_references {
+            secrets_by_path
+                .entry(reference.path.as_str())
+                .or_default()
+                .push(reference);
+        }
+        let mut secrets_to_reenter = Vec::new();
+        for (file, target) in files.iter().zip(targets) {
+            let mut bytes = read_entry(&mut archive, &file.path)?;
+            if let Some(references) = secrets_by_path.get(file.path.as_str()) {
+                bytes = restore_secrets(&bytes, &target, references, &mut secrets_to_reenter)?;
+            }
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, bytes)?;
+        }
+
+        info!(
+            "Backup restored: path={}, files={}",
+            archive_path.display(),
+            files.len()
+        );
+        Ok(RestoreBackupResult {
+            sections,
+            restored_files: files.len(),
+            secrets_to_reenter,
+            restart_required: true,
+        })
+    }
+
+    fn write_archive(
+        &self,
+        path: &Path,
+        sections: &[BackupSection],
+        created_at_ms: i64,
+    ) -> BitFunResult<BackupManifest> {
+        let mut zip = zip::ZipWriter::new(File::create(path)?);
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let mut manifest = BackupManifest {
+            format_version: FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at_ms,
+            sections: sections.to_vec(),
+            files: Vec::new(),
+            secret_references: Vec::new(),
+        };
+
+        for &section in sections {
+            for (prefix, root) in self.roots.sources(section) {
+                let mut files = Vec::new();
+                collect_files(root, &mut Vec::new(), &mut files)?;
+                for relative in files {
+                    let parts: Vec<&str> = relative.iter().map(String::as_str).collect();
+                    if !includes(section, &parts) {
+                        continue;
+                    }
+                    let archive_name =
+                        format!("{}/{}/{}", FILES_PREFIX, prefix, relative.join("/"));
+                    let mut bytes = fs::read(root.join(relative.iter().collect::<PathBuf>()))?;
+                    if is_json(&archive_name) {
+                        bytes =
+                            redact_json_file(bytes, &archive_name, &mut manifest.secret_references);
+                    }
+                    zip.start_file(archive_name.as_str(), options)
+                        .map_err(archive_error)?;
+                    zip.write_all(&bytes)?;
+                    manifest.files.push(BackupFileEntry {
+                        sha256: sha256_hex(&bytes),
+                        size: bytes.len() as u64,
+                        path: archive_name,
+                        section,
+                    });
+                }
+            }
+        }
+
+        zip.start_file(MANIFEST_FILE, options)
+            .map_err(archive_error)?;
+        zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+        zip.finish().map_err(archive_error)?;
+        Ok(manifest)
+    }
+
+    /// Maps an archive path back to where it is restored, refusing anything
+    /// that would land outside the section's directories.
+    fn restore_target(&self, file: &BackupFileEntry) -> BitFunResult<PathBuf> {
+        let unsafe_path =
+            || BitFunError::validation(format!("Backup contains an unsafe path: {}", file.path));
+        let mut parts = file.path.split('/');
+        if parts.next() != Some(FILES_PREFIX) {
+            return Err(unsafe_path());
+        }
+        let prefix = parts.next().ok_or_else(unsafe_path)?;
+        let relative: Vec<&str> = parts.collect();
+        if relative.is_empty()
+            || !relative.iter().all(|part| is_plain_name(part))
+            || !includes(file.section, &relative)
+        {
+            return Err(unsafe_path());
+        }
+        let (_, root) = self
+            .roots
+            .sources(file.section)
+            .into_iter()
+            .find(|(source_prefix, _)| *source_prefix == prefix)
+            .ok_or_else(unsafe_path)?;
+        Ok(relative
+            .iter()
+            .fold(root.to_path_buf(), |path, part| path.join(part)))
+    }
+}
+
+fn is_plain_name(part: &str) -> bool {
+    let mut components = Path::new(part).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    ) && !part.contains(['/', '\\'])
+}
+
+fn normalized_sections(sections: &[BackupSection]) -> Vec<BackupSection> {
+    if sections.is_empty() {
+        return BackupSection::ALL.to_vec();
+    }
+    sections
+        .iter()
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn collect_files(
+    dir: &Path,
+    prefix: &mut Vec<String>,
+    files: &mut Vec<Vec<String>>,
+) -> BitFunResult<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            warn!(
+                "Skipping non UTF-8 path in backup: {}",
+                entry.path().display()
+            );
+            continue;
+        };
+        prefix.push(name);
+        if file_type.is_dir() {
+            collect_files(&entry.path(), prefix, files)?;
+        } else if file_type.is_file() {
+            files.push(prefix.clone());
+        }
+        prefix.pop();
+    }
+    Ok(())
+}
+
+fn is_json(name: &str) -> bool {
+    name.rsplit('.').next() == Some("json")
+}
+
+/// Blanks secrets in a JSON file. Files that do not parse are kept as is.
+fn redact_json_file(
+    bytes: Vec<u8>,
+    archive_name: &str,
+    references: &mut Vec<SecretReference>,
+) -> Vec<u8> {
+    let Ok(mut value) = serde_json::from_slice::<Value>(&bytes) else {
+        return bytes;
+    };
+    let pointers = strip_secrets(&mut value);
+    if pointers.is_empty() {
+        return bytes;
+    }
+    references.extend(pointers.into_iter().map(|pointer| SecretReference {
+        path: archive_name.to_string(),
+        pointer,
+    }));
+    serde_json::to_vec_pretty(&value).unwrap_or(bytes)
+}
+
+/// Fills blanked secrets from the file about to be replaced.
+fn restore_secrets(
+    bytes: &[u8],
+    target: &Path,
+    references: &[&SecretReference],
+    secrets_to_reenter: &mut Vec<SecretReference>,
+) -> BitFunResult<Vec<u8>> {
+    let mut restored: Value = serde_json::from_slice(bytes)?;
+    let current: Option<Value> = fs::read(target)
+        .ok()
+        .and_then(|current| serde_json::from_slice(&current).ok());
+    for reference in references {
+        let secret = current
+            .as_ref()
+            .and_then(|current| current.pointer(&reference.pointer))
+            .and_then(Value::as_str)
+            .filter(|secret| !secret.is_empty());
+        match (secret, restored.pointer_mut(&reference.pointer)) {
+            (Some(secret), Some(slot)) => *slot = Value::String(secret.to_string()),
+            _ => secrets_to_reenter.push((*reference).clone()),
+        }
+    }
+    Ok(serde_json::to_vec_pretty(&restored)?)
+}
+
+fn open_archive(path: &Path) -> BitFunResult<zip::ZipArchive<File>> {
+    zip::ZipArchive::new(File::open(path)?).map_err(|error| {
+        BitFunError::validation(format!(
+            "Not a BitFun backup: {}: {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
+fn read_manifest_entry(archive: &mut zip::ZipArchive<File>) -> BitFunResult<BackupManifest> {
+    let bytes = read_entry(archive, MANIFEST_FILE)?;
+    serde_json::from_slice(&bytes)
+        .map_err(|error| BitFunError::validation(format!("Invalid backup manifest: {}", error)))
+}
+
+fn read_entry(archive: &mut zip::ZipArchive<File>, name: &str) -> BitFunResult<Vec<u8>> {
+    let mut entry = archive.by_name(name).map_err(|error| {
+        BitFunError::validation(format!("Backup entry {} is missing: {}", name, error))
+    })?;
+    let mut bytes = Vec::with_capacity(entry.size() as usize);
+    entry.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn archive_error(error: zip::result::ZipError) -> BitFunError {
+    BitFunError::io(format!("Failed to write backup archive: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn roots(base: &Path) -> BackupRoots {
+        BackupRoots {
+            config_dir: base.join("config"),
+            agents_dir: base.join("agents"),
+            rules_dir: base.join("data").join("rules"),
+            skills_dir: base.join("skills"),
+            projects_dir: base.join("projects"),
+            data_dir: base.join("data"),
+        }
+    }
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn app_config(api_key: &str) -> String {
+        json!({
+            "ai": { "models": [{ "id": "m1", "api_key": api_key, "max_tokens": 100 }] },
+            "mcp_servers": { "github": { "env": { "GITHUB_TOKEN": "ghp-1", "LOG": "1" } } }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn backups_leave_out_secrets_and_restore_keeps_current_ones() {
+        let base = std::env::temp_dir().join(format!("bitfun-backup-{}", uuid::Uuid::new_v4()));
+        let source = roots(&base.join("source"));
+        write(&source.config_dir.join("app.json"), &app_config("sk-live"));
+        write(&source.config_dir.join("backups").join("old.json"), "{}");
+        write(&source.skills_dir.join("demo").join("SKILL.md"), "# Demo");
+        write(
+            &source
+                .projects_dir
+                .join("ws")
+                .join("sessions")
+                .join("s1.json"),
+            "{}",
+        );
+        write(
+            &source.projects_dir.join("ws").join("snapshots").join("x"),
+            "big",
+        );
+        write(&source.data_dir.join(SUBSCRIPTION_AUTH_FILE), "{}");
+
+        let summary = BackupService::new(source)
+            .create_backup(&base.join("out"), &[])
+            .unwrap();
+        assert_eq!(summary.file_count, 4);
+        assert_eq!(summary.redacted_secrets, 2);
+        let manifest = BackupService::read_manifest(&summary.archive_path).unwrap();
+        assert!(manifest
+            .secret_references
+            .iter()
+            .any(|reference| reference.pointer == "/ai/models/0/api_key"));
+        let mut archive = open_archive(&summary.archive_path).unwrap();
+        let archived = read_entry(&mut archive, "files/config/app.json").unwrap();
+        assert!(!String::from_utf8(archived).unwrap().contains("sk-live"));
+
+        let target = roots(&base.join("target"));
+        write(
+            &target.config_dir.join("app.json"),
+            &app_config("sk-current"),
+        );
+        let result = BackupService::new(target.clone())
+            .restore_backup(&summary.archive_path, Some(&[BackupSection::Config]))
+            .unwrap();
+        assert_eq!(result.restored_files, 1);
+        assert_eq!(result.secrets_to_reenter, Vec::new());
+        let restored: Value =
+            serde_json::from_slice(&fs::read(target.config_dir.join("app.json")).unwrap()).unwrap();
+        assert_eq!(restored["ai"]["models"][0]["api_key"], "sk-current");
+        assert_eq!(
+            restored["mcp_servers"]["github"]["env"]["GITHUB_TOKEN"],
+            "ghp-1"
+        );
+        assert!(!target.skills_dir.exists());
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn tampered_archives_are_rejected_before_writing() {
+        let base = std::env::temp_dir().join(format!("bitfun-backup-{}", uuid::Uuid::new_v4()));
+        let source = roots(&base.join("source"));
+        write(&source.skills_dir.join("demo").join("SKILL.md"), "# Demo");
+        let summary = BackupService::new(source)
+            .create_backup(&base.join("out"), &[BackupSection::Skills])
+            .unwrap();
+
+        let mut manifest = BackupService::read_manifest(&summary.archive_path).unwrap();
+        manifest.files[0].sha256 = sha256_hex(b"something else");
+        let tampered = base.join("tampered.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&tampered).unwrap());
+        zip.start_file(MANIFEST_FILE, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&serde_json::to_vec(&manifest).unwrap())
+            .unwrap();
+        zip.start_file(
+            manifest.files[0].path.as_str(),
+            SimpleFileOptions::default(),
+        )
+        .unwrap();
+        zip.write_all(b"# Demo").unwrap();
+        zip.finish().unwrap();
+
+        let target = roots(&base.join("target"));
+        assert!(BackupService::new(target.clone())
+            .restore_backup(&tampered, None)
+            .is_err());
+        assert!(!target.skills_dir.exists());
+
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -0,0 +1,82 @@
+//! Backup archive types.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupSection {
+    /// `app.json` (models, MCP servers, preferences), custom agents and rules.
+    Config,
+    Skills,
+    /// Session history and plans of every workspace.
+    Sessions,
+    /// Subscription account metadata with the keychain entries it points to.
+    Credentials,
+}
+
+impl BackupSection {
+    pub const ALL: [Self; 4] = [
+        Self::Config,
+        Self::Skills,
+        Self::Sessions,
+        Self::Credentials,
+    ];
+}
+
+/// `manifest.json` at the archive root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub app_version: String,
+    pub created_at_ms: i64,
+    pub sections: Vec<BackupSection>,
+    pub files: Vec<BackupFileEntry>,
+    /// Secrets left out of the archive; they stay in place on restore or have
+    /// to be entered again.
+    pub secret_references: Vec<SecretReference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupFileEntry {
+    /// Path inside the archive, `/`-separated.
+    pub path: String,
+    pub section: BackupSection,
+    pub size: u64,
+    /// Hex SHA-256 of the archived bytes.
+    pub sha256: String,
+}
+
+/// A secret value blanked in an archived JSON file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretReference {
+    /// Archive path of the file.
+    pub path: String,
+    /// JSON pointer of the value within the file.
+    pub pointer: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    pub archive_path: PathBuf,
+    pub created_at_ms: i64,
+    pub sections: Vec<BackupSection>,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub redacted_secrets: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupResult {
+    pub sections: Vec<BackupSection>,
+    pub restored_files: usize,
+    /// Redacted secrets with no current value to keep.
+    pub secrets_to_reenter: Vec<SecretReference>,
+    /// Running services still hold the previous data until the app restarts.
+    pub restart_required: bool,
+}
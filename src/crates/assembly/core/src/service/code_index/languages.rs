@@ -0,0 +1,544 @@
+//! Per-language tree-sitter grammars and the node kinds that define symbols.
+//!
+//! Extraction walks the syntax tree directly instead of running queries, so
+//! each language is just a table of node kinds. Function bodies are not
+//! descended into; local helpers and closures stay out of the index.
+
+use super::types::{CodeLanguage, CodeSymbol, SymbolKind, SyntaxError};
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser};
+
+const MAX_SIGNATURE_CHARS: usize = 200;
+const MAX_SYNTAX_ERRORS: usize = 20;
+
+struct SymbolRule {
+    node_kind: &'static str,
+    kind: SymbolKind,
+    /// Field holding the name. `declarator` fields are followed down to the
+    /// innermost identifier, as C declarators nest.
+    name_field: &'static str,
+    /// Only match when this field is present, e.g. a C struct with a body
+    /// rather than a `struct foo *` reference.
+    required_field: Option<&'static str>,
+}
+
+const fn rule(node_kind: &'static str, kind: SymbolKind, name_field: &'static str) -> SymbolRule {
+    SymbolRule {
+        node_kind,
+        kind,
+        name_field,
+        required_field: None,
+    }
+}
+
+const fn rule_with(
+    node_kind: &'static str,
+    kind: SymbolKind,
+    name_field: &'static str,
+    required_field: &'static str,
+) -> SymbolRule {
+    SymbolRule {
+        node_kind,
+        kind,
+        name_field,
+        required_field: Some(required_field),
+    }
+}
+
+struct LanguageSpec {
+    symbols: &'static [SymbolRule],
+    /// Nodes that are not symbols themselves but name the members inside
+    /// them, such as Rust `impl` blocks: `(node kind, name field)`.
+    containers: &'static [(&'static str, &'static str)],
+    imports: &'static [&'static str],
+}
+
+use SymbolKind::*;
+
+const RUST: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_item", Function, "name"),
+        rule("function_signature_item", Function, "name"),
+        rule("struct_item", Struct, "name"),
+        rule("union_item", Struct, "name"),
+        rule("enum_item", Enum, "name"),
+        rule("trait_item", Trait, "name"),
+        rule("type_item", TypeAlias, "name"),
+        rule("const_item", Constant, "name"),
+        rule("static_item", Constant, "name"),
+        rule("mod_item", Module, "name"),
+    ],
+    containers: &[("impl_item", "type")],
+    imports: &["use_declaration", "extern_crate_declaration"],
+};
+
+const PYTHON: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_definition", Function, "name"),
+        rule("class_definition", Class, "name"),
+    ],
+    containers: &[],
+    imports: &["import_statement", "import_from_statement"],
+};
+
+const JAVASCRIPT: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_declaration", Function, "name"),
+        rule("generator_function_declaration", Function, "name"),
+        rule("class_declaration", Class, "name"),
+        rule("method_definition", Method, "name"),
+        rule_with("variable_declarator", Function, "name", "value"),
+    ],
+    containers: &[],
+    imports: &["import_statement"],
+};
+
+const TYPESCRIPT: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_declaration", Function, "name"),
+        rule("generator_function_declaration", Function, "name"),
+        rule("function_signature", Function, "name"),
+        rule("class_declaration", Class, "name"),
+        rule("abstract_class_declaration", Class, "name"),
+        rule("method_definition", Method, "name"),
+        rule("method_signature", Method, "name"),
+        rule("abstract_method_signature", Method, "name"),
+        rule("interface_declaration", Interface, "name"),
+        rule("type_alias_declaration", TypeAlias, "name"),
+        rule("enum_declaration", Enum, "name"),
+        rule("internal_module", Module, "name"),
+        rule_with("variable_declarator", Function, "name", "value"),
+    ],
+    containers: &[],
+    imports: &["import_statement"],
+};
+
+const GO: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_declaration", Function, "name"),
+        rule("method_declaration", Method, "name"),
+        rule("type_spec", TypeAlias, "name"),
+        rule("type_alias", TypeAlias, "name"),
+        rule("const_spec", Constant, "name"),
+    ],
+    containers: &[],
+    imports: &["import_spec"],
+};
+
+const JAVA: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("class_declaration", Class, "name"),
+        rule("record_declaration", Class, "name"),
+        rule("interface_declaration", Interface, "name"),
+        rule("annotation_type_declaration", Interface, "name"),
+        rule("enum_declaration", Enum, "name"),
+        rule("method_declaration", Method, "name"),
+        rule("constructor_declaration", Method, "name"),
+    ],
+    containers: &[],
+    imports: &["import_declaration"],
+};
+
+const C: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_definition", Function, "declarator"),
+        rule_with("struct_specifier", Struct, "name", "body"),
+        rule_with("union_specifier", Struct, "name", "body"),
+        rule_with("enum_specifier", Enum, "name", "body"),
+        rule("type_definition", TypeAlias, "declarator"),
+    ],
+    containers: &[],
+    imports: &["preproc_include"],
+};
+
+const CPP: LanguageSpec = LanguageSpec {
+    symbols: &[
+        rule("function_definition", Function, "declarator"),
+        rule_with("class_specifier", Class, "name", "body"),
+        rule_with("struct_specifier", Struct, "name", "body"),
+        rule_with("union_specifier", Struct, "name", "body"),
+        rule_with("enum_specifier", Enum, "name", "body"),
+        rule("type_definition", TypeAlias, "declarator"),
+        rule("alias_declaration", TypeAlias, "name"),
+        rule("namespace_definition", Module, "name"),
+    ],
+    containers: &[],
+    imports: &["preproc_include", "using_declaration"],
+};
+
+impl CodeLanguage {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        Some(match extension.as_str() {
+            "rs" => Self::Rust,
+            "ts" | "mts" | "cts" => Self::TypeScript,
+            "tsx" => Self::Tsx,
+            "js" | "jsx" | "mjs" | "cjs" => Self::JavaScript,
+            "py" | "pyi" => Self::Python,
+            "go" => Self::Go,
+            "java" => Self::Java,
+            "c" | "h" => Self::C,
+            "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Self::Cpp,
+            _ => return None,
+        })
+    }
+
+    fn grammar(self) -> Language {
+        match self {
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::Go => tree_sitter_go::LANGUAGE.into(),
+            Self::Java => tree_sitter_java::LANGUAGE.into(),
+            Self::C => tree_sitter_c::LANGUAGE.into(),
+            Self::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+        }
+    }
+
+    fn spec(self) -> &'static LanguageSpec {
+        match self {
+            Self::Rust => &RUST,
+            Self::TypeScript | Self::Tsx => &TYPESCRIPT,
+            Self::JavaScript => &JAVASCRIPT,
+            Self::Python => &PYTHON,
+            Self::Go => &GO,
+            Self::Java => &JAVA,
+            Self::C => &C,
+            Self::Cpp => &CPP,
+        }
+    }
+}
+
+/// Parses `source` with the grammar for `path` and returns where the parser
+/// had to recover, innermost first. `None` means the language is not
+/// supported, so nothing can be said about the file.
+pub fn check_syntax(path: &Path, source: &str) -> Option<Vec<SyntaxError>> {
+    let language = CodeLanguage::from_path(path)?;
+    let mut parser = Parser::new();
+    parser.set_language(&language.grammar()).ok()?;
+    let tree = parser.parse(source, None)?;
+
+    let mut errors = Vec::new();
+    if tree.root_node().has_error() {
+        collect_syntax_errors(tree.root_node(), source.as_bytes(), &mut errors);
+    }
+    Some(errors)
+}
+
+fn collect_syntax_errors(node: Node, source: &[u8], errors: &mut Vec<SyntaxError>) {
+    if errors.len() >= MAX_SYNTAX_ERRORS {
+        return;
+    }
+    if node.is_missing() || node.is_error() {
+        let position = node.start_position();
+        let message = if node.is_missing() {
+            format!("missing `{}`", node.kind())
+        } else {
+            let text = node.utf8_text(source).unwrap_or_default();
+            let snippet = text.lines().next().unwrap_or_default().trim();
+            format!(
+                "unexpected `{}`",
+                snippet
+                    .chars()
+                    .take(MAX_SIGNATURE_CHARS)
+                    .collect::<String>()
+            )
+        };
+        errors.push(SyntaxError {
+            line: position.row + 1,
+            column: position.column + 1,
+            message,
+        });
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.has_error() {
+            collect_syntax_errors(child, source, errors);
+        }
+    }
+}
+
+/// Parses `source` and returns its symbols in source order. `path` is the
+/// workspace-relative path recorded on each symbol.
+pub(super) fn extract_symbols(
+    language: CodeLanguage,
+    path: &str,
+    source: &str,
+) -> Result<Vec<CodeSymbol>, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&language.grammar())
+        .map_err(|e| format!("Failed to load {:?} grammar: {}", language, e))?;
+    let tree = parser
+        .parse(source, None)
+        .ok_or_else(|| format!("Failed to parse {}", path))?;
+
+    let mut extractor = Extractor {
+        spec: language.spec(),
+        path,
+        source: source.as_bytes(),
+        containers: Vec::new(),
+        symbols: Vec::new(),
+    };
+    extractor.visit_children(tree.root_node());
+    Ok(extractor.symbols)
+}
+
+struct Extractor<'a> {
+    spec: &'static LanguageSpec,
+    path: &'a str,
+    source: &'a [u8],
+    /// Enclosing named scopes and whether each is a type.
+    containers: Vec<(String, bool)>,
+    symbols: Vec<CodeSymbol>,
+}
+
+impl Extractor<'_> {
+    fn visit_children(&mut self, node: Node) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child);
+        }
+    }
+
+    fn visit(&mut self, node: Node) {
+        let node_kind = node.kind();
+
+        if self.spec.imports.contains(&node_kind) {
+            let text = self.text(node);
+            let name = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            self.push(node, name, Import);
+            return;
+        }
+
+        if let Some(&(_, name_field)) = self
+            .spec
+            .containers
+            .iter()
+            .find(|(kind, _)| *kind == node_kind)
+        {
+            let name = node
+                .child_by_field_name(name_field)
+                .map(|name| self.text(name).to_string());
+            self.visit_scope(node, name, true);
+            return;
+        }
+
+        let Some(rule) = self
+            .spec
+            .symbols
+            .iter()
+            .find(|rule| rule.node_kind == node_kind && self.rule_applies(rule, node))
+        else {
+            self.visit_children(node);
+            return;
+        };
+
+        let kind = refine_kind(node, rule.kind);
+        let Some(name) = self.symbol_name(node, rule.name_field) else {
+            // Anonymous namespaces and the like: keep their members.
+            self.visit_children(node);
+            return;
+        };
+        let in_type = self.containers.last().is_some_and(|(_, is_type)| *is_type);
+        let kind = if kind == Function && in_type {
+            Method
+        } else {
+            kind
+        };
+        self.push(node, name.clone(), kind);
+
+        if !matches!(kind, Function | Method) {
+            self.visit_scope(node, Some(name), kind.is_type());
+        }
+    }
+
+    fn visit_scope(&mut self, node: Node, name: Option<String>, is_type: bool) {
+        match name {
+            Some(name) => {
+                self.containers.push((name, is_type));
+                self.visit_children(node);
+                self.containers.pop();
+            }
+            None => self.visit_children(node),
+        }
+    }
+
+    fn rule_applies(&self, rule: &SymbolRule, node: Node) -> bool {
+        let Some(field) = rule.required_field else {
+            return true;
+        };
+        let Some(value) = node.child_by_field_name(field) else {
+            return false;
+        };
+        // `const x = 1` is not a function; `const f = () => {}` is.
+        node.kind() != "variable_declarator"
+            || matches!(
+                value.kind(),
+                "arrow_function" | "function_expression" | "function" | "generator_function"
+            )
+    }
+
+    fn symbol_name(&self, node: Node, field: &str) -> Option<String> {
+        let mut name = node.child_by_field_name(field)?;
+        while let Some(inner) = name.child_by_field_name("declarator") {
+            name = inner;
+        }
+        let text = self.text(name).trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
+    fn push(&mut self, node: Node, name: String, kind: SymbolKind) {
+        let signature = self
+            .text(node)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .chars()
+            .take(MAX_SIGNATURE_CHARS)
+            .collect();
+        self.symbols.push(CodeSymbol {
+            name,
+            kind,
+            path: self.path.to_string(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            container: self.containers.last().map(|(name, _)| name.clone()),
+            signature,
+        });
+    }
+
+    fn text(&self, node: Node) -> &str {
+        node.utf8_text(self.source).unwrap_or_default()
+    }
+}
+
+/// Go declares every named type with `type_spec`; the kind comes from the
+/// underlying type.
+fn refine_kind(node: Node, kind: SymbolKind) -> SymbolKind {
+    if node.kind() != "type_spec" {
+        return kind;
+    }
+    match node.child_by_field_name("type").map(|ty| ty.kind()) {
+        Some("struct_type") => Struct,
+        Some("interface_type") => Interface,
+        _ => kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline(language: CodeLanguage, source: &str) -> Vec<(String, SymbolKind, Option<String>)> {
+        extract_symbols(language, "test", source)
+            .unwrap()
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.container))
+            .collect()
+    }
+
+    fn entry(
+        name: &str,
+        kind: SymbolKind,
+        container: Option<&str>,
+    ) -> (String, SymbolKind, Option<String>) {
+        (name.to_string(), kind, container.map(str::to_string))
+    }
+
+    #[test]
+    fn rust_impl_functions_become_methods_of_the_type() {
+        let source = "use std::fmt;\n\npub struct Parser {\n    pos: usize,\n}\n\nimpl Parser {\n    pub fn parse(&self) {\n        fn helper() {}\n    }\n}\n\nfn main() {}\n";
+
+        assert_eq!(
+            outline(CodeLanguage::Rust, source),
+            vec![
+                entry("use std::fmt;", Import, None),
+                entry("Parser", Struct, None),
+                entry("parse", Method, Some("Parser")),
+                entry("main", Function, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn typescript_arrow_functions_count_but_plain_constants_do_not() {
+        let source = "import { a } from './a';\nexport interface Props { id: string }\nexport const render = () => null;\nconst LIMIT = 3;\nclass View {\n  draw() {}\n}\n";
+
+        assert_eq!(
+            outline(CodeLanguage::TypeScript, source),
+            vec![
+                entry("import { a } from './a';", Import, None),
+                entry("Props", Interface, None),
+                entry("render", Function, None),
+                entry("View", Class, None),
+                entry("draw", Method, Some("View")),
+            ]
+        );
+    }
+
+    #[test]
+    fn python_go_and_c_report_definitions_with_lines() {
+        let python = extract_symbols(
+            CodeLanguage::Python,
+            "app.py",
+            "import os\n\nclass App:\n    def run(self):\n        pass\n",
+        )
+        .unwrap();
+        assert_eq!(python[2].name, "run");
+        assert_eq!(python[2].kind, Method);
+        assert_eq!((python[2].start_line, python[2].end_line), (4, 5));
+
+        assert_eq!(
+            outline(
+                CodeLanguage::Go,
+                "package main\n\ntype Server struct{}\n\nfunc (s *Server) Start() {}\n"
+            ),
+            vec![entry("Server", Struct, None), entry("Start", Method, None)]
+        );
+
+        assert_eq!(
+            outline(
+                CodeLanguage::C,
+                "#include <stdio.h>\nstruct point { int x; };\nstatic int *make(void) { return 0; }\n"
+            ),
+            vec![
+                entry("#include <stdio.h>", Import, None),
+                entry("point", Struct, None),
+                entry("make", Function, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn languages_are_detected_by_extension() {
+        assert_eq!(
+            CodeLanguage::from_path(Path::new("src/App.TSX")),
+            Some(CodeLanguage::Tsx)
+        );
+        assert_eq!(
+            CodeLanguage::from_path(Path::new("lib/util.hpp")),
+            Some(CodeLanguage::Cpp)
+        );
+        assert_eq!(CodeLanguage::from_path(Path::new("README.md")), None);
+    }
+
+    #[test]
+    fn syntax_check_reports_recovery_points() {
+        assert_eq!(
+            check_syntax(Path::new("ok.rs"), "fn main() {}\n"),
+            Some(Vec::new())
+        );
+
+        let errors = check_syntax(Path::new("broken.ts"), "function f( {\n  return 1;\n")
+            .expect("typescript is supported");
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(|error| error.line >= 1));
+
+        assert_eq!(check_syntax(Path::new("notes.md"), "# {"), None);
+    }
+}
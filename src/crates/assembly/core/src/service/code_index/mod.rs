@@ -0,0 +1,15 @@
+//! Code index service
+//!
+//! Parses workspace sources with bundled tree-sitter grammars and keeps an
+//! incremental index of functions, types and imports for symbol lookup and
+//! file outlines without full-text search.
+
+mod languages;
+mod service;
+mod types;
+
+pub use languages::check_syntax;
+pub use service::{get_code_index_service, CodeIndexService};
+pub use types::{
+    CodeLanguage, CodeSymbol, FileOutline, SymbolKind, SymbolQuery, SymbolSearchResult, SyntaxError,
+};
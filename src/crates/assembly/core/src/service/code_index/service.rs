@@ -0,0 +1,435 @@
+//! Code index service.
+//!
+//! Keeps one symbol index per workspace root. Every query re-walks the
+//! workspace (respecting `.gitignore`) but only re-parses files whose size or
+//! modification time changed, so repeated lookups stay cheap after the first.
+
+use super::languages::extract_symbols;
+use super::types::{
+    CodeLanguage, CodeSymbol, FileOutline, SymbolKind, SymbolQuery, SymbolSearchResult,
+};
+use crate::service::file_ops::resolve_in_workspace;
+use crate::util::errors::{BitFunError, BitFunResult};
+use ignore::WalkBuilder;
+use log::{debug, info};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Instant, SystemTime};
+
+/// Generated bundles and data dumps are skipped; they are rarely what a
+/// lookup is after and dominate parse time.
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+const MAX_INDEXED_FILES: usize = 20_000;
+const DEFAULT_RESULT_LIMIT: usize = 50;
+const MAX_RESULT_LIMIT: usize = 500;
+
+static CODE_INDEX_SERVICE: OnceLock<Arc<CodeIndexService>> = OnceLock::new();
+
+pub fn get_code_index_service() -> Arc<CodeIndexService> {
+    CODE_INDEX_SERVICE
+        .get_or_init(|| Arc::new(CodeIndexService::default()))
+        .clone()
+}
+
+#[derive(Default)]
+pub struct CodeIndexService {
+    workspaces: Mutex<HashMap<PathBuf, Arc<Mutex<WorkspaceIndex>>>>,
+}
+
+#[derive(Default)]
+struct WorkspaceIndex {
+    /// Keyed by workspace-relative path.
+    files: HashMap<String, IndexedFile>,
+}
+
+struct IndexedFile {
+    modified: Option<SystemTime>,
+    size: u64,
+    symbols: Vec<CodeSymbol>,
+}
+
+impl IndexedFile {
+    fn is_fresh(&self, metadata: &std::fs::Metadata) -> bool {
+        self.size == metadata.len() && self.modified == metadata.modified().ok()
+    }
+}
+
+impl CodeIndexService {
+    /// Finds definitions by name across the workspace. `Type::member` and
+    /// `Type.member` also match on the enclosing type.
+    pub async fn find_symbol(
+        &self,
+        workspace_root: &Path,
+        query: SymbolQuery,
+    ) -> BitFunResult<SymbolSearchResult> {
+        if query.name.trim().is_empty() {
+            return Err(BitFunError::validation("Symbol name cannot be empty"));
+        }
+        let (root, index) = self.workspace_index(workspace_root)?;
+        tokio::task::spawn_blocking(move || {
+            let mut index = index.lock().map_err(lock_error)?;
+            let files_parsed = index.refresh(&root);
+            let mut result = index.search(&query);
+            result.files_parsed = files_parsed;
+            Ok(result)
+        })
+        .await
+        .map_err(|e| BitFunError::service(format!("Symbol search task failed: {}", e)))?
+    }
+
+    /// Lists the symbols of one file in source order.
+    pub async fn get_file_outline(
+        &self,
+        workspace_root: &Path,
+        path: &Path,
+    ) -> BitFunResult<FileOutline> {
+        let (root, index) = self.workspace_index(workspace_root)?;
+        let absolute = resolve_in_workspace(&root, path)?;
+        let language = CodeLanguage::from_path(&absolute).ok_or_else(|| {
+            BitFunError::validation(format!(
+                "No symbol parser for {}; supported: Rust, TypeScript, JavaScript, Python, Go, Java, C, C++",
+                path.display()
+            ))
+        })?;
+        tokio::task::spawn_blocking(move || {
+            let relative = relative_path(&root, &absolute);
+            let metadata = std::fs::metadata(&absolute)?;
+            if !metadata.is_file() {
+                return Err(BitFunError::validation(format!("Not a file: {}", relative)));
+            }
+            let mut index = index.lock().map_err(lock_error)?;
+            if !index
+                .files
+                .get(&relative)
+                .is_some_and(|file| file.is_fresh(&metadata))
+            {
+                let file = parse_file(&absolute, &relative, language, &metadata)?;
+                index.files.insert(relative.clone(), file);
+            }
+            let symbols = index
+                .files
+                .get(&relative)
+                .map(|file| file.symbols.clone())
+                .unwrap_or_default();
+            Ok(FileOutline {
+                path: relative,
+                language,
+                symbols,
+            })
+        })
+        .await
+        .map_err(|e| BitFunError::service(format!("File outline task failed: {}", e)))?
+    }
+
+    /// Drops the cached index for a workspace, e.g. when it is closed.
+    pub fn invalidate(&self, workspace_root: &Path) {
+        let root = dunce::canonicalize(workspace_root).unwrap_or_else(|_| workspace_root.into());
+        if let Ok(mut workspaces) = self.workspaces.lock() {
+            workspaces.remove(&root);
+        }
+    }
+
+    fn workspace_index(
+        &self,
+        workspace_root: &Path,
+    ) -> BitFunResult<(PathBuf, Arc<Mutex<WorkspaceIndex>>)> {
+        let root = dunce::canonicalize(workspace_root).map_err(|e| {
+            BitFunError::validation(format!(
+                "Workspace root is not accessible: {}: {}",
+                workspace_root.display(),
+                e
+            ))
+        })?;
+        let mut workspaces = self.workspaces.lock().map_err(lock_error)?;
+        let index = workspaces.entry(root.clone()).or_default().clone();
+        Ok((root, index))
+    }
+}
+
+impl WorkspaceIndex {
+    /// Brings the index in line with the files on disk. Returns how many
+    /// files were parsed.
+    fn refresh(&mut self, root: &Path) -> usize {
+        let started = Instant::now();
+        let mut seen = HashSet::new();
+        let mut parsed = 0;
+
+        for entry in WalkBuilder::new(root).require_git(false).build().flatten() {
+            if seen.len() >= MAX_INDEXED_FILES {
+                debug!(
+                    "Code index file limit reached: root={}, limit={}",
+                    root.display(),
+                    MAX_INDEXED_FILES
+                );
+                break;
+            }
+            let path = entry.path();
+            let Some(language) = CodeLanguage::from_path(path) else {
+                continue;
+            };
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() || metadata.len() > MAX_FILE_BYTES {
+                continue;
+            }
+
+            let relative = relative_path(root, path);
+            seen.insert(relative.clone());
+            if self
+                .files
+                .get(&relative)
+                .is_some_and(|file| file.is_fresh(&metadata))
+            {
+                continue;
+            }
+            match parse_file(path, &relative, language, &metadata) {
+                Ok(file) => {
+                    self.files.insert(relative, file);
+                    parsed += 1;
+                }
+                Err(e) => {
+                    debug!("Skipping file in code index: path={}, {}", relative, e);
+                    self.files.remove(&relative);
+                }
+            }
+        }
+        self.files.retain(|path, _| seen.contains(path));
+
+        if parsed > 0 {
+            info!(
+                "Code index refreshed: root={}, files={}, parsed={}, elapsed_ms={}",
+                root.display(),
+                self.files.len(),
+                parsed,
+                started.elapsed().as_millis()
+            );
+        }
+        parsed
+    }
+
+    fn search(&self, query: &SymbolQuery) -> SymbolSearchResult {
+        let matcher = NameMatcher::new(&query.name);
+        let path_prefix = query
+            .path_prefix
+            .as_deref()
+            .map(|prefix| prefix.trim_matches('/').replace('\\', "/"))
+            .filter(|prefix| !prefix.is_empty() && prefix != ".");
+
+        let mut matches = self
+            .files
+            .iter()
+            .filter(|(path, _)| {
+                path_prefix.as_deref().is_none_or(|prefix| {
+                    path.strip_prefix(prefix)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+                })
+            })
+            .flat_map(|(_, file)| &file.symbols)
+            .filter(|symbol| {
+                if query.kinds.is_empty() {
+                    symbol.kind != SymbolKind::Import
+                } else {
+                    query.kinds.contains(&symbol.kind)
+                }
+            })
+            .filter_map(|symbol| matcher.rank(symbol).map(|rank| (rank, symbol)))
+            .collect::<Vec<_>>();
+        matches.sort_by(|(a_rank, a), (b_rank, b)| {
+            a_rank
+                .cmp(b_rank)
+                .then_with(|| a.path.cmp(&b.path))
+                .then_with(|| a.start_line.cmp(&b.start_line))
+        });
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_RESULT_LIMIT)
+            .clamp(1, MAX_RESULT_LIMIT);
+        SymbolSearchResult {
+            total_matches: matches.len(),
+            symbols: matches
+                .into_iter()
+                .take(limit)
+                .map(|(_, symbol)| symbol.clone())
+                .collect(),
+            files_indexed: self.files.len(),
+            files_parsed: 0,
+        }
+    }
+}
+
+/// Case-insensitive name matching; lower ranks are better.
+struct NameMatcher {
+    /// The queried name as typed, for ranking exact-case matches first.
+    exact: String,
+    name: String,
+    container: Option<String>,
+}
+
+impl NameMatcher {
+    fn new(query: &str) -> Self {
+        let query = query.trim();
+        let split = query
+            .rfind("::")
+            .map(|index| (index, 2))
+            .or_else(|| query.rfind('.').map(|index| (index, 1)));
+        match split {
+            Some((index, separator)) if index > 0 && index + separator < query.len() => Self {
+                exact: query[index + separator..].to_string(),
+                name: query[index + separator..].to_lowercase(),
+                container: Some(query[..index].to_lowercase()),
+            },
+            _ => Self {
+                exact: query.to_string(),
+                name: query.to_lowercase(),
+                container: None,
+            },
+        }
+    }
+
+    fn rank(&self, symbol: &CodeSymbol) -> Option<u8> {
+        if let Some(container) = &self.container {
+            let symbol_container = symbol.container.as_deref()?.to_lowercase();
+            if symbol_container != *container && !symbol_container.ends_with(container.as_str()) {
+                return None;
+            }
+        }
+        let name = symbol.name.to_lowercase();
+        if name == self.name {
+            Some(if symbol.name == self.exact { 0 } else { 1 })
+        } else if name.starts_with(&self.name) {
+            Some(2)
+        } else if name.contains(&self.name) {
+            Some(3)
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_file(
+    path: &Path,
+    relative: &str,
+    language: CodeLanguage,
+    metadata: &std::fs::Metadata,
+) -> BitFunResult<IndexedFile> {
+    if metadata.len() > MAX_FILE_BYTES {
+        return Err(BitFunError::validation(format!(
+            "File is too large to index ({} bytes, limit {}): {}",
+            metadata.len(),
+            MAX_FILE_BYTES,
+            relative
+        )));
+    }
+    let source = std::fs::read_to_string(path)?;
+    let symbols = extract_symbols(language, relative, &source).map_err(BitFunError::service)?;
+    Ok(IndexedFile {
+        modified: metadata.modified().ok(),
+        size: metadata.len(),
+        symbols,
+    })
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn lock_error<T>(_: std::sync::PoisonError<T>) -> BitFunError {
+    BitFunError::service("Code index lock poisoned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[tokio::test]
+    async fn find_symbol_ranks_exact_matches_and_reparses_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "pub struct Parser;\nimpl Parser {\n    pub fn parse(&self) {}\n}\n",
+        );
+        write(
+            dir.path(),
+            "web/parse.ts",
+            "export function parseQuery() {}\n",
+        );
+        let service = CodeIndexService::default();
+
+        let first = service
+            .find_symbol(
+                dir.path(),
+                SymbolQuery {
+                    name: "parse".to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(first.files_parsed, 2);
+        assert_eq!(
+            first
+                .symbols
+                .iter()
+                .map(|symbol| symbol.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["parse", "Parser", "parseQuery"]
+        );
+
+        write(
+            dir.path(),
+            "web/parse.ts",
+            "export function parseQuery(): void {}\nexport function parseBody() {}\n",
+        );
+        let second = service
+            .find_symbol(
+                dir.path(),
+                SymbolQuery {
+                    name: "Parser::parse".to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(second.files_parsed, 1);
+        assert_eq!(second.symbols.len(), 1);
+        assert_eq!(second.symbols[0].path, "src/lib.rs");
+        assert_eq!(second.symbols[0].container.as_deref(), Some("Parser"));
+    }
+
+    #[tokio::test]
+    async fn outline_rejects_unsupported_files_and_paths_outside_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "notes.md", "# Notes\n");
+        write(dir.path(), "main.py", "def main():\n    pass\n");
+        let service = CodeIndexService::default();
+
+        let outline = service
+            .get_file_outline(dir.path(), Path::new("main.py"))
+            .await
+            .unwrap();
+        assert_eq!(outline.path, "main.py");
+        assert_eq!(outline.symbols[0].name, "main");
+
+        assert!(service
+            .get_file_outline(dir.path(), Path::new("notes.md"))
+            .await
+            .is_err());
+        assert!(service
+            .get_file_outline(dir.path(), Path::new("../outside.rs"))
+            .await
+            .is_err());
+    }
+}
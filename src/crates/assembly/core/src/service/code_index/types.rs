@@ -0,0 +1,140 @@
+//! Symbol index types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Function,
+    Method,
+    Struct,
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    TypeAlias,
+    Constant,
+    Module,
+    Import,
+}
+
+impl SymbolKind {
+    /// Kinds whose members are reported as methods and get a container name.
+    pub fn is_type(self) -> bool {
+        matches!(
+            self,
+            Self::Struct | Self::Class | Self::Interface | Self::Trait | Self::Enum
+        )
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Method => "method",
+            Self::Struct => "struct",
+            Self::Class => "class",
+            Self::Interface => "interface",
+            Self::Trait => "trait",
+            Self::Enum => "enum",
+            Self::TypeAlias => "type_alias",
+            Self::Constant => "constant",
+            Self::Module => "module",
+            Self::Import => "import",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Some(match value.trim().to_ascii_lowercase().as_str() {
+            "function" => Self::Function,
+            "method" => Self::Method,
+            "struct" => Self::Struct,
+            "class" => Self::Class,
+            "interface" => Self::Interface,
+            "trait" => Self::Trait,
+            "enum" => Self::Enum,
+            "type_alias" | "type" => Self::TypeAlias,
+            "constant" | "const" => Self::Constant,
+            "module" | "namespace" => Self::Module,
+            "import" => Self::Import,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodeLanguage {
+    Rust,
+    TypeScript,
+    Tsx,
+    JavaScript,
+    Python,
+    Go,
+    Java,
+    C,
+    Cpp,
+}
+
+/// A definition or import found in a source file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Workspace-relative path with `/` separators.
+    pub path: String,
+    /// 1-based, inclusive.
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Enclosing type, impl or module, e.g. `Parser` for `Parser::parse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// First line of the definition, trimmed.
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileOutline {
+    pub path: String,
+    pub language: CodeLanguage,
+    pub symbols: Vec<CodeSymbol>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolQuery {
+    /// Matched case-insensitively; exact names rank above prefixes and
+    /// substrings.
+    pub name: String,
+    /// Restrict to these kinds. Imports are only returned when asked for.
+    #[serde(default)]
+    pub kinds: Vec<SymbolKind>,
+    /// Restrict to files under this workspace-relative directory.
+    #[serde(default)]
+    pub path_prefix: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolSearchResult {
+    pub symbols: Vec<CodeSymbol>,
+    /// Matches before `limit` was applied.
+    pub total_matches: usize,
+    pub files_indexed: usize,
+    /// Files re-parsed for this query because they were new or changed.
+    pub files_parsed: usize,
+}
+
+/// A point where the parser had to recover from invalid syntax.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyntaxError {
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in bytes.
+    pub column: usize,
+    pub message: String,
+}
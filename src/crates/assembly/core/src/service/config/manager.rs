@@ -266,6 +266,9 @@ impl ConfigManager {
         };
 
         manager.load_or_create_config().await?;
+        bitfun_services_core::managed_runtime::set_resolution_settings(
+            manager.config.app.runtime_resolution.clone(),
+        );
         #[cfg(feature = "ai-adapter-runtime")]
         {
             bitfun_ai_adapters::diagnostics::set_include_sensitive_diagnostics(
@@ -714,10 +717,12 @@ impl ConfigManager {
         old_config: &GlobalConfig,
     ) -> BitFunResult<()> {
         self.check_and_broadcast_app_change(path).await;
+        self.check_and_broadcast_workspace_change(path).await;
         self.check_and_broadcast_debug_mode_change(old_config).await;
         self.check_and_broadcast_log_level_change(old_config).await;
         self.check_and_broadcast_sensitive_diagnostics_change(old_config)
             .await;
+        self.check_and_apply_runtime_resolution_change(old_config);
 
         self.providers
             .notify_config_changed(path, old_config, &self.config)
@@ -732,6 +737,14 @@ impl ConfigManager {
         }
     }
 
+    /// Detects and broadcasts workspace-scope configuration changes.
+    async fn check_and_broadcast_workspace_change(&self, path: &str) {
+        if path == "workspace" || path.starts_with("workspace.") {
+            use super::global::{ConfigUpdateEvent, GlobalConfigManager};
+            GlobalConfigManager::broadcast_update(ConfigUpdateEvent::WorkspaceUpdated).await;
+        }
+    }
+
     /// Detects and broadcasts debug-mode configuration changes.
     async fn check_and_broadcast_debug_mode_change(&self, old_config: &GlobalConfig) {
         let old_debug = &old_config.ai.debug_mode_config;
@@ -774,6 +787,19 @@ impl ConfigManager {
         }
     }
 
+    /// Applies managed runtime resolution policy changes to command lookup.
+    fn check_and_apply_runtime_resolution_change(&self, old_config: &GlobalConfig) {
+        let new_settings = &self.config.app.runtime_resolution;
+        if &old_config.app.runtime_resolution != new_settings {
+            debug!(
+                "Runtime resolution policy changed: default={:?}, overrides={}",
+                new_settings.default_policy,
+                new_settings.commands.len()
+            );
+            bitfun_services_core::managed_runtime::set_resolution_settings(new_settings.clone());
+        }
+    }
+
     /// Detects and broadcasts runtime sensitive diagnostics changes.
     async fn check_and_broadcast_sensitive_diagnostics_change(&self, old_config: &GlobalConfig) {
         let old_include = old_config.app.logging.include_sensitive_diagnostics;
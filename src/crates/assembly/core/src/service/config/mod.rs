@@ -10,7 +10,9 @@ pub mod global;
 pub mod manager;
 #[cfg(feature = "product-full")]
 pub mod mode_config_canonicalizer;
+pub mod project_model_store;
 pub mod project_permission_store;
+pub mod project_secret_redaction_store;
 pub mod providers;
 pub mod service;
 pub mod types;
@@ -0,0 +1,282 @@
+//! Workspace-scoped model configuration overrides.
+//!
+//! `{project}/.bitfun/models.json` can pick different default models and
+//! adjust `max_tokens` and custom headers of models the user already
+//! configured. Workspace values win over user-level settings; headers are
+//! merged per name. Endpoints and credentials stay user-level so a file
+//! committed to a repository cannot redirect API keys. The file is read on
+//! every lookup, so edits apply to the next turn without a restart.
+
+use super::types::{is_valid_configured_max_output_tokens, AIConfig, AIModelConfig};
+use crate::infrastructure::get_path_manager_arc;
+use crate::util::errors::{BitFunError, BitFunResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectModelConfig {
+    /// Replaces the user-level default model selections that are set.
+    #[serde(skip_serializing_if = "ProjectDefaultModels::is_empty")]
+    pub default_models: ProjectDefaultModels,
+    /// Replaces the user-level model of each listed functional agent.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub func_agent_models: HashMap<String, String>,
+    /// Per-model adjustments, keyed by configured model id.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub models: HashMap<String, ProjectModelOverride>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectDefaultModels {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast: Option<String>,
+}
+
+impl ProjectDefaultModels {
+    pub fn is_empty(&self) -> bool {
+        self.primary.is_none() && self.fast.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectModelOverride {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Added to the model's custom headers, replacing same-named ones.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub custom_headers: HashMap<String, String>,
+}
+
+impl ProjectModelOverride {
+    pub fn apply_to(&self, model: &AIModelConfig) -> AIModelConfig {
+        let mut merged = model.clone();
+        if let Some(max_tokens) = self.max_tokens {
+            merged.max_tokens = Some(max_tokens);
+        }
+        if !self.custom_headers.is_empty() {
+            merged
+                .custom_headers
+                .get_or_insert_with(HashMap::new)
+                .extend(self.custom_headers.clone());
+        }
+        merged
+    }
+}
+
+impl ProjectModelConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Checks that every referenced model is an enabled user-level model and
+    /// that the overrides are usable.
+    pub fn validate(&self, ai_config: &AIConfig) -> BitFunResult<()> {
+        let check_reference = |field: &str, model_id: &str| {
+            if ai_config.resolve_model_reference(model_id).is_none() {
+                return Err(BitFunError::config(format!(
+                    "Invalid project model config: {field} refers to '{model_id}', which is not an enabled model"
+                )));
+            }
+            Ok(())
+        };
+
+        if let Some(primary) = &self.default_models.primary {
+            check_reference("default_models.primary", primary)?;
+        }
+        if let Some(fast) = &self.default_models.fast {
+            check_reference("default_models.fast", fast)?;
+        }
+        for (agent, model_id) in &self.func_agent_models {
+            check_reference(&format!("func_agent_models.{agent}"), model_id)?;
+        }
+        for (model_id, model_override) in &self.models {
+            check_reference(&format!("models.{model_id}"), model_id)?;
+            if let Some(max_tokens) = model_override.max_tokens {
+                let context_window = ai_config
+                    .models
+                    .iter()
+                    .find(|model| &model.id == model_id)
+                    .and_then(|model| model.context_window);
+                let fits = match context_window {
+                    Some(context_window) => {
+                        is_valid_configured_max_output_tokens(context_window, max_tokens)
+                    }
+                    None => max_tokens > 0,
+                };
+                if !fits {
+                    return Err(BitFunError::config(format!(
+                        "Invalid project model config: models.{model_id}.max_tokens must be positive and fit the model's context window"
+                    )));
+                }
+            }
+            for (name, value) in &model_override.custom_headers {
+                if !is_valid_header_name(name) || value.contains(['\r', '\n']) {
+                    return Err(BitFunError::config(format!(
+                        "Invalid project model config: models.{model_id}.custom_headers has an invalid header '{name}'"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Layers this workspace's overrides onto the user-level AI settings.
+    pub fn apply_to(&self, ai_config: &AIConfig) -> AIConfig {
+        let mut merged = ai_config.clone();
+        if let Some(primary) = &self.default_models.primary {
+            merged.default_models.primary = Some(primary.clone());
+        }
+        if let Some(fast) = &self.default_models.fast {
+            merged.default_models.fast = Some(fast.clone());
+        }
+        merged
+            .func_agent_models
+            .extend(self.func_agent_models.clone());
+        for model in &mut merged.models {
+            if let Some(model_override) = self.models.get(&model.id) {
+                *model = model_override.apply_to(model);
+            }
+        }
+        merged
+    }
+}
+
+fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
+
+pub fn project_models_file_path(workspace_root: &Path) -> PathBuf {
+    get_path_manager_arc().project_models_file(workspace_root)
+}
+
+pub fn deserialize_project_model_config(content: &str) -> BitFunResult<ProjectModelConfig> {
+    serde_json::from_str(content)
+        .map_err(|error| BitFunError::config(format!("Invalid project model config: {error}")))
+}
+
+pub async fn load_project_model_config_local(
+    workspace_root: &Path,
+) -> BitFunResult<ProjectModelConfig> {
+    let path = project_models_file_path(workspace_root);
+    match tokio::fs::read_to_string(&path).await {
+        Ok(content) => deserialize_project_model_config(&content),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            Ok(ProjectModelConfig::default())
+        }
+        Err(error) => Err(BitFunError::config(format!(
+            "Failed to read project model config '{}': {error}",
+            path.display()
+        ))),
+    }
+}
+
+/// Loads and validates the overrides of a local workspace; no workspace means
+/// no overrides.
+pub async fn load_validated_project_model_config(
+    ai_config: &AIConfig,
+    workspace_root: Option<&Path>,
+) -> BitFunResult<ProjectModelConfig> {
+    let Some(workspace_root) = workspace_root else {
+        return Ok(ProjectModelConfig::default());
+    };
+    let project_config = load_project_model_config_local(workspace_root).await?;
+    project_config.validate(ai_config)?;
+    Ok(project_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_config() -> AIConfig {
+        let model = |id: &str| AIModelConfig {
+            id: id.to_string(),
+            enabled: true,
+            max_tokens: Some(8192),
+            custom_headers: Some(HashMap::from([
+                ("X-Team".to_string(), "personal".to_string()),
+                ("X-Client".to_string(), "bitfun".to_string()),
+            ])),
+            ..Default::default()
+        };
+        let mut config = AIConfig {
+            models: vec![model("public-sonnet"), model("gateway-sonnet")],
+            ..Default::default()
+        };
+        config.default_models.primary = Some("public-sonnet".to_string());
+        config.default_models.fast = Some("public-sonnet".to_string());
+        config
+    }
+
+    #[test]
+    fn workspace_overrides_win_over_user_settings() {
+        let project = deserialize_project_model_config(
+            r#"{
+                "default_models": { "primary": "gateway-sonnet" },
+                "models": {
+                    "gateway-sonnet": { "max_tokens": 32000, "custom_headers": { "X-Team": "payments" } }
+                }
+            }"#,
+        )
+        .expect("config should parse");
+        let user = user_config();
+        project.validate(&user).expect("config should be valid");
+
+        let merged = project.apply_to(&user);
+
+        assert_eq!(
+            merged.resolve_model_selection("primary").as_deref(),
+            Some("gateway-sonnet")
+        );
+        assert_eq!(
+            merged.resolve_model_selection("fast").as_deref(),
+            Some("public-sonnet")
+        );
+        let gateway = &merged.models[1];
+        assert_eq!(gateway.max_tokens, Some(32000));
+        let headers = gateway.custom_headers.as_ref().expect("headers");
+        assert_eq!(headers["X-Team"], "payments");
+        assert_eq!(headers["X-Client"], "bitfun");
+        assert_eq!(merged.models[0].max_tokens, Some(8192));
+        assert_eq!(
+            merged.models[0].custom_headers.as_ref().expect("headers")["X-Team"],
+            "personal"
+        );
+    }
+
+    #[test]
+    fn invalid_overrides_are_rejected() {
+        let user = user_config();
+        let error_for = |content: &str| {
+            deserialize_project_model_config(content)
+                .and_then(|project| project.validate(&user))
+                .expect_err("config should be rejected")
+                .to_string()
+        };
+
+        assert!(error_for(r#"{"default_models":{"primary":"missing"}}"#)
+            .contains("default_models.primary refers to 'missing'"));
+        assert!(
+            error_for(r#"{"models":{"gateway-sonnet":{"max_tokens":0}}}"#)
+                .contains("max_tokens must be positive")
+        );
+
+        assert!(error_for(
+            r#"{"models":{"gateway-sonnet":{"custom_headers":{"Bad Header":"x"}}}}"#
+        )
+        .contains("invalid header 'Bad Header'"));
+        assert!(
+            error_for(r#"{"models":{"gateway-sonnet":{"base_url":"https://evil"}}}"#)
+                .contains("unknown field `base_url`")
+        );
+    }
+}
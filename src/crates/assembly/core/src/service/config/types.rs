@@ -2,9 +2,14 @@
 //!
 //! Defines all configuration-related types shared between backend and frontend.
 
+use crate::service::downloads::DownloadSettings;
+use crate::service::power::PowerSettings;
+use crate::service::session::SessionRetentionPolicy;
 use crate::util::errors::*;
 use async_trait::async_trait;
+use bitfun_agent_runtime::skills::SKILL_MODEL_INHERIT;
 use bitfun_runtime_ports::{PermissionRule, ToolPermissionConfig};
+use bitfun_services_core::managed_runtime::RuntimeResolutionSettings;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
@@ -134,6 +139,18 @@ pub struct AppConfig {
     /// Allowed values: "quit" | "minimize_to_tray" | "ask".
     #[serde(default = "default_close_button_behavior")]
     pub close_button_behavior: String,
+    /// Automatic archiving and deletion of old sessions (off by default).
+    #[serde(default)]
+    pub session_retention: SessionRetentionPolicy,
+    /// Whether system or managed runtimes win when both provide a command.
+    #[serde(default)]
+    pub runtime_resolution: RuntimeResolutionSettings,
+    /// When background work yields to battery saver or an active user.
+    #[serde(default)]
+    pub power: PowerSettings,
+    /// Speed cap for large downloads such as runtime components.
+    #[serde(default)]
+    pub downloads: DownloadSettings,
 }
 
 /// Versioned user preference for grouping selectable Agent tools in the UI.
@@ -273,6 +290,40 @@ impl Default for VoiceInputConfig {
     }
 }
 
+/// Read-aloud preferences for task summaries and notifications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextToSpeechConfig {
+    pub enabled: bool,
+    /// "system" or "cloud".
+    pub provider: String,
+    /// AI model config whose base URL and API key are used for cloud speech.
+    pub cloud_model_config_id: String,
+    /// Speech model sent to the cloud endpoint, e.g. "gpt-4o-mini-tts".
+    pub cloud_model_name: String,
+    /// Speaking rate where 1.0 is normal speed.
+    pub rate: f32,
+    /// Voice id per language tag ("en-US", "zh"); "default" applies otherwise.
+    pub voices: HashMap<String, String>,
+    pub read_task_summaries: bool,
+    pub read_notifications: bool,
+}
+
+impl Default for TextToSpeechConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: "system".to_string(),
+            cloud_model_config_id: String::new(),
+            cloud_model_name: "gpt-4o-mini-tts".to_string(),
+            rate: 1.0,
+            voices: HashMap::new(),
+            read_task_summaries: true,
+            read_notifications: false,
+        }
+    }
+}
+
 /// AI experience configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -297,6 +348,8 @@ pub struct AIExperienceConfig {
     pub enable_workspace_search: bool,
     /// Local speech-to-text settings for the chat composer.
     pub voice_input: VoiceInputConfig,
+    /// Text-to-speech settings for reading results aloud.
+    pub text_to_speech: TextToSpeechConfig,
     /// User-defined quick actions (post-coding menu); persisted for the web UI.
     #[serde(default)]
     pub quick_actions: Vec<AiExperienceQuickAction>,
@@ -365,7 +418,8 @@ pub struct NotificationConfig {
 pub struct ThemesConfig {
     /// Currently active theme ID.
     pub current: String,
-    /// User-defined themes (stored as JSON).
+    /// Custom themes saved by older versions. The web UI moves them into
+    /// theme registry files on startup.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<serde_json::Value>,
 }
@@ -463,6 +517,27 @@ pub struct WorkspaceConfig {
     pub line_ending: String,
     pub trim_trailing_whitespace: bool,
     pub insert_final_newline: bool,
+    /// Language servers keyed by LSP language id (e.g. `rust`, `python`).
+    /// An entry takes precedence over an installed LSP plugin for the same
+    /// language.
+    pub language_servers: HashMap<String, LanguageServerSettings>,
+}
+
+/// A user-configured language server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageServerSettings {
+    /// Executable name or path; bare names are resolved through the system
+    /// PATH and BitFun-managed runtimes.
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Extensions routed to this server, with or without the leading dot.
+    #[serde(default)]
+    pub file_extensions: Vec<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 }
 
 /// Model capability type (a model can have multiple capabilities).
@@ -528,6 +603,8 @@ pub struct DefaultModelsConfig {
     pub image_generation: Option<String>,
     /// Speech recognition model.
     pub speech_recognition: Option<String>,
+    /// Embedding model used for semantic workspace search.
+    pub embedding: Option<String>,
 }
 
 /// Model choice for a subagent created in the context of a parent session.
@@ -706,6 +783,11 @@ pub struct AIConfig {
     #[serde(default = "default_subagent_max_concurrency")]
     pub subagent_max_concurrency: usize,
 
+    /// How many subagent levels may sit below a top-level session. At 1,
+    /// subagents cannot launch further subagents.
+    #[serde(default = "default_subagent_max_depth")]
+    pub subagent_max_depth: u8,
+
     /// Scheduling policy for multiple subagent launch calls in the same model batch.
     #[serde(default = "default_subagent_batch_execution_policy")]
     pub subagent_batch_execution_policy: SubagentBatchExecutionPolicy,
@@ -726,6 +808,12 @@ pub struct AIConfig {
     #[serde(default = "default_tool_execution_timeout")]
     pub tool_execution_timeout_secs: Option<u64>,
 
+    /// Token budget for a single tool result sent to the model. Larger
+    /// results are stored in full and replaced by a preview; `None` keeps the
+    /// built-in per-tool limits.
+    #[serde(default)]
+    pub tool_result_max_tokens: Option<usize>,
+
     /// Whether tools with deferred exposure load their schemas on demand.
     #[serde(default = "default_enable_deferred_tool_loading")]
     pub enable_deferred_tool_loading: bool,
@@ -750,6 +838,303 @@ pub struct AIConfig {
     /// Maximum number of rounds per dialog turn before soft-pausing.
     #[serde(default = "default_max_rounds")]
     pub max_rounds: usize,
+
+    /// Maximum number of independent tool calls from one model round that run
+    /// at the same time.
+    #[serde(default = "default_max_parallel_tool_calls")]
+    pub max_parallel_tool_calls: usize,
+
+    /// Cap on model requests in flight across all models; `None` means
+    /// unlimited. Per-model limits live in `AIModelConfig::rate_limit`.
+    #[serde(default)]
+    pub max_concurrent_requests: Option<u32>,
+
+    /// Per-provider overrides for agent prompt context providers, keyed by
+    /// provider id. Providers without an entry use their built-in defaults.
+    #[serde(default)]
+    pub context_providers: HashMap<String, ContextProviderSettings>,
+
+    /// Limits and domain policy for the built-in WebFetch tool.
+    #[serde(default)]
+    pub web_fetch: WebFetchSettings,
+
+    /// Secret detection on content sent to model providers. Workspaces can
+    /// override it in `.bitfun/config/secret_redaction.json`.
+    #[serde(default)]
+    pub secret_redaction: SecretRedactionSettings,
+
+    /// Where agent file writes may land, by workspace trust level.
+    #[serde(default)]
+    pub write_sandbox: WriteSandboxSettings,
+
+    /// User commands run on tool calls, edits and session lifecycle events.
+    #[serde(default)]
+    pub hooks: HooksSettings,
+
+    /// Checks run before an EditTransaction is kept.
+    #[serde(default)]
+    pub edit_transaction: EditTransactionSettings,
+
+    /// Per-turn cap on tools sent with full schemas; needs deferred tool
+    /// loading.
+    #[serde(default)]
+    pub tool_exposure_budget: ToolExposureBudgetSettings,
+
+    /// Whether skills may pick the model for the turns they drive.
+    #[serde(default)]
+    pub skill_models: SkillModelSettings,
+
+    /// Reuse of responses to repeated utility requests such as session
+    /// titles and commit messages.
+    #[serde(default)]
+    pub response_cache: ResponseCacheSettings,
+}
+
+/// Response cache shared by utility model calls that opt into it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ResponseCacheSettings {
+    pub enabled: bool,
+    /// How long a cached response is reused.
+    pub ttl_secs: u64,
+    /// Least recently used responses are dropped beyond this count.
+    pub max_entries: usize,
+}
+
+impl Default for ResponseCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_secs: 10 * 60,
+            max_entries: 256,
+        }
+    }
+}
+
+/// User policy over the `model` field of SKILL.md front matter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SkillModelSettings {
+    /// When false, skills never change the session model.
+    pub enabled: bool,
+    /// Selector used instead of the skill's own preference, keyed by skill
+    /// name. `inherit` keeps the session model for that skill.
+    pub overrides: HashMap<String, String>,
+}
+
+impl Default for SkillModelSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+/// WebFetch tool limits. Requests go through the global proxy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct WebFetchSettings {
+    /// Domains the tool may read, subdomains included; empty allows any domain.
+    pub allowed_domains: Vec<String>,
+    /// Response bodies beyond this size are truncated.
+    pub max_response_bytes: usize,
+    pub timeout_secs: u64,
+    /// How long fetched pages are reused; 0 disables the cache.
+    pub cache_ttl_secs: u64,
+    pub respect_robots_txt: bool,
+}
+
+impl Default for WebFetchSettings {
+    fn default() -> Self {
+        Self {
+            allowed_domains: Vec::new(),
+            max_response_bytes: 5 * 1024 * 1024,
+            timeout_secs: 30,
+            cache_ttl_secs: 15 * 60,
+            respect_robots_txt: true,
+        }
+    }
+}
+
+/// Secrets found in model-bound content are replaced with stable
+/// placeholders; placeholders the model sends back in tool arguments are
+/// restored before the tool runs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SecretRedactionSettings {
+    pub enabled: bool,
+    /// Exact values never redacted, e.g. published test keys.
+    pub allowlist: Vec<String>,
+    /// Extra regular expressions; the first capture group is redacted when
+    /// present, otherwise the whole match.
+    pub custom_patterns: Vec<String>,
+}
+
+impl Default for SecretRedactionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            allowlist: Vec::new(),
+            custom_patterns: Vec::new(),
+        }
+    }
+}
+
+/// Containment for Write, Edit and Delete in local workspaces. Traversal
+/// out of the workspace root is always rejected while enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct WriteSandboxSettings {
+    pub enabled: bool,
+    /// Absolute directories writable in addition to the workspace root.
+    pub extra_write_paths: Vec<String>,
+    pub trusted: WriteSandboxLevel,
+    /// Applies to restricted and undecided workspaces.
+    pub restricted: WriteSandboxLevel,
+}
+
+impl Default for WriteSandboxSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            extra_write_paths: Vec::new(),
+            trusted: WriteSandboxLevel {
+                confine_to_workspace: false,
+                approve_sensitive_files: true,
+            },
+            restricted: WriteSandboxLevel {
+                confine_to_workspace: true,
+                approve_sensitive_files: true,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct WriteSandboxLevel {
+    /// Reject writes to absolute paths outside the allowed directories
+    /// instead of asking for external directory access.
+    pub confine_to_workspace: bool,
+    /// Ask before writing `.git/config`, CI definitions or lockfiles, even
+    /// with full access.
+    pub approve_sensitive_files: bool,
+}
+
+/// Lifecycle hooks. Each hook command receives the event as JSON on stdin;
+/// `pre_tool_call` hooks can block or rewrite the call they precede.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct HooksSettings {
+    pub enabled: bool,
+    pub hooks: Vec<HookDefinition>,
+}
+
+impl Default for HooksSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hooks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    PreToolCall,
+    PostToolCall,
+    /// After a successful Write, Edit, EditTransaction or Delete.
+    PostEdit,
+    SessionStart,
+    SessionEnd,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HookDefinition {
+    pub event: HookEvent,
+    /// Tool name pattern for tool events, with `*` wildcards and `|`
+    /// alternatives; empty matches every tool.
+    #[serde(default)]
+    pub matcher: String,
+    /// Program name or path, resolved through managed runtimes first.
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    30
+}
+
+/// Validation for multi-file edit transactions. The command runs from the
+/// workspace root after the files are written; a non-zero exit rolls every
+/// file back.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct EditTransactionSettings {
+    /// Reject transactions that introduce parse errors in files with a
+    /// bundled grammar.
+    pub check_syntax: bool,
+    /// Build or test program, e.g. `cargo`; empty disables the step.
+    pub validation_command: String,
+    pub validation_args: Vec<String>,
+    pub validation_timeout_secs: u64,
+}
+
+impl Default for EditTransactionSettings {
+    fn default() -> Self {
+        Self {
+            check_syntax: true,
+            validation_command: String::new(),
+            validation_args: Vec::new(),
+            validation_timeout_secs: 300,
+        }
+    }
+}
+
+/// Bounds how many tools are sent to the model each turn. Tools outside the
+/// budget are demoted to deferred tools and can still be loaded with
+/// GetToolSpec or found with SearchTools. The selection can change between
+/// turns, which costs provider prompt-cache hits, so it is off by default.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ToolExposureBudgetSettings {
+    pub enabled: bool,
+    /// Tools sent with full schemas, not counting pinned tools and the
+    /// deferred-loading tools.
+    pub max_direct_tools: usize,
+    /// Deferred tools named in the prompt listing; the rest are only
+    /// reachable through SearchTools.
+    pub max_listed_deferred_tools: usize,
+    /// Tools that are always sent with full schemas.
+    pub pinned_tools: Vec<String>,
+}
+
+impl Default for ToolExposureBudgetSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_direct_tools: 24,
+            max_listed_deferred_tools: 40,
+            pinned_tools: Vec::new(),
+        }
+    }
+}
+
+/// Overrides for one agent prompt context provider. Unset fields fall back to
+/// the provider's defaults.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ContextProviderSettings {
+    pub enabled: Option<bool>,
+    /// Approximate token cap for the provider's section; longer output is truncated.
+    pub token_budget: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -882,6 +1267,29 @@ impl AIConfig {
             _ => self.resolve_model_reference(model_ref),
         }
     }
+
+    /// Model id for a turn driven by `skill_name`, which requested `requested`
+    /// in its front matter. User overrides win over the skill; `None` keeps the
+    /// session model.
+    pub fn resolve_skill_model_preference(
+        &self,
+        skill_name: &str,
+        requested: &str,
+    ) -> Option<String> {
+        if !self.skill_models.enabled {
+            return None;
+        }
+        let selector = self
+            .skill_models
+            .overrides
+            .get(skill_name)
+            .map(|selector| selector.trim())
+            .unwrap_or(requested);
+        if selector.is_empty() || selector == SKILL_MODEL_INHERIT {
+            return None;
+        }
+        self.resolve_model_selection(selector)
+    }
 }
 
 /// Shared agent-profile configuration.
@@ -958,6 +1366,10 @@ fn default_subagent_max_concurrency() -> usize {
     5
 }
 
+fn default_subagent_max_depth() -> u8 {
+    1
+}
+
 fn default_memory_max_raw_memories_for_consolidation() -> usize {
     64
 }
@@ -1016,6 +1428,12 @@ fn default_max_rounds() -> usize {
     DEFAULT_MAX_ROUNDS
 }
 
+pub const DEFAULT_MAX_PARALLEL_TOOL_CALLS: usize = 8;
+
+fn default_max_parallel_tool_calls() -> usize {
+    DEFAULT_MAX_PARALLEL_TOOL_CALLS
+}
+
 /// Debug-mode configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -1358,6 +1776,20 @@ pub struct AIModelConfig {
     /// time and inject the resolved Bearer token / extra headers.
     #[serde(default)]
     pub auth: AuthConfig,
+
+    /// Client-side throttling so parallel sessions stay under provider limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<ModelRateLimitConfig>,
+}
+
+/// Per-model request throttling. Unset fields are unlimited.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ModelRateLimitConfig {
+    pub requests_per_minute: Option<u32>,
+    /// Charged from an estimate of the request input size.
+    pub tokens_per_minute: Option<u32>,
+    pub max_concurrent_requests: Option<u32>,
 }
 
 /// Stable identity of the runtime-affecting parts of a concrete model config.
@@ -1371,6 +1803,8 @@ pub fn model_runtime_binding_fingerprint(model: &AIModelConfig) -> String {
     let mut value = serde_json::to_value(model).unwrap_or(serde_json::Value::Null);
     if let serde_json::Value::Object(fields) = &mut value {
         fields.remove("api_key");
+        // Throttling does not change what the model does.
+        fields.remove("rate_limit");
     }
 
     fn canonicalize(value: serde_json::Value) -> serde_json::Value {
@@ -1455,6 +1889,7 @@ struct AIModelConfigCompat {
     /// Parsed flexibly so unknown legacy auth tags fall back to ApiKey.
     #[serde(default)]
     auth: Option<serde_json::Value>,
+    rate_limit: Option<ModelRateLimitConfig>,
 }
 
 fn parse_auth_config(value: Option<serde_json::Value>) -> AuthConfig {
@@ -1504,6 +1939,7 @@ impl From<AIModelConfigCompat> for AIModelConfig {
             custom_request_body: value.custom_request_body,
             custom_request_body_mode: value.custom_request_body_mode,
             auth: parse_auth_config(value.auth),
+            rate_limit: value.rate_limit,
         }
     }
 }
@@ -1637,6 +2073,10 @@ impl Default for AppConfig {
             user_tool_groups: UserToolGroupsConfig::default(),
             user_skill_groups: UserSkillGroupsConfig::default(),
             close_button_behavior: default_close_button_behavior(),
+            session_retention: SessionRetentionPolicy::default(),
+            runtime_resolution: RuntimeResolutionSettings::default(),
+            power: PowerSettings::default(),
+            downloads: DownloadSettings::default(),
         }
     }
 }
@@ -1671,6 +2111,7 @@ impl Default for AIExperienceConfig {
             agent_companion_pet: default_agent_companion_pet(),
             enable_workspace_search: false,
             voice_input: VoiceInputConfig::default(),
+            text_to_speech: TextToSpeechConfig::default(),
             quick_actions: Vec::new(),
         }
     }
@@ -1764,6 +2205,7 @@ impl Default for WorkspaceConfig {
             line_ending: "auto".to_string(),
             trim_trailing_whitespace: true,
             insert_final_newline: true,
+            language_servers: HashMap::new(),
         }
     }
 }
@@ -1779,17 +2221,30 @@ impl Default for AIConfig {
             review_teams: default_review_team_configs(),
             review_team_rate_limit_status: default_review_team_rate_limit_status(),
             subagent_max_concurrency: default_subagent_max_concurrency(),
+            subagent_max_depth: default_subagent_max_depth(),
             subagent_batch_execution_policy: default_subagent_batch_execution_policy(),
             proxy: ProxyConfig::default(),
             stream_idle_timeout_secs: default_stream_idle_timeout(),
             stream_ttft_timeout_secs: default_stream_ttft_timeout(),
             tool_execution_timeout_secs: default_tool_execution_timeout(),
+            tool_result_max_tokens: None,
             enable_deferred_tool_loading: default_enable_deferred_tool_loading(),
             allow_tool_json_repair: true,
             debug_mode_config: DebugModeConfig::default(),
             computer_use_enabled: false,
             browser_control_preferred_browser: String::new(),
             max_rounds: default_max_rounds(),
+            max_parallel_tool_calls: default_max_parallel_tool_calls(),
+            max_concurrent_requests: None,
+            context_providers: HashMap::new(),
+            web_fetch: WebFetchSettings::default(),
+            secret_redaction: SecretRedactionSettings::default(),
+            write_sandbox: WriteSandboxSettings::default(),
+            hooks: HooksSettings::default(),
+            edit_transaction: EditTransactionSettings::default(),
+            tool_exposure_budget: ToolExposureBudgetSettings::default(),
+            skill_models: SkillModelSettings::default(),
+            response_cache: ResponseCacheSettings::default(),
         }
     }
 }
@@ -1849,6 +2304,7 @@ impl Default for AIModelConfig {
             custom_request_body: None,
             custom_request_body_mode: None,
             auth: AuthConfig::ApiKey,
+            rate_limit: None,
         }
     }
 }
@@ -1901,6 +2357,13 @@ impl AIModelConfig {
             || matches!(self.category, ModelCategory::Multimodal)
     }
 
+    pub fn supports_embedding(&self) -> bool {
+        self.capabilities
+            .iter()
+            .any(|cap| matches!(cap, ModelCapability::Embedding))
+            || matches!(self.category, ModelCategory::Embedding)
+    }
+
     /// Legacy helper that infers the model category from the model name and provider.
     ///
     /// This is kept for one-off migrations/debugging, but runtime behavior should prefer
@@ -2488,6 +2951,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skill_model_preference_follows_user_policy() {
+        let mut config = AIConfig::default();
+        for id in ["primary-model", "fast-model", "review-model"] {
+            config.models.push(
+                serde_json::from_value(serde_json::json!({
+                    "id": id,
+                    "name": id,
+                    "provider": "openai",
+                    "model_name": id,
+                    "base_url": "https://example.com/v1",
+                    "api_key": "key",
+                    "enabled": true
+                }))
+                .expect("model config should deserialize"),
+            );
+        }
+        config.default_models.primary = Some("primary-model".to_string());
+        config.default_models.fast = Some("fast-model".to_string());
+
+        assert_eq!(
+            config
+                .resolve_skill_model_preference("pdf", "fast")
+                .as_deref(),
+            Some("fast-model")
+        );
+        assert_eq!(
+            config.resolve_skill_model_preference("pdf", "missing"),
+            None
+        );
+
+        config
+            .skill_models
+            .overrides
+            .insert("pdf".to_string(), "review-model".to_string());
+        config
+            .skill_models
+            .overrides
+            .insert("docx".to_string(), "inherit".to_string());
+        assert_eq!(
+            config
+                .resolve_skill_model_preference("pdf", "fast")
+                .as_deref(),
+            Some("review-model")
+        );
+        assert_eq!(config.resolve_skill_model_preference("docx", "fast"), None);
+
+        config.skill_models.enabled = false;
+        assert_eq!(config.resolve_skill_model_preference("pdf", "fast"), None);
+    }
+
     #[test]
     fn subagent_model_selection_uses_a_tagged_persistent_shape() {
         let selection = SubagentModelSelection::fixed("fast");
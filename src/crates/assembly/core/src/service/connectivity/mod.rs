@@ -0,0 +1,16 @@
+//! Network connectivity monitor
+//!
+//! Periodically probes the general internet and configured endpoints (model
+//! base URLs, remote MCP servers, skill registries) so dependent services can
+//! report an offline state instead of repeatedly waiting on timeouts.
+
+mod service;
+mod types;
+
+pub use service::{
+    get_global_connectivity_service, set_global_connectivity_service, ConnectivityService,
+};
+pub use types::{
+    ConnectivitySnapshot, ConnectivityTarget, ConnectivityTargetKind, EndpointStatus,
+    ReachabilityState,
+};
@@ -0,0 +1,453 @@
+//! Connectivity service.
+
+use super::types::{
+    ConnectivitySnapshot, ConnectivityTarget, ConnectivityTargetKind, EndpointStatus,
+    ReachabilityState,
+};
+use crate::service::config::{get_global_config_service, AIConfig};
+use crate::service::mcp::{get_global_mcp_service, MCPServerType};
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, Mutex, Notify, RwLock};
+use tokio::time::{timeout, Duration};
+
+static GLOBAL_CONNECTIVITY_SERVICE: OnceLock<Arc<ConnectivityService>> = OnceLock::new();
+
+const ONLINE_PROBE_INTERVAL: Duration = Duration::from_secs(120);
+const OFFLINE_PROBE_INTERVAL: Duration = Duration::from_secs(15);
+const ENDPOINT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const INTERNET_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Well-known anycast resolvers; the internet counts as reachable when any
+/// of them accepts a TCP connection.
+const INTERNET_PROBE_ADDRS: &[&str] = &["1.1.1.1:443", "223.5.5.5:53", "8.8.8.8:53"];
+/// Endpoints only short-circuit callers after repeated failures so a single
+/// dropped probe does not hide a working service.
+const UNREACHABLE_FAILURE_THRESHOLD: u32 = 2;
+const EVENT_CHANNEL_CAPACITY: usize = 16;
+
+pub struct ConnectivityService {
+    client: reqwest::Client,
+    snapshot: RwLock<ConnectivitySnapshot>,
+    extra_targets: std::sync::RwLock<HashMap<String, ConnectivityTarget>>,
+    events: broadcast::Sender<ConnectivitySnapshot>,
+    refresh_lock: Mutex<()>,
+    wakeup: Notify,
+    monitor_started: AtomicBool,
+}
+
+impl ConnectivityService {
+    pub fn new() -> BitFunResult<Arc<Self>> {
+        let client = reqwest::Client::builder()
+            .timeout(ENDPOINT_PROBE_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|error| {
+                BitFunError::service(format!("Failed to create connectivity client: {error}"))
+            })?;
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        Ok(Arc::new(Self {
+            client,
+            snapshot: RwLock::new(ConnectivitySnapshot {
+                internet: ReachabilityState::Unknown,
+                endpoints: Vec::new(),
+                checked_at_ms: None,
+            }),
+            extra_targets: std::sync::RwLock::new(HashMap::new()),
+            events,
+            refresh_lock: Mutex::new(()),
+            wakeup: Notify::new(),
+            monitor_started: AtomicBool::new(false),
+        }))
+    }
+
+    /// Adds an endpoint that core does not discover from config, such as the
+    /// skill registry owned by the desktop shell. Replaces any target with
+    /// the same id.
+    pub fn register_target(&self, target: ConnectivityTarget) {
+        if let Ok(mut targets) = self.extra_targets.write() {
+            targets.insert(target.id.clone(), target);
+        }
+    }
+
+    pub fn unregister_target(&self, target_id: &str) {
+        if let Ok(mut targets) = self.extra_targets.write() {
+            targets.remove(target_id);
+        }
+    }
+
+    /// Emits a snapshot whenever internet or endpoint reachability changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<ConnectivitySnapshot> {
+        self.events.subscribe()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self
+            .monitor_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                let snapshot = service.refresh().await;
+                let interval = if snapshot.is_offline() {
+                    OFFLINE_PROBE_INTERVAL
+                } else {
+                    ONLINE_PROBE_INTERVAL
+                };
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = service.wakeup.notified() => {}
+                }
+            }
+        });
+    }
+
+    /// Asks the monitor loop to probe again without waiting for the interval,
+    /// e.g. after model or MCP configuration changed.
+    pub fn request_refresh(&self) {
+        self.wakeup.notify_one();
+    }
+
+    pub async fn snapshot(&self) -> ConnectivitySnapshot {
+        self.snapshot.read().await.clone()
+    }
+
+    pub async fn is_offline(&self) -> bool {
+        self.snapshot.read().await.is_offline()
+    }
+
+    /// Fails fast when the last probe found no internet connection.
+    pub async fn ensure_online(&self, purpose: &str) -> BitFunResult<()> {
+        if self.is_offline().await {
+            return Err(BitFunError::http(format!(
+                "{purpose} is unavailable while offline"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails fast when the internet is down or the origin of `url` failed
+    /// its recent probes. Unknown origins are always allowed through.
+    pub async fn ensure_reachable(&self, url: &str, purpose: &str) -> BitFunResult<()> {
+        let snapshot = self.snapshot.read().await;
+        if snapshot.is_offline() {
+            return Err(BitFunError::http(format!(
+                "{purpose} is unavailable while offline"
+            )));
+        }
+        let Some(origin) = origin_of(url) else {
+            return Ok(());
+        };
+        let unreachable = snapshot.endpoints.iter().any(|endpoint| {
+            endpoint.state == ReachabilityState::Unreachable
+                && endpoint.consecutive_failures >= UNREACHABLE_FAILURE_THRESHOLD
+                && origin_of(&endpoint.target.url).as_deref() == Some(origin.as_str())
+        });
+        if unreachable {
+            return Err(BitFunError::http(format!(
+                "{purpose} is unavailable: {} is unreachable",
+                origin
+            )));
+        }
+        Ok(())
+    }
+
+    /// Probes the internet and every known endpoint, publishing the result.
+    pub async fn refresh(&self) -> ConnectivitySnapshot {
+        let _guard = self.refresh_lock.lock().await;
+        let targets = self.collect_targets().await;
+
+        let mut origins = targets
+            .iter()
+            .filter_map(|target| origin_of(&target.url))
+            .collect::<Vec<_>>();
+        origins.sort();
+        origins.dedup();
+
+        let (internet, probes) = tokio::join!(
+            probe_internet(),
+            futures::future::join_all(
+                origins
+                    .iter()
+                    .map(|origin| probe_endpoint(&self.client, origin))
+            )
+        );
+        let results = origins.into_iter().zip(probes).collect::<HashMap<_, _>>();
+
+        let checked_at_ms = Utc::now().timestamp_millis();
+        let mut snapshot = self.snapshot.write().await;
+        let previous = snapshot.clone();
+        let endpoints = targets
+            .into_iter()
+            .map(|target| {
+                let result = origin_of(&target.url)
+                    .and_then(|origin| results.get(&origin).cloned())
+                    .unwrap_or_else(|| Err("Invalid endpoint URL".to_string()));
+                let prior = previous
+                    .endpoints
+                    .iter()
+                    .find(|endpoint| endpoint.target.id == target.id);
+                next_endpoint_status(prior, target, result, checked_at_ms)
+            })
+            .collect::<Vec<_>>();
+
+        // A proxy or firewall may block the raw TCP probes while HTTP endpoints
+        // still work, so any reachable endpoint also proves connectivity.
+        let internet = if internet
+            || endpoints
+                .iter()
+                .any(|endpoint| endpoint.state == ReachabilityState::Reachable)
+        {
+            ReachabilityState::Reachable
+        } else {
+            ReachabilityState::Unreachable
+        };
+
+        *snapshot = ConnectivitySnapshot {
+            internet,
+            endpoints,
+            checked_at_ms: Some(checked_at_ms),
+        };
+        let current = snapshot.clone();
+        drop(snapshot);
+
+        if reachability_changed(&previous, &current) {
+            if previous.internet != current.internet {
+                info!("Connectivity changed: internet={:?}", current.internet);
+            }
+            let _ = self.events.send(current.clone());
+        }
+        current
+    }
+
+    async fn collect_targets(&self) -> Vec<ConnectivityTarget> {
+        let mut targets = Vec::new();
+
+        match get_global_config_service().await {
+            Ok(config_service) => match config_service.get_config::<AIConfig>(Some("ai")).await {
+                Ok(ai_config) => {
+                    targets.extend(
+                        ai_config
+                            .models
+                            .into_iter()
+                            .filter(|model| model.enabled && !model.base_url.trim().is_empty())
+                            .map(|model| ConnectivityTarget {
+                                id: format!("model:{}", model.id),
+                                label: model.name,
+                                kind: ConnectivityTargetKind::Model,
+                                url: model.base_url,
+                            }),
+                    );
+                }
+                Err(error) => debug!("Connectivity skipped model endpoints: {}", error),
+            },
+            Err(error) => debug!("Connectivity skipped model endpoints: {}", error),
+        }
+
+        if let Some(mcp_service) = get_global_mcp_service() {
+            match mcp_service.config_service().load_all_configs().await {
+                Ok(configs) => {
+                    targets.extend(configs.into_iter().filter_map(|config| {
+                        if !config.enabled || config.server_type != MCPServerType::Remote {
+                            return None;
+                        }
+                        let url = config.url?;
+                        Some(ConnectivityTarget {
+                            id: format!("mcp:{}", config.id),
+                            label: config.name,
+                            kind: ConnectivityTargetKind::McpServer,
+                            url,
+                        })
+                    }));
+                }
+                Err(error) => warn!("Connectivity skipped MCP endpoints: {}", error),
+            }
+        }
+
+        if let Ok(extra_targets) = self.extra_targets.read() {
+            let mut extra_targets = extra_targets.values().cloned().collect::<Vec<_>>();
+            extra_targets.sort_by(|left, right| left.id.cmp(&right.id));
+            targets.extend(extra_targets);
+        }
+
+        targets
+    }
+}
+
+pub fn get_global_connectivity_service() -> Option<Arc<ConnectivityService>> {
+    GLOBAL_CONNECTIVITY_SERVICE.get().cloned()
+}
+
+pub fn set_global_connectivity_service(service: Arc<ConnectivityService>) {
+    let _ = GLOBAL_CONNECTIVITY_SERVICE.set(service);
+}
+
+/// `scheme://host[:port]` for http(s) URLs.
+fn origin_of(url: &str) -> Option<String> {
+    let parsed = reqwest::Url::parse(url.trim()).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return None;
+    }
+    Some(parsed.origin().ascii_serialization())
+}
+
+/// Any HTTP response, including 4xx/5xx, means the origin is reachable.
+async fn probe_endpoint(client: &reqwest::Client, origin: &str) -> Result<u64, String> {
+    let started = Instant::now();
+    match client.head(origin).send().await {
+        Ok(_) => Ok(started.elapsed().as_millis() as u64),
+        Err(error) if error.is_timeout() => Err("Timed out".to_string()),
+        Err(error) if error.is_connect() => Err(format!("Connection failed: {error}")),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+async fn probe_internet() -> bool {
+    let attempts = INTERNET_PROBE_ADDRS.iter().map(|addr| async move {
+        matches!(
+            timeout(INTERNET_PROBE_TIMEOUT, TcpStream::connect(*addr)).await,
+            Ok(Ok(_))
+        )
+    });
+    futures::future::join_all(attempts)
+        .await
+        .into_iter()
+        .any(|connected| connected)
+}
+
+fn next_endpoint_status(
+    previous: Option<&EndpointStatus>,
+    target: ConnectivityTarget,
+    result: Result<u64, String>,
+    checked_at_ms: i64,
+) -> EndpointStatus {
+    match result {
+        Ok(latency_ms) => EndpointStatus {
+            target,
+            state: ReachabilityState::Reachable,
+            latency_ms: Some(latency_ms),
+            error: None,
+            last_checked_at_ms: Some(checked_at_ms),
+            consecutive_failures: 0,
+        },
+        Err(error) => EndpointStatus {
+            target,
+            state: ReachabilityState::Unreachable,
+            latency_ms: None,
+            error: Some(error),
+            last_checked_at_ms: Some(checked_at_ms),
+            consecutive_failures: previous
+                .map(|status| status.consecutive_failures)
+                .unwrap_or(0)
+                .saturating_add(1),
+        },
+    }
+}
+
+fn reachability_changed(previous: &ConnectivitySnapshot, current: &ConnectivitySnapshot) -> bool {
+    if previous.internet != current.internet || previous.endpoints.len() != current.endpoints.len()
+    {
+        return true;
+    }
+    previous
+        .endpoints
+        .iter()
+        .zip(&current.endpoints)
+        .any(|(left, right)| left.target != right.target || left.state != right.state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(id: &str, url: &str) -> ConnectivityTarget {
+        ConnectivityTarget {
+            id: id.to_string(),
+            label: id.to_string(),
+            kind: ConnectivityTargetKind::Other,
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn origin_strips_path_and_rejects_non_http_urls() {
+        assert_eq!(
+            origin_of("https://api.example.com/v1/chat").as_deref(),
+            Some("https://api.example.com")
+        );
+        assert_eq!(
+            origin_of("http://localhost:8080/mcp").as_deref(),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(origin_of("file:///tmp/model"), None);
+        assert_eq!(origin_of("not a url"), None);
+    }
+
+    #[test]
+    fn failures_accumulate_until_a_probe_succeeds() {
+        let first = next_endpoint_status(
+            None,
+            target("a", "https://a.example.com"),
+            Err("Timed out".to_string()),
+            1,
+        );
+        let second = next_endpoint_status(
+            Some(&first),
+            target("a", "https://a.example.com"),
+            Err("Timed out".to_string()),
+            2,
+        );
+        assert_eq!(second.consecutive_failures, 2);
+
+        let recovered = next_endpoint_status(
+            Some(&second),
+            target("a", "https://a.example.com"),
+            Ok(40),
+            3,
+        );
+        assert_eq!(recovered.state, ReachabilityState::Reachable);
+        assert_eq!(recovered.consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn ensure_reachable_short_circuits_offline_and_failing_origins() {
+        let service = ConnectivityService::new().unwrap();
+        service.ensure_online("Update check").await.unwrap();
+
+        let failing = EndpointStatus {
+            target: target("registry", "https://skills.example.com/api"),
+            state: ReachabilityState::Unreachable,
+            latency_ms: None,
+            error: Some("Timed out".to_string()),
+            last_checked_at_ms: Some(1),
+            consecutive_failures: UNREACHABLE_FAILURE_THRESHOLD,
+        };
+        *service.snapshot.write().await = ConnectivitySnapshot {
+            internet: ReachabilityState::Reachable,
+            endpoints: vec![failing],
+            checked_at_ms: Some(1),
+        };
+        assert!(service
+            .ensure_reachable("https://skills.example.com/search?q=x", "Skill market")
+            .await
+            .is_err());
+        service
+            .ensure_reachable("https://other.example.com", "Skill market")
+            .await
+            .unwrap();
+
+        service.snapshot.write().await.internet = ReachabilityState::Unreachable;
+        assert!(service.ensure_online("Update check").await.is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Connectivity monitor types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectivityTargetKind {
+    Model,
+    SkillRegistry,
+    McpServer,
+    Other,
+}
+
+/// An endpoint probed by the connectivity monitor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectivityTarget {
+    pub id: String,
+    pub label: String,
+    pub kind: ConnectivityTargetKind,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReachabilityState {
+    Unknown,
+    Reachable,
+    Unreachable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointStatus {
+    #[serde(flatten)]
+    pub target: ConnectivityTarget,
+    pub state: ReachabilityState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_checked_at_ms: Option<i64>,
+    pub consecutive_failures: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectivitySnapshot {
+    /// General internet reachability. `Unknown` until the first probe.
+    pub internet: ReachabilityState,
+    pub endpoints: Vec<EndpointStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_at_ms: Option<i64>,
+}
+
+impl ConnectivitySnapshot {
+    pub fn is_offline(&self) -> bool {
+        self.internet == ReachabilityState::Unreachable
+    }
+}
@@ -0,0 +1,15 @@
+//! API key health monitor
+//!
+//! Periodically sends a minimal authenticated request per enabled model so
+//! expired or revoked credentials are flagged in settings, and agent turns on
+//! a model whose key the provider keeps rejecting fail before any work is
+//! done.
+
+mod service;
+mod types;
+
+pub use service::{
+    get_global_credential_health_service, set_global_credential_health_service,
+    CredentialHealthService,
+};
+pub use types::{CredentialHealthSnapshot, CredentialState, ModelCredentialStatus};
@@ -0,0 +1,365 @@
+//! API key health service.
+
+use super::types::{CredentialHealthSnapshot, CredentialState, ModelCredentialStatus};
+use crate::infrastructure::ai::{get_global_ai_client_factory, RequestPriority};
+use crate::service::config::types::AIModelConfig;
+use crate::service::config::{get_global_config_service, AIConfig};
+use crate::service::connectivity::get_global_connectivity_service;
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::types::ConnectionTestMessageCode;
+use chrono::Utc;
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{broadcast, Mutex, Notify, RwLock};
+use tokio::time::Duration;
+
+static GLOBAL_CREDENTIAL_HEALTH_SERVICE: OnceLock<Arc<CredentialHealthService>> = OnceLock::new();
+
+/// Each check is a billed request, so keys are checked rarely; config changes
+/// trigger an immediate check instead.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Leaves startup traffic alone before the first round of checks.
+const STARTUP_DELAY: Duration = Duration::from_secs(60);
+/// A single rejected check may be a provider hiccup; turns are only blocked
+/// once the key failed repeatedly.
+const AUTH_FAILURE_THRESHOLD: u32 = 2;
+const EVENT_CHANNEL_CAPACITY: usize = 16;
+
+pub struct CredentialHealthService {
+    snapshot: RwLock<CredentialHealthSnapshot>,
+    events: broadcast::Sender<CredentialHealthSnapshot>,
+    refresh_lock: Mutex<()>,
+    wakeup: Notify,
+    monitor_started: AtomicBool,
+}
+
+impl CredentialHealthService {
+    pub fn new() -> Arc<Self> {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Arc::new(Self {
+            snapshot: RwLock::new(CredentialHealthSnapshot::default()),
+            events,
+            refresh_lock: Mutex::new(()),
+            wakeup: Notify::new(),
+            monitor_started: AtomicBool::new(false),
+        })
+    }
+
+    /// Emits a snapshot whenever a model's credential state changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<CredentialHealthSnapshot> {
+        self.events.subscribe()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self
+            .monitor_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = STARTUP_DELAY;
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = service.wakeup.notified() => {}
+                }
+                service.refresh().await;
+                interval = CHECK_INTERVAL;
+            }
+        });
+    }
+
+    /// Asks the monitor loop to check again without waiting for the interval,
+    /// e.g. after a key was edited.
+    pub fn request_refresh(&self) {
+        self.wakeup.notify_one();
+    }
+
+    pub async fn snapshot(&self) -> CredentialHealthSnapshot {
+        self.snapshot.read().await.clone()
+    }
+
+    /// Fails when the provider kept rejecting the current credential of
+    /// `model_id`, so an agent turn does not start on a dead key.
+    pub async fn ensure_usable(&self, model_id: &str) -> BitFunResult<()> {
+        let Some(status) = self
+            .snapshot
+            .read()
+            .await
+            .model(model_id)
+            .filter(|status| status.state == CredentialState::Rejected)
+            .cloned()
+        else {
+            return Ok(());
+        };
+        // A key edited since the last check gets the benefit of the doubt.
+        if current_model_config(model_id)
+            .await
+            .is_some_and(|model| credential_fingerprint(&model) != status.credential_fingerprint)
+        {
+            return Ok(());
+        }
+        Err(BitFunError::AIClient(format!(
+            "The provider rejected the API key of model '{}'; update it in model settings: {}",
+            status.model_name,
+            status.error.as_deref().unwrap_or("authentication failed")
+        )))
+    }
+
+    /// Checks every enabled model once, publishing the result. Skipped while
+    /// offline since no check could tell a dead key from a dead network.
+    pub async fn refresh(&self) -> CredentialHealthSnapshot {
+        let _guard = self.refresh_lock.lock().await;
+        if let Some(connectivity) = get_global_connectivity_service() {
+            if connectivity.is_offline().await {
+                debug!("Credential health check skipped while offline");
+                return self.snapshot().await;
+            }
+        }
+
+        let models = match enabled_models().await {
+            Ok(models) => models,
+            Err(error) => {
+                warn!("Credential health check skipped: {}", error);
+                return self.snapshot().await;
+            }
+        };
+        let factory = match get_global_ai_client_factory().await {
+            Ok(factory) => factory,
+            Err(error) => {
+                warn!("Credential health check skipped: {}", error);
+                return self.snapshot().await;
+            }
+        };
+
+        let previous = self.snapshot().await;
+        let mut statuses = Vec::with_capacity(models.len());
+        for model in models {
+            let fingerprint = credential_fingerprint(&model);
+            let prior = previous
+                .model(&model.id)
+                .filter(|status| status.credential_fingerprint == fingerprint);
+            let outcome = match factory.get_client_by_id(&model.id).await {
+                Ok(client) => match client
+                    .with_request_priority(RequestPriority::Background)
+                    .check_credentials()
+                    .await
+                {
+                    Ok(result) if result.success => CheckOutcome::Accepted,
+                    Ok(result) => {
+                        let error = result
+                            .error_details
+                            .unwrap_or_else(|| "Credential check failed".to_string());
+                        if matches!(
+                            result.message_code,
+                            Some(ConnectionTestMessageCode::AuthenticationFailed)
+                        ) {
+                            CheckOutcome::AuthFailed(error)
+                        } else {
+                            CheckOutcome::Inconclusive(error)
+                        }
+                    }
+                    Err(error) => CheckOutcome::Inconclusive(error.to_string()),
+                },
+                Err(error) => CheckOutcome::Inconclusive(error.to_string()),
+            };
+            statuses.push(next_status(
+                prior,
+                &model,
+                fingerprint,
+                outcome,
+                Utc::now().timestamp_millis(),
+            ));
+        }
+
+        let current = CredentialHealthSnapshot {
+            models: statuses,
+            checked_at_ms: Some(Utc::now().timestamp_millis()),
+        };
+        *self.snapshot.write().await = current.clone();
+
+        if states_changed(&previous, &current) {
+            for status in &current.models {
+                if status.state == CredentialState::Rejected {
+                    info!(
+                        "Model credential rejected: model_id={}, failures={}",
+                        status.model_id, status.consecutive_auth_failures
+                    );
+                }
+            }
+            let _ = self.events.send(current.clone());
+        }
+        current
+    }
+}
+
+pub fn get_global_credential_health_service() -> Option<Arc<CredentialHealthService>> {
+    GLOBAL_CREDENTIAL_HEALTH_SERVICE.get().cloned()
+}
+
+pub fn set_global_credential_health_service(service: Arc<CredentialHealthService>) {
+    let _ = GLOBAL_CREDENTIAL_HEALTH_SERVICE.set(service);
+}
+
+enum CheckOutcome {
+    Accepted,
+    AuthFailed(String),
+    /// Network errors, rate limits and the like say nothing about the key.
+    Inconclusive(String),
+}
+
+async fn enabled_models() -> BitFunResult<Vec<AIModelConfig>> {
+    let ai_config: AIConfig = get_global_config_service()
+        .await?
+        .get_config(Some("ai"))
+        .await?;
+    Ok(ai_config
+        .models
+        .into_iter()
+        .filter(|model| model.enabled)
+        .collect())
+}
+
+async fn current_model_config(model_id: &str) -> Option<AIModelConfig> {
+    enabled_models()
+        .await
+        .ok()?
+        .into_iter()
+        .find(|model| model.id == model_id)
+}
+
+fn credential_fingerprint(model: &AIModelConfig) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(model.api_key.as_bytes());
+    hasher.update([0]);
+    hasher.update(model.base_url.as_bytes());
+    hasher.update([0]);
+    hasher.update(serde_json::to_vec(&model.auth).unwrap_or_default());
+    hex::encode(hasher.finalize())
+}
+
+fn next_status(
+    prior: Option<&ModelCredentialStatus>,
+    model: &AIModelConfig,
+    credential_fingerprint: String,
+    outcome: CheckOutcome,
+    checked_at_ms: i64,
+) -> ModelCredentialStatus {
+    let prior_failures = prior
+        .map(|status| status.consecutive_auth_failures)
+        .unwrap_or(0);
+    let (state, error, consecutive_auth_failures) = match outcome {
+        CheckOutcome::Accepted => (CredentialState::Valid, None, 0),
+        CheckOutcome::AuthFailed(error) => {
+            let failures = prior_failures.saturating_add(1);
+            let state = if failures >= AUTH_FAILURE_THRESHOLD {
+                CredentialState::Rejected
+            } else {
+                prior
+                    .map(|status| status.state)
+                    .unwrap_or(CredentialState::Unknown)
+            };
+            (state, Some(error), failures)
+        }
+        CheckOutcome::Inconclusive(error) => (
+            prior
+                .map(|status| status.state)
+                .unwrap_or(CredentialState::Unknown),
+            Some(error),
+            prior_failures,
+        ),
+    };
+
+    ModelCredentialStatus {
+        model_id: model.id.clone(),
+        model_name: model.name.clone(),
+        state,
+        error,
+        last_checked_at_ms: Some(checked_at_ms),
+        consecutive_auth_failures,
+        credential_fingerprint,
+    }
+}
+
+fn states_changed(previous: &CredentialHealthSnapshot, current: &CredentialHealthSnapshot) -> bool {
+    previous.models.len() != current.models.len()
+        || previous
+            .models
+            .iter()
+            .zip(&current.models)
+            .any(|(left, right)| left.model_id != right.model_id || left.state != right.state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> AIModelConfig {
+        AIModelConfig {
+            id: "m1".to_string(),
+            name: "Model".to_string(),
+            api_key: "sk-old".to_string(),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn repeated_auth_failures_reject_and_success_recovers() {
+        let model = model();
+        let fingerprint = credential_fingerprint(&model);
+        let auth_failed = || CheckOutcome::AuthFailed("client error 401".to_string());
+
+        let first = next_status(None, &model, fingerprint.clone(), auth_failed(), 1);
+        assert_eq!(first.state, CredentialState::Unknown);
+
+        let offline = next_status(
+            Some(&first),
+            &model,
+            fingerprint.clone(),
+            CheckOutcome::Inconclusive("Timed out".to_string()),
+            2,
+        );
+        assert_eq!(offline.consecutive_auth_failures, 1);
+
+        let second = next_status(
+            Some(&offline),
+            &model,
+            fingerprint.clone(),
+            auth_failed(),
+            3,
+        );
+        assert_eq!(second.state, CredentialState::Rejected);
+        assert_eq!(second.consecutive_auth_failures, 2);
+
+        let recovered = next_status(
+            Some(&second),
+            &model,
+            fingerprint,
+            CheckOutcome::Accepted,
+            4,
+        );
+        assert_eq!(recovered.state, CredentialState::Valid);
+        assert_eq!(recovered.consecutive_auth_failures, 0);
+    }
+
+    #[test]
+    fn a_new_key_changes_the_fingerprint() {
+        let old = model();
+        let new = AIModelConfig {
+            api_key: "sk-new".to_string(),
+            ..model()
+        };
+        assert_ne!(credential_fingerprint(&old), credential_fingerprint(&new));
+        assert_eq!(
+            credential_fingerprint(&old),
+            credential_fingerprint(&model())
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! API key health monitor types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialState {
+    /// Not checked yet, or the last checks failed for reasons other than
+    /// authentication.
+    Unknown,
+    Valid,
+    /// The provider rejected the credential on repeated checks.
+    Rejected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelCredentialStatus {
+    pub model_id: String,
+    pub model_name: String,
+    pub state: CredentialState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_checked_at_ms: Option<i64>,
+    pub consecutive_auth_failures: u32,
+    /// Identifies the credential that was checked, so a changed key starts
+    /// over instead of inheriting the old key's failures.
+    #[serde(skip)]
+    pub(crate) credential_fingerprint: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialHealthSnapshot {
+    pub models: Vec<ModelCredentialStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_at_ms: Option<i64>,
+}
+
+impl CredentialHealthSnapshot {
+    pub fn model(&self, model_id: &str) -> Option<&ModelCredentialStatus> {
+        self.models
+            .iter()
+            .find(|status| status.model_id == model_id)
+    }
+}
@@ -6,6 +6,7 @@ mod store;
 mod subscriber;
 mod types;
 
+pub(crate) use schedule::{compute_next_run_after_ms, validate_schedule};
 pub use service::{get_global_cron_service, set_global_cron_service, CronService};
 pub use subscriber::CronEventSubscriber;
 pub use types::{
@@ -7,7 +7,7 @@ use chrono_tz::Tz;
 use cron::Schedule;
 use std::str::FromStr;
 
-pub(super) fn validate_schedule(schedule: &CronSchedule, created_at_ms: i64) -> BitFunResult<()> {
+pub(crate) fn validate_schedule(schedule: &CronSchedule, created_at_ms: i64) -> BitFunResult<()> {
     let _ = compute_next_run_after_ms(schedule, created_at_ms, created_at_ms - 1)?;
     Ok(())
 }
@@ -28,7 +28,7 @@ pub(super) fn compute_initial_next_run_at_ms(
     }
 }
 
-pub(super) fn compute_next_run_after_ms(
+pub(crate) fn compute_next_run_after_ms(
     schedule: &CronSchedule,
     created_at_ms: i64,
     after_ms: i64,
@@ -15,6 +15,7 @@ use crate::agentic::coordination::{
 use crate::agentic::core::SessionConfig;
 use crate::agentic::workspace::WorkspaceBinding;
 use crate::infrastructure::PathManager;
+use crate::service::power::{wait_for_background_allowance, BackgroundWork};
 use crate::service_agent_runtime::CoreServiceAgentRuntime;
 use crate::util::errors::{BitFunError, BitFunResult};
 use bitfun_agent_runtime::scheduled_job::ScheduledJobEnqueueFailureAction;
@@ -377,6 +378,8 @@ impl CronService {
                 }
             }
 
+            // Due jobs stay due while paused and run once the pause ends.
+            wait_for_background_allowance(BackgroundWork::ScheduledJobs).await;
             if let Err(error) = self.process_due_jobs().await {
                 warn!("Failed to process due scheduled jobs: {}", error);
                 tokio::time::sleep(Duration::from_millis(1_000)).await;
@@ -0,0 +1,16 @@
+//! Document conversion service
+//!
+//! Typed conversions on top of the managed pandoc, LibreOffice and poppler
+//! runtimes (docx→md, pptx→pdf, html→pdf, pdf→png pages, pdf→text).
+
+mod service;
+mod types;
+
+pub use service::{
+    get_global_document_conversion_service, set_global_document_conversion_service,
+    DocumentConversionService, DEFAULT_CONVERSION_TIMEOUT_SECS, DEFAULT_PDF_DPI,
+};
+pub use types::{
+    DocumentConversionCapability, DocumentConversionEvent, DocumentConversionKind,
+    DocumentConversionRequest, DocumentConversionResult,
+};
@@ -0,0 +1,632 @@
+//! Document conversion service.
+//!
+//! Each job runs the managed converter inside its own directory under
+//! `PathManager::document_conversion_temp_dir`, so concurrent jobs never share
+//! intermediate files. Outputs are moved to the requested directory on success
+//! and the job directory is removed; failed or timed-out jobs are cleaned up
+//! immediately.
+
+use super::types::{
+    DocumentConversionCapability, DocumentConversionEvent, DocumentConversionKind,
+    DocumentConversionRequest, DocumentConversionResult,
+};
+use crate::infrastructure::PathManager;
+use crate::service::runtime::RuntimeManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::process_manager;
+use log::{debug, info, warn};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::broadcast;
+
+static GLOBAL_DOCUMENT_CONVERSION_SERVICE: OnceLock<Arc<DocumentConversionService>> =
+    OnceLock::new();
+
+pub const DEFAULT_CONVERSION_TIMEOUT_SECS: u64 = 120;
+pub const DEFAULT_PDF_DPI: u32 = 150;
+
+const MAX_CONVERSION_TIMEOUT_SECS: u64 = 30 * 60;
+const PDF_DPI_RANGE: std::ops::RangeInclusive<u32> = 36..=600;
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const STALE_JOB_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+const STDERR_TAIL_CHARS: usize = 2000;
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+pub struct DocumentConversionService {
+    runtime: RuntimeManager,
+    temp_root: PathBuf,
+    events: broadcast::Sender<DocumentConversionEvent>,
+}
+
+impl DocumentConversionService {
+    pub fn new(path_manager: &PathManager) -> BitFunResult<Arc<Self>> {
+        Ok(Self::with_runtime(
+            RuntimeManager::new()?,
+            path_manager.document_conversion_temp_dir(),
+        ))
+    }
+
+    pub fn with_runtime(runtime: RuntimeManager, temp_root: PathBuf) -> Arc<Self> {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Arc::new(Self {
+            runtime,
+            temp_root,
+            events,
+        })
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DocumentConversionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Which conversions can run with the currently installed runtimes.
+    pub fn capabilities(&self) -> Vec<DocumentConversionCapability> {
+        DocumentConversionKind::ALL
+            .iter()
+            .map(|kind| self.capability(*kind))
+            .collect()
+    }
+
+    pub fn capability(&self, kind: DocumentConversionKind) -> DocumentConversionCapability {
+        let command = self.runtime.get_command_capability(kind.command());
+        DocumentConversionCapability {
+            kind,
+            available: command.available,
+            command,
+        }
+    }
+
+    pub async fn convert(
+        &self,
+        request: DocumentConversionRequest,
+    ) -> BitFunResult<DocumentConversionResult> {
+        validate_request(&request)?;
+        let resolved = self
+            .runtime
+            .resolve_command(request.kind.command())
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!(
+                    "'{}' is required for {:?} conversions but is not installed",
+                    request.kind.command(),
+                    request.kind
+                ))
+            })?;
+        let program = resolved
+            .resolved_path
+            .unwrap_or_else(|| resolved.command.clone());
+
+        self.sweep_stale_jobs().await;
+
+        let job_id = uuid::Uuid::new_v4().to_string();
+        let work_dir = self.temp_root.join(&job_id);
+        tokio::fs::create_dir_all(&work_dir).await?;
+
+        info!(
+            "Document conversion started: job_id={}, kind={:?}, input={}",
+            job_id,
+            request.kind,
+            request.input_path.display()
+        );
+        let _ = self.events.send(DocumentConversionEvent::Started {
+            job_id: job_id.clone(),
+            kind: request.kind,
+            input_path: request.input_path.clone(),
+        });
+
+        let started = Instant::now();
+        let result = self
+            .run_job(&job_id, &program, &request, &work_dir)
+            .await
+            .map(|(outputs, output_dir)| DocumentConversionResult {
+                job_id: job_id.clone(),
+                kind: request.kind,
+                outputs,
+                output_dir,
+                duration_ms: started.elapsed().as_millis() as u64,
+            });
+        RuntimeManager::record_execution(request.kind.command(), result.is_ok(), started.elapsed());
+
+        match &result {
+            Ok(result) => {
+                info!(
+                    "Document conversion completed: job_id={}, outputs={}, duration_ms={}",
+                    job_id,
+                    result.outputs.len(),
+                    result.duration_ms
+                );
+                let _ = self.events.send(DocumentConversionEvent::Completed {
+                    result: result.clone(),
+                });
+            }
+            Err(error) => {
+                warn!(
+                    "Document conversion failed: job_id={}, error={}",
+                    job_id, error
+                );
+                remove_dir_quietly(&work_dir).await;
+                let _ = self.events.send(DocumentConversionEvent::Failed {
+                    job_id: job_id.clone(),
+                    kind: request.kind,
+                    error: error.to_string(),
+                });
+            }
+        }
+        result
+    }
+
+    async fn run_job(
+        &self,
+        job_id: &str,
+        program: &str,
+        request: &DocumentConversionRequest,
+        work_dir: &Path,
+    ) -> BitFunResult<(Vec<PathBuf>, PathBuf)> {
+        let args = conversion_args(request, work_dir);
+        debug!(
+            "Running document converter: job_id={}, program={}, args={:?}",
+            job_id, program, args
+        );
+
+        let mut command = process_manager::create_tokio_command(program);
+        command
+            .args(&args)
+            .current_dir(work_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(path) = self
+            .runtime
+            .merged_path_env(std::env::var("PATH").ok().as_deref())
+        {
+            command.env("PATH", path);
+        }
+
+        let child = command.spawn().map_err(|e| {
+            BitFunError::ProcessError(format!("Failed to start '{}': {}", program, e))
+        })?;
+        let timeout = Duration::from_secs(
+            request
+                .timeout_secs
+                .unwrap_or(DEFAULT_CONVERSION_TIMEOUT_SECS),
+        );
+
+        // Dropping the wait future on timeout kills the converter (kill_on_drop).
+        let output = tokio::time::timeout(timeout, async {
+            let wait = child.wait_with_output();
+            tokio::pin!(wait);
+            let mut ticker = tokio::time::interval(PROGRESS_POLL_INTERVAL);
+            let mut reported = 0;
+            loop {
+                tokio::select! {
+                    output = &mut wait => break output,
+                    _ = ticker.tick() => {
+                        let written = collect_outputs(work_dir, request.kind).await.len();
+                        if written > reported {
+                            reported = written;
+                            let _ = self.events.send(DocumentConversionEvent::Progress {
+                                job_id: job_id.to_string(),
+                                kind: request.kind,
+                                outputs_written: written,
+                            });
+                        }
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| {
+            BitFunError::Timeout(format!(
+                "{:?} conversion timed out after {}s",
+                request.kind,
+                timeout.as_secs()
+            ))
+        })?
+        .map_err(|e| BitFunError::ProcessError(format!("Converter process failed: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(BitFunError::ProcessError(format!(
+                "'{}' exited with {}: {}",
+                request.kind.command(),
+                output.status,
+                stderr_tail(&output.stderr)
+            )));
+        }
+
+        let outputs = collect_outputs(work_dir, request.kind).await;
+        if outputs.is_empty() {
+            return Err(BitFunError::ProcessError(format!(
+                "'{}' finished without producing any .{} output: {}",
+                request.kind.command(),
+                request.kind.output_extension(),
+                stderr_tail(&output.stderr)
+            )));
+        }
+
+        let Some(output_dir) = request.output_dir.as_ref() else {
+            return Ok((outputs, work_dir.to_path_buf()));
+        };
+        tokio::fs::create_dir_all(output_dir).await?;
+        let mut entries = tokio::fs::read_dir(work_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            // Skips converter scratch state such as the private soffice profile.
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            move_entry(&entry.path(), &output_dir.join(entry.file_name()))?;
+        }
+        remove_dir_quietly(work_dir).await;
+
+        let moved = outputs
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| output_dir.join(name))
+            .collect();
+        Ok((moved, output_dir.clone()))
+    }
+
+    /// Removes job directories left behind by callers that kept outputs in
+    /// the temp area or by a crash mid-conversion.
+    async fn sweep_stale_jobs(&self) {
+        let Ok(mut entries) = tokio::fs::read_dir(&self.temp_root).await else {
+            return;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let is_stale = entry
+                .metadata()
+                .await
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .is_some_and(|age| age > STALE_JOB_MAX_AGE);
+            if is_stale {
+                remove_dir_quietly(&entry.path()).await;
+            }
+        }
+    }
+}
+
+pub fn get_global_document_conversion_service() -> Option<Arc<DocumentConversionService>> {
+    GLOBAL_DOCUMENT_CONVERSION_SERVICE.get().cloned()
+}
+
+pub fn set_global_document_conversion_service(service: Arc<DocumentConversionService>) {
+    let _ = GLOBAL_DOCUMENT_CONVERSION_SERVICE.set(service);
+}
+
+fn validate_request(request: &DocumentConversionRequest) -> BitFunResult<()> {
+    let input = &request.input_path;
+    if !input.is_absolute() {
+        return Err(BitFunError::validation(format!(
+            "Document conversion requires an absolute input path: {}",
+            input.display()
+        )));
+    }
+    if !input.is_file() {
+        return Err(BitFunError::NotFound(format!(
+            "Input document not found: {}",
+            input.display()
+        )));
+    }
+
+    let extension = input
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !request
+        .kind
+        .input_extensions()
+        .contains(&extension.as_str())
+    {
+        return Err(BitFunError::validation(format!(
+            "{:?} expects a .{} input, got {}",
+            request.kind,
+            request.kind.input_extensions().join("/."),
+            input.display()
+        )));
+    }
+
+    if let Some(output_dir) = &request.output_dir {
+        if !output_dir.is_absolute() {
+            return Err(BitFunError::validation(format!(
+                "Output directory must be absolute: {}",
+                output_dir.display()
+            )));
+        }
+    }
+    if let Some(timeout) = request.timeout_secs {
+        if timeout == 0 || timeout > MAX_CONVERSION_TIMEOUT_SECS {
+            return Err(BitFunError::validation(format!(
+                "Conversion timeout must be between 1 and {} seconds",
+                MAX_CONVERSION_TIMEOUT_SECS
+            )));
+        }
+    }
+    if let Some(dpi) = request.dpi {
+        if !PDF_DPI_RANGE.contains(&dpi) {
+            return Err(BitFunError::validation(format!(
+                "PDF render resolution must be between {} and {} dpi",
+                PDF_DPI_RANGE.start(),
+                PDF_DPI_RANGE.end()
+            )));
+        }
+    }
+    match (request.first_page, request.last_page) {
+        (Some(0), _) | (_, Some(0)) => Err(BitFunError::validation(
+            "PDF page numbers start at 1".to_string(),
+        )),
+        (Some(first), Some(last)) if first > last => Err(BitFunError::validation(format!(
+            "First page {} is after last page {}",
+            first, last
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn conversion_args(request: &DocumentConversionRequest, work_dir: &Path) -> Vec<String> {
+    let input = request.input_path.to_string_lossy().to_string();
+    let stem = request
+        .input_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "document".to_string());
+
+    match request.kind {
+        // Runs with the job directory as cwd so media links stay relative.
+        DocumentConversionKind::DocxToMarkdown => vec![
+            input,
+            "--from=docx".to_string(),
+            "--to=gfm".to_string(),
+            "--wrap=none".to_string(),
+            "--extract-media=.".to_string(),
+            format!("--output={}.md", stem),
+        ],
+        // A private profile keeps conversions working while the user has
+        // LibreOffice open. HTML opens in Writer/Web, which needs its own
+        // PDF filter.
+        DocumentConversionKind::PptxToPdf | DocumentConversionKind::HtmlToPdf => vec![
+            "--headless".to_string(),
+            "--norestore".to_string(),
+            format!(
+                "-env:UserInstallation={}",
+                file_url(&work_dir.join(".soffice-profile"))
+            ),
+            "--convert-to".to_string(),
+            if request.kind == DocumentConversionKind::HtmlToPdf {
+                "pdf:writer_web_pdf_Export".to_string()
+            } else {
+                "pdf".to_string()
+            },
+            "--outdir".to_string(),
+            work_dir.to_string_lossy().to_string(),
+            input,
+        ],
+        DocumentConversionKind::PdfToPng => {
+            let mut args = vec![
+                "-png".to_string(),
+                "-r".to_string(),
+                request.dpi.unwrap_or(DEFAULT_PDF_DPI).to_string(),
+            ];
+            if let Some(first) = request.first_page {
+                args.extend(["-f".to_string(), first.to_string()]);
+            }
+            if let Some(last) = request.last_page {
+                args.extend(["-l".to_string(), last.to_string()]);
+            }
+            args.push(input);
+            args.push(work_dir.join(stem).to_string_lossy().to_string());
+            args
+        }
+        DocumentConversionKind::PdfToText => {
+            let mut args = vec!["-enc".to_string(), "UTF-8".to_string()];
+            if let Some(first) = request.first_page {
+                args.extend(["-f".to_string(), first.to_string()]);
+            }
+            if let Some(last) = request.last_page {
+                args.extend(["-l".to_string(), last.to_string()]);
+            }
+            args.push(input);
+            args.push(
+                work_dir
+                    .join(format!("{}.txt", stem))
+                    .to_string_lossy()
+                    .to_string(),
+            );
+            args
+        }
+    }
+}
+
+fn file_url(path: &Path) -> String {
+    let normalized = path.to_string_lossy().replace('\\', "/");
+    format!("file:///{}", normalized.trim_start_matches('/'))
+}
+
+/// Top-level outputs of `kind` in `dir`, with numbered pages in page order.
+async fn collect_outputs(dir: &Path, kind: DocumentConversionKind) -> Vec<PathBuf> {
+    let mut outputs = Vec::new();
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return outputs;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        let matches = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(kind.output_extension()));
+        if matches && path.is_file() {
+            outputs.push(path);
+        }
+    }
+    sort_by_page_number(&mut outputs);
+    outputs
+}
+
+/// pdftoppm zero-pads page numbers only as wide as the page count, so a plain
+/// name sort would put `-10` before `-2` for some documents.
+fn sort_by_page_number(paths: &mut [PathBuf]) {
+    paths.sort_by_key(|path| {
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let page = stem
+            .rsplit_once('-')
+            .and_then(|(_, page)| page.parse::<u64>().ok());
+        (page, stem)
+    });
+}
+
+fn move_entry(source: &Path, target: &Path) -> std::io::Result<()> {
+    if source.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            move_entry(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        return std::fs::remove_dir(source);
+    }
+
+    if target.is_file() {
+        std::fs::remove_file(target)?;
+    }
+    if std::fs::rename(source, target).is_err() {
+        // Temp and output directories can live on different volumes.
+        std::fs::copy(source, target)?;
+        std::fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+fn stderr_tail(stderr: &[u8]) -> String {
+    let text = String::from_utf8_lossy(stderr);
+    let text = text.trim();
+    let skip = text.chars().count().saturating_sub(STDERR_TAIL_CHARS);
+    text.chars().skip(skip).collect()
+}
+
+async fn remove_dir_quietly(dir: &Path) {
+    if let Err(e) = tokio::fs::remove_dir_all(dir).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            warn!(
+                "Failed to remove document conversion directory: path={}, error={}",
+                dir.display(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdf_args_include_page_range_and_prefix_outputs_with_input_stem() {
+        let mut request =
+            DocumentConversionRequest::new(DocumentConversionKind::PdfToPng, "/docs/report.pdf");
+        request.first_page = Some(2);
+        request.last_page = Some(4);
+
+        let args = conversion_args(&request, Path::new("/tmp/job"));
+        let prefix = Path::new("/tmp/job").join("report");
+
+        assert_eq!(
+            args,
+            vec![
+                "-png",
+                "-r",
+                "150",
+                "-f",
+                "2",
+                "-l",
+                "4",
+                "/docs/report.pdf",
+                &*prefix.to_string_lossy(),
+            ]
+        );
+    }
+
+    #[test]
+    fn pdf_text_args_write_utf8_next_to_the_job() {
+        let mut request =
+            DocumentConversionRequest::new(DocumentConversionKind::PdfToText, "/docs/report.pdf");
+        request.last_page = Some(3);
+
+        let args = conversion_args(&request, Path::new("/tmp/job"));
+        let output = Path::new("/tmp/job").join("report.txt");
+
+        assert_eq!(
+            args,
+            vec![
+                "-enc",
+                "UTF-8",
+                "-l",
+                "3",
+                "/docs/report.pdf",
+                &*output.to_string_lossy(),
+            ]
+        );
+    }
+
+    #[test]
+    fn html_args_use_the_writer_web_pdf_filter() {
+        let request =
+            DocumentConversionRequest::new(DocumentConversionKind::HtmlToPdf, "/docs/report.html");
+
+        let args = conversion_args(&request, Path::new("/tmp/job"));
+
+        assert_eq!(args[3], "--convert-to");
+        assert_eq!(args[4], "pdf:writer_web_pdf_Export");
+        assert_eq!(args.last().map(String::as_str), Some("/docs/report.html"));
+    }
+
+    #[test]
+    fn pages_sort_numerically() {
+        let mut pages = vec![
+            PathBuf::from("/job/report-10.png"),
+            PathBuf::from("/job/report-2.png"),
+            PathBuf::from("/job/report-1.png"),
+        ];
+
+        sort_by_page_number(&mut pages);
+
+        assert_eq!(
+            pages,
+            vec![
+                PathBuf::from("/job/report-1.png"),
+                PathBuf::from("/job/report-2.png"),
+                PathBuf::from("/job/report-10.png"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_inputs_and_inverted_page_ranges() {
+        let dir =
+            std::env::temp_dir().join(format!("bitfun-docconv-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let pdf = dir.join("slides.pdf");
+        std::fs::write(&pdf, b"%PDF-1.4").unwrap();
+
+        let wrong_kind = DocumentConversionRequest::new(DocumentConversionKind::PptxToPdf, &pdf);
+        assert!(matches!(
+            validate_request(&wrong_kind),
+            Err(BitFunError::Validation(_))
+        ));
+
+        let mut inverted = DocumentConversionRequest::new(DocumentConversionKind::PdfToPng, &pdf);
+        inverted.first_page = Some(5);
+        inverted.last_page = Some(3);
+        assert!(matches!(
+            validate_request(&inverted),
+            Err(BitFunError::Validation(_))
+        ));
+
+        let ok = DocumentConversionRequest::new(DocumentConversionKind::PdfToPng, &pdf);
+        assert!(validate_request(&ok).is_ok());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
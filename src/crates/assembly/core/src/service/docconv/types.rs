@@ -0,0 +1,153 @@
+//! Document conversion requests, results and events.
+
+use crate::service::runtime::RuntimeCommandCapability;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentConversionKind {
+    /// Word document to GitHub-flavored Markdown (pandoc). Embedded media is
+    /// extracted next to the Markdown file.
+    DocxToMarkdown,
+    /// PowerPoint deck to PDF (LibreOffice).
+    PptxToPdf,
+    /// Standalone HTML page to PDF (LibreOffice Writer/Web).
+    HtmlToPdf,
+    /// One PNG image per PDF page (poppler).
+    PdfToPng,
+    /// Plain UTF-8 text in reading order (poppler).
+    PdfToText,
+}
+
+impl DocumentConversionKind {
+    pub const ALL: [DocumentConversionKind; 5] = [
+        DocumentConversionKind::DocxToMarkdown,
+        DocumentConversionKind::PptxToPdf,
+        DocumentConversionKind::HtmlToPdf,
+        DocumentConversionKind::PdfToPng,
+        DocumentConversionKind::PdfToText,
+    ];
+
+    /// Managed runtime command that performs the conversion.
+    pub fn command(self) -> &'static str {
+        match self {
+            Self::DocxToMarkdown => "pandoc",
+            Self::PptxToPdf | Self::HtmlToPdf => "soffice",
+            Self::PdfToPng => "pdftoppm",
+            Self::PdfToText => "pdftotext",
+        }
+    }
+
+    pub fn input_extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::DocxToMarkdown => &["docx"],
+            Self::PptxToPdf => &["pptx", "ppt", "odp"],
+            Self::HtmlToPdf => &["html", "htm"],
+            Self::PdfToPng | Self::PdfToText => &["pdf"],
+        }
+    }
+
+    pub fn output_extension(self) -> &'static str {
+        match self {
+            Self::DocxToMarkdown => "md",
+            Self::PptxToPdf | Self::HtmlToPdf => "pdf",
+            Self::PdfToPng => "png",
+            Self::PdfToText => "txt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentConversionRequest {
+    pub kind: DocumentConversionKind,
+    pub input_path: PathBuf,
+    /// Where outputs are moved once the conversion succeeds. When omitted the
+    /// outputs stay in the job's temp directory, which is swept after a day.
+    #[serde(default)]
+    pub output_dir: Option<PathBuf>,
+    /// Defaults to `DEFAULT_CONVERSION_TIMEOUT_SECS`.
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// PDF only: first page to convert (1-based).
+    #[serde(default)]
+    pub first_page: Option<u32>,
+    /// PDF only: last page to convert (inclusive).
+    #[serde(default)]
+    pub last_page: Option<u32>,
+    /// PDF pages only: render resolution, defaults to `DEFAULT_PDF_DPI`.
+    #[serde(default)]
+    pub dpi: Option<u32>,
+}
+
+impl DocumentConversionRequest {
+    pub fn new(kind: DocumentConversionKind, input_path: impl Into<PathBuf>) -> Self {
+        Self {
+            kind,
+            input_path: input_path.into(),
+            output_dir: None,
+            timeout_secs: None,
+            first_page: None,
+            last_page: None,
+            dpi: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentConversionResult {
+    pub job_id: String,
+    pub kind: DocumentConversionKind,
+    /// Primary outputs in order (PDF pages are sorted by page number).
+    pub outputs: Vec<PathBuf>,
+    /// Directory holding the outputs and any extracted media.
+    pub output_dir: PathBuf,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentConversionCapability {
+    pub kind: DocumentConversionKind,
+    pub available: bool,
+    pub command: RuntimeCommandCapability,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DocumentConversionEvent {
+    #[serde(rename_all = "camelCase")]
+    Started {
+        job_id: String,
+        kind: DocumentConversionKind,
+        input_path: PathBuf,
+    },
+    /// Emitted while the converter runs whenever more outputs appear on disk.
+    #[serde(rename_all = "camelCase")]
+    Progress {
+        job_id: String,
+        kind: DocumentConversionKind,
+        outputs_written: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    Completed { result: DocumentConversionResult },
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        job_id: String,
+        kind: DocumentConversionKind,
+        error: String,
+    },
+}
+
+impl DocumentConversionEvent {
+    pub fn job_id(&self) -> &str {
+        match self {
+            Self::Started { job_id, .. }
+            | Self::Progress { job_id, .. }
+            | Self::Failed { job_id, .. } => job_id,
+            Self::Completed { result } => &result.job_id,
+        }
+    }
+}
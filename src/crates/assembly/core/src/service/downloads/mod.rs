@@ -0,0 +1,16 @@
+//! Throttled, resumable downloads for large artifacts.
+//!
+//! Runtime components and other multi-megabyte downloads go through here so
+//! the speed cap in `app.downloads` applies to all of them together, and so
+//! an interrupted download continues where it stopped, even after a restart.
+
+mod resumable;
+mod throttle;
+mod types;
+
+pub use resumable::{discard_partial, download_resumable, partial_size, ResumableDownload};
+pub use throttle::{
+    download_speed_limits, set_download_speed_limit, start_download_settings_listener,
+    DownloadThrottle,
+};
+pub use types::{DownloadSettings, DownloadSpeedLimits, DownloadedFile, OperationSpeedLimit};
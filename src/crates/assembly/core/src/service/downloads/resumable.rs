@@ -0,0 +1,346 @@
+//! Downloads that continue from a partial file.
+
+use super::throttle::DownloadThrottle;
+use super::types::{DownloadedFile, PartialDownloadMeta};
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::hashing::{HashAlgorithm, StreamingHasher};
+use futures::StreamExt;
+use log::{debug, info};
+use reqwest::header::{CONTENT_RANGE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE, USER_AGENT};
+use reqwest::StatusCode;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+
+pub struct ResumableDownload<'a> {
+    pub client: &'a reqwest::Client,
+    pub url: &'a str,
+    /// Where the bytes land. Between attempts the partial file stays here,
+    /// with its source recorded in a `.download.json` file next to it.
+    pub path: &'a Path,
+    pub throttle: &'a DownloadThrottle,
+    pub cancel: &'a CancellationToken,
+}
+
+fn meta_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".download.json");
+    path.with_file_name(name)
+}
+
+async fn read_meta(path: &Path) -> Option<PartialDownloadMeta> {
+    let raw = fs::read(meta_path(path)).await.ok()?;
+    serde_json::from_slice(&raw).ok()
+}
+
+/// Bytes of `path` a later download can keep; `0` when there is nothing to
+/// resume.
+pub async fn partial_size(path: &Path) -> u64 {
+    if read_meta(path).await.is_none() {
+        return 0;
+    }
+    fs::metadata(path)
+        .await
+        .map(|metadata| metadata.len())
+        .unwrap_or(0)
+}
+
+/// Removes a partial download, e.g. after its checksum did not match.
+pub async fn discard_partial(path: &Path) {
+    let _ = fs::remove_file(path).await;
+    let _ = fs::remove_file(meta_path(path)).await;
+}
+
+/// Downloads `url` into `path` under the request's throttle and returns the
+/// file's SHA-256. A partial file from an earlier attempt with the same URL
+/// is continued with a range request; servers that ignore the range, or
+/// whose file changed since, send the whole body and the download restarts.
+/// A range that does not start at the partial file's end is re-requested in
+/// full.
+///
+/// Cancellation and network errors keep the partial file for the next
+/// attempt. `on_progress` receives the bytes on disk and the total size when
+/// the server reports it.
+pub async fn download_resumable<F>(
+    request: ResumableDownload<'_>,
+    mut on_progress: F,
+) -> BitFunResult<DownloadedFile>
+where
+    F: FnMut(u64, Option<u64>),
+{
+    let ResumableDownload {
+        client,
+        url,
+        path,
+        throttle,
+        cancel,
+    } = request;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    let meta = read_meta(path).await.filter(|meta| meta.url == url);
+    let offset = match &meta {
+        Some(_) => fs::metadata(path).await.map(|m| m.len()).unwrap_or(0),
+        None => 0,
+    };
+
+    let mut response = send(client, url, offset, meta.as_ref(), cancel).await?;
+    let unresumable = match response.status() {
+        // The partial file is not a prefix of the current resource.
+        StatusCode::RANGE_NOT_SATISFIABLE => true,
+        // A range other than the one requested cannot be appended.
+        StatusCode::PARTIAL_CONTENT => content_range_start(&response) != Some(offset),
+        _ => false,
+    };
+    if offset > 0 && unresumable {
+        debug!("Discarding unresumable partial download: url={}", url);
+        response = send(client, url, 0, None, cancel).await?;
+    }
+    let response = response
+        .error_for_status()
+        .map_err(|error| BitFunError::http(error.to_string()))?;
+
+    let resumed_from = if response.status() == StatusCode::PARTIAL_CONTENT
+        && content_range_start(&response) == Some(offset)
+    {
+        offset
+    } else {
+        0
+    };
+    let total = response
+        .content_length()
+        .map(|length| length.saturating_add(resumed_from));
+
+    let meta = PartialDownloadMeta {
+        url: url.to_string(),
+        etag: header_value(&response, ETAG),
+        last_modified: header_value(&response, LAST_MODIFIED),
+    };
+    fs::write(meta_path(path), serde_json::to_vec(&meta)?).await?;
+
+    let mut hasher = StreamingHasher::new(HashAlgorithm::Sha256);
+    let mut file = if resumed_from > 0 {
+        info!(
+            "Resuming download: url={}, resumed_from={}",
+            url, resumed_from
+        );
+        hash_existing(path, &mut hasher).await?;
+        fs::OpenOptions::new().append(true).open(path).await?
+    } else {
+        fs::File::create(path).await?
+    };
+
+    let mut downloaded = resumed_from;
+    on_progress(downloaded, total);
+    let mut stream = response.bytes_stream();
+    loop {
+        let next_chunk = tokio::select! {
+            _ = cancel.cancelled() => {
+                file.flush().await?;
+                return Err(BitFunError::cancelled("Download cancelled"));
+            }
+            chunk = stream.next() => chunk,
+        };
+        let Some(chunk) = next_chunk else {
+            break;
+        };
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                file.flush().await?;
+                return Err(BitFunError::http(error.to_string()));
+            }
+        };
+        file.write_all(&chunk).await?;
+        hasher.update(&chunk);
+        downloaded += chunk.len() as u64;
+        on_progress(downloaded, total);
+        tokio::select! {
+            _ = cancel.cancelled() => {}
+            _ = throttle.acquire(chunk.len() as u64) => {}
+        }
+    }
+    file.flush().await?;
+    let _ = fs::remove_file(meta_path(path)).await;
+
+    Ok(DownloadedFile {
+        sha256: hasher.finalize_hex(),
+        size_bytes: downloaded,
+        resumed_from,
+    })
+}
+
+async fn send(
+    client: &reqwest::Client,
+    url: &str,
+    offset: u64,
+    meta: Option<&PartialDownloadMeta>,
+    cancel: &CancellationToken,
+) -> BitFunResult<reqwest::Response> {
+    let mut request = client.get(url).header(USER_AGENT, "BitFun");
+    if offset > 0 {
+        request = request.header(RANGE, format!("bytes={}-", offset));
+        if let Some(validator) = meta.and_then(PartialDownloadMeta::validator) {
+            request = request.header(IF_RANGE, validator);
+        }
+    }
+    tokio::select! {
+        _ = cancel.cancelled() => Err(BitFunError::cancelled("Download cancelled")),
+        response = request.send() => {
+            response.map_err(|error| BitFunError::http(error.to_string()))
+        }
+    }
+}
+
+fn header_value(response: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// First byte position from `Content-Range: bytes <start>-<end>/<size>`.
+fn content_range_start(response: &reqwest::Response) -> Option<u64> {
+    parse_content_range_start(&header_value(response, CONTENT_RANGE)?)
+}
+
+fn parse_content_range_start(value: &str) -> Option<u64> {
+    let range = value.trim().strip_prefix("bytes ")?;
+    let (start, _) = range.split_once('-')?;
+    start.trim().parse().ok()
+}
+
+async fn hash_existing(path: &Path, hasher: &mut StreamingHasher) -> BitFunResult<()> {
+    let mut file = fs::File::open(path).await?;
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_content_range_and_prefers_strong_validators() {
+        assert_eq!(
+            parse_content_range_start("bytes 1048576-2097151/2097152"),
+            Some(1_048_576)
+        );
+        assert_eq!(parse_content_range_start("bytes */2097152"), None);
+
+        let meta = PartialDownloadMeta {
+            url: "https://example.com/node.tar.gz".to_string(),
+            etag: Some("W/\"abc\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2026 07:28:00 GMT".to_string()),
+        };
+        assert_eq!(meta.validator(), Some("Wed, 21 Oct 2026 07:28:00 GMT"));
+    }
+
+    #[tokio::test]
+    async fn partial_size_requires_a_recorded_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.tar.gz");
+        fs::write(&path, b"partial").await.unwrap();
+        assert_eq!(partial_size(&path).await, 0);
+
+        let meta = PartialDownloadMeta {
+            url: "https://example.com/node.tar.gz".to_string(),
+            ..Default::default()
+        };
+        fs::write(meta_path(&path), serde_json::to_vec(&meta).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(partial_size(&path).await, 7);
+
+        discard_partial(&path).await;
+        assert!(!path.exists());
+        assert!(!meta_path(&path).exists());
+    }
+
+    #[tokio::test]
+    async fn mismatched_content_range_restarts_without_range() {
+        use std::io::ErrorKind;
+        use tokio::net::TcpListener;
+
+        const BODY: &str = "0123456789";
+        let listener = match TcpListener::bind("127.0.0.1:0").await {
+            Ok(listener) => listener,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                eprintln!(
+                    "Skipping resumable download test due to sandbox socket restrictions: {e}"
+                );
+                return;
+            }
+            Err(e) => panic!("bind local test server: {e}"),
+        };
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = [0u8; 2048];
+                let read = socket.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_ascii_lowercase();
+                let response = if request.contains("\r\nrange:") {
+                    // Asked for `bytes=5-` but answers from byte 2.
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 2-9/10\r\nContent-Length: 8\r\nConnection: close\r\n\r\n{}",
+                        &BODY[2..]
+                    )
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n{BODY}"
+                    )
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+                let _ = socket.shutdown().await;
+                requests.push(request);
+            }
+            requests
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.tar.gz");
+        let url = format!("http://{addr}/node.tar.gz");
+        fs::write(&path, &BODY[..5]).await.unwrap();
+        let meta = PartialDownloadMeta {
+            url: url.clone(),
+            ..Default::default()
+        };
+        fs::write(meta_path(&path), serde_json::to_vec(&meta).unwrap())
+            .await
+            .unwrap();
+
+        let client = reqwest::Client::new();
+        let throttle = DownloadThrottle::global();
+        let cancel = CancellationToken::new();
+        let downloaded = download_resumable(
+            ResumableDownload {
+                client: &client,
+                url: &url,
+                path: &path,
+                throttle: &throttle,
+                cancel: &cancel,
+            },
+            |_, _| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(downloaded.resumed_from, 0);
+        assert_eq!(downloaded.size_bytes, 10);
+        assert_eq!(fs::read_to_string(&path).await.unwrap(), BODY);
+        let requests = server.await.unwrap();
+        assert!(requests[0].contains("range: bytes=5-"));
+        assert!(!requests[1].contains("range:"));
+    }
+}
@@ -0,0 +1,181 @@
+//! Global and per-operation download speed caps.
+
+use super::types::{DownloadSettings, DownloadSpeedLimits, OperationSpeedLimit};
+use crate::service::config::{
+    get_global_config_service, subscribe_config_updates, ConfigUpdateEvent,
+};
+use crate::util::errors::{BitFunError, BitFunResult};
+use bitfun_services_core::bandwidth::{global_download_limiter, BandwidthLimiter};
+use log::{debug, info};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+const SETTINGS_PATH: &str = "app.downloads";
+
+static OPERATION_LIMITERS: LazyLock<Mutex<HashMap<String, Arc<BandwidthLimiter>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn operation_limiters() -> std::sync::MutexGuard<'static, HashMap<String, Arc<BandwidthLimiter>>> {
+    match OPERATION_LIMITERS.lock() {
+        Ok(limiters) => limiters,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Speed caps one download honours: always the global cap, plus the cap of
+/// its operation when it runs as one.
+pub struct DownloadThrottle {
+    operation: Option<(String, Arc<BandwidthLimiter>)>,
+}
+
+impl DownloadThrottle {
+    pub fn global() -> Self {
+        Self { operation: None }
+    }
+
+    /// Registers an override for `operation_id` that
+    /// [`set_download_speed_limit`] can change while the download runs.
+    /// `None` or `0` starts without an override.
+    pub fn for_operation(operation_id: &str, max_bytes_per_sec: Option<u64>) -> Self {
+        let limiter = Arc::new(BandwidthLimiter::new(max_bytes_per_sec.unwrap_or(0)));
+        operation_limiters().insert(operation_id.to_string(), Arc::clone(&limiter));
+        Self {
+            operation: Some((operation_id.to_string(), limiter)),
+        }
+    }
+
+    /// Call after receiving `bytes`; waits while either cap is exceeded.
+    pub async fn acquire(&self, bytes: u64) {
+        global_download_limiter().acquire(bytes).await;
+        if let Some((_, limiter)) = &self.operation {
+            limiter.acquire(bytes).await;
+        }
+    }
+}
+
+impl Drop for DownloadThrottle {
+    fn drop(&mut self) {
+        if let Some((operation_id, limiter)) = &self.operation {
+            let mut limiters = operation_limiters();
+            if limiters
+                .get(operation_id)
+                .is_some_and(|registered| Arc::ptr_eq(registered, limiter))
+            {
+                limiters.remove(operation_id);
+            }
+        }
+    }
+}
+
+pub fn download_speed_limits() -> DownloadSpeedLimits {
+    let mut operations = operation_limiters()
+        .iter()
+        .map(|(operation_id, limiter)| OperationSpeedLimit {
+            operation_id: operation_id.clone(),
+            max_bytes_per_sec: limiter.limit(),
+        })
+        .collect::<Vec<_>>();
+    operations.sort_by(|a, b| a.operation_id.cmp(&b.operation_id));
+    DownloadSpeedLimits {
+        max_bytes_per_sec: global_download_limiter().limit(),
+        operations,
+    }
+}
+
+/// Changes a cap immediately, including for downloads in progress. Without
+/// `operation_id` the global cap changes and is saved to `app.downloads`.
+pub async fn set_download_speed_limit(
+    operation_id: Option<&str>,
+    max_bytes_per_sec: u64,
+) -> BitFunResult<()> {
+    let Some(operation_id) = operation_id else {
+        global_download_limiter().set_limit(max_bytes_per_sec);
+        info!(
+            "Download speed limit changed: max_bytes_per_sec={}",
+            max_bytes_per_sec
+        );
+        let config = get_global_config_service().await?;
+        return config
+            .set_config(
+                &format!("{}.max_bytes_per_sec", SETTINGS_PATH),
+                max_bytes_per_sec,
+            )
+            .await;
+    };
+
+    let limiter = operation_limiters().get(operation_id).cloned();
+    let limiter = limiter.ok_or_else(|| {
+        BitFunError::NotFound(format!(
+            "No running download for operation '{}'",
+            operation_id
+        ))
+    })?;
+    limiter.set_limit(max_bytes_per_sec);
+    debug!(
+        "Operation download speed limit changed: operation_id={}, max_bytes_per_sec={}",
+        operation_id, max_bytes_per_sec
+    );
+    Ok(())
+}
+
+async fn apply_download_settings() {
+    let settings = match get_global_config_service().await {
+        Ok(config) => config
+            .get_config::<DownloadSettings>(Some(SETTINGS_PATH))
+            .await
+            .unwrap_or_else(|e| {
+                debug!("Using default download settings: {}", e);
+                DownloadSettings::default()
+            }),
+        Err(_) => DownloadSettings::default(),
+    };
+    global_download_limiter().set_limit(settings.max_bytes_per_sec);
+}
+
+/// Applies `app.downloads` now and again whenever app settings change.
+pub fn start_download_settings_listener() {
+    let config_updates = subscribe_config_updates();
+    tokio::spawn(async move {
+        apply_download_settings().await;
+        let Some(mut config_updates) = config_updates else {
+            return;
+        };
+        loop {
+            match config_updates.recv().await {
+                Ok(ConfigUpdateEvent::AppUpdated | ConfigUpdateEvent::ConfigReloaded) => {
+                    apply_download_settings().await
+                }
+                Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn operation_override_is_adjustable_while_registered() {
+        let throttle = DownloadThrottle::for_operation("throttle-test-op", Some(1_000));
+        assert!(download_speed_limits()
+            .operations
+            .contains(&OperationSpeedLimit {
+                operation_id: "throttle-test-op".to_string(),
+                max_bytes_per_sec: 1_000,
+            }));
+
+        set_download_speed_limit(Some("throttle-test-op"), 5_000)
+            .await
+            .unwrap();
+        let (_, limiter) = throttle.operation.as_ref().unwrap();
+        assert_eq!(limiter.limit(), 5_000);
+
+        drop(throttle);
+        assert!(matches!(
+            set_download_speed_limit(Some("throttle-test-op"), 1).await,
+            Err(BitFunError::NotFound(_))
+        ));
+    }
+}
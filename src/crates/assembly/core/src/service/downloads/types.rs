@@ -0,0 +1,58 @@
+//! Download types.
+
+use serde::{Deserialize, Serialize};
+
+/// User settings stored under `app.downloads`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DownloadSettings {
+    /// Combined cap for large downloads in bytes per second; `0` is unlimited.
+    pub max_bytes_per_sec: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationSpeedLimit {
+    pub operation_id: String,
+    /// `0` means only the global cap applies.
+    pub max_bytes_per_sec: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadSpeedLimits {
+    pub max_bytes_per_sec: u64,
+    /// Running downloads that accept a per-operation override.
+    pub operations: Vec<OperationSpeedLimit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedFile {
+    pub sha256: String,
+    pub size_bytes: u64,
+    /// Bytes kept from an earlier attempt; `0` for a fresh download.
+    pub resumed_from: u64,
+}
+
+/// Written next to a partial download so a later attempt only continues it
+/// when the bytes came from the same source.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PartialDownloadMeta {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl PartialDownloadMeta {
+    /// `If-Range` validator; servers send the full body when it no longer
+    /// matches, so a changed file is never spliced onto old bytes.
+    pub fn validator(&self) -> Option<&str> {
+        self.etag
+            .as_deref()
+            .filter(|etag| !etag.starts_with("W/"))
+            .or(self.last_modified.as_deref())
+    }
+}
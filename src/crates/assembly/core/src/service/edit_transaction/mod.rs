@@ -0,0 +1,14 @@
+//! Edit transaction service
+//!
+//! Stages edits across many files, checks the result for syntax errors and
+//! with an optional build or test command, and keeps all of the changes or
+//! none of them.
+
+mod service;
+mod types;
+
+pub use service::EditTransactionService;
+pub use types::{
+    EditTransactionOptions, EditTransactionReport, EditTransactionStatus, StagedEdit,
+    TransactionFileReport, ValidationCommand, ValidationRun,
+};
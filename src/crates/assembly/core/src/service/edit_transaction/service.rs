@@ -0,0 +1,410 @@
+//! Edit transaction service.
+//!
+//! Applies every staged edit in memory first, so an edit that does not match
+//! fails the whole transaction before anything is written. The staged files
+//! are then syntax-checked, written together through the patch service, and
+//! validated with the configured build or test command; a failed validation
+//! restores every file.
+
+use super::types::{
+    EditTransactionOptions, EditTransactionReport, EditTransactionStatus, StagedEdit,
+    TransactionFileReport, ValidationCommand, ValidationRun,
+};
+use crate::service::code_index::{check_syntax, SyntaxError};
+use crate::service::file_ops::resolve_in_workspace;
+use crate::service::patch::{read_text, write_all, FileWrite, PatchFileChange};
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::process_manager;
+use log::{info, warn};
+use similar::{ChangeTag, TextDiff};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tool_runtime::fs::edit_file::apply_edit_to_content;
+
+const MAX_VALIDATION_TIMEOUT_SECS: u64 = 30 * 60;
+const VALIDATION_OUTPUT_TAIL_CHARS: usize = 4000;
+
+pub struct EditTransactionService;
+
+/// A file with all of its edits applied in memory.
+struct StagedFile {
+    path: PathBuf,
+    display_path: String,
+    original: Option<String>,
+    updated: String,
+    edit_count: usize,
+}
+
+impl EditTransactionService {
+    /// Stages `edits`, checks them and, unless `options.dry_run` is set,
+    /// writes them all or none. An edit that cannot be applied is returned
+    /// as an error; failed checks are reported with the per-file diffs.
+    pub async fn run(
+        workspace_root: &Path,
+        edits: &[StagedEdit],
+        options: &EditTransactionOptions,
+    ) -> BitFunResult<EditTransactionReport> {
+        if edits.is_empty() {
+            return Err(BitFunError::validation(
+                "An edit transaction needs at least one edit".to_string(),
+            ));
+        }
+        let staged = stage_edits(workspace_root, edits).await?;
+        let mut report = EditTransactionReport {
+            status: EditTransactionStatus::Previewed,
+            files: staged.iter().map(file_report).collect(),
+            validation: None,
+            reason: None,
+        };
+
+        if options.check_syntax {
+            for (file, file_report) in staged.iter().zip(report.files.iter_mut()) {
+                file_report.syntax_errors = introduced_syntax_errors(file);
+            }
+            let broken = report
+                .files
+                .iter()
+                .filter(|file| !file.syntax_errors.is_empty())
+                .map(|file| file.path.as_str())
+                .collect::<Vec<_>>();
+            if !broken.is_empty() {
+                report.reason = Some(format!(
+                    "The edits introduce syntax errors in {}",
+                    broken.join(", ")
+                ));
+                report.status = EditTransactionStatus::Rejected;
+                return Ok(report);
+            }
+        }
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        let writes = staged
+            .into_iter()
+            .filter(|file| file.original.as_deref() != Some(file.updated.as_str()))
+            .map(|file| FileWrite {
+                source: file.original.is_some().then(|| file.path.clone()),
+                target: Some(file.path),
+                original: file.original,
+                updated: Some(file.updated),
+            })
+            .collect::<Vec<_>>();
+        let committed = write_all(&writes).await?;
+
+        if let Some(command) = &options.validation {
+            let run = run_validation(workspace_root, command).await;
+            let passed = run.passed;
+            report.validation = Some(run);
+            if !passed {
+                committed.revert().await;
+                warn!(
+                    "Edit transaction rolled back after failed validation: workspace={}, command={}",
+                    workspace_root.display(),
+                    command.display()
+                );
+                report.reason = Some(format!("Validation failed: {}", command.display()));
+                report.status = EditTransactionStatus::RolledBack;
+                return Ok(report);
+            }
+        }
+
+        info!(
+            "Edit transaction committed: workspace={}, files={}",
+            workspace_root.display(),
+            writes.len()
+        );
+        report.status = EditTransactionStatus::Committed;
+        Ok(report)
+    }
+}
+
+async fn stage_edits(workspace_root: &Path, edits: &[StagedEdit]) -> BitFunResult<Vec<StagedFile>> {
+    let root = dunce::canonicalize(workspace_root).unwrap_or_else(|_| workspace_root.to_path_buf());
+    let mut files: Vec<StagedFile> = Vec::new();
+    let mut index_by_path = HashMap::new();
+
+    for (edit_index, edit) in edits.iter().enumerate() {
+        let path = resolve_in_workspace(workspace_root, Path::new(edit.path()))?;
+        let index = match index_by_path.get(&path) {
+            Some(index) => *index,
+            None => {
+                let original = read_text(&path).await?;
+                files.push(StagedFile {
+                    display_path: display_path(&root, &path),
+                    updated: original.clone().unwrap_or_default(),
+                    original,
+                    path: path.clone(),
+                    edit_count: 0,
+                });
+                index_by_path.insert(path, files.len() - 1);
+                files.len() - 1
+            }
+        };
+
+        let file = &mut files[index];
+        file.updated = match edit {
+            StagedEdit::Write { content, .. } => content.clone(),
+            StagedEdit::Replace { .. } if file.original.is_none() && file.edit_count == 0 => {
+                return Err(edit_error(
+                    edit_index,
+                    &file.display_path,
+                    "the file does not exist; stage a write to create it",
+                ));
+            }
+            StagedEdit::Replace {
+                old_string,
+                new_string,
+                replace_all,
+                ..
+            } => {
+                apply_edit_to_content(&file.updated, old_string, new_string, *replace_all)
+                    .map_err(|error| edit_error(edit_index, &file.display_path, &error))?
+                    .new_content
+            }
+        };
+        file.edit_count += 1;
+    }
+    Ok(files)
+}
+
+fn edit_error(edit_index: usize, path: &str, reason: &str) -> BitFunError {
+    BitFunError::validation(format!(
+        "Edit {} ({}) cannot be applied: {}",
+        edit_index + 1,
+        path,
+        reason
+    ))
+}
+
+fn display_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn file_report(file: &StagedFile) -> TransactionFileReport {
+    let original = file.original.as_deref().unwrap_or_default();
+    let diff = TextDiff::from_lines(original, &file.updated);
+    let (mut additions, mut deletions) = (0, 0);
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => additions += 1,
+            ChangeTag::Delete => deletions += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+    let old_header = if file.original.is_some() {
+        format!("a/{}", file.display_path)
+    } else {
+        "/dev/null".to_string()
+    };
+    let new_header = format!("b/{}", file.display_path);
+
+    TransactionFileReport {
+        path: file.display_path.clone(),
+        change: if file.original.is_some() {
+            PatchFileChange::Modify
+        } else {
+            PatchFileChange::Create
+        },
+        edit_count: file.edit_count,
+        additions,
+        deletions,
+        diff: diff
+            .unified_diff()
+            .header(&old_header, &new_header)
+            .to_string(),
+        syntax_errors: Vec::new(),
+    }
+}
+
+/// Files that already failed to parse before the transaction are not held
+/// against it.
+fn introduced_syntax_errors(file: &StagedFile) -> Vec<SyntaxError> {
+    let original_is_clean = match &file.original {
+        Some(original) => check_syntax(&file.path, original).is_some_and(|e| e.is_empty()),
+        None => true,
+    };
+    if !original_is_clean {
+        return Vec::new();
+    }
+    check_syntax(&file.path, &file.updated).unwrap_or_default()
+}
+
+async fn run_validation(workspace_root: &Path, command: &ValidationCommand) -> ValidationRun {
+    let mut run = ValidationRun {
+        command: command.display(),
+        passed: false,
+        exit_code: None,
+        timed_out: false,
+        output: String::new(),
+    };
+    let mut process = process_manager::create_tokio_command(&command.program);
+    process
+        .args(&command.args)
+        .current_dir(workspace_root)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let child = match process.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            run.output = format!("Failed to start '{}': {}", command.program, error);
+            return run;
+        }
+    };
+    let timeout = Duration::from_secs(command.timeout_secs.clamp(1, MAX_VALIDATION_TIMEOUT_SECS));
+    // Dropping the child on timeout kills it (kill_on_drop).
+    match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        Ok(Ok(output)) => {
+            run.exit_code = output.status.code();
+            run.passed = output.status.success();
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            run.output = output_tail(&text);
+        }
+        Ok(Err(error)) => run.output = format!("Validation process failed: {}", error),
+        Err(_) => {
+            run.timed_out = true;
+            run.output = format!("Validation timed out after {}s", timeout.as_secs());
+        }
+    }
+    run
+}
+
+fn output_tail(text: &str) -> String {
+    let text = text.trim();
+    let skip = text
+        .chars()
+        .count()
+        .saturating_sub(VALIDATION_OUTPUT_TAIL_CHARS);
+    text.chars().skip(skip).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace(path: &str, old_string: &str, new_string: &str) -> StagedEdit {
+        StagedEdit::Replace {
+            path: path.to_string(),
+            old_string: old_string.to_string(),
+            new_string: new_string.to_string(),
+            replace_all: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn stages_edits_per_file_and_writes_all_or_nothing() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::write(root.join("a.rs"), "fn a() {}\nfn b() {}\n").expect("write a");
+
+        let edits = vec![
+            replace("a.rs", "fn a()", "fn alpha()"),
+            replace("a.rs", "fn b()", "fn beta()"),
+            StagedEdit::Write {
+                path: "src/new.txt".to_string(),
+                content: "hello\n".to_string(),
+            },
+        ];
+        let options = EditTransactionOptions {
+            check_syntax: true,
+            ..Default::default()
+        };
+
+        let missing = [edits.clone(), vec![replace("a.rs", "fn gamma()", "x")]].concat();
+        assert!(EditTransactionService::run(root, &missing, &options)
+            .await
+            .is_err());
+        assert!(!root.join("src/new.txt").exists());
+
+        let report = EditTransactionService::run(root, &edits, &options)
+            .await
+            .expect("run transaction");
+        assert_eq!(report.status, EditTransactionStatus::Committed);
+        assert_eq!(report.files[0].edit_count, 2);
+        assert_eq!(report.files[0].change, PatchFileChange::Modify);
+        assert!(report.files[0].diff.contains("+fn alpha() {}"));
+        assert_eq!(report.files[1].change, PatchFileChange::Create);
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.rs")).expect("read a"),
+            "fn alpha() {}\nfn beta() {}\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/new.txt")).expect("read new"),
+            "hello\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_introduced_syntax_errors_without_writing() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::write(root.join("lib.rs"), "fn main() {}\n").expect("write lib");
+
+        let report = EditTransactionService::run(
+            root,
+            &[replace("lib.rs", "fn main() {}", "fn main() {")],
+            &EditTransactionOptions {
+                check_syntax: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("run transaction");
+
+        assert_eq!(report.status, EditTransactionStatus::Rejected);
+        assert!(!report.files[0].syntax_errors.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(root.join("lib.rs")).expect("read lib"),
+            "fn main() {}\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn failed_validation_rolls_every_file_back() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::write(root.join("a.txt"), "one\n").expect("write a");
+
+        let report = EditTransactionService::run(
+            root,
+            &[
+                replace("a.txt", "one", "two"),
+                StagedEdit::Write {
+                    path: "b.txt".to_string(),
+                    content: "new\n".to_string(),
+                },
+            ],
+            &EditTransactionOptions {
+                validation: Some(ValidationCommand {
+                    program: "false".to_string(),
+                    args: Vec::new(),
+                    timeout_secs: 30,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("run transaction");
+
+        assert_eq!(report.status, EditTransactionStatus::RolledBack);
+        assert_eq!(
+            report.validation.as_ref().map(|run| run.passed),
+            Some(false)
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).expect("read a"),
+            "one\n"
+        );
+        assert!(!root.join("b.txt").exists());
+    }
+}
@@ -0,0 +1,116 @@
+//! Edit transaction request and report types.
+
+use crate::service::code_index::SyntaxError;
+use crate::service::patch::PatchFileChange;
+use serde::{Deserialize, Serialize};
+
+/// One change staged in a transaction. Several edits may target the same
+/// file; they apply in order to the file's staged content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StagedEdit {
+    /// Exact string replacement, as done by the Edit tool.
+    Replace {
+        path: String,
+        old_string: String,
+        new_string: String,
+        #[serde(default)]
+        replace_all: bool,
+    },
+    /// Full file content; creates the file when it does not exist.
+    Write { path: String, content: String },
+}
+
+impl StagedEdit {
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Replace { path, .. } | Self::Write { path, .. } => path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditTransactionOptions {
+    pub check_syntax: bool,
+    /// Run after the files are written; failure rolls them back.
+    pub validation: Option<ValidationCommand>,
+    /// Report the staged result without writing anything.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub timeout_secs: u64,
+}
+
+impl ValidationCommand {
+    pub fn display(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditTransactionStatus {
+    /// Dry run; nothing was written.
+    Previewed,
+    Committed,
+    /// A pre-write check failed; nothing was written.
+    Rejected,
+    /// Written, then restored because validation failed.
+    RolledBack,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTransactionReport {
+    pub status: EditTransactionStatus,
+    pub files: Vec<TransactionFileReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ValidationRun>,
+    /// Why the transaction was rejected or rolled back.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl EditTransactionReport {
+    pub fn changed_files(&self) -> impl Iterator<Item = &TransactionFileReport> {
+        self.files
+            .iter()
+            .filter(|file| file.additions > 0 || file.deletions > 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFileReport {
+    /// Workspace-relative path with `/` separators.
+    pub path: String,
+    /// `Create` or `Modify`.
+    pub change: PatchFileChange,
+    pub edit_count: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Unified diff of the staged change.
+    pub diff: String,
+    /// Parse errors the transaction would introduce.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub syntax_errors: Vec<SyntaxError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationRun {
+    pub command: String,
+    pub passed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    /// Tail of combined stdout and stderr.
+    pub output: String,
+}
@@ -0,0 +1,13 @@
+//! File operation service
+//!
+//! Workspace-confined create/rename/copy/delete with OS trash and undo.
+
+mod service;
+mod trash_bin;
+mod types;
+
+pub use service::{
+    get_global_file_operation_service, resolve_in_workspace, set_global_file_operation_service,
+    FileOperationService, DEFAULT_JOURNAL_LIMIT,
+};
+pub use types::{FileOperationEvent, FileOperationKind, FileOperationRecord};
@@ -0,0 +1,589 @@
+//! File operation service.
+//!
+//! Single entry point for create/rename/copy/delete requested by the agent or
+//! the UI. Paths are confined to the workspace root, deletions go to the OS
+//! trash, and recent operations are journaled so they can be undone. Where no
+//! trash exists, a delete is only made permanent when the caller asks for it.
+
+use super::trash_bin::{move_to_trash, restore_from_trash};
+use super::types::{FileOperationEvent, FileOperationKind, FileOperationRecord};
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info, warn};
+use std::collections::VecDeque;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{broadcast, Mutex};
+
+static GLOBAL_FILE_OPERATION_SERVICE: OnceLock<Arc<FileOperationService>> = OnceLock::new();
+
+pub const DEFAULT_JOURNAL_LIMIT: usize = 50;
+const EVENT_CHANNEL_CAPACITY: usize = 128;
+
+pub struct FileOperationService {
+    journal: Mutex<VecDeque<FileOperationRecord>>,
+    journal_limit: usize,
+    events: broadcast::Sender<FileOperationEvent>,
+}
+
+impl FileOperationService {
+    pub fn new() -> Arc<Self> {
+        Self::with_journal_limit(DEFAULT_JOURNAL_LIMIT)
+    }
+
+    pub fn with_journal_limit(journal_limit: usize) -> Arc<Self> {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Arc::new(Self {
+            journal: Mutex::new(VecDeque::new()),
+            journal_limit: journal_limit.max(1),
+            events,
+        })
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<FileOperationEvent> {
+        self.events.subscribe()
+    }
+
+    pub async fn create_file(
+        &self,
+        workspace_root: &Path,
+        path: &Path,
+        contents: &[u8],
+    ) -> BitFunResult<FileOperationRecord> {
+        let target = resolve_in_workspace(workspace_root, path)?;
+        ensure_absent(&target).await?;
+        ensure_parent_dir(&target).await?;
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&target)
+            .await
+            .map_err(|error| io_error("create file", &target, error))?;
+        file.write_all(contents)
+            .await
+            .map_err(|error| io_error("write file", &target, error))?;
+
+        Ok(self
+            .record(FileOperationKind::CreateFile, &target, None, false)
+            .await)
+    }
+
+    pub async fn create_directory(
+        &self,
+        workspace_root: &Path,
+        path: &Path,
+    ) -> BitFunResult<FileOperationRecord> {
+        let target = resolve_in_workspace(workspace_root, path)?;
+        ensure_absent(&target).await?;
+        fs::create_dir_all(&target)
+            .await
+            .map_err(|error| io_error("create directory", &target, error))?;
+
+        Ok(self
+            .record(FileOperationKind::CreateDirectory, &target, None, true)
+            .await)
+    }
+
+    /// Renames or moves `from` to `to`; both must stay inside the workspace.
+    pub async fn rename(
+        &self,
+        workspace_root: &Path,
+        from: &Path,
+        to: &Path,
+    ) -> BitFunResult<FileOperationRecord> {
+        let source = resolve_mutable_path(workspace_root, from)?;
+        let target = resolve_in_workspace(workspace_root, to)?;
+        let is_directory = existing_metadata(&source).await?.is_dir();
+        if source != target {
+            ensure_absent(&target).await?;
+        }
+        ensure_parent_dir(&target).await?;
+        fs::rename(&source, &target)
+            .await
+            .map_err(|error| io_error("rename", &source, error))?;
+
+        Ok(self
+            .record(
+                FileOperationKind::Rename,
+                &target,
+                Some(&source),
+                is_directory,
+            )
+            .await)
+    }
+
+    pub async fn copy(
+        &self,
+        workspace_root: &Path,
+        from: &Path,
+        to: &Path,
+    ) -> BitFunResult<FileOperationRecord> {
+        let source = resolve_in_workspace(workspace_root, from)?;
+        let target = resolve_in_workspace(workspace_root, to)?;
+        let is_directory = existing_metadata(&source).await?.is_dir();
+        if target.starts_with(&source) {
+            return Err(BitFunError::validation(format!(
+                "Cannot copy {} into itself",
+                source.display()
+            )));
+        }
+        ensure_absent(&target).await?;
+        ensure_parent_dir(&target).await?;
+
+        let (copy_source, copy_target) = (source.clone(), target.clone());
+        tokio::task::spawn_blocking(move || copy_recursively(&copy_source, &copy_target))
+            .await
+            .map_err(|error| BitFunError::service(format!("Copy task failed: {}", error)))?
+            .map_err(|error| io_error("copy", &source, error))?;
+
+        Ok(self
+            .record(
+                FileOperationKind::Copy,
+                &target,
+                Some(&source),
+                is_directory,
+            )
+            .await)
+    }
+
+    /// Moves `path` to the OS trash. Non-empty directories need `recursive`.
+    ///
+    /// When the OS has no usable trash for `path`, fails with
+    /// `BitFunError::NotImplemented` unless `permanent_fallback` is set, in
+    /// which case the path is removed for good and journaled as a
+    /// `PermanentDelete`.
+    pub async fn delete(
+        &self,
+        workspace_root: &Path,
+        path: &Path,
+        recursive: bool,
+        permanent_fallback: bool,
+    ) -> BitFunResult<FileOperationRecord> {
+        let target = resolve_mutable_path(workspace_root, path)?;
+        let is_directory = existing_metadata(&target).await?.is_dir();
+        if is_directory && !recursive && !is_empty_dir(&target).await? {
+            return Err(BitFunError::validation(format!(
+                "Directory is not empty: {}",
+                target.display()
+            )));
+        }
+
+        let kind = match trash_blocking(target.clone()).await {
+            Ok(()) => FileOperationKind::Delete,
+            Err(BitFunError::NotImplemented(reason)) if permanent_fallback => {
+                warn!(
+                    "Trash unavailable, deleting permanently: path={}, reason={}",
+                    target.display(),
+                    reason
+                );
+                remove_permanently(&target, is_directory).await?;
+                FileOperationKind::PermanentDelete
+            }
+            Err(error) => return Err(error),
+        };
+
+        Ok(self.record(kind, &target, None, is_directory).await)
+    }
+
+    /// Most recent first.
+    pub async fn recent_operations(&self) -> Vec<FileOperationRecord> {
+        self.journal.lock().await.iter().rev().cloned().collect()
+    }
+
+    /// Undoes the most recent operation that can be undone; permanent
+    /// deletions are skipped.
+    pub async fn undo_last(&self) -> BitFunResult<FileOperationRecord> {
+        let mut journal = self.journal.lock().await;
+        let index = journal
+            .iter()
+            .rposition(|operation| operation.kind.is_undoable())
+            .ok_or_else(|| BitFunError::NotFound("No file operation to undo".to_string()))?;
+        let operation = journal[index].clone();
+        self.revert(&operation).await?;
+        journal.remove(index);
+        drop(journal);
+
+        self.emit(FileOperationEvent::Undone {
+            operation: operation.clone(),
+        });
+        Ok(operation)
+    }
+
+    pub async fn undo(&self, operation_id: &str) -> BitFunResult<FileOperationRecord> {
+        let mut journal = self.journal.lock().await;
+        let index = journal
+            .iter()
+            .position(|operation| operation.id == operation_id)
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!("File operation not found: {}", operation_id))
+            })?;
+        let operation = journal[index].clone();
+        self.revert(&operation).await?;
+        journal.remove(index);
+        drop(journal);
+
+        self.emit(FileOperationEvent::Undone {
+            operation: operation.clone(),
+        });
+        Ok(operation)
+    }
+
+    async fn revert(&self, operation: &FileOperationRecord) -> BitFunResult<()> {
+        let path = PathBuf::from(&operation.path);
+        debug!(
+            "Undoing file operation: id={}, kind={:?}, path={}",
+            operation.id, operation.kind, operation.path
+        );
+
+        match operation.kind {
+            // Undoing a creation goes through the trash too, so edits made
+            // after the creation are never lost.
+            FileOperationKind::CreateFile
+            | FileOperationKind::CreateDirectory
+            | FileOperationKind::Copy => {
+                existing_metadata(&path).await?;
+                trash_blocking(path).await
+            }
+            FileOperationKind::Rename => {
+                let source = operation
+                    .source
+                    .as_deref()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| {
+                        BitFunError::service("Rename record is missing its source path")
+                    })?;
+                existing_metadata(&path).await?;
+                ensure_absent(&source).await?;
+                ensure_parent_dir(&source).await?;
+                fs::rename(&path, &source)
+                    .await
+                    .map_err(|error| io_error("rename", &path, error))
+            }
+            FileOperationKind::PermanentDelete => Err(BitFunError::validation(format!(
+                "{} was deleted permanently and cannot be restored",
+                operation.path
+            ))),
+            FileOperationKind::Delete => {
+                ensure_absent(&path).await?;
+                tokio::task::spawn_blocking(move || restore_from_trash(&path))
+                    .await
+                    .map_err(|error| {
+                        BitFunError::service(format!("Restore task failed: {}", error))
+                    })?
+            }
+        }
+    }
+
+    async fn record(
+        &self,
+        kind: FileOperationKind,
+        path: &Path,
+        source: Option<&Path>,
+        is_directory: bool,
+    ) -> FileOperationRecord {
+        let operation = FileOperationRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            path: path.to_string_lossy().to_string(),
+            source: source.map(|source| source.to_string_lossy().to_string()),
+            is_directory,
+            performed_at_ms: Utc::now().timestamp_millis(),
+        };
+        info!(
+            "File operation applied: kind={:?}, path={}, source={}",
+            operation.kind,
+            operation.path,
+            operation.source.as_deref().unwrap_or("-")
+        );
+
+        {
+            let mut journal = self.journal.lock().await;
+            journal.push_back(operation.clone());
+            while journal.len() > self.journal_limit {
+                journal.pop_front();
+            }
+        }
+
+        self.emit(FileOperationEvent::Applied {
+            operation: operation.clone(),
+        });
+        operation
+    }
+
+    fn emit(&self, event: FileOperationEvent) {
+        let _ = self.events.send(event);
+    }
+}
+
+pub fn get_global_file_operation_service() -> Option<Arc<FileOperationService>> {
+    GLOBAL_FILE_OPERATION_SERVICE.get().cloned()
+}
+
+pub fn set_global_file_operation_service(service: Arc<FileOperationService>) {
+    let _ = GLOBAL_FILE_OPERATION_SERVICE.set(service);
+}
+
+/// Resolves `path` (absolute or relative to `workspace_root`) and rejects
+/// anything that escapes the root, including through `..` or symlinked
+/// parent directories. The final component is not followed, so symlinks
+/// themselves can be renamed or deleted.
+pub fn resolve_in_workspace(workspace_root: &Path, path: &Path) -> BitFunResult<PathBuf> {
+    let root = dunce::canonicalize(workspace_root).map_err(|error| {
+        BitFunError::validation(format!(
+            "Workspace root is not accessible: {}: {}",
+            workspace_root.display(),
+            error
+        ))
+    })?;
+    let joined = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        root.join(path)
+    };
+    let normalized = normalize_lexically(&joined).ok_or_else(|| outside_workspace(path))?;
+
+    let resolved = match (normalized.parent(), normalized.file_name()) {
+        (Some(parent), Some(file_name)) => canonicalize_existing_prefix(parent).join(file_name),
+        _ => canonicalize_existing_prefix(&normalized),
+    };
+    if resolved.starts_with(&root) {
+        Ok(resolved)
+    } else {
+        Err(outside_workspace(path))
+    }
+}
+
+/// Like [`resolve_in_workspace`], but the workspace root itself is rejected.
+fn resolve_mutable_path(workspace_root: &Path, path: &Path) -> BitFunResult<PathBuf> {
+    let resolved = resolve_in_workspace(workspace_root, path)?;
+    let root = dunce::canonicalize(workspace_root)?;
+    if resolved == root {
+        return Err(BitFunError::validation(
+            "The workspace root itself cannot be moved or deleted",
+        ));
+    }
+    Ok(resolved)
+}
+
+fn outside_workspace(path: &Path) -> BitFunError {
+    BitFunError::validation(format!("Path is outside the workspace: {}", path.display()))
+}
+
+fn normalize_lexically(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    Some(normalized)
+}
+
+/// Canonicalizes the deepest existing ancestor and re-appends the rest.
+fn canonicalize_existing_prefix(path: &Path) -> PathBuf {
+    for ancestor in path.ancestors() {
+        if let Ok(canonical) = dunce::canonicalize(ancestor) {
+            let rest = path.strip_prefix(ancestor).unwrap_or(Path::new(""));
+            return canonical.join(rest);
+        }
+    }
+    path.to_path_buf()
+}
+
+async fn existing_metadata(path: &Path) -> BitFunResult<std::fs::Metadata> {
+    fs::symlink_metadata(path)
+        .await
+        .map_err(|_| BitFunError::NotFound(format!("Path does not exist: {}", path.display())))
+}
+
+async fn ensure_absent(path: &Path) -> BitFunResult<()> {
+    if fs::symlink_metadata(path).await.is_ok() {
+        return Err(BitFunError::validation(format!(
+            "Path already exists: {}",
+            path.display()
+        )));
+    }
+    Ok(())
+}
+
+async fn ensure_parent_dir(path: &Path) -> BitFunResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|error| io_error("create directory", parent, error))?;
+    }
+    Ok(())
+}
+
+async fn is_empty_dir(path: &Path) -> BitFunResult<bool> {
+    let mut entries = fs::read_dir(path)
+        .await
+        .map_err(|error| io_error("read directory", path, error))?;
+    Ok(entries
+        .next_entry()
+        .await
+        .map_err(|error| io_error("read directory", path, error))?
+        .is_none())
+}
+
+async fn remove_permanently(path: &Path, is_directory: bool) -> BitFunResult<()> {
+    let result = if is_directory {
+        fs::remove_dir_all(path).await
+    } else {
+        fs::remove_file(path).await
+    };
+    result.map_err(|error| io_error("delete", path, error))
+}
+
+async fn trash_blocking(path: PathBuf) -> BitFunResult<()> {
+    tokio::task::spawn_blocking(move || move_to_trash(&path))
+        .await
+        .map_err(|error| BitFunError::service(format!("Trash task failed: {}", error)))?
+}
+
+/// Symlinked directories are skipped rather than followed to avoid cycles.
+fn copy_recursively(source: &Path, target: &Path) -> std::io::Result<()> {
+    let metadata = std::fs::symlink_metadata(source)?;
+    if metadata.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursively(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        return Ok(());
+    }
+    if metadata.file_type().is_symlink() && std::fs::metadata(source)?.is_dir() {
+        debug!(
+            "Skipping symlinked directory during copy: {}",
+            source.display()
+        );
+        return Ok(());
+    }
+    std::fs::copy(source, target).map(|_| ())
+}
+
+fn io_error(action: &str, path: &Path, error: std::io::Error) -> BitFunError {
+    BitFunError::io(format!(
+        "Failed to {} {}: {}",
+        action,
+        path.display(),
+        error
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rename_and_create_can_be_undone_in_order() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        let service = FileOperationService::new();
+
+        service
+            .create_directory(root, Path::new("src"))
+            .await
+            .expect("create directory");
+        service
+            .create_file(root, Path::new("src/main.rs"), b"fn main() {}")
+            .await
+            .expect("create file");
+        let renamed = service
+            .rename(root, Path::new("src/main.rs"), Path::new("src/bin/app.rs"))
+            .await
+            .expect("rename");
+        assert_eq!(renamed.changed_paths().len(), 2);
+
+        let undone = service.undo_last().await.expect("undo rename");
+        assert_eq!(undone.kind, FileOperationKind::Rename);
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/main.rs")).expect("restored file"),
+            "fn main() {}"
+        );
+        assert!(!root.join("src/bin/app.rs").exists());
+        assert_eq!(service.recent_operations().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn permanent_deletions_are_journaled_but_never_undone() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::write(root.join("notes.txt"), "x").expect("write file");
+        let service = FileOperationService::new();
+
+        service
+            .rename(root, Path::new("notes.txt"), Path::new("todo.txt"))
+            .await
+            .expect("rename");
+        let permanent = service
+            .record(
+                FileOperationKind::PermanentDelete,
+                &root.join("build.log"),
+                None,
+                false,
+            )
+            .await;
+
+        assert!(service.undo(&permanent.id).await.is_err());
+        let undone = service.undo_last().await.expect("undo rename");
+        assert_eq!(undone.kind, FileOperationKind::Rename);
+        assert!(root.join("notes.txt").is_file());
+        assert_eq!(service.recent_operations().await, vec![permanent]);
+        assert!(service.undo_last().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn paths_outside_the_workspace_are_rejected() {
+        let parent = tempfile::tempdir().expect("create parent");
+        let root = parent.path().join("workspace");
+        std::fs::create_dir_all(&root).expect("create workspace");
+        std::fs::write(parent.path().join("secret.txt"), "x").expect("write outside file");
+        let service = FileOperationService::new();
+
+        assert!(resolve_in_workspace(&root, Path::new("../secret.txt")).is_err());
+        assert!(resolve_in_workspace(&root, &parent.path().join("secret.txt")).is_err());
+        assert!(service
+            .rename(&root, Path::new("."), Path::new("moved"))
+            .await
+            .is_err());
+        assert!(service
+            .copy(&root, Path::new("../secret.txt"), Path::new("copy.txt"))
+            .await
+            .is_err());
+        assert!(service.recent_operations().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn copy_rejects_existing_targets_and_self_nesting() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::create_dir_all(root.join("assets/icons")).expect("create assets");
+        std::fs::write(root.join("assets/icons/a.svg"), "<svg/>").expect("write asset");
+        let service = FileOperationService::new();
+
+        service
+            .copy(root, Path::new("assets"), Path::new("assets-copy"))
+            .await
+            .expect("copy directory");
+        assert!(root.join("assets-copy/icons/a.svg").is_file());
+
+        assert!(service
+            .copy(root, Path::new("assets"), Path::new("assets-copy"))
+            .await
+            .is_err());
+        assert!(service
+            .copy(root, Path::new("assets"), Path::new("assets/nested"))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! OS trash integration.
+
+use crate::util::errors::{BitFunError, BitFunResult};
+use std::path::Path;
+
+/// Moves `path` to the OS trash.
+///
+/// Returns `BitFunError::NotImplemented` when the trash refused the path and
+/// left it in place, e.g. on a network mount or in a session without a
+/// desktop trash.
+pub(super) fn move_to_trash(path: &Path) -> BitFunResult<()> {
+    trash::delete(path).map_err(|error| {
+        if std::fs::symlink_metadata(path).is_ok() {
+            BitFunError::NotImplemented(format!(
+                "The system trash is unavailable for {}: {}",
+                path.display(),
+                error
+            ))
+        } else {
+            BitFunError::service(format!(
+                "Failed to move {} to the trash: {}",
+                path.display(),
+                error
+            ))
+        }
+    })
+}
+
+/// Restores the most recently trashed item that originally lived at `original`.
+#[cfg(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+pub(super) fn restore_from_trash(original: &Path) -> BitFunResult<()> {
+    let items = trash::os_limited::list()
+        .map_err(|error| BitFunError::service(format!("Failed to list the trash: {}", error)))?;
+    let item = items
+        .into_iter()
+        .filter(|item| item.original_path() == original)
+        .max_by_key(|item| item.time_deleted)
+        .ok_or_else(|| {
+            BitFunError::NotFound(format!(
+                "No trashed item found for {}; it may have been emptied",
+                original.display()
+            ))
+        })?;
+
+    trash::os_limited::restore_all([item]).map_err(|error| {
+        BitFunError::service(format!(
+            "Failed to restore {} from the trash: {}",
+            original.display(),
+            error
+        ))
+    })
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+pub(super) fn restore_from_trash(original: &Path) -> BitFunResult<()> {
+    Err(BitFunError::NotImplemented(format!(
+        "Restoring from the trash is not supported on this platform; restore {} from the system trash",
+        original.display()
+    )))
+}
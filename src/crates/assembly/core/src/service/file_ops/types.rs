@@ -0,0 +1,63 @@
+//! File operation journal and event types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileOperationKind {
+    CreateFile,
+    CreateDirectory,
+    /// Rename or move within the workspace.
+    Rename,
+    Copy,
+    /// Moved to the OS trash.
+    Delete,
+    /// Removed for good because the OS had no usable trash.
+    PermanentDelete,
+}
+
+impl FileOperationKind {
+    /// Whether the operation can be reverted from the journal.
+    pub fn is_undoable(self) -> bool {
+        self != Self::PermanentDelete
+    }
+}
+
+/// One applied operation, kept in the undo journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileOperationRecord {
+    pub id: String,
+    pub kind: FileOperationKind,
+    /// Path the operation produced or removed.
+    pub path: String,
+    /// Original path for renames and copies.
+    pub source: Option<String>,
+    pub is_directory: bool,
+    pub performed_at_ms: i64,
+}
+
+impl FileOperationRecord {
+    /// Every path whose existence changed when the operation was applied.
+    pub fn changed_paths(&self) -> Vec<String> {
+        match (&self.kind, &self.source) {
+            (FileOperationKind::Rename, Some(source)) => vec![source.clone(), self.path.clone()],
+            _ => vec![self.path.clone()],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FileOperationEvent {
+    Applied { operation: FileOperationRecord },
+    Undone { operation: FileOperationRecord },
+}
+
+impl FileOperationEvent {
+    pub fn operation(&self) -> &FileOperationRecord {
+        match self {
+            Self::Applied { operation } | Self::Undone { operation } => operation,
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! Credential prompt bridge for Git processes started by BitFun.
+//!
+//! Git runs `GIT_ASKPASS` with the prompt as its only argument and reads the
+//! answer from stdout. BitFun points it at a small helper script (a `.cmd`
+//! file on Windows, a POSIX shell script elsewhere) that re-runs the host
+//! binary with [`GIT_ASKPASS_ARG`]; that process forwards the prompt to
+//! the running app over a loopback socket guarded by a per-process token, and
+//! the app asks the user through the UI. Answers are never logged or stored.
+
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader as AsyncBufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, oneshot, Mutex};
+use tokio::time::Duration;
+use uuid::Uuid;
+
+/// First argument that makes the host binary act as the askpass helper.
+pub const GIT_ASKPASS_ARG: &str = "--git-askpass";
+
+const ADDR_ENV: &str = "BITFUN_ASKPASS_ADDR";
+const TOKEN_ENV: &str = "BITFUN_ASKPASS_TOKEN";
+#[cfg(windows)]
+const SCRIPT_FILE: &str = "git-askpass.cmd";
+#[cfg(not(windows))]
+const SCRIPT_FILE: &str = "git-askpass.sh";
+/// Unanswered prompts fail the Git command instead of hanging it.
+const PROMPT_TIMEOUT: Duration = Duration::from_secs(300);
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+const EVENT_CHANNEL_CAPACITY: usize = 32;
+
+static GLOBAL_GIT_ASKPASS_BRIDGE: OnceLock<Arc<GitAskpassBridge>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCredentialPrompt {
+    pub id: String,
+    /// Prompt text from Git, e.g. `Username for 'https://github.com': `.
+    pub prompt: String,
+    /// The answer is a password or token and should be masked.
+    pub secret: bool,
+    pub created_at_ms: i64,
+    pub expires_at_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GitAskpassEvent {
+    Requested { prompt: GitCredentialPrompt },
+    Resolved { id: String, answered: bool },
+}
+
+#[derive(Serialize, Deserialize)]
+struct AskpassRequest {
+    token: String,
+    prompt: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AskpassResponse {
+    answer: Option<String>,
+}
+
+struct PendingPrompt {
+    prompt: GitCredentialPrompt,
+    sender: oneshot::Sender<Option<String>>,
+}
+
+pub struct GitAskpassBridge {
+    addr: String,
+    token: String,
+    script_path: PathBuf,
+    pending: Mutex<HashMap<String, PendingPrompt>>,
+    events: broadcast::Sender<GitAskpassEvent>,
+}
+
+impl GitAskpassBridge {
+    /// Starts listening on a loopback port and writes the askpass script
+    /// into `script_dir`. `host_program` must handle [`GIT_ASKPASS_ARG`].
+    pub async fn start(host_program: &Path, script_dir: &Path) -> BitFunResult<Arc<Self>> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?.to_string();
+        let script_path = write_askpass_script(host_program, script_dir).await?;
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        let bridge = Arc::new(Self {
+            addr,
+            token: Uuid::new_v4().simple().to_string(),
+            script_path,
+            pending: Mutex::new(HashMap::new()),
+            events,
+        });
+        let accept_bridge = bridge.clone();
+        tokio::spawn(async move { accept_bridge.accept_loop(listener).await });
+        info!(
+            "Git askpass bridge listening: addr={}, script={}",
+            bridge.addr,
+            bridge.script_path.display()
+        );
+        Ok(bridge)
+    }
+
+    /// Environment that routes credential prompts of a Git child process to
+    /// this bridge.
+    pub fn child_env(&self) -> Vec<(String, String)> {
+        vec![
+            (
+                "GIT_ASKPASS".to_string(),
+                self.script_path.to_string_lossy().to_string(),
+            ),
+            (ADDR_ENV.to_string(), self.addr.clone()),
+            (TOKEN_ENV.to_string(), self.token.clone()),
+        ]
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<GitAskpassEvent> {
+        self.events.subscribe()
+    }
+
+    /// Prompts waiting for an answer, oldest first.
+    pub async fn list_pending(&self) -> Vec<GitCredentialPrompt> {
+        let mut prompts: Vec<_> = self
+            .pending
+            .lock()
+            .await
+            .values()
+            .filter(|pending| !pending.sender.is_closed())
+            .map(|pending| pending.prompt.clone())
+            .collect();
+        prompts.sort_by_key(|prompt| prompt.created_at_ms);
+        prompts
+    }
+
+    /// Answers a pending prompt. `None` cancels it, which fails the Git
+    /// command.
+    pub async fn respond(&self, prompt_id: &str, answer: Option<String>) -> BitFunResult<()> {
+        let pending = self.pending.lock().await.remove(prompt_id).ok_or_else(|| {
+            BitFunError::NotFound(format!(
+                "Pending Git credential prompt not found: {}",
+                prompt_id
+            ))
+        })?;
+        let answered = answer.is_some();
+        if pending.sender.send(answer).is_err() {
+            warn!(
+                "Git askpass helper went away before the answer arrived: id={}",
+                prompt_id
+            );
+        }
+        self.emit(GitAskpassEvent::Resolved {
+            id: prompt_id.to_string(),
+            answered,
+        });
+        Ok(())
+    }
+
+    async fn accept_loop(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let bridge = self.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = bridge.handle_connection(stream).await {
+                            warn!("Git askpass request failed: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    warn!("Git askpass bridge stopped accepting connections: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn handle_connection(&self, stream: TcpStream) -> BitFunResult<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut line = String::new();
+        AsyncBufReader::new(reader.take(MAX_REQUEST_BYTES))
+            .read_line(&mut line)
+            .await?;
+        let request: AskpassRequest = serde_json::from_str(line.trim())?;
+        if request.token != self.token {
+            return Err(BitFunError::validation(
+                "Git askpass request has an invalid token",
+            ));
+        }
+
+        let answer = self.ask(request.prompt).await;
+        let mut response = serde_json::to_string(&AskpassResponse { answer })?;
+        response.push('\n');
+        writer.write_all(response.as_bytes()).await?;
+        Ok(())
+    }
+
+    async fn ask(&self, prompt: String) -> Option<String> {
+        let created_at_ms = Utc::now().timestamp_millis();
+        let prompt = GitCredentialPrompt {
+            id: format!("git_askpass_{}", Uuid::new_v4()),
+            secret: is_secret_prompt(&prompt),
+            prompt,
+            created_at_ms,
+            expires_at_ms: created_at_ms + PROMPT_TIMEOUT.as_millis() as i64,
+        };
+        let id = prompt.id.clone();
+        let (sender, mut receiver) = oneshot::channel();
+        self.pending.lock().await.insert(
+            id.clone(),
+            PendingPrompt {
+                prompt: prompt.clone(),
+                sender,
+            },
+        );
+        info!(
+            "Git credential prompt requested: id={}, secret={}",
+            id, prompt.secret
+        );
+        self.emit(GitAskpassEvent::Requested { prompt });
+
+        match tokio::time::timeout(PROMPT_TIMEOUT, &mut receiver).await {
+            Ok(answer) => answer.ok().flatten(),
+            Err(_) => {
+                // An answer may race the timeout; whoever removes the entry wins.
+                if self.pending.lock().await.remove(&id).is_none() {
+                    return receiver.await.ok().flatten();
+                }
+                info!("Git credential prompt timed out: id={}", id);
+                self.emit(GitAskpassEvent::Resolved {
+                    id,
+                    answered: false,
+                });
+                None
+            }
+        }
+    }
+
+    fn emit(&self, event: GitAskpassEvent) {
+        // No subscribers is fine; the UI lists pending prompts on startup.
+        let _ = self.events.send(event);
+    }
+}
+
+/// Git asks for `Username for ...` and `Password for ...`; SSH asks for a
+/// `passphrase`. Anything that is not a username is treated as secret.
+fn is_secret_prompt(prompt: &str) -> bool {
+    !prompt.trim_start().to_lowercase().starts_with("username")
+}
+
+async fn write_askpass_script(host_program: &Path, script_dir: &Path) -> BitFunResult<PathBuf> {
+    tokio::fs::create_dir_all(script_dir).await?;
+    let script_path = script_dir.join(SCRIPT_FILE);
+    tokio::fs::write(&script_path, askpass_script(host_program)).await?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tokio::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o700)).await?;
+    }
+    Ok(script_path)
+}
+
+/// Git for Windows starts `GIT_ASKPASS` with `CreateProcess`, which runs
+/// `.cmd` files but not `#!` scripts. `%` is doubled so a path containing it
+/// is not expanded as a variable.
+#[cfg(windows)]
+fn askpass_script(host_program: &Path) -> String {
+    let program = host_program.to_string_lossy().replace('%', "%%");
+    format!(
+        "@echo off\r\n\"{}\" {} %*\r\nexit /b %errorlevel%\r\n",
+        program, GIT_ASKPASS_ARG
+    )
+}
+
+/// Single quotes keep `$`, backticks and `"` in the path literal.
+#[cfg(not(windows))]
+fn askpass_script(host_program: &Path) -> String {
+    let program = host_program.to_string_lossy().replace('\'', r"'\''");
+    format!("#!/bin/sh\nexec '{}' {} \"$@\"\n", program, GIT_ASKPASS_ARG)
+}
+
+/// Entry point for the host binary started with [`GIT_ASKPASS_ARG`]. Prints
+/// the answer for Git and returns the process exit code.
+pub fn run_askpass_client(prompt: &str) -> i32 {
+    match request_answer(prompt) {
+        Ok(Some(answer)) => {
+            let mut stdout = std::io::stdout().lock();
+            match writeln!(stdout, "{}", answer).and_then(|()| stdout.flush()) {
+                Ok(()) => 0,
+                Err(_) => 1,
+            }
+        }
+        Ok(None) => 1,
+        Err(e) => {
+            eprintln!("BitFun askpass: {}", e);
+            1
+        }
+    }
+}
+
+fn request_answer(prompt: &str) -> Result<Option<String>, String> {
+    let addr = std::env::var(ADDR_ENV).map_err(|_| format!("{} is not set", ADDR_ENV))?;
+    let token = std::env::var(TOKEN_ENV).map_err(|_| format!("{} is not set", TOKEN_ENV))?;
+    let mut stream = std::net::TcpStream::connect(&addr)
+        .map_err(|e| format!("cannot reach BitFun at {}: {}", addr, e))?;
+
+    let mut request = serde_json::to_string(&AskpassRequest {
+        token,
+        prompt: prompt.to_string(),
+    })
+    .map_err(|e| e.to_string())?;
+    request.push('\n');
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut line)
+        .map_err(|e| e.to_string())?;
+    let response: AskpassResponse = serde_json::from_str(line.trim()).map_err(|e| e.to_string())?;
+    Ok(response.answer)
+}
+
+pub fn get_global_git_askpass_bridge() -> Option<Arc<GitAskpassBridge>> {
+    GLOBAL_GIT_ASKPASS_BRIDGE.get().cloned()
+}
+
+pub fn set_global_git_askpass_bridge(bridge: Arc<GitAskpassBridge>) {
+    let _ = GLOBAL_GIT_ASKPASS_BRIDGE.set(bridge);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_username_prompts_are_not_secret() {
+        assert!(!is_secret_prompt("Username for 'https://github.com': "));
+        assert!(is_secret_prompt(
+            "Password for 'https://alice@github.com': "
+        ));
+        assert!(is_secret_prompt(
+            "Enter passphrase for key '/home/alice/.ssh/id_ed25519': "
+        ));
+    }
+
+    #[tokio::test]
+    async fn bridge_round_trips_an_answer() {
+        let dir = std::env::temp_dir().join(format!("bitfun-askpass-test-{}", Uuid::new_v4()));
+        let bridge = GitAskpassBridge::start(Path::new("/usr/bin/bitfun"), &dir)
+            .await
+            .unwrap();
+        let mut events = bridge.subscribe();
+
+        let mut stream = TcpStream::connect(&bridge.addr).await.unwrap();
+        let request = serde_json::to_string(&AskpassRequest {
+            token: bridge.token.clone(),
+            prompt: "Password for 'https://example.com': ".to_string(),
+        })
+        .unwrap();
+        stream
+            .write_all(format!("{}\n", request).as_bytes())
+            .await
+            .unwrap();
+
+        let GitAskpassEvent::Requested { prompt } = events.recv().await.unwrap() else {
+            panic!("expected a prompt");
+        };
+        assert!(prompt.secret);
+        bridge
+            .respond(&prompt.id, Some("s3cret".to_string()))
+            .await
+            .unwrap();
+
+        let mut line = String::new();
+        AsyncBufReader::new(stream)
+            .read_line(&mut line)
+            .await
+            .unwrap();
+        let response: AskpassResponse = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(response.answer.as_deref(), Some("s3cret"));
+
+        assert!(dir.join(SCRIPT_FILE).is_file());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn windows_helper_is_a_cmd_file_forwarding_every_argument() {
+        let script = askpass_script(Path::new(r"C:\Program Files\BitFun 100%\BitFun.exe"));
+        assert!(script.starts_with("@echo off\r\n"));
+        assert!(script.contains(r#""C:\Program Files\BitFun 100%%\BitFun.exe" --git-askpass %*"#));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn posix_helper_execs_the_host_with_every_argument() {
+        let script = askpass_script(Path::new("/opt/Bit's $Fun/bitfun"));
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(r#"exec '/opt/Bit'\''s $Fun/bitfun' --git-askpass "$@""#));
+    }
+}
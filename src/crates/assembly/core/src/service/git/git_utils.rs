@@ -1,7 +1,7 @@
 pub use bitfun_services_integrations::git::{
-    build_git_changed_files_args, build_git_diff_args, check_git_available, execute_git_command,
-    execute_git_command_raw, execute_git_command_sync, execute_git_command_sync_raw,
-    format_timestamp, get_current_branch, get_file_statuses, get_repository_root,
-    is_git_repository, parse_branch_line, parse_git_log_line, parse_name_status_output,
-    status_to_string,
+    build_git_changed_files_args, build_git_diff_args, check_git_available,
+    configure_git_processes, execute_git_command, execute_git_command_raw,
+    execute_git_command_sync, execute_git_command_sync_raw, format_timestamp, get_current_branch,
+    get_file_statuses, get_repository_root, is_git_repository, parse_branch_line,
+    parse_git_log_line, parse_name_status_output, status_to_string, GitProcessConfig,
 };
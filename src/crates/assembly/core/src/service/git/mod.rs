@@ -1,6 +1,7 @@
 /**
  * Git service module
  */
+pub mod askpass;
 pub mod git_service;
 pub mod git_types;
 pub mod git_utils;
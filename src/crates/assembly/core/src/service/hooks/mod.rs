@@ -0,0 +1,13 @@
+//! User lifecycle hooks
+//!
+//! Commands configured under `ai.hooks` run on agent events (tool calls,
+//! edits, session start/end). The event is piped to the command as JSON;
+//! exit code 2 or a `"decision": "block"` reply stops a pending tool call, and
+//! `pre_tool_call` hooks may return `updated_input` to rewrite its arguments.
+
+mod runner;
+mod types;
+
+pub use crate::service::config::types::{HookDefinition, HookEvent, HooksSettings};
+pub use runner::{spawn_notification_hooks, HookRunner, BLOCKING_EXIT_CODE};
+pub use types::{HookDecision, HookInput, HookOutcome, HookOutput};
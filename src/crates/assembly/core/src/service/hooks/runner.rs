@@ -0,0 +1,337 @@
+//! Hook command execution.
+//!
+//! Hooks for one event run one after another in configuration order, so a
+//! formatter registered after a policy hook only sees calls the policy let
+//! through. A hook that fails to start, times out or exits with an unexpected
+//! code is logged and skipped; only an explicit block stops the action.
+
+use super::types::{HookDecision, HookInput, HookOutcome, HookOutput};
+use crate::service::config::get_global_config_service;
+use crate::service::config::types::{HookDefinition, HookEvent, HooksSettings};
+use crate::service::runtime::RuntimeManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::process_manager;
+use bitfun_runtime_ports::{wildcard_matches, PermissionResourceCaseSensitivity};
+use log::{debug, warn};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+
+/// Exit code with which a hook blocks the action; stderr is the reason.
+pub const BLOCKING_EXIT_CODE: i32 = 2;
+
+const MAX_HOOK_TIMEOUT_SECS: u64 = 10 * 60;
+const STDERR_TAIL_CHARS: usize = 2000;
+
+pub struct HookRunner {
+    hooks: Vec<HookDefinition>,
+    runtime: Option<RuntimeManager>,
+}
+
+impl HookRunner {
+    /// Reads `ai.hooks`. Returns `None` when hooks are off or none are
+    /// configured, so callers can skip building event payloads.
+    pub async fn load() -> Option<Self> {
+        let config_service = get_global_config_service().await.ok()?;
+        match config_service
+            .get_config::<HooksSettings>(Some("ai.hooks"))
+            .await
+        {
+            Ok(settings) => Self::from_settings(settings),
+            Err(e) => {
+                debug!("Hooks config unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    pub fn from_settings(settings: HooksSettings) -> Option<Self> {
+        if !settings.enabled {
+            return None;
+        }
+        let hooks = settings
+            .hooks
+            .into_iter()
+            .filter(|hook| hook.enabled && !hook.command.trim().is_empty())
+            .collect::<Vec<_>>();
+        if hooks.is_empty() {
+            return None;
+        }
+        let runtime = RuntimeManager::new()
+            .map_err(|e| warn!("Hooks run without managed runtimes: {}", e))
+            .ok();
+        Some(Self { hooks, runtime })
+    }
+
+    pub fn handles(&self, event: HookEvent, tool_name: Option<&str>) -> bool {
+        self.hooks
+            .iter()
+            .any(|hook| hook_matches(hook, event, tool_name))
+    }
+
+    /// Runs every hook registered for `input.event`. `cwd` should be the
+    /// local workspace root; remote workspaces run hooks from the app's
+    /// working directory.
+    pub async fn run(&self, mut input: HookInput, cwd: Option<&Path>) -> HookOutcome {
+        let mut outcome = HookOutcome::default();
+        let event = input.event;
+        for hook in self
+            .hooks
+            .iter()
+            .filter(|hook| hook_matches(hook, event, input.tool_name.as_deref()))
+        {
+            let started = Instant::now();
+            let result = self.run_hook(hook, &input, cwd).await;
+            RuntimeManager::record_execution(
+                hook.command.trim(),
+                result.is_ok(),
+                started.elapsed(),
+            );
+            let reply = match result {
+                Ok(reply) => reply,
+                Err(e) => {
+                    warn!(
+                        "Hook failed, continuing: event={:?}, command={}, error={}",
+                        event, hook.command, e
+                    );
+                    continue;
+                }
+            };
+
+            if let Some(context) = reply.additional_context.filter(|c| !c.trim().is_empty()) {
+                outcome.additional_context.push(context.trim().to_string());
+            }
+            if reply.decision == Some(HookDecision::Block) {
+                let reason = reply
+                    .reason
+                    .filter(|reason| !reason.trim().is_empty())
+                    .unwrap_or_else(|| format!("'{}' gave no reason", hook.command));
+                debug!(
+                    "Hook blocked action: event={:?}, command={}, reason={}",
+                    event, hook.command, reason
+                );
+                outcome.blocked = Some(reason);
+                break;
+            }
+            if event == HookEvent::PreToolCall {
+                if let Some(updated_input) = reply.updated_input {
+                    debug!(
+                        "Hook rewrote tool input: tool_name={:?}, command={}",
+                        input.tool_name, hook.command
+                    );
+                    input.tool_input = Some(updated_input.clone());
+                    outcome.updated_input = Some(updated_input);
+                }
+            }
+        }
+        outcome
+    }
+
+    async fn run_hook(
+        &self,
+        hook: &HookDefinition,
+        input: &HookInput,
+        cwd: Option<&Path>,
+    ) -> BitFunResult<HookOutput> {
+        let program = self.resolve_program(hook.command.trim(), cwd);
+        let payload = serde_json::to_vec(input)?;
+
+        let mut command = process_manager::create_tokio_command(&program);
+        command
+            .args(&hook.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(cwd) = cwd {
+            command.current_dir(cwd);
+        }
+        if let Some(path) = self
+            .runtime
+            .as_ref()
+            .and_then(|runtime| runtime.merged_path_env(std::env::var("PATH").ok().as_deref()))
+        {
+            command.env("PATH", path);
+        }
+
+        let mut child = command.spawn().map_err(|e| {
+            BitFunError::ProcessError(format!("Failed to start '{}': {}", program, e))
+        })?;
+        let timeout = Duration::from_secs(hook.timeout_secs.clamp(1, MAX_HOOK_TIMEOUT_SECS));
+
+        // Dropping the child on timeout kills the hook (kill_on_drop).
+        let output = tokio::time::timeout(timeout, async {
+            if let Some(mut stdin) = child.stdin.take() {
+                // Hooks that ignore stdin may exit before reading it.
+                if let Err(e) = stdin.write_all(&payload).await {
+                    if e.kind() != std::io::ErrorKind::BrokenPipe {
+                        return Err(e);
+                    }
+                }
+            }
+            child.wait_with_output().await
+        })
+        .await
+        .map_err(|_| BitFunError::Timeout(format!("Hook timed out after {}s", timeout.as_secs())))?
+        .map_err(|e| BitFunError::ProcessError(format!("Hook process failed: {}", e)))?;
+
+        parse_hook_reply(output.status.code(), &output.stdout, &output.stderr)
+            .map_err(BitFunError::ProcessError)
+    }
+
+    fn resolve_program(&self, command: &str, cwd: Option<&Path>) -> String {
+        if command.contains('/') || command.contains('\\') {
+            let path = Path::new(command);
+            return match cwd {
+                Some(cwd) if path.is_relative() => cwd.join(path).to_string_lossy().into_owned(),
+                _ => command.to_string(),
+            };
+        }
+        self.runtime
+            .as_ref()
+            .and_then(|runtime| runtime.resolve_command(command))
+            .and_then(|resolved| resolved.resolved_path)
+            .unwrap_or_else(|| command.to_string())
+    }
+}
+
+/// Runs hooks for events that cannot be blocked, such as session start and
+/// end, without delaying the caller. Replies are ignored.
+pub fn spawn_notification_hooks(input: HookInput, cwd: Option<PathBuf>) {
+    tokio::spawn(async move {
+        let Some(runner) = HookRunner::load().await else {
+            return;
+        };
+        if runner.handles(input.event, None) {
+            runner.run(input, cwd.as_deref()).await;
+        }
+    });
+}
+
+/// Tool matchers apply only to tool events and accept `|`-separated
+/// alternatives, e.g. `Write|Edit` or `mcp_*`.
+fn hook_matches(hook: &HookDefinition, event: HookEvent, tool_name: Option<&str>) -> bool {
+    if hook.event != event {
+        return false;
+    }
+    let matcher = hook.matcher.trim();
+    if matcher.is_empty() || matcher == "*" {
+        return true;
+    }
+    let Some(tool_name) = tool_name else {
+        return true;
+    };
+    matcher.split('|').map(str::trim).any(|pattern| {
+        wildcard_matches(
+            tool_name,
+            pattern,
+            PermissionResourceCaseSensitivity::Sensitive,
+        )
+    })
+}
+
+fn parse_hook_reply(
+    exit_code: Option<i32>,
+    stdout: &[u8],
+    stderr: &[u8],
+) -> Result<HookOutput, String> {
+    match exit_code {
+        Some(0) => {
+            let stdout = String::from_utf8_lossy(stdout);
+            let stdout = stdout.trim();
+            if !stdout.starts_with('{') {
+                return Ok(HookOutput::default());
+            }
+            serde_json::from_str(stdout)
+                .map_err(|e| format!("Hook printed invalid JSON on stdout: {}", e))
+        }
+        Some(BLOCKING_EXIT_CODE) => Ok(HookOutput {
+            decision: Some(HookDecision::Block),
+            reason: Some(stderr_tail(stderr)),
+            ..Default::default()
+        }),
+        Some(code) => Err(format!("exited with {}: {}", code, stderr_tail(stderr))),
+        None => Err("terminated by signal".to_string()),
+    }
+}
+
+fn stderr_tail(stderr: &[u8]) -> String {
+    let text = String::from_utf8_lossy(stderr);
+    let text = text.trim();
+    let skip = text.chars().count().saturating_sub(STDERR_TAIL_CHARS);
+    text.chars().skip(skip).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hook(event: HookEvent, matcher: &str) -> HookDefinition {
+        HookDefinition {
+            event,
+            matcher: matcher.to_string(),
+            command: "true".to_string(),
+            args: Vec::new(),
+            timeout_secs: 30,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn matcher_selects_tools_by_pattern() {
+        let edits = hook(HookEvent::PreToolCall, "Write|Edit");
+        assert!(hook_matches(&edits, HookEvent::PreToolCall, Some("Edit")));
+        assert!(!hook_matches(&edits, HookEvent::PreToolCall, Some("Read")));
+        assert!(!hook_matches(&edits, HookEvent::PostToolCall, Some("Edit")));
+
+        let mcp = hook(HookEvent::PostToolCall, "mcp_*");
+        assert!(hook_matches(
+            &mcp,
+            HookEvent::PostToolCall,
+            Some("mcp_github_search")
+        ));
+
+        let any = hook(HookEvent::SessionStart, "");
+        assert!(hook_matches(&any, HookEvent::SessionStart, None));
+    }
+
+    #[test]
+    fn exit_codes_and_stdout_map_to_decisions() {
+        let rewrite = parse_hook_reply(
+            Some(0),
+            br#"{"updated_input":{"command":"cargo fmt"},"additional_context":"formatted"}"#,
+            b"",
+        )
+        .unwrap();
+        assert_eq!(
+            rewrite.updated_input,
+            Some(json!({ "command": "cargo fmt" }))
+        );
+        assert_eq!(rewrite.additional_context.as_deref(), Some("formatted"));
+
+        let plain = parse_hook_reply(Some(0), b"ok\n", b"").unwrap();
+        assert_eq!(plain, HookOutput::default());
+
+        let blocked = parse_hook_reply(Some(BLOCKING_EXIT_CODE), b"", b"no rm -rf\n").unwrap();
+        assert_eq!(blocked.decision, Some(HookDecision::Block));
+        assert_eq!(blocked.reason.as_deref(), Some("no rm -rf"));
+
+        assert!(parse_hook_reply(Some(1), b"", b"boom").is_err());
+    }
+
+    #[test]
+    fn disabled_or_empty_settings_build_no_runner() {
+        let mut settings = HooksSettings {
+            enabled: true,
+            hooks: vec![hook(HookEvent::PostEdit, "")],
+        };
+        settings.hooks[0].enabled = false;
+        assert!(HookRunner::from_settings(settings.clone()).is_none());
+
+        settings.hooks[0].enabled = true;
+        settings.enabled = false;
+        assert!(HookRunner::from_settings(settings).is_none());
+    }
+}
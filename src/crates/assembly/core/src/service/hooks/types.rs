@@ -0,0 +1,80 @@
+//! Hook stdin/stdout payloads.
+
+use crate::service::config::types::HookEvent;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Event description written to a hook's stdin.
+#[derive(Debug, Clone, Serialize)]
+pub struct HookInput {
+    pub event: HookEvent,
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_input: Option<Value>,
+    /// Text the tool returned to the model; post events only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_error: Option<bool>,
+}
+
+impl HookInput {
+    pub fn new(event: HookEvent, session_id: impl Into<String>) -> Self {
+        Self {
+            event,
+            session_id: session_id.into(),
+            workspace_path: None,
+            agent_type: None,
+            tool_name: None,
+            tool_input: None,
+            tool_result: None,
+            is_error: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookDecision {
+    Allow,
+    Block,
+}
+
+/// Optional JSON reply on a hook's stdout. Non-JSON output is ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HookOutput {
+    pub decision: Option<HookDecision>,
+    pub reason: Option<String>,
+    /// Replacement tool arguments; honoured for `pre_tool_call` only.
+    pub updated_input: Option<Value>,
+    /// Text appended to the tool result the model sees; post events only.
+    pub additional_context: Option<String>,
+}
+
+/// Combined result of every hook run for one event.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HookOutcome {
+    /// Reason given by the first hook that blocked; later hooks do not run.
+    pub blocked: Option<String>,
+    pub updated_input: Option<Value>,
+    pub additional_context: Vec<String>,
+}
+
+impl HookOutcome {
+    /// Text to show the model alongside the tool result, if any hook had
+    /// something to say.
+    pub fn feedback(&self) -> Option<String> {
+        let mut parts = self.additional_context.clone();
+        if let Some(reason) = &self.blocked {
+            parts.push(format!("Hook blocked: {}", reason));
+        }
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+}
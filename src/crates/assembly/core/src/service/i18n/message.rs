@@ -0,0 +1,191 @@
+//! Localized backend messages
+//!
+//! Backend errors and progress strings are rendered in the configured language,
+//! but the message id and its parameters travel with the text so the frontend
+//! can re-render them after a language switch or with richer formatting.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::service::{get_global_i18n_service, I18nService};
+use super::types::{FluentValue, LocaleId, TranslationArgs};
+use crate::util::errors::{ApiError, ApiErrorCode};
+
+/// A rendered catalog message plus the inputs needed to render it again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedMessage {
+    /// Fluent message id, e.g. `mcp-server-start-failed`.
+    pub id: String,
+    /// Interpolation parameters, keyed by Fluent variable name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, serde_json::Value>,
+    /// Text rendered in `locale`. Equals `id` when the catalog has no entry.
+    pub text: String,
+    pub locale: LocaleId,
+}
+
+impl LocalizedMessage {
+    /// Rebuilds Fluent arguments from the serialized params.
+    pub fn args(&self) -> TranslationArgs {
+        params_to_translation_args(&self.params)
+    }
+}
+
+impl std::fmt::Display for LocalizedMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl TranslationArgs {
+    /// Serializable view of the arguments, used in `LocalizedMessage::params`.
+    pub fn to_params(&self) -> BTreeMap<String, serde_json::Value> {
+        self.iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    FluentValue::String(s) => serde_json::Value::String(s.clone()),
+                    FluentValue::Number(n) => serde_json::Number::from_f64(*n)
+                        .map(serde_json::Value::Number)
+                        .unwrap_or(serde_json::Value::Null),
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+}
+
+/// Inverse of `TranslationArgs::to_params`; non-scalar values are passed as JSON text.
+pub fn params_to_translation_args(params: &BTreeMap<String, serde_json::Value>) -> TranslationArgs {
+    params
+        .iter()
+        .fold(TranslationArgs::new(), |args, (key, value)| match value {
+            serde_json::Value::Number(n) => args.with_number(key, n.as_f64().unwrap_or_default()),
+            serde_json::Value::String(s) => args.with_string(key, s.clone()),
+            other => args.with_string(key, other.to_string()),
+        })
+}
+
+impl I18nService {
+    /// Renders `id` in the current locale and keeps id + params alongside the text.
+    pub async fn localize(&self, id: &str, args: Option<TranslationArgs>) -> LocalizedMessage {
+        let locale = self.get_current_locale().await;
+        self.localize_with_locale(&locale, id, args).await
+    }
+
+    /// Renders `id` in a specific locale.
+    pub async fn localize_with_locale(
+        &self,
+        locale: &LocaleId,
+        id: &str,
+        args: Option<TranslationArgs>,
+    ) -> LocalizedMessage {
+        let params = args
+            .as_ref()
+            .map(TranslationArgs::to_params)
+            .unwrap_or_default();
+        let text = self.translate_with_locale(locale, id, args).await;
+        LocalizedMessage {
+            id: id.to_string(),
+            params,
+            text,
+            locale: *locale,
+        }
+    }
+}
+
+/// Renders a catalog message with the global i18n service.
+///
+/// Falls back to a fresh service in the default locale when the global service
+/// has not been initialized (CLI tools, tests), so callers never have to branch.
+pub async fn localize(id: &str, args: Option<TranslationArgs>) -> LocalizedMessage {
+    if let Some(service) = get_global_i18n_service().await {
+        return service.localize(id, args).await;
+    }
+
+    let service = I18nService::new();
+    if let Err(e) = service.initialize().await {
+        log::warn!("Failed to initialize fallback I18nService: {}", e);
+    }
+    service.localize(id, args).await
+}
+
+/// Builds a command error whose text is the catalog message `id`.
+pub async fn localized_error(code: ApiErrorCode, id: &str, args: TranslationArgs) -> ApiError {
+    ApiError::new(code, id).with_localized(localize(id, Some(args)).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn initialized_service() -> I18nService {
+        let service = I18nService::new();
+        service.initialize().await.unwrap();
+        service
+    }
+
+    #[tokio::test]
+    async fn localize_keeps_id_and_params_with_rendered_text() {
+        let service = initialized_service().await;
+        let args = TranslationArgs::new()
+            .with_string("server", "github")
+            .with_string("reason", "exit code 1");
+
+        let message = service
+            .localize_with_locale(&LocaleId::EnUS, "mcp-server-start-failed", Some(args))
+            .await;
+
+        assert_eq!(message.id, "mcp-server-start-failed");
+        assert_eq!(message.params["server"], "github");
+        assert!(message.text.contains("github"));
+        assert!(message.text.contains("exit code 1"));
+    }
+
+    #[tokio::test]
+    async fn localized_message_can_be_rendered_again_in_another_locale() {
+        let service = initialized_service().await;
+        let english = service
+            .localize_with_locale(
+                &LocaleId::EnUS,
+                "skill-not-found",
+                Some(TranslationArgs::new().with_string("name", "pdf")),
+            )
+            .await;
+
+        let chinese = service
+            .localize_with_locale(&LocaleId::ZhCN, &english.id, Some(english.args()))
+            .await;
+
+        assert_eq!(chinese.params, english.params);
+        assert_ne!(chinese.text, english.text);
+        assert!(chinese.text.contains("pdf"));
+    }
+
+    #[tokio::test]
+    async fn unknown_message_ids_render_as_the_id() {
+        let service = initialized_service().await;
+        let message = service
+            .localize_with_locale(&LocaleId::EnUS, "not-a-real-message", None)
+            .await;
+        assert_eq!(message.text, "not-a-real-message");
+        assert!(message.params.is_empty());
+    }
+
+    #[tokio::test]
+    async fn localized_errors_keep_the_message_id_for_the_frontend() {
+        let error = localized_error(
+            ApiErrorCode::NotFound,
+            "skill-not-found",
+            TranslationArgs::new().with_string("name", "pdf"),
+        )
+        .await;
+
+        let value = serde_json::to_value(&error).unwrap();
+        assert_eq!(value["code"], "not_found");
+        assert_eq!(value["localized"]["id"], "skill-not-found");
+        assert_eq!(value["localized"]["params"]["name"], "pdf");
+        assert_eq!(value["message"], value["localized"]["text"]);
+        assert!(error.message.contains("pdf"));
+    }
+}
@@ -4,11 +4,13 @@
 
 pub mod generated_locale_contract;
 mod locale_registry;
+mod message;
 mod model_copy;
 mod service;
 mod types;
 
 pub use locale_registry::*;
+pub use message::*;
 pub use model_copy::*;
 pub use service::*;
 pub use types::*;
@@ -15,6 +15,7 @@ use super::locale_registry::LOCALE_RESOURCE_REGISTRY;
 use super::types::{FluentValue, LocaleId, LocaleMetadata, TranslationArgs};
 use crate::service::config::ConfigService;
 use crate::util::errors::*;
+use crate::util::format::LocaleFormatter;
 
 /// Type alias for a thread-safe `FluentBundle`.
 type ConcurrentBundle = ConcurrentFluentBundle<FluentResource>;
@@ -267,6 +268,15 @@ pub async fn get_global_i18n_service() -> Option<Arc<I18nService>> {
     GLOBAL_I18N_SERVICE.read().await.clone()
 }
 
+/// Formatter for the configured locale, or the default locale before the
+/// global service is initialized.
+pub async fn current_locale_formatter() -> LocaleFormatter {
+    match get_global_i18n_service().await {
+        Some(service) => service.get_current_locale().await.formatter(),
+        None => LocaleId::default().formatter(),
+    }
+}
+
 /// Updates the global i18n service locale if it has been initialized.
 pub async fn sync_global_i18n_service_locale(locale: LocaleId) -> BitFunResult<bool> {
     if let Some(service) = get_global_i18n_service().await {
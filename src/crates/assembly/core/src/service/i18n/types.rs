@@ -4,6 +4,7 @@ use super::generated_locale_contract::{
     generated_locale_entry, generated_locale_entry_from_code, GENERATED_DEFAULT_LOCALE,
     GENERATED_FALLBACK_LOCALE, GENERATED_LOCALE_CONTRACT,
 };
+use crate::util::format::LocaleFormatter;
 use serde::{Deserialize, Serialize};
 
 /// Locale identifier.
@@ -52,6 +53,11 @@ impl LocaleId {
     pub fn content_fallbacks(&self) -> &'static [LocaleId] {
         generated_locale_entry(*self).content_fallbacks
     }
+
+    /// Returns the number, size, date and list formatter for this locale.
+    pub fn formatter(&self) -> LocaleFormatter {
+        LocaleFormatter::new(self.as_str())
+    }
 }
 
 impl Default for LocaleId {
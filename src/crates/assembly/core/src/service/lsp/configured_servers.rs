@@ -0,0 +1,240 @@
+//! Language servers configured in workspace settings.
+//!
+//! Entries under `workspace.language_servers` become LSP plugins whose
+//! commands are resolved through [`RuntimeManager`], so servers installed on
+//! the system PATH or as BitFun-managed runtimes work without packaging a
+//! plugin. They are re-applied whenever workspace settings change.
+
+use crate::service::config::{
+    get_global_config_service, subscribe_config_updates, ConfigUpdateEvent, GlobalConfig,
+    LanguageServerSettings,
+};
+use crate::service::runtime::RuntimeManager;
+use bitfun_core_types::lsp::{CapabilitiesConfig, LspPlugin, ServerConfig};
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::global::{get_all_workspace_paths, get_global_lsp_manager, get_workspace_manager};
+
+/// Plugin id prefix for settings-configured servers.
+const CONFIGURED_PLUGIN_PREFIX: &str = "config:";
+
+/// Builds the plugin manifest for a configured server.
+fn configured_plugin(language: &str, settings: &LanguageServerSettings) -> LspPlugin {
+    let file_extensions = settings
+        .file_extensions
+        .iter()
+        .map(|ext| ext.trim())
+        .filter(|ext| !ext.is_empty())
+        .map(|ext| {
+            if ext.starts_with('.') {
+                ext.to_string()
+            } else {
+                format!(".{}", ext)
+            }
+        })
+        .collect();
+
+    LspPlugin {
+        id: format!("{}{}", CONFIGURED_PLUGIN_PREFIX, language),
+        name: format!("{} ({})", language, settings.command),
+        version: String::new(),
+        author: String::new(),
+        description: "Configured in workspace settings".to_string(),
+        server: ServerConfig {
+            command: settings.command.clone(),
+            args: settings.args.clone(),
+            env: settings.env.clone(),
+            runtime: None,
+        },
+        languages: vec![language.to_string()],
+        file_extensions,
+        capabilities: CapabilitiesConfig {
+            completion: true,
+            hover: true,
+            definition: true,
+            references: true,
+            rename: true,
+            formatting: true,
+            diagnostics: true,
+            inlay_hints: true,
+        },
+        settings: HashMap::new(),
+        checksum: String::new(),
+        min_bitfun_version: String::new(),
+    }
+}
+
+/// Resolves enabled entries to plugins and server binaries. Entries whose
+/// command cannot be found are skipped with a warning.
+fn resolve_configured_servers(
+    language_servers: &HashMap<String, LanguageServerSettings>,
+) -> Vec<(LspPlugin, PathBuf)> {
+    let enabled: Vec<_> = language_servers
+        .iter()
+        .filter(|(language, settings)| {
+            settings.enabled && !language.trim().is_empty() && !settings.command.trim().is_empty()
+        })
+        .collect();
+    if enabled.is_empty() {
+        return Vec::new();
+    }
+
+    let runtime = match RuntimeManager::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            warn!(
+                "Failed to create runtime manager for language servers: {}",
+                e
+            );
+            return Vec::new();
+        }
+    };
+
+    enabled
+        .into_iter()
+        .filter_map(|(language, settings)| {
+            let resolved = runtime
+                .resolve_command(settings.command.trim())
+                .and_then(|resolved| resolved.resolved_path);
+            match resolved {
+                Some(path) => Some((configured_plugin(language, settings), PathBuf::from(path))),
+                None => {
+                    warn!(
+                        "Language server command not found: language={}, command={}",
+                        language, settings.command
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Reads `workspace.language_servers` and registers the resolved servers.
+/// Running servers whose definition changed are stopped in every open
+/// workspace; the next document or request starts the new one.
+pub async fn apply_configured_servers() -> anyhow::Result<()> {
+    let config_service = get_global_config_service()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to get config service: {}", e))?;
+    let config: GlobalConfig = config_service
+        .get_config(None)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to read config: {}", e))?;
+
+    let servers = resolve_configured_servers(&config.workspace.language_servers);
+    let count = servers.len();
+
+    let changed_languages = {
+        let manager = get_global_lsp_manager()?;
+        let manager = manager.read().await;
+        manager.sync_configured_servers(servers).await?
+    };
+
+    if changed_languages.is_empty() {
+        debug!("Configured language servers unchanged");
+        return Ok(());
+    }
+
+    for workspace_path in get_all_workspace_paths().await.unwrap_or_default() {
+        let Ok(workspace) = get_workspace_manager(PathBuf::from(&workspace_path)).await else {
+            continue;
+        };
+        for language in &changed_languages {
+            if let Err(e) = workspace.stop_server(language).await {
+                warn!(
+                    "Failed to stop {} server in {}: {}",
+                    language, workspace_path, e
+                );
+            }
+        }
+    }
+
+    info!(
+        "Configured language servers applied: count={}, changed={:?}",
+        count, changed_languages
+    );
+    Ok(())
+}
+
+/// Re-applies configured servers when workspace settings change.
+pub(crate) fn spawn_configured_servers_listener() {
+    let Some(mut receiver) = subscribe_config_updates() else {
+        debug!(
+            "Config update subscription unavailable; configured language servers will not reload"
+        );
+        return;
+    };
+
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(ConfigUpdateEvent::WorkspaceUpdated | ConfigUpdateEvent::ConfigReloaded) => {
+                    if let Err(e) = apply_configured_servers().await {
+                        warn!("Failed to apply configured language servers: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_plugin_normalizes_extensions_and_enables_capabilities() {
+        let settings = LanguageServerSettings {
+            command: "pyright-langserver".to_string(),
+            args: vec!["--stdio".to_string()],
+            env: HashMap::new(),
+            file_extensions: vec!["py".to_string(), ".pyi".to_string(), " ".to_string()],
+            enabled: true,
+        };
+
+        let plugin = configured_plugin("python", &settings);
+
+        assert_eq!(plugin.id, "config:python");
+        assert_eq!(plugin.languages, vec!["python".to_string()]);
+        assert_eq!(
+            plugin.file_extensions,
+            vec![".py".to_string(), ".pyi".to_string()]
+        );
+        assert_eq!(plugin.server.args, vec!["--stdio".to_string()]);
+        assert!(plugin.capabilities.definition && plugin.capabilities.diagnostics);
+    }
+
+    #[test]
+    fn disabled_and_blank_entries_are_not_resolved() {
+        let mut servers = HashMap::new();
+        servers.insert(
+            "rust".to_string(),
+            LanguageServerSettings {
+                command: "rust-analyzer".to_string(),
+                args: Vec::new(),
+                env: HashMap::new(),
+                file_extensions: Vec::new(),
+                enabled: false,
+            },
+        );
+        servers.insert(
+            "go".to_string(),
+            LanguageServerSettings {
+                command: "  ".to_string(),
+                args: Vec::new(),
+                env: HashMap::new(),
+                file_extensions: Vec::new(),
+                enabled: true,
+            },
+        );
+
+        assert!(resolve_configured_servers(&servers).is_empty());
+    }
+}
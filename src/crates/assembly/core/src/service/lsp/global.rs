@@ -55,6 +55,11 @@ pub async fn initialize_global_lsp_manager() -> anyhow::Result<()> {
         .set(file_sync)
         .map_err(|_| anyhow::anyhow!("Failed to set file sync"))?;
 
+    if let Err(e) = super::configured_servers::apply_configured_servers().await {
+        warn!("Failed to apply configured language servers: {}", e);
+    }
+    super::configured_servers::spawn_configured_servers_listener();
+
     info!("Global LSP Manager initialized");
 
     Ok(())
@@ -6,6 +6,7 @@
 //! import paths.
 
 pub mod config_watcher;
+pub mod configured_servers;
 pub mod debouncer;
 pub mod file_sync;
 pub mod global;
@@ -18,6 +19,7 @@ pub mod registry;
 pub mod types;
 pub mod workspace_manager;
 
+pub use configured_servers::apply_configured_servers;
 pub use global::{
     close_workspace, get_all_workspace_paths, get_global_lsp_manager, get_workspace_manager,
     initialize_global_lsp_manager, is_lsp_manager_initialized, open_workspace,
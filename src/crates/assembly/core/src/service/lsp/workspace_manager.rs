@@ -476,8 +476,7 @@ impl WorkspaceLspManager {
 
     /// Ensures the server is running (prevents duplicate starts).
     /// Returns the actual server language key in use (may differ from the requested one, e.g. c -> cpp).
-    #[allow(dead_code)]
-    async fn ensure_server_running(&self, language: &str) -> Result<String> {
+    pub(crate) async fn ensure_server_running(&self, language: &str) -> Result<String> {
         let status = {
             let states = self.server_states.read().await;
 
@@ -925,7 +924,6 @@ impl WorkspaceLspManager {
     }
 
     /// Waits for server startup to complete.
-    #[allow(dead_code)]
     async fn wait_for_server_start(&self, language: &str) -> Result<()> {
         let notify = {
             let locks = self.starting_locks.read().await;
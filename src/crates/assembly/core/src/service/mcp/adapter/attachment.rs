@@ -0,0 +1,534 @@
+//! MCP resource attachments
+//!
+//! Reads resources with `resources/read` and converts each content block into
+//! something a model can consume: text and Markdown pass through, PDFs are
+//! converted to text through the document conversion service, and images
+//! become image blocks when the primary model accepts them. Every attached
+//! resource draws from one token budget so a large resource cannot crowd the
+//! conversation out.
+
+use crate::agentic::image_analysis::optimize_image_for_provider;
+use crate::infrastructure::try_get_path_manager_arc;
+use crate::service::docconv::{
+    get_global_document_conversion_service, DocumentConversionKind, DocumentConversionRequest,
+};
+use crate::service::mcp::protocol::MCPResourceContent;
+use crate::service::mcp::server::MCPConnection;
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::TokenCounter;
+use base64::Engine as _;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+
+/// Default estimated tokens shared by all resources of one attach request.
+pub const DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET: usize = 16_000;
+/// Binary resources above this size are not decoded at all.
+const MAX_RESOURCE_BLOB_BYTES: usize = 20 * 1024 * 1024;
+/// Charged per image when it is attached without provider-specific resizing.
+const DEFAULT_IMAGE_TOKEN_ESTIMATE: usize = 1_600;
+/// Pixels per token used by vision models to bill an image.
+const IMAGE_PIXELS_PER_TOKEN: u64 = 750;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPResourceAttachmentOptions {
+    /// Estimated tokens shared by every resource in the request.
+    pub token_budget: usize,
+    /// Whether image resources become image parts.
+    pub include_images: bool,
+    /// Primary model API format. When set, images are re-encoded within that
+    /// provider's limits and billed by their final size.
+    #[serde(default)]
+    pub image_provider: Option<String>,
+}
+
+impl Default for MCPResourceAttachmentOptions {
+    fn default() -> Self {
+        Self {
+            token_budget: DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET,
+            include_images: false,
+            image_provider: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MCPResourceAttachmentPart {
+    #[serde(rename_all = "camelCase")]
+    Text {
+        uri: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        text: String,
+        estimated_tokens: usize,
+        /// The text was cut to fit the remaining budget.
+        truncated: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Image {
+        uri: String,
+        mime_type: String,
+        data_base64: String,
+        estimated_tokens: usize,
+    },
+    /// Content that could not be attached, with the reason shown to the user
+    /// and the model.
+    #[serde(rename_all = "camelCase")]
+    Omitted {
+        uri: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        reason: String,
+    },
+}
+
+impl MCPResourceAttachmentPart {
+    pub fn estimated_tokens(&self) -> usize {
+        match self {
+            Self::Text {
+                estimated_tokens, ..
+            }
+            | Self::Image {
+                estimated_tokens, ..
+            } => *estimated_tokens,
+            Self::Omitted { .. } => 0,
+        }
+    }
+}
+
+/// One resource converted for the conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPResourceAttachment {
+    pub server_id: String,
+    pub uri: String,
+    pub parts: Vec<MCPResourceAttachmentPart>,
+    pub estimated_tokens: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResourceContentKind {
+    Text,
+    Pdf,
+    Image,
+    Binary,
+}
+
+/// Reads `uris` from `connection` and converts them in order until the token
+/// budget runs out. Resources that no longer fit are kept as omitted parts so
+/// the caller can tell the user what was left out.
+pub async fn attach_mcp_resources(
+    connection: &MCPConnection,
+    server_id: &str,
+    uris: &[String],
+    options: &MCPResourceAttachmentOptions,
+) -> BitFunResult<Vec<MCPResourceAttachment>> {
+    let mut remaining = options.token_budget;
+    let mut attachments = Vec::with_capacity(uris.len());
+
+    for uri in uris {
+        let result = connection.read_resource(uri).await?;
+        let mut parts = Vec::with_capacity(result.contents.len());
+        for content in &result.contents {
+            let part = convert_resource_content(content, options, remaining).await;
+            remaining = remaining.saturating_sub(part.estimated_tokens());
+            parts.push(part);
+        }
+        if parts.is_empty() {
+            parts.push(MCPResourceAttachmentPart::Omitted {
+                uri: uri.clone(),
+                mime_type: None,
+                reason: "The server returned no content for this resource.".to_string(),
+            });
+        }
+
+        let estimated_tokens = parts
+            .iter()
+            .map(MCPResourceAttachmentPart::estimated_tokens)
+            .sum();
+        debug!(
+            "MCP resource attached: server_id={}, uri={}, parts={}, estimated_tokens={}",
+            server_id,
+            uri,
+            parts.len(),
+            estimated_tokens
+        );
+        attachments.push(MCPResourceAttachment {
+            server_id: server_id.to_string(),
+            uri: uri.clone(),
+            parts,
+            estimated_tokens,
+        });
+    }
+
+    Ok(attachments)
+}
+
+async fn convert_resource_content(
+    content: &MCPResourceContent,
+    options: &MCPResourceAttachmentOptions,
+    remaining: usize,
+) -> MCPResourceAttachmentPart {
+    let uri = content.uri.clone();
+    let mime_type = content.mime_type.clone();
+    let omitted = |reason: String| MCPResourceAttachmentPart::Omitted {
+        uri: content.uri.clone(),
+        mime_type: content.mime_type.clone(),
+        reason,
+    };
+
+    if let Some(text) = &content.content {
+        return text_part(uri, mime_type, text, remaining);
+    }
+    let Some(blob) = &content.blob else {
+        return omitted("The resource content is empty.".to_string());
+    };
+    if blob.len() / 4 * 3 > MAX_RESOURCE_BLOB_BYTES {
+        return omitted(format!(
+            "The resource is larger than {} MB.",
+            MAX_RESOURCE_BLOB_BYTES / (1024 * 1024)
+        ));
+    }
+
+    let kind = classify_resource_content(mime_type.as_deref(), &uri);
+    match kind {
+        ResourceContentKind::Binary => {
+            omitted("Binary content of this type cannot be attached.".to_string())
+        }
+        ResourceContentKind::Image if !options.include_images => {
+            omitted("The current model does not accept images.".to_string())
+        }
+        kind => {
+            let bytes = match base64::engine::general_purpose::STANDARD.decode(blob) {
+                Ok(bytes) => bytes,
+                Err(_) => return omitted("The resource blob is not valid base64.".to_string()),
+            };
+            match kind {
+                ResourceContentKind::Text => {
+                    text_part(uri, mime_type, &String::from_utf8_lossy(&bytes), remaining)
+                }
+                ResourceContentKind::Pdf => match extract_pdf_text(&bytes).await {
+                    Ok(text) => text_part(uri, mime_type, &text, remaining),
+                    Err(e) => {
+                        warn!(
+                            "Failed to extract MCP resource PDF text: uri={}, error={}",
+                            uri, e
+                        );
+                        omitted(format!("PDF text extraction failed: {}", e))
+                    }
+                },
+                _ => image_part(uri, mime_type, bytes, blob, options, remaining),
+            }
+        }
+    }
+}
+
+fn text_part(
+    uri: String,
+    mime_type: Option<String>,
+    text: &str,
+    remaining: usize,
+) -> MCPResourceAttachmentPart {
+    if remaining == 0 {
+        return MCPResourceAttachmentPart::Omitted {
+            uri,
+            mime_type,
+            reason: "The attachment token budget is exhausted.".to_string(),
+        };
+    }
+    let (text, estimated_tokens, truncated) = fit_text_to_budget(text, remaining);
+    MCPResourceAttachmentPart::Text {
+        uri,
+        mime_type,
+        text,
+        estimated_tokens,
+        truncated,
+    }
+}
+
+fn image_part(
+    uri: String,
+    mime_type: Option<String>,
+    bytes: Vec<u8>,
+    blob: &str,
+    options: &MCPResourceAttachmentOptions,
+    remaining: usize,
+) -> MCPResourceAttachmentPart {
+    let (image_mime_type, data_base64, estimated_tokens) = match &options.image_provider {
+        Some(provider) => {
+            match optimize_image_for_provider(bytes, provider, mime_type.as_deref()) {
+                Ok(processed) => (
+                    processed.mime_type,
+                    base64::engine::general_purpose::STANDARD.encode(&processed.data),
+                    (u64::from(processed.width) * u64::from(processed.height)
+                        / IMAGE_PIXELS_PER_TOKEN)
+                        .max(1) as usize,
+                ),
+                Err(e) => {
+                    return MCPResourceAttachmentPart::Omitted {
+                        uri,
+                        mime_type,
+                        reason: format!("The image could not be prepared: {}", e),
+                    };
+                }
+            }
+        }
+        None => (
+            mime_type.clone().unwrap_or_else(|| "image/png".to_string()),
+            blob.to_string(),
+            DEFAULT_IMAGE_TOKEN_ESTIMATE,
+        ),
+    };
+
+    if estimated_tokens > remaining {
+        return MCPResourceAttachmentPart::Omitted {
+            uri,
+            mime_type,
+            reason: "The attachment token budget is exhausted.".to_string(),
+        };
+    }
+    MCPResourceAttachmentPart::Image {
+        uri,
+        mime_type: image_mime_type,
+        data_base64,
+        estimated_tokens,
+    }
+}
+
+/// Returns the longest prefix of `text` within `budget` estimated tokens.
+fn fit_text_to_budget(text: &str, budget: usize) -> (String, usize, bool) {
+    let tokens = TokenCounter::estimate_tokens(text);
+    if tokens <= budget {
+        return (text.to_string(), tokens, false);
+    }
+
+    let prefix = TokenCounter::truncate_to_tokens(text, budget);
+    (
+        prefix.to_string(),
+        TokenCounter::estimate_tokens(prefix),
+        true,
+    )
+}
+
+fn classify_resource_content(mime_type: Option<&str>, uri: &str) -> ResourceContentKind {
+    if let Some(mime_type) = mime_type {
+        let essence = mime_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if essence == "application/pdf" {
+            return ResourceContentKind::Pdf;
+        }
+        // SVG is markup the model can read; raster formats need vision.
+        if essence == "image/svg+xml" {
+            return ResourceContentKind::Text;
+        }
+        if essence.starts_with("image/") {
+            return ResourceContentKind::Image;
+        }
+        if essence.starts_with("text/")
+            || essence.ends_with("+json")
+            || essence.ends_with("+xml")
+            || matches!(
+                essence.as_str(),
+                "application/json"
+                    | "application/xml"
+                    | "application/yaml"
+                    | "application/x-yaml"
+                    | "application/toml"
+                    | "application/javascript"
+                    | "application/x-sh"
+            )
+        {
+            return ResourceContentKind::Text;
+        }
+        if essence != "application/octet-stream" {
+            return ResourceContentKind::Binary;
+        }
+    }
+
+    let extension = uri
+        .split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "pdf" => ResourceContentKind::Pdf,
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp" => ResourceContentKind::Image,
+        "md" | "markdown" | "txt" | "json" | "yaml" | "yml" | "toml" | "xml" | "csv" | "html"
+        | "svg" => ResourceContentKind::Text,
+        _ => ResourceContentKind::Binary,
+    }
+}
+
+async fn extract_pdf_text(bytes: &[u8]) -> BitFunResult<String> {
+    let service = get_global_document_conversion_service()
+        .ok_or_else(|| BitFunError::service("Document conversion service is not initialized"))?;
+    let input_dir = try_get_path_manager_arc()?.temp_dir().join("mcp-resources");
+    tokio::fs::create_dir_all(&input_dir).await?;
+    let input_path = input_dir.join(format!("{}.pdf", uuid::Uuid::new_v4()));
+    tokio::fs::write(&input_path, bytes).await?;
+
+    let result = service
+        .convert(DocumentConversionRequest::new(
+            DocumentConversionKind::PdfToText,
+            &input_path,
+        ))
+        .await;
+    let _ = tokio::fs::remove_file(&input_path).await;
+    let result = result?;
+
+    let text = match result.outputs.first() {
+        Some(output) => String::from_utf8_lossy(&tokio::fs::read(output).await?).into_owned(),
+        None => String::new(),
+    };
+    let _ = tokio::fs::remove_dir_all(&result.output_dir).await;
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_content(uri: &str, mime_type: &str, text: &str) -> MCPResourceContent {
+        MCPResourceContent {
+            uri: uri.to_string(),
+            content: Some(text.to_string()),
+            blob: None,
+            mime_type: Some(mime_type.to_string()),
+            annotations: None,
+            meta: None,
+        }
+    }
+
+    fn blob_content(uri: &str, mime_type: &str, bytes: &[u8]) -> MCPResourceContent {
+        MCPResourceContent {
+            uri: uri.to_string(),
+            content: None,
+            blob: Some(base64::engine::general_purpose::STANDARD.encode(bytes)),
+            mime_type: Some(mime_type.to_string()),
+            annotations: None,
+            meta: None,
+        }
+    }
+
+    #[test]
+    fn classifies_by_mime_type_then_extension() {
+        assert_eq!(
+            classify_resource_content(Some("text/markdown; charset=utf-8"), "docs://a"),
+            ResourceContentKind::Text
+        );
+        assert_eq!(
+            classify_resource_content(Some("application/pdf"), "docs://a"),
+            ResourceContentKind::Pdf
+        );
+        assert_eq!(
+            classify_resource_content(Some("image/svg+xml"), "docs://logo"),
+            ResourceContentKind::Text
+        );
+        assert_eq!(
+            classify_resource_content(Some("image/png"), "docs://logo"),
+            ResourceContentKind::Image
+        );
+        assert_eq!(
+            classify_resource_content(Some("application/zip"), "docs://a.md"),
+            ResourceContentKind::Binary
+        );
+        assert_eq!(
+            classify_resource_content(None, "file:///docs/report.PDF?v=2"),
+            ResourceContentKind::Pdf
+        );
+        assert_eq!(
+            classify_resource_content(Some("application/octet-stream"), "docs://notes.md"),
+            ResourceContentKind::Text
+        );
+    }
+
+    #[tokio::test]
+    async fn markdown_passes_through_within_budget() {
+        let content = text_content("docs://readme", "text/markdown", "# Title\n\nBody");
+        let part =
+            convert_resource_content(&content, &MCPResourceAttachmentOptions::default(), 100).await;
+
+        match part {
+            MCPResourceAttachmentPart::Text {
+                text, truncated, ..
+            } => {
+                assert_eq!(text, "# Title\n\nBody");
+                assert!(!truncated);
+            }
+            other => panic!("expected text part, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn text_is_truncated_to_the_remaining_budget() {
+        let content = text_content("docs://big", "text/plain", &"a".repeat(1_000));
+        let part =
+            convert_resource_content(&content, &MCPResourceAttachmentOptions::default(), 30).await;
+
+        match part {
+            MCPResourceAttachmentPart::Text {
+                text,
+                estimated_tokens,
+                truncated,
+                ..
+            } => {
+                assert!(truncated);
+                assert_eq!(text.len(), 240);
+                assert_eq!(estimated_tokens, 30);
+            }
+            other => panic!("expected text part, got {:?}", other),
+        }
+
+        let exhausted =
+            convert_resource_content(&content, &MCPResourceAttachmentOptions::default(), 0).await;
+        assert!(matches!(
+            exhausted,
+            MCPResourceAttachmentPart::Omitted { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn images_are_omitted_unless_requested() {
+        let content = blob_content("docs://logo", "image/png", b"not-really-a-png");
+        let part =
+            convert_resource_content(&content, &MCPResourceAttachmentOptions::default(), 10_000)
+                .await;
+        assert!(matches!(part, MCPResourceAttachmentPart::Omitted { .. }));
+
+        let options = MCPResourceAttachmentOptions {
+            include_images: true,
+            ..Default::default()
+        };
+        let part = convert_resource_content(&content, &options, 10_000).await;
+        match part {
+            MCPResourceAttachmentPart::Image {
+                mime_type,
+                data_base64,
+                estimated_tokens,
+                ..
+            } => {
+                assert_eq!(mime_type, "image/png");
+                assert_eq!(data_base64, content.blob.clone().unwrap());
+                assert_eq!(estimated_tokens, DEFAULT_IMAGE_TOKEN_ESTIMATE);
+            }
+            other => panic!("expected image part, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn text_blobs_are_decoded() {
+        let content = blob_content("docs://data.json", "application/json", br#"{"a":1}"#);
+        let part =
+            convert_resource_content(&content, &MCPResourceAttachmentOptions::default(), 100).await;
+        assert!(matches!(
+            part,
+            MCPResourceAttachmentPart::Text { ref text, .. } if text == r#"{"a":1}"#
+        ));
+    }
+}
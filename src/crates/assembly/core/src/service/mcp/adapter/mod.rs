@@ -2,14 +2,19 @@
 //!
 //! Adapts MCP resources, prompts, and tools to BitFun's agentic system.
 
+mod attachment;
 mod context;
 mod prompt;
 mod resource;
 mod tool;
 
+pub use attachment::{
+    attach_mcp_resources, MCPResourceAttachment, MCPResourceAttachmentOptions,
+    MCPResourceAttachmentPart, DEFAULT_MCP_ATTACHMENT_TOKEN_BUDGET,
+};
 pub use bitfun_services_integrations::mcp::adapter::MCPContextEnhancer as ContextEnhancer;
 pub use context::MCPContextProvider;
 pub use prompt::PromptAdapter;
 pub use resource::ResourceAdapter;
 pub use tool::MCPToolAdapter;
-pub(crate) use tool::{MCPToolContextPolicy, MCPWorkspaceToolRoute};
+pub(crate) use tool::{MCPToolContextPolicy, MCPWorkspaceToolRoute, MCP_READ_ONLY_METADATA_KEY};
@@ -2,14 +2,17 @@
 //!
 //! Wraps MCP tools as implementations of BitFun's `Tool` trait.
 
+use crate::agentic::image_analysis::optimize_image_for_provider;
 use crate::agentic::tools::framework::{
     DynamicToolInfo, PermissionIntent, Tool, ToolExposure, ToolRenderOptions, ToolResult,
     ToolUseContext, ValidationResult,
 };
-use crate::service::mcp::protocol::{MCPTool, MCPToolResult};
+use crate::service::mcp::protocol::{MCPTool, MCPToolResult, MCPToolResultContent};
 use crate::service::mcp::server::MCPConnection;
 use crate::util::errors::BitFunResult;
+use crate::util::types::ToolImageAttachment;
 use async_trait::async_trait;
+use base64::Engine as _;
 use bitfun_agent_tools::{
     build_mcp_tool_bridge_result, mcp_tool_bridge_dynamic_tool_info,
     mcp_tool_bridge_short_description, render_mcp_tool_bridge_rejected_message,
@@ -19,6 +22,7 @@ use bitfun_agent_tools::{
 use bitfun_services_integrations::mcp::adapter::{
     render_mcp_tool_result_for_assistant, MCPDynamicToolProvider, McpDynamicToolDescriptor,
 };
+use bitfun_services_integrations::mcp::server::MCPCapabilitySnapshot;
 use log::{debug, error, info, warn};
 use serde_json::Value;
 use std::collections::{BTreeSet, HashMap};
@@ -26,9 +30,20 @@ use std::sync::Arc;
 use std::sync::RwLock;
 
 const MCP_TOOL_DEFAULT_EXPOSURE: ToolExposure = ToolExposure::Deferred;
-
-fn dynamic_mcp_permission_intent(full_name: &str) -> PermissionIntent {
-    PermissionIntent::new("mcp", vec![full_name.to_string()])
+/// Most images forwarded to the model from one MCP tool result.
+const MCP_TOOL_RESULT_MAX_IMAGES: usize = 4;
+
+/// Display metadata flag marking an MCP tool the server declares read-only;
+/// asks for other tools go through the destructive-tool approval.
+pub(crate) const MCP_READ_ONLY_METADATA_KEY: &str = "mcpReadOnly";
+
+fn dynamic_mcp_permission_intent(full_name: &str, read_only: bool) -> PermissionIntent {
+    let mut intent = PermissionIntent::new("mcp", vec![full_name.to_string()]);
+    intent.display_metadata.insert(
+        MCP_READ_ONLY_METADATA_KEY.to_string(),
+        Value::Bool(read_only),
+    );
+    intent
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -40,6 +55,9 @@ pub(crate) struct MCPWorkspaceToolRoute {
 #[derive(Debug, Default)]
 pub(crate) struct MCPToolContextPolicy {
     routes: RwLock<HashMap<String, MCPWorkspaceToolRoute>>,
+    /// Tools per server that changed since the user approved the server's
+    /// capabilities and stay unavailable until re-approved.
+    gated_tools: RwLock<HashMap<String, BTreeSet<String>>>,
 }
 
 impl MCPToolContextPolicy {
@@ -52,6 +70,23 @@ impl MCPToolContextPolicy {
         }
     }
 
+    pub(crate) fn replace_gated_tools(&self, server_id: &str, tools: BTreeSet<String>) {
+        let mut gated_tools = self.gated_tools.write().expect("MCP gate lock poisoned");
+        if tools.is_empty() {
+            gated_tools.remove(server_id);
+        } else {
+            gated_tools.insert(server_id.to_string(), tools);
+        }
+    }
+
+    pub(crate) fn tool_awaits_approval(&self, server_id: &str, tool_name: &str) -> bool {
+        self.gated_tools
+            .read()
+            .expect("MCP gate lock poisoned")
+            .get(server_id)
+            .is_some_and(|tools| tools.contains(tool_name))
+    }
+
     pub(crate) fn server_available_for_route(
         &self,
         server_id: &str,
@@ -130,6 +165,53 @@ impl MCPToolWrapper {
         )
     }
 
+    fn awaits_approval(&self) -> bool {
+        self.context_policy
+            .tool_awaits_approval(&self.server_id, &self.mcp_tool.name)
+    }
+
+    fn awaiting_approval_message(&self) -> String {
+        format!(
+            "MCP tool '{}' from server '{}' changed since it was approved and is unavailable until the update is re-approved",
+            self.mcp_tool.name, self.descriptor.tool_info.server_name
+        )
+    }
+
+    /// Image blocks of `result`, downscaled to `provider`'s limits. Images
+    /// that cannot be decoded stay text-only; the rendered result still names
+    /// them.
+    fn result_image_attachments(
+        result: &MCPToolResult,
+        provider: &str,
+    ) -> Vec<ToolImageAttachment> {
+        if result.is_error {
+            return Vec::new();
+        }
+        result
+            .content
+            .iter()
+            .flatten()
+            .filter_map(|content| match content {
+                MCPToolResultContent::Image { data, mime_type } => Some((data, mime_type)),
+                _ => None,
+            })
+            .filter_map(|(data, mime_type)| {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(data.trim())
+                    .map_err(|e| warn!("Skipping undecodable MCP image result: {}", e))
+                    .ok()?;
+                let processed = optimize_image_for_provider(bytes, provider, Some(mime_type))
+                    .map_err(|e| warn!("Skipping MCP image result: {}", e))
+                    .ok()?;
+                Some(ToolImageAttachment {
+                    mime_type: processed.mime_type,
+                    data_base64: base64::engine::general_purpose::STANDARD.encode(&processed.data),
+                })
+            })
+            .take(MCP_TOOL_RESULT_MAX_IMAGES)
+            .collect()
+    }
+
     // Do not pre-truncate MCP output here. The shared tool-result storage policy
     // owns the model-visible budget and persists oversized results with a preview.
     fn render_mcp_result_for_assistant(tool_name: &str, result: &MCPToolResult) -> String {
@@ -189,7 +271,7 @@ impl Tool for MCPToolWrapper {
     }
 
     async fn is_available_in_context(&self, context: Option<&ToolUseContext>) -> bool {
-        !self.is_blocked_in_context(context)
+        !self.is_blocked_in_context(context) && !self.awaits_approval()
     }
 
     fn is_readonly(&self) -> bool {
@@ -207,6 +289,7 @@ impl Tool for MCPToolWrapper {
     ) -> BitFunResult<Vec<PermissionIntent>> {
         Ok(vec![dynamic_mcp_permission_intent(
             &self.descriptor.full_name,
+            self.is_readonly(),
         )])
     }
 
@@ -215,6 +298,14 @@ impl Tool for MCPToolWrapper {
         input: &Value,
         context: Option<&ToolUseContext>,
     ) -> ValidationResult {
+        if self.awaits_approval() {
+            return ValidationResult {
+                result: false,
+                message: Some(self.awaiting_approval_message()),
+                error_code: Some(400),
+                meta: None,
+            };
+        }
         validate_mcp_tool_bridge_input(
             input,
             &self.descriptor.tool_info.server_name,
@@ -263,6 +354,11 @@ impl Tool for MCPToolWrapper {
                 self.descriptor.tool_info.server_name
             )));
         }
+        if self.awaits_approval() {
+            return Err(crate::util::errors::BitFunError::tool(
+                self.awaiting_approval_message(),
+            ));
+        }
 
         info!(
             "Calling MCP tool: {} from server: {}",
@@ -284,12 +380,28 @@ impl Tool for MCPToolWrapper {
         let elapsed = start.elapsed();
         debug!("MCP tool returned after {:?}", elapsed);
 
+        let facts = context.primary_model_facts();
+        let image_attachments = if context.primary_model_supports_image_understanding()
+            && facts.multimodal_tool_output_supported()
+        {
+            Self::result_image_attachments(&result, &facts.api_format.to_lowercase())
+        } else {
+            Vec::new()
+        };
+
         let result_value = serde_json::to_value(&result)?;
 
         let result_for_assistant = self.render_result_for_assistant(&result_value);
-        Ok(vec![build_mcp_tool_bridge_result(
+        if image_attachments.is_empty() {
+            return Ok(vec![build_mcp_tool_bridge_result(
+                result_value,
+                result_for_assistant,
+            )]);
+        }
+        Ok(vec![ToolResult::ok_with_images(
             result_value,
-            result_for_assistant,
+            Some(result_for_assistant),
+            image_attachments,
         )])
     }
 }
@@ -297,12 +409,18 @@ impl Tool for MCPToolWrapper {
 /// MCP tool adapter that manages multiple MCP tool wrappers.
 pub struct MCPToolAdapter {
     tools: Vec<Arc<dyn Tool>>,
+    mcp_tools: Vec<MCPTool>,
+    capabilities: MCPCapabilitySnapshot,
 }
 
 impl MCPToolAdapter {
     /// Creates a new tool adapter.
     pub fn new() -> Self {
-        Self { tools: Vec::new() }
+        Self {
+            tools: Vec::new(),
+            mcp_tools: Vec::new(),
+            capabilities: MCPCapabilitySnapshot::default(),
+        }
     }
 
     /// Loads tools from an MCP server.
@@ -334,6 +452,13 @@ impl MCPToolAdapter {
             server_name
         );
 
+        let mcp_tools = definitions
+            .iter()
+            .map(|definition| definition.mcp_tool.clone())
+            .collect::<Vec<_>>();
+        self.capabilities = MCPCapabilitySnapshot::from_tools(&mcp_tools);
+        self.mcp_tools = mcp_tools;
+
         if definitions.is_empty() {
             warn!("Server {} provided no tools", server_name);
             return Ok(());
@@ -363,9 +488,21 @@ impl MCPToolAdapter {
         &self.tools
     }
 
+    /// Returns the raw MCP definitions, in the same order as [`Self::get_tools`].
+    pub fn mcp_tools(&self) -> &[MCPTool] {
+        &self.mcp_tools
+    }
+
+    /// Returns the capability snapshot of the last loaded catalog.
+    pub fn capability_snapshot(&self) -> &MCPCapabilitySnapshot {
+        &self.capabilities
+    }
+
     /// Clears all tools.
     pub fn clear(&mut self) {
         self.tools.clear();
+        self.mcp_tools.clear();
+        self.capabilities = MCPCapabilitySnapshot::default();
     }
 }
 
@@ -378,9 +515,48 @@ impl Default for MCPToolAdapter {
 #[cfg(test)]
 mod tests {
     use super::{
-        dynamic_mcp_permission_intent, MCPToolContextPolicy, MCPWorkspaceToolRoute, ToolExposure,
-        MCP_TOOL_DEFAULT_EXPOSURE,
+        dynamic_mcp_permission_intent, MCPToolContextPolicy, MCPToolResult, MCPToolResultContent,
+        MCPToolWrapper, MCPWorkspaceToolRoute, ToolExposure, MCP_READ_ONLY_METADATA_KEY,
+        MCP_TOOL_DEFAULT_EXPOSURE, MCP_TOOL_RESULT_MAX_IMAGES,
     };
+    use base64::Engine as _;
+
+    #[test]
+    fn mcp_result_images_are_decoded_and_capped() {
+        let mut png = Vec::new();
+        image::DynamicImage::new_rgb8(2, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .expect("encode png");
+        let image = MCPToolResultContent::Image {
+            data: base64::engine::general_purpose::STANDARD.encode(&png),
+            mime_type: "image/png".to_string(),
+        };
+        let mut content = vec![
+            MCPToolResultContent::Text {
+                text: "screenshot".to_string(),
+            },
+            MCPToolResultContent::Image {
+                data: "not base64".to_string(),
+                mime_type: "image/png".to_string(),
+            },
+        ];
+        content.extend(std::iter::repeat(image).take(MCP_TOOL_RESULT_MAX_IMAGES + 1));
+        let mut result = MCPToolResult {
+            content: Some(content),
+            is_error: false,
+            structured_content: None,
+            meta: None,
+        };
+
+        let attachments = MCPToolWrapper::result_image_attachments(&result, "anthropic");
+        assert_eq!(attachments.len(), MCP_TOOL_RESULT_MAX_IMAGES);
+        assert!(attachments
+            .iter()
+            .all(|attachment| attachment.mime_type == "image/png"));
+
+        result.is_error = true;
+        assert!(MCPToolWrapper::result_image_attachments(&result, "anthropic").is_empty());
+    }
 
     #[test]
     fn external_mcp_routes_are_workspace_scoped_and_remote_fail_closed() {
@@ -417,6 +593,19 @@ mod tests {
         assert!(policy.server_available_for_route("native", None, Some("workspace-a"), true,));
     }
 
+    #[test]
+    fn gated_tools_await_approval_until_the_gate_is_cleared() {
+        let policy = MCPToolContextPolicy::default();
+        policy.replace_gated_tools("github", ["delete_repo".to_string()].into_iter().collect());
+
+        assert!(policy.tool_awaits_approval("github", "delete_repo"));
+        assert!(!policy.tool_awaits_approval("github", "search_repos"));
+        assert!(!policy.tool_awaits_approval("gitlab", "delete_repo"));
+
+        policy.replace_gated_tools("github", Default::default());
+        assert!(!policy.tool_awaits_approval("github", "delete_repo"));
+    }
+
     #[test]
     fn mcp_tool_wrapper_defaults_to_deferred_exposure() {
         assert_eq!(MCP_TOOL_DEFAULT_EXPOSURE, ToolExposure::Deferred);
@@ -424,12 +613,16 @@ mod tests {
 
     #[test]
     fn dynamic_mcp_tools_use_the_full_server_tool_identity() {
-        let intent = dynamic_mcp_permission_intent("mcp__github__search_repositories");
+        let intent = dynamic_mcp_permission_intent("mcp__github__search_repositories", true);
 
         assert_eq!(intent.action, "mcp");
         assert_eq!(
             intent.resources,
             ["mcp__github__search_repositories".to_string()]
         );
+        assert_eq!(
+            intent.display_metadata[MCP_READ_ONLY_METADATA_KEY],
+            serde_json::Value::Bool(true)
+        );
     }
 }
@@ -0,0 +1,276 @@
+//! Scratch storage for MCP App widgets.
+//!
+//! Each widget instance owns one directory under the MCP Apps data folder.
+//! Widgets address files by relative path only; anything that could leave the
+//! directory (absolute paths, `..`, drive prefixes, symlinks) is rejected, and
+//! both single files and the whole directory are size-capped.
+
+use crate::util::errors::{BitFunError, BitFunResult};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Largest file a widget may read or write, scratch or user-picked.
+pub const MAX_MCP_APP_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Total size of one widget's scratch directory.
+pub const MAX_MCP_APP_SCRATCH_BYTES: u64 = 50 * 1024 * 1024;
+
+const MAX_MCP_APP_PATH_LEN: usize = 256;
+
+/// How file content travels over the JSON-RPC bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum McpAppFileEncoding {
+    #[default]
+    Utf8,
+    Base64,
+}
+
+impl McpAppFileEncoding {
+    pub fn encode(self, bytes: Vec<u8>) -> BitFunResult<String> {
+        match self {
+            Self::Utf8 => String::from_utf8(bytes).map_err(|_| {
+                BitFunError::validation("File is not valid UTF-8; read it with base64 encoding")
+            }),
+            Self::Base64 => Ok(BASE64.encode(bytes)),
+        }
+    }
+
+    pub fn decode(self, content: &str) -> BitFunResult<Vec<u8>> {
+        match self {
+            Self::Utf8 => Ok(content.as_bytes().to_vec()),
+            Self::Base64 => BASE64.decode(content).map_err(|error| {
+                BitFunError::validation(format!("Invalid base64 content: {}", error))
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpAppScratchEntry {
+    /// Relative path with `/` separators.
+    pub path: String,
+    pub size: u64,
+}
+
+/// One widget instance's scratch directory.
+#[derive(Debug, Clone)]
+pub struct McpAppScratchDir {
+    root: PathBuf,
+}
+
+impl McpAppScratchDir {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    pub async fn read(&self, relative: &str) -> BitFunResult<Vec<u8>> {
+        let path = self.resolve(relative)?;
+        let metadata = match tokio::fs::symlink_metadata(&path).await {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Err(BitFunError::NotFound(format!(
+                    "Scratch file not found: {}",
+                    relative
+                )));
+            }
+            Err(error) => return Err(error.into()),
+        };
+        if !metadata.is_file() {
+            return Err(BitFunError::validation(format!(
+                "Not a regular file: {}",
+                relative
+            )));
+        }
+        check_file_size(metadata.len())?;
+        Ok(tokio::fs::read(&path).await?)
+    }
+
+    pub async fn write(&self, relative: &str, bytes: &[u8]) -> BitFunResult<()> {
+        let path = self.resolve(relative)?;
+        check_file_size(bytes.len() as u64)?;
+        let replaced = match tokio::fs::symlink_metadata(&path).await {
+            Ok(metadata) if metadata.is_file() => metadata.len(),
+            Ok(_) => {
+                return Err(BitFunError::validation(format!(
+                    "Not a regular file: {}",
+                    relative
+                )));
+            }
+            Err(_) => 0,
+        };
+        let used = self.used_bytes().await?;
+        if used.saturating_sub(replaced) + bytes.len() as u64 > MAX_MCP_APP_SCRATCH_BYTES {
+            return Err(BitFunError::validation(format!(
+                "Widget storage is full; the limit is {} bytes",
+                MAX_MCP_APP_SCRATCH_BYTES
+            )));
+        }
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, bytes).await?;
+        Ok(())
+    }
+
+    /// Returns whether the file existed.
+    pub async fn delete(&self, relative: &str) -> BitFunResult<bool> {
+        let path = self.resolve(relative)?;
+        match tokio::fs::symlink_metadata(&path).await {
+            Ok(metadata) if metadata.is_file() => {
+                tokio::fs::remove_file(&path).await?;
+                Ok(true)
+            }
+            Ok(_) => Err(BitFunError::validation(format!(
+                "Not a regular file: {}",
+                relative
+            ))),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Regular files in the directory, sorted by path.
+    pub async fn list(&self) -> BitFunResult<Vec<McpAppScratchEntry>> {
+        let mut entries = Vec::new();
+        let mut pending = vec![self.root.clone()];
+        while let Some(dir) = pending.pop() {
+            let mut read_dir = match tokio::fs::read_dir(&dir).await {
+                Ok(read_dir) => read_dir,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error.into()),
+            };
+            while let Some(entry) = read_dir.next_entry().await? {
+                let file_type = entry.file_type().await?;
+                if file_type.is_dir() {
+                    pending.push(entry.path());
+                } else if file_type.is_file() {
+                    let path = entry.path();
+                    let Ok(relative) = path.strip_prefix(&self.root) else {
+                        continue;
+                    };
+                    entries.push(McpAppScratchEntry {
+                        path: relative
+                            .components()
+                            .map(|component| component.as_os_str().to_string_lossy())
+                            .collect::<Vec<_>>()
+                            .join("/"),
+                        size: entry.metadata().await?.len(),
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(entries)
+    }
+
+    pub async fn used_bytes(&self) -> BitFunResult<u64> {
+        Ok(self.list().await?.iter().map(|entry| entry.size).sum())
+    }
+
+    pub async fn remove_all(&self) -> BitFunResult<()> {
+        match tokio::fs::remove_dir_all(&self.root).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn resolve(&self, relative: &str) -> BitFunResult<PathBuf> {
+        Ok(self.root.join(validate_scratch_path(relative)?))
+    }
+}
+
+/// Checks a widget-supplied path and returns it as a relative `PathBuf`.
+///
+/// Backslashes and colons are refused on every platform so a path accepted on
+/// one OS cannot mean something else on another.
+pub fn validate_scratch_path(relative: &str) -> BitFunResult<PathBuf> {
+    let invalid = |reason: &str| {
+        BitFunError::validation(format!("Invalid scratch path '{}': {}", relative, reason))
+    };
+    if relative.is_empty() {
+        return Err(invalid("path is empty"));
+    }
+    if relative.len() > MAX_MCP_APP_PATH_LEN {
+        return Err(invalid("path is too long"));
+    }
+    if relative.contains(['\\', ':', '\0']) {
+        return Err(invalid("path contains a reserved character"));
+    }
+    let path = Path::new(relative);
+    if path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Err(invalid(
+            "path must be relative and stay inside the widget folder",
+        ));
+    }
+    Ok(path.to_path_buf())
+}
+
+pub fn check_file_size(size: u64) -> BitFunResult<()> {
+    if size > MAX_MCP_APP_FILE_BYTES {
+        return Err(BitFunError::validation(format!(
+            "File is {} bytes; the limit is {} bytes",
+            size, MAX_MCP_APP_FILE_BYTES
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_paths_that_leave_the_scratch_dir() {
+        assert!(validate_scratch_path("notes/today.md").is_ok());
+        for bad in [
+            "",
+            "/etc/passwd",
+            "../other",
+            "a/../../b",
+            "./a",
+            "C:/x",
+            "a\\b",
+        ] {
+            assert!(validate_scratch_path(bad).is_err(), "accepted {:?}", bad);
+        }
+    }
+
+    #[tokio::test]
+    async fn writes_reads_and_enforces_limits() {
+        let dir = tempfile::tempdir().unwrap();
+        let scratch = McpAppScratchDir::new(dir.path().join("instance"));
+
+        scratch.write("data/a.json", b"{}").await.unwrap();
+        assert_eq!(scratch.read("data/a.json").await.unwrap(), b"{}");
+        assert_eq!(
+            scratch.list().await.unwrap(),
+            vec![McpAppScratchEntry {
+                path: "data/a.json".to_string(),
+                size: 2
+            }]
+        );
+
+        let too_big = vec![0u8; MAX_MCP_APP_FILE_BYTES as usize + 1];
+        assert!(scratch.write("big.bin", &too_big).await.is_err());
+        assert!(scratch.read("missing").await.is_err());
+
+        assert!(scratch.delete("data/a.json").await.unwrap());
+        assert!(!scratch.delete("data/a.json").await.unwrap());
+        scratch.remove_all().await.unwrap();
+        assert!(scratch.list().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn round_trips_encodings() {
+        let bytes = vec![0xff, 0x00, 0x10];
+        let encoded = McpAppFileEncoding::Base64.encode(bytes.clone()).unwrap();
+        assert_eq!(McpAppFileEncoding::Base64.decode(&encoded).unwrap(), bytes);
+        assert!(McpAppFileEncoding::Utf8.encode(bytes).is_err());
+    }
+}
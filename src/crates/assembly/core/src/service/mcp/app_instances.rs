@@ -0,0 +1,369 @@
+//! Open MCP App widget instances.
+//!
+//! Every rendered `ui://` widget gets its own instance with an isolated state
+//! blob, so two cards showing the same resource never share state. Instances
+//! are keyed by the tool call that produced them; re-opening the same call
+//! after a restart returns the saved instance instead of a fresh one.
+//! Each instance also owns a scratch directory (see [`super::app_files`]) that
+//! is removed together with the instance.
+
+use super::app_files::McpAppScratchDir;
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use bitfun_services_core::json_store::JsonFileStore;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+pub const MCP_APP_INSTANCES_VERSION: u32 = 1;
+
+/// Largest widget state blob accepted, measured as serialized JSON.
+pub const MAX_MCP_APP_STATE_BYTES: usize = 256 * 1024;
+
+/// Instances beyond this count are dropped, least recently used first.
+const MAX_MCP_APP_INSTANCES: usize = 200;
+
+/// Instances untouched for this long are dropped on load.
+const MCP_APP_INSTANCE_RETENTION_MS: i64 = 30 * 24 * 60 * 60 * 1000;
+
+static GLOBAL_MCP_APP_INSTANCES: OnceLock<Arc<McpAppInstanceRegistry>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpAppInstance {
+    pub instance_id: String,
+    pub server_id: String,
+    pub resource_uri: String,
+    /// Tool call that rendered the widget; reopening the same call restores this instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub created_at_ms: i64,
+    pub updated_at_ms: i64,
+    /// Opaque state the widget asked the host to keep.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenMcpAppInstance {
+    pub server_id: String,
+    pub resource_uri: String,
+    #[serde(default)]
+    pub tool_call_id: Option<String>,
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpAppInstancesFile {
+    pub version: u32,
+    #[serde(default)]
+    pub instances: Vec<McpAppInstance>,
+}
+
+impl Default for McpAppInstancesFile {
+    fn default() -> Self {
+        Self {
+            version: MCP_APP_INSTANCES_VERSION,
+            instances: Vec::new(),
+        }
+    }
+}
+
+pub struct McpAppInstanceRegistry {
+    path: PathBuf,
+    scratch_root: PathBuf,
+    instances: Mutex<Vec<McpAppInstance>>,
+}
+
+impl McpAppInstanceRegistry {
+    pub async fn new(path_manager: Arc<PathManager>) -> Arc<Self> {
+        Self::load(
+            path_manager.mcp_app_instances_file(),
+            path_manager.mcp_app_scratch_dir(),
+        )
+        .await
+    }
+
+    async fn load(path: PathBuf, scratch_root: PathBuf) -> Arc<Self> {
+        let file = match JsonFileStore
+            .read_optional::<McpAppInstancesFile>(&path)
+            .await
+        {
+            Ok(Some(file)) if file.version == MCP_APP_INSTANCES_VERSION => file,
+            Ok(Some(file)) => {
+                warn!(
+                    "Ignoring MCP App instances file with unsupported version: path={}, version={}",
+                    path.display(),
+                    file.version
+                );
+                McpAppInstancesFile::default()
+            }
+            Ok(None) => McpAppInstancesFile::default(),
+            Err(error) => {
+                warn!(
+                    "Failed to load MCP App instances, starting empty: path={}, error={}",
+                    path.display(),
+                    error
+                );
+                McpAppInstancesFile::default()
+            }
+        };
+        let mut instances = file.instances;
+        prune(&mut instances, now_ms());
+        let registry = Arc::new(Self {
+            path,
+            scratch_root,
+            instances: Mutex::new(instances),
+        });
+        registry.remove_orphaned_scratch_dirs().await;
+        registry
+    }
+
+    /// Scratch directory owned by one instance. Callers must have resolved the
+    /// id through [`Self::get`]; ids are generated, never taken from widgets.
+    pub fn scratch_dir(&self, instance_id: &str) -> McpAppScratchDir {
+        McpAppScratchDir::new(self.scratch_root.join(instance_id))
+    }
+
+    /// Instances restored from the last run plus those opened since, most recent first.
+    pub async fn list(&self) -> Vec<McpAppInstance> {
+        let mut instances = self.instances.lock().await.clone();
+        instances.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+        instances
+    }
+
+    pub async fn get(&self, instance_id: &str) -> Option<McpAppInstance> {
+        self.instances
+            .lock()
+            .await
+            .iter()
+            .find(|instance| instance.instance_id == instance_id)
+            .cloned()
+    }
+
+    /// Returns the instance already bound to the same tool call, or registers a new one.
+    pub async fn open(&self, request: OpenMcpAppInstance) -> BitFunResult<McpAppInstance> {
+        if !request.resource_uri.starts_with("ui://") {
+            return Err(BitFunError::validation(
+                "Resource URI must use ui:// scheme",
+            ));
+        }
+        let now = now_ms();
+        let mut instances = self.instances.lock().await;
+        if let Some(tool_call_id) = request.tool_call_id.as_deref() {
+            if let Some(existing) = instances.iter_mut().find(|instance| {
+                instance.tool_call_id.as_deref() == Some(tool_call_id)
+                    && instance.server_id == request.server_id
+                    && instance.resource_uri == request.resource_uri
+            }) {
+                existing.updated_at_ms = now;
+                let existing = existing.clone();
+                self.save(&instances).await?;
+                return Ok(existing);
+            }
+        }
+
+        let instance = McpAppInstance {
+            instance_id: format!("mcp_app_{}", Uuid::new_v4()),
+            server_id: request.server_id,
+            resource_uri: request.resource_uri,
+            tool_call_id: request.tool_call_id,
+            session_id: request.session_id,
+            created_at_ms: now,
+            updated_at_ms: now,
+            state: None,
+        };
+        debug!(
+            "MCP App instance opened: instance_id={}, server_id={}, resource_uri={}",
+            instance.instance_id, instance.server_id, instance.resource_uri
+        );
+        instances.push(instance.clone());
+        let before = instances.len();
+        prune(&mut instances, now);
+        self.save(&instances).await?;
+        let pruned = instances.len() < before;
+        drop(instances);
+        if pruned {
+            self.remove_orphaned_scratch_dirs().await;
+        }
+        Ok(instance)
+    }
+
+    /// Replaces the state blob of one instance; `None` clears it.
+    pub async fn set_state(
+        &self,
+        instance_id: &str,
+        state: Option<serde_json::Value>,
+    ) -> BitFunResult<McpAppInstance> {
+        if let Some(state) = &state {
+            let size = serde_json::to_vec(state)?.len();
+            if size > MAX_MCP_APP_STATE_BYTES {
+                return Err(BitFunError::validation(format!(
+                    "MCP App state is {} bytes; the limit is {} bytes",
+                    size, MAX_MCP_APP_STATE_BYTES
+                )));
+            }
+        }
+        let mut instances = self.instances.lock().await;
+        let instance = instances
+            .iter_mut()
+            .find(|instance| instance.instance_id == instance_id)
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!("MCP App instance not found: {}", instance_id))
+            })?;
+        instance.state = state;
+        instance.updated_at_ms = now_ms();
+        let instance = instance.clone();
+        self.save(&instances).await?;
+        Ok(instance)
+    }
+
+    /// Forgets an instance and its state. Returns whether it existed.
+    pub async fn close(&self, instance_id: &str) -> BitFunResult<bool> {
+        let mut instances = self.instances.lock().await;
+        let before = instances.len();
+        instances.retain(|instance| instance.instance_id != instance_id);
+        if instances.len() == before {
+            return Ok(false);
+        }
+        self.save(&instances).await?;
+        drop(instances);
+        if let Err(error) = self.scratch_dir(instance_id).remove_all().await {
+            warn!(
+                "Failed to remove MCP App scratch directory: instance_id={}, error={}",
+                instance_id, error
+            );
+        }
+        Ok(true)
+    }
+
+    /// Best effort: scratch directories of pruned or closed instances are
+    /// removed so widget files never outlive their instance.
+    async fn remove_orphaned_scratch_dirs(&self) {
+        let Ok(mut read_dir) = tokio::fs::read_dir(&self.scratch_root).await else {
+            return;
+        };
+        let instances = self.instances.lock().await.clone();
+        while let Ok(Some(entry)) = read_dir.next_entry().await {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if instances
+                .iter()
+                .any(|instance| instance.instance_id == name)
+            {
+                continue;
+            }
+            if let Err(error) = tokio::fs::remove_dir_all(entry.path()).await {
+                warn!(
+                    "Failed to remove orphaned MCP App scratch directory: path={}, error={}",
+                    entry.path().display(),
+                    error
+                );
+            }
+        }
+    }
+
+    async fn save(&self, instances: &[McpAppInstance]) -> BitFunResult<()> {
+        let file = McpAppInstancesFile {
+            version: MCP_APP_INSTANCES_VERSION,
+            instances: instances.to_vec(),
+        };
+        JsonFileStore
+            .write_atomic(&self.path, &file)
+            .await
+            .map_err(|error| {
+                BitFunError::service(format!("Failed to save MCP App instances: {}", error))
+            })
+    }
+}
+
+fn prune(instances: &mut Vec<McpAppInstance>, now: i64) {
+    instances.retain(|instance| now - instance.updated_at_ms <= MCP_APP_INSTANCE_RETENTION_MS);
+    if instances.len() > MAX_MCP_APP_INSTANCES {
+        instances.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+        instances.truncate(MAX_MCP_APP_INSTANCES);
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+pub fn set_global_mcp_app_instances(registry: Arc<McpAppInstanceRegistry>) {
+    let _ = GLOBAL_MCP_APP_INSTANCES.set(registry);
+}
+
+pub fn get_global_mcp_app_instances() -> Option<Arc<McpAppInstanceRegistry>> {
+    GLOBAL_MCP_APP_INSTANCES.get().cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(tool_call_id: Option<&str>) -> OpenMcpAppInstance {
+        OpenMcpAppInstance {
+            server_id: "charts".to_string(),
+            resource_uri: "ui://charts/widget".to_string(),
+            tool_call_id: tool_call_id.map(str::to_string),
+            session_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn instances_of_one_widget_keep_separate_state_across_reloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("instances.json");
+        let scratch_root = dir.path().join("scratch");
+        let registry = McpAppInstanceRegistry::load(path.clone(), scratch_root.clone()).await;
+
+        let first = registry.open(request(Some("call-1"))).await.unwrap();
+        let second = registry.open(request(Some("call-2"))).await.unwrap();
+        assert_ne!(first.instance_id, second.instance_id);
+        registry
+            .set_state(&first.instance_id, Some(serde_json::json!({ "zoom": 2 })))
+            .await
+            .unwrap();
+
+        let reloaded = McpAppInstanceRegistry::load(path, scratch_root).await;
+        let reopened = reloaded.open(request(Some("call-1"))).await.unwrap();
+        assert_eq!(reopened.instance_id, first.instance_id);
+        assert_eq!(reopened.state, Some(serde_json::json!({ "zoom": 2 })));
+        let other = reloaded.get(&second.instance_id).await.unwrap();
+        assert_eq!(other.state, None);
+        assert_eq!(reloaded.list().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_state_and_non_ui_uris() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = McpAppInstanceRegistry::load(
+            dir.path().join("instances.json"),
+            dir.path().join("scratch"),
+        )
+        .await;
+        let instance = registry.open(request(None)).await.unwrap();
+        let scratch = registry.scratch_dir(&instance.instance_id);
+        scratch.write("note.txt", b"hi").await.unwrap();
+
+        let oversized = serde_json::Value::String("x".repeat(MAX_MCP_APP_STATE_BYTES));
+        assert!(registry
+            .set_state(&instance.instance_id, Some(oversized))
+            .await
+            .is_err());
+
+        let mut bad = request(None);
+        bad.resource_uri = "https://example.com".to_string();
+        assert!(registry.open(bad).await.is_err());
+        assert!(registry.close(&instance.instance_id).await.unwrap());
+        assert!(registry.list().await.is_empty());
+        assert!(scratch.list().await.unwrap().is_empty());
+    }
+}
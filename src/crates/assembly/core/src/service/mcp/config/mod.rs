@@ -2,7 +2,12 @@
 
 mod json_config;
 mod location;
+mod presets;
 mod service;
 
+pub use bitfun_services_integrations::mcp::config::{
+    render_mcp_server_preset, MCPPresetFieldError, MCPPresetParameter, MCPPresetParameterKind,
+    MCPServerPreset, MCPServerPresetCatalog,
+};
 pub use location::ConfigLocation;
 pub use service::MCPConfigService;
@@ -0,0 +1,81 @@
+use bitfun_services_integrations::mcp::config::{
+    insert_mcp_server_into_json_config, render_mcp_server_preset, MCPServerPresetCatalog,
+};
+use log::{info, warn};
+use std::collections::HashMap;
+
+use crate::util::errors::{BitFunError, BitFunResult};
+
+use super::service::MCPConfigService;
+
+/// Optional catalog update in the user data directory, same shape as the
+/// bundled catalog.
+const PRESET_CATALOG_FILE: &str = "mcp_presets.json";
+
+impl MCPConfigService {
+    /// Returns the bundled MCP server presets merged with the user's catalog
+    /// update, if one is installed.
+    pub async fn load_preset_catalog(&self) -> MCPServerPresetCatalog {
+        let catalog = MCPServerPresetCatalog::builtin();
+        let Ok(path_manager) = crate::infrastructure::try_get_path_manager_arc() else {
+            return catalog;
+        };
+        let path = path_manager.user_data_dir().join(PRESET_CATALOG_FILE);
+        let body = match tokio::fs::read_to_string(&path).await {
+            Ok(body) => body,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return catalog,
+            Err(e) => {
+                warn!(
+                    "Failed to read MCP preset catalog update: path={} error={}",
+                    path.display(),
+                    e
+                );
+                return catalog;
+            }
+        };
+        match MCPServerPresetCatalog::parse(&body) {
+            Ok(update) => catalog.merge(update),
+            Err(e) => {
+                warn!(
+                    "Ignoring invalid MCP preset catalog update: path={} error={}",
+                    path.display(),
+                    e
+                );
+                catalog
+            }
+        }
+    }
+
+    /// Renders a preset with the given form values and adds it to the user
+    /// MCP config. Returns the server id it was stored under.
+    pub async fn install_preset(
+        &self,
+        preset_id: &str,
+        values: &HashMap<String, String>,
+    ) -> BitFunResult<String> {
+        let catalog = self.load_preset_catalog().await;
+        let preset = catalog.get(preset_id).ok_or_else(|| {
+            BitFunError::NotFound(format!("MCP server preset not found: {}", preset_id))
+        })?;
+        let server = render_mcp_server_preset(preset, values).map_err(|errors| {
+            BitFunError::validation(
+                errors
+                    .into_iter()
+                    .map(|error| error.message)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })?;
+
+        let mut config: serde_json::Value =
+            serde_json::from_str(&self.load_mcp_json_config().await?)?;
+        let server_id = insert_mcp_server_into_json_config(&mut config, &preset.id, server)?;
+        self.save_mcp_json_config(&serde_json::to_string_pretty(&config)?)
+            .await?;
+        info!(
+            "Installed MCP server preset: preset_id={} server_id={}",
+            preset_id, server_id
+        );
+        Ok(server_id)
+    }
+}
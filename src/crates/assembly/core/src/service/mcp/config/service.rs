@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::service::config::ConfigService;
+use crate::service::mcp::config::ConfigLocation;
 use crate::service::mcp::server::MCPServerConfig;
 use crate::util::errors::BitFunResult;
 
@@ -82,7 +83,11 @@ impl MCPConfigService {
     }
 
     pub async fn load_all_configs(&self) -> BitFunResult<Vec<MCPServerConfig>> {
-        Ok(self.inner.load_all_configs().await?)
+        let mut configs = self.inner.load_all_configs().await?;
+        if current_workspace_restricted() {
+            configs.retain(|config| config.location != ConfigLocation::Project);
+        }
+        Ok(configs)
     }
 
     pub async fn get_server_config(
@@ -119,10 +124,16 @@ impl MCPConfigService {
     }
 }
 
+/// Project-level MCP servers stay disabled until the open workspace is trusted.
+fn current_workspace_restricted() -> bool {
+    crate::service::workspace::get_global_workspace_service()
+        .and_then(|service| service.try_get_current_workspace_path())
+        .is_some_and(|path| crate::service::workspace_trust::is_workspace_restricted(&path))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::service::mcp::config::ConfigLocation;
     use crate::service::mcp::server::MCPServerType;
     use std::collections::HashMap;
 
@@ -143,8 +154,11 @@ mod tests {
             env: HashMap::new(),
             working_directory: None,
             inherit_parent_environment: None,
+            isolation: None,
+            framing: None,
             headers: HashMap::new(),
             url: url.map(str::to_string),
+            tls: None,
             auto_start: true,
             enabled: true,
             location,
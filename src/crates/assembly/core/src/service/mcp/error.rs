@@ -0,0 +1,234 @@
+//! MCP failure taxonomy surfaced to the UI.
+//!
+//! `BitFunError` flattens most MCP failures into strings. This module sorts
+//! them into the categories a user can act on, so the frontend can tell a
+//! crashed server (restart it) from a tool that rejected its arguments (fix
+//! the call) without matching on error text.
+
+use crate::util::errors::{ApiError, ApiErrorCode, BitFunError};
+use bitfun_services_integrations::mcp::server::is_mcp_auth_error_message;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+const TRANSPORT_FAILURE_PATTERNS: &[&str] = &[
+    "channel closed",
+    "connection closed",
+    "connection refused",
+    "connection reset",
+    "broken pipe",
+    "stream closed",
+    "transport",
+    "process exited",
+    "not running",
+    "failed to start mcp server",
+];
+
+/// A classified MCP failure.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MCPFailure {
+    /// The server process or connection is gone (crashed, exited, unreachable).
+    Transport { message: String },
+    /// The server did not answer in time.
+    Timeout { message: String },
+    /// The server answered with something that violates the protocol.
+    Protocol { message: String },
+    /// The server requires (re)authentication.
+    Auth { message: String },
+    /// The server answered with a JSON-RPC error, e.g. rejected tool arguments.
+    Server {
+        message: String,
+        code: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Value>,
+    },
+    /// The MCP configuration or request is invalid.
+    Config { message: String },
+    /// The server, resource, or prompt does not exist.
+    NotFound { message: String },
+    /// Anything else, including host-side failures.
+    Internal { message: String },
+}
+
+impl MCPFailure {
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Transport { message }
+            | Self::Timeout { message }
+            | Self::Protocol { message }
+            | Self::Auth { message }
+            | Self::Server { message, .. }
+            | Self::Config { message }
+            | Self::NotFound { message }
+            | Self::Internal { message } => message,
+        }
+    }
+
+    /// Classifies an error raised while talking to an MCP server.
+    pub fn from_error(error: &BitFunError) -> Self {
+        let message = error.to_string();
+        if is_mcp_auth_error_message(&message) {
+            return Self::Auth { message };
+        }
+
+        match error {
+            BitFunError::MCPServerError {
+                code,
+                message: server_message,
+                data,
+            } => Self::Server {
+                message: server_message.clone(),
+                code: *code,
+                data: data.clone(),
+            },
+            BitFunError::Timeout(_) => Self::Timeout { message },
+            BitFunError::ProcessError(_) | BitFunError::Io(_) | BitFunError::Http(_) => {
+                Self::Transport { message }
+            }
+            BitFunError::MCPError(detail) if is_transport_failure(detail) => {
+                Self::Transport { message }
+            }
+            BitFunError::MCPError(_)
+            | BitFunError::Deserialization(_)
+            | BitFunError::Serialization(_) => Self::Protocol { message },
+            BitFunError::Configuration(_) | BitFunError::Validation(_) => Self::Config { message },
+            BitFunError::NotFound(_) => Self::NotFound { message },
+            _ => Self::Internal { message },
+        }
+    }
+}
+
+fn is_transport_failure(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    TRANSPORT_FAILURE_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+impl fmt::Display for MCPFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Server { message, code, .. } => write!(f, "MCP Error {}: {}", code, message),
+            other => f.write_str(other.message()),
+        }
+    }
+}
+
+impl std::error::Error for MCPFailure {}
+
+impl From<BitFunError> for MCPFailure {
+    fn from(error: BitFunError) -> Self {
+        Self::from_error(&error)
+    }
+}
+
+impl From<bitfun_services_integrations::mcp::MCPRuntimeError> for MCPFailure {
+    fn from(error: bitfun_services_integrations::mcp::MCPRuntimeError) -> Self {
+        Self::from_error(&BitFunError::from(error))
+    }
+}
+
+/// Keeps the classified failure in `details` so the UI can still offer
+/// MCP-specific recovery.
+impl From<MCPFailure> for ApiError {
+    fn from(failure: MCPFailure) -> Self {
+        let code = match &failure {
+            MCPFailure::Transport { .. } => ApiErrorCode::Network,
+            MCPFailure::Timeout { .. } => ApiErrorCode::Timeout,
+            MCPFailure::Protocol { .. } => ApiErrorCode::Protocol,
+            MCPFailure::Auth { .. } => ApiErrorCode::Unauthorized,
+            MCPFailure::Server { .. } => ApiErrorCode::Rejected,
+            MCPFailure::Config { .. } => ApiErrorCode::Configuration,
+            MCPFailure::NotFound { .. } => ApiErrorCode::NotFound,
+            MCPFailure::Internal { .. } => ApiErrorCode::Internal,
+        };
+        let details = serde_json::to_value(&failure).unwrap_or_default();
+        ApiError::new(code, failure.to_string()).with_details(details)
+    }
+}
+
+impl From<bitfun_services_integrations::mcp::MCPRuntimeError> for ApiError {
+    fn from(error: bitfun_services_integrations::mcp::MCPRuntimeError) -> Self {
+        MCPFailure::from(error).into()
+    }
+}
+
+/// Host-side failures that never reached a server.
+impl From<String> for MCPFailure {
+    fn from(message: String) -> Self {
+        Self::Internal { message }
+    }
+}
+
+impl From<&str> for MCPFailure {
+    fn from(message: &str) -> Self {
+        Self::Internal {
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MCPFailure;
+    use crate::util::errors::{ApiError, ApiErrorCode, BitFunError};
+    use serde_json::json;
+
+    #[test]
+    fn server_reported_errors_keep_code_and_data() {
+        let failure = MCPFailure::from(BitFunError::MCPServerError {
+            code: -32602,
+            message: "Invalid params".to_string(),
+            data: Some(json!({"field": "path"})),
+        });
+
+        assert_eq!(
+            serde_json::to_value(&failure).unwrap(),
+            json!({
+                "kind": "server",
+                "message": "Invalid params",
+                "code": -32602,
+                "data": {"field": "path"},
+            })
+        );
+    }
+
+    #[test]
+    fn closed_channels_are_transport_failures() {
+        let failure = MCPFailure::from(BitFunError::MCPError(
+            "Request channel closed for method: tools/call".to_string(),
+        ));
+        assert!(matches!(failure, MCPFailure::Transport { .. }));
+
+        let failure = MCPFailure::from(BitFunError::MCPError(
+            "Missing result in MCP response".to_string(),
+        ));
+        assert!(matches!(failure, MCPFailure::Protocol { .. }));
+    }
+
+    #[test]
+    fn api_errors_keep_the_classified_failure() {
+        let error = ApiError::from(MCPFailure::Transport {
+            message: "Request channel closed".to_string(),
+        });
+        assert_eq!(error.code, ApiErrorCode::Network);
+        assert!(error.retryable);
+        assert_eq!(
+            error.details,
+            Some(json!({"kind": "transport", "message": "Request channel closed"}))
+        );
+    }
+
+    #[test]
+    fn auth_messages_win_over_the_error_variant() {
+        let failure = MCPFailure::from(BitFunError::Http(
+            "Request failed with status code: 401".to_string(),
+        ));
+        assert!(matches!(failure, MCPFailure::Auth { .. }));
+        assert!(matches!(
+            MCPFailure::from(BitFunError::Timeout("tools/call".to_string())),
+            MCPFailure::Timeout { .. }
+        ));
+    }
+}
@@ -8,10 +8,16 @@
 //! - `server`: MCP server management (processes, connections, registry)
 //! - `adapter`: Adapter layer (Resource/Prompt/Tool adapters)
 //! - `config`: MCP configuration management
+//! - `error`: MCP failure taxonomy surfaced to the UI
+//! - `app_instances`: open MCP App widgets and their persisted state
+//! - `app_files`: per-widget scratch storage with path and size checks
 
 pub mod adapter;
+pub mod app_files;
+pub mod app_instances;
 pub mod auth;
 pub mod config;
+pub mod error;
 pub mod protocol;
 pub mod server;
 mod tool_info;
@@ -36,6 +42,7 @@ pub use adapter::{
 };
 
 pub use config::{ConfigLocation, MCPConfigService};
+pub use error::MCPFailure;
 pub use tool_info::McpToolInfo;
 pub use tool_name::{
     build_mcp_tool_name, normalize_name_for_mcp, MCP_TOOL_DELIMITER, MCP_TOOL_PREFIX,
@@ -16,10 +16,11 @@ where
     T: serde::de::DeserializeOwned,
 {
     if let Some(error) = &response.error {
-        return Err(crate::util::errors::BitFunError::MCPError(format!(
-            "MCP Error {}: {}",
-            error.code, error.message
-        )));
+        return Err(crate::util::errors::BitFunError::MCPServerError {
+            code: error.code,
+            message: error.message.clone(),
+            data: error.data.clone(),
+        });
     }
 
     let result = response.result.as_ref().ok_or_else(|| {
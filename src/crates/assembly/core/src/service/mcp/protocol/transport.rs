@@ -1 +1,4 @@
 pub use bitfun_services_integrations::mcp::protocol::MCPTransport;
+pub use bitfun_services_integrations::mcp::protocol::{
+    MCPProtocolTrace, MCPTraceDirection, MCPTraceFrame, MCPTraceSnapshot,
+};
@@ -0,0 +1,187 @@
+use super::*;
+use bitfun_services_integrations::mcp::server::{
+    diff_mcp_capabilities, MCPCapabilityChange, MCPCapabilitySnapshot, MCPCapabilityTrustStore,
+};
+
+/// A capability update that waits for the user before it becomes trusted.
+pub(super) struct PendingCapabilityApproval {
+    change: MCPCapabilityChange,
+    snapshot: MCPCapabilitySnapshot,
+}
+
+impl MCPServerManager {
+    fn capability_trust_store() -> BitFunResult<MCPCapabilityTrustStore> {
+        let data_dir = crate::infrastructure::try_get_path_manager_arc()?.user_data_dir();
+        Ok(MCPCapabilityTrustStore::new(data_dir))
+    }
+
+    /// Compares a freshly loaded tool catalog with the last approved one.
+    ///
+    /// The first catalog is trusted on first use. Changes that only remove
+    /// tools, alter schemas or UI resources, or add non-destructive tools are
+    /// reported and accepted; added or escalated destructive tools are gated
+    /// until `approve_capability_changes` is called.
+    pub(super) async fn review_capability_changes(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        current: &MCPCapabilitySnapshot,
+    ) {
+        let store = match Self::capability_trust_store() {
+            Ok(store) => store,
+            Err(e) => {
+                warn!(
+                    "MCP capability trust unavailable: server_id={} error={}",
+                    server_id, e
+                );
+                return;
+            }
+        };
+        let trusted = match store.load(server_id).await {
+            Ok(trusted) => trusted,
+            Err(e) => {
+                warn!(
+                    "Failed to load MCP capability trust: server_id={} error={}",
+                    server_id, e
+                );
+                None
+            }
+        };
+
+        let Some(trusted) = trusted else {
+            self.trust_capabilities(&store, server_id, current).await;
+            return;
+        };
+        let diff = diff_mcp_capabilities(&trusted, current);
+        if diff.is_empty() {
+            self.pending_capability_approvals
+                .write()
+                .await
+                .remove(server_id);
+            self.tool_context_policy
+                .replace_gated_tools(server_id, Default::default());
+            return;
+        }
+
+        let change = MCPCapabilityChange {
+            server_id: server_id.to_string(),
+            server_name: server_name.to_string(),
+            requires_approval: diff.requires_approval(),
+            diff,
+        };
+        warn!(
+            "MCP server capabilities changed: server_id={} added={} removed={} changed_schemas={} gated={}",
+            server_id,
+            change.diff.added_tools.len(),
+            change.diff.removed_tools.len(),
+            change.diff.changed_schemas.len(),
+            change.diff.gated_tools.len()
+        );
+
+        if change.requires_approval {
+            self.tool_context_policy
+                .replace_gated_tools(server_id, change.diff.gated_tools.iter().cloned().collect());
+            self.pending_capability_approvals.write().await.insert(
+                server_id.to_string(),
+                PendingCapabilityApproval {
+                    change: change.clone(),
+                    snapshot: current.clone(),
+                },
+            );
+        } else {
+            self.trust_capabilities(&store, server_id, current).await;
+        }
+
+        let event_system = get_global_event_system();
+        if let Err(e) = event_system
+            .emit(BackendEvent::Custom {
+                event_name: "backend-event-mcpcapabilitychanged".to_string(),
+                payload: json!(change),
+            })
+            .await
+        {
+            warn!(
+                "Failed to emit MCP capability change event: server_id={} error={}",
+                server_id, e
+            );
+        }
+    }
+
+    async fn trust_capabilities(
+        &self,
+        store: &MCPCapabilityTrustStore,
+        server_id: &str,
+        snapshot: &MCPCapabilitySnapshot,
+    ) {
+        if let Err(e) = store.store(server_id, snapshot).await {
+            warn!(
+                "Failed to store MCP capability trust: server_id={} error={}",
+                server_id, e
+            );
+        }
+        self.pending_capability_approvals
+            .write()
+            .await
+            .remove(server_id);
+        self.tool_context_policy
+            .replace_gated_tools(server_id, Default::default());
+    }
+
+    /// Returns capability updates that wait for approval.
+    pub async fn get_pending_capability_changes(&self) -> Vec<MCPCapabilityChange> {
+        let mut changes = self
+            .pending_capability_approvals
+            .read()
+            .await
+            .values()
+            .map(|pending| pending.change.clone())
+            .collect::<Vec<_>>();
+        changes.sort_by(|a, b| a.server_id.cmp(&b.server_id));
+        changes
+    }
+
+    /// Trusts the pending capability update of a server and releases its
+    /// gated tools.
+    pub async fn approve_capability_changes(&self, server_id: &str) -> BitFunResult<()> {
+        let pending = self
+            .pending_capability_approvals
+            .read()
+            .await
+            .get(server_id)
+            .map(|pending| pending.snapshot.clone())
+            .ok_or_else(|| {
+                BitFunError::NotFound(format!(
+                    "No pending MCP capability changes for server: {}",
+                    server_id
+                ))
+            })?;
+        let store = Self::capability_trust_store()?;
+        store.store(server_id, &pending).await?;
+        self.pending_capability_approvals
+            .write()
+            .await
+            .remove(server_id);
+        self.tool_context_policy
+            .replace_gated_tools(server_id, Default::default());
+        info!("Approved MCP capability changes: server_id={}", server_id);
+        Ok(())
+    }
+
+    /// Forgets the trusted capabilities of a removed server.
+    pub(super) async fn forget_capability_trust(&self, server_id: &str) {
+        self.pending_capability_approvals
+            .write()
+            .await
+            .remove(server_id);
+        self.tool_context_policy
+            .replace_gated_tools(server_id, Default::default());
+        if let Ok(store) = Self::capability_trust_store() {
+            if let Err(e) = store.clear(server_id).await {
+                warn!(
+                    "Failed to clear MCP capability trust: server_id={} error={}",
+                    server_id, e
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use bitfun_services_integrations::mcp::server::{detect_mcp_list_changed_kind, MCPListChangedKind};
+use bitfun_services_integrations::mcp::server::detect_mcp_list_changed_kind;
 use std::collections::HashSet;
 
 impl MCPServerManager {
@@ -279,60 +279,17 @@ impl MCPServerManager {
                 match rx.recv().await {
                     Ok(MCPConnectionEvent::Notification { method, .. }) => {
                         match detect_mcp_list_changed_kind(&method) {
-                            Some(MCPListChangedKind::Tools) => {
+                            Some(kind) => {
                                 info!(
-                                    "Received MCP tools list-changed notification: server_name={} server_id={}",
-                                    server_name_owned, server_id_owned
+                                    "Received MCP list-changed notification: server_name={} server_id={} kind={:?}",
+                                    server_name_owned, server_id_owned, kind
                                 );
-                                if let Err(e) = manager
-                                    .refresh_mcp_tools(
-                                        &server_id_owned,
-                                        &server_name_owned,
-                                        connection_for_refresh.clone(),
-                                    )
-                                    .await
-                                {
-                                    warn!(
-                                        "Failed to refresh MCP tools after list-changed notification: server_name={} server_id={} error={}",
-                                        server_name_owned, server_id_owned, e
-                                    );
-                                }
-                            }
-                            Some(MCPListChangedKind::Prompts) => {
-                                info!(
-                                    "Received MCP prompts list-changed notification: server_name={} server_id={}",
-                                    server_name_owned, server_id_owned
-                                );
-                                if let Err(e) = manager
-                                    .refresh_prompts_catalog(
-                                        &server_id_owned,
-                                        connection_for_refresh.clone(),
-                                    )
-                                    .await
-                                {
-                                    warn!(
-                                        "Failed to refresh MCP prompts catalog after list-changed notification: server_name={} server_id={} error={}",
-                                        server_name_owned, server_id_owned, e
-                                    );
-                                }
-                            }
-                            Some(MCPListChangedKind::Resources) => {
-                                info!(
-                                    "Received MCP resources list-changed notification: server_name={} server_id={}",
-                                    server_name_owned, server_id_owned
+                                manager.schedule_list_changed_refresh(
+                                    &server_id_owned,
+                                    &server_name_owned,
+                                    kind,
+                                    connection_for_refresh.clone(),
                                 );
-                                if let Err(e) = manager
-                                    .refresh_resources_catalog(
-                                        &server_id_owned,
-                                        connection_for_refresh.clone(),
-                                    )
-                                    .await
-                                {
-                                    warn!(
-                                        "Failed to refresh MCP resources catalog after list-changed notification: server_name={} server_id={} error={}",
-                                        server_name_owned, server_id_owned, e
-                                    );
-                                }
                             }
                             None => {
                                 debug!(
@@ -383,6 +340,7 @@ impl MCPServerManager {
     }
 
     pub(super) async fn stop_connection_event_listener(&self, server_id: &str) {
+        self.cancel_list_changed_refresh(server_id);
         let mut tasks = self.connection_event_tasks.write().await;
         if let Some(handle) = tasks.remove(server_id) {
             handle.abort();
@@ -1,6 +1,7 @@
 use super::*;
 use bitfun_services_integrations::mcp::server::{
     mcp_server_is_running, mcp_should_start_after_config_update, resolve_mcp_local_command,
+    MCPInitializationSummary,
 };
 
 impl MCPServerManager {
@@ -24,7 +25,11 @@ impl MCPServerManager {
     }
 
     /// Initializes all servers.
-    pub async fn initialize_all(&self) -> BitFunResult<()> {
+    ///
+    /// Auto-start servers are started concurrently and reported through
+    /// readiness events; the call returns once all of them settled or the
+    /// startup timeout elapsed, whichever comes first.
+    pub async fn initialize_all(&self) -> BitFunResult<MCPInitializationSummary> {
         info!("Initializing all MCP servers");
         let _lifecycle_guard = self.ephemeral_lifecycle.lock().await;
 
@@ -54,7 +59,7 @@ impl MCPServerManager {
 
         if configs.is_empty() {
             debug!("No MCP server configurations found, skipping initialization");
-            return Ok(());
+            return Ok(self.start_servers_concurrently(Vec::new()).await);
         }
 
         self.start_reconnect_monitor_if_needed();
@@ -82,35 +87,20 @@ impl MCPServerManager {
         }
         info!("Registered {} MCP servers", registered_count);
 
-        let mut started_count = 0;
-        let mut failed_count = 0;
-        for config in configs {
-            if config.enabled && config.auto_start {
-                info!(
-                    "Auto-starting MCP server: name={} id={}",
-                    config.name, config.id
-                );
-                match self.start_server(&config.id).await {
-                    Ok(_) => {
-                        started_count += 1;
-                        info!("MCP server started successfully: name={}", config.name);
-                    }
-                    Err(e) => {
-                        failed_count += 1;
-                        error!(
-                            "Failed to auto-start MCP server: name={} id={} error={}",
-                            config.name, config.id, e
-                        );
-                    }
-                }
-            }
-        }
+        let auto_start = configs
+            .into_iter()
+            .filter(|config| config.enabled && config.auto_start)
+            .map(|config| (config.id, config.name))
+            .collect::<Vec<_>>();
+        let summary = self.start_servers_concurrently(auto_start).await;
 
         info!(
-            "MCP server initialization completed: started={} failed={}",
-            started_count, failed_count
+            "MCP server initialization completed: ready={} failed={} pending={}",
+            summary.ready.len(),
+            summary.failed.len(),
+            summary.pending.len()
         );
-        Ok(())
+        Ok(summary)
     }
 
     /// Initializes servers without shutting down existing ones.
@@ -234,13 +224,7 @@ impl MCPServerManager {
                     resolved_command, source_label, server_id
                 );
 
-                proc.start_with_environment_policy(
-                    &resolved_command,
-                    &config.args,
-                    &config.env,
-                    config.working_directory.as_deref().map(Path::new),
-                    config.inherits_parent_environment(),
-                )
+                proc.start_local(&resolved_command, &config)
                     .await
                     .map_err(|e| {
                         error!(
@@ -415,14 +399,7 @@ impl MCPServerManager {
                     .command
                     .as_ref()
                     .ok_or_else(|| BitFunError::Configuration("Missing command".to_string()))?;
-                proc.restart_with_environment_policy(
-                    command,
-                    &config.args,
-                    &config.env,
-                    config.working_directory.as_deref().map(Path::new),
-                    config.inherits_parent_environment(),
-                )
-                .await?;
+                proc.restart_local(command, &config).await?;
             }
             super::super::MCPServerType::Remote => {
                 self.ensure_registered(server_id).await?;
@@ -887,6 +864,8 @@ impl MCPServerManager {
         self.config_service.delete_server_config(server_id).await?;
         self.clear_reconnect_state(server_id).await;
         self.runtime.remove_catalog(server_id).await;
+        self.forget_capability_trust(server_id).await;
+        self.startup_readiness.remove(server_id);
         info!("Deleted MCP server config: id={}", server_id);
 
         Ok(())
@@ -0,0 +1,146 @@
+use super::*;
+use bitfun_services_integrations::mcp::server::{
+    diff_mcp_catalog, MCPCatalogDiff, MCPListChangedEvent, MCPListChangedKind,
+    MCP_LIST_CHANGED_DEBOUNCE,
+};
+
+impl MCPServerManager {
+    /// Schedules a debounced refetch of the catalog named by a list-changed
+    /// notification. Notifications arriving within the debounce window are
+    /// coalesced into a single refetch per catalog.
+    pub(super) fn schedule_list_changed_refresh(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        kind: MCPListChangedKind,
+        connection: Arc<MCPConnection>,
+    ) {
+        let generation = self.list_changed_debouncer.schedule(server_id, kind);
+        let manager = self.clone();
+        let server_id = server_id.to_string();
+        let server_name = server_name.to_string();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(MCP_LIST_CHANGED_DEBOUNCE).await;
+            let Some(kinds) = manager
+                .list_changed_debouncer
+                .take_if_current(&server_id, generation)
+            else {
+                return;
+            };
+            for kind in kinds {
+                manager
+                    .refresh_list_changed_catalog(
+                        &server_id,
+                        &server_name,
+                        kind,
+                        connection.clone(),
+                    )
+                    .await;
+            }
+        });
+    }
+
+    /// Drops list-changed refreshes that have not fired yet.
+    pub(super) fn cancel_list_changed_refresh(&self, server_id: &str) {
+        self.list_changed_debouncer.cancel(server_id);
+    }
+
+    async fn refresh_list_changed_catalog(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        kind: MCPListChangedKind,
+        connection: Arc<MCPConnection>,
+    ) {
+        let result = match kind {
+            MCPListChangedKind::Tools => {
+                self.sync_mcp_tools(server_id, server_name, connection)
+                    .await
+            }
+            MCPListChangedKind::Prompts => self.sync_prompts_catalog(server_id, connection).await,
+            MCPListChangedKind::Resources => {
+                self.sync_resources_catalog(server_id, connection).await
+            }
+        };
+
+        let diff = match result {
+            Ok(diff) => diff,
+            Err(e) => {
+                warn!(
+                    "Failed to refresh MCP catalog after list-changed notification: server_name={} server_id={} kind={:?} error={}",
+                    server_name, server_id, kind, e
+                );
+                return;
+            }
+        };
+
+        if diff.is_empty() {
+            debug!(
+                "MCP catalog unchanged after list-changed notification: server_name={} server_id={} kind={:?}",
+                server_name, server_id, kind
+            );
+            return;
+        }
+
+        info!(
+            "MCP catalog changed: server_name={} server_id={} kind={:?} added={} changed={} removed={}",
+            server_name,
+            server_id,
+            kind,
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len()
+        );
+        self.emit_list_changed_event(MCPListChangedEvent {
+            server_id: server_id.to_string(),
+            server_name: server_name.to_string(),
+            kind,
+            diff,
+        })
+        .await;
+    }
+
+    async fn sync_prompts_catalog(
+        &self,
+        server_id: &str,
+        connection: Arc<MCPConnection>,
+    ) -> BitFunResult<MCPCatalogDiff> {
+        let previous = self.runtime.get_cached_prompts(server_id).await;
+        self.refresh_prompts_catalog(server_id, connection).await?;
+        let current = self.runtime.get_cached_prompts(server_id).await;
+        Ok(diff_mcp_catalog(&previous, &current, |prompt| {
+            prompt.name.as_str()
+        }))
+    }
+
+    async fn sync_resources_catalog(
+        &self,
+        server_id: &str,
+        connection: Arc<MCPConnection>,
+    ) -> BitFunResult<MCPCatalogDiff> {
+        let previous = self.runtime.get_cached_resources(server_id).await;
+        self.refresh_resources_catalog(server_id, connection)
+            .await?;
+        let current = self.runtime.get_cached_resources(server_id).await;
+        Ok(diff_mcp_catalog(&previous, &current, |resource| {
+            resource.uri.as_str()
+        }))
+    }
+
+    async fn emit_list_changed_event(&self, event: MCPListChangedEvent) {
+        let event_system = get_global_event_system();
+        if let Err(e) = event_system
+            .emit(BackendEvent::Custom {
+                event_name: "backend-event-mcplistchanged".to_string(),
+                payload: json!(event),
+            })
+            .await
+        {
+            warn!(
+                "Failed to emit MCP list-changed event: server_id={} error={}",
+                event.server_id, e
+            );
+        }
+    }
+}
@@ -1,16 +1,21 @@
 //! MCP server manager
 //!
-//! The manager is split into focused submodules so lifecycle, reconnect,
-//! catalog, interaction, and tool-registration logic can evolve independently.
+//! The manager is split into focused submodules so lifecycle, startup
+//! readiness, reconnect, catalog, list-changed refresh, interaction,
+//! protocol tracing, and tool-registration logic can evolve independently.
 
 mod auth;
+mod capability;
 mod catalog;
 mod interaction;
 mod lifecycle;
+mod list_changed;
+mod readiness;
 mod reconnect;
 #[cfg(test)]
 mod tests;
 mod tools;
+mod trace;
 
 use super::connection::MCPConnection;
 use super::{MCPServerConfig, MCPServerStatus};
@@ -22,7 +27,9 @@ use crate::service::mcp::protocol::{MCPError, MCPPrompt, MCPResource};
 use crate::service::workspace::get_global_workspace_service;
 use crate::util::errors::{BitFunError, BitFunResult};
 use bitfun_services_integrations::mcp::server::MCPConnectionEvent;
+use bitfun_services_integrations::mcp::server::MCPListChangedDebouncer;
 use bitfun_services_integrations::mcp::server::MCPServerRuntimeState;
+use bitfun_services_integrations::mcp::server::MCPStartupReadiness;
 use log::{debug, error, info, warn};
 use serde_json::{json, Value};
 use std::collections::{HashMap, HashSet};
@@ -56,6 +63,8 @@ pub struct MCPServerManager {
     runtime: Arc<MCPServerRuntimeState>,
     reconnect_monitor_started: Arc<AtomicBool>,
     connection_event_tasks: Arc<tokio::sync::RwLock<HashMap<String, JoinHandle<()>>>>,
+    list_changed_debouncer: Arc<MCPListChangedDebouncer>,
+    startup_readiness: Arc<MCPStartupReadiness>,
     pending_interactions: Arc<tokio::sync::RwLock<HashMap<String, PendingMCPInteraction>>>,
     oauth_sessions: Arc<tokio::sync::RwLock<HashMap<String, Arc<ActiveRemoteOAuthSession>>>>,
     ephemeral_retirements: Arc<tokio::sync::RwLock<HashMap<String, Arc<AtomicBool>>>>,
@@ -63,6 +72,8 @@ pub struct MCPServerManager {
     ephemeral_ready_servers: Arc<tokio::sync::RwLock<HashSet<String>>>,
     ephemeral_start_tokens: Arc<tokio::sync::RwLock<HashMap<String, Arc<()>>>>,
     tool_context_policy: Arc<MCPToolContextPolicy>,
+    pending_capability_approvals:
+        Arc<tokio::sync::RwLock<HashMap<String, capability::PendingCapabilityApproval>>>,
     ephemeral_lifecycle: Arc<Mutex<()>>,
 }
 
@@ -74,6 +85,8 @@ impl MCPServerManager {
             runtime: Arc::new(MCPServerRuntimeState::new()),
             reconnect_monitor_started: Arc::new(AtomicBool::new(false)),
             connection_event_tasks: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            list_changed_debouncer: Arc::new(MCPListChangedDebouncer::new()),
+            startup_readiness: Arc::new(MCPStartupReadiness::new()),
             pending_interactions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             oauth_sessions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             ephemeral_retirements: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
@@ -81,6 +94,7 @@ impl MCPServerManager {
             ephemeral_ready_servers: Arc::new(tokio::sync::RwLock::new(HashSet::new())),
             ephemeral_start_tokens: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             tool_context_policy: Arc::new(MCPToolContextPolicy::default()),
+            pending_capability_approvals: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             ephemeral_lifecycle: Arc::new(Mutex::new(())),
         }
     }
@@ -0,0 +1,138 @@
+use super::*;
+use bitfun_services_integrations::mcp::server::{
+    MCPInitializationSummary, MCPServerReadiness, MCPServerReadinessEvent,
+    MCP_INITIALIZE_ALL_TIMEOUT,
+};
+use std::collections::BTreeMap;
+
+impl MCPServerManager {
+    /// Starts the given servers concurrently and waits at most
+    /// [`MCP_INITIALIZE_ALL_TIMEOUT`] for them. Servers still starting when
+    /// the timeout elapses keep starting in the background; each server's
+    /// tools are registered as soon as that server is ready.
+    pub(super) async fn start_servers_concurrently(
+        &self,
+        servers: Vec<(String, String)>,
+    ) -> MCPInitializationSummary {
+        self.startup_readiness
+            .reset(servers.iter().map(|(server_id, _)| server_id.clone()));
+        if servers.is_empty() {
+            return MCPInitializationSummary::default();
+        }
+
+        let handles = servers
+            .into_iter()
+            .map(|(server_id, server_name)| {
+                let manager = self.clone();
+                tokio::spawn(async move {
+                    manager
+                        .start_server_with_readiness(&server_id, &server_name)
+                        .await;
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Dropping the handles on timeout detaches the tasks instead of
+        // aborting them.
+        let timed_out = tokio::time::timeout(
+            MCP_INITIALIZE_ALL_TIMEOUT,
+            futures::future::join_all(handles),
+        )
+        .await
+        .is_err();
+
+        let summary = self.startup_readiness.summary(timed_out);
+        if timed_out {
+            warn!(
+                "MCP startup timed out, remaining servers continue in background: timeout_secs={} pending={:?}",
+                MCP_INITIALIZE_ALL_TIMEOUT.as_secs(),
+                summary.pending
+            );
+        }
+        summary
+    }
+
+    async fn start_server_with_readiness(&self, server_id: &str, server_name: &str) {
+        info!(
+            "Auto-starting MCP server: name={} id={}",
+            server_name, server_id
+        );
+        self.update_server_readiness(
+            server_id,
+            server_name,
+            MCPServerReadiness::Starting,
+            None,
+            None,
+        )
+        .await;
+
+        match self.start_server(server_id).await {
+            Ok(()) => {
+                let tool_count = self.runtime.get_cached_tools(server_id).await.len();
+                info!(
+                    "MCP server ready: name={} id={} tools={}",
+                    server_name, server_id, tool_count
+                );
+                self.update_server_readiness(
+                    server_id,
+                    server_name,
+                    MCPServerReadiness::Ready,
+                    Some(tool_count),
+                    None,
+                )
+                .await;
+            }
+            Err(e) => {
+                error!(
+                    "Failed to auto-start MCP server: name={} id={} error={}",
+                    server_name, server_id, e
+                );
+                self.update_server_readiness(
+                    server_id,
+                    server_name,
+                    MCPServerReadiness::Failed,
+                    None,
+                    Some(e.to_string()),
+                )
+                .await;
+            }
+        }
+    }
+
+    async fn update_server_readiness(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        readiness: MCPServerReadiness,
+        tool_count: Option<usize>,
+        error: Option<String>,
+    ) {
+        self.startup_readiness.set(server_id, readiness);
+
+        let event = MCPServerReadinessEvent {
+            server_id: server_id.to_string(),
+            server_name: server_name.to_string(),
+            readiness,
+            tool_count,
+            error,
+        };
+        let event_system = get_global_event_system();
+        if let Err(e) = event_system
+            .emit(BackendEvent::Custom {
+                event_name: "backend-event-mcpserverreadiness".to_string(),
+                payload: json!(event),
+            })
+            .await
+        {
+            warn!(
+                "Failed to emit MCP server readiness event: server_id={} error={}",
+                server_id, e
+            );
+        }
+    }
+
+    /// Readiness of the servers launched by the most recent startup pass.
+    pub fn get_startup_readiness(&self) -> BTreeMap<String, MCPServerReadiness> {
+        self.startup_readiness.snapshot()
+    }
+}
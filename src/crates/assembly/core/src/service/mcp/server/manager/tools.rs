@@ -1,4 +1,6 @@
 use super::*;
+use bitfun_services_integrations::mcp::server::{diff_mcp_catalog, MCPCatalogDiff};
+use std::collections::BTreeSet;
 
 impl MCPServerManager {
     pub(super) async fn refresh_mcp_tools(
@@ -12,18 +14,14 @@ impl MCPServerManager {
             .await
     }
 
-    /// Registers MCP tools into the global tool registry.
-    pub(super) async fn register_mcp_tools(
+    /// Lists a server's tools and reviews them against the trusted capability
+    /// snapshot.
+    async fn load_mcp_tool_adapter(
         &self,
         server_id: &str,
         server_name: &str,
         connection: Arc<MCPConnection>,
-    ) -> BitFunResult<usize> {
-        info!(
-            "Registering MCP tools: server_name={} server_id={}",
-            server_name, server_id
-        );
-
+    ) -> BitFunResult<MCPToolAdapter> {
         let mut adapter = MCPToolAdapter::new();
         let external_workspace_scope = self
             .ephemeral_workspace_scopes
@@ -48,6 +46,30 @@ impl MCPServerManager {
                 );
                 e
             })?;
+        self.review_capability_changes(server_id, server_name, adapter.capability_snapshot())
+            .await;
+        self.runtime
+            .replace_cached_tools(server_id, adapter.mcp_tools().to_vec())
+            .await;
+
+        Ok(adapter)
+    }
+
+    /// Registers MCP tools into the global tool registry.
+    pub(super) async fn register_mcp_tools(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        connection: Arc<MCPConnection>,
+    ) -> BitFunResult<usize> {
+        info!(
+            "Registering MCP tools: server_name={} server_id={}",
+            server_name, server_id
+        );
+
+        let adapter = self
+            .load_mcp_tool_adapter(server_id, server_name, connection)
+            .await?;
 
         let tools = adapter.get_tools();
         let tool_count = tools.len();
@@ -75,6 +97,57 @@ impl MCPServerManager {
         Ok(tool_count)
     }
 
+    /// Refetches a server's tools and applies only the difference to the
+    /// global tool registry, leaving unchanged tools registered as they are.
+    pub(super) async fn sync_mcp_tools(
+        &self,
+        server_id: &str,
+        server_name: &str,
+        connection: Arc<MCPConnection>,
+    ) -> BitFunResult<MCPCatalogDiff> {
+        let previous = self.runtime.get_cached_tools(server_id).await;
+        let adapter = self
+            .load_mcp_tool_adapter(server_id, server_name, connection)
+            .await?;
+        let diff = diff_mcp_catalog(&previous, adapter.mcp_tools(), |tool| tool.name.as_str());
+
+        let refreshed = diff
+            .added
+            .iter()
+            .chain(diff.changed.iter())
+            .map(String::as_str)
+            .collect::<HashSet<_>>();
+        let published = adapter
+            .get_tools()
+            .iter()
+            .map(|tool| tool.name().to_string())
+            .collect::<BTreeSet<_>>();
+        let upserts = adapter
+            .mcp_tools()
+            .iter()
+            .zip(adapter.get_tools())
+            .filter(|(definition, _)| refreshed.contains(definition.name.as_str()))
+            .map(|(_, tool)| Arc::clone(tool))
+            .collect::<Vec<_>>();
+
+        let registry = crate::agentic::tools::registry::get_global_tool_registry();
+        let removed = registry
+            .write()
+            .await
+            .sync_mcp_server_tools(server_id, &published, upserts);
+
+        info!(
+            "Synced MCP tools: server_name={} server_id={} added={} changed={} removed={}",
+            server_name,
+            server_id,
+            diff.added.len(),
+            diff.changed.len(),
+            removed
+        );
+
+        Ok(diff)
+    }
+
     /// Unregisters MCP tools from the global tool registry.
     pub(super) async fn unregister_mcp_tools(server_id: &str) {
         let registry = crate::agentic::tools::registry::get_global_tool_registry();
@@ -0,0 +1,38 @@
+use super::*;
+use crate::service::mcp::protocol::{MCPProtocolTrace, MCPTraceSnapshot};
+
+impl MCPServerManager {
+    async fn protocol_trace(&self, server_id: &str) -> BitFunResult<MCPProtocolTrace> {
+        self.ensure_registered(server_id).await?;
+        let process = self.runtime.get_process(server_id).await.ok_or_else(|| {
+            BitFunError::NotFound(format!("MCP server not registered: {}", server_id))
+        })?;
+        let trace = process.read().await.protocol_trace().clone();
+        Ok(trace)
+    }
+
+    /// Turns the protocol inspector on or off for a server. Enabling clears
+    /// frames left over from an earlier capture.
+    pub async fn set_protocol_trace_enabled(
+        &self,
+        server_id: &str,
+        enabled: bool,
+    ) -> BitFunResult<()> {
+        let trace = self.protocol_trace(server_id).await?;
+        if enabled && !trace.is_enabled() {
+            trace.clear();
+        }
+        trace.set_enabled(enabled);
+        info!(
+            "MCP protocol inspector {}: server_id={}",
+            if enabled { "enabled" } else { "disabled" },
+            server_id
+        );
+        Ok(())
+    }
+
+    /// Returns the redacted JSON-RPC frames captured for a server.
+    pub async fn get_protocol_trace(&self, server_id: &str) -> BitFunResult<MCPTraceSnapshot> {
+        Ok(self.protocol_trace(server_id).await?.snapshot())
+    }
+}
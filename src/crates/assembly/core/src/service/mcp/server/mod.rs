@@ -8,8 +8,14 @@ mod manager;
 mod process;
 mod registry;
 
-pub use bitfun_services_integrations::mcp::server::{MCPServerStatus, MCPServerType};
-pub use config::{MCPServerConfig, MCPServerOAuthConfig, MCPServerTransport, MCPServerXaaConfig};
+pub use bitfun_services_integrations::mcp::server::{
+    MCPCapabilityChange, MCPCapabilityDiff, MCPInitializationSummary, MCPServerReadiness,
+    MCPServerStatus, MCPServerType,
+};
+pub use config::{
+    MCPServerConfig, MCPServerIsolationConfig, MCPServerOAuthConfig, MCPServerTlsConfig,
+    MCPServerTransport, MCPServerXaaConfig,
+};
 pub use connection::{MCPConnection, MCPConnectionPool};
 pub use manager::MCPServerManager;
 pub use process::MCPServerProcess;
@@ -66,6 +66,15 @@ impl MCPServerProcess {
         Ok(())
     }
 
+    pub async fn start_local(
+        &mut self,
+        command: &str,
+        config: &MCPServerConfig,
+    ) -> BitFunResult<()> {
+        self.inner.start_local(command, config).await?;
+        Ok(())
+    }
+
     pub async fn start_remote(&mut self, config: &MCPServerConfig) -> BitFunResult<()> {
         let data_dir = try_get_path_manager_arc()?.user_data_dir();
         self.inner.start_remote(data_dir, config).await?;
@@ -120,6 +129,15 @@ impl MCPServerProcess {
         Ok(())
     }
 
+    pub async fn restart_local(
+        &mut self,
+        command: &str,
+        config: &MCPServerConfig,
+    ) -> BitFunResult<()> {
+        self.inner.restart_local(command, config).await?;
+        Ok(())
+    }
+
     pub async fn status(&self) -> MCPServerStatus {
         self.inner.status().await
     }
@@ -6,21 +6,46 @@
 
 #[cfg(feature = "service-integrations")]
 pub mod announcement; // Announcement / feature-demo / tips system
+#[cfg(feature = "product-full")]
+pub mod approvals; // Confirmation workflow for sensitive actions
+pub mod artifacts; // Managed files produced by agent tasks
+pub mod attachments; // Session attachments from drag-drop and clipboard
+#[cfg(feature = "product-full")]
+pub mod background_jobs; // Recurring maintenance jobs with run history
+#[cfg(feature = "product-full")]
+pub mod backup; // User data backup archives and restore
 pub(crate) mod bootstrap; // Workspace persona bootstrap helpers
 #[cfg(feature = "canvas-runtime")]
 pub mod canvas; // Canvas service compatibility facade
+#[cfg(feature = "product-full")]
+pub mod code_index; // Tree-sitter symbol index for code navigation
 pub mod config; // Config management
+#[cfg(feature = "service-integrations")]
+pub mod connectivity; // Network connectivity and endpoint reachability monitor
+#[cfg(feature = "product-full")]
+pub mod credential_health; // Periodic API key validation
 #[cfg(feature = "product-full")]
 pub mod cron; // Scheduled jobs
+pub mod docconv; // Document conversion via managed runtimes
+pub mod downloads; // Throttled, resumable downloads for large artifacts
+#[cfg(feature = "product-full")]
+pub mod edit_transaction; // Staged multi-file edits with validation and rollback
+pub mod file_ops; // Workspace file operations with trash and undo
 pub mod filesystem; // FileSystem management
 #[cfg(feature = "service-integrations")]
 pub mod git; // Git service
+#[cfg(feature = "product-full")]
+pub mod hooks; // User commands run on agent lifecycle events
 pub mod i18n; // I18n service
 #[cfg(feature = "product-full")]
 pub(crate) mod instruction_context; // Workspace instruction file prompt helpers
 pub mod lsp; // LSP (Language Server Protocol) system
 #[cfg(feature = "service-integrations")]
 pub mod mcp; // MCP (Model Context Protocol) system
+pub mod operations; // Cancellable long-running operations
+pub mod patch; // Unified diff application with conflict reports
+pub mod power; // Battery and idle awareness for background work
+pub mod project_templates; // "New Project…" templates and scaffolding
 #[cfg(feature = "service-integrations")]
 pub mod remote_connect; // Remote Connect (phone → desktop)
 pub mod remote_ssh; // Remote SSH (desktop → server)
@@ -29,15 +54,22 @@ pub mod review_platform; // Pull request review platform adapters
 pub mod runtime; // Managed runtime and capability management
 #[cfg(feature = "product-full")]
 pub mod search; // Workspace search via managed flashgrep daemon
+#[cfg(feature = "product-full")]
+pub mod semantic_search; // Embeddings-backed semantic workspace search
+pub mod service_health; // Startup status of optional subsystems
 pub mod session; // Session persistence
 #[cfg(feature = "product-full")]
 pub mod session_usage; // Session runtime usage reports
 #[cfg(feature = "product-full")]
+pub mod sidecar_plugins; // Third-party extensions running as sidecar processes
+#[cfg(feature = "product-full")]
 pub mod snapshot; // Snapshot-based change tracking
+pub mod theme; // Built-in and user theme registry
 #[cfg(feature = "product-full")]
 pub mod token_usage; // Token usage tracking
 pub mod workspace; // Workspace management // Diff calculation and merge service
 pub mod workspace_runtime; // Workspace runtime layout / migration / initialization
+pub mod workspace_trust; // Per-folder trust decisions and restricted mode
 
 // Terminal is implemented in the workspace-level `terminal-core` crate.
 // This re-export preserves the legacy `bitfun_core::service::terminal` path.
@@ -46,7 +78,7 @@ pub use terminal_core as terminal;
 // Re-export main components.
 #[cfg(feature = "service-integrations")]
 pub use announcement::{AnnouncementCard, AnnouncementScheduler, AnnouncementSchedulerRef};
-pub use bitfun_services_core::{diagnostics, diff, system};
+pub use bitfun_services_core::{diagnostics, diff, first_launch, system};
 #[cfg(feature = "service-integrations")]
 pub use bitfun_services_integrations::file_watch;
 pub use bootstrap::reset_workspace_persona_files_to_default;
@@ -102,6 +134,7 @@ pub use system::{
     check_command, check_commands, run_command, run_command_simple, CheckCommandResult,
     CommandOutput, SystemError,
 };
+pub use theme::{ThemeDefinition, ThemeRegistry, ThemeSummary};
 #[cfg(feature = "product-full")]
 pub use token_usage::{
     ModelTokenStats, SessionTokenStats, TimeRange, TokenUsageQuery, TokenUsageRecord,
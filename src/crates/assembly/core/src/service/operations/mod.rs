@@ -0,0 +1,13 @@
+//! Cancellable operations
+//!
+//! Long-running commands (skill market installs, runtime downloads) register
+//! here for the duration of their work. Each gets an operation ID and a
+//! cancellation token; `cancel` trips the token and the work stops at its
+//! next await point, killing any child process it started.
+
+mod service;
+mod types;
+
+pub use service::{operation_registry, OperationHandle, OperationRegistry};
+pub use tokio_util::sync::CancellationToken;
+pub use types::{OperationInfo, OperationKind};
@@ -0,0 +1,200 @@
+//! Process-wide registry of running operations.
+
+use super::types::{OperationInfo, OperationKind};
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::{debug, info};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio_util::sync::CancellationToken;
+
+static OPERATION_REGISTRY: LazyLock<OperationRegistry> = LazyLock::new(OperationRegistry::new);
+
+pub fn operation_registry() -> &'static OperationRegistry {
+    &OPERATION_REGISTRY
+}
+
+struct OperationEntry {
+    info: OperationInfo,
+    token: CancellationToken,
+}
+
+type Entries = Arc<Mutex<HashMap<String, OperationEntry>>>;
+
+pub struct OperationRegistry {
+    entries: Entries,
+}
+
+impl OperationRegistry {
+    fn new() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Registers an operation under `id`, or a fresh ID when the caller did
+    /// not pick one. Callers that need to cancel while the command is still
+    /// pending supply their own ID up front.
+    pub fn begin(
+        &self,
+        id: Option<String>,
+        kind: OperationKind,
+        label: impl Into<String>,
+    ) -> BitFunResult<OperationHandle> {
+        let id = id
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let token = CancellationToken::new();
+        let info = OperationInfo {
+            id: id.clone(),
+            kind,
+            label: label.into(),
+            started_at_ms: Utc::now().timestamp_millis(),
+            cancel_requested: false,
+        };
+
+        let mut entries = self.lock();
+        if entries.contains_key(&id) {
+            return Err(BitFunError::validation(format!(
+                "Operation '{}' is already running",
+                id
+            )));
+        }
+        debug!("Operation started: id={}, kind={:?}", id, kind);
+        entries.insert(
+            id.clone(),
+            OperationEntry {
+                info,
+                token: token.clone(),
+            },
+        );
+
+        Ok(OperationHandle {
+            id,
+            token,
+            entries: self.entries.clone(),
+        })
+    }
+
+    /// Requests cancellation. Returns `false` when no operation with `id` is
+    /// running.
+    pub fn cancel(&self, id: &str) -> bool {
+        let mut entries = self.lock();
+        match entries.get_mut(id) {
+            Some(entry) => {
+                info!(
+                    "Operation cancellation requested: id={}, kind={:?}",
+                    id, entry.info.kind
+                );
+                entry.info.cancel_requested = true;
+                entry.token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn list(&self) -> Vec<OperationInfo> {
+        let mut operations: Vec<OperationInfo> = self
+            .lock()
+            .values()
+            .map(|entry| entry.info.clone())
+            .collect();
+        operations.sort_by_key(|info| info.started_at_ms);
+        operations
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, OperationEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A registered operation. Dropping the handle unregisters it.
+pub struct OperationHandle {
+    id: String,
+    token: CancellationToken,
+    entries: Entries,
+}
+
+impl OperationHandle {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+impl Drop for OperationHandle {
+    fn drop(&mut self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.id);
+        debug!("Operation finished: id={}", self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_trips_the_token_and_drop_unregisters() {
+        let registry = OperationRegistry::new();
+        let handle = registry
+            .begin(
+                Some("op-1".to_string()),
+                OperationKind::SkillMarketDownload,
+                "owner/repo",
+            )
+            .expect("begin");
+        assert_eq!(handle.id(), "op-1");
+        assert_eq!(registry.list().len(), 1);
+
+        assert!(registry.cancel("op-1"));
+        assert!(handle.is_cancelled());
+        assert!(registry.list()[0].cancel_requested);
+
+        drop(handle);
+        assert!(registry.list().is_empty());
+        assert!(!registry.cancel("op-1"));
+    }
+
+    #[test]
+    fn duplicate_ids_are_rejected_and_missing_ids_are_generated() {
+        let registry = OperationRegistry::new();
+        let _first = registry
+            .begin(
+                Some("op".to_string()),
+                OperationKind::RuntimeInstall,
+                "node",
+            )
+            .expect("begin");
+        assert!(registry
+            .begin(
+                Some("op".to_string()),
+                OperationKind::RuntimeInstall,
+                "node"
+            )
+            .is_err());
+
+        let generated = registry
+            .begin(
+                Some("  ".to_string()),
+                OperationKind::SkillMarketPreview,
+                "x",
+            )
+            .expect("begin");
+        assert!(!generated.id().is_empty());
+        assert_ne!(generated.id(), "op");
+    }
+}
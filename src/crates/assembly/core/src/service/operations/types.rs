@@ -0,0 +1,23 @@
+//! Operation registry types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    SkillMarketDownload,
+    SkillMarketPreview,
+    RuntimeInstall,
+    ProjectScaffold,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationInfo {
+    pub id: String,
+    pub kind: OperationKind,
+    /// Human-readable subject, e.g. the package being installed.
+    pub label: String,
+    pub started_at_ms: i64,
+    pub cancel_requested: bool,
+}
@@ -0,0 +1,337 @@
+//! Places hunks in file text with offset search, whitespace-tolerant
+//! matching and context fuzz, reporting every hunk that cannot be placed.
+
+use super::types::{HunkMatch, HunkPreview, PatchConflict, PatchHunk, PatchLine};
+
+/// Most context lines dropped from each end of a hunk while searching.
+const MAX_FUZZ: usize = 2;
+/// File lines shown beyond the hunk's extent in a conflict report.
+const CONFLICT_CONTEXT_LINES: usize = 2;
+const MATCH_KINDS: [HunkMatch; 3] = [
+    HunkMatch::Exact,
+    HunkMatch::IgnoringTrailingWhitespace,
+    HunkMatch::IgnoringIndentation,
+];
+
+pub struct AppliedText {
+    pub text: String,
+    pub hunks: Vec<HunkPreview>,
+}
+
+struct Placement {
+    position: usize,
+    match_kind: HunkMatch,
+    lead: usize,
+    trail: usize,
+}
+
+/// Applies `hunks` in order to `original`. Line endings follow the original
+/// text. On failure every conflicting hunk is returned, not just the first.
+pub fn apply_hunks(original: &str, hunks: &[PatchHunk]) -> Result<AppliedText, Vec<PatchConflict>> {
+    let eol = if original.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let file_lines = original.lines().collect::<Vec<_>>();
+    let mut trailing_newline = original.is_empty() || original.ends_with('\n');
+
+    let mut output: Vec<&str> = Vec::with_capacity(file_lines.len());
+    let mut previews = Vec::with_capacity(hunks.len());
+    let mut conflicts = Vec::new();
+    let mut cursor = 0;
+    let mut offset: isize = 0;
+
+    for (hunk_index, hunk) in hunks.iter().enumerate() {
+        // `-N,0` means "insert after line N"; otherwise N is the first line.
+        let expected = if hunk.old_lines().next().is_none() {
+            hunk.old_start
+        } else {
+            hunk.old_start.saturating_sub(1)
+        };
+        let guess = if hunk.old_start == 0 {
+            cursor
+        } else {
+            (expected as isize + offset).max(0) as usize
+        };
+
+        let Some(placement) = place_hunk(&file_lines, hunk, cursor, guess) else {
+            conflicts.push(build_conflict(&file_lines, hunk, hunk_index, cursor, guess));
+            continue;
+        };
+
+        let applied_lines = &hunk.lines[placement.lead..hunk.lines.len() - placement.trail];
+        output.extend_from_slice(&file_lines[cursor..placement.position]);
+        let mut file_index = placement.position;
+        for line in applied_lines {
+            match line {
+                PatchLine::Context(_) => {
+                    // Keep the file's own text where matching was loose.
+                    output.push(file_lines[file_index]);
+                    file_index += 1;
+                }
+                PatchLine::Remove(_) => file_index += 1,
+                PatchLine::Add(text) => output.push(text),
+            }
+        }
+
+        let changes_content = applied_lines
+            .iter()
+            .any(|line| !matches!(line, PatchLine::Context(_)));
+        if file_index == file_lines.len() && changes_content {
+            trailing_newline = !hunk.new_missing_newline;
+        }
+        if hunk.old_start > 0 {
+            offset = placement.position as isize - placement.lead as isize - expected as isize;
+        }
+        cursor = file_index;
+        previews.push(HunkPreview {
+            hunk_index,
+            expected_start: hunk.old_start,
+            applied_start: placement.position + 1,
+            match_kind: placement.match_kind,
+            fuzz: placement.lead.max(placement.trail),
+            lines: applied_lines.to_vec(),
+        });
+    }
+
+    if !conflicts.is_empty() {
+        return Err(conflicts);
+    }
+
+    output.extend_from_slice(&file_lines[cursor..]);
+    let mut text = output.join(eol);
+    if trailing_newline && !output.is_empty() {
+        text.push_str(eol);
+    }
+    Ok(AppliedText {
+        text,
+        hunks: previews,
+    })
+}
+
+fn place_hunk(
+    file_lines: &[&str],
+    hunk: &PatchHunk,
+    cursor: usize,
+    guess: usize,
+) -> Option<Placement> {
+    let leading_context = hunk
+        .lines
+        .iter()
+        .take_while(|line| matches!(line, PatchLine::Context(_)))
+        .count();
+    let trailing_context = hunk
+        .lines
+        .iter()
+        .rev()
+        .take_while(|line| matches!(line, PatchLine::Context(_)))
+        .count();
+
+    let mut previous = None;
+    for fuzz in 0..=MAX_FUZZ {
+        let lead = fuzz.min(leading_context);
+        let trail = fuzz.min(trailing_context);
+        // Stop once nothing more can be dropped, and never drop every line.
+        if previous == Some((lead, trail)) || (fuzz > 0 && lead + trail >= hunk.lines.len()) {
+            break;
+        }
+        previous = Some((lead, trail));
+        let old_lines = hunk.lines[lead..hunk.lines.len() - trail]
+            .iter()
+            .filter_map(|line| match line {
+                PatchLine::Context(text) | PatchLine::Remove(text) => Some(text.as_str()),
+                PatchLine::Add(_) => None,
+            })
+            .collect::<Vec<_>>();
+        // A hunk reduced to bare insertions would match anywhere.
+        if old_lines.is_empty() && hunk.old_lines().next().is_some() {
+            break;
+        }
+        let guess = guess.saturating_add(lead);
+
+        for match_kind in MATCH_KINDS {
+            if let Some(position) = find_nearest(file_lines, &old_lines, cursor, guess, match_kind)
+            {
+                return Some(Placement {
+                    position,
+                    match_kind,
+                    lead,
+                    trail,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Finds the match at or after `cursor` closest to `guess`.
+fn find_nearest(
+    file_lines: &[&str],
+    needle: &[&str],
+    cursor: usize,
+    guess: usize,
+    match_kind: HunkMatch,
+) -> Option<usize> {
+    if needle.len() > file_lines.len().saturating_sub(cursor) {
+        return None;
+    }
+    let last = file_lines.len() - needle.len();
+    let guess = guess.clamp(cursor, last);
+    if needle.is_empty() {
+        return Some(guess);
+    }
+
+    let matches_at = |position: usize| {
+        needle
+            .iter()
+            .zip(&file_lines[position..])
+            .all(|(expected, actual)| lines_match(match_kind, expected, actual))
+    };
+    for distance in 0..=(last - cursor) {
+        if guess >= cursor + distance && matches_at(guess - distance) {
+            return Some(guess - distance);
+        }
+        if distance > 0 && guess + distance <= last && matches_at(guess + distance) {
+            return Some(guess + distance);
+        }
+    }
+    None
+}
+
+fn lines_match(match_kind: HunkMatch, expected: &str, actual: &str) -> bool {
+    match match_kind {
+        HunkMatch::Exact => expected == actual,
+        HunkMatch::IgnoringTrailingWhitespace => expected.trim_end() == actual.trim_end(),
+        HunkMatch::IgnoringIndentation => expected.trim() == actual.trim(),
+    }
+}
+
+fn build_conflict(
+    file_lines: &[&str],
+    hunk: &PatchHunk,
+    hunk_index: usize,
+    cursor: usize,
+    guess: usize,
+) -> PatchConflict {
+    let expected_lines = hunk.old_lines().map(str::to_string).collect::<Vec<_>>();
+    let new_lines = hunk.new_lines().collect::<Vec<_>>();
+    let old_refs = expected_lines
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+
+    let reason = if !new_lines.is_empty()
+        && new_lines != old_refs
+        && find_nearest(
+            file_lines,
+            &new_lines,
+            0,
+            guess,
+            HunkMatch::IgnoringTrailingWhitespace,
+        )
+        .is_some()
+    {
+        "The change appears to be applied already".to_string()
+    } else if find_nearest(
+        file_lines,
+        &old_refs,
+        0,
+        guess,
+        HunkMatch::IgnoringIndentation,
+    )
+    .is_some_and(|position| position < cursor)
+    {
+        "The expected lines only occur before an earlier hunk; hunks may be out of order"
+            .to_string()
+    } else {
+        "The expected lines were not found in the file".to_string()
+    };
+
+    let actual_start = guess
+        .min(file_lines.len())
+        .saturating_sub(CONFLICT_CONTEXT_LINES);
+    let actual_end = (guess + expected_lines.len() + CONFLICT_CONTEXT_LINES).min(file_lines.len());
+    PatchConflict {
+        hunk_index,
+        expected_start: hunk.old_start,
+        reason,
+        expected_lines,
+        actual_start: actual_start + 1,
+        actual_lines: file_lines[actual_start..actual_end.max(actual_start)]
+            .iter()
+            .map(|line| line.to_string())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::patch::parse_unified_diff;
+
+    fn apply(original: &str, patch: &str) -> Result<AppliedText, Vec<PatchConflict>> {
+        let files = parse_unified_diff(patch).expect("patch parses");
+        apply_hunks(original, &files[0].hunks)
+    }
+
+    #[test]
+    fn applies_hunk_at_shifted_offset_with_loose_whitespace() {
+        let original = "header\nextra\nfn main() {\n    old();  \n}\n";
+        let patch = "--- a/main.rs\n+++ b/main.rs\n@@ -1,3 +1,3 @@\n fn main() {\n-  old();\n+    new();\n }\n";
+
+        let applied = apply(original, patch).expect("hunk applies");
+
+        assert_eq!(applied.text, "header\nextra\nfn main() {\n    new();\n}\n");
+        assert_eq!(applied.hunks[0].applied_start, 3);
+        assert_eq!(applied.hunks[0].match_kind, HunkMatch::IgnoringIndentation);
+    }
+
+    #[test]
+    fn fuzz_drops_stale_context_and_keeps_crlf() {
+        let original = "a\r\nb\r\nc\r\nd\r\n";
+        let patch = "--- a/f\n+++ b/f\n@@ -1,4 +1,4 @@\n stale\n b\n-c\n+C\n d\n";
+
+        let applied = apply(original, patch).expect("hunk applies with fuzz");
+
+        assert_eq!(applied.text, "a\r\nb\r\nC\r\nd\r\n");
+        assert_eq!(applied.hunks[0].fuzz, 1);
+    }
+
+    #[test]
+    fn reports_every_conflicting_hunk_with_file_context() {
+        let original = "one\ntwo\nthree\nfour\n";
+        let patch = "--- a/f\n+++ b/f\n@@ -1 +1 @@\n-uno\n+1\n@@ -4 +4 @@\n-four\n+4\n@@ -2 +2 @@\n-dos\n+2\n";
+
+        let conflicts = match apply(original, patch) {
+            Ok(_) => panic!("patch should conflict"),
+            Err(conflicts) => conflicts,
+        };
+
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|conflict| conflict.hunk_index)
+                .collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+        assert_eq!(conflicts[0].expected_lines, vec!["uno".to_string()]);
+        assert_eq!(conflicts[0].actual_start, 1);
+        assert!(conflicts[0].actual_lines.contains(&"one".to_string()));
+    }
+
+    #[test]
+    fn detects_already_applied_change() {
+        let conflicts = match apply(
+            "keep\nnew\n",
+            "--- a/f\n+++ b/f\n@@ -1,2 +1,2 @@\n keep\n-old\n+new\n",
+        ) {
+            Ok(_) => panic!("patch should conflict"),
+            Err(conflicts) => conflicts,
+        };
+        assert_eq!(
+            conflicts[0].reason,
+            "The change appears to be applied already"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Patch service
+//!
+//! Applies model-generated unified diffs to the workspace with fuzzy hunk
+//! placement, conflict reports, dry-run previews and all-or-nothing writes.
+
+mod apply;
+mod parser;
+mod service;
+mod types;
+
+pub use parser::parse_unified_diff;
+pub use service::PatchService;
+pub(crate) use service::{read_text, write_all, CommittedWrites, FileWrite};
+pub use types::{
+    FilePatch, FilePatchReport, HunkMatch, HunkPreview, PatchConflict, PatchFileChange, PatchHunk,
+    PatchLine, PatchReport,
+};
@@ -0,0 +1,281 @@
+//! Lenient unified diff parser.
+//!
+//! Accepts plain `---`/`+++` patches and `diff --git` output, and tolerates
+//! the usual defects of model-written diffs: wrong hunk line counts, bare
+//! `@@` headers, blank context lines without the leading space, and prose
+//! around the patch.
+
+use super::types::{FilePatch, PatchHunk, PatchLine};
+use crate::util::errors::{BitFunError, BitFunResult};
+
+pub fn parse_unified_diff(text: &str) -> BitFunResult<Vec<FilePatch>> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let mut files = Vec::new();
+    let mut current: Option<FilePatch> = None;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            files.extend(current.take());
+            let (old_path, new_path) = parse_git_header_paths(rest);
+            current = Some(FilePatch {
+                old_path,
+                new_path,
+                hunks: Vec::new(),
+            });
+            index += 1;
+            continue;
+        }
+
+        if is_file_header(&lines, index) {
+            let old_path = parse_header_path(&line[4..]);
+            let new_path = parse_header_path(&lines[index + 1][4..]);
+            // A git header already opened this file; `---`/`+++` refine it.
+            match current.as_mut() {
+                Some(file) if file.hunks.is_empty() => {
+                    file.old_path = old_path;
+                    file.new_path = new_path;
+                }
+                _ => {
+                    files.extend(current.take());
+                    current = Some(FilePatch {
+                        old_path,
+                        new_path,
+                        hunks: Vec::new(),
+                    });
+                }
+            }
+            index += 2;
+            continue;
+        }
+
+        if let Some(file) = current.as_mut().filter(|file| file.hunks.is_empty()) {
+            if let Some(path) = line.strip_prefix("rename from ") {
+                file.old_path = Some(path.trim().to_string());
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                file.new_path = Some(path.trim().to_string());
+            } else if line.starts_with("new file mode") {
+                file.old_path = None;
+            } else if line.starts_with("deleted file mode") {
+                file.new_path = None;
+            }
+        }
+
+        if line.starts_with("@@") {
+            let Some(file) = current.as_mut() else {
+                return Err(BitFunError::validation(format!(
+                    "Hunk on line {} has no file header",
+                    index + 1
+                )));
+            };
+            let (old_start, new_start) = parse_hunk_header(line).ok_or_else(|| {
+                BitFunError::validation(format!(
+                    "Malformed hunk header on line {}: {}",
+                    index + 1,
+                    line
+                ))
+            })?;
+            let (hunk, next) = parse_hunk_body(&lines, index + 1, old_start, new_start);
+            if !hunk.lines.is_empty() {
+                file.hunks.push(hunk);
+            }
+            index = next;
+            continue;
+        }
+
+        index += 1;
+    }
+    files.extend(current);
+
+    if files.is_empty() {
+        return Err(BitFunError::validation(
+            "Patch does not contain any file changes",
+        ));
+    }
+    if let Some(file) = files
+        .iter()
+        .find(|file| file.old_path.is_none() && file.new_path.is_none())
+    {
+        return Err(BitFunError::validation(format!(
+            "Patch file entry has no path: {} hunk(s)",
+            file.hunks.len()
+        )));
+    }
+    Ok(files)
+}
+
+fn is_file_header(lines: &[&str], index: usize) -> bool {
+    lines[index].starts_with("--- ")
+        && lines
+            .get(index + 1)
+            .is_some_and(|next| next.starts_with("+++ "))
+}
+
+fn is_section_start(lines: &[&str], index: usize) -> bool {
+    let line = lines[index];
+    line.starts_with("@@") || line.starts_with("diff --git ") || is_file_header(lines, index)
+}
+
+fn parse_hunk_body(
+    lines: &[&str],
+    start: usize,
+    old_start: usize,
+    new_start: usize,
+) -> (PatchHunk, usize) {
+    let mut hunk = PatchHunk {
+        old_start,
+        new_start,
+        lines: Vec::new(),
+        new_missing_newline: false,
+    };
+    // Bare empty lines are usually blank context with the space stripped, but
+    // trailing ones are more often separators between patch sections.
+    let mut trailing_bare_blank = 0;
+    let mut index = start;
+
+    while index < lines.len() && !is_section_start(lines, index) {
+        let line = lines[index];
+        let parsed = if line.is_empty() {
+            trailing_bare_blank += 1;
+            Some(PatchLine::Context(String::new()))
+        } else {
+            trailing_bare_blank = 0;
+            // Markers are ASCII, so slicing past them stays on a char boundary.
+            match line.as_bytes()[0] {
+                b' ' => Some(PatchLine::Context(line[1..].to_string())),
+                b'+' => Some(PatchLine::Add(line[1..].to_string())),
+                b'-' => Some(PatchLine::Remove(line[1..].to_string())),
+                b'\\' => {
+                    if matches!(
+                        hunk.lines.last(),
+                        Some(PatchLine::Add(_) | PatchLine::Context(_))
+                    ) {
+                        hunk.new_missing_newline = true;
+                    }
+                    None
+                }
+                _ => break,
+            }
+        };
+        hunk.lines.extend(parsed);
+        index += 1;
+    }
+
+    hunk.lines
+        .truncate(hunk.lines.len().saturating_sub(trailing_bare_blank));
+    (hunk, index)
+}
+
+/// Returns `(old_start, new_start)`; missing numbers become 0.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let body = line.strip_prefix("@@")?;
+    let body = match body.find("@@") {
+        Some(end) => &body[..end],
+        None => body,
+    };
+    let mut old_start = 0;
+    let mut new_start = 0;
+    for part in body.split_whitespace() {
+        let parse_start = |range: &str| range.split(',').next()?.parse::<usize>().ok();
+        if let Some(range) = part.strip_prefix('-') {
+            old_start = parse_start(range)?;
+        } else if let Some(range) = part.strip_prefix('+') {
+            new_start = parse_start(range)?;
+        } else {
+            return None;
+        }
+    }
+    Some((old_start, new_start))
+}
+
+fn parse_header_path(raw: &str) -> Option<String> {
+    // `--- a/file.rs\t2024-01-01 00:00:00` carries a timestamp after a tab.
+    let path = raw.split('\t').next().unwrap_or(raw).trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(strip_git_prefix(unquote(path)).to_string())
+}
+
+fn parse_git_header_paths(rest: &str) -> (Option<String>, Option<String>) {
+    // `a/<path> b/<path>`; split on the last ` b/` so spaces in paths survive.
+    match rest.rfind(" b/") {
+        Some(split) => (
+            Some(strip_git_prefix(unquote(&rest[..split])).to_string()),
+            Some(strip_git_prefix(unquote(&rest[split + 1..])).to_string()),
+        ),
+        None => (None, None),
+    }
+}
+
+fn unquote(path: &str) -> &str {
+    path.strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+        .unwrap_or(path)
+}
+
+fn strip_git_prefix(path: &str) -> &str {
+    path.strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::patch::PatchFileChange;
+
+    #[test]
+    fn parses_git_patch_with_creation_rename_and_prose() {
+        let patch = [
+            "Here is the change:",
+            "diff --git a/src/old.rs b/src/new.rs",
+            "similarity index 90%",
+            "rename from src/old.rs",
+            "rename to src/new.rs",
+            "--- a/src/old.rs",
+            "+++ b/src/new.rs",
+            "@@ -1,2 +1,2 @@",
+            " fn f() {}",
+            "-fn g() {}",
+            "+fn h() {}",
+            "",
+            "diff --git a/README.md b/README.md",
+            "new file mode 100644",
+            "--- /dev/null",
+            "+++ b/README.md",
+            "@@ -0,0 +1 @@",
+            "+# Title",
+            "\\ No newline at end of file",
+        ]
+        .join("\n");
+
+        let files = parse_unified_diff(&patch).expect("patch parses");
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].change_kind(), PatchFileChange::Rename);
+        assert_eq!(files[0].old_path.as_deref(), Some("src/old.rs"));
+        assert_eq!(files[0].hunks[0].lines.len(), 3);
+        assert_eq!(files[1].change_kind(), PatchFileChange::Create);
+        assert!(files[1].hunks[0].new_missing_newline);
+    }
+
+    #[test]
+    fn accepts_bare_hunk_headers_and_unprefixed_blank_context() {
+        let patch = "--- a/f\n+++ b/f\n@@\n a\n\n-b\n+c\n";
+
+        let files = parse_unified_diff(patch).expect("patch parses");
+        let hunk = &files[0].hunks[0];
+
+        assert_eq!(hunk.old_start, 0);
+        assert_eq!(hunk.old_lines().collect::<Vec<_>>(), vec!["a", "", "b"]);
+    }
+
+    #[test]
+    fn rejects_hunks_without_a_file() {
+        assert!(parse_unified_diff("@@ -1 +1 @@\n-a\n+b\n").is_err());
+        assert!(parse_unified_diff("no patch here").is_err());
+    }
+}
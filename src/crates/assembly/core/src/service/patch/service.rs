@@ -0,0 +1,433 @@
+//! Patch service.
+//!
+//! Parses a unified diff, places every hunk against the current workspace
+//! files, and either reports the result (dry run) or writes all files at
+//! once. Nothing is written unless every hunk of every file applies, and a
+//! failure part-way through the writes restores the files already changed.
+
+use super::apply::apply_hunks;
+use super::parser::parse_unified_diff;
+use super::types::{
+    FilePatch, FilePatchReport, PatchConflict, PatchFileChange, PatchLine, PatchReport,
+};
+use crate::service::file_ops::resolve_in_workspace;
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+pub struct PatchService;
+
+/// A file whose new state has been computed but not yet written.
+struct PlannedFile {
+    report: FilePatchReport,
+    write: FileWrite,
+}
+
+/// The new state of one file, ready for [`write_all`].
+pub(crate) struct FileWrite {
+    /// Existing file the new state is derived from; absent for creations.
+    pub(crate) source: Option<PathBuf>,
+    /// Path written; absent for deletions.
+    pub(crate) target: Option<PathBuf>,
+    pub(crate) original: Option<String>,
+    pub(crate) updated: Option<String>,
+}
+
+/// Changes made by [`write_all`], kept so a later check can undo them.
+pub(crate) struct CommittedWrites(Vec<CommittedChange>);
+
+impl CommittedWrites {
+    /// Restores every file to its state before the writes.
+    pub(crate) async fn revert(self) {
+        rollback(self.0).await;
+    }
+}
+
+/// A change already made on disk, kept so it can be undone.
+enum CommittedChange {
+    Wrote {
+        path: PathBuf,
+        previous: Option<String>,
+    },
+    Removed {
+        path: PathBuf,
+        previous: String,
+    },
+}
+
+impl PatchService {
+    /// Reports where each hunk would land and any conflicts, without writing.
+    pub async fn preview(workspace_root: &Path, patch: &str) -> BitFunResult<PatchReport> {
+        let planned = plan_patch(workspace_root, patch).await?;
+        Ok(PatchReport {
+            applied: false,
+            files: planned.into_iter().map(|file| file.report).collect(),
+        })
+    }
+
+    /// Applies the patch to the workspace if every hunk applies. With any
+    /// conflict the report is returned with `applied: false` and no file is
+    /// touched.
+    pub async fn apply(workspace_root: &Path, patch: &str) -> BitFunResult<PatchReport> {
+        let planned = plan_patch(workspace_root, patch).await?;
+        let mut report = PatchReport {
+            applied: false,
+            files: planned.iter().map(|file| file.report.clone()).collect(),
+        };
+        if report.has_conflicts() {
+            return Ok(report);
+        }
+
+        let file_count = planned.len();
+        let writes = planned
+            .into_iter()
+            .map(|file| file.write)
+            .collect::<Vec<_>>();
+        write_all(&writes).await?;
+        info!(
+            "Patch applied: workspace={}, files={}",
+            workspace_root.display(),
+            file_count
+        );
+        report.applied = true;
+        Ok(report)
+    }
+
+    /// Applies a single-file patch to in-memory text. Conflicts are returned
+    /// as a validation error describing each failed hunk.
+    pub fn apply_to_text(content: &str, patch: &str) -> BitFunResult<String> {
+        let files = parse_unified_diff(patch)?;
+        let [file] = files.as_slice() else {
+            return Err(BitFunError::validation(format!(
+                "Expected a patch for one file, found {}",
+                files.len()
+            )));
+        };
+        apply_hunks(content, &file.hunks)
+            .map(|applied| applied.text)
+            .map_err(|conflicts| conflict_error(file.target_path(), &conflicts))
+    }
+}
+
+async fn plan_patch(workspace_root: &Path, patch: &str) -> BitFunResult<Vec<PlannedFile>> {
+    let files = parse_unified_diff(patch)?;
+    let mut touched = HashSet::new();
+    let mut planned = Vec::with_capacity(files.len());
+
+    for file in &files {
+        let source = file
+            .old_path
+            .as_deref()
+            .map(|path| resolve_in_workspace(workspace_root, Path::new(path)))
+            .transpose()?;
+        let target = file
+            .new_path
+            .as_deref()
+            .map(|path| resolve_in_workspace(workspace_root, Path::new(path)))
+            .transpose()?;
+        let paths = source.iter().chain(target.iter()).collect::<HashSet<_>>();
+        if let Some(path) = paths
+            .into_iter()
+            .find(|path| !touched.insert((*path).clone()))
+        {
+            return Err(BitFunError::validation(format!(
+                "Patch changes {} more than once",
+                path.display()
+            )));
+        }
+        planned.push(plan_file(file, source, target).await?);
+    }
+    Ok(planned)
+}
+
+async fn plan_file(
+    file: &FilePatch,
+    source: Option<PathBuf>,
+    target: Option<PathBuf>,
+) -> BitFunResult<PlannedFile> {
+    let change = file.change_kind();
+    let mut report = FilePatchReport {
+        path: file.target_path().to_string(),
+        previous_path: (change == PatchFileChange::Rename)
+            .then(|| file.old_path.clone())
+            .flatten(),
+        change,
+        additions: count_lines(file, |line| matches!(line, PatchLine::Add(_))),
+        deletions: count_lines(file, |line| matches!(line, PatchLine::Remove(_))),
+        hunks: Vec::new(),
+        conflicts: Vec::new(),
+    };
+
+    let original = match &source {
+        Some(path) => {
+            let text = read_text(path).await?;
+            if text.is_none() {
+                report
+                    .conflicts
+                    .push(file_conflict("The file does not exist"));
+            }
+            text
+        }
+        None => None,
+    };
+    let target_is_new = change == PatchFileChange::Create || change == PatchFileChange::Rename;
+    if let Some(target) = target.as_ref().filter(|_| target_is_new) {
+        if fs::symlink_metadata(target).await.is_ok() {
+            report
+                .conflicts
+                .push(file_conflict("A file already exists at the target path"));
+        }
+    }
+
+    let mut updated = None;
+    if report.conflicts.is_empty() {
+        match apply_hunks(original.as_deref().unwrap_or_default(), &file.hunks) {
+            Ok(applied) => {
+                report.hunks = applied.hunks;
+                if change == PatchFileChange::Delete && !applied.text.trim().is_empty() {
+                    report.conflicts.push(file_conflict(
+                        "The file has content that the deletion patch does not remove",
+                    ));
+                }
+                updated = target.is_some().then_some(applied.text);
+            }
+            Err(conflicts) => report.conflicts = conflicts,
+        }
+    }
+
+    Ok(PlannedFile {
+        report,
+        write: FileWrite {
+            source,
+            target,
+            original,
+            updated,
+        },
+    })
+}
+
+/// Writes every file through a temporary sibling and renames it into place;
+/// a failure undoes what was already changed.
+pub(crate) async fn write_all(files: &[FileWrite]) -> BitFunResult<CommittedWrites> {
+    let mut staged = Vec::with_capacity(files.len());
+    for file in files {
+        let temp = match (&file.target, &file.updated) {
+            (Some(target), Some(updated)) => {
+                match stage(target, updated, file.source.as_deref()).await {
+                    Ok(temp) => Some(temp),
+                    Err(error) => {
+                        discard_staged(&staged).await;
+                        return Err(error);
+                    }
+                }
+            }
+            _ => None,
+        };
+        staged.push(temp);
+    }
+
+    let mut committed = Vec::new();
+    for (file, temp) in files.iter().zip(&staged) {
+        if let Err(error) = commit_file(file, temp.as_deref(), &mut committed).await {
+            rollback(committed).await;
+            discard_staged(&staged).await;
+            return Err(error);
+        }
+    }
+    Ok(CommittedWrites(committed))
+}
+
+async fn commit_file(
+    file: &FileWrite,
+    temp: Option<&Path>,
+    committed: &mut Vec<CommittedChange>,
+) -> BitFunResult<()> {
+    if let (Some(target), Some(temp)) = (&file.target, temp) {
+        let previous = if file.source.as_ref() == Some(target) {
+            file.original.clone()
+        } else {
+            None
+        };
+        fs::rename(temp, target)
+            .await
+            .map_err(|error| io_error("replace", target, error))?;
+        committed.push(CommittedChange::Wrote {
+            path: target.clone(),
+            previous,
+        });
+    }
+    if let (Some(source), Some(original)) = (&file.source, &file.original) {
+        if file.target.as_ref() != Some(source) {
+            fs::remove_file(source)
+                .await
+                .map_err(|error| io_error("remove", source, error))?;
+            committed.push(CommittedChange::Removed {
+                path: source.clone(),
+                previous: original.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+async fn stage(target: &Path, content: &str, source: Option<&Path>) -> BitFunResult<PathBuf> {
+    let parent = target
+        .parent()
+        .ok_or_else(|| BitFunError::validation(format!("Invalid path: {}", target.display())))?;
+    fs::create_dir_all(parent)
+        .await
+        .map_err(|error| io_error("create directory", parent, error))?;
+
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let temp = parent.join(format!(".{}.{}.patch-tmp", file_name, uuid::Uuid::new_v4()));
+    fs::write(&temp, content)
+        .await
+        .map_err(|error| io_error("write", &temp, error))?;
+    if let Some(metadata) = match source {
+        Some(source) => fs::metadata(source).await.ok(),
+        None => None,
+    } {
+        let _ = fs::set_permissions(&temp, metadata.permissions()).await;
+    }
+    Ok(temp)
+}
+
+/// Removes temporaries that were not renamed into place.
+async fn discard_staged(staged: &[Option<PathBuf>]) {
+    for temp in staged.iter().flatten() {
+        let _ = fs::remove_file(temp).await;
+    }
+}
+
+async fn rollback(committed: Vec<CommittedChange>) {
+    for change in committed.into_iter().rev() {
+        let result = match &change {
+            CommittedChange::Wrote {
+                path,
+                previous: Some(previous),
+            }
+            | CommittedChange::Removed { path, previous } => fs::write(path, previous).await,
+            CommittedChange::Wrote {
+                path,
+                previous: None,
+            } => fs::remove_file(path).await,
+        };
+        if let Err(error) = result {
+            let (CommittedChange::Wrote { path, .. } | CommittedChange::Removed { path, .. }) =
+                &change;
+            warn!(
+                "Failed to roll back patched file: path={}, error={}",
+                path.display(),
+                error
+            );
+        }
+    }
+}
+
+pub(crate) async fn read_text(path: &Path) -> BitFunResult<Option<String>> {
+    match fs::read(path).await {
+        Ok(bytes) => String::from_utf8(bytes).map(Some).map_err(|_| {
+            BitFunError::validation(format!(
+                "Cannot patch a file that is not UTF-8 text: {}",
+                path.display()
+            ))
+        }),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(io_error("read", path, error)),
+    }
+}
+
+fn count_lines(file: &FilePatch, predicate: impl Fn(&PatchLine) -> bool) -> usize {
+    file.hunks
+        .iter()
+        .flat_map(|hunk| &hunk.lines)
+        .filter(|line| predicate(line))
+        .count()
+}
+
+/// A conflict about the file as a whole rather than one hunk.
+fn file_conflict(reason: &str) -> PatchConflict {
+    PatchConflict {
+        hunk_index: 0,
+        expected_start: 0,
+        reason: reason.to_string(),
+        expected_lines: Vec::new(),
+        actual_start: 0,
+        actual_lines: Vec::new(),
+    }
+}
+
+fn conflict_error(path: &str, conflicts: &[PatchConflict]) -> BitFunError {
+    let details = conflicts
+        .iter()
+        .map(|conflict| {
+            format!(
+                "hunk {} (line {}): {}",
+                conflict.hunk_index + 1,
+                conflict.expected_start,
+                conflict.reason
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    BitFunError::validation(format!("Patch does not apply to {}: {}", path, details))
+}
+
+fn io_error(action: &str, path: &Path, error: std::io::Error) -> BitFunError {
+    BitFunError::io(format!(
+        "Failed to {} {}: {}",
+        action,
+        path.display(),
+        error
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_all_files_or_none() {
+        let workspace = tempfile::tempdir().expect("create workspace");
+        let root = workspace.path();
+        std::fs::write(root.join("a.txt"), "one\ntwo\n").expect("write a");
+        std::fs::write(root.join("gone.txt"), "bye\n").expect("write gone");
+
+        let conflicting = "--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n one\n-two\n+2\n\
+--- a/gone.txt\n+++ b/gone.txt\n@@ -1 +1 @@\n-missing\n+x\n";
+        let report = PatchService::apply(root, conflicting)
+            .await
+            .expect("apply reports");
+        assert!(!report.applied);
+        assert!(report.files[0].conflicts.is_empty());
+        assert_eq!(report.files[1].conflicts.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).expect("read a"),
+            "one\ntwo\n"
+        );
+
+        let patch = "--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n one\n-two\n+2\n\
+--- a/gone.txt\n+++ /dev/null\n@@ -1 +0,0 @@\n-bye\n\
+--- /dev/null\n+++ b/src/new.txt\n@@ -0,0 +1 @@\n+hello\n";
+        let preview = PatchService::preview(root, patch).await.expect("preview");
+        assert!(!preview.applied && !preview.has_conflicts());
+        assert!(root.join("gone.txt").exists());
+
+        let report = PatchService::apply(root, patch).await.expect("apply");
+        assert!(report.applied);
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).expect("read a"),
+            "one\n2\n"
+        );
+        assert!(!root.join("gone.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/new.txt")).expect("read new"),
+            "hello\n"
+        );
+    }
+}
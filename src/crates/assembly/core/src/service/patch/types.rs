@@ -0,0 +1,156 @@
+//! Parsed patch and application report types.
+
+use serde::{Deserialize, Serialize};
+
+/// One line of a hunk body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "text", rename_all = "snake_case")]
+pub enum PatchLine {
+    Context(String),
+    Add(String),
+    Remove(String),
+}
+
+impl PatchLine {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Context(text) | Self::Add(text) | Self::Remove(text) => text,
+        }
+    }
+}
+
+/// A `@@ -a,b +c,d @@` block. Line counts in the header are informational;
+/// the body is what gets applied, since model output often miscounts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchHunk {
+    /// 1-based line in the original file, or 0 when the header omits it.
+    pub old_start: usize,
+    pub new_start: usize,
+    pub lines: Vec<PatchLine>,
+    /// `\ No newline at end of file` followed the last new-side line.
+    pub new_missing_newline: bool,
+}
+
+impl PatchHunk {
+    /// Lines the hunk expects to find in the original file.
+    pub fn old_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Remove(text) => Some(text.as_str()),
+            PatchLine::Add(_) => None,
+        })
+    }
+
+    /// Lines the hunk leaves in their place.
+    pub fn new_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            PatchLine::Context(text) | PatchLine::Add(text) => Some(text.as_str()),
+            PatchLine::Remove(_) => None,
+        })
+    }
+}
+
+/// All hunks for one file. `None` paths stand for `/dev/null`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilePatch {
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    pub hunks: Vec<PatchHunk>,
+}
+
+impl FilePatch {
+    pub fn change_kind(&self) -> PatchFileChange {
+        match (&self.old_path, &self.new_path) {
+            (None, _) => PatchFileChange::Create,
+            (_, None) => PatchFileChange::Delete,
+            (Some(old), Some(new)) if old != new => PatchFileChange::Rename,
+            _ => PatchFileChange::Modify,
+        }
+    }
+
+    /// The path the file has once the patch is applied.
+    pub fn target_path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatchFileChange {
+    Create,
+    Modify,
+    Delete,
+    Rename,
+}
+
+/// How loosely a hunk had to be matched against the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HunkMatch {
+    Exact,
+    /// Matched after ignoring trailing whitespace.
+    IgnoringTrailingWhitespace,
+    /// Matched after ignoring all leading and trailing whitespace.
+    IgnoringIndentation,
+}
+
+/// Where a hunk landed, for UI previews.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HunkPreview {
+    pub hunk_index: usize,
+    /// 1-based line the hunk header pointed at.
+    pub expected_start: usize,
+    /// 1-based line in the original file where the hunk applied.
+    pub applied_start: usize,
+    pub match_kind: HunkMatch,
+    /// Context lines dropped from each end to find a match.
+    pub fuzz: usize,
+    pub lines: Vec<PatchLine>,
+}
+
+/// A hunk that could not be placed, with the lines it expected and what the
+/// file holds around the expected position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchConflict {
+    pub hunk_index: usize,
+    pub expected_start: usize,
+    pub reason: String,
+    pub expected_lines: Vec<String>,
+    /// 1-based line of `actual_lines[0]` in the file.
+    pub actual_start: usize,
+    pub actual_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilePatchReport {
+    pub path: String,
+    /// Set for renames.
+    pub previous_path: Option<String>,
+    pub change: PatchFileChange,
+    pub additions: usize,
+    pub deletions: usize,
+    pub hunks: Vec<HunkPreview>,
+    pub conflicts: Vec<PatchConflict>,
+}
+
+/// Outcome of previewing or applying a multi-file patch. Application is
+/// all-or-nothing, so `applied` is false whenever any file has conflicts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchReport {
+    pub applied: bool,
+    pub files: Vec<FilePatchReport>,
+}
+
+impl PatchReport {
+    pub fn has_conflicts(&self) -> bool {
+        self.files.iter().any(|file| !file.conflicts.is_empty())
+    }
+}
@@ -0,0 +1,15 @@
+//! Power and idle awareness for background work
+//!
+//! Tracks whether the machine runs on battery, whether battery saver is on
+//! and how long the user has been idle, so background indexing, scheduled
+//! jobs and large downloads can hold off instead of draining a laptop
+//! battery during long agent tasks.
+
+mod probe;
+mod service;
+mod types;
+
+pub use service::{
+    get_global_power_service, set_global_power_service, wait_for_background_allowance, PowerService,
+};
+pub use types::{BackgroundWork, PowerSettings, PowerSnapshot, PowerSource};
@@ -0,0 +1,264 @@
+//! Platform power and idle probes.
+//!
+//! Every probe is best effort: anything the platform cannot report comes
+//! back as unknown rather than as an error.
+
+use super::types::{PowerReading, PowerSource};
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+use std::time::Duration;
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Runs a small query command and returns its stdout, or `None` when it is
+/// missing, fails or hangs.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+async fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = crate::util::process_manager::create_tokio_command(program);
+    command.args(args).kill_on_drop(true);
+    let output = tokio::time::timeout(COMMAND_TIMEOUT, command.output())
+        .await
+        .ok()?
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "macos")]
+pub(super) async fn probe() -> PowerReading {
+    let (battery, settings, hid) = tokio::join!(
+        command_output("pmset", &["-g", "batt"]),
+        command_output("pmset", &["-g"]),
+        command_output(
+            "ioreg",
+            &["-c", "IOHIDSystem", "-d", "4", "-r", "-k", "HIDIdleTime"]
+        ),
+    );
+    let (power_source, battery_percent) = battery
+        .as_deref()
+        .map(parse_pmset_battery)
+        .unwrap_or((PowerSource::Unknown, None));
+    PowerReading {
+        power_source,
+        battery_percent,
+        system_power_saver: settings.as_deref().is_some_and(parse_pmset_low_power_mode),
+        idle_secs: hid.as_deref().and_then(parse_hid_idle_secs),
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(super) async fn probe() -> PowerReading {
+    let (power_source, battery_percent) = tokio::task::spawn_blocking(read_linux_power_supplies)
+        .await
+        .unwrap_or((PowerSource::Unknown, None));
+    let platform_profile = tokio::fs::read_to_string("/sys/firmware/acpi/platform_profile")
+        .await
+        .unwrap_or_default();
+    PowerReading {
+        power_source,
+        battery_percent,
+        system_power_saver: platform_profile.trim() == "low-power",
+        idle_secs: linux_idle_secs().await,
+    }
+}
+
+#[cfg(windows)]
+pub(super) async fn probe() -> PowerReading {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+    use windows::Win32::System::SystemInformation::GetTickCount;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    const NO_SYSTEM_BATTERY: u8 = 128;
+    const UNKNOWN: u8 = 255;
+
+    let mut reading = PowerReading::default();
+    let mut status = SYSTEM_POWER_STATUS::default();
+    // SAFETY: `status` is a valid, writable SYSTEM_POWER_STATUS.
+    if unsafe { GetSystemPowerStatus(&mut status) }.is_ok() {
+        reading.power_source = match status.ACLineStatus {
+            0 => PowerSource::Battery,
+            1 => PowerSource::Ac,
+            _ if status.BatteryFlag & NO_SYSTEM_BATTERY != 0 => PowerSource::Ac,
+            _ => PowerSource::Unknown,
+        };
+        reading.battery_percent =
+            (status.BatteryLifePercent != UNKNOWN).then_some(status.BatteryLifePercent.min(100));
+        reading.system_power_saver = status.SystemStatusFlag == 1;
+    }
+
+    let mut input = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    // SAFETY: `input` is a valid LASTINPUTINFO with `cbSize` set.
+    if unsafe { GetLastInputInfo(&mut input) }.as_bool() {
+        // Both tick counts wrap together every ~49 days.
+        // SAFETY: `GetTickCount` takes no arguments and cannot fail.
+        let idle_ms = unsafe { GetTickCount() }.wrapping_sub(input.dwTime);
+        reading.idle_secs = Some(u64::from(idle_ms) / 1000);
+    }
+    reading
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", windows)))]
+pub(super) async fn probe() -> PowerReading {
+    PowerReading::default()
+}
+
+/// Source and level from `pmset -g batt`, e.g.
+/// `Now drawing from 'Battery Power'` followed by `... 76%; discharging; ...`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_pmset_battery(output: &str) -> (PowerSource, Option<u8>) {
+    let source = if output.contains("'AC Power'") {
+        PowerSource::Ac
+    } else if output.contains("'Battery Power'") {
+        PowerSource::Battery
+    } else {
+        PowerSource::Unknown
+    };
+    let percent = output.split_whitespace().find_map(|word| {
+        word.trim_end_matches(';')
+            .strip_suffix('%')
+            .and_then(|value| value.parse::<u8>().ok())
+    });
+    (source, percent.map(|percent| percent.min(100)))
+}
+
+/// `lowpowermode 1` in the active settings printed by `pmset -g`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_pmset_low_power_mode(output: &str) -> bool {
+    output.lines().any(|line| {
+        let mut fields = line.split_whitespace();
+        matches!(
+            (fields.next(), fields.next()),
+            (Some("lowpowermode" | "powermode"), Some("1"))
+        )
+    })
+}
+
+/// `"HIDIdleTime" = <nanoseconds>` from `ioreg`.
+#[cfg(any(target_os = "macos", test))]
+fn parse_hid_idle_secs(output: &str) -> Option<u64> {
+    output.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if !key.contains("\"HIDIdleTime\"") {
+            return None;
+        }
+        let nanos = value.trim().parse::<u64>().ok()?;
+        Some(nanos / 1_000_000_000)
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_linux_power_supplies() -> (PowerSource, Option<u8>) {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return (PowerSource::Unknown, None);
+    };
+    let read = |dir: &std::path::Path, name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut external_online = false;
+    let mut batteries = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        match read(&dir, "type").as_str() {
+            "Battery" if read(&dir, "scope") != "Device" => {
+                batteries.push((
+                    read(&dir, "status"),
+                    read(&dir, "capacity").parse::<u8>().ok(),
+                ));
+            }
+            "Mains" | "USB" => external_online |= read(&dir, "online") == "1",
+            _ => {}
+        }
+    }
+
+    if batteries.is_empty() {
+        // Without a battery the machine always runs on external power.
+        return (PowerSource::Ac, None);
+    }
+    let discharging = batteries.iter().any(|(status, _)| status == "Discharging");
+    let source = if external_online || !discharging {
+        PowerSource::Ac
+    } else {
+        PowerSource::Battery
+    };
+    let percent = batteries.iter().filter_map(|(_, capacity)| *capacity).min();
+    (source, percent.map(|percent| percent.min(100)))
+}
+
+/// GNOME exposes idle time over D-Bus; X11 sessions may have `xprintidle`.
+/// Other desktops report nothing.
+#[cfg(target_os = "linux")]
+async fn linux_idle_secs() -> Option<u64> {
+    if let Some(output) = command_output(
+        "gdbus",
+        &[
+            "call",
+            "--session",
+            "--dest",
+            "org.gnome.Mutter.IdleMonitor",
+            "--object-path",
+            "/org/gnome/Mutter/IdleMonitor/Core",
+            "--method",
+            "org.gnome.Mutter.IdleMonitor.GetIdletime",
+        ],
+    )
+    .await
+    {
+        // `(uint64 123456,)`
+        let millis = output
+            .trim()
+            .trim_start_matches("(uint64")
+            .trim_end_matches(",)")
+            .trim()
+            .parse::<u64>()
+            .ok();
+        if let Some(millis) = millis {
+            return Some(millis / 1000);
+        }
+    }
+    if std::env::var_os("DISPLAY").is_some() {
+        let output = command_output("xprintidle", &[]).await?;
+        return output
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(|millis| millis / 1000);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pmset_battery_output() {
+        let on_battery = "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1234)\t76%; discharging; 4:12 remaining present: true\n";
+        assert_eq!(
+            parse_pmset_battery(on_battery),
+            (PowerSource::Battery, Some(76))
+        );
+        let desktop = "Now drawing from 'AC Power'\n";
+        assert_eq!(parse_pmset_battery(desktop), (PowerSource::Ac, None));
+    }
+
+    #[test]
+    fn parses_low_power_mode_and_idle_time() {
+        assert!(parse_pmset_low_power_mode(
+            "System-wide power settings:\nCurrently in use:\n lowpowermode         1\n sleep                1\n"
+        ));
+        assert!(!parse_pmset_low_power_mode(" lowpowermode         0\n"));
+        assert_eq!(
+            parse_hid_idle_secs("    |   \"HIDIdleTime\" = 312000000000\n"),
+            Some(312)
+        );
+        assert_eq!(parse_hid_idle_secs("\"Other\" = 1"), None);
+    }
+}
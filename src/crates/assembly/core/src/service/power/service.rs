@@ -0,0 +1,288 @@
+//! Power state service.
+
+use super::probe;
+use super::types::{BackgroundWork, PowerReading, PowerSettings, PowerSnapshot};
+use crate::service::config::{
+    get_global_config_service, subscribe_config_updates, ConfigUpdateEvent,
+};
+use chrono::Utc;
+use log::{debug, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use tokio::sync::{watch, Notify};
+use tokio::time::Duration;
+
+static GLOBAL_POWER_SERVICE: OnceLock<Arc<PowerService>> = OnceLock::new();
+
+/// Short enough that idle detection and a plugged-in charger are noticed
+/// promptly; each probe is a few file reads or tiny commands.
+const PROBE_INTERVAL: Duration = Duration::from_secs(30);
+const SETTINGS_PATH: &str = "app.power";
+
+pub struct PowerService {
+    settings: RwLock<PowerSettings>,
+    reading: RwLock<PowerReading>,
+    snapshot: watch::Sender<PowerSnapshot>,
+    wakeup: Notify,
+    monitor_started: AtomicBool,
+}
+
+impl PowerService {
+    pub fn new() -> Arc<Self> {
+        let settings = PowerSettings::default();
+        let reading = PowerReading::default();
+        let (snapshot, _) = watch::channel(PowerSnapshot::evaluate(reading, &settings, None));
+        Arc::new(Self {
+            settings: RwLock::new(settings),
+            reading: RwLock::new(reading),
+            snapshot,
+            wakeup: Notify::new(),
+            monitor_started: AtomicBool::new(false),
+        })
+    }
+
+    /// Notified when the power source, battery saver, idle state or the set
+    /// of deferred work changes; the idle counter alone does not notify.
+    pub fn subscribe(&self) -> watch::Receiver<PowerSnapshot> {
+        self.snapshot.subscribe()
+    }
+
+    pub fn snapshot(&self) -> PowerSnapshot {
+        self.snapshot.borrow().clone()
+    }
+
+    pub fn settings(&self) -> PowerSettings {
+        match self.settings.read() {
+            Ok(settings) => settings.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if self
+            .monitor_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            service.reload_settings().await;
+            loop {
+                service.refresh().await;
+                tokio::select! {
+                    _ = tokio::time::sleep(PROBE_INTERVAL) => {}
+                    _ = service.wakeup.notified() => {}
+                }
+            }
+        });
+
+        if let Some(mut config_updates) = subscribe_config_updates() {
+            let service = Arc::clone(self);
+            tokio::spawn(async move {
+                loop {
+                    match config_updates.recv().await {
+                        Ok(ConfigUpdateEvent::AppUpdated | ConfigUpdateEvent::ConfigReloaded) => {
+                            service.reload_settings().await
+                        }
+                        Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+    }
+
+    /// Asks the monitor loop to probe again without waiting for the interval.
+    pub fn request_refresh(&self) {
+        self.wakeup.notify_one();
+    }
+
+    /// Probes the platform and publishes the result.
+    pub async fn refresh(&self) -> PowerSnapshot {
+        let reading = probe::probe().await;
+        if let Ok(mut current) = self.reading.write() {
+            *current = reading;
+        }
+        self.publish(reading, Some(Utc::now().timestamp_millis()))
+    }
+
+    /// Why `work` should wait right now, or `None` when it may run.
+    pub fn defer_reason(&self, work: BackgroundWork) -> Option<String> {
+        let settings = self.settings();
+        self.snapshot.borrow().defer_reason(work, &settings)
+    }
+
+    /// Returns once the power policy allows `work`, immediately if it
+    /// already does.
+    pub async fn wait_until_allowed(&self, work: BackgroundWork) {
+        let mut receiver = self.snapshot.subscribe();
+        let mut deferred = false;
+        loop {
+            let settings = self.settings();
+            let reason = receiver.borrow_and_update().defer_reason(work, &settings);
+            match reason {
+                None => {
+                    if deferred {
+                        info!("Resuming deferred background work: work={:?}", work);
+                    }
+                    return;
+                }
+                Some(reason) if !deferred => {
+                    info!(
+                        "Deferring background work: work={:?}, reason={}",
+                        work, reason
+                    );
+                    deferred = true;
+                }
+                Some(_) => {}
+            }
+            if receiver.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+
+    async fn reload_settings(&self) {
+        let settings = match get_global_config_service().await {
+            Ok(config) => config
+                .get_config::<PowerSettings>(Some(SETTINGS_PATH))
+                .await
+                .unwrap_or_else(|e| {
+                    debug!("Using default power settings: {}", e);
+                    PowerSettings::default()
+                }),
+            Err(_) => PowerSettings::default(),
+        };
+        if let Ok(mut current) = self.settings.write() {
+            if *current == settings {
+                return;
+            }
+            *current = settings;
+        }
+        let (reading, checked_at_ms) = (
+            self.reading
+                .read()
+                .map(|reading| *reading)
+                .unwrap_or_default(),
+            self.snapshot.borrow().checked_at_ms,
+        );
+        self.publish(reading, checked_at_ms);
+    }
+
+    fn publish(&self, reading: PowerReading, checked_at_ms: Option<i64>) -> PowerSnapshot {
+        let next = PowerSnapshot::evaluate(reading, &self.settings(), checked_at_ms);
+        self.snapshot.send_if_modified(|current| {
+            let changed = current.state_differs(&next);
+            if changed {
+                debug!(
+                    "Power state changed: source={:?}, battery_saver={}, user_idle={}",
+                    next.power_source, next.battery_saver, next.user_idle
+                );
+            }
+            *current = next.clone();
+            changed
+        });
+        next
+    }
+}
+
+pub fn get_global_power_service() -> Option<Arc<PowerService>> {
+    GLOBAL_POWER_SERVICE.get().cloned()
+}
+
+pub fn set_global_power_service(service: Arc<PowerService>) {
+    let _ = GLOBAL_POWER_SERVICE.set(service);
+}
+
+/// Waits while the power policy defers `work`. Returns at once when the
+/// power service is not running, so callers need no special case.
+pub async fn wait_for_background_allowance(work: BackgroundWork) {
+    if let Some(service) = get_global_power_service() {
+        service.wait_until_allowed(work).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::power::PowerSource;
+
+    fn reading(power_source: PowerSource, percent: u8, idle_secs: Option<u64>) -> PowerReading {
+        PowerReading {
+            power_source,
+            battery_percent: Some(percent),
+            system_power_saver: false,
+            idle_secs,
+        }
+    }
+
+    #[test]
+    fn low_battery_counts_as_battery_saver_and_pauses_background_work() {
+        let settings = PowerSettings::default();
+        let plugged_in =
+            PowerSnapshot::evaluate(reading(PowerSource::Ac, 10, None), &settings, None);
+        assert!(!plugged_in.battery_saver);
+        assert!(plugged_in.deferred.is_empty());
+
+        let low = PowerSnapshot::evaluate(reading(PowerSource::Battery, 15, None), &settings, None);
+        assert!(low.battery_saver);
+        assert_eq!(
+            low.deferred,
+            vec![BackgroundWork::Indexing, BackgroundWork::ScheduledJobs]
+        );
+
+        let overridden = PowerSettings {
+            pause_scheduled_jobs_on_battery_saver: false,
+            ..settings
+        };
+        assert!(low
+            .defer_reason(BackgroundWork::ScheduledJobs, &overridden)
+            .is_none());
+    }
+
+    #[test]
+    fn downloads_wait_for_idle_only_when_idle_time_is_known() {
+        let settings = PowerSettings::default();
+        let active =
+            PowerSnapshot::evaluate(reading(PowerSource::Ac, 90, Some(20)), &settings, None);
+        assert!(!active.user_idle);
+        assert!(active
+            .defer_reason(BackgroundWork::RuntimeDownloads, &settings)
+            .is_some());
+
+        let idle =
+            PowerSnapshot::evaluate(reading(PowerSource::Ac, 90, Some(600)), &settings, None);
+        assert!(idle.user_idle);
+        assert!(idle
+            .defer_reason(BackgroundWork::RuntimeDownloads, &settings)
+            .is_none());
+
+        let unknown = PowerSnapshot::evaluate(reading(PowerSource::Ac, 90, None), &settings, None);
+        assert!(unknown
+            .defer_reason(BackgroundWork::RuntimeDownloads, &settings)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn waiting_work_resumes_when_power_is_connected() {
+        let service = PowerService::new();
+        service.publish(reading(PowerSource::Battery, 5, None), Some(1));
+        assert!(service.defer_reason(BackgroundWork::Indexing).is_some());
+
+        let waiter = tokio::spawn({
+            let service = Arc::clone(&service);
+            async move { service.wait_until_allowed(BackgroundWork::Indexing).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        service.publish(reading(PowerSource::Ac, 6, None), Some(2));
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should resume")
+            .unwrap();
+    }
+}
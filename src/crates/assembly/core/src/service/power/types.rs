@@ -0,0 +1,169 @@
+//! Power state types.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerSource {
+    Ac,
+    Battery,
+    Unknown,
+}
+
+/// Background work that consults the power state before running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundWork {
+    /// Incremental semantic index updates after file changes.
+    Indexing,
+    /// Cron jobs and recurring maintenance jobs.
+    ScheduledJobs,
+    /// Runtime downloads the user asked to run when the machine is idle.
+    RuntimeDownloads,
+}
+
+impl BackgroundWork {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Indexing => "Background indexing",
+            Self::ScheduledJobs => "Scheduled jobs",
+            Self::RuntimeDownloads => "Runtime download",
+        }
+    }
+}
+
+/// User overrides stored under `app.power`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerSettings {
+    pub pause_indexing_on_battery_saver: bool,
+    pub pause_scheduled_jobs_on_battery_saver: bool,
+    /// Downloads requested with "install when idle" wait for the user to be
+    /// idle. Off means they start right away.
+    pub defer_downloads_until_idle: bool,
+    /// Seconds without keyboard or mouse input before the user counts as idle.
+    pub idle_threshold_secs: u64,
+    /// Running on battery at or below this level counts as battery saver even
+    /// when the system setting is off. `0` disables the check.
+    pub low_battery_percent: u8,
+}
+
+impl Default for PowerSettings {
+    fn default() -> Self {
+        Self {
+            pause_indexing_on_battery_saver: true,
+            pause_scheduled_jobs_on_battery_saver: true,
+            defer_downloads_until_idle: true,
+            idle_threshold_secs: 300,
+            low_battery_percent: 20,
+        }
+    }
+}
+
+/// What the platform reported on one probe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PowerReading {
+    pub power_source: PowerSource,
+    pub battery_percent: Option<u8>,
+    /// The system's own power saving mode (Battery Saver, Low Power Mode).
+    pub system_power_saver: bool,
+    /// `None` where the platform cannot report input idle time.
+    pub idle_secs: Option<u64>,
+}
+
+impl Default for PowerReading {
+    fn default() -> Self {
+        Self {
+            power_source: PowerSource::Unknown,
+            battery_percent: None,
+            system_power_saver: false,
+            idle_secs: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerSnapshot {
+    pub power_source: PowerSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery_percent: Option<u8>,
+    /// System power saving mode or a low battery; pauses background work.
+    pub battery_saver: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_secs: Option<u64>,
+    /// Unknown idle time never counts as idle.
+    pub user_idle: bool,
+    /// Background work currently held back, for status displays.
+    pub deferred: Vec<BackgroundWork>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_at_ms: Option<i64>,
+}
+
+impl PowerSnapshot {
+    pub(super) fn evaluate(
+        reading: PowerReading,
+        settings: &PowerSettings,
+        checked_at_ms: Option<i64>,
+    ) -> Self {
+        let low_battery = reading.power_source == PowerSource::Battery
+            && settings.low_battery_percent > 0
+            && reading
+                .battery_percent
+                .is_some_and(|percent| percent <= settings.low_battery_percent);
+        let mut snapshot = Self {
+            power_source: reading.power_source,
+            battery_percent: reading.battery_percent,
+            battery_saver: reading.system_power_saver || low_battery,
+            idle_secs: reading.idle_secs,
+            user_idle: reading
+                .idle_secs
+                .is_some_and(|idle| idle >= settings.idle_threshold_secs),
+            deferred: Vec::new(),
+            checked_at_ms,
+        };
+        snapshot.deferred = [
+            BackgroundWork::Indexing,
+            BackgroundWork::ScheduledJobs,
+            BackgroundWork::RuntimeDownloads,
+        ]
+        .into_iter()
+        .filter(|work| snapshot.defer_reason(*work, settings).is_some())
+        .collect();
+        snapshot
+    }
+
+    /// Why `work` should wait right now, or `None` when it may run.
+    pub fn defer_reason(&self, work: BackgroundWork, settings: &PowerSettings) -> Option<String> {
+        let paused_on_saver = match work {
+            BackgroundWork::Indexing => settings.pause_indexing_on_battery_saver,
+            BackgroundWork::ScheduledJobs => settings.pause_scheduled_jobs_on_battery_saver,
+            BackgroundWork::RuntimeDownloads => false,
+        };
+        if paused_on_saver && self.battery_saver {
+            return Some(format!(
+                "{} is paused while battery saver is on",
+                work.label()
+            ));
+        }
+        // Without an idle signal there is nothing to wait for.
+        if work == BackgroundWork::RuntimeDownloads
+            && settings.defer_downloads_until_idle
+            && self.idle_secs.is_some()
+            && !self.user_idle
+        {
+            return Some(format!("{} waits until you are idle", work.label()));
+        }
+        None
+    }
+
+    /// Whether two snapshots differ in anything besides the idle counter.
+    pub(super) fn state_differs(&self, other: &PowerSnapshot) -> bool {
+        self.power_source != other.power_source
+            || self.battery_percent != other.battery_percent
+            || self.battery_saver != other.battery_saver
+            || self.user_idle != other.user_idle
+            || self.idle_secs.is_some() != other.idle_secs.is_some()
+            || self.deferred != other.deferred
+    }
+}
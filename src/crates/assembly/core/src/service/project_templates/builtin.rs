@@ -0,0 +1,81 @@
+//! Templates shipped with the app.
+//!
+//! Kept small on purpose: a README, ignore rules and the one entry point a
+//! toolchain needs, so the first agent session starts from a known layout.
+
+use super::types::{PostCreateCommand, ProjectTemplate, ProjectTemplateSource, TemplateSkeleton};
+
+/// Built-in template ids; their display names are localized by the frontend.
+pub const BUILTIN_TEMPLATE_IDS: &[&str] = &["empty", "basic", "rust-binary", "node", "python"];
+
+const README: &str = "# {{project_name}}\n";
+
+pub fn builtin_template(id: &str) -> Option<ProjectTemplate> {
+    let files: &[(&str, &str)] = match id {
+        "empty" => &[],
+        "basic" => &[("README.md", README), (".gitignore", ".DS_Store\n")],
+        "rust-binary" => &[
+            ("README.md", README),
+            (".gitignore", "/target\n"),
+            (
+                "Cargo.toml",
+                "[package]\nname = \"{{project_slug}}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n",
+            ),
+            (
+                "src/main.rs",
+                "fn main() {\n    println!(\"Hello from {{project_name}}!\");\n}\n",
+            ),
+        ],
+        "node" => &[
+            ("README.md", README),
+            (".gitignore", "node_modules/\ndist/\n"),
+            (
+                "package.json",
+                "{\n  \"name\": \"{{project_slug}}\",\n  \"version\": \"0.1.0\",\n  \"private\": true,\n  \"type\": \"module\",\n  \"scripts\": {\n    \"start\": \"node index.js\"\n  }\n}\n",
+            ),
+            (
+                "index.js",
+                "console.log('Hello from {{project_name}}!');\n",
+            ),
+        ],
+        "python" => &[
+            ("README.md", README),
+            (".gitignore", "__pycache__/\n.venv/\n"),
+            (
+                "pyproject.toml",
+                "[project]\nname = \"{{project_slug}}\"\nversion = \"0.1.0\"\nrequires-python = \">=3.9\"\n",
+            ),
+            (
+                "main.py",
+                "def main():\n    print(\"Hello from {{project_name}}!\")\n\n\nif __name__ == \"__main__\":\n    main()\n",
+            ),
+        ],
+        _ => return None,
+    };
+    let post_create = if id == "empty" {
+        Vec::new()
+    } else {
+        vec![PostCreateCommand {
+            command: "git".to_string(),
+            args: vec!["init".to_string()],
+        }]
+    };
+
+    Some(ProjectTemplate {
+        id: id.to_string(),
+        source: ProjectTemplateSource::Builtin,
+        name: id.to_string(),
+        description: None,
+        skeleton: TemplateSkeleton::Embedded(
+            files
+                .iter()
+                .map(|(path, content)| (path.to_string(), content.to_string()))
+                .collect(),
+        ),
+        post_create,
+    })
+}
+
+pub fn is_builtin_template_id(id: &str) -> bool {
+    BUILTIN_TEMPLATE_IDS.contains(&id)
+}
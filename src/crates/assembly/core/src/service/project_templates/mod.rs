@@ -0,0 +1,15 @@
+//! Project template module
+//!
+//! Built-in and user-defined templates for "New Project…": a directory
+//! skeleton with `{{project_name}}` substitution plus commands run once the
+//! files are in place.
+
+mod builtin;
+mod registry;
+mod scaffold;
+mod types;
+
+pub use builtin::{is_builtin_template_id, BUILTIN_TEMPLATE_IDS};
+pub use registry::*;
+pub use scaffold::create_project;
+pub use types::*;
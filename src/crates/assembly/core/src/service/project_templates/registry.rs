@@ -0,0 +1,225 @@
+//! Project template registry
+//!
+//! User templates are directories in `PathManager::user_project_templates_dir()`,
+//! each holding a `template.json` and an optional `files/` skeleton. Like
+//! user themes, the directory is re-scanned on every query.
+
+use log::{debug, warn};
+use std::path::{Path, PathBuf};
+
+use super::builtin::{builtin_template, is_builtin_template_id, BUILTIN_TEMPLATE_IDS};
+use super::types::*;
+use crate::infrastructure::PathManager;
+use crate::util::errors::*;
+
+const MAX_MANIFEST_FILE_BYTES: u64 = 64 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct ProjectTemplateRegistry {
+    user_templates_dir: PathBuf,
+}
+
+impl ProjectTemplateRegistry {
+    /// Creates a registry that reads user templates from `user_templates_dir`.
+    pub fn new(user_templates_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            user_templates_dir: user_templates_dir.into(),
+        }
+    }
+
+    /// Creates a registry rooted at the user config directory.
+    pub fn from_path_manager(path_manager: &PathManager) -> Self {
+        Self::new(path_manager.user_project_templates_dir())
+    }
+
+    pub fn user_templates_dir(&self) -> &Path {
+        &self.user_templates_dir
+    }
+
+    /// Lists built-in and valid user templates, plus diagnostics for user
+    /// template directories that failed validation.
+    pub fn list(&self) -> ProjectTemplateCatalog {
+        let mut catalog = ProjectTemplateCatalog::default();
+        catalog.templates.extend(
+            BUILTIN_TEMPLATE_IDS
+                .iter()
+                .filter_map(|id| builtin_template(id))
+                .map(|template| ProjectTemplateSummary {
+                    id: template.id,
+                    source: ProjectTemplateSource::Builtin,
+                    name: None,
+                    description: None,
+                    post_create: template.post_create,
+                }),
+        );
+
+        for dir in self.user_template_dirs() {
+            let dir_name = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match Self::load_user_template(&dir) {
+                Ok(template) => catalog.templates.push(ProjectTemplateSummary {
+                    id: template.id,
+                    source: ProjectTemplateSource::User,
+                    name: Some(template.name),
+                    description: template.description,
+                    post_create: template.post_create,
+                }),
+                Err(error) => {
+                    debug!(
+                        "Skipping invalid project template: dir={}, error={}",
+                        dir_name, error
+                    );
+                    catalog
+                        .invalid
+                        .push(InvalidProjectTemplate { dir_name, error });
+                }
+            }
+        }
+
+        catalog
+    }
+
+    /// Resolves a built-in or user template by id.
+    pub fn get(&self, template_id: &str) -> BitFunResult<ProjectTemplate> {
+        if let Some(template) = builtin_template(template_id) {
+            return Ok(template);
+        }
+        let dir = self.user_templates_dir.join(template_id);
+        if !is_valid_template_id(template_id) || !dir.is_dir() {
+            return Err(BitFunError::NotFound(format!(
+                "Project template not found: {}",
+                template_id
+            )));
+        }
+        Self::load_user_template(&dir).map_err(BitFunError::validation)
+    }
+
+    fn user_template_dirs(&self) -> Vec<PathBuf> {
+        let entries = match std::fs::read_dir(&self.user_templates_dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(error) => {
+                warn!(
+                    "Failed to read project templates directory: path={}, error={}",
+                    self.user_templates_dir.display(),
+                    error
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut dirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+        dirs
+    }
+
+    fn load_user_template(dir: &Path) -> Result<ProjectTemplate, String> {
+        let id = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !is_valid_template_id(&id) {
+            return Err(format!(
+                "Invalid template directory name '{}': use lowercase letters, digits and '-'",
+                id
+            ));
+        }
+        if is_builtin_template_id(&id) {
+            return Err(format!(
+                "Template id '{}' is reserved by a built-in template",
+                id
+            ));
+        }
+
+        let manifest_path = dir.join(TEMPLATE_MANIFEST_FILE);
+        let metadata = std::fs::metadata(&manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", TEMPLATE_MANIFEST_FILE, e))?;
+        if metadata.len() > MAX_MANIFEST_FILE_BYTES {
+            return Err(format!(
+                "{} is {} bytes, the limit is {}",
+                TEMPLATE_MANIFEST_FILE,
+                metadata.len(),
+                MAX_MANIFEST_FILE_BYTES
+            ));
+        }
+        let content = std::fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", TEMPLATE_MANIFEST_FILE, e))?;
+        let manifest: UserTemplateManifest = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid {}: {}", TEMPLATE_MANIFEST_FILE, e))?;
+        if manifest.name.trim().is_empty() {
+            return Err("Template name must not be empty".to_string());
+        }
+        if let Some(command) = manifest
+            .post_create
+            .iter()
+            .find(|command| command.command.trim().is_empty())
+        {
+            return Err(format!(
+                "Post-create command with arguments {:?} has no program",
+                command.args
+            ));
+        }
+
+        Ok(ProjectTemplate {
+            id,
+            source: ProjectTemplateSource::User,
+            name: manifest.name,
+            description: manifest.description,
+            skeleton: TemplateSkeleton::Directory(dir.join(TEMPLATE_SKELETON_DIR)),
+            post_create: manifest.post_create,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write_template(root: &Path, dir_name: &str, manifest: &serde_json::Value) {
+        let dir = root.join(dir_name);
+        std::fs::create_dir_all(dir.join(TEMPLATE_SKELETON_DIR)).unwrap();
+        std::fs::write(dir.join(TEMPLATE_MANIFEST_FILE), manifest.to_string()).unwrap();
+    }
+
+    #[test]
+    fn lists_builtin_and_user_templates_and_reports_invalid_ones() {
+        let temp = tempfile::tempdir().unwrap();
+        write_template(
+            temp.path(),
+            "team-service",
+            &json!({
+                "name": "Team service",
+                "postCreate": [{ "command": "npm", "args": ["install"] }]
+            }),
+        );
+        write_template(temp.path(), "rust-binary", &json!({ "name": "Shadow" }));
+        write_template(temp.path(), "Bad_Name", &json!({ "name": "Bad" }));
+        std::fs::create_dir_all(temp.path().join("no-manifest")).unwrap();
+
+        let registry = ProjectTemplateRegistry::new(temp.path());
+        let catalog = registry.list();
+
+        assert_eq!(catalog.invalid.len(), 3);
+        let user = catalog
+            .templates
+            .iter()
+            .find(|template| template.id == "team-service")
+            .unwrap();
+        assert_eq!(user.source, ProjectTemplateSource::User);
+        assert_eq!(user.post_create[0].command, "npm");
+        assert_eq!(
+            registry.get("rust-binary").unwrap().source,
+            ProjectTemplateSource::Builtin
+        );
+        assert!(matches!(
+            registry.get("../team-service"),
+            Err(BitFunError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,363 @@
+//! Creates a project directory from a template.
+
+use log::{info, warn};
+use std::path::{Component, Path, PathBuf};
+use tokio::fs;
+use tokio_util::sync::CancellationToken;
+
+use super::types::*;
+use crate::service::system;
+use crate::util::errors::*;
+
+/// Guards against a user template pointing at something huge by mistake.
+const MAX_SKELETON_FILES: usize = 5_000;
+
+enum FileContent {
+    Text(String),
+    Copy(PathBuf),
+}
+
+/// Writes `template` into `<parent_dir>/<project_name>` and runs its
+/// post-create commands there.
+///
+/// The target may exist only as an empty directory. If writing files fails
+/// or the operation is cancelled, a directory created here is removed again.
+/// Post-create command failures are reported in the result and leave the
+/// project in place.
+pub async fn create_project<F>(
+    template: &ProjectTemplate,
+    parent_dir: &Path,
+    project_name: &str,
+    cancel: &CancellationToken,
+    mut on_progress: F,
+) -> BitFunResult<ScaffoldResult>
+where
+    F: FnMut(ScaffoldProgress),
+{
+    validate_project_name(project_name).map_err(BitFunError::validation)?;
+    if !fs::metadata(parent_dir)
+        .await
+        .map(|metadata| metadata.is_dir())
+        .unwrap_or(false)
+    {
+        return Err(BitFunError::NotFound(format!(
+            "Parent directory not found: {}",
+            parent_dir.display()
+        )));
+    }
+
+    let project_dir = parent_dir.join(project_name);
+    let created_dir = match fs::create_dir(&project_dir).await {
+        Ok(()) => true,
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            if !is_empty_dir(&project_dir).await {
+                return Err(BitFunError::validation(format!(
+                    "'{}' already exists and is not an empty directory",
+                    project_dir.display()
+                )));
+            }
+            false
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let files = match skeleton_files(&template.skeleton, project_name).await {
+        Ok(files) => files,
+        Err(e) => {
+            discard_project_dir(&project_dir, created_dir).await;
+            return Err(e);
+        }
+    };
+    let total = files.len() + template.post_create.len();
+    info!(
+        "Creating project from template: template_id={}, path={}, files={}, commands={}",
+        template.id,
+        project_dir.display(),
+        files.len(),
+        template.post_create.len()
+    );
+
+    for (index, (relative_path, content)) in files.iter().enumerate() {
+        if cancel.is_cancelled() {
+            discard_project_dir(&project_dir, created_dir).await;
+            return Err(BitFunError::cancelled("Project creation cancelled"));
+        }
+        on_progress(ScaffoldProgress {
+            phase: ScaffoldPhase::WritingFiles,
+            completed: index,
+            total,
+            current: Some(relative_path.to_string_lossy().replace('\\', "/")),
+        });
+        if let Err(e) = write_file(&project_dir.join(relative_path), content, project_name).await {
+            discard_project_dir(&project_dir, created_dir).await;
+            return Err(BitFunError::io(format!(
+                "Failed to write {}: {}",
+                relative_path.display(),
+                e
+            )));
+        }
+    }
+
+    let cwd = project_dir.to_string_lossy().to_string();
+    let mut commands = Vec::with_capacity(template.post_create.len());
+    for (index, command) in template.post_create.iter().enumerate() {
+        if cancel.is_cancelled() {
+            discard_project_dir(&project_dir, created_dir).await;
+            return Err(BitFunError::cancelled("Project creation cancelled"));
+        }
+        let args: Vec<String> = command
+            .args
+            .iter()
+            .map(|arg| substitute_variables(arg, project_name))
+            .collect();
+        let command_line = std::iter::once(command.command.as_str())
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ");
+        on_progress(ScaffoldProgress {
+            phase: ScaffoldPhase::RunningCommand,
+            completed: files.len() + index,
+            total,
+            current: Some(command_line.clone()),
+        });
+        commands.push(run_post_create(&command.command, &args, &cwd, command_line).await);
+    }
+
+    on_progress(ScaffoldProgress {
+        phase: ScaffoldPhase::Completed,
+        completed: total,
+        total,
+        current: None,
+    });
+    Ok(ScaffoldResult {
+        project_path: cwd,
+        template_id: template.id.clone(),
+        files_written: files.len(),
+        commands,
+    })
+}
+
+async fn run_post_create(
+    program: &str,
+    args: &[String],
+    cwd: &str,
+    command_line: String,
+) -> PostCreateCommandResult {
+    if !system::check_command(program).exists {
+        return PostCreateCommandResult {
+            command_line,
+            ran: false,
+            success: false,
+            exit_code: None,
+            error: Some(format!("'{}' is not installed", program)),
+        };
+    }
+    match system::run_command(program, args, Some(cwd), None).await {
+        Ok(output) => {
+            if !output.success {
+                warn!(
+                    "Post-create command failed: command={}, exit_code={}",
+                    command_line, output.exit_code
+                );
+            }
+            let error = (!output.success).then(|| {
+                let stderr = output.stderr.trim();
+                if stderr.is_empty() {
+                    output.stdout.trim().to_string()
+                } else {
+                    stderr.to_string()
+                }
+            });
+            PostCreateCommandResult {
+                command_line,
+                ran: true,
+                success: output.success,
+                exit_code: Some(output.exit_code),
+                error,
+            }
+        }
+        Err(e) => PostCreateCommandResult {
+            command_line,
+            ran: true,
+            success: false,
+            exit_code: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Relative target paths, after substitution, with their contents.
+async fn skeleton_files(
+    skeleton: &TemplateSkeleton,
+    project_name: &str,
+) -> BitFunResult<Vec<(PathBuf, FileContent)>> {
+    let files: Vec<(PathBuf, FileContent)> = match skeleton {
+        TemplateSkeleton::Embedded(files) => files
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), FileContent::Text(content.clone())))
+            .collect(),
+        TemplateSkeleton::Directory(root) => {
+            let root = root.clone();
+            tokio::task::spawn_blocking(move || collect_directory_files(&root))
+                .await
+                .map_err(|e| BitFunError::service(e.to_string()))??
+        }
+    };
+
+    files
+        .into_iter()
+        .map(|(path, content)| {
+            let path = PathBuf::from(substitute_variables(&path.to_string_lossy(), project_name));
+            if path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                Ok((path, content))
+            } else {
+                Err(BitFunError::validation(format!(
+                    "Template file path '{}' escapes the project directory",
+                    path.display()
+                )))
+            }
+        })
+        .collect()
+}
+
+fn collect_directory_files(root: &Path) -> BitFunResult<Vec<(PathBuf, FileContent)>> {
+    let mut files = Vec::new();
+    if !root.is_dir() {
+        return Ok(files);
+    }
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                pending.push(path);
+            } else if file_type.is_file() {
+                if files.len() >= MAX_SKELETON_FILES {
+                    return Err(BitFunError::validation(format!(
+                        "Template has more than {} files",
+                        MAX_SKELETON_FILES
+                    )));
+                }
+                let relative = path
+                    .strip_prefix(root)
+                    .map(Path::to_path_buf)
+                    .map_err(|e| BitFunError::service(e.to_string()))?;
+                files.push((relative, FileContent::Copy(path)));
+            }
+            // Symlinks are skipped so a template cannot pull in files from
+            // outside its own directory.
+        }
+    }
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+async fn write_file(
+    target: &Path,
+    content: &FileContent,
+    project_name: &str,
+) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    match content {
+        FileContent::Text(text) => {
+            fs::write(target, substitute_variables(text, project_name)).await
+        }
+        FileContent::Copy(source) => {
+            let bytes = fs::read(source).await?;
+            // Binary files are copied unchanged.
+            match String::from_utf8(bytes) {
+                Ok(text) => fs::write(target, substitute_variables(&text, project_name)).await,
+                Err(e) => fs::write(target, e.into_bytes()).await,
+            }
+        }
+    }
+}
+
+async fn is_empty_dir(path: &Path) -> bool {
+    match fs::read_dir(path).await {
+        Ok(mut entries) => matches!(entries.next_entry().await, Ok(None)),
+        Err(_) => false,
+    }
+}
+
+async fn discard_project_dir(project_dir: &Path, created_dir: bool) {
+    if !created_dir {
+        return;
+    }
+    if let Err(e) = fs::remove_dir_all(project_dir).await {
+        warn!(
+            "Failed to remove partially created project: path={}, error={}",
+            project_dir.display(),
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::builtin::builtin_template;
+    use super::*;
+
+    #[tokio::test]
+    async fn writes_substituted_files_and_refuses_non_empty_targets() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut template = builtin_template("rust-binary").unwrap();
+        template.post_create.clear();
+
+        let mut progress = Vec::new();
+        let result = create_project(
+            &template,
+            temp.path(),
+            "My App",
+            &CancellationToken::new(),
+            |update| progress.push(update.phase),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.files_written, 4);
+        let manifest = std::fs::read_to_string(temp.path().join("My App/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"my-app\""));
+        assert_eq!(progress.last(), Some(&ScaffoldPhase::Completed));
+
+        let again = create_project(
+            &template,
+            temp.path(),
+            "My App",
+            &CancellationToken::new(),
+            |_| {},
+        )
+        .await;
+        assert!(matches!(again, Err(BitFunError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn cancellation_removes_the_created_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let template = builtin_template("node").unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let result = create_project(&template, temp.path(), "demo", &cancel, |_| {}).await;
+
+        assert!(matches!(result, Err(BitFunError::Cancelled(_))));
+        assert!(!temp.path().join("demo").exists());
+    }
+
+    #[test]
+    fn project_names_and_slugs() {
+        assert!(validate_project_name("my-project").is_ok());
+        assert!(validate_project_name("../escape").is_err());
+        assert!(validate_project_name("a:b").is_err());
+        assert!(validate_project_name(" padded").is_err());
+        assert_eq!(project_slug("My  Cool_App!"), "my-cool-app");
+        assert_eq!(project_slug("项目"), "project");
+    }
+}
@@ -0,0 +1,204 @@
+//! Project template type definitions
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// File read from each user template directory.
+pub const TEMPLATE_MANIFEST_FILE: &str = "template.json";
+/// Subdirectory of a user template copied into the new project.
+pub const TEMPLATE_SKELETON_DIR: &str = "files";
+
+const MAX_TEMPLATE_ID_LEN: usize = 64;
+const MAX_PROJECT_NAME_LEN: usize = 128;
+
+/// Where a template comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectTemplateSource {
+    Builtin,
+    User,
+}
+
+/// A command run in the new project directory once its files are written.
+/// `{{project_name}}` and `{{project_slug}}` are substituted in `args`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostCreateCommand {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// `template.json` of a user template
+/// (`<config>/project_templates/<id>/template.json`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTemplateManifest {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub post_create: Vec<PostCreateCommand>,
+}
+
+/// Files a template writes into the new project.
+#[derive(Debug, Clone)]
+pub enum TemplateSkeleton {
+    /// Relative path and contents.
+    Embedded(Vec<(String, String)>),
+    /// Directory copied recursively.
+    Directory(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectTemplate {
+    pub id: String,
+    pub source: ProjectTemplateSource,
+    pub name: String,
+    pub description: Option<String>,
+    pub skeleton: TemplateSkeleton,
+    pub post_create: Vec<PostCreateCommand>,
+}
+
+/// Entry returned by template listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplateSummary {
+    pub id: String,
+    pub source: ProjectTemplateSource,
+    /// Display name; `None` for built-ins, whose names are localized by the frontend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub post_create: Vec<PostCreateCommand>,
+}
+
+/// A user template directory that was found but rejected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidProjectTemplate {
+    pub dir_name: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplateCatalog {
+    pub templates: Vec<ProjectTemplateSummary>,
+    pub invalid: Vec<InvalidProjectTemplate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaffoldPhase {
+    WritingFiles,
+    RunningCommand,
+    Completed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaffoldProgress {
+    pub phase: ScaffoldPhase,
+    /// Completed steps: files written, then commands run.
+    pub completed: usize,
+    pub total: usize,
+    /// File or command the phase is working on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+}
+
+/// Outcome of one post-create command. A failing command does not undo the
+/// project; its output is returned so the user can fix it by hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostCreateCommandResult {
+    /// Command line after variable substitution.
+    pub command_line: String,
+    /// `false` when the command is not installed.
+    pub ran: bool,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaffoldResult {
+    pub project_path: String,
+    pub template_id: String,
+    pub files_written: usize,
+    pub commands: Vec<PostCreateCommandResult>,
+}
+
+/// Template ids double as directory names, so keep them to a portable subset.
+pub fn is_valid_template_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_TEMPLATE_ID_LEN
+        && !id.starts_with('-')
+        && !id.ends_with('-')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// The project name becomes a directory name on every platform, so reject
+/// anything Windows or a path join would misread.
+pub fn validate_project_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Project name must not be empty".to_string());
+    }
+    if name != name.trim() {
+        return Err("Project name must not start or end with whitespace".to_string());
+    }
+    if name.chars().count() > MAX_PROJECT_NAME_LEN {
+        return Err(format!(
+            "Project name is longer than {} characters",
+            MAX_PROJECT_NAME_LEN
+        ));
+    }
+    if name == "." || name == ".." || name.ends_with('.') {
+        return Err(format!("Invalid project name '{}'", name));
+    }
+    if let Some(c) = name.chars().find(|c| {
+        c.is_control() || matches!(c, '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*')
+    }) {
+        return Err(format!(
+            "Project name must not contain '{}'",
+            c.escape_default()
+        ));
+    }
+    Ok(())
+}
+
+/// Lowercase, `-`-separated form of the project name for package manifests,
+/// e.g. `My App` becomes `my-app`.
+pub fn project_slug(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    if slug.is_empty() {
+        slug.push_str("project");
+    }
+    slug
+}
+
+/// Replaces `{{project_name}}` and `{{project_slug}}` in `text`.
+pub fn substitute_variables(text: &str, project_name: &str) -> String {
+    if !text.contains("{{") {
+        return text.to_string();
+    }
+    text.replace("{{project_name}}", project_name)
+        .replace("{{project_slug}}", &project_slug(project_name))
+}
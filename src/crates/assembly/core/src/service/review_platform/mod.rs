@@ -2,24 +2,28 @@
 //!
 //! Provider detection, provider DTO mapping, token persistence, and HTTP/Git
 //! integration logic live in `bitfun-services-integrations::review_platform`.
-//! Core only preserves the legacy static API, injects BitFun storage paths, and
-//! connects the product remote-workspace classifier.
+//! Core only preserves the legacy static API, injects BitFun storage paths and
+//! the system keychain token vault, and connects the product remote-workspace
+//! classifier.
 
 use crate::infrastructure::try_get_path_manager_arc;
 use std::sync::Arc;
 
 pub use bitfun_services_integrations::review_platform::{
     ReviewAuthSource, ReviewAuthState, ReviewChecks, ReviewDecision, ReviewEvidenceCompleteness,
-    ReviewFileStatus, ReviewItemState, ReviewPlatformAccount, ReviewPlatformActionResult,
-    ReviewPlatformApprovalRequest, ReviewPlatformAuthChallenge, ReviewPlatformAuthChallengeState,
-    ReviewPlatformCapabilities, ReviewPlatformCiItem, ReviewPlatformCiLog, ReviewPlatformCommit,
-    ReviewPlatformCreatePullRequestRequest, ReviewPlatformDetailSection, ReviewPlatformError,
-    ReviewPlatformFile, ReviewPlatformIssueComment, ReviewPlatformIssueEvidence,
-    ReviewPlatformKind, ReviewPlatformPullRequest, ReviewPlatformPullRequestDetail,
-    ReviewPlatformPullRequestDetailPage, ReviewPlatformPullRequestFileDiff,
-    ReviewPlatformPullRequestReviewTarget, ReviewPlatformRemote,
-    ReviewPlatformReplyToThreadRequest, ReviewPlatformRepositoryRef,
-    ReviewPlatformRequestChangesRequest, ReviewPlatformResolveThreadRequest,
+    ReviewFileStatus, ReviewIssueStateFilter, ReviewItemState, ReviewPlatformAccount,
+    ReviewPlatformActionResult, ReviewPlatformApprovalRequest, ReviewPlatformAuthChallenge,
+    ReviewPlatformAuthChallengeState, ReviewPlatformBranchResult, ReviewPlatformCapabilities,
+    ReviewPlatformCiItem, ReviewPlatformCiLog, ReviewPlatformCommit,
+    ReviewPlatformCreateBranchRequest, ReviewPlatformCreatePullRequestRequest,
+    ReviewPlatformDetailSection, ReviewPlatformError, ReviewPlatformFile,
+    ReviewPlatformIssueComment, ReviewPlatformIssueEvidence, ReviewPlatformIssuePage,
+    ReviewPlatformIssueSummary, ReviewPlatformKind, ReviewPlatformPullRequest,
+    ReviewPlatformPullRequestDetail, ReviewPlatformPullRequestDetailPage,
+    ReviewPlatformPullRequestFileDiff, ReviewPlatformPullRequestReviewTarget,
+    ReviewPlatformPushBranchRequest, ReviewPlatformRemote, ReviewPlatformReplyToThreadRequest,
+    ReviewPlatformRepositoryRef, ReviewPlatformRequestChangesRequest,
+    ReviewPlatformResolveThreadRequest, ReviewPlatformReviewComments,
     ReviewPlatformSubmitReviewRequest, ReviewPlatformThread, ReviewPlatformThreadKind,
     ReviewPlatformWorkspaceSnapshot, ReviewSubmitEvent,
 };
@@ -28,6 +32,7 @@ use bitfun_services_integrations::review_platform::{
     ReviewPlatformService as ReviewPlatformOwnerService, ReviewPlatformWorkspaceClassifier,
     REVIEW_PLATFORM_TOKEN_FILE_NAME,
 };
+use bitfun_services_integrations::review_platform_keychain::SystemKeychainTokenVault;
 
 pub struct ReviewPlatformService;
 
@@ -101,7 +106,8 @@ fn owner_service() -> Result<ReviewPlatformOwnerService, ReviewPlatformError> {
             .user_data_dir()
             .join(REVIEW_PLATFORM_TOKEN_FILE_NAME),
         Arc::new(CoreReviewPlatformWorkspaceClassifier),
-    ))
+    )
+    .with_token_vault(Arc::new(SystemKeychainTokenVault)))
 }
 
 impl ReviewPlatformService {
@@ -173,6 +179,18 @@ impl ReviewPlatformService {
             .await
     }
 
+    pub async fn list_issues(
+        repository_path: &str,
+        remote_id: Option<&str>,
+        state: ReviewIssueStateFilter,
+        page: Option<u32>,
+        per_page: Option<u32>,
+    ) -> Result<ReviewPlatformIssuePage, ReviewPlatformError> {
+        owner_service()?
+            .list_issues(repository_path, remote_id, state, page, per_page)
+            .await
+    }
+
     pub async fn pull_request_review_target_by_identity(
         platform: ReviewPlatformKind,
         host: &str,
@@ -278,6 +296,34 @@ impl ReviewPlatformService {
             .await
     }
 
+    pub async fn pull_request_review_comments(
+        repository_path: &str,
+        remote_id: &str,
+        pull_request_id: &str,
+        include_resolved: bool,
+    ) -> Result<ReviewPlatformReviewComments, ReviewPlatformError> {
+        owner_service()?
+            .pull_request_review_comments(
+                repository_path,
+                remote_id,
+                pull_request_id,
+                include_resolved,
+            )
+            .await
+    }
+
+    pub async fn create_branch(
+        request: ReviewPlatformCreateBranchRequest,
+    ) -> Result<ReviewPlatformBranchResult, ReviewPlatformError> {
+        owner_service()?.create_branch(request).await
+    }
+
+    pub async fn push_branch(
+        request: ReviewPlatformPushBranchRequest,
+    ) -> Result<ReviewPlatformBranchResult, ReviewPlatformError> {
+        owner_service()?.push_branch(request).await
+    }
+
     pub async fn create_pull_request(
         request: ReviewPlatformCreatePullRequestRequest,
     ) -> Result<ReviewPlatformActionResult, ReviewPlatformError> {
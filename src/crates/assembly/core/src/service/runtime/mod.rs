@@ -3,13 +3,18 @@
 //! Command resolution and PATH merge rules are owned by
 //! `bitfun-services-core`; core only supplies the product-managed runtime root.
 
+#[cfg(feature = "product-full")]
+pub mod provisioning;
+
 use crate::infrastructure::get_path_manager_arc;
 use crate::util::errors::BitFunResult;
 use bitfun_services_core::managed_runtime::ManagedRuntimeResolver;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub use bitfun_services_core::managed_runtime::{
-    ResolvedCommand, RuntimeCommandCapability, RuntimeSource,
+    CommandConflict, CommandConflictKind, CommandDiagnosis, CommandMatch, CommandMatchOrigin,
+    ResolvedCommand, RuntimeCommandCapability, RuntimeCommandUsage, RuntimeSource,
 };
 
 #[derive(Debug, Clone)]
@@ -59,10 +64,32 @@ impl RuntimeManager {
         self.inner.get_capabilities_for_commands(commands)
     }
 
+    pub async fn probe_command_version(&self, command: &str) -> Option<String> {
+        self.inner.probe_command_version(command).await
+    }
+
+    pub async fn diagnose_command(&self, command: &str) -> CommandDiagnosis {
+        self.inner.diagnose_command(command).await
+    }
+
+    pub fn get_usage(&self) -> Vec<RuntimeCommandUsage> {
+        self.inner.get_usage()
+    }
+
+    /// Record one finished run of a resolved command so `get_usage` can
+    /// report how often it is used and how often it fails.
+    pub fn record_execution(command: &str, success: bool, duration: Duration) {
+        bitfun_services_core::managed_runtime::record_command_execution(command, success, duration);
+    }
+
     pub fn managed_path_entries(&self) -> Vec<PathBuf> {
         self.inner.managed_path_entries()
     }
 
+    pub fn managed_git_config_env(&self) -> Vec<(String, String)> {
+        self.inner.managed_git_config_env()
+    }
+
     pub fn merged_path_env(&self, existing_path: Option<&str>) -> Option<String> {
         self.inner.merged_path_env(existing_path)
     }
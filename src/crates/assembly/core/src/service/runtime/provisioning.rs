@@ -0,0 +1,827 @@
+//! First-run runtime provisioning.
+//!
+//! Users who skipped components in the installer can install the
+//! recommended runtimes from inside the app. The plan is built from the
+//! missing runtime capabilities; downloads are verified against the
+//! publisher's checksum list and unpacked into `runtimes/<component>/current`,
+//! where the managed runtime resolver picks them up.
+
+use super::RuntimeManager;
+use crate::service::downloads::{
+    discard_partial, download_resumable, partial_size, DownloadThrottle, ResumableDownload,
+};
+use crate::service::power::{
+    get_global_power_service, wait_for_background_allowance, BackgroundWork,
+};
+use crate::util::errors::{BitFunError, BitFunResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::fs;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+const NODE_VERSION: &str = "22.20.0";
+const NODE_DIST_SOURCES: &[&str] = &[
+    "https://nodejs.org/dist",
+    "https://npmmirror.com/mirrors/node",
+];
+/// Used when the size probe fails; Node archives are 25-35 MB on every
+/// platform.
+const NODE_ESTIMATED_SIZE_BYTES: u64 = 32 * 1024 * 1024;
+const SIZE_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+static GLOBAL_RUNTIME_PROVISIONER: OnceLock<Arc<RuntimeProvisioner>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuntimeArchiveKind {
+    Zip,
+    TarGz,
+}
+
+/// A downloadable runtime for the current platform.
+#[derive(Debug, Clone)]
+struct RuntimePackage {
+    component: &'static str,
+    display_name: &'static str,
+    version: &'static str,
+    /// Commands the package provides; the first one is checked after install.
+    commands: &'static [&'static str],
+    file_name: String,
+    /// Mirrors of the same release directory, tried in order.
+    base_urls: Vec<String>,
+    /// Checksum list published next to the archive (`SHASUMS256.txt` format).
+    checksums_file: &'static str,
+    archive: RuntimeArchiveKind,
+    estimated_size_bytes: u64,
+}
+
+impl RuntimePackage {
+    fn urls(&self, file_name: &str) -> Vec<String> {
+        self.base_urls
+            .iter()
+            .map(|base| format!("{}/{}", base, file_name))
+            .collect()
+    }
+}
+
+fn node_package() -> Option<RuntimePackage> {
+    let platform = match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("windows", "x86_64") => "win-x64",
+        ("windows", "aarch64") => "win-arm64",
+        ("macos", "x86_64") => "darwin-x64",
+        ("macos", "aarch64") => "darwin-arm64",
+        ("linux", "x86_64") => "linux-x64",
+        ("linux", "aarch64") => "linux-arm64",
+        _ => return None,
+    };
+    let archive = if cfg!(windows) {
+        RuntimeArchiveKind::Zip
+    } else {
+        RuntimeArchiveKind::TarGz
+    };
+    let extension = match archive {
+        RuntimeArchiveKind::Zip => "zip",
+        RuntimeArchiveKind::TarGz => "tar.gz",
+    };
+    Some(RuntimePackage {
+        component: "node",
+        display_name: "Node.js",
+        version: NODE_VERSION,
+        commands: &["node", "npm", "npx"],
+        file_name: format!("node-v{}-{}.{}", NODE_VERSION, platform, extension),
+        base_urls: NODE_DIST_SOURCES
+            .iter()
+            .map(|source| format!("{}/v{}", source, NODE_VERSION))
+            .collect(),
+        checksums_file: "SHASUMS256.txt",
+        archive,
+        estimated_size_bytes: NODE_ESTIMATED_SIZE_BYTES,
+    })
+}
+
+/// Packages with a verified download for this platform. Components without
+/// an entry are reported in the plan as needing a manual install.
+fn builtin_runtime_packages() -> Vec<RuntimePackage> {
+    node_package().into_iter().collect()
+}
+
+/// Version of a runtime this build provisions, for the About dialog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedRuntimeVersion {
+    pub component: String,
+    pub display_name: String,
+    pub version: String,
+}
+
+/// Runtimes this build downloads on demand, whether or not they are
+/// installed yet.
+pub fn managed_runtime_versions() -> Vec<ManagedRuntimeVersion> {
+    builtin_runtime_packages()
+        .into_iter()
+        .map(|package| ManagedRuntimeVersion {
+            component: package.component.to_string(),
+            display_name: package.display_name.to_string(),
+            version: package.version.to_string(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeProvisioningItem {
+    pub component: String,
+    pub display_name: String,
+    pub version: String,
+    pub missing_commands: Vec<String>,
+    pub size_bytes: u64,
+    /// The download size could not be probed and is an estimate.
+    pub size_estimated: bool,
+    /// Already downloaded by an interrupted install; the next install
+    /// continues from here.
+    pub resumable_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeProvisioningPlan {
+    pub items: Vec<RuntimeProvisioningItem>,
+    /// Missing commands with no downloadable package for this platform.
+    pub unavailable_commands: Vec<String>,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuntimeProvisioningPhase {
+    /// Held back until the user is idle; see [`BackgroundWork::RuntimeDownloads`].
+    Waiting,
+    Downloading,
+    Extracting,
+    Installed,
+    Failed,
+}
+
+/// Combined progress across every component of one install run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeProvisioningProgress {
+    pub component: String,
+    pub phase: RuntimeProvisioningPhase,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    pub percent: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeProvisioningFailure {
+    pub component: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeProvisioningResult {
+    pub installed: Vec<String>,
+    pub failed: Vec<RuntimeProvisioningFailure>,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeInstallOptions {
+    /// Start downloading once the power policy allows it.
+    pub defer_until_idle: bool,
+    /// Registers a speed cap that can be changed while the install runs.
+    pub operation_id: Option<String>,
+    /// Initial cap for this install on top of the global one.
+    pub max_bytes_per_sec: Option<u64>,
+}
+
+pub struct RuntimeProvisioner {
+    runtime_manager: RuntimeManager,
+    active: Mutex<Option<CancellationToken>>,
+}
+
+impl RuntimeProvisioner {
+    pub fn new(runtime_manager: RuntimeManager) -> Self {
+        Self {
+            runtime_manager,
+            active: Mutex::new(None),
+        }
+    }
+
+    /// Missing capabilities grouped into installable packages, with sizes
+    /// probed from the download servers where reachable.
+    pub async fn plan(&self) -> BitFunResult<RuntimeProvisioningPlan> {
+        let missing = self
+            .runtime_manager
+            .get_capabilities()
+            .into_iter()
+            .filter(|capability| !capability.available)
+            .map(|capability| capability.command)
+            .collect::<Vec<_>>();
+        let packages = builtin_runtime_packages();
+        let (grouped, unavailable_commands) = group_missing_commands(&missing, &packages);
+
+        let client = http_client(SIZE_PROBE_TIMEOUT)?;
+        let download_dir = self.download_dir();
+        let mut items = Vec::with_capacity(grouped.len());
+        for (package, missing_commands) in grouped {
+            let probed = probe_download_size(&client, package).await;
+            let resumable_bytes = partial_size(&download_dir.join(&package.file_name)).await;
+            items.push(RuntimeProvisioningItem {
+                component: package.component.to_string(),
+                display_name: package.display_name.to_string(),
+                version: package.version.to_string(),
+                missing_commands,
+                size_bytes: probed.unwrap_or(package.estimated_size_bytes),
+                size_estimated: probed.is_none(),
+                resumable_bytes,
+            });
+        }
+        let total_bytes = items.iter().map(|item| item.size_bytes).sum();
+        Ok(RuntimeProvisioningPlan {
+            items,
+            unavailable_commands,
+            total_bytes,
+        })
+    }
+
+    /// Downloads and installs `components` one after another. A failed
+    /// component does not stop the rest; cancellation does, either through
+    /// `cancel` or through [`Self::cancel`]. Cancelling keeps partial
+    /// downloads, so installing again later resumes them.
+    pub async fn install<F>(
+        &self,
+        components: &[String],
+        cancel: CancellationToken,
+        options: RuntimeInstallOptions,
+        on_progress: F,
+    ) -> BitFunResult<RuntimeProvisioningResult>
+    where
+        F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+    {
+        {
+            let mut active = self.active.lock().expect("provisioning state poisoned");
+            if active.is_some() {
+                return Err(BitFunError::service(
+                    "A runtime installation is already running",
+                ));
+            }
+            *active = Some(cancel.clone());
+        }
+        let result = self
+            .install_all(components, &cancel, &options, &on_progress)
+            .await;
+        *self.active.lock().expect("provisioning state poisoned") = None;
+        result
+    }
+
+    /// Cancels the running install, if any.
+    pub fn cancel(&self) -> bool {
+        match self
+            .active
+            .lock()
+            .expect("provisioning state poisoned")
+            .as_ref()
+        {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Archives download here; partial files stay between installs.
+    fn download_dir(&self) -> PathBuf {
+        self.runtime_manager.runtime_root().join(".downloads")
+    }
+
+    async fn install_all<F>(
+        &self,
+        components: &[String],
+        cancel: &CancellationToken,
+        options: &RuntimeInstallOptions,
+        on_progress: &F,
+    ) -> BitFunResult<RuntimeProvisioningResult>
+    where
+        F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+    {
+        let packages = builtin_runtime_packages();
+        let mut selected = Vec::with_capacity(components.len());
+        for component in components {
+            let package = packages
+                .iter()
+                .find(|package| package.component == component)
+                .ok_or_else(|| {
+                    BitFunError::NotFound(format!(
+                        "No runtime package for this platform: {}",
+                        component
+                    ))
+                })?;
+            selected.push(package);
+        }
+
+        let client = http_client(Duration::from_secs(30 * 60))?;
+        let mut progress = ProgressTracker {
+            completed_bytes: 0,
+            total_bytes: selected
+                .iter()
+                .map(|package| package.estimated_size_bytes)
+                .sum(),
+            on_progress,
+        };
+        let mut result = RuntimeProvisioningResult::default();
+
+        let deferred = options.defer_until_idle
+            && get_global_power_service()
+                .and_then(|power| power.defer_reason(BackgroundWork::RuntimeDownloads))
+                .is_some();
+        if let Some(first) = selected.first().filter(|_| deferred) {
+            progress.emit(first, RuntimeProvisioningPhase::Waiting, 0, None);
+            tokio::select! {
+                _ = wait_for_background_allowance(BackgroundWork::RuntimeDownloads) => {}
+                _ = cancel.cancelled() => {
+                    result.cancelled = true;
+                    return Ok(result);
+                }
+            }
+        }
+
+        let throttle = match &options.operation_id {
+            Some(operation_id) => {
+                DownloadThrottle::for_operation(operation_id, options.max_bytes_per_sec)
+            }
+            None => DownloadThrottle::global(),
+        };
+        for package in selected {
+            match self
+                .install_package(&client, package, &throttle, cancel, &mut progress)
+                .await
+            {
+                Ok(()) => {
+                    progress.emit(package, RuntimeProvisioningPhase::Installed, 0, None);
+                    result.installed.push(package.component.to_string());
+                }
+                Err(_) if cancel.is_cancelled() => {
+                    result.cancelled = true;
+                    break;
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Runtime provisioning failed: component={}, error={}",
+                        package.component,
+                        error
+                    );
+                    progress.emit(
+                        package,
+                        RuntimeProvisioningPhase::Failed,
+                        0,
+                        Some(error.to_string()),
+                    );
+                    result.failed.push(RuntimeProvisioningFailure {
+                        component: package.component.to_string(),
+                        error: error.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    async fn install_package<F>(
+        &self,
+        client: &reqwest::Client,
+        package: &RuntimePackage,
+        throttle: &DownloadThrottle,
+        cancel: &CancellationToken,
+        progress: &mut ProgressTracker<'_, F>,
+    ) -> BitFunResult<()>
+    where
+        F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+    {
+        let expected_hash = fetch_expected_sha256(client, package).await?;
+        let archive_path = self.download_dir().join(&package.file_name);
+        download_package(
+            client,
+            package,
+            &archive_path,
+            &expected_hash,
+            throttle,
+            cancel,
+            progress,
+        )
+        .await?;
+
+        progress.emit(package, RuntimeProvisioningPhase::Extracting, 0, None);
+        let component_dir = self.runtime_manager.runtime_root().join(package.component);
+        let install_result = install_archive(package, &archive_path, &component_dir).await;
+        discard_partial(&archive_path).await;
+        install_result?;
+
+        let command = package.commands[0];
+        if !self
+            .runtime_manager
+            .get_command_capability(command)
+            .available
+        {
+            return Err(BitFunError::validation(format!(
+                "Installed {} does not provide `{}`",
+                package.display_name, command
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The process-wide provisioner, created on first use.
+pub fn get_global_runtime_provisioner() -> BitFunResult<Arc<RuntimeProvisioner>> {
+    if let Some(provisioner) = GLOBAL_RUNTIME_PROVISIONER.get() {
+        return Ok(provisioner.clone());
+    }
+    let provisioner = Arc::new(RuntimeProvisioner::new(RuntimeManager::new()?));
+    Ok(GLOBAL_RUNTIME_PROVISIONER
+        .get_or_init(|| provisioner)
+        .clone())
+}
+
+struct ProgressTracker<'a, F> {
+    completed_bytes: u64,
+    total_bytes: u64,
+    on_progress: &'a F,
+}
+
+impl<F> ProgressTracker<'_, F>
+where
+    F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+{
+    fn emit(
+        &self,
+        package: &RuntimePackage,
+        phase: RuntimeProvisioningPhase,
+        component_bytes: u64,
+        error: Option<String>,
+    ) {
+        let downloaded_bytes = self.completed_bytes.saturating_add(component_bytes);
+        let percent = if self.total_bytes > 0 {
+            (downloaded_bytes as f64 / self.total_bytes as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+        (self.on_progress)(RuntimeProvisioningProgress {
+            component: package.component.to_string(),
+            phase,
+            downloaded_bytes,
+            total_bytes: self.total_bytes,
+            percent,
+            error,
+        });
+    }
+
+    /// Replaces the package estimate with the real size once the server
+    /// reports it.
+    fn set_package_size(&mut self, package: &RuntimePackage, size_bytes: u64) {
+        self.total_bytes = self
+            .total_bytes
+            .saturating_sub(package.estimated_size_bytes)
+            .saturating_add(size_bytes);
+    }
+}
+
+fn http_client(timeout: Duration) -> BitFunResult<reqwest::Client> {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(15))
+        .timeout(timeout)
+        .build()
+        .map_err(|error| BitFunError::http(error.to_string()))
+}
+
+/// Splits missing commands into packages that provide them and commands
+/// that have no package.
+fn group_missing_commands<'a>(
+    missing: &[String],
+    packages: &'a [RuntimePackage],
+) -> (Vec<(&'a RuntimePackage, Vec<String>)>, Vec<String>) {
+    let mut grouped: BTreeMap<&str, (&RuntimePackage, Vec<String>)> = BTreeMap::new();
+    let mut unavailable = Vec::new();
+    for command in missing {
+        match packages
+            .iter()
+            .find(|package| package.commands.contains(&command.as_str()))
+        {
+            Some(package) => grouped
+                .entry(package.component)
+                .or_insert_with(|| (package, Vec::new()))
+                .1
+                .push(command.clone()),
+            None => unavailable.push(command.clone()),
+        }
+    }
+    (grouped.into_values().collect(), unavailable)
+}
+
+async fn probe_download_size(client: &reqwest::Client, package: &RuntimePackage) -> Option<u64> {
+    for url in package.urls(&package.file_name) {
+        let response = client
+            .head(&url)
+            .header(reqwest::header::USER_AGENT, "BitFun")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        if let Some(size) = response.ok().and_then(|response| response.content_length()) {
+            return Some(size);
+        }
+    }
+    None
+}
+
+async fn fetch_expected_sha256(
+    client: &reqwest::Client,
+    package: &RuntimePackage,
+) -> BitFunResult<String> {
+    let mut errors = Vec::new();
+    for url in package.urls(package.checksums_file) {
+        let response = client
+            .get(&url)
+            .header(reqwest::header::USER_AGENT, "BitFun")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match response {
+            Ok(response) => match response.text().await {
+                Ok(text) => {
+                    return find_checksum(&text, &package.file_name).ok_or_else(|| {
+                        BitFunError::validation(format!(
+                            "{} is not listed in {}",
+                            package.file_name, url
+                        ))
+                    })
+                }
+                Err(error) => errors.push(error.to_string()),
+            },
+            Err(error) => errors.push(error.to_string()),
+        }
+    }
+    Err(BitFunError::http(format!(
+        "Failed to fetch checksums for {}: {}",
+        package.display_name,
+        errors.join("; ")
+    )))
+}
+
+/// Looks up `file_name` in a `SHASUMS256.txt`-style list
+/// (`<hex digest>  <file name>` per line).
+fn find_checksum(checksums: &str, file_name: &str) -> Option<String> {
+    checksums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let digest = parts.next()?;
+        let name = parts.next()?.trim_start_matches('*');
+        (name == file_name && digest.len() == 64).then(|| digest.to_ascii_lowercase())
+    })
+}
+
+async fn download_package<F>(
+    client: &reqwest::Client,
+    package: &RuntimePackage,
+    archive_path: &Path,
+    expected_hash: &str,
+    throttle: &DownloadThrottle,
+    cancel: &CancellationToken,
+    progress: &mut ProgressTracker<'_, F>,
+) -> BitFunResult<()>
+where
+    F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+{
+    let mut errors = Vec::new();
+    for url in package.urls(&package.file_name) {
+        if cancel.is_cancelled() {
+            return Err(cancelled_error(package));
+        }
+        progress.emit(package, RuntimeProvisioningPhase::Downloading, 0, None);
+        let download = ResumableDownload {
+            client,
+            url: &url,
+            path: archive_path,
+            throttle,
+            cancel,
+        };
+        match download_source(download, package, progress).await {
+            Ok(actual_hash) if actual_hash == expected_hash => return Ok(()),
+            Ok(actual_hash) => {
+                discard_partial(archive_path).await;
+                errors.push(format!(
+                    "checksum mismatch from {}: expected={}, actual={}",
+                    url, expected_hash, actual_hash
+                ))
+            }
+            Err(_) if cancel.is_cancelled() => return Err(cancelled_error(package)),
+            Err(error) => errors.push(error.to_string()),
+        }
+    }
+    Err(BitFunError::http(format!(
+        "{} download failed from all {} sources: {}",
+        package.display_name,
+        package.base_urls.len(),
+        errors.join("; ")
+    )))
+}
+
+/// Downloads one source, continuing a partial archive from an earlier
+/// attempt, and returns the archive's SHA-256.
+async fn download_source<F>(
+    download: ResumableDownload<'_>,
+    package: &RuntimePackage,
+    progress: &mut ProgressTracker<'_, F>,
+) -> BitFunResult<String>
+where
+    F: Fn(RuntimeProvisioningProgress) + Send + Sync,
+{
+    let cancel = download.cancel;
+    let mut size_known = false;
+    let downloaded = download_resumable(download, |downloaded, total| {
+        if let Some(total) = total.filter(|_| !size_known) {
+            progress.set_package_size(package, total);
+            size_known = true;
+        }
+        progress.emit(
+            package,
+            RuntimeProvisioningPhase::Downloading,
+            downloaded,
+            None,
+        );
+    })
+    .await
+    .map_err(|error| match error {
+        _ if cancel.is_cancelled() => cancelled_error(package),
+        error => error,
+    })?;
+    progress.completed_bytes = progress
+        .completed_bytes
+        .saturating_add(downloaded.size_bytes);
+    Ok(downloaded.sha256)
+}
+
+fn cancelled_error(package: &RuntimePackage) -> BitFunError {
+    BitFunError::cancelled(format!("{} installation cancelled", package.display_name))
+}
+
+/// Unpacks into a staging directory next to `current` and swaps it in, so
+/// a failed extraction never leaves a half-written runtime behind.
+async fn install_archive(
+    package: &RuntimePackage,
+    archive_path: &Path,
+    component_dir: &Path,
+) -> BitFunResult<()> {
+    fs::create_dir_all(component_dir).await?;
+    let staging = component_dir.join(format!(".installing-{}", Uuid::new_v4().simple()));
+    fs::create_dir_all(&staging).await?;
+
+    let archive = package.archive;
+    let archive_for_extract = archive_path.to_path_buf();
+    let staging_for_extract = staging.clone();
+    let extracted = tokio::task::spawn_blocking(move || match archive {
+        RuntimeArchiveKind::Zip => extract_zip(&archive_for_extract, &staging_for_extract),
+        RuntimeArchiveKind::TarGz => extract_tar_gz(&archive_for_extract, &staging_for_extract),
+    })
+    .await
+    .map_err(|error| BitFunError::service(format!("Runtime extraction task failed: {error}")))
+    .and_then(|result| result);
+
+    let swapped = match extracted {
+        Ok(()) => swap_into_current(&staging, component_dir).await,
+        Err(error) => Err(error),
+    };
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging).await;
+    }
+    swapped
+}
+
+async fn swap_into_current(staging: &Path, component_dir: &Path) -> BitFunResult<()> {
+    let payload = single_top_level_dir(staging)
+        .await?
+        .unwrap_or_else(|| staging.to_path_buf());
+    let current = component_dir.join("current");
+    if current.exists() {
+        fs::remove_dir_all(&current).await?;
+    }
+    fs::rename(&payload, &current).await?;
+    Ok(())
+}
+
+/// Release archives usually wrap everything in one versioned directory.
+async fn single_top_level_dir(dir: &Path) -> BitFunResult<Option<PathBuf>> {
+    let mut entries = fs::read_dir(dir).await?;
+    let mut only = None;
+    while let Some(entry) = entries.next_entry().await? {
+        if only.is_some() || !entry.file_type().await?.is_dir() {
+            return Ok(None);
+        }
+        only = Some(entry.path());
+    }
+    Ok(only)
+}
+
+fn extract_tar_gz(archive_path: &Path, destination: &Path) -> BitFunResult<()> {
+    let decoder = flate2::read::GzDecoder::new(File::open(archive_path)?);
+    let mut archive = tar::Archive::new(decoder);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        // `unpack_in` refuses entries that would escape `destination`.
+        if !entry.unpack_in(destination)? {
+            return Err(BitFunError::validation(format!(
+                "Runtime archive contains an unsafe path: {}",
+                entry.path()?.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn extract_zip(archive_path: &Path, destination: &Path) -> BitFunResult<()> {
+    let mut archive = zip::ZipArchive::new(File::open(archive_path)?)
+        .map_err(|error| BitFunError::validation(error.to_string()))?;
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|error| BitFunError::validation(error.to_string()))?;
+        let relative = entry.enclosed_name().ok_or_else(|| {
+            BitFunError::validation(format!(
+                "Runtime archive contains an unsafe path: {}",
+                entry.name()
+            ))
+        })?;
+        let target = destination.join(relative);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut output = File::create(&target)?;
+        std::io::copy(&mut entry, &mut output)?;
+        #[cfg(unix)]
+        if let Some(mode) = entry.unix_mode() {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&target, std::fs::Permissions::from_mode(mode))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(component: &'static str, commands: &'static [&'static str]) -> RuntimePackage {
+        RuntimePackage {
+            component,
+            display_name: component,
+            version: "1.0.0",
+            commands,
+            file_name: format!("{component}.zip"),
+            base_urls: vec!["https://example.invalid".to_string()],
+            checksums_file: "SHASUMS256.txt",
+            archive: RuntimeArchiveKind::Zip,
+            estimated_size_bytes: 1,
+        }
+    }
+
+    #[test]
+    fn groups_missing_commands_by_package() {
+        let packages = vec![package("node", &["node", "npm", "npx"])];
+        let missing = ["npm", "pandoc", "node"].map(String::from);
+
+        let (grouped, unavailable) = group_missing_commands(&missing, &packages);
+
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].0.component, "node");
+        assert_eq!(grouped[0].1, vec!["npm", "node"]);
+        assert_eq!(unavailable, vec!["pandoc"]);
+    }
+
+    #[test]
+    fn finds_checksum_for_exact_file_name() {
+        let digest = "a".repeat(64);
+        let checksums = format!(
+            "{}  node-v1-linux-x64.tar.gz.sig\n{}  node-v1-linux-x64.tar.gz\n",
+            "b".repeat(64),
+            digest.to_uppercase()
+        );
+
+        assert_eq!(
+            find_checksum(&checksums, "node-v1-linux-x64.tar.gz"),
+            Some(digest)
+        );
+        assert_eq!(find_checksum(&checksums, "node-v1-win-x64.zip"), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Splits text files into overlapping line windows for embedding.
+
+/// Lines per chunk; small enough that a hit points at one function or
+/// section rather than a whole file.
+const CHUNK_LINES: usize = 60;
+/// Lines shared with the previous chunk so definitions cut at a boundary
+/// still appear whole in one of them.
+const CHUNK_OVERLAP_LINES: usize = 10;
+/// Keeps minified or generated lines from blowing the embedding input limit.
+const MAX_CHUNK_CHARS: usize = 4_000;
+/// Bytes inspected when deciding whether a file is text.
+const BINARY_SNIFF_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TextChunk {
+    /// 1-based, inclusive.
+    pub start_line: usize,
+    pub end_line: usize,
+    pub content: String,
+}
+
+/// Files with a NUL byte near the start are treated as binary.
+pub(crate) fn looks_like_text(bytes: &[u8]) -> bool {
+    !bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0)
+}
+
+pub(crate) fn chunk_text(text: &str) -> Vec<TextChunk> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < lines.len() {
+        let mut end = start;
+        let mut chars = 0;
+        while end < lines.len() && end - start < CHUNK_LINES {
+            let line_chars = lines[end].chars().count() + 1;
+            if end > start && chars + line_chars > MAX_CHUNK_CHARS {
+                break;
+            }
+            chars += line_chars;
+            end += 1;
+        }
+
+        let content = lines[start..end].join("\n");
+        let content = match content.char_indices().nth(MAX_CHUNK_CHARS) {
+            Some((cut, _)) => content[..cut].to_string(),
+            None => content,
+        };
+        if !content.trim().is_empty() {
+            chunks.push(TextChunk {
+                start_line: start + 1,
+                end_line: end,
+                content,
+            });
+        }
+
+        if end >= lines.len() {
+            break;
+        }
+        start = end.saturating_sub(CHUNK_OVERLAP_LINES).max(start + 1);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_overlap_and_cover_every_line() {
+        let text = (1..=130)
+            .map(|n| format!("line {}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let chunks = chunk_text(&text);
+        let ranges: Vec<_> = chunks.iter().map(|c| (c.start_line, c.end_line)).collect();
+
+        assert_eq!(ranges, vec![(1, 60), (51, 110), (101, 130)]);
+        assert!(chunks[0].content.starts_with("line 1\n"));
+        assert!(chunks[2].content.ends_with("line 130"));
+    }
+
+    #[test]
+    fn long_lines_are_split_and_blank_windows_dropped() {
+        let long_line = "x".repeat(MAX_CHUNK_CHARS * 2);
+        let text = format!("{}\nshort\n\n\n", long_line);
+
+        let chunks = chunk_text(&text);
+
+        assert_eq!(chunks[0].start_line, 1);
+        assert_eq!(chunks[0].content.chars().count(), MAX_CHUNK_CHARS);
+        assert!(chunks.iter().all(|chunk| !chunk.content.trim().is_empty()));
+        assert!(chunk_text("\n\n  \n").is_empty());
+    }
+
+    #[test]
+    fn nul_bytes_mark_binary_files() {
+        assert!(looks_like_text(b"fn main() {}"));
+        assert!(!looks_like_text(b"\x89PNG\r\n\x1a\n\0\0"));
+    }
+}
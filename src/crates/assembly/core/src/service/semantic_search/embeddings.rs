@@ -0,0 +1,200 @@
+//! Embeddings client.
+//!
+//! Calls the OpenAI-compatible `/embeddings` endpoint of the model selected
+//! in `ai.default_models.embedding`, falling back to the first enabled model
+//! with the embedding capability.
+
+use crate::service::config::{get_global_config_service, AIModelConfig, GlobalConfig};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+/// Inputs per request; providers cap batch size and total tokens.
+const BATCH_SIZE: usize = 32;
+
+/// Turns text into vectors. Vectors from different `model_id`s live in
+/// different spaces and are never compared.
+#[async_trait]
+pub trait Embedder: Send + Sync {
+    fn model_id(&self) -> &str;
+
+    /// Returns one vector per input, in input order.
+    async fn embed(&self, inputs: &[String]) -> BitFunResult<Vec<Vec<f32>>>;
+}
+
+pub struct EmbeddingClient {
+    http: reqwest::Client,
+    model_id: String,
+    url: String,
+    model: AIModelConfig,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingData {
+    #[serde(default)]
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+impl EmbeddingClient {
+    pub fn new(model: AIModelConfig) -> BitFunResult<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .danger_accept_invalid_certs(model.skip_ssl_verify)
+            .build()
+            .map_err(|e| {
+                BitFunError::service(format!("Failed to create embeddings client: {}", e))
+            })?;
+        Ok(Self {
+            http,
+            // Re-embed when the same entry is pointed at a different model.
+            model_id: format!("{}:{}", model.id, model.model_name),
+            url: embeddings_url(&model.base_url, model.request_url.as_deref()),
+            model,
+        })
+    }
+
+    /// Builds a client for the configured embedding model.
+    pub async fn from_config() -> BitFunResult<Self> {
+        let config: GlobalConfig = get_global_config_service().await?.get_config(None).await?;
+        let models = &config.ai.models;
+        let selected = config
+            .ai
+            .default_models
+            .embedding
+            .as_deref()
+            .and_then(|id| models.iter().find(|model| model.id == id && model.enabled))
+            .or_else(|| {
+                models
+                    .iter()
+                    .find(|model| model.enabled && model.supports_embedding())
+            })
+            .ok_or_else(|| {
+                BitFunError::validation(
+                    "No embedding model is configured; add a model with the embedding capability in AI model settings",
+                )
+            })?;
+        Self::new(selected.clone())
+    }
+
+    async fn embed_batch(&self, inputs: &[String]) -> BitFunResult<Vec<Vec<f32>>> {
+        let mut request = self.http.post(&self.url).json(&json!({
+            "model": self.model.model_name,
+            "input": inputs,
+        }));
+
+        // Same header policy as chat requests: custom headers are merged over
+        // the defaults unless the model asks to replace them.
+        let custom_headers = self
+            .model
+            .custom_headers
+            .as_ref()
+            .filter(|headers| !headers.is_empty());
+        let replace_defaults = custom_headers.is_some()
+            && self.model.custom_headers_mode.as_deref() == Some("replace");
+        if !replace_defaults && !self.model.api_key.is_empty() {
+            request = request.bearer_auth(&self.model.api_key);
+        }
+        for (key, value) in custom_headers.into_iter().flatten() {
+            request = request.header(key.as_str(), value.as_str());
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| BitFunError::service(format!("Embeddings request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(BitFunError::service(format!(
+                "Embeddings request failed with HTTP {}: {}",
+                status,
+                body.chars().take(500).collect::<String>()
+            )));
+        }
+
+        let mut parsed: EmbeddingResponse = response
+            .json()
+            .await
+            .map_err(|e| BitFunError::service(format!("Invalid embeddings response: {}", e)))?;
+        if parsed.data.len() != inputs.len() {
+            return Err(BitFunError::service(format!(
+                "Embeddings response has {} vectors for {} inputs",
+                parsed.data.len(),
+                inputs.len()
+            )));
+        }
+        parsed.data.sort_by_key(|item| item.index);
+        Ok(parsed.data.into_iter().map(|item| item.embedding).collect())
+    }
+}
+
+#[async_trait]
+impl Embedder for EmbeddingClient {
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn embed(&self, inputs: &[String]) -> BitFunResult<Vec<Vec<f32>>> {
+        let mut vectors = Vec::with_capacity(inputs.len());
+        for batch in inputs.chunks(BATCH_SIZE) {
+            vectors.extend(self.embed_batch(batch).await?);
+        }
+        Ok(vectors)
+    }
+}
+
+/// Accepts either an API base (`https://host/v1`) or a full endpoint URL.
+fn embeddings_url(base_url: &str, request_url: Option<&str>) -> String {
+    if let Some(url) = request_url
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| url.ends_with("/embeddings"))
+    {
+        return url.to_string();
+    }
+    let base = base_url.trim().trim_end_matches('/');
+    if base.ends_with("/embeddings") {
+        base.to_string()
+    } else {
+        format!("{}/embeddings", base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embeddings_url_accepts_base_or_endpoint() {
+        assert_eq!(
+            embeddings_url("https://api.example.com/v1/", None),
+            "https://api.example.com/v1/embeddings"
+        );
+        assert_eq!(
+            embeddings_url("https://api.example.com/v1/embeddings", None),
+            "https://api.example.com/v1/embeddings"
+        );
+        assert_eq!(
+            embeddings_url(
+                "https://api.example.com/v1",
+                Some("https://gateway.example.com/embed/v1/embeddings")
+            ),
+            "https://gateway.example.com/embed/v1/embeddings"
+        );
+        assert_eq!(
+            embeddings_url(
+                "https://api.example.com/v1",
+                Some("https://api.example.com/v1/chat/completions")
+            ),
+            "https://api.example.com/v1/embeddings"
+        );
+    }
+}
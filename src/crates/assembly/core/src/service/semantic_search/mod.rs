@@ -0,0 +1,16 @@
+//! Semantic search service
+//!
+//! Embeds workspace files in overlapping line chunks and retrieves the
+//! chunks closest in meaning to a query. The index lives in the shared
+//! SQLite database and follows file changes through the file watcher.
+
+mod chunker;
+mod embeddings;
+mod service;
+mod types;
+
+pub use embeddings::{Embedder, EmbeddingClient};
+pub use service::{get_semantic_search_service, SemanticSearchService};
+pub use types::{
+    SemanticIndexReport, SemanticIndexStatus, SemanticSearchHit, SemanticSearchResult,
+};
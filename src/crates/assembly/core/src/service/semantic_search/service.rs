@@ -0,0 +1,1125 @@
+//! Semantic search service.
+//!
+//! Chunks workspace text files, embeds the chunks and stores the vectors in
+//! the shared SQLite database. The first search in a workspace scans it and
+//! re-embeds only files whose size, modification time or embedding model
+//! changed; after that the file watcher queues changed paths and a debounced
+//! task re-embeds just those. Ranking is an exact cosine scan over an
+//! in-memory copy of the vectors, which stays fast at the limits below.
+
+use super::chunker::{chunk_text, looks_like_text, TextChunk};
+use super::embeddings::{Embedder, EmbeddingClient};
+use super::types::{
+    SemanticIndexReport, SemanticIndexStatus, SemanticSearchHit, SemanticSearchResult,
+};
+use crate::infrastructure::storage::{get_global_sqlite_storage, SqliteMigration, SqliteStorage};
+use crate::service::file_watch::{
+    get_global_file_watch_service, FileWatchEvent, FileWatchEventKind,
+};
+use crate::service::power::{wait_for_background_allowance, BackgroundWork};
+use crate::util::errors::{BitFunError, BitFunResult};
+use ignore::gitignore::Gitignore;
+use ignore::WalkBuilder;
+use log::{debug, info, warn};
+use rusqlite::{params, OptionalExtension};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, UNIX_EPOCH};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Mutex, OnceCell, RwLock};
+
+const STORAGE_COMPONENT: &str = "semantic_search";
+const MIGRATIONS: &[SqliteMigration] = &[SqliteMigration {
+    version: 1,
+    description: "semantic search files and chunk vectors",
+    sql: r#"
+CREATE TABLE semantic_files (
+    workspace TEXT NOT NULL,
+    path TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    modified_ms INTEGER NOT NULL,
+    model_id TEXT NOT NULL,
+    PRIMARY KEY (workspace, path)
+);
+CREATE TABLE semantic_chunks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    workspace TEXT NOT NULL,
+    path TEXT NOT NULL,
+    start_line INTEGER NOT NULL,
+    end_line INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    vector BLOB NOT NULL
+);
+CREATE INDEX semantic_chunks_file ON semantic_chunks(workspace, path);
+"#,
+}];
+
+/// Generated bundles and data dumps cost many embeddings and rarely answer a
+/// question about the code.
+const MAX_FILE_BYTES: u64 = 512 * 1024;
+const MAX_INDEXED_FILES: usize = 5_000;
+/// Further stale files are left for the next update.
+const MAX_CHUNKS_PER_UPDATE: usize = 20_000;
+/// Chunks embedded and committed together, so a failed request loses at
+/// most one group of work.
+const EMBED_GROUP_CHUNKS: usize = 128;
+const DEFAULT_RESULT_LIMIT: usize = 10;
+const MAX_RESULT_LIMIT: usize = 50;
+/// Editors save in bursts; wait for them to settle before re-embedding.
+const UPDATE_DEBOUNCE: Duration = Duration::from_secs(2);
+
+static SEMANTIC_SEARCH_SERVICE: OnceCell<Arc<SemanticSearchService>> = OnceCell::const_new();
+
+/// Shared service on the user-level database, watching for file changes.
+pub async fn get_semantic_search_service() -> BitFunResult<Arc<SemanticSearchService>> {
+    SEMANTIC_SEARCH_SERVICE
+        .get_or_try_init(|| async {
+            let service = Arc::new(SemanticSearchService::new(get_global_sqlite_storage()?).await?);
+            service.start_watching();
+            Ok::<_, BitFunError>(service)
+        })
+        .await
+        .cloned()
+}
+
+pub struct SemanticSearchService {
+    storage: Arc<SqliteStorage>,
+    workspaces: std::sync::Mutex<HashMap<PathBuf, Arc<WorkspaceState>>>,
+    watching: AtomicBool,
+}
+
+#[derive(Default)]
+struct WorkspaceState {
+    /// Serializes index updates for the workspace.
+    update_lock: Mutex<()>,
+    /// Workspace-relative paths changed since the last update.
+    pending: std::sync::Mutex<HashSet<String>>,
+    /// Model of the last full scan in this process; `None` until one ran or
+    /// after watcher events were lost.
+    scanned_model: std::sync::Mutex<Option<String>>,
+    vectors: RwLock<Option<Arc<VectorCache>>>,
+    indexing: AtomicBool,
+    update_scheduled: AtomicBool,
+}
+
+impl WorkspaceState {
+    fn scanned_model(&self) -> Option<String> {
+        self.scanned_model
+            .lock()
+            .ok()
+            .and_then(|model| model.clone())
+    }
+
+    fn set_scanned_model(&self, model_id: Option<String>) {
+        if let Ok(mut scanned) = self.scanned_model.lock() {
+            *scanned = model_id;
+        }
+    }
+
+    fn take_pending(&self) -> Vec<String> {
+        self.pending
+            .lock()
+            .map(|mut pending| pending.drain().collect())
+            .unwrap_or_default()
+    }
+
+    fn requeue(&self, paths: Vec<String>) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.extend(paths);
+        }
+    }
+}
+
+/// Normalized vectors of one model, keyed by chunk id.
+struct VectorCache {
+    model_id: String,
+    chunks: Vec<(i64, Vec<f32>)>,
+}
+
+struct FileStamp {
+    size: u64,
+    modified_ms: i64,
+    model_id: String,
+}
+
+/// A file eligible for the index, as found on disk.
+struct Candidate {
+    relative: String,
+    absolute: PathBuf,
+    size: u64,
+    modified_ms: i64,
+}
+
+impl SemanticSearchService {
+    pub async fn new(storage: Arc<SqliteStorage>) -> BitFunResult<Self> {
+        storage.migrate(STORAGE_COMPONENT, MIGRATIONS).await?;
+        Ok(Self {
+            storage,
+            workspaces: std::sync::Mutex::new(HashMap::new()),
+            watching: AtomicBool::new(false),
+        })
+    }
+
+    /// Returns the `k` workspace chunks closest in meaning to `query`,
+    /// embedding new or changed files first.
+    pub async fn semantic_search(
+        &self,
+        workspace_root: &Path,
+        query: &str,
+        k: usize,
+    ) -> BitFunResult<SemanticSearchResult> {
+        let embedder = EmbeddingClient::from_config().await?;
+        self.search_with(&embedder, workspace_root, query, k).await
+    }
+
+    /// Like [`Self::semantic_search`] but never embeds files inline, for
+    /// callers on the prompt path. Returns `None` while the workspace has no
+    /// vectors; an index build is started in the background then.
+    pub async fn semantic_search_indexed(
+        self: &Arc<Self>,
+        workspace_root: &Path,
+        query: &str,
+        k: usize,
+    ) -> BitFunResult<Option<SemanticSearchResult>> {
+        let query = validate_query(query)?;
+        let embedder = EmbeddingClient::from_config().await?;
+        let (root, state) = self.workspace_state(workspace_root)?;
+        if state.scanned_model().as_deref() != Some(embedder.model_id()) {
+            self.schedule_update(root.clone(), state.clone(), Duration::ZERO);
+        }
+
+        let cache = self.vector_cache(&embedder, &root, &state).await?;
+        if cache.chunks.is_empty() {
+            return Ok(None);
+        }
+        let hits = self.rank(&embedder, &root, &cache, query, k).await?;
+        Ok(Some(SemanticSearchResult {
+            hits,
+            chunks_searched: cache.chunks.len(),
+            files_embedded: 0,
+        }))
+    }
+
+    /// Rescans the whole workspace and embeds anything new or changed.
+    pub async fn index_workspace(
+        &self,
+        workspace_root: &Path,
+    ) -> BitFunResult<SemanticIndexReport> {
+        let embedder = EmbeddingClient::from_config().await?;
+        let (root, state) = self.workspace_state(workspace_root)?;
+        self.update(&embedder, &root, &state, true).await
+    }
+
+    pub async fn status(&self, workspace_root: &Path) -> BitFunResult<SemanticIndexStatus> {
+        let (root, state) = self.workspace_state(workspace_root)?;
+        let workspace = workspace_key(&root);
+        let (model_id, files_indexed, chunks_indexed) = self
+            .storage
+            .with_connection(move |connection| {
+                let model_id = connection
+                    .query_row(
+                        "SELECT model_id FROM semantic_files WHERE workspace = ?1
+                         GROUP BY model_id ORDER BY COUNT(*) DESC LIMIT 1",
+                        params![workspace],
+                        |row| row.get::<_, String>(0),
+                    )
+                    .optional()
+                    .map_err(db_error)?;
+                let files = connection
+                    .query_row(
+                        "SELECT COUNT(*) FROM semantic_files WHERE workspace = ?1",
+                        params![workspace],
+                        |row| row.get::<_, i64>(0),
+                    )
+                    .map_err(db_error)?;
+                let chunks = connection
+                    .query_row(
+                        "SELECT COUNT(*) FROM semantic_chunks WHERE workspace = ?1",
+                        params![workspace],
+                        |row| row.get::<_, i64>(0),
+                    )
+                    .map_err(db_error)?;
+                Ok((model_id, files as usize, chunks as usize))
+            })
+            .await?;
+
+        Ok(SemanticIndexStatus {
+            workspace_path: root.to_string_lossy().to_string(),
+            model_id,
+            files_indexed,
+            chunks_indexed,
+            pending_files: state.pending.lock().map(|p| p.len()).unwrap_or(0),
+            indexing: state.indexing.load(Ordering::SeqCst),
+        })
+    }
+
+    /// Deletes the stored index of a workspace.
+    pub async fn clear(&self, workspace_root: &Path) -> BitFunResult<()> {
+        let (root, state) = self.workspace_state(workspace_root)?;
+        let _guard = state.update_lock.lock().await;
+        let workspace = workspace_key(&root);
+        self.storage
+            .with_transaction(move |transaction| {
+                transaction
+                    .execute(
+                        "DELETE FROM semantic_chunks WHERE workspace = ?1",
+                        params![workspace],
+                    )
+                    .map_err(db_error)?;
+                transaction
+                    .execute(
+                        "DELETE FROM semantic_files WHERE workspace = ?1",
+                        params![workspace],
+                    )
+                    .map_err(db_error)?;
+                Ok(())
+            })
+            .await?;
+        state.take_pending();
+        state.set_scanned_model(None);
+        *state.vectors.write().await = None;
+        Ok(())
+    }
+
+    pub(crate) async fn search_with(
+        &self,
+        embedder: &dyn Embedder,
+        workspace_root: &Path,
+        query: &str,
+        k: usize,
+    ) -> BitFunResult<SemanticSearchResult> {
+        let query = validate_query(query)?;
+        let (root, state) = self.workspace_state(workspace_root)?;
+        let full = state.scanned_model().as_deref() != Some(embedder.model_id());
+        let report = self.update(embedder, &root, &state, full).await?;
+
+        let cache = self.vector_cache(embedder, &root, &state).await?;
+        let hits = if cache.chunks.is_empty() {
+            Vec::new()
+        } else {
+            self.rank(embedder, &root, &cache, query, k).await?
+        };
+        Ok(SemanticSearchResult {
+            hits,
+            chunks_searched: cache.chunks.len(),
+            files_embedded: report.files_embedded,
+        })
+    }
+
+    /// Queues watched changes for every workspace that has been scanned.
+    fn start_watching(self: &Arc<Self>) {
+        if self.watching.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let mut receiver = get_global_file_watch_service().subscribe();
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(events) => service.queue_changes(&events),
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(
+                            "Semantic search missed {} file watch batches; rescanning on next search",
+                            skipped
+                        );
+                        for (_, state) in service.workspace_states() {
+                            state.set_scanned_model(None);
+                        }
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    fn queue_changes(self: &Arc<Self>, events: &[FileWatchEvent]) {
+        for (root, state) in self.workspace_states() {
+            // The first scan picks up anything that changed before it.
+            if state.scanned_model().is_none() {
+                continue;
+            }
+            let mut queued = false;
+            if let Ok(mut pending) = state.pending.lock() {
+                for event in events {
+                    let paths = match &event.kind {
+                        FileWatchEventKind::Rename { from, to } => vec![from, to],
+                        _ => vec![&event.path],
+                    };
+                    for path in paths {
+                        if let Ok(relative) = Path::new(path).strip_prefix(&root) {
+                            if !relative.as_os_str().is_empty() {
+                                pending.insert(relative_path(relative));
+                                queued = true;
+                            }
+                        }
+                    }
+                }
+            }
+            if queued {
+                self.schedule_update(root, state, UPDATE_DEBOUNCE);
+            }
+        }
+    }
+
+    fn schedule_update(
+        self: &Arc<Self>,
+        root: PathBuf,
+        state: Arc<WorkspaceState>,
+        delay: Duration,
+    ) {
+        if state.update_scheduled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            // Changes keep queuing while paused and are embedded together.
+            wait_for_background_allowance(BackgroundWork::Indexing).await;
+            state.update_scheduled.store(false, Ordering::SeqCst);
+            let embedder = match EmbeddingClient::from_config().await {
+                Ok(embedder) => embedder,
+                Err(e) => {
+                    debug!("Semantic index update skipped: {}", e);
+                    return;
+                }
+            };
+            let full = state.scanned_model().as_deref() != Some(embedder.model_id());
+            if let Err(e) = service.update(&embedder, &root, &state, full).await {
+                warn!(
+                    "Semantic index update failed: root={}, {}",
+                    root.display(),
+                    e
+                );
+            }
+        });
+    }
+
+    /// Brings the stored index in line with the files on disk: every file on
+    /// a full scan, otherwise only the queued paths.
+    async fn update(
+        &self,
+        embedder: &dyn Embedder,
+        root: &Path,
+        state: &WorkspaceState,
+        full: bool,
+    ) -> BitFunResult<SemanticIndexReport> {
+        let _guard = state.update_lock.lock().await;
+        state.indexing.store(true, Ordering::SeqCst);
+        let pending = state.take_pending();
+        let result = self
+            .update_locked(embedder, root, state, full, pending.clone())
+            .await;
+        state.indexing.store(false, Ordering::SeqCst);
+        if result.is_err() && !full {
+            state.requeue(pending);
+        }
+        result
+    }
+
+    async fn update_locked(
+        &self,
+        embedder: &dyn Embedder,
+        root: &Path,
+        state: &WorkspaceState,
+        full: bool,
+        pending: Vec<String>,
+    ) -> BitFunResult<SemanticIndexReport> {
+        let started = Instant::now();
+        let workspace = workspace_key(root);
+        let model_id = embedder.model_id().to_string();
+        let known = self.load_file_stamps(&workspace).await?;
+
+        let scan_root = root.to_path_buf();
+        let (candidates, gone, truncated) = if full {
+            tokio::task::spawn_blocking(move || {
+                let (candidates, truncated) = scan_workspace(&scan_root);
+                (candidates, Vec::new(), truncated)
+            })
+            .await
+        } else if pending.is_empty() {
+            Ok((Vec::new(), Vec::new(), false))
+        } else {
+            tokio::task::spawn_blocking(move || {
+                let (candidates, gone) = inspect_paths(&scan_root, pending);
+                (candidates, gone, false)
+            })
+            .await
+        }
+        .map_err(|e| BitFunError::service(format!("Semantic index scan failed: {}", e)))?;
+
+        let removed: Vec<String> = if full {
+            let seen: HashSet<&str> = candidates.iter().map(|c| c.relative.as_str()).collect();
+            known
+                .keys()
+                .filter(|path| !seen.contains(path.as_str()))
+                .cloned()
+                .collect()
+        } else {
+            gone.into_iter()
+                .filter(|path| known.contains_key(path))
+                .collect()
+        };
+
+        let mut report = SemanticIndexReport {
+            files_scanned: candidates.len(),
+            truncated,
+            ..Default::default()
+        };
+        let mut room_for_new = MAX_INDEXED_FILES.saturating_sub(known.len());
+        let stale = candidates.into_iter().filter(|candidate| {
+            match known.get(&candidate.relative) {
+                Some(stamp) => {
+                    stamp.size != candidate.size
+                        || stamp.modified_ms != candidate.modified_ms
+                        || stamp.model_id != model_id
+                }
+                // A full scan is capped while walking; queued paths are not.
+                None if full => true,
+                None if room_for_new > 0 => {
+                    room_for_new -= 1;
+                    true
+                }
+                None => false,
+            }
+        });
+
+        let mut group: Vec<(Candidate, Vec<TextChunk>)> = Vec::new();
+        let mut group_chunks = 0;
+        for candidate in stale {
+            if report.chunks_embedded + group_chunks >= MAX_CHUNKS_PER_UPDATE {
+                report.truncated = true;
+                break;
+            }
+            let chunks = match tokio::fs::read(&candidate.absolute).await {
+                Ok(bytes) => prepare_chunks(&bytes),
+                Err(e) => {
+                    debug!(
+                        "Skipping file in semantic index: path={}, {}",
+                        candidate.relative, e
+                    );
+                    continue;
+                }
+            };
+            group_chunks += chunks.len();
+            group.push((candidate, chunks));
+            if group_chunks >= EMBED_GROUP_CHUNKS {
+                self.embed_and_store(
+                    embedder,
+                    &workspace,
+                    std::mem::take(&mut group),
+                    &mut report,
+                )
+                .await?;
+                group_chunks = 0;
+            }
+        }
+        if !group.is_empty() {
+            self.embed_and_store(embedder, &workspace, group, &mut report)
+                .await?;
+        }
+
+        if !removed.is_empty() {
+            report.files_removed = removed.len();
+            let workspace = workspace.clone();
+            self.storage
+                .with_transaction(move |transaction| {
+                    for path in &removed {
+                        transaction
+                            .execute(
+                                "DELETE FROM semantic_chunks WHERE workspace = ?1 AND path = ?2",
+                                params![workspace, path],
+                            )
+                            .map_err(db_error)?;
+                        transaction
+                            .execute(
+                                "DELETE FROM semantic_files WHERE workspace = ?1 AND path = ?2",
+                                params![workspace, path],
+                            )
+                            .map_err(db_error)?;
+                    }
+                    Ok(())
+                })
+                .await?;
+        }
+
+        if full {
+            state.set_scanned_model(Some(model_id));
+        }
+        if report.files_embedded > 0 || report.files_removed > 0 {
+            *state.vectors.write().await = None;
+        }
+        report.elapsed_ms = started.elapsed().as_millis() as u64;
+        if report.files_embedded > 0 || report.files_removed > 0 {
+            info!(
+                "Semantic index updated: root={}, embedded={}, chunks={}, removed={}, truncated={}, elapsed_ms={}",
+                root.display(),
+                report.files_embedded,
+                report.chunks_embedded,
+                report.files_removed,
+                report.truncated,
+                report.elapsed_ms
+            );
+        }
+        Ok(report)
+    }
+
+    async fn embed_and_store(
+        &self,
+        embedder: &dyn Embedder,
+        workspace: &str,
+        group: Vec<(Candidate, Vec<TextChunk>)>,
+        report: &mut SemanticIndexReport,
+    ) -> BitFunResult<()> {
+        let inputs: Vec<String> = group
+            .iter()
+            .flat_map(|(candidate, chunks)| {
+                chunks
+                    .iter()
+                    .map(|chunk| embedding_input(&candidate.relative, chunk))
+            })
+            .collect();
+        let vectors = if inputs.is_empty() {
+            Vec::new()
+        } else {
+            embedder.embed(&inputs).await?
+        };
+        if vectors.len() != inputs.len() {
+            return Err(BitFunError::service(format!(
+                "Embedder returned {} vectors for {} chunks",
+                vectors.len(),
+                inputs.len()
+            )));
+        }
+        report.files_embedded += group.len();
+        report.chunks_embedded += inputs.len();
+
+        let workspace = workspace.to_string();
+        let model_id = embedder.model_id().to_string();
+        self.storage
+            .with_transaction(move |transaction| {
+                let mut vectors = vectors.into_iter();
+                for (candidate, chunks) in &group {
+                    transaction
+                        .execute(
+                            "DELETE FROM semantic_chunks WHERE workspace = ?1 AND path = ?2",
+                            params![workspace, candidate.relative],
+                        )
+                        .map_err(db_error)?;
+                    for (chunk, vector) in chunks.iter().zip(vectors.by_ref()) {
+                        transaction
+                            .execute(
+                                "INSERT INTO semantic_chunks
+                                     (workspace, path, start_line, end_line, content, vector)
+                                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                                params![
+                                    workspace,
+                                    candidate.relative,
+                                    chunk.start_line as i64,
+                                    chunk.end_line as i64,
+                                    chunk.content,
+                                    encode_vector(&normalize(vector)),
+                                ],
+                            )
+                            .map_err(db_error)?;
+                    }
+                    transaction
+                        .execute(
+                            "INSERT INTO semantic_files
+                                 (workspace, path, size_bytes, modified_ms, model_id)
+                             VALUES (?1, ?2, ?3, ?4, ?5)
+                             ON CONFLICT(workspace, path) DO UPDATE SET
+                                 size_bytes = excluded.size_bytes,
+                                 modified_ms = excluded.modified_ms,
+                                 model_id = excluded.model_id",
+                            params![
+                                workspace,
+                                candidate.relative,
+                                candidate.size as i64,
+                                candidate.modified_ms,
+                                model_id,
+                            ],
+                        )
+                        .map_err(db_error)?;
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    async fn rank(
+        &self,
+        embedder: &dyn Embedder,
+        root: &Path,
+        cache: &VectorCache,
+        query: &str,
+        k: usize,
+    ) -> BitFunResult<Vec<SemanticSearchHit>> {
+        let query_vector = embedder
+            .embed(&[query.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .map(normalize)
+            .ok_or_else(|| BitFunError::service("Embedder returned no vector for the query"))?;
+
+        let limit = if k == 0 {
+            DEFAULT_RESULT_LIMIT
+        } else {
+            k.min(MAX_RESULT_LIMIT)
+        };
+        let mut scored: Vec<(i64, f32)> = cache
+            .chunks
+            .iter()
+            .filter(|(_, vector)| vector.len() == query_vector.len())
+            .map(|(id, vector)| (*id, dot(vector, &query_vector)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.truncate(limit);
+
+        let workspace = workspace_key(root);
+        self.storage
+            .with_connection(move |connection| {
+                let mut statement = connection
+                    .prepare(
+                        "SELECT path, start_line, end_line, content FROM semantic_chunks
+                         WHERE workspace = ?1 AND id = ?2",
+                    )
+                    .map_err(db_error)?;
+                let mut hits = Vec::with_capacity(scored.len());
+                for (id, score) in scored {
+                    let hit = statement
+                        .query_row(params![workspace, id], |row| {
+                            Ok(SemanticSearchHit {
+                                path: row.get(0)?,
+                                start_line: row.get::<_, i64>(1)? as usize,
+                                end_line: row.get::<_, i64>(2)? as usize,
+                                score,
+                                content: row.get(3)?,
+                            })
+                        })
+                        .optional()
+                        .map_err(db_error)?;
+                    hits.extend(hit);
+                }
+                Ok(hits)
+            })
+            .await
+    }
+
+    async fn vector_cache(
+        &self,
+        embedder: &dyn Embedder,
+        root: &Path,
+        state: &WorkspaceState,
+    ) -> BitFunResult<Arc<VectorCache>> {
+        let model_id = embedder.model_id().to_string();
+        {
+            let cached = state.vectors.read().await;
+            if let Some(cache) = cached.as_ref().filter(|cache| cache.model_id == model_id) {
+                return Ok(cache.clone());
+            }
+        }
+
+        let mut cached = state.vectors.write().await;
+        if let Some(cache) = cached.as_ref().filter(|cache| cache.model_id == model_id) {
+            return Ok(cache.clone());
+        }
+        let workspace = workspace_key(root);
+        let query_model = model_id.clone();
+        let chunks = self
+            .storage
+            .with_connection(move |connection| {
+                let mut statement = connection
+                    .prepare(
+                        "SELECT c.id, c.vector FROM semantic_chunks c
+                         JOIN semantic_files f ON f.workspace = c.workspace AND f.path = c.path
+                         WHERE c.workspace = ?1 AND f.model_id = ?2",
+                    )
+                    .map_err(db_error)?;
+                let rows = statement
+                    .query_map(params![workspace, query_model], |row| {
+                        Ok((
+                            row.get::<_, i64>(0)?,
+                            decode_vector(&row.get::<_, Vec<u8>>(1)?),
+                        ))
+                    })
+                    .map_err(db_error)?;
+                rows.collect::<Result<Vec<_>, _>>().map_err(db_error)
+            })
+            .await?;
+
+        let cache = Arc::new(VectorCache { model_id, chunks });
+        *cached = Some(cache.clone());
+        Ok(cache)
+    }
+
+    async fn load_file_stamps(&self, workspace: &str) -> BitFunResult<HashMap<String, FileStamp>> {
+        let workspace = workspace.to_string();
+        self.storage
+            .with_connection(move |connection| {
+                let mut statement = connection
+                    .prepare(
+                        "SELECT path, size_bytes, modified_ms, model_id FROM semantic_files
+                         WHERE workspace = ?1",
+                    )
+                    .map_err(db_error)?;
+                let rows = statement
+                    .query_map(params![workspace], |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            FileStamp {
+                                size: row.get::<_, i64>(1)? as u64,
+                                modified_ms: row.get(2)?,
+                                model_id: row.get(3)?,
+                            },
+                        ))
+                    })
+                    .map_err(db_error)?;
+                rows.collect::<Result<HashMap<_, _>, _>>().map_err(db_error)
+            })
+            .await
+    }
+
+    fn workspace_state(
+        &self,
+        workspace_root: &Path,
+    ) -> BitFunResult<(PathBuf, Arc<WorkspaceState>)> {
+        let root = dunce::canonicalize(workspace_root).map_err(|e| {
+            BitFunError::validation(format!(
+                "Workspace root is not accessible: {}: {}",
+                workspace_root.display(),
+                e
+            ))
+        })?;
+        let mut workspaces = self.workspaces.lock().map_err(lock_error)?;
+        let state = workspaces.entry(root.clone()).or_default().clone();
+        Ok((root, state))
+    }
+
+    fn workspace_states(&self) -> Vec<(PathBuf, Arc<WorkspaceState>)> {
+        self.workspaces
+            .lock()
+            .map(|workspaces| {
+                workspaces
+                    .iter()
+                    .map(|(root, state)| (root.clone(), state.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn validate_query(query: &str) -> BitFunResult<&str> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(BitFunError::validation("Search query cannot be empty"));
+    }
+    Ok(query)
+}
+
+/// Walks the workspace the way the code index does, honoring ignore files.
+fn scan_workspace(root: &Path) -> (Vec<Candidate>, bool) {
+    let mut candidates = Vec::new();
+    for entry in WalkBuilder::new(root).require_git(false).build().flatten() {
+        if candidates.len() >= MAX_INDEXED_FILES {
+            debug!(
+                "Semantic index file limit reached: root={}, limit={}",
+                root.display(),
+                MAX_INDEXED_FILES
+            );
+            return (candidates, true);
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_file() && metadata.len() <= MAX_FILE_BYTES {
+            candidates.push(candidate(root, entry.path(), &metadata));
+        }
+    }
+    (candidates, false)
+}
+
+/// Splits queued paths into files to index and paths that left the index.
+fn inspect_paths(root: &Path, paths: Vec<String>) -> (Vec<Candidate>, Vec<String>) {
+    let (gitignore, _) = Gitignore::new(root.join(".gitignore"));
+    let mut candidates = Vec::new();
+    let mut gone = Vec::new();
+    for relative in paths {
+        let absolute = root.join(&relative);
+        let eligible = !is_hidden(&relative)
+            && !gitignore
+                .matched_path_or_any_parents(&absolute, false)
+                .is_ignore();
+        match std::fs::metadata(&absolute) {
+            Ok(metadata) if eligible && metadata.is_file() && metadata.len() <= MAX_FILE_BYTES => {
+                candidates.push(candidate(root, &absolute, &metadata));
+            }
+            _ => gone.push(relative),
+        }
+    }
+    (candidates, gone)
+}
+
+/// Matches the walker, which skips dot files and directories.
+fn is_hidden(relative: &str) -> bool {
+    relative.split('/').any(|part| part.starts_with('.'))
+}
+
+fn candidate(root: &Path, path: &Path, metadata: &std::fs::Metadata) -> Candidate {
+    Candidate {
+        relative: relative_path(path.strip_prefix(root).unwrap_or(path)),
+        absolute: path.to_path_buf(),
+        size: metadata.len(),
+        modified_ms: metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|since| since.as_millis() as i64)
+            .unwrap_or(0),
+    }
+}
+
+/// Binary and non-UTF-8 files are recorded with no chunks so they are not
+/// read again until they change.
+fn prepare_chunks(bytes: &[u8]) -> Vec<TextChunk> {
+    if !looks_like_text(bytes) {
+        return Vec::new();
+    }
+    std::str::from_utf8(bytes)
+        .map(chunk_text)
+        .unwrap_or_default()
+}
+
+/// The path gives the model context the chunk text often lacks.
+fn embedding_input(path: &str, chunk: &TextChunk) -> String {
+    format!(
+        "{}:{}-{}\n{}",
+        path, chunk.start_line, chunk.end_line, chunk.content
+    )
+}
+
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn workspace_key(root: &Path) -> String {
+    root.to_string_lossy().to_string()
+}
+
+fn relative_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn lock_error<T>(_: std::sync::PoisonError<T>) -> BitFunError {
+    BitFunError::service("Semantic search lock poisoned")
+}
+
+fn db_error(error: rusqlite::Error) -> BitFunError {
+    BitFunError::service(format!("Semantic search database error: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Bag-of-words hashing; texts sharing words land close together.
+    struct FakeEmbedder {
+        model_id: String,
+        embedded: AtomicUsize,
+    }
+
+    impl FakeEmbedder {
+        fn new(model_id: &str) -> Self {
+            Self {
+                model_id: model_id.to_string(),
+                embedded: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Embedder for FakeEmbedder {
+        fn model_id(&self) -> &str {
+            &self.model_id
+        }
+
+        async fn embed(&self, inputs: &[String]) -> BitFunResult<Vec<Vec<f32>>> {
+            self.embedded.fetch_add(inputs.len(), Ordering::SeqCst);
+            Ok(inputs
+                .iter()
+                .map(|input| {
+                    let mut vector = vec![0.0; 64];
+                    for word in input
+                        .split(|c: char| !c.is_alphanumeric())
+                        .filter(|word| word.len() > 2)
+                    {
+                        let hash = word
+                            .to_lowercase()
+                            .bytes()
+                            .fold(7usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+                        vector[hash % 64] += 1.0;
+                    }
+                    vector
+                })
+                .collect())
+        }
+    }
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    async fn service(dir: &Path) -> Arc<SemanticSearchService> {
+        let storage = Arc::new(SqliteStorage::new(dir.join("storage.sqlite")));
+        Arc::new(SemanticSearchService::new(storage).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn search_ranks_by_meaning_and_embeds_only_changed_files() {
+        let data = tempfile::tempdir().unwrap();
+        let workspace = tempfile::tempdir().unwrap();
+        write(
+            workspace.path(),
+            "src/config.rs",
+            "fn parse_config(path: &str) -> Config {\n    load settings file\n}\n",
+        );
+        write(
+            workspace.path(),
+            "docs/storage.md",
+            "Database migration notes for the storage schema.\n",
+        );
+        write(workspace.path(), "assets/logo.bin", "\0\0\0binary");
+        let service = service(data.path()).await;
+        let embedder = FakeEmbedder::new("fake");
+
+        let first = service
+            .search_with(&embedder, workspace.path(), "load config settings", 5)
+            .await
+            .unwrap();
+        assert_eq!(first.files_embedded, 3);
+        assert_eq!(first.chunks_searched, 2);
+        assert_eq!(first.hits[0].path, "src/config.rs");
+        assert_eq!((first.hits[0].start_line, first.hits[0].end_line), (1, 3));
+
+        let second = service
+            .search_with(&embedder, workspace.path(), "database migration", 1)
+            .await
+            .unwrap();
+        assert_eq!(second.files_embedded, 0);
+        assert_eq!(second.hits.len(), 1);
+        assert_eq!(second.hits[0].path, "docs/storage.md");
+
+        // Watched edits are re-embedded on the next search.
+        std::fs::remove_file(workspace.path().join("src/config.rs")).unwrap();
+        write(
+            workspace.path(),
+            "docs/storage.md",
+            "Database migration notes, now with rollback steps.\n",
+        );
+        let root = dunce::canonicalize(workspace.path()).unwrap();
+        service.queue_changes(&[
+            FileWatchEvent {
+                path: root.join("src/config.rs").to_string_lossy().to_string(),
+                kind: FileWatchEventKind::Remove,
+                timestamp: 0,
+            },
+            FileWatchEvent {
+                path: root.join("docs/storage.md").to_string_lossy().to_string(),
+                kind: FileWatchEventKind::Modify,
+                timestamp: 0,
+            },
+        ]);
+        let third = service
+            .search_with(&embedder, workspace.path(), "load config settings", 5)
+            .await
+            .unwrap();
+        assert_eq!(third.files_embedded, 1);
+        assert!(third.hits.iter().all(|hit| hit.path != "src/config.rs"));
+
+        let status = service.status(workspace.path()).await.unwrap();
+        assert_eq!(status.files_indexed, 2);
+        assert_eq!(status.chunks_indexed, 1);
+        assert_eq!(status.model_id.as_deref(), Some("fake"));
+        assert_eq!(status.pending_files, 0);
+    }
+
+    #[tokio::test]
+    async fn switching_models_reembeds_and_clear_drops_the_index() {
+        let data = tempfile::tempdir().unwrap();
+        let workspace = tempfile::tempdir().unwrap();
+        write(workspace.path(), "a.txt", "alpha words here\n");
+        write(workspace.path(), "b.txt", "beta words here\n");
+        let service = service(data.path()).await;
+
+        let first = FakeEmbedder::new("model-a");
+        service
+            .search_with(&first, workspace.path(), "alpha", 1)
+            .await
+            .unwrap();
+        let second = FakeEmbedder::new("model-b");
+        let result = service
+            .search_with(&second, workspace.path(), "alpha", 1)
+            .await
+            .unwrap();
+        assert_eq!(result.files_embedded, 2);
+        assert_eq!(result.chunks_searched, 2);
+
+        service.clear(workspace.path()).await.unwrap();
+        let status = service.status(workspace.path()).await.unwrap();
+        assert_eq!((status.files_indexed, status.chunks_indexed), (0, 0));
+        assert!(status.model_id.is_none());
+    }
+
+    #[test]
+    fn queued_hidden_and_ignored_paths_leave_the_index() {
+        let workspace = tempfile::tempdir().unwrap();
+        write(workspace.path(), ".gitignore", "dist/\n");
+        write(workspace.path(), "dist/bundle.js", "ignored");
+        write(workspace.path(), ".env", "SECRET=1");
+        write(workspace.path(), "src/main.rs", "fn main() {}");
+
+        let (candidates, gone) = inspect_paths(
+            workspace.path(),
+            vec![
+                "dist/bundle.js".to_string(),
+                ".env".to_string(),
+                "src/main.rs".to_string(),
+                "src/deleted.rs".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|c| c.relative.as_str())
+                .collect::<Vec<_>>(),
+            vec!["src/main.rs"]
+        );
+        assert_eq!(gone.len(), 3);
+    }
+}
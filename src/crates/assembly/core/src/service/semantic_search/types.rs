@@ -0,0 +1,51 @@
+//! Semantic search types.
+
+use serde::{Deserialize, Serialize};
+
+/// A workspace chunk ranked by similarity to the query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchHit {
+    /// Workspace-relative path with `/` separators.
+    pub path: String,
+    /// 1-based, inclusive.
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Cosine similarity in `[-1, 1]`; higher is closer.
+    pub score: f32,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchResult {
+    pub hits: Vec<SemanticSearchHit>,
+    pub chunks_searched: usize,
+    /// Files embedded for this query because they were new or changed.
+    pub files_embedded: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticIndexReport {
+    pub files_scanned: usize,
+    pub files_embedded: usize,
+    pub files_removed: usize,
+    pub chunks_embedded: usize,
+    /// Set when the file or chunk limit stopped the scan early.
+    pub truncated: bool,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticIndexStatus {
+    pub workspace_path: String,
+    /// Embedding model the stored vectors belong to, if any are stored.
+    pub model_id: Option<String>,
+    pub files_indexed: usize,
+    pub chunks_indexed: usize,
+    /// Files changed on disk that are waiting to be re-embedded.
+    pub pending_files: usize,
+    pub indexing: bool,
+}
@@ -0,0 +1,12 @@
+//! Service health registry
+//!
+//! Optional subsystems (MCP, skills, AI, managed runtimes, file watching)
+//! report whether they came up fully, partially or not at all. A failed
+//! subsystem no longer blocks startup; commands that depend on it surface the
+//! recorded reason instead of a generic "not initialized" error.
+
+mod service;
+mod types;
+
+pub use service::{service_health_registry, ServiceHealthRegistry};
+pub use types::{ServiceHealth, ServiceHealthSnapshot, ServiceKind, ServiceStatus};
@@ -0,0 +1,174 @@
+//! Process-wide service health registry.
+
+use super::types::{ServiceHealth, ServiceHealthSnapshot, ServiceKind, ServiceStatus};
+use chrono::Utc;
+use log::{info, warn};
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, RwLock};
+use tokio::sync::broadcast;
+
+const EVENT_CHANNEL_CAPACITY: usize = 16;
+
+static SERVICE_HEALTH_REGISTRY: LazyLock<ServiceHealthRegistry> =
+    LazyLock::new(ServiceHealthRegistry::new);
+
+pub fn service_health_registry() -> &'static ServiceHealthRegistry {
+    &SERVICE_HEALTH_REGISTRY
+}
+
+pub struct ServiceHealthRegistry {
+    entries: RwLock<BTreeMap<ServiceKind, ServiceHealth>>,
+    events: broadcast::Sender<ServiceHealthSnapshot>,
+}
+
+impl ServiceHealthRegistry {
+    fn new() -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            entries: RwLock::new(BTreeMap::new()),
+            events,
+        }
+    }
+
+    /// Emits a snapshot whenever a service's status or reason changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<ServiceHealthSnapshot> {
+        self.events.subscribe()
+    }
+
+    pub fn report_initialized(&self, service: ServiceKind) {
+        self.report(service, ServiceStatus::Initialized, None);
+    }
+
+    pub fn report_degraded(&self, service: ServiceKind, reason: impl Into<String>) {
+        self.report(service, ServiceStatus::Degraded, Some(reason.into()));
+    }
+
+    pub fn report_failed(&self, service: ServiceKind, reason: impl Into<String>) {
+        self.report(service, ServiceStatus::Failed, Some(reason.into()));
+    }
+
+    fn report(&self, service: ServiceKind, status: ServiceStatus, reason: Option<String>) {
+        let snapshot = {
+            let mut entries = self
+                .entries
+                .write()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if entries
+                .get(&service)
+                .is_some_and(|entry| entry.status == status && entry.reason == reason)
+            {
+                return;
+            }
+
+            match (status, reason.as_deref()) {
+                (ServiceStatus::Initialized, _) => {
+                    info!("{} initialized", service.label());
+                }
+                (_, reason) => warn!(
+                    "{} {:?}: {}",
+                    service.label(),
+                    status,
+                    reason.unwrap_or("no reason given")
+                ),
+            }
+
+            entries.insert(
+                service,
+                ServiceHealth {
+                    service,
+                    status,
+                    reason,
+                    updated_at_ms: Utc::now().timestamp_millis(),
+                },
+            );
+            Self::snapshot_of(&entries)
+        };
+        let _ = self.events.send(snapshot);
+    }
+
+    pub fn get(&self, service: ServiceKind) -> Option<ServiceHealth> {
+        self.entries
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&service)
+            .cloned()
+    }
+
+    pub fn snapshot(&self) -> ServiceHealthSnapshot {
+        Self::snapshot_of(
+            &self
+                .entries
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+
+    fn snapshot_of(entries: &BTreeMap<ServiceKind, ServiceHealth>) -> ServiceHealthSnapshot {
+        ServiceHealthSnapshot {
+            services: entries.values().cloned().collect(),
+        }
+    }
+
+    /// Error message for a command whose service is missing, carrying the
+    /// recorded failure reason when there is one.
+    pub fn unavailable_message(&self, service: ServiceKind) -> String {
+        match self.get(service) {
+            Some(ServiceHealth {
+                status: ServiceStatus::Initialized,
+                ..
+            })
+            | None => format!("{} not initialized", service.label()),
+            Some(ServiceHealth {
+                reason: Some(reason),
+                ..
+            }) => format!("{} unavailable: {}", service.label(), reason),
+            Some(_) => format!("{} unavailable", service.label()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_replace_previous_status_and_only_changes_are_broadcast() {
+        let registry = ServiceHealthRegistry::new();
+        let mut events = registry.subscribe();
+
+        registry.report_failed(ServiceKind::Mcp, "config unreadable");
+        registry.report_failed(ServiceKind::Mcp, "config unreadable");
+        registry.report_initialized(ServiceKind::Ai);
+
+        let first = events.try_recv().expect("failure event");
+        assert_eq!(first.services.len(), 1);
+        assert_eq!(first.services[0].status, ServiceStatus::Failed);
+        let second = events.try_recv().expect("ai event");
+        assert_eq!(second.services.len(), 2);
+        assert!(events.try_recv().is_err());
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.services[0].service, ServiceKind::Mcp);
+        assert_eq!(snapshot.services[1].service, ServiceKind::Ai);
+        assert!(!snapshot.is_healthy());
+
+        registry.report_initialized(ServiceKind::Mcp);
+        assert!(registry.snapshot().is_healthy());
+        assert_eq!(registry.get(ServiceKind::Mcp).unwrap().reason, None);
+    }
+
+    #[test]
+    fn unavailable_message_includes_recorded_reason() {
+        let registry = ServiceHealthRegistry::new();
+        assert_eq!(
+            registry.unavailable_message(ServiceKind::Mcp),
+            "MCP service not initialized"
+        );
+
+        registry.report_failed(ServiceKind::Mcp, "config unreadable");
+        assert_eq!(
+            registry.unavailable_message(ServiceKind::Mcp),
+            "MCP service unavailable: config unreadable"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Service health types.
+
+use serde::{Deserialize, Serialize};
+
+/// Subsystems tracked by the health registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceKind {
+    Mcp,
+    Skills,
+    Ai,
+    /// Managed JavaScript runtimes used by MiniApp workers.
+    Runtime,
+    FileWatcher,
+}
+
+impl ServiceKind {
+    /// Same as the serialized name.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Mcp => "mcp",
+            Self::Skills => "skills",
+            Self::Ai => "ai",
+            Self::Runtime => "runtime",
+            Self::FileWatcher => "file_watcher",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Mcp => "MCP service",
+            Self::Skills => "Skill registry",
+            Self::Ai => "AI service",
+            Self::Runtime => "JS Worker pool",
+            Self::FileWatcher => "File watch service",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceStatus {
+    Initialized,
+    /// Running with reduced functionality.
+    Degraded,
+    /// Unavailable for the rest of the session.
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealth {
+    pub service: ServiceKind,
+    pub status: ServiceStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub updated_at_ms: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealthSnapshot {
+    /// Services that have reported so far, in `ServiceKind` order.
+    pub services: Vec<ServiceHealth>,
+}
+
+impl ServiceHealthSnapshot {
+    pub fn is_healthy(&self) -> bool {
+        self.services
+            .iter()
+            .all(|entry| entry.status == ServiceStatus::Initialized)
+    }
+}
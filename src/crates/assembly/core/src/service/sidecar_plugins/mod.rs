@@ -0,0 +1,25 @@
+//! Third-party backend extensions running as sidecar processes.
+//!
+//! A plugin is a directory with a `plugin.json` manifest declaring the tools,
+//! context providers and commands it offers and the permissions it needs. The
+//! host starts the declared command and talks JSON-RPC to it over stdio, so a
+//! plugin may be written in any language and a crash stays in its own process.
+//!
+//! These are separate from managed plugin packages (`plugin_source`), which
+//! are reviewed and projected but never executed by the host.
+
+mod process;
+mod runtime;
+mod service;
+mod tool;
+mod types;
+
+pub use service::{
+    get_global_sidecar_plugin_service, set_global_sidecar_plugin_service, SidecarPluginService,
+};
+pub use types::{
+    SidecarPluginCommandSpec, SidecarPluginContextProviderSpec, SidecarPluginInfo,
+    SidecarPluginIssue, SidecarPluginList, SidecarPluginManifest, SidecarPluginPermission,
+    SidecarPluginState, SidecarPluginStatus, SidecarPluginToolSpec, SidecarPluginsFile,
+    SIDECAR_PLUGINS_STATE_VERSION, SIDECAR_PLUGIN_MANIFEST_FILE,
+};
@@ -0,0 +1,322 @@
+//! Sidecar plugin process speaking line-delimited JSON-RPC 2.0 over stdio.
+//!
+//! Each request is one JSON object per line on stdin; the plugin answers with
+//! one object per line on stdout carrying the same `id`. Anything the plugin
+//! writes to stderr goes to the log. Plugins are expected to exit when stdin
+//! closes, which is also what happens when the host goes away.
+
+use crate::util::errors::{BitFunError, BitFunResult};
+use crate::util::process_manager;
+use crate::util::process_tree::{ProcessTreeChild, ProcessTreeLimits};
+use log::{debug, warn};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::ChildStdin;
+use tokio::sync::{oneshot, Mutex};
+
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+/// Keeps a runaway plugin from taking the host down with it.
+const SIDECAR_LIMITS: ProcessTreeLimits = ProcessTreeLimits {
+    max_memory_bytes: Some(2 * 1024 * 1024 * 1024),
+    max_cpu_seconds: None,
+};
+const MAX_STDERR_LINE_CHARS: usize = 500;
+
+type PendingCalls = Arc<StdMutex<HashMap<u64, oneshot::Sender<Result<Value, CallFailure>>>>>;
+
+/// Why a call produced no result.
+#[derive(Debug)]
+pub(super) enum CallFailure {
+    /// The plugin answered with a JSON-RPC error.
+    Plugin(String),
+    /// The process is gone or its pipes broke.
+    Exited(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for CallFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plugin(message) => write!(f, "{}", message),
+            Self::Exited(detail) => write!(f, "plugin process exited: {}", detail),
+            Self::TimedOut(timeout) => {
+                write!(f, "plugin did not answer within {}s", timeout.as_secs())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    id: Option<u64>,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    #[serde(default)]
+    code: i64,
+    message: String,
+}
+
+pub(super) struct SidecarProcess {
+    plugin_id: String,
+    child: Mutex<ProcessTreeChild>,
+    stdin: Mutex<ChildStdin>,
+    pending: PendingCalls,
+    next_id: AtomicU64,
+    exited: Arc<AtomicBool>,
+    last_stderr_line: Arc<StdMutex<Option<String>>>,
+}
+
+impl SidecarProcess {
+    pub(super) async fn spawn(
+        plugin_id: &str,
+        directory: &Path,
+        command: &str,
+        args: &[String],
+    ) -> BitFunResult<Arc<Self>> {
+        let program = resolve_program(directory, command);
+        let mut process = process_manager::create_tokio_command(&program);
+        process
+            .args(args)
+            .current_dir(directory)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = ProcessTreeChild::spawn_with_limits(&mut process, SIDECAR_LIMITS)
+            .await
+            .map_err(|e| {
+                BitFunError::ProcessError(format!(
+                    "Failed to start plugin '{}' ({}): {}",
+                    plugin_id, program, e
+                ))
+            })?;
+        let (Some(stdin), Some(stdout), Some(stderr)) =
+            (child.take_stdin(), child.take_stdout(), child.take_stderr())
+        else {
+            return Err(BitFunError::ProcessError(format!(
+                "Plugin '{}' started without stdio pipes",
+                plugin_id
+            )));
+        };
+
+        let pending: PendingCalls = Arc::default();
+        let exited = Arc::new(AtomicBool::new(false));
+        let last_stderr_line = Arc::new(StdMutex::new(None));
+
+        {
+            let plugin_id = plugin_id.to_string();
+            let pending = Arc::clone(&pending);
+            let exited = Arc::clone(&exited);
+            let last_stderr_line = Arc::clone(&last_stderr_line);
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stdout).lines();
+                loop {
+                    match lines.next_line().await {
+                        Ok(Some(line)) => route_response(&plugin_id, &pending, &line),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(
+                                "Plugin stdout read failed: plugin={}, error={}",
+                                plugin_id, e
+                            );
+                            break;
+                        }
+                    }
+                }
+                exited.store(true, Ordering::SeqCst);
+                let detail = exit_detail(&last_stderr_line);
+                let senders = match pending.lock() {
+                    Ok(mut pending) => pending.drain().map(|(_, sender)| sender).collect(),
+                    Err(_) => Vec::new(),
+                };
+                for sender in senders {
+                    let _ = sender.send(Err(CallFailure::Exited(detail.clone())));
+                }
+            });
+        }
+        {
+            let plugin_id = plugin_id.to_string();
+            let last_stderr_line = Arc::clone(&last_stderr_line);
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let line = line.chars().take(MAX_STDERR_LINE_CHARS).collect::<String>();
+                    debug!("Plugin stderr: plugin={}, line={}", plugin_id, line);
+                    if let Ok(mut last) = last_stderr_line.lock() {
+                        *last = Some(line);
+                    }
+                }
+            });
+        }
+
+        Ok(Arc::new(Self {
+            plugin_id: plugin_id.to_string(),
+            child: Mutex::new(child),
+            stdin: Mutex::new(stdin),
+            pending,
+            next_id: AtomicU64::new(1),
+            exited,
+            last_stderr_line,
+        }))
+    }
+
+    pub(super) fn has_exited(&self) -> bool {
+        self.exited.load(Ordering::SeqCst)
+    }
+
+    pub(super) async fn call(
+        &self,
+        method: &str,
+        params: Value,
+        timeout: Duration,
+    ) -> Result<Value, CallFailure> {
+        if self.has_exited() {
+            return Err(CallFailure::Exited(self.exit_detail()));
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = oneshot::channel();
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.insert(id, sender);
+        }
+        // Callers may be dropped mid-call (context providers run under their
+        // own timeout); the guard keeps abandoned ids from piling up.
+        let _guard = PendingGuard {
+            pending: &self.pending,
+            id,
+        };
+        // The reader may have drained pending calls between the check above
+        // and the insert.
+        if self.has_exited() {
+            return Err(CallFailure::Exited(self.exit_detail()));
+        }
+
+        let mut line = serde_json::to_vec(&json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }))
+        .map_err(|e| CallFailure::Plugin(e.to_string()))?;
+        line.push(b'\n');
+        {
+            let mut stdin = self.stdin.lock().await;
+            let written = match stdin.write_all(&line).await {
+                Ok(()) => stdin.flush().await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                return Err(CallFailure::Exited(e.to_string()));
+            }
+        }
+
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(_)) => Err(CallFailure::Exited(self.exit_detail())),
+            Err(_) => Err(CallFailure::TimedOut(timeout)),
+        }
+    }
+
+    /// Asks the plugin to exit, then tears down its process tree.
+    pub(super) async fn shutdown(&self) {
+        if !self.has_exited() {
+            let mut stdin = self.stdin.lock().await;
+            let _ = stdin
+                .write_all(b"{\"jsonrpc\":\"2.0\",\"method\":\"shutdown\"}\n")
+                .await;
+            let _ = stdin.flush().await;
+        }
+        if let Err(e) = self.child.lock().await.terminate(SHUTDOWN_GRACE).await {
+            warn!(
+                "Failed to stop plugin process: plugin={}, error={}",
+                self.plugin_id, e
+            );
+        }
+    }
+
+    fn exit_detail(&self) -> String {
+        exit_detail(&self.last_stderr_line)
+    }
+}
+
+struct PendingGuard<'a> {
+    pending: &'a PendingCalls,
+    id: u64,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.remove(&self.id);
+        }
+    }
+}
+
+/// The plugin's last stderr line usually says why it went away.
+fn exit_detail(last_stderr_line: &StdMutex<Option<String>>) -> String {
+    last_stderr_line
+        .lock()
+        .ok()
+        .and_then(|line| line.clone())
+        .unwrap_or_else(|| "stdout closed".to_string())
+}
+
+fn route_response(plugin_id: &str, pending: &PendingCalls, line: &str) {
+    let line = line.trim();
+    if line.is_empty() {
+        return;
+    }
+    let response = match serde_json::from_str::<RpcResponse>(line) {
+        Ok(response) => response,
+        Err(e) => {
+            warn!(
+                "Ignoring malformed plugin output: plugin={}, error={}",
+                plugin_id, e
+            );
+            return;
+        }
+    };
+    let Some(id) = response.id else {
+        return;
+    };
+    let Some(sender) = pending
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.remove(&id))
+    else {
+        return;
+    };
+    let outcome = match response.error {
+        Some(error) => Err(CallFailure::Plugin(format!(
+            "{} (code {})",
+            error.message, error.code
+        ))),
+        None => Ok(response.result.unwrap_or(Value::Null)),
+    };
+    let _ = sender.send(outcome);
+}
+
+fn resolve_program(directory: &Path, command: &str) -> String {
+    let command = command.trim();
+    match command.strip_prefix("./") {
+        Some(relative) => directory.join(relative).to_string_lossy().into_owned(),
+        None => command.to_string(),
+    }
+}
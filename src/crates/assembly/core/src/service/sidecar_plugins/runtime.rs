@@ -0,0 +1,362 @@
+//! Supervision of one enabled plugin's sidecar process.
+//!
+//! The process starts on first use. A crash or hang fails only the calls in
+//! flight; the next call restarts the plugin after a backoff, and a plugin
+//! that keeps crashing is parked as failed instead of being restarted forever.
+
+use super::process::{CallFailure, SidecarProcess};
+use super::types::{SidecarPluginManifest, SidecarPluginPermission, SidecarPluginStatus};
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const PROTOCOL_VERSION: u32 = 1;
+const INITIALIZE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Crashes within `CRASH_WINDOW` after which the plugin is parked.
+const MAX_CRASHES: usize = 3;
+const CRASH_WINDOW: Duration = Duration::from_secs(300);
+const RESTART_BACKOFF_BASE: Duration = Duration::from_secs(1);
+
+/// Time limit for one call and whether running past it counts as a hang.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CallDeadline {
+    pub timeout: Duration,
+    pub restart_on_timeout: bool,
+}
+
+pub(super) const TOOL_CALL_DEADLINE: CallDeadline = CallDeadline {
+    timeout: Duration::from_secs(120),
+    restart_on_timeout: true,
+};
+pub(super) const COMMAND_CALL_DEADLINE: CallDeadline = CallDeadline {
+    timeout: Duration::from_secs(60),
+    restart_on_timeout: true,
+};
+/// Context providers already run under the composer's short timeout; a slow
+/// answer there is not treated as a hang.
+pub(super) const CONTEXT_CALL_DEADLINE: CallDeadline = CallDeadline {
+    timeout: Duration::from_secs(3),
+    restart_on_timeout: false,
+};
+
+/// Recent crashes of one plugin.
+#[derive(Debug, Default)]
+pub(super) struct CrashTracker {
+    recent: VecDeque<Instant>,
+    total: u32,
+}
+
+impl CrashTracker {
+    pub(super) fn record(&mut self, now: Instant) {
+        self.recent.push_back(now);
+        self.total += 1;
+        while self.recent.len() > MAX_CRASHES {
+            self.recent.pop_front();
+        }
+    }
+
+    pub(super) fn total(&self) -> u32 {
+        self.total
+    }
+
+    fn recent_count(&self, now: Instant) -> usize {
+        self.recent
+            .iter()
+            .filter(|crash| now.saturating_duration_since(**crash) < CRASH_WINDOW)
+            .count()
+    }
+
+    pub(super) fn is_failed(&self, now: Instant) -> bool {
+        self.recent_count(now) >= MAX_CRASHES
+    }
+
+    /// When the next restart may happen, doubling with each recent crash.
+    pub(super) fn restart_not_before(&self, now: Instant) -> Option<Instant> {
+        let recent = self.recent_count(now);
+        let last = self.recent.back()?;
+        if recent == 0 {
+            return None;
+        }
+        let backoff = RESTART_BACKOFF_BASE * (1u32 << (recent - 1).min(8));
+        let not_before = *last + backoff;
+        (not_before > now).then_some(not_before)
+    }
+}
+
+#[derive(Default)]
+struct RuntimeState {
+    process: Option<Arc<SidecarProcess>>,
+    crashes: CrashTracker,
+    last_error: Option<String>,
+}
+
+pub(super) struct SidecarPluginRuntime {
+    manifest: SidecarPluginManifest,
+    directory: PathBuf,
+    granted: BTreeSet<SidecarPluginPermission>,
+    state: Mutex<RuntimeState>,
+}
+
+impl SidecarPluginRuntime {
+    pub(super) fn new(
+        manifest: SidecarPluginManifest,
+        directory: PathBuf,
+        granted: BTreeSet<SidecarPluginPermission>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            manifest,
+            directory,
+            granted,
+            state: Mutex::new(RuntimeState::default()),
+        })
+    }
+
+    pub(super) fn manifest(&self) -> &SidecarPluginManifest {
+        &self.manifest
+    }
+
+    pub(super) fn has_permission(&self, permission: SidecarPluginPermission) -> bool {
+        self.granted.contains(&permission)
+    }
+
+    /// Tools that are not read-only need `WorkspaceWrite`, whatever the
+    /// manifest claims.
+    pub(super) fn may_run_tool(&self, read_only: bool) -> bool {
+        read_only || self.has_permission(SidecarPluginPermission::WorkspaceWrite)
+    }
+
+    /// The workspace path to hand to the plugin, if it may read the workspace.
+    pub(super) fn visible_workspace_path(&self, workspace: Option<&Path>) -> Option<String> {
+        workspace
+            .filter(|_| self.has_permission(SidecarPluginPermission::WorkspaceRead))
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    pub(super) async fn status(&self) -> (SidecarPluginStatus, u32, Option<String>) {
+        let state = self.state.lock().await;
+        let now = Instant::now();
+        let status = if state.crashes.is_failed(now) {
+            SidecarPluginStatus::Failed
+        } else if state
+            .process
+            .as_ref()
+            .is_some_and(|process| !process.has_exited())
+        {
+            SidecarPluginStatus::Running
+        } else if state.crashes.restart_not_before(now).is_some() {
+            SidecarPluginStatus::Restarting
+        } else {
+            SidecarPluginStatus::Idle
+        };
+        (status, state.crashes.total(), state.last_error.clone())
+    }
+
+    pub(super) async fn is_failed(&self) -> bool {
+        self.state.lock().await.crashes.is_failed(Instant::now())
+    }
+
+    pub(super) async fn call(
+        &self,
+        method: &str,
+        params: Value,
+        deadline: CallDeadline,
+    ) -> BitFunResult<Value> {
+        let process = self.ensure_process().await?;
+        match process.call(method, params, deadline.timeout).await {
+            Ok(result) => Ok(result),
+            Err(CallFailure::Plugin(message)) => Err(BitFunError::service(format!(
+                "Plugin '{}' failed: {}",
+                self.manifest.id, message
+            ))),
+            Err(failure @ CallFailure::TimedOut(_)) if !deadline.restart_on_timeout => Err(
+                BitFunError::Timeout(format!("Plugin '{}': {}", self.manifest.id, failure)),
+            ),
+            Err(failure) => {
+                let message = failure.to_string();
+                self.record_crash(&process, &message).await;
+                Err(BitFunError::service(format!(
+                    "Plugin '{}' stopped responding: {}",
+                    self.manifest.id, message
+                )))
+            }
+        }
+    }
+
+    pub(super) async fn shutdown(&self) {
+        let process = self.state.lock().await.process.take();
+        if let Some(process) = process {
+            process.shutdown().await;
+        }
+    }
+
+    async fn ensure_process(&self) -> BitFunResult<Arc<SidecarProcess>> {
+        let mut state = self.state.lock().await;
+        if let Some(process) = state.process.clone() {
+            if !process.has_exited() {
+                return Ok(process);
+            }
+            state.process = None;
+            state.crashes.record(Instant::now());
+            state.last_error = Some("plugin process exited unexpectedly".to_string());
+            warn!("Plugin process exited: plugin={}", self.manifest.id);
+        }
+
+        let now = Instant::now();
+        if state.crashes.is_failed(now) {
+            return Err(BitFunError::service(format!(
+                "Plugin '{}' crashed {} times and was stopped; re-enable it to try again",
+                self.manifest.id, MAX_CRASHES
+            )));
+        }
+        if let Some(not_before) = state.crashes.restart_not_before(now) {
+            return Err(BitFunError::service(format!(
+                "Plugin '{}' is restarting after a crash; try again in {}s",
+                self.manifest.id,
+                not_before.saturating_duration_since(now).as_secs().max(1)
+            )));
+        }
+
+        let process = match self.start().await {
+            Ok(process) => process,
+            Err(e) => {
+                state.crashes.record(Instant::now());
+                state.last_error = Some(e.to_string());
+                return Err(e);
+            }
+        };
+        info!(
+            "Plugin started: plugin={}, version={}",
+            self.manifest.id, self.manifest.version
+        );
+        state.process = Some(Arc::clone(&process));
+        state.last_error = None;
+        Ok(process)
+    }
+
+    async fn start(&self) -> BitFunResult<Arc<SidecarProcess>> {
+        let process = SidecarProcess::spawn(
+            &self.manifest.id,
+            &self.directory,
+            &self.manifest.command,
+            &self.manifest.args,
+        )
+        .await?;
+        let params = json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "pluginId": self.manifest.id,
+            "grantedPermissions": self.granted,
+        });
+        if let Err(failure) = process.call("initialize", params, INITIALIZE_TIMEOUT).await {
+            process.shutdown().await;
+            return Err(BitFunError::ProcessError(format!(
+                "Plugin '{}' failed to initialize: {}",
+                self.manifest.id, failure
+            )));
+        }
+        Ok(process)
+    }
+
+    async fn record_crash(&self, process: &Arc<SidecarProcess>, message: &str) {
+        let stale = {
+            let mut state = self.state.lock().await;
+            // Concurrent calls into the same dead process count as one crash.
+            if !state
+                .process
+                .as_ref()
+                .is_some_and(|current| Arc::ptr_eq(current, process))
+            {
+                return;
+            }
+            state.crashes.record(Instant::now());
+            state.last_error = Some(message.to_string());
+            state.process.take()
+        };
+        warn!(
+            "Plugin crashed: plugin={}, error={}",
+            self.manifest.id, message
+        );
+        if let Some(process) = stale {
+            process.shutdown().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crash_tracker_backs_off_then_parks_the_plugin() {
+        let start = Instant::now();
+        let mut crashes = CrashTracker::default();
+        assert_eq!(crashes.restart_not_before(start), None);
+
+        crashes.record(start);
+        assert_eq!(
+            crashes.restart_not_before(start),
+            Some(start + Duration::from_secs(1))
+        );
+        assert_eq!(
+            crashes.restart_not_before(start + Duration::from_secs(1)),
+            None
+        );
+
+        crashes.record(start + Duration::from_secs(5));
+        assert_eq!(
+            crashes.restart_not_before(start + Duration::from_secs(5)),
+            Some(start + Duration::from_secs(7))
+        );
+        assert!(!crashes.is_failed(start + Duration::from_secs(5)));
+
+        crashes.record(start + Duration::from_secs(10));
+        assert!(crashes.is_failed(start + Duration::from_secs(10)));
+        assert_eq!(crashes.total(), 3);
+    }
+
+    #[test]
+    fn permissions_gate_workspace_paths_and_write_tools() {
+        let manifest: SidecarPluginManifest = serde_json::from_value(json!({
+            "id": "notes",
+            "name": "Notes",
+            "version": "0.1.0",
+            "command": "notes",
+            "permissions": ["workspace_read", "workspace_write"],
+        }))
+        .unwrap();
+        let workspace = Path::new("/work/app");
+
+        let read_only = SidecarPluginRuntime::new(
+            manifest.clone(),
+            PathBuf::from("/plugins/notes"),
+            BTreeSet::from([SidecarPluginPermission::WorkspaceRead]),
+        );
+        assert!(read_only.may_run_tool(true));
+        assert!(!read_only.may_run_tool(false));
+        assert!(read_only.visible_workspace_path(Some(workspace)).is_some());
+
+        let nothing_granted =
+            SidecarPluginRuntime::new(manifest, PathBuf::from("/plugins/notes"), BTreeSet::new());
+        assert!(nothing_granted
+            .visible_workspace_path(Some(workspace))
+            .is_none());
+    }
+
+    #[test]
+    fn crash_tracker_forgets_crashes_outside_the_window() {
+        let start = Instant::now();
+        let mut crashes = CrashTracker::default();
+        for offset in 0..3 {
+            crashes.record(start + Duration::from_secs(offset));
+        }
+        assert!(crashes.is_failed(start + Duration::from_secs(3)));
+
+        let later = start + CRASH_WINDOW + Duration::from_secs(3);
+        assert!(!crashes.is_failed(later));
+        assert_eq!(crashes.restart_not_before(later), None);
+    }
+}
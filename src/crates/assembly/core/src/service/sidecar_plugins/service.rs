@@ -0,0 +1,659 @@
+//! Sidecar plugin discovery, enablement and registration.
+//!
+//! Plugins live in one directory each under the sidecar plugins root. Enabling
+//! a plugin asks the user to grant the permissions its manifest declares; the
+//! grant is stored and asked for again only when the manifest adds new ones.
+//! Enabled plugins register their tools and context providers right away and
+//! start their process on first use.
+
+use super::runtime::{SidecarPluginRuntime, COMMAND_CALL_DEADLINE};
+use super::tool::{
+    context_provider_prefix, tool_name_prefix, SidecarPluginContextProvider, SidecarPluginTool,
+};
+use super::types::{
+    SidecarPluginInfo, SidecarPluginIssue, SidecarPluginList, SidecarPluginManifest,
+    SidecarPluginPermission, SidecarPluginState, SidecarPluginStatus, SidecarPluginsFile,
+    SIDECAR_PLUGINS_STATE_VERSION, SIDECAR_PLUGIN_MANIFEST_FILE,
+};
+use crate::agentic::context_providers::get_global_context_composer;
+use crate::agentic::tools::registry::get_global_tool_registry;
+use crate::infrastructure::storage::{PersistenceService, StorageOptions};
+use crate::infrastructure::PathManager;
+use crate::service::approvals::{get_global_approval_service, ApprovalKind, NewApprovalRequest};
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::fs;
+use tokio::sync::Mutex;
+
+static GLOBAL_SIDECAR_PLUGIN_SERVICE: OnceLock<Arc<SidecarPluginService>> = OnceLock::new();
+
+const STATE_KEY: &str = "state";
+const MAX_IDENTIFIER_LEN: usize = 64;
+
+#[derive(Debug, Clone)]
+struct DiscoveredPlugin {
+    manifest: SidecarPluginManifest,
+    directory: PathBuf,
+}
+
+#[derive(Default)]
+struct PluginSet {
+    discovered: BTreeMap<String, DiscoveredPlugin>,
+    issues: Vec<SidecarPluginIssue>,
+    runtimes: HashMap<String, Arc<SidecarPluginRuntime>>,
+}
+
+pub struct SidecarPluginService {
+    plugins_dir: PathBuf,
+    persistence: PersistenceService,
+    state: Mutex<SidecarPluginsFile>,
+    plugins: Mutex<PluginSet>,
+}
+
+impl SidecarPluginService {
+    pub async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Arc<Self>> {
+        let plugins_dir = path_manager.user_sidecar_plugins_dir();
+        path_manager.ensure_dir(&plugins_dir).await?;
+        let persistence = PersistenceService::new(plugins_dir.clone()).await?;
+        let state = match persistence.load_json::<SidecarPluginsFile>(STATE_KEY).await {
+            Ok(Some(file)) if file.version == SIDECAR_PLUGINS_STATE_VERSION => file,
+            Ok(None) => SidecarPluginsFile::default(),
+            Ok(Some(file)) => {
+                warn!(
+                    "Ignoring sidecar plugin state with unsupported version: version={}",
+                    file.version
+                );
+                SidecarPluginsFile::default()
+            }
+            Err(e) => {
+                warn!("Failed to load sidecar plugin state: {}", e);
+                SidecarPluginsFile::default()
+            }
+        };
+
+        Ok(Arc::new(Self {
+            plugins_dir,
+            persistence,
+            state: Mutex::new(state),
+            plugins: Mutex::new(PluginSet::default()),
+        }))
+    }
+
+    /// Rescans the plugins directory and restarts every enabled plugin, which
+    /// also gives plugins parked after repeated crashes another chance.
+    pub async fn reload(&self) -> SidecarPluginList {
+        let (discovered, issues) = discover(&self.plugins_dir).await;
+        {
+            let mut plugins = self.plugins.lock().await;
+            for (plugin_id, runtime) in plugins.runtimes.drain() {
+                deactivate(&plugin_id, &runtime).await;
+            }
+            plugins.discovered = discovered;
+            plugins.issues = issues;
+
+            let state = self.state.lock().await.clone();
+            let mut runtimes = HashMap::new();
+            for (plugin_id, plugin) in &plugins.discovered {
+                let Some(plugin_state) = state.plugins.get(plugin_id) else {
+                    continue;
+                };
+                if plugin_state.enabled
+                    && missing_permissions(&plugin.manifest, &plugin_state.granted_permissions)
+                        .is_empty()
+                {
+                    let runtime = activate(plugin, plugin_state.granted_permissions.clone()).await;
+                    runtimes.insert(plugin_id.clone(), runtime);
+                }
+            }
+            plugins.runtimes = runtimes;
+        }
+        self.list().await
+    }
+
+    pub async fn list(&self) -> SidecarPluginList {
+        let plugins = self.plugins.lock().await;
+        let state = self.state.lock().await.clone();
+        let mut infos = Vec::with_capacity(plugins.discovered.len());
+        for (plugin_id, plugin) in &plugins.discovered {
+            infos.push(
+                plugin_info(
+                    plugin,
+                    state.plugins.get(plugin_id),
+                    plugins.runtimes.get(plugin_id),
+                )
+                .await,
+            );
+        }
+        SidecarPluginList {
+            plugins_dir: self.plugins_dir.to_string_lossy().into_owned(),
+            plugins: infos,
+            issues: plugins.issues.clone(),
+        }
+    }
+
+    /// Enables or disables a plugin. Enabling a plugin for the first time, or
+    /// after its manifest asked for more permissions, waits for the user to
+    /// approve the grant.
+    pub async fn set_enabled(
+        &self,
+        plugin_id: &str,
+        enabled: bool,
+    ) -> BitFunResult<SidecarPluginInfo> {
+        let manifest = self.discovered(plugin_id).await?.manifest;
+        if enabled {
+            let needs_approval = match self.state.lock().await.plugins.get(plugin_id) {
+                Some(state) => {
+                    !missing_permissions(&manifest, &state.granted_permissions).is_empty()
+                }
+                None => true,
+            };
+            if needs_approval {
+                request_permission_grant(&manifest).await?;
+            }
+        }
+
+        let mut plugins = self.plugins.lock().await;
+        let plugin = plugins
+            .discovered
+            .get(plugin_id)
+            .cloned()
+            .ok_or_else(|| not_found(plugin_id))?;
+        if plugin.manifest != manifest {
+            return Err(BitFunError::validation(format!(
+                "Plugin '{}' changed while waiting for approval; try again",
+                plugin_id
+            )));
+        }
+
+        let plugin_state = {
+            let mut state = self.state.lock().await;
+            if enabled {
+                let entry = state.plugins.entry(plugin_id.to_string()).or_default();
+                entry.enabled = true;
+                entry.granted_permissions = manifest.permissions.clone();
+            } else if let Some(entry) = state.plugins.get_mut(plugin_id) {
+                // Keep the grant so re-enabling does not ask again.
+                entry.enabled = false;
+            }
+            self.persistence
+                .save_json(STATE_KEY, &*state, StorageOptions::default())
+                .await?;
+            state.plugins.get(plugin_id).cloned().unwrap_or_default()
+        };
+
+        if let Some(runtime) = plugins.runtimes.remove(plugin_id) {
+            deactivate(plugin_id, &runtime).await;
+        }
+        if enabled {
+            let runtime = activate(&plugin, plugin_state.granted_permissions.clone()).await;
+            plugins.runtimes.insert(plugin_id.to_string(), runtime);
+        }
+        info!(
+            "Sidecar plugin {}: plugin={}",
+            if enabled { "enabled" } else { "disabled" },
+            plugin_id
+        );
+
+        Ok(plugin_info(
+            &plugin,
+            Some(&plugin_state),
+            plugins.runtimes.get(plugin_id),
+        )
+        .await)
+    }
+
+    /// Runs a command the plugin declared in its manifest.
+    pub async fn invoke_command(
+        &self,
+        plugin_id: &str,
+        command: &str,
+        arguments: Value,
+    ) -> BitFunResult<Value> {
+        let runtime = self
+            .plugins
+            .lock()
+            .await
+            .runtimes
+            .get(plugin_id)
+            .cloned()
+            .ok_or_else(|| {
+                BitFunError::validation(format!("Plugin '{}' is not enabled", plugin_id))
+            })?;
+        if !runtime
+            .manifest()
+            .commands
+            .iter()
+            .any(|declared| declared.name == command)
+        {
+            return Err(BitFunError::validation(format!(
+                "Plugin '{}' has no command '{}'",
+                plugin_id, command
+            )));
+        }
+        runtime
+            .call(
+                "commands/invoke",
+                json!({ "name": command, "arguments": arguments }),
+                COMMAND_CALL_DEADLINE,
+            )
+            .await
+    }
+
+    /// Unregisters every plugin and stops their processes.
+    pub async fn shutdown(&self) {
+        let mut plugins = self.plugins.lock().await;
+        for (plugin_id, runtime) in plugins.runtimes.drain() {
+            deactivate(&plugin_id, &runtime).await;
+        }
+    }
+
+    async fn discovered(&self, plugin_id: &str) -> BitFunResult<DiscoveredPlugin> {
+        self.plugins
+            .lock()
+            .await
+            .discovered
+            .get(plugin_id)
+            .cloned()
+            .ok_or_else(|| not_found(plugin_id))
+    }
+}
+
+fn not_found(plugin_id: &str) -> BitFunError {
+    BitFunError::NotFound(format!("Plugin '{}' not found", plugin_id))
+}
+
+fn missing_permissions(
+    manifest: &SidecarPluginManifest,
+    granted: &BTreeSet<SidecarPluginPermission>,
+) -> Vec<SidecarPluginPermission> {
+    manifest.permissions.difference(granted).copied().collect()
+}
+
+async fn request_permission_grant(manifest: &SidecarPluginManifest) -> BitFunResult<()> {
+    let approvals = get_global_approval_service()
+        .ok_or_else(|| BitFunError::service("Approval service is not available"))?;
+    let abilities = if manifest.permissions.is_empty() {
+        "It requests no extra permissions.".to_string()
+    } else {
+        let lines = manifest
+            .permissions
+            .iter()
+            .map(|permission| format!("- {}", permission.label()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("It asks to:\n{}", lines)
+    };
+    let resolution = approvals
+        .request_approval(NewApprovalRequest {
+            id: None,
+            kind: ApprovalKind::PluginPermission,
+            title: format!("Enable plugin \"{}\"", manifest.name),
+            detail: Some(format!(
+                "{} {} runs `{}` on this computer. {}",
+                manifest.name, manifest.version, manifest.command, abilities
+            )),
+            subject: format!("{}@{}", manifest.id, manifest.version),
+            session_id: None,
+            requester: None,
+        })
+        .await?;
+    if !resolution.decision.is_approved() {
+        return Err(BitFunError::cancelled(format!(
+            "Enabling plugin '{}' was not approved",
+            manifest.id
+        )));
+    }
+    Ok(())
+}
+
+async fn activate(
+    plugin: &DiscoveredPlugin,
+    granted: BTreeSet<SidecarPluginPermission>,
+) -> Arc<SidecarPluginRuntime> {
+    let runtime =
+        SidecarPluginRuntime::new(plugin.manifest.clone(), plugin.directory.clone(), granted);
+    if !plugin.manifest.tools.is_empty() {
+        let registry = get_global_tool_registry();
+        let mut registry = registry.write().await;
+        for spec in &plugin.manifest.tools {
+            registry.register_tool(Arc::new(SidecarPluginTool::new(
+                spec.clone(),
+                Arc::clone(&runtime),
+            )));
+        }
+    }
+    let composer = get_global_context_composer();
+    for spec in &plugin.manifest.context_providers {
+        composer.register(Arc::new(SidecarPluginContextProvider::new(
+            spec.clone(),
+            Arc::clone(&runtime),
+        )));
+    }
+    info!(
+        "Registered sidecar plugin: plugin={}, tools={}, context_providers={}",
+        plugin.manifest.id,
+        plugin.manifest.tools.len(),
+        plugin.manifest.context_providers.len()
+    );
+    runtime
+}
+
+async fn deactivate(plugin_id: &str, runtime: &SidecarPluginRuntime) {
+    get_global_tool_registry()
+        .write()
+        .await
+        .unregister_tools_by_prefix(&tool_name_prefix(plugin_id));
+    get_global_context_composer().unregister_by_prefix(&context_provider_prefix(plugin_id));
+    runtime.shutdown().await;
+}
+
+async fn plugin_info(
+    plugin: &DiscoveredPlugin,
+    state: Option<&SidecarPluginState>,
+    runtime: Option<&Arc<SidecarPluginRuntime>>,
+) -> SidecarPluginInfo {
+    let manifest = &plugin.manifest;
+    let enabled = state.is_some_and(|state| state.enabled);
+    let granted_permissions = state
+        .map(|state| state.granted_permissions.clone())
+        .unwrap_or_default();
+    let (status, crash_count, last_error) = match runtime {
+        Some(runtime) => runtime.status().await,
+        None => {
+            let missing = missing_permissions(manifest, &granted_permissions);
+            let last_error = (enabled && !missing.is_empty()).then(|| {
+                format!(
+                    "The plugin now also asks to {}; enable it again to review",
+                    missing
+                        .iter()
+                        .map(|permission| permission.label())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+            (SidecarPluginStatus::Disabled, 0, last_error)
+        }
+    };
+
+    SidecarPluginInfo {
+        id: manifest.id.clone(),
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        description: manifest.description.clone(),
+        directory: plugin.directory.to_string_lossy().into_owned(),
+        enabled: enabled && runtime.is_some(),
+        status,
+        permissions: manifest.permissions.clone(),
+        granted_permissions,
+        tools: manifest
+            .tools
+            .iter()
+            .map(|tool| tool.name.clone())
+            .collect(),
+        context_providers: manifest
+            .context_providers
+            .iter()
+            .map(|provider| provider.id.clone())
+            .collect(),
+        commands: manifest.commands.clone(),
+        crash_count,
+        last_error,
+    }
+}
+
+async fn discover(
+    plugins_dir: &Path,
+) -> (BTreeMap<String, DiscoveredPlugin>, Vec<SidecarPluginIssue>) {
+    let mut discovered = BTreeMap::new();
+    let mut issues = Vec::new();
+    let mut entries = match fs::read_dir(plugins_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return (discovered, issues),
+        Err(e) => {
+            issues.push(SidecarPluginIssue {
+                directory: plugins_dir.to_string_lossy().into_owned(),
+                message: format!("Cannot read plugins directory: {}", e),
+            });
+            return (discovered, issues);
+        }
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if !entry
+            .file_type()
+            .await
+            .is_ok_and(|file_type| file_type.is_dir())
+        {
+            continue;
+        }
+        let directory = entry.path();
+        let dir_name = entry.file_name().to_string_lossy().into_owned();
+        match load_manifest(&directory, &dir_name).await {
+            Ok(manifest) => {
+                discovered.insert(
+                    manifest.id.clone(),
+                    DiscoveredPlugin {
+                        manifest,
+                        directory,
+                    },
+                );
+            }
+            Err(message) => {
+                warn!(
+                    "Skipping sidecar plugin: directory={}, error={}",
+                    directory.display(),
+                    message
+                );
+                issues.push(SidecarPluginIssue {
+                    directory: directory.to_string_lossy().into_owned(),
+                    message,
+                });
+            }
+        }
+    }
+    issues.sort_by(|a, b| a.directory.cmp(&b.directory));
+    (discovered, issues)
+}
+
+async fn load_manifest(directory: &Path, dir_name: &str) -> Result<SidecarPluginManifest, String> {
+    let content = fs::read_to_string(directory.join(SIDECAR_PLUGIN_MANIFEST_FILE))
+        .await
+        .map_err(|e| format!("Cannot read {}: {}", SIDECAR_PLUGIN_MANIFEST_FILE, e))?;
+    let manifest = serde_json::from_str::<SidecarPluginManifest>(&content)
+        .map_err(|e| format!("Invalid {}: {}", SIDECAR_PLUGIN_MANIFEST_FILE, e))?;
+    validate_manifest(&manifest, dir_name)?;
+    Ok(manifest)
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_IDENTIFIER_LEN
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn validate_manifest(manifest: &SidecarPluginManifest, dir_name: &str) -> Result<(), String> {
+    if !is_identifier(&manifest.id) || manifest.id.contains("__") {
+        return Err(format!(
+            "Plugin id '{}' must be 1-{} letters, digits, '-' or '_' without '__'",
+            manifest.id, MAX_IDENTIFIER_LEN
+        ));
+    }
+    if manifest.id != dir_name {
+        return Err(format!(
+            "Plugin id '{}' does not match its directory name '{}'",
+            manifest.id, dir_name
+        ));
+    }
+    if manifest.name.trim().is_empty() {
+        return Err("Plugin name cannot be empty".to_string());
+    }
+    if manifest.command.trim().is_empty() {
+        return Err("Plugin command cannot be empty".to_string());
+    }
+
+    let mut tool_names = HashSet::new();
+    for tool in &manifest.tools {
+        if !is_identifier(&tool.name) || !tool_names.insert(tool.name.as_str()) {
+            return Err(format!("Invalid or duplicate tool name '{}'", tool.name));
+        }
+        if !tool.input_schema.is_object() {
+            return Err(format!(
+                "Input schema of tool '{}' must be an object",
+                tool.name
+            ));
+        }
+        if !tool.read_only
+            && !manifest
+                .permissions
+                .contains(&SidecarPluginPermission::WorkspaceWrite)
+        {
+            return Err(format!(
+                "Tool '{}' is not read-only and requires the 'workspace_write' permission",
+                tool.name
+            ));
+        }
+    }
+
+    let mut provider_ids = HashSet::new();
+    for provider in &manifest.context_providers {
+        if !is_identifier(&provider.id) || !provider_ids.insert(provider.id.as_str()) {
+            return Err(format!(
+                "Invalid or duplicate context provider id '{}'",
+                provider.id
+            ));
+        }
+        if !manifest
+            .permissions
+            .contains(&SidecarPluginPermission::WorkspaceRead)
+        {
+            return Err(format!(
+                "Context provider '{}' requires the 'workspace_read' permission",
+                provider.id
+            ));
+        }
+    }
+
+    let mut command_names = HashSet::new();
+    for command in &manifest.commands {
+        if !is_identifier(&command.name) || !command_names.insert(command.name.as_str()) {
+            return Err(format!(
+                "Invalid or duplicate command name '{}'",
+                command.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn get_global_sidecar_plugin_service() -> Option<Arc<SidecarPluginService>> {
+    GLOBAL_SIDECAR_PLUGIN_SERVICE.get().cloned()
+}
+
+pub fn set_global_sidecar_plugin_service(service: Arc<SidecarPluginService>) {
+    let _ = GLOBAL_SIDECAR_PLUGIN_SERVICE.set(service);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(value: Value) -> SidecarPluginManifest {
+        serde_json::from_value(value).expect("manifest should parse")
+    }
+
+    #[test]
+    fn validate_manifest_ties_capabilities_to_permissions() {
+        let mut plugin = manifest(json!({
+            "id": "jira",
+            "name": "Jira",
+            "version": "1.0.0",
+            "command": "./bin/jira-plugin",
+            "permissions": ["workspace_read"],
+            "tools": [{ "name": "search_issues", "description": "Search", "readOnly": true }],
+            "contextProviders": [{ "id": "sprint", "title": "Current sprint" }],
+            "commands": [{ "name": "sync" }],
+        }));
+        assert_eq!(validate_manifest(&plugin, "jira"), Ok(()));
+        assert!(validate_manifest(&plugin, "other").is_err());
+
+        plugin.tools[0].read_only = false;
+        let error = validate_manifest(&plugin, "jira").unwrap_err();
+        assert!(error.contains("workspace_write"), "{}", error);
+
+        plugin.tools[0].read_only = true;
+        plugin
+            .permissions
+            .remove(&SidecarPluginPermission::WorkspaceRead);
+        let error = validate_manifest(&plugin, "jira").unwrap_err();
+        assert!(error.contains("workspace_read"), "{}", error);
+    }
+
+    #[test]
+    fn missing_permissions_lists_only_new_requests() {
+        let plugin = manifest(json!({
+            "id": "jira",
+            "name": "Jira",
+            "version": "1.1.0",
+            "command": "jira-plugin",
+            "permissions": ["workspace_read", "workspace_write"],
+        }));
+        let granted = BTreeSet::from([SidecarPluginPermission::WorkspaceWrite]);
+        assert_eq!(
+            missing_permissions(&plugin, &granted),
+            vec![SidecarPluginPermission::WorkspaceRead]
+        );
+        assert!(missing_permissions(&plugin, &plugin.permissions).is_empty());
+    }
+
+    #[test]
+    fn unenforceable_permissions_are_not_accepted() {
+        let declared = serde_json::from_value::<SidecarPluginManifest>(json!({
+            "id": "jira",
+            "name": "Jira",
+            "version": "1.0.0",
+            "command": "jira-plugin",
+            "permissions": ["network"],
+        }));
+        assert!(declared.is_err());
+
+        let state: SidecarPluginsFile = serde_json::from_value(json!({
+            "version": 1,
+            "plugins": {
+                "jira": { "enabled": true, "grantedPermissions": ["network", "workspace_read"] }
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            state.plugins["jira"].granted_permissions,
+            BTreeSet::from([SidecarPluginPermission::WorkspaceRead])
+        );
+    }
+
+    #[tokio::test]
+    async fn discover_reports_invalid_plugin_directories() {
+        let root = tempfile::tempdir().expect("temp dir");
+        let valid = root.path().join("notes");
+        let broken = root.path().join("broken");
+        std::fs::create_dir_all(&valid).unwrap();
+        std::fs::create_dir_all(&broken).unwrap();
+        std::fs::write(
+            valid.join(SIDECAR_PLUGIN_MANIFEST_FILE),
+            r#"{"id":"notes","name":"Notes","version":"0.1.0","command":"notes"}"#,
+        )
+        .unwrap();
+        std::fs::write(broken.join(SIDECAR_PLUGIN_MANIFEST_FILE), "{").unwrap();
+        std::fs::write(root.path().join("state.json"), "{}").unwrap();
+
+        let (discovered, issues) = discover(root.path()).await;
+
+        assert_eq!(discovered.keys().collect::<Vec<_>>(), vec!["notes"]);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].directory.ends_with("broken"));
+    }
+}
@@ -0,0 +1,283 @@
+//! Plugin tools and context providers exposed to agents.
+
+use super::runtime::{SidecarPluginRuntime, CONTEXT_CALL_DEADLINE, TOOL_CALL_DEADLINE};
+use super::types::{SidecarPluginContextProviderSpec, SidecarPluginToolSpec};
+use crate::agentic::context_providers::{ContextFragment, ContextProvider, ContextProviderRequest};
+use crate::agentic::tools::framework::{
+    DynamicToolInfo, Tool, ToolExposure, ToolResult, ToolUseContext, ValidationResult,
+};
+use crate::util::errors::{BitFunError, BitFunResult};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::path::Path;
+use std::sync::Arc;
+
+const PROVIDER_KIND: &str = "sidecar_plugin";
+/// Plugin context sections come after the built-in ones.
+const CONTEXT_PROVIDER_ORDER: u32 = 1000;
+const DEFAULT_CONTEXT_TOKEN_BUDGET: usize = 1_000;
+
+/// Registry prefix shared by every tool of one plugin, e.g. `plugin__jira__`.
+pub(super) fn tool_name_prefix(plugin_id: &str) -> String {
+    format!("plugin__{}__", plugin_id)
+}
+
+/// Composer id prefix shared by every context provider of one plugin.
+pub(super) fn context_provider_prefix(plugin_id: &str) -> String {
+    format!("plugin:{}:", plugin_id)
+}
+
+fn provider_id(plugin_id: &str) -> String {
+    format!("plugin:{}", plugin_id)
+}
+
+/// Text for the model: a plain string result, a `content` string, or the JSON.
+fn render_result(result: &Value) -> String {
+    match result {
+        Value::String(text) => text.clone(),
+        Value::Object(object) => match object.get("content") {
+            Some(Value::String(text)) => text.clone(),
+            _ => serde_json::to_string_pretty(result).unwrap_or_default(),
+        },
+        Value::Null => "Plugin tool completed".to_string(),
+        _ => result.to_string(),
+    }
+}
+
+pub(super) struct SidecarPluginTool {
+    full_name: String,
+    provider_id: String,
+    spec: SidecarPluginToolSpec,
+    runtime: Arc<SidecarPluginRuntime>,
+}
+
+impl SidecarPluginTool {
+    pub(super) fn new(spec: SidecarPluginToolSpec, runtime: Arc<SidecarPluginRuntime>) -> Self {
+        let plugin_id = &runtime.manifest().id;
+        Self {
+            full_name: format!("{}{}", tool_name_prefix(plugin_id), spec.name),
+            provider_id: provider_id(plugin_id),
+            spec,
+            runtime,
+        }
+    }
+
+    fn plugin_name(&self) -> &str {
+        &self.runtime.manifest().name
+    }
+
+    fn remote_message(&self) -> String {
+        format!(
+            "Plugin tool '{}' runs on this computer and is unavailable in remote workspaces",
+            self.spec.name
+        )
+    }
+}
+
+#[async_trait]
+impl Tool for SidecarPluginTool {
+    fn name(&self) -> &str {
+        &self.full_name
+    }
+
+    async fn description(&self) -> BitFunResult<String> {
+        Ok(format!(
+            "{}\n\nProvided by the '{}' plugin.",
+            self.spec.description.trim(),
+            self.plugin_name()
+        ))
+    }
+
+    fn short_description(&self) -> String {
+        let summary = self.spec.description.lines().next().unwrap_or_default();
+        format!("{} (plugin: {})", summary.trim(), self.plugin_name())
+    }
+
+    fn default_exposure(&self) -> ToolExposure {
+        ToolExposure::Deferred
+    }
+
+    fn input_schema(&self) -> Value {
+        self.spec.input_schema.clone()
+    }
+
+    fn dynamic_provider_id(&self) -> Option<&str> {
+        Some(&self.provider_id)
+    }
+
+    fn dynamic_tool_info(&self) -> Option<DynamicToolInfo> {
+        Some(DynamicToolInfo {
+            provider_id: self.provider_id.clone(),
+            provider_kind: Some(PROVIDER_KIND.to_string()),
+            mcp: None,
+        })
+    }
+
+    fn user_facing_name(&self) -> String {
+        format!("{} ({})", self.spec.name, self.plugin_name())
+    }
+
+    async fn is_available_in_context(&self, context: Option<&ToolUseContext>) -> bool {
+        !context.is_some_and(ToolUseContext::is_remote)
+            && self.runtime.may_run_tool(self.spec.read_only)
+            && !self.runtime.is_failed().await
+    }
+
+    fn is_readonly(&self) -> bool {
+        self.spec.read_only
+    }
+
+    async fn validate_input(
+        &self,
+        input: &Value,
+        context: Option<&ToolUseContext>,
+    ) -> ValidationResult {
+        let message = if context.is_some_and(ToolUseContext::is_remote) {
+            Some(self.remote_message())
+        } else if !input.is_object() {
+            Some("Plugin tool input must be a JSON object".to_string())
+        } else {
+            None
+        };
+        ValidationResult {
+            result: message.is_none(),
+            message,
+            error_code: None,
+            meta: None,
+        }
+    }
+
+    fn render_result_for_assistant(&self, output: &Value) -> String {
+        render_result(output)
+    }
+
+    async fn call_impl(
+        &self,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> BitFunResult<Vec<ToolResult>> {
+        if context.is_remote() {
+            return Err(BitFunError::tool(self.remote_message()));
+        }
+        if !self.runtime.may_run_tool(self.spec.read_only) {
+            return Err(BitFunError::tool(format!(
+                "Plugin tool '{}' changes state and the '{}' plugin was not granted 'workspace_write'",
+                self.spec.name,
+                self.plugin_name()
+            )));
+        }
+        let params = json!({
+            "name": self.spec.name,
+            "arguments": input,
+            "sessionId": context.session_id,
+            "workspacePath": self.runtime.visible_workspace_path(context.workspace_root()),
+        });
+        let result = self
+            .runtime
+            .call("tools/call", params, TOOL_CALL_DEADLINE)
+            .await
+            .map_err(|e| BitFunError::tool(e.to_string()))?;
+        let text = render_result(&result);
+        Ok(vec![ToolResult::ok(result, Some(text))])
+    }
+}
+
+pub(super) struct SidecarPluginContextProvider {
+    id: String,
+    spec: SidecarPluginContextProviderSpec,
+    runtime: Arc<SidecarPluginRuntime>,
+}
+
+impl SidecarPluginContextProvider {
+    pub(super) fn new(
+        spec: SidecarPluginContextProviderSpec,
+        runtime: Arc<SidecarPluginRuntime>,
+    ) -> Self {
+        Self {
+            id: format!(
+                "{}{}",
+                context_provider_prefix(&runtime.manifest().id),
+                spec.id
+            ),
+            spec,
+            runtime,
+        }
+    }
+}
+
+#[async_trait]
+impl ContextProvider for SidecarPluginContextProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn order(&self) -> u32 {
+        CONTEXT_PROVIDER_ORDER
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    fn default_token_budget(&self) -> usize {
+        self.spec
+            .token_budget
+            .unwrap_or(DEFAULT_CONTEXT_TOKEN_BUDGET)
+    }
+
+    async fn provide(
+        &self,
+        request: &ContextProviderRequest,
+    ) -> BitFunResult<Option<ContextFragment>> {
+        if request.remote || self.runtime.is_failed().await {
+            return Ok(None);
+        }
+        let Some(workspace_path) = self
+            .runtime
+            .visible_workspace_path(Some(Path::new(&request.workspace_path)))
+        else {
+            return Ok(None);
+        };
+        let params = json!({
+            "provider": self.spec.id,
+            "workspacePath": workspace_path,
+            "sessionId": request.session_id,
+            "query": request.query,
+        });
+        let result = self
+            .runtime
+            .call("context/provide", params, CONTEXT_CALL_DEADLINE)
+            .await?;
+        let body = match &result {
+            Value::String(body) => body.clone(),
+            Value::Object(object) => object
+                .get("body")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        };
+        if body.trim().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ContextFragment {
+            title: self.spec.title.clone(),
+            body,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_result_prefers_plain_text() {
+        assert_eq!(render_result(&json!("done")), "done");
+        assert_eq!(
+            render_result(&json!({ "content": "3 issues", "issues": [1, 2, 3] })),
+            "3 issues"
+        );
+        assert!(render_result(&json!({ "issues": [] })).contains("\"issues\""));
+    }
+}
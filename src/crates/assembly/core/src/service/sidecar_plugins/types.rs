@@ -0,0 +1,178 @@
+//! Sidecar plugin manifest, state and view types.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const SIDECAR_PLUGIN_MANIFEST_FILE: &str = "plugin.json";
+pub const SIDECAR_PLUGINS_STATE_VERSION: u32 = 1;
+
+/// Capability a plugin declares and the user grants when enabling it.
+///
+/// The host cannot sandbox a sidecar process, so only capabilities the host
+/// itself hands out can be declared: workspace paths and write-capable tools.
+/// Manifests naming anything else fail to load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarPluginPermission {
+    /// Receives the workspace path and may contribute prompt context.
+    WorkspaceRead,
+    /// May expose tools that change files or other state.
+    WorkspaceWrite,
+}
+
+impl SidecarPluginPermission {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::WorkspaceRead => "read the current workspace",
+            Self::WorkspaceWrite => "modify files through its tools",
+        }
+    }
+}
+
+/// `plugin.json` at the root of a plugin directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginManifest {
+    /// Must match the directory name.
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Program to start. Paths starting with `./` resolve against the plugin
+    /// directory; anything else is looked up on `PATH`.
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub permissions: BTreeSet<SidecarPluginPermission>,
+    #[serde(default)]
+    pub tools: Vec<SidecarPluginToolSpec>,
+    #[serde(default)]
+    pub context_providers: Vec<SidecarPluginContextProviderSpec>,
+    #[serde(default)]
+    pub commands: Vec<SidecarPluginCommandSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginToolSpec {
+    pub name: String,
+    pub description: String,
+    #[serde(default = "empty_object_schema")]
+    pub input_schema: Value,
+    /// Tools that are not read-only require `workspace_write`.
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginContextProviderSpec {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub token_budget: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginCommandSpec {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+fn empty_object_schema() -> Value {
+    serde_json::json!({ "type": "object", "properties": {} })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarPluginStatus {
+    Disabled,
+    /// Enabled; the process starts on first use.
+    Idle,
+    Running,
+    /// Crashed and waiting out the restart backoff.
+    Restarting,
+    /// Crashed too often; stays down until re-enabled or reloaded.
+    Failed,
+}
+
+/// One plugin as shown in settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginInfo {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub directory: String,
+    pub enabled: bool,
+    pub status: SidecarPluginStatus,
+    pub permissions: BTreeSet<SidecarPluginPermission>,
+    pub granted_permissions: BTreeSet<SidecarPluginPermission>,
+    pub tools: Vec<String>,
+    pub context_providers: Vec<String>,
+    pub commands: Vec<SidecarPluginCommandSpec>,
+    pub crash_count: u32,
+    pub last_error: Option<String>,
+}
+
+/// A plugin directory whose manifest could not be used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginIssue {
+    pub directory: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginList {
+    /// Where plugins are discovered, for "open folder" in settings.
+    pub plugins_dir: String,
+    pub plugins: Vec<SidecarPluginInfo>,
+    pub issues: Vec<SidecarPluginIssue>,
+}
+
+/// Persisted user choice for one plugin.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginState {
+    pub enabled: bool,
+    /// Grants for permissions that no longer exist are dropped on load.
+    #[serde(default, deserialize_with = "known_permissions")]
+    pub granted_permissions: BTreeSet<SidecarPluginPermission>,
+}
+
+fn known_permissions<'de, D>(deserializer: D) -> Result<BTreeSet<SidecarPluginPermission>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Vec::<Value>::deserialize(deserializer)?;
+    Ok(values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
+}
+
+/// `state.json` in the sidecar plugins directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarPluginsFile {
+    pub version: u32,
+    #[serde(default)]
+    pub plugins: BTreeMap<String, SidecarPluginState>,
+}
+
+impl Default for SidecarPluginsFile {
+    fn default() -> Self {
+        Self {
+            version: SIDECAR_PLUGINS_STATE_VERSION,
+            plugins: BTreeMap::new(),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use crate::service::remote_ssh::workspace_state::is_remote_path;
 use crate::service::snapshot::service::SnapshotService;
 use crate::service::snapshot::snapshot_core::SessionStats;
 use crate::service::snapshot::types::{
-    OperationType, SnapshotConfig, SnapshotError, SnapshotResult,
+    OperationType, SnapshotConfig, SnapshotError, SnapshotResult, TurnCheckpoint,
 };
 use crate::service::workspace_runtime::get_workspace_runtime_service_arc;
 use async_trait::async_trait;
@@ -126,6 +126,12 @@ impl SnapshotManager {
         snapshot_service.get_session_turns(session_id).await
     }
 
+    /// Returns the per-turn restore points recorded for a session.
+    pub async fn list_checkpoints(&self, session_id: &str) -> SnapshotResult<Vec<TurnCheckpoint>> {
+        let snapshot_service = self.snapshot_service.read().await;
+        snapshot_service.list_checkpoints(session_id).await
+    }
+
     /// Returns the list of files modified in a turn.
     pub async fn get_turn_files(
         &self,
@@ -412,6 +418,7 @@ impl WrappedTool {
             "rename_file",
             "move_file",
             "search_replace",
+            "EditTransaction",
         ]
         .contains(&tool_name)
     }
@@ -583,6 +590,12 @@ impl WrappedTool {
             )
         })?;
 
+        if self.name() == "EditTransaction" {
+            return self
+                .handle_multi_file_modification(&session_id, input, context)
+                .await;
+        }
+
         let raw_path = match self.extract_file_path_simple(input) {
             Ok(path) => path,
             Err(e) => return Err(crate::util::errors::BitFunError::Tool(e.to_string())),
@@ -691,6 +704,93 @@ impl WrappedTool {
         Ok(results)
     }
 
+    /// Records one snapshot operation per file touched by a multi-file
+    /// transaction, so each file can be reviewed and reverted on its own.
+    async fn handle_multi_file_modification(
+        &self,
+        session_id: &str,
+        input: &Value,
+        context: &ToolUseContext,
+    ) -> crate::util::errors::BitFunResult<Vec<ToolResult>> {
+        let dry_run = input
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let snapshot_workspace = match context.workspace_root().map(PathBuf::from) {
+            Some(workspace) if !dry_run && !context.is_remote() => workspace,
+            _ => return self.original_tool.call(input, context).await,
+        };
+        let snapshot_manager = get_or_create_snapshot_manager(snapshot_workspace.clone(), None)
+            .await
+            .map_err(|e| crate::util::errors::BitFunError::Tool(e.to_string()))?;
+
+        let mut file_paths: Vec<PathBuf> = Vec::new();
+        for raw_path in input
+            .get("edits")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|edit| edit.get("file_path").and_then(Value::as_str))
+        {
+            let raw_path = PathBuf::from(raw_path);
+            let file_path = if raw_path.is_absolute() {
+                raw_path
+            } else {
+                snapshot_workspace.join(raw_path)
+            };
+            if !file_paths.contains(&file_path) {
+                file_paths.push(file_path);
+            }
+        }
+
+        let turn_index = self.extract_turn_index(context);
+        let snapshot_service = snapshot_manager.get_snapshot_service();
+        let snapshot_service = snapshot_service.read().await;
+        let mut operation_ids = Vec::with_capacity(file_paths.len());
+        for (index, file_path) in file_paths.iter().enumerate() {
+            let operation_type = if file_path.exists() {
+                OperationType::Modify
+            } else {
+                OperationType::Create
+            };
+            let operation_id = snapshot_service
+                .intercept_file_modification(
+                    session_id,
+                    turn_index,
+                    self.name(),
+                    input.clone(),
+                    file_path,
+                    operation_type,
+                    // One operation per file; the tool call id alone would collide.
+                    context
+                        .tool_call_id
+                        .as_ref()
+                        .map(|tool_call_id| format!("{}#{}", tool_call_id, index)),
+                )
+                .await
+                .map_err(|e| crate::util::errors::BitFunError::Tool(e.to_string()))?;
+            operation_ids.push(operation_id);
+        }
+
+        let start_time = std::time::Instant::now();
+        let results = self.original_tool.call(input, context).await?;
+        let tool_call_ms = crate::util::elapsed_ms_u64(start_time);
+
+        for operation_id in &operation_ids {
+            snapshot_service
+                .complete_file_modification(session_id, operation_id, tool_call_ms)
+                .await
+                .map_err(|e| crate::util::errors::BitFunError::Tool(e.to_string()))?;
+        }
+        debug!(
+            "Multi-file modification tool completed: tool_name={}, files={}, tool_call_ms={}",
+            self.name(),
+            file_paths.len(),
+            tool_call_ms
+        );
+        Ok(results)
+    }
+
     /// Extracts the turn index.
     fn extract_turn_index(&self, context: &ToolUseContext) -> usize {
         context
@@ -4,7 +4,7 @@ use crate::service::snapshot::isolation_manager::IsolationManager;
 use crate::service::snapshot::snapshot_core::{SessionStats, SnapshotCore};
 use crate::service::snapshot::snapshot_system::FileSnapshotSystem;
 use crate::service::snapshot::types::{
-    OperationType, SessionInfo, SnapshotConfig, SnapshotError, SnapshotResult,
+    OperationType, SessionInfo, SnapshotConfig, SnapshotError, SnapshotResult, TurnCheckpoint,
 };
 use crate::service::workspace_runtime::WorkspaceRuntimeContext;
 use log::{debug, info};
@@ -383,6 +383,12 @@ impl SnapshotService {
         Ok(snapshot_core.get_turn_files(session_id, turn_index))
     }
 
+    pub async fn list_checkpoints(&self, session_id: &str) -> SnapshotResult<Vec<TurnCheckpoint>> {
+        self.ensure_initialized().await?;
+        let snapshot_core = self.snapshot_core.read().await;
+        Ok(snapshot_core.list_checkpoints(session_id))
+    }
+
     pub async fn get_file_diff(
         &self,
         session_id: &str,
@@ -1,7 +1,7 @@
 use crate::service::snapshot::snapshot_system::FileSnapshotSystem;
 use crate::service::snapshot::types::{
     DiffSummary, FileOperation, OperationType, SessionFileDiffStats, SnapshotError, SnapshotResult,
-    ToolContext,
+    ToolContext, TurnCheckpoint,
 };
 use crate::service::workspace_runtime::WorkspaceRuntimeContext;
 use log::{debug, info, warn};
@@ -368,6 +368,47 @@ impl SnapshotCore {
         )
     }
 
+    /// One checkpoint per turn that completed at least one edit, oldest first.
+    pub fn list_checkpoints(&self, session_id: &str) -> Vec<TurnCheckpoint> {
+        let Some(session) = self.sessions.get(session_id) else {
+            return Vec::new();
+        };
+        session
+            .turns
+            .values()
+            .filter_map(|turn| {
+                let ops = turn
+                    .operations
+                    .iter()
+                    .filter(|op| operation_is_completed_for_session_file(op))
+                    .collect::<Vec<_>>();
+                let created_at = ops.iter().map(|op| op.timestamp).min()?;
+                let mut tool_names = Vec::new();
+                for op in &ops {
+                    if !tool_names.contains(&op.tool_context.tool_name) {
+                        tool_names.push(op.tool_context.tool_name.clone());
+                    }
+                }
+                Some(TurnCheckpoint {
+                    session_id: session_id.to_string(),
+                    turn_index: turn.turn_index,
+                    created_at_ms: created_at
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .map(|elapsed| elapsed.as_millis() as u64)
+                        .unwrap_or_default(),
+                    files: unique_paths(ops.iter().map(|op| op.file_path.clone()))
+                        .into_iter()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .collect(),
+                    operation_count: ops.len(),
+                    lines_added: ops.iter().map(|op| op.diff_summary.lines_added).sum(),
+                    lines_removed: ops.iter().map(|op| op.diff_summary.lines_removed).sum(),
+                    tool_names,
+                })
+            })
+            .collect()
+    }
+
     pub fn get_session_files(&self, session_id: &str) -> Vec<PathBuf> {
         let Some(session) = self.sessions.get(session_id) else {
             return Vec::new();
@@ -1268,6 +1309,54 @@ mod tests {
         assert_eq!(after, "base\nsession\n");
     }
 
+    #[tokio::test]
+    async fn restoring_a_checkpoint_reverts_that_turn_and_later_ones() {
+        let mut runtime = make_test_runtime("checkpoints").await;
+        let file_path = runtime.workspace.join("src/lib.rs");
+        fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        tokio::fs::write(&file_path, "base\n").await.unwrap();
+
+        for (turn_index, content) in [(0, "base\nturn0\n"), (1, "base\nturn0\nturn1\n")] {
+            let operation_id = runtime
+                .core
+                .start_file_operation(
+                    "session-1",
+                    turn_index,
+                    file_path.clone(),
+                    OperationType::Modify,
+                    "Edit".to_string(),
+                    json!({ "file_path": "src/lib.rs" }),
+                    None,
+                )
+                .await
+                .unwrap();
+            tokio::fs::write(&file_path, content).await.unwrap();
+            runtime
+                .core
+                .complete_file_operation("session-1", &operation_id, 1)
+                .await
+                .unwrap();
+        }
+
+        let checkpoints = runtime.core.list_checkpoints("session-1");
+        assert_eq!(
+            checkpoints
+                .iter()
+                .map(|checkpoint| checkpoint.turn_index)
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(checkpoints[1].lines_added, 1);
+        assert_eq!(checkpoints[1].tool_names, vec!["Edit".to_string()]);
+
+        runtime.core.rollback_to_turn("session-1", 1).await.unwrap();
+        assert_eq!(
+            tokio::fs::read_to_string(&file_path).await.unwrap(),
+            "base\nturn0\n"
+        );
+        assert_eq!(runtime.core.list_checkpoints("session-1").len(), 1);
+    }
+
     #[tokio::test]
     async fn session_files_ignore_unfinished_operations() {
         let mut runtime = make_test_runtime("unfinished_ops").await;
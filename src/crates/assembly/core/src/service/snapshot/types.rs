@@ -75,6 +75,23 @@ pub struct SessionFileDiffStats {
     pub change_kind: String,
 }
 
+/// Workspace state before an agent turn's first edit. Restoring it undoes
+/// that turn and every later turn in the session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnCheckpoint {
+    pub session_id: String,
+    pub turn_index: usize,
+    /// Unix milliseconds of the turn's first recorded edit.
+    pub created_at_ms: u64,
+    pub files: Vec<String>,
+    pub operation_count: usize,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    /// Tools that edited files in this turn, in first-use order.
+    pub tool_names: Vec<String>,
+}
+
 /// File modification status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum FileModificationStatus {
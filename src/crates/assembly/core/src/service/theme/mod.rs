@@ -0,0 +1,10 @@
+//! Theme registry module
+//!
+//! Discovers built-in and user-provided themes and owns the validated
+//! `themes.current` selection.
+
+mod registry;
+mod types;
+
+pub use registry::*;
+pub use types::*;
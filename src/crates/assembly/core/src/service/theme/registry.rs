@@ -0,0 +1,353 @@
+//! Theme registry implementation
+//!
+//! User themes are plain JSON files in `PathManager::user_themes_dir()`. The
+//! directory is re-scanned on every query so that dropping a file in place is
+//! enough to make a theme available.
+
+use log::{debug, info, warn};
+use std::path::{Path, PathBuf};
+
+use super::types::*;
+use crate::infrastructure::PathManager;
+use crate::service::config::{get_global_config_service, GlobalConfigManager};
+use crate::util::errors::*;
+
+const THEME_FILE_EXTENSION: &str = "json";
+const MAX_THEME_FILE_BYTES: u64 = 256 * 1024;
+
+/// Theme registry
+#[derive(Debug, Clone)]
+pub struct ThemeRegistry {
+    user_themes_dir: PathBuf,
+}
+
+impl ThemeRegistry {
+    /// Creates a registry that reads user themes from `user_themes_dir`.
+    pub fn new(user_themes_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            user_themes_dir: user_themes_dir.into(),
+        }
+    }
+
+    /// Creates a registry rooted at the user config directory.
+    pub fn from_path_manager(path_manager: &PathManager) -> Self {
+        Self::new(path_manager.user_themes_dir())
+    }
+
+    /// Returns the directory scanned for user themes.
+    pub fn user_themes_dir(&self) -> &Path {
+        &self.user_themes_dir
+    }
+
+    /// Lists `system`, built-in and valid user themes, plus diagnostics for
+    /// user theme files that failed validation.
+    pub fn list(&self) -> ThemeCatalog {
+        let mut catalog = ThemeCatalog::default();
+        catalog.themes.push(ThemeSummary {
+            id: SYSTEM_THEME_ID.to_string(),
+            source: ThemeSource::Builtin,
+            name: None,
+            kind: None,
+        });
+        catalog
+            .themes
+            .extend(BUILTIN_THEME_IDS.iter().map(|id| ThemeSummary {
+                id: (*id).to_string(),
+                source: ThemeSource::Builtin,
+                name: None,
+                kind: None,
+            }));
+
+        for path in self.user_theme_files() {
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match Self::load_theme_file(&path) {
+                Ok(theme) => catalog.themes.push(ThemeSummary {
+                    id: theme.id,
+                    source: ThemeSource::User,
+                    name: Some(theme.name),
+                    kind: Some(theme.kind),
+                }),
+                Err(error) => {
+                    debug!(
+                        "Skipping invalid user theme: file={}, error={}",
+                        file_name, error
+                    );
+                    catalog.invalid.push(InvalidUserTheme { file_name, error });
+                }
+            }
+        }
+
+        catalog
+    }
+
+    /// Loads and validates a user theme by id.
+    ///
+    /// Returns `Ok(None)` when no file exists for `theme_id`, and an error when
+    /// the file exists but does not pass schema validation.
+    pub fn get_user_theme(&self, theme_id: &str) -> BitFunResult<Option<ThemeDefinition>> {
+        if !is_valid_theme_id(theme_id) {
+            return Ok(None);
+        }
+        let path = self.user_theme_path(theme_id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Self::load_theme_file(&path)
+            .map(Some)
+            .map_err(BitFunError::validation)
+    }
+
+    /// Validates `theme` and writes it as `<id>.json`, replacing an existing file.
+    pub fn save_user_theme(&self, theme: &ThemeDefinition) -> BitFunResult<()> {
+        theme.validate().map_err(BitFunError::validation)?;
+        std::fs::create_dir_all(&self.user_themes_dir)?;
+        let content = serde_json::to_string_pretty(theme)?;
+        std::fs::write(self.user_theme_path(&theme.id), content)?;
+        info!("User theme saved: theme_id={}", theme.id);
+        Ok(())
+    }
+
+    /// Removes the file for a user theme. Returns `false` when none existed.
+    pub fn delete_user_theme(&self, theme_id: &str) -> BitFunResult<bool> {
+        if !is_valid_theme_id(theme_id) {
+            return Ok(false);
+        }
+        match std::fs::remove_file(self.user_theme_path(theme_id)) {
+            Ok(()) => {
+                info!("User theme deleted: theme_id={}", theme_id);
+                Ok(true)
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Returns whether `theme_id` may be stored in `themes.current`.
+    pub fn is_selectable(&self, theme_id: &str) -> bool {
+        theme_id == SYSTEM_THEME_ID
+            || is_builtin_theme_id(theme_id)
+            || matches!(self.get_user_theme(theme_id), Ok(Some(_)))
+    }
+
+    /// Validates `theme_id` and persists it as the active theme.
+    ///
+    /// `GlobalConfigManager::update_theme` broadcasts `ConfigUpdateEvent::ThemeUpdated`,
+    /// which hosts forward to open windows so the change applies without a restart.
+    pub async fn set_current_theme(&self, theme_id: &str) -> BitFunResult<()> {
+        if theme_id != SYSTEM_THEME_ID && !is_builtin_theme_id(theme_id) {
+            match self.get_user_theme(theme_id)? {
+                Some(_) => {}
+                None => {
+                    return Err(BitFunError::NotFound(format!(
+                        "Theme not found: {}",
+                        theme_id
+                    )));
+                }
+            }
+        }
+
+        GlobalConfigManager.update_theme(theme_id).await?;
+        info!("Theme changed: theme_id={}", theme_id);
+        Ok(())
+    }
+
+    /// Returns the active theme id, falling back to the default when the stored
+    /// id no longer resolves (e.g. a user theme file was deleted).
+    pub async fn current_theme_id(&self) -> BitFunResult<String> {
+        let config_service = get_global_config_service().await?;
+        let current: String = config_service
+            .get_config(Some("themes.current"))
+            .await
+            .unwrap_or_default();
+        if self.is_selectable(&current) {
+            return Ok(current);
+        }
+        if !current.is_empty() {
+            warn!(
+                "Configured theme is unavailable, using default: theme_id={}",
+                current
+            );
+        }
+        Ok(crate::service::config::ThemesConfig::default().current)
+    }
+
+    fn user_theme_path(&self, theme_id: &str) -> PathBuf {
+        self.user_themes_dir
+            .join(format!("{}.{}", theme_id, THEME_FILE_EXTENSION))
+    }
+
+    fn user_theme_files(&self) -> Vec<PathBuf> {
+        let entries = match std::fs::read_dir(&self.user_themes_dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(error) => {
+                warn!(
+                    "Failed to read user themes directory: path={}, error={}",
+                    self.user_themes_dir.display(),
+                    error
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case(THEME_FILE_EXTENSION))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    fn load_theme_file(path: &Path) -> Result<ThemeDefinition, String> {
+        let metadata = std::fs::metadata(path).map_err(|e| e.to_string())?;
+        if metadata.len() > MAX_THEME_FILE_BYTES {
+            return Err(format!(
+                "Theme file is {} bytes, the limit is {}",
+                metadata.len(),
+                MAX_THEME_FILE_BYTES
+            ));
+        }
+
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let theme: ThemeDefinition =
+            serde_json::from_str(&content).map_err(|e| format!("Invalid theme JSON: {}", e))?;
+        theme.validate()?;
+
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if stem != theme.id {
+            return Err(format!(
+                "Theme id '{}' must match its file name '{}.{}'",
+                theme.id, stem, THEME_FILE_EXTENSION
+            ));
+        }
+
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn valid_theme_json(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "name": "Ocean",
+            "type": "dark",
+            "colors": {
+                "bgPrimary": "#0b1e2d",
+                "bgSecondary": "#10283b",
+                "bgScene": "#10283b",
+                "textPrimary": "#e6f1ff",
+                "textMuted": "rgba(230, 241, 255, 0.6)",
+                "accentColor": "#4fc3f7"
+            }
+        })
+    }
+
+    fn write_theme(dir: &Path, file_name: &str, value: &serde_json::Value) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join(file_name), value.to_string()).unwrap();
+    }
+
+    #[test]
+    fn lists_builtin_and_valid_user_themes() {
+        let temp = tempfile::tempdir().unwrap();
+        write_theme(temp.path(), "ocean.json", &valid_theme_json("ocean"));
+
+        let catalog = ThemeRegistry::new(temp.path()).list();
+
+        assert!(catalog.invalid.is_empty());
+        assert_eq!(catalog.themes[0].id, SYSTEM_THEME_ID);
+        assert!(catalog
+            .themes
+            .iter()
+            .any(|theme| theme.id == "bitfun-light" && theme.source == ThemeSource::Builtin));
+        let ocean = catalog
+            .themes
+            .iter()
+            .find(|theme| theme.id == "ocean")
+            .unwrap();
+        assert_eq!(ocean.source, ThemeSource::User);
+        assert_eq!(ocean.kind, Some(ThemeKind::Dark));
+    }
+
+    #[test]
+    fn reports_invalid_user_themes_without_listing_them() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut missing_token = valid_theme_json("broken");
+        missing_token["colors"]
+            .as_object_mut()
+            .unwrap()
+            .remove("accentColor");
+        write_theme(temp.path(), "broken.json", &missing_token);
+        write_theme(temp.path(), "renamed.json", &valid_theme_json("ocean"));
+        write_theme(
+            temp.path(),
+            "bitfun-dark.json",
+            &valid_theme_json("bitfun-dark"),
+        );
+
+        let registry = ThemeRegistry::new(temp.path());
+        let catalog = registry.list();
+
+        assert_eq!(catalog.invalid.len(), 3);
+        assert!(catalog
+            .themes
+            .iter()
+            .all(|theme| theme.source == ThemeSource::Builtin));
+        assert!(registry.get_user_theme("broken").is_err());
+        assert!(!registry.is_selectable("broken"));
+    }
+
+    #[test]
+    fn rejects_color_values_that_are_not_colors() {
+        let mut value = valid_theme_json("ocean");
+        value["colors"]["bgPrimary"] = json!("red; background: url(x)");
+        let theme: ThemeDefinition = serde_json::from_value(value).unwrap();
+        assert!(theme.validate().is_err());
+    }
+
+    #[test]
+    fn selectable_ids_include_system_builtins_and_user_themes() {
+        let temp = tempfile::tempdir().unwrap();
+        write_theme(temp.path(), "ocean.json", &valid_theme_json("ocean"));
+        let registry = ThemeRegistry::new(temp.path());
+
+        assert!(registry.is_selectable("system"));
+        assert!(registry.is_selectable("bitfun-tokyo-night"));
+        assert!(registry.is_selectable("ocean"));
+        assert!(!registry.is_selectable("missing"));
+        assert!(!registry.is_selectable("../ocean"));
+    }
+
+    #[test]
+    fn saved_user_themes_are_listed_until_deleted() {
+        let temp = tempfile::tempdir().unwrap();
+        let registry = ThemeRegistry::new(temp.path().join("themes"));
+        let theme: ThemeDefinition = serde_json::from_value(valid_theme_json("ocean")).unwrap();
+
+        registry.save_user_theme(&theme).unwrap();
+        assert_eq!(registry.get_user_theme("ocean").unwrap(), Some(theme));
+
+        assert!(registry.delete_user_theme("ocean").unwrap());
+        assert!(!registry.delete_user_theme("ocean").unwrap());
+        assert!(!registry.is_selectable("ocean"));
+
+        let reserved: ThemeDefinition =
+            serde_json::from_value(valid_theme_json("bitfun-dark")).unwrap();
+        assert!(registry.save_user_theme(&reserved).is_err());
+    }
+}
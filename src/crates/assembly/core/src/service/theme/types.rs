@@ -0,0 +1,182 @@
+//! Theme type definitions
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Pseudo theme that follows the OS light/dark preference.
+pub const SYSTEM_THEME_ID: &str = "system";
+
+/// Theme ids shipped with the web UI.
+/// Keep in sync with `src/apps/desktop/src/generated/startup_theme_bootstrap.json`.
+pub const BUILTIN_THEME_IDS: &[&str] = &[
+    "bitfun-dark",
+    "bitfun-light",
+    "bitfun-midnight",
+    "bitfun-china-style",
+    "bitfun-china-night",
+    "bitfun-cyber",
+    "bitfun-slate",
+    "bitfun-tokyo-night",
+];
+
+/// Tokens every user theme must define; these drive the native splash screen
+/// and window chrome before the web UI has loaded.
+pub const REQUIRED_THEME_TOKENS: &[&str] = &[
+    "bgPrimary",
+    "bgSecondary",
+    "bgScene",
+    "textPrimary",
+    "textMuted",
+    "accentColor",
+];
+
+const MAX_THEME_ID_LEN: usize = 64;
+const MAX_THEME_TOKENS: usize = 512;
+
+/// Light or dark base palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeKind {
+    Light,
+    Dark,
+}
+
+/// Where a theme definition comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeSource {
+    Builtin,
+    User,
+}
+
+/// A user theme file (`<config>/themes/<id>.json`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ThemeKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Design tokens keyed by camelCase token name, e.g. `bgPrimary`.
+    pub colors: BTreeMap<String, String>,
+}
+
+impl ThemeDefinition {
+    /// Validates the token schema. Returns a human-readable reason on failure.
+    pub fn validate(&self) -> Result<(), String> {
+        if !is_valid_theme_id(&self.id) {
+            return Err(format!(
+                "Invalid theme id '{}': use lowercase letters, digits and '-'",
+                self.id
+            ));
+        }
+        if is_reserved_theme_id(&self.id) {
+            return Err(format!(
+                "Theme id '{}' is reserved by a built-in theme",
+                self.id
+            ));
+        }
+        if self.name.trim().is_empty() {
+            return Err("Theme name must not be empty".to_string());
+        }
+        if self.colors.len() > MAX_THEME_TOKENS {
+            return Err(format!(
+                "Theme defines {} tokens, the limit is {}",
+                self.colors.len(),
+                MAX_THEME_TOKENS
+            ));
+        }
+        for token in REQUIRED_THEME_TOKENS {
+            if !self.colors.contains_key(*token) {
+                return Err(format!("Missing required theme token '{}'", token));
+            }
+        }
+        for (token, value) in &self.colors {
+            if token.is_empty() || !token.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(format!("Invalid theme token name '{}'", token));
+            }
+            if !is_valid_color_value(value) {
+                return Err(format!(
+                    "Invalid color value '{}' for token '{}'",
+                    value, token
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn token(&self, name: &str) -> Option<&str> {
+        self.colors.get(name).map(String::as_str)
+    }
+}
+
+/// Entry returned by theme listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeSummary {
+    pub id: String,
+    pub source: ThemeSource,
+    /// Display name; `None` for built-ins, whose names are localized by the frontend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ThemeKind>,
+}
+
+/// A user theme file that was found but rejected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidUserTheme {
+    pub file_name: String,
+    pub error: String,
+}
+
+/// Result of discovering all themes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeCatalog {
+    pub themes: Vec<ThemeSummary>,
+    pub invalid: Vec<InvalidUserTheme>,
+}
+
+pub fn is_builtin_theme_id(id: &str) -> bool {
+    BUILTIN_THEME_IDS.contains(&id)
+}
+
+fn is_reserved_theme_id(id: &str) -> bool {
+    id == SYSTEM_THEME_ID || is_builtin_theme_id(id)
+}
+
+/// Theme ids double as file stems, so keep them to a portable subset.
+pub fn is_valid_theme_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_THEME_ID_LEN
+        && !id.starts_with('-')
+        && !id.ends_with('-')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Accepts hex colors and CSS color functions; rejects anything that could
+/// break out of a CSS declaration.
+fn is_valid_color_value(value: &str) -> bool {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    let lower = value.to_ascii_lowercase();
+    let is_function = ["rgb(", "rgba(", "hsl(", "hsla("]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix))
+        && lower.ends_with(')');
+    is_function
+        && value[..value.len() - 1]
+            .chars()
+            .skip_while(|c| *c != '(')
+            .skip(1)
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '%' | ' ' | '/'))
+}
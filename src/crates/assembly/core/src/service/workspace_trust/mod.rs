@@ -0,0 +1,18 @@
+//! Workspace trust model.
+//!
+//! Folders opened for the first time run in restricted mode: project-level
+//! skills, project MCP servers, project allow-rules and automatic approval of
+//! tool prompts stay disabled until the user trusts the folder.
+
+mod service;
+mod store;
+mod types;
+
+pub use service::{
+    get_global_workspace_trust_service, is_workspace_restricted,
+    set_global_workspace_trust_service, WorkspaceTrustService,
+};
+pub use types::{
+    WorkspaceTrustDecision, WorkspaceTrustFile, WorkspaceTrustInfo, WorkspaceTrustRecord,
+    WorkspaceTrustState, WORKSPACE_TRUST_VERSION,
+};
@@ -0,0 +1,257 @@
+//! Workspace trust service.
+//!
+//! Holds per-folder trust decisions in memory so hot paths (skill discovery,
+//! MCP config loading, permission resolution) can check trust synchronously.
+//! App-managed folders under the BitFun home and user root are always trusted.
+
+use super::store::WorkspaceTrustStore;
+use super::types::{
+    WorkspaceTrustDecision, WorkspaceTrustInfo, WorkspaceTrustRecord, WorkspaceTrustState,
+};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use chrono::Utc;
+use log::info;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+use tokio::sync::{broadcast, Mutex};
+
+static GLOBAL_WORKSPACE_TRUST_SERVICE: OnceLock<Arc<WorkspaceTrustService>> = OnceLock::new();
+
+const EVENT_CHANNEL_CAPACITY: usize = 32;
+
+pub struct WorkspaceTrustService {
+    store: WorkspaceTrustStore,
+    records: RwLock<Vec<WorkspaceTrustRecord>>,
+    implicit_roots: Vec<PathBuf>,
+    mutation_lock: Mutex<()>,
+    events: broadcast::Sender<WorkspaceTrustInfo>,
+}
+
+impl WorkspaceTrustService {
+    pub async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Arc<Self>> {
+        let implicit_roots = vec![
+            normalize_trust_path(&path_manager.bitfun_home_dir()),
+            normalize_trust_path(path_manager.user_root_dir()),
+        ];
+        let store = WorkspaceTrustStore::new(path_manager).await?;
+        let loaded = store.load().await?;
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        Ok(Arc::new(Self {
+            store,
+            records: RwLock::new(loaded.records),
+            implicit_roots,
+            mutation_lock: Mutex::new(()),
+            events,
+        }))
+    }
+
+    /// Notified with the affected folder's trust whenever a decision changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<WorkspaceTrustInfo> {
+        self.events.subscribe()
+    }
+
+    pub fn trust_info(&self, path: &Path) -> WorkspaceTrustInfo {
+        let records = self
+            .records
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        resolve_trust(&records, &self.implicit_roots, path)
+    }
+
+    pub fn trust_state(&self, path: &Path) -> WorkspaceTrustState {
+        self.trust_info(path).state
+    }
+
+    pub fn list_decisions(&self) -> Vec<WorkspaceTrustRecord> {
+        self.records
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub async fn set_decision(
+        &self,
+        path: &Path,
+        decision: WorkspaceTrustDecision,
+    ) -> BitFunResult<WorkspaceTrustInfo> {
+        let normalized = normalize_trust_path(path);
+        if !normalized.is_absolute() {
+            return Err(BitFunError::validation(format!(
+                "Workspace trust requires an absolute path: {}",
+                path.display()
+            )));
+        }
+        let key = path_key(&normalized);
+
+        let _guard = self.mutation_lock.lock().await;
+        let mut records = self.list_decisions();
+        records.retain(|record| record.path != key);
+        records.push(WorkspaceTrustRecord {
+            path: key.clone(),
+            decision,
+            decided_at_ms: Utc::now().timestamp_millis(),
+        });
+        self.store.save(records.clone()).await?;
+        self.replace_records(records);
+
+        info!(
+            "Workspace trust decision saved: path={}, decision={:?}",
+            key, decision
+        );
+        let info = self.trust_info(&normalized);
+        let _ = self.events.send(info.clone());
+        Ok(info)
+    }
+
+    /// Removes the decision recorded for exactly `path`.
+    pub async fn forget_decision(&self, path: &Path) -> BitFunResult<bool> {
+        let normalized = normalize_trust_path(path);
+        let key = path_key(&normalized);
+
+        let _guard = self.mutation_lock.lock().await;
+        let mut records = self.list_decisions();
+        let before = records.len();
+        records.retain(|record| record.path != key);
+        if records.len() == before {
+            return Ok(false);
+        }
+        self.store.save(records.clone()).await?;
+        self.replace_records(records);
+
+        let _ = self.events.send(self.trust_info(&normalized));
+        Ok(true)
+    }
+
+    fn replace_records(&self, records: Vec<WorkspaceTrustRecord>) {
+        *self
+            .records
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = records;
+    }
+}
+
+/// Whether project-level extensions must be disabled for `workspace_root`.
+///
+/// Returns `false` when no trust service is installed (e.g. embedders that
+/// manage trust themselves), so restricted mode is opt-in per host.
+pub fn is_workspace_restricted(workspace_root: &Path) -> bool {
+    get_global_workspace_trust_service()
+        .is_some_and(|service| service.trust_state(workspace_root).is_restricted())
+}
+
+pub fn get_global_workspace_trust_service() -> Option<Arc<WorkspaceTrustService>> {
+    GLOBAL_WORKSPACE_TRUST_SERVICE.get().cloned()
+}
+
+pub fn set_global_workspace_trust_service(service: Arc<WorkspaceTrustService>) {
+    let _ = GLOBAL_WORKSPACE_TRUST_SERVICE.set(service);
+}
+
+fn resolve_trust(
+    records: &[WorkspaceTrustRecord],
+    implicit_roots: &[PathBuf],
+    path: &Path,
+) -> WorkspaceTrustInfo {
+    let normalized = normalize_trust_path(path);
+    let key = path_key(&normalized);
+    let comparable = PathBuf::from(&key);
+
+    if implicit_roots
+        .iter()
+        .any(|root| comparable.starts_with(path_key(root)))
+    {
+        return WorkspaceTrustInfo {
+            path: key,
+            state: WorkspaceTrustState::Trusted,
+            decided_by: None,
+        };
+    }
+
+    let closest = records
+        .iter()
+        .filter(|record| comparable.starts_with(&record.path))
+        .max_by_key(|record| Path::new(&record.path).components().count());
+
+    WorkspaceTrustInfo {
+        path: key,
+        state: closest
+            .map(|record| record.decision.into())
+            .unwrap_or(WorkspaceTrustState::Unknown),
+        decided_by: closest.map(|record| record.path.clone()),
+    }
+}
+
+fn normalize_trust_path(path: &Path) -> PathBuf {
+    dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Stable string key for a normalized path; case-folded where the file
+/// system is case-insensitive by default.
+fn path_key(path: &Path) -> String {
+    let key = path.to_string_lossy().to_string();
+    if cfg!(any(windows, target_os = "macos")) {
+        key.to_lowercase()
+    } else {
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(path: &str, decision: WorkspaceTrustDecision) -> WorkspaceTrustRecord {
+        WorkspaceTrustRecord {
+            path: path_key(Path::new(path)),
+            decision,
+            decided_at_ms: 0,
+        }
+    }
+
+    #[test]
+    fn closest_decision_wins_and_undecided_paths_are_restricted() {
+        let records = [
+            record("/work", WorkspaceTrustDecision::Trusted),
+            record("/work/vendor", WorkspaceTrustDecision::Restricted),
+        ];
+
+        let trusted = resolve_trust(&records, &[], Path::new("/work/app"));
+        assert_eq!(trusted.state, WorkspaceTrustState::Trusted);
+        assert_eq!(
+            trusted.decided_by.as_deref(),
+            Some(records[0].path.as_str())
+        );
+
+        let vendor = resolve_trust(&records, &[], Path::new("/work/vendor/lib"));
+        assert_eq!(vendor.state, WorkspaceTrustState::Restricted);
+
+        let unknown = resolve_trust(&records, &[], Path::new("/elsewhere"));
+        assert_eq!(unknown.state, WorkspaceTrustState::Unknown);
+        assert!(unknown.state.is_restricted());
+    }
+
+    #[test]
+    fn sibling_prefixes_do_not_inherit_trust() {
+        let records = [record("/work/app", WorkspaceTrustDecision::Trusted)];
+
+        let sibling = resolve_trust(&records, &[], Path::new("/work/app-evil"));
+
+        assert_eq!(sibling.state, WorkspaceTrustState::Unknown);
+    }
+
+    #[test]
+    fn app_managed_roots_are_implicitly_trusted() {
+        let roots = [PathBuf::from("/home/user/.bitfun")];
+
+        let info = resolve_trust(
+            &[],
+            &roots,
+            Path::new("/home/user/.bitfun/personal_assistant/workspace"),
+        );
+
+        assert_eq!(info.state, WorkspaceTrustState::Trusted);
+        assert_eq!(info.decided_by, None);
+    }
+}
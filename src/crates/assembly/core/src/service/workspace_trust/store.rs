@@ -0,0 +1,122 @@
+//! workspace_trust.json persistence wrapper.
+
+use super::types::{WorkspaceTrustFile, WorkspaceTrustRecord, WORKSPACE_TRUST_VERSION};
+use crate::infrastructure::storage::{PersistenceService, StorageOptions};
+use crate::infrastructure::PathManager;
+use crate::util::errors::{BitFunError, BitFunResult};
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+
+pub(super) struct WorkspaceTrustStore {
+    persistence: PersistenceService,
+    path_manager: Arc<PathManager>,
+}
+
+impl WorkspaceTrustStore {
+    pub(super) async fn new(path_manager: Arc<PathManager>) -> BitFunResult<Self> {
+        let trust_dir = path_manager.user_workspace_trust_dir();
+        path_manager.ensure_dir(&trust_dir).await?;
+
+        let persistence = PersistenceService::new(trust_dir).await?;
+
+        Ok(Self {
+            persistence,
+            path_manager,
+        })
+    }
+
+    pub(super) async fn load(&self) -> BitFunResult<WorkspaceTrustFile> {
+        let trust_file_path = self.path_manager.workspace_trust_file();
+        if !trust_file_path.exists() {
+            return Ok(WorkspaceTrustFile::default());
+        }
+
+        let content = fs::read_to_string(&trust_file_path)
+            .await
+            .map_err(|error| BitFunError::service(format!("Failed to read file: {}", error)))?;
+
+        match parse_trust_file_content(&content, &trust_file_path) {
+            Ok(file) => Ok(file),
+            Err(error) => {
+                // Resetting means every workspace falls back to restricted mode,
+                // which is the safe direction.
+                warn!(
+                    "Failed to load workspace trust file; backing up and resetting: path={}, error={}",
+                    trust_file_path.display(),
+                    error
+                );
+                self.backup_incompatible_file(&trust_file_path).await?;
+                self.save(Vec::new()).await?;
+                Ok(WorkspaceTrustFile::default())
+            }
+        }
+    }
+
+    pub(super) async fn save(&self, records: Vec<WorkspaceTrustRecord>) -> BitFunResult<()> {
+        let mut records = records;
+        records.sort_by(|left, right| left.path.cmp(&right.path));
+
+        let data = WorkspaceTrustFile {
+            version: WORKSPACE_TRUST_VERSION,
+            records,
+        };
+
+        self.persistence
+            .save_json("workspace_trust", &data, StorageOptions::default())
+            .await
+    }
+
+    async fn backup_incompatible_file(&self, trust_file_path: &Path) -> BitFunResult<()> {
+        let backup_path = incompatible_backup_path(trust_file_path);
+        fs::rename(trust_file_path, &backup_path)
+            .await
+            .map_err(|error| {
+                BitFunError::service(format!(
+                    "Failed to back up incompatible workspace trust file {} to {}: {}",
+                    trust_file_path.display(),
+                    backup_path.display(),
+                    error
+                ))
+            })?;
+        info!(
+            "Backed up incompatible workspace trust file: source={}, backup={}",
+            trust_file_path.display(),
+            backup_path.display()
+        );
+        Ok(())
+    }
+}
+
+fn incompatible_backup_path(trust_file_path: &Path) -> PathBuf {
+    let timestamp_ms = chrono::Utc::now().timestamp_millis();
+    let file_name = trust_file_path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .unwrap_or("workspace_trust.json");
+    trust_file_path.with_file_name(format!("{}.incompatible.{}.bak", file_name, timestamp_ms))
+}
+
+fn parse_trust_file_content(
+    content: &str,
+    trust_file_path: &Path,
+) -> BitFunResult<WorkspaceTrustFile> {
+    let file: WorkspaceTrustFile = serde_json::from_str(content).map_err(|error| {
+        BitFunError::service(format!(
+            "Failed to parse workspace trust file {}: {}",
+            trust_file_path.display(),
+            error
+        ))
+    })?;
+
+    if file.version != WORKSPACE_TRUST_VERSION {
+        return Err(BitFunError::service(format!(
+            "Unsupported workspace trust file version {} in {}",
+            file.version,
+            trust_file_path.display()
+        )));
+    }
+
+    Ok(file)
+}
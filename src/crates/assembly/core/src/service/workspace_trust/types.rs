@@ -0,0 +1,75 @@
+//! Workspace trust types.
+
+use serde::{Deserialize, Serialize};
+
+pub const WORKSPACE_TRUST_VERSION: u32 = 1;
+
+/// Decision the user made for a folder. Applies to its subfolders unless a
+/// closer folder has its own decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceTrustDecision {
+    Trusted,
+    Restricted,
+}
+
+/// Effective trust of a workspace path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceTrustState {
+    Trusted,
+    Restricted,
+    /// No decision yet; the workspace runs restricted until the user decides.
+    Unknown,
+}
+
+impl WorkspaceTrustState {
+    pub fn is_restricted(self) -> bool {
+        !matches!(self, Self::Trusted)
+    }
+}
+
+impl From<WorkspaceTrustDecision> for WorkspaceTrustState {
+    fn from(decision: WorkspaceTrustDecision) -> Self {
+        match decision {
+            WorkspaceTrustDecision::Trusted => Self::Trusted,
+            WorkspaceTrustDecision::Restricted => Self::Restricted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTrustRecord {
+    /// Normalized folder path the decision applies to.
+    pub path: String,
+    pub decision: WorkspaceTrustDecision,
+    pub decided_at_ms: i64,
+}
+
+/// Trust of a specific workspace and the folder whose decision produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTrustInfo {
+    pub path: String,
+    pub state: WorkspaceTrustState,
+    /// Folder whose decision applies; `None` for undecided or app-managed folders.
+    pub decided_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTrustFile {
+    pub version: u32,
+    #[serde(default)]
+    pub records: Vec<WorkspaceTrustRecord>,
+}
+
+impl Default for WorkspaceTrustFile {
+    fn default() -> Self {
+        Self {
+            version: WORKSPACE_TRUST_VERSION,
+            records: Vec::new(),
+        }
+    }
+}
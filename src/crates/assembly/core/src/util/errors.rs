@@ -2,10 +2,11 @@
 //!
 //! Provide unified error types and handling for the whole application
 
+use crate::service::i18n::LocalizedMessage;
 use bitfun_core_types::errors::{
     ai_error_detail_from_message, classify_ai_error_message, AiErrorDetail, ErrorCategory,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Unified error type for the BitFun application
@@ -62,6 +63,14 @@ pub enum BitFunError {
     #[error("MCP error: {0}")]
     MCPError(String),
 
+    /// JSON-RPC error returned by an MCP server, with its code and data intact.
+    #[error("MCP error: MCP Error {code}: {message}")]
+    MCPServerError {
+        code: i32,
+        message: String,
+        data: Option<serde_json::Value>,
+    },
+
     #[error("Process error: {0}")]
     ProcessError(String),
 
@@ -205,6 +214,14 @@ impl From<bitfun_services_integrations::mcp::MCPRuntimeError> for BitFunError {
     fn from(error: bitfun_services_integrations::mcp::MCPRuntimeError) -> Self {
         use bitfun_services_integrations::mcp::MCPRuntimeErrorKind;
 
+        if let Some(server_error) = error.server_error() {
+            return Self::MCPServerError {
+                code: server_error.code,
+                message: server_error.message.clone(),
+                data: server_error.data.clone(),
+            };
+        }
+
         let message = error.message().to_string();
         match error.kind() {
             MCPRuntimeErrorKind::Configuration => Self::Configuration(message),
@@ -222,6 +239,152 @@ impl From<bitfun_services_integrations::mcp::MCPRuntimeError> for BitFunError {
     }
 }
 
+/// Stable error codes the frontend can branch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiErrorCode {
+    InvalidInput,
+    NotFound,
+    /// The target already exists.
+    Conflict,
+    Configuration,
+    /// Credentials are missing, expired or rejected.
+    Unauthorized,
+    Io,
+    Network,
+    Timeout,
+    Cancelled,
+    /// A peer (model provider, MCP server) answered with a malformed response.
+    Protocol,
+    /// A peer understood the request and refused it.
+    Rejected,
+    /// The backing service failed to start or is still initializing.
+    ServiceUnavailable,
+    Ai,
+    NotImplemented,
+    Internal,
+}
+
+impl ApiErrorCode {
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Network | Self::Timeout | Self::ServiceUnavailable
+        )
+    }
+}
+
+/// Error returned across the Tauri command boundary.
+///
+/// `details` carries domain-specific data, e.g. an `AiErrorDetail` or an
+/// `MCPFailure`, for callers that need more than the code. `localized` is set
+/// when `message` came from the backend message catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Error)]
+#[serde(rename_all = "camelCase")]
+#[error("{message}")]
+pub struct ApiError {
+    pub code: ApiErrorCode,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+    pub retryable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localized: Option<LocalizedMessage>,
+}
+
+impl ApiError {
+    pub fn new<T: Into<String>>(code: ApiErrorCode, message: T) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            details: None,
+            retryable: code.is_retryable(),
+            localized: None,
+        }
+    }
+
+    pub fn invalid_input<T: Into<String>>(message: T) -> Self {
+        Self::new(ApiErrorCode::InvalidInput, message)
+    }
+
+    pub fn not_found<T: Into<String>>(message: T) -> Self {
+        Self::new(ApiErrorCode::NotFound, message)
+    }
+
+    pub fn service_unavailable<T: Into<String>>(message: T) -> Self {
+        Self::new(ApiErrorCode::ServiceUnavailable, message)
+    }
+
+    pub fn internal<T: Into<String>>(message: T) -> Self {
+        Self::new(ApiErrorCode::Internal, message)
+    }
+
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Uses a catalog message as the error text, keeping its id and params so
+    /// the frontend can render it again in another language.
+    pub fn with_localized(mut self, message: LocalizedMessage) -> Self {
+        self.message = message.text.clone();
+        self.localized = Some(message);
+        self
+    }
+
+    /// Prefixes the message, keeping the code and details.
+    pub fn with_context(mut self, context: impl std::fmt::Display) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+}
+
+impl From<BitFunError> for ApiError {
+    fn from(error: BitFunError) -> Self {
+        let code = match &error {
+            BitFunError::AIClient(_) => {
+                let detail = error.error_detail();
+                let retryable = detail.retryable.unwrap_or(false);
+                return Self::new(ApiErrorCode::Ai, error.to_string())
+                    .with_details(serde_json::to_value(&detail).unwrap_or_default())
+                    .with_retryable(retryable);
+            }
+            #[cfg(feature = "service-integrations")]
+            BitFunError::MCPError(_) | BitFunError::MCPServerError { .. } => {
+                return crate::service::mcp::MCPFailure::from_error(&error).into();
+            }
+            BitFunError::Validation(_) => ApiErrorCode::InvalidInput,
+            BitFunError::NotFound(_) => ApiErrorCode::NotFound,
+            BitFunError::Configuration(_) => ApiErrorCode::Configuration,
+            BitFunError::Io(_) => ApiErrorCode::Io,
+            BitFunError::Http(_) => ApiErrorCode::Network,
+            BitFunError::Timeout(_) => ApiErrorCode::Timeout,
+            BitFunError::Cancelled(_) => ApiErrorCode::Cancelled,
+            BitFunError::NotImplemented(_) => ApiErrorCode::NotImplemented,
+            _ => ApiErrorCode::Internal,
+        };
+        Self::new(code, error.to_string())
+    }
+}
+
+/// Errors that still come out of string-based helpers.
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        Self::internal(message)
+    }
+}
+
+impl From<&str> for ApiError {
+    fn from(message: &str) -> Self {
+        Self::internal(message)
+    }
+}
+
 impl From<BitFunError> for String {
     fn from(err: BitFunError) -> String {
         err.to_string()
@@ -239,3 +402,30 @@ impl From<&str> for BitFunError {
         BitFunError::Service(error.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiError, ApiErrorCode, BitFunError};
+    use serde_json::json;
+
+    #[test]
+    fn api_errors_carry_code_and_retryability() {
+        let error = ApiError::from(BitFunError::Timeout("skill market".to_string()));
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "timeout",
+                "message": "Timeout: skill market",
+                "retryable": true,
+            })
+        );
+
+        let error = ApiError::from(BitFunError::validation("bad skill name"));
+        assert_eq!(error.code, ApiErrorCode::InvalidInput);
+        assert!(!error.retryable);
+
+        let error = ApiError::from(BitFunError::ai("Request failed with status 429"));
+        assert_eq!(error.code, ApiErrorCode::Ai);
+        assert!(error.details.is_some());
+    }
+}
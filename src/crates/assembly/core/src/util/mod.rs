@@ -1,10 +1,14 @@
 //! Common utilities and type definitions
 
 pub mod errors;
+pub use bitfun_services_core::format;
 pub mod front_matter_markdown;
 pub mod json_extract;
 pub mod plain_output;
+pub mod secret_redaction;
+pub use bitfun_services_core::hashing;
 pub use bitfun_services_core::process_manager;
+pub use bitfun_services_core::process_tree;
 pub mod timing;
 pub mod token_counter;
 pub mod types;
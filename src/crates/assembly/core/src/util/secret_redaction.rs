@@ -0,0 +1,87 @@
+//! Blanks credential-like values in JSON documents before they leave the
+//! machine, e.g. in backups or diagnostics bundles.
+
+use serde_json::Value;
+
+/// JSON keys whose string values are secrets wherever they appear.
+const SECRET_KEYS: &[&str] = &[
+    "apikey",
+    "accesstoken",
+    "refreshtoken",
+    "token",
+    "secret",
+    "clientsecret",
+    "password",
+    "authorization",
+    "privatekey",
+];
+/// Inside `env` and `headers` maps, names containing these are secrets too,
+/// e.g. `GITHUB_TOKEN` or `X-Api-Key`.
+const SECRET_NAME_HINTS: &[&str] = &["key", "token", "secret", "password", "auth"];
+
+/// Blanks credential-like string values in `value`, e.g. before a config is
+/// shared, and returns their JSON pointers.
+pub fn strip_secrets(value: &mut Value) -> Vec<String> {
+    let mut pointers = Vec::new();
+    redact_secrets(value, "", false, &mut pointers);
+    pointers
+}
+
+fn redact_secrets(value: &mut Value, pointer: &str, in_secret_map: bool, found: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, child) in fields.iter_mut() {
+                let child_pointer = format!("{}/{}", pointer, escape_pointer_token(key));
+                let name = key.to_ascii_lowercase().replace(['_', '-'], "");
+                let is_secret = SECRET_KEYS.contains(&name.as_str())
+                    || (in_secret_map && SECRET_NAME_HINTS.iter().any(|hint| name.contains(hint)));
+                match child {
+                    Value::String(secret) if is_secret && !secret.is_empty() => {
+                        secret.clear();
+                        found.push(child_pointer);
+                    }
+                    _ => redact_secrets(
+                        child,
+                        &child_pointer,
+                        name == "env" || name == "headers",
+                        found,
+                    ),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                redact_secrets(item, &format!("{}/{}", pointer, index), false, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_secrets;
+    use serde_json::json;
+
+    #[test]
+    fn strip_secrets_blanks_secret_keys_and_env_entries() {
+        let mut value = json!({
+            "models": [{ "name": "gpt", "api_key": "sk-1" }],
+            "mcpServers": { "gh": { "env": { "GITHUB_TOKEN": "ghp", "PATH": "/bin" } } }
+        });
+
+        let mut pointers = strip_secrets(&mut value);
+        pointers.sort();
+
+        assert_eq!(
+            pointers,
+            vec!["/mcpServers/gh/env/GITHUB_TOKEN", "/models/0/api_key"]
+        );
+        assert_eq!(value["models"][0]["api_key"], "");
+        assert_eq!(value["mcpServers"]["gh"]["env"]["PATH"], "/bin");
+    }
+}
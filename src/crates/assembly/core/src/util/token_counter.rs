@@ -1,8 +1,10 @@
 //! Token estimation utility
 
 use crate::util::types::{Message, ToolDefinition};
+use bitfun_ai_adapters::tokenizer;
 
-/// Heuristic: ASCII chars 0.3 token, non-ASCII chars 0.6 token
+/// Counts with the o200k vocabulary unless a model is given; see
+/// [`bitfun_ai_adapters::tokenizer`] for how models map to tokenizers.
 pub struct TokenCounter;
 
 impl TokenCounter {
@@ -10,18 +12,20 @@ impl TokenCounter {
         if text.is_empty() {
             return 0;
         }
+        tokenizer::default_tokenizer().count_tokens(text)
+    }
 
-        let mut token_count: f32 = 0.;
-
-        for c in text.chars() {
-            if c.is_ascii() {
-                token_count += 0.3;
-            } else {
-                token_count += 0.6;
-            }
+    pub fn count_tokens(model: &str, text: &str) -> usize {
+        if text.is_empty() {
+            return 0;
         }
+        tokenizer::count_tokens(model, text)
+    }
 
-        token_count as usize
+    /// Longest prefix of `text` within `max_tokens` as counted by
+    /// [`Self::estimate_tokens`].
+    pub fn truncate_to_tokens(text: &str, max_tokens: usize) -> &str {
+        tokenizer::default_tokenizer().truncate_to_tokens(text, max_tokens)
     }
 
     pub fn estimate_message_tokens(message: &Message) -> usize {
@@ -260,6 +260,7 @@ mod tests {
             custom_request_body: None,
             custom_request_body_mode: None,
             auth: Default::default(),
+            rate_limit: None,
         }
     }
 
@@ -189,6 +189,8 @@ pub enum ConnectionTestMessageCode {
     TlsOrCertificateIssue,
     ProxyIssue,
     NetworkIssue,
+    /// The provider rejected the API key or token (expired, revoked or wrong).
+    AuthenticationFailed,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
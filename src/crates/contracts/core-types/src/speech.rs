@@ -75,9 +75,24 @@ pub struct SpeechModelProgressEvent {
     pub status: SpeechModelStatus,
 }
 
+/// Which backend transcribes a voice input session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechInputProvider {
+    /// Built-in sherpa-onnx models downloaded through the model manager.
+    #[default]
+    Local,
+    /// The managed whisper.cpp runtime component.
+    WhisperCpp,
+    /// An OpenAI-compatible transcription endpoint from the model config.
+    Cloud,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpeechStartInputSessionRequest {
+    #[serde(default)]
+    pub provider: SpeechInputProvider,
     #[serde(default)]
     pub model_id: Option<String>,
     #[serde(default)]
@@ -92,6 +107,8 @@ pub struct SpeechStartInputSessionRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SpeechInputSession {
     pub session_id: String,
+    #[serde(default)]
+    pub provider: SpeechInputProvider,
     pub model_id: String,
     pub language: String,
     pub sample_rate: u32,
@@ -126,6 +143,33 @@ pub struct SpeechCancelInputSessionRequest {
     pub session_id: String,
 }
 
+/// Best-effort transcript of the audio received so far; superseded by the
+/// result of finishing the session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeechPartialTranscriptEvent {
+    pub session_id: String,
+    pub text: String,
+    pub audio_duration_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeechWhisperCppModel {
+    pub model_id: String,
+    pub path: PathBuf,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeechWhisperCppStatus {
+    /// Resolved `whisper-cli` binary, if the runtime component is installed.
+    pub binary_path: Option<PathBuf>,
+    pub models_dir: PathBuf,
+    pub models: Vec<SpeechWhisperCppModel>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpeechTranscriptionResult {
@@ -134,3 +178,78 @@ pub struct SpeechTranscriptionResult {
     pub duration_ms: u64,
     pub audio_duration_seconds: f64,
 }
+
+/// Which backend reads text aloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextToSpeechProvider {
+    /// The operating system's built-in voices.
+    #[default]
+    System,
+    /// An OpenAI-compatible `/audio/speech` endpoint.
+    Cloud,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechVoice {
+    /// Value passed back as `voice` when speaking.
+    pub id: String,
+    pub name: String,
+    /// BCP 47 style tag such as `en-US`, when the backend reports one.
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechListVoicesResponse {
+    pub provider: TextToSpeechProvider,
+    pub voices: Vec<TextToSpeechVoice>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechSpeakRequest {
+    pub text: String,
+    /// Overrides the voice configured for the language.
+    #[serde(default)]
+    pub voice: Option<String>,
+    /// Language of `text`, used to pick the configured voice.
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// Encoded audio that the webview plays itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechAudioClip {
+    pub mime_type: String,
+    pub data_base64: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechSpeakResult {
+    pub utterance_id: String,
+    pub provider: TextToSpeechProvider,
+    pub voice: Option<String>,
+    /// Set for cloud speech; system voices play natively and leave this empty.
+    pub audio: Option<TextToSpeechAudioClip>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextToSpeechUtteranceState {
+    Started,
+    Finished,
+    Stopped,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextToSpeechStateEvent {
+    pub utterance_id: String,
+    pub state: TextToSpeechUtteranceState,
+    pub error: Option<String>,
+}
@@ -23,5 +23,8 @@ pub use backend::{
 pub use bitfun_core_types::ToolImageAttachment;
 pub use emitter::EventEmitter;
 pub use frontend_projection::{project_agentic_frontend_event, AgenticFrontendEvent};
-pub use speech::{SPEECH_MODEL_PROGRESS_EVENT, SPEECH_MODEL_STATUS_CHANGED_EVENT};
+pub use speech::{
+    SPEECH_MODEL_PROGRESS_EVENT, SPEECH_MODEL_STATUS_CHANGED_EVENT,
+    SPEECH_PARTIAL_TRANSCRIPT_EVENT, SPEECH_TTS_STATE_EVENT,
+};
 pub use types::*;
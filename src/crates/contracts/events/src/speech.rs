@@ -1,2 +1,4 @@
 pub const SPEECH_MODEL_PROGRESS_EVENT: &str = "speech://model-download-progress";
 pub const SPEECH_MODEL_STATUS_CHANGED_EVENT: &str = "speech://model-status-changed";
+pub const SPEECH_PARTIAL_TRANSCRIPT_EVENT: &str = "speech://partial-transcript";
+pub const SPEECH_TTS_STATE_EVENT: &str = "speech://tts-state";
@@ -314,6 +314,31 @@ pub fn parse_commit_analysis_value(value: &serde_json::Value) -> Result<AICommit
     })
 }
 
+/// JSON schema of the commit analysis object the commit message prompt asks
+/// for; `parse_commit_analysis_value` reads values matching it.
+pub fn commit_analysis_json_schema() -> serde_json::Value {
+    let optional_text = serde_json::json!({ "type": ["string", "null"] });
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "type": {
+                "type": "string",
+                "enum": [
+                    "feat", "fix", "docs", "style", "refactor", "perf", "test", "chore", "ci",
+                    "revert"
+                ]
+            },
+            "scope": optional_text,
+            "title": { "type": "string" },
+            "body": optional_text,
+            "breaking_changes": optional_text,
+            "reasoning": { "type": "string" },
+            "confidence": { "type": "number", "minimum": 0, "maximum": 1 }
+        },
+        "required": ["type", "title"]
+    })
+}
+
 pub fn parse_commit_analysis_json(json: &str) -> Result<AICommitAnalysis, String> {
     let value = serde_json::from_str::<serde_json::Value>(json)
         .map_err(|error| format!("Failed to parse AI response: {}", error))?;
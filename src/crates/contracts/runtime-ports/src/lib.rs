@@ -1764,6 +1764,16 @@ impl AgentInputAttachment {
             metadata,
         }
     }
+
+    /// A file stored by the session attachment service, resolved by id when
+    /// the turn is submitted.
+    pub fn session_attachment(id: impl Into<String>) -> Self {
+        Self {
+            kind: "session_attachment".to_string(),
+            id: id.into(),
+            metadata: serde_json::Map::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2211,9 +2221,16 @@ impl DelegationPolicy {
     }
 
     pub fn spawn_child(self) -> Self {
+        self.spawn_child_within(1)
+    }
+
+    /// Child policy when subagents may nest up to `max_depth` levels below
+    /// the top-level session.
+    pub fn spawn_child_within(self, max_depth: u8) -> Self {
+        let nesting_depth = self.nesting_depth.saturating_add(1);
         Self {
-            allow_subagent_spawn: false,
-            nesting_depth: self.nesting_depth.saturating_add(1),
+            allow_subagent_spawn: nesting_depth < max_depth,
+            nesting_depth,
         }
     }
 }
@@ -3289,6 +3306,16 @@ mod tests {
         assert_eq!(child.spawn_child().nesting_depth, 2);
     }
 
+    #[test]
+    fn delegation_policy_allows_spawn_until_max_depth() {
+        let child = DelegationPolicy::top_level().spawn_child_within(2);
+        assert!(child.allow_subagent_spawn);
+
+        let grandchild = child.spawn_child_within(2);
+        assert!(!grandchild.allow_subagent_spawn);
+        assert_eq!(grandchild.nesting_depth, 2);
+    }
+
     #[test]
     fn dynamic_tool_descriptor_omits_missing_provider_id() {
         let descriptor = DynamicToolDescriptor {
@@ -371,6 +371,31 @@ pub fn render_user_context_reminder(sections: impl IntoIterator<Item = String>)
     }
 }
 
+/// Renders per-turn context gathered by context providers. Each section is
+/// expected to carry its own heading.
+pub fn render_provided_context_reminder(
+    sections: impl IntoIterator<Item = String>,
+) -> Option<String> {
+    let sections = sections
+        .into_iter()
+        .map(|section| section.trim().to_string())
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<_>>();
+
+    if sections.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "# Provided Context\n{}\n\n{}",
+            PROVIDED_CONTEXT_PROMPT,
+            sections.join("\n\n")
+        ))
+    }
+}
+
+const PROVIDED_CONTEXT_PROMPT: &str =
+    "The following context was collected from the user's environment for this turn. Sections may be truncated to fit their token budget; read the source directly when you need the full content.";
+
 const USER_CONTEXT_PROMPT: &str =
     "As you answer the user's questions, you can use the following context.\nNote: this is a snapshot captured at the start of the conversation and may not reflect real-time changes made afterward.";
 
@@ -610,6 +635,8 @@ pub struct PrependedPromptReminders {
     pub agent_listing: Option<String>,
     pub runtime_context: Option<String>,
     pub user_context: Option<String>,
+    /// Rebuilt every turn from the enabled context providers.
+    pub provided_context: Option<String>,
 }
 
 impl PrependedPromptReminders {
@@ -630,6 +657,9 @@ impl PrependedPromptReminders {
         if let Some(user_context) = self.user_context.as_deref() {
             reminders.push(user_context);
         }
+        if let Some(provided_context) = self.provided_context.as_deref() {
+            reminders.push(provided_context);
+        }
         reminders
     }
 }
@@ -0,0 +1,167 @@
+//! Companion files a skill references from its markdown body.
+//!
+//! Skills point at scripts, templates and reference docs by paths relative to
+//! the skill folder. This module only finds those references and normalizes
+//! them; hosts check that they exist and resolve them against the folder.
+
+use serde::{Deserialize, Serialize};
+
+/// A relative path referenced by a skill, as resolved by the host.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillAssetReference {
+    /// Path as written in the skill, normalized (`./` and `..` folded).
+    pub reference: String,
+    /// Absolute path on the host that owns the skill.
+    pub resolved_path: String,
+    pub exists: bool,
+    /// False when the reference leaves the skill folder, so a bundle of the
+    /// folder would not carry it.
+    pub inside_skill_dir: bool,
+}
+
+/// Relative file paths referenced by `content`, in order of first
+/// appearance. Looks at link and image targets, inline code spans and the
+/// words of fenced code blocks.
+pub fn extract_skill_asset_references(content: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+    let mut in_fence = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            candidates.extend(line.split_whitespace().map(str::to_string));
+            continue;
+        }
+        candidates.extend(link_targets(line));
+        candidates.extend(
+            code_spans(line)
+                .flat_map(str::split_whitespace)
+                .map(str::to_string),
+        );
+    }
+
+    let mut references: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let Some(reference) = normalize_asset_reference(&candidate) else {
+            continue;
+        };
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    }
+    references
+}
+
+fn link_targets(line: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("](") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find(')') else {
+            break;
+        };
+        // Drop an optional title: [a](path "title").
+        let target = rest[..end].split_whitespace().next().unwrap_or_default();
+        targets.push(target.trim_matches(['<', '>']).to_string());
+        rest = &rest[end + 1..];
+    }
+    targets
+}
+
+fn code_spans(line: &str) -> impl Iterator<Item = &str> {
+    line.split('`').skip(1).step_by(2)
+}
+
+/// Returns the folded relative path when `candidate` looks like a file
+/// reference, e.g. `scripts/fill.py` or `./template.docx`.
+fn normalize_asset_reference(candidate: &str) -> Option<String> {
+    let candidate = candidate
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '(' | '[' | ']'))
+        .trim_end_matches(|c: char| matches!(c, '.' | ',' | ';' | ':' | ')'));
+    let candidate = candidate.split(['#', '?']).next().unwrap_or_default();
+    if candidate.is_empty()
+        || candidate.contains("://")
+        || candidate.starts_with(['/', '\\', '~', '$', '-', '@', '%'])
+        || candidate.contains(|c: char| {
+            c.is_whitespace() || matches!(c, '*' | '<' | '>' | '|' | '{' | '}' | '=' | '`')
+        })
+        || candidate.chars().nth(1) == Some(':')
+    {
+        return None;
+    }
+
+    let candidate = candidate.replace('\\', "/");
+    let explicit = candidate.starts_with("./") || candidate.starts_with("../");
+    if !explicit && !candidate.contains('/') {
+        return None;
+    }
+    let file_name = candidate.rsplit('/').next().unwrap_or_default();
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    if stem.is_empty()
+        || extension.is_empty()
+        || extension.len() > 8
+        || !extension.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+
+    let mut parts: Vec<&str> = Vec::new();
+    for part in candidate.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.last().is_some_and(|last| *last != "..") {
+                    parts.pop();
+                } else {
+                    parts.push("..");
+                }
+            }
+            part => parts.push(part),
+        }
+    }
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_come_from_links_code_spans_and_fences() {
+        let content = "\
+Fill the form with [the helper](./scripts/fill_form.py \"helper\").
+Start from `templates/report.docx`, see [docs](https://example.com/a.md).
+
+```bash
+python scripts/fill_form.py --schema ../shared/schema.json
+```
+
+Mentions like `SKILL.md`, `/etc/hosts` and `*.py` are not assets.
+";
+        assert_eq!(
+            extract_skill_asset_references(content),
+            vec![
+                "scripts/fill_form.py",
+                "templates/report.docx",
+                "../shared/schema.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn references_are_folded() {
+        assert_eq!(
+            normalize_asset_reference("./a/../b/./c.txt").as_deref(),
+            Some("b/c.txt")
+        );
+        assert_eq!(
+            normalize_asset_reference("../../x/y.sh,").as_deref(),
+            Some("../../x/y.sh")
+        );
+        assert_eq!(normalize_asset_reference("C:/tools/x.exe"), None);
+        assert_eq!(normalize_asset_reference("scripts/"), None);
+    }
+}
@@ -0,0 +1,161 @@
+//! Where a skill can run, as declared in its front matter.
+//!
+//! `min_bitfun_version` and `platforms` are parsed here and checked against
+//! the version and platform the host supplies. Hosts keep incompatible skills
+//! listed but disabled, with the reason returned by
+//! [`check_skill_compatibility`].
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillCompatibility {
+    /// Oldest BitFun release the skill works with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_bitfun_version: Option<String>,
+    /// Platforms the skill supports (`windows`, `macos`, `linux`, matching
+    /// `std::env::consts::OS`); empty means any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub platforms: Vec<String>,
+}
+
+impl SkillCompatibility {
+    pub(crate) fn from_front_matter(metadata: &Value) -> Self {
+        let min_bitfun_version = metadata
+            .get("min_bitfun_version")
+            .and_then(|value| match value {
+                Value::String(text) => Some(text.trim().to_string()),
+                // `min_bitfun_version: 0.3` parses as a number.
+                Value::Number(number) => Some(number.to_string()),
+                _ => None,
+            })
+            .filter(|value| !value.is_empty());
+
+        let mut platforms: Vec<String> = match metadata.get("platforms") {
+            Some(Value::String(text)) => text.split(',').map(normalize_platform).collect(),
+            Some(Value::Sequence(items)) => items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(normalize_platform)
+                .collect(),
+            _ => Vec::new(),
+        };
+        platforms.retain(|platform| !platform.is_empty());
+        platforms.sort();
+        platforms.dedup();
+
+        Self {
+            min_bitfun_version,
+            platforms,
+        }
+    }
+
+    pub fn is_unconstrained(&self) -> bool {
+        self.min_bitfun_version.is_none() && self.platforms.is_empty()
+    }
+}
+
+fn normalize_platform(value: &str) -> String {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "darwin" | "mac" | "osx" => "macos".to_string(),
+        "win" | "win32" => "windows".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Platform name of the running host, as used in `platforms`.
+pub fn current_skill_platform() -> &'static str {
+    std::env::consts::OS
+}
+
+/// Returns why the skill cannot run on this host, or `None` when it can.
+///
+/// `platform` is `None` when the host running the skill is not known (for
+/// example a project skill in a remote workspace); only the version is
+/// checked then. An unparseable `min_bitfun_version` is ignored.
+pub fn check_skill_compatibility(
+    compatibility: &SkillCompatibility,
+    app_version: &str,
+    platform: Option<&str>,
+) -> Option<String> {
+    if let Some(min_version) = compatibility.min_bitfun_version.as_deref() {
+        if let (Some(required), Some(current)) =
+            (parse_version(min_version), parse_version(app_version))
+        {
+            if current < required {
+                return Some(format!(
+                    "Requires BitFun {} or newer (current {})",
+                    min_version, app_version
+                ));
+            }
+        }
+    }
+
+    if let Some(platform) = platform {
+        if !compatibility.platforms.is_empty()
+            && !compatibility.platforms.iter().any(|item| item == platform)
+        {
+            return Some(format!(
+                "Only supports {} (current {})",
+                compatibility.platforms.join(", "),
+                platform
+            ));
+        }
+    }
+
+    None
+}
+
+/// Numeric release components with trailing zeros dropped, so `0.3` and
+/// `0.3.0` compare equal. Pre-release and build suffixes are ignored.
+fn parse_version(value: &str) -> Option<Vec<u64>> {
+    let value = value.trim();
+    let value = value.strip_prefix(['v', 'V']).unwrap_or(value);
+    let release = value.split(['-', '+']).next()?;
+    let mut parts = release
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    while parts.last() == Some(&0) {
+        parts.pop();
+    }
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_compare_by_release_components() {
+        assert_eq!(parse_version("v0.3"), parse_version("0.3.0"));
+        assert!(parse_version("0.10.0") > parse_version("0.9.4"));
+        assert_eq!(parse_version("1.2.0-beta.1"), Some(vec![1, 2]));
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn compatibility_reports_version_before_platform() {
+        let compatibility = SkillCompatibility {
+            min_bitfun_version: Some("0.5.0".to_string()),
+            platforms: vec!["macos".to_string()],
+        };
+        assert_eq!(
+            check_skill_compatibility(&compatibility, "0.4.2", Some("linux")).as_deref(),
+            Some("Requires BitFun 0.5.0 or newer (current 0.4.2)")
+        );
+        assert_eq!(
+            check_skill_compatibility(&compatibility, "0.5.0", Some("linux")).as_deref(),
+            Some("Only supports macos (current linux)")
+        );
+        assert_eq!(
+            check_skill_compatibility(&compatibility, "0.5.1", None),
+            None
+        );
+        assert_eq!(
+            check_skill_compatibility(&compatibility, "0.5.1", Some("macos")),
+            None
+        );
+    }
+}
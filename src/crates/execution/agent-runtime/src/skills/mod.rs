@@ -1,19 +1,26 @@
 //! Provider-neutral skill contracts and runtime decisions.
 //!
 //! This module owns skill DTOs, built-in catalog facts, mode default policy,
-//! override resolution, markdown parsing, and assistant-visible payload
+//! override resolution, markdown parsing, asset reference extraction,
+//! compatibility checks, declared parameters, and assistant-visible payload
 //! rendering. Product hosts still own filesystem/config IO and registry
 //! scanning.
 
+mod assets;
 mod catalog;
+mod compat;
 mod keys;
+mod parameters;
 mod policy;
 mod resolver;
 mod roots;
 mod selection;
 mod types;
 
+pub use assets::{extract_skill_asset_references, SkillAssetReference};
 pub use catalog::builtin_skill_group_key;
+pub use compat::{check_skill_compatibility, current_skill_platform, SkillCompatibility};
+pub use parameters::{skill_parameters_schema, validate_skill_arguments, SkillParameter};
 pub use policy::resolve_builtin_default_enabled;
 pub use resolver::{
     normalize_user_mode_skill_overrides, resolve_skill_default_enabled_for_mode,
@@ -21,9 +28,10 @@ pub use resolver::{
 };
 pub use roots::{
     normalize_local_skill_dir_name, normalize_remote_skill_dir_name,
-    resolve_user_config_skill_root, SkillRootSpec, BITFUN_SKILL_SOURCE_ID,
-    BITFUN_SKILL_SOURCE_LABEL, BITFUN_SYSTEM_SKILL_DIR, BITFUN_SYSTEM_SKILL_SLOT,
-    BITFUN_USER_SKILL_SLOT, PROJECT_SKILL_KEY_PREFIX, PROJECT_SKILL_ROOTS, USER_CONFIG_SKILL_ROOTS,
+    resolve_user_config_skill_root, select_skill_md_language, skill_md_file_name,
+    skill_md_variant_language, SkillRootSpec, BITFUN_SKILL_SOURCE_ID, BITFUN_SKILL_SOURCE_LABEL,
+    BITFUN_SYSTEM_SKILL_DIR, BITFUN_SYSTEM_SKILL_SLOT, BITFUN_USER_SKILL_SLOT,
+    PROJECT_SKILL_KEY_PREFIX, PROJECT_SKILL_ROOTS, SKILL_MD_FILE_NAME, USER_CONFIG_SKILL_ROOTS,
     USER_HOME_SKILL_ROOTS, USER_SKILL_KEY_PREFIX,
 };
 pub use selection::{
@@ -34,5 +42,5 @@ pub use selection::{
 };
 pub use types::{
     render_loaded_skill_for_assistant, ModeSkillInfo, ModeSkillStateReason, SkillData, SkillInfo,
-    SkillLocation, SkillParseError,
+    SkillLocation, SkillModelPreference, SkillParseError, SKILL_MODEL_INHERIT,
 };
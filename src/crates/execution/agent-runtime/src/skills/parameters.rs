@@ -0,0 +1,225 @@
+//! Arguments a skill declares in its front matter.
+//!
+//! `parameters` maps each argument name to a JSON schema type, an optional
+//! description and whether it is required. Hosts advertise the declaration as
+//! a JSON schema in the skill listing and check invocation arguments against
+//! it before loading the skill.
+
+use super::types::SkillParseError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value as JsonValue};
+use serde_yaml::Value;
+
+const SKILL_PARAMETER_TYPES: &[&str] =
+    &["string", "number", "integer", "boolean", "array", "object"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillParameter {
+    pub name: String,
+    /// JSON schema type: `string`, `number`, `integer`, `boolean`, `array`
+    /// or `object`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Reads `parameters` from the front matter. Each entry is either a type name
+/// (`pages: integer`) or a mapping with `type`, `description` and `required`.
+pub(crate) fn parse_skill_parameters(
+    metadata: &Value,
+) -> Result<Vec<SkillParameter>, SkillParseError> {
+    let Some(declared) = metadata.get("parameters").filter(|value| !value.is_null()) else {
+        return Ok(Vec::new());
+    };
+    let Some(declared) = declared.as_mapping() else {
+        return Err(SkillParseError::InvalidFormat(
+            "'parameters' must map parameter names to their types".to_string(),
+        ));
+    };
+
+    let mut parameters = Vec::new();
+    for (name, spec) in declared {
+        let Some(name) = name.as_str().map(str::trim).filter(|name| !name.is_empty()) else {
+            return Err(SkillParseError::InvalidFormat(
+                "Skill parameter names must be strings".to_string(),
+            ));
+        };
+        let (kind, description, required) = match spec {
+            Value::String(kind) => (kind.as_str(), None, false),
+            Value::Mapping(_) => (
+                spec.get("type").and_then(Value::as_str).unwrap_or("string"),
+                spec.get("description")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|description| !description.is_empty())
+                    .map(str::to_string),
+                spec.get("required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            ),
+            _ => {
+                return Err(SkillParseError::InvalidFormat(format!(
+                    "Skill parameter '{}' must be a type name or a mapping",
+                    name
+                )))
+            }
+        };
+        let kind = kind.trim().to_ascii_lowercase();
+        if !SKILL_PARAMETER_TYPES.contains(&kind.as_str()) {
+            return Err(SkillParseError::InvalidFormat(format!(
+                "Skill parameter '{}' has unsupported type '{}'",
+                name, kind
+            )));
+        }
+        parameters.push(SkillParameter {
+            name: name.to_string(),
+            kind,
+            description,
+            required,
+        });
+    }
+    Ok(parameters)
+}
+
+/// JSON schema of the arguments object for `parameters`.
+pub fn skill_parameters_schema(parameters: &[SkillParameter]) -> JsonValue {
+    let mut properties = Map::new();
+    for parameter in parameters {
+        let mut property = Map::new();
+        property.insert("type".to_string(), json!(parameter.kind));
+        if let Some(description) = &parameter.description {
+            property.insert("description".to_string(), json!(description));
+        }
+        properties.insert(parameter.name.clone(), JsonValue::Object(property));
+    }
+    let required: Vec<&str> = parameters
+        .iter()
+        .filter(|parameter| parameter.required)
+        .map(|parameter| parameter.name.as_str())
+        .collect();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    })
+}
+
+/// Checks invocation arguments against the declared parameters: every
+/// argument must be declared, required ones present and each value of the
+/// declared type.
+pub fn validate_skill_arguments(
+    parameters: &[SkillParameter],
+    arguments: &Map<String, JsonValue>,
+) -> Result<(), String> {
+    for (name, value) in arguments {
+        let Some(parameter) = parameters.iter().find(|parameter| &parameter.name == name) else {
+            return Err(if parameters.is_empty() {
+                format!("Unknown argument '{}': the skill takes no arguments", name)
+            } else {
+                format!(
+                    "Unknown argument '{}'; declared parameters: {}",
+                    name,
+                    parameters
+                        .iter()
+                        .map(|parameter| parameter.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+        };
+        let matches_type = match parameter.kind.as_str() {
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        };
+        if !matches_type {
+            return Err(format!(
+                "Argument '{}' must be of type {}",
+                name, parameter.kind
+            ));
+        }
+    }
+
+    if let Some(missing) = parameters
+        .iter()
+        .find(|parameter| parameter.required && !arguments.contains_key(&parameter.name))
+    {
+        return Err(format!("Missing required argument '{}'", missing.name));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(yaml: &str) -> Result<Vec<SkillParameter>, SkillParseError> {
+        parse_skill_parameters(&serde_yaml::from_str(yaml).expect("valid yaml"))
+    }
+
+    #[test]
+    fn parameters_accept_type_names_and_mappings() {
+        let parsed = parameters(
+            "parameters:\n  file:\n    type: string\n    description: PDF to fill\n    required: true\n  pages: Integer\n",
+        )
+        .expect("parameters should parse");
+
+        assert_eq!(
+            parsed,
+            vec![
+                SkillParameter {
+                    name: "file".to_string(),
+                    kind: "string".to_string(),
+                    description: Some("PDF to fill".to_string()),
+                    required: true,
+                },
+                SkillParameter {
+                    name: "pages".to_string(),
+                    kind: "integer".to_string(),
+                    description: None,
+                    required: false,
+                },
+            ]
+        );
+        assert_eq!(
+            skill_parameters_schema(&parsed)["required"],
+            json!(["file"])
+        );
+        assert!(parameters("parameters:\n  file: path\n").is_err());
+        assert!(parameters("parameters: [file]\n").is_err());
+    }
+
+    #[test]
+    fn arguments_are_checked_against_parameters() {
+        let parsed = parameters("parameters:\n  file:\n    required: true\n  pages: integer\n")
+            .expect("parameters should parse");
+        let arguments = |value: JsonValue| value.as_object().cloned().expect("object");
+
+        assert_eq!(
+            validate_skill_arguments(&parsed, &arguments(json!({ "file": "a.pdf", "pages": 3 }))),
+            Ok(())
+        );
+        assert_eq!(
+            validate_skill_arguments(&parsed, &arguments(json!({ "pages": 3 }))),
+            Err("Missing required argument 'file'".to_string())
+        );
+        assert_eq!(
+            validate_skill_arguments(&parsed, &arguments(json!({ "file": "a", "pages": "3" }))),
+            Err("Argument 'pages' must be of type integer".to_string())
+        );
+        assert_eq!(
+            validate_skill_arguments(&[], &arguments(json!({ "file": "a" }))),
+            Err("Unknown argument 'file': the skill takes no arguments".to_string())
+        );
+    }
+}
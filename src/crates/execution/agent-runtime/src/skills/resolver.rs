@@ -32,9 +32,12 @@ pub fn normalize_user_mode_skill_overrides(
 }
 
 pub fn resolve_skill_default_enabled_for_mode(skill: &SkillInfo, mode_id: &str) -> bool {
+    if skill.incompatible_reason.is_some() {
+        return false;
+    }
     match skill.level {
         SkillLocation::Project => true,
-        SkillLocation::User => {
+        SkillLocation::User | SkillLocation::Builtin => {
             if !skill.is_builtin {
                 true
             } else {
@@ -71,6 +74,15 @@ pub fn resolve_skill_state_for_mode(
     user_overrides: &UserModeSkillOverrides,
     disabled_project_skills: &HashSet<String>,
 ) -> ModeSkillState {
+    // Overrides cannot enable a skill that does not run on this host.
+    if skill.incompatible_reason.is_some() {
+        return ModeSkillState {
+            default_enabled: false,
+            effective_enabled: false,
+            reason: ModeSkillStateReason::Incompatible,
+        };
+    }
+
     match skill.level {
         SkillLocation::Project => {
             let disabled = disabled_project_skills.contains(&skill.key);
@@ -84,7 +96,7 @@ pub fn resolve_skill_state_for_mode(
                 },
             }
         }
-        SkillLocation::User => {
+        SkillLocation::User | SkillLocation::Builtin => {
             let default_state = resolve_default_state_for_user_skill(skill, mode_id);
 
             if default_state.default_enabled {
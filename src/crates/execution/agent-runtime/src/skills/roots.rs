@@ -7,6 +7,9 @@ pub const BITFUN_SYSTEM_SKILL_SLOT: &str = "bitfun-system";
 pub const BITFUN_SYSTEM_SKILL_DIR: &str = ".system";
 pub const BITFUN_SKILL_SOURCE_ID: &str = "bitfun";
 pub const BITFUN_SKILL_SOURCE_LABEL: &str = "BitFun";
+/// Default skill definition. `SKILL.<lang>.md` siblings (e.g. `SKILL.zh-CN.md`)
+/// translate it for a specific app language.
+pub const SKILL_MD_FILE_NAME: &str = "SKILL.md";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SkillRootSpec {
@@ -139,3 +142,47 @@ pub fn normalize_remote_skill_dir_name(path: &str) -> Option<String> {
         .filter(|value| !value.is_empty())
         .map(|value| value.to_string())
 }
+
+/// Language tag of a `SKILL.<lang>.md` variant file name.
+pub fn skill_md_variant_language(file_name: &str) -> Option<&str> {
+    file_name
+        .strip_prefix("SKILL.")?
+        .strip_suffix(".md")
+        .filter(|language| {
+            !language.is_empty()
+                && language
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+/// Picks the variant to load for `app_language` among the languages a skill
+/// provides: an exact tag match first, then a variant named after the primary
+/// subtag (`zh` for `zh-CN`). `None` means the default `SKILL.md`.
+pub fn select_skill_md_language<'a>(
+    app_language: &str,
+    available: &'a [String],
+) -> Option<&'a str> {
+    let normalize = |tag: &str| tag.replace('_', "-").to_ascii_lowercase();
+    let wanted = normalize(app_language);
+    if wanted.is_empty() {
+        return None;
+    }
+    let primary = wanted.split('-').next().unwrap_or_default();
+    available
+        .iter()
+        .find(|language| normalize(language) == wanted)
+        .or_else(|| {
+            available
+                .iter()
+                .find(|language| normalize(language) == primary)
+        })
+        .map(String::as_str)
+}
+
+pub fn skill_md_file_name(language: Option<&str>) -> String {
+    match language {
+        Some(language) => format!("SKILL.{}.md", language),
+        None => SKILL_MD_FILE_NAME.to_string(),
+    }
+}
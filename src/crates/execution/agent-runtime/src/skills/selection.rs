@@ -45,6 +45,10 @@ impl SkillCandidate {
                 group_key,
                 is_shadowed: false,
                 shadowed_by_key: None,
+                languages: Vec::new(),
+                compatibility: data.compatibility,
+                incompatible_reason: None,
+                parameters: data.parameters,
             },
             priority,
         }
@@ -56,6 +60,7 @@ pub enum ExplicitSkillInvocationResolution {
     Found(SkillInfo),
     NotFound,
     DisabledForMode { mode_id: String },
+    Incompatible { reason: String },
 }
 
 fn build_skill_key(prefix: &str, slot: &str, dir_name: &str) -> String {
@@ -92,7 +97,7 @@ pub fn sort_skill_candidates_by_dir(mut skills: Vec<SkillCandidate>) -> Vec<Skil
 fn skill_level_rank(level: SkillLocation) -> u8 {
     match level {
         SkillLocation::Project => 0,
-        SkillLocation::User => 1,
+        SkillLocation::User | SkillLocation::Builtin => 1,
     }
 }
 
@@ -254,8 +259,11 @@ pub fn resolve_default_hidden_builtin_for_explicit_invocation(
         return ExplicitSkillInvocationResolution::NotFound;
     };
 
-    if info.level == SkillLocation::User
-        && info.is_builtin
+    if let Some(reason) = info.incompatible_reason {
+        return ExplicitSkillInvocationResolution::Incompatible { reason };
+    }
+
+    if info.is_builtin
         && info.group_key.as_deref() == Some("gstack")
         && !resolve_skill_default_enabled_for_mode(&info, mode_id)
     {
@@ -305,6 +313,10 @@ mod tests {
                 group_key: None,
                 is_shadowed: false,
                 shadowed_by_key: None,
+                languages: Vec::new(),
+                compatibility: Default::default(),
+                incompatible_reason: None,
+                parameters: Vec::new(),
             },
             priority: 0,
         }
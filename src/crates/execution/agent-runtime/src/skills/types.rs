@@ -1,5 +1,9 @@
+use super::assets::SkillAssetReference;
+use super::compat::SkillCompatibility;
+use super::parameters::{parse_skill_parameters, skill_parameters_schema, SkillParameter};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
 use serde_yaml::Value;
 use std::path::Path;
 
@@ -18,6 +22,9 @@ pub enum SkillParseError {
 pub enum SkillLocation {
     User,
     Project,
+    /// Skills shipped with BitFun. They are reinstalled on update, so they are
+    /// read-only and are edited through a user-level copy.
+    Builtin,
 }
 
 impl SkillLocation {
@@ -25,8 +32,13 @@ impl SkillLocation {
         match self {
             SkillLocation::User => "user",
             SkillLocation::Project => "project",
+            SkillLocation::Builtin => "builtin",
         }
     }
+
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, SkillLocation::Builtin)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,13 +65,33 @@ pub struct SkillInfo {
     pub is_shadowed: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shadowed_by_key: Option<String>,
+    /// Languages with a `SKILL.<lang>.md` variant next to the default file.
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default, skip_serializing_if = "SkillCompatibility::is_unconstrained")]
+    pub compatibility: SkillCompatibility,
+    /// Set by the host when `compatibility` rules the skill out; such skills
+    /// stay listed but are disabled in every mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incompatible_reason: Option<String>,
+    /// Arguments the skill accepts, from `parameters` in the front matter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<SkillParameter>,
 }
 
 impl SkillInfo {
     pub fn to_xml_desc(&self) -> String {
+        if self.parameters.is_empty() {
+            return format!(
+                r#"<skill name="{}">{}</skill>"#,
+                self.name, self.description
+            );
+        }
         format!(
-            r#"<skill name="{}">{}</skill>"#,
-            self.name, self.description
+            r#"<skill name="{}">{}<parameters>{}</parameters></skill>"#,
+            self.name,
+            self.description,
+            skill_parameters_schema(&self.parameters)
         )
     }
 }
@@ -74,6 +106,7 @@ pub enum ModeSkillStateReason {
     BuiltinPolicyDisabled,
     EnabledByUserOverride,
     DisabledByUserOverride,
+    Incompatible,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +131,27 @@ pub struct SkillData {
     pub path: String,
     pub source_slot: String,
     pub dir_name: String,
+    /// Companion files referenced by `content`, filled in by the host that
+    /// loaded the skill.
+    pub assets: Vec<SkillAssetReference>,
+    /// Model the skill asks for while it drives the task (`model` in the
+    /// front matter): `primary`, `fast` or a configured model id.
+    pub model: Option<String>,
+    /// `min_bitfun_version` and `platforms` from the front matter.
+    pub compatibility: SkillCompatibility,
+    /// Arguments declared under `parameters` in the front matter.
+    pub parameters: Vec<SkillParameter>,
+}
+
+/// Front-matter value that keeps the session's own model.
+pub const SKILL_MODEL_INHERIT: &str = "inherit";
+
+/// Model preference of the skill most recently loaded in a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillModelPreference {
+    pub skill_name: String,
+    pub model: String,
 }
 
 fn parse_front_matter_markdown(content: &str) -> Result<(Value, String), SkillParseError> {
@@ -147,6 +201,15 @@ impl SkillData {
             .map(str::to_string)
             .ok_or(SkillParseError::MissingField("description"))?;
 
+        let model = metadata
+            .get("model")
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && *value != SKILL_MODEL_INHERIT)
+            .map(str::to_string);
+        let compatibility = SkillCompatibility::from_front_matter(&metadata);
+        let parameters = parse_skill_parameters(&metadata)?;
+
         let skill_content = if with_content { body } else { String::new() };
         let dir_name = Path::new(&path)
             .file_name()
@@ -163,13 +226,21 @@ impl SkillData {
             path,
             source_slot: String::new(),
             dir_name,
+            assets: Vec::new(),
+            model,
+            compatibility,
+            parameters,
         })
     }
 }
 
+/// Renders a loaded skill for the model. `arguments` are the validated
+/// invocation arguments and are echoed back so the model sees the values the
+/// skill runs with.
 pub fn render_loaded_skill_for_assistant(
     skill_data: &SkillData,
     loaded_by_stable_key: bool,
+    arguments: &Map<String, JsonValue>,
 ) -> String {
     let loaded_from = if loaded_by_stable_key {
         format!(" from stable key '{}'", skill_data.key)
@@ -177,8 +248,26 @@ pub fn render_loaded_skill_for_assistant(
         String::new()
     };
 
-    format!(
+    let mut rendered = format!(
         "Skill '{}' loaded successfully{}. Note: any paths mentioned in this skill are relative to {}, not the workspace.\n\n<skill_content>\n{}\n</skill_content>",
         skill_data.name, loaded_from, skill_data.path, skill_data.content
-    )
+    );
+    if !arguments.is_empty() {
+        rendered.push_str(&format!(
+            "\n\n<skill_arguments>\n{}\n</skill_arguments>",
+            JsonValue::Object(arguments.clone())
+        ));
+    }
+    if !skill_data.assets.is_empty() {
+        rendered.push_str("\n\n<skill_assets>");
+        for asset in &skill_data.assets {
+            let status = if asset.exists { "" } else { " (not found)" };
+            rendered.push_str(&format!(
+                "\n- {} -> {}{}",
+                asset.reference, asset.resolved_path, status
+            ));
+        }
+        rendered.push_str("\n</skill_assets>");
+    }
+    rendered
 }
@@ -78,6 +78,7 @@ fn prepended_prompt_reminders_keep_runtime_injection_order() {
         agent_listing: Some("agents".to_string()),
         runtime_context: Some("runtime-context".to_string()),
         user_context: Some("user-context".to_string()),
+        provided_context: Some("provided-context".to_string()),
     };
 
     assert_eq!(
@@ -87,7 +88,8 @@ fn prepended_prompt_reminders_keep_runtime_injection_order() {
             "skills",
             "agents",
             "runtime-context",
-            "user-context"
+            "user-context",
+            "provided-context"
         ]
     );
     assert!(PrependedPromptReminders::default()
@@ -1,15 +1,19 @@
 use std::collections::HashSet;
 use std::path::PathBuf;
 
+use serde_json::{json, Map};
+
 use bitfun_agent_runtime::skills::{
     annotate_shadowed_skills, build_mode_skill_infos, builtin_skill_group_key,
     filter_candidates_for_mode, render_loaded_skill_for_assistant, resolve_builtin_default_enabled,
     resolve_default_hidden_builtin_for_explicit_invocation, resolve_skill_default_enabled_for_mode,
     resolve_skill_state_for_mode, resolve_user_config_skill_root, resolve_visible_skills,
-    sort_skills, ExplicitSkillInvocationResolution, ModeSkillStateReason, SkillCandidate,
-    SkillData, SkillInfo, SkillLocation, UserModeSkillOverrides, BITFUN_SYSTEM_SKILL_DIR,
-    BITFUN_SYSTEM_SKILL_SLOT, BITFUN_USER_SKILL_SLOT, PROJECT_SKILL_KEY_PREFIX,
-    PROJECT_SKILL_ROOTS, USER_CONFIG_SKILL_ROOTS, USER_HOME_SKILL_ROOTS, USER_SKILL_KEY_PREFIX,
+    select_skill_md_language, skill_md_file_name, skill_md_variant_language, sort_skills,
+    validate_skill_arguments, ExplicitSkillInvocationResolution, ModeSkillStateReason,
+    SkillCandidate, SkillCompatibility, SkillData, SkillInfo, SkillLocation,
+    UserModeSkillOverrides, BITFUN_SYSTEM_SKILL_DIR, BITFUN_SYSTEM_SKILL_SLOT,
+    BITFUN_USER_SKILL_SLOT, PROJECT_SKILL_KEY_PREFIX, PROJECT_SKILL_ROOTS, SKILL_MODEL_INHERIT,
+    USER_CONFIG_SKILL_ROOTS, USER_HOME_SKILL_ROOTS, USER_SKILL_KEY_PREFIX,
 };
 
 fn builtin_skill(dir_name: &str) -> SkillInfo {
@@ -18,7 +22,7 @@ fn builtin_skill(dir_name: &str) -> SkillInfo {
         name: dir_name.to_string(),
         description: String::new(),
         path: format!("/tmp/{}", dir_name),
-        level: SkillLocation::User,
+        level: SkillLocation::Builtin,
         source_slot: "bitfun-system".to_string(),
         source_id: "bitfun".to_string(),
         source_label: "BitFun".to_string(),
@@ -27,6 +31,10 @@ fn builtin_skill(dir_name: &str) -> SkillInfo {
         group_key: builtin_skill_group_key(dir_name).map(str::to_string),
         is_shadowed: false,
         shadowed_by_key: None,
+        languages: Vec::new(),
+        compatibility: Default::default(),
+        incompatible_reason: None,
+        parameters: Vec::new(),
     }
 }
 
@@ -45,6 +53,10 @@ fn custom_user_skill(dir_name: &str) -> SkillInfo {
         group_key: None,
         is_shadowed: false,
         shadowed_by_key: None,
+        languages: Vec::new(),
+        compatibility: Default::default(),
+        incompatible_reason: None,
+        parameters: Vec::new(),
     }
 }
 
@@ -63,6 +75,10 @@ fn project_skill(dir_name: &str) -> SkillInfo {
         group_key: None,
         is_shadowed: false,
         shadowed_by_key: None,
+        languages: Vec::new(),
+        compatibility: Default::default(),
+        incompatible_reason: None,
+        parameters: Vec::new(),
     }
 }
 
@@ -286,17 +302,130 @@ Use the pdf workflow.
     assert_eq!(data.description, "Work with PDF files.");
     assert_eq!(data.dir_name, "pdf");
     assert_eq!(data.content, "Use the pdf workflow.\n");
+    assert_eq!(data.model, None);
+    assert!(data.parameters.is_empty());
 
-    let assistant = render_loaded_skill_for_assistant(&data, false);
+    let assistant = render_loaded_skill_for_assistant(&data, false, &Map::new());
     assert!(assistant.contains("Skill 'pdf' loaded successfully."));
     assert!(assistant.contains("relative to /workspace/.bitfun/skills/pdf"));
     assert!(assistant.contains("<skill_content>\nUse the pdf workflow.\n\n</skill_content>"));
     assert!(!assistant.contains("from stable key"));
+    assert!(!assistant.contains("<skill_arguments>"));
 
-    let stable_assistant = render_loaded_skill_for_assistant(&data, true);
+    let stable_assistant = render_loaded_skill_for_assistant(&data, true, &Map::new());
     assert!(stable_assistant.contains("from stable key 'project::bitfun::pdf'"));
 }
 
+#[test]
+fn declared_skill_parameters_reach_the_listing_and_the_loaded_payload() {
+    let markdown = "---\nname: fill\ndescription: Fill forms.\nparameters:\n  file:\n    type: string\n    required: true\n---\nbody\n";
+    let data = SkillData::from_markdown(
+        "/skills/fill".to_string(),
+        markdown,
+        SkillLocation::User,
+        true,
+    )
+    .expect("valid skill markdown should parse");
+    let candidate = SkillCandidate::from_data(
+        data.clone(),
+        BITFUN_USER_SKILL_SLOT,
+        "bitfun",
+        "BitFun",
+        USER_SKILL_KEY_PREFIX,
+        0,
+        false,
+    );
+
+    let listing = candidate.info.to_xml_desc();
+    let schema = listing
+        .strip_prefix(r#"<skill name="fill">Fill forms.<parameters>"#)
+        .and_then(|rest| rest.strip_suffix("</parameters></skill>"))
+        .expect("listing should carry the parameter schema");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(schema).unwrap(),
+        json!({
+            "type": "object",
+            "properties": { "file": { "type": "string" } },
+            "required": ["file"],
+            "additionalProperties": false
+        })
+    );
+
+    let arguments = json!({ "file": "a.pdf" }).as_object().cloned().unwrap();
+    assert_eq!(
+        validate_skill_arguments(&data.parameters, &arguments),
+        Ok(())
+    );
+    let assistant = render_loaded_skill_for_assistant(&data, false, &arguments);
+    assert!(assistant.contains("<skill_arguments>\n{\"file\":\"a.pdf\"}\n</skill_arguments>"));
+}
+
+#[test]
+fn skill_model_front_matter_is_parsed() {
+    let parse = |model: &str| {
+        let markdown = format!("---\nname: pdf\ndescription: d\nmodel: {model}\n---\nbody\n");
+        SkillData::from_markdown(
+            "/skills/pdf".to_string(),
+            &markdown,
+            SkillLocation::User,
+            false,
+        )
+        .expect("valid skill markdown should parse")
+        .model
+    };
+
+    assert_eq!(parse("fast").as_deref(), Some("fast"));
+    assert_eq!(parse(" gpt-mini ").as_deref(), Some("gpt-mini"));
+    assert_eq!(parse(SKILL_MODEL_INHERIT), None);
+}
+
+#[test]
+fn skill_compatibility_front_matter_is_parsed() {
+    let markdown = "---\nname: pdf\ndescription: d\nmin_bitfun_version: 0.4\nplatforms: [Darwin, linux]\n---\nbody\n";
+    let data = SkillData::from_markdown(
+        "/skills/pdf".to_string(),
+        markdown,
+        SkillLocation::User,
+        false,
+    )
+    .expect("valid skill markdown should parse");
+
+    assert_eq!(
+        data.compatibility,
+        SkillCompatibility {
+            min_bitfun_version: Some("0.4".to_string()),
+            platforms: vec!["linux".to_string(), "macos".to_string()],
+        }
+    );
+}
+
+#[test]
+fn incompatible_skills_are_disabled_in_every_mode() {
+    let mut skill = custom_user_skill("pdf");
+    skill.incompatible_reason = Some("Only supports macos (current linux)".to_string());
+    let overrides = UserModeSkillOverrides {
+        disabled_skills: Vec::new(),
+        enabled_skills: vec![skill.key.clone()],
+    };
+
+    let state = resolve_skill_state_for_mode(&skill, "agentic", &overrides, &HashSet::new());
+    assert!(!state.effective_enabled);
+    assert_eq!(state.reason, ModeSkillStateReason::Incompatible);
+
+    let candidate = SkillCandidate {
+        info: skill,
+        priority: 0,
+    };
+    assert!(matches!(
+        resolve_default_hidden_builtin_for_explicit_invocation(
+            "pdf",
+            vec![candidate],
+            Some("agentic")
+        ),
+        ExplicitSkillInvocationResolution::Incompatible { .. }
+    ));
+}
+
 #[test]
 fn skill_candidate_key_group_and_resolution_are_runtime_owned() {
     let markdown = r#"---
@@ -546,3 +675,22 @@ fn explicit_invocation_hidden_builtin_fallback_is_runtime_owned() {
         ExplicitSkillInvocationResolution::NotFound
     ));
 }
+
+#[test]
+fn localized_skill_md_variants_fall_back_to_the_default_file() {
+    assert_eq!(skill_md_variant_language("SKILL.zh-CN.md"), Some("zh-CN"));
+    assert_eq!(skill_md_variant_language("SKILL.md"), None);
+    assert_eq!(skill_md_variant_language("SKILL..md"), None);
+    assert_eq!(skill_md_variant_language("SKILL.zh/CN.md"), None);
+    assert_eq!(skill_md_variant_language("README.zh-CN.md"), None);
+
+    let available = vec!["en".to_string(), "zh-CN".to_string()];
+    assert_eq!(select_skill_md_language("zh-CN", &available), Some("zh-CN"));
+    assert_eq!(select_skill_md_language("zh_cn", &available), Some("zh-CN"));
+    assert_eq!(select_skill_md_language("en-US", &available), Some("en"));
+    assert_eq!(select_skill_md_language("zh-TW", &available), None);
+    assert_eq!(select_skill_md_language("", &available), None);
+
+    assert_eq!(skill_md_file_name(Some("zh-CN")), "SKILL.zh-CN.md");
+    assert_eq!(skill_md_file_name(None), "SKILL.md");
+}
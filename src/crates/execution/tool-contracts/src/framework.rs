@@ -2168,6 +2168,77 @@ pub struct ToolPathPolicy {
     pub edit_roots: Vec<String>,
     #[serde(default)]
     pub delete_roots: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<ToolWriteSandbox>,
+}
+
+/// Containment for agent writes in a local workspace. Paths inside the
+/// workspace root or an extra root are always writable; traversal out of the
+/// root is always rejected. Runtime artifacts are not affected.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolWriteSandbox {
+    /// Absolute directories writable in addition to the workspace root.
+    #[serde(default)]
+    pub extra_roots: Vec<String>,
+    /// Reject absolute paths outside the allowed roots instead of leaving
+    /// them to the `external_directory` permission.
+    #[serde(default)]
+    pub confine_to_workspace: bool,
+    /// Raise a `sensitive_file` permission intent for writes to files such as
+    /// `.git/config`, CI definitions and lockfiles.
+    #[serde(default)]
+    pub approve_sensitive_files: bool,
+}
+
+pub const SENSITIVE_FILE_PERMISSION_ACTION: &str = "sensitive_file";
+
+/// Workspace-relative patterns for files whose modification can run code or
+/// change what gets built: VCS config and hooks, CI definitions, lockfiles.
+const SENSITIVE_WRITE_PATTERNS: &[&str] = &[
+    ".git/config",
+    ".git/hooks/*",
+    ".gitmodules",
+    ".github/workflows/*",
+    ".github/actions/*",
+    ".gitlab-ci.yml",
+    ".circleci/*",
+    ".buildkite/*",
+    "azure-pipelines.yml",
+    "bitbucket-pipelines.yml",
+    "Jenkinsfile",
+    ".travis.yml",
+    "*.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "pnpm-lock.yaml",
+    "go.sum",
+];
+
+/// Whether a workspace-relative path (any separator) names a sensitive file.
+/// Patterns without a directory also match in nested packages.
+pub fn is_sensitive_write_path(relative_path: &str) -> bool {
+    let normalized = relative_path.replace('\\', "/");
+    let normalized = normalized.trim_start_matches("./").trim_start_matches('/');
+    let file_name = normalized.rsplit('/').next().unwrap_or(normalized);
+    SENSITIVE_WRITE_PATTERNS.iter().any(|pattern| {
+        if pattern.contains('/') {
+            sensitive_pattern_matches(normalized, pattern)
+        } else {
+            sensitive_pattern_matches(file_name, pattern)
+        }
+    })
+}
+
+/// `*` matches any run of characters, including `/`.
+fn sensitive_pattern_matches(value: &str, pattern: &str) -> bool {
+    match pattern.split_once('*') {
+        None => value == pattern,
+        Some((prefix, suffix)) => {
+            value.len() >= prefix.len() + suffix.len()
+                && value.starts_with(prefix)
+                && value.ends_with(suffix)
+        }
+    }
 }
 
 impl ToolPathPolicy {
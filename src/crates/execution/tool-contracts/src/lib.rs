@@ -15,7 +15,10 @@ pub mod input_validator;
 pub mod mcp_tool_bridge;
 pub mod permission_intent;
 pub mod tool_execution_presentation;
+pub mod tool_exposure_budget;
+pub mod tool_replay;
 pub mod tool_result_storage;
+pub mod tool_search;
 pub mod tool_snapshot;
 
 pub use acp_tool_bridge::{
@@ -59,7 +62,7 @@ pub use framework::{
     collect_loaded_deferred_tool_specs, get_tool_spec_input_schema,
     get_tool_spec_is_concurrency_safe, get_tool_spec_is_readonly, get_tool_spec_short_description,
     is_bitfun_current_session_uri, is_bitfun_runtime_uri, is_bitfun_tool_uri,
-    is_miniapp_headless_agent_run, is_remote_posix_path_within_root,
+    is_miniapp_headless_agent_run, is_remote_posix_path_within_root, is_sensitive_write_path,
     is_tool_path_allowed_by_resolved_roots, materialize_static_tool_provider_groups,
     miniapp_headless_agent_tool_restrictions, normalize_absolute_posix_path, normalize_host_path,
     normalize_runtime_relative_path, parse_bitfun_current_session_uri, parse_bitfun_runtime_uri,
@@ -88,8 +91,8 @@ pub use framework::{
     ToolManifestPolicyTool, ToolPathBackend, ToolPathContractError, ToolPathOperation,
     ToolPathPolicy, ToolPathResolution, ToolRef, ToolRegistry, ToolRegistryItem, ToolRenderOptions,
     ToolRestrictionError, ToolResult, ToolRuntimeAssembly, ToolRuntimeRestrictions,
-    ToolWorkspaceKind, ValidationResult, BITFUN_CURRENT_SESSION_URI_PREFIX,
-    BITFUN_RUNTIME_URI_PREFIX, GET_TOOL_SPEC_TOOL_NAME,
+    ToolWorkspaceKind, ToolWriteSandbox, ValidationResult, BITFUN_CURRENT_SESSION_URI_PREFIX,
+    BITFUN_RUNTIME_URI_PREFIX, GET_TOOL_SPEC_TOOL_NAME, SENSITIVE_FILE_PERMISSION_ACTION,
 };
 pub use input_validator::InputValidator;
 pub use mcp_tool_bridge::{
@@ -112,12 +115,25 @@ pub use tool_execution_presentation::{
     ToolExecutionErrorPresentation, TOOL_ERROR_ARGUMENTS_PREVIEW_BYTES, USER_REJECTED_TOOL_MESSAGE,
     USER_STEERING_INTERRUPTED_MESSAGE,
 };
+pub use tool_exposure_budget::{
+    select_budgeted_tools, ToolExposureBudget, ToolExposureBudgetMetrics, ToolExposureCandidate,
+    ToolExposureSelection, ToolExposureSignals, TOOL_EXPOSURE_BUDGET_EXEMPT_TOOLS,
+};
+pub use tool_replay::{
+    ToolInvocationRecord, ToolReplayBundle, ToolReplayCursor, ToolReplayDivergence,
+    ToolReplayStatus, TOOL_INVOCATION_LOG_FILE_NAME, TOOL_REPLAY_BUNDLE_SCHEMA_VERSION,
+};
 pub use tool_result_storage::{
     build_persisted_tool_output_message, count_tool_result_lines, generate_tool_result_preview,
     sanitize_tool_result_file_component, select_tool_result_indices_for_persistence,
     tool_result_is_persisted_output, PersistedToolOutput, ToolResultPersistenceCandidate,
     ToolResultStoragePolicy, DEFAULT_MAX_TOOL_RESULT_CHARS, MAX_TOOL_RESULTS_PER_ROUND_CHARS,
-    PERSISTED_OUTPUT_CLOSING_TAG, PERSISTED_OUTPUT_TAG, TOOL_RESULT_PREVIEW_CHARS,
+    PERSISTED_OUTPUT_CLOSING_TAG, PERSISTED_OUTPUT_TAG, READ_MORE_TOOL_NAME,
+    TOOL_RESULT_CHARS_PER_TOKEN, TOOL_RESULT_PREVIEW_CHARS,
+};
+pub use tool_search::{
+    search_tool_catalog, tokenize_tool_search_query, ToolSearchEntry, ToolSearchMatch,
+    ToolSearchSource, DEFAULT_TOOL_SEARCH_LIMIT, MAX_TOOL_SEARCH_LIMIT, SEARCH_TOOLS_TOOL_NAME,
 };
 pub use tool_snapshot::{
     materialize_tool_snapshot, MaterializedToolSnapshot, ToolCallSnapshotGuard,
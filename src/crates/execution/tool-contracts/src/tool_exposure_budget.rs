@@ -0,0 +1,199 @@
+//! Per-turn tool exposure budget.
+//!
+//! Large MCP servers can add dozens of tool schemas to every request. When the
+//! budget is enabled, only the most relevant tools are sent with full schemas;
+//! the rest are demoted to deferred tools that stay loadable through
+//! `GetToolSpec` and discoverable through `SearchTools`.
+
+use crate::deferred_tool::CALL_DEFERRED_TOOL_NAME;
+use crate::framework::{tool_manifest_sort_rank, GET_TOOL_SPEC_TOOL_NAME};
+use crate::tool_search::{tokenize_tool_search_query, SEARCH_TOOLS_TOOL_NAME};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Tools that make demoted tools reachable; they never count against the
+/// budget and are never demoted.
+pub const TOOL_EXPOSURE_BUDGET_EXEMPT_TOOLS: &[&str] = &[
+    GET_TOOL_SPEC_TOOL_NAME,
+    CALL_DEFERRED_TOOL_NAME,
+    SEARCH_TOOLS_TOOL_NAME,
+];
+
+/// Query terms shorter than this are too generic to signal relevance.
+const MIN_REQUEST_TERM_CHARS: usize = 3;
+const MAX_REQUEST_MATCH_SCORE: u32 = 60;
+
+/// A tool competing for a slot in the budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolExposureCandidate {
+    pub name: String,
+    pub description: String,
+    /// MCP and other runtime-registered tools rank below built-in tools when
+    /// nothing else distinguishes them.
+    pub is_dynamic: bool,
+}
+
+/// Per-turn relevance signals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolExposureSignals {
+    /// Tools called earlier in the session, most recent first.
+    pub recent_tool_names: Vec<String>,
+    /// The user's request for this turn.
+    pub request_text: String,
+    /// Languages detected in the workspace, e.g. `rust` or `typescript`.
+    pub workspace_languages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolExposureBudget {
+    pub max_tools: usize,
+    /// Tools the user always wants exposed. Pinned tools do not count
+    /// against `max_tools`.
+    pub pinned_tools: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolExposureBudgetMetrics {
+    pub candidate_count: usize,
+    pub exposed_count: usize,
+    pub demoted_count: usize,
+    pub pinned_count: usize,
+    /// Exposed tools that were selected because of a recency, request or
+    /// language signal rather than base priority alone.
+    pub signal_matched_count: usize,
+    /// Characters of tool descriptions kept out of the request.
+    pub demoted_description_chars: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolExposureSelection {
+    /// Exposed tool names in candidate order.
+    pub exposed: Vec<String>,
+    /// Demoted tool names in candidate order.
+    pub demoted: Vec<String>,
+    pub metrics: ToolExposureBudgetMetrics,
+}
+
+/// Selects at most `budget.max_tools` candidates to expose, plus pinned and
+/// exempt tools.
+///
+/// Candidates are scored by how recently they were called, how well their
+/// name and description match the request, and whether they mention a
+/// workspace language, on top of a base priority that keeps core tools ahead.
+/// Ties fall back to the manifest order, then the name, so the same inputs
+/// always select the same tools.
+pub fn select_budgeted_tools(
+    candidates: &[ToolExposureCandidate],
+    signals: &ToolExposureSignals,
+    budget: &ToolExposureBudget,
+) -> ToolExposureSelection {
+    let pinned = budget
+        .pinned_tools
+        .iter()
+        .map(String::as_str)
+        .collect::<HashSet<_>>();
+    let request_terms = tokenize_tool_search_query(&signals.request_text)
+        .into_iter()
+        .filter(|term| term.chars().count() >= MIN_REQUEST_TERM_CHARS)
+        .collect::<Vec<_>>();
+    let languages = signals
+        .workspace_languages
+        .iter()
+        .map(|language| language.to_lowercase())
+        .filter(|language| !language.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut metrics = ToolExposureBudgetMetrics {
+        candidate_count: candidates.len(),
+        ..Default::default()
+    };
+    let mut exposed = HashSet::new();
+    let mut ranked = Vec::new();
+    for candidate in candidates {
+        let name = candidate.name.as_str();
+        if TOOL_EXPOSURE_BUDGET_EXEMPT_TOOLS.contains(&name) || pinned.contains(name) {
+            if pinned.contains(name) {
+                metrics.pinned_count += 1;
+            }
+            exposed.insert(name);
+            continue;
+        }
+        let signal_score = recency_score(name, &signals.recent_tool_names)
+            + request_score(candidate, &request_terms)
+            + language_score(candidate, &languages);
+        let score = signal_score + base_priority(candidate);
+        ranked.push((candidate, score, signal_score > 0));
+    }
+    ranked.sort_by(|(left, left_score, _), (right, right_score, _)| {
+        right_score
+            .cmp(left_score)
+            .then_with(|| {
+                tool_manifest_sort_rank(&left.name).cmp(&tool_manifest_sort_rank(&right.name))
+            })
+            .then_with(|| left.name.cmp(&right.name))
+    });
+    for (candidate, _, signal_matched) in ranked.into_iter().take(budget.max_tools) {
+        if signal_matched {
+            metrics.signal_matched_count += 1;
+        }
+        exposed.insert(candidate.name.as_str());
+    }
+
+    let mut selection = ToolExposureSelection::default();
+    for candidate in candidates {
+        if exposed.contains(candidate.name.as_str()) {
+            selection.exposed.push(candidate.name.clone());
+        } else {
+            metrics.demoted_description_chars += candidate.description.chars().count();
+            selection.demoted.push(candidate.name.clone());
+        }
+    }
+    metrics.exposed_count = selection.exposed.len();
+    metrics.demoted_count = selection.demoted.len();
+    selection.metrics = metrics;
+    selection
+}
+
+fn recency_score(name: &str, recent_tool_names: &[String]) -> u32 {
+    recent_tool_names
+        .iter()
+        .position(|recent| recent == name)
+        .map_or(0, |index| 80u32.saturating_sub(5 * index as u32).max(40))
+}
+
+fn request_score(candidate: &ToolExposureCandidate, request_terms: &[String]) -> u32 {
+    let name = candidate.name.to_lowercase();
+    let description = candidate.description.to_lowercase();
+    let score = request_terms
+        .iter()
+        .map(|term| {
+            if name.contains(term.as_str()) {
+                30
+            } else if description.contains(term.as_str()) {
+                5
+            } else {
+                0
+            }
+        })
+        .sum::<u32>();
+    score.min(MAX_REQUEST_MATCH_SCORE)
+}
+
+fn language_score(candidate: &ToolExposureCandidate, languages: &[String]) -> u32 {
+    let text = format!("{} {}", candidate.name, candidate.description).to_lowercase();
+    if languages
+        .iter()
+        .any(|language| text.contains(language.as_str()))
+    {
+        10
+    } else {
+        0
+    }
+}
+
+fn base_priority(candidate: &ToolExposureCandidate) -> u32 {
+    let rank = tool_manifest_sort_rank(&candidate.name) as u32;
+    let builtin_bonus = if candidate.is_dynamic { 0 } else { 20 };
+    100u32.saturating_sub(rank) + builtin_bonus
+}
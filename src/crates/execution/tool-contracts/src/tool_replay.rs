@@ -0,0 +1,164 @@
+//! Tool invocation recording and deterministic replay.
+//!
+//! Every executed tool call is appended to a per-session invocation log. A
+//! replay bundle built from that log can be loaded into a session, after which
+//! the pipeline answers each tool call with the recorded result instead of
+//! executing the tool. Calls are matched by effective tool name and arguments,
+//! in recorded order, so the same model output always sees the same results.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Invocation log file inside a session directory, one JSON record per line.
+pub const TOOL_INVOCATION_LOG_FILE_NAME: &str = "tool-invocations.jsonl";
+pub const TOOL_REPLAY_BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// One executed tool call with its inputs, output and timing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolInvocationRecord {
+    pub tool_id: String,
+    /// Provider-facing tool name; deferred calls keep the gateway name.
+    pub tool_name: String,
+    pub effective_tool_name: String,
+    pub arguments: Value,
+    pub result: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_for_assistant: Option<String>,
+    pub is_error: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dialog_turn_id: Option<String>,
+    /// Unix milliseconds when the call was created.
+    pub started_at_ms: u64,
+    pub duration_ms: u64,
+}
+
+/// Portable set of recorded invocations for bug reports and replay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolReplayBundle {
+    pub schema_version: u32,
+    pub session_id: String,
+    pub exported_at_ms: u64,
+    pub invocations: Vec<ToolInvocationRecord>,
+}
+
+impl ToolReplayBundle {
+    /// Builds a bundle from invocation log content. Lines that fail to parse,
+    /// such as a partial write at crash time, are skipped and counted.
+    pub fn from_invocation_log(
+        session_id: impl Into<String>,
+        exported_at_ms: u64,
+        log: &str,
+    ) -> (Self, usize) {
+        let mut skipped = 0;
+        let invocations = log
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                let record = serde_json::from_str::<ToolInvocationRecord>(line).ok();
+                if record.is_none() {
+                    skipped += 1;
+                }
+                record
+            })
+            .collect();
+        (
+            Self {
+                schema_version: TOOL_REPLAY_BUNDLE_SCHEMA_VERSION,
+                session_id: session_id.into(),
+                exported_at_ms,
+                invocations,
+            },
+            skipped,
+        )
+    }
+}
+
+/// Progress of a replay, reported to the UI and in logs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolReplayStatus {
+    pub recorded: usize,
+    pub replayed: usize,
+    pub diverged: usize,
+}
+
+/// A call with no unused recording for the same tool and arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolReplayDivergence {
+    pub effective_tool_name: String,
+    /// Unused recordings of the same tool with other arguments.
+    pub same_tool_remaining: usize,
+}
+
+impl ToolReplayDivergence {
+    pub fn message(&self) -> String {
+        format!(
+            "Replay diverged: no recorded result for tool '{}' with these arguments ({} other recorded call(s) of this tool remain). The tool was not executed.",
+            self.effective_tool_name, self.same_tool_remaining
+        )
+    }
+}
+
+/// Hands out recorded results in recorded order.
+#[derive(Debug, Clone, Default)]
+pub struct ToolReplayCursor {
+    records: Vec<ToolInvocationRecord>,
+    consumed: Vec<bool>,
+    status: ToolReplayStatus,
+}
+
+impl ToolReplayCursor {
+    pub fn new(bundle: ToolReplayBundle) -> Self {
+        let recorded = bundle.invocations.len();
+        Self {
+            consumed: vec![false; recorded],
+            records: bundle.invocations,
+            status: ToolReplayStatus {
+                recorded,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Consumes the earliest unused recording of `effective_tool_name` whose
+    /// arguments equal `arguments`.
+    pub fn next_result(
+        &mut self,
+        effective_tool_name: &str,
+        arguments: &Value,
+    ) -> Result<ToolInvocationRecord, ToolReplayDivergence> {
+        let found = self.records.iter().enumerate().position(|(index, record)| {
+            !self.consumed[index]
+                && record.effective_tool_name == effective_tool_name
+                && &record.arguments == arguments
+        });
+        match found {
+            Some(index) => {
+                self.consumed[index] = true;
+                self.status.replayed += 1;
+                Ok(self.records[index].clone())
+            }
+            None => {
+                self.status.diverged += 1;
+                Err(ToolReplayDivergence {
+                    effective_tool_name: effective_tool_name.to_string(),
+                    same_tool_remaining: self
+                        .records
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, record)| {
+                            !self.consumed[*index]
+                                && record.effective_tool_name == effective_tool_name
+                        })
+                        .count(),
+                })
+            }
+        }
+    }
+
+    pub fn status(&self) -> ToolReplayStatus {
+        self.status
+    }
+}
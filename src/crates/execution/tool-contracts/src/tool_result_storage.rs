@@ -8,6 +8,10 @@ pub const MAX_TOOL_RESULTS_PER_ROUND_CHARS: usize = 200_000;
 pub const TOOL_RESULT_PREVIEW_CHARS: usize = 2_000;
 pub const PERSISTED_OUTPUT_TAG: &str = "<persisted-output>";
 pub const PERSISTED_OUTPUT_CLOSING_TAG: &str = "</persisted-output>";
+/// Tool that pages through persisted output by its output id.
+pub const READ_MORE_TOOL_NAME: &str = "ReadMore";
+/// Rough chars-per-token ratio used to turn token budgets into char limits.
+pub const TOOL_RESULT_CHARS_PER_TOKEN: usize = 4;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ToolResultStoragePolicy {
@@ -26,8 +30,28 @@ impl Default for ToolResultStoragePolicy {
     }
 }
 
+impl ToolResultStoragePolicy {
+    /// Char limit for a single result under an optional per-result token
+    /// budget. The budget can only tighten `default_limit_chars`.
+    pub fn per_tool_limit_for_budget(
+        default_limit_chars: usize,
+        max_tokens: Option<usize>,
+    ) -> usize {
+        match max_tokens.filter(|tokens| *tokens > 0) {
+            Some(tokens) => default_limit_chars.min(
+                tokens
+                    .saturating_mul(TOOL_RESULT_CHARS_PER_TOKEN)
+                    .max(TOOL_RESULT_PREVIEW_CHARS),
+            ),
+            None => default_limit_chars,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PersistedToolOutput {
+    /// Id accepted by `ReadMore` to page through the stored output.
+    pub output_id: String,
     pub reference: String,
     pub original_chars: usize,
     pub line_count: usize,
@@ -114,8 +138,13 @@ pub fn build_persisted_tool_output_message(
     preview_chars: usize,
 ) -> String {
     let mut message = format!(
-        "{PERSISTED_OUTPUT_TAG}\nOutput too large ({} chars). Full output saved to: {}\nLine count: {}\n\nPreview (first {} chars):\n{}",
-        result.original_chars, result.reference, result.line_count, preview_chars, result.preview
+        "{PERSISTED_OUTPUT_TAG}\nOutput too large ({} chars). Full output saved to: {}\nOutput id: {} (page through it with {READ_MORE_TOOL_NAME} and a line range)\nLine count: {}\n\nPreview (first {} chars):\n{}",
+        result.original_chars,
+        result.reference,
+        result.output_id,
+        result.line_count,
+        preview_chars,
+        result.preview
     );
     if result.has_more {
         message.push_str("\n...\n");
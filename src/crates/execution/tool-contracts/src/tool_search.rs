@@ -0,0 +1,165 @@
+//! Ranked search over the registered tool catalog.
+//!
+//! With many MCP servers and skills installed the full tool list is too large
+//! to show the model every turn. `SearchTools` lets it look tools up by
+//! keyword instead; this module owns the matching and ranking so the agent
+//! tool and the desktop command return the same order.
+
+use serde::{Deserialize, Serialize};
+
+pub const SEARCH_TOOLS_TOOL_NAME: &str = "SearchTools";
+pub const DEFAULT_TOOL_SEARCH_LIMIT: usize = 10;
+pub const MAX_TOOL_SEARCH_LIMIT: usize = 50;
+
+/// Where a searchable tool comes from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolSearchSource {
+    Builtin,
+    Mcp,
+    Skill,
+}
+
+impl ToolSearchSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::Mcp => "mcp",
+            Self::Skill => "skill",
+        }
+    }
+}
+
+/// One searchable catalog entry.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolSearchEntry {
+    /// Name the model calls: the tool name, or the skill name for skills.
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub description: String,
+    pub source: ToolSearchSource,
+    /// MCP server name or skill source, shown as `namespace/name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Whether the schema has to be loaded with GetToolSpec before calling.
+    #[serde(default)]
+    pub deferred: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolSearchMatch {
+    #[serde(flatten)]
+    pub entry: ToolSearchEntry,
+    pub score: u32,
+}
+
+impl ToolSearchMatch {
+    pub fn qualified_name(&self) -> String {
+        match &self.entry.namespace {
+            Some(namespace) => format!("{}/{}", namespace, self.entry.name),
+            None => self.entry.name.clone(),
+        }
+    }
+}
+
+/// Splits a query into lowercase terms. `_`, `-`, `.` and `/` separate terms
+/// so `create_issue` and `create issue` search the same way.
+pub fn tokenize_tool_search_query(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| c.is_whitespace() || matches!(c, '_' | '-' | '.' | '/'))
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Returns the entries matching every query term, best first.
+///
+/// Name matches outrank title and namespace matches, which outrank
+/// description matches. Ties keep built-in tools ahead of MCP tools and
+/// skills, then sort by name so results are stable.
+pub fn search_tool_catalog(
+    query: &str,
+    entries: impl IntoIterator<Item = ToolSearchEntry>,
+    limit: usize,
+) -> Vec<ToolSearchMatch> {
+    let terms = tokenize_tool_search_query(query);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let whole_query = terms.join("");
+
+    let mut matches = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let score = score_entry(&entry, &terms, &whole_query)?;
+            Some(ToolSearchMatch { entry, score })
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|left, right| {
+        right
+            .score
+            .cmp(&left.score)
+            .then_with(|| source_rank(left.entry.source).cmp(&source_rank(right.entry.source)))
+            .then_with(|| left.entry.name.cmp(&right.entry.name))
+    });
+    matches.truncate(limit.clamp(1, MAX_TOOL_SEARCH_LIMIT));
+    matches
+}
+
+fn score_entry(entry: &ToolSearchEntry, terms: &[String], whole_query: &str) -> Option<u32> {
+    let name = compact(&entry.name);
+    let title = entry.title.as_deref().map(str::to_lowercase);
+    let namespace = entry.namespace.as_deref().map(str::to_lowercase);
+    let description = entry.description.to_lowercase();
+
+    let mut score = 0;
+    for term in terms {
+        let term_score = if name == *term {
+            60
+        } else if name.starts_with(term.as_str()) {
+            40
+        } else if name.contains(term.as_str()) {
+            30
+        } else if title
+            .as_deref()
+            .is_some_and(|title| title.contains(term.as_str()))
+        {
+            20
+        } else if namespace
+            .as_deref()
+            .is_some_and(|namespace| namespace.contains(term.as_str()))
+        {
+            15
+        } else if description.contains(term.as_str()) {
+            5
+        } else {
+            return None;
+        };
+        score += term_score;
+    }
+    if name == whole_query {
+        score += 100;
+    }
+    Some(score)
+}
+
+/// Lowercases a tool name and drops separators, so `create_issue` and
+/// `CreateIssue` both compact to `createissue`.
+fn compact(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '_' | '-' | '.' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn source_rank(source: ToolSearchSource) -> u8 {
+    match source {
+        ToolSearchSource::Builtin => 0,
+        ToolSearchSource::Mcp => 1,
+        ToolSearchSource::Skill => 2,
+    }
+}
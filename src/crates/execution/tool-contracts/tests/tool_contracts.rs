@@ -15,7 +15,7 @@ use bitfun_agent_tools::{
     call_deferred_tool_description, call_deferred_tool_input_schema,
     collect_loaded_deferred_tool_specs, effective_tool_invocation, get_tool_spec_input_schema,
     get_tool_spec_is_concurrency_safe, get_tool_spec_is_readonly, get_tool_spec_short_description,
-    is_bitfun_runtime_uri, is_remote_posix_path_within_root,
+    is_bitfun_runtime_uri, is_remote_posix_path_within_root, is_sensitive_write_path,
     is_tool_path_allowed_by_resolved_roots, normalize_host_path, normalize_runtime_relative_path,
     parse_bitfun_current_session_uri, parse_bitfun_runtime_uri, posix_resolve_path_with_workspace,
     posix_style_path_is_absolute, render_get_tool_spec_tool_use_message,
@@ -35,7 +35,8 @@ use bitfun_agent_tools::{
     ToolExecutionAdmissionRejection, ToolExecutionAdmissionRequest, ToolExposure,
     ToolImageAttachment, ToolManifestDefinition, ToolManifestPolicyTool, ToolPathBackend,
     ToolPathOperation, ToolPathResolution, ToolRenderOptions, ToolResult, ToolRuntimeRestrictions,
-    ToolWorkspaceKind, ValidationResult, CALL_DEFERRED_TOOL_NAME, GET_TOOL_SPEC_TOOL_NAME,
+    ToolWorkspaceKind, ToolWriteSandbox, ValidationResult, CALL_DEFERRED_TOOL_NAME,
+    GET_TOOL_SPEC_TOOL_NAME,
 };
 use bitfun_agent_tools::{
     build_invalid_tool_call_error_message, build_normal_tool_json_repair_notice,
@@ -57,7 +58,8 @@ use bitfun_agent_tools::{
     generate_tool_result_preview, is_file_tool_guidance_message,
     sanitize_tool_result_file_component, select_tool_result_indices_for_persistence,
     tool_result_is_persisted_output, PersistedToolOutput, ToolResultPersistenceCandidate,
-    FILE_TOOL_GUIDANCE_PREFIX, PERSISTED_OUTPUT_TAG, TOOL_RESULT_PREVIEW_CHARS,
+    ToolResultStoragePolicy, FILE_TOOL_GUIDANCE_PREFIX, PERSISTED_OUTPUT_TAG,
+    TOOL_RESULT_CHARS_PER_TOKEN, TOOL_RESULT_PREVIEW_CHARS,
 };
 use bitfun_agent_tools::{
     file_read_facts_are_fresh, file_read_facts_content_matches, normalize_tool_file_content,
@@ -71,6 +73,11 @@ use bitfun_agent_tools::{
     ToolCatalogSnapshotProvider, ToolDecorator, ToolDecoratorRef, ToolEffectFactsSource,
     ToolEffectFilter, ToolRegistry, ToolRegistryItem, ToolRuntimeAssembly, ToolSnapshotCallError,
 };
+use bitfun_agent_tools::{search_tool_catalog, ToolSearchEntry, ToolSearchSource};
+use bitfun_agent_tools::{
+    select_budgeted_tools, ToolExposureBudget, ToolExposureCandidate, ToolExposureSignals,
+};
+use bitfun_agent_tools::{ToolInvocationRecord, ToolReplayBundle, ToolReplayCursor};
 use serde_json::json;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -884,6 +891,7 @@ fn file_read_freshness_tolerates_read_tool_trailing_newline_reconstruction_gap()
 fn persisted_tool_output_message_keeps_reference_preview_and_metadata_shape() {
     let rendered = build_persisted_tool_output_message(
         &PersistedToolOutput {
+            output_id: "bash_1".to_string(),
             reference: "bitfun-runtime://session/session-1/tool-results/bash_1.txt".to_string(),
             original_chars: 12_345,
             line_count: 7,
@@ -899,6 +907,7 @@ fn persisted_tool_output_message_keeps_reference_preview_and_metadata_shape() {
 
     assert!(rendered.starts_with(PERSISTED_OUTPUT_TAG));
     assert!(rendered.contains("Output too large (12345 chars). Full output saved to:"));
+    assert!(rendered.contains("Output id: bash_1 (page through it with ReadMore"));
     assert!(rendered.contains("Line count: 7"));
     assert!(rendered.contains("Preview (first 2000 chars):\nfirst lines"));
     assert!(rendered.contains("- exit_code: 1"));
@@ -906,6 +915,26 @@ fn persisted_tool_output_message_keeps_reference_preview_and_metadata_shape() {
     assert!(tool_result_is_persisted_output(&rendered));
 }
 
+#[test]
+fn token_budget_only_tightens_the_per_tool_char_limit() {
+    assert_eq!(
+        ToolResultStoragePolicy::per_tool_limit_for_budget(50_000, None),
+        50_000
+    );
+    assert_eq!(
+        ToolResultStoragePolicy::per_tool_limit_for_budget(50_000, Some(2_000)),
+        2_000 * TOOL_RESULT_CHARS_PER_TOKEN
+    );
+    assert_eq!(
+        ToolResultStoragePolicy::per_tool_limit_for_budget(50_000, Some(100_000)),
+        50_000
+    );
+    assert_eq!(
+        ToolResultStoragePolicy::per_tool_limit_for_budget(50_000, Some(1)),
+        TOOL_RESULT_PREVIEW_CHARS
+    );
+}
+
 #[test]
 fn tool_result_preview_prefers_line_boundary_when_possible() {
     let content = "first line\nsecond line\nthird line";
@@ -980,6 +1009,61 @@ fn runtime_restrictions_keep_current_snake_case_wire_shape() {
     assert_eq!(round_trip, value);
 }
 
+#[test]
+fn write_sandbox_is_optional_on_the_path_policy_wire_shape() {
+    let value = json!({
+        "path_policy": {
+            "write_roots": [],
+            "edit_roots": [],
+            "delete_roots": [],
+            "sandbox": {
+                "extra_roots": ["/tmp/scratch"],
+                "confine_to_workspace": true,
+                "approve_sensitive_files": true
+            }
+        }
+    });
+
+    let restrictions: ToolRuntimeRestrictions =
+        serde_json::from_value(value).expect("deserialize restrictions");
+    assert_eq!(
+        restrictions.path_policy.sandbox,
+        Some(ToolWriteSandbox {
+            extra_roots: vec!["/tmp/scratch".to_string()],
+            confine_to_workspace: true,
+            approve_sensitive_files: true,
+        })
+    );
+    assert!(!restrictions
+        .path_policy
+        .is_restricted(ToolPathOperation::Write));
+}
+
+#[test]
+fn sensitive_write_paths_cover_vcs_ci_and_lockfiles() {
+    for path in [
+        ".git/config",
+        ".git/hooks/pre-commit",
+        ".github/workflows/ci.yml",
+        ".gitlab-ci.yml",
+        "Cargo.lock",
+        "web\\package-lock.json",
+        "./crates/app/Cargo.lock",
+        "services/api/go.sum",
+    ] {
+        assert!(is_sensitive_write_path(path), "{path}");
+    }
+    for path in [
+        "src/lib.rs",
+        "Cargo.toml",
+        "docs/.github/workflows.md",
+        "src/.git/config.rs",
+        "package.json",
+    ] {
+        assert!(!is_sensitive_write_path(path), "{path}");
+    }
+}
+
 #[test]
 fn path_resolution_contract_keeps_backend_and_runtime_helpers() {
     let remote = ToolPathResolution {
@@ -3502,3 +3586,176 @@ async fn generic_tool_registry_clears_stale_dynamic_metadata_on_overwrite() {
         .expect("list dynamic tools");
     assert!(descriptors.is_empty());
 }
+
+fn search_entry(
+    name: &str,
+    description: &str,
+    source: ToolSearchSource,
+    namespace: Option<&str>,
+) -> ToolSearchEntry {
+    ToolSearchEntry {
+        name: name.to_string(),
+        title: None,
+        description: description.to_string(),
+        source,
+        namespace: namespace.map(str::to_string),
+        deferred: false,
+    }
+}
+
+#[test]
+fn tool_search_ranks_name_matches_before_description_matches() {
+    let entries = vec![
+        search_entry(
+            "Grep",
+            "Search file contents for an issue pattern",
+            ToolSearchSource::Builtin,
+            None,
+        ),
+        search_entry(
+            "mcp__github__create_issue",
+            "Open a new issue",
+            ToolSearchSource::Mcp,
+            Some("GitHub"),
+        ),
+        search_entry(
+            "issue-triage",
+            "Label incoming reports",
+            ToolSearchSource::Skill,
+            Some("Project"),
+        ),
+        search_entry("Read", "Read a file", ToolSearchSource::Builtin, None),
+    ];
+
+    let matches = search_tool_catalog("issue", entries.clone(), 10);
+    let names = matches
+        .iter()
+        .map(|found| found.entry.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["issue-triage", "mcp__github__create_issue", "Grep"]
+    );
+    assert_eq!(
+        matches[1].qualified_name(),
+        "GitHub/mcp__github__create_issue"
+    );
+
+    let matches = search_tool_catalog("github create_issue", entries.clone(), 10);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].entry.name, "mcp__github__create_issue");
+
+    assert!(search_tool_catalog("  ", entries.clone(), 10).is_empty());
+    assert_eq!(search_tool_catalog("read", entries, 1).len(), 1);
+}
+
+#[test]
+fn tool_exposure_budget_keeps_relevant_tools_and_demotes_the_rest() {
+    let candidate = |name: &str, description: &str, is_dynamic| ToolExposureCandidate {
+        name: name.to_string(),
+        description: description.to_string(),
+        is_dynamic,
+    };
+    let candidates = vec![
+        candidate("Bash", "Run shell commands", false),
+        candidate("Read", "Read a file", false),
+        candidate("GetToolSpec", "Load deferred tool schemas", false),
+        candidate("mcp__github__create_issue", "Open a new GitHub issue", true),
+        candidate("mcp__jira__search", "Search Jira tickets", true),
+        candidate("mcp__slack__post_message", "Post a Slack message", true),
+        candidate("mcp__crates__analyze", "Analyze Rust crates", true),
+    ];
+    let signals = ToolExposureSignals {
+        recent_tool_names: vec!["mcp__jira__search".to_string()],
+        request_text: "Open a GitHub issue for the crash".to_string(),
+        workspace_languages: vec!["Rust".to_string()],
+    };
+    let budget = ToolExposureBudget {
+        max_tools: 4,
+        pinned_tools: vec!["mcp__slack__post_message".to_string()],
+    };
+
+    let selection = select_budgeted_tools(&candidates, &signals, &budget);
+
+    assert_eq!(
+        selection.exposed,
+        vec![
+            "Bash",
+            "Read",
+            "GetToolSpec",
+            "mcp__github__create_issue",
+            "mcp__jira__search",
+            "mcp__slack__post_message",
+        ]
+    );
+    assert_eq!(selection.demoted, vec!["mcp__crates__analyze"]);
+    assert_eq!(selection.metrics.candidate_count, 7);
+    assert_eq!(selection.metrics.pinned_count, 1);
+    assert_eq!(selection.metrics.signal_matched_count, 2);
+    assert_eq!(
+        selection.metrics.demoted_description_chars,
+        "Analyze Rust crates".len()
+    );
+
+    let tight = select_budgeted_tools(
+        &candidates,
+        &signals,
+        &ToolExposureBudget {
+            max_tools: 0,
+            pinned_tools: Vec::new(),
+        },
+    );
+    assert_eq!(tight.exposed, vec!["GetToolSpec"]);
+}
+
+fn invocation_record(tool_id: &str, tool_name: &str, arguments: serde_json::Value) -> String {
+    serde_json::to_string(&ToolInvocationRecord {
+        tool_id: tool_id.to_string(),
+        tool_name: tool_name.to_string(),
+        effective_tool_name: tool_name.to_string(),
+        arguments,
+        result: json!({ "from": tool_id }),
+        result_for_assistant: Some(format!("result of {}", tool_id)),
+        is_error: false,
+        dialog_turn_id: Some("turn-1".to_string()),
+        started_at_ms: 1_000,
+        duration_ms: 5,
+    })
+    .expect("record serializes")
+}
+
+#[test]
+fn tool_replay_matches_recorded_calls_by_tool_and_arguments_in_order() {
+    let log = [
+        invocation_record("call-1", "Read", json!({ "file_path": "/a.rs" })),
+        invocation_record("call-2", "Read", json!({ "file_path": "/b.rs" })),
+        "{\"toolId\": \"partial".to_string(),
+        invocation_record("call-3", "Read", json!({ "file_path": "/a.rs" })),
+    ]
+    .join("\n");
+    let (bundle, skipped) = ToolReplayBundle::from_invocation_log("session-1", 2_000, &log);
+    assert_eq!(skipped, 1);
+    assert_eq!(bundle.invocations.len(), 3);
+
+    let mut cursor = ToolReplayCursor::new(bundle);
+    let replayed = |cursor: &mut ToolReplayCursor, path: &str| {
+        cursor
+            .next_result("Read", &json!({ "file_path": path }))
+            .map(|record| record.tool_id)
+    };
+    assert_eq!(replayed(&mut cursor, "/b.rs").as_deref(), Ok("call-2"));
+    assert_eq!(replayed(&mut cursor, "/a.rs").as_deref(), Ok("call-1"));
+    assert_eq!(replayed(&mut cursor, "/a.rs").as_deref(), Ok("call-3"));
+
+    let divergence = cursor
+        .next_result("Read", &json!({ "file_path": "/a.rs" }))
+        .expect_err("every /a.rs recording was used");
+    assert_eq!(divergence.same_tool_remaining, 0);
+    assert!(divergence.message().contains("'Read'"));
+
+    let status = cursor.status();
+    assert_eq!(
+        (status.recorded, status.replayed, status.diverged),
+        (3, 3, 1)
+    );
+}
@@ -191,6 +191,99 @@ pub fn partition_tool_batches(task_ids: &[String], flags: &[bool]) -> Vec<ToolBa
     batches
 }
 
+/// A resource a tool call reads or writes, as declared by the tool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ToolResource {
+    /// Normalized absolute file path.
+    File(String),
+    /// Any other shared resource, such as a terminal session or the todo list.
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ToolResourceAccess {
+    Read(ToolResource),
+    Write(ToolResource),
+}
+
+impl ToolResourceAccess {
+    pub fn resource(&self) -> &ToolResource {
+        match self {
+            Self::Read(resource) | Self::Write(resource) => resource,
+        }
+    }
+
+    pub fn is_write(&self) -> bool {
+        matches!(self, Self::Write(_))
+    }
+}
+
+/// How one tool call interacts with the other calls of the same batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolCallFootprint {
+    /// No declared resources and not concurrency-safe: runs alone.
+    Exclusive,
+    /// Concurrency-safe without declared resources: may read anything, so it
+    /// only waits for writers.
+    SharedRead,
+    /// Touches only the declared resources.
+    Scoped(Vec<ToolResourceAccess>),
+}
+
+impl ToolCallFootprint {
+    pub fn from_declaration(
+        concurrency_safe: bool,
+        accesses: Option<Vec<ToolResourceAccess>>,
+    ) -> Self {
+        match accesses {
+            Some(accesses) if !accesses.is_empty() => Self::Scoped(accesses),
+            _ if concurrency_safe => Self::SharedRead,
+            _ => Self::Exclusive,
+        }
+    }
+
+    fn writes(&self) -> bool {
+        match self {
+            Self::Exclusive => true,
+            Self::SharedRead => false,
+            Self::Scoped(accesses) => accesses.iter().any(ToolResourceAccess::is_write),
+        }
+    }
+
+    /// Whether two calls must not overlap.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Exclusive, _) | (_, Self::Exclusive) => true,
+            (Self::SharedRead, footprint) | (footprint, Self::SharedRead) => footprint.writes(),
+            (Self::Scoped(left), Self::Scoped(right)) => left.iter().any(|left| {
+                right.iter().any(|right| {
+                    left.resource() == right.resource() && (left.is_write() || right.is_write())
+                })
+            }),
+        }
+    }
+}
+
+/// For each call, the indices of earlier calls it must wait for.
+///
+/// A call depends on every earlier call it conflicts with, so conflicting
+/// calls keep the order the model emitted them in while independent calls may
+/// overlap.
+pub fn plan_tool_dependencies(footprints: &[ToolCallFootprint]) -> Vec<Vec<usize>> {
+    footprints
+        .iter()
+        .enumerate()
+        .map(|(index, footprint)| {
+            footprints[..index]
+                .iter()
+                .enumerate()
+                .filter(|(_, earlier)| earlier.conflicts_with(footprint))
+                .map(|(earlier_index, _)| earlier_index)
+                .collect()
+        })
+        .collect()
+}
+
 pub fn tool_call_concurrency_safe_for_batch(
     tool_name: &str,
     tool_is_concurrency_safe: bool,
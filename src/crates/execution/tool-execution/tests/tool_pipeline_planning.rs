@@ -1,8 +1,9 @@
 use tool_runtime::pipeline::{
-    count_tool_states, partition_tool_batches, retry_delay_ms, should_cancel_tool_state,
-    should_retry_tool_attempt, summarize_dialog_turn_cancellation,
-    tool_call_concurrency_safe_for_batch, SubagentBatchExecutionPolicy, ToolCancellationTokenStore,
-    ToolExecutionErrorClass, ToolRetryAttemptFacts, ToolTaskStateKind,
+    count_tool_states, partition_tool_batches, plan_tool_dependencies, retry_delay_ms,
+    should_cancel_tool_state, should_retry_tool_attempt, summarize_dialog_turn_cancellation,
+    tool_call_concurrency_safe_for_batch, SubagentBatchExecutionPolicy, ToolCallFootprint,
+    ToolCancellationTokenStore, ToolExecutionErrorClass, ToolResource, ToolResourceAccess,
+    ToolRetryAttemptFacts, ToolTaskStateKind,
 };
 
 #[test]
@@ -44,6 +45,57 @@ fn partitions_serial_tools_as_individual_batches() {
     assert!(!batches[1].is_concurrent);
 }
 
+fn write(path: &str) -> ToolResourceAccess {
+    ToolResourceAccess::Write(ToolResource::File(path.to_string()))
+}
+
+fn read(path: &str) -> ToolResourceAccess {
+    ToolResourceAccess::Read(ToolResource::File(path.to_string()))
+}
+
+#[test]
+fn writes_to_different_files_run_independently() {
+    let footprints = [
+        ToolCallFootprint::Scoped(vec![write("/ws/a.rs")]),
+        ToolCallFootprint::Scoped(vec![write("/ws/b.rs")]),
+        ToolCallFootprint::Scoped(vec![read("/ws/b.rs")]),
+        ToolCallFootprint::Scoped(vec![write("/ws/a.rs")]),
+    ];
+
+    assert_eq!(
+        plan_tool_dependencies(&footprints),
+        vec![vec![], vec![], vec![1], vec![0]]
+    );
+}
+
+#[test]
+fn undeclared_calls_keep_batch_ordering() {
+    let footprints = [
+        ToolCallFootprint::SharedRead,
+        ToolCallFootprint::Scoped(vec![read("/ws/a.rs")]),
+        ToolCallFootprint::Scoped(vec![write("/ws/b.rs")]),
+        ToolCallFootprint::Exclusive,
+        ToolCallFootprint::SharedRead,
+    ];
+
+    assert_eq!(
+        plan_tool_dependencies(&footprints),
+        vec![vec![], vec![], vec![0], vec![0, 1, 2], vec![2, 3]]
+    );
+}
+
+#[test]
+fn footprint_falls_back_to_concurrency_safety() {
+    assert_eq!(
+        ToolCallFootprint::from_declaration(true, Some(Vec::new())),
+        ToolCallFootprint::SharedRead
+    );
+    assert_eq!(
+        ToolCallFootprint::from_declaration(false, None),
+        ToolCallFootprint::Exclusive
+    );
+}
+
 #[test]
 fn subagent_batch_policy_preserves_task_concurrency_contract() {
     assert!(!tool_call_concurrency_safe_for_batch(
@@ -126,14 +126,20 @@ const PRODUCT_TOOL_PROVIDER_GROUP_PLAN: &[ToolProviderGroupPlan] = &[
             "analyze_image",
             "Glob",
             "Grep",
+            "FindSymbol",
+            "FileOutline",
+            "LSP",
             "Write",
             "Edit",
+            "EditTransaction",
             "Delete",
             "ExecCommand",
             "WriteStdin",
             "ExecControl",
             "GetTime",
             "ListModels",
+            "ReadMore",
+            "SearchTools",
         ],
     },
     ToolProviderGroupPlan {
@@ -174,6 +180,7 @@ const PRODUCT_TOOL_PROVIDER_GROUP_PLAN: &[ToolProviderGroupPlan] = &[
             "WebFetch",
             "ListMCPResources",
             "ReadMCPResource",
+            "AttachMCPResources",
             "ListMCPPrompts",
             "GetMCPPrompt",
             "GenerativeUI",
@@ -356,14 +363,20 @@ mod tests {
                 "analyze_image",
                 "Glob",
                 "Grep",
+                "FindSymbol",
+                "FileOutline",
+                "LSP",
                 "Write",
                 "Edit",
+                "EditTransaction",
                 "Delete",
                 "ExecCommand",
                 "WriteStdin",
                 "ExecControl",
                 "GetTime",
                 "ListModels",
+                "ReadMore",
+                "SearchTools",
                 "Task",
                 "AgentWait",
                 "LaunchReviewAgent",
@@ -390,6 +403,7 @@ mod tests {
                 "WebFetch",
                 "ListMCPResources",
                 "ReadMCPResource",
+                "AttachMCPResources",
                 "ListMCPPrompts",
                 "GetMCPPrompt",
                 "GenerativeUI",
@@ -153,8 +153,11 @@ fn stdio_server_config(acp_session_id: &str, server: McpServerStdio) -> Result<M
             .collect(),
         working_directory: None,
         inherit_parent_environment: None,
+        isolation: None,
+        framing: None,
         headers: HashMap::new(),
         url: None,
+        tls: None,
         auto_start: true,
         enabled: true,
         location: ConfigLocation::Project,
@@ -194,8 +197,11 @@ fn remote_server_config(
         env: HashMap::new(),
         working_directory: None,
         inherit_parent_environment: None,
+        isolation: None,
+        framing: None,
         headers,
         url: Some(url),
+        tls: None,
         auto_start: true,
         enabled: true,
         location: ConfigLocation::Project,
@@ -0,0 +1,122 @@
+//! Download bandwidth limiting.
+//!
+//! A token bucket shared by every download that should respect the user's
+//! speed cap. Callers report each chunk after receiving it; the limiter
+//! delays the next one until the average rate is back under the cap.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Notify};
+
+static GLOBAL_DOWNLOAD_LIMITER: OnceLock<BandwidthLimiter> = OnceLock::new();
+
+/// The process-wide limiter for large downloads. Unlimited until the app
+/// applies the user's setting.
+pub fn global_download_limiter() -> &'static BandwidthLimiter {
+    GLOBAL_DOWNLOAD_LIMITER.get_or_init(|| BandwidthLimiter::new(0))
+}
+
+struct Bucket {
+    /// Negative while earlier chunks are still being paid off.
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+pub struct BandwidthLimiter {
+    /// `0` means unlimited.
+    bytes_per_sec: AtomicU64,
+    bucket: Mutex<Bucket>,
+    limit_changed: Notify,
+}
+
+impl BandwidthLimiter {
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: AtomicU64::new(bytes_per_sec),
+            bucket: Mutex::new(Bucket {
+                tokens: 0.0,
+                refilled_at: Instant::now(),
+            }),
+            limit_changed: Notify::new(),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.bytes_per_sec.load(Ordering::Relaxed)
+    }
+
+    /// Takes effect for downloads already in progress.
+    pub fn set_limit(&self, bytes_per_sec: u64) {
+        if self.bytes_per_sec.swap(bytes_per_sec, Ordering::Relaxed) != bytes_per_sec {
+            self.limit_changed.notify_waiters();
+        }
+    }
+
+    /// Accounts for `bytes` just received, first waiting out any debt left
+    /// by earlier chunks. Waiters are served in arrival order, so concurrent
+    /// downloads share the cap.
+    pub async fn acquire(&self, bytes: u64) {
+        let mut bucket = self.bucket.lock().await;
+        loop {
+            let limit_changed = self.limit_changed.notified();
+            let rate = self.limit();
+            let now = Instant::now();
+            if rate == 0 {
+                bucket.tokens = 0.0;
+                bucket.refilled_at = now;
+                return;
+            }
+
+            let rate = rate as f64;
+            let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+            // At most one second of burst after an idle period.
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+            bucket.refilled_at = now;
+            if bucket.tokens >= 0.0 {
+                bucket.tokens -= bytes as f64;
+                return;
+            }
+
+            let wait = Duration::from_secs_f64(-bucket.tokens / rate);
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = limit_changed => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn limits_average_rate() {
+        let limiter = BandwidthLimiter::new(100_000);
+        let started = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire(10_000).await;
+        }
+        // The first chunk passes at once; the other three wait 0.1s each.
+        assert!(started.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn lifting_the_limit_releases_a_waiting_download() {
+        let limiter = std::sync::Arc::new(BandwidthLimiter::new(1_000));
+        limiter.acquire(10_000).await;
+        let waiter = tokio::spawn({
+            let limiter = limiter.clone();
+            async move { limiter.acquire(1).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        limiter.set_limit(0);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should be released")
+            .unwrap();
+    }
+}
@@ -247,6 +247,7 @@ impl FileSystemService {
                     whole_word: options.whole_word,
                     max_results: options.max_results.unwrap_or(10_000),
                     max_file_size_bytes: 10 * 1024 * 1024,
+                    globs: options.globs.clone(),
                     cancel_flag,
                 },
                 progress_sink,
@@ -5,6 +5,7 @@
 use super::error::{FileSystemError, FileSystemResult};
 use log::warn;
 
+use ignore::overrides::{Override, OverrideBuilder};
 use ignore::WalkBuilder;
 use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
@@ -189,6 +190,10 @@ pub struct FileContentSearchOptions {
     pub whole_word: bool,
     pub max_results: usize,
     pub max_file_size_bytes: u64,
+    /// Ripgrep-style path globs relative to the search root; a leading `!`
+    /// excludes. When any non-negated glob is present, only matching files are
+    /// searched.
+    pub globs: Vec<String>,
     pub cancel_flag: Option<Arc<AtomicBool>>,
 }
 
@@ -200,6 +205,7 @@ impl Default for FileContentSearchOptions {
             whole_word: false,
             max_results: 10_000,
             max_file_size_bytes: 10 * 1024 * 1024,
+            globs: Vec::new(),
             cancel_flag: None,
         }
     }
@@ -980,6 +986,7 @@ impl FileTreeService {
                         whole_word,
                         max_results: remaining,
                         max_file_size_bytes: 10 * 1024 * 1024,
+                        globs: Vec::new(),
                         cancel_flag: None,
                     },
                 )
@@ -1029,7 +1036,7 @@ impl FileTreeService {
         let max_results = options.max_results.max(1);
         let progress_sink_for_walker = progress_sink.clone();
 
-        let walker = Self::build_search_walker(&root_path_buf);
+        let walker = Self::search_walker_builder(&root_path_buf).build_parallel();
 
         walker.run(|| {
             let matcher = Arc::clone(&matcher);
@@ -1183,7 +1190,11 @@ impl FileTreeService {
         let max_file_size_bytes = options.max_file_size_bytes;
         let progress_sink_for_walker = progress_sink.clone();
 
-        let walker = Self::build_search_walker(&root_path_buf);
+        let mut walker_builder = Self::search_walker_builder(&root_path_buf);
+        if !options.globs.is_empty() {
+            walker_builder.overrides(Self::build_glob_overrides(&root_path_buf, &options.globs)?);
+        }
+        let walker = walker_builder.build_parallel();
 
         walker.run(|| {
             let matcher = Arc::clone(&matcher);
@@ -1279,8 +1290,9 @@ impl FileTreeService {
         })
     }
 
-    fn build_search_walker(root_path: &Path) -> ignore::WalkParallel {
-        WalkBuilder::new(root_path)
+    fn search_walker_builder(root_path: &Path) -> WalkBuilder {
+        let mut builder = WalkBuilder::new(root_path);
+        builder
             .hidden(false)
             .ignore(true)
             .git_ignore(true)
@@ -1291,8 +1303,23 @@ impl FileTreeService {
                     .map(|count| count.get())
                     .unwrap_or(1)
                     .min(8),
-            )
-            .build_parallel()
+            );
+        builder
+    }
+
+    fn build_glob_overrides(root_path: &Path, globs: &[String]) -> FileSystemResult<Override> {
+        let mut builder = OverrideBuilder::new(root_path);
+        for glob in globs.iter().map(|glob| glob.trim()) {
+            if glob.is_empty() || glob == "!" {
+                continue;
+            }
+            builder.add(glob).map_err(|error| {
+                FileSystemError::service(format!("Invalid search glob '{}': {}", glob, error))
+            })?;
+        }
+        builder
+            .build()
+            .map_err(|error| FileSystemError::service(format!("Invalid search globs: {}", error)))
     }
 
     fn compile_search_regex(
@@ -1631,6 +1658,7 @@ mod tests {
                     whole_word: false,
                     max_results: 10,
                     max_file_size_bytes: 1024,
+                    globs: Vec::new(),
                     cancel_flag: None,
                 },
             )
@@ -1651,4 +1679,28 @@ mod tests {
         assert_eq!(outcome.results[0].preview_inside.as_deref(), Some("needle"));
         assert_eq!(outcome.results[0].preview_after.as_deref(), Some(" after"));
     }
+
+    #[tokio::test]
+    async fn content_search_honors_include_and_exclude_globs() {
+        let root = tempfile::tempdir().expect("create temp search directory");
+        std::fs::create_dir_all(root.path().join("src/generated")).expect("create src");
+        std::fs::write(root.path().join("src/lib.rs"), "needle").expect("write lib");
+        std::fs::write(root.path().join("src/generated/api.rs"), "needle").expect("write api");
+        std::fs::write(root.path().join("notes.md"), "needle").expect("write notes");
+
+        let outcome = FileTreeService::default()
+            .search_file_contents(
+                root.path().to_str().expect("utf-8 temp path"),
+                "needle",
+                FileContentSearchOptions {
+                    globs: vec!["*.rs".to_string(), "!src/generated/**".to_string()],
+                    ..FileContentSearchOptions::default()
+                },
+            )
+            .await
+            .expect("content search");
+
+        let names: Vec<_> = outcome.results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["lib.rs"]);
+    }
 }
@@ -27,6 +27,8 @@ pub struct FileSearchOptions {
     pub file_extensions: Option<Vec<String>>,
     /// Whether to include directories in the search results
     pub include_directories: bool,
+    /// Ripgrep-style path globs for content search; `!` prefix excludes
+    pub globs: Vec<String>,
 }
 
 impl Default for FileSearchOptions {
@@ -39,6 +41,7 @@ impl Default for FileSearchOptions {
             max_results: None, // No limit
             file_extensions: None,
             include_directories: true, // Include directories by default
+            globs: Vec::new(),
         }
     }
 }
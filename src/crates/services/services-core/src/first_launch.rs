@@ -0,0 +1,34 @@
+//! Installer-to-app first-launch handoff.
+//!
+//! The installer writes [`FirstLaunchHandoff`] as [`FIRST_LAUNCH_HANDOFF_FILE`]
+//! next to the app config; the app reads and deletes it on its first start.
+//! Both sides use this type so the file format cannot drift.
+
+use serde::{Deserialize, Serialize};
+
+pub const FIRST_LAUNCH_HANDOFF_FILE: &str = "first-launch.json";
+pub const FIRST_LAUNCH_HANDOFF_SCHEMA_VERSION: u32 = 1;
+
+/// Unknown fields from newer installers are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FirstLaunchHandoff {
+    pub schema_version: u32,
+    pub installer_version: String,
+    /// Unix milliseconds when the installation finished.
+    #[serde(default)]
+    pub installed_at_ms: Option<u64>,
+    pub install_path: String,
+    /// Installed integrations, e.g. `desktopShortcut`, `startMenuShortcut`.
+    /// Windows only: installs on other platforms create no integrations, so
+    /// this is always empty there.
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub app_language: Option<String>,
+    #[serde(default)]
+    pub theme_preference: Option<String>,
+    /// A model was set up in the installer, so model onboarding can be skipped.
+    #[serde(default)]
+    pub model_configured: bool,
+}
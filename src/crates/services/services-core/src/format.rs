@@ -0,0 +1,188 @@
+//! Locale-aware formatting for user-facing strings generated in Rust.
+//!
+//! Covers byte sizes, grouped numbers, dates and list joining for the locales
+//! the product ships (`en-US`, `zh-CN`, `zh-TW`). Unknown locale codes fall
+//! back to English conventions.
+
+use chrono::{DateTime, Datelike, TimeZone};
+use std::fmt::Display;
+
+const BYTE_UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Convention {
+    #[default]
+    English,
+    SimplifiedChinese,
+    TraditionalChinese,
+}
+
+/// Formats numbers, sizes, dates and lists for one locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LocaleFormatter {
+    convention: Convention,
+}
+
+impl LocaleFormatter {
+    /// Accepts BCP 47 style codes (`zh-CN`, `zh_TW`, `zh-Hant-HK`, `en`);
+    /// anything unrecognised formats as `en-US`.
+    pub fn new(locale: &str) -> Self {
+        let normalized = locale.trim().replace('_', "-").to_ascii_lowercase();
+        let mut subtags = normalized.split('-');
+        let convention = match subtags.next() {
+            Some("zh") => {
+                let traditional = subtags.any(|tag| matches!(tag, "tw" | "hk" | "mo" | "hant"));
+                if traditional {
+                    Convention::TraditionalChinese
+                } else {
+                    Convention::SimplifiedChinese
+                }
+            }
+            _ => Convention::English,
+        };
+        Self { convention }
+    }
+
+    /// Canonical code of the convention actually applied.
+    pub fn locale(&self) -> &'static str {
+        match self.convention {
+            Convention::English => "en-US",
+            Convention::SimplifiedChinese => "zh-CN",
+            Convention::TraditionalChinese => "zh-TW",
+        }
+    }
+
+    /// Integer with thousands separators, e.g. `1,234,567`.
+    pub fn number(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                out.push(',');
+            }
+            out.push(digit);
+        }
+        out
+    }
+
+    /// Binary-prefixed size with one decimal above a kilobyte, e.g. `1.5 MB`.
+    pub fn bytes(&self, bytes: u64) -> String {
+        if bytes < 1024 {
+            return match self.convention {
+                Convention::English if bytes == 1 => "1 byte".to_string(),
+                Convention::English => format!("{} bytes", bytes),
+                Convention::SimplifiedChinese => format!("{} 字节", bytes),
+                Convention::TraditionalChinese => format!("{} 位元組", bytes),
+            };
+        }
+
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        format!("{:.1} {}", value, BYTE_UNITS[unit])
+    }
+
+    /// Calendar date, e.g. `Jan 5, 2026` or `2026年1月5日`.
+    pub fn date<Tz: TimeZone>(&self, value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        match self.convention {
+            Convention::English => {
+                format!("{} {}, {}", value.format("%b"), value.day(), value.year())
+            }
+            Convention::SimplifiedChinese | Convention::TraditionalChinese => {
+                format!("{}年{}月{}日", value.year(), value.month(), value.day())
+            }
+        }
+    }
+
+    /// Date and 24-hour time in the value's own time zone.
+    pub fn date_time<Tz: TimeZone>(&self, value: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let time = value.format("%H:%M");
+        match self.convention {
+            Convention::English => format!("{}, {}", self.date(value), time),
+            Convention::SimplifiedChinese | Convention::TraditionalChinese => {
+                format!("{} {}", self.date(value), time)
+            }
+        }
+    }
+
+    /// Conjunction list: `a, b, and c` in English, `a、b和c` in Chinese.
+    pub fn list<S: AsRef<str>>(&self, items: &[S]) -> String {
+        let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+        let (separator, conjunction, final_separator) = match self.convention {
+            Convention::English => (", ", " and ", ", and "),
+            Convention::SimplifiedChinese | Convention::TraditionalChinese => ("、", "和", "和"),
+        };
+        match items.as_slice() {
+            [] => String::new(),
+            [only] => (*only).to_string(),
+            [first, second] => format!("{}{}{}", first, conjunction, second),
+            [head @ .., last] => format!("{}{}{}", head.join(separator), final_separator, last),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn parses_locale_variants() {
+        assert_eq!(LocaleFormatter::new("zh_CN").locale(), "zh-CN");
+        assert_eq!(LocaleFormatter::new("zh-Hant-HK").locale(), "zh-TW");
+        assert_eq!(LocaleFormatter::new("zh-TW").locale(), "zh-TW");
+        assert_eq!(LocaleFormatter::new("fr-FR").locale(), "en-US");
+        assert_eq!(LocaleFormatter::default().locale(), "en-US");
+    }
+
+    #[test]
+    fn formats_numbers_and_sizes() {
+        let en = LocaleFormatter::new("en-US");
+        let zh = LocaleFormatter::new("zh-CN");
+        assert_eq!(en.number(0), "0");
+        assert_eq!(en.number(1_234_567), "1,234,567");
+        assert_eq!(en.bytes(1), "1 byte");
+        assert_eq!(en.bytes(512), "512 bytes");
+        assert_eq!(zh.bytes(512), "512 字节");
+        assert_eq!(LocaleFormatter::new("zh-TW").bytes(512), "512 位元組");
+        assert_eq!(en.bytes(1536), "1.5 KB");
+        assert_eq!(zh.bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn formats_dates() {
+        let value = Utc.with_ymd_and_hms(2026, 1, 5, 9, 7, 0).unwrap();
+        assert_eq!(LocaleFormatter::new("en-US").date(&value), "Jan 5, 2026");
+        assert_eq!(
+            LocaleFormatter::new("en-US").date_time(&value),
+            "Jan 5, 2026, 09:07"
+        );
+        assert_eq!(
+            LocaleFormatter::new("zh-TW").date_time(&value),
+            "2026年1月5日 09:07"
+        );
+    }
+
+    #[test]
+    fn joins_lists() {
+        let en = LocaleFormatter::new("en-US");
+        let zh = LocaleFormatter::new("zh-CN");
+        assert_eq!(en.list::<&str>(&[]), "");
+        assert_eq!(en.list(&["logs"]), "logs");
+        assert_eq!(en.list(&["logs", "cache"]), "logs and cache");
+        assert_eq!(en.list(&["logs", "cache", "temp"]), "logs, cache, and temp");
+        assert_eq!(
+            zh.list(&["日志", "缓存", "临时文件"]),
+            "日志、缓存和临时文件"
+        );
+    }
+}
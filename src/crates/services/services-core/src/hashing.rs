@@ -0,0 +1,279 @@
+//! Streaming content hashing.
+//!
+//! One place for the digests used to verify downloads, payloads and skill
+//! bundles and to derive cache keys. Files are hashed in fixed-size chunks,
+//! so multi-gigabyte inputs never need to fit in memory, and callers can
+//! report progress while large files are read.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+const CHUNK_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha256,
+    /// Much faster than SHA-256; for cache keys and our own manifests.
+    /// Published checksums are SHA-256.
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+}
+
+/// Incremental hasher for either algorithm. Also usable as an
+/// [`io::Write`] sink, e.g. with [`io::copy`].
+#[derive(Clone)]
+pub struct StreamingHasher {
+    inner: HasherState,
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl StreamingHasher {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let inner = match algorithm {
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            HashAlgorithm::Blake3 => HasherState::Blake3(Box::new(blake3::Hasher::new())),
+        };
+        Self { inner }
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        match self.inner {
+            HasherState::Sha256(_) => HashAlgorithm::Sha256,
+            HasherState::Blake3(_) => HashAlgorithm::Blake3,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            HasherState::Sha256(hasher) => hasher.update(data),
+            HasherState::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    /// Lowercase hex digest.
+    pub fn finalize_hex(self) -> String {
+        match self.inner {
+            HasherState::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            HasherState::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+impl io::Write for StreamingHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentHash {
+    pub algorithm: HashAlgorithm,
+    /// Lowercase hex digest.
+    pub hex: String,
+    pub size_bytes: u64,
+}
+
+/// A digest to check against, written `sha256:<hex>`, `blake3:<hex>` or as
+/// bare hex, which means SHA-256 since that is what publishers list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedHash {
+    pub algorithm: HashAlgorithm,
+    pub hex: String,
+}
+
+impl ExpectedHash {
+    pub fn parse(value: &str) -> io::Result<Self> {
+        let value = value.trim();
+        let (algorithm, hex) = match value.split_once(':') {
+            Some((prefix, hex)) if prefix.eq_ignore_ascii_case("sha256") => {
+                (HashAlgorithm::Sha256, hex)
+            }
+            Some((prefix, hex)) if prefix.eq_ignore_ascii_case("blake3") => {
+                (HashAlgorithm::Blake3, hex)
+            }
+            Some((prefix, _)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Unsupported hash algorithm: {}", prefix),
+                ))
+            }
+            None => (HashAlgorithm::Sha256, value),
+        };
+        let hex = hex.trim().to_ascii_lowercase();
+        if hex.len() != 64 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Expected a 64-character hex {} digest", algorithm.as_str()),
+            ));
+        }
+        Ok(Self { algorithm, hex })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileVerification {
+    pub algorithm: HashAlgorithm,
+    pub expected: String,
+    pub actual: String,
+    pub size_bytes: u64,
+    pub matches: bool,
+}
+
+pub fn hash_bytes(data: &[u8], algorithm: HashAlgorithm) -> String {
+    let mut hasher = StreamingHasher::new(algorithm);
+    hasher.update(data);
+    hasher.finalize_hex()
+}
+
+/// Hashes everything `reader` yields. `on_progress` receives the bytes read
+/// so far after every chunk.
+pub fn hash_reader<R: Read>(
+    mut reader: R,
+    algorithm: HashAlgorithm,
+    mut on_progress: impl FnMut(u64),
+) -> io::Result<ContentHash> {
+    let mut hasher = StreamingHasher::new(algorithm);
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut size_bytes = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..read]);
+        size_bytes += read as u64;
+        on_progress(size_bytes);
+    }
+    Ok(ContentHash {
+        algorithm,
+        hex: hasher.finalize_hex(),
+        size_bytes,
+    })
+}
+
+/// Async counterpart of [`hash_reader`] for a file on disk.
+pub async fn hash_file(
+    path: &Path,
+    algorithm: HashAlgorithm,
+    mut on_progress: impl FnMut(u64),
+) -> io::Result<ContentHash> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = StreamingHasher::new(algorithm);
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut size_bytes = 0u64;
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size_bytes += read as u64;
+        on_progress(size_bytes);
+    }
+    Ok(ContentHash {
+        algorithm,
+        hex: hasher.finalize_hex(),
+        size_bytes,
+    })
+}
+
+/// Hashes `path` with the algorithm named by `expected` and compares.
+/// A mismatch is a result, not an error; errors are unreadable files and
+/// malformed expectations.
+pub async fn verify_file(
+    path: &Path,
+    expected: &str,
+    on_progress: impl FnMut(u64),
+) -> io::Result<FileVerification> {
+    let expected = ExpectedHash::parse(expected)?;
+    let actual = hash_file(path, expected.algorithm, on_progress).await?;
+    Ok(FileVerification {
+        algorithm: expected.algorithm,
+        matches: actual.hex == expected.hex,
+        expected: expected.hex,
+        actual: actual.hex,
+        size_bytes: actual.size_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const ABC_BLAKE3: &str = "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85";
+
+    #[test]
+    fn hashes_match_known_vectors_across_chunking() {
+        assert_eq!(hash_bytes(b"abc", HashAlgorithm::Sha256), ABC_SHA256);
+        assert_eq!(hash_bytes(b"abc", HashAlgorithm::Blake3), ABC_BLAKE3);
+
+        let data = vec![7u8; CHUNK_SIZE * 2 + 11];
+        let mut progress = Vec::new();
+        let hashed = hash_reader(data.as_slice(), HashAlgorithm::Blake3, |done| {
+            progress.push(done)
+        })
+        .unwrap();
+        assert_eq!(hashed.hex, hash_bytes(&data, HashAlgorithm::Blake3));
+        assert_eq!(hashed.size_bytes, data.len() as u64);
+        assert_eq!(progress.last(), Some(&(data.len() as u64)));
+    }
+
+    #[test]
+    fn parses_expected_hashes() {
+        let bare = ExpectedHash::parse(&ABC_SHA256.to_uppercase()).unwrap();
+        assert_eq!(bare.algorithm, HashAlgorithm::Sha256);
+        assert_eq!(bare.hex, ABC_SHA256);
+
+        let prefixed = ExpectedHash::parse(&format!("blake3:{}", ABC_BLAKE3)).unwrap();
+        assert_eq!(prefixed.algorithm, HashAlgorithm::Blake3);
+
+        assert!(ExpectedHash::parse("md5:900150983cd24fb0d6963f7d28e17f72").is_err());
+        assert!(ExpectedHash::parse("sha256:abc").is_err());
+    }
+
+    #[tokio::test]
+    async fn verify_file_reports_mismatch_without_failing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("payload.bin");
+        tokio::fs::write(&path, b"abc").await.unwrap();
+
+        let ok = verify_file(&path, &format!("sha256:{}", ABC_SHA256), |_| {})
+            .await
+            .unwrap();
+        assert!(ok.matches);
+        assert_eq!(ok.size_bytes, 3);
+
+        let mismatch = verify_file(&path, ABC_BLAKE3, |_| {}).await.unwrap();
+        assert!(!mismatch.matches);
+        assert_eq!(mismatch.actual, ABC_SHA256);
+    }
+}
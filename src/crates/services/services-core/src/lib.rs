@@ -3,9 +3,13 @@
 //! This crate owns platform-agnostic service building blocks that can be
 //! tested without compiling the full BitFun product runtime.
 
+pub mod bandwidth;
 pub mod diagnostics;
 pub mod diff;
 pub mod filesystem;
+pub mod first_launch;
+pub mod format;
+pub mod hashing;
 pub mod json_store;
 #[cfg(feature = "workspace-runtime")]
 pub mod local_runtime_ports;
@@ -19,6 +23,7 @@ pub mod permission_store;
 pub mod persistence;
 pub mod process_manager;
 pub mod process_tree;
+pub mod secret_scan;
 pub mod session;
 pub mod session_usage;
 pub mod storage_cleanup;
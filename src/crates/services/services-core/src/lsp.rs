@@ -150,11 +150,21 @@ impl PluginRegistry {
             .ok_or_else(|| LspPluginRegistryError::NotFound(plugin_id.to_string()))?;
 
         for language in &plugin.languages {
-            remove_index_if_owned(&mut self.language_map, language, plugin_id);
+            if remove_index_if_owned(&mut self.language_map, language, plugin_id) {
+                if let Some(fallback) = self.fallback_plugin_id(|p| p.languages.contains(language))
+                {
+                    self.language_map.insert(language.clone(), fallback);
+                }
+            }
         }
 
         for ext in &plugin.file_extensions {
-            remove_index_if_owned(&mut self.extension_map, ext, plugin_id);
+            if remove_index_if_owned(&mut self.extension_map, ext, plugin_id) {
+                if let Some(fallback) = self.fallback_plugin_id(|p| p.file_extensions.contains(ext))
+                {
+                    self.extension_map.insert(ext.clone(), fallback);
+                }
+            }
         }
 
         info!("Plugin unregistered: {}", plugin_id);
@@ -162,6 +172,17 @@ impl PluginRegistry {
         Ok(())
     }
 
+    /// Picks a remaining plugin to take over an index entry freed by
+    /// `unregister`, so overriding and removing a plugin restores the one it
+    /// shadowed.
+    fn fallback_plugin_id(&self, claims: impl Fn(&LspPlugin) -> bool) -> Option<String> {
+        self.plugins
+            .values()
+            .filter(|plugin| claims(plugin))
+            .map(|plugin| plugin.id.clone())
+            .min()
+    }
+
     /// Gets a plugin by plugin ID.
     pub fn get_plugin(&self, plugin_id: &str) -> Option<&LspPlugin> {
         self.plugins.get(plugin_id)
@@ -251,12 +272,14 @@ impl Default for PluginRegistry {
     }
 }
 
-fn remove_index_if_owned(index: &mut HashMap<String, String>, key: &str, plugin_id: &str) {
+fn remove_index_if_owned(index: &mut HashMap<String, String>, key: &str, plugin_id: &str) -> bool {
     if index
         .get(key)
         .map(|current_plugin_id| current_plugin_id == plugin_id)
         .unwrap_or(false)
     {
         index.remove(key);
+        return true;
     }
+    false
 }
@@ -26,6 +26,9 @@ pub struct LspManager {
     lifecycle_locks: Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>,
     /// Diagnostics cache (`uri -> diagnostics`).
     diagnostics_cache: Arc<RwLock<HashMap<String, Vec<serde_json::Value>>>>,
+    /// Settings-configured servers (`plugin_id -> resolved binary`). These
+    /// are spawned from the resolved path instead of the plugins directory.
+    configured_servers: Arc<RwLock<HashMap<String, PathBuf>>>,
 }
 
 impl LspManager {
@@ -37,6 +40,7 @@ impl LspManager {
             processes: Arc::new(RwLock::new(HashMap::new())),
             lifecycle_locks: Arc::new(Mutex::new(HashMap::new())),
             diagnostics_cache: Arc::new(RwLock::new(HashMap::new())),
+            configured_servers: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -117,6 +121,60 @@ impl LspManager {
         Ok(())
     }
 
+    /// Replaces the settings-configured servers.
+    ///
+    /// Each entry is registered as a plugin that takes precedence over
+    /// installed plugins for its languages and extensions. Returns the
+    /// languages whose server definition changed or was removed, so callers
+    /// can stop running servers and let the next request start the new one.
+    pub async fn sync_configured_servers(
+        &self,
+        servers: Vec<(LspPlugin, PathBuf)>,
+    ) -> Result<Vec<String>> {
+        let mut configured = self.configured_servers.write().await;
+        let mut registry = self.registry.write().await;
+        let mut changed_languages = Vec::new();
+
+        let mut incoming: HashMap<String, (LspPlugin, PathBuf)> = servers
+            .into_iter()
+            .map(|(plugin, path)| (plugin.id.clone(), (plugin, path)))
+            .collect();
+
+        let previous_ids: Vec<String> = configured.keys().cloned().collect();
+        for plugin_id in previous_ids {
+            let Some(previous) = registry.get_plugin(&plugin_id).cloned() else {
+                configured.remove(&plugin_id);
+                continue;
+            };
+            let unchanged = incoming.get(&plugin_id).is_some_and(|(plugin, path)| {
+                configured.get(&plugin_id) == Some(path)
+                    && serde_json::to_value(plugin).ok() == serde_json::to_value(&previous).ok()
+            });
+            if unchanged {
+                incoming.remove(&plugin_id);
+                continue;
+            }
+
+            registry.unregister(&plugin_id)?;
+            configured.remove(&plugin_id);
+            changed_languages.extend(previous.languages);
+        }
+
+        for (plugin_id, (plugin, path)) in incoming {
+            changed_languages.extend(plugin.languages.iter().cloned());
+            registry.register(plugin)?;
+            info!(
+                "Configured LSP server registered: {} -> {:?}",
+                plugin_id, path
+            );
+            configured.insert(plugin_id, path);
+        }
+
+        changed_languages.sort();
+        changed_languages.dedup();
+        Ok(changed_languages)
+    }
+
     /// Starts an LSP server.
     /// workspace_root: Workspace root path, provided by the caller (WorkspaceLspManager).
     /// crash_callback: Callback invoked when the process crashes.
@@ -155,10 +213,17 @@ impl LspManager {
             }
         }
 
-        let server_path = self.plugin_loader.get_server_path(&plugin).map_err(|e| {
-            error!("Failed to get server path: {}", e);
-            e
-        })?;
+        let configured_path = {
+            let configured = self.configured_servers.read().await;
+            configured.get(&plugin_id).cloned()
+        };
+        let server_path = match configured_path {
+            Some(path) => path,
+            None => self.plugin_loader.get_server_path(&plugin).map_err(|e| {
+                error!("Failed to get server path: {}", e);
+                e
+            })?,
+        };
 
         let process = LspServerProcess::spawn(
             plugin_id.clone(),
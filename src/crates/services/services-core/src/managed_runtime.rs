@@ -4,15 +4,118 @@
 //! crates supply the managed runtime root; command lookup and PATH merging stay
 //! reusable and testable without `bitfun-core`.
 
+use crate::process_manager;
 use crate::system;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
 
 const DEFAULT_RUNTIME_COMMANDS: &[&str] = &[
-    "node", "npm", "npx", "python", "python3", "pandoc", "soffice", "pdftoppm",
+    "node",
+    "npm",
+    "npx",
+    "python",
+    "python3",
+    "pandoc",
+    "soffice",
+    "pdftoppm",
+    "pdftotext",
+    "chromium",
+    "java",
+    "dotnet",
+    "git",
+    "whisper-cli",
 ];
-const MANAGED_COMPONENTS: &[&str] = &["node", "python", "pandoc", "office", "poppler"];
+const MANAGED_COMPONENTS: &[&str] = &[
+    "node", "python", "pandoc", "office", "poppler", "java", "dotnet", "git",
+];
+/// Git config applied through `GIT_CONFIG_*` while the managed Git component
+/// is installed. Portable Git on Windows is often unpacked deep in the user
+/// profile, and agent-driven clones must not fail on paths over `MAX_PATH`.
+const MANAGED_GIT_CONFIG: &[(&str, &str)] = &[("core.longpaths", "true")];
+/// Upper bound for a version probe; some runtimes (notably `soffice`) start
+/// slowly on a cold cache.
+const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Process-wide resolution settings, applied from the app config.
+static RESOLUTION_SETTINGS: LazyLock<RwLock<RuntimeResolutionSettings>> =
+    LazyLock::new(|| RwLock::new(RuntimeResolutionSettings::default()));
+
+/// Which copy of a command wins when both a system and a managed one exist.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeResolutionPolicy {
+    #[default]
+    PreferSystem,
+    PreferManaged,
+    /// Never fall back to a system copy.
+    ManagedOnly,
+}
+
+impl RuntimeResolutionPolicy {
+    fn prefers_managed(self) -> bool {
+        matches!(self, Self::PreferManaged | Self::ManagedOnly)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuntimeResolutionSettings {
+    pub default_policy: RuntimeResolutionPolicy,
+    /// Per-command overrides keyed by command name, e.g. `python3`.
+    pub commands: BTreeMap<String, RuntimeResolutionPolicy>,
+}
+
+impl RuntimeResolutionSettings {
+    pub fn policy_for(&self, command: &str) -> RuntimeResolutionPolicy {
+        let normalized = normalize_command_alias(command);
+        self.commands
+            .iter()
+            .find(|(name, _)| normalize_command_alias(name) == normalized)
+            .map(|(_, policy)| *policy)
+            .unwrap_or(self.default_policy)
+    }
+
+    /// A component's PATH entries follow the most managed-leaning override
+    /// among its commands, or the default policy when none is set.
+    fn policy_for_component(&self, component: &str) -> RuntimeResolutionPolicy {
+        let overrides = self
+            .commands
+            .iter()
+            .filter(|(name, _)| {
+                managed_command_spec(&normalize_command_alias(name))
+                    .is_some_and(|spec| spec.component == component)
+            })
+            .map(|(_, policy)| *policy)
+            .collect::<Vec<_>>();
+        if overrides.is_empty() {
+            return self.default_policy;
+        }
+        overrides
+            .iter()
+            .copied()
+            .find(|policy| policy.prefers_managed())
+            .unwrap_or(RuntimeResolutionPolicy::PreferSystem)
+    }
+}
+
+/// Replace the process-wide resolution settings.
+pub fn set_resolution_settings(settings: RuntimeResolutionSettings) {
+    match RESOLUTION_SETTINGS.write() {
+        Ok(mut guard) => *guard = settings,
+        Err(poisoned) => *poisoned.into_inner() = settings,
+    }
+}
+
+pub fn resolution_settings() -> RuntimeResolutionSettings {
+    match RESOLUTION_SETTINGS.read() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -38,23 +141,104 @@ pub struct RuntimeCommandCapability {
     pub resolved_path: Option<String>,
 }
 
+/// How often a command was executed, joined with where it currently resolves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeCommandUsage {
+    pub command: String,
+    /// Managed component that can provide the command, if any.
+    pub component: Option<String>,
+    pub available: bool,
+    pub source: Option<RuntimeSource>,
+    pub executions: u64,
+    pub failures: u64,
+    pub failure_rate: f64,
+    pub average_duration_ms: u64,
+    pub last_executed_at: Option<i64>,
+}
+
+/// Where a command match was found, in resolution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommandMatchOrigin {
+    SystemPath,
+    /// Platform PATH extras, e.g. Homebrew directories on macOS.
+    PlatformPath,
+    Managed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMatch {
+    pub path: String,
+    pub origin: CommandMatchOrigin,
+    /// The match `resolve_command` picks.
+    pub active: bool,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommandConflictKind {
+    /// An installed managed runtime is hidden by a system copy.
+    ManagedShadowed,
+    /// Copies with different versions; the first one wins.
+    VersionMismatch,
+    /// Several copies whose versions could not be compared.
+    DuplicateOnPath,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandConflict {
+    pub kind: CommandConflictKind,
+    pub active_path: String,
+    pub shadowed_path: String,
+}
+
+/// Every match for a command in resolution order, with shadowing conflicts
+/// and suggested fixes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandDiagnosis {
+    pub command: String,
+    pub matches: Vec<CommandMatch>,
+    pub conflicts: Vec<CommandConflict>,
+    pub suggestions: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ManagedRuntimeResolver {
     runtime_root: PathBuf,
+    /// Overrides the process-wide settings when set.
+    settings: Option<RuntimeResolutionSettings>,
 }
 
 struct ManagedCommandSpec {
     component: &'static str,
     candidates: &'static [&'static str],
+    /// Arguments that make the command print its version and exit.
+    version_args: &'static [&'static str],
 }
 
 impl ManagedRuntimeResolver {
     pub fn new(runtime_root: impl Into<PathBuf>) -> Self {
         Self {
             runtime_root: runtime_root.into(),
+            settings: None,
         }
     }
 
+    /// Use `settings` instead of the process-wide resolution settings.
+    pub fn with_resolution_settings(mut self, settings: RuntimeResolutionSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    pub fn resolution_settings(&self) -> RuntimeResolutionSettings {
+        self.settings.clone().unwrap_or_else(resolution_settings)
+    }
+
     pub fn runtime_root(&self) -> &Path {
         &self.runtime_root
     }
@@ -65,15 +249,22 @@ impl ManagedRuntimeResolver {
 
     /// Resolve a command from:
     /// 1) explicit path command
-    /// 2) system PATH
-    /// 3) BitFun managed runtimes
+    /// 2) system PATH and BitFun managed runtimes, in the order set by the
+    ///    command's [`RuntimeResolutionPolicy`]
     pub fn resolve_command(&self, command: &str) -> Option<ResolvedCommand> {
         if is_path_like_command(command) {
             return self.resolve_explicit_path_command(command);
         }
 
-        self.resolve_system_command(command)
-            .or_else(|| self.resolve_managed_command(command))
+        match self.resolution_settings().policy_for(command) {
+            RuntimeResolutionPolicy::PreferSystem => self
+                .resolve_system_command(command)
+                .or_else(|| self.resolve_managed_command(command)),
+            RuntimeResolutionPolicy::PreferManaged => self
+                .resolve_managed_command(command)
+                .or_else(|| self.resolve_system_command(command)),
+            RuntimeResolutionPolicy::ManagedOnly => self.resolve_managed_command(command),
+        }
     }
 
     /// Build a snapshot of runtime capabilities for commonly used commands.
@@ -114,23 +305,98 @@ impl ManagedRuntimeResolver {
             .collect()
     }
 
-    /// Returns managed runtime PATH entries to be prepended to process PATH.
-    pub fn managed_path_entries(&self) -> Vec<PathBuf> {
-        let mut entries = Vec::new();
-        for component in MANAGED_COMPONENTS {
-            let component_root = self.runtime_root.join(component).join("current");
-            if !component_root.exists() || !component_root.is_dir() {
-                continue;
+    /// Execution statistics for the default runtime commands plus any other
+    /// command that has been recorded, sorted by command name.
+    pub fn get_usage(&self) -> Vec<RuntimeCommandUsage> {
+        let stats = process_manager::command_execution_stats();
+        let mut commands = DEFAULT_RUNTIME_COMMANDS
+            .iter()
+            .map(|command| command.to_string())
+            .collect::<Vec<_>>();
+        for recorded in &stats {
+            if !commands.contains(&recorded.command) {
+                commands.push(recorded.command.clone());
             }
+        }
+        commands.sort();
 
-            for rel in managed_component_path_entries(component) {
-                let candidate = if rel.is_empty() {
-                    component_root.clone()
-                } else {
-                    component_root.join(rel)
-                };
+        commands
+            .into_iter()
+            .map(|command| {
+                let capability = self.get_command_capability(&command);
+                let recorded = stats
+                    .iter()
+                    .find(|recorded| recorded.command == command)
+                    .cloned()
+                    .unwrap_or_default();
+                RuntimeCommandUsage {
+                    component: managed_command_spec(&command)
+                        .map(|spec| spec.component.to_string()),
+                    available: capability.available,
+                    source: capability.source,
+                    executions: recorded.executions,
+                    failures: recorded.failures,
+                    failure_rate: recorded.failure_rate(),
+                    average_duration_ms: recorded.average_duration_ms(),
+                    last_executed_at: recorded.last_executed_at,
+                    command,
+                }
+            })
+            .collect()
+    }
+
+    /// Run the command's version probe and return the first line it prints.
+    /// Returns `None` for unknown or unresolvable commands, or when the probe
+    /// fails or times out.
+    pub async fn probe_command_version(&self, command: &str) -> Option<String> {
+        let version_args = managed_command_spec(&normalize_command_alias(command))?.version_args;
+        let resolved = self.resolve_command(command)?;
+        probe_version(&resolved.command, version_args).await
+    }
+
+    /// List every copy of `command` across system PATH, platform PATH extras
+    /// and managed runtimes, in the order `resolve_command` considers them.
+    pub async fn diagnose_command(&self, command: &str) -> CommandDiagnosis {
+        let command = command.trim();
+        let version_args = managed_command_spec(&normalize_command_alias(command))
+            .map(|spec| spec.version_args)
+            .unwrap_or_default();
 
-                if candidate.exists() && candidate.is_dir() && !entries.contains(&candidate) {
+        let active_path = self
+            .resolve_command(command)
+            .and_then(|resolved| resolved.resolved_path)
+            .map(|path| canonical_key(Path::new(&path)));
+        let mut matches = Vec::new();
+        for (path, origin) in self.find_all_command_paths(command) {
+            let version = if version_args.is_empty() {
+                None
+            } else {
+                probe_version(&path.to_string_lossy(), version_args).await
+            };
+            matches.push(CommandMatch {
+                path: path.to_string_lossy().to_string(),
+                origin,
+                active: active_path.as_ref() == Some(&canonical_key(&path)),
+                version,
+            });
+        }
+
+        let conflicts = find_command_conflicts(&matches);
+        let suggestions = suggest_command_fixes(command, &matches, &conflicts);
+        CommandDiagnosis {
+            command: command.to_string(),
+            matches,
+            conflicts,
+            suggestions,
+        }
+    }
+
+    /// Returns managed runtime PATH entries for every installed component.
+    pub fn managed_path_entries(&self) -> Vec<PathBuf> {
+        let mut entries = Vec::new();
+        for component in MANAGED_COMPONENTS {
+            for candidate in self.component_path_entries(component) {
+                if !entries.contains(&candidate) {
                     entries.push(candidate);
                 }
             }
@@ -138,12 +404,41 @@ impl ManagedRuntimeResolver {
         entries
     }
 
+    /// `GIT_CONFIG_COUNT` / `GIT_CONFIG_KEY_n` / `GIT_CONFIG_VALUE_n` variables
+    /// carrying the managed Git defaults. Empty unless the managed Git
+    /// component is installed. Entries are appended after any config already
+    /// passed through the environment.
+    pub fn managed_git_config_env(&self) -> Vec<(String, String)> {
+        if !self.runtime_root.join("git").join("current").is_dir() {
+            return Vec::new();
+        }
+        git_config_env(
+            std::env::var("GIT_CONFIG_COUNT").ok().as_deref(),
+            MANAGED_GIT_CONFIG,
+        )
+    }
+
     /// Merge managed runtime PATH entries with existing PATH value.
+    ///
+    /// Components whose policy prefers managed runtimes go before the
+    /// existing PATH; the rest go after it and the platform extras, matching
+    /// the order `resolve_command` uses.
     pub fn merged_path_env(&self, existing_path: Option<&str>) -> Option<String> {
-        let managed_entries = self.managed_path_entries();
+        let settings = self.resolution_settings();
+        let mut leading_entries = Vec::new();
+        let mut trailing_entries = Vec::new();
+        for component in MANAGED_COMPONENTS {
+            let entries = self.component_path_entries(component);
+            if settings.policy_for_component(component).prefers_managed() {
+                leading_entries.extend(entries);
+            } else {
+                trailing_entries.extend(entries);
+            }
+        }
         let platform_entries = system::platform_path_entries();
 
-        if managed_entries.is_empty()
+        if leading_entries.is_empty()
+            && trailing_entries.is_empty()
             && platform_entries.is_empty()
             && existing_path.map(|v| v.trim().is_empty()).unwrap_or(true)
         {
@@ -153,7 +448,7 @@ impl ManagedRuntimeResolver {
         let mut merged = Vec::new();
         let mut seen = HashSet::new();
 
-        for path in managed_entries {
+        for path in leading_entries {
             let key = path.to_string_lossy().to_string();
             if seen.insert(key) {
                 merged.push(path);
@@ -182,6 +477,13 @@ impl ManagedRuntimeResolver {
             }
         }
 
+        for path in trailing_entries {
+            let key = path.to_string_lossy().to_string();
+            if seen.insert(key) {
+                merged.push(path);
+            }
+        }
+
         std::env::join_paths(merged)
             .ok()
             .map(|v| v.to_string_lossy().to_string())
@@ -223,6 +525,70 @@ impl ManagedRuntimeResolver {
         })
     }
 
+    fn component_path_entries(&self, component: &str) -> Vec<PathBuf> {
+        let component_root = self.runtime_root.join(component).join("current");
+        if !component_root.is_dir() {
+            return Vec::new();
+        }
+
+        managed_component_path_entries(component)
+            .iter()
+            .map(|rel| {
+                if rel.is_empty() {
+                    component_root.clone()
+                } else {
+                    component_root.join(rel)
+                }
+            })
+            .filter(|candidate| candidate.is_dir())
+            .collect()
+    }
+
+    fn find_all_command_paths(&self, command: &str) -> Vec<(PathBuf, CommandMatchOrigin)> {
+        if is_path_like_command(command) {
+            return self
+                .resolve_explicit_path_command(command)
+                .map(|_| vec![(PathBuf::from(command), CommandMatchOrigin::SystemPath)])
+                .unwrap_or_default();
+        }
+
+        let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let mut found = Vec::new();
+        let mut seen = HashSet::new();
+        let mut push = |path: PathBuf, origin: CommandMatchOrigin| {
+            // `/bin` is often a symlink to `/usr/bin`; report the file once.
+            if seen.insert(canonical_key(&path)) {
+                found.push((path, origin));
+            }
+        };
+
+        if let Some(path_env) = std::env::var_os("PATH") {
+            if let Ok(paths) = which::which_in_all(command, Some(path_env), &cwd) {
+                paths.for_each(|path| push(path, CommandMatchOrigin::SystemPath));
+            }
+        }
+        let platform_entries = system::platform_path_entries();
+        if !platform_entries.is_empty() {
+            if let Ok(joined) = std::env::join_paths(platform_entries) {
+                if let Ok(paths) = which::which_in_all(command, Some(joined), &cwd) {
+                    paths.for_each(|path| push(path, CommandMatchOrigin::PlatformPath));
+                }
+            }
+        }
+        if let Some(path) = self.find_managed_command_path(command) {
+            push(path, CommandMatchOrigin::Managed);
+        }
+        if self
+            .resolution_settings()
+            .policy_for(command)
+            .prefers_managed()
+        {
+            // Stable sort keeps the system matches in PATH order.
+            found.sort_by_key(|(_, origin)| *origin != CommandMatchOrigin::Managed);
+        }
+        found
+    }
+
     fn find_managed_command_path(&self, command: &str) -> Option<PathBuf> {
         let normalized = normalize_command_alias(command);
         let spec = managed_command_spec(&normalized)?;
@@ -239,6 +605,153 @@ impl ManagedRuntimeResolver {
     }
 }
 
+/// Record one finished run of `command` for [`ManagedRuntimeResolver::get_usage`].
+/// Platform aliases such as `npm.cmd` are counted under their base command.
+pub fn record_command_execution(command: &str, success: bool, duration: Duration) {
+    let name = Path::new(command.trim())
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| command.trim().to_string());
+    process_manager::record_command_execution(&normalize_command_alias(&name), success, duration);
+}
+
+async fn probe_version(program: &str, version_args: &[&str]) -> Option<String> {
+    let mut probe = process_manager::create_tokio_command(program);
+    probe
+        .args(version_args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let output = tokio::time::timeout(VERSION_PROBE_TIMEOUT, probe.output())
+        .await
+        .ok()?
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // `java -version` and the poppler tools' `-v` print to stderr.
+    first_non_empty_line(&output.stdout).or_else(|| first_non_empty_line(&output.stderr))
+}
+
+/// Compares the active match with every match it shadows.
+fn find_command_conflicts(matches: &[CommandMatch]) -> Vec<CommandConflict> {
+    let Some(active) = matches.iter().find(|candidate| candidate.active) else {
+        return Vec::new();
+    };
+    matches
+        .iter()
+        .filter(|other| !other.active)
+        .filter_map(|other| {
+            let kind = if other.origin == CommandMatchOrigin::Managed
+                && active.origin != CommandMatchOrigin::Managed
+            {
+                CommandConflictKind::ManagedShadowed
+            } else {
+                match (&active.version, &other.version) {
+                    (Some(active_version), Some(other_version))
+                        if active_version == other_version =>
+                    {
+                        return None;
+                    }
+                    (Some(_), Some(_)) => CommandConflictKind::VersionMismatch,
+                    _ => CommandConflictKind::DuplicateOnPath,
+                }
+            };
+            Some(CommandConflict {
+                kind,
+                active_path: active.path.clone(),
+                shadowed_path: other.path.clone(),
+            })
+        })
+        .collect()
+}
+
+fn suggest_command_fixes(
+    command: &str,
+    matches: &[CommandMatch],
+    conflicts: &[CommandConflict],
+) -> Vec<String> {
+    if matches.is_empty() {
+        let spec = managed_command_spec(&normalize_command_alias(command));
+        return vec![match spec {
+            Some(spec) => format!(
+                "`{}` was not found. Install the managed `{}` runtime, or install it on the system PATH.",
+                command, spec.component
+            ),
+            None => format!(
+                "`{}` was not found. Install it and make sure its directory is on PATH.",
+                command
+            ),
+        }];
+    }
+
+    let version_of = |path: &str| {
+        matches
+            .iter()
+            .find(|candidate| candidate.path == path)
+            .and_then(|candidate| candidate.version.as_deref())
+            .unwrap_or("unknown version")
+    };
+    conflicts
+        .iter()
+        .map(|conflict| match conflict.kind {
+            CommandConflictKind::ManagedShadowed => format!(
+                "The managed `{}` ({}) is hidden by `{}` ({}). Set the command to prefer the managed runtime, or remove that copy from PATH.",
+                command,
+                version_of(&conflict.shadowed_path),
+                conflict.active_path,
+                version_of(&conflict.active_path)
+            ),
+            CommandConflictKind::VersionMismatch => format!(
+                "`{}` ({}) takes precedence over `{}` ({}). Reorder PATH if you meant to use the latter.",
+                conflict.active_path,
+                version_of(&conflict.active_path),
+                conflict.shadowed_path,
+                version_of(&conflict.shadowed_path)
+            ),
+            CommandConflictKind::DuplicateOnPath => format!(
+                "Several copies of `{}` are on PATH; `{}` is used and `{}` is ignored.",
+                command, conflict.active_path, conflict.shadowed_path
+            ),
+        })
+        .collect()
+}
+
+fn canonical_key(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn git_config_env(existing_count: Option<&str>, config: &[(&str, &str)]) -> Vec<(String, String)> {
+    let start = existing_count
+        .and_then(|count| count.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut env = Vec::with_capacity(config.len() * 2 + 1);
+    for (offset, (key, value)) in config.iter().enumerate() {
+        env.push((
+            format!("GIT_CONFIG_KEY_{}", start + offset),
+            key.to_string(),
+        ));
+        env.push((
+            format!("GIT_CONFIG_VALUE_{}", start + offset),
+            value.to_string(),
+        ));
+    }
+    env.push((
+        "GIT_CONFIG_COUNT".to_string(),
+        (start + config.len()).to_string(),
+    ));
+    env
+}
+
+fn first_non_empty_line(output: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(output)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
 fn normalize_command_alias(command: &str) -> String {
     match command.to_ascii_lowercase().as_str() {
         "node.exe" => "node".to_string(),
@@ -248,6 +761,14 @@ fn normalize_command_alias(command: &str) -> String {
         "python3.exe" => "python3".to_string(),
         "soffice.exe" => "soffice".to_string(),
         "pdftoppm.exe" => "pdftoppm".to_string(),
+        "pdftotext.exe" => "pdftotext".to_string(),
+        "java.exe" => "java".to_string(),
+        "dotnet.exe" => "dotnet".to_string(),
+        "git.exe" => "git".to_string(),
+        "whisper-cli.exe" => "whisper-cli".to_string(),
+        "chromium.exe" | "chrome-headless-shell" | "chrome-headless-shell.exe" => {
+            "chromium".to_string()
+        }
         other => other.to_string(),
     }
 }
@@ -257,14 +778,17 @@ fn managed_command_spec(command: &str) -> Option<ManagedCommandSpec> {
         "node" => Some(ManagedCommandSpec {
             component: "node",
             candidates: &["node", "node.exe", "bin/node", "bin/node.exe"],
+            version_args: &["--version"],
         }),
         "npm" => Some(ManagedCommandSpec {
             component: "node",
             candidates: &["npm", "npm.cmd", "bin/npm", "bin/npm.cmd"],
+            version_args: &["--version"],
         }),
         "npx" => Some(ManagedCommandSpec {
             component: "node",
             candidates: &["npx", "npx.cmd", "bin/npx", "bin/npx.cmd"],
+            version_args: &["--version"],
         }),
         "python" => Some(ManagedCommandSpec {
             component: "python",
@@ -276,6 +800,7 @@ fn managed_command_spec(command: &str) -> Option<ManagedCommandSpec> {
                 "bin/python3",
                 "bin/python3.exe",
             ],
+            version_args: &["--version"],
         }),
         "python3" => Some(ManagedCommandSpec {
             component: "python",
@@ -289,10 +814,12 @@ fn managed_command_spec(command: &str) -> Option<ManagedCommandSpec> {
                 "bin/python",
                 "bin/python.exe",
             ],
+            version_args: &["--version"],
         }),
         "pandoc" => Some(ManagedCommandSpec {
             component: "pandoc",
             candidates: &["pandoc", "pandoc.exe", "bin/pandoc", "bin/pandoc.exe"],
+            version_args: &["--version"],
         }),
         "soffice" => Some(ManagedCommandSpec {
             component: "office",
@@ -304,6 +831,7 @@ fn managed_command_spec(command: &str) -> Option<ManagedCommandSpec> {
                 "program/soffice",
                 "program/soffice.exe",
             ],
+            version_args: &["--version"],
         }),
         "pdftoppm" => Some(ManagedCommandSpec {
             component: "poppler",
@@ -314,6 +842,76 @@ fn managed_command_spec(command: &str) -> Option<ManagedCommandSpec> {
                 "bin/pdftoppm.exe",
                 "Library/bin/pdftoppm.exe",
             ],
+            version_args: &["-v"],
+        }),
+        "pdftotext" => Some(ManagedCommandSpec {
+            component: "poppler",
+            candidates: &[
+                "pdftotext",
+                "pdftotext.exe",
+                "bin/pdftotext",
+                "bin/pdftotext.exe",
+                "Library/bin/pdftotext.exe",
+            ],
+            version_args: &["-v"],
+        }),
+        // Browsers are launched by path rather than from PATH, so the
+        // component is not listed in `MANAGED_COMPONENTS`.
+        "chromium" => Some(ManagedCommandSpec {
+            component: "chromium",
+            candidates: &[
+                "chrome-headless-shell",
+                "chrome-headless-shell.exe",
+                "chrome",
+                "chrome.exe",
+                "chromium",
+                "chrome-headless-shell-linux64/chrome-headless-shell",
+                "chrome-headless-shell-win64/chrome-headless-shell.exe",
+                "chrome-headless-shell-mac-arm64/chrome-headless-shell",
+                "chrome-headless-shell-mac-x64/chrome-headless-shell",
+                "chrome-linux64/chrome",
+                "chrome-win64/chrome.exe",
+                "Chromium.app/Contents/MacOS/Chromium",
+            ],
+            version_args: &["--version"],
+        }),
+        "java" => Some(ManagedCommandSpec {
+            component: "java",
+            candidates: &[
+                "bin/java",
+                "bin/java.exe",
+                "java",
+                "java.exe",
+                "Contents/Home/bin/java",
+            ],
+            version_args: &["-version"],
+        }),
+        // A runtime-only .NET install has no SDK, so `dotnet --version`
+        // fails; `--list-runtimes` works for both.
+        "dotnet" => Some(ManagedCommandSpec {
+            component: "dotnet",
+            candidates: &["dotnet", "dotnet.exe"],
+            version_args: &["--list-runtimes"],
+        }),
+        // Portable Git for Windows keeps the `git.exe` meant for callers in
+        // `cmd/`; `bin/git.exe` is a fallback for trimmed layouts.
+        "git" => Some(ManagedCommandSpec {
+            component: "git",
+            candidates: &["cmd/git.exe", "bin/git.exe", "bin/git", "git", "git.exe"],
+            version_args: &["--version"],
+        }),
+        // whisper.cpp is only invoked by the speech service, so like the
+        // browser it stays off PATH. It has no version flag; `--help` exits 0.
+        "whisper-cli" => Some(ManagedCommandSpec {
+            component: "whisper-cpp",
+            candidates: &[
+                "whisper-cli",
+                "whisper-cli.exe",
+                "bin/whisper-cli",
+                "bin/whisper-cli.exe",
+                "Release/whisper-cli.exe",
+            ],
+            version_args: &["--help"],
         }),
         _ => None,
     }
@@ -326,6 +924,12 @@ fn managed_component_path_entries(component: &str) -> &'static [&'static str] {
         "pandoc" => &["", "bin"],
         "office" => &["", "program", "bin"],
         "poppler" => &["", "bin", "Library/bin"],
+        // JRE archives for macOS keep the runtime inside a bundle layout.
+        "java" => &["bin", "Contents/Home/bin"],
+        "dotnet" => &[""],
+        // `usr/bin` is left out on purpose: it would shadow Windows tools
+        // such as `find` and `sort` with their MSYS counterparts.
+        "git" => &["cmd", "bin"],
         _ => &[""],
     }
 }
@@ -387,6 +991,115 @@ mod tests {
         let _ = fs::remove_dir_all(root);
     }
 
+    #[test]
+    fn finds_managed_headless_chromium_by_alias() {
+        let root = temp_runtime_root();
+        let shell_path = root
+            .join("chromium")
+            .join("current")
+            .join("chrome-headless-shell-linux64")
+            .join("chrome-headless-shell");
+        create_test_file(&shell_path);
+
+        let manager = ManagedRuntimeResolver::new(root.clone());
+        assert_eq!(
+            manager.find_managed_command_path("chrome-headless-shell"),
+            Some(shell_path)
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn finds_managed_whisper_cli() {
+        let root = temp_runtime_root();
+        let cli_path = root
+            .join("whisper-cpp")
+            .join("current")
+            .join("bin")
+            .join("whisper-cli");
+        create_test_file(&cli_path);
+
+        let manager = ManagedRuntimeResolver::new(root.clone());
+        assert_eq!(
+            manager.find_managed_command_path("whisper-cli"),
+            Some(cli_path)
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn finds_managed_java_and_dotnet() {
+        let root = temp_runtime_root();
+        let java_path = root
+            .join("java")
+            .join("current")
+            .join("Contents")
+            .join("Home")
+            .join("bin")
+            .join("java");
+        let dotnet_path = root.join("dotnet").join("current").join("dotnet.exe");
+        create_test_file(&java_path);
+        create_test_file(&dotnet_path);
+
+        let manager = ManagedRuntimeResolver::new(root.clone());
+        assert_eq!(
+            manager.find_managed_command_path("java"),
+            Some(java_path.clone())
+        );
+        assert_eq!(
+            manager.find_managed_command_path("dotnet.exe"),
+            Some(dotnet_path.clone())
+        );
+
+        let entries = manager.managed_path_entries();
+        assert!(entries
+            .iter()
+            .any(|p| Some(p.as_path()) == java_path.parent()));
+        assert!(entries
+            .iter()
+            .any(|p| Some(p.as_path()) == dotnet_path.parent()));
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn finds_managed_portable_git_and_sets_long_paths() {
+        let root = temp_runtime_root();
+        let git_path = root.join("git").join("current").join("cmd").join("git.exe");
+        create_test_file(&git_path);
+
+        let manager = ManagedRuntimeResolver::new(root.clone());
+        assert_eq!(
+            manager.find_managed_command_path("git.exe"),
+            Some(git_path.clone())
+        );
+        assert!(manager
+            .managed_path_entries()
+            .iter()
+            .any(|p| Some(p.as_path()) == git_path.parent()));
+        assert!(manager
+            .managed_git_config_env()
+            .iter()
+            .any(|(_, value)| value == "core.longpaths"));
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn git_config_env_appends_after_existing_entries() {
+        let env = git_config_env(Some("2"), &[("core.longpaths", "true")]);
+        assert_eq!(
+            env,
+            vec![
+                ("GIT_CONFIG_KEY_2".to_string(), "core.longpaths".to_string()),
+                ("GIT_CONFIG_VALUE_2".to_string(), "true".to_string()),
+                ("GIT_CONFIG_COUNT".to_string(), "3".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn merged_path_env_prepends_managed_entries() {
         let root = temp_runtime_root();
@@ -409,4 +1122,169 @@ mod tests {
 
         let _ = fs::remove_dir_all(root);
     }
+
+    #[test]
+    fn usage_counts_recorded_executions_under_base_command() {
+        let command = format!("bitfun-usage-test-{}", std::process::id());
+        record_command_execution(&command, true, Duration::from_millis(100));
+        record_command_execution(
+            &format!("/opt/tools/{}", command),
+            false,
+            Duration::from_millis(300),
+        );
+
+        let manager = ManagedRuntimeResolver::new(temp_runtime_root());
+        let usage = manager.get_usage();
+        let entry = usage.iter().find(|usage| usage.command == command).unwrap();
+
+        assert_eq!(entry.executions, 2);
+        assert_eq!(entry.failures, 1);
+        assert_eq!(entry.average_duration_ms, 200);
+        assert!((entry.failure_rate - 0.5).abs() < f64::EPSILON);
+        assert!(entry.component.is_none());
+        assert!(usage.iter().any(|usage| usage.command == "node"));
+    }
+
+    fn command_match(
+        path: &str,
+        origin: CommandMatchOrigin,
+        version: Option<&str>,
+    ) -> CommandMatch {
+        CommandMatch {
+            path: path.to_string(),
+            origin,
+            active: false,
+            version: version.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn flags_managed_runtime_shadowed_by_old_system_copy() {
+        let mut matches = vec![
+            command_match(
+                "/usr/bin/node",
+                CommandMatchOrigin::SystemPath,
+                Some("v12.22.9"),
+            ),
+            command_match(
+                "/usr/local/bin/node",
+                CommandMatchOrigin::SystemPath,
+                Some("v12.22.9"),
+            ),
+            command_match("/opt/n/bin/node", CommandMatchOrigin::SystemPath, None),
+            command_match(
+                "/rt/node/current/bin/node",
+                CommandMatchOrigin::Managed,
+                Some("v22.20.0"),
+            ),
+        ];
+        matches[0].active = true;
+
+        let conflicts = find_command_conflicts(&matches);
+        let kinds = conflicts.iter().map(|c| c.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                CommandConflictKind::DuplicateOnPath,
+                CommandConflictKind::ManagedShadowed
+            ]
+        );
+        assert_eq!(conflicts[1].shadowed_path, "/rt/node/current/bin/node");
+
+        let suggestions = suggest_command_fixes("node", &matches, &conflicts);
+        assert_eq!(suggestions.len(), 2);
+        assert!(suggestions[1].contains("v22.20.0"));
+        assert!(suggestions[1].contains("v12.22.9"));
+    }
+
+    #[test]
+    fn suggests_managed_install_when_command_is_missing() {
+        let suggestions = suggest_command_fixes("pandoc", &[], &[]);
+        assert_eq!(suggestions.len(), 1);
+        assert!(suggestions[0].contains("managed `pandoc` runtime"));
+        assert!(find_command_conflicts(&[]).is_empty());
+    }
+
+    #[test]
+    fn resolution_policy_orders_managed_path_entries() {
+        let root = temp_runtime_root();
+        let node_bin = root.join("node").join("current").join("bin");
+        fs::create_dir_all(&node_bin).unwrap();
+        let existing = if cfg!(windows) {
+            r"C:\Windows\System32"
+        } else {
+            "/usr/bin"
+        };
+        let position = |settings: RuntimeResolutionSettings, path: &Path| {
+            let manager =
+                ManagedRuntimeResolver::new(root.clone()).with_resolution_settings(settings);
+            let merged = manager.merged_path_env(Some(existing)).unwrap();
+            std::env::split_paths(&merged)
+                .position(|entry| entry == path)
+                .unwrap()
+        };
+
+        let prefer_system = RuntimeResolutionSettings::default();
+        assert!(
+            position(prefer_system.clone(), node_bin.as_path())
+                > position(prefer_system, Path::new(existing))
+        );
+
+        let prefer_managed_node = RuntimeResolutionSettings {
+            commands: BTreeMap::from([(
+                "npm.cmd".to_string(),
+                RuntimeResolutionPolicy::PreferManaged,
+            )]),
+            ..Default::default()
+        };
+        assert!(
+            position(prefer_managed_node.clone(), node_bin.as_path())
+                < position(prefer_managed_node, Path::new(existing))
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn prefer_managed_policy_picks_managed_copy_first() {
+        let root = temp_runtime_root();
+        let node_path = root.join("node").join("current").join("bin").join("node");
+        create_test_file(&node_path);
+
+        let settings = RuntimeResolutionSettings {
+            default_policy: RuntimeResolutionPolicy::PreferManaged,
+            ..Default::default()
+        };
+        let manager = ManagedRuntimeResolver::new(root.clone()).with_resolution_settings(settings);
+        let resolved = manager.resolve_command("node").unwrap();
+
+        assert_eq!(resolved.source, RuntimeSource::Managed);
+        assert_eq!(
+            resolved.resolved_path,
+            Some(node_path.to_string_lossy().to_string())
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn managed_only_policy_ignores_system_commands() {
+        let root = temp_runtime_root();
+        let manager = ManagedRuntimeResolver::new(root.clone());
+        assert!(manager
+            .clone()
+            .with_resolution_settings(RuntimeResolutionSettings::default())
+            .resolve_command("sh")
+            .is_some());
+
+        let settings = RuntimeResolutionSettings {
+            commands: BTreeMap::from([("sh".to_string(), RuntimeResolutionPolicy::ManagedOnly)]),
+            ..Default::default()
+        };
+        assert!(manager
+            .with_resolution_settings(settings)
+            .resolve_command("sh")
+            .is_none());
+    }
 }
@@ -1,16 +1,19 @@
 //! Unified process management to avoid Windows child process leaks
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::process::Command;
-use std::sync::LazyLock;
 #[cfg(target_os = "macos")]
 use std::sync::OnceLock;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
 use tokio::process::Command as TokioCommand;
 
 #[cfg(windows)]
 use log::warn;
 
 #[cfg(windows)]
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
@@ -23,9 +26,38 @@ const CREATE_NO_WINDOW: u32 = 0x08000000;
 
 static GLOBAL_PROCESS_MANAGER: LazyLock<ProcessManager> = LazyLock::new(ProcessManager::new);
 
+/// Execution counters for one command, accumulated since app start.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandExecutionStats {
+    pub command: String,
+    pub executions: u64,
+    pub failures: u64,
+    pub total_duration_ms: u64,
+    /// Unix timestamp in milliseconds of the most recent execution.
+    pub last_executed_at: Option<i64>,
+}
+
+impl CommandExecutionStats {
+    pub fn average_duration_ms(&self) -> u64 {
+        self.total_duration_ms
+            .checked_div(self.executions)
+            .unwrap_or(0)
+    }
+
+    pub fn failure_rate(&self) -> f64 {
+        if self.executions == 0 {
+            0.0
+        } else {
+            self.failures as f64 / self.executions as f64
+        }
+    }
+}
+
 pub struct ProcessManager {
     #[cfg(windows)]
     job: Arc<Mutex<Option<Job>>>,
+    executions: Mutex<HashMap<String, CommandExecutionStats>>,
 }
 
 impl ProcessManager {
@@ -33,6 +65,7 @@ impl ProcessManager {
         let manager = Self {
             #[cfg(windows)]
             job: Arc::new(Mutex::new(None)),
+            executions: Mutex::new(HashMap::new()),
         };
 
         #[cfg(windows)]
@@ -69,6 +102,38 @@ impl ProcessManager {
         Ok(())
     }
 
+    pub fn record_execution(&self, command: &str, success: bool, duration: Duration) {
+        let mut executions = match self.executions.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let stats =
+            executions
+                .entry(command.to_string())
+                .or_insert_with(|| CommandExecutionStats {
+                    command: command.to_string(),
+                    ..Default::default()
+                });
+        stats.executions += 1;
+        if !success {
+            stats.failures += 1;
+        }
+        stats.total_duration_ms = stats
+            .total_duration_ms
+            .saturating_add(duration.as_millis() as u64);
+        stats.last_executed_at = Some(chrono::Utc::now().timestamp_millis());
+    }
+
+    pub fn execution_stats(&self) -> Vec<CommandExecutionStats> {
+        let executions = match self.executions.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut stats = executions.values().cloned().collect::<Vec<_>>();
+        stats.sort_by(|a, b| a.command.cmp(&b.command));
+        stats
+    }
+
     pub fn cleanup_all(&self) {
         #[cfg(windows)]
         {
@@ -162,6 +227,17 @@ fn build_macos_path_env() -> Option<std::ffi::OsString> {
     std::env::join_paths(merged).ok()
 }
 
+/// Record one finished run of `command` (a logical command name such as
+/// `pandoc`, not a resolved path).
+pub fn record_command_execution(command: &str, success: bool, duration: Duration) {
+    GLOBAL_PROCESS_MANAGER.record_execution(command, success, duration);
+}
+
+/// Execution counters for every command recorded so far, sorted by name.
+pub fn command_execution_stats() -> Vec<CommandExecutionStats> {
+    GLOBAL_PROCESS_MANAGER.execution_stats()
+}
+
 pub fn cleanup_all_processes() {
     GLOBAL_PROCESS_MANAGER.cleanup_all();
 }
@@ -21,6 +21,21 @@ pub enum CleanupOutcome {
     Forced,
 }
 
+/// Resource ceilings for a spawned process tree. Unix applies them as
+/// rlimits that descendants inherit; Windows applies the memory ceiling as a
+/// job working-set limit and has no CPU-time equivalent here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessTreeLimits {
+    pub max_memory_bytes: Option<u64>,
+    pub max_cpu_seconds: Option<u64>,
+}
+
+impl ProcessTreeLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_memory_bytes.is_none() && self.max_cpu_seconds.is_none()
+    }
+}
+
 pub struct ProcessTreeChild {
     child: Child,
     platform: PlatformProcessTree,
@@ -37,11 +52,25 @@ impl fmt::Debug for ProcessTreeChild {
 
 impl ProcessTreeChild {
     pub async fn spawn(command: &mut Command) -> io::Result<Self> {
+        Self::spawn_with_limits(command, ProcessTreeLimits::default()).await
+    }
+
+    pub async fn spawn_with_limits(
+        command: &mut Command,
+        limits: ProcessTreeLimits,
+    ) -> io::Result<Self> {
         command.kill_on_drop(true);
 
         #[cfg(unix)]
         {
             command.process_group(0);
+            if !limits.is_unlimited() {
+                // SAFETY: the hook runs in the forked child before exec and
+                // only calls `setrlimit`, which is async-signal-safe.
+                unsafe {
+                    command.pre_exec(move || apply_unix_limits(limits));
+                }
+            }
             let child = command.spawn()?;
             let process_group_id = child
                 .id()
@@ -55,7 +84,7 @@ impl ProcessTreeChild {
 
         #[cfg(windows)]
         {
-            spawn_windows_process_tree(command).await
+            spawn_windows_process_tree(command, limits).await
         }
     }
 
@@ -148,6 +177,34 @@ struct PlatformProcessTree {
     process_group_id: i32,
 }
 
+#[cfg(unix)]
+fn apply_unix_limits(limits: ProcessTreeLimits) -> io::Result<()> {
+    if let Some(bytes) = limits.max_memory_bytes {
+        let limit = unix_rlimit(bytes);
+        // SAFETY: `limit` is a fully initialized rlimit owned by this frame.
+        if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    if let Some(seconds) = limits.max_cpu_seconds {
+        let limit = unix_rlimit(seconds);
+        // SAFETY: `limit` is a fully initialized rlimit owned by this frame.
+        if unsafe { libc::setrlimit(libc::RLIMIT_CPU, &limit) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn unix_rlimit(value: u64) -> libc::rlimit {
+    let value = value as libc::rlim_t;
+    libc::rlimit {
+        rlim_cur: value,
+        rlim_max: value,
+    }
+}
+
 #[cfg(unix)]
 async fn terminate_unix_process_tree(
     child: &mut Child,
@@ -204,15 +261,24 @@ struct PlatformProcessTree {
 }
 
 #[cfg(windows)]
-async fn spawn_windows_process_tree(command: &mut Command) -> io::Result<ProcessTreeChild> {
+async fn spawn_windows_process_tree(
+    command: &mut Command,
+    limits: ProcessTreeLimits,
+) -> io::Result<ProcessTreeChild> {
     use windows::Win32::System::Threading::CREATE_SUSPENDED;
 
     const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+    const MIN_WORKING_SET_BYTES: usize = 1024 * 1024;
 
     let job = win32job::Job::create().map_err(job_error)?;
-    let mut limits = win32job::ExtendedLimitInfo::new();
-    limits.limit_kill_on_job_close();
-    job.set_extended_limit_info(&limits).map_err(job_error)?;
+    let mut job_limits = win32job::ExtendedLimitInfo::new();
+    job_limits.limit_kill_on_job_close();
+    if let Some(bytes) = limits.max_memory_bytes {
+        let max = usize::try_from(bytes).unwrap_or(usize::MAX);
+        job_limits.limit_working_memory(MIN_WORKING_SET_BYTES.min(max), max);
+    }
+    job.set_extended_limit_info(&job_limits)
+        .map_err(job_error)?;
 
     command.creation_flags(CREATE_SUSPENDED.0 | CREATE_NO_WINDOW);
     let mut child = command.spawn()?;
@@ -291,6 +357,8 @@ fn job_error(error: impl std::fmt::Display) -> io::Error {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(unix)]
+    use super::ProcessTreeLimits;
     use super::{CleanupOutcome, ProcessTreeChild};
     use std::path::Path;
     use std::process::Stdio;
@@ -452,6 +520,36 @@ mod tests {
         command
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_limits_are_applied_to_the_spawned_process() {
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg("ulimit -t")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+
+        let mut tree = ProcessTreeChild::spawn_with_limits(
+            &mut command,
+            ProcessTreeLimits {
+                max_memory_bytes: None,
+                max_cpu_seconds: Some(7),
+            },
+        )
+        .await
+        .expect("spawn limited process tree");
+        let mut stdout = tree.take_stdout().expect("capture stdout");
+        let mut output = String::new();
+        tokio::io::AsyncReadExt::read_to_string(&mut stdout, &mut output)
+            .await
+            .expect("read ulimit output");
+        tree.wait().await.expect("wait for limited process");
+
+        assert_eq!(output.trim(), "7");
+    }
+
     async fn wait_until_parent_exits(tree: &mut ProcessTreeChild) {
         let deadline = Instant::now() + Duration::from_secs(5);
         loop {
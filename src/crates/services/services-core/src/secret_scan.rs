@@ -0,0 +1,273 @@
+//! Secret detection and redaction for text sent to model providers.
+//!
+//! Built-in rules match well-known credential formats (cloud keys, VCS and
+//! chat tokens, JWTs, PEM private keys, URL credentials) plus values assigned
+//! to secret-looking keys that pass an entropy check. Each secret becomes a
+//! placeholder derived from its hash, so the same secret always maps to the
+//! same placeholder and callers can map placeholders back when the model
+//! repeats them in tool arguments.
+
+use regex::{Captures, Regex};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+const PLACEHOLDER_PREFIX: &str = "<secret:";
+/// Assigned values shorter than this are left alone.
+const MIN_ASSIGNED_SECRET_CHARS: usize = 8;
+/// Bits per character; filler values like `changeme1` or `xxxxxxxx` fall below it.
+const MIN_ASSIGNED_SECRET_ENTROPY: f64 = 3.0;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecretScanOptions {
+    /// Exact values never redacted, e.g. published test keys.
+    pub allowlist: Vec<String>,
+    /// Extra regular expressions. The first capture group is redacted when
+    /// the pattern has one, otherwise the whole match.
+    pub custom_patterns: Vec<String>,
+}
+
+/// One distinct secret found in a text.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretFinding {
+    pub rule: String,
+    pub placeholder: String,
+    pub value: String,
+}
+
+impl fmt::Debug for SecretFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretFinding")
+            .field("rule", &self.rule)
+            .field("placeholder", &self.placeholder)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactedText {
+    pub text: String,
+    /// Distinct secrets, in order of first appearance per rule.
+    pub findings: Vec<SecretFinding>,
+    /// Occurrences replaced, counting repeats of the same secret.
+    pub redaction_count: usize,
+}
+
+struct SecretRule {
+    id: String,
+    regex: Regex,
+    /// Only redact values that look random rather than like code.
+    check_entropy: bool,
+}
+
+pub struct SecretScanner {
+    custom_rules: Vec<SecretRule>,
+    allowlist: HashSet<String>,
+}
+
+impl SecretScanner {
+    /// Fails when a custom pattern is not a valid regular expression.
+    pub fn new(options: &SecretScanOptions) -> Result<Self, String> {
+        let custom_rules = options
+            .custom_patterns
+            .iter()
+            .map(|pattern| pattern.trim())
+            .filter(|pattern| !pattern.is_empty())
+            .enumerate()
+            .map(|(index, pattern)| {
+                Regex::new(pattern)
+                    .map(|regex| SecretRule {
+                        id: format!("custom_{}", index + 1),
+                        regex,
+                        check_entropy: false,
+                    })
+                    .map_err(|e| format!("Invalid secret pattern '{}': {}", pattern, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            custom_rules,
+            allowlist: options
+                .allowlist
+                .iter()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect(),
+        })
+    }
+
+    pub fn redact(&self, text: &str) -> RedactedText {
+        let mut redacted = RedactedText {
+            text: text.to_string(),
+            findings: Vec::new(),
+            redaction_count: 0,
+        };
+        for rule in builtin_rules().iter().chain(&self.custom_rules) {
+            if rule.regex.is_match(&redacted.text) {
+                let text = std::mem::take(&mut redacted.text);
+                redacted.text = self.apply_rule(rule, &text, &mut redacted);
+            }
+        }
+        redacted
+    }
+
+    fn apply_rule(&self, rule: &SecretRule, text: &str, redacted: &mut RedactedText) -> String {
+        rule.regex
+            .replace_all(text, |captures: &Captures<'_>| {
+                let whole = captures.get(0).expect("match has a whole group");
+                let value = captures.get(1).unwrap_or(whole);
+                let secret = value.as_str();
+                if secret.starts_with(PLACEHOLDER_PREFIX)
+                    || self.allowlist.contains(secret)
+                    || (rule.check_entropy && !looks_like_assigned_secret(secret))
+                {
+                    return whole.as_str().to_string();
+                }
+
+                let placeholder = placeholder_for(&rule.id, secret);
+                redacted.redaction_count += 1;
+                if !redacted
+                    .findings
+                    .iter()
+                    .any(|finding| finding.placeholder == placeholder)
+                {
+                    redacted.findings.push(SecretFinding {
+                        rule: rule.id.clone(),
+                        placeholder: placeholder.clone(),
+                        value: secret.to_string(),
+                    });
+                }
+
+                let start = value.start() - whole.start();
+                let end = value.end() - whole.start();
+                format!(
+                    "{}{}{}",
+                    &whole.as_str()[..start],
+                    placeholder,
+                    &whole.as_str()[end..]
+                )
+            })
+            .into_owned()
+    }
+}
+
+/// Placeholder for `value`; stable across calls and processes.
+pub fn placeholder_for(rule: &str, value: &str) -> String {
+    let digest = Sha256::digest(value.as_bytes());
+    let hash = digest[..4]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!("{}{}:{}>", PLACEHOLDER_PREFIX, rule, hash)
+}
+
+/// Whether `text` contains anything that looks like a redaction placeholder.
+pub fn contains_placeholder(text: &str) -> bool {
+    text.contains(PLACEHOLDER_PREFIX)
+}
+
+/// Shannon entropy in bits per character.
+pub fn shannon_entropy(value: &str) -> f64 {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.is_empty() {
+        return 0.0;
+    }
+    let mut counts = std::collections::HashMap::new();
+    for c in &chars {
+        *counts.entry(*c).or_insert(0usize) += 1;
+    }
+    let len = chars.len() as f64;
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Rejects references and identifiers such as `${TOKEN}`, `self.api_key` or
+/// `get_password()` that appear next to secret-named keys in code.
+fn looks_like_assigned_secret(value: &str) -> bool {
+    if value.chars().count() < MIN_ASSIGNED_SECRET_CHARS
+        || value.starts_with(['$', '%', '<', '{', '['])
+        || value.contains(['(', ')', '<', '>', '{', '}'])
+    {
+        return false;
+    }
+    let identifier_like = value
+        .chars()
+        .all(|c| c.is_ascii_alphabetic() || c == '_' || c == '.' || c == '-');
+    !identifier_like && shannon_entropy(value) >= MIN_ASSIGNED_SECRET_ENTROPY
+}
+
+fn builtin_rules() -> &'static [SecretRule] {
+    static RULES: OnceLock<Vec<SecretRule>> = OnceLock::new();
+    RULES.get_or_init(|| {
+        [
+            (
+                "private_key",
+                r"-----BEGIN (?:[A-Z0-9]+ )*PRIVATE KEY(?: BLOCK)?-----[\s\S]*?-----END (?:[A-Z0-9]+ )*PRIVATE KEY(?: BLOCK)?-----",
+                false,
+            ),
+            ("aws_access_key", r"\b((?:AKIA|ASIA)[0-9A-Z]{16})\b", false),
+            (
+                "github_token",
+                r"\b((?:ghp|gho|ghu|ghs|ghr)_[A-Za-z0-9]{36,255}|github_pat_[A-Za-z0-9_]{22,255})\b",
+                false,
+            ),
+            ("anthropic_api_key", r"\b(sk-ant-[A-Za-z0-9_\-]{20,})", false),
+            (
+                "openai_api_key",
+                r"\b(sk-(?:proj-|svcacct-|admin-)?[A-Za-z0-9_\-]{20,})",
+                false,
+            ),
+            ("stripe_key", r"\b((?:sk|rk)_(?:live|test)_[0-9A-Za-z]{16,})", false),
+            ("slack_token", r"\b(xox[abposr]-[A-Za-z0-9-]{10,})", false),
+            ("google_api_key", r"\b(AIza[0-9A-Za-z_\-]{35})", false),
+            (
+                "jwt",
+                r"\b(eyJ[A-Za-z0-9_-]{8,}\.eyJ[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,})",
+                false,
+            ),
+            ("bearer_token", r"(?i)\bBearer\s+([A-Za-z0-9._~+/=-]{16,})", false),
+            (
+                "url_credentials",
+                r"\b[a-zA-Z][a-zA-Z0-9+.-]*://[^\s:/@]+:([^\s@/]{4,})@",
+                false,
+            ),
+            (
+                "assigned_secret",
+                r#"(?i)\b[A-Za-z0-9_.-]*(?:secret|token|passwd|password|api[_-]?key|access[_-]?key|private[_-]?key|credential)[A-Za-z0-9_.-]*(?:\\?["'])?\s*[:=]\s*(?:\\?["'])?([^\s"'`,;\\]+)"#,
+                true,
+            ),
+        ]
+        .into_iter()
+        .map(|(id, pattern, check_entropy)| SecretRule {
+            id: id.to_string(),
+            regex: Regex::new(pattern).expect("built-in secret rule must compile"),
+            check_entropy,
+        })
+        .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entropy_separates_filler_from_random_values() {
+        assert!(shannon_entropy("aaaaaaaa") < 1.0);
+        assert!(shannon_entropy("q8Zr2LmX0vKp9TsW") > 3.5);
+    }
+
+    #[test]
+    fn code_references_are_not_assigned_secrets() {
+        assert!(!looks_like_assigned_secret("self.api_key"));
+        assert!(!looks_like_assigned_secret("${API_TOKEN}"));
+        assert!(!looks_like_assigned_secret("get_password()"));
+        assert!(looks_like_assigned_secret("hunter22-Qx9"));
+    }
+}
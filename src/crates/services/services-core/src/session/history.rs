@@ -0,0 +1,354 @@
+//! Session history search, export rendering, and retention planning.
+//!
+//! Pure functions over persisted metadata and turns; the persistence manager
+//! owns loading and applying the results.
+
+use super::types::{DialogTurnData, SessionMetadata, SessionStatus};
+use bitfun_core_types::SessionKind;
+use serde::{Deserialize, Serialize};
+
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionSearchField {
+    Title,
+    UserMessage,
+    AssistantText,
+    ToolCall,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSearchHit {
+    pub session_id: String,
+    pub session_name: String,
+    /// `None` for title matches.
+    pub turn_index: Option<usize>,
+    pub field: SessionSearchField,
+    /// Matched text with some surrounding context.
+    pub snippet: String,
+    pub last_active_at: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionExportFormat {
+    Markdown,
+    Json,
+}
+
+impl SessionExportFormat {
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// Automatic clean-up of old standard sessions. Both steps are off by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionRetentionPolicy {
+    /// Archive active sessions with no activity for this many days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_inactive_after_days: Option<u32>,
+    /// Permanently delete archived sessions idle for this many days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_archived_after_days: Option<u32>,
+}
+
+impl SessionRetentionPolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.archive_inactive_after_days.is_some() || self.delete_archived_after_days.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRetentionPlan {
+    pub archive: Vec<String>,
+    pub delete: Vec<String>,
+}
+
+/// Finds up to `max_hits` case-insensitive matches of `query` in a session's
+/// title and turns. At most one hit is reported per turn and field.
+pub fn search_session_history(
+    metadata: &SessionMetadata,
+    turns: &[DialogTurnData],
+    query: &str,
+    max_hits: usize,
+) -> Vec<SessionSearchHit> {
+    let needle = query.trim().to_lowercase();
+    let mut hits = Vec::new();
+    if needle.is_empty() || max_hits == 0 {
+        return hits;
+    }
+
+    let mut push = |turn_index: Option<usize>, field: SessionSearchField, text: &str| {
+        if hits.len() >= max_hits {
+            return false;
+        }
+        if let Some(snippet) = match_snippet(text, &needle) {
+            hits.push(SessionSearchHit {
+                session_id: metadata.session_id.clone(),
+                session_name: metadata.session_name.clone(),
+                turn_index,
+                field,
+                snippet,
+                last_active_at: metadata.last_active_at,
+            });
+        }
+        hits.len() < max_hits
+    };
+
+    if !push(None, SessionSearchField::Title, &metadata.session_name) {
+        return hits;
+    }
+    for turn in turns {
+        let index = Some(turn.turn_index);
+        if !push(
+            index,
+            SessionSearchField::UserMessage,
+            &turn.user_message.content,
+        ) {
+            break;
+        }
+        let assistant_text = turn
+            .model_rounds
+            .iter()
+            .flat_map(|round| round.text_items.iter())
+            .map(|item| item.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !push(index, SessionSearchField::AssistantText, &assistant_text) {
+            break;
+        }
+        let tool_text = turn
+            .model_rounds
+            .iter()
+            .flat_map(|round| round.tool_items.iter())
+            .map(|item| format!("{} {}", item.tool_name, item.tool_call.input))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !push(index, SessionSearchField::ToolCall, &tool_text) {
+            break;
+        }
+    }
+    hits
+}
+
+fn match_snippet(text: &str, needle: &str) -> Option<String> {
+    let lowered = text.to_lowercase();
+    let byte_index = lowered.find(needle)?;
+    // Lowercasing can change byte lengths, so map back through char counts.
+    let match_start = lowered[..byte_index].chars().count();
+    let match_len = needle.chars().count();
+
+    let chars: Vec<char> = text.chars().collect();
+    let start = match_start.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let end = (match_start + match_len + SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let body: String = chars[start.min(chars.len())..end]
+        .iter()
+        .map(|ch| if ch.is_whitespace() { ' ' } else { *ch })
+        .collect();
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < chars.len() { "…" } else { "" };
+    Some(format!("{}{}{}", prefix, body.trim(), suffix))
+}
+
+/// Renders a session for export. JSON keeps the persisted structures as-is;
+/// Markdown is a readable transcript with collapsed tool calls.
+pub fn render_session_export(
+    metadata: &SessionMetadata,
+    turns: &[DialogTurnData],
+    format: SessionExportFormat,
+) -> serde_json::Result<String> {
+    match format {
+        SessionExportFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+            "metadata": metadata,
+            "turns": turns,
+        })),
+        SessionExportFormat::Markdown => Ok(render_markdown(metadata, turns)),
+    }
+}
+
+fn render_markdown(metadata: &SessionMetadata, turns: &[DialogTurnData]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("# {}\n\n", metadata.session_name));
+    out.push_str(&format!(
+        "- Session: `{}`\n- Agent: {}\n- Model: {}\n- Turns: {}\n\n",
+        metadata.session_id, metadata.agent_type, metadata.model_name, metadata.turn_count
+    ));
+
+    for turn in turns {
+        out.push_str(&format!("## Turn {}\n\n", turn.turn_index + 1));
+        out.push_str("### User\n\n");
+        out.push_str(turn.user_message.content.trim());
+        out.push_str("\n\n");
+
+        let mut wrote_assistant_header = false;
+        for round in &turn.model_rounds {
+            for item in &round.text_items {
+                let text = item.content.trim();
+                if text.is_empty() {
+                    continue;
+                }
+                if !wrote_assistant_header {
+                    out.push_str("### Assistant\n\n");
+                    wrote_assistant_header = true;
+                }
+                out.push_str(text);
+                out.push_str("\n\n");
+            }
+            for tool in &round.tool_items {
+                let status = match &tool.tool_result {
+                    Some(result) if result.success => "ok",
+                    Some(_) => "failed",
+                    None => "no result",
+                };
+                let input = serde_json::to_string_pretty(&tool.tool_call.input)
+                    .unwrap_or_else(|_| tool.tool_call.input.to_string());
+                out.push_str(&format!(
+                    "<details>\n<summary>Tool: {} ({})</summary>\n\n```json\n{}\n```\n\n</details>\n\n",
+                    tool.tool_name, status, input
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Decides which sessions a retention policy archives or deletes at
+/// `now_ms`. Only standard sessions are considered; child sessions follow
+/// their parents.
+pub fn plan_session_retention(
+    sessions: &[SessionMetadata],
+    policy: &SessionRetentionPolicy,
+    now_ms: u64,
+) -> SessionRetentionPlan {
+    let idle_longer_than = |session: &SessionMetadata, days: Option<u32>| {
+        days.is_some_and(|days| {
+            now_ms.saturating_sub(session.last_active_at) > u64::from(days) * DAY_MS
+        })
+    };
+
+    let mut plan = SessionRetentionPlan::default();
+    for session in sessions
+        .iter()
+        .filter(|session| session.session_kind == SessionKind::Standard)
+    {
+        match session.status {
+            SessionStatus::Archived => {
+                if idle_longer_than(session, policy.delete_archived_after_days) {
+                    plan.delete.push(session.session_id.clone());
+                }
+            }
+            SessionStatus::Active | SessionStatus::Completed => {
+                if idle_longer_than(session, policy.archive_inactive_after_days) {
+                    plan.archive.push(session.session_id.clone());
+                }
+            }
+        }
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::types::UserMessageData;
+
+    fn metadata(
+        id: &str,
+        name: &str,
+        status: SessionStatus,
+        last_active_at: u64,
+    ) -> SessionMetadata {
+        let mut metadata = SessionMetadata::new(
+            id.to_string(),
+            name.to_string(),
+            "agentic".to_string(),
+            "model".to_string(),
+        );
+        metadata.status = status;
+        metadata.last_active_at = last_active_at;
+        metadata
+    }
+
+    fn turn(index: usize, user: &str) -> DialogTurnData {
+        DialogTurnData::new(
+            format!("turn-{index}"),
+            index,
+            "s1".to_string(),
+            UserMessageData {
+                id: format!("user-{index}"),
+                content: user.to_string(),
+                timestamp: 0,
+                metadata: None,
+            },
+        )
+    }
+
+    #[test]
+    fn search_matches_titles_and_turns_case_insensitively() {
+        let meta = metadata("s1", "Fix Login Bug", SessionStatus::Active, 0);
+        let turns = vec![turn(0, "why does login fail?"), turn(1, "thanks")];
+
+        let hits = search_session_history(&meta, &turns, "LOGIN", 10);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].field, SessionSearchField::Title);
+        assert_eq!(hits[1].turn_index, Some(0));
+        assert_eq!(hits[1].snippet, "why does login fail?");
+        assert_eq!(search_session_history(&meta, &turns, "LOGIN", 1).len(), 1);
+    }
+
+    #[test]
+    fn snippets_are_trimmed_around_the_match() {
+        let text = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+
+        let snippet = match_snippet(&text, "needle").unwrap();
+
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("needle"));
+        assert_eq!(snippet.chars().count(), SNIPPET_CONTEXT_CHARS * 2 + 6 + 2);
+    }
+
+    #[test]
+    fn retention_archives_idle_sessions_and_deletes_old_archives() {
+        let now = 100 * DAY_MS;
+        let sessions = vec![
+            metadata("recent", "a", SessionStatus::Active, now - DAY_MS),
+            metadata("idle", "b", SessionStatus::Active, now - 40 * DAY_MS),
+            metadata(
+                "old-archive",
+                "c",
+                SessionStatus::Archived,
+                now - 90 * DAY_MS,
+            ),
+            metadata(
+                "new-archive",
+                "d",
+                SessionStatus::Archived,
+                now - 40 * DAY_MS,
+            ),
+        ];
+        let policy = SessionRetentionPolicy {
+            archive_inactive_after_days: Some(30),
+            delete_archived_after_days: Some(60),
+        };
+
+        let plan = plan_session_retention(&sessions, &policy, now);
+
+        assert_eq!(plan.archive, vec!["idle".to_string()]);
+        assert_eq!(plan.delete, vec!["old-archive".to_string()]);
+        assert_eq!(
+            plan_session_retention(&sessions, &SessionRetentionPolicy::default(), now),
+            SessionRetentionPlan::default()
+        );
+    }
+}
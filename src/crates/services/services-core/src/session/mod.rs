@@ -1,3 +1,4 @@
+mod history;
 pub mod layout;
 mod lineage;
 mod memory_workspace;
@@ -5,9 +6,14 @@ mod metadata;
 mod metadata_store;
 mod migration;
 pub mod page;
+mod report;
 pub mod types;
 
 pub use bitfun_core_types::SessionKind;
+pub use history::{
+    plan_session_retention, render_session_export, search_session_history, SessionExportFormat,
+    SessionRetentionPlan, SessionRetentionPolicy, SessionSearchField, SessionSearchHit,
+};
 pub use layout::SessionStorageLayout;
 pub use lineage::{
     apply_session_lineage, build_branched_session_metadata, collect_hidden_subagent_cascade,
@@ -32,4 +38,8 @@ pub use migration::{
     SessionStoreMigrationRecord,
 };
 pub use page::{build_session_metadata_page, empty_session_metadata_page, SessionMetadataPage};
+pub use report::{
+    render_session_report, render_session_report_html, RenderedSessionReport,
+    SessionReportArtifact, SessionReportFormat, SessionReportOptions, SessionReportTemplate,
+};
 pub use types::*;
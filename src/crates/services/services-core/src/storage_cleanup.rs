@@ -1,3 +1,4 @@
+use crate::format::LocaleFormatter;
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -79,10 +80,10 @@ impl CleanupService {
         }
 
         info!(
-            "Cleanup completed: {} files, {} dirs, {:.2} MB freed",
+            "Cleanup completed: {} files, {} dirs, {} freed",
             result.files_deleted,
             result.directories_deleted,
-            result.bytes_freed as f64 / 1_048_576.0
+            LocaleFormatter::default().bytes(result.bytes_freed)
         );
 
         Ok(result)
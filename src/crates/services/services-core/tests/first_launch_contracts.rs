@@ -0,0 +1,35 @@
+use bitfun_services_core::first_launch::{FirstLaunchHandoff, FIRST_LAUNCH_HANDOFF_SCHEMA_VERSION};
+
+#[test]
+fn handoff_round_trips_with_camel_case_fields() {
+    let handoff = FirstLaunchHandoff {
+        schema_version: FIRST_LAUNCH_HANDOFF_SCHEMA_VERSION,
+        installer_version: "0.2.13".to_string(),
+        installed_at_ms: Some(1_780_000_000_000),
+        install_path: "C:\\Program Files\\BitFun".to_string(),
+        components: vec!["desktopShortcut".to_string()],
+        app_language: Some("zh-CN".to_string()),
+        theme_preference: None,
+        model_configured: true,
+    };
+
+    let json = serde_json::to_value(&handoff).expect("serialize handoff");
+    assert_eq!(json["installerVersion"], "0.2.13");
+    assert_eq!(json["installPath"], "C:\\Program Files\\BitFun");
+    assert_eq!(json["modelConfigured"], true);
+
+    let parsed: FirstLaunchHandoff = serde_json::from_value(json).expect("parse handoff");
+    assert_eq!(parsed, handoff);
+}
+
+#[test]
+fn handoff_from_older_or_newer_installers_still_parses() {
+    let parsed: FirstLaunchHandoff = serde_json::from_str(
+        r#"{"schemaVersion":2,"installerVersion":"9.0.0","installPath":"/opt/bitfun","futureField":1}"#,
+    )
+    .expect("parse handoff with missing and unknown fields");
+
+    assert_eq!(parsed.schema_version, 2);
+    assert!(parsed.components.is_empty());
+    assert!(!parsed.model_configured);
+}
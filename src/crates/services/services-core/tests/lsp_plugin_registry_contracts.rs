@@ -102,6 +102,32 @@ fn registry_unregister_preserves_indexes_owned_by_newer_plugin() {
     assert_eq!(registry.find_by_extension("rs").unwrap().id, "current-rust");
 }
 
+#[test]
+fn registry_unregister_restores_indexes_of_shadowed_plugin() {
+    let mut registry = PluginRegistry::new();
+    registry
+        .register(plugin("rust-analyzer", &["rust"], &[".rs"]))
+        .expect("installed plugin should register");
+    registry
+        .register(plugin("config:rust", &["rust"], &[".rs"]))
+        .expect("override plugin should register");
+
+    assert_eq!(registry.find_by_language("rust").unwrap().id, "config:rust");
+
+    registry
+        .unregister("config:rust")
+        .expect("override plugin should unregister");
+
+    assert_eq!(
+        registry.find_by_language("rust").unwrap().id,
+        "rust-analyzer"
+    );
+    assert_eq!(
+        registry.find_by_extension("rs").unwrap().id,
+        "rust-analyzer"
+    );
+}
+
 #[test]
 fn registry_duplicate_and_missing_errors_keep_legacy_messages() {
     let mut registry = PluginRegistry::new();
@@ -0,0 +1,179 @@
+//! Sandboxed headless Chromium processes for agent browser automation.
+//!
+//! Each process gets its own loopback-only debug port and throwaway profile
+//! directory, and is killed when its handle is dropped. Choosing which
+//! executable to run and which session owns a process stays in product
+//! assembly.
+
+use super::launcher::BrowserLauncher;
+use anyhow::{anyhow, Result};
+use bitfun_services_core::process_manager;
+use log::{debug, info, warn};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Child, Stdio};
+use std::time::{Duration, Instant};
+
+const STARTUP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone)]
+pub struct HeadlessLaunchOptions {
+    pub executable: PathBuf,
+    pub port: u16,
+    /// Profile directory; created if missing and deleted with the process.
+    pub user_data_dir: PathBuf,
+    pub window_size: (u32, u32),
+    pub startup_timeout: Duration,
+}
+
+/// A running headless browser. Dropping it kills the process and removes its
+/// profile directory.
+pub struct HeadlessBrowserProcess {
+    child: Child,
+    port: u16,
+    user_data_dir: PathBuf,
+}
+
+impl HeadlessBrowserProcess {
+    /// Starts the browser and waits until its CDP endpoint answers.
+    pub async fn launch(options: HeadlessLaunchOptions) -> Result<Self> {
+        std::fs::create_dir_all(&options.user_data_dir).map_err(|e| {
+            anyhow!(
+                "Failed to create headless browser profile {}: {}",
+                options.user_data_dir.display(),
+                e
+            )
+        })?;
+
+        info!(
+            "Launching headless browser: executable={}, port={}, user_data_dir={}",
+            options.executable.display(),
+            options.port,
+            options.user_data_dir.display()
+        );
+        let child = process_manager::create_command(&options.executable)
+            .args(headless_args(&options))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to start headless browser {}: {}",
+                    options.executable.display(),
+                    e
+                )
+            })?;
+        let mut process = Self {
+            child,
+            port: options.port,
+            user_data_dir: options.user_data_dir,
+        };
+
+        let deadline = Instant::now() + options.startup_timeout;
+        loop {
+            if BrowserLauncher::is_cdp_available(process.port).await {
+                return Ok(process);
+            }
+            if let Some(status) = process.child.try_wait()? {
+                return Err(anyhow!(
+                    "Headless browser exited during startup with {}",
+                    status
+                ));
+            }
+            if Instant::now() >= deadline {
+                return Err(anyhow!(
+                    "Headless browser did not open CDP on port {} within {} seconds",
+                    process.port,
+                    options.startup_timeout.as_secs()
+                ));
+            }
+            tokio::time::sleep(STARTUP_POLL_INTERVAL).await;
+        }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    pub fn is_running(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+}
+
+impl Drop for HeadlessBrowserProcess {
+    fn drop(&mut self) {
+        if let Err(e) = self.child.kill() {
+            debug!(
+                "Headless browser already stopped: port={}, {}",
+                self.port, e
+            );
+        }
+        let _ = self.child.wait();
+        if let Err(e) = std::fs::remove_dir_all(&self.user_data_dir) {
+            warn!(
+                "Failed to remove headless browser profile {}: {}",
+                self.user_data_dir.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Asks the OS for a free loopback port for a new debug endpoint.
+pub fn pick_free_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .map_err(|e| anyhow!("Failed to reserve a browser debug port: {}", e))?;
+    Ok(listener.local_addr()?.port())
+}
+
+/// Flags for an isolated, quiet browser. Chromium's own sandbox stays on;
+/// everything that would sync, update or phone home is off.
+fn headless_args(options: &HeadlessLaunchOptions) -> Vec<String> {
+    let (width, height) = options.window_size;
+    vec![
+        "--headless=new".to_string(),
+        "--remote-debugging-address=127.0.0.1".to_string(),
+        format!("--remote-debugging-port={}", options.port),
+        format!("--user-data-dir={}", options.user_data_dir.display()),
+        format!("--window-size={},{}", width, height),
+        "--no-first-run".to_string(),
+        "--no-default-browser-check".to_string(),
+        "--disable-extensions".to_string(),
+        "--disable-sync".to_string(),
+        "--disable-background-networking".to_string(),
+        "--disable-component-update".to_string(),
+        "--disable-default-apps".to_string(),
+        "--password-store=basic".to_string(),
+        "--use-mock-keychain".to_string(),
+        "--mute-audio".to_string(),
+        "about:blank".to_string(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headless_args_bind_loopback_port_and_private_profile() {
+        let options = HeadlessLaunchOptions {
+            executable: PathBuf::from("chromium"),
+            port: 9333,
+            user_data_dir: PathBuf::from("/tmp/bitfun-headless/session-a"),
+            window_size: (1280, 800),
+            startup_timeout: Duration::from_secs(10),
+        };
+
+        let args = headless_args(&options);
+
+        assert!(args.contains(&"--remote-debugging-address=127.0.0.1".to_string()));
+        assert!(args.contains(&"--remote-debugging-port=9333".to_string()));
+        assert!(args.contains(&"--user-data-dir=/tmp/bitfun-headless/session-a".to_string()));
+        assert!(!args.iter().any(|arg| arg == "--no-sandbox"));
+    }
+}
@@ -1,14 +1,16 @@
 //! Browser control integration services.
 //!
-//! This module owns platform browser detection, CDP endpoint HTTP handling, and
-//! CDP launch process handling.
+//! This module owns platform browser detection, CDP endpoint HTTP handling,
+//! CDP launch process handling, and sandboxed headless browser processes.
 //! Product policy, tool routing, and UI commands stay in product assembly and
 //! app entrypoints.
 
 pub mod cdp;
+pub mod headless;
 pub mod launcher;
 
 pub use cdp::{CdpEndpointProvider, CdpPageInfo, CdpVersionInfo};
+pub use headless::{pick_free_port, HeadlessBrowserProcess, HeadlessLaunchOptions};
 pub use launcher::{
     BrowserKind, BrowserLaunchOptions, BrowserLauncher, LaunchResult, DEFAULT_CDP_PORT,
 };
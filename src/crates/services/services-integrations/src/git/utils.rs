@@ -7,8 +7,9 @@ pub use super::{
 use super::{GitCommandOutput, GitError, GitFileStatus};
 use bitfun_services_core::process_manager;
 use git2::{Repository, Status, StatusOptions};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::RwLock;
 use std::time::Duration;
 use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio::time::timeout;
@@ -16,6 +17,59 @@ use tokio::time::timeout;
 const REVIEW_GIT_TIMEOUT: Duration = Duration::from_secs(30);
 const REVIEW_GIT_OUTPUT_LIMIT: usize = 8 * 1024 * 1024;
 
+/// Program and extra environment for Git processes started by BitFun.
+#[derive(Debug, Clone, Default)]
+pub struct GitProcessConfig {
+    /// Resolved Git executable, such as a managed portable Git. `git` from
+    /// `PATH` when unset.
+    pub program: Option<PathBuf>,
+    /// Extra environment for commands that may talk to a remote, such as
+    /// managed Git config defaults and the credential prompt bridge.
+    pub env: Vec<(String, String)>,
+}
+
+static GIT_PROCESS_CONFIG: RwLock<Option<GitProcessConfig>> = RwLock::new(None);
+
+/// Sets the program and environment used by the Git command helpers in this
+/// module. Hosts call this once the managed runtimes are known.
+pub fn configure_git_processes(config: GitProcessConfig) {
+    match GIT_PROCESS_CONFIG.write() {
+        Ok(mut guard) => *guard = Some(config),
+        Err(poisoned) => *poisoned.into_inner() = Some(config),
+    }
+}
+
+fn git_process_config() -> GitProcessConfig {
+    match GIT_PROCESS_CONFIG.read() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+    .unwrap_or_default()
+}
+
+fn git_program(config: &GitProcessConfig) -> PathBuf {
+    config
+        .program
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("git"))
+}
+
+/// Git command with the configured program and environment.
+fn git_tokio_command() -> tokio::process::Command {
+    let config = git_process_config();
+    let mut command = process_manager::create_tokio_command(git_program(&config));
+    command.envs(config.env);
+    command
+}
+
+/// Blocking variant of [`git_tokio_command`].
+fn git_std_command() -> std::process::Command {
+    let config = git_process_config();
+    let mut command = process_manager::create_command(git_program(&config));
+    command.envs(config.env);
+    command
+}
+
 async fn read_bounded_review_git_stream<R>(reader: R) -> Result<Vec<u8>, GitError>
 where
     R: AsyncRead + Unpin,
@@ -254,7 +308,7 @@ pub async fn execute_git_command_raw(
     repo_path: &str,
     args: &[&str],
 ) -> Result<GitCommandOutput, GitError> {
-    let output = process_manager::create_tokio_command("git")
+    let output = git_tokio_command()
         .current_dir(repo_path)
         .args(args)
         .output()
@@ -295,7 +349,9 @@ pub async fn execute_git_readonly_command(
     repo_path: &str,
     args: &[&str],
 ) -> Result<String, GitError> {
-    let mut command = process_manager::create_tokio_command("git");
+    // Inspections never prompt, so the configured environment (which may
+    // carry the credential prompt bridge) is not applied.
+    let mut command = process_manager::create_tokio_command(git_program(&git_process_config()));
     command
         .current_dir(repo_path)
         .env("GIT_OPTIONAL_LOCKS", "0")
@@ -363,7 +419,7 @@ pub fn execute_git_command_sync_raw(
     repo_path: &str,
     args: &[&str],
 ) -> Result<GitCommandOutput, GitError> {
-    let output = process_manager::create_command("git")
+    let output = git_std_command()
         .current_dir(repo_path)
         .args(args)
         .output()
@@ -404,7 +460,7 @@ pub fn format_timestamp(timestamp: i64) -> String {
 
 /// Checks whether Git is available.
 pub fn check_git_available() -> bool {
-    process_manager::create_command("git")
+    git_std_command()
         .arg("--version")
         .output()
         .map(|output| output.status.success())
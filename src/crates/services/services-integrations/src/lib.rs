@@ -56,6 +56,9 @@ pub mod review_platform;
 #[cfg(feature = "review-platform")]
 pub(crate) mod review_platform_http;
 
+#[cfg(feature = "review-platform-keychain")]
+pub mod review_platform_keychain;
+
 #[cfg(feature = "script-tool-runtime")]
 pub mod script_tool;
 #[cfg(feature = "speech")]
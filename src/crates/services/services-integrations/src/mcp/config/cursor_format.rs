@@ -75,6 +75,18 @@ pub fn config_to_cursor_format(config: &MCPServerConfig) -> serde_json::Value {
         cursor_config.insert("env".to_string(), serde_json::json!(config.env));
     }
 
+    if let Some(working_directory) = &config.working_directory {
+        cursor_config.insert("cwd".to_string(), serde_json::json!(working_directory));
+    }
+
+    if let Some(isolation) = &config.isolation {
+        cursor_config.insert("isolation".to_string(), serde_json::json!(isolation));
+    }
+
+    if let Some(framing) = &config.framing {
+        cursor_config.insert("framing".to_string(), serde_json::json!(framing));
+    }
+
     if !config.headers.is_empty() {
         cursor_config.insert("headers".to_string(), serde_json::json!(config.headers));
     }
@@ -83,6 +95,10 @@ pub fn config_to_cursor_format(config: &MCPServerConfig) -> serde_json::Value {
         cursor_config.insert("url".to_string(), serde_json::json!(url));
     }
 
+    if let Some(tls) = &config.tls {
+        cursor_config.insert("tls".to_string(), serde_json::json!(tls));
+    }
+
     if let Some(oauth) = &config.oauth {
         cursor_config.insert("oauth".to_string(), serde_json::json!(oauth));
     }
@@ -137,11 +153,56 @@ pub fn parse_cursor_format(config: &serde_json::Value) -> Vec<MCPServerConfig> {
                     })
                     .unwrap_or_default();
 
+                let working_directory = obj
+                    .get("cwd")
+                    .or_else(|| obj.get("workingDirectory"))
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
+
+                let isolation = match obj.get("isolation") {
+                    Some(value) => match serde_json::from_value(value.clone()) {
+                        Ok(isolation) => Some(isolation),
+                        Err(error) => {
+                            warn!(
+                                "Invalid MCP isolation config for server '{}': {}",
+                                server_id, error
+                            );
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+
+                let framing = match obj.get("framing") {
+                    Some(value) => match serde_json::from_value(value.clone()) {
+                        Ok(framing) => Some(framing),
+                        Err(error) => {
+                            warn!("Invalid MCP framing for server '{}': {}", server_id, error);
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+
                 let url = obj
                     .get("url")
                     .and_then(|v| v.as_str())
                     .map(|s| s.to_string());
 
+                let tls = match obj.get("tls") {
+                    Some(value) => match serde_json::from_value(value.clone()) {
+                        Ok(tls) => Some(tls),
+                        Err(error) => {
+                            warn!(
+                                "Invalid MCP tls config for server '{}': {}",
+                                server_id, error
+                            );
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+
                 let explicit_source_value = obj.get("source").and_then(|v| v.as_str());
                 let explicit_source = match explicit_source_value {
                     Some(value) => match parse_source(value) {
@@ -220,10 +281,13 @@ pub fn parse_cursor_format(config: &serde_json::Value) -> Vec<MCPServerConfig> {
                     command,
                     args,
                     env,
-                    working_directory: None,
+                    working_directory,
                     inherit_parent_environment: None,
+                    isolation,
+                    framing,
                     headers,
                     url,
+                    tls,
                     auto_start,
                     enabled,
                     location: ConfigLocation::User,
@@ -1,5 +1,7 @@
 //! MCP JSON config validation and formatting helpers.
 
+use crate::mcp::protocol::StdioFraming;
+use crate::mcp::server::{MCPServerIsolationConfig, MCPServerTlsConfig};
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -224,6 +226,24 @@ pub fn validate_mcp_json_config(
             )));
         }
 
+        if effective_transport != "stdio"
+            && ["cwd", "isolation", "framing"]
+                .iter()
+                .any(|key| obj.contains_key(*key))
+        {
+            return Err(MCPJsonConfigValidationError::new(format!(
+                "Server '{}' ({}) cannot set 'cwd', 'isolation' or 'framing'; they apply to local processes only",
+                server_id, effective_transport
+            )));
+        }
+
+        if effective_transport == "stdio" && obj.contains_key("tls") {
+            return Err(MCPJsonConfigValidationError::new(format!(
+                "Server '{}' (stdio) cannot set 'tls'; it applies to remote servers only",
+                server_id
+            )));
+        }
+
         if matches!(effective_transport, "streamable-http" | "sse") && url.is_none() {
             return Err(MCPJsonConfigValidationError::new(format!(
                 "Server '{}' ({}) must provide 'url' field",
@@ -235,7 +255,9 @@ pub fn validate_mcp_json_config(
             ("args", "array"),
             ("env", "object"),
             ("headers", "object"),
+            ("isolation", "object"),
             ("oauth", "object"),
+            ("tls", "object"),
             ("xaa", "object"),
         ] {
             if let Some(value) = obj.get(key) {
@@ -252,6 +274,50 @@ pub fn validate_mcp_json_config(
                 }
             }
         }
+
+        if let Some(value) = obj.get("isolation") {
+            let isolation: MCPServerIsolationConfig = serde_json::from_value(value.clone())
+                .map_err(|error| {
+                    MCPJsonConfigValidationError::new(format!(
+                        "Server '{}' 'isolation' field is invalid: {}",
+                        server_id, error
+                    ))
+                })?;
+            isolation
+                .validate(server_id)
+                .map_err(|error| MCPJsonConfigValidationError::new(error.to_string()))?;
+        }
+
+        if let Some(value) = obj.get("framing") {
+            serde_json::from_value::<StdioFraming>(value.clone()).map_err(|_| {
+                MCPJsonConfigValidationError::new(format!(
+                    "Server '{}' 'framing' field must be 'newline' or 'content-length'",
+                    server_id
+                ))
+            })?;
+        }
+
+        if let Some(value) = obj.get("tls") {
+            let tls: MCPServerTlsConfig =
+                serde_json::from_value(value.clone()).map_err(|error| {
+                    MCPJsonConfigValidationError::new(format!(
+                        "Server '{}' 'tls' field is invalid: {}",
+                        server_id, error
+                    ))
+                })?;
+            if !url.is_some_and(|url| {
+                url.trim_start()
+                    .to_ascii_lowercase()
+                    .starts_with("https://")
+            }) {
+                return Err(MCPJsonConfigValidationError::new(format!(
+                    "Server '{}' can only set 'tls' for an https:// url",
+                    server_id
+                )));
+            }
+            tls.validate(server_id)
+                .map_err(|error| MCPJsonConfigValidationError::new(error.to_string()))?;
+        }
     }
 
     Ok(())
@@ -3,6 +3,7 @@
 mod cursor_format;
 mod json_config;
 mod location;
+mod presets;
 mod service;
 mod service_helpers;
 
@@ -11,6 +12,10 @@ pub use json_config::{
     format_mcp_json_config_value, validate_mcp_json_config, MCPJsonConfigValidationError,
 };
 pub use location::ConfigLocation;
+pub use presets::{
+    insert_mcp_server_into_json_config, render_mcp_server_preset, MCPPresetFieldError,
+    MCPPresetParameter, MCPPresetParameterKind, MCPServerPreset, MCPServerPresetCatalog,
+};
 pub use service::{MCPConfigService, MCPConfigStore};
 pub use service_helpers::{
     get_mcp_remote_authorization_source, get_mcp_remote_authorization_value,
@@ -0,0 +1,248 @@
+//! Built-in MCP server presets.
+//!
+//! Presets are parameterized `mcp.json` entries for common servers. Values the
+//! user fills in are substituted into `${key}` placeholders in the command
+//! arguments, environment, URL and headers. The bundled catalog can be
+//! extended or updated by a catalog file of the same shape; entries with the
+//! same id replace bundled ones.
+
+use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+const BUILTIN_PRESET_CATALOG: &str = include_str!("presets.json");
+
+/// What kind of value a preset parameter takes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MCPPresetParameterKind {
+    #[default]
+    Text,
+    /// Token or credential; the form should mask it.
+    Secret,
+    /// Absolute filesystem path.
+    Path,
+    /// `http://` or `https://` URL.
+    Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPPresetParameter {
+    pub key: String,
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub kind: MCPPresetParameterKind,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+}
+
+/// A parameterized MCP server entry. Local presets set `command`; remote
+/// presets set `url`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerPreset {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub parameters: Vec<MCPPresetParameter>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerPresetCatalog {
+    pub version: u32,
+    pub presets: Vec<MCPServerPreset>,
+}
+
+impl MCPServerPresetCatalog {
+    pub fn builtin() -> Self {
+        serde_json::from_str(BUILTIN_PRESET_CATALOG).expect("bundled MCP preset catalog is valid")
+    }
+
+    pub fn parse(body: &str) -> MCPRuntimeResult<Self> {
+        serde_json::from_str(body).map_err(|error| {
+            MCPRuntimeError::deserialization(format!(
+                "Failed to parse MCP preset catalog: {}",
+                error
+            ))
+        })
+    }
+
+    /// Applies an update catalog: presets with a known id are replaced in
+    /// place, new presets are appended.
+    pub fn merge(mut self, update: MCPServerPresetCatalog) -> Self {
+        self.version = self.version.max(update.version);
+        for preset in update.presets {
+            match self.presets.iter_mut().find(|known| known.id == preset.id) {
+                Some(known) => *known = preset,
+                None => self.presets.push(preset),
+            }
+        }
+        self
+    }
+
+    pub fn get(&self, preset_id: &str) -> Option<&MCPServerPreset> {
+        self.presets.iter().find(|preset| preset.id == preset_id)
+    }
+}
+
+/// A problem with one install form field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPPresetFieldError {
+    pub key: String,
+    pub message: String,
+}
+
+/// Renders a preset into a Cursor-format server entry.
+///
+/// Empty optional parameters drop the argument, environment variable or
+/// header that references them.
+pub fn render_mcp_server_preset(
+    preset: &MCPServerPreset,
+    values: &HashMap<String, String>,
+) -> Result<Value, Vec<MCPPresetFieldError>> {
+    let mut resolved = HashMap::new();
+    let mut errors = Vec::new();
+    for parameter in &preset.parameters {
+        let value = values
+            .get(&parameter.key)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .or_else(|| parameter.default.clone())
+            .unwrap_or_default();
+        if let Some(message) = validate_parameter_value(parameter, &value) {
+            errors.push(MCPPresetFieldError {
+                key: parameter.key.clone(),
+                message,
+            });
+        }
+        resolved.insert(parameter.key.as_str(), value);
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut server = Map::new();
+    server.insert("name".to_string(), json!(preset.name));
+    if let Some(command) = &preset.command {
+        server.insert("command".to_string(), json!(command));
+        let args = preset
+            .args
+            .iter()
+            .filter_map(|arg| substitute(arg, &resolved))
+            .collect::<Vec<_>>();
+        if !args.is_empty() {
+            server.insert("args".to_string(), json!(args));
+        }
+    }
+    if let Some(url) = &preset.url {
+        server.insert("type".to_string(), json!("streamable-http"));
+        server.insert("url".to_string(), json!(substitute(url, &resolved)));
+    }
+    for (field, entries) in [("env", &preset.env), ("headers", &preset.headers)] {
+        let entries = entries
+            .iter()
+            .filter_map(|(key, value)| Some((key.clone(), substitute(value, &resolved)?)))
+            .collect::<BTreeMap<_, _>>();
+        if !entries.is_empty() {
+            server.insert(field.to_string(), json!(entries));
+        }
+    }
+    Ok(Value::Object(server))
+}
+
+fn validate_parameter_value(parameter: &MCPPresetParameter, value: &str) -> Option<String> {
+    if value.is_empty() {
+        return parameter
+            .required
+            .then(|| format!("{} is required", parameter.label));
+    }
+    match parameter.kind {
+        MCPPresetParameterKind::Path if !Path::new(value).is_absolute() => {
+            Some(format!("{} must be an absolute path", parameter.label))
+        }
+        MCPPresetParameterKind::Url
+            if !(value.starts_with("http://") || value.starts_with("https://")) =>
+        {
+            Some(format!(
+                "{} must start with http:// or https://",
+                parameter.label
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Replaces `${key}` placeholders; returns `None` when a referenced value is
+/// empty so the caller can drop the entry.
+fn substitute(template: &str, values: &HashMap<&str, String>) -> Option<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        let key = &rest[start + 2..start + end];
+        let value = values.get(key)?;
+        if value.is_empty() {
+            return None;
+        }
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(value);
+        rest = &rest[start + end + 1..];
+    }
+    rendered.push_str(rest);
+    Some(rendered)
+}
+
+/// Adds a server entry to an `mcp.json` document and returns the id it was
+/// stored under. Existing servers are never overwritten: a taken id gets a
+/// numeric suffix.
+pub fn insert_mcp_server_into_json_config(
+    config: &mut Value,
+    preferred_id: &str,
+    server: Value,
+) -> MCPRuntimeResult<String> {
+    let root = config
+        .as_object_mut()
+        .ok_or_else(|| MCPRuntimeError::validation("MCP config must be a JSON object"))?;
+    let servers = root
+        .entry("mcpServers")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(|| MCPRuntimeError::validation("'mcpServers' field must be an object"))?;
+
+    let mut server_id = preferred_id.to_string();
+    let mut suffix = 2;
+    while servers.contains_key(&server_id) {
+        server_id = format!("{}-{}", preferred_id, suffix);
+        suffix += 1;
+    }
+    servers.insert(server_id.clone(), server);
+    Ok(server_id)
+}
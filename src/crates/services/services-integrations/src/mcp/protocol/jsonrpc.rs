@@ -137,10 +137,11 @@ where
     T: serde::de::DeserializeOwned,
 {
     if let Some(error) = &response.error {
-        return Err(MCPRuntimeError::mcp(format!(
-            "MCP Error {}: {}",
-            error.code, error.message
-        )));
+        return Err(MCPRuntimeError::server_reported(
+            error.code,
+            error.message.clone(),
+            error.data.clone(),
+        ));
     }
 
     let result = response
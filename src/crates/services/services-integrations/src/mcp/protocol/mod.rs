@@ -3,13 +3,16 @@
 pub mod client_info;
 pub mod jsonrpc;
 pub mod rmcp_mapping;
+pub mod trace;
 pub mod transport;
 pub mod transport_remote;
+mod transport_tls;
 pub mod types;
 
 pub use client_info::*;
 pub use jsonrpc::*;
 pub use rmcp_mapping::*;
+pub use trace::*;
 pub use transport::*;
 pub use transport_remote::*;
 pub use types::*;
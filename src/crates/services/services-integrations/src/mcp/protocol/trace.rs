@@ -0,0 +1,287 @@
+//! Protocol inspector for a single MCP server.
+//!
+//! When enabled, the transports record every JSON-RPC frame they send or
+//! receive into a bounded ring buffer so users can attach the exchange to a
+//! bug report about a misbehaving server. Frames are redacted before they are
+//! stored: values under credential-like keys are replaced and free-form
+//! strings go through the secret scanner.
+
+use bitfun_services_core::secret_scan::{SecretScanOptions, SecretScanner};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Frames kept per server; older frames are dropped first.
+pub const MCP_TRACE_CAPACITY: usize = 500;
+/// Longer string values are truncated so large resources do not crowd out
+/// the rest of the buffer.
+const MAX_TRACED_STRING_CHARS: usize = 4 * 1024;
+const REDACTED: &str = "[REDACTED]";
+
+static SECRET_SCANNER: LazyLock<Option<SecretScanner>> =
+    LazyLock::new(|| SecretScanner::new(&SecretScanOptions::default()).ok());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MCPTraceDirection {
+    /// Client to server.
+    Outgoing,
+    /// Server to client.
+    Incoming,
+}
+
+/// One recorded JSON-RPC frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPTraceFrame {
+    pub sequence: u64,
+    pub timestamp_ms: u64,
+    pub direction: MCPTraceDirection,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    pub frame: Value,
+}
+
+/// Buffered frames of one server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPTraceSnapshot {
+    pub enabled: bool,
+    pub capacity: usize,
+    /// Frames evicted because the buffer was full.
+    pub dropped_frames: u64,
+    pub frames: Vec<MCPTraceFrame>,
+}
+
+#[derive(Debug, Default)]
+struct TraceBuffer {
+    next_sequence: u64,
+    dropped_frames: u64,
+    frames: VecDeque<MCPTraceFrame>,
+}
+
+#[derive(Debug, Default)]
+struct TraceInner {
+    enabled: AtomicBool,
+    buffer: Mutex<TraceBuffer>,
+}
+
+/// Shared handle to a server's trace buffer. Disabled by default, in which
+/// case recording is a single atomic load.
+#[derive(Debug, Clone, Default)]
+pub struct MCPProtocolTrace {
+    inner: Arc<TraceInner>,
+}
+
+impl MCPProtocolTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turns capture on or off. Frames already captured are kept until
+    /// [`Self::clear`].
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn record(&self, direction: MCPTraceDirection, frame: &Value) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let method = frame
+            .get("method")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let frame = redact_mcp_trace_frame(frame);
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+
+        let mut buffer = self.inner.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        buffer.next_sequence += 1;
+        let sequence = buffer.next_sequence;
+        if buffer.frames.len() >= MCP_TRACE_CAPACITY {
+            buffer.frames.pop_front();
+            buffer.dropped_frames += 1;
+        }
+        buffer.frames.push_back(MCPTraceFrame {
+            sequence,
+            timestamp_ms,
+            direction,
+            method,
+            frame,
+        });
+    }
+
+    /// Records a frame given as serialized JSON. Text that is not JSON is
+    /// kept as a string so malformed frames still show up in the trace.
+    pub fn record_text(&self, direction: MCPTraceDirection, text: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        let frame = serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()));
+        self.record(direction, &frame);
+    }
+
+    pub fn snapshot(&self) -> MCPTraceSnapshot {
+        let buffer = self.inner.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        MCPTraceSnapshot {
+            enabled: self.is_enabled(),
+            capacity: MCP_TRACE_CAPACITY,
+            dropped_frames: buffer.dropped_frames,
+            frames: buffer.frames.iter().cloned().collect(),
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut buffer = self.inner.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        buffer.frames.clear();
+        buffer.dropped_frames = 0;
+    }
+}
+
+/// Returns a copy of `frame` that is safe to export.
+pub fn redact_mcp_trace_frame(frame: &Value) -> Value {
+    match frame {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_trace_key(key) && !value.is_null() {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_mcp_trace_frame(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_mcp_trace_frame).collect()),
+        Value::String(text) => Value::String(redact_trace_string(text)),
+        other => other.clone(),
+    }
+}
+
+fn redact_trace_string(text: &str) -> String {
+    let text = match SECRET_SCANNER.as_ref() {
+        Some(scanner) => scanner.redact(text).text,
+        None => text.to_string(),
+    };
+    let total_chars = text.chars().count();
+    if total_chars <= MAX_TRACED_STRING_CHARS {
+        return text;
+    }
+    let mut truncated = text
+        .chars()
+        .take(MAX_TRACED_STRING_CHARS)
+        .collect::<String>();
+    truncated.push_str(&format!(
+        "…[truncated {} chars]",
+        total_chars - MAX_TRACED_STRING_CHARS
+    ));
+    truncated
+}
+
+fn is_sensitive_trace_key(key: &str) -> bool {
+    let normalized = key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    matches!(
+        normalized.as_str(),
+        "authorization"
+            | "proxyauthorization"
+            | "cookie"
+            | "setcookie"
+            | "password"
+            | "passwd"
+            | "secret"
+            | "clientsecret"
+            | "token"
+            | "accesstoken"
+            | "refreshtoken"
+            | "idtoken"
+            | "bearertoken"
+            | "apikey"
+            | "xapikey"
+            | "privatekey"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn disabled_trace_records_nothing() {
+        let trace = MCPProtocolTrace::new();
+        trace.record(
+            MCPTraceDirection::Outgoing,
+            &json!({"jsonrpc": "2.0", "method": "ping"}),
+        );
+        assert!(trace.snapshot().frames.is_empty());
+    }
+
+    #[test]
+    fn buffer_is_bounded_and_counts_dropped_frames() {
+        let trace = MCPProtocolTrace::new();
+        trace.set_enabled(true);
+        for id in 0..MCP_TRACE_CAPACITY + 3 {
+            trace.record(
+                MCPTraceDirection::Incoming,
+                &json!({"jsonrpc": "2.0", "id": id, "result": {}}),
+            );
+        }
+
+        let snapshot = trace.snapshot();
+        assert_eq!(snapshot.frames.len(), MCP_TRACE_CAPACITY);
+        assert_eq!(snapshot.dropped_frames, 3);
+        assert_eq!(snapshot.frames[0].sequence, 4);
+        assert_eq!(snapshot.frames[0].frame["id"], 3);
+    }
+
+    #[test]
+    fn frames_are_redacted_before_they_are_stored() {
+        let trace = MCPProtocolTrace::new();
+        trace.set_enabled(true);
+        trace.record(
+            MCPTraceDirection::Outgoing,
+            &json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": {
+                    "name": "deploy",
+                    "arguments": {"api_key": "abc123", "X-Api-Key": "def456", "region": "eu"},
+                },
+            }),
+        );
+
+        let frame = &trace.snapshot().frames[0];
+        assert_eq!(frame.method.as_deref(), Some("tools/call"));
+        let arguments = &frame.frame["params"]["arguments"];
+        assert_eq!(arguments["api_key"], REDACTED);
+        assert_eq!(arguments["X-Api-Key"], REDACTED);
+        assert_eq!(arguments["region"], "eu");
+    }
+
+    #[test]
+    fn long_strings_are_truncated() {
+        let long = "a".repeat(MAX_TRACED_STRING_CHARS + 10);
+        let redacted = redact_mcp_trace_frame(&json!({"text": long}));
+        assert!(redacted["text"]
+            .as_str()
+            .unwrap()
+            .ends_with("…[truncated 10 chars]"));
+    }
+}
@@ -1,28 +1,141 @@
 //! MCP stdio transport runtime.
+//!
+//! Servers delimit stdio messages either with newlines (the MCP default) or
+//! with LSP-style `Content-Length` headers. The receive loop accepts both and
+//! switches outgoing messages to headers once the server is seen using them;
+//! a server config can instead pin the framing with `framing`.
+//! Lines that are not JSON are server output, not protocol errors, and go to
+//! the `mcp_server` log target.
 
-use super::{MCPError, MCPMessage, MCPNotification, MCPRequest, MCPResponse};
+use super::{
+    MCPError, MCPMessage, MCPNotification, MCPProtocolTrace, MCPRequest, MCPResponse,
+    MCPTraceDirection,
+};
 use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
 use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{ChildStdin, ChildStdout};
 use tokio::sync::mpsc;
 use tokio::sync::Mutex;
 
+/// Log target for output a server writes outside the protocol.
+pub const MCP_SERVER_LOG_TARGET: &str = "mcp_server";
+/// Upper bound for a single `Content-Length` frame.
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StdioFraming {
+    #[serde(alias = "newline")]
+    NewlineDelimited,
+    ContentLength,
+}
+
+/// Framing shared between the receive loop, which detects it, and the
+/// transport, which uses it for outgoing messages.
+#[derive(Debug, Clone, Default)]
+pub struct StdioFramingState {
+    content_length: Arc<AtomicBool>,
+    /// Set once any protocol message has been received.
+    detected: Arc<AtomicBool>,
+    /// Configured framing never changes with what the server sends.
+    fixed: bool,
+}
+
+impl StdioFramingState {
+    /// State for a server whose config pins the framing.
+    pub fn fixed(framing: StdioFraming) -> Self {
+        let state = Self {
+            fixed: true,
+            ..Self::default()
+        };
+        state.set(framing);
+        state
+    }
+
+    /// Fixed framing when configured, otherwise detected from the server.
+    pub fn from_config(framing: Option<StdioFraming>) -> Self {
+        framing.map(Self::fixed).unwrap_or_default()
+    }
+
+    pub fn get(&self) -> StdioFraming {
+        if self.content_length.load(Ordering::Relaxed) {
+            StdioFraming::ContentLength
+        } else {
+            StdioFraming::NewlineDelimited
+        }
+    }
+
+    /// Whether an unanswered newline-framed `initialize` is worth retrying
+    /// with `Content-Length` headers: the framing is not configured and the
+    /// server has not sent anything that would have settled it.
+    pub fn should_retry_with_content_length(&self) -> bool {
+        !self.fixed
+            && !self.detected.load(Ordering::Relaxed)
+            && self.get() == StdioFraming::NewlineDelimited
+    }
+
+    /// Switches outgoing framing until the server is seen using another one.
+    pub fn set(&self, framing: StdioFraming) {
+        self.content_length
+            .store(framing == StdioFraming::ContentLength, Ordering::Relaxed);
+    }
+
+    /// Records the framing of a received message; returns `true` when it
+    /// changed.
+    fn observe(&self, framing: StdioFraming) -> bool {
+        self.detected.store(true, Ordering::Relaxed);
+        if self.fixed {
+            return false;
+        }
+        let content_length = framing == StdioFraming::ContentLength;
+        self.content_length.swap(content_length, Ordering::Relaxed) != content_length
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum StdioFrame {
+    Json { body: String, framing: StdioFraming },
+    Log(String),
+}
+
 pub struct MCPTransport {
     stdin: Arc<Mutex<ChildStdin>>,
     request_id: Arc<Mutex<u64>>,
+    framing: StdioFramingState,
+    trace: MCPProtocolTrace,
 }
 
 impl MCPTransport {
     pub fn new(stdin: ChildStdin) -> Self {
+        Self::with_framing(
+            stdin,
+            StdioFramingState::default(),
+            MCPProtocolTrace::default(),
+        )
+    }
+
+    pub fn with_framing(
+        stdin: ChildStdin,
+        framing: StdioFramingState,
+        trace: MCPProtocolTrace,
+    ) -> Self {
         Self {
             stdin: Arc::new(Mutex::new(stdin)),
             request_id: Arc::new(Mutex::new(0)),
+            framing,
+            trace,
         }
     }
 
+    pub fn framing(&self) -> &StdioFramingState {
+        &self.framing
+    }
+
     pub async fn next_request_id(&self) -> u64 {
         let mut id = self.request_id.lock().await;
         *id += 1;
@@ -76,56 +189,117 @@ impl MCPTransport {
         })?;
 
         let mut stdin = self.stdin.lock().await;
+        let framing = self.framing.get();
+        if framing == StdioFraming::ContentLength {
+            let header = format!("Content-Length: {}\r\n\r\n", json.len());
+            stdin.write_all(header.as_bytes()).await.map_err(|e| {
+                MCPRuntimeError::io(format!("Failed to write header to MCP server stdin: {}", e))
+            })?;
+        }
         stdin.write_all(json.as_bytes()).await.map_err(|e| {
             MCPRuntimeError::io(format!("Failed to write to MCP server stdin: {}", e))
         })?;
-        stdin.write_all(b"\n").await.map_err(|e| {
-            MCPRuntimeError::io(format!(
-                "Failed to write newline to MCP server stdin: {}",
-                e
-            ))
-        })?;
+        if framing == StdioFraming::NewlineDelimited {
+            stdin.write_all(b"\n").await.map_err(|e| {
+                MCPRuntimeError::io(format!(
+                    "Failed to write newline to MCP server stdin: {}",
+                    e
+                ))
+            })?;
+        }
         stdin
             .flush()
             .await
             .map_err(|e| MCPRuntimeError::io(format!("Failed to flush MCP server stdin: {}", e)))?;
 
+        self.trace.record_text(MCPTraceDirection::Outgoing, &json);
         debug!("Sent MCP message: {}", json);
         Ok(())
     }
 
-    pub fn start_receive_loop(stdout: ChildStdout, tx: mpsc::UnboundedSender<MCPMessage>) {
+    pub fn start_receive_loop(
+        stdout: ChildStdout,
+        tx: mpsc::UnboundedSender<MCPMessage>,
+        framing: StdioFramingState,
+        trace: MCPProtocolTrace,
+        server_id: String,
+    ) {
         tokio::spawn(async move {
             let mut reader = BufReader::new(stdout);
-            let mut line = String::new();
+            let mut line = Vec::new();
 
             loop {
-                line.clear();
-                match reader.read_line(&mut line).await {
-                    Ok(0) => {
-                        info!("MCP server stdout closed");
+                let frame = match read_frame(&mut reader, &mut line).await {
+                    Ok(Some(frame)) => frame,
+                    Ok(None) => {
+                        info!("MCP server stdout closed: server_id={}", server_id);
                         break;
                     }
-                    Ok(_) => {
-                        let trimmed = line.trim();
-                        if trimmed.is_empty() {
-                            continue;
-                        }
+                    Err(e) => {
+                        error!(
+                            "Error reading from MCP server stdout: server_id={} error={}",
+                            server_id, e
+                        );
+                        break;
+                    }
+                };
 
-                        match serde_json::from_str::<MCPMessage>(trimmed) {
-                            Ok(message) => {
-                                if tx.send(message).is_err() {
-                                    warn!("Failed to send MCP message to handler: channel closed");
-                                    break;
-                                }
-                            }
-                            Err(e) => {
-                                error!("Failed to parse MCP message: {} - Raw: {}", e, trimmed);
+                let (body, frame_framing) = match frame {
+                    StdioFrame::Json { body, framing } => (body, framing),
+                    StdioFrame::Log(text) => {
+                        info!(target: MCP_SERVER_LOG_TARGET, "[{}] {}", server_id, text);
+                        continue;
+                    }
+                };
+
+                trace.record_text(MCPTraceDirection::Incoming, &body);
+                match decode_messages(&body) {
+                    Ok(messages) => {
+                        if framing.observe(frame_framing) {
+                            info!(
+                                "MCP server stdio framing detected: server_id={} framing={:?}",
+                                server_id, frame_framing
+                            );
+                        }
+                        for message in messages {
+                            if tx.send(message).is_err() {
+                                warn!("Failed to send MCP message to handler: channel closed");
+                                return;
                             }
                         }
                     }
+                    // A line that only looks like JSON is still server output.
                     Err(e) => {
-                        error!("Error reading from MCP server stdout: {}", e);
+                        warn!(
+                            target: MCP_SERVER_LOG_TARGET,
+                            "[{}] Unparseable MCP message ({}): {}", server_id, e, body
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    /// Forwards server stderr to the `mcp_server` log target. Draining it
+    /// also keeps a chatty server from blocking on a full pipe.
+    pub fn start_stderr_log_loop<R>(stderr: R, server_id: String)
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            loop {
+                match lines.next_line().await {
+                    Ok(Some(line)) if line.trim().is_empty() => {}
+                    Ok(Some(line)) => {
+                        info!(target: MCP_SERVER_LOG_TARGET, "[{}] {}", server_id, line.trim_end());
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        debug!(
+                            "Stopped reading MCP server stderr: server_id={} error={}",
+                            server_id, e
+                        );
                         break;
                     }
                 }
@@ -133,3 +307,157 @@ impl MCPTransport {
         });
     }
 }
+
+/// Reads the next frame, accepting `Content-Length` headers or one JSON
+/// value per line. Returns `None` at end of stream.
+async fn read_frame<R>(reader: &mut R, line: &mut Vec<u8>) -> std::io::Result<Option<StdioFrame>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', line).await? == 0 {
+            return Ok(None);
+        }
+        let text = String::from_utf8_lossy(line);
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(length) = parse_content_length(trimmed) {
+            let length =
+                length.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            // Skip any further headers (e.g. `Content-Type`) up to the blank line.
+            loop {
+                line.clear();
+                if reader.read_until(b'\n', line).await? == 0 {
+                    return Ok(None);
+                }
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).await?;
+            return Ok(Some(StdioFrame::Json {
+                body: String::from_utf8_lossy(&body).into_owned(),
+                framing: StdioFraming::ContentLength,
+            }));
+        }
+
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            return Ok(Some(StdioFrame::Json {
+                body: trimmed.to_string(),
+                framing: StdioFraming::NewlineDelimited,
+            }));
+        }
+        return Ok(Some(StdioFrame::Log(trimmed.to_string())));
+    }
+}
+
+/// `Some` when `line` is a `Content-Length` header; the inner result carries
+/// the parsed length.
+fn parse_content_length(line: &str) -> Option<Result<usize, String>> {
+    let (name, value) = line.split_once(':')?;
+    if !name.trim().eq_ignore_ascii_case("content-length") {
+        return None;
+    }
+    Some(match value.trim().parse::<usize>() {
+        Ok(length) if length <= MAX_CONTENT_LENGTH => Ok(length),
+        Ok(length) => Err(format!(
+            "MCP frame of {} bytes exceeds the {} byte limit",
+            length, MAX_CONTENT_LENGTH
+        )),
+        Err(e) => Err(format!("Invalid Content-Length header '{}': {}", line, e)),
+    })
+}
+
+/// Decodes a single message or a JSON-RPC batch.
+fn decode_messages(body: &str) -> Result<Vec<MCPMessage>, serde_json::Error> {
+    match serde_json::from_str::<Value>(body)? {
+        Value::Array(items) => items.into_iter().map(serde_json::from_value).collect(),
+        value => serde_json::from_value(value).map(|message| vec![message]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read_all(input: &[u8]) -> Vec<StdioFrame> {
+        let mut reader = BufReader::new(input);
+        let mut line = Vec::new();
+        let mut frames = Vec::new();
+        while let Some(frame) = read_frame(&mut reader, &mut line).await.unwrap() {
+            frames.push(frame);
+        }
+        frames
+    }
+
+    fn json(body: &str, framing: StdioFraming) -> StdioFrame {
+        StdioFrame::Json {
+            body: body.to_string(),
+            framing,
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_mixed_framings_and_log_lines() {
+        let first = r#"{"jsonrpc":"2.0","id":1,"result":{}}"#;
+        let second = r#"{"jsonrpc":"2.0","method":"notifications/tools/list_changed"}"#;
+        let input = format!(
+            "Server listening on stdio\n{}\r\nContent-Length: {}\r\nContent-Type: application/json\r\n\r\n{}\n",
+            first,
+            second.len(),
+            second
+        );
+
+        let frames = read_all(input.as_bytes()).await;
+
+        assert_eq!(
+            frames,
+            vec![
+                StdioFrame::Log("Server listening on stdio".to_string()),
+                json(first, StdioFraming::NewlineDelimited),
+                json(second, StdioFraming::ContentLength),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_content_length() {
+        let input = format!("Content-Length: {}\r\n\r\n", MAX_CONTENT_LENGTH + 1);
+        let mut reader = BufReader::new(input.as_bytes());
+        let mut line = Vec::new();
+
+        let error = read_frame(&mut reader, &mut line).await.unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn decodes_batches_and_tracks_framing_changes() {
+        let batch = r#"[{"jsonrpc":"2.0","id":1,"result":{}},{"jsonrpc":"2.0","method":"ping"}]"#;
+        assert_eq!(decode_messages(batch).unwrap().len(), 2);
+        assert!(decode_messages("{not json").is_err());
+
+        let state = StdioFramingState::default();
+        assert!(state.should_retry_with_content_length());
+        assert!(!state.observe(StdioFraming::NewlineDelimited));
+        assert!(!state.should_retry_with_content_length());
+        assert!(state.observe(StdioFraming::ContentLength));
+        assert_eq!(state.get(), StdioFraming::ContentLength);
+    }
+
+    #[test]
+    fn configured_framing_ignores_detection() {
+        let state = StdioFramingState::from_config(Some(StdioFraming::ContentLength));
+        assert_eq!(state.get(), StdioFraming::ContentLength);
+        assert!(!state.should_retry_with_content_length());
+        assert!(!state.observe(StdioFraming::NewlineDelimited));
+        assert_eq!(state.get(), StdioFraming::ContentLength);
+
+        let framing: StdioFraming = serde_json::from_str(r#""newline""#).unwrap();
+        assert_eq!(framing, StdioFraming::NewlineDelimited);
+    }
+}
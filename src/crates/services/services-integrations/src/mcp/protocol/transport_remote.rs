@@ -8,10 +8,13 @@ use super::types::{
 };
 use crate::mcp::auth::build_authorization_manager;
 use crate::mcp::config::normalize_mcp_authorization_value;
+use crate::mcp::protocol::transport_tls::build_mcp_tls_client_config;
 use crate::mcp::protocol::{
     create_mcp_client_info, map_rmcp_initialize_result, map_rmcp_prompt, map_rmcp_prompt_message,
     map_rmcp_resource, map_rmcp_resource_content, map_rmcp_tool, map_rmcp_tool_result,
+    MCPProtocolTrace, MCPTraceDirection,
 };
+use crate::mcp::server::MCPServerTlsConfig;
 use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
 use futures::StreamExt;
 use log::{debug, error, info, warn};
@@ -118,6 +121,7 @@ enum ClientState {
 struct BitFunStreamableHttpClient {
     client: reqwest::Client,
     oauth_manager: Option<Arc<Mutex<AuthorizationManager>>>,
+    trace: MCPProtocolTrace,
 }
 
 impl BitFunStreamableHttpClient {
@@ -136,6 +140,32 @@ impl BitFunStreamableHttpClient {
         let token = oauth_manager.lock().await.get_access_token().await?;
         Ok(Some(token))
     }
+
+    /// Records the JSON-RPC payload of each SSE event as it is consumed.
+    /// Streams can outlive a toggle of the inspector, so the check whether
+    /// tracing is enabled happens per event.
+    fn trace_sse_stream(
+        &self,
+        stream: futures::stream::BoxStream<'static, Result<Sse, SseError>>,
+    ) -> futures::stream::BoxStream<'static, Result<Sse, SseError>> {
+        let trace = self.trace.clone();
+        stream
+            .inspect(move |event| {
+                if let Some(data) = event.as_ref().ok().and_then(|sse| sse.data.as_deref()) {
+                    trace.record_text(MCPTraceDirection::Incoming, data);
+                }
+            })
+            .boxed()
+    }
+
+    fn trace_json_message(&self, message: &rmcp::model::ServerJsonRpcMessage) {
+        if !self.trace.is_enabled() {
+            return;
+        }
+        if let Ok(frame) = serde_json::to_value(message) {
+            self.trace.record(MCPTraceDirection::Incoming, &frame);
+        }
+    }
 }
 
 fn apply_custom_headers(
@@ -203,7 +233,7 @@ impl StreamableHttpClient for BitFunStreamableHttpClient {
         }
 
         let event_stream = SseStream::from_byte_stream(response.bytes_stream()).boxed();
-        Ok(event_stream)
+        Ok(self.trace_sse_stream(event_stream))
     }
 
     async fn delete_session(
@@ -255,6 +285,11 @@ impl StreamableHttpClient for BitFunStreamableHttpClient {
         }
         request = apply_custom_headers(request, custom_headers);
 
+        if self.trace.is_enabled() {
+            if let Ok(frame) = serde_json::to_value(&message) {
+                self.trace.record(MCPTraceDirection::Outgoing, &frame);
+            }
+        }
         let response = request
             .json(&message)
             .send()
@@ -304,11 +339,15 @@ impl StreamableHttpClient for BitFunStreamableHttpClient {
         match content_type.as_deref() {
             Some(ct) if ct.as_bytes().starts_with(EVENT_STREAM_MIME_TYPE.as_bytes()) => {
                 let event_stream = SseStream::from_byte_stream(response.bytes_stream()).boxed();
-                Ok(StreamableHttpPostResponse::Sse(event_stream, session_id))
+                Ok(StreamableHttpPostResponse::Sse(
+                    self.trace_sse_stream(event_stream),
+                    session_id,
+                ))
             }
             Some(ct) if ct.as_bytes().starts_with(JSON_MIME_TYPE.as_bytes()) => {
                 let message: rmcp::model::ServerJsonRpcMessage =
                     response.json().await.map_err(StreamableHttpError::Client)?;
+                self.trace_json_message(&message);
                 Ok(StreamableHttpPostResponse::Json(message, session_id))
             }
             _ => {
@@ -331,6 +370,7 @@ impl StreamableHttpClient for BitFunStreamableHttpClient {
                 if let Ok(message) =
                     serde_json::from_slice::<rmcp::model::ServerJsonRpcMessage>(&bytes)
                 {
+                    self.trace_json_message(&message);
                     return Ok(StreamableHttpPostResponse::Json(message, session_id));
                 }
 
@@ -340,6 +380,17 @@ impl StreamableHttpClient for BitFunStreamableHttpClient {
     }
 }
 
+/// Keeps JSON-RPC errors returned by the server distinguishable from
+/// transport failures so callers can surface the server's code and data.
+fn map_rmcp_service_error(operation: &str, error: rmcp::service::ServiceError) -> MCPRuntimeError {
+    match error {
+        rmcp::service::ServiceError::McpError(error) => {
+            MCPRuntimeError::server_reported(error.code.0, error.message.to_string(), error.data)
+        }
+        other => MCPRuntimeError::mcp(format!("{} failed: {}", operation, other)),
+    }
+}
+
 /// Remote MCP transport backed by Streamable HTTP.
 pub struct RemoteMCPTransport {
     url: String,
@@ -393,6 +444,7 @@ impl RemoteMCPTransport {
     }
 
     /// Creates a new streamable HTTP remote transport instance.
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         data_dir: impl Into<PathBuf>,
         server_id: &str,
@@ -400,6 +452,8 @@ impl RemoteMCPTransport {
         headers: HashMap<String, String>,
         request_timeout: Option<Duration>,
         oauth_enabled: bool,
+        tls: Option<&MCPServerTlsConfig>,
+        trace: MCPProtocolTrace,
     ) -> MCPRuntimeResult<Self> {
         let default_headers = Self::build_default_headers(&headers);
         let oauth_manager = if oauth_enabled
@@ -420,21 +474,37 @@ impl RemoteMCPTransport {
             None
         };
 
-        let http_client = reqwest::Client::builder()
-            .connect_timeout(Duration::from_secs(10))
-            .danger_accept_invalid_certs(false)
-            .use_rustls_tls()
-            .default_headers(default_headers.clone())
-            .build()
-            .unwrap_or_else(|e| {
-                warn!("Failed to create HTTP client, using default config: {}", e);
-                reqwest::Client::new()
-            });
+        let http_client = match tls {
+            // No fallback client here: it would silently drop the CA, client
+            // certificate and pins.
+            Some(tls) => reqwest::Client::builder()
+                .connect_timeout(Duration::from_secs(10))
+                .use_preconfigured_tls(build_mcp_tls_client_config(server_id, tls)?)
+                .default_headers(default_headers.clone())
+                .build()
+                .map_err(|e| {
+                    MCPRuntimeError::configuration(format!(
+                        "Failed to create TLS client for MCP server '{}': {}",
+                        server_id, e
+                    ))
+                })?,
+            None => reqwest::Client::builder()
+                .connect_timeout(Duration::from_secs(10))
+                .danger_accept_invalid_certs(false)
+                .use_rustls_tls()
+                .default_headers(default_headers.clone())
+                .build()
+                .unwrap_or_else(|e| {
+                    warn!("Failed to create HTTP client, using default config: {}", e);
+                    reqwest::Client::new()
+                }),
+        };
 
         let transport = StreamableHttpClientTransport::with_client(
             BitFunStreamableHttpClient {
                 client: http_client,
                 oauth_manager: oauth_manager.clone(),
+                trace,
             },
             StreamableHttpClientTransportConfig::with_uri(url.clone()),
         );
@@ -563,7 +633,7 @@ impl RemoteMCPTransport {
             "MCP ping timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP ping failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP ping", e))?;
 
         match result {
             rmcp::model::ServerResult::EmptyResult(_) => Ok(()),
@@ -588,7 +658,7 @@ impl RemoteMCPTransport {
             "MCP resources/list timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP resources/list failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP resources/list", e))?;
         Ok(ResourcesListResult {
             resources: result
                 .resources
@@ -610,7 +680,7 @@ impl RemoteMCPTransport {
             "MCP resources/read timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP resources/read failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP resources/read", e))?;
         Ok(ResourcesReadResult {
             contents: result
                 .contents
@@ -634,7 +704,7 @@ impl RemoteMCPTransport {
             "MCP prompts/list timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP prompts/list failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP prompts/list", e))?;
         Ok(PromptsListResult {
             prompts: result.prompts.into_iter().map(map_rmcp_prompt).collect(),
             next_cursor: result.next_cursor,
@@ -667,7 +737,7 @@ impl RemoteMCPTransport {
             "MCP prompts/get timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP prompts/get failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP prompts/get", e))?;
 
         Ok(PromptsGetResult {
             description: result.description,
@@ -690,7 +760,7 @@ impl RemoteMCPTransport {
             "MCP tools/list timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP tools/list failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP tools/list", e))?;
 
         Ok(ToolsListResult {
             tools: result.tools.into_iter().map(map_rmcp_tool).collect(),
@@ -727,7 +797,7 @@ impl RemoteMCPTransport {
             "MCP tools/call timeout".to_string(),
         )
         .await?
-        .map_err(|e| MCPRuntimeError::mcp(format!("MCP tools/call failed: {}", e)))?;
+        .map_err(|e| map_rmcp_service_error("MCP tools/call", e))?;
 
         Ok(map_rmcp_tool_result(result))
     }
@@ -0,0 +1,190 @@
+//! rustls client configuration for remote MCP servers with TLS options.
+//!
+//! Servers without a `tls` block keep reqwest's default verifier. With one,
+//! the client trusts the system roots plus the configured CA, presents the
+//! client certificate, and checks SPKI pins after normal verification.
+
+use crate::mcp::server::{spki_sha256, MCPServerTlsConfig};
+use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
+use log::warn;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use std::sync::Arc;
+
+/// Builds the rustls configuration for a remote MCP server.
+pub(crate) fn build_mcp_tls_client_config(
+    server_id: &str,
+    tls: &MCPServerTlsConfig,
+) -> MCPRuntimeResult<rustls::ClientConfig> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let mut roots = RootCertStore::empty();
+    let native = rustls_native_certs::load_native_certs();
+    for error in &native.errors {
+        warn!(
+            "Failed to load a system root certificate for MCP server: server_id={} error={}",
+            server_id, error
+        );
+    }
+    roots.add_parsable_certificates(native.certs);
+    if let Some(path) = &tls.ca_cert_path {
+        let certs = read_pem_certificates(server_id, "caCertPath", path)?;
+        for cert in certs {
+            roots.add(cert).map_err(|error| {
+                MCPRuntimeError::configuration(format!(
+                    "MCP server '{}' tls.caCertPath contains an unusable certificate: {}",
+                    server_id, error
+                ))
+            })?;
+        }
+    }
+
+    let webpki = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+        .build()
+        .map_err(|error| {
+            MCPRuntimeError::configuration(format!(
+                "MCP server '{}' has no usable root certificates: {}",
+                server_id, error
+            ))
+        })?;
+    if tls.insecure_skip_verify {
+        warn!(
+            "TLS certificate verification is disabled for MCP server: server_id={} pinned={}",
+            server_id,
+            !tls.pinned_spki_sha256.is_empty()
+        );
+    }
+    let verifier = MCPServerCertVerifier {
+        webpki: (!tls.insecure_skip_verify).then_some(webpki),
+        pins: tls.spki_pin_digests(),
+        provider: provider.clone(),
+    };
+
+    let builder = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|error| MCPRuntimeError::configuration(error.to_string()))?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier));
+    let mut config = match (&tls.client_cert_path, &tls.client_key_path) {
+        (Some(cert_path), Some(key_path)) => {
+            let chain = read_pem_certificates(server_id, "clientCertPath", cert_path)?;
+            let key = PrivateKeyDer::from_pem_file(key_path).map_err(|error| {
+                MCPRuntimeError::configuration(format!(
+                    "MCP server '{}' tls.clientKeyPath could not be read: {}",
+                    server_id, error
+                ))
+            })?;
+            builder.with_client_auth_cert(chain, key).map_err(|error| {
+                MCPRuntimeError::configuration(format!(
+                    "MCP server '{}' client certificate and key do not match: {}",
+                    server_id, error
+                ))
+            })?
+        }
+        _ => builder.with_no_client_auth(),
+    };
+    // A preconfigured reqwest client does not negotiate ALPN on its own.
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+fn read_pem_certificates(
+    server_id: &str,
+    field: &str,
+    path: &str,
+) -> MCPRuntimeResult<Vec<CertificateDer<'static>>> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|error| {
+            MCPRuntimeError::configuration(format!(
+                "MCP server '{}' tls.{} could not be read: {}",
+                server_id, field, error
+            ))
+        })?;
+    if certs.is_empty() {
+        return Err(MCPRuntimeError::configuration(format!(
+            "MCP server '{}' tls.{} contains no PEM certificates",
+            server_id, field
+        )));
+    }
+    Ok(certs)
+}
+
+/// Normal WebPKI verification (unless skipped) followed by SPKI pinning.
+/// Handshake signatures are always checked so a pin proves key possession.
+#[derive(Debug)]
+struct MCPServerCertVerifier {
+    webpki: Option<Arc<WebPkiServerVerifier>>,
+    pins: Vec<[u8; 32]>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for MCPServerCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if let Some(webpki) = &self.webpki {
+            webpki.verify_server_cert(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            )?;
+        }
+        if !self.pins.is_empty() {
+            let digest = spki_sha256(end_entity.as_ref()).ok_or_else(|| {
+                rustls::Error::General("server certificate public key could not be read".into())
+            })?;
+            if !self.pins.contains(&digest) {
+                return Err(rustls::Error::General(
+                    "server certificate does not match any pinned public key".into(),
+                ));
+            }
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
@@ -1,5 +1,6 @@
 //! Shared MCP runtime error contracts.
 
+use serde_json::Value;
 use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,10 +18,20 @@ pub enum MCPRuntimeErrorKind {
     Other,
 }
 
+/// A JSON-RPC error object returned by the MCP server itself, as opposed to a
+/// failure in reaching or understanding the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MCPServerReportedError {
+    pub code: i32,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MCPRuntimeError {
     kind: MCPRuntimeErrorKind,
     message: String,
+    server_error: Option<Box<MCPServerReportedError>>,
 }
 
 pub type MCPRuntimeResult<T> = Result<T, MCPRuntimeError>;
@@ -30,6 +41,21 @@ impl MCPRuntimeError {
         Self {
             kind,
             message: message.into(),
+            server_error: None,
+        }
+    }
+
+    /// Wraps a JSON-RPC error response, keeping its code and data.
+    pub fn server_reported(code: i32, message: impl Into<String>, data: Option<Value>) -> Self {
+        let message = message.into();
+        Self {
+            kind: MCPRuntimeErrorKind::MCP,
+            message: format!("MCP Error {}: {}", code, message),
+            server_error: Some(Box::new(MCPServerReportedError {
+                code,
+                message,
+                data,
+            })),
         }
     }
 
@@ -41,6 +67,11 @@ impl MCPRuntimeError {
         &self.message
     }
 
+    /// The server's JSON-RPC error when this failure was reported by the server.
+    pub fn server_error(&self) -> Option<&MCPServerReportedError> {
+        self.server_error.as_deref()
+    }
+
     pub fn configuration(message: impl Into<String>) -> Self {
         Self::new(MCPRuntimeErrorKind::Configuration, message)
     }
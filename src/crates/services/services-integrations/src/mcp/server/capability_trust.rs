@@ -0,0 +1,248 @@
+//! Trusted MCP capability snapshots.
+//!
+//! The first catalog a server publishes is trusted as-is. Later catalogs are
+//! diffed against the last approved snapshot so an updated server cannot
+//! silently add destructive tools, change tool schemas, or point tools at
+//! different UI resources.
+
+use crate::mcp::protocol::MCPTool;
+use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// What the user trusted about one tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPToolCapability {
+    /// SHA-256 of the canonical input and output schemas.
+    pub schema_hash: String,
+    pub read_only: bool,
+    /// Follows the MCP default: a tool that is not read-only is destructive
+    /// unless it explicitly sets `destructiveHint: false`.
+    pub destructive: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui_resource_uri: Option<String>,
+}
+
+impl MCPToolCapability {
+    pub fn from_tool(tool: &MCPTool) -> Self {
+        let annotations = tool.annotations.as_ref();
+        let read_only = annotations
+            .and_then(|annotations| annotations.read_only_hint)
+            .unwrap_or(false);
+        let destructive = !read_only
+            && annotations
+                .and_then(|annotations| annotations.destructive_hint)
+                .unwrap_or(true);
+        let schemas = Value::Array(vec![
+            canonical_json(&tool.input_schema),
+            tool.output_schema
+                .as_ref()
+                .map(canonical_json)
+                .unwrap_or(Value::Null),
+        ]);
+        Self {
+            schema_hash: format!("{:x}", Sha256::digest(schemas.to_string().as_bytes())),
+            read_only,
+            destructive,
+            ui_resource_uri: tool
+                .meta
+                .as_ref()
+                .and_then(|meta| meta.ui.as_ref())
+                .and_then(|ui| ui.resource_uri.clone()),
+        }
+    }
+}
+
+/// Capabilities of one server's tool catalog, keyed by tool name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPCapabilitySnapshot {
+    pub tools: BTreeMap<String, MCPToolCapability>,
+}
+
+impl MCPCapabilitySnapshot {
+    pub fn from_tools(tools: &[MCPTool]) -> Self {
+        Self {
+            tools: tools
+                .iter()
+                .map(|tool| (tool.name.clone(), MCPToolCapability::from_tool(tool)))
+                .collect(),
+        }
+    }
+}
+
+/// Difference between a trusted snapshot and a newly published catalog.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPCapabilityDiff {
+    pub added_tools: Vec<String>,
+    pub removed_tools: Vec<String>,
+    pub changed_schemas: Vec<String>,
+    pub changed_ui_resources: Vec<String>,
+    /// Existing tools that were read-only or non-destructive and no longer are.
+    pub escalated_tools: Vec<String>,
+    /// Tools that stay unavailable until the user approves the update: added
+    /// destructive tools and escalated tools.
+    pub gated_tools: Vec<String>,
+}
+
+impl MCPCapabilityDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_tools.is_empty()
+            && self.removed_tools.is_empty()
+            && self.changed_schemas.is_empty()
+            && self.changed_ui_resources.is_empty()
+            && self.escalated_tools.is_empty()
+    }
+
+    pub fn requires_approval(&self) -> bool {
+        !self.gated_tools.is_empty()
+    }
+}
+
+/// A detected capability change, as reported to the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPCapabilityChange {
+    pub server_id: String,
+    pub server_name: String,
+    pub diff: MCPCapabilityDiff,
+    pub requires_approval: bool,
+}
+
+pub fn diff_mcp_capabilities(
+    trusted: &MCPCapabilitySnapshot,
+    current: &MCPCapabilitySnapshot,
+) -> MCPCapabilityDiff {
+    let mut diff = MCPCapabilityDiff::default();
+    for (name, capability) in &current.tools {
+        let Some(previous) = trusted.tools.get(name) else {
+            diff.added_tools.push(name.clone());
+            if capability.destructive {
+                diff.gated_tools.push(name.clone());
+            }
+            continue;
+        };
+        if previous.schema_hash != capability.schema_hash {
+            diff.changed_schemas.push(name.clone());
+        }
+        if previous.ui_resource_uri != capability.ui_resource_uri {
+            diff.changed_ui_resources.push(name.clone());
+        }
+        let escalated = (previous.read_only && !capability.read_only)
+            || (!previous.destructive && capability.destructive);
+        if escalated {
+            diff.escalated_tools.push(name.clone());
+            diff.gated_tools.push(name.clone());
+        }
+    }
+    diff.removed_tools = trusted
+        .tools
+        .keys()
+        .filter(|name| !current.tools.contains_key(*name))
+        .cloned()
+        .collect();
+    diff
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct TrustFile {
+    servers: HashMap<String, MCPCapabilitySnapshot>,
+}
+
+/// Approved capability snapshots, persisted per server id.
+pub struct MCPCapabilityTrustStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl MCPCapabilityTrustStore {
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            path: data_dir.into().join("mcp_capability_trust.json"),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn load(&self, server_id: &str) -> MCPRuntimeResult<Option<MCPCapabilitySnapshot>> {
+        let _guard = self.lock.lock().await;
+        Ok(self.read_file().await?.servers.remove(server_id))
+    }
+
+    pub async fn store(
+        &self,
+        server_id: &str,
+        snapshot: &MCPCapabilitySnapshot,
+    ) -> MCPRuntimeResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut file = self.read_file().await?;
+        file.servers.insert(server_id.to_string(), snapshot.clone());
+        self.write_file(&file).await
+    }
+
+    pub async fn clear(&self, server_id: &str) -> MCPRuntimeResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut file = self.read_file().await?;
+        if file.servers.remove(server_id).is_none() {
+            return Ok(());
+        }
+        self.write_file(&file).await
+    }
+
+    async fn read_file(&self) -> MCPRuntimeResult<TrustFile> {
+        if !self.path.exists() {
+            return Ok(TrustFile::default());
+        }
+        let body = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|error| {
+                MCPRuntimeError::io(format!("Failed to read MCP capability trust: {}", error))
+            })?;
+        serde_json::from_str(&body).map_err(|error| {
+            MCPRuntimeError::deserialization(format!(
+                "Failed to parse MCP capability trust: {}",
+                error
+            ))
+        })
+    }
+
+    async fn write_file(&self, file: &TrustFile) -> MCPRuntimeResult<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|error| {
+                MCPRuntimeError::io(format!(
+                    "Failed to create MCP capability trust directory: {}",
+                    error
+                ))
+            })?;
+        }
+        let body = serde_json::to_string_pretty(file).map_err(|error| {
+            MCPRuntimeError::serialization(format!(
+                "Failed to serialize MCP capability trust: {}",
+                error
+            ))
+        })?;
+        tokio::fs::write(&self.path, body).await.map_err(|error| {
+            MCPRuntimeError::io(format!("Failed to write MCP capability trust: {}", error))
+        })
+    }
+}
+
+/// Sorts object keys so the hash does not depend on map ordering.
+fn canonical_json(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let sorted = map
+                .iter()
+                .map(|(key, value)| (key.clone(), canonical_json(value)))
+                .collect::<BTreeMap<_, _>>();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(items) => Value::Array(items.iter().map(canonical_json).collect()),
+        other => other.clone(),
+    }
+}
@@ -1,15 +1,16 @@
 //! MCP server catalog cache state.
 
 use super::connection::MCPConnection;
-use crate::mcp::protocol::{MCPPrompt, MCPResource};
+use crate::mcp::protocol::{MCPPrompt, MCPResource, MCPTool};
 use crate::mcp::MCPRuntimeResult;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-/// Caches MCP resources and prompts by server id.
+/// Caches MCP resources, prompts, and published tool definitions by server id.
 pub struct MCPCatalogCache {
+    tools: RwLock<HashMap<String, Vec<MCPTool>>>,
     resources: RwLock<HashMap<String, Vec<MCPResource>>>,
     prompts: RwLock<HashMap<String, Vec<MCPPrompt>>>,
 }
@@ -17,11 +18,22 @@ pub struct MCPCatalogCache {
 impl MCPCatalogCache {
     pub fn new() -> Self {
         Self {
+            tools: RwLock::new(HashMap::new()),
             resources: RwLock::new(HashMap::new()),
             prompts: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Stores the tool definitions a server last published, returning the
+    /// previous listing so callers can diff registrations.
+    pub async fn replace_tools(&self, server_id: &str, tools: Vec<MCPTool>) -> Vec<MCPTool> {
+        self.tools
+            .write()
+            .await
+            .insert(server_id.to_string(), tools)
+            .unwrap_or_default()
+    }
+
     pub async fn replace_resources(&self, server_id: &str, resources: Vec<MCPResource>) {
         self.resources
             .write()
@@ -112,6 +124,15 @@ impl MCPCatalogCache {
         }
     }
 
+    pub async fn get_tools(&self, server_id: &str) -> Vec<MCPTool> {
+        self.tools
+            .read()
+            .await
+            .get(server_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub async fn get_resources(&self, server_id: &str) -> Vec<MCPResource> {
         self.resources
             .read()
@@ -131,11 +152,13 @@ impl MCPCatalogCache {
     }
 
     pub async fn remove_server(&self, server_id: &str) {
+        self.tools.write().await.remove(server_id);
         self.resources.write().await.remove(server_id);
         self.prompts.write().await.remove(server_id);
     }
 
     pub async fn clear(&self) {
+        self.tools.write().await.clear();
         self.resources.write().await.clear();
         self.prompts.write().await.clear();
     }
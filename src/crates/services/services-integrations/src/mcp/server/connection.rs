@@ -7,12 +7,13 @@ use crate::mcp::protocol::{
     create_initialize_request, create_ping_request, create_prompts_get_request,
     create_prompts_list_request, create_resources_list_request, create_resources_read_request,
     create_tools_call_request, create_tools_list_request, parse_response_result, InitializeResult,
-    MCPError, MCPMessage, MCPResponse, MCPToolResult, MCPTransport, PromptsGetResult,
-    PromptsListResult, RemoteMCPTransport, ResourcesListResult, ResourcesReadResult,
-    ToolsListResult,
+    MCPError, MCPMessage, MCPProtocolTrace, MCPResponse, MCPToolResult, MCPTransport,
+    PromptsGetResult, PromptsListResult, RemoteMCPTransport, ResourcesListResult,
+    ResourcesReadResult, StdioFraming, StdioFramingState, ToolsListResult,
 };
-use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
-use log::{debug, warn};
+use crate::mcp::server::MCPServerTlsConfig;
+use crate::mcp::{MCPRuntimeError, MCPRuntimeErrorKind, MCPRuntimeResult};
+use log::{debug, info, warn};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -58,7 +59,23 @@ const LOCAL_INITIALIZE_TIMEOUT: Duration = Duration::from_secs(30);
 impl MCPConnection {
     /// Creates a new local connection instance (stdin/stdout).
     pub fn new_local(stdin: ChildStdin, message_rx: mpsc::UnboundedReceiver<MCPMessage>) -> Self {
-        let transport = Arc::new(MCPTransport::new(stdin));
+        Self::new_local_with_framing(
+            stdin,
+            message_rx,
+            StdioFramingState::default(),
+            MCPProtocolTrace::default(),
+        )
+    }
+
+    /// Creates a local connection whose outgoing framing follows what the
+    /// receive loop detects on the server's stdout.
+    pub fn new_local_with_framing(
+        stdin: ChildStdin,
+        message_rx: mpsc::UnboundedReceiver<MCPMessage>,
+        framing: StdioFramingState,
+        trace: MCPProtocolTrace,
+    ) -> Self {
+        let transport = Arc::new(MCPTransport::with_framing(stdin, framing, trace));
         let pending_requests = Arc::new(RwLock::new(HashMap::new()));
         let (event_tx, _) = broadcast::channel(64);
 
@@ -89,21 +106,42 @@ impl MCPConnection {
         headers: HashMap<String, String>,
         oauth_enabled: bool,
     ) -> MCPRuntimeResult<Self> {
-        Self::new_remote_with_data_dir(std::env::temp_dir(), server_id, url, headers, oauth_enabled)
-            .await
+        Self::new_remote_with_data_dir(
+            std::env::temp_dir(),
+            server_id,
+            url,
+            headers,
+            oauth_enabled,
+            None,
+            MCPProtocolTrace::default(),
+        )
+        .await
     }
 
-    /// Creates a new remote connection with an injected OAuth data directory.
+    /// Creates a new remote connection with an injected OAuth data directory,
+    /// optional TLS settings, and the server's protocol trace.
     pub async fn new_remote_with_data_dir(
         data_dir: impl Into<PathBuf>,
         server_id: &str,
         url: String,
         headers: HashMap<String, String>,
         oauth_enabled: bool,
+        tls: Option<&MCPServerTlsConfig>,
+        trace: MCPProtocolTrace,
     ) -> MCPRuntimeResult<Self> {
         let initialize_timeout = None;
         let transport = Arc::new(
-            RemoteMCPTransport::new(data_dir, server_id, url, headers, None, oauth_enabled).await?,
+            RemoteMCPTransport::new(
+                data_dir,
+                server_id,
+                url,
+                headers,
+                None,
+                oauth_enabled,
+                tls,
+                trace,
+            )
+            .await?,
         );
         let pending_requests = Arc::new(RwLock::new(HashMap::new()));
         let (event_tx, _) = broadcast::channel(64);
@@ -265,22 +303,39 @@ impl MCPConnection {
         client_version: &str,
     ) -> MCPRuntimeResult<InitializeResult> {
         match &self.transport {
-            TransportType::Local(_) => {
+            TransportType::Local(transport) => {
                 let request = create_initialize_request(0, client_name, client_version);
-                let response = self
+                let response = match self
                     .send_request_and_wait_with_timeout(
                         request.method.clone(),
-                        request.params,
+                        request.params.clone(),
                         self.initialize_timeout,
                     )
-                    .await?;
+                    .await
+                {
+                    // A server that only reads `Content-Length` frames never
+                    // answers the newline-framed first request, so detection
+                    // alone cannot find its framing.
+                    Err(error)
+                        if error.kind() == MCPRuntimeErrorKind::Timeout
+                            && transport.framing().should_retry_with_content_length() =>
+                    {
+                        info!("MCP server did not answer initialize; retrying with Content-Length framing");
+                        transport.framing().set(StdioFraming::ContentLength);
+                        self.send_request_and_wait_with_timeout(
+                            request.method,
+                            request.params,
+                            self.initialize_timeout,
+                        )
+                        .await?
+                    }
+                    result => result?,
+                };
                 let result = parse_response_result(&response)?;
 
-                if let TransportType::Local(transport) = &self.transport {
-                    transport
-                        .send_notification("notifications/initialized".to_string(), None)
-                        .await?;
-                }
+                transport
+                    .send_notification("notifications/initialized".to_string(), None)
+                    .await?;
 
                 Ok(result)
             }
@@ -433,7 +488,7 @@ mod tests {
     use super::*;
     use crate::mcp::protocol::MCPToolResultContent;
     use serde_json::json;
-    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
 
     #[tokio::test]
     async fn local_tool_calls_do_not_inherit_initialize_timeout() {
@@ -521,6 +576,76 @@ mod tests {
         drop(stdout);
         let _ = child.kill().await;
     }
+
+    #[tokio::test]
+    async fn initialize_retries_with_content_length_for_header_only_servers() {
+        let mut child = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg("cat")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn stdio echo child");
+
+        let stdin = child.stdin.take().expect("capture stdin");
+        let stdout = child.stdout.take().expect("capture stdout");
+        let (tx, rx) = mpsc::unbounded_channel();
+        let framing = StdioFramingState::default();
+        let connection = MCPConnection::new_local_with_framing(
+            stdin,
+            rx,
+            framing.clone(),
+            MCPProtocolTrace::default(),
+        )
+        .with_initialize_timeout(Some(Duration::from_millis(100)));
+
+        // Fake server that ignores newline-framed lines and only answers
+        // requests sent with `Content-Length` headers.
+        tokio::spawn(async move {
+            let mut reader = BufReader::new(stdout);
+            let mut line = String::new();
+            loop {
+                line.clear();
+                if reader.read_line(&mut line).await.expect("read line") == 0 {
+                    break;
+                }
+                let Some(length) = line
+                    .trim()
+                    .strip_prefix("Content-Length:")
+                    .map(|length| length.trim().parse::<usize>().expect("frame length"))
+                else {
+                    continue;
+                };
+                line.clear();
+                reader.read_line(&mut line).await.expect("read blank line");
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).await.expect("read frame body");
+                let message: Value = serde_json::from_slice(&body).expect("parse frame");
+                let Some(id) = message.get("id").cloned() else {
+                    continue;
+                };
+                tx.send(MCPMessage::Response(MCPResponse::success(
+                    id,
+                    json!({
+                        "protocolVersion": "2025-06-18",
+                        "capabilities": {},
+                        "serverInfo": { "name": "header-only", "version": "1.0.0" }
+                    }),
+                )))
+                .expect("send response");
+            }
+        });
+
+        let result = connection
+            .initialize("BitFunTest", "0.0.0")
+            .await
+            .expect("initialize should succeed after the Content-Length retry");
+
+        assert_eq!(result.server_info.name, "header-only");
+        assert_eq!(framing.get(), StdioFraming::ContentLength);
+
+        let _ = child.kill().await;
+    }
 }
 
 /// MCP connection pool.
@@ -0,0 +1,293 @@
+//! Process isolation policy for local (stdio) MCP servers.
+//!
+//! Covers what the server process sees at startup: which parent environment
+//! variables pass through, which directory it runs in, and the resource
+//! ceilings applied to its process tree. It is not an OS sandbox.
+
+use super::MCPServerConfigValidationError;
+use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Smallest accepted memory ceiling; lower values cannot start a runtime.
+const MIN_MEMORY_MB: u64 = 16;
+
+/// Isolation settings for one local MCP server (`isolation` in mcp.json).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerIsolationConfig {
+    /// Parent variables passed through, as exact names or `PREFIX*`
+    /// patterns. A non-empty list starts the server from the minimal base
+    /// environment even when inheritance is enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_allowlist: Vec<String>,
+    /// Parent variables never passed through, whether inherited or allowed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_denylist: Vec<String>,
+    /// Variables that must be non-empty in the final environment.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_env: Vec<String>,
+    /// Directories the working directory must resolve inside.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub working_directory_roots: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_memory_mb: Option<u64>,
+    /// Unix only; Windows job objects here carry no CPU-time ceiling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cpu_seconds: Option<u64>,
+}
+
+impl MCPServerIsolationConfig {
+    pub fn validate(&self, server_id: &str) -> Result<(), MCPServerConfigValidationError> {
+        for (field, patterns) in [
+            ("envAllowlist", &self.env_allowlist),
+            ("envDenylist", &self.env_denylist),
+        ] {
+            if let Some(pattern) = patterns.iter().find(|pattern| !is_valid_pattern(pattern)) {
+                return Err(MCPServerConfigValidationError::new(format!(
+                    "Local MCP server '{}' has an invalid {} entry '{}'",
+                    server_id, field, pattern
+                )));
+            }
+        }
+        if let Some(name) = self
+            .required_env
+            .iter()
+            .find(|name| !is_valid_variable_name(name))
+        {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Local MCP server '{}' has an invalid requiredEnv entry '{}'",
+                server_id, name
+            )));
+        }
+        if let Some(root) = self
+            .working_directory_roots
+            .iter()
+            .find(|root| !Path::new(root.trim()).is_absolute())
+        {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Local MCP server '{}' working directory root '{}' must be an absolute path",
+                server_id, root
+            )));
+        }
+        if self.max_memory_mb.is_some_and(|mb| mb < MIN_MEMORY_MB) {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Local MCP server '{}' maxMemoryMb must be at least {}",
+                server_id, MIN_MEMORY_MB
+            )));
+        }
+        if self.max_cpu_seconds == Some(0) {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Local MCP server '{}' maxCpuSeconds must be greater than 0",
+                server_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether `name` matches an `envDenylist` entry.
+    pub fn denies(&self, name: &str) -> bool {
+        matches_any(&self.env_denylist, name)
+    }
+
+    pub fn max_memory_bytes(&self) -> Option<u64> {
+        self.max_memory_mb.map(|mb| mb.saturating_mul(1024 * 1024))
+    }
+}
+
+/// Builds the complete environment for a local MCP server process.
+///
+/// Parent variables pass through when inherited, or when they are in the base
+/// set or allowlist of a restricted environment; the denylist then removes
+/// matches. Configured `env` entries are applied last and always win.
+pub fn build_mcp_process_environment<I>(
+    server_id: &str,
+    parent: I,
+    configured: &HashMap<String, String>,
+    inherit_parent_environment: bool,
+    isolation: Option<&MCPServerIsolationConfig>,
+) -> MCPRuntimeResult<Vec<(OsString, OsString)>>
+where
+    I: IntoIterator<Item = (OsString, OsString)>,
+{
+    let allowlist = isolation
+        .map(|isolation| isolation.env_allowlist.as_slice())
+        .unwrap_or_default();
+    let restricted = !inherit_parent_environment || !allowlist.is_empty();
+
+    let mut environment = BTreeMap::new();
+    for (key, value) in parent {
+        let name = key.to_str();
+        let passes = !restricted
+            || name.is_some_and(|name| {
+                safe_process_environment_keys()
+                    .iter()
+                    .any(|safe| names_equal(safe, name))
+                    || matches_any(allowlist, name)
+            });
+        let denied =
+            name.is_some_and(|name| isolation.is_some_and(|isolation| isolation.denies(name)));
+        if passes && !denied {
+            environment.insert(key, value);
+        }
+    }
+    for (key, value) in configured {
+        environment.insert(OsString::from(key), OsString::from(value));
+    }
+
+    if let Some(isolation) = isolation {
+        let missing = isolation
+            .required_env
+            .iter()
+            .filter(|required| {
+                !environment.iter().any(|(key, value)| {
+                    !value.is_empty() && key.to_str().is_some_and(|key| names_equal(key, required))
+                })
+            })
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(MCPRuntimeError::configuration(format!(
+                "MCP server '{}' is missing required environment variables: {}",
+                server_id,
+                missing.join(", ")
+            )));
+        }
+    }
+
+    Ok(environment.into_iter().collect())
+}
+
+/// Checks the working directory before spawn and confines it to the
+/// configured roots. Returns the directory the process should start in.
+pub fn resolve_mcp_working_directory(
+    server_id: &str,
+    working_directory: Option<&Path>,
+    isolation: Option<&MCPServerIsolationConfig>,
+) -> MCPRuntimeResult<Option<PathBuf>> {
+    let roots = isolation
+        .map(|isolation| isolation.working_directory_roots.as_slice())
+        .unwrap_or_default();
+    let Some(directory) = working_directory else {
+        if roots.is_empty() {
+            return Ok(None);
+        }
+        return Err(MCPRuntimeError::configuration(format!(
+            "MCP server '{}' confines its working directory but does not set one",
+            server_id
+        )));
+    };
+
+    if !directory.is_dir() {
+        return Err(MCPRuntimeError::configuration(format!(
+            "MCP server '{}' working directory does not exist: {}",
+            server_id,
+            directory.display()
+        )));
+    }
+    if roots.is_empty() {
+        return Ok(Some(directory.to_path_buf()));
+    }
+
+    let canonical = directory.canonicalize().map_err(|error| {
+        MCPRuntimeError::configuration(format!(
+            "MCP server '{}' working directory cannot be resolved: {}",
+            server_id, error
+        ))
+    })?;
+    let confined = roots.iter().any(|root| {
+        Path::new(root.trim())
+            .canonicalize()
+            .is_ok_and(|root| canonical.starts_with(root))
+    });
+    if !confined {
+        return Err(MCPRuntimeError::configuration(format!(
+            "MCP server '{}' working directory is outside its allowed roots: {}",
+            server_id,
+            directory.display()
+        )));
+    }
+    Ok(Some(canonical))
+}
+
+/// Variables a restricted environment keeps so common runtimes still start.
+#[cfg(windows)]
+pub(super) fn safe_process_environment_keys() -> &'static [&'static str] {
+    &[
+        "SYSTEMROOT",
+        "WINDIR",
+        "COMSPEC",
+        "PATHEXT",
+        "PATH",
+        "TEMP",
+        "TMP",
+        "USERPROFILE",
+        "APPDATA",
+        "LOCALAPPDATA",
+    ]
+}
+
+/// Variables a restricted environment keeps so common runtimes still start.
+#[cfg(not(windows))]
+pub(super) fn safe_process_environment_keys() -> &'static [&'static str] {
+    &[
+        "PATH", "HOME", "TMPDIR", "LANG", "LC_ALL", "LC_CTYPE", "SHELL",
+    ]
+}
+
+fn matches_any(patterns: &[String], name: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => name
+                .get(..prefix.len())
+                .is_some_and(|head| names_equal(head, prefix)),
+            None => names_equal(pattern, name),
+        })
+}
+
+/// Environment names are case-insensitive on Windows.
+fn names_equal(left: &str, right: &str) -> bool {
+    if cfg!(windows) {
+        left.eq_ignore_ascii_case(right)
+    } else {
+        left == right
+    }
+}
+
+fn is_valid_pattern(pattern: &str) -> bool {
+    let name = pattern.strip_suffix('*').unwrap_or(pattern);
+    (!name.is_empty() || pattern == "*") && !name.contains('*') && is_valid_name_chars(name)
+}
+
+fn is_valid_variable_name(name: &str) -> bool {
+    !name.is_empty() && is_valid_name_chars(name)
+}
+
+fn is_valid_name_chars(name: &str) -> bool {
+    !name.contains('=') && !name.chars().any(char::is_control)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_any, safe_process_environment_keys};
+
+    #[test]
+    fn isolated_environment_excludes_common_secret_variables() {
+        let keys = safe_process_environment_keys();
+        assert!(!keys.contains(&"OPENAI_API_KEY"));
+        assert!(!keys.contains(&"ANTHROPIC_API_KEY"));
+        assert!(keys.contains(&"PATH"));
+    }
+
+    #[test]
+    fn patterns_match_exact_names_and_prefixes() {
+        let patterns = vec!["NODE_*".to_string(), "GITHUB_TOKEN".to_string()];
+        assert!(matches_any(&patterns, "NODE_OPTIONS"));
+        assert!(matches_any(&patterns, "GITHUB_TOKEN"));
+        assert!(!matches_any(&patterns, "GITHUB_TOKEN_2"));
+        assert!(!matches_any(&patterns, "NODE"));
+    }
+}
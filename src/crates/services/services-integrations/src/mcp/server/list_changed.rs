@@ -0,0 +1,208 @@
+//! Debounced handling of MCP `*/list_changed` notifications.
+//!
+//! Servers often emit bursts of list-changed notifications while they load
+//! plugins or reindex. The debouncer coalesces a burst into a single refresh
+//! per catalog kind, and the catalog diff lets callers update registrations
+//! incrementally instead of tearing a server's catalog down and rebuilding it.
+
+use super::MCPListChangedKind;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Quiet period after the last list-changed notification before refetching.
+pub const MCP_LIST_CHANGED_DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Default)]
+struct PendingListChanges {
+    generation: u64,
+    kinds: BTreeSet<MCPListChangedKind>,
+}
+
+#[derive(Debug, Default)]
+struct DebounceState {
+    next_generation: u64,
+    pending: HashMap<String, PendingListChanges>,
+}
+
+/// Coalesces list-changed notifications per server.
+///
+/// Every notification takes a fresh generation from a counter shared by all
+/// servers. A refresh task scheduled for an older generation finds nothing to
+/// take, so only the task spawned for the last notification of a burst
+/// performs the refetch, and tasks left over from a cancelled connection can
+/// never claim a later burst.
+#[derive(Debug, Default)]
+pub struct MCPListChangedDebouncer {
+    state: Mutex<DebounceState>,
+}
+
+impl MCPListChangedDebouncer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a notification and returns the generation the caller should
+    /// pass to [`Self::take_if_current`] once the debounce window elapses.
+    pub fn schedule(&self, server_id: &str, kind: MCPListChangedKind) -> u64 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.next_generation = state.next_generation.wrapping_add(1);
+        let generation = state.next_generation;
+        let entry = state.pending.entry(server_id.to_string()).or_default();
+        entry.generation = generation;
+        entry.kinds.insert(kind);
+        generation
+    }
+
+    /// Returns the coalesced kinds when no newer notification arrived since
+    /// `generation` was scheduled, clearing them for the next burst.
+    pub fn take_if_current(
+        &self,
+        server_id: &str,
+        generation: u64,
+    ) -> Option<Vec<MCPListChangedKind>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.pending.get(server_id)?.generation != generation {
+            return None;
+        }
+        state
+            .pending
+            .remove(server_id)
+            .map(|entry| entry.kinds.into_iter().collect())
+    }
+
+    /// Drops pending refreshes, e.g. when the server disconnects.
+    pub fn cancel(&self, server_id: &str) {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pending
+            .remove(server_id);
+    }
+}
+
+/// Entries added, removed, or redefined between two catalog listings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPCatalogDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl MCPCatalogDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diffs two catalog listings keyed by `key`. Entries are compared by their
+/// serialized form, so any definition change (description, schema,
+/// annotations) counts as `changed`.
+pub fn diff_mcp_catalog<T, K>(previous: &[T], current: &[T], key: K) -> MCPCatalogDiff
+where
+    T: Serialize,
+    K: Fn(&T) -> &str,
+{
+    let index = |items: &[T]| -> BTreeMap<String, Value> {
+        items
+            .iter()
+            .map(|item| {
+                (
+                    key(item).to_string(),
+                    serde_json::to_value(item).unwrap_or(Value::Null),
+                )
+            })
+            .collect()
+    };
+    let previous = index(previous);
+    let current = index(current);
+
+    let mut diff = MCPCatalogDiff::default();
+    for (name, definition) in &current {
+        match previous.get(name) {
+            None => diff.added.push(name.clone()),
+            Some(old) if old != definition => diff.changed.push(name.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = previous
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// UI notification for a catalog that changed after a list-changed refresh.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPListChangedEvent {
+    pub server_id: String,
+    pub server_name: String,
+    pub kind: MCPListChangedKind,
+    pub diff: MCPCatalogDiff,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn debouncer_only_releases_the_latest_generation() {
+        let debouncer = MCPListChangedDebouncer::new();
+        let first = debouncer.schedule("srv", MCPListChangedKind::Tools);
+        let second = debouncer.schedule("srv", MCPListChangedKind::Prompts);
+        let third = debouncer.schedule("srv", MCPListChangedKind::Tools);
+
+        assert_eq!(debouncer.take_if_current("srv", first), None);
+        assert_eq!(debouncer.take_if_current("srv", second), None);
+        assert_eq!(
+            debouncer.take_if_current("srv", third),
+            Some(vec![MCPListChangedKind::Tools, MCPListChangedKind::Prompts])
+        );
+        assert_eq!(debouncer.take_if_current("srv", third), None);
+    }
+
+    #[test]
+    fn debouncer_cancel_drops_pending_kinds() {
+        let debouncer = MCPListChangedDebouncer::new();
+        let stale = debouncer.schedule("srv", MCPListChangedKind::Resources);
+        debouncer.cancel("srv");
+        assert_eq!(debouncer.take_if_current("srv", stale), None);
+
+        let fresh = debouncer.schedule("srv", MCPListChangedKind::Tools);
+        assert_ne!(stale, fresh);
+        assert_eq!(debouncer.take_if_current("srv", stale), None);
+        assert_eq!(
+            debouncer.take_if_current("srv", fresh),
+            Some(vec![MCPListChangedKind::Tools])
+        );
+    }
+
+    #[test]
+    fn catalog_diff_reports_added_removed_and_changed_entries() {
+        let previous = vec![
+            json!({"name": "keep", "description": "same"}),
+            json!({"name": "edit", "description": "old"}),
+            json!({"name": "gone"}),
+        ];
+        let current = vec![
+            json!({"name": "keep", "description": "same"}),
+            json!({"name": "edit", "description": "new"}),
+            json!({"name": "fresh"}),
+        ];
+        fn key(value: &Value) -> &str {
+            value["name"].as_str().unwrap_or_default()
+        }
+
+        let diff = diff_mcp_catalog(&previous, &current, key);
+        assert_eq!(diff.added, vec!["fresh"]);
+        assert_eq!(diff.removed, vec!["gone"]);
+        assert_eq!(diff.changed, vec!["edit"]);
+        assert!(diff_mcp_catalog(&current, &current, key).is_empty());
+    }
+}
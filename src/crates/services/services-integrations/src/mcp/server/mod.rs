@@ -1,24 +1,45 @@
 //! MCP server data contracts.
 
+mod capability_trust;
 mod catalog_cache;
 mod connection;
+mod isolation;
+mod list_changed;
 mod process;
+mod readiness;
 mod reconnect;
 mod registry;
 mod runtime_helpers;
 mod runtime_policy;
 mod runtime_state;
+mod tls;
 
 use crate::mcp::config::ConfigLocation;
+use crate::mcp::protocol::StdioFraming;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 
 pub use crate::mcp::{MCPRuntimeError, MCPRuntimeErrorKind, MCPRuntimeResult};
+pub use capability_trust::{
+    diff_mcp_capabilities, MCPCapabilityChange, MCPCapabilityDiff, MCPCapabilitySnapshot,
+    MCPCapabilityTrustStore, MCPToolCapability,
+};
 pub use catalog_cache::MCPCatalogCache;
 pub use connection::{MCPConnection, MCPConnectionEvent, MCPConnectionPool};
+pub use isolation::{
+    build_mcp_process_environment, resolve_mcp_working_directory, MCPServerIsolationConfig,
+};
+pub use list_changed::{
+    diff_mcp_catalog, MCPCatalogDiff, MCPListChangedDebouncer, MCPListChangedEvent,
+    MCP_LIST_CHANGED_DEBOUNCE,
+};
 pub use process::MCPServerProcess;
+pub use readiness::{
+    MCPInitializationSummary, MCPServerReadiness, MCPServerReadinessEvent, MCPStartupReadiness,
+    MCP_INITIALIZE_ALL_TIMEOUT,
+};
 pub use reconnect::MCPReconnectTracker;
 pub use registry::MCPServerRegistry;
 pub use runtime_helpers::{
@@ -31,6 +52,8 @@ pub use runtime_policy::{
     MCPListChangedKind, MCPReconnectRuntimeDecision,
 };
 pub use runtime_state::MCPServerRuntimeState;
+pub(crate) use tls::spki_sha256;
+pub use tls::{mcp_spki_pin_for_certificate, MCPServerTlsConfig, MCP_SPKI_PIN_PREFIX};
 
 /// MCP server type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -125,11 +148,22 @@ pub struct MCPServerConfig {
     /// configurations; imported executable configuration can opt out.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inherit_parent_environment: Option<bool>,
+    /// Environment filtering, working-directory confinement and resource
+    /// ceilings for a local process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<MCPServerIsolationConfig>,
+    /// Stdio message framing. `None` detects it from the server's output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framing: Option<StdioFraming>,
     /// Additional HTTP headers for remote MCP servers (Cursor-style `headers`).
     #[serde(default)]
     pub headers: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Custom CA, client certificate, pinning and verification options for
+    /// a remote server's HTTPS connection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<MCPServerTlsConfig>,
     #[serde(default = "default_true")]
     pub auto_start: bool,
     #[serde(default = "default_true")]
@@ -212,6 +246,12 @@ impl MCPServerConfig {
                         self.id
                     )));
                 }
+                if self.tls.is_some() {
+                    return Err(MCPServerConfigValidationError::new(format!(
+                        "Local MCP server '{}' cannot configure TLS",
+                        self.id
+                    )));
+                }
                 if self.command.is_none() {
                     return Err(MCPServerConfigValidationError::new(format!(
                         "Local MCP server '{}' must have a command",
@@ -234,6 +274,15 @@ impl MCPServerConfig {
                         self.id
                     )));
                 }
+                if let Some(isolation) = &self.isolation {
+                    isolation.validate(&self.id)?;
+                    if let Some(key) = self.env.keys().find(|key| isolation.denies(key)) {
+                        return Err(MCPServerConfigValidationError::new(format!(
+                            "Local MCP server '{}' sets env '{}' that its envDenylist blocks",
+                            self.id, key
+                        )));
+                    }
+                }
             }
             MCPServerType::Remote => {
                 if self.inherit_parent_environment.is_some() {
@@ -248,11 +297,36 @@ impl MCPServerConfig {
                         self.id
                     )));
                 }
-                if self.url.is_none() {
+                if self.isolation.is_some() {
+                    return Err(MCPServerConfigValidationError::new(format!(
+                        "Remote MCP server '{}' cannot configure process isolation",
+                        self.id
+                    )));
+                }
+                if self.framing.is_some() {
+                    return Err(MCPServerConfigValidationError::new(format!(
+                        "Remote MCP server '{}' cannot configure stdio framing",
+                        self.id
+                    )));
+                }
+                let Some(url) = self.url.as_deref() else {
                     return Err(MCPServerConfigValidationError::new(format!(
                         "Remote MCP server '{}' must have a URL",
                         self.id
                     )));
+                };
+                if let Some(tls) = &self.tls {
+                    if !url
+                        .trim_start()
+                        .to_ascii_lowercase()
+                        .starts_with("https://")
+                    {
+                        return Err(MCPServerConfigValidationError::new(format!(
+                            "Remote MCP server '{}' can only configure TLS for an https:// URL",
+                            self.id
+                        )));
+                    }
+                    tls.validate(&self.id)?;
                 }
 
                 if let Some(oauth) = &self.oauth {
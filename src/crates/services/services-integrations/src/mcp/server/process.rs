@@ -3,14 +3,22 @@
 //! Handles starting, stopping, monitoring, and restarting MCP server processes.
 
 use super::connection::MCPConnection;
-use super::{MCPServerConfig, MCPServerStatus, MCPServerTransport, MCPServerType};
-use crate::mcp::protocol::{InitializeResult, MCPMessage, MCPServerInfo, MCPTransport};
+use super::{
+    build_mcp_process_environment, resolve_mcp_working_directory, MCPServerConfig,
+    MCPServerIsolationConfig, MCPServerStatus, MCPServerTransport, MCPServerType,
+};
+use crate::mcp::protocol::{
+    InitializeResult, MCPMessage, MCPProtocolTrace, MCPServerInfo, MCPTransport, StdioFraming,
+    StdioFramingState,
+};
 use crate::mcp::server::{is_mcp_auth_error_message, merge_mcp_remote_headers};
 use crate::mcp::{MCPRuntimeError, MCPRuntimeResult};
 use bitfun_services_core::process_manager;
-use bitfun_services_core::process_tree::ProcessTreeChild;
+use bitfun_services_core::process_tree::{ProcessTreeChild, ProcessTreeLimits};
 use log::{debug, error, info, warn};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, RwLock};
@@ -32,6 +40,8 @@ pub struct MCPServerProcess {
     last_error_message: Arc<RwLock<Option<String>>>,
     message_rx: Option<mpsc::UnboundedReceiver<MCPMessage>>,
     remote_url: Option<String>,
+    /// Outlives individual connections so a trace spans restarts.
+    protocol_trace: MCPProtocolTrace,
     #[cfg(test)]
     fail_next_stop: bool,
 }
@@ -55,6 +65,7 @@ impl MCPServerProcess {
             last_error_message: Arc::new(RwLock::new(None)),
             message_rx: None,
             remote_url: None,
+            protocol_trace: MCPProtocolTrace::default(),
             #[cfg(test)]
             fail_next_stop: false,
         }
@@ -95,9 +106,58 @@ impl MCPServerProcess {
         working_directory: Option<&std::path::Path>,
         inherit_parent_environment: bool,
     ) -> MCPRuntimeResult<()> {
+        self.start_local_process(LocalLaunch {
+            command,
+            args,
+            env,
+            working_directory,
+            inherit_parent_environment,
+            isolation: None,
+            framing: None,
+        })
+        .await
+    }
+
+    /// Starts a local server with the launch and isolation settings of its
+    /// config. `command` is the resolved executable.
+    pub async fn start_local(
+        &mut self,
+        command: &str,
+        config: &MCPServerConfig,
+    ) -> MCPRuntimeResult<()> {
+        self.start_local_process(LocalLaunch::from_config(command, config))
+            .await
+    }
+
+    async fn start_local_process(&mut self, launch: LocalLaunch<'_>) -> MCPRuntimeResult<()> {
         info!("Starting MCP server: name={} id={}", self.name, self.id);
         self.set_status(MCPServerStatus::Starting).await;
 
+        let (environment, working_directory) = match launch.prepare(&self.id) {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                error!(
+                    "MCP server startup validation failed: name={} id={} error={}",
+                    self.name, self.id, e
+                );
+                self.set_status_with_error(MCPServerStatus::Failed, Some(e.to_string()))
+                    .await;
+                return Err(e);
+            }
+        };
+        let limits = launch.limits();
+        #[cfg(windows)]
+        if launch
+            .isolation
+            .is_some_and(|isolation| isolation.max_cpu_seconds.is_some())
+        {
+            warn!(
+                "MCP server CPU-time ceiling is not supported on Windows: name={} id={}",
+                self.name, self.id
+            );
+        }
+        let (command, args) = (launch.command, launch.args);
+
         #[cfg(windows)]
         let (final_command, final_args) = {
             let node_commands = ["npm", "npx", "node", "yarn", "pnpm"];
@@ -120,15 +180,8 @@ impl MCPServerProcess {
 
         let mut cmd = process_manager::create_tokio_command(&final_command);
         cmd.args(&final_args);
-        if !inherit_parent_environment {
-            cmd.env_clear();
-            for key in safe_process_environment_keys() {
-                if let Some(value) = std::env::var_os(key) {
-                    cmd.env(key, value);
-                }
-            }
-        }
-        cmd.envs(env);
+        cmd.env_clear();
+        cmd.envs(environment);
         if let Some(working_directory) = working_directory {
             cmd.current_dir(working_directory);
         }
@@ -136,16 +189,18 @@ impl MCPServerProcess {
         cmd.stdout(std::process::Stdio::piped());
         cmd.stderr(std::process::Stdio::piped());
 
-        let child = ProcessTreeChild::spawn(&mut cmd).await.map_err(|e| {
-            error!(
-                "Failed to spawn MCP server process: command={} error={}",
-                final_command, e
-            );
-            MCPRuntimeError::process(format!(
-                "Failed to start MCP server '{}': {}",
-                final_command, e
-            ))
-        });
+        let child = ProcessTreeChild::spawn_with_limits(&mut cmd, limits)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to spawn MCP server process: command={} error={}",
+                    final_command, e
+                );
+                MCPRuntimeError::process(format!(
+                    "Failed to start MCP server '{}': {}",
+                    final_command, e
+                ))
+            });
         let mut child = match child {
             Ok(c) => c,
             Err(e) => {
@@ -163,11 +218,26 @@ impl MCPServerProcess {
             .ok_or_else(|| MCPRuntimeError::process("Failed to capture stdout".to_string()))?;
 
         let (tx, rx) = mpsc::unbounded_channel();
-
-        let connection = Arc::new(MCPConnection::new(stdin, rx));
+        let framing = StdioFramingState::from_config(launch.framing);
+
+        let connection = Arc::new(MCPConnection::new_local_with_framing(
+            stdin,
+            rx,
+            framing.clone(),
+            self.protocol_trace.clone(),
+        ));
         self.message_rx = None; // The connection already owns rx
 
-        MCPTransport::start_receive_loop(stdout, tx);
+        MCPTransport::start_receive_loop(
+            stdout,
+            tx,
+            framing,
+            self.protocol_trace.clone(),
+            self.id.clone(),
+        );
+        if let Some(stderr) = child.take_stderr() {
+            MCPTransport::start_stderr_log_loop(stderr, self.id.clone());
+        }
 
         self.connection = Some(connection.clone());
         self.child = Some(child);
@@ -234,6 +304,8 @@ impl MCPServerProcess {
                 url.to_string(),
                 merged_headers,
                 config.remote_oauth_enabled(),
+                config.tls.as_ref(),
+                self.protocol_trace.clone(),
             )
             .await
             .map_err(|error| {
@@ -370,6 +442,29 @@ impl MCPServerProcess {
         working_directory: Option<&std::path::Path>,
         inherit_parent_environment: bool,
     ) -> MCPRuntimeResult<()> {
+        self.stop_for_restart().await?;
+        self.start_with_environment_policy(
+            command,
+            args,
+            env,
+            working_directory,
+            inherit_parent_environment,
+        )
+        .await
+    }
+
+    /// Restarts a local server with the launch and isolation settings of its
+    /// config.
+    pub async fn restart_local(
+        &mut self,
+        command: &str,
+        config: &MCPServerConfig,
+    ) -> MCPRuntimeResult<()> {
+        self.stop_for_restart().await?;
+        self.start_local(command, config).await
+    }
+
+    async fn stop_for_restart(&mut self) -> MCPRuntimeResult<()> {
         if self.restart_count >= self.max_restarts {
             error!(
                 "Max restart attempts reached: name={} id={} max_restarts={}",
@@ -397,14 +492,7 @@ impl MCPServerProcess {
 
         self.stop().await?;
         tokio::time::sleep(Duration::from_secs(1)).await;
-        self.start_with_environment_policy(
-            command,
-            args,
-            env,
-            working_directory,
-            inherit_parent_environment,
-        )
-        .await
+        Ok(())
     }
 
     /// Sets status.
@@ -434,6 +522,11 @@ impl MCPServerProcess {
         self.connection.clone()
     }
 
+    /// Protocol inspector buffer for this server.
+    pub fn protocol_trace(&self) -> &MCPProtocolTrace {
+        &self.protocol_trace
+    }
+
     /// Returns server info.
     pub fn server_info(&self) -> Option<&MCPServerInfo> {
         self.server_info.as_ref()
@@ -517,20 +610,56 @@ impl MCPServerProcess {
     }
 }
 
-#[cfg(windows)]
-fn safe_process_environment_keys() -> &'static [&'static str] {
-    &[
-        "SYSTEMROOT",
-        "WINDIR",
-        "COMSPEC",
-        "PATHEXT",
-        "PATH",
-        "TEMP",
-        "TMP",
-        "USERPROFILE",
-        "APPDATA",
-        "LOCALAPPDATA",
-    ]
+/// Launch settings for one local server start.
+struct LocalLaunch<'a> {
+    command: &'a str,
+    args: &'a [String],
+    env: &'a HashMap<String, String>,
+    working_directory: Option<&'a Path>,
+    inherit_parent_environment: bool,
+    isolation: Option<&'a MCPServerIsolationConfig>,
+    framing: Option<StdioFraming>,
+}
+
+impl<'a> LocalLaunch<'a> {
+    fn from_config(command: &'a str, config: &'a MCPServerConfig) -> Self {
+        Self {
+            command,
+            args: &config.args,
+            env: &config.env,
+            working_directory: config.working_directory.as_deref().map(Path::new),
+            inherit_parent_environment: config.inherits_parent_environment(),
+            isolation: config.isolation.as_ref(),
+            framing: config.framing,
+        }
+    }
+
+    /// Startup-time checks: builds the process environment and resolves the
+    /// working directory, failing before anything is spawned.
+    fn prepare(
+        &self,
+        server_id: &str,
+    ) -> MCPRuntimeResult<(Vec<(OsString, OsString)>, Option<PathBuf>)> {
+        let environment = build_mcp_process_environment(
+            server_id,
+            std::env::vars_os(),
+            self.env,
+            self.inherit_parent_environment,
+            self.isolation,
+        )?;
+        let working_directory =
+            resolve_mcp_working_directory(server_id, self.working_directory, self.isolation)?;
+        Ok((environment, working_directory))
+    }
+
+    fn limits(&self) -> ProcessTreeLimits {
+        self.isolation
+            .map(|isolation| ProcessTreeLimits {
+                max_memory_bytes: isolation.max_memory_bytes(),
+                max_cpu_seconds: isolation.max_cpu_seconds,
+            })
+            .unwrap_or_default()
+    }
 }
 
 fn redact_sensitive_value(message: &str, sensitive_value: Option<&str>) -> String {
@@ -542,15 +671,7 @@ fn redact_sensitive_value(message: &str, sensitive_value: Option<&str>) -> Strin
 
 #[cfg(test)]
 mod tests {
-    use super::{redact_sensitive_value, safe_process_environment_keys};
-
-    #[test]
-    fn isolated_environment_excludes_common_secret_variables() {
-        let keys = safe_process_environment_keys();
-        assert!(!keys.contains(&"OPENAI_API_KEY"));
-        assert!(!keys.contains(&"ANTHROPIC_API_KEY"));
-        assert!(keys.contains(&"PATH"));
-    }
+    use super::redact_sensitive_value;
 
     #[test]
     fn remote_errors_do_not_expose_the_configured_url() {
@@ -563,13 +684,6 @@ mod tests {
     }
 }
 
-#[cfg(not(windows))]
-fn safe_process_environment_keys() -> &'static [&'static str] {
-    &[
-        "PATH", "HOME", "TMPDIR", "LANG", "LC_ALL", "LC_CTYPE", "SHELL",
-    ]
-}
-
 impl Drop for MCPServerProcess {
     fn drop(&mut self) {
         self.child.take();
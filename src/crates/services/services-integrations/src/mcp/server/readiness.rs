@@ -0,0 +1,177 @@
+//! Per-server readiness during concurrent MCP startup.
+//!
+//! Auto-start servers are launched in parallel and each one becomes ready on
+//! its own schedule. A slow server (typically an `npx` package that has to be
+//! downloaded first) no longer holds back the others: its tools are published
+//! when it reaches `Ready`, and startup stops waiting for it once the global
+//! timeout elapses while the server keeps starting in the background.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How long `initialize_all` waits for auto-start servers before returning.
+pub const MCP_INITIALIZE_ALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Startup readiness of a single server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MCPServerReadiness {
+    /// Registered, start not attempted yet.
+    Pending,
+    /// Process launched or connection in progress.
+    Starting,
+    /// Connected and its tool catalog is published.
+    Ready,
+    /// Start failed.
+    Failed,
+}
+
+/// UI notification emitted each time a server's readiness changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerReadinessEvent {
+    pub server_id: String,
+    pub server_name: String,
+    pub readiness: MCPServerReadiness,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of `initialize_all` at the moment it returned.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPInitializationSummary {
+    pub ready: Vec<String>,
+    pub failed: Vec<String>,
+    /// Servers still starting when the global timeout elapsed.
+    pub pending: Vec<String>,
+    pub timed_out: bool,
+}
+
+/// Readiness of the servers launched by the most recent startup pass.
+#[derive(Debug, Default)]
+pub struct MCPStartupReadiness {
+    servers: Mutex<HashMap<String, MCPServerReadiness>>,
+}
+
+impl MCPStartupReadiness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new pass, marking `server_ids` as pending.
+    pub fn reset<I, S>(&self, server_ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut servers = self.servers.lock().unwrap_or_else(|e| e.into_inner());
+        servers.clear();
+        servers.extend(
+            server_ids
+                .into_iter()
+                .map(|server_id| (server_id.into(), MCPServerReadiness::Pending)),
+        );
+    }
+
+    pub fn set(&self, server_id: &str, readiness: MCPServerReadiness) {
+        self.servers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(server_id.to_string(), readiness);
+    }
+
+    pub fn get(&self, server_id: &str) -> Option<MCPServerReadiness> {
+        self.servers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(server_id)
+            .copied()
+    }
+
+    pub fn remove(&self, server_id: &str) {
+        self.servers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(server_id);
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, MCPServerReadiness> {
+        self.servers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(server_id, readiness)| (server_id.clone(), *readiness))
+            .collect()
+    }
+
+    /// Summarizes the current pass. Servers not yet `Ready` or `Failed` are
+    /// reported as pending.
+    pub fn summary(&self, timed_out: bool) -> MCPInitializationSummary {
+        let mut summary = MCPInitializationSummary {
+            timed_out,
+            ..Default::default()
+        };
+        for (server_id, readiness) in self.snapshot() {
+            match readiness {
+                MCPServerReadiness::Ready => summary.ready.push(server_id),
+                MCPServerReadiness::Failed => summary.failed.push(server_id),
+                MCPServerReadiness::Pending | MCPServerReadiness::Starting => {
+                    summary.pending.push(server_id)
+                }
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn summary_groups_servers_by_readiness() {
+        let readiness = MCPStartupReadiness::new();
+        readiness.reset(["slow", "fast", "broken", "queued"]);
+        readiness.set("fast", MCPServerReadiness::Ready);
+        readiness.set("broken", MCPServerReadiness::Failed);
+        readiness.set("slow", MCPServerReadiness::Starting);
+
+        let summary = readiness.summary(true);
+        assert_eq!(summary.ready, vec!["fast"]);
+        assert_eq!(summary.failed, vec!["broken"]);
+        assert_eq!(summary.pending, vec!["queued", "slow"]);
+        assert!(summary.timed_out);
+    }
+
+    #[test]
+    fn reset_drops_servers_from_the_previous_pass() {
+        let readiness = MCPStartupReadiness::new();
+        readiness.reset(["old"]);
+        readiness.set("old", MCPServerReadiness::Ready);
+        readiness.reset(["new"]);
+
+        assert_eq!(readiness.get("old"), None);
+        assert_eq!(readiness.get("new"), Some(MCPServerReadiness::Pending));
+    }
+
+    #[test]
+    fn readiness_event_omits_absent_fields() {
+        let event = MCPServerReadinessEvent {
+            server_id: "srv".to_string(),
+            server_name: "Server".to_string(),
+            readiness: MCPServerReadiness::Starting,
+            tool_count: None,
+            error: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({"serverId": "srv", "serverName": "Server", "readiness": "starting"})
+        );
+    }
+}
@@ -208,8 +208,11 @@ mod tests {
             env: HashMap::new(),
             working_directory: None,
             inherit_parent_environment: None,
+            isolation: None,
+            framing: None,
             headers: HashMap::new(),
             url: None,
+            tls: None,
             auto_start: false,
             enabled: true,
             location: ConfigLocation::User,
@@ -3,8 +3,10 @@
 use std::time::Duration;
 
 use super::{MCPServerConfig, MCPServerStatus};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MCPListChangedKind {
     Tools,
     Prompts,
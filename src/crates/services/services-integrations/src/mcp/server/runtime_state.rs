@@ -8,7 +8,7 @@ use super::{
     MCPCatalogCache, MCPConnection, MCPConnectionPool, MCPReconnectTracker, MCPRuntimeResult,
     MCPServerConfig, MCPServerProcess, MCPServerRegistry, MCPServerStatus,
 };
-use crate::mcp::protocol::{MCPPrompt, MCPResource};
+use crate::mcp::protocol::{MCPPrompt, MCPResource, MCPTool};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -136,6 +136,14 @@ impl MCPServerRuntimeState {
         self.catalog_cache.warm(server_id, connection).await;
     }
 
+    pub async fn replace_cached_tools(&self, server_id: &str, tools: Vec<MCPTool>) -> Vec<MCPTool> {
+        self.catalog_cache.replace_tools(server_id, tools).await
+    }
+
+    pub async fn get_cached_tools(&self, server_id: &str) -> Vec<MCPTool> {
+        self.catalog_cache.get_tools(server_id).await
+    }
+
     pub async fn get_cached_resources(&self, server_id: &str) -> Vec<MCPResource> {
         self.catalog_cache.get_resources(server_id).await
     }
@@ -0,0 +1,204 @@
+//! TLS options for remote MCP servers.
+//!
+//! Self-hosted MCP gateways often sit behind a private CA, require client
+//! certificates, or are pinned to a known key. These options apply to the
+//! HTTPS connection of a remote server; the client is built in
+//! `protocol::transport_tls`.
+
+use super::MCPServerConfigValidationError;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Prefix of an SPKI pin, as used by HPKP and most HTTP clients.
+pub const MCP_SPKI_PIN_PREFIX: &str = "sha256/";
+
+/// TLS settings for one remote MCP server (`tls` in mcp.json).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPServerTlsConfig {
+    /// PEM file with CA certificates trusted in addition to the system roots.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_cert_path: Option<String>,
+    /// PEM certificate chain presented for mutual TLS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_cert_path: Option<String>,
+    /// PEM private key for `clientCertPath`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_key_path: Option<String>,
+    /// `sha256/<base64>` hashes of the server certificate's public key. When
+    /// set, the leaf certificate must match one of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_spki_sha256: Vec<String>,
+    /// Disables CA chain and hostname verification. Pins still apply, so a
+    /// pinned self-signed gateway stays protected; without pins the
+    /// connection can be intercepted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub insecure_skip_verify: bool,
+}
+
+impl MCPServerTlsConfig {
+    pub fn validate(&self, server_id: &str) -> Result<(), MCPServerConfigValidationError> {
+        for (field, path) in [
+            ("caCertPath", &self.ca_cert_path),
+            ("clientCertPath", &self.client_cert_path),
+            ("clientKeyPath", &self.client_key_path),
+        ] {
+            if let Some(path) = path {
+                if !Path::new(path.trim()).is_absolute() {
+                    return Err(MCPServerConfigValidationError::new(format!(
+                        "Remote MCP server '{}' tls.{} must be an absolute path",
+                        server_id, field
+                    )));
+                }
+            }
+        }
+        if self.client_cert_path.is_some() != self.client_key_path.is_some() {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Remote MCP server '{}' tls.clientCertPath and tls.clientKeyPath must be set together",
+                server_id
+            )));
+        }
+        if let Some(pin) = self
+            .pinned_spki_sha256
+            .iter()
+            .find(|pin| decode_spki_pin(pin).is_none())
+        {
+            return Err(MCPServerConfigValidationError::new(format!(
+                "Remote MCP server '{}' has an invalid tls.pinnedSpkiSha256 entry '{}'; expected sha256/<base64>",
+                server_id, pin
+            )));
+        }
+        Ok(())
+    }
+
+    /// Decoded pin digests. Invalid entries are rejected by `validate`.
+    pub fn spki_pin_digests(&self) -> Vec<[u8; 32]> {
+        self.pinned_spki_sha256
+            .iter()
+            .filter_map(|pin| decode_spki_pin(pin))
+            .collect()
+    }
+}
+
+fn decode_spki_pin(pin: &str) -> Option<[u8; 32]> {
+    let encoded = pin.trim();
+    let encoded = encoded.strip_prefix(MCP_SPKI_PIN_PREFIX).unwrap_or(encoded);
+    BASE64_STANDARD.decode(encoded).ok()?.try_into().ok()
+}
+
+/// Returns the `sha256/<base64>` pin of a DER certificate's public key, or
+/// `None` when the certificate cannot be parsed.
+pub fn mcp_spki_pin_for_certificate(cert_der: &[u8]) -> Option<String> {
+    let digest = spki_sha256(cert_der)?;
+    Some(format!(
+        "{}{}",
+        MCP_SPKI_PIN_PREFIX,
+        BASE64_STANDARD.encode(digest)
+    ))
+}
+
+/// SHA-256 of the certificate's DER-encoded SubjectPublicKeyInfo.
+pub(crate) fn spki_sha256(cert_der: &[u8]) -> Option<[u8; 32]> {
+    let spki = certificate_spki(cert_der)?;
+    Some(Sha256::digest(spki).into())
+}
+
+const DER_SEQUENCE: u8 = 0x30;
+const DER_INTEGER: u8 = 0x02;
+const DER_EXPLICIT_VERSION: u8 = 0xa0;
+
+/// Walks `Certificate -> TBSCertificate` up to `subjectPublicKeyInfo` and
+/// returns its full encoding (tag, length and content).
+fn certificate_spki(cert_der: &[u8]) -> Option<&[u8]> {
+    let (_, certificate, _) = der_element(cert_der, DER_SEQUENCE)?;
+    let (_, tbs, _) = der_element(certificate, DER_SEQUENCE)?;
+    let mut rest = tbs;
+    if rest.first() == Some(&DER_EXPLICIT_VERSION) {
+        rest = der_element(rest, DER_EXPLICIT_VERSION)?.2;
+    }
+    // serialNumber, signature, issuer, validity, subject
+    for tag in [
+        DER_INTEGER,
+        DER_SEQUENCE,
+        DER_SEQUENCE,
+        DER_SEQUENCE,
+        DER_SEQUENCE,
+    ] {
+        rest = der_element(rest, tag)?.2;
+    }
+    let (spki, _, _) = der_element(rest, DER_SEQUENCE)?;
+    Some(spki)
+}
+
+/// Splits one DER element with the expected tag into
+/// `(whole element, content, remaining input)`.
+fn der_element(input: &[u8], tag: u8) -> Option<(&[u8], &[u8], &[u8])> {
+    if *input.first()? != tag {
+        return None;
+    }
+    let first_length_byte = *input.get(1)?;
+    let (length, header_len) = if first_length_byte < 0x80 {
+        (first_length_byte as usize, 2)
+    } else {
+        let length_bytes = (first_length_byte & 0x7f) as usize;
+        if length_bytes == 0 || length_bytes > 4 {
+            return None;
+        }
+        let length = input
+            .get(2..2 + length_bytes)?
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+        (length, 2 + length_bytes)
+    };
+    let end = header_len.checked_add(length)?;
+    let element = input.get(..end)?;
+    Some((element, &element[header_len..], &input[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{certificate_spki, der_element};
+
+    /// Wraps `content` in a DER element, using the long length form when
+    /// needed.
+    fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut encoded = vec![tag];
+        if content.len() < 0x80 {
+            encoded.push(content.len() as u8);
+        } else {
+            encoded.extend([0x82, (content.len() >> 8) as u8, content.len() as u8]);
+        }
+        encoded.extend_from_slice(content);
+        encoded
+    }
+
+    #[test]
+    fn spki_is_found_after_version_and_subject() {
+        let spki = der(0x30, &[0x01; 200]);
+        let tbs = [
+            der(0xa0, &der(0x02, &[2])),
+            der(0x02, &[0x10, 0x20]),
+            der(0x30, &[]),
+            der(0x30, &[0x31, 0x00]),
+            der(0x30, &[]),
+            der(0x30, &[0x31, 0x00]),
+            spki.clone(),
+            der(0xa3, &[]),
+        ]
+        .concat();
+        let certificate = der(
+            0x30,
+            &[der(0x30, &tbs), der(0x30, &[]), der(0x03, &[0])].concat(),
+        );
+
+        assert_eq!(certificate_spki(&certificate), Some(spki.as_slice()));
+        assert_eq!(
+            certificate_spki(&certificate[..certificate.len() - 1]),
+            None
+        );
+        assert!(der_element(&[0x30, 0x85, 0, 0, 0, 0, 1], 0x30).is_none());
+    }
+}
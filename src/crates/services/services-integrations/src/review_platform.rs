@@ -46,6 +46,7 @@ const MAX_ISSUE_COMMENTS_RESPONSE_BYTES: usize = 8 * 1024 * 1024;
 const MAX_ISSUE_BODY_CHARS: usize = 128_000;
 const MAX_ISSUE_COMMENT_BODY_CHARS: usize = 32_000;
 const MAX_ISSUE_COMMENTS_AGGREGATE_CHARS: usize = 512_000;
+const MAX_REVIEW_COMMENTS_CONTEXT_CHARS: usize = 80_000;
 const DEFAULT_GH_OUTPUT_MAX_BYTES: usize = 16 * 1024 * 1024;
 const GH_ERROR_OUTPUT_MAX_CHARS: usize = 8 * 1024;
 
@@ -322,6 +323,37 @@ pub struct ReviewPlatformIssueEvidence {
     pub next_cursor: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewIssueStateFilter {
+    #[default]
+    Open,
+    Closed,
+    All,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformIssueSummary {
+    pub issue_id: String,
+    pub title: String,
+    pub state: String,
+    pub author: Option<String>,
+    pub labels: Vec<String>,
+    pub comments: i64,
+    pub web_url: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformIssuePage {
+    pub remote_id: String,
+    pub items: Vec<ReviewPlatformIssueSummary>,
+    pub pagination: ReviewPlatformPagination,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewPlatformCommit {
@@ -367,6 +399,17 @@ pub struct ReviewPlatformPullRequestDetail {
     pub threads: Vec<ReviewPlatformThread>,
 }
 
+/// Review threads of one pull request plus a Markdown rendering of them that
+/// can be handed to an agent as context.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformReviewComments {
+    pub pull_request: ReviewPlatformPullRequest,
+    pub threads: Vec<ReviewPlatformThread>,
+    pub context: String,
+    pub truncated: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ReviewPlatformDetailSection {
@@ -482,6 +525,37 @@ pub struct ReviewPlatformRequestChangesRequest {
     pub body: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformCreateBranchRequest {
+    pub repository_path: String,
+    pub branch_name: String,
+    /// Commit-ish the branch starts from; defaults to `HEAD`.
+    pub start_point: Option<String>,
+    /// Switches to the new branch; defaults to `true`.
+    pub checkout: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformPushBranchRequest {
+    pub repository_path: String,
+    pub remote_id: Option<String>,
+    /// Local branch to push; defaults to the checked-out branch.
+    pub branch_name: Option<String>,
+    /// Records the pushed branch as upstream; defaults to `true`.
+    pub set_upstream: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewPlatformBranchResult {
+    pub branch_name: String,
+    pub head_revision: String,
+    pub remote_name: Option<String>,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewPlatformActionResult {
@@ -528,11 +602,12 @@ pub struct ReviewPlatformWorkspaceSnapshot {
 
 /// Product-level workspace runtime access for review-platform Git probing.
 ///
-/// Review-platform only touches the workspace for repository discovery
-/// (`git rev-parse --show-toplevel`, `git remote -v`); provider data itself is
+/// Review-platform only touches the workspace through Git: repository
+/// discovery (`git rev-parse --show-toplevel`, `git remote -v`) and the branch
+/// operations (`git switch`, `git branch`, `git push`). Provider data itself is
 /// fetched over HTTP from the host running BitFun. Remote SSH workspaces are
 /// therefore fully supported as long as the product runtime can execute those
-/// Git probes on the remote host, which is what this port injects.
+/// Git commands on the remote host, which is what this port injects.
 #[async_trait::async_trait]
 pub trait ReviewPlatformWorkspaceClassifier: Send + Sync {
     /// True when `path` belongs to a remote workspace whose Git repository is
@@ -562,11 +637,28 @@ pub trait ReviewPlatformWorkspaceClassifier: Send + Sync {
     }
 }
 
+/// Secret storage for GitLab and GitCode access tokens.
+///
+/// When a vault is injected, the token store file only records which hosts
+/// have a token; the secret itself lives in the vault under the same
+/// `platform:host` key. Plaintext tokens left in the file by older builds are
+/// moved into the vault the next time tokens are loaded.
+#[async_trait::async_trait]
+pub trait ReviewPlatformTokenVault: Send + Sync {
+    async fn get_token(&self, key: &str) -> Result<Option<String>, ReviewPlatformError>;
+
+    async fn set_token(&self, key: &str, token: &str) -> Result<(), ReviewPlatformError>;
+
+    /// Deleting a key that does not exist is not an error.
+    async fn delete_token(&self, key: &str) -> Result<(), ReviewPlatformError>;
+}
+
 #[derive(Clone)]
 pub struct ReviewPlatformService {
     token_store_path: PathBuf,
     token_store_lock: Arc<AsyncMutex<()>>,
     workspace_classifier: Arc<dyn ReviewPlatformWorkspaceClassifier>,
+    token_vault: Option<Arc<dyn ReviewPlatformTokenVault>>,
 }
 
 /// Resolved Git execution scope for one workspace path.
@@ -715,8 +807,12 @@ struct StoredReviewPlatformTokens {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct StoredReviewPlatformToken {
+    /// Empty when the secret is kept in the token vault.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     token: String,
     updated_at: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    keychain: bool,
 }
 
 impl ReviewPlatformService {
@@ -733,9 +829,16 @@ impl ReviewPlatformService {
             token_store_path,
             token_store_lock,
             workspace_classifier,
+            token_vault: None,
         }
     }
 
+    /// Keeps access tokens in `token_vault` instead of the token store file.
+    pub fn with_token_vault(mut self, token_vault: Arc<dyn ReviewPlatformTokenVault>) -> Self {
+        self.token_vault = Some(token_vault);
+        self
+    }
+
     /// Creates a local-only owner for services tests and local Git-only hosts.
     pub fn new_local_only(token_store_path: PathBuf) -> Self {
         Self::new(
@@ -1098,6 +1201,36 @@ impl ReviewPlatformService {
         acquire_issue_evidence(&context, &identity, IssuePagination::new(page, per_page)).await
     }
 
+    /// Lists issues of the repository behind `remote_id`. Pull requests, which
+    /// GitHub returns from the same endpoint, are left out.
+    pub async fn list_issues(
+        &self,
+        repository_path: &str,
+        remote_id: Option<&str>,
+        state: ReviewIssueStateFilter,
+        page: Option<u32>,
+        per_page: Option<u32>,
+    ) -> Result<ReviewPlatformIssuePage, ReviewPlatformError> {
+        let ctx = self
+            .provider_context_for_repository(repository_path, remote_id)
+            .await?;
+        let pagination = PullRequestPagination::new(page, per_page);
+        let (items, pagination) = match ctx.remote.platform {
+            ReviewPlatformKind::Github => github_list_issues(&ctx, state, pagination).await?,
+            ReviewPlatformKind::Gitlab => gitlab_list_issues(&ctx, state, pagination).await?,
+            platform => {
+                return Err(ReviewPlatformError::UnsupportedPlatform(
+                    platform_label(platform).to_string(),
+                ));
+            }
+        };
+        Ok(ReviewPlatformIssuePage {
+            remote_id: ctx.remote.id,
+            items,
+            pagination,
+        })
+    }
+
     pub async fn pull_request_review_target_by_identity(
         &self,
         platform: ReviewPlatformKind,
@@ -1323,6 +1456,141 @@ impl ReviewPlatformService {
             .await
     }
 
+    /// Creates a local branch, switching to it unless `checkout` is `false`.
+    pub async fn create_branch(
+        &self,
+        request: ReviewPlatformCreateBranchRequest,
+    ) -> Result<ReviewPlatformBranchResult, ReviewPlatformError> {
+        let scope = self.workspace_git_scope(&request.repository_path).await?;
+        let branch_name = self
+            .checked_branch_name(&scope, &request.branch_name)
+            .await?;
+        let start_point = request
+            .start_point
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or("HEAD");
+        if start_point.starts_with('-') {
+            return Err(ReviewPlatformError::InvalidRepository(format!(
+                "Invalid start point: {start_point}"
+            )));
+        }
+        let checkout = request.checkout.unwrap_or(true);
+        let args = if checkout {
+            vec!["switch", "--create", branch_name.as_str(), start_point]
+        } else {
+            vec!["branch", branch_name.as_str(), start_point]
+        };
+        self.execute_scope_git_command(&scope, &args).await?;
+        let head_revision = self.branch_head_revision(&scope, &branch_name).await?;
+        Ok(ReviewPlatformBranchResult {
+            message: if checkout {
+                format!("Created and switched to branch {branch_name}")
+            } else {
+                format!("Created branch {branch_name}")
+            },
+            branch_name,
+            head_revision,
+            remote_name: None,
+        })
+    }
+
+    /// Pushes a local branch to the selected remote. Never force-pushes.
+    pub async fn push_branch(
+        &self,
+        request: ReviewPlatformPushBranchRequest,
+    ) -> Result<ReviewPlatformBranchResult, ReviewPlatformError> {
+        let auth_tokens = self.load_stored_tokens().await?;
+        let scope = self.workspace_git_scope(&request.repository_path).await?;
+        let remotes = self.discover_remotes_in_scope(&scope, &auth_tokens).await?;
+        let remote = select_remote_for_action(&remotes, request.remote_id.as_deref())?;
+        let branch_name = match request
+            .branch_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            Some(branch_name) => branch_name.to_string(),
+            None => self
+                .execute_scope_git_command(&scope, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+                .await
+                .map_err(|_| {
+                    ReviewPlatformError::InvalidRepository(
+                        "HEAD is detached; name the branch to push".to_string(),
+                    )
+                })?
+                .trim()
+                .to_string(),
+        };
+        let branch_name = self.checked_branch_name(&scope, &branch_name).await?;
+        let head_revision = self.branch_head_revision(&scope, &branch_name).await?;
+        let refspec = format!("refs/heads/{branch_name}:refs/heads/{branch_name}");
+        let mut args = vec!["push"];
+        if request.set_upstream.unwrap_or(true) {
+            args.push("--set-upstream");
+        }
+        args.push(remote.name.as_str());
+        args.push(refspec.as_str());
+        self.execute_scope_git_command(&scope, &args).await?;
+        Ok(ReviewPlatformBranchResult {
+            message: format!("Pushed {branch_name} to {}", remote.name),
+            branch_name,
+            head_revision,
+            remote_name: Some(remote.name.clone()),
+        })
+    }
+
+    /// Loads the review threads of a pull request and renders them as
+    /// Markdown for use as agent context.
+    pub async fn pull_request_review_comments(
+        &self,
+        repository_path: &str,
+        remote_id: &str,
+        pull_request_id: &str,
+        include_resolved: bool,
+    ) -> Result<ReviewPlatformReviewComments, ReviewPlatformError> {
+        let detail = self
+            .pull_request_detail(repository_path, remote_id, pull_request_id)
+            .await?;
+        Ok(review_comments_from_detail(detail, include_resolved))
+    }
+
+    async fn checked_branch_name(
+        &self,
+        scope: &WorkspaceGitScope,
+        branch_name: &str,
+    ) -> Result<String, ReviewPlatformError> {
+        let branch_name = branch_name_argument(branch_name)?;
+        self.execute_scope_git_command(
+            scope,
+            &["check-ref-format", &format!("refs/heads/{branch_name}")],
+        )
+        .await
+        .map_err(|_| {
+            ReviewPlatformError::InvalidRepository(format!("Invalid branch name: {branch_name}"))
+        })?;
+        Ok(branch_name)
+    }
+
+    async fn branch_head_revision(
+        &self,
+        scope: &WorkspaceGitScope,
+        branch_name: &str,
+    ) -> Result<String, ReviewPlatformError> {
+        let output = self
+            .execute_scope_git_command(
+                scope,
+                &[
+                    "rev-parse",
+                    "--verify",
+                    &format!("refs/heads/{branch_name}"),
+                ],
+            )
+            .await?;
+        Ok(output.trim().to_string())
+    }
+
     async fn provider_context_for_repository(
         &self,
         repository_path: &str,
@@ -1430,13 +1698,22 @@ impl ReviewPlatformService {
         stored.tokens.retain(|stored_key, _| {
             normalize_stored_token_key(stored_key).as_deref() != Some(key.as_str())
         });
-        stored.tokens.insert(
-            key,
-            StoredReviewPlatformToken {
+        let entry = match &self.token_vault {
+            Some(vault) => {
+                vault.set_token(&key, token).await?;
+                StoredReviewPlatformToken {
+                    token: String::new(),
+                    updated_at: chrono::Utc::now().to_rfc3339(),
+                    keychain: true,
+                }
+            }
+            None => StoredReviewPlatformToken {
                 token: token.to_string(),
                 updated_at: chrono::Utc::now().to_rfc3339(),
+                keychain: false,
             },
-        );
+        };
+        stored.tokens.insert(key, entry);
         self.save_stored_token_file_unlocked(&stored).await
     }
 
@@ -1459,6 +1736,9 @@ impl ReviewPlatformService {
         stored.tokens.retain(|stored_key, _| {
             normalize_stored_token_key(stored_key).as_deref() != Some(key.as_str())
         });
+        if let Some(vault) = &self.token_vault {
+            vault.delete_token(&key).await?;
+        }
         self.save_stored_token_file_unlocked(&stored).await
     }
 }
@@ -4040,6 +4320,83 @@ fn map_issue_comments_response(
     }
 }
 
+async fn github_list_issues(
+    ctx: &ProviderContext,
+    state: ReviewIssueStateFilter,
+    pagination: PullRequestPagination,
+) -> Result<(Vec<ReviewPlatformIssueSummary>, ReviewPlatformPagination), ReviewPlatformError> {
+    let url = format!(
+        "{}/repos/{}/{}/issues",
+        ctx.api_base_url, ctx.remote.owner, ctx.remote.repository_name
+    );
+    let state = match state {
+        ReviewIssueStateFilter::Open => "open",
+        ReviewIssueStateFilter::Closed => "closed",
+        ReviewIssueStateFilter::All => "all",
+    };
+    let value = github_api_get_json(
+        ctx,
+        &url,
+        &[
+            ("state".to_string(), state.to_string()),
+            ("page".to_string(), pagination.page.to_string()),
+            ("per_page".to_string(), pagination.per_page.to_string()),
+        ],
+        MAX_ISSUE_RESPONSE_BYTES,
+    )
+    .await?;
+    let values = value.as_array().ok_or_else(|| {
+        ReviewPlatformError::Parse("GitHub Issue list response was not an array".to_string())
+    })?;
+    // The page size counts pull requests too, so a full page means there may
+    // be more issues even if fewer than `per_page` survive the filter.
+    let has_next = values.len() == pagination.per_page as usize;
+    let items = values
+        .iter()
+        .filter_map(github_issue_summary_from_value)
+        .collect();
+    Ok((
+        items,
+        ReviewPlatformPagination {
+            page: pagination.page,
+            per_page: pagination.per_page,
+            total: None,
+            has_next,
+        },
+    ))
+}
+
+async fn gitlab_list_issues(
+    ctx: &ProviderContext,
+    state: ReviewIssueStateFilter,
+    pagination: PullRequestPagination,
+) -> Result<(Vec<ReviewPlatformIssueSummary>, ReviewPlatformPagination), ReviewPlatformError> {
+    let project = urlencoding::encode(&ctx.remote.project_path);
+    let url = format!("{}/projects/{}/issues", ctx.api_base_url, project);
+    let per_page = pagination.per_page.to_string();
+    let page = pagination.page.to_string();
+    let mut query = vec![("per_page", per_page.as_str()), ("page", page.as_str())];
+    match state {
+        ReviewIssueStateFilter::Open => query.push(("state", "opened")),
+        ReviewIssueStateFilter::Closed => query.push(("state", "closed")),
+        ReviewIssueStateFilter::All => {}
+    }
+    let response = send_json_response(
+        gitlab_request(http_client()?, &url, ctx.token.as_deref()).query(&query),
+    )
+    .await?;
+    let items = response
+        .value
+        .as_array()
+        .ok_or_else(|| {
+            ReviewPlatformError::Parse("GitLab Issue list response was not an array".to_string())
+        })?
+        .iter()
+        .map(gitlab_issue_summary_from_value)
+        .collect();
+    Ok((items, pagination_from_response(&response, pagination)))
+}
+
 fn provider_context(
     remote: ReviewPlatformRemote,
     auth_tokens: &ReviewPlatformAuthTokens,
@@ -4205,7 +4562,9 @@ fn canonicalize_stored_tokens(
             continue;
         }
         entry.token = entry.token.trim().to_string();
-        if entry.token.is_empty() {
+        if entry.keychain {
+            entry.token.clear();
+        } else if entry.token.is_empty() {
             continue;
         }
         let is_canonical = raw_key == canonical_key;
@@ -4922,25 +5281,47 @@ fn normalize_repository_root(root: &str) -> String {
 impl ReviewPlatformService {
     async fn load_stored_tokens(&self) -> Result<ReviewPlatformAuthTokens, ReviewPlatformError> {
         let _transaction = self.token_store_lock.lock().await;
-        let (stored, migrated) =
+        let (mut stored, mut migrated) =
             canonicalize_stored_tokens(self.load_stored_token_file_unlocked().await?);
+        let mut tokens = HashMap::new();
+        for (key, entry) in stored.tokens.iter_mut() {
+            let Some(key) = normalize_stored_token_key(key) else {
+                continue;
+            };
+            let token = match &self.token_vault {
+                Some(vault) if entry.keychain => match vault.get_token(&key).await {
+                    Ok(token) => token.unwrap_or_default(),
+                    Err(error) => {
+                        log::warn!("Failed to read review platform token for {key}: {error}");
+                        String::new()
+                    }
+                },
+                Some(vault) if !entry.token.is_empty() => {
+                    match vault.set_token(&key, &entry.token).await {
+                        Ok(()) => {
+                            entry.keychain = true;
+                            migrated = true;
+                            std::mem::take(&mut entry.token)
+                        }
+                        Err(error) => {
+                            log::warn!(
+                                "Keeping review platform token for {key} in the token file: {error}"
+                            );
+                            entry.token.clone()
+                        }
+                    }
+                }
+                _ => entry.token.clone(),
+            };
+            let token = token.trim().to_string();
+            if !token.is_empty() {
+                tokens.insert(key, token);
+            }
+        }
         if migrated {
             self.save_stored_token_file_unlocked(&stored).await?;
         }
-        Ok(ReviewPlatformAuthTokens {
-            tokens: stored
-                .tokens
-                .into_iter()
-                .filter_map(|(key, entry)| {
-                    let token = entry.token.trim().to_string();
-                    if token.is_empty() {
-                        None
-                    } else {
-                        normalize_stored_token_key(&key).map(|key| (key, token))
-                    }
-                })
-                .collect(),
-        })
+        Ok(ReviewPlatformAuthTokens { tokens })
     }
 
     async fn load_stored_token_file_unlocked(
@@ -6136,6 +6517,49 @@ fn reject_pull_request_issue_target(
     Ok(())
 }
 
+fn github_issue_summary_from_value(value: &Value) -> Option<ReviewPlatformIssueSummary> {
+    if value.get("pull_request").is_some() {
+        return None;
+    }
+    Some(ReviewPlatformIssueSummary {
+        issue_id: value_string(value, "number"),
+        title: value_string(value, "title"),
+        state: value_string(value, "state"),
+        author: nested_optional_string(value, &["user", "login"]),
+        labels: array_items(value.get("labels").unwrap_or(&Value::Null))
+            .iter()
+            .filter_map(|label| {
+                label
+                    .as_str()
+                    .map(str::to_string)
+                    .or_else(|| optional_string(label, "name"))
+            })
+            .collect(),
+        comments: value_i64(value, "comments"),
+        web_url: value_string(value, "html_url"),
+        created_at: optional_string(value, "created_at"),
+        updated_at: optional_string(value, "updated_at"),
+    })
+}
+
+fn gitlab_issue_summary_from_value(value: &Value) -> ReviewPlatformIssueSummary {
+    ReviewPlatformIssueSummary {
+        issue_id: value_string(value, "iid"),
+        title: value_string(value, "title"),
+        state: value_string(value, "state"),
+        author: nested_optional_string(value, &["author", "username"]),
+        labels: array_items(value.get("labels").unwrap_or(&Value::Null))
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        comments: value_i64(value, "user_notes_count"),
+        web_url: value_string(value, "web_url"),
+        created_at: optional_string(value, "created_at"),
+        updated_at: optional_string(value, "updated_at"),
+    }
+}
+
 fn map_gitlab_issue(
     identity: &ProviderIssueIdentity,
     issue: &Value,
@@ -6972,6 +7396,115 @@ fn gitcode_threads(value: &Value) -> Vec<ReviewPlatformThread> {
         .collect()
 }
 
+/// Rejects branch names Git would parse as an option or revision syntax.
+/// Everything else is left to `git check-ref-format`.
+fn branch_name_argument(branch_name: &str) -> Result<String, ReviewPlatformError> {
+    let branch_name = branch_name.trim();
+    let branch_name = branch_name
+        .strip_prefix("refs/heads/")
+        .unwrap_or(branch_name);
+    if branch_name.is_empty() || branch_name.starts_with('-') || branch_name == "HEAD" {
+        return Err(ReviewPlatformError::InvalidRepository(format!(
+            "Invalid branch name: {branch_name}"
+        )));
+    }
+    Ok(branch_name.to_string())
+}
+
+fn review_comments_from_detail(
+    detail: ReviewPlatformPullRequestDetail,
+    include_resolved: bool,
+) -> ReviewPlatformReviewComments {
+    let mut threads = detail
+        .threads
+        .into_iter()
+        .filter(|thread| include_resolved || !thread.resolved)
+        .filter(|thread| !thread.body.trim().is_empty())
+        .collect::<Vec<_>>();
+    // File comments first, grouped by location; general discussion last.
+    threads.sort_by(|left, right| {
+        (
+            left.file_path.is_none(),
+            left.file_path.as_deref(),
+            left.line,
+            left.updated_at.as_str(),
+        )
+            .cmp(&(
+                right.file_path.is_none(),
+                right.file_path.as_deref(),
+                right.line,
+                right.updated_at.as_str(),
+            ))
+    });
+    let (context, truncated) = render_review_comments_context(&detail.pull_request, &threads);
+    ReviewPlatformReviewComments {
+        pull_request: detail.pull_request,
+        threads,
+        context,
+        truncated,
+    }
+}
+
+fn render_review_comments_context(
+    pull_request: &ReviewPlatformPullRequest,
+    threads: &[ReviewPlatformThread],
+) -> (String, bool) {
+    let mut context = format!(
+        "# Review comments on #{} {}\n\n`{}` into `{}`",
+        pull_request.number,
+        pull_request.title,
+        pull_request.source_branch,
+        pull_request.target_branch
+    );
+    if !pull_request.web_url.is_empty() {
+        context.push_str(&format!(" ({})", pull_request.web_url));
+    }
+    context.push('\n');
+    if threads.is_empty() {
+        context.push_str("\nNo open review comments.\n");
+    }
+    let mut current_location = None;
+    for thread in threads {
+        let location = match (&thread.file_path, thread.line) {
+            (Some(path), Some(line)) => format!("{path}:{line}"),
+            (Some(path), None) => path.clone(),
+            (None, _) => "General discussion".to_string(),
+        };
+        if current_location.as_ref() != Some(&location) {
+            context.push_str(&format!("\n## {location}\n\n"));
+            current_location = Some(location);
+        }
+        let author = if thread.author.is_empty() {
+            "unknown"
+        } else {
+            thread.author.as_str()
+        };
+        context.push_str(&format!(
+            "- **{}**{}: {}\n",
+            author,
+            if thread.resolved { " (resolved)" } else { "" },
+            thread.body.trim().replace('\n', "\n  ")
+        ));
+    }
+
+    let char_count = context.chars().count();
+    if char_count <= MAX_REVIEW_COMMENTS_CONTEXT_CHARS {
+        return (context, false);
+    }
+    (
+        format!(
+            "{}\n\n[Review comments truncated: showing first {} of {} chars]\n",
+            context
+                .chars()
+                .take(MAX_REVIEW_COMMENTS_CONTEXT_CHARS)
+                .collect::<String>(),
+            MAX_REVIEW_COMMENTS_CONTEXT_CHARS,
+            char_count
+        ),
+        true,
+    )
+}
+
 fn empty_checks() -> ReviewChecks {
     ReviewChecks {
         total: 0,
@@ -7573,6 +8106,7 @@ mod tests {
                     StoredReviewPlatformToken {
                         token: "legacy-github-token".to_string(),
                         updated_at: "2026-07-14T00:00:00Z".to_string(),
+                        keychain: false,
                     },
                 ),
                 (
@@ -7580,6 +8114,7 @@ mod tests {
                     StoredReviewPlatformToken {
                         token: "gitlab-token".to_string(),
                         updated_at: "2026-07-14T00:00:00Z".to_string(),
+                        keychain: false,
                     },
                 ),
             ]),
@@ -7681,6 +8216,95 @@ mod tests {
         let _ = fs::remove_file(path).await;
     }
 
+    #[derive(Default)]
+    struct MemoryTokenVault {
+        tokens: StdMutex<HashMap<String, String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ReviewPlatformTokenVault for MemoryTokenVault {
+        async fn get_token(&self, key: &str) -> Result<Option<String>, ReviewPlatformError> {
+            Ok(self.tokens.lock().unwrap().get(key).cloned())
+        }
+
+        async fn set_token(&self, key: &str, token: &str) -> Result<(), ReviewPlatformError> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), token.to_string());
+            Ok(())
+        }
+
+        async fn delete_token(&self, key: &str) -> Result<(), ReviewPlatformError> {
+            self.tokens.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn token_vault_keeps_secrets_out_of_the_token_file() {
+        let path = temp_token_store_path("token-vault");
+        fs::write(
+            &path,
+            serde_json::to_vec(&json!({
+                "tokens": {
+                    "gitcode:gitcode.com": {
+                        "token": "legacy-token",
+                        "updatedAt": "2026-07-11T00:00:00Z"
+                    }
+                }
+            }))
+            .expect("plaintext token fixture should serialize"),
+        )
+        .await
+        .expect("plaintext token fixture should be written");
+        let vault = Arc::new(MemoryTokenVault::default());
+        let service =
+            ReviewPlatformService::new_local_only(path.clone()).with_token_vault(vault.clone());
+
+        let tokens = service
+            .load_stored_tokens()
+            .await
+            .expect("plaintext token should migrate");
+        assert_eq!(
+            tokens.get(ReviewPlatformKind::Gitcode, "gitcode.com"),
+            Some("legacy-token")
+        );
+        service
+            .update_auth_token(ReviewPlatformKind::Gitlab, "gitlab.com", "secret-token")
+            .await
+            .expect("token should save to the vault");
+
+        let content = fs::read_to_string(&path)
+            .await
+            .expect("token file should be readable");
+        assert!(!content.contains("legacy-token"));
+        assert!(!content.contains("secret-token"));
+        let tokens = service
+            .load_stored_tokens()
+            .await
+            .expect("vault tokens should load");
+        assert_eq!(
+            tokens.get(ReviewPlatformKind::Gitlab, "gitlab.com"),
+            Some("secret-token")
+        );
+        assert_eq!(
+            tokens.get(ReviewPlatformKind::Gitcode, "gitcode.com"),
+            Some("legacy-token")
+        );
+
+        service
+            .clear_auth_token(ReviewPlatformKind::Gitlab, "gitlab.com")
+            .await
+            .expect("token should clear");
+        assert!(!vault
+            .tokens
+            .lock()
+            .unwrap()
+            .contains_key("gitlab:gitlab.com"));
+        let _ = fs::remove_file(path).await;
+    }
+
     #[tokio::test]
     async fn loaded_legacy_token_authorities_are_normalized() {
         let path = temp_token_store_path("legacy-token-authority");
@@ -9291,6 +9915,125 @@ mod tests {
         assert!(matches!(result, Err(ReviewPlatformError::Parse(_))));
     }
 
+    #[test]
+    fn issue_list_mapping_skips_github_pull_requests() {
+        let issues = [
+            json!({
+                "number": 12,
+                "title": "Crash on start",
+                "state": "open",
+                "user": { "login": "octocat" },
+                "labels": [{ "name": "bug" }],
+                "comments": 3,
+                "html_url": "https://github.com/acme/repo/issues/12"
+            }),
+            json!({
+                "number": 13,
+                "title": "Fix crash",
+                "pull_request": { "url": "https://api.github.com/repos/acme/repo/pulls/13" }
+            }),
+        ];
+
+        let items = issues
+            .iter()
+            .filter_map(github_issue_summary_from_value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].issue_id, "12");
+        assert_eq!(items[0].author.as_deref(), Some("octocat"));
+        assert_eq!(items[0].labels, vec!["bug"]);
+        assert_eq!(items[0].comments, 3);
+
+        let gitlab = gitlab_issue_summary_from_value(&json!({
+            "id": 900,
+            "iid": 7,
+            "title": "Broken link",
+            "state": "opened",
+            "author": { "username": "alice" },
+            "labels": ["docs"],
+            "user_notes_count": 2,
+            "web_url": "https://gitlab.com/acme/repo/-/issues/7"
+        }));
+        assert_eq!(gitlab.issue_id, "7");
+        assert_eq!(gitlab.author.as_deref(), Some("alice"));
+        assert_eq!(gitlab.comments, 2);
+    }
+
+    #[test]
+    fn branch_name_argument_rejects_option_like_names() {
+        assert_eq!(
+            branch_name_argument(" refs/heads/fix/issue-12 ").unwrap(),
+            "fix/issue-12"
+        );
+        for bad in ["", "  ", "-f", "--force", "HEAD"] {
+            assert!(branch_name_argument(bad).is_err(), "accepted {bad:?}");
+        }
+    }
+
+    #[test]
+    fn review_comments_context_groups_open_threads_by_location() {
+        let pull_request = github_pull_request_from_value(&json!({
+            "number": 42,
+            "title": "Fix crash",
+            "state": "open",
+            "head": { "ref": "fix/crash" },
+            "base": { "ref": "main" },
+            "html_url": "https://github.com/acme/repo/pull/42"
+        }));
+        let general = github_thread_from_issue_comment(&json!({
+            "id": 1,
+            "user": { "login": "bob" },
+            "body": "Please add a test.",
+            "updated_at": "2026-07-01T00:00:00Z"
+        }));
+        let inline = github_thread_from_review_comment(&json!({
+            "id": 2,
+            "path": "src/main.rs",
+            "line": 10,
+            "user": { "login": "alice" },
+            "body": "Handle the error\ninstead of unwrapping.",
+            "updated_at": "2026-07-02T00:00:00Z"
+        }));
+        let mut resolved = github_thread_from_review_comment(&json!({
+            "id": 3,
+            "path": "src/lib.rs",
+            "line": 4,
+            "user": { "login": "alice" },
+            "body": "Typo.",
+            "updated_at": "2026-07-03T00:00:00Z"
+        }));
+        resolved.resolved = true;
+        let detail = ReviewPlatformPullRequestDetail {
+            pull_request,
+            body: String::new(),
+            ci: Vec::new(),
+            files: Vec::new(),
+            commits: Vec::new(),
+            threads: vec![general, inline, resolved],
+        };
+
+        let comments = review_comments_from_detail(detail.clone(), false);
+
+        assert_eq!(comments.threads.len(), 2);
+        assert!(!comments.truncated);
+        assert!(comments
+            .context
+            .starts_with("# Review comments on #42 Fix crash"));
+        let inline_at = comments.context.find("## src/main.rs:10").unwrap();
+        let general_at = comments.context.find("## General discussion").unwrap();
+        assert!(inline_at < general_at);
+        assert!(comments
+            .context
+            .contains("- **alice**: Handle the error\n  instead of unwrapping."));
+        assert!(!comments.context.contains("Typo."));
+
+        let with_resolved = review_comments_from_detail(detail, true);
+        assert!(with_resolved
+            .context
+            .contains("## src/lib.rs:4\n\n- **alice** (resolved): Typo."));
+    }
+
     #[test]
     fn issue_mapping_identity_plan_normalizes_public_hosts_and_rejects_unsafe_input() {
         let tokens = ReviewPlatformAuthTokens::default();
@@ -0,0 +1,107 @@
+//! System keychain storage for review-platform access tokens.
+//!
+//! Entries are keyed by the same `platform:host` key the token store file uses;
+//! the file keeps only metadata for tokens that live here.
+
+use crate::review_platform::{ReviewPlatformError, ReviewPlatformTokenVault};
+use std::sync::{Mutex, OnceLock};
+
+const KEYRING_SERVICE: &str = "openbitfun.bitfun.review-platform.v1";
+
+/// Token vault backed by the OS credential store (macOS Keychain, Windows
+/// Credential Manager, Linux Secret Service).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemKeychainTokenVault;
+
+#[async_trait::async_trait]
+impl ReviewPlatformTokenVault for SystemKeychainTokenVault {
+    async fn get_token(&self, key: &str) -> Result<Option<String>, ReviewPlatformError> {
+        let key = key.to_string();
+        run_keyring_task(move || {
+            let entry = open_native_keyring_entry(&key)?;
+            match entry.get_password() {
+                Ok(token) => Ok(Some(token)),
+                Err(keyring_core::Error::NoEntry) => Ok(None),
+                Err(error) => Err(format!("read system credential entry: {error}")),
+            }
+        })
+        .await
+    }
+
+    async fn set_token(&self, key: &str, token: &str) -> Result<(), ReviewPlatformError> {
+        let key = key.to_string();
+        let token = token.to_string();
+        run_keyring_task(move || {
+            let entry = open_native_keyring_entry(&key)?;
+            entry
+                .set_password(&token)
+                .map_err(|error| format!("write system credential entry: {error}"))
+        })
+        .await
+    }
+
+    async fn delete_token(&self, key: &str) -> Result<(), ReviewPlatformError> {
+        let key = key.to_string();
+        run_keyring_task(move || {
+            let entry = open_native_keyring_entry(&key)?;
+            match entry.delete_credential() {
+                Ok(()) | Err(keyring_core::Error::NoEntry) => Ok(()),
+                Err(error) => Err(format!("delete system credential entry: {error}")),
+            }
+        })
+        .await
+    }
+}
+
+fn native_keyring_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+async fn run_keyring_task<T, F>(task: F) -> Result<T, ReviewPlatformError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, String> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let _guard = native_keyring_lock()
+            .lock()
+            .map_err(|_| "review platform keyring lock poisoned".to_string())?;
+        task()
+    })
+    .await
+    .map_err(|error| ReviewPlatformError::Api(format!("System keychain task failed: {error}")))?
+    .map_err(|error| ReviewPlatformError::Api(format!("System keychain unavailable: {error}")))
+}
+
+fn open_native_keyring_entry(entry_name: &str) -> Result<keyring_core::Entry, String> {
+    if keyring_core::get_default_store().is_none() {
+        #[cfg(target_os = "macos")]
+        let store = apple_native_keyring_store::keychain::Store::new();
+        #[cfg(target_os = "windows")]
+        let store = windows_native_keyring_store::Store::new();
+        #[cfg(all(
+            unix,
+            not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+        ))]
+        let store = zbus_secret_service_keyring_store::Store::new();
+        #[cfg(not(any(
+            target_os = "macos",
+            target_os = "windows",
+            all(
+                unix,
+                not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+            )
+        )))]
+        let store: keyring_core::Result<std::sync::Arc<keyring_core::CredentialStore>> =
+            Err(keyring_core::Error::NoDefaultStore);
+
+        // A failed initialization is not cached, so a later call can still
+        // reach a Secret Service that started after BitFun.
+        let store =
+            store.map_err(|error| format!("initialize system credential store: {error}"))?;
+        keyring_core::set_default_store(store);
+    }
+    keyring_core::Entry::new(KEYRING_SERVICE, entry_name)
+        .map_err(|error| format!("open system credential entry: {error}"))
+}
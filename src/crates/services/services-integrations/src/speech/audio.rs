@@ -21,3 +21,43 @@ pub(super) fn pcm16_duration_seconds(byte_len: u64, sample_rate: u32) -> f64 {
     }
     byte_len as f64 / 2.0 / sample_rate as f64
 }
+
+/// Wraps mono PCM16 little-endian samples in a canonical 44-byte WAV header.
+pub(super) fn pcm16_le_to_wav(pcm16_le: &[u8], sample_rate: u32) -> Vec<u8> {
+    let data_len = pcm16_le.len() as u32;
+    let byte_rate = sample_rate * 2;
+    let mut wav = Vec::with_capacity(44 + pcm16_le.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&byte_rate.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.extend_from_slice(pcm16_le);
+    wav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wav_header_describes_mono_pcm16() {
+        let wav = pcm16_le_to_wav(&[1, 0, 2, 0], 16_000);
+        assert_eq!(wav.len(), 48);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()), 40);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 16_000);
+        assert_eq!(u32::from_le_bytes(wav[28..32].try_into().unwrap()), 32_000);
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 4);
+        assert_eq!(&wav[44..], &[1, 0, 2, 0]);
+    }
+}
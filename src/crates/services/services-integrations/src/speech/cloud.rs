@@ -0,0 +1,88 @@
+//! Transcription through an OpenAI-compatible `/audio/transcriptions` endpoint.
+
+use super::audio::{pcm16_duration_seconds, pcm16_le_to_wav};
+use super::types::SpeechTranscriptionResult;
+use super::{iso_language_code, BitFunError, BitFunResult};
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Endpoint details resolved from a `speech_recognition` model config.
+#[derive(Clone)]
+pub struct SpeechCloudEndpoint {
+    pub request_url: String,
+    pub api_key: String,
+    pub model_name: String,
+}
+
+impl fmt::Debug for SpeechCloudEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpeechCloudEndpoint")
+            .field("request_url", &self.request_url)
+            .field("model_name", &self.model_name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+}
+
+pub(super) async fn transcribe(
+    endpoint: &SpeechCloudEndpoint,
+    pcm16_le: &[u8],
+    sample_rate: u32,
+    language: &str,
+) -> BitFunResult<SpeechTranscriptionResult> {
+    let started = Instant::now();
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(15))
+        .timeout(Duration::from_secs(120))
+        .build()
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+
+    let file = Part::bytes(pcm16_le_to_wav(pcm16_le, sample_rate))
+        .file_name("audio.wav")
+        .mime_str("audio/wav")
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+    let mut form = Form::new()
+        .part("file", file)
+        .text("model", endpoint.model_name.clone())
+        .text("response_format", "json");
+    if let Some(code) = iso_language_code(language) {
+        form = form.text("language", code);
+    }
+
+    let mut request = client
+        .post(&endpoint.request_url)
+        .header(reqwest::header::USER_AGENT, "BitFun")
+        .multipart(form);
+    if !endpoint.api_key.is_empty() {
+        request = request.bearer_auth(&endpoint.api_key);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(BitFunError::Http(format!(
+            "Transcription request failed with {status}: {}",
+            body.trim()
+        )));
+    }
+    let body: TranscriptionResponse = response
+        .json()
+        .await
+        .map_err(|error| BitFunError::Http(format!("Invalid transcription response: {error}")))?;
+
+    Ok(SpeechTranscriptionResult {
+        text: body.text.trim().to_string(),
+        language: language.to_string(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        audio_duration_seconds: pcm16_duration_seconds(pcm16_le.len() as u64, sample_rate),
+    })
+}
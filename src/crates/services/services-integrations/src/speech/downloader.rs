@@ -4,15 +4,16 @@ use super::types::{
     SpeechModelStatus,
 };
 use super::{BitFunError, BitFunResult};
+use bitfun_services_core::bandwidth::global_download_limiter;
+use bitfun_services_core::hashing::{hash_file, HashAlgorithm, StreamingHasher};
 use bzip2::read::BzDecoder;
 use futures_util::StreamExt;
-use sha2::{Digest, Sha256};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tar::Archive;
 use tokio::fs;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::AsyncWriteExt;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
@@ -179,7 +180,7 @@ where
     let total_bytes = response.content_length().unwrap_or(artifact.size_bytes);
     let mut stream = response.bytes_stream();
     let mut file = fs::File::create(&partial_path).await?;
-    let mut hasher = Sha256::new();
+    let mut hasher = StreamingHasher::new(HashAlgorithm::Sha256);
     let mut downloaded = 0u64;
 
     loop {
@@ -213,6 +214,10 @@ where
             total_bytes: model_total_bytes,
             percent,
         });
+        tokio::select! {
+            _ = cancel.cancelled() => {}
+            _ = global_download_limiter().acquire(chunk.len() as u64) => {}
+        }
     }
     file.flush().await?;
     drop(file);
@@ -222,7 +227,7 @@ where
         return Err(download_cancelled_error(manifest));
     }
 
-    let actual_hash = format!("{:x}", hasher.finalize());
+    let actual_hash = hasher.finalize_hex();
     if actual_hash != artifact.sha256 {
         let _ = fs::remove_file(&partial_path).await;
         return Err(BitFunError::validation(format!(
@@ -328,17 +333,7 @@ async fn install_artifacts_into_staging(
 }
 
 async fn sha256_file(path: &Path) -> BitFunResult<String> {
-    let mut file = fs::File::open(path).await?;
-    let mut hasher = Sha256::new();
-    let mut buffer = vec![0u8; 1024 * 1024];
-    loop {
-        let read = file.read(&mut buffer).await?;
-        if read == 0 {
-            break;
-        }
-        hasher.update(&buffer[..read]);
-    }
-    Ok(format!("{:x}", hasher.finalize()))
+    Ok(hash_file(path, HashAlgorithm::Sha256, |_| {}).await?.hex)
 }
 
 fn extract_tar_bz2(archive_path: &Path, destination: &Path) -> BitFunResult<()> {
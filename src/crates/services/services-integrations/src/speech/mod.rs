@@ -1,6 +1,12 @@
-//! Local speech input services.
+//! Speech input and output services.
+//!
+//! Audio arrives from the frontend as PCM16 chunks and is transcribed by one
+//! of three backends: the built-in sherpa-onnx models, the managed whisper.cpp
+//! runtime, or an OpenAI-compatible cloud endpoint. [`TextToSpeechService`]
+//! covers the other direction.
 
 mod audio;
+mod cloud;
 mod downloader;
 mod error;
 mod model_catalog;
@@ -9,19 +15,27 @@ mod qwen3_asr_int8;
 mod recognizer;
 mod recognizer_router;
 mod sensevoice_int8;
+mod tts;
 mod types;
+mod whisper_cpp;
 
+pub use self::cloud::SpeechCloudEndpoint;
 use self::downloader::download_and_install_model;
 use self::model_catalog::get_builtin_speech_model_manifest;
 use self::model_store::SpeechModelStore;
 use self::recognizer::{SpeechRecognizer, SpeechRecognizerWarmupRequest};
 use self::recognizer_router::SpeechRecognizerRouter;
+pub use self::tts::{TextToSpeechBackend, TextToSpeechService, MAX_TEXT_TO_SPEECH_CHARS};
 use self::types::SpeechTranscribeRequest;
+use self::types::{SpeechModelManifest, SpeechRecognizerKind};
 pub use self::types::{
     DEFAULT_MAX_RECORDING_SECONDS, DEFAULT_SPEECH_SAMPLE_RATE, LOCAL_QWEN3_ASR_0_6B_INT8_MODEL_ID,
     LOCAL_QWEN3_ASR_0_6B_INT8_MODEL_REF, LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID,
     LOCAL_SENSEVOICE_SMALL_INT8_MODEL_REF,
 };
+pub use self::whisper_cpp::{
+    list_whisper_cpp_models, whisper_cpp_models_dir, WHISPER_CPP_COMMAND, WHISPER_CPP_COMPONENT,
+};
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
 pub use bitfun_core_types::speech::*;
@@ -29,6 +43,7 @@ pub use error::{SpeechError, SpeechResult};
 use error::{SpeechError as BitFunError, SpeechResult as BitFunResult};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
@@ -36,6 +51,9 @@ use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+/// Audio received since the last partial transcript before another is started.
+const PARTIAL_TRANSCRIPT_INTERVAL_SECONDS: u64 = 2;
+
 #[derive(Debug, Clone)]
 pub struct SpeechStoragePaths {
     models_dir: PathBuf,
@@ -79,11 +97,46 @@ struct SpeechModelDownloadControl {
     finished: CancellationToken,
 }
 
+/// Backend chosen by the caller for a new input session. The desktop layer
+/// resolves the managed runtime and model config; this service only runs them.
+#[derive(Debug, Clone)]
+pub enum SpeechInputBackend {
+    LocalModel,
+    WhisperCpp {
+        binary: PathBuf,
+        models_dir: PathBuf,
+    },
+    Cloud(SpeechCloudEndpoint),
+}
+
+#[derive(Debug, Clone)]
+enum SpeechSessionTranscriber {
+    LocalModel {
+        model_dir: PathBuf,
+        recognizer: SpeechRecognizerKind,
+    },
+    WhisperCpp {
+        binary: PathBuf,
+        model: PathBuf,
+    },
+    Cloud(SpeechCloudEndpoint),
+}
+
+impl SpeechSessionTranscriber {
+    /// Cloud requests are billed per call, so only the final audio is sent.
+    fn supports_partials(&self) -> bool {
+        !matches!(self, Self::Cloud(_))
+    }
+}
+
 #[derive(Debug)]
 struct SpeechInputSessionState {
     session: SpeechInputSession,
+    transcriber: SpeechSessionTranscriber,
     audio_path: PathBuf,
     received_bytes: u64,
+    partial_transcribed_bytes: u64,
+    partial_in_flight: Arc<AtomicBool>,
 }
 
 impl SpeechService {
@@ -215,18 +268,8 @@ impl SpeechService {
     pub async fn start_input_session(
         &self,
         request: SpeechStartInputSessionRequest,
+        backend: SpeechInputBackend,
     ) -> BitFunResult<SpeechInputSession> {
-        let model_id = request
-            .model_id
-            .unwrap_or_else(|| LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID.to_string());
-        let manifest = get_builtin_speech_model_manifest(&model_id)?;
-        if !self.store.has_required_files(&manifest).await {
-            return Err(BitFunError::NotFound(
-                "Speech model is not installed; download it before starting voice input"
-                    .to_string(),
-            ));
-        }
-
         let sample_rate = request.sample_rate.unwrap_or(DEFAULT_SPEECH_SAMPLE_RATE);
         if sample_rate == 0 {
             return Err(BitFunError::validation(
@@ -242,27 +285,60 @@ impl SpeechService {
             ));
         }
         let language = request.language.unwrap_or_else(|| "auto".to_string());
-        let model_dir = self.store.model_dir(&manifest);
-        let recognizer = Arc::clone(&self.recognizer);
-        let warmup_language = language.clone();
-        let warmup_model_id = model_id.clone();
-        let warmup_recognizer = manifest.recognizer;
-        tokio::spawn(async move {
-            if let Err(error) = recognizer
-                .warmup(SpeechRecognizerWarmupRequest {
-                    model_dir,
-                    recognizer: warmup_recognizer,
-                    language: warmup_language,
-                })
-                .await
-            {
-                log::warn!(
-                    "Failed to warm up speech recognizer: model_id={}, error={}",
-                    warmup_model_id,
-                    error
-                );
+
+        let (model_id, transcriber) = match backend {
+            SpeechInputBackend::LocalModel => {
+                let model_id = request
+                    .model_id
+                    .unwrap_or_else(|| LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID.to_string());
+                let manifest = get_builtin_speech_model_manifest(&model_id)?;
+                if !self.store.has_required_files(&manifest).await {
+                    return Err(BitFunError::NotFound(
+                        "Speech model is not installed; download it before starting voice input"
+                            .to_string(),
+                    ));
+                }
+                self.spawn_warmup(&manifest, &language);
+                let transcriber = SpeechSessionTranscriber::LocalModel {
+                    model_dir: self.store.model_dir(&manifest),
+                    recognizer: manifest.recognizer,
+                };
+                (model_id, transcriber)
             }
-        });
+            SpeechInputBackend::WhisperCpp { binary, models_dir } => {
+                let model_id = match request.model_id {
+                    Some(model_id) => model_id,
+                    None => list_whisper_cpp_models(&models_dir)
+                        .await?
+                        .into_iter()
+                        .next()
+                        .map(|model| model.model_id)
+                        .ok_or_else(|| {
+                            BitFunError::NotFound(format!(
+                                "No whisper.cpp models found in {}",
+                                models_dir.display()
+                            ))
+                        })?,
+                };
+                let model = whisper_cpp::model_path(&models_dir, &model_id)?;
+                if !model.is_file() {
+                    return Err(BitFunError::NotFound(format!(
+                        "whisper.cpp model is not installed: {}",
+                        model.display()
+                    )));
+                }
+                (
+                    model_id,
+                    SpeechSessionTranscriber::WhisperCpp { binary, model },
+                )
+            }
+            SpeechInputBackend::Cloud(endpoint) => {
+                let model_id = request
+                    .model_id
+                    .unwrap_or_else(|| endpoint.model_name.clone());
+                (model_id, SpeechSessionTranscriber::Cloud(endpoint))
+            }
+        };
 
         let session_id = Uuid::new_v4().to_string();
         let temp_dir = self.store.paths().input_temp_dir().to_path_buf();
@@ -272,6 +348,7 @@ impl SpeechService {
 
         let session = SpeechInputSession {
             session_id: session_id.clone(),
+            provider: request.provider,
             model_id,
             language,
             sample_rate,
@@ -281,17 +358,50 @@ impl SpeechService {
             session_id,
             SpeechInputSessionState {
                 session: session.clone(),
+                transcriber,
                 audio_path,
                 received_bytes: 0,
+                partial_transcribed_bytes: 0,
+                partial_in_flight: Arc::new(AtomicBool::new(false)),
             },
         );
         Ok(session)
     }
 
-    pub async fn append_audio_chunk(
+    fn spawn_warmup(&self, manifest: &SpeechModelManifest, language: &str) {
+        let model_dir = self.store.model_dir(manifest);
+        let recognizer = Arc::clone(&self.recognizer);
+        let warmup_language = language.to_string();
+        let warmup_model_id = manifest.id.clone();
+        let warmup_recognizer = manifest.recognizer;
+        tokio::spawn(async move {
+            if let Err(error) = recognizer
+                .warmup(SpeechRecognizerWarmupRequest {
+                    model_dir,
+                    recognizer: warmup_recognizer,
+                    language: warmup_language,
+                })
+                .await
+            {
+                log::warn!(
+                    "Failed to warm up speech recognizer: model_id={}, error={}",
+                    warmup_model_id,
+                    error
+                );
+            }
+        });
+    }
+
+    /// Appends audio and, every few seconds of new audio, transcribes the
+    /// recording so far in the background and reports it through `on_partial`.
+    pub async fn append_audio_chunk<F>(
         &self,
         request: SpeechAppendAudioChunkRequest,
-    ) -> BitFunResult<SpeechAppendAudioChunkResponse> {
+        on_partial: F,
+    ) -> BitFunResult<SpeechAppendAudioChunkResponse>
+    where
+        F: FnOnce(SpeechPartialTranscriptEvent) + Send + 'static,
+    {
         let bytes = BASE64_STANDARD
             .decode(request.pcm16_base64.as_bytes())
             .map_err(|e| BitFunError::validation(format!("Invalid base64 audio chunk: {e}")))?;
@@ -318,6 +428,17 @@ impl SpeechService {
             file.write_all(&bytes[..accepted_bytes]).await?;
             state.received_bytes += accepted_bytes as u64;
         }
+
+        let partial_interval_bytes =
+            state.session.sample_rate as u64 * PARTIAL_TRANSCRIPT_INTERVAL_SECONDS * 2;
+        if state.transcriber.supports_partials()
+            && state.received_bytes - state.partial_transcribed_bytes >= partial_interval_bytes
+            && !state.partial_in_flight.swap(true, Ordering::AcqRel)
+        {
+            state.partial_transcribed_bytes = state.received_bytes;
+            self.spawn_partial_transcript(state, on_partial);
+        }
+
         Ok(SpeechAppendAudioChunkResponse {
             received_bytes: state.received_bytes,
             received_seconds: audio::pcm16_duration_seconds(
@@ -338,22 +459,104 @@ impl SpeechService {
             .await
             .remove(&request.session_id)
             .ok_or_else(|| BitFunError::NotFound("Speech input session not found".to_string()))?;
-        let manifest = get_builtin_speech_model_manifest(&state.session.model_id)?;
         let pcm16_le = fs::read(&state.audio_path).await?;
         let _ = fs::remove_file(&state.audio_path).await;
         if pcm16_le.is_empty() {
             return Err(BitFunError::validation("No speech audio was captured"));
         }
 
-        self.recognizer
-            .transcribe(SpeechTranscribeRequest {
-                model_dir: self.store.model_dir(&manifest),
-                recognizer: manifest.recognizer,
-                pcm16_le,
-                sample_rate: state.session.sample_rate,
-                language: state.session.language,
-            })
-            .await
+        self.transcribe(
+            &state.transcriber,
+            pcm16_le,
+            state.session.sample_rate,
+            state.session.language,
+        )
+        .await
+    }
+
+    fn spawn_partial_transcript<F>(&self, state: &SpeechInputSessionState, on_partial: F)
+    where
+        F: FnOnce(SpeechPartialTranscriptEvent) + Send + 'static,
+    {
+        let service = self.clone();
+        let session_id = state.session.session_id.clone();
+        let transcriber = state.transcriber.clone();
+        let audio_path = state.audio_path.clone();
+        let sample_rate = state.session.sample_rate;
+        let language = state.session.language.clone();
+        let in_flight = Arc::clone(&state.partial_in_flight);
+        tokio::spawn(async move {
+            let result = async {
+                let mut pcm16_le = fs::read(&audio_path).await?;
+                pcm16_le.truncate(pcm16_le.len() & !1);
+                service
+                    .transcribe(&transcriber, pcm16_le, sample_rate, language)
+                    .await
+            }
+            .await;
+            in_flight.store(false, Ordering::Release);
+            match result {
+                // The session may have finished while this was running; its
+                // final result supersedes anything reported here.
+                Ok(result)
+                    if !result.text.is_empty()
+                        && service.sessions.lock().await.contains_key(&session_id) =>
+                {
+                    on_partial(SpeechPartialTranscriptEvent {
+                        session_id,
+                        text: result.text,
+                        audio_duration_seconds: result.audio_duration_seconds,
+                    });
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    log::debug!(
+                        "Partial speech transcription failed: session_id={}, error={}",
+                        session_id,
+                        error
+                    );
+                }
+            }
+        });
+    }
+
+    async fn transcribe(
+        &self,
+        transcriber: &SpeechSessionTranscriber,
+        pcm16_le: Vec<u8>,
+        sample_rate: u32,
+        language: String,
+    ) -> BitFunResult<SpeechTranscriptionResult> {
+        match transcriber {
+            SpeechSessionTranscriber::LocalModel {
+                model_dir,
+                recognizer,
+            } => {
+                self.recognizer
+                    .transcribe(SpeechTranscribeRequest {
+                        model_dir: model_dir.clone(),
+                        recognizer: *recognizer,
+                        pcm16_le,
+                        sample_rate,
+                        language,
+                    })
+                    .await
+            }
+            SpeechSessionTranscriber::WhisperCpp { binary, model } => {
+                whisper_cpp::transcribe(
+                    binary,
+                    model,
+                    self.store.paths().input_temp_dir(),
+                    &pcm16_le,
+                    sample_rate,
+                    &language,
+                )
+                .await
+            }
+            SpeechSessionTranscriber::Cloud(endpoint) => {
+                cloud::transcribe(endpoint, &pcm16_le, sample_rate, &language).await
+            }
+        }
     }
 
     pub async fn cancel_input_session(
@@ -367,6 +570,18 @@ impl SpeechService {
     }
 }
 
+/// Two-letter language code for backends that take ISO 639-1, or `None` for
+/// automatic detection. `zh-CN` and `en_US` style tags keep their base code.
+fn iso_language_code(language: &str) -> Option<String> {
+    let code = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    (!code.is_empty() && code != "auto").then_some(code)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -391,21 +606,32 @@ mod tests {
             SpeechInputSessionState {
                 session: SpeechInputSession {
                     session_id: session_id.clone(),
+                    provider: SpeechInputProvider::Local,
                     model_id: LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID.to_string(),
                     language: "auto".to_string(),
                     sample_rate: 2,
                     max_recording_seconds: 1,
                 },
+                transcriber: SpeechSessionTranscriber::Cloud(SpeechCloudEndpoint {
+                    request_url: String::new(),
+                    api_key: String::new(),
+                    model_name: String::new(),
+                }),
                 audio_path: audio_path.clone(),
                 received_bytes: 0,
+                partial_transcribed_bytes: 0,
+                partial_in_flight: Arc::new(AtomicBool::new(false)),
             },
         );
 
         let response = service
-            .append_audio_chunk(SpeechAppendAudioChunkRequest {
-                session_id,
-                pcm16_base64: BASE64_STANDARD.encode([1_u8, 2, 3, 4, 5, 6]),
-            })
+            .append_audio_chunk(
+                SpeechAppendAudioChunkRequest {
+                    session_id,
+                    pcm16_base64: BASE64_STANDARD.encode([1_u8, 2, 3, 4, 5, 6]),
+                },
+                |_| {},
+            )
             .await
             .unwrap();
 
@@ -416,4 +642,13 @@ mod tests {
 
         let _ = fs::remove_dir_all(root).await;
     }
+
+    #[test]
+    fn reduces_language_tags_to_iso_codes() {
+        assert_eq!(iso_language_code("zh-CN").as_deref(), Some("zh"));
+        assert_eq!(iso_language_code("en_US").as_deref(), Some("en"));
+        assert_eq!(iso_language_code("JA").as_deref(), Some("ja"));
+        assert_eq!(iso_language_code("auto"), None);
+        assert_eq!(iso_language_code(""), None);
+    }
 }
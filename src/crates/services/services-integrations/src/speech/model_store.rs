@@ -4,13 +4,12 @@ use super::types::{
     SpeechModelArtifact, SpeechModelInstallState, SpeechModelManifest, SpeechModelStatus,
 };
 use super::{BitFunError, BitFunResult, SpeechStoragePaths};
+use bitfun_services_core::hashing::{hash_file, HashAlgorithm};
 use chrono::Utc;
 use serde::Deserialize;
 use serde_json::json;
-use sha2::{Digest, Sha256};
 use std::path::{Component, Path, PathBuf};
 use tokio::fs;
-use tokio::io::AsyncReadExt;
 
 const INSTALL_RECORD_FILE: &str = "bitfun-model-install.json";
 
@@ -292,17 +291,7 @@ impl SpeechModelStore {
 }
 
 async fn sha256_file(path: &Path) -> BitFunResult<String> {
-    let mut file = fs::File::open(path).await?;
-    let mut hasher = Sha256::new();
-    let mut buffer = vec![0u8; 1024 * 1024];
-    loop {
-        let read = file.read(&mut buffer).await?;
-        if read == 0 {
-            break;
-        }
-        hasher.update(&buffer[..read]);
-    }
-    Ok(format!("{:x}", hasher.finalize()))
+    Ok(hash_file(path, HashAlgorithm::Sha256, |_| {}).await?.hex)
 }
 
 pub(super) fn validate_relative_archive_path(path: &Path) -> BitFunResult<PathBuf> {
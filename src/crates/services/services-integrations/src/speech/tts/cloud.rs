@@ -0,0 +1,97 @@
+//! Speech synthesis through an OpenAI-compatible `/audio/speech` endpoint.
+
+use super::super::{BitFunError, BitFunResult, SpeechCloudEndpoint};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use bitfun_core_types::speech::{TextToSpeechAudioClip, TextToSpeechVoice};
+use serde::Serialize;
+use std::time::Duration;
+
+pub(super) const DEFAULT_CLOUD_VOICE: &str = "alloy";
+
+/// Voices offered by the OpenAI speech API. Compatible providers accept a
+/// subset or their own names, so the id is still passed through as typed.
+const CLOUD_VOICES: &[&str] = &[
+    "alloy", "ash", "coral", "echo", "fable", "nova", "onyx", "sage", "shimmer",
+];
+
+#[derive(Serialize)]
+struct SpeechRequest<'a> {
+    model: &'a str,
+    input: &'a str,
+    voice: &'a str,
+    response_format: &'static str,
+    speed: f32,
+}
+
+pub(super) fn list_voices() -> Vec<TextToSpeechVoice> {
+    CLOUD_VOICES
+        .iter()
+        .map(|voice| TextToSpeechVoice {
+            id: voice.to_string(),
+            name: voice.to_string(),
+            language: None,
+        })
+        .collect()
+}
+
+pub(super) async fn synthesize(
+    endpoint: &SpeechCloudEndpoint,
+    text: &str,
+    voice: &str,
+    rate: f32,
+) -> BitFunResult<TextToSpeechAudioClip> {
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(15))
+        .timeout(Duration::from_secs(120))
+        .build()
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+
+    let mut request = client
+        .post(&endpoint.request_url)
+        .header(reqwest::header::USER_AGENT, "BitFun")
+        .json(&SpeechRequest {
+            model: &endpoint.model_name,
+            input: text,
+            voice,
+            response_format: "mp3",
+            // The API accepts 0.25 to 4.0.
+            speed: rate.clamp(0.25, 4.0),
+        });
+    if !endpoint.api_key.is_empty() {
+        request = request.bearer_auth(&endpoint.api_key);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(BitFunError::Http(format!(
+            "Speech request failed with {status}: {}",
+            body.trim()
+        )));
+    }
+    let mime_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| value.starts_with("audio/"))
+        .unwrap_or("audio/mpeg")
+        .to_string();
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|error| BitFunError::Http(error.to_string()))?;
+    if bytes.is_empty() {
+        return Err(BitFunError::Http(
+            "Speech request returned no audio".to_string(),
+        ));
+    }
+
+    Ok(TextToSpeechAudioClip {
+        mime_type,
+        data_base64: BASE64_STANDARD.encode(&bytes),
+    })
+}
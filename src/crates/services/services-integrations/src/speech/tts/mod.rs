@@ -0,0 +1,217 @@
+//! Text-to-speech for reading summaries and notifications aloud.
+//!
+//! System voices play natively and report progress through state events.
+//! Cloud voices are synthesized here and returned as an audio clip for the
+//! webview to play, since only the webview knows when playback ends.
+
+mod cloud;
+mod system;
+
+use super::{BitFunError, BitFunResult, SpeechCloudEndpoint};
+use bitfun_core_types::speech::{
+    TextToSpeechListVoicesResponse, TextToSpeechProvider, TextToSpeechSpeakResult,
+    TextToSpeechStateEvent, TextToSpeechUtteranceState,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// Longer text is cut at this many characters before it is spoken.
+pub const MAX_TEXT_TO_SPEECH_CHARS: usize = 4096;
+
+/// Backend chosen by the caller; the desktop layer resolves the model config.
+#[derive(Debug, Clone)]
+pub enum TextToSpeechBackend {
+    System,
+    Cloud(SpeechCloudEndpoint),
+}
+
+impl TextToSpeechBackend {
+    pub fn provider(&self) -> TextToSpeechProvider {
+        match self {
+            Self::System => TextToSpeechProvider::System,
+            Self::Cloud(_) => TextToSpeechProvider::Cloud,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ActiveUtterance {
+    utterance_id: String,
+    cancel: CancellationToken,
+}
+
+/// Plays one utterance at a time; speaking again interrupts the current one.
+#[derive(Clone, Default)]
+pub struct TextToSpeechService {
+    active: Arc<Mutex<Option<ActiveUtterance>>>,
+}
+
+impl TextToSpeechService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn list_voices(
+        &self,
+        backend: &TextToSpeechBackend,
+    ) -> BitFunResult<TextToSpeechListVoicesResponse> {
+        let voices = match backend {
+            TextToSpeechBackend::System => system::list_voices().await?,
+            TextToSpeechBackend::Cloud(_) => cloud::list_voices(),
+        };
+        Ok(TextToSpeechListVoicesResponse {
+            provider: backend.provider(),
+            voices,
+        })
+    }
+
+    /// Starts speaking `text`. For system voices this returns once playback
+    /// has been scheduled and `on_state` reports the rest; for cloud voices it
+    /// returns the synthesized clip.
+    pub async fn speak<F>(
+        &self,
+        text: &str,
+        voice: Option<String>,
+        rate: f32,
+        backend: TextToSpeechBackend,
+        on_state: F,
+    ) -> BitFunResult<TextToSpeechSpeakResult>
+    where
+        F: Fn(TextToSpeechStateEvent) + Send + Sync + 'static,
+    {
+        let text = prepare_text(text)?;
+        let rate = if rate.is_finite() && rate > 0.0 {
+            rate
+        } else {
+            1.0
+        };
+        self.stop_speaking().await;
+
+        let utterance_id = Uuid::new_v4().to_string();
+        let provider = backend.provider();
+        match backend {
+            TextToSpeechBackend::System => {
+                let cancel = CancellationToken::new();
+                *self.active.lock().await = Some(ActiveUtterance {
+                    utterance_id: utterance_id.clone(),
+                    cancel: cancel.clone(),
+                });
+                self.spawn_system_utterance(
+                    utterance_id.clone(),
+                    text,
+                    voice.clone(),
+                    rate,
+                    cancel,
+                    on_state,
+                );
+                Ok(TextToSpeechSpeakResult {
+                    utterance_id,
+                    provider,
+                    voice,
+                    audio: None,
+                })
+            }
+            TextToSpeechBackend::Cloud(endpoint) => {
+                let voice = voice.unwrap_or_else(|| cloud::DEFAULT_CLOUD_VOICE.to_string());
+                let audio = cloud::synthesize(&endpoint, &text, &voice, rate).await?;
+                Ok(TextToSpeechSpeakResult {
+                    utterance_id,
+                    provider,
+                    voice: Some(voice),
+                    audio: Some(audio),
+                })
+            }
+        }
+    }
+
+    /// Stops the system utterance in progress, returning its id. Its task
+    /// reports the `stopped` state.
+    pub async fn stop_speaking(&self) -> Option<String> {
+        let active = self.active.lock().await.take()?;
+        active.cancel.cancel();
+        Some(active.utterance_id)
+    }
+
+    fn spawn_system_utterance<F>(
+        &self,
+        utterance_id: String,
+        text: String,
+        voice: Option<String>,
+        rate: f32,
+        cancel: CancellationToken,
+        on_state: F,
+    ) where
+        F: Fn(TextToSpeechStateEvent) + Send + Sync + 'static,
+    {
+        let active = Arc::clone(&self.active);
+        tokio::spawn(async move {
+            on_state(TextToSpeechStateEvent {
+                utterance_id: utterance_id.clone(),
+                state: TextToSpeechUtteranceState::Started,
+                error: None,
+            });
+            let result = system::speak(&text, voice.as_deref(), rate, cancel.clone()).await;
+
+            {
+                let mut active = active.lock().await;
+                if active
+                    .as_ref()
+                    .is_some_and(|current| current.utterance_id == utterance_id)
+                {
+                    *active = None;
+                }
+            }
+            let (state, error) = match result {
+                _ if cancel.is_cancelled() => (TextToSpeechUtteranceState::Stopped, None),
+                Ok(()) => (TextToSpeechUtteranceState::Finished, None),
+                Err(error) => {
+                    log::warn!(
+                        "System text-to-speech failed: utterance_id={}, error={}",
+                        utterance_id,
+                        error
+                    );
+                    (TextToSpeechUtteranceState::Failed, Some(error.to_string()))
+                }
+            };
+            on_state(TextToSpeechStateEvent {
+                utterance_id,
+                state,
+                error,
+            });
+        });
+    }
+}
+
+fn prepare_text(text: &str) -> BitFunResult<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(BitFunError::validation("Nothing to speak"));
+    }
+    Ok(match text.char_indices().nth(MAX_TEXT_TO_SPEECH_CHARS) {
+        Some((end, _)) => text[..end].to_string(),
+        None => text.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_and_caps_spoken_text() {
+        assert!(prepare_text("  \n ").is_err());
+        assert_eq!(prepare_text("  done \n").unwrap(), "done");
+        let long = "语".repeat(MAX_TEXT_TO_SPEECH_CHARS + 10);
+        assert_eq!(
+            prepare_text(&long).unwrap().chars().count(),
+            MAX_TEXT_TO_SPEECH_CHARS
+        );
+    }
+
+    #[tokio::test]
+    async fn stop_without_active_utterance_is_a_no_op() {
+        assert_eq!(TextToSpeechService::new().stop_speaking().await, None);
+    }
+}
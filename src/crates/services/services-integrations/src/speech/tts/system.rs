@@ -0,0 +1,330 @@
+//! Operating-system voices: `say` on macOS, System.Speech through PowerShell
+//! on Windows, and espeak-ng or speech-dispatcher on Linux.
+//!
+//! Text is always written to the child's stdin so it never passes through an
+//! argument list or a shell.
+
+use super::super::{BitFunError, BitFunResult};
+use bitfun_core_types::speech::TextToSpeechVoice;
+use bitfun_services_core::process_manager::create_tokio_command;
+use std::process::Stdio;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+
+/// Words per minute that macOS and espeak treat as normal speed.
+const BASE_WORDS_PER_MINUTE: f32 = 175.0;
+
+#[cfg(windows)]
+const WINDOWS_SPEAK_SCRIPT: &str = "[Console]::InputEncoding = [System.Text.Encoding]::UTF8; \
+Add-Type -AssemblyName System.Speech; \
+$s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+if ($env:BITFUN_TTS_VOICE) { $s.SelectVoice($env:BITFUN_TTS_VOICE) }; \
+$s.Rate = [int]$env:BITFUN_TTS_RATE; \
+$s.Speak([Console]::In.ReadToEnd())";
+
+#[cfg(windows)]
+const WINDOWS_VOICES_SCRIPT: &str = "[Console]::OutputEncoding = [System.Text.Encoding]::UTF8; \
+Add-Type -AssemblyName System.Speech; \
+(New-Object System.Speech.Synthesis.SpeechSynthesizer).GetInstalledVoices() | \
+ForEach-Object { $_.VoiceInfo.Name + '|' + $_.VoiceInfo.Culture.Name }";
+
+struct SystemSpeechCommand {
+    program: &'static str,
+    args: Vec<String>,
+    envs: Vec<(&'static str, String)>,
+}
+
+/// Speaks `text` and resolves when playback ends or `cancel` fires. The child
+/// is killed on cancellation.
+pub(super) async fn speak(
+    text: &str,
+    voice: Option<&str>,
+    rate: f32,
+    cancel: CancellationToken,
+) -> BitFunResult<()> {
+    let mut last_error = None;
+    for candidate in speak_commands(voice, rate) {
+        let mut command = create_tokio_command(candidate.program);
+        command
+            .args(&candidate.args)
+            .envs(candidate.envs.iter().map(|(key, value)| (*key, value)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                last_error = Some(format!("{} is not installed", candidate.program));
+                continue;
+            }
+            Err(error) => {
+                return Err(BitFunError::service(format!(
+                    "Failed to start {}: {error}",
+                    candidate.program
+                )));
+            }
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes()).await?;
+            drop(stdin);
+        }
+
+        let status = tokio::select! {
+            _ = cancel.cancelled() => None,
+            status = child.wait() => Some(status?),
+        };
+        let Some(status) = status else {
+            let _ = child.kill().await;
+            return Ok(());
+        };
+        if status.success() {
+            return Ok(());
+        }
+        let mut stderr = String::new();
+        if let Some(mut pipe) = child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr).await;
+        }
+        return Err(BitFunError::service(format!(
+            "{} exited with {status}: {}",
+            candidate.program,
+            stderr.trim()
+        )));
+    }
+    Err(BitFunError::NotFound(last_error.unwrap_or_else(|| {
+        "No system text-to-speech engine is available".to_string()
+    })))
+}
+
+#[cfg(target_os = "macos")]
+pub(super) async fn list_voices() -> BitFunResult<Vec<TextToSpeechVoice>> {
+    let stdout = run_for_stdout("say", &["-v", "?"]).await?;
+    Ok(parse_say_voices(&stdout))
+}
+
+#[cfg(windows)]
+pub(super) async fn list_voices() -> BitFunResult<Vec<TextToSpeechVoice>> {
+    let stdout = run_for_stdout(
+        "powershell",
+        &[
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            WINDOWS_VOICES_SCRIPT,
+        ],
+    )
+    .await?;
+    Ok(parse_windows_voices(&stdout))
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+pub(super) async fn list_voices() -> BitFunResult<Vec<TextToSpeechVoice>> {
+    match run_for_stdout("espeak-ng", &["--voices"]).await {
+        Ok(stdout) => Ok(parse_espeak_voices(&stdout)),
+        // speech-dispatcher has no portable voice listing; its default voice
+        // follows the language.
+        Err(BitFunError::NotFound(_)) => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg_attr(windows, allow(dead_code))]
+fn words_per_minute(rate: f32) -> u32 {
+    (BASE_WORDS_PER_MINUTE * rate).round().clamp(80.0, 500.0) as u32
+}
+
+#[cfg(target_os = "macos")]
+fn speak_commands(voice: Option<&str>, rate: f32) -> Vec<SystemSpeechCommand> {
+    let mut args = vec!["-r".to_string(), words_per_minute(rate).to_string()];
+    if let Some(voice) = voice {
+        args.extend(["-v".to_string(), voice.to_string()]);
+    }
+    args.extend(["-f".to_string(), "-".to_string()]);
+    vec![SystemSpeechCommand {
+        program: "say",
+        args,
+        envs: Vec::new(),
+    }]
+}
+
+#[cfg(windows)]
+fn speak_commands(voice: Option<&str>, rate: f32) -> Vec<SystemSpeechCommand> {
+    // SpeechSynthesizer.Rate runs from -10 to 10 with 0 as normal speed.
+    let sapi_rate = ((rate - 1.0) * 10.0).round().clamp(-10.0, 10.0) as i32;
+    vec![SystemSpeechCommand {
+        program: "powershell",
+        args: [
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            WINDOWS_SPEAK_SCRIPT,
+        ]
+        .map(str::to_string)
+        .to_vec(),
+        envs: vec![
+            ("BITFUN_TTS_VOICE", voice.unwrap_or_default().to_string()),
+            ("BITFUN_TTS_RATE", sapi_rate.to_string()),
+        ],
+    }]
+}
+
+/// Tries espeak-ng first and falls back to speech-dispatcher.
+#[cfg(not(any(target_os = "macos", windows)))]
+fn speak_commands(voice: Option<&str>, rate: f32) -> Vec<SystemSpeechCommand> {
+    let mut espeak_args = vec![
+        "--stdin".to_string(),
+        "-s".to_string(),
+        words_per_minute(rate).to_string(),
+    ];
+    if let Some(voice) = voice {
+        espeak_args.extend(["-v".to_string(), voice.to_string()]);
+    }
+    // spd-say takes -100..100 with 0 as normal speed.
+    let spd_rate = ((rate - 1.0) * 100.0).round().clamp(-100.0, 100.0) as i32;
+    let mut spd_args = vec![
+        "--wait".to_string(),
+        "--pipe-mode".to_string(),
+        "-r".to_string(),
+        spd_rate.to_string(),
+    ];
+    if let Some(voice) = voice {
+        spd_args.extend(["-l".to_string(), voice.to_string()]);
+    }
+    vec![
+        SystemSpeechCommand {
+            program: "espeak-ng",
+            args: espeak_args,
+            envs: Vec::new(),
+        },
+        SystemSpeechCommand {
+            program: "spd-say",
+            args: spd_args,
+            envs: Vec::new(),
+        },
+    ]
+}
+
+async fn run_for_stdout(program: &str, args: &[&str]) -> BitFunResult<String> {
+    let output = create_tokio_command(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .map_err(|error| {
+            if error.kind() == std::io::ErrorKind::NotFound {
+                BitFunError::NotFound(format!("{program} is not installed"))
+            } else {
+                BitFunError::service(format!("Failed to run {program}: {error}"))
+            }
+        })?;
+    if !output.status.success() {
+        return Err(BitFunError::service(format!(
+            "{program} exited with {}",
+            output.status
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses `say -v ?`, whose lines look like
+/// `Bad News            en_US    # The light you see…`.
+#[cfg_attr(not(any(target_os = "macos", test)), allow(dead_code))]
+fn parse_say_voices(stdout: &str) -> Vec<TextToSpeechVoice> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let head = line.split('#').next()?.trim_end();
+            let (name, locale) = head.rsplit_once(char::is_whitespace)?;
+            let name = name.trim();
+            (!name.is_empty()).then(|| TextToSpeechVoice {
+                id: name.to_string(),
+                name: name.to_string(),
+                language: Some(locale.replace('_', "-")),
+            })
+        })
+        .collect()
+}
+
+/// Parses the `Name|Culture` lines printed by [`WINDOWS_VOICES_SCRIPT`].
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+fn parse_windows_voices(stdout: &str) -> Vec<TextToSpeechVoice> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, culture) = line.trim().split_once('|')?;
+            (!name.is_empty()).then(|| TextToSpeechVoice {
+                id: name.to_string(),
+                name: name.to_string(),
+                language: (!culture.is_empty()).then(|| culture.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Parses `espeak-ng --voices`:
+/// `Pty Language       Age/Gender VoiceName          File          Other Languages`.
+#[cfg_attr(any(target_os = "macos", windows), allow(dead_code))]
+fn parse_espeak_voices(stdout: &str) -> Vec<TextToSpeechVoice> {
+    stdout
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns = line.split_whitespace().collect::<Vec<_>>();
+            let language = *columns.get(1)?;
+            let name = columns.get(3).copied().unwrap_or(language);
+            Some(TextToSpeechVoice {
+                id: language.to_string(),
+                name: name.replace('_', " "),
+                language: Some(language.to_string()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_macos_voice_list() {
+        let voices = parse_say_voices(
+            "Alex                en_US    # Most people recognize me by my voice.\n\
+             Bad News            en_US    # The light you see at the end of the tunnel.\n\
+             Ting-Ting           zh_CN    # 你好，我叫婷婷。\n",
+        );
+        assert_eq!(voices.len(), 3);
+        assert_eq!(voices[1].id, "Bad News");
+        assert_eq!(voices[2].language.as_deref(), Some("zh-CN"));
+    }
+
+    #[test]
+    fn parses_windows_voice_list() {
+        let voices = parse_windows_voices("Microsoft Zira Desktop|en-US\r\nbroken\r\n");
+        assert_eq!(
+            voices,
+            vec![TextToSpeechVoice {
+                id: "Microsoft Zira Desktop".to_string(),
+                name: "Microsoft Zira Desktop".to_string(),
+                language: Some("en-US".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_espeak_voice_list() {
+        let voices = parse_espeak_voices(
+            "Pty Language       Age/Gender VoiceName          File                 Other Languages\n \
+             5  en-us           --/M      English_(America)  gmw/en-US            (en 3)\n",
+        );
+        assert_eq!(voices[0].id, "en-us");
+        assert_eq!(voices[0].name, "English (America)");
+    }
+
+    #[test]
+    fn clamps_speaking_rate() {
+        assert_eq!(words_per_minute(1.0), 175);
+        assert_eq!(words_per_minute(0.1), 80);
+        assert_eq!(words_per_minute(10.0), 500);
+    }
+}
@@ -0,0 +1,174 @@
+//! Transcription through the managed whisper.cpp runtime component.
+//!
+//! The component lives under `runtimes/whisper-cpp/current`: `whisper-cli` is
+//! resolved like any other managed command, and GGML model files are read from
+//! its `models/` folder.
+
+use super::audio::pcm16_le_to_wav;
+use super::types::SpeechTranscriptionResult;
+use super::{iso_language_code, BitFunError, BitFunResult};
+use bitfun_core_types::speech::SpeechWhisperCppModel;
+use bitfun_services_core::process_manager::create_tokio_command;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::fs;
+use uuid::Uuid;
+
+pub const WHISPER_CPP_COMPONENT: &str = "whisper-cpp";
+pub const WHISPER_CPP_COMMAND: &str = "whisper-cli";
+
+const MODEL_FILE_PREFIX: &str = "ggml-";
+const MODEL_FILE_EXTENSION: &str = "bin";
+const TRANSCRIBE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+pub fn whisper_cpp_models_dir(runtime_root: &Path) -> PathBuf {
+    runtime_root
+        .join(WHISPER_CPP_COMPONENT)
+        .join("current")
+        .join("models")
+}
+
+/// `ggml-<id>.bin` files in `models_dir`, sorted by id.
+pub async fn list_whisper_cpp_models(
+    models_dir: &Path,
+) -> BitFunResult<Vec<SpeechWhisperCppModel>> {
+    let mut models = Vec::new();
+    let mut entries = match fs::read_dir(models_dir).await {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(models),
+        Err(error) => return Err(error.into()),
+    };
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let Some(model_id) = model_id_from_path(&path) else {
+            continue;
+        };
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            models.push(SpeechWhisperCppModel {
+                model_id,
+                path,
+                size_bytes: metadata.len(),
+            });
+        }
+    }
+    models.sort_by(|a, b| a.model_id.cmp(&b.model_id));
+    Ok(models)
+}
+
+pub(super) fn model_path(models_dir: &Path, model_id: &str) -> BitFunResult<PathBuf> {
+    if model_id.is_empty()
+        || !model_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        || model_id.contains("..")
+    {
+        return Err(BitFunError::validation(format!(
+            "Invalid whisper.cpp model id: {model_id}"
+        )));
+    }
+    Ok(models_dir.join(format!(
+        "{MODEL_FILE_PREFIX}{model_id}.{MODEL_FILE_EXTENSION}"
+    )))
+}
+
+fn model_id_from_path(path: &Path) -> Option<String> {
+    if path.extension()? != MODEL_FILE_EXTENSION {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let model_id = stem.strip_prefix(MODEL_FILE_PREFIX)?;
+    (!model_id.is_empty()).then(|| model_id.to_string())
+}
+
+pub(super) async fn transcribe(
+    binary: &Path,
+    model: &Path,
+    temp_dir: &Path,
+    pcm16_le: &[u8],
+    sample_rate: u32,
+    language: &str,
+) -> BitFunResult<SpeechTranscriptionResult> {
+    let started = Instant::now();
+    fs::create_dir_all(temp_dir).await?;
+    let wav_path = temp_dir.join(format!("whisper-{}.wav", Uuid::new_v4().simple()));
+    fs::write(&wav_path, pcm16_le_to_wav(pcm16_le, sample_rate)).await?;
+
+    let output = create_tokio_command(binary)
+        .arg("-m")
+        .arg(model)
+        .arg("-f")
+        .arg(&wav_path)
+        .arg("-l")
+        .arg(iso_language_code(language).unwrap_or_else(|| "auto".to_string()))
+        .args(["-nt", "-np"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .output();
+    let output = tokio::time::timeout(TRANSCRIBE_TIMEOUT, output).await;
+    let _ = fs::remove_file(&wav_path).await;
+    let output = output
+        .map_err(|_| BitFunError::service("whisper.cpp transcription timed out"))?
+        .map_err(|error| BitFunError::service(format!("Failed to run whisper.cpp: {error}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(BitFunError::service(format!(
+            "whisper.cpp exited with {}: {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    Ok(SpeechTranscriptionResult {
+        text: parse_transcript(&String::from_utf8_lossy(&output.stdout)),
+        language: language.to_string(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        audio_duration_seconds: super::audio::pcm16_duration_seconds(
+            pcm16_le.len() as u64,
+            sample_rate,
+        ),
+    })
+}
+
+/// whisper-cli prints one line per segment; blank-audio markers are dropped.
+fn parse_transcript(stdout: &str) -> String {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && *line != "[BLANK_AUDIO]")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_model_ids_to_ggml_files() {
+        let dir = Path::new("/models");
+        assert_eq!(
+            model_path(dir, "base.en").unwrap(),
+            dir.join("ggml-base.en.bin")
+        );
+        assert!(model_path(dir, "../base").is_err());
+        assert!(model_path(dir, "a/b").is_err());
+        assert_eq!(
+            model_id_from_path(Path::new("/models/ggml-large-v3-turbo.bin")).as_deref(),
+            Some("large-v3-turbo")
+        );
+        assert_eq!(model_id_from_path(Path::new("/models/readme.bin")), None);
+        assert_eq!(model_id_from_path(Path::new("/models/ggml-.bin")), None);
+    }
+
+    #[test]
+    fn joins_segments_and_drops_blank_markers() {
+        assert_eq!(
+            parse_transcript("\n [BLANK_AUDIO]\n Hello there.\n How are you?\n"),
+            "Hello there. How are you?"
+        );
+    }
+}
@@ -1,12 +1,29 @@
 //! Network providers for built-in web tools.
 
+mod robots;
+
+use bitfun_core_types::ProxyConfig;
+use log::debug;
+use robots::RobotsRules;
 use serde::Deserialize;
 use serde_json::json;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 const USER_AGENT_VALUE: &str = "BitFun/1.0";
+/// Product token matched against robots.txt `User-agent` lines.
+const ROBOTS_AGENT_TOKEN: &str = "bitfun";
 const WEB_FETCH_TIMEOUT_SECS: u64 = 30;
+const WEB_FETCH_MAX_BYTES: usize = 5 * 1024 * 1024;
+const WEB_FETCH_CACHE_TTL_SECS: u64 = 15 * 60;
+const WEB_CONNECT_TIMEOUT_SECS: u64 = 10;
+const MAX_REDIRECTS: usize = 10;
+const RESPONSE_CACHE_CAPACITY: usize = 64;
+const ROBOTS_MAX_BYTES: usize = 512 * 1024;
+const ROBOTS_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
 const EXA_URL: &str = "https://mcp.exa.ai/mcp";
 const EXA_TIMEOUT_SECS: u64 = 25;
 
@@ -14,6 +31,16 @@ const EXA_TIMEOUT_SECS: u64 = 25;
 pub enum WebToolNetworkError {
     #[error("Failed to create HTTP client: {0}")]
     BuildClient(String),
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("Domain is not in the web fetch allowlist: {0}")]
+    DomainNotAllowed(String),
+    #[error("robots.txt disallows fetching {0}")]
+    RobotsDisallowed(String),
+    #[error("Stopped after {0} redirects")]
+    TooManyRedirects(usize),
+    #[error("Fetch timed out after {0} seconds")]
+    Timeout(u64),
     #[error("Failed to fetch URL: {0}")]
     Fetch(String),
     #[error("HTTP error {status}: {reason}")]
@@ -28,10 +55,43 @@ pub enum WebToolNetworkError {
     SearchEmpty,
 }
 
+/// Limits and routing for `WebToolNetworkProvider::fetch_text`.
+#[derive(Debug, Clone)]
+pub struct WebFetchOptions {
+    /// Upper bound for the whole fetch, redirects and robots.txt included.
+    pub timeout: Duration,
+    /// Bodies past this many bytes are cut off and marked truncated.
+    pub max_bytes: usize,
+    /// Domains that may be fetched, subdomains included; empty allows any.
+    pub allowed_domains: Vec<String>,
+    pub respect_robots_txt: bool,
+    /// How long a response is reused for the same URL; zero disables caching.
+    pub cache_ttl: Duration,
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Default for WebFetchOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(WEB_FETCH_TIMEOUT_SECS),
+            max_bytes: WEB_FETCH_MAX_BYTES,
+            allowed_domains: Vec::new(),
+            respect_robots_txt: true,
+            cache_ttl: Duration::from_secs(WEB_FETCH_CACHE_TTL_SECS),
+            proxy: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WebFetchResponse {
+    /// Final URL after redirects.
+    pub url: String,
     pub content_type: Option<String>,
     pub content: String,
+    /// The body exceeded `WebFetchOptions::max_bytes`.
+    pub truncated: bool,
+    pub from_cache: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -63,52 +123,25 @@ struct ExaContent {
 pub struct WebToolNetworkProvider;
 
 impl WebToolNetworkProvider {
-    pub async fn fetch_text(url: &str) -> Result<WebFetchResponse, WebToolNetworkError> {
-        let client = reqwest::Client::builder()
-            .user_agent(USER_AGENT_VALUE)
-            .timeout(Duration::from_secs(WEB_FETCH_TIMEOUT_SECS))
-            .build()
-            .map_err(|error| WebToolNetworkError::BuildClient(error.to_string()))?;
-
-        let response = client
-            .get(url)
-            .send()
-            .await
-            .map_err(|error| WebToolNetworkError::Fetch(error.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(WebToolNetworkError::HttpStatus {
-                status: response.status().to_string(),
-                reason: response
-                    .status()
-                    .canonical_reason()
-                    .unwrap_or("Unknown error")
-                    .to_string(),
-            });
+    pub async fn fetch_text(
+        url: &str,
+        options: &WebFetchOptions,
+    ) -> Result<WebFetchResponse, WebToolNetworkError> {
+        // Checked before the cache so narrowing the allowlist takes effect at once.
+        ensure_url_allowed(url, &options.allowed_domains)?;
+        if let Some(cached) = cached_response(url, options.cache_ttl) {
+            return Ok(cached);
         }
 
-        let content_type = response
-            .headers()
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|value| value.to_str().ok())
-            .map(str::to_string);
-
-        let content = response
-            .text()
+        let response = tokio::time::timeout(options.timeout, fetch_uncached(url, options))
             .await
-            .map_err(|error| WebToolNetworkError::ReadResponse(error.to_string()))?;
-
-        Ok(WebFetchResponse {
-            content_type,
-            content,
-        })
+            .map_err(|_| WebToolNetworkError::Timeout(options.timeout.as_secs()))??;
+        cache_response(url, &response, options.cache_ttl);
+        Ok(response)
     }
 
     pub async fn search_exa(request: ExaSearchRequest<'_>) -> Result<String, WebToolNetworkError> {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(EXA_TIMEOUT_SECS))
-            .build()
-            .map_err(|error| WebToolNetworkError::BuildClient(error.to_string()))?;
+        let client = web_http_client(None)?;
 
         let body = json!({
             "jsonrpc": "2.0",
@@ -128,6 +161,7 @@ impl WebToolNetworkProvider {
 
         let response = client
             .post(EXA_URL)
+            .timeout(Duration::from_secs(EXA_TIMEOUT_SECS))
             .header("accept", "application/json, text/event-stream")
             .header("content-type", "application/json")
             .json(&body)
@@ -156,6 +190,315 @@ impl WebToolNetworkProvider {
     }
 }
 
+/// Returns the client shared by web tools for a proxy setting. Redirects are
+/// followed by hand so every hop is checked against the allowlist and robots.txt.
+pub fn web_http_client(
+    proxy: Option<&ProxyConfig>,
+) -> Result<reqwest::Client, WebToolNetworkError> {
+    type ProxyKey = Option<(String, Option<String>, Option<String>)>;
+    static CLIENTS: OnceLock<Mutex<HashMap<ProxyKey, reqwest::Client>>> = OnceLock::new();
+
+    let proxy = proxy.filter(|proxy| proxy.enabled && !proxy.url.trim().is_empty());
+    let key = proxy.map(|proxy| {
+        (
+            proxy.url.clone(),
+            proxy.username.clone(),
+            proxy.password.clone(),
+        )
+    });
+    let mut clients = CLIENTS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(client) = clients.get(&key) {
+        return Ok(client.clone());
+    }
+
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT_VALUE)
+        .connect_timeout(Duration::from_secs(WEB_CONNECT_TIMEOUT_SECS))
+        .redirect(reqwest::redirect::Policy::none());
+    if let Some(proxy) = proxy {
+        let mut reqwest_proxy = reqwest::Proxy::all(&proxy.url)
+            .map_err(|error| WebToolNetworkError::BuildClient(error.to_string()))?;
+        if let (Some(username), Some(password)) = (&proxy.username, &proxy.password) {
+            if !username.is_empty() && !password.is_empty() {
+                reqwest_proxy = reqwest_proxy.basic_auth(username, password);
+            }
+        }
+        builder = builder.proxy(reqwest_proxy);
+    }
+    let client = builder
+        .build()
+        .map_err(|error| WebToolNetworkError::BuildClient(error.to_string()))?;
+    clients.insert(key, client.clone());
+    Ok(client)
+}
+
+/// Whether `host` is one of `allowed_domains` or a subdomain of one. Entries
+/// may be written as `example.com`, `.example.com` or `*.example.com`.
+pub fn is_domain_allowed(host: &str, allowed_domains: &[String]) -> bool {
+    if allowed_domains.is_empty() {
+        return true;
+    }
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    allowed_domains.iter().any(|entry| {
+        let entry = entry.trim().to_ascii_lowercase();
+        let entry = entry.trim_start_matches("*.").trim_start_matches('.');
+        !entry.is_empty()
+            && (host == entry
+                || host
+                    .strip_suffix(entry)
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    })
+}
+
+/// Rejects URLs that are not http(s) or whose host is outside `allowed_domains`.
+pub fn ensure_url_allowed(
+    url: &str,
+    allowed_domains: &[String],
+) -> Result<(), WebToolNetworkError> {
+    ensure_domain_allowed(&parse_web_url(url)?, allowed_domains)
+}
+
+fn parse_web_url(url: &str) -> Result<reqwest::Url, WebToolNetworkError> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|error| WebToolNetworkError::InvalidUrl(error.to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(WebToolNetworkError::InvalidUrl(url.to_string()));
+    }
+    Ok(parsed)
+}
+
+fn ensure_domain_allowed(
+    url: &reqwest::Url,
+    allowed_domains: &[String],
+) -> Result<(), WebToolNetworkError> {
+    let host = url.host_str().unwrap_or_default();
+    if is_domain_allowed(host, allowed_domains) {
+        Ok(())
+    } else {
+        Err(WebToolNetworkError::DomainNotAllowed(host.to_string()))
+    }
+}
+
+async fn fetch_uncached(
+    url: &str,
+    options: &WebFetchOptions,
+) -> Result<WebFetchResponse, WebToolNetworkError> {
+    let client = web_http_client(options.proxy.as_ref())?;
+    let mut current = parse_web_url(url)?;
+
+    for _ in 0..=MAX_REDIRECTS {
+        ensure_domain_allowed(&current, &options.allowed_domains)?;
+        if options.respect_robots_txt {
+            ensure_robots_allow(&client, &current).await?;
+        }
+
+        let response = client
+            .get(current.clone())
+            .send()
+            .await
+            .map_err(|error| WebToolNetworkError::Fetch(error.to_string()))?;
+        let status = response.status();
+
+        if status.is_redirection() {
+            if let Some(location) = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|value| value.to_str().ok())
+            {
+                let next = current
+                    .join(location)
+                    .map_err(|error| WebToolNetworkError::InvalidUrl(error.to_string()))?;
+                current = parse_web_url(next.as_str())?;
+                continue;
+            }
+        }
+
+        if !status.is_success() {
+            return Err(WebToolNetworkError::HttpStatus {
+                status: status.to_string(),
+                reason: status
+                    .canonical_reason()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let (content, truncated) = read_limited_text(response, options.max_bytes).await?;
+
+        return Ok(WebFetchResponse {
+            url: current.to_string(),
+            content_type,
+            content,
+            truncated,
+            from_cache: false,
+        });
+    }
+
+    Err(WebToolNetworkError::TooManyRedirects(MAX_REDIRECTS))
+}
+
+/// Reads at most `max_bytes` of the body, dropping a character split by the cut.
+async fn read_limited_text(
+    mut response: reqwest::Response,
+    max_bytes: usize,
+) -> Result<(String, bool), WebToolNetworkError> {
+    let mut body = Vec::new();
+    let mut truncated = false;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|error| WebToolNetworkError::ReadResponse(error.to_string()))?
+    {
+        let room = max_bytes - body.len();
+        if chunk.len() > room {
+            body.extend_from_slice(&chunk[..room]);
+            truncated = true;
+            break;
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    if truncated {
+        if let Err(error) = std::str::from_utf8(&body) {
+            if error.error_len().is_none() {
+                body.truncate(error.valid_up_to());
+            }
+        }
+    }
+    Ok((String::from_utf8_lossy(&body).into_owned(), truncated))
+}
+
+/// Loopback and private-network hosts are developer services, not crawl
+/// targets, so robots.txt is not consulted for them.
+fn is_private_host(url: &reqwest::Url) -> bool {
+    let host = url.host_str().unwrap_or_default();
+    if host.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+    match host
+        .trim_matches(|c| c == '[' || c == ']')
+        .parse::<IpAddr>()
+    {
+        Ok(IpAddr::V4(ip)) => ip.is_loopback() || ip.is_private() || ip.is_link_local(),
+        Ok(IpAddr::V6(ip)) => ip.is_loopback(),
+        Err(_) => false,
+    }
+}
+
+async fn ensure_robots_allow(
+    client: &reqwest::Client,
+    url: &reqwest::Url,
+) -> Result<(), WebToolNetworkError> {
+    if is_private_host(url) {
+        return Ok(());
+    }
+    let rules = robots_rules(client, &url.origin().ascii_serialization()).await;
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    if rules.is_allowed(&path) {
+        Ok(())
+    } else {
+        Err(WebToolNetworkError::RobotsDisallowed(url.to_string()))
+    }
+}
+
+/// Rules for an origin, cached for an hour. A robots.txt that cannot be read
+/// allows everything.
+async fn robots_rules(client: &reqwest::Client, origin: &str) -> Arc<RobotsRules> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Instant, Arc<RobotsRules>)>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+
+    if let Some((fetched_at, rules)) = cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(origin)
+    {
+        if fetched_at.elapsed() < ROBOTS_CACHE_TTL {
+            return rules.clone();
+        }
+    }
+
+    let robots_url = format!("{}/robots.txt", origin);
+    let text = match client.get(&robots_url).send().await {
+        Ok(response) if response.status().is_success() => {
+            read_limited_text(response, ROBOTS_MAX_BYTES)
+                .await
+                .map(|(text, _)| text)
+                .unwrap_or_default()
+        }
+        Ok(response) => {
+            debug!(
+                "No robots.txt rules: url={}, status={}",
+                robots_url,
+                response.status()
+            );
+            String::new()
+        }
+        Err(error) => {
+            debug!(
+                "Failed to fetch robots.txt: url={}, error={}",
+                robots_url, error
+            );
+            String::new()
+        }
+    };
+    let rules = Arc::new(RobotsRules::parse(&text, ROBOTS_AGENT_TOKEN));
+    cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(origin.to_string(), (Instant::now(), rules.clone()));
+    rules
+}
+
+fn response_cache() -> &'static Mutex<HashMap<String, (Instant, WebFetchResponse)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Instant, WebFetchResponse)>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn cached_response(url: &str, ttl: Duration) -> Option<WebFetchResponse> {
+    if ttl.is_zero() {
+        return None;
+    }
+    let cache = response_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (fetched_at, response) = cache.get(url)?;
+    (fetched_at.elapsed() < ttl).then(|| WebFetchResponse {
+        from_cache: true,
+        ..response.clone()
+    })
+}
+
+fn cache_response(url: &str, response: &WebFetchResponse, ttl: Duration) {
+    if ttl.is_zero() {
+        return;
+    }
+    let mut cache = response_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < ttl);
+    if cache.len() >= RESPONSE_CACHE_CAPACITY {
+        if let Some(oldest) = cache
+            .iter()
+            .min_by_key(|(_, (fetched_at, _))| *fetched_at)
+            .map(|(url, _)| url.clone())
+        {
+            cache.remove(&oldest);
+        }
+    }
+    cache.insert(url.to_string(), (Instant::now(), response.clone()));
+}
+
 fn parse_exa_sse(text: &str) -> Result<String, WebToolNetworkError> {
     let out = text
         .lines()
@@ -204,4 +547,32 @@ mod tests {
 
         assert!(matches!(error, WebToolNetworkError::SearchEmpty));
     }
+
+    #[test]
+    fn allowlist_matches_domains_and_subdomains_only() {
+        let allowed = vec!["docs.rs".to_string(), "*.example.com".to_string()];
+
+        assert!(is_domain_allowed("docs.rs", &allowed));
+        assert!(is_domain_allowed("api.example.com", &allowed));
+        assert!(is_domain_allowed("Example.com.", &allowed));
+        assert!(!is_domain_allowed("notdocs.rs", &allowed));
+        assert!(!is_domain_allowed("example.com.evil.io", &allowed));
+        assert!(is_domain_allowed("anything.io", &[]));
+    }
+
+    #[tokio::test]
+    async fn fetch_rejects_disallowed_domain_before_any_request() {
+        let options = WebFetchOptions {
+            allowed_domains: vec!["docs.rs".to_string()],
+            ..WebFetchOptions::default()
+        };
+
+        let error = WebToolNetworkProvider::fetch_text("https://example.com/page", &options)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, WebToolNetworkError::DomainNotAllowed(host) if host == "example.com")
+        );
+    }
 }
@@ -0,0 +1,136 @@
+//! Minimal robots.txt evaluation (RFC 9309): the groups matching the crawler
+//! token, `Allow`/`Disallow` rules with `*` and `$`, longest match wins.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct RobotsRules {
+    /// `(allow, pattern)` pairs from every group that applies to the agent.
+    rules: Vec<(bool, String)>,
+}
+
+impl RobotsRules {
+    /// Collects the rules for `agent`. Groups naming the agent replace the
+    /// `*` group; a file without either allows everything.
+    pub(super) fn parse(text: &str, agent: &str) -> Self {
+        let agent = agent.to_ascii_lowercase();
+        let mut specific = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        let mut named_group_seen = false;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim();
+
+            match key.as_str() {
+                "user-agent" => {
+                    // A user-agent line after rules starts the next group.
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    let name = value.to_ascii_lowercase();
+                    named_group_seen |= agent_matches(&name, &agent);
+                    group_agents.push(name);
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty `Disallow:` permits everything and adds nothing.
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let rule = (key == "allow", value.to_string());
+                    if group_agents.iter().any(|name| agent_matches(name, &agent)) {
+                        specific.push(rule);
+                    } else if group_agents.iter().any(|name| name == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            rules: if named_group_seen { specific } else { wildcard },
+        }
+    }
+
+    /// Checks a path (with query) against the rules; ties go to `Allow`.
+    pub(super) fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| pattern_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+}
+
+/// `BitFun/1.0` style values still match the `bitfun` product token.
+fn agent_matches(name: &str, agent: &str) -> bool {
+    name.split('/')
+        .next()
+        .is_some_and(|token| token.trim() == agent)
+}
+
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let parts = pattern.split('*').collect::<Vec<_>>();
+    if !path.starts_with(parts[0]) {
+        return false;
+    }
+    let mut position = parts[0].len();
+    if parts.len() == 1 {
+        return !anchored || position == path.len();
+    }
+
+    for (index, part) in parts.iter().enumerate().skip(1) {
+        if anchored && index == parts.len() - 1 {
+            return path.len() >= position + part.len() && path.ends_with(part);
+        }
+        match path[position..].find(part) {
+            Some(found) => position += found + part.len(),
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_group_overrides_wildcard_group() {
+        let text = "User-agent: *\nDisallow: /\n\nUser-agent: BitFun\nDisallow: /private\n";
+
+        let rules = RobotsRules::parse(text, "bitfun");
+
+        assert!(rules.is_allowed("/docs/page"));
+        assert!(!rules.is_allowed("/private/notes"));
+    }
+
+    #[test]
+    fn longest_match_wins_and_supports_wildcards() {
+        let text = "User-agent: *\nDisallow: /api/\nAllow: /api/public/\nDisallow: /*.pdf$\n";
+
+        let rules = RobotsRules::parse(text, "bitfun");
+
+        assert!(!rules.is_allowed("/api/keys"));
+        assert!(rules.is_allowed("/api/public/status"));
+        assert!(!rules.is_allowed("/files/report.pdf"));
+        assert!(rules.is_allowed("/files/report.pdf?download=1"));
+    }
+
+    #[test]
+    fn missing_or_empty_rules_allow_everything() {
+        assert!(RobotsRules::parse("", "bitfun").is_allowed("/"));
+        assert!(RobotsRules::parse("User-agent: *\nDisallow:\n", "bitfun").is_allowed("/a"));
+    }
+}
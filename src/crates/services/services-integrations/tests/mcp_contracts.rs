@@ -8,9 +8,10 @@ use bitfun_services_integrations::mcp::config::ConfigLocation;
 use bitfun_services_integrations::mcp::config::{
     config_to_cursor_format, format_mcp_json_config_value, get_mcp_remote_authorization_source,
     get_mcp_remote_authorization_value, has_mcp_remote_authorization, has_mcp_remote_oauth,
-    has_mcp_remote_xaa, merge_mcp_server_config_sources, normalize_mcp_authorization_value,
-    parse_cursor_format, remove_mcp_authorization_keys, validate_mcp_json_config, MCPConfigService,
-    MCPConfigStore,
+    has_mcp_remote_xaa, insert_mcp_server_into_json_config, merge_mcp_server_config_sources,
+    normalize_mcp_authorization_value, parse_cursor_format, remove_mcp_authorization_keys,
+    render_mcp_server_preset, validate_mcp_json_config, MCPConfigService, MCPConfigStore,
+    MCPPresetParameterKind, MCPServerPresetCatalog,
 };
 use bitfun_services_integrations::mcp::protocol::{
     create_initialize_request, create_mcp_client_info, create_ping_request,
@@ -19,14 +20,16 @@ use bitfun_services_integrations::mcp::protocol::{
     map_rmcp_tool, map_rmcp_tool_result, MCPCapability, MCPError, MCPPrompt, MCPPromptArgument,
     MCPPromptContent, MCPPromptMessage, MCPPromptMessageContent, MCPPromptMessageContentBlock,
     MCPRequest, MCPResource, MCPResourceContent, MCPTool, MCPToolAnnotations, MCPToolResult,
-    MCPToolResultContent,
+    MCPToolResultContent, StdioFraming,
 };
 use bitfun_services_integrations::mcp::server::{
-    compute_mcp_backoff_delay, detect_mcp_list_changed_kind, is_mcp_auth_error_message,
-    mcp_reconnect_runtime_decision, mcp_server_is_running, mcp_should_start_after_config_update,
-    merge_mcp_remote_headers, MCPCatalogCache, MCPConnectionPool, MCPListChangedKind,
-    MCPReconnectRuntimeDecision, MCPRuntimeErrorKind, MCPRuntimeResult, MCPServerConfig,
-    MCPServerProcess, MCPServerRuntimeState, MCPServerStatus, MCPServerTransport, MCPServerType,
+    build_mcp_process_environment, compute_mcp_backoff_delay, detect_mcp_list_changed_kind,
+    diff_mcp_capabilities, is_mcp_auth_error_message, mcp_reconnect_runtime_decision,
+    mcp_server_is_running, mcp_should_start_after_config_update, merge_mcp_remote_headers,
+    resolve_mcp_working_directory, MCPCapabilitySnapshot, MCPCapabilityTrustStore, MCPCatalogCache,
+    MCPConnectionPool, MCPListChangedKind, MCPReconnectRuntimeDecision, MCPRuntimeErrorKind,
+    MCPRuntimeResult, MCPServerConfig, MCPServerIsolationConfig, MCPServerProcess,
+    MCPServerRuntimeState, MCPServerStatus, MCPServerTlsConfig, MCPServerTransport, MCPServerType,
 };
 use bitfun_services_integrations::mcp::{
     build_mcp_tool_descriptor, build_mcp_tool_name, normalize_name_for_mcp,
@@ -37,6 +40,7 @@ use bitfun_services_integrations::mcp::{
 use rmcp::model::{AnnotateAble, Annotations, Content, Icon, Meta, RawResource, ResourceContents};
 use rmcp::transport::auth::StoredCredentials;
 use std::collections::HashMap;
+use std::ffi::OsString;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -57,8 +61,11 @@ fn make_mcp_config(
         env: HashMap::new(),
         working_directory: None,
         inherit_parent_environment: None,
+        isolation: None,
+        framing: None,
         headers: HashMap::new(),
         url: url.map(str::to_string),
+        tls: None,
         auto_start: true,
         enabled: true,
         location,
@@ -1576,8 +1583,11 @@ fn mcp_server_config_preserves_transport_defaults_and_validation_contract() {
         env: Default::default(),
         working_directory: None,
         inherit_parent_environment: None,
+        isolation: None,
+        framing: None,
         headers: Default::default(),
         url: None,
+        tls: None,
         auto_start: true,
         enabled: true,
         location: ConfigLocation::User,
@@ -1691,6 +1701,254 @@ fn local_mcp_can_disable_parent_environment_inheritance_without_changing_legacy_
     assert!(legacy.inherits_parent_environment());
 }
 
+fn parent_environment(pairs: &[(&str, &str)]) -> Vec<(OsString, OsString)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (OsString::from(key), OsString::from(value)))
+        .collect()
+}
+
+fn environment_keys(environment: &[(OsString, OsString)]) -> Vec<String> {
+    environment
+        .iter()
+        .map(|(key, _)| key.to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn local_mcp_environment_allowlist_restricts_inherited_variables() {
+    let isolation = MCPServerIsolationConfig {
+        env_allowlist: vec!["NODE_*".to_string()],
+        env_denylist: vec!["NODE_AUTH_TOKEN".to_string()],
+        ..Default::default()
+    };
+    let parent = parent_environment(&[
+        ("PATH", "/usr/bin"),
+        ("NODE_OPTIONS", "--max-old-space-size=512"),
+        ("NODE_AUTH_TOKEN", "secret"),
+        ("OPENAI_API_KEY", "secret"),
+    ]);
+    let configured = HashMap::from([("SERVER_MODE".to_string(), "strict".to_string())]);
+
+    let environment =
+        build_mcp_process_environment("local", parent, &configured, true, Some(&isolation))
+            .expect("environment builds");
+
+    assert_eq!(
+        environment_keys(&environment),
+        vec!["NODE_OPTIONS", "PATH", "SERVER_MODE"]
+    );
+}
+
+#[test]
+fn local_mcp_environment_denylist_applies_to_inherited_variables() {
+    let isolation = MCPServerIsolationConfig {
+        env_denylist: vec!["AWS_*".to_string()],
+        ..Default::default()
+    };
+    let parent = parent_environment(&[
+        ("AWS_SECRET_ACCESS_KEY", "secret"),
+        ("EDITOR", "vim"),
+        ("PATH", "/usr/bin"),
+    ]);
+
+    let environment =
+        build_mcp_process_environment("local", parent, &HashMap::new(), true, Some(&isolation))
+            .expect("environment builds");
+
+    assert_eq!(environment_keys(&environment), vec!["EDITOR", "PATH"]);
+}
+
+#[test]
+fn local_mcp_startup_fails_when_required_environment_is_missing() {
+    let isolation = MCPServerIsolationConfig {
+        required_env: vec!["GITHUB_TOKEN".to_string(), "PATH".to_string()],
+        ..Default::default()
+    };
+    let parent = parent_environment(&[("PATH", "/usr/bin"), ("GITHUB_TOKEN", "")]);
+
+    let error =
+        build_mcp_process_environment("github", parent, &HashMap::new(), false, Some(&isolation))
+            .expect_err("empty required variable is rejected");
+
+    assert_eq!(error.kind(), MCPRuntimeErrorKind::Configuration);
+    assert!(error.to_string().contains("GITHUB_TOKEN"));
+    assert!(!error.to_string().contains("PATH"));
+}
+
+#[test]
+fn local_mcp_working_directory_is_confined_to_configured_roots() {
+    let root = tempfile::tempdir().expect("create root");
+    let inside = root.path().join("project");
+    std::fs::create_dir(&inside).expect("create project directory");
+    let outside = tempfile::tempdir().expect("create outside directory");
+    let isolation = MCPServerIsolationConfig {
+        working_directory_roots: vec![root.path().to_string_lossy().into_owned()],
+        ..Default::default()
+    };
+
+    let resolved = resolve_mcp_working_directory("local", Some(&inside), Some(&isolation))
+        .expect("directory inside the root is allowed");
+    assert_eq!(resolved, Some(inside.canonicalize().unwrap()));
+
+    let escaped = resolve_mcp_working_directory("local", Some(outside.path()), Some(&isolation))
+        .expect_err("directory outside the root is rejected");
+    assert_eq!(escaped.kind(), MCPRuntimeErrorKind::Configuration);
+
+    let unset = resolve_mcp_working_directory("local", None, Some(&isolation))
+        .expect_err("confinement requires a working directory");
+    assert_eq!(unset.kind(), MCPRuntimeErrorKind::Configuration);
+}
+
+#[test]
+fn mcp_json_isolation_round_trips_and_is_validated() {
+    let value = serde_json::json!({
+        "mcpServers": {
+            "local": {
+                "command": "node",
+                "cwd": "/workspace/project",
+                "env": {"SERVER_MODE": "strict"},
+                "isolation": {
+                    "envAllowlist": ["NODE_*"],
+                    "requiredEnv": ["SERVER_MODE"],
+                    "maxMemoryMb": 512,
+                    "maxCpuSeconds": 600
+                }
+            }
+        }
+    });
+    validate_mcp_json_config(&value).expect("isolation config is valid");
+
+    let servers = parse_cursor_format(&value);
+    assert_eq!(servers.len(), 1);
+    let server = &servers[0];
+    assert_eq!(
+        server.working_directory.as_deref(),
+        Some("/workspace/project")
+    );
+    let isolation = server.isolation.as_ref().expect("isolation parsed");
+    assert_eq!(isolation.max_memory_bytes(), Some(512 * 1024 * 1024));
+    assert_eq!(isolation.max_cpu_seconds, Some(600));
+
+    let exported = config_to_cursor_format(server);
+    assert_eq!(exported["cwd"], "/workspace/project");
+    assert_eq!(exported["isolation"]["envAllowlist"][0], "NODE_*");
+
+    let invalid_limit = serde_json::json!({
+        "mcpServers": {"local": {"command": "node", "isolation": {"maxMemoryMb": 1}}}
+    });
+    assert!(validate_mcp_json_config(&invalid_limit).is_err());
+
+    let remote = serde_json::json!({
+        "mcpServers": {"remote": {"url": "https://example.test/mcp", "isolation": {}}}
+    });
+    assert!(validate_mcp_json_config(&remote).is_err());
+}
+
+#[test]
+fn mcp_json_framing_round_trips_and_is_validated() {
+    let value = serde_json::json!({
+        "mcpServers": {"local": {"command": "legacy-server", "framing": "content-length"}}
+    });
+    validate_mcp_json_config(&value).expect("framing config is valid");
+
+    let servers = parse_cursor_format(&value);
+    assert_eq!(servers[0].framing, Some(StdioFraming::ContentLength));
+    assert_eq!(
+        config_to_cursor_format(&servers[0])["framing"],
+        "content-length"
+    );
+
+    let unknown = serde_json::json!({
+        "mcpServers": {"local": {"command": "legacy-server", "framing": "lsp"}}
+    });
+    assert!(validate_mcp_json_config(&unknown).is_err());
+
+    let remote = serde_json::json!({
+        "mcpServers": {"remote": {"url": "https://example.test/mcp", "framing": "newline"}}
+    });
+    assert!(validate_mcp_json_config(&remote).is_err());
+}
+
+#[test]
+fn local_mcp_config_rejects_explicit_env_blocked_by_its_denylist() {
+    let mut config = make_mcp_config(
+        "local",
+        ConfigLocation::User,
+        MCPServerType::Local,
+        Some("node"),
+        None,
+    );
+    config
+        .env
+        .insert("GITHUB_TOKEN".to_string(), "explicit".to_string());
+    config.isolation = Some(MCPServerIsolationConfig {
+        env_denylist: vec!["GITHUB_*".to_string()],
+        ..Default::default()
+    });
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn mcp_json_tls_round_trips_and_is_validated() {
+    let pin = format!("sha256/{}", "A".repeat(43) + "=");
+    let value = serde_json::json!({
+        "mcpServers": {
+            "gateway": {
+                "url": "https://mcp.example.test/mcp",
+                "tls": {
+                    "caCertPath": absolute_test_path(),
+                    "pinnedSpkiSha256": [pin],
+                    "insecureSkipVerify": true
+                }
+            }
+        }
+    });
+    validate_mcp_json_config(&value).expect("tls config is valid");
+
+    let servers = parse_cursor_format(&value);
+    assert_eq!(servers.len(), 1);
+    servers[0].validate().expect("parsed server is valid");
+    let tls = servers[0].tls.as_ref().expect("tls parsed");
+    assert!(tls.insecure_skip_verify);
+    assert_eq!(tls.spki_pin_digests(), vec![[0u8; 32]]);
+
+    let exported = config_to_cursor_format(&servers[0]);
+    assert_eq!(exported["tls"]["pinnedSpkiSha256"][0], pin.as_str());
+    assert!(exported["tls"].get("clientCertPath").is_none());
+
+    for invalid in [
+        serde_json::json!({"url": "http://mcp.example.test/mcp", "tls": {}}),
+        serde_json::json!({"command": "node", "tls": {}}),
+        serde_json::json!({
+            "url": "https://mcp.example.test/mcp",
+            "tls": {"clientCertPath": absolute_test_path()}
+        }),
+        serde_json::json!({
+            "url": "https://mcp.example.test/mcp",
+            "tls": {"pinnedSpkiSha256": ["sha256/not-a-digest"]}
+        }),
+        serde_json::json!({
+            "url": "https://mcp.example.test/mcp",
+            "tls": {"caCertPath": "relative/ca.pem"}
+        }),
+    ] {
+        let config = serde_json::json!({"mcpServers": {"gateway": invalid}});
+        assert!(validate_mcp_json_config(&config).is_err(), "{}", config);
+    }
+
+    let mut local = make_mcp_config(
+        "local",
+        ConfigLocation::User,
+        MCPServerType::Local,
+        Some("node"),
+        None,
+    );
+    local.tls = Some(MCPServerTlsConfig::default());
+    assert!(local.validate().is_err());
+}
+
 #[test]
 fn mcp_oauth_session_snapshot_preserves_camel_case_status_contract() {
     let snapshot = MCPRemoteOAuthSessionSnapshot::new(
@@ -1765,11 +2023,14 @@ fn mcp_cursor_format_helpers_preserve_cursor_compatibility_contract() {
         env: Default::default(),
         working_directory: None,
         inherit_parent_environment: None,
+        isolation: None,
+        framing: None,
         headers: std::collections::HashMap::from([(
             "Authorization".to_string(),
             "Bearer token".to_string(),
         )]),
         url: Some("https://example.com/sse".to_string()),
+        tls: None,
         auto_start: false,
         enabled: true,
         location: ConfigLocation::User,
@@ -1814,3 +2075,210 @@ fn mcp_cursor_format_helpers_preserve_cursor_compatibility_contract() {
     assert_eq!(parsed[0].transport, Some(MCPServerTransport::Sse));
     assert_eq!(parsed[0].location, ConfigLocation::User);
 }
+
+fn capability_tool(
+    name: &str,
+    schema: serde_json::Value,
+    annotations: Option<MCPToolAnnotations>,
+) -> MCPTool {
+    MCPTool {
+        name: name.to_string(),
+        title: None,
+        description: None,
+        input_schema: schema,
+        output_schema: None,
+        icons: None,
+        annotations,
+        meta: None,
+    }
+}
+
+fn read_only_annotations() -> Option<MCPToolAnnotations> {
+    Some(MCPToolAnnotations {
+        title: None,
+        read_only_hint: Some(true),
+        destructive_hint: None,
+        idempotent_hint: None,
+        open_world_hint: None,
+    })
+}
+
+#[test]
+fn mcp_capability_diff_gates_added_and_escalated_destructive_tools() {
+    let trusted = MCPCapabilitySnapshot::from_tools(&[
+        capability_tool(
+            "search",
+            serde_json::json!({ "type": "object", "required": ["q"] }),
+            read_only_annotations(),
+        ),
+        capability_tool("list", serde_json::json!({}), read_only_annotations()),
+        capability_tool("legacy", serde_json::json!({}), read_only_annotations()),
+    ]);
+    let current = MCPCapabilitySnapshot::from_tools(&[
+        capability_tool(
+            "search",
+            serde_json::json!({ "required": ["q", "limit"], "type": "object" }),
+            read_only_annotations(),
+        ),
+        capability_tool("list", serde_json::json!({}), None),
+        capability_tool("summarize", serde_json::json!({}), read_only_annotations()),
+        capability_tool("delete_repo", serde_json::json!({}), None),
+    ]);
+
+    let diff = diff_mcp_capabilities(&trusted, &current);
+
+    assert_eq!(diff.added_tools, vec!["delete_repo", "summarize"]);
+    assert_eq!(diff.removed_tools, vec!["legacy"]);
+    assert_eq!(diff.changed_schemas, vec!["search"]);
+    assert_eq!(diff.escalated_tools, vec!["list"]);
+    assert_eq!(diff.gated_tools, vec!["delete_repo", "list"]);
+    assert!(diff.requires_approval());
+}
+
+#[test]
+fn mcp_capability_schema_hash_ignores_key_order() {
+    let trusted = MCPCapabilitySnapshot::from_tools(&[capability_tool(
+        "search",
+        serde_json::json!({ "type": "object", "properties": { "a": {}, "b": {} } }),
+        read_only_annotations(),
+    )]);
+    let current = MCPCapabilitySnapshot::from_tools(&[capability_tool(
+        "search",
+        serde_json::json!({ "properties": { "b": {}, "a": {} }, "type": "object" }),
+        read_only_annotations(),
+    )]);
+
+    assert!(diff_mcp_capabilities(&trusted, &current).is_empty());
+}
+
+#[tokio::test]
+async fn mcp_capability_trust_store_round_trips_and_clears_snapshots() {
+    let data_dir = tempfile::tempdir().expect("create data dir");
+    let store = MCPCapabilityTrustStore::new(data_dir.path());
+    let snapshot =
+        MCPCapabilitySnapshot::from_tools(&[capability_tool("run", serde_json::json!({}), None)]);
+
+    assert_eq!(store.load("server").await.expect("load empty"), None);
+    store.store("server", &snapshot).await.expect("store");
+    assert_eq!(
+        MCPCapabilityTrustStore::new(data_dir.path())
+            .load("server")
+            .await
+            .expect("load stored"),
+        Some(snapshot)
+    );
+
+    store.clear("server").await.expect("clear");
+    assert_eq!(store.load("server").await.expect("load cleared"), None);
+}
+
+fn absolute_test_path() -> &'static str {
+    if cfg!(windows) {
+        "C:\\data"
+    } else {
+        "/data"
+    }
+}
+
+#[test]
+fn mcp_builtin_presets_render_to_valid_config_entries() {
+    let catalog = MCPServerPresetCatalog::builtin();
+    assert!(catalog.get("filesystem").is_some());
+    assert!(catalog.get("github").is_some());
+
+    for preset in &catalog.presets {
+        let values = preset
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let value = match parameter.kind {
+                    MCPPresetParameterKind::Path => absolute_test_path(),
+                    MCPPresetParameterKind::Url => "https://example.com",
+                    _ => "value",
+                };
+                (parameter.key.clone(), value.to_string())
+            })
+            .collect::<HashMap<_, _>>();
+        let server = render_mcp_server_preset(preset, &values)
+            .unwrap_or_else(|errors| panic!("preset {} failed: {:?}", preset.id, errors));
+        assert!(
+            !server.to_string().contains("${"),
+            "preset {} left a placeholder",
+            preset.id
+        );
+        validate_mcp_json_config(
+            &serde_json::json!({ "mcpServers": { preset.id.clone(): server } }),
+        )
+        .unwrap_or_else(|error| panic!("preset {} is invalid: {}", preset.id, error));
+    }
+}
+
+#[test]
+fn mcp_preset_rendering_validates_fields_and_drops_empty_optionals() {
+    let catalog = MCPServerPresetCatalog::builtin();
+
+    let errors = render_mcp_server_preset(
+        catalog.get("filesystem").unwrap(),
+        &HashMap::from([("allowed_directory".to_string(), "relative/dir".to_string())]),
+    )
+    .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].key, "allowed_directory");
+    assert!(render_mcp_server_preset(catalog.get("github").unwrap(), &HashMap::new()).is_err());
+
+    let playwright =
+        render_mcp_server_preset(catalog.get("playwright").unwrap(), &HashMap::new()).unwrap();
+    assert_eq!(
+        playwright["args"],
+        serde_json::json!(["-y", "@playwright/mcp@latest"])
+    );
+    let github = render_mcp_server_preset(
+        catalog.get("github").unwrap(),
+        &HashMap::from([("github_token".to_string(), " ghp_token ".to_string())]),
+    )
+    .unwrap();
+    assert_eq!(github["type"], "streamable-http");
+    assert_eq!(github["headers"]["Authorization"], "Bearer ghp_token");
+}
+
+#[test]
+fn mcp_preset_catalog_updates_and_insertion_keep_existing_servers() {
+    let update = MCPServerPresetCatalog::parse(
+        r#"{
+            "version": 7,
+            "presets": [
+                { "id": "fetch", "name": "Fetch v2", "description": "Updated", "command": "uvx", "args": ["mcp-server-fetch@2"] },
+                { "id": "internal", "name": "Internal", "description": "Team server", "url": "https://mcp.internal.example/" }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let builtin = MCPServerPresetCatalog::builtin();
+    let builtin_len = builtin.presets.len();
+    let merged = builtin.merge(update);
+    assert_eq!(merged.version, 7);
+    assert_eq!(merged.presets.len(), builtin_len + 1);
+    assert_eq!(merged.get("fetch").unwrap().name, "Fetch v2");
+
+    let mut config = serde_json::json!({ "mcpServers": { "fetch": { "command": "custom" } } });
+    let fetch = render_mcp_server_preset(merged.get("fetch").unwrap(), &HashMap::new()).unwrap();
+    let server_id = insert_mcp_server_into_json_config(&mut config, "fetch", fetch).unwrap();
+    assert_eq!(server_id, "fetch-2");
+    assert_eq!(config["mcpServers"]["fetch"]["command"], "custom");
+    assert_eq!(
+        config["mcpServers"]["fetch-2"]["args"][0],
+        "mcp-server-fetch@2"
+    );
+
+    let mut empty = serde_json::json!({});
+    assert_eq!(
+        insert_mcp_server_into_json_config(&mut empty, "memory", serde_json::json!({})).unwrap(),
+        "memory"
+    );
+    assert!(insert_mcp_server_into_json_config(
+        &mut serde_json::json!({ "mcpServers": [] }),
+        "memory",
+        serde_json::json!({})
+    )
+    .is_err());
+}
@@ -15,6 +15,9 @@ import { startupTrace } from '@/shared/utils/startupTrace';
 import { isTauriRuntime } from '@/infrastructure/runtime';
 import { useWorkspaceContext } from '../infrastructure/contexts/WorkspaceContext';
 import { useGlobalSceneShortcuts } from './hooks/useGlobalSceneShortcuts';
+import { useDeepLinks } from './hooks/useDeepLinks';
+import { useWorkspaceTrustPrompt } from './hooks/useWorkspaceTrustPrompt';
+import { useApprovalPrompts } from './hooks/useApprovalPrompts';
 import { useDebugInspector } from '@/infrastructure/debug/useDebugInspector';
 import { useI18n } from '@/infrastructure/i18n';
 import { scheduleDeferredStartupSystems } from './startup/deferredStartupSystems';
@@ -714,6 +717,15 @@ function App() {
   // Top SceneBar: Mod+Alt+1..9 / Mod+Alt+PageUp/PageDown
   useGlobalSceneShortcuts();
 
+  // Confirmed bitfun:// links: open folder, install skill, add MCP server
+  useDeepLinks();
+
+  // Trust prompt for folders opened without a trust decision
+  useWorkspaceTrustPrompt();
+
+  // Pending approvals for plugin permissions and remote device actions
+  useApprovalPrompts();
+
   // Debug inspector shortcuts (desktop devtools only)
   useDebugInspector();
 
@@ -1,6 +1,7 @@
 /**
  * About dialog component.
- * Shows app version and license info.
+ * Shows app version, bundled component versions, license info and the
+ * release notes of the installed version.
  * Uses component library Modal.
  */
 
@@ -14,7 +15,8 @@ import {
   formatBuildDate
 } from '@/shared/utils/version';
 import { createLogger } from '@/shared/utils/logger';
-import { systemAPI } from '@/infrastructure/api';
+import { aboutAPI, systemAPI } from '@/infrastructure/api';
+import type { AppInfo } from '@/infrastructure/api/service-api/AboutAPI';
 import type { CheckForUpdatesResponse } from '@/infrastructure/api/service-api/SystemAPI';
 import { isTauriRuntime } from '@/infrastructure/update/tauriEnv';
 import { UpdateAvailableDialog } from '@/infrastructure/update/UpdateAvailableDialog';
@@ -42,6 +44,7 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({
   const [manualCheckErrorMessage, setManualCheckErrorMessage] = useState<string | null>(null);
   const [manualOpen, setManualOpen] = useState(false);
   const [manualData, setManualData] = useState<CheckForUpdatesResponse | null>(null);
+  const [appInfo, setAppInfo] = useState<AppInfo | null>(null);
   const updateStatus = useUpdateInstallStore(state => state.status);
   const updateProgress = useUpdateInstallStore(state => state.progress);
   const updateError = useUpdateInstallStore(state => state.error);
@@ -61,6 +64,26 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({
     }
   }, [isOpen]);
 
+  useEffect(() => {
+    if (!isOpen || !isTauriRuntime()) {
+      return;
+    }
+    let cancelled = false;
+    aboutAPI
+      .getAppInfo()
+      .then(info => {
+        if (!cancelled) {
+          setAppInfo(info);
+        }
+      })
+      .catch(e => log.warn('get_app_info failed', e));
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen]);
+
+  const gitCommit = version.gitCommit || appInfo?.buildHash || undefined;
+
   const handleCheckForUpdates = useCallback(async () => {
     if (!isTauriRuntime()) {
       return;
@@ -132,6 +155,11 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({
           <div className="bitfun-about-dialog__version-badge">
             {t('about.version', { version: formatVersion(version.version, version.isDev) })}
           </div>
+          {appInfo ? (
+            <div className="bitfun-about-dialog__channel">
+              {t(`about.channels.${appInfo.channel}`)}
+            </div>
+          ) : null}
           <div className="bitfun-about-dialog__divider" />
           <div className="bitfun-about-dialog__dots">
             <span></span>
@@ -262,17 +290,17 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({
                 </span>
               </div>
 
-              {version.gitCommit && (
+              {gitCommit && (
                 <div className="bitfun-about-dialog__info-row">
                   <span className="bitfun-about-dialog__info-label">{t('about.commit')}</span>
                   <div className="bitfun-about-dialog__info-value-group">
                     <span className="bitfun-about-dialog__info-value bitfun-about-dialog__info-value--mono">
-                      {version.gitCommit}
+                      {gitCommit}
                     </span>
                     <Tooltip content={t('about.copy')}>
                       <button
                         className="bitfun-about-dialog__copy-btn"
-                        onClick={() => copyToClipboard(version.gitCommit || '', 'commit')}
+                        onClick={() => copyToClipboard(gitCommit, 'commit')}
                       >
                         {copiedItem === 'commit' ? <Check size={12} /> : <Copy size={12} />}
                       </button>
@@ -288,12 +316,35 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({
                 </div>
               )}
             </div>
+
+            {appInfo && appInfo.components.length > 0 ? (
+              <div className="bitfun-about-dialog__info-card">
+                <div className="bitfun-about-dialog__section-title">{t('about.components')}</div>
+                {appInfo.components.map(component => (
+                  <div key={component.id} className="bitfun-about-dialog__info-row">
+                    <span className="bitfun-about-dialog__info-label">{component.name}</span>
+                    <span className="bitfun-about-dialog__info-value bitfun-about-dialog__info-value--mono">
+                      {component.version}
+                    </span>
+                  </div>
+                ))}
+              </div>
+            ) : null}
+
+            {appInfo?.changelog ? (
+              <div className="bitfun-about-dialog__info-card">
+                <div className="bitfun-about-dialog__section-title">{t('about.whatsNew')}</div>
+                <pre className="bitfun-about-dialog__changelog">{appInfo.changelog}</pre>
+              </div>
+            ) : null}
           </div>
         </div>
 
         {/* Footer */}
         <div className="bitfun-about-dialog__footer">
-          <p className="bitfun-about-dialog__license">{license.text}</p>
+          <p className="bitfun-about-dialog__license">
+            {appInfo ? t('about.licensedUnder', { license: appInfo.license.spdxId }) : license.text}
+          </p>
           <p className="bitfun-about-dialog__copyright">
             {t('about.copyright')}
           </p>
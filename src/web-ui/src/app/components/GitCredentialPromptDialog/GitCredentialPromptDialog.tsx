@@ -0,0 +1,81 @@
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import { InputDialog } from '@/component-library';
+import {
+  gitCredentialAPI,
+  type GitCredentialPrompt,
+} from '@/infrastructure/api/service-api/GitCredentialAPI';
+import { useI18n } from '@/infrastructure/i18n';
+import { isTauriRuntime } from '@/infrastructure/runtime';
+import { notificationService } from '@/shared/notification-system';
+import { createLogger } from '@/shared/utils/logger';
+import { applyGitAskpassEvent } from './gitCredentialPromptQueue';
+
+const log = createLogger('GitCredentialPromptDialog');
+
+/**
+ * Answers credential prompts from Git commands BitFun runs (clone, fetch,
+ * push). Closing the dialog cancels the prompt, which fails the Git command.
+ */
+export const GitCredentialPromptDialog: React.FC = () => {
+  const { t } = useI18n('notifications');
+  const [queue, setQueue] = useState<GitCredentialPrompt[]>([]);
+  // InputDialog calls onClose right after onConfirm; answer each prompt once.
+  const answeredRef = useRef(new Set<string>());
+  const current = queue[0] ?? null;
+
+  useEffect(() => {
+    if (!isTauriRuntime()) {
+      return undefined;
+    }
+    const unlisten = gitCredentialAPI.onGitAskpassEvent(event => {
+      setQueue(prev => applyGitAskpassEvent(prev, event));
+    });
+    // Prompts raised before this listener existed.
+    gitCredentialAPI
+      .listPending()
+      .then(pending =>
+        setQueue(prev =>
+          pending.reduce(
+            (next, prompt) => applyGitAskpassEvent(next, { type: 'requested', prompt }),
+            prev,
+          ),
+        ),
+      )
+      .catch(error => log.error('Failed to load pending Git credential prompts', { error }));
+    return unlisten;
+  }, []);
+
+  const respond = useCallback(
+    (prompt: GitCredentialPrompt, answer: string | null) => {
+      if (answeredRef.current.has(prompt.id)) {
+        return;
+      }
+      answeredRef.current.add(prompt.id);
+      setQueue(prev => prev.filter(item => item.id !== prompt.id));
+      gitCredentialAPI.respond(prompt.id, answer).catch(error => {
+        log.error('Failed to answer Git credential prompt', { promptId: prompt.id, error });
+        notificationService.error(t('gitCredential.failed'));
+      });
+    },
+    [t],
+  );
+
+  if (!current) {
+    return null;
+  }
+
+  return (
+    <InputDialog
+      key={current.id}
+      isOpen
+      title={t('gitCredential.title')}
+      description={current.prompt.trim()}
+      placeholder=""
+      inputType={current.secret ? 'password' : 'text'}
+      required={false}
+      confirmText={t('gitCredential.submit')}
+      onConfirm={value => respond(current, value)}
+      onClose={() => respond(current, null)}
+    />
+  );
+};
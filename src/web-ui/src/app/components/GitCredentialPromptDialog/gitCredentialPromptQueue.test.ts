@@ -0,0 +1,29 @@
+import { describe, expect, it } from 'vitest';
+import type { GitCredentialPrompt } from '@/infrastructure/api/service-api/GitCredentialAPI';
+import { applyGitAskpassEvent } from './gitCredentialPromptQueue';
+
+function prompt(id: string, createdAtMs: number): GitCredentialPrompt {
+  return {
+    id,
+    prompt: "Password for 'https://example.com': ",
+    secret: true,
+    createdAtMs,
+    expiresAtMs: createdAtMs + 300_000,
+  };
+}
+
+describe('applyGitAskpassEvent', () => {
+  it('queues new prompts oldest first and ignores repeats', () => {
+    let queue = applyGitAskpassEvent([], { type: 'requested', prompt: prompt('b', 2) });
+    queue = applyGitAskpassEvent(queue, { type: 'requested', prompt: prompt('a', 1) });
+    queue = applyGitAskpassEvent(queue, { type: 'requested', prompt: prompt('b', 2) });
+    expect(queue.map(item => item.id)).toEqual(['a', 'b']);
+  });
+
+  it('drops prompts resolved elsewhere or timed out', () => {
+    const queue = [prompt('a', 1), prompt('b', 2)];
+    expect(
+      applyGitAskpassEvent(queue, { type: 'resolved', id: 'a', answered: false }).map(item => item.id),
+    ).toEqual(['b']);
+  });
+});
@@ -0,0 +1,18 @@
+import type {
+  GitAskpassEvent,
+  GitCredentialPrompt,
+} from '@/infrastructure/api/service-api/GitCredentialAPI';
+
+/** Prompts waiting for an answer, oldest first, without duplicates. */
+export function applyGitAskpassEvent(
+  queue: GitCredentialPrompt[],
+  event: GitAskpassEvent,
+): GitCredentialPrompt[] {
+  if (event.type === 'resolved') {
+    return queue.filter(prompt => prompt.id !== event.id);
+  }
+  if (queue.some(prompt => prompt.id === event.prompt.id)) {
+    return queue;
+  }
+  return [...queue, event.prompt].sort((left, right) => left.createdAtMs - right.createdAtMs);
+}
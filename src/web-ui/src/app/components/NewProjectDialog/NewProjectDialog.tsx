@@ -2,27 +2,41 @@
  * New Project Dialog Component
  */
 
-import React, { useState, useCallback, useMemo } from 'react';
+import React, { useState, useCallback, useEffect, useMemo } from 'react';
 import { 
   FolderPlus, 
   FolderOpen, 
   FileText,
   FolderTree,
+  LayoutTemplate,
   AlertCircle,
   Check,
   X
 } from 'lucide-react';
 import { useTranslation } from 'react-i18next';
 import { createLogger } from '@/shared/utils/logger';
-import { Modal, Button, Input } from '@/component-library';
+import { Modal, Button, Input, Select } from '@/component-library';
+import { projectTemplateAPI } from '@/infrastructure/api/service-api/ProjectTemplateAPI';
+import type {
+  ProjectTemplateSummary,
+  ScaffoldProgress,
+} from '@/infrastructure/api/service-api/ProjectTemplateAPI';
 import './NewProjectDialog.scss';
 
 const log = createLogger('NewProjectDialog');
 
+/** Matches the previous behaviour of creating an empty directory. */
+const DEFAULT_TEMPLATE_ID = 'empty';
+
 export interface NewProjectDialogProps {
   isOpen: boolean;
   onClose: () => void;
-  onConfirm: (parentPath: string, projectName: string) => Promise<void>;
+  onConfirm: (
+    parentPath: string,
+    projectName: string,
+    templateId: string,
+    onProgress: (progress: ScaffoldProgress) => void
+  ) => Promise<void>;
   defaultParentPath?: string;
 }
 
@@ -37,6 +51,47 @@ export const NewProjectDialog: React.FC<NewProjectDialogProps> = ({
   const [projectName, setProjectName] = useState<string>('');
   const [isCreating, setIsCreating] = useState(false);
   const [error, setError] = useState<string>('');
+  const [templates, setTemplates] = useState<ProjectTemplateSummary[]>([]);
+  const [templateId, setTemplateId] = useState<string>(DEFAULT_TEMPLATE_ID);
+  const [progress, setProgress] = useState<ScaffoldProgress | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    projectTemplateAPI
+      .listTemplates()
+      .then(catalog => {
+        if (!cancelled) setTemplates(catalog.templates);
+      })
+      .catch(error => log.warn('Failed to load project templates', error));
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen]);
+
+  const templateOptions = useMemo(
+    () =>
+      templates.map(template => ({
+        value: template.id,
+        label:
+          template.source === 'builtin'
+            ? t(`newProject.templates.${template.id}`, { defaultValue: template.id })
+            : template.name ?? template.id,
+        description: template.description,
+      })),
+    [templates, t]
+  );
+
+  const progressText = useMemo(() => {
+    if (!progress || progress.phase === 'completed') return '';
+    return progress.phase === 'writing_files'
+      ? t('newProject.progressFile', {
+          current: progress.current,
+          completed: progress.completed,
+          total: progress.total,
+        })
+      : t('newProject.progressCommand', { current: progress.current });
+  }, [progress, t]);
 
   // Combine parent path and project name
   const fullPath = useMemo(() => {
@@ -81,22 +136,25 @@ export const NewProjectDialog: React.FC<NewProjectDialogProps> = ({
     setError('');
 
     try {
-      await onConfirm(parentPath, projectName.trim());
+      await onConfirm(parentPath, projectName.trim(), templateId, setProgress);
       setParentPath('');
       setProjectName('');
+      setTemplateId(DEFAULT_TEMPLATE_ID);
       onClose();
     } catch (error) {
       log.error('Failed to create project', error);
       setError(error instanceof Error ? error.message : t('newProject.errorCreateFailed'));
     } finally {
       setIsCreating(false);
+      setProgress(null);
     }
-  }, [parentPath, projectName, onConfirm, onClose, t]);
+  }, [parentPath, projectName, templateId, onConfirm, onClose, t]);
 
   // Reset form and close dialog
   const handleCancel = useCallback(() => {
     setParentPath('');
     setProjectName('');
+    setTemplateId(DEFAULT_TEMPLATE_ID);
     setError('');
     onClose();
   }, [onClose]);
@@ -173,6 +231,23 @@ export const NewProjectDialog: React.FC<NewProjectDialogProps> = ({
             </div>
           </div>
 
+          {/* Template */}
+          {templateOptions.length > 0 && (
+            <div className="new-project-dialog__field">
+              <label className="new-project-dialog__label">
+                <LayoutTemplate size={14} />
+                {t('newProject.template')}
+              </label>
+              <Select
+                size="small"
+                options={templateOptions}
+                value={templateId}
+                onChange={value => setTemplateId(String(value))}
+                disabled={isCreating}
+              />
+            </div>
+          )}
+
           {/* Full path display */}
           {fullPath && (
             <div className="new-project-dialog__preview">
@@ -186,6 +261,10 @@ export const NewProjectDialog: React.FC<NewProjectDialogProps> = ({
             </div>
           )}
 
+          {progressText && (
+            <div className="new-project-dialog__progress">{progressText}</div>
+          )}
+
           {/* Error message */}
           {error && (
             <div className="new-project-dialog__error">
@@ -16,6 +16,7 @@ import { useExplorerSearch } from '@/tools/file-explorer';
 import { Search, IconButton, Tooltip, Badge, confirmWarning } from '@/component-library';
 import { FileSearchResults } from '@/tools/file-system/components/FileSearchResults';
 import { workspaceAPI } from '@/infrastructure/api';
+import { isTrashUnavailableError } from '@/infrastructure/api/service-api/WorkspaceAPI';
 import type { FileSystemNode } from '@/tools/file-system/types';
 import { globalEventBus } from '@/infrastructure/event-bus';
 import { useNotification } from '@/shared/notification-system';
@@ -451,12 +452,25 @@ const FilesPanel: React.FC<FilesPanelProps> = ({
 
   const handleDelete = useCallback(async (data: { path: string; isDirectory: boolean }) => {
     const normalizedPath = normalizePathForCurrentWorkspace(data.path);
+    const remove = (permanent: boolean) => data.isDirectory
+      ? workspaceAPI.deleteDirectory(normalizedPath, true, currentWorkspace?.connectionId, permanent)
+      : workspaceAPI.deleteFile(normalizedPath, currentWorkspace?.connectionId, permanent);
 
     try {
-      if (data.isDirectory) {
-        await workspaceAPI.deleteDirectory(normalizedPath, true, currentWorkspace?.connectionId);
-      } else {
-        await workspaceAPI.deleteFile(normalizedPath, currentWorkspace?.connectionId);
+      try {
+        await remove(false);
+      } catch (error) {
+        if (!isTrashUnavailableError(error)) throw error;
+        const confirmed = await confirmWarning(
+          t('dialog.deletePermanently.title'),
+          t('dialog.deletePermanently.message', { name: basenamePath(normalizedPath) }),
+          {
+            confirmText: t('dialog.deletePermanently.confirm'),
+            cancelText: t('dialog.deletePermanently.cancel'),
+          },
+        );
+        if (!confirmed) return;
+        await remove(true);
       }
       log.info('File deleted', { path: normalizedPath, isDirectory: data.isDirectory });
       removePath(normalizedPath);
@@ -0,0 +1,114 @@
+/**
+ * Open files context sync.
+ * Mirrors the file tabs open in the agent and project canvases to the backend
+ * so the `open_files` context provider can list them in agent prompts.
+ */
+
+import { contextProviderAPI } from '@/infrastructure/api/service-api/ContextProviderAPI';
+import type { OpenEditorFile } from '@/infrastructure/api/service-api/ContextProviderAPI';
+import { workspaceManager } from '@/infrastructure/services/business/workspaceManager';
+import { normalizePath } from '@/shared/utils/pathUtils';
+import { createLogger } from '@/shared/utils/logger';
+import type { EditorGroupId, EditorGroupState } from '../types';
+import { isFileViewerType } from '../types';
+import { useAgentCanvasStore, useProjectCanvasStore } from './canvasStore';
+
+const log = createLogger('OpenFilesContextSync');
+
+const SYNC_DEBOUNCE_MS = 500;
+
+type CanvasStoreState = ReturnType<typeof useAgentCanvasStore.getState>;
+
+let started = false;
+let syncTimer: ReturnType<typeof setTimeout> | null = null;
+const lastSynced = new Map<string, string>();
+
+function groupsOf(state: CanvasStoreState): Array<[EditorGroupId, EditorGroupState]> {
+  return [
+    ['primary', state.primaryGroup],
+    ['secondary', state.secondaryGroup],
+    ['tertiary', state.tertiaryGroup],
+  ];
+}
+
+function collectOpenFiles(states: CanvasStoreState[]): Map<string, OpenEditorFile[]> {
+  const fallbackWorkspace = workspaceManager.getWorkspacePath();
+  const byWorkspace = new Map<string, Map<string, OpenEditorFile>>();
+
+  for (const state of states) {
+    for (const [groupId, group] of groupsOf(state)) {
+      for (const tab of group.tabs) {
+        const filePath: unknown = tab.content.data?.filePath;
+        if (tab.isHidden || !isFileViewerType(tab.content.type) || typeof filePath !== 'string') {
+          continue;
+        }
+        const workspacePath: string = tab.content.data?.workspacePath || fallbackWorkspace;
+        if (!workspacePath) {
+          continue;
+        }
+
+        const path = normalizePath(filePath);
+        const files = byWorkspace.get(workspacePath) ?? new Map<string, OpenEditorFile>();
+        const existing = files.get(path);
+        const active = state.activeGroupId === groupId && group.activeTabId === tab.id;
+        files.set(path, {
+          path,
+          active: Boolean(existing?.active) || active,
+          dirty: Boolean(existing?.dirty) || tab.isDirty,
+        });
+        byWorkspace.set(workspacePath, files);
+      }
+    }
+  }
+
+  return new Map(
+    Array.from(byWorkspace, ([workspacePath, files]) => [workspacePath, Array.from(files.values())]),
+  );
+}
+
+async function syncOpenFiles(): Promise<void> {
+  syncTimer = null;
+  const current = collectOpenFiles([
+    useAgentCanvasStore.getState(),
+    useProjectCanvasStore.getState(),
+  ]);
+
+  // Workspaces whose tabs were all closed are cleared with an empty list.
+  const workspaces = new Set([...lastSynced.keys(), ...current.keys()]);
+  for (const workspacePath of workspaces) {
+    const files = current.get(workspacePath) ?? [];
+    const serialized = JSON.stringify(files);
+    if (lastSynced.get(workspacePath) === serialized) {
+      continue;
+    }
+    try {
+      await contextProviderAPI.setOpenFiles({ workspacePath, files });
+      if (files.length === 0) {
+        lastSynced.delete(workspacePath);
+      } else {
+        lastSynced.set(workspacePath, serialized);
+      }
+    } catch (error) {
+      log.warn('Failed to sync open files', { workspacePath, error });
+    }
+  }
+}
+
+function scheduleSync(): void {
+  if (syncTimer) {
+    clearTimeout(syncTimer);
+  }
+  syncTimer = setTimeout(() => {
+    void syncOpenFiles();
+  }, SYNC_DEBOUNCE_MS);
+}
+
+export function initOpenFilesContextSync(): void {
+  if (started) {
+    return;
+  }
+  started = true;
+  useAgentCanvasStore.subscribe(scheduleSync);
+  useProjectCanvasStore.subscribe(scheduleSync);
+  scheduleSync();
+}
@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest';
+import type { ApprovalRequest } from '@/infrastructure/api/service-api/ApprovalAPI';
+import { approvalPromptMessage, showsApprovalPrompt } from './approvalPrompts';
+
+function request(overrides: Partial<ApprovalRequest> = {}): ApprovalRequest {
+  return {
+    id: 'approval_1',
+    kind: 'plugin_permission',
+    title: 'Enable plugin "Demo"',
+    subject: 'demo@1.0.0',
+    createdAtMs: 0,
+    onTimeout: 'deny',
+    allowRemember: false,
+    ...overrides,
+  };
+}
+
+describe('showsApprovalPrompt', () => {
+  it('prompts for approvals outside a chat session', () => {
+    expect(showsApprovalPrompt(request())).toBe(true);
+  });
+
+  it('leaves session approvals to the chat permission prompt', () => {
+    expect(showsApprovalPrompt(request({ kind: 'dangerous_command', sessionId: 's1' }))).toBe(false);
+  });
+});
+
+describe('approvalPromptMessage', () => {
+  it('prefers the detail and falls back to the subject', () => {
+    expect(approvalPromptMessage(request({ detail: 'Runs `demo` on this computer.' }))).toBe(
+      'Runs `demo` on this computer.',
+    );
+    expect(approvalPromptMessage(request({ detail: '  ' }))).toBe('demo@1.0.0');
+  });
+});
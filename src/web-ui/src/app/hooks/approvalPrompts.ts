@@ -0,0 +1,13 @@
+import type { ApprovalRequest } from '@/infrastructure/api/service-api/ApprovalAPI';
+
+/**
+ * Approvals tied to a chat session mirror that session's tool permission
+ * prompt, which already asks in the chat; answering either settles both.
+ */
+export function showsApprovalPrompt(request: ApprovalRequest): boolean {
+  return !request.sessionId;
+}
+
+export function approvalPromptMessage(request: ApprovalRequest): string {
+  return request.detail?.trim() || request.subject;
+}
@@ -0,0 +1,36 @@
+import { describe, expect, it } from 'vitest';
+import { mergeMcpServerIntoJsonConfig } from './deepLinkActions';
+
+describe('mergeMcpServerIntoJsonConfig', () => {
+  it('adds the server under an id derived from its name', () => {
+    const { jsonConfig, serverId } = mergeMcpServerIntoJsonConfig(
+      '',
+      { name: 'Demo Server', url: 'https://example.com/mcp' },
+    );
+
+    expect(serverId).toBe('demo-server');
+    expect(JSON.parse(jsonConfig)).toEqual({
+      mcpServers: {
+        'demo-server': { name: 'Demo Server', url: 'https://example.com/mcp' },
+      },
+    });
+  });
+
+  it('keeps existing servers and suffixes a clashing id', () => {
+    const current = JSON.stringify({
+      mcpServers: { demo: { command: 'node' }, 'demo-2': { command: 'deno' } },
+    });
+
+    const { jsonConfig, serverId } = mergeMcpServerIntoJsonConfig(
+      current,
+      { id: 'demo', command: 'bun' },
+    );
+
+    expect(serverId).toBe('demo-3');
+    expect(JSON.parse(jsonConfig).mcpServers).toEqual({
+      demo: { command: 'node' },
+      'demo-2': { command: 'deno' },
+      'demo-3': { command: 'bun' },
+    });
+  });
+});
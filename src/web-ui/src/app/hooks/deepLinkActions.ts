@@ -0,0 +1,43 @@
+export interface MergedMcpServerConfig {
+  jsonConfig: string;
+  serverId: string;
+}
+
+function serverIdBase(config: Record<string, unknown>): string {
+  const raw = typeof config.id === 'string' && config.id.trim()
+    ? config.id
+    : typeof config.name === 'string' ? config.name : '';
+  const slug = raw.trim().toLowerCase().replace(/[^a-z0-9_-]+/g, '-').replace(/^-+|-+$/g, '');
+  return slug || 'mcp-server';
+}
+
+/**
+ * Adds a server config from an `add-mcp-server` link to the `mcpServers`
+ * map of mcp.json. Existing servers are never replaced; a clashing id gets a
+ * numeric suffix instead.
+ */
+export function mergeMcpServerIntoJsonConfig(
+  jsonConfig: string,
+  config: Record<string, unknown>,
+): MergedMcpServerConfig {
+  const parsed: unknown = jsonConfig.trim() ? JSON.parse(jsonConfig) : {};
+  const root = parsed && typeof parsed === 'object' && !Array.isArray(parsed)
+    ? parsed as Record<string, unknown>
+    : {};
+  const existing = root.mcpServers;
+  const servers = existing && typeof existing === 'object' && !Array.isArray(existing)
+    ? existing as Record<string, unknown>
+    : {};
+
+  const base = serverIdBase(config);
+  let serverId = base;
+  for (let suffix = 2; serverId in servers; suffix += 1) {
+    serverId = `${base}-${suffix}`;
+  }
+
+  const { id: _id, ...serverConfig } = config;
+  return {
+    jsonConfig: JSON.stringify({ ...root, mcpServers: { ...servers, [serverId]: serverConfig } }, null, 2),
+    serverId,
+  };
+}
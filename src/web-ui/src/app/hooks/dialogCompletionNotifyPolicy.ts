@@ -25,16 +25,28 @@ export function shouldSendDialogCompletionNotification({
     return false;
   }
 
-  if (!session) {
-    return false;
-  }
+  return isAnnounceableSession(session);
+}
 
-  const sessionKind = session?.sessionKind ?? 'normal';
-  if (sessionKind === 'btw' || sessionKind === 'review' || sessionKind === 'subagent') {
+/**
+ * Read-aloud is an accessibility aid, so unlike desktop notifications it also
+ * applies while the window is focused.
+ */
+export function shouldReadDialogCompletionAloud(
+  session?: Pick<Session, 'sessionKind' | 'parentSessionId'> | null,
+): boolean {
+  return isAnnounceableSession(session);
+}
+
+function isAnnounceableSession(
+  session?: Pick<Session, 'sessionKind' | 'parentSessionId'> | null,
+): boolean {
+  if (!session) {
     return false;
   }
 
-  return true;
+  const sessionKind = session.sessionKind ?? 'normal';
+  return sessionKind !== 'btw' && sessionKind !== 'review' && sessionKind !== 'subagent';
 }
 
 export function buildDialogCompletionNotificationCopy({
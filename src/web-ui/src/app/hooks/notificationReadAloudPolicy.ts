@@ -0,0 +1,17 @@
+import type { Notification } from '@/shared/notification-system/types';
+
+/** Progress and loading toasts update too often to be spoken; silent ones never show. */
+export function shouldReadNotificationAloud(notification: Pick<Notification, 'variant'>): boolean {
+  return notification.variant === 'toast' || notification.variant === 'persistent';
+}
+
+export function buildNotificationSpeechText(
+  notification: Pick<Notification, 'title' | 'message'>,
+): string {
+  const title = notification.title.trim();
+  const message = notification.message.trim();
+  if (!title || title === message) {
+    return message;
+  }
+  return message ? `${title}. ${message}` : title;
+}
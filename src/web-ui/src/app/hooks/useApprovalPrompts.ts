@@ -0,0 +1,94 @@
+import { useEffect } from 'react';
+import {
+  approvalAPI,
+  type ApprovalDecision,
+  type ApprovalRequest,
+} from '@/infrastructure/api/service-api/ApprovalAPI';
+import { i18nService } from '@/infrastructure/i18n';
+import { isTauriRuntime } from '@/infrastructure/runtime';
+import { notificationService } from '@/shared/notification-system';
+import { createLogger } from '@/shared/utils/logger';
+import { approvalPromptMessage, showsApprovalPrompt } from './approvalPrompts';
+
+const log = createLogger('useApprovalPrompts');
+
+/**
+ * Shows pending approvals (plugin permissions, remote device actions) as
+ * notifications the user answers in place. A notification goes away once its
+ * approval is resolved, whether here, from a remote client or by timeout.
+ */
+export const useApprovalPrompts = () => {
+  useEffect(() => {
+    if (!isTauriRuntime()) {
+      return undefined;
+    }
+
+    let disposed = false;
+    const notifications = new Map<string, string>();
+    const t = (key: string) => i18nService.t(`notifications:approvals.${key}`);
+
+    const dismiss = (requestId: string) => {
+      const notificationId = notifications.get(requestId);
+      if (notificationId) {
+        notifications.delete(requestId);
+        notificationService.dismiss(notificationId);
+      }
+    };
+
+    const respond = (request: ApprovalRequest, decision: ApprovalDecision, remember = false) => {
+      void approvalAPI
+        .respond(request.id, decision, remember)
+        .then(() => dismiss(request.id))
+        .catch(error => {
+          log.error('Failed to answer approval', { requestId: request.id, error });
+          notificationService.error(t('failed'));
+        });
+    };
+
+    const show = (request: ApprovalRequest) => {
+      if (disposed || notifications.has(request.id) || !showsApprovalPrompt(request)) {
+        return;
+      }
+      const notificationId = notificationService.persistent({
+        type: 'warning',
+        title: request.title,
+        message: approvalPromptMessage(request),
+        closable: false,
+        actions: [
+          { label: t('approve'), variant: 'primary', onClick: () => respond(request, 'approve') },
+          ...(request.allowRemember
+            ? [
+                {
+                  label: t('alwaysApprove'),
+                  variant: 'secondary' as const,
+                  onClick: () => respond(request, 'approve', true),
+                },
+              ]
+            : []),
+          { label: t('deny'), variant: 'danger', onClick: () => respond(request, 'deny') },
+        ],
+      });
+      notifications.set(request.id, notificationId);
+    };
+
+    const unlisten = approvalAPI.onApprovalEvent(event => {
+      if (event.type === 'requested') {
+        show(event.request);
+      } else {
+        dismiss(event.request.id);
+      }
+    });
+    // Requests raised before this listener existed, e.g. at startup.
+    approvalAPI
+      .listPending()
+      .then(pending => pending.forEach(show))
+      .catch(error => log.error('Failed to load pending approvals', { error }));
+
+    return () => {
+      disposed = true;
+      unlisten();
+      notifications.forEach(notificationId => notificationService.dismiss(notificationId));
+      notifications.clear();
+    };
+  }, []);
+};
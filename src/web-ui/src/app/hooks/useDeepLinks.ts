@@ -0,0 +1,96 @@
+import { useEffect } from 'react';
+import { configAPI } from '@/infrastructure/api/service-api/ConfigAPI';
+import { deepLinkAPI, type DeepLinkAction } from '@/infrastructure/api/service-api/DeepLinkAPI';
+import { MCPAPI } from '@/infrastructure/api/service-api/MCPAPI';
+import { i18nService } from '@/infrastructure/i18n';
+import { isTauriRuntime } from '@/infrastructure/runtime';
+import { workspaceManager } from '@/infrastructure/services/business/workspaceManager';
+import { notificationService } from '@/shared/notification-system';
+import { createLogger } from '@/shared/utils/logger';
+import { mergeMcpServerIntoJsonConfig } from './deepLinkActions';
+
+const log = createLogger('useDeepLinks');
+
+function errorMessage(error: unknown): string {
+  return error instanceof Error ? error.message : String(error);
+}
+
+async function runDeepLinkAction(link: DeepLinkAction): Promise<void> {
+  const t = (key: string, options?: Record<string, unknown>) =>
+    i18nService.t(`notifications:deepLink.${key}`, options);
+
+  switch (link.action) {
+    case 'open':
+      try {
+        await workspaceManager.openWorkspace(link.path);
+        notificationService.success(t('opened', { path: link.path }));
+      } catch (error) {
+        notificationService.error(t('openFailed', { path: link.path, error: errorMessage(error) }));
+      }
+      return;
+    case 'installSkill':
+      try {
+        // User level: a link carries no workspace to install into.
+        const result = await configAPI.downloadSkillMarket({
+          packageId: link.skillId,
+          level: 'user',
+        });
+        notificationService.success(t('skillInstalled', {
+          name: result.installedSkills[0] ?? link.skillId,
+        }));
+      } catch (error) {
+        notificationService.error(t('skillInstallFailed', {
+          name: link.skillId,
+          error: errorMessage(error),
+        }));
+      }
+      return;
+    case 'addMcpServer':
+      try {
+        const current = await MCPAPI.loadMCPJsonConfig();
+        const { jsonConfig, serverId } = mergeMcpServerIntoJsonConfig(current, link.config);
+        await MCPAPI.saveMCPJsonConfig(jsonConfig);
+        notificationService.success(t('mcpServerAdded', { id: serverId }));
+      } catch (error) {
+        notificationService.error(t('mcpServerAddFailed', { error: errorMessage(error) }));
+      }
+      return;
+  }
+}
+
+/**
+ * Acts on `bitfun://` links the user confirmed in the native dialog. The
+ * queue is drained once on mount, because a cold-start link is confirmed
+ * before this listener exists, and again on every received event.
+ */
+export const useDeepLinks = () => {
+  useEffect(() => {
+    if (!isTauriRuntime()) {
+      return undefined;
+    }
+
+    let disposed = false;
+    let draining = Promise.resolve();
+    const drain = () => {
+      draining = draining.then(async () => {
+        if (disposed) {
+          return;
+        }
+        try {
+          for (const link of await deepLinkAPI.takePending()) {
+            await runDeepLinkAction(link);
+          }
+        } catch (error) {
+          log.error('Failed to handle deep links', { error });
+        }
+      });
+    };
+
+    const unlisten = deepLinkAPI.onReceived(drain);
+    drain();
+    return () => {
+      disposed = true;
+      unlisten();
+    };
+  }, []);
+};
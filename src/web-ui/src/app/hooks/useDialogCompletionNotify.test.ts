@@ -3,6 +3,7 @@ import type { AgenticEvent } from '@/infrastructure/api/service-api/AgentAPI';
 import type { Session } from '@/flow_chat/types/flow-chat';
 import {
   buildDialogCompletionNotificationCopy,
+  shouldReadDialogCompletionAloud,
   shouldSendDialogCompletionNotification,
 } from './dialogCompletionNotifyPolicy';
 
@@ -116,6 +117,20 @@ describe('shouldSendDialogCompletionNotification', () => {
   });
 });
 
+describe('shouldReadDialogCompletionAloud', () => {
+  it('reads normal session completions even while the window is focused', () => {
+    expect(shouldReadDialogCompletionAloud(session())).toBe(true);
+  });
+
+  it('skips child sessions and unknown sessions', () => {
+    expect(
+      shouldReadDialogCompletionAloud(session({ sessionKind: 'subagent', parentSessionId: 'parent-1' })),
+    ).toBe(false);
+    expect(shouldReadDialogCompletionAloud(session({ sessionKind: 'btw' }))).toBe(false);
+    expect(shouldReadDialogCompletionAloud(undefined)).toBe(false);
+  });
+});
+
 describe('buildDialogCompletionNotificationCopy', () => {
   const t = (key: string, options?: Record<string, unknown>) => {
     if (key === 'notify.dialogCompletedTitle') return 'BitFun finished a task';
@@ -4,9 +4,11 @@ import { systemAPI } from '@/infrastructure/api/service-api/SystemAPI';
 import { configManager } from '@/infrastructure/config/services/ConfigManager';
 import { flowChatStore } from '@/flow_chat/store/FlowChatStore';
 import { useI18n } from '@/infrastructure/i18n';
+import { readAloud } from '@/infrastructure/speech/readAloud';
 import { createLogger } from '@/shared/utils/logger';
 import {
   buildDialogCompletionNotificationCopy,
+  shouldReadDialogCompletionAloud,
   shouldSendDialogCompletionNotification,
 } from './dialogCompletionNotifyPolicy';
 
@@ -22,6 +24,9 @@ const log = createLogger('useDialogCompletionNotify');
  *
  * "Not focused" means: the page is hidden (minimized / tab switched) OR
  * the window has lost focus to another OS-level application.
+ *
+ * The same copy is read aloud, focused or not, when text-to-speech is enabled
+ * for task summaries.
  */
 export const useDialogCompletionNotify = () => {
   const { t } = useI18n('common');
@@ -53,6 +58,17 @@ export const useDialogCompletionNotify = () => {
       const session = sessionId
         ? flowChatStore.getState().sessions.get(sessionId)
         : undefined;
+      const notificationCopy = buildDialogCompletionNotificationCopy({
+        sessionTitle: session?.title,
+        success: event?.success,
+        finishReason: event?.finishReason ?? event?.finish_reason,
+        t,
+      });
+
+      if (shouldReadDialogCompletionAloud(session)) {
+        void readAloud(notificationCopy.body, 'task_summary');
+      }
+
       if (
         !shouldSendDialogCompletionNotification({
           event,
@@ -64,13 +80,6 @@ export const useDialogCompletionNotify = () => {
         return;
       }
 
-      const notificationCopy = buildDialogCompletionNotificationCopy({
-        sessionTitle: session?.title,
-        success: event?.success,
-        finishReason: event?.finishReason ?? event?.finish_reason,
-        t,
-      });
-
       await systemAPI.sendSystemNotification(
         notificationCopy.title,
         notificationCopy.body,
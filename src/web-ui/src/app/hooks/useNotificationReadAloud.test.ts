@@ -0,0 +1,25 @@
+import { describe, expect, it } from 'vitest';
+import {
+  buildNotificationSpeechText,
+  shouldReadNotificationAloud,
+} from './notificationReadAloudPolicy';
+
+describe('shouldReadNotificationAloud', () => {
+  it('reads toasts and persistent notifications only', () => {
+    expect(shouldReadNotificationAloud({ variant: 'toast' })).toBe(true);
+    expect(shouldReadNotificationAloud({ variant: 'persistent' })).toBe(true);
+    expect(shouldReadNotificationAloud({ variant: 'progress' })).toBe(false);
+    expect(shouldReadNotificationAloud({ variant: 'loading' })).toBe(false);
+    expect(shouldReadNotificationAloud({ variant: 'silent' })).toBe(false);
+  });
+});
+
+describe('buildNotificationSpeechText', () => {
+  it('joins the title and message without repeating either', () => {
+    expect(buildNotificationSpeechText({ title: 'Saved', message: 'Settings updated' }))
+      .toBe('Saved. Settings updated');
+    expect(buildNotificationSpeechText({ title: 'Saved', message: 'Saved' })).toBe('Saved');
+    expect(buildNotificationSpeechText({ title: '', message: 'Copied' })).toBe('Copied');
+    expect(buildNotificationSpeechText({ title: 'Offline', message: ' ' })).toBe('Offline');
+  });
+});
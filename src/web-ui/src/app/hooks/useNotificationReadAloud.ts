@@ -0,0 +1,29 @@
+import { useEffect } from 'react';
+import { notificationStore } from '@/shared/notification-system/store/NotificationStore';
+import { readAloud } from '@/infrastructure/speech/readAloud';
+import {
+  buildNotificationSpeechText,
+  shouldReadNotificationAloud,
+} from './notificationReadAloudPolicy';
+
+/**
+ * Reads newly shown in-app notifications aloud when text-to-speech is enabled
+ * for notifications.
+ */
+export const useNotificationReadAloud = () => {
+  useEffect(() => {
+    const seen = new Set(notificationStore.getState().activeNotifications.map(item => item.id));
+
+    return notificationStore.subscribe(state => {
+      for (const notification of state.activeNotifications) {
+        if (seen.has(notification.id)) {
+          continue;
+        }
+        seen.add(notification.id);
+        if (shouldReadNotificationAloud(notification)) {
+          void readAloud(buildNotificationSpeechText(notification), 'notification');
+        }
+      }
+    });
+  }, []);
+};
@@ -0,0 +1,102 @@
+import { useEffect } from 'react';
+import { confirmDialogChoice } from '@/component-library';
+import {
+  workspaceTrustAPI,
+  type WorkspaceTrustDecision,
+} from '@/infrastructure/api/service-api/WorkspaceTrustAPI';
+import { i18nService } from '@/infrastructure/i18n';
+import { isTauriRuntime } from '@/infrastructure/runtime';
+import { workspaceManager } from '@/infrastructure/services/business/workspaceManager';
+import { notificationService } from '@/shared/notification-system';
+import { createLogger } from '@/shared/utils/logger';
+import { trustPromptPath } from './workspaceTrustPrompt';
+
+const log = createLogger('useWorkspaceTrustPrompt');
+
+/**
+ * Asks once per normalized folder; "Later" holds for the rest of the app
+ * session. `prompted` is keyed by the backend's normalized path because the
+ * backend event and the workspace manager may spell the same folder
+ * differently.
+ */
+async function promptForTrust(path: string, prompted: Set<string>): Promise<void> {
+  const t = (key: string, options?: Record<string, unknown>) =>
+    i18nService.t(`notifications:workspaceTrust.${key}`, options);
+
+  const info = await workspaceTrustAPI.getTrust(path);
+  if (info.state !== 'unknown' || prompted.has(info.path)) {
+    return;
+  }
+  prompted.add(info.path);
+
+  const choice = await confirmDialogChoice({
+    title: t('title'),
+    message: t('message', { path }),
+    type: 'warning',
+    confirmText: t('trust'),
+    secondaryText: t('restrict'),
+    cancelText: t('later'),
+  });
+  const decision: WorkspaceTrustDecision | null =
+    choice === 'confirm' ? 'trusted' : choice === 'secondary' ? 'restricted' : null;
+  if (!decision) {
+    return;
+  }
+
+  await workspaceTrustAPI.setTrust(path, decision);
+  if (decision === 'trusted') {
+    notificationService.success(t('trusted', { path }));
+  } else {
+    notificationService.info(t('restricted', { path }));
+  }
+}
+
+/**
+ * Asks for a trust decision the first time a folder without one becomes the
+ * active workspace. Until the user answers, project skills, project MCP
+ * servers and tool auto-approval stay off for that folder.
+ */
+export const useWorkspaceTrustPrompt = () => {
+  useEffect(() => {
+    if (!isTauriRuntime()) {
+      return undefined;
+    }
+
+    let disposed = false;
+    const prompted = new Set<string>();
+    let queue = Promise.resolve();
+    const request = (path: string | null) => {
+      if (!path) {
+        return;
+      }
+      queue = queue.then(async () => {
+        if (disposed) {
+          return;
+        }
+        try {
+          await promptForTrust(path, prompted);
+        } catch (error) {
+          log.error('Failed to resolve workspace trust', { path, error });
+          notificationService.error(
+            i18nService.t('notifications:workspaceTrust.failed', { path }),
+          );
+        }
+      });
+    };
+
+    const unlistenRequired = workspaceTrustAPI.onTrustRequired(info => request(info.path));
+    const unlistenWorkspace = workspaceManager.addEventListener(event => {
+      if (event.type === 'workspace:active-changed' || event.type === 'workspace:switched') {
+        request(trustPromptPath(event.workspace));
+      }
+    });
+    // The workspace restored at startup was opened before this listener existed.
+    request(trustPromptPath(workspaceManager.getState().currentWorkspace));
+
+    return () => {
+      disposed = true;
+      unlistenRequired();
+      unlistenWorkspace();
+    };
+  }, []);
+};
@@ -0,0 +1,20 @@
+import { describe, expect, it } from 'vitest';
+import { WorkspaceKind, type WorkspaceInfo } from '@/shared/types';
+import { trustPromptPath } from './workspaceTrustPrompt';
+
+function workspace(workspaceKind: WorkspaceKind, rootPath = '/work/app'): WorkspaceInfo {
+  return { rootPath, workspaceKind } as WorkspaceInfo;
+}
+
+describe('trustPromptPath', () => {
+  it('asks about local and assistant workspaces', () => {
+    expect(trustPromptPath(workspace(WorkspaceKind.Normal))).toBe('/work/app');
+    expect(trustPromptPath(workspace(WorkspaceKind.Assistant))).toBe('/work/app');
+  });
+
+  it('skips remote, missing and pathless workspaces', () => {
+    expect(trustPromptPath(workspace(WorkspaceKind.Remote))).toBeNull();
+    expect(trustPromptPath(null)).toBeNull();
+    expect(trustPromptPath(workspace(WorkspaceKind.Normal, ''))).toBeNull();
+  });
+});
@@ -0,0 +1,9 @@
+import { WorkspaceKind, type WorkspaceInfo } from '@/shared/types';
+
+/** Remote workspaces live on another machine and are not covered by local trust. */
+export function trustPromptPath(workspace: WorkspaceInfo | null): string | null {
+  if (!workspace || workspace.workspaceKind === WorkspaceKind.Remote) {
+    return null;
+  }
+  return workspace.rootPath || null;
+}
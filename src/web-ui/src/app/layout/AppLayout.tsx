@@ -21,7 +21,9 @@ import { FlowChatManager } from '../../flow_chat/services/FlowChatManager';
 import WorkspaceBody from './WorkspaceBody';
 import { useToolbarModeContext } from '../../flow_chat/components/toolbar-mode/ToolbarModeContext';
 import { MCPInteractionDialog } from '../components/MCPInteractionDialog/MCPInteractionDialog';
-import { workspaceAPI } from '@/infrastructure/api';
+import { GitCredentialPromptDialog } from '../components/GitCredentialPromptDialog/GitCredentialPromptDialog';
+import { projectTemplateAPI } from '@/infrastructure/api/service-api/ProjectTemplateAPI';
+import type { ScaffoldProgress } from '@/infrastructure/api/service-api/ProjectTemplateAPI';
 import { systemAPI } from '@/infrastructure/api/service-api/SystemAPI';
 import type { CloseBehavior } from '@/infrastructure/api/service-api/SystemAPI';
 import { confirmDialog } from '@/component-library';
@@ -32,7 +34,7 @@ import { WorkspaceKind } from '@/shared/types';
 import { SSHContext } from '@/features/ssh-remote/SSHRemoteContext';
 import { shortcutManager, parseStoredKeybindings } from '@/infrastructure/services/ShortcutManager';
 import { useSessionModeStore } from '../stores/sessionModeStore';
-import { isMacOSDesktopRuntime } from '@/infrastructure/runtime';
+import { isMacOSDesktopRuntime, isTauriRuntime } from '@/infrastructure/runtime';
 import { flowChatSessionConfigForWorkspace } from '../utils/projectSessionWorkspace';
 import { notificationService } from '@/shared/notification-system';
 import './AppLayout.scss';
@@ -56,6 +58,27 @@ const AboutDialog = lazy(() =>
 );
 const WorkspaceManager = lazy(() => import('../../tools/workspace/components/WorkspaceManager'));
 
+/**
+ * First launch after an install: opens model settings unless the installer
+ * already configured a model or models exist from an earlier install.
+ */
+async function openModelSetupAfterInstall(isCancelled: () => boolean): Promise<void> {
+  try {
+    const handoff = await systemAPI.getFirstLaunchContext();
+    if (!handoff || handoff.modelConfigured || isCancelled()) {
+      return;
+    }
+    const models = await configManager.getConfig<unknown[]>('ai.models');
+    if ((models?.length ?? 0) > 0 || isCancelled()) {
+      return;
+    }
+    const { quickActions } = await import('@/shared/services/ide-control');
+    quickActions.openSettings('models');
+  } catch (error) {
+    log.warn('Failed to apply first-launch handoff', error);
+  }
+}
+
 interface AppLayoutProps {
   className?: string;
 }
@@ -235,17 +258,39 @@ const AppLayout: React.FC<AppLayoutProps> = ({ className = '' }) => {
   const handleNewProject = useCallback(() => setShowNewProjectDialog(true), []);
   const handleShowAbout  = useCallback(() => setShowAboutDialog(true), []);
 
-  const handleConfirmNewProject = useCallback(async (parentPath: string, projectName: string) => {
-    const normalized = parentPath.replace(/\\/g, '/');
-    const newProjectPath = `${normalized}/${projectName}`;
+  const handleConfirmNewProject = useCallback(async (
+    parentPath: string,
+    projectName: string,
+    templateId: string,
+    onProgress: (progress: ScaffoldProgress) => void
+  ) => {
+    const operationId = `new-project-${Date.now()}`;
+    const unlisten = projectTemplateAPI.onScaffoldProgress(onProgress, operationId);
     try {
-      await workspaceAPI.createDirectory(newProjectPath);
-      await openWorkspace(newProjectPath);
+      const result = await projectTemplateAPI.newProjectFromTemplate({
+        parentPath,
+        projectName,
+        templateId,
+        operationId,
+      });
+      const failed = result.commands.filter(command => !command.success);
+      if (failed.length > 0) {
+        log.warn('Project setup commands failed', failed);
+        notificationService.warning(
+          tCommon('newProject.commandsFailed', {
+            commands: failed.map(command => command.commandLine).join(', '),
+          }),
+          { duration: 8000 }
+        );
+      }
+      await openWorkspace(result.projectPath);
     } catch (error) {
       log.error('Failed to create project', error);
       throw error;
+    } finally {
+      unlisten();
     }
-  }, [openWorkspace]);
+  }, [openWorkspace, tCommon]);
 
   // Listen for nav-panel events dispatched by the workspace area
   useEffect(() => {
@@ -389,6 +434,8 @@ const AppLayout: React.FC<AppLayoutProps> = ({ className = '' }) => {
               log.error('Failed to open pending settings', settingsError);
             }
           }, 500);
+        } else if (isTauriRuntime()) {
+          void openModelSetupAfterInstall(() => cancelled);
         }
       } catch (error) {
         if (cancelled) {
@@ -772,6 +819,7 @@ const AppLayout: React.FC<AppLayoutProps> = ({ className = '' }) => {
         </Suspense>
       )}
       <MCPInteractionDialog />
+      <GitCredentialPromptDialog />
     </>
   );
 };
@@ -21,6 +21,7 @@ import type { SceneTabId } from '../components/SceneBar/types';
 import { useSceneManager } from '../hooks/useSceneManager';
 import { useI18n } from '@/infrastructure/i18n/hooks/useI18n';
 import { useDialogCompletionNotify } from '../hooks/useDialogCompletionNotify';
+import { useNotificationReadAloud } from '../hooks/useNotificationReadAloud';
 import { ProcessingIndicator } from '@/flow_chat/components/modern/ProcessingIndicator';
 import SettingsScene from './settings/SettingsScene';
 import AssistantScene from './assistant/AssistantScene';
@@ -89,6 +90,7 @@ const SceneViewport: React.FC<SceneViewportProps> = ({ workspacePath, isEntering
   const readySceneIdsRef = useRef<Set<SceneTabId>>(new Set());
   const previousActiveTabIdRef = useRef<SceneTabId>(activeTabId);
   useDialogCompletionNotify();
+  useNotificationReadAloud();
 
   const markSceneReady = useCallback((sceneId: SceneTabId) => {
     if (readySceneIdsRef.current.has(sceneId)) return;
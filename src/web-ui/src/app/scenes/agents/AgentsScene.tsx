@@ -220,6 +220,7 @@ const AgentsHomeView: React.FC = () => {
     getModeManageableSubagents,
     counts,
     hiddenAgentIds,
+    loadIssues = [],
     loadAgents,
     getModeConfig,
     handleSetTools,
@@ -775,6 +776,25 @@ const AgentsHomeView: React.FC = () => {
             </>
           )}
         >
+          {!loading && loadIssues.length > 0 ? (
+            <div className="bitfun-agents-scene__load-issues" data-testid="agents-load-issues">
+              <span className="bitfun-agents-scene__load-issues-title">
+                {t('agentsZone.loadIssues.title', { count: loadIssues.length })}
+              </span>
+              <ul>
+                {loadIssues.map((issue) => (
+                  <li key={`${issue.path}::${issue.message}`} className="bitfun-agents-scene__load-issue">
+                    <Badge variant={issue.severity}>
+                      {t(`agentsZone.loadIssues.${issue.severity}`)}
+                    </Badge>
+                    <code title={issue.path}>{issue.agentId ?? issue.path.split(/[\\/]/).pop()}</code>
+                    <span>{issue.message}</span>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          ) : null}
+
           {loading ? renderSkeletons('agent') : null}
 
           {!loading && visibleAgents.length === 0 ? (
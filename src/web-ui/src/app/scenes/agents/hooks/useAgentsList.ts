@@ -1,7 +1,11 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react';
 import type { TFunction } from 'i18next';
 import { agentAPI, type ModeInfo } from '@/infrastructure/api/service-api/AgentAPI';
-import type { AgentSource } from '@/infrastructure/api/service-api/CustomAgentAPI';
+import {
+  CustomAgentAPI,
+  type AgentSource,
+  type CustomAgentLoadIssue,
+} from '@/infrastructure/api/service-api/CustomAgentAPI';
 import { SubagentAPI, type SubagentInfo } from '@/infrastructure/api/service-api/SubagentAPI';
 import { configAPI } from '@/infrastructure/api/service-api/ConfigAPI';
 import type {
@@ -175,6 +179,7 @@ export function useAgentsList({
   const [hiddenAgentIds, setHiddenAgentIds] = useState<ReadonlySet<string>>(
     () => new Set(HIDDEN_AGENT_IDS),
   );
+  const [loadIssues, setLoadIssues] = useState<CustomAgentLoadIssue[]>([]);
   const loadRequestIdRef = useRef(0);
 
   const loadAgents = useCallback(async () => {
@@ -191,7 +196,15 @@ export function useAgentsList({
     };
 
     try {
-      const [modes, subagents, tools, configs, reviewTeamDefinition, modelConfigs] = await Promise.all([
+      const [
+        modes,
+        subagents,
+        tools,
+        configs,
+        reviewTeamDefinition,
+        modelConfigs,
+        customAgentLoadIssues,
+      ] = await Promise.all([
         agentAPI.getAvailableModes().catch(() => []),
         SubagentAPI.listSubagents({ workspacePath: workspacePath || undefined }).catch(() => []),
         fetchTools(),
@@ -202,6 +215,7 @@ export function useAgentsList({
           'ai.default_models',
           'ai.agent_model_defaults',
         ]).catch((): Record<string, unknown> => ({})),
+        CustomAgentAPI.getCustomAgentLoadIssues(workspacePath || undefined).catch(() => []),
       ]);
 
       const profileMap = buildProfileMap(modes);
@@ -289,6 +303,7 @@ export function useAgentsList({
       });
 
       setAllAgents([...modeAgents, ...subAgents]);
+      setLoadIssues(customAgentLoadIssues);
       setAvailableTools(tools);
       setConfiguredModels(models);
       setModeProfiles(profileMap);
@@ -592,6 +607,7 @@ export function useAgentsList({
     getModeManageableSubagents,
     counts,
     hiddenAgentIds,
+    loadIssues,
     loadAgents,
     getModeConfig,
     handleSetTools,
@@ -196,7 +196,8 @@ const WorkingCopyView: React.FC<WorkingCopyViewProps> = ({
         const { workspaceAPI } = await import('@/infrastructure/api');
         if (fileType === 'untracked') {
           const full = workspacePath.replace(/\\/g, '/') + '/' + filePath.replace(/\\/g, '/');
-          await workspaceAPI.deleteFile(full);
+          // The confirmation already warns that this cannot be undone.
+          await workspaceAPI.deleteFile(full, undefined, true);
         } else {
           const unstage = fileType === 'staged';
           if (unstage) await gitService.resetFiles(workspacePath, [filePath], true);
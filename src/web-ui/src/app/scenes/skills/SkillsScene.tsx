@@ -4,6 +4,7 @@ import {
   CheckCircle2,
   ChevronLeft,
   ChevronRight,
+  Copy,
   Download,
   Filter,
   FolderOpen,
@@ -146,10 +147,10 @@ const SkillsScene: React.FC = () => {
   const installedSkillAriaLabel = useCallback((skill: SkillInfo) => {
     const source = getSkillSourceLabel(skill, t('list.item.unknownSource'));
     const scope = market.isRemoteWorkspace
-      ? skill.level === 'user'
+      ? skill.level !== 'project'
         ? t('list.item.localUser')
         : t('list.item.remoteProject')
-      : skill.level === 'user'
+      : skill.level !== 'project'
         ? t('list.item.user')
         : t('list.item.project');
     return [
@@ -397,16 +398,16 @@ const SkillsScene: React.FC = () => {
                                 {getSkillSourceLabel(skill, t('list.item.unknownSource'))}
                               </Badge>
                               <Badge
-                                variant={skill.level === 'user' ? 'info' : 'purple'}
+                                variant={skill.level !== 'project' ? 'info' : 'purple'}
                               >
-                                {skill.level === 'user'
+                                {skill.level !== 'project'
                                   ? <User size={11} />
                                   : <FolderOpen size={11} />}
                                 {market.isRemoteWorkspace
-                                  ? skill.level === 'user'
+                                  ? skill.level !== 'project'
                                     ? t('list.item.localUser')
                                     : t('list.item.remoteProject')
-                                  : skill.level === 'user'
+                                  : skill.level !== 'project'
                                     ? t('list.item.user')
                                     : t('list.item.project')}
                               </Badge>
@@ -421,6 +422,14 @@ const SkillsScene: React.FC = () => {
                                   </Badge>
                                 </span>
                               )}
+                              {skill.incompatibleReason && (
+                                <span title={skill.incompatibleReason}>
+                                  <Badge variant="warning">
+                                    <ShieldAlert size={11} />
+                                    {t('list.item.incompatible')}
+                                  </Badge>
+                                </span>
+                              )}
                             </div>
 
                             <div
@@ -671,18 +680,26 @@ const SkillsScene: React.FC = () => {
                 </Badge>
               </span>
             )}
+            {selectedInstalledSkill.incompatibleReason && (
+              <span title={selectedInstalledSkill.incompatibleReason}>
+                <Badge variant="warning">
+                  <ShieldAlert size={11} />
+                  {t('list.item.incompatible')}
+                </Badge>
+              </span>
+            )}
             <Badge variant="neutral">
               {getSkillSourceLabel(selectedInstalledSkill, t('list.item.unknownSource'))}
             </Badge>
             <Badge variant={selectedInstalledSkill.isBuiltin ? 'accent' : 'success'}>
               {selectedInstalledSkill.isBuiltin ? t('list.item.builtin') : t('list.item.userInstalled')}
             </Badge>
-            <Badge variant={selectedInstalledSkill.level === 'user' ? 'info' : 'purple'}>
+            <Badge variant={selectedInstalledSkill.level !== 'project' ? 'info' : 'purple'}>
               {market.isRemoteWorkspace
-                ? selectedInstalledSkill.level === 'user'
+                ? selectedInstalledSkill.level !== 'project'
                   ? t('list.item.localUser')
                   : t('list.item.remoteProject')
-                : selectedInstalledSkill.level === 'user'
+                : selectedInstalledSkill.level !== 'project'
                   ? t('list.item.user')
                   : t('list.item.project')}
             </Badge>
@@ -716,6 +733,21 @@ const SkillsScene: React.FC = () => {
             <Trash2 size={14} />
             {t('deleteModal.delete')}
           </Button>
+        ) : selectedInstalledSkill?.level === 'builtin' ? (
+          <Button
+            variant="secondary"
+            size="small"
+            onClick={() => {
+              void installed.handleDuplicateToUser(selectedInstalledSkill).then((duplicated) => {
+                if (duplicated) {
+                  setSelectedDetail(null);
+                }
+              });
+            }}
+          >
+            <Copy size={14} />
+            {t('list.item.duplicateToUser')}
+          </Button>
         ) : selectedMarketSkill ? (
           <>
             {installedSkillNames.has(selectedMarketSkill.name) ? (
@@ -6,11 +6,17 @@ import type { SkillInfo, SkillLevel, SkillValidationResult } from '@/infrastruct
 import { canDeleteSkill } from '@/infrastructure/config/skillSourcePresentation';
 import { useWorkspaceManagerSync } from '@/infrastructure/hooks/useWorkspaceManagerSync';
 import { useNotification } from '@/shared/notification-system';
+import { commandErrorMessage } from '@/infrastructure/i18n/core/commandErrorMessage';
 import { createLogger } from '@/shared/utils/logger';
 import type { InstalledFilter } from '../skillsSceneStore';
 
 const log = createLogger('SkillsScene:useInstalledSkills');
 
+/** Backend catalog text when the command sent one, else the raw error message. */
+async function skillErrorText(err: unknown): Promise<string> {
+  return (await commandErrorMessage(err)) ?? (err instanceof Error ? err.message : String(err));
+}
+
 interface UseInstalledSkillsOptions {
   searchQuery: string;
   activeFilter: InstalledFilter;
@@ -214,7 +220,7 @@ export function useInstalledSkills({
       }
       notification.error(
         t('messages.addFailed', {
-          error: err instanceof Error ? err.message : String(err),
+          error: await skillErrorText(err),
         }),
       );
       return false;
@@ -250,13 +256,39 @@ export function useInstalledSkills({
       }
       notification.error(
         t('messages.deleteFailed', {
-          error: err instanceof Error ? err.message : String(err),
+          error: await skillErrorText(err),
         }),
       );
       return false;
     }
   }, [capabilityIsCurrent, currentCapabilityEpoch, loadSkills, notification, t, workspacePath]);
 
+  const handleDuplicateToUser = useCallback(async (skill: SkillInfo) => {
+    const capabilityEpoch = currentCapabilityEpoch();
+    if (capabilityEpoch === null || skill.level !== 'builtin') {
+      return false;
+    }
+    try {
+      await configAPI.duplicateSkillToUser(skill.dirName);
+      if (!capabilityIsCurrent(capabilityEpoch)) {
+        return false;
+      }
+      notification.success(t('messages.duplicateSuccess', { name: skill.name }));
+      await loadSkills(true);
+      return capabilityIsCurrent(capabilityEpoch);
+    } catch (err) {
+      if (!capabilityIsCurrent(capabilityEpoch)) {
+        return false;
+      }
+      notification.error(
+        t('messages.duplicateFailed', {
+          error: await skillErrorText(err),
+        }),
+      );
+      return false;
+    }
+  }, [capabilityIsCurrent, currentCapabilityEpoch, loadSkills, notification, t]);
+
   const normalizedQuery = searchQuery.trim().toLowerCase();
 
   const filteredSkills = useMemo(() => {
@@ -297,6 +329,7 @@ export function useInstalledSkills({
     error,
     loadSkills,
     handleDelete,
+    handleDuplicateToUser,
     formLevel,
     setFormLevel,
     formPath,
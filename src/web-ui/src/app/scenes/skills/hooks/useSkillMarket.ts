@@ -1,5 +1,6 @@
 import { useCallback, useEffect, useLayoutEffect, useMemo, useRef, useState } from 'react';
 import { useTranslation } from 'react-i18next';
+import { confirmWarning } from '@/component-library';
 import { configAPI } from '@/infrastructure/api';
 import type { SkillLevel, SkillMarketItem } from '@/infrastructure/config/types';
 import { useWorkspaceManagerSync } from '@/infrastructure/hooks/useWorkspaceManagerSync';
@@ -207,11 +208,40 @@ export function useSkillMarket({
     }
     try {
       setDownloadingPackage(skill.installId);
-      const result = await configAPI.downloadSkillMarket({
+      const request = {
         packageId: skill.installId,
         level: resolvedLevel,
         workspacePath: resolvedLevel === 'project' ? workspacePath || undefined : undefined,
-      });
+      };
+      const incompatible = await configAPI
+        .previewSkillMarketInstall(request)
+        .then((preview) => preview.skills.filter((item) => item.incompatibleReason))
+        .catch((err) => {
+          // The preview only feeds this warning; install without it.
+          log.warn('Failed to preview skill install', err);
+          return [];
+        });
+      if (!capabilityIsCurrent(capabilityEpoch)) {
+        return;
+      }
+      if (incompatible.length > 0) {
+        const confirmed = await confirmWarning(
+          t('compatibilityModal.title'),
+          t('compatibilityModal.message', {
+            details: incompatible
+              .map((item) => `${item.name}: ${item.incompatibleReason}`)
+              .join('\n'),
+          }),
+          {
+            confirmText: t('compatibilityModal.install'),
+            cancelText: t('compatibilityModal.cancel'),
+          },
+        );
+        if (!confirmed || !capabilityIsCurrent(capabilityEpoch)) {
+          return;
+        }
+      }
+      const result = await configAPI.downloadSkillMarket(request);
       if (!capabilityIsCurrent(capabilityEpoch)) {
         return;
       }
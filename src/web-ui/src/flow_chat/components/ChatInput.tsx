@@ -6,7 +6,7 @@
 import React, { useRef, useCallback, useEffect, useReducer, useState, useMemo, useSyncExternalStore } from 'react';
 import path from 'path-browserify';
 import { useTranslation } from 'react-i18next';
-import { ArrowUp, BotMessageSquare, Image, RotateCcw, Plus, X, Sparkles, Loader2, ChevronRight, Files, MessageSquarePlus, Star } from 'lucide-react';
+import { ArrowUp, BotMessageSquare, Image, RotateCcw, Plus, X, Sparkles, Loader2, ChevronRight, Files, MessageSquarePlus, Star, ScreenShare } from 'lucide-react';
 import { ContextDropZone, useContextStore } from '../../shared/context-system';
 import { useActiveSessionState } from '@/flow_chat/hooks';
 import { RichTextInput, type MentionState, type InlineTriggerState } from './RichTextInput';
@@ -30,12 +30,14 @@ import type {
   DirectoryContext,
   FileContext,
   ImageContext,
+  MCPResourceContext,
   SessionReferenceContext,
 } from '@/types/context.ts';
 import { SmartRecommendations } from './smart-recommendations';
 import { useCurrentWorkspace, useWorkspaceContext } from '@/infrastructure/contexts/WorkspaceContext';
 import { flowChatSessionConfigForCurrentWorkspace } from '@/app/utils/projectSessionWorkspace';
-import { createImageContextFromFile, createImageContextFromClipboard } from '../utils/imageUtils';
+import { createImageContextFromFile, createImageContextFromClipboard, createImageContextFromCapture } from '../utils/imageUtils';
+import { createAttachmentContext } from '../utils/attachmentContext';
 import { getInlineSlashCommandPickerQuery, getSlashCommandPickerQuery, isSlashCommand, stripSlashCommand } from '../utils/slashCommand';
 import {
   resolveSlashActionInputValue,
@@ -62,6 +64,7 @@ import {
 } from '../services/sessionOpenIntent';
 import { useThreadGoalController } from '../hooks/useThreadGoalController';
 import { useComposerDefaultFocus } from '../hooks/useComposerDefaultFocus';
+import { useAttachmentDrop } from '../hooks/useAttachmentDrop';
 import { ThreadGoalDialogs } from './thread-goal/ThreadGoalDialogs';
 import { FlowChatManager } from '@/flow_chat/services/FlowChatManager';
 import {
@@ -129,6 +132,7 @@ import {
   type ContextUsageDisplay,
 } from '../utils/tokenUsageDisplay';
 import { agentAPI } from '@/infrastructure/api/service-api/AgentAPI';
+import { screenCaptureAPI } from '@/infrastructure/api/service-api/ScreenCaptureAPI';
 import './ChatInput.scss';
 
 import { setChatPopupActive } from './chatPopupState';
@@ -1561,6 +1565,18 @@ export const ChatInput: React.FC<ChatInputProps> = ({
     void loadMcpPromptCommands();
   }, [derivedState?.isProcessing, loadMcpPromptCommands, slashCommandState.isActive, slashCommandState.kind]);
 
+  React.useEffect(() => {
+    if (!slashCommandState.isActive || slashCommandState.kind !== 'all') {
+      return;
+    }
+
+    return MCPAPI.onListChanged((event) => {
+      if (event.kind === 'prompts') {
+        void loadMcpPromptCommands();
+      }
+    });
+  }, [loadMcpPromptCommands, slashCommandState.isActive, slashCommandState.kind]);
+
   // Stable ref so the mcp-app:message handler can read the latest value without
   // being included in the effect's dependency array (prevents rapid listener
   // teardown/re-registration on every keystroke or streaming update).
@@ -3709,6 +3725,61 @@ export const ChatInput: React.FC<ChatInputProps> = ({
     [handleImageInput]
   );
 
+  const handleBoostCaptureScreen = useCallback(
+    async (e: React.SyntheticEvent) => {
+      e.stopPropagation();
+      dispatchMode({ type: 'CLOSE_DROPDOWN' });
+      if (!currentSessionId) return;
+      if (currentImageCount >= CHAT_INPUT_CONFIG.image.maxCount) {
+        notificationService.warning(t('input.maxImagesWarning', { count: CHAT_INPUT_CONFIG.image.maxCount }), { duration: 3000 });
+        return;
+      }
+      try {
+        // The backend shows a native confirmation before anything is captured.
+        const capture = await screenCaptureAPI.captureRegion({ sessionId: currentSessionId });
+        if (!capture.artifact) return;
+        addContext(createImageContextFromCapture({ ...capture, artifact: capture.artifact }));
+      } catch (error) {
+        log.error('Screen capture failed', { error });
+        notificationService.error(
+          error instanceof Error ? error.message : t('input.screenCaptureFailed'),
+          { duration: 4000 }
+        );
+      }
+    },
+    [addContext, currentImageCount, currentSessionId, t]
+  );
+
+  useAttachmentDrop({
+    sessionId: effectiveTargetSessionId || undefined,
+    targetRef: containerRef,
+    onIngested: ({ attachments, rejected }) => {
+      attachments.forEach(handle => {
+        const context = createAttachmentContext(handle);
+        addContext(context);
+        (richTextInputRef.current as any)?.insertTag?.(context);
+      });
+      if (attachments.length > 0 && !inputState.isActive) {
+        dispatchInput({ type: 'ACTIVATE' });
+      }
+      if (rejected.length > 0) {
+        notificationService.warning(
+          t('input.attachmentsRejected', {
+            count: rejected.length,
+            details: rejected.map(item => `${item.source}: ${item.error}`).join('\n'),
+          }),
+          { duration: 5000 }
+        );
+      }
+    },
+    onError: (error) => {
+      notificationService.error(
+        error instanceof Error ? error.message : t('input.attachmentsFailed'),
+        { duration: 4000 }
+      );
+    },
+  });
+
   const handleBoostOpenAtContext = useCallback((e: React.SyntheticEvent) => {
     e.stopPropagation();
     dispatchMode({ type: 'CLOSE_DROPDOWN' });
@@ -3980,7 +4051,7 @@ export const ChatInput: React.FC<ChatInputProps> = ({
                 searchQuery={mentionState.query}
                 workspacePath={workspacePath}
                 excludeSessionId={effectiveTargetSessionId || undefined}
-                onSelect={(context: FileContext | DirectoryContext | SessionReferenceContext) => {
+                onSelect={(context: FileContext | DirectoryContext | SessionReferenceContext | MCPResourceContext) => {
                   addContext(context);
                   
                   if (richTextInputRef.current && (richTextInputRef.current as any).insertTagReplacingMention) {
@@ -4347,6 +4418,19 @@ export const ChatInput: React.FC<ChatInputProps> = ({
                           <span>{t('input.addImage')}</span>
                         </div>
 
+                        {isTauriRuntime() && currentSessionId && (
+                          <div
+                            role="button"
+                            tabIndex={0}
+                            className="bitfun-chat-input__boost-context-row"
+                            onClick={handleBoostCaptureScreen}
+                            onKeyDown={e => e.key === 'Enter' && handleBoostCaptureScreen(e)}
+                          >
+                            <ScreenShare size={14} className="bitfun-chat-input__boost-context-icon" aria-hidden />
+                            <span>{t('input.captureScreen')}</span>
+                          </div>
+                        )}
+
                         <div
                           role="button"
                           tabIndex={0}
@@ -1,6 +1,7 @@
 /**
- * File and session mention picker.
- * Shown when the user types @ to select files, folders, or idle sessions.
+ * File, session and MCP resource mention picker.
+ * Shown when the user types @ to select files, folders, idle sessions, or
+ * resources of connected MCP servers.
  */
 
 import React, { useState, useEffect, useCallback, useMemo, useRef } from 'react';
@@ -9,6 +10,7 @@ import {
   File,
   Folder,
   Loader2,
+  Plug,
   MessageCircle,
   Search,
   ChevronRight,
@@ -20,9 +22,16 @@ import type {
   FileSearchResult,
 } from '@/infrastructure/api/service-api/tauri-commands';
 import type { SessionReferenceCandidate } from '@/infrastructure/api/service-api/SessionAPI';
+import {
+  MCPAPI,
+  type MCPResource,
+  type MCPResourceAttachment,
+} from '@/infrastructure/api/service-api/MCPAPI';
+import { isTauriRuntime } from '@/infrastructure/runtime';
 import type {
   DirectoryContext,
   FileContext,
+  MCPResourceContext,
   SessionReferenceContext,
 } from '@/shared/types/context';
 import { Tooltip } from '@/component-library';
@@ -39,7 +48,9 @@ export interface FileMentionPickerProps {
   workspacePath?: string;
   /** The composing session itself must not appear as a reference candidate. */
   excludeSessionId?: string;
-  onSelect: (context: FileContext | DirectoryContext | SessionReferenceContext) => void;
+  onSelect: (
+    context: FileContext | DirectoryContext | SessionReferenceContext | MCPResourceContext
+  ) => void;
   onClose: () => void;
   position?: { top: number; left: number };
   onNavigate?: (direction: 'up' | 'down' | 'enter' | 'escape') => void;
@@ -52,9 +63,57 @@ interface FileItem {
   relativePath: string;
 }
 
+interface MCPResourceItem {
+  serverId: string;
+  serverName: string;
+  resource: MCPResource;
+}
+
 type MentionItem =
   | { kind: 'file'; item: FileItem }
-  | { kind: 'session'; item: SessionReferenceCandidate };
+  | { kind: 'session'; item: SessionReferenceCandidate }
+  | { kind: 'mcp-resource'; item: MCPResourceItem };
+
+function isConnectedMCPStatus(status: string): boolean {
+  const normalized = status.toLowerCase();
+  return normalized.includes('connected') || normalized.includes('healthy');
+}
+
+/** Searches the cached resource catalogs of every connected MCP server. */
+async function searchMCPResources(query: string, limit: number): Promise<MCPResourceItem[]> {
+  const needle = query.toLowerCase();
+  const servers = (await MCPAPI.getServers()).filter(server => isConnectedMCPStatus(server.status));
+  const catalogs = await Promise.all(servers.map(async (server) => {
+    try {
+      const resources = await MCPAPI.listResources({ serverId: server.id });
+      return resources.map(resource => ({ serverId: server.id, serverName: server.name, resource }));
+    } catch (error) {
+      log.debug('Skipping MCP server resources in mention search', { serverId: server.id, error });
+      return [];
+    }
+  }));
+  return catalogs
+    .flat()
+    .filter(({ resource }) => [resource.title, resource.name, resource.uri]
+      .some(value => value?.toLowerCase().includes(needle)))
+    .slice(0, limit);
+}
+
+/** Flattens converted parts into prompt text; left-out parts keep their reason. */
+function formatMCPAttachmentContent(attachment: MCPResourceAttachment): string {
+  return attachment.parts.map((part) => {
+    switch (part.kind) {
+      case 'text':
+        return part.truncated
+          ? `${part.text}\n\n[Truncated to fit the attachment token budget.]`
+          : part.text;
+      case 'image':
+        return `[Image resource: ${part.uri}]`;
+      case 'omitted':
+        return `[Not attached: ${part.reason}]`;
+    }
+  }).join('\n\n');
+}
 
 export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
   isOpen,
@@ -68,9 +127,11 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
   const { t } = useTranslation('flow-chat');
   const [results, setResults] = useState<FileItem[]>([]);
   const [sessionResults, setSessionResults] = useState<SessionReferenceCandidate[]>([]);
+  const [mcpResults, setMcpResults] = useState<MCPResourceItem[]>([]);
   const [currentFiles, setCurrentFiles] = useState<FileItem[]>([]);
   const [isFileLoading, setIsFileLoading] = useState(false);
   const [isSessionLoading, setIsSessionLoading] = useState(false);
+  const [isMcpLoading, setIsMcpLoading] = useState(false);
   const [selectedIndex, setSelectedIndex] = useState(0);
   const [currentPath, setCurrentPath] = useState<string>('');
   const [pathHistory, setPathHistory] = useState<string[]>([]);
@@ -78,11 +139,13 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
   const fileAbortControllerRef = useRef<AbortController | null>(null);
   const fileSearchDebounceTimerRef = useRef<number | null>(null);
   const sessionSearchDebounceTimerRef = useRef<number | null>(null);
+  const mcpSearchDebounceTimerRef = useRef<number | null>(null);
   const selectedItemHistoryRef = useRef<string[]>([]);
   const targetSelectedPathRef = useRef<string | null>(null);
   const directoryLoadRequestIdRef = useRef(0);
   const fileSearchRequestIdRef = useRef(0);
   const sessionSearchRequestIdRef = useRef(0);
+  const mcpSearchRequestIdRef = useRef(0);
   const skipNextPathLoadRef = useRef(false);
 
   const getRelativePath = useCallback((fullPath: string): string => {
@@ -291,15 +354,53 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
     };
   }, [excludeSessionId, isOpen, searchQuery]);
 
+  useEffect(() => {
+    if (mcpSearchDebounceTimerRef.current !== null) {
+      window.clearTimeout(mcpSearchDebounceTimerRef.current);
+      mcpSearchDebounceTimerRef.current = null;
+    }
+    const query = searchQuery.trim();
+    if (!isOpen || !query || !isTauriRuntime()) {
+      mcpSearchRequestIdRef.current += 1;
+      setMcpResults([]);
+      setIsMcpLoading(false);
+      return;
+    }
+
+    const requestId = ++mcpSearchRequestIdRef.current;
+    setIsMcpLoading(true);
+    mcpSearchDebounceTimerRef.current = window.setTimeout(() => {
+      mcpSearchDebounceTimerRef.current = null;
+      void searchMCPResources(query, FILE_MENTION_MAX_RESULTS)
+        .then((items) => {
+          if (requestId === mcpSearchRequestIdRef.current) setMcpResults(items);
+        })
+        .catch((error) => {
+          log.error('MCP resource mention search failed', error);
+          if (requestId === mcpSearchRequestIdRef.current) setMcpResults([]);
+        })
+        .finally(() => {
+          if (requestId === mcpSearchRequestIdRef.current) setIsMcpLoading(false);
+        });
+    }, FILE_MENTION_SEARCH_DEBOUNCE_MS);
+    return () => {
+      if (mcpSearchDebounceTimerRef.current !== null) {
+        window.clearTimeout(mcpSearchDebounceTimerRef.current);
+        mcpSearchDebounceTimerRef.current = null;
+      }
+    };
+  }, [isOpen, searchQuery]);
+
   const isSearchMode = searchQuery.trim().length > 0;
   const displayItems = useMemo<MentionItem[]>(() => (
     isSearchMode
       ? [
           ...results.map(item => ({ kind: 'file' as const, item })),
           ...sessionResults.map(item => ({ kind: 'session' as const, item })),
+          ...mcpResults.map(item => ({ kind: 'mcp-resource' as const, item })),
         ]
       : currentFiles.map(item => ({ kind: 'file' as const, item }))
-  ), [currentFiles, isSearchMode, results, sessionResults]);
+  ), [currentFiles, isSearchMode, mcpResults, results, sessionResults]);
   const currentDirName = currentPath
     ? currentPath.replace(/\\/g, '/').split('/').pop() || ''
     : workspacePath?.replace(/\\/g, '/').split('/').pop() || t('fileMention.rootDirectory');
@@ -307,6 +408,7 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
   useEffect(() => () => {
     if (fileSearchDebounceTimerRef.current !== null) window.clearTimeout(fileSearchDebounceTimerRef.current);
     if (sessionSearchDebounceTimerRef.current !== null) window.clearTimeout(sessionSearchDebounceTimerRef.current);
+    if (mcpSearchDebounceTimerRef.current !== null) window.clearTimeout(mcpSearchDebounceTimerRef.current);
     fileAbortControllerRef.current?.abort();
   }, []);
 
@@ -328,6 +430,31 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
       onClose();
       return;
     }
+    if (mention.kind === 'mcp-resource') {
+      const { serverId, serverName, resource } = mention.item;
+      onClose();
+      void MCPAPI.attachResources({ serverId, resourceUris: [resource.uri] })
+        .then(([attachment]) => {
+          if (!attachment) return;
+          onSelect({
+            id: `mcp-resource-${timestamp}-${Math.random().toString(36).slice(2, 9)}`,
+            type: 'mcp-resource',
+            serverId,
+            serverName,
+            uri: resource.uri,
+            label: resource.title || resource.name,
+            mimeType: resource.mimeType,
+            content: formatMCPAttachmentContent(attachment),
+            estimatedTokens: attachment.estimatedTokens,
+            truncated: attachment.parts.some(part => part.kind === 'omitted' || (part.kind === 'text' && part.truncated)),
+            timestamp,
+          });
+        })
+        .catch((error) => {
+          log.error('Failed to attach MCP resource', { serverId, uri: resource.uri, error });
+        });
+      return;
+    }
 
     const item = mention.item;
     onSelect(item.isDirectory ? {
@@ -426,7 +553,7 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
 
   if (!isOpen) return null;
   const style: React.CSSProperties = position ? { position: 'absolute', top: position.top, left: position.left } : {};
-  const isLoading = isFileLoading || isSessionLoading;
+  const isLoading = isFileLoading || isSessionLoading || isMcpLoading;
 
   return (
     <div ref={containerRef} className="file-mention-picker" style={style} onMouseDown={event => event.preventDefault()}>
@@ -451,7 +578,10 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
               const isSession = mention.kind === 'session';
               const file = mention.kind === 'file' ? mention.item : null;
               const session = mention.kind === 'session' ? mention.item : null;
-              const key = isSession ? `session-${session?.sessionId}-${session?.workspacePath}` : `file-${file?.path}`;
+              const mcpResource = mention.kind === 'mcp-resource' ? mention.item : null;
+              const key = mcpResource
+                ? `mcp-${mcpResource.serverId}-${mcpResource.resource.uri}`
+                : isSession ? `session-${session?.sessionId}-${session?.workspacePath}` : `file-${file?.path}`;
               return (
                 <div
                   key={key}
@@ -464,9 +594,10 @@ export const FileMentionPicker: React.FC<FileMentionPickerProps> = ({
                   }}
                   onMouseEnter={() => setSelectedIndex(index)}
                 >
-                  {isSession ? <MessageCircle size={13} className="file-mention-picker__icon file-mention-picker__icon--session" /> : file?.isDirectory ? <Folder size={13} className="file-mention-picker__icon file-mention-picker__icon--folder" /> : <File size={13} className="file-mention-picker__icon file-mention-picker__icon--file" />}
-                  <span className="file-mention-picker__item-name">{session?.sessionName ?? file?.name}</span>
+                  {mcpResource ? <Plug size={13} className="file-mention-picker__icon file-mention-picker__icon--mcp" /> : isSession ? <MessageCircle size={13} className="file-mention-picker__icon file-mention-picker__icon--session" /> : file?.isDirectory ? <Folder size={13} className="file-mention-picker__icon file-mention-picker__icon--folder" /> : <File size={13} className="file-mention-picker__icon file-mention-picker__icon--file" />}
+                  <span className="file-mention-picker__item-name">{mcpResource ? mcpResource.resource.title || mcpResource.resource.name : session?.sessionName ?? file?.name}</span>
                   {session && <span className="file-mention-picker__item-detail">{session.workspaceLabel}</span>}
+                  {mcpResource && <span className="file-mention-picker__item-detail" title={mcpResource.resource.uri}>{mcpResource.serverName}</span>}
                   {file?.isDirectory && !isSearchMode && <ChevronRight size={12} className="file-mention-picker__expand-icon" />}
                 </div>
               );
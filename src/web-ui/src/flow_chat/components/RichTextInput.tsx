@@ -84,6 +84,8 @@ function getContextDisplayName(context: ContextItem): string {
     case 'url': return context.title || context.url;
     case 'mermaid-node': return context.nodeText;
     case 'mermaid-diagram': return context.diagramTitle || 'Mermaid diagram';
+    case 'mcp-resource': return context.label;
+    case 'attachment': return context.name;
     case 'web-element': {
       const label = typeof context.metadata?.label === 'string' ? context.metadata.label.trim() : '';
       return label || context.textContent || context.tagName;
@@ -108,6 +110,8 @@ function getContextTagFormat(context: ContextItem): string {
     case 'url': return `#link:${context.title || context.url}`;
     case 'mermaid-node': return `#chart:${context.nodeText}`;
     case 'mermaid-diagram': return `#mermaid:${context.diagramTitle || 'Mermaid diagram'}`;
+    case 'mcp-resource': return `#mcp:${context.label.replace(/\s+/g, '_')}`;
+    case 'attachment': return `#attach:${context.name.replace(/\s+/g, '_')}`;
     case 'web-element': {
       const label = typeof context.metadata?.label === 'string' ? context.metadata.label.trim() : context.tagName;
       return `#element:${label.replace(/\s+/g, '_')}`;
@@ -151,6 +155,10 @@ function getContextFullPath(context: ContextItem): string {
       return `Mermaid diagram${context.diagramTitle ? ': ' + context.diagramTitle : ''} (${context.diagramCode.length} chars)`;
     case 'web-element':
       return context.sourceUrl ? `${context.sourceUrl} · ${context.path}` : context.path;
+    case 'mcp-resource':
+      return `${context.serverName} · ${context.uri}`;
+    case 'attachment':
+      return `${context.mimeType} · ${context.path}`;
     default: {
       const exhaustive: never = context;
       return String(exhaustive);
@@ -35,6 +35,7 @@ const PERMISSION_ACTION_LABEL_KEYS: Record<string, string> = {
   page_deploy: 'permission.actions.pageDeploy',
   custom_tool: 'permission.actions.customTool',
   external_directory: 'permission.actions.externalDirectory',
+  sensitive_file: 'permission.actions.sensitiveFile',
 };
 
 const PAGE_VISIBILITY_LABEL_KEYS: Record<string, string> = {
@@ -6,11 +6,20 @@ import type { ComposerVoiceInputController } from './useComposerVoiceInput';
 const VOICE_TIMELINE_SAMPLE_COUNT = 32;
 const VOICE_TIMELINE_TICK_MS = 86;
 const VOICE_SILENCE_THRESHOLD = 0.035;
+const VOICE_PARTIAL_TRANSCRIPT_MAX_CHARS = 48;
 
 function createFlatTimelineSamples(): number[] {
   return Array.from({ length: VOICE_TIMELINE_SAMPLE_COUNT }, () => 0);
 }
 
+/** Keeps the most recent words visible when the transcript outgrows the pill. */
+function formatPartialTranscript(text: string): string {
+  const trimmed = text.trim();
+  return trimmed.length > VOICE_PARTIAL_TRANSCRIPT_MAX_CHARS
+    ? `…${trimmed.slice(-VOICE_PARTIAL_TRANSCRIPT_MAX_CHARS)}`
+    : trimmed;
+}
+
 function formatElapsedTime(totalSeconds: number): string {
   const minutes = Math.floor(totalSeconds / 60).toString().padStart(2, '0');
   const seconds = (totalSeconds % 60).toString().padStart(2, '0');
@@ -131,6 +140,16 @@ export function ComposerVoiceInputButton({ controller }: ComposerVoiceInputButto
             })}
           </span>
 
+          {controller.partialTranscript ? (
+            <span
+              className="bitfun-chat-input__voice-pill-partial"
+              title={controller.partialTranscript}
+              aria-live="polite"
+            >
+              {formatPartialTranscript(controller.partialTranscript)}
+            </span>
+          ) : null}
+
           <span className="bitfun-chat-input__voice-pill-divider" aria-hidden="true" />
 
           <IconButton
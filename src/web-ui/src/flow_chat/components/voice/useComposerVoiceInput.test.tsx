@@ -19,6 +19,7 @@ const mocks = vi.hoisted(() => ({
   finishInputSession: vi.fn(),
   cancelInputSession: vi.fn(async () => undefined),
   notificationInfo: vi.fn(),
+  partialListener: null as ((event: { sessionId: string; text: string }) => void) | null,
 }));
 
 vi.mock('@/infrastructure/api', () => ({
@@ -39,6 +40,14 @@ vi.mock('@/infrastructure/api', () => ({
       }],
     })),
     onModelStatusChanged: vi.fn(() => () => undefined),
+    onPartialTranscript: vi.fn((callback: (event: { sessionId: string; text: string }) => void) => {
+      mocks.partialListener = callback;
+      return () => {
+        if (mocks.partialListener === callback) {
+          mocks.partialListener = null;
+        }
+      };
+    }),
     startInputSession: vi.fn(async () => ({ sessionId: 'voice-session-1' })),
     appendAudioChunk: vi.fn(async () => undefined),
     finishInputSession: mocks.finishInputSession,
@@ -221,4 +230,25 @@ describe('useComposerVoiceInput completion modes', () => {
     expect(submitText).not.toHaveBeenCalled();
     expect(mocks.notificationInfo).toHaveBeenCalledOnce();
   });
+
+  it('shows partial transcripts for the active session until recording finishes', async () => {
+    await startRecording();
+    await act(async () => {
+      await Promise.resolve();
+    });
+
+    await act(async () => {
+      mocks.partialListener?.({ sessionId: 'other-session', text: 'Ignored' });
+      mocks.partialListener?.({ sessionId: 'voice-session-1', text: 'Transcribed' });
+    });
+    expect(controller?.partialTranscript).toBe('Transcribed');
+
+    await act(async () => {
+      controller?.transcribe();
+      await Promise.resolve();
+      await Promise.resolve();
+    });
+    expect(controller?.phase).toBe('idle');
+    expect(controller?.partialTranscript).toBe('');
+  });
 });
@@ -4,6 +4,7 @@ import {
   DEFAULT_SPEECH_SAMPLE_RATE,
   LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID,
   speechAPI,
+  type SpeechInputProvider,
   type SpeechInputSession,
 } from '@/infrastructure/api';
 import { useAIExperienceSettings } from '@/infrastructure/config/hooks';
@@ -35,6 +36,8 @@ export interface ComposerVoiceInputController {
   audioLevel: number;
   lowVolumeWarning: boolean;
   lowVolumeTooltip: string;
+  /** Best-effort transcript of the current recording, replaced by the final result. */
+  partialTranscript: string;
   tooltip: string;
   cancelTooltip: string;
   transcribeTooltip: string;
@@ -71,6 +74,17 @@ function isModelMissingError(error: unknown): boolean {
   return message.toLowerCase().includes('speech model is not installed');
 }
 
+function isWhisperCppMissingError(error: unknown): boolean {
+  const message = (error instanceof Error ? error.message : String(error)).toLowerCase();
+  return message.includes('whisper-cli was not found') ||
+    message.includes('whisper.cpp model is not installed') ||
+    message.includes('no whisper.cpp models found');
+}
+
+function resolveVoiceInputProvider(provider?: string): SpeechInputProvider {
+  return provider === 'cloud' || provider === 'whisper_cpp' ? provider : 'local';
+}
+
 function estimatePcm16Base64Seconds(pcm16Base64: string, sampleRate: number): number {
   const padding = pcm16Base64.endsWith('==') ? 2 : pcm16Base64.endsWith('=') ? 1 : 0;
   const bytes = Math.max(0, Math.floor((pcm16Base64.length * 3) / 4) - padding);
@@ -86,13 +100,14 @@ export function useComposerVoiceInput({
   const { t } = useTranslation('flow-chat');
   const { settings: aiExperienceSettings } = useAIExperienceSettings();
   const settings = aiExperienceSettings?.voice_input ?? null;
-  const selectedProvider = settings?.provider === 'cloud' ? 'cloud' : 'local';
+  const selectedProvider = resolveVoiceInputProvider(settings?.provider);
   const selectedModelId = settings?.model_id || DEFAULT_LOCAL_VOICE_MODEL_ID;
   const [modelInstalled, setModelInstalled] = useState<boolean | null>(null);
   const [phase, setPhase] = useState<VoiceInputPhase>('idle');
   const [completionMode, setCompletionMode] = useState<VoiceInputCompletionMode | null>(null);
   const [audioLevel, setAudioLevel] = useState(0);
   const [lowVolumeWarning, setLowVolumeWarning] = useState(false);
+  const [partialTranscript, setPartialTranscript] = useState('');
   const sessionRef = useRef<SpeechInputSession | null>(null);
   const sessionPromiseRef = useRef<Promise<SpeechInputSession> | null>(null);
   const recorderRef = useRef<VoiceInputRecorder | null>(null);
@@ -126,7 +141,8 @@ export function useComposerVoiceInput({
       return;
     }
     if (selectedProvider !== 'local') {
-      setModelInstalled(false);
+      // Other backends are checked when the session starts.
+      setModelInstalled(null);
       return;
     }
     try {
@@ -148,7 +164,7 @@ export function useComposerVoiceInput({
       return undefined;
     }
     if (selectedProvider !== 'local') {
-      setModelInstalled(false);
+      setModelInstalled(null);
       return undefined;
     }
     void refreshCapability();
@@ -163,6 +179,18 @@ export function useComposerVoiceInput({
     };
   }, [refreshCapability, selectedModelId, selectedProvider, speechRuntimeSupported]);
 
+  useEffect(() => {
+    if (!speechRuntimeSupported || (phase !== 'recording' && phase !== 'transcribing')) {
+      setPartialTranscript('');
+      return undefined;
+    }
+    return speechAPI.onPartialTranscript(event => {
+      if (event.sessionId === sessionRef.current?.sessionId) {
+        setPartialTranscript(event.text);
+      }
+    });
+  }, [phase, speechRuntimeSupported]);
+
   useEffect(() => () => {
     activeRecordingIdRef.current += 1;
     const session = sessionRef.current;
@@ -431,8 +459,8 @@ export function useComposerVoiceInput({
       notificationService.error(t('input.voiceInput.unsupported'));
       return;
     }
-    if (settings.provider === 'cloud') {
-      notificationService.info(t('input.voiceInput.cloudPending'));
+    if (selectedProvider === 'cloud' && !settings.model_id) {
+      notificationService.warning(t('input.voiceInput.cloudModelMissing'));
       openVoiceInputSettings();
       return;
     }
@@ -499,7 +527,10 @@ export function useComposerVoiceInput({
 
       const sessionStartedAt = performance.now();
       sessionPromise = speechAPI.startInputSession({
-        modelId: voiceSettings.model_id || DEFAULT_LOCAL_VOICE_MODEL_ID,
+        provider: selectedProvider,
+        modelId: selectedProvider === 'local'
+          ? (voiceSettings.model_id || DEFAULT_LOCAL_VOICE_MODEL_ID)
+          : (voiceSettings.model_id || null),
         language: voiceSettings.default_language,
         sampleRate: DEFAULT_SPEECH_SAMPLE_RATE,
         maxRecordingSeconds: voiceSettings.max_recording_seconds,
@@ -539,6 +570,11 @@ export function useComposerVoiceInput({
             openVoiceInputSettings();
             return;
           }
+          if (isWhisperCppMissingError(error)) {
+            notificationService.warning(t('input.voiceInput.whisperCppMissing'));
+            openVoiceInputSettings();
+            return;
+          }
           notificationService.error(t('input.voiceInput.failed'));
         });
     } catch (error) {
@@ -584,7 +620,7 @@ export function useComposerVoiceInput({
       setAudioLevel(0);
       setPhase('idle');
     }
-  }, [attachSession, enqueueChunk, modelInstalled, openVoiceInputSettings, settings, speechRuntimeSupported, stopAndTranscribe, t, updateAudioLevel]);
+  }, [attachSession, enqueueChunk, modelInstalled, openVoiceInputSettings, selectedProvider, settings, speechRuntimeSupported, stopAndTranscribe, t, updateAudioLevel]);
 
   const toggle = useCallback(() => {
     if (phase === 'recording') {
@@ -629,13 +665,13 @@ export function useComposerVoiceInput({
   const tooltip = useMemo(() => {
     if (!settings?.enabled) return t('input.voiceInput.disabled');
     if (!speechRuntimeSupported || !isMediaCaptureSupported()) return t('input.voiceInput.unsupported');
-    if (settings.provider === 'cloud') return t('input.voiceInput.cloudPending');
+    if (selectedProvider === 'cloud' && !settings.model_id) return t('input.voiceInput.cloudModelMissing');
     if (modelInstalled === false) return t('input.voiceInput.modelMissing');
     if (phase === 'preparing') return t('input.voiceInput.preparing');
     if (phase === 'recording') return t('input.voiceInput.stop');
     if (phase === 'transcribing') return t('input.voiceInput.transcribing');
     return t('input.voiceInput.start');
-  }, [modelInstalled, phase, settings?.enabled, settings?.provider, speechRuntimeSupported, t]);
+  }, [modelInstalled, phase, selectedProvider, settings?.enabled, settings?.model_id, speechRuntimeSupported, t]);
 
   return {
     enabled: settings?.enabled === true && speechRuntimeSupported,
@@ -645,6 +681,7 @@ export function useComposerVoiceInput({
     audioLevel,
     lowVolumeWarning,
     lowVolumeTooltip: t('input.voiceInput.lowVolume'),
+    partialTranscript,
     tooltip,
     cancelTooltip: t('input.cancelShortcut'),
     transcribeTooltip: t('input.voiceInput.transcribeOnly'),
@@ -0,0 +1,103 @@
+import { useEffect, useRef, type RefObject } from 'react';
+import { createLogger } from '@/shared/utils/logger';
+import {
+  attachmentAPI,
+  type IngestAttachmentsResponse,
+} from '@/infrastructure/api/service-api/AttachmentAPI';
+import { isDragPositionOverElement } from '@/tools/file-system/services/workspaceFileTransfer';
+
+const log = createLogger('useAttachmentDrop');
+
+export interface UseAttachmentDropOptions {
+  sessionId?: string;
+  targetRef: RefObject<HTMLElement | null>;
+  enabled?: boolean;
+  onIngested: (response: IngestAttachmentsResponse) => void;
+  onError: (error: unknown) => void;
+}
+
+/**
+ * Stores files dropped from the OS onto the composer as session attachments.
+ * Drops elsewhere in the window are left to their own listeners.
+ */
+export function useAttachmentDrop({
+  sessionId,
+  targetRef,
+  enabled = true,
+  onIngested,
+  onError,
+}: UseAttachmentDropOptions): void {
+  const callbacksRef = useRef({ onIngested, onError });
+  callbacksRef.current = { onIngested, onError };
+
+  useEffect(() => {
+    if (
+      typeof window === 'undefined'
+      || !('__TAURI__' in window)
+      || !sessionId
+      || !enabled
+    ) {
+      return;
+    }
+
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+    let lastEnterPaths: string[] = [];
+
+    const setup = async () => {
+      try {
+        const { getCurrentWebview } = await import('@tauri-apps/api/webview');
+        const webview = getCurrentWebview();
+
+        if (cancelled) {
+          return;
+        }
+
+        unlisten = await webview.onDragDropEvent(async (event) => {
+          if (cancelled) {
+            return;
+          }
+
+          const payload = event.payload;
+          if (payload.type === 'enter') {
+            lastEnterPaths = [...payload.paths];
+            return;
+          }
+          if (payload.type !== 'drop') {
+            return;
+          }
+
+          const factor = await webview.window.scaleFactor();
+          if (!isDragPositionOverElement(payload.position, factor, targetRef.current)) {
+            lastEnterPaths = [];
+            return;
+          }
+
+          const paths = payload.paths.length > 0 ? payload.paths : lastEnterPaths;
+          lastEnterPaths = [];
+          if (paths.length === 0) {
+            return;
+          }
+
+          try {
+            callbacksRef.current.onIngested(
+              await attachmentAPI.ingestDroppedFiles(sessionId, paths)
+            );
+          } catch (error) {
+            log.error('Failed to attach dropped files', { sessionId, error });
+            callbacksRef.current.onError(error);
+          }
+        });
+      } catch (error) {
+        log.warn('File drag-drop listener not available', error);
+      }
+    };
+
+    void setup();
+
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [sessionId, enabled, targetRef]);
+}
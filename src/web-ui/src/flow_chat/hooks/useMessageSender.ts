@@ -13,6 +13,7 @@ import { FlowChatManager } from '../services/FlowChatManager';
 import { flowChatSessionConfigForCurrentWorkspace } from '@/app/utils/projectSessionWorkspace';
 import { notificationService } from '@/shared/notification-system';
 import type {
+  AttachmentContext,
   ContextItem,
   ImageContext,
   SessionReferenceContext,
@@ -112,6 +113,9 @@ export function useMessageSender(props: UseMessageSenderProps): UseMessageSender
       }
 
       const imageContexts = contexts.filter(ctx => ctx.type === 'image') as ImageContext[];
+      const attachmentIds = contexts
+        .filter((context): context is AttachmentContext => context.type === 'attachment')
+        .map(context => context.attachmentId);
       const sessionReferences = contexts
         .filter((context): context is SessionReferenceContext => context.type === 'session-reference')
         .map((context) => ({
@@ -162,6 +166,7 @@ export function useMessageSender(props: UseMessageSenderProps): UseMessageSender
         undefined,
         {
           ...(imagePayload ?? {}),
+          ...(attachmentIds.length > 0 ? { attachmentIds } : {}),
           ...(sessionReferences.length > 0
             ? { userMessageMetadata: { sessionReferences } }
             : {}),
@@ -178,6 +183,7 @@ export function useMessageSender(props: UseMessageSenderProps): UseMessageSender
         agentType: agentTypeForSend,
         contextCount: contexts.length,
         imageCount: imageContexts.length,
+        attachmentCount: attachmentIds.length,
       });
     } catch (error) {
       log.error('Failed to send message', {
@@ -637,6 +637,7 @@ export class FlowChatManager {
     options?: {
       imageContexts?: import('@/infrastructure/api/service-api/ImageContextTypes').ImageContextData[];
       imageDisplayData?: Array<{ id: string; name: string; dataUrl?: string; imagePath?: string; mimeType?: string }>;
+      attachmentIds?: string[];
       userMessageMetadata?: Record<string, unknown>;
       turnId?: string;
       preserveTurnOnStartError?: boolean;
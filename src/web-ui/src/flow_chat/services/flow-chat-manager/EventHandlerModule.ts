@@ -21,6 +21,7 @@ import {
 } from '../EventBatcher';
 import { notificationService } from '../../../shared/notification-system/services/NotificationService';
 import { createLogger } from '@/shared/utils/logger';
+import { i18nService } from '@/infrastructure/i18n';
 import { handleThreadGoalUpdated } from '../threadGoalEventService';
 import { resolveThreadGoalUserMessageDisplay } from '../../utils/threadGoalDisplay';
 import { effectiveToolInvocation, getEffectiveToolName } from '../../utils/toolInvocationIdentity';
@@ -35,7 +36,7 @@ import type {
   SessionModelAutoMigratedEvent,
   SubagentSessionLinkedEvent,
 } from '@/infrastructure/api/service-api/AgentAPI';
-import { MCPAPI } from '@/infrastructure/api/service-api/MCPAPI';
+import { MCPAPI, type MCPCapabilityChange } from '@/infrastructure/api/service-api/MCPAPI';
 import { ACPClientAPI, type AcpPermissionRequestEvent } from '@/infrastructure/api/service-api/ACPClientAPI';
 import { globalEventBus } from '@/infrastructure/event-bus';
 import type { FlowChatContext, DialogTurn, ModelRound, FlowToolItem } from './types';
@@ -709,6 +710,9 @@ export async function initializeEventListeners(
   const unlistenMcpInteractionRequest = api.listen('backend-event-mcpinteractionrequest', (payload: any) => {
     void handleMcpInteractionRequest((payload as any)?.value || payload);
   });
+  const unlistenMcpCapabilityChanged = api.listen('backend-event-mcpcapabilitychanged', (payload: any) => {
+    handleMcpCapabilityChanged((payload as any)?.value || payload);
+  });
   const unlistenAcpPermissionRequest = api.listen('backend-event-acppermissionrequest', (payload: any) => {
     void handleAcpPermissionRequest((payload as any)?.value || payload);
   });
@@ -801,6 +805,7 @@ export async function initializeEventListeners(
     unlistenTerminalReady();
     unlistenBackgroundCommandLifecycle();
     unlistenMcpInteractionRequest();
+    unlistenMcpCapabilityChanged();
     unlistenAcpPermissionRequest();
     agenticEventListener.stopListening();
   };
@@ -841,6 +846,54 @@ async function handleMcpInteractionRequest(rawEvent: unknown): Promise<void> {
   }
 }
 
+function handleMcpCapabilityChanged(rawEvent: unknown): void {
+  const event = rawEvent as MCPCapabilityChange | undefined;
+  if (!event?.serverId || !event.diff) {
+    log.warn('Received invalid MCP capability change event', { rawEvent });
+    return;
+  }
+
+  const { diff } = event;
+  const summary = [
+    diff.addedTools.length > 0 &&
+      i18nService.t('settings/mcp:notifications.capabilityAdded', { tools: diff.addedTools.join(', ') }),
+    diff.removedTools.length > 0 &&
+      i18nService.t('settings/mcp:notifications.capabilityRemoved', { tools: diff.removedTools.join(', ') }),
+    diff.changedSchemas.length > 0 &&
+      i18nService.t('settings/mcp:notifications.capabilitySchemas', { tools: diff.changedSchemas.join(', ') }),
+    diff.changedUiResources.length > 0 &&
+      i18nService.t('settings/mcp:notifications.capabilityUiResources', { tools: diff.changedUiResources.join(', ') }),
+    event.requiresApproval &&
+      i18nService.t('settings/mcp:notifications.capabilityGated', { tools: diff.gatedTools.join(', ') }),
+  ].filter(Boolean).join('\n');
+  const title = i18nService.t('settings/mcp:notifications.capabilityChanged', { name: event.serverName });
+
+  if (!event.requiresApproval) {
+    notificationService.warning(summary, { title });
+    return;
+  }
+
+  const notificationId = notificationService.persistent({
+    type: 'warning',
+    title,
+    message: summary,
+    actions: [
+      {
+        label: i18nService.t('settings/mcp:notifications.capabilityApprove'),
+        variant: 'primary',
+        onClick: () => {
+          void MCPAPI.approveCapabilityChanges({ serverId: event.serverId })
+            .then(() => notificationService.dismiss(notificationId))
+            .catch((error) => {
+              log.error('Failed to approve MCP capability changes', { serverId: event.serverId, error });
+              notificationService.error(i18nService.t('settings/mcp:notifications.operationFailed'));
+            });
+        },
+      },
+    ],
+  });
+}
+
 async function handleAcpPermissionRequest(rawEvent: unknown): Promise<void> {
   const event = rawEvent as AcpPermissionRequestEvent | undefined;
   const permissionId = event?.permissionId;
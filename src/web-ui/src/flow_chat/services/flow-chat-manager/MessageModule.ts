@@ -128,6 +128,8 @@ export async function sendMessage(
   options?: {
     imageContexts?: ImageInputContextData[];
     imageDisplayData?: Array<{ id: string; name: string; dataUrl?: string; imagePath?: string; mimeType?: string }>;
+    /** Session attachment ids resolved by the backend when the turn starts. */
+    attachmentIds?: string[];
     /**
      * When true, bypass the pending-queue check. Used by the queue drain path
      * to actually start a new dialog turn after the previous one finished.
@@ -160,6 +162,7 @@ export async function sendMessage(
           agentType,
           imageContexts: options?.imageContexts,
           imageDisplayData: options?.imageDisplayData,
+          attachmentIds: options?.attachmentIds,
           userMessageMetadata: options?.userMessageMetadata,
         });
         log.info('Message enqueued: session busy or queue non-empty', {
@@ -320,6 +323,7 @@ export async function sendMessage(
           remoteConnectionId: updatedSession.remoteConnectionId,
           remoteSshHost: updatedSession.remoteSshHost,
           imageContexts: options?.imageContexts,
+          attachmentIds: options?.attachmentIds,
           userMessageMetadata: options?.userMessageMetadata,
         });
         context.flowChatStore.updateSessionLastSubmittedMode(sessionId, currentAgentType);
@@ -342,6 +346,7 @@ export async function sendMessage(
             remoteConnectionId: updatedSession.remoteConnectionId,
             remoteSshHost: updatedSession.remoteSshHost,
             imageContexts: options?.imageContexts,
+            attachmentIds: options?.attachmentIds,
             userMessageMetadata: options?.userMessageMetadata,
           });
           context.flowChatStore.updateSessionLastSubmittedMode(sessionId, currentAgentType);
@@ -488,6 +493,7 @@ export async function drainPendingQueue(
               mimeType?: string;
             }>
           | undefined,
+        attachmentIds: next.attachmentIds,
         userMessageMetadata: next.userMessageMetadata,
         bypassPendingQueue: true,
       },
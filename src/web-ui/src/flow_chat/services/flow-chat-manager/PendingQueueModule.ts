@@ -26,6 +26,7 @@ export interface EnqueueInput {
   agentType?: string;
   imageContexts?: unknown[];
   imageDisplayData?: unknown[];
+  attachmentIds?: string[];
   userMessageMetadata?: Record<string, unknown>;
   /**
    * How many times this content has already been auto-restored from a failed
@@ -126,6 +127,7 @@ class PendingQueueManager {
       agentType: input.agentType,
       imageContexts: input.imageContexts,
       imageDisplayData: input.imageDisplayData,
+      attachmentIds: input.attachmentIds,
       userMessageMetadata: input.userMessageMetadata,
     };
     items.push(item);
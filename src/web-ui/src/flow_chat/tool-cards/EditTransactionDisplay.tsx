@@ -0,0 +1,203 @@
+/**
+ * Display component for the EditTransaction tool.
+ */
+
+import React, { useMemo, useState, useCallback } from 'react';
+import { Files } from 'lucide-react';
+import { useTranslation } from 'react-i18next';
+import { CubeLoading } from '../../component-library';
+import type { ToolCardProps } from '../types/flow-chat';
+import { BaseToolCard, ToolCardHeader } from './BaseToolCard';
+import { useToolCardHeightContract } from './useToolCardHeightContract';
+import './GetFileDiffDisplay.scss';
+import './EditTransactionDisplay.scss';
+
+type EditTransactionStatus = 'previewed' | 'committed' | 'rejected' | 'rolled_back';
+
+interface TransactionFileReport {
+  path: string;
+  change: string;
+  editCount: number;
+  additions: number;
+  deletions: number;
+  diff: string;
+  syntaxErrors?: Array<{ line: number; column: number; message: string }>;
+}
+
+interface EditTransactionResult {
+  status?: EditTransactionStatus;
+  files?: TransactionFileReport[];
+  validation?: {
+    command: string;
+    passed: boolean;
+    exitCode?: number;
+    timedOut: boolean;
+    output: string;
+  };
+  reason?: string;
+}
+
+export const EditTransactionDisplay: React.FC<ToolCardProps> = React.memo(({
+  toolItem,
+}) => {
+  const { t } = useTranslation('flow-chat');
+  const { toolCall, toolResult, status } = toolItem;
+  const [isExpanded, setIsExpanded] = useState(false);
+  const toolId = toolItem.id ?? toolCall?.id;
+  const { cardRootRef, applyExpandedState } = useToolCardHeightContract({
+    toolId,
+    toolName: toolItem.toolName,
+  });
+
+  const resultData = useMemo((): EditTransactionResult | null => {
+    const result = toolResult?.result;
+    if (!result || typeof result !== 'object') return null;
+    return result as EditTransactionResult;
+  }, [toolResult]);
+
+  const files = useMemo(() => resultData?.files ?? [], [resultData]);
+
+  const fileCount = useMemo(() => {
+    if (files.length > 0) return files.length;
+    const edits = toolCall?.input?.edits;
+    if (!Array.isArray(edits)) return 0;
+    return new Set(edits.map((edit: { file_path?: string }) => edit?.file_path)).size;
+  }, [files, toolCall?.input]);
+
+  const totals = useMemo(() => files.reduce(
+    (sum, file) => ({
+      additions: sum.additions + file.additions,
+      deletions: sum.deletions + file.deletions,
+    }),
+    { additions: 0, deletions: 0 },
+  ), [files]);
+
+  const isFailed = status === 'error';
+  const hasDetails = status === 'completed' && files.length > 0;
+
+  const handleCardClick = useCallback(() => {
+    if (hasDetails) {
+      applyExpandedState(isExpanded, !isExpanded, setIsExpanded);
+    }
+  }, [applyExpandedState, hasDetails, isExpanded]);
+
+  const getActionText = () => {
+    if (isFailed) {
+      return t('toolCards.editTransaction.failed');
+    }
+    if (status === 'running' || status === 'streaming') {
+      return t('toolCards.editTransaction.applying');
+    }
+    if (status === 'pending' || status === 'preparing') {
+      return t('toolCards.editTransaction.preparing');
+    }
+    return t('toolCards.editTransaction.action');
+  };
+
+  const renderStatusIcon = () => {
+    if (status === 'running' || status === 'streaming' || status === 'preparing') {
+      return <CubeLoading size="small" />;
+    }
+    return null;
+  };
+
+  const transactionStatus = resultData?.status;
+
+  const renderHeader = () => (
+    <ToolCardHeader
+      icon={<Files size={16} />}
+      iconClassName="diff-icon"
+      action={`${getActionText()}:`}
+      content={
+        <span className="diff-tool-info">
+          <span className="diff-file-name">
+            {t('toolCards.editTransaction.filesCount', { count: fileCount })}
+          </span>
+          {transactionStatus && status === 'completed' && (
+            <span className={`diff-type-tag edit-transaction-status--${transactionStatus}`}>
+              {t(`toolCards.editTransaction.status.${transactionStatus}`)}
+            </span>
+          )}
+        </span>
+      }
+      extra={
+        !isFailed && status === 'completed' && files.length > 0 ? (
+          <span className="diff-stats">
+            {totals.additions > 0 && <span className="additions">+{totals.additions}</span>}
+            {totals.deletions > 0 && <span className="deletions">-{totals.deletions}</span>}
+          </span>
+        ) : null
+      }
+      statusIcon={renderStatusIcon()}
+    />
+  );
+
+  const renderExpandedContent = () => {
+    if (!resultData) return null;
+    const { validation, reason } = resultData;
+
+    return (
+      <div className="diff-expanded-content edit-transaction-content">
+        {reason && <div className="diff-message">{reason}</div>}
+        {files.map((file) => (
+          <div key={file.path} className="edit-transaction-file">
+            <div className="edit-transaction-file__header">
+              <span className="edit-transaction-file__path">{file.path}</span>
+              <span className="diff-stats">
+                {file.additions > 0 && <span className="additions">+{file.additions}</span>}
+                {file.deletions > 0 && <span className="deletions">-{file.deletions}</span>}
+              </span>
+            </div>
+            {file.syntaxErrors?.map((error) => (
+              <div
+                key={`${error.line}:${error.column}`}
+                className="edit-transaction-file__syntax-error"
+              >
+                {t('toolCards.editTransaction.syntaxError', error)}
+              </div>
+            ))}
+            {file.diff && <pre className="diff-content-preview">{file.diff}</pre>}
+          </div>
+        ))}
+        {validation && (
+          <div className="edit-transaction-validation">
+            <div className="diff-message">
+              {t('toolCards.editTransaction.validation', { command: validation.command })}
+              {' — '}
+              {validation.passed
+                ? t('toolCards.editTransaction.validationPassed')
+                : t('toolCards.editTransaction.validationFailed')}
+            </div>
+            {!validation.passed && validation.output && (
+              <pre className="diff-content-preview">{validation.output}</pre>
+            )}
+          </div>
+        )}
+      </div>
+    );
+  };
+
+  const renderErrorContent = () => (
+    <div className="error-content">
+      <div className="error-message">
+        {t('toolCards.editTransaction.failed')}
+      </div>
+    </div>
+  );
+
+  return (
+    <div ref={cardRootRef} data-tool-card-id={toolId ?? ''}>
+      <BaseToolCard
+        status={status}
+        isExpanded={isExpanded}
+        onClick={handleCardClick}
+        className="get-file-diff-card edit-transaction-card"
+        header={renderHeader()}
+        expandedContent={renderExpandedContent()}
+        errorContent={isFailed ? renderErrorContent() : null}
+        isFailed={isFailed}
+        headerExpandAffordance={hasDetails}
+      />
+    </div>
+  );
+});
@@ -10,7 +10,7 @@ import { CubeLoading, IconButton } from '../../component-library';
 import type { ToolCardProps } from '../types/flow-chat';
 import { BaseToolCard, ToolCardHeader } from './BaseToolCard';
 import { createLogger } from '@/shared/utils/logger';
-import { MCPAPI, MCP_APPS_PROTOCOL_VERSION, type McpUiResourceCsp, type McpUiResourcePermissions, type McpUiMessageParams, type McpUiMessageResult, type McpAppMessageEvent, type McpAppMessageResponseEvent } from '@/infrastructure/api/service-api/MCPAPI';
+import { MCPAPI, MCP_APPS_PROTOCOL_VERSION, type McpUiResourceCsp, type McpUiResourcePermissions, type McpUiMessageParams, type McpUiMessageResult, type McpAppMessageEvent, type McpAppMessageResponseEvent, type MCPAppInstance } from '@/infrastructure/api/service-api/MCPAPI';
 import { systemAPI } from '@/infrastructure/api/service-api/SystemAPI';
 import { globalEventBus } from '@/infrastructure/event-bus';
 import { isMcpToolName } from '@/infrastructure/mcp/toolName';
@@ -149,6 +149,7 @@ const injectPreamble = (html: string, csp?: McpUiResourceCsp): string => {
 export const MCPToolDisplay: React.FC<ToolCardProps> = ({
   toolItem,
   config,
+  sessionId,
 }) => {
   const { t } = useTranslation('flow-chat');
   const { status, toolCall, toolResult, requiresConfirmation, userConfirmed } = toolItem;
@@ -224,6 +225,9 @@ export const MCPToolDisplay: React.FC<ToolCardProps> = ({
   // Latest CSP for hostCapabilities (updated when MCP App loads)
   const latestCspRef = useRef<McpUiResourceCsp | undefined>(undefined);
 
+  // Backend instance for this widget; holds its isolated, persisted state
+  const mcpAppInstanceRef = useRef<MCPAppInstance | null>(null);
+
   // Find first ui:// resource in result for MCP App rendering
   // Fallback: MCP Apps declare UI in tool metadata (_meta.ui.resourceUri), not in result
   const uiResourceUriFromResult = resultData?.content
@@ -299,7 +303,8 @@ export const MCPToolDisplay: React.FC<ToolCardProps> = ({
                 }
               },
               displayMode: 'inline' as const,
-              containerDimensions: { maxHeight: 600 }
+              containerDimensions: { maxHeight: 600 },
+              widgetState: mcpAppInstanceRef.current?.state ?? null
             };
             const initResult = {
               jsonrpc: '2.0',
@@ -354,6 +359,22 @@ export const MCPToolDisplay: React.FC<ToolCardProps> = ({
             postToIframe(response);
             break;
           }
+          case 'bitfun/widgetState/get':
+          case 'bitfun/widgetState/set':
+          case 'bitfun/files/read':
+          case 'bitfun/files/write':
+          case 'bitfun/files/delete':
+          case 'bitfun/files/list':
+          case 'bitfun/files/pickOpen':
+          case 'bitfun/files/pickSave': {
+            const response = await MCPAPI.sendMCPAppMessage({
+              serverId,
+              instanceId: mcpAppInstanceRef.current?.instanceId,
+              ...data
+            });
+            postToIframe(response);
+            break;
+          }
           case 'ui/notifications/size-changed': {
             const height = params?.height as number | undefined;
             if (typeof height === 'number') setMcpAppHeight(height);
@@ -528,7 +549,23 @@ export const MCPToolDisplay: React.FC<ToolCardProps> = ({
         const html = injectPreamble(rawHtml, meta.csp);
         // Update latest CSP ref for hostCapabilities
         latestCspRef.current = meta.csp;
-        setMcpAppState((s) => s ? { ...s, html, rawHtml, meta, loading: false, error: html ? null : 'No content' } : null);
+        return MCPAPI.openMCPAppInstance({
+          serverId,
+          resourceUri: uiResourceUri,
+          toolCallId: toolCall?.id,
+          sessionId,
+        })
+          .then((instance) => {
+            mcpAppInstanceRef.current = instance;
+          })
+          .catch((err) => {
+            mcpAppInstanceRef.current = null;
+            log.warn('Failed to open MCP App instance; widget state will not persist', { uiResourceUri, err });
+          })
+          .then(() => {
+            if (cancelled) return;
+            setMcpAppState((s) => s ? { ...s, html, rawHtml, meta, loading: false, error: html ? null : 'No content' } : null);
+          });
       })
       .catch((err) => {
         if (cancelled) return;
@@ -543,7 +580,9 @@ export const MCPToolDisplay: React.FC<ToolCardProps> = ({
     isFailed,
     resultData,
     serverId,
+    sessionId,
     status,
+    toolCall?.id,
     toolId,
     toolMetaUiUri,
     uiResourceUri,
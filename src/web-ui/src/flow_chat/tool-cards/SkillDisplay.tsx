@@ -29,6 +29,15 @@ export const SkillDisplay: React.FC<ToolCardProps> = React.memo(({ toolItem }) =
 
   const displayName = status === 'completed' && skillInfo ? skillInfo.name : commandName;
 
+  // Arguments passed against the skill's declared parameters, e.g. `file=a.pdf`.
+  const argumentSummary = useMemo(() => {
+    const args = toolCall?.input?.arguments;
+    if (!args || typeof args !== 'object' || Array.isArray(args)) return '';
+    return Object.entries(args as Record<string, unknown>)
+      .map(([key, value]) => `${key}=${typeof value === 'string' ? value : JSON.stringify(value)}`)
+      .join(', ');
+  }, [toolCall?.input?.arguments]);
+
   const getErrorMessage = () => {
     if (toolResult && 'error' in toolResult && toolResult.error) {
       return String(toolResult.error);
@@ -49,6 +58,7 @@ export const SkillDisplay: React.FC<ToolCardProps> = React.memo(({ toolItem }) =
       return (
         <>
           {t('toolCards.skill.skillAction')} {displayName}
+          {argumentSummary ? <span className="read-file-meta"> {argumentSummary}</span> : null}
         </>
       );
     }
@@ -39,6 +39,7 @@ import { SkillDisplay } from './SkillDisplay';
 import { AskUserQuestionCard } from './AskUserQuestionCard';
 import { GitToolDisplay } from './GitToolDisplay';
 import { GetFileDiffDisplay } from './GetFileDiffDisplay';
+import { EditTransactionDisplay } from './EditTransactionDisplay';
 import { CreatePlanDisplay } from './CreatePlanDisplay';
 import { TerminalToolCard } from './TerminalToolCard';
 import { ExecCommandToolCard } from './ExecCommandToolCard';
@@ -62,6 +63,7 @@ export const TOOL_CARD_COMPONENTS = {
   'Read': ReadFileDisplay, // Read does not need snapshot support.
   'Write': FileOperationToolCard,
   'Edit': FileOperationToolCard,
+  'EditTransaction': EditTransactionDisplay,
   'Delete': FileOperationToolCard,
   
   // Search tools
@@ -23,6 +23,16 @@ export const TOOL_CARD_CONFIGS: Record<string, ToolCardConfig> = {
     displayMode: 'compact',
     primaryColor: 'var(--color-accent-600)'
   },
+  'ReadMore': {
+    toolName: 'ReadMore',
+    displayName: 'Read More Output',
+    icon: 'R',
+    requiresConfirmation: false,
+    resultDisplayType: 'summary',
+    description: 'Read more lines of stored tool output',
+    displayMode: 'compact',
+    primaryColor: 'var(--color-accent-600)'
+  },
   'Write': {
     toolName: 'Write',
     displayName: 'Write File',
@@ -43,6 +53,16 @@ export const TOOL_CARD_CONFIGS: Record<string, ToolCardConfig> = {
     displayMode: 'standard',
     primaryColor: 'var(--color-warning)'
   },
+  'EditTransaction': {
+    toolName: 'EditTransaction',
+    displayName: 'Edit Transaction',
+    icon: 'E',
+    requiresConfirmation: false, // Snapshot system handles confirmation.
+    resultDisplayType: 'detailed',
+    description: 'Apply edits across several files all at once, or not at all',
+    displayMode: 'standard',
+    primaryColor: 'var(--color-warning)'
+  },
   'Delete': {
     toolName: 'Delete',
     displayName: 'Delete File',
@@ -85,6 +105,37 @@ export const TOOL_CARD_CONFIGS: Record<string, ToolCardConfig> = {
     displayMode: 'compact',
     primaryColor: UI_EXCEPTION_ACCENTS.toolIdentity.search
   },
+  'FindSymbol': {
+    toolName: 'FindSymbol',
+    displayName: 'Symbol Search',
+    icon: 'S',
+    requiresConfirmation: false,
+    resultDisplayType: 'summary',
+    description: 'Find symbol definitions by name',
+    displayMode: 'compact',
+    primaryColor: UI_EXCEPTION_ACCENTS.toolIdentity.search
+  },
+  'FileOutline': {
+    toolName: 'FileOutline',
+    displayName: 'File Outline',
+    icon: 'O',
+    requiresConfirmation: false,
+    resultDisplayType: 'summary',
+    description: 'List the symbols declared in a file',
+    displayMode: 'compact',
+    primaryColor: UI_EXCEPTION_ACCENTS.toolIdentity.search
+  },
+
+  'LSP': {
+    toolName: 'LSP',
+    displayName: 'Language Server',
+    icon: 'L',
+    requiresConfirmation: false,
+    resultDisplayType: 'summary',
+    description: 'Hover, definitions, references and diagnostics from the language server',
+    displayMode: 'compact',
+    primaryColor: UI_EXCEPTION_ACCENTS.toolIdentity.search
+  },
 
   // Web tools
   'WebSearch': {
@@ -159,6 +210,16 @@ export const TOOL_CARD_CONFIGS: Record<string, ToolCardConfig> = {
     displayMode: 'compact',
     primaryColor: UI_EXCEPTION_ACCENTS.tealAction
   },
+  'SearchTools': {
+    toolName: 'SearchTools',
+    displayName: 'Search Tools',
+    icon: 'S',
+    requiresConfirmation: false,
+    resultDisplayType: 'summary',
+    description: 'Search available built-in, MCP and skill tools',
+    displayMode: 'compact',
+    primaryColor: UI_EXCEPTION_ACCENTS.toolIdentity.search
+  },
 
   // Skill tool
   'Skill': {
@@ -474,14 +535,14 @@ export function getAllToolNames(): string[] {
  * They are auto-collapsed during streaming to reduce visual noise.
  */
 export const COLLAPSIBLE_TOOL_NAMES = new Set([
-  'Read', 'LS', 'Grep', 'Glob', 'WebSearch', 'Bash', 'Git',
+  'Read', 'ReadMore', 'LS', 'Grep', 'Glob', 'FindSymbol', 'FileOutline', 'LSP', 'WebSearch', 'SearchTools', 'Bash', 'Git',
 ]);
 
 /** Read tools (counted in readCount). */
-export const READ_TOOL_NAMES = new Set(['Read', 'LS']);
+export const READ_TOOL_NAMES = new Set(['Read', 'ReadMore', 'LS', 'FileOutline']);
 
 /** Search tools (counted in searchCount). */
-export const SEARCH_TOOL_NAMES = new Set(['Grep', 'Glob', 'WebSearch']);
+export const SEARCH_TOOL_NAMES = new Set(['Grep', 'Glob', 'FindSymbol', 'LSP', 'WebSearch', 'SearchTools']);
 
 /** Command tools (counted in commandCount). */
 export const COMMAND_TOOL_NAMES = new Set(['Bash', 'Git']);
@@ -527,6 +527,8 @@ export interface QueuedMessage {
   /** Image / attachment payloads forwarded to `start_dialog_turn` when drained. */
   imageContexts?: unknown[];
   imageDisplayData?: unknown[];
+  /** Session attachment ids forwarded to `start_dialog_turn` when drained. */
+  attachmentIds?: string[];
   /** Structured metadata forwarded to `start_dialog_turn` when drained. */
   userMessageMetadata?: Record<string, unknown>;
   localDialogTurnId?: string;
@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest';
+import { formatContextForPrompt } from '@/shared/utils/contextPrompt';
+import { createAttachmentContext } from './attachmentContext';
+
+describe('createAttachmentContext', () => {
+  const handle = {
+    id: 'att-1',
+    sessionId: 'session-1',
+    kind: 'text' as const,
+    origin: 'drop' as const,
+    name: 'notes.md',
+    mimeType: 'text/markdown',
+    sizeBytes: 42,
+    path: '/data/attachments/session-1/att-1/notes.md',
+    sourcePath: '/home/me/notes.md',
+    createdAtMs: 1000,
+  };
+
+  it('keeps the attachment id the turn request sends', () => {
+    const context = createAttachmentContext(handle);
+
+    expect(context).toMatchObject({
+      type: 'attachment',
+      attachmentId: 'att-1',
+      name: 'notes.md',
+      kind: 'text',
+      path: handle.path,
+      metadata: { origin: 'drop', sourcePath: '/home/me/notes.md' },
+    });
+  });
+
+  it('stays out of the prompt text', () => {
+    expect(formatContextForPrompt(createAttachmentContext(handle))).toBe('');
+  });
+});
@@ -0,0 +1,21 @@
+import type { AttachmentHandle } from '@/infrastructure/api/service-api/AttachmentAPI';
+import type { AttachmentContext } from '@/shared/types/context';
+
+/** Composer context for a stored attachment; only the id is sent with the turn. */
+export function createAttachmentContext(handle: AttachmentHandle): AttachmentContext {
+  return {
+    id: `attachment-${handle.id}`,
+    type: 'attachment',
+    attachmentId: handle.id,
+    name: handle.name,
+    kind: handle.kind,
+    mimeType: handle.mimeType,
+    sizeBytes: handle.sizeBytes,
+    path: handle.path,
+    timestamp: handle.createdAtMs,
+    metadata: {
+      origin: handle.origin,
+      ...(handle.sourcePath ? { sourcePath: handle.sourcePath } : {}),
+    },
+  };
+}
@@ -3,6 +3,7 @@
  */
 
 import type { ImageContext } from '@/shared/types/context';
+import type { ScreenCaptureResult } from '@/infrastructure/api/service-api/ScreenCaptureAPI';
 import { isImageFile as checkIsImageFile } from '@/infrastructure/language-detection';
 import { createLogger } from '@/shared/utils/logger';
 
@@ -319,6 +320,34 @@ export async function createImageContextFromClipboard(
   return imageContext;
 }
 
+/**
+ * Create ImageContext from a screen capture stored in the artifact store
+ * @param capture Result of a confirmed capture
+ * @returns ImageContext pointing at the managed artifact copy
+ */
+export function createImageContextFromCapture(
+  capture: ScreenCaptureResult & { artifact: NonNullable<ScreenCaptureResult['artifact']> }
+): ImageContext {
+  const { artifact } = capture;
+  return {
+    id: `img-capture-${artifact.id}`,
+    type: 'image',
+    imagePath: artifact.path,
+    imageName: artifact.name,
+    width: capture.width,
+    height: capture.height,
+    fileSize: artifact.sizeBytes,
+    mimeType: artifact.mimeType,
+    source: 'file',
+    isLocal: true,
+    timestamp: Date.now(),
+    metadata: {
+      fromScreenCapture: true,
+      artifactId: artifact.id
+    }
+  };
+}
+
 /**
  * Check if file is an image
  * Use global language detection service
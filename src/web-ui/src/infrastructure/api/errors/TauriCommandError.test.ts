@@ -0,0 +1,48 @@
+import { describe, expect, it, vi } from 'vitest';
+
+vi.mock('../service-api/ApiClient', () => ({ api: {} }));
+
+import { createTauriCommandError, getCommandError } from './TauriCommandError';
+import { getMCPFailure } from '../service-api/MCPAPI';
+
+const backendError = {
+  code: 'network',
+  message: 'Request channel closed',
+  details: { kind: 'transport', message: 'Request channel closed' },
+  retryable: true,
+};
+
+describe('getCommandError', () => {
+  it('unwraps raw, ApiClient and TauriCommandError rejections', () => {
+    const clientError = Object.assign(new Error('Request channel closed'), {
+      code: 'COMMAND_FAILED',
+      details: { originalError: backendError },
+    });
+
+    expect(getCommandError(backendError)).toEqual(backendError);
+    expect(getCommandError(clientError)).toEqual(backendError);
+    expect(getCommandError(createTauriCommandError('start_mcp_server', clientError))).toEqual(backendError);
+    expect(getCommandError(new Error('plain failure'))).toBeNull();
+  });
+
+  it('classifies TauriCommandError by code before message text', () => {
+    const error = createTauriCommandError('add_skill', {
+      code: 'invalid_input',
+      message: 'Network path is missing SKILL.md',
+      retryable: false,
+    });
+
+    expect(error.isParameterError()).toBe(true);
+    expect(error.isNetworkError()).toBe(false);
+    expect(error.isRetryable()).toBe(false);
+  });
+});
+
+describe('getMCPFailure', () => {
+  it('reads the failure from structured details or derives it from the code', () => {
+    expect(getMCPFailure(backendError)).toEqual(backendError.details);
+    expect(
+      getMCPFailure({ code: 'timeout', message: 'Timeout: tools/call', retryable: true }),
+    ).toEqual({ kind: 'timeout', message: 'Timeout: tools/call' });
+  });
+});
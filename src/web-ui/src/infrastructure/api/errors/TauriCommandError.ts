@@ -1,5 +1,67 @@
  
 
+/** Stable error codes sent by commands that return a structured `ApiError`. */
+export type CommandErrorCode =
+  | 'invalid_input'
+  | 'not_found'
+  | 'conflict'
+  | 'configuration'
+  | 'unauthorized'
+  | 'io'
+  | 'network'
+  | 'timeout'
+  | 'cancelled'
+  | 'protocol'
+  | 'rejected'
+  | 'service_unavailable'
+  | 'ai'
+  | 'not_implemented'
+  | 'internal';
+
+/** A backend catalog message plus the inputs needed to render it again. */
+export interface LocalizedMessage {
+  /** Fluent message id, e.g. `mcp-server-start-failed`. */
+  id: string;
+  params?: Record<string, unknown>;
+  text: string;
+  locale: string;
+}
+
+/** Structured error returned by a backend command. */
+export interface CommandError {
+  code: CommandErrorCode;
+  message: string;
+  /** Domain data such as an AI error detail or an MCP failure. */
+  details?: unknown;
+  retryable: boolean;
+  /** Set when `message` was rendered from the backend message catalog. */
+  localized?: LocalizedMessage;
+}
+
+function asCommandError(value: unknown): CommandError | null {
+  if (!value || typeof value !== 'object') return null;
+  const candidate = value as Partial<CommandError>;
+  if (
+    typeof candidate.code !== 'string' ||
+    typeof candidate.message !== 'string' ||
+    typeof candidate.retryable !== 'boolean'
+  ) {
+    return null;
+  }
+  return candidate as CommandError;
+}
+
+/**
+ * Extracts the structured backend error from a raw invoke rejection, an
+ * `ApiClient` error, or a `TauriCommandError`.
+ */
+export function getCommandError(error: unknown): CommandError | null {
+  const unwrap = (value: unknown): CommandError | null =>
+    asCommandError(value) ??
+    asCommandError((value as { details?: { originalError?: unknown } } | null)?.details?.originalError);
+  return unwrap(error) ?? unwrap((error as { context?: { originalError?: unknown } } | null)?.context?.originalError);
+}
+
 export interface TauriCommandErrorContext {
   command: string;
   request?: any;
@@ -47,7 +109,20 @@ export class TauriCommandError extends Error {
   }
 
    
+  /** Structured backend error, when the command returned one. */
+  public get commandError(): CommandError | null {
+    return getCommandError(this.context.originalError);
+  }
+
+  public isRetryable(): boolean {
+    return this.commandError?.retryable ?? this.isNetworkError();
+  }
+
   public isNetworkError(): boolean {
+    const code = this.commandError?.code;
+    if (code) {
+      return code === 'network' || code === 'timeout';
+    }
     const message = this.message.toLowerCase();
     return message.includes('network') || 
            message.includes('connection') || 
@@ -57,6 +132,10 @@ export class TauriCommandError extends Error {
 
    
   public isPermissionError(): boolean {
+    const code = this.commandError?.code;
+    if (code) {
+      return code === 'unauthorized';
+    }
     const message = this.message.toLowerCase();
     return message.includes('permission') || 
            message.includes('access') || 
@@ -66,6 +145,10 @@ export class TauriCommandError extends Error {
 
    
   public isParameterError(): boolean {
+    const code = this.commandError?.code;
+    if (code) {
+      return code === 'invalid_input';
+    }
     const message = this.message.toLowerCase();
     return message.includes('parameter') || 
            message.includes('argument') || 
@@ -9,9 +9,26 @@ export * from './service-api/ApiClient';
 export * from './service-api/tauri-commands';
 export * from './service-api/AIApi';
 export * from './service-api/CronAPI';
+export * from './service-api/AgentTaskAPI';
+export * from './service-api/ApprovalAPI';
+export * from './service-api/GitCredentialAPI';
+export * from './service-api/WorkspaceTrustAPI';
+export * from './service-api/ThemeAPI';
+export * from './service-api/FileOperationAPI';
+export * from './service-api/CodeIndexAPI';
+export * from './service-api/SemanticSearchAPI';
+export * from './service-api/DocumentConversionAPI';
+export * from './service-api/AttachmentAPI';
+export * from './service-api/ArtifactAPI';
+export * from './service-api/ScreenCaptureAPI';
+export * from './service-api/BackgroundJobAPI';
+export * from './service-api/BackupAPI';
+export * from './service-api/ConnectivityAPI';
+export * from './service-api/ContextProviderAPI';
 export * from './service-api/PermissionAPI';
 export * from './service-api/PageAPI';
 export * from './service-api/SpeechAPI';
+export * from './service-api/DeepLinkAPI';
 
 // Import API modules
 import { workspaceAPI } from './service-api/WorkspaceAPI';
@@ -26,10 +43,37 @@ import { snapshotAPI } from './service-api/SnapshotAPI';
 import { globalAPI } from './service-api/GlobalAPI';
 import { contextAPI } from './service-api/ContextAPI';
 import { cronAPI } from './service-api/CronAPI';
+import { agentTaskAPI } from './service-api/AgentTaskAPI';
+import { approvalAPI } from './service-api/ApprovalAPI';
+import { workspaceTrustAPI } from './service-api/WorkspaceTrustAPI';
+import { themeAPI } from './service-api/ThemeAPI';
+import { fileOperationAPI } from './service-api/FileOperationAPI';
+import { codeIndexAPI } from './service-api/CodeIndexAPI';
+import { semanticSearchAPI } from './service-api/SemanticSearchAPI';
+import { documentConversionAPI } from './service-api/DocumentConversionAPI';
+import { attachmentAPI } from './service-api/AttachmentAPI';
+import { artifactAPI } from './service-api/ArtifactAPI';
+import { screenCaptureAPI } from './service-api/ScreenCaptureAPI';
+import { backgroundJobAPI } from './service-api/BackgroundJobAPI';
+import { backupAPI } from './service-api/BackupAPI';
+import { connectivityAPI } from './service-api/ConnectivityAPI';
+import { contextProviderAPI } from './service-api/ContextProviderAPI';
+import { credentialHealthAPI } from './service-api/CredentialHealthAPI';
+import { serviceHealthAPI } from './service-api/ServiceHealthAPI';
+import { operationsAPI } from './service-api/OperationsAPI';
+import { eventReplayAPI } from './service-api/EventReplayAPI';
+import { sidecarPluginAPI } from './service-api/SidecarPluginAPI';
+import { windowAPI } from './service-api/WindowAPI';
+import { powerAPI } from './service-api/PowerAPI';
+import { downloadAPI } from './service-api/DownloadAPI';
+import { hashingAPI } from './service-api/HashingAPI';
+import { projectTemplateAPI } from './service-api/ProjectTemplateAPI';
+import { aboutAPI } from './service-api/AboutAPI';
 import { permissionAPI } from './service-api/PermissionAPI';
 import { pageAPI } from './service-api/PageAPI';
 import { gitAPI } from './service-api/GitAPI';
 import { gitAgentAPI } from './service-api/GitAgentAPI';
+import { gitCredentialAPI } from './service-api/GitCredentialAPI';
 import { gitRepoHistoryAPI, type GitRepoHistory } from './service-api/GitRepoHistoryAPI';
 import { startchatAgentAPI } from './service-api/StartchatAgentAPI';
 import { sessionAPI } from './service-api/SessionAPI';
@@ -39,9 +83,10 @@ import { editorAiAPI } from './service-api/EditorAiAPI';
 import { reviewPlatformAPI } from './service-api/ReviewPlatformAPI';
 import { insightsApi } from './insightsApi';
 import { speechAPI } from './service-api/SpeechAPI';
+import { deepLinkAPI } from './service-api/DeepLinkAPI';
 
 // Export API modules
-export { workspaceAPI, configAPI, aiApi, toolAPI, agentAPI, systemAPI, projectAPI, diffAPI, snapshotAPI, globalAPI, contextAPI, cronAPI, permissionAPI, pageAPI, gitAPI, gitAgentAPI, gitRepoHistoryAPI, startchatAgentAPI, sessionAPI, i18nAPI, btwAPI, editorAiAPI, reviewPlatformAPI, insightsApi, speechAPI };
+export { workspaceAPI, configAPI, aiApi, toolAPI, agentAPI, systemAPI, projectAPI, diffAPI, snapshotAPI, globalAPI, contextAPI, cronAPI, agentTaskAPI, approvalAPI, workspaceTrustAPI, themeAPI, fileOperationAPI, codeIndexAPI, semanticSearchAPI, documentConversionAPI, attachmentAPI, artifactAPI, screenCaptureAPI, backgroundJobAPI, backupAPI, connectivityAPI, contextProviderAPI, credentialHealthAPI, serviceHealthAPI, operationsAPI, eventReplayAPI, sidecarPluginAPI, windowAPI, powerAPI, downloadAPI, hashingAPI, projectTemplateAPI, aboutAPI, permissionAPI, pageAPI, gitAPI, gitAgentAPI, gitCredentialAPI, gitRepoHistoryAPI, startchatAgentAPI, sessionAPI, i18nAPI, btwAPI, editorAiAPI, reviewPlatformAPI, insightsApi, speechAPI, deepLinkAPI };
 export * from './service-api/ReviewPlatformAPI';
 
 // Export types
@@ -62,10 +107,37 @@ export const bitfunAPI = {
   global: globalAPI,
   context: contextAPI,
   cron: cronAPI,
+  agentTasks: agentTaskAPI,
+  approvals: approvalAPI,
+  workspaceTrust: workspaceTrustAPI,
+  themes: themeAPI,
+  fileOperations: fileOperationAPI,
+  codeIndex: codeIndexAPI,
+  semanticSearch: semanticSearchAPI,
+  documentConversion: documentConversionAPI,
+  attachments: attachmentAPI,
+  artifacts: artifactAPI,
+  screenCapture: screenCaptureAPI,
+  backgroundJobs: backgroundJobAPI,
+  backup: backupAPI,
+  connectivity: connectivityAPI,
+  contextProvider: contextProviderAPI,
+  credentialHealth: credentialHealthAPI,
+  serviceHealth: serviceHealthAPI,
+  operations: operationsAPI,
+  eventReplay: eventReplayAPI,
+  sidecarPlugins: sidecarPluginAPI,
+  windows: windowAPI,
+  power: powerAPI,
+  downloads: downloadAPI,
+  hashing: hashingAPI,
+  projectTemplates: projectTemplateAPI,
+  about: aboutAPI,
   permission: permissionAPI,
   pages: pageAPI,
   git: gitAPI,
   gitAgent: gitAgentAPI,
+  gitCredentials: gitCredentialAPI,
   gitRepoHistory: gitRepoHistoryAPI,
   startchatAgent: startchatAgentAPI,
   session: sessionAPI,
@@ -75,6 +147,7 @@ export const bitfunAPI = {
   reviewPlatform: reviewPlatformAPI,
   insights: insightsApi,
   speech: speechAPI,
+  deepLinks: deepLinkAPI,
 };
 
 // Default export
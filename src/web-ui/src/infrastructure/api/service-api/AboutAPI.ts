@@ -0,0 +1,38 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type UpdateChannel = 'stable' | 'beta' | 'nightly' | 'dev';
+
+export interface ComponentVersion {
+  id: string;
+  name: string;
+  version: string;
+}
+
+export interface AppInfo {
+  name: string;
+  version: string;
+  /** Short commit hash; absent for builds made outside a git checkout. */
+  buildHash: string | null;
+  channel: UpdateChannel;
+  /** Tauri, MCP protocol and managed runtime versions. */
+  components: ComponentVersion[];
+  license: {
+    spdxId: string;
+    text: string;
+  };
+  /** Release notes of the installed version, as markdown. */
+  changelog: string | null;
+}
+
+export class AboutAPI {
+  async getAppInfo(): Promise<AppInfo> {
+    try {
+      return await api.invoke<AppInfo>('get_app_info');
+    } catch (error) {
+      throw createTauriCommandError('get_app_info', error);
+    }
+  }
+}
+
+export const aboutAPI = new AboutAPI();
@@ -79,6 +79,8 @@ export interface StartDialogTurnRequest {
   /** Optional multimodal image contexts (snake_case fields, aligned with backend ImageContextData). */
   imageContexts?: ImageInputContextData[];
   userMessageMetadata?: Record<string, unknown>;
+  /** Ids of session attachments (see `AttachmentAPI`) sent with this message. */
+  attachmentIds?: string[];
 }
 
 export interface StartDialogTurnResponse {
@@ -0,0 +1,111 @@
+import { api } from './ApiClient';
+import { eventReplayAPI } from './EventReplayAPI';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type AgentTaskPriority = 'low' | 'normal' | 'high';
+export type AgentTaskStatus = 'queued' | 'running' | 'paused' | 'completed' | 'failed' | 'cancelled';
+export type AgentTaskLifecycle =
+  | 'enqueued'
+  | 'started'
+  | 'paused'
+  | 'resumed'
+  | 'retried'
+  | 'completed'
+  | 'failed'
+  | 'cancelled';
+
+export interface AgentTask {
+  id: string;
+  sessionId: string;
+  title?: string | null;
+  prompt: string;
+  agentType: string;
+  workspacePath?: string | null;
+  remoteConnectionId?: string | null;
+  remoteSshHost?: string | null;
+  priority: AgentTaskPriority;
+  status: AgentTaskStatus;
+  attempts: number;
+  maxAttempts: number;
+  activeTurnId?: string | null;
+  lastError?: string | null;
+  createdAtMs: number;
+  updatedAtMs: number;
+  startedAtMs?: number | null;
+  finishedAtMs?: number | null;
+}
+
+export interface AgentTaskEvent {
+  lifecycle: AgentTaskLifecycle;
+  task: AgentTask;
+}
+
+export interface EnqueueAgentTaskRequest {
+  sessionId: string;
+  title?: string;
+  prompt: string;
+  agentType?: string;
+  workspacePath?: string;
+  remoteConnectionId?: string;
+  remoteSshHost?: string;
+  priority?: AgentTaskPriority;
+  maxAttempts?: number;
+}
+
+type AgentTaskAction = 'pause' | 'resume' | 'retry' | 'cancel';
+
+export class AgentTaskAPI {
+  async listTasks(sessionId?: string): Promise<AgentTask[]> {
+    try {
+      return await api.invoke<AgentTask[]>('list_agent_tasks', { request: { sessionId } });
+    } catch (error) {
+      throw createTauriCommandError('list_agent_tasks', error, { sessionId });
+    }
+  }
+
+  async enqueueTask(request: EnqueueAgentTaskRequest): Promise<AgentTask> {
+    try {
+      return await api.invoke<AgentTask>('enqueue_agent_task', { request });
+    } catch (error) {
+      throw createTauriCommandError('enqueue_agent_task', error, request);
+    }
+  }
+
+  pauseTask(taskId: string): Promise<AgentTask> {
+    return this.applyAction('pause', taskId);
+  }
+
+  resumeTask(taskId: string): Promise<AgentTask> {
+    return this.applyAction('resume', taskId);
+  }
+
+  retryTask(taskId: string): Promise<AgentTask> {
+    return this.applyAction('retry', taskId);
+  }
+
+  cancelTask(taskId: string): Promise<AgentTask> {
+    return this.applyAction('cancel', taskId);
+  }
+
+  /** With `replay`, first delivers task updates this page missed, e.g. before a reload. */
+  onTaskUpdated(
+    callback: (event: AgentTaskEvent) => void,
+    options: { replay?: boolean } = {},
+  ): () => void {
+    if (options.replay) {
+      return eventReplayAPI.listenWithReplay<AgentTaskEvent>('agent_task_updated', callback);
+    }
+    return api.listen<AgentTaskEvent>('agent_task_updated', callback);
+  }
+
+  private async applyAction(action: AgentTaskAction, taskId: string): Promise<AgentTask> {
+    const command = `${action}_agent_task`;
+    try {
+      return await api.invoke<AgentTask>(command, { request: { taskId } });
+    } catch (error) {
+      throw createTauriCommandError(command, error, { taskId });
+    }
+  }
+}
+
+export const agentTaskAPI = new AgentTaskAPI();
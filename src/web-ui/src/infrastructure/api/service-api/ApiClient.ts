@@ -34,6 +34,17 @@ function shouldEstimateApiPayloadBytes(): boolean {
   return globalThis.__BITFUN_PERF_TRACE_ENABLED__ === true;
 }
 
+/** Tauri commands may reject with structured errors (e.g. `{ kind, message }`). */
+function commandErrorMessage(error: unknown): string {
+  if (error instanceof Error) {
+    return error.message;
+  }
+  if (error && typeof error === 'object' && typeof (error as { message?: unknown }).message === 'string') {
+    return (error as { message: string }).message;
+  }
+  return String(error);
+}
+
 function apiErrorCause(error: unknown): unknown {
   if (!(error instanceof Error)) {
     return error;
@@ -63,8 +74,7 @@ function isOptionalConfigNotFoundCommand(config: TauriCommandConfig, error: unkn
     return false;
   }
 
-  const errorMessage = error instanceof Error ? error.message : String(error);
-  const normalized = errorMessage.toLowerCase();
+  const normalized = commandErrorMessage(error).toLowerCase();
   return normalized.includes('not found') && normalized.includes('config path');
 }
 
@@ -421,7 +431,7 @@ export class ApiClient implements IApiClient {
         timestamp: new Date()
       };
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = commandErrorMessage(error);
       
       
       const isExpectedError = errorMessage.includes('not found') || 
@@ -0,0 +1,131 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type ApprovalKind =
+  | 'dangerous_command'
+  | 'destructive_mcp_tool'
+  | 'file_deletion'
+  | 'remote_device_action'
+  | 'plugin_permission';
+export type ApprovalDecision = 'approve' | 'deny';
+export type ApprovalResponder =
+  | 'desktop'
+  | 'remote_client'
+  | 'remembered'
+  | 'timeout'
+  | 'tool_permission';
+
+export interface ApprovalRequest {
+  id: string;
+  kind: ApprovalKind;
+  title: string;
+  detail?: string | null;
+  subject: string;
+  sessionId?: string | null;
+  requester?: string | null;
+  createdAtMs: number;
+  expiresAtMs?: number | null;
+  onTimeout: ApprovalDecision;
+  allowRemember: boolean;
+}
+
+export interface ApprovalResolution {
+  requestId: string;
+  decision: ApprovalDecision;
+  responder: ApprovalResponder;
+  remembered: boolean;
+  resolvedAtMs: number;
+}
+
+export interface RememberedApproval {
+  kind: ApprovalKind;
+  subject: string;
+  decision: ApprovalDecision;
+  rememberedAtMs: number;
+}
+
+export interface ApprovalPolicy {
+  timeoutSecs?: number | null;
+  onTimeout: ApprovalDecision;
+  allowRemember: boolean;
+}
+
+export interface ApprovalPolicyEntry {
+  kind: ApprovalKind;
+  policy: ApprovalPolicy;
+}
+
+export type ApprovalEvent =
+  | { type: 'requested'; request: ApprovalRequest }
+  | { type: 'resolved'; request: ApprovalRequest; resolution: ApprovalResolution };
+
+export class ApprovalAPI {
+  async listPending(): Promise<ApprovalRequest[]> {
+    try {
+      return await api.invoke<ApprovalRequest[]>('list_pending_approvals');
+    } catch (error) {
+      throw createTauriCommandError('list_pending_approvals', error);
+    }
+  }
+
+  async respond(
+    requestId: string,
+    decision: ApprovalDecision,
+    remember = false
+  ): Promise<ApprovalResolution> {
+    const request = { requestId, decision, remember };
+    try {
+      return await api.invoke<ApprovalResolution>('respond_approval', { request });
+    } catch (error) {
+      throw createTauriCommandError('respond_approval', error, request);
+    }
+  }
+
+  async listRemembered(): Promise<RememberedApproval[]> {
+    try {
+      return await api.invoke<RememberedApproval[]>('list_remembered_approvals');
+    } catch (error) {
+      throw createTauriCommandError('list_remembered_approvals', error);
+    }
+  }
+
+  async forgetRemembered(kind: ApprovalKind, subject: string): Promise<boolean> {
+    const request = { kind, subject };
+    try {
+      return await api.invoke<boolean>('forget_remembered_approval', { request });
+    } catch (error) {
+      throw createTauriCommandError('forget_remembered_approval', error, request);
+    }
+  }
+
+  async clearRemembered(): Promise<number> {
+    try {
+      return await api.invoke<number>('clear_remembered_approvals');
+    } catch (error) {
+      throw createTauriCommandError('clear_remembered_approvals', error);
+    }
+  }
+
+  async getPolicies(): Promise<ApprovalPolicyEntry[]> {
+    try {
+      return await api.invoke<ApprovalPolicyEntry[]>('get_approval_policies');
+    } catch (error) {
+      throw createTauriCommandError('get_approval_policies', error);
+    }
+  }
+
+  async setPolicy(kind: ApprovalKind, policy: ApprovalPolicy): Promise<void> {
+    const request = { kind, policy };
+    try {
+      await api.invoke<void>('set_approval_policy', { request });
+    } catch (error) {
+      throw createTauriCommandError('set_approval_policy', error, request);
+    }
+  }
+
+  onApprovalEvent(callback: (event: ApprovalEvent) => void): () => void {
+    return api.listen<ApprovalEvent>('approval_event', callback);
+  }
+}
+
+export const approvalAPI = new ApprovalAPI();
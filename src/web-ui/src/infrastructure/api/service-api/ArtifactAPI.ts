@@ -0,0 +1,92 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type ArtifactKind = 'code' | 'document' | 'image' | 'other';
+
+export interface ArtifactRecord {
+  id: string;
+  sessionId: string;
+  /** Dialog turn or tool call that produced the file. */
+  taskId?: string | null;
+  name: string;
+  kind: ArtifactKind;
+  mimeType: string;
+  sizeBytes: number;
+  /** Absolute path of the managed copy. */
+  path: string;
+  sourcePath?: string | null;
+  createdAtMs: number;
+}
+
+export type ArtifactPreview =
+  | { type: 'text'; content: string; truncated: boolean }
+  | { type: 'image'; dataUrl: string }
+  | { type: 'unavailable' };
+
+export interface RegisterArtifactRequest {
+  sessionId: string;
+  taskId?: string;
+  sourcePath: string;
+  name?: string;
+}
+
+export class ArtifactAPI {
+  /** Newest first; every session when `sessionId` is omitted. */
+  async list(sessionId?: string): Promise<ArtifactRecord[]> {
+    const request = { sessionId };
+    try {
+      return await api.invoke<ArtifactRecord[]>('list_artifacts', { request });
+    } catch (error) {
+      throw createTauriCommandError('list_artifacts', error, request);
+    }
+  }
+
+  /** Copies a file produced by an agent task into managed storage. */
+  async register(request: RegisterArtifactRequest): Promise<ArtifactRecord> {
+    try {
+      return await api.invoke<ArtifactRecord>('register_artifact', { request });
+    } catch (error) {
+      throw createTauriCommandError('register_artifact', error, request);
+    }
+  }
+
+  async preview(artifactId: string): Promise<ArtifactPreview> {
+    const request = { artifactId };
+    try {
+      return await api.invoke<ArtifactPreview>('preview_artifact', { request });
+    } catch (error) {
+      throw createTauriCommandError('preview_artifact', error, request);
+    }
+  }
+
+  /** Opens the artifact with the system's default application. */
+  async open(artifactId: string): Promise<void> {
+    const request = { artifactId };
+    try {
+      await api.invoke<void>('open_artifact', { request });
+    } catch (error) {
+      throw createTauriCommandError('open_artifact', error, request);
+    }
+  }
+
+  /** Returns the path the artifact was written to. */
+  async export(artifactId: string, targetPath: string): Promise<string> {
+    const request = { artifactId, targetPath };
+    try {
+      return await api.invoke<string>('export_artifact', { request });
+    } catch (error) {
+      throw createTauriCommandError('export_artifact', error, request);
+    }
+  }
+
+  async delete(artifactId: string): Promise<boolean> {
+    const request = { artifactId };
+    try {
+      return await api.invoke<boolean>('delete_artifact', { request });
+    } catch (error) {
+      throw createTauriCommandError('delete_artifact', error, request);
+    }
+  }
+}
+
+export const artifactAPI = new ArtifactAPI();
@@ -0,0 +1,71 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type AttachmentKind = 'image' | 'text' | 'file';
+export type AttachmentOrigin = 'drop' | 'clipboard';
+
+export interface AttachmentHandle {
+  id: string;
+  sessionId: string;
+  kind: AttachmentKind;
+  origin: AttachmentOrigin;
+  name: string;
+  mimeType: string;
+  sizeBytes: number;
+  /** Absolute path of the stored copy. */
+  path: string;
+  sourcePath?: string | null;
+  createdAtMs: number;
+}
+
+export interface RejectedAttachment {
+  source: string;
+  error: string;
+}
+
+export interface IngestAttachmentsResponse {
+  attachments: AttachmentHandle[];
+  rejected: RejectedAttachment[];
+}
+
+export class AttachmentAPI {
+  /** Stores dropped files (paths from the native drag-drop event) for a session. */
+  async ingestDroppedFiles(sessionId: string, paths: string[]): Promise<IngestAttachmentsResponse> {
+    const request = { sessionId, paths };
+    try {
+      return await api.invoke<IngestAttachmentsResponse>('ingest_dropped_files', { request });
+    } catch (error) {
+      throw createTauriCommandError('ingest_dropped_files', error, request);
+    }
+  }
+
+  /** Stores the current clipboard files, image or text for a session. */
+  async ingestClipboard(sessionId: string): Promise<IngestAttachmentsResponse> {
+    const request = { sessionId };
+    try {
+      return await api.invoke<IngestAttachmentsResponse>('ingest_clipboard', { request });
+    } catch (error) {
+      throw createTauriCommandError('ingest_clipboard', error, request);
+    }
+  }
+
+  async list(sessionId: string): Promise<AttachmentHandle[]> {
+    const request = { sessionId };
+    try {
+      return await api.invoke<AttachmentHandle[]>('list_session_attachments', { request });
+    } catch (error) {
+      throw createTauriCommandError('list_session_attachments', error, request);
+    }
+  }
+
+  async remove(sessionId: string, attachmentId: string): Promise<boolean> {
+    const request = { sessionId, attachmentId };
+    try {
+      return await api.invoke<boolean>('remove_session_attachment', { request });
+    } catch (error) {
+      throw createTauriCommandError('remove_session_attachment', error, request);
+    }
+  }
+}
+
+export const attachmentAPI = new AttachmentAPI();
@@ -0,0 +1,110 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+import type { CronSchedule } from './CronAPI';
+import type { EnqueueAgentTaskRequest } from './AgentTaskAPI';
+
+export type BackgroundJobAction =
+  | { kind: 'refresh_skill_market_cache' }
+  | { kind: 'check_updates' }
+  | { kind: 'cleanup_temp_dirs'; retentionDays?: number }
+  | { kind: 'run_agent_task'; task: EnqueueAgentTaskRequest };
+
+export type BackgroundJobKind = BackgroundJobAction['kind'];
+export type BackgroundJobRunStatus = 'succeeded' | 'failed' | 'skipped';
+
+export interface BackgroundJob {
+  id: string;
+  name: string;
+  action: BackgroundJobAction;
+  schedule: CronSchedule;
+  /** Each run is delayed by a random amount up to this many milliseconds. */
+  jitterMs: number;
+  enabled: boolean;
+  createdAtMs: number;
+  updatedAtMs: number;
+  nextRunAtMs?: number | null;
+  lastRunAtMs?: number | null;
+}
+
+export interface BackgroundJobRun {
+  id: number;
+  jobId: string;
+  manual: boolean;
+  startedAtMs: number;
+  finishedAtMs: number;
+  status: BackgroundJobRunStatus;
+  message?: string | null;
+}
+
+export interface CreateBackgroundJobRequest {
+  name: string;
+  action: BackgroundJobAction;
+  schedule: CronSchedule;
+  jitterMs?: number;
+  enabled?: boolean;
+}
+
+export interface UpdateBackgroundJobRequest {
+  name?: string;
+  action?: BackgroundJobAction;
+  schedule?: CronSchedule;
+  jitterMs?: number;
+  enabled?: boolean;
+}
+
+export class BackgroundJobAPI {
+  async listJobs(): Promise<BackgroundJob[]> {
+    try {
+      return await api.invoke<BackgroundJob[]>('list_background_jobs');
+    } catch (error) {
+      throw createTauriCommandError('list_background_jobs', error);
+    }
+  }
+
+  async createJob(request: CreateBackgroundJobRequest): Promise<BackgroundJob> {
+    try {
+      return await api.invoke<BackgroundJob>('create_background_job', { request });
+    } catch (error) {
+      throw createTauriCommandError('create_background_job', error, request);
+    }
+  }
+
+  async updateJob(jobId: string, update: UpdateBackgroundJobRequest): Promise<BackgroundJob> {
+    const request = { jobId, ...update };
+    try {
+      return await api.invoke<BackgroundJob>('update_background_job', { request });
+    } catch (error) {
+      throw createTauriCommandError('update_background_job', error, request);
+    }
+  }
+
+  async deleteJob(jobId: string): Promise<boolean> {
+    const request = { jobId };
+    try {
+      return await api.invoke<boolean>('delete_background_job', { request });
+    } catch (error) {
+      throw createTauriCommandError('delete_background_job', error, request);
+    }
+  }
+
+  async runJobNow(jobId: string): Promise<BackgroundJobRun> {
+    const request = { jobId };
+    try {
+      return await api.invoke<BackgroundJobRun>('run_background_job_now', { request });
+    } catch (error) {
+      throw createTauriCommandError('run_background_job_now', error, request);
+    }
+  }
+
+  /** Most recent first; every job when `jobId` is omitted. */
+  async listRuns(jobId?: string, limit?: number): Promise<BackgroundJobRun[]> {
+    const request = { jobId, limit };
+    try {
+      return await api.invoke<BackgroundJobRun[]>('list_background_job_runs', { request });
+    } catch (error) {
+      throw createTauriCommandError('list_background_job_runs', error, request);
+    }
+  }
+}
+
+export const backgroundJobAPI = new BackgroundJobAPI();
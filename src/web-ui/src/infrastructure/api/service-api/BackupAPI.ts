@@ -0,0 +1,52 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type BackupSection = 'config' | 'skills' | 'sessions' | 'credentials';
+
+export interface BackupSummary {
+  archivePath: string;
+  createdAtMs: number;
+  sections: BackupSection[];
+  fileCount: number;
+  totalBytes: number;
+  /** Secrets left out of the archive. */
+  redactedSecrets: number;
+}
+
+/** A secret blanked in the archive, as a JSON pointer into an archived file. */
+export interface SecretReference {
+  path: string;
+  pointer: string;
+}
+
+export interface RestoreBackupResult {
+  sections: BackupSection[];
+  restoredFiles: number;
+  /** Secrets with no current value to keep; they must be entered again. */
+  secretsToReenter: SecretReference[];
+  restartRequired: boolean;
+}
+
+export class BackupAPI {
+  /** Every section and the managed backups directory by default. */
+  async create(sections: BackupSection[] = [], destinationDir?: string): Promise<BackupSummary> {
+    const request = { sections, destinationDir };
+    try {
+      return await api.invoke<BackupSummary>('create_backup', { request });
+    } catch (error) {
+      throw createTauriCommandError('create_backup', error, request);
+    }
+  }
+
+  /** Restores every archived section unless `sections` narrows it; restart afterwards. */
+  async restore(archivePath: string, sections?: BackupSection[]): Promise<RestoreBackupResult> {
+    const request = { archivePath, sections };
+    try {
+      return await api.invoke<RestoreBackupResult>('restore_backup', { request });
+    } catch (error) {
+      throw createTauriCommandError('restore_backup', error, request);
+    }
+  }
+}
+
+export const backupAPI = new BackupAPI();
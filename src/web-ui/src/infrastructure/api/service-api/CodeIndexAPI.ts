@@ -0,0 +1,81 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type SymbolKind =
+  | 'function'
+  | 'method'
+  | 'struct'
+  | 'class'
+  | 'interface'
+  | 'trait'
+  | 'enum'
+  | 'type_alias'
+  | 'constant'
+  | 'module'
+  | 'import';
+
+export type CodeLanguage =
+  | 'rust'
+  | 'typescript'
+  | 'tsx'
+  | 'javascript'
+  | 'python'
+  | 'go'
+  | 'java'
+  | 'c'
+  | 'cpp';
+
+export interface CodeSymbol {
+  name: string;
+  kind: SymbolKind;
+  /** Workspace-relative, `/`-separated. */
+  path: string;
+  /** 1-based, inclusive. */
+  startLine: number;
+  endLine: number;
+  container?: string;
+  signature: string;
+}
+
+export interface FileOutline {
+  path: string;
+  language: CodeLanguage;
+  symbols: CodeSymbol[];
+}
+
+export interface SymbolQuery {
+  /** Case-insensitive; `Type::member` and `Type.member` also match the container. */
+  name: string;
+  kinds?: SymbolKind[];
+  pathPrefix?: string;
+  limit?: number;
+}
+
+export interface SymbolSearchResult {
+  symbols: CodeSymbol[];
+  totalMatches: number;
+  filesIndexed: number;
+  filesParsed: number;
+}
+
+export class CodeIndexAPI {
+  async findSymbol(workspacePath: string, query: SymbolQuery): Promise<SymbolSearchResult> {
+    const request = { workspacePath, ...query };
+    try {
+      return await api.invoke<SymbolSearchResult>('find_symbol', { request });
+    } catch (error) {
+      throw createTauriCommandError('find_symbol', error, request);
+    }
+  }
+
+  async getFileOutline(workspacePath: string, filePath: string): Promise<FileOutline> {
+    const request = { workspacePath, filePath };
+    try {
+      return await api.invoke<FileOutline>('get_file_outline', { request });
+    } catch (error) {
+      throw createTauriCommandError('get_file_outline', error, request);
+    }
+  }
+}
+
+export const codeIndexAPI = new CodeIndexAPI();
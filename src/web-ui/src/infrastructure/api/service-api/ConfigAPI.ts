@@ -7,9 +7,12 @@ import type {
   DiagnosticsBundleInfo,
   ModeSkillInfo,
   RuntimeLoggingInfo,
+  SkillBundleExportResult,
   SkillInfo,
+  SkillInstallUndoResult,
   SkillLevel,
   SkillMarketDownloadResult,
+  SkillMarketInstallPreview,
   SkillMarketItem,
   SkillValidationResult,
 } from '../../config/types';
@@ -54,10 +57,18 @@ export interface DeleteSkillParams {
   workspacePath?: string;
 }
 
+export interface ExportSkillBundleParams {
+  skillKey: string;
+  workspacePath?: string;
+  targetPath: string;
+}
+
 export interface DownloadSkillMarketParams {
   packageId: string;
   level?: SkillLevel;
   workspacePath?: string;
+  /** Pass to `operationsAPI.cancelOperation` to stop the installer. */
+  operationId?: string;
 }
 
 
@@ -194,6 +205,15 @@ export class ConfigAPI {
     }
   }
 
+  /** Support archive for bug reports: system info, runtimes, MCP status, redacted logs and config. */
+  async generateDiagnosticsBundle(): Promise<DiagnosticsBundleInfo> {
+    try {
+      return await api.invoke('generate_diagnostics_bundle');
+    } catch (error) {
+      throw createTauriCommandError('generate_diagnostics_bundle', error);
+    }
+  }
+
    
   async getModelConfigs(): Promise<any[]> {
     try {
@@ -381,6 +401,14 @@ export class ConfigAPI {
     }
   }
 
+  async duplicateSkillToUser(skillName: string): Promise<SkillInfo> {
+    try {
+      return await api.invoke('duplicate_skill_to_user', { skillName });
+    } catch (error) {
+      throw createTauriCommandError('duplicate_skill_to_user', error, { skillName });
+    }
+  }
+
   async listSkillMarket(query?: string, limit?: number): Promise<SkillMarketItem[]> {
     try {
       return await api.invoke('list_skill_market', {
@@ -405,10 +433,11 @@ export class ConfigAPI {
     packageId,
     level = 'project',
     workspacePath,
+    operationId,
   }: DownloadSkillMarketParams): Promise<SkillMarketDownloadResult> {
     try {
       return await api.invoke('download_skill_market', {
-        request: { package: packageId, level, workspacePath }
+        request: { package: packageId, level, workspacePath, operationId }
       });
     } catch (error) {
       throw createTauriCommandError('download_skill_market', error, {
@@ -418,6 +447,44 @@ export class ConfigAPI {
       });
     }
   }
+
+  /** Zips a skill folder together with the companion files it references. */
+  async exportSkillBundle(params: ExportSkillBundleParams): Promise<SkillBundleExportResult> {
+    try {
+      return await api.invoke('export_skill_bundle', { request: params });
+    } catch (error) {
+      throw createTauriCommandError('export_skill_bundle', error, params);
+    }
+  }
+
+  /** Removes the files and folders a previous downloadSkillMarket call created. */
+  async undoSkillInstall(installId: string): Promise<SkillInstallUndoResult> {
+    try {
+      return await api.invoke('undo_skill_install', { installId });
+    } catch (error) {
+      throw createTauriCommandError('undo_skill_install', error, { installId });
+    }
+  }
+
+  /** Resolves what downloadSkillMarket would install without writing anything. */
+  async previewSkillMarketInstall({
+    packageId,
+    level = 'project',
+    workspacePath,
+    operationId,
+  }: DownloadSkillMarketParams): Promise<SkillMarketInstallPreview> {
+    try {
+      return await api.invoke('preview_skill_market_install', {
+        request: { package: packageId, level, workspacePath, operationId }
+      });
+    } catch (error) {
+      throw createTauriCommandError('preview_skill_market_install', error, {
+        package: packageId,
+        level,
+        workspacePath,
+      });
+    }
+  }
 }
 
 
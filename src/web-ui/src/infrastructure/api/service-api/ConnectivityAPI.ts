@@ -0,0 +1,49 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type ConnectivityTargetKind = 'model' | 'skill_registry' | 'mcp_server' | 'other';
+export type ReachabilityState = 'unknown' | 'reachable' | 'unreachable';
+
+export interface EndpointStatus {
+  id: string;
+  label: string;
+  kind: ConnectivityTargetKind;
+  url: string;
+  state: ReachabilityState;
+  latencyMs?: number;
+  error?: string;
+  lastCheckedAtMs?: number;
+  consecutiveFailures: number;
+}
+
+export interface ConnectivitySnapshot {
+  /** General internet reachability; `unknown` until the first probe finishes. */
+  internet: ReachabilityState;
+  endpoints: EndpointStatus[];
+  checkedAtMs?: number;
+}
+
+export class ConnectivityAPI {
+  async getStatus(): Promise<ConnectivitySnapshot> {
+    try {
+      return await api.invoke<ConnectivitySnapshot>('get_connectivity_status');
+    } catch (error) {
+      throw createTauriCommandError('get_connectivity_status', error);
+    }
+  }
+
+  /** Probes every endpoint immediately instead of waiting for the next interval. */
+  async refresh(): Promise<ConnectivitySnapshot> {
+    try {
+      return await api.invoke<ConnectivitySnapshot>('refresh_connectivity');
+    } catch (error) {
+      throw createTauriCommandError('refresh_connectivity', error);
+    }
+  }
+
+  onConnectivityChanged(callback: (snapshot: ConnectivitySnapshot) => void): () => void {
+    return api.listen<ConnectivitySnapshot>('connectivity_changed', callback);
+  }
+}
+
+export const connectivityAPI = new ConnectivityAPI();
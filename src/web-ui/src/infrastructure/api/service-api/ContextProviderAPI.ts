@@ -0,0 +1,28 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export interface OpenEditorFile {
+  path: string;
+  /** The tab currently focused in the editor. */
+  active?: boolean;
+  /** Has unsaved edits. */
+  dirty?: boolean;
+}
+
+export interface SetContextOpenFilesRequest {
+  workspacePath: string;
+  files: OpenEditorFile[];
+}
+
+export class ContextProviderAPI {
+  /** Reports the editor tabs open in a workspace for the `open_files` context provider. */
+  async setOpenFiles(request: SetContextOpenFilesRequest): Promise<void> {
+    try {
+      await api.invoke<void>('set_context_open_files', { request });
+    } catch (error) {
+      throw createTauriCommandError('set_context_open_files', error, request);
+    }
+  }
+}
+
+export const contextProviderAPI = new ContextProviderAPI();
@@ -0,0 +1,43 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type CredentialState = 'unknown' | 'valid' | 'rejected';
+
+export interface ModelCredentialStatus {
+  modelId: string;
+  modelName: string;
+  state: CredentialState;
+  error?: string;
+  lastCheckedAtMs?: number;
+  consecutiveAuthFailures: number;
+}
+
+export interface CredentialHealthSnapshot {
+  models: ModelCredentialStatus[];
+  checkedAtMs?: number;
+}
+
+export class CredentialHealthAPI {
+  async getStatus(): Promise<CredentialHealthSnapshot> {
+    try {
+      return await api.invoke<CredentialHealthSnapshot>('get_credential_health_status');
+    } catch (error) {
+      throw createTauriCommandError('get_credential_health_status', error);
+    }
+  }
+
+  /** Checks every model's credentials now instead of waiting for the next interval. */
+  async refresh(): Promise<CredentialHealthSnapshot> {
+    try {
+      return await api.invoke<CredentialHealthSnapshot>('refresh_credential_health');
+    } catch (error) {
+      throw createTauriCommandError('refresh_credential_health', error);
+    }
+  }
+
+  onCredentialHealthChanged(callback: (snapshot: CredentialHealthSnapshot) => void): () => void {
+    return api.listen<CredentialHealthSnapshot>('credential_health_changed', callback);
+  }
+}
+
+export const credentialHealthAPI = new CredentialHealthAPI();
@@ -57,6 +57,16 @@ export interface UpdateCustomAgentPayload {
   workspacePath?: string;
 }
 
+export type CustomAgentLoadIssueSeverity = 'error' | 'warning';
+
+/** A `.bitfun/agents` file that was skipped or partially ignored on load. */
+export interface CustomAgentLoadIssue {
+  path: string;
+  agentId?: string;
+  severity: CustomAgentLoadIssueSeverity;
+  message: string;
+}
+
 function emitCustomAgentCatalogUpdated(payload: {
   agentId?: string;
   kind?: CustomAgentKind;
@@ -106,6 +116,12 @@ export const CustomAgentAPI = {
     emitCustomAgentCatalogUpdated({ agentId, workspacePath });
   },
 
+  async getCustomAgentLoadIssues(workspacePath?: string): Promise<CustomAgentLoadIssue[]> {
+    return api.invoke<CustomAgentLoadIssue[]>('get_custom_agent_load_issues', {
+      request: { workspacePath },
+    });
+  },
+
   async reloadCustomAgents(workspacePath?: string): Promise<void> {
     await api.invoke('reload_custom_agents', {
       request: { workspacePath },
@@ -0,0 +1,26 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+/** A `bitfun://` link the user already confirmed in the native dialog. */
+export type DeepLinkAction =
+  | { action: 'open'; path: string }
+  | { action: 'installSkill'; skillId: string }
+  | { action: 'addMcpServer'; config: Record<string, unknown> };
+
+export class DeepLinkAPI {
+  /** Returns and clears the confirmed links the frontend has not handled yet. */
+  async takePending(): Promise<DeepLinkAction[]> {
+    try {
+      return await api.invoke<DeepLinkAction[]>('take_pending_deep_links');
+    } catch (error) {
+      throw createTauriCommandError('take_pending_deep_links', error);
+    }
+  }
+
+  /** Fired without a payload whenever a confirmed link is queued. */
+  onReceived(callback: () => void): () => void {
+    return api.listen<void>('bitfun_deep_link_received', callback);
+  }
+}
+
+export const deepLinkAPI = new DeepLinkAPI();
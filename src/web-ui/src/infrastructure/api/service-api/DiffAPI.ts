@@ -32,6 +32,46 @@ export interface DiffLine {
   new_line_number?: number;
 }
 
+export type PatchLine =
+  | { type: 'context'; text: string }
+  | { type: 'add'; text: string }
+  | { type: 'remove'; text: string };
+
+export interface PatchHunkPreview {
+  hunkIndex: number;
+  expectedStart: number;
+  appliedStart: number;
+  matchKind: 'exact' | 'ignoring_trailing_whitespace' | 'ignoring_indentation';
+  /** Context lines dropped from each end to place the hunk. */
+  fuzz: number;
+  lines: PatchLine[];
+}
+
+export interface PatchConflict {
+  hunkIndex: number;
+  expectedStart: number;
+  reason: string;
+  expectedLines: string[];
+  actualStart: number;
+  actualLines: string[];
+}
+
+export interface FilePatchReport {
+  path: string;
+  previousPath?: string | null;
+  change: 'create' | 'modify' | 'delete' | 'rename';
+  additions: number;
+  deletions: number;
+  hunks: PatchHunkPreview[];
+  conflicts: PatchConflict[];
+}
+
+/** Application is all-or-nothing: `applied` is false whenever any file conflicts. */
+export interface PatchReport {
+  applied: boolean;
+  files: FilePatchReport[];
+}
+
 
 
 export class DiffAPI {
@@ -57,6 +97,26 @@ export class DiffAPI {
     }
   }
 
+  async previewWorkspacePatch(workspacePath: string, patch: string): Promise<PatchReport> {
+    try {
+      return await api.invoke<PatchReport>('preview_workspace_patch', {
+        request: { workspacePath, patch }
+      });
+    } catch (error) {
+      throw createTauriCommandError('preview_workspace_patch', error, { workspacePath });
+    }
+  }
+
+  async applyWorkspacePatch(workspacePath: string, patch: string): Promise<PatchReport> {
+    try {
+      return await api.invoke<PatchReport>('apply_workspace_patch', {
+        request: { workspacePath, patch }
+      });
+    } catch (error) {
+      throw createTauriCommandError('apply_workspace_patch', error, { workspacePath });
+    }
+  }
+
    
   async saveMergedDiffContent(filePath: string, content: string): Promise<void> {
     try {
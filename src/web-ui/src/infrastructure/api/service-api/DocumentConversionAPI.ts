@@ -0,0 +1,76 @@
+import { api } from './ApiClient';
+import { eventReplayAPI } from './EventReplayAPI';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+import type { RuntimeCommandCapability } from './MCPAPI';
+
+export type DocumentConversionKind =
+  | 'docx_to_markdown'
+  | 'pptx_to_pdf'
+  | 'html_to_pdf'
+  | 'pdf_to_png'
+  | 'pdf_to_text';
+
+export interface DocumentConversionRequest {
+  kind: DocumentConversionKind;
+  inputPath: string;
+  /** Outputs stay in a temp job directory when omitted. */
+  outputDir?: string;
+  timeoutSecs?: number;
+  firstPage?: number;
+  lastPage?: number;
+  dpi?: number;
+}
+
+export interface DocumentConversionResult {
+  jobId: string;
+  kind: DocumentConversionKind;
+  outputs: string[];
+  outputDir: string;
+  durationMs: number;
+}
+
+export interface DocumentConversionCapability {
+  kind: DocumentConversionKind;
+  available: boolean;
+  command: RuntimeCommandCapability;
+}
+
+export type DocumentConversionEvent =
+  | { type: 'started'; jobId: string; kind: DocumentConversionKind; inputPath: string }
+  | { type: 'progress'; jobId: string; kind: DocumentConversionKind; outputsWritten: number }
+  | { type: 'completed'; result: DocumentConversionResult }
+  | { type: 'failed'; jobId: string; kind: DocumentConversionKind; error: string };
+
+export class DocumentConversionAPI {
+  async getCapabilities(): Promise<DocumentConversionCapability[]> {
+    try {
+      return await api.invoke<DocumentConversionCapability[]>('get_document_conversion_capabilities');
+    } catch (error) {
+      throw createTauriCommandError('get_document_conversion_capabilities', error);
+    }
+  }
+
+  async convert(request: DocumentConversionRequest): Promise<DocumentConversionResult> {
+    try {
+      return await api.invoke<DocumentConversionResult>('convert_document', { request });
+    } catch (error) {
+      throw createTauriCommandError('convert_document', error, request);
+    }
+  }
+
+  /** With `replay`, first delivers job events this page missed, e.g. before a reload. */
+  onConversionEvent(
+    callback: (event: DocumentConversionEvent) => void,
+    options: { replay?: boolean } = {},
+  ): () => void {
+    if (options.replay) {
+      return eventReplayAPI.listenWithReplay<DocumentConversionEvent>(
+        'document_conversion_event',
+        callback,
+      );
+    }
+    return api.listen<DocumentConversionEvent>('document_conversion_event', callback);
+  }
+}
+
+export const documentConversionAPI = new DocumentConversionAPI();
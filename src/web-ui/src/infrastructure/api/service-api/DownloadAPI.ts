@@ -0,0 +1,41 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export interface OperationSpeedLimit {
+  operationId: string;
+  /** `0` means only the global cap applies. */
+  maxBytesPerSec: number;
+}
+
+export interface DownloadSpeedLimits {
+  /** `0` is unlimited. */
+  maxBytesPerSec: number;
+  /** Running downloads that accept a per-operation cap. */
+  operations: OperationSpeedLimit[];
+}
+
+export class DownloadAPI {
+  async getSpeedLimits(): Promise<DownloadSpeedLimits> {
+    try {
+      return await api.invoke<DownloadSpeedLimits>('get_download_speed_limits');
+    } catch (error) {
+      throw createTauriCommandError('get_download_speed_limits', error);
+    }
+  }
+
+  /**
+   * Takes effect for downloads in progress. Without `operationId` the global
+   * cap changes and is saved to the `app.downloads` setting.
+   */
+  async setSpeedLimit(maxBytesPerSec: number, operationId?: string): Promise<DownloadSpeedLimits> {
+    try {
+      return await api.invoke<DownloadSpeedLimits>('set_download_speed_limit', {
+        request: { operationId, maxBytesPerSec },
+      });
+    } catch (error) {
+      throw createTauriCommandError('set_download_speed_limit', error, { operationId });
+    }
+  }
+}
+
+export const downloadAPI = new DownloadAPI();
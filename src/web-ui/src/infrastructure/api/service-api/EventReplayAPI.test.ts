@@ -0,0 +1,80 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest';
+
+const invoke = vi.hoisted(() => vi.fn());
+const listen = vi.hoisted(() => vi.fn());
+
+vi.mock('./ApiClient', () => ({ api: { invoke, listen } }));
+vi.mock('../errors/TauriCommandError', () => ({
+  createTauriCommandError: (_command: string, error: unknown) => error,
+}));
+
+import { eventReplayAPI } from './EventReplayAPI';
+
+describe('EventReplayAPI', () => {
+  beforeEach(() => {
+    invoke.mockReset();
+    listen.mockReset();
+  });
+
+  it('delivers replayed events before held live events and skips duplicates', async () => {
+    let emitLive: (payload: unknown) => void = () => {};
+    listen.mockImplementation((_event: string, callback: (payload: unknown) => void) => {
+      emitLive = callback;
+      return () => {};
+    });
+    let resolveReplay: (value: unknown) => void = () => {};
+    invoke.mockReturnValue(new Promise((resolve) => { resolveReplay = resolve; }));
+
+    const received: number[] = [];
+    const onIncomplete = vi.fn();
+    eventReplayAPI.listenWithReplay<{ eventSeq: number }>(
+      'agent_task_updated',
+      (payload) => received.push(payload.eventSeq),
+      { scope: 'session-1', onIncomplete },
+    );
+
+    expect(invoke).toHaveBeenCalledWith('replay_events', {
+      request: { since: 0, scope: 'session-1', eventNames: ['agent_task_updated'] },
+    });
+
+    emitLive({ eventSeq: 2 });
+    emitLive({ eventSeq: 3 });
+    expect(received).toEqual([]);
+
+    resolveReplay({
+      events: [1, 2].map((seq) => ({
+        seq,
+        eventName: 'agent_task_updated',
+        payload: { eventSeq: seq },
+        emittedAtMs: 0,
+      })),
+      latestSeq: 3,
+      complete: true,
+    });
+    await vi.waitFor(() => expect(received).toEqual([1, 2, 3]));
+
+    emitLive({ eventSeq: 3 });
+    emitLive({ eventSeq: 4 });
+    expect(received).toEqual([1, 2, 3, 4]);
+    expect(onIncomplete).not.toHaveBeenCalled();
+  });
+
+  it('reports an incomplete replay and still delivers live events when replay fails', async () => {
+    let emitLive: (payload: unknown) => void = () => {};
+    listen.mockImplementation((_event: string, callback: (payload: unknown) => void) => {
+      emitLive = callback;
+      return () => {};
+    });
+    invoke.mockResolvedValueOnce({ events: [], latestSeq: 900, complete: false });
+
+    const onIncomplete = vi.fn();
+    eventReplayAPI.listenWithReplay('document_conversion_event', () => {}, { onIncomplete });
+    await vi.waitFor(() => expect(onIncomplete).toHaveBeenCalledTimes(1));
+
+    invoke.mockRejectedValueOnce(new Error('unavailable'));
+    const received: unknown[] = [];
+    eventReplayAPI.listenWithReplay('document_conversion_event', (payload) => received.push(payload));
+    emitLive({ type: 'started' });
+    await vi.waitFor(() => expect(received).toEqual([{ type: 'started' }]));
+  });
+});
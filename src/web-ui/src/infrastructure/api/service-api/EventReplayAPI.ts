@@ -0,0 +1,106 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+import { createLogger } from '@/shared/utils/logger';
+
+const log = createLogger('EventReplayAPI');
+
+/** Sequence number the backend stamps into journaled event payloads. */
+export const EVENT_SEQ_FIELD = 'eventSeq';
+
+export interface JournaledEvent<T = unknown> {
+  seq: number;
+  scope?: string;
+  eventName: string;
+  payload: T;
+  emittedAtMs: number;
+}
+
+export interface EventReplay {
+  events: JournaledEvent[];
+  latestSeq: number;
+  /** False when events after `since` were already evicted from the journal. */
+  complete: boolean;
+}
+
+export interface ReplayEventsParams {
+  since?: number;
+  /** Session, operation or job ID. */
+  scope?: string;
+  eventNames?: string[];
+}
+
+export interface ListenWithReplayOptions {
+  scope?: string;
+  /** Called instead of relying on the replay when the journal lost events. */
+  onIncomplete?: () => void;
+}
+
+function eventSeqOf(payload: unknown): number | undefined {
+  if (payload && typeof payload === 'object') {
+    const seq = (payload as Record<string, unknown>)[EVENT_SEQ_FIELD];
+    return typeof seq === 'number' ? seq : undefined;
+  }
+  return undefined;
+}
+
+export class EventReplayAPI {
+  async replayEvents({ since = 0, scope, eventNames = [] }: ReplayEventsParams = {}): Promise<EventReplay> {
+    try {
+      return await api.invoke<EventReplay>('replay_events', {
+        request: { since, scope, eventNames },
+      });
+    } catch (error) {
+      throw createTauriCommandError('replay_events', error, { since, scope, eventNames });
+    }
+  }
+
+  /**
+   * Listens to `eventName` after first delivering the journaled events the
+   * current page missed, e.g. after a webview reload. Live events that arrive
+   * while the replay is loading are held back and de-duplicated by sequence.
+   */
+  listenWithReplay<T>(
+    eventName: string,
+    callback: (payload: T) => void,
+    options: ListenWithReplayOptions = {},
+  ): () => void {
+    let lastSeq = 0;
+    let pending: T[] | null = [];
+    const deliver = (payload: T) => {
+      const seq = eventSeqOf(payload);
+      if (seq !== undefined) {
+        if (seq <= lastSeq) return;
+        lastSeq = seq;
+      }
+      callback(payload);
+    };
+
+    const unlisten = api.listen<T>(eventName, (payload) => {
+      if (pending) {
+        pending.push(payload);
+      } else {
+        deliver(payload);
+      }
+    });
+
+    this.replayEvents({ scope: options.scope, eventNames: [eventName] })
+      .then((replay) => {
+        if (!replay.complete) {
+          options.onIncomplete?.();
+        }
+        replay.events.forEach((event) => deliver(event.payload as T));
+      })
+      .catch((error) => {
+        log.warn('Failed to replay events', { eventName, error });
+      })
+      .finally(() => {
+        const held = pending ?? [];
+        pending = null;
+        held.forEach(deliver);
+      });
+
+    return unlisten;
+  }
+}
+
+export const eventReplayAPI = new EventReplayAPI();
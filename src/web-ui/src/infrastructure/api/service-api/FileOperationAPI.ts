@@ -0,0 +1,51 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type FileOperationKind =
+  | 'create_file'
+  | 'create_directory'
+  | 'rename'
+  | 'copy'
+  | 'delete'
+  /** Removed without a trash copy; never undone. */
+  | 'permanent_delete';
+
+export interface FileOperationRecord {
+  id: string;
+  kind: FileOperationKind;
+  path: string;
+  source?: string | null;
+  isDirectory: boolean;
+  performedAtMs: number;
+}
+
+export type FileOperationEvent =
+  | { type: 'applied'; operation: FileOperationRecord }
+  | { type: 'undone'; operation: FileOperationRecord };
+
+export class FileOperationAPI {
+  /** Most recent first. */
+  async listRecent(): Promise<FileOperationRecord[]> {
+    try {
+      return await api.invoke<FileOperationRecord[]>('list_file_operations');
+    } catch (error) {
+      throw createTauriCommandError('list_file_operations', error);
+    }
+  }
+
+  /** Undoes `operationId`, or the most recent operation when omitted. */
+  async undo(operationId?: string): Promise<FileOperationRecord> {
+    const request = { operationId: operationId ?? null };
+    try {
+      return await api.invoke<FileOperationRecord>('undo_file_operation', { request });
+    } catch (error) {
+      throw createTauriCommandError('undo_file_operation', error, request);
+    }
+  }
+
+  onFileOperationEvent(callback: (event: FileOperationEvent) => void): () => void {
+    return api.listen<FileOperationEvent>('file_operation_event', callback);
+  }
+}
+
+export const fileOperationAPI = new FileOperationAPI();
@@ -0,0 +1,44 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+/** A credential prompt from a Git process started by BitFun. */
+export interface GitCredentialPrompt {
+  id: string;
+  /** Prompt text from Git, e.g. `Username for 'https://github.com': `. */
+  prompt: string;
+  /** Mask the input: the answer is a password, token or passphrase. */
+  secret: boolean;
+  createdAtMs: number;
+  expiresAtMs: number;
+}
+
+export type GitAskpassEvent =
+  | { type: 'requested'; prompt: GitCredentialPrompt }
+  | { type: 'resolved'; id: string; answered: boolean };
+
+export class GitCredentialAPI {
+  async listPending(): Promise<GitCredentialPrompt[]> {
+    try {
+      return await api.invoke<GitCredentialPrompt[]>('list_pending_git_credential_prompts');
+    } catch (error) {
+      throw createTauriCommandError('list_pending_git_credential_prompts', error);
+    }
+  }
+
+  /** Answers a prompt; `null` cancels it and fails the Git command. */
+  async respond(promptId: string, answer: string | null): Promise<void> {
+    const request = { promptId, answer };
+    try {
+      await api.invoke<void>('respond_git_credential_prompt', { request });
+    } catch (error) {
+      // Never include the answer in the error context.
+      throw createTauriCommandError('respond_git_credential_prompt', error, { promptId });
+    }
+  }
+
+  onGitAskpassEvent(callback: (event: GitAskpassEvent) => void): () => void {
+    return api.listen<GitAskpassEvent>('git_askpass_event', callback);
+  }
+}
+
+export const gitCredentialAPI = new GitCredentialAPI();
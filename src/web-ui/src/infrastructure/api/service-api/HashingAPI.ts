@@ -0,0 +1,32 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type HashAlgorithm = 'sha256' | 'blake3';
+
+export interface FileVerification {
+  algorithm: HashAlgorithm;
+  /** Lowercase hex digest. */
+  expected: string;
+  actual: string;
+  sizeBytes: number;
+  matches: boolean;
+}
+
+export class HashingAPI {
+  /**
+   * `expected` is `sha256:<hex>`, `blake3:<hex>` or bare SHA-256 hex. A
+   * mismatch resolves with `matches: false`; only unreadable files and
+   * malformed digests reject.
+   */
+  async verifyFile(path: string, expected: string): Promise<FileVerification> {
+    try {
+      return await api.invoke<FileVerification>('verify_file', {
+        request: { path, expected },
+      });
+    } catch (error) {
+      throw createTauriCommandError('verify_file', error, { path });
+    }
+  }
+}
+
+export const hashingAPI = new HashingAPI();
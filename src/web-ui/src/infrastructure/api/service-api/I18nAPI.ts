@@ -4,6 +4,7 @@ import { invoke } from '@tauri-apps/api/core';
 import type { LocaleId, LocaleMetadata, I18nConfig } from '@/infrastructure/i18n/types';
 import { getLocaleMetadata } from '@/infrastructure/i18n/presets';
 import { createLogger } from '@/shared/utils/logger';
+import type { LocalizedMessage } from '../errors/TauriCommandError';
 
 const log = createLogger('I18nAPI');
 
@@ -89,6 +90,16 @@ class I18nAPIClass {
   async setConfig(config: Partial<I18nConfig>): Promise<string> {
     return invoke<string>('i18n_set_config', { config });
   }
+
+  /** Renders backend catalog messages again, in `language` or the configured language. */
+  async localizeMessages(
+    messages: Array<Pick<LocalizedMessage, 'id' | 'params'>>,
+    language?: LocaleId
+  ): Promise<LocalizedMessage[]> {
+    return invoke<LocalizedMessage[]>('i18n_localize_messages', {
+      request: { messages, language }
+    });
+  }
 }
 
 
@@ -1,6 +1,9 @@
  
 
 import { api } from './ApiClient';
+import { eventReplayAPI } from './EventReplayAPI';
+import { getCommandError } from '../errors/TauriCommandError';
+import type { CommandErrorCode } from '../errors/TauriCommandError';
 
 /** MCP Apps protocol version (aligned with VSCode modelContextProtocolApps.ts). */
 export const MCP_APPS_PROTOCOL_VERSION = '2026-01-26';
@@ -31,6 +34,7 @@ export interface MCPServerInfo {
   authSource?: 'headers' | 'env' | 'oauth';
   oauthEnabled?: boolean;
   xaaEnabled?: boolean;
+  tlsMode?: 'custom' | 'pinned' | 'insecure';
   command?: string;
   commandAvailable?: boolean;
   commandSource?: 'system' | 'managed';
@@ -46,6 +50,88 @@ export interface RuntimeCommandCapability {
   resolvedPath?: string;
 }
 
+export interface RuntimeCommandUsage {
+  command: string;
+  component?: string;
+  available: boolean;
+  source?: 'system' | 'managed';
+  executions: number;
+  failures: number;
+  failureRate: number;
+  averageDurationMs: number;
+  lastExecutedAt?: number;
+}
+
+export type CommandMatchOrigin = 'systemPath' | 'platformPath' | 'managed';
+
+export interface CommandMatch {
+  path: string;
+  origin: CommandMatchOrigin;
+  active: boolean;
+  version?: string;
+}
+
+export type CommandConflictKind = 'managedShadowed' | 'versionMismatch' | 'duplicateOnPath';
+
+export interface CommandConflict {
+  kind: CommandConflictKind;
+  activePath: string;
+  shadowedPath: string;
+}
+
+export interface CommandDiagnosis {
+  command: string;
+  matches: CommandMatch[];
+  conflicts: CommandConflict[];
+  suggestions: string[];
+}
+
+export const RUNTIME_PROVISIONING_PROGRESS_EVENT = 'runtime://provisioning-progress';
+
+export interface RuntimeProvisioningItem {
+  component: string;
+  displayName: string;
+  version: string;
+  missingCommands: string[];
+  sizeBytes: number;
+  sizeEstimated: boolean;
+  /** Downloaded by an interrupted install; installing again continues from here. */
+  resumableBytes: number;
+}
+
+export interface RuntimeInstallOptions {
+  /** Lets `cancel_operation` and `set_download_speed_limit` target this install. */
+  operationId?: string;
+  /** Downloads wait until the power settings allow them. */
+  deferUntilIdle?: boolean;
+  /** Speed cap for this install on top of the global one. */
+  maxBytesPerSec?: number;
+}
+
+export interface RuntimeProvisioningPlan {
+  items: RuntimeProvisioningItem[];
+  unavailableCommands: string[];
+  totalBytes: number;
+}
+
+/** `waiting`: held back until the user is idle (`deferUntilIdle`). */
+export type RuntimeProvisioningPhase = 'waiting' | 'downloading' | 'extracting' | 'installed' | 'failed';
+
+export interface RuntimeProvisioningProgress {
+  component: string;
+  phase: RuntimeProvisioningPhase;
+  downloadedBytes: number;
+  totalBytes: number;
+  percent: number;
+  error?: string;
+}
+
+export interface RuntimeProvisioningResult {
+  installed: string[];
+  failed: { component: string; error: string }[];
+  cancelled: boolean;
+}
+
  
 export interface MCPResource {
   uri: string;
@@ -78,6 +164,40 @@ export interface ReadMCPResourceResponse {
   contents: MCPResourceContent[];
 }
 
+export interface AttachMCPResourcesRequest {
+  serverId: string;
+  resourceUris: string[];
+  /** Estimated tokens shared by all resources; the backend default applies when omitted. */
+  tokenBudget?: number;
+  includeImages?: boolean;
+}
+
+export type MCPResourceAttachmentPart =
+  | {
+      kind: 'text';
+      uri: string;
+      mimeType?: string;
+      text: string;
+      estimatedTokens: number;
+      truncated: boolean;
+    }
+  | {
+      kind: 'image';
+      uri: string;
+      mimeType: string;
+      dataBase64: string;
+      estimatedTokens: number;
+    }
+  | { kind: 'omitted'; uri: string; mimeType?: string; reason: string };
+
+/** A resource converted for the conversation by `attach_mcp_resources`. */
+export interface MCPResourceAttachment {
+  serverId: string;
+  uri: string;
+  parts: MCPResourceAttachmentPart[];
+  estimatedTokens: number;
+}
+
  
 export interface MCPPrompt {
   name: string;
@@ -208,6 +328,26 @@ export interface MCPAppResourceContent {
   permissions?: McpUiResourcePermissions;
 }
 
+/** A rendered MCP App widget with its own persisted state. */
+export interface MCPAppInstance {
+  instanceId: string;
+  serverId: string;
+  resourceUri: string;
+  /** Tool call that rendered the widget; reopening the same call restores the instance. */
+  toolCallId?: string;
+  sessionId?: string;
+  createdAtMs: number;
+  updatedAtMs: number;
+  state?: unknown;
+}
+
+export interface OpenMCPAppInstanceRequest {
+  serverId: string;
+  resourceUri: string;
+  toolCallId?: string;
+  sessionId?: string;
+}
+
 /** Fetch MCP App UI resource for rendering in sandboxed iframe. */
 export interface FetchMCPAppResourceRequest {
   serverId: string;
@@ -272,9 +412,218 @@ export interface CancelMCPRemoteOAuthRequest {
   serverId: string;
 }
 
+export interface MCPCapabilityDiff {
+  addedTools: string[];
+  removedTools: string[];
+  changedSchemas: string[];
+  changedUiResources: string[];
+  escalatedTools: string[];
+  gatedTools: string[];
+}
+
+export interface MCPCapabilityChange {
+  serverId: string;
+  serverName: string;
+  diff: MCPCapabilityDiff;
+  requiresApproval: boolean;
+}
+
+export const MCP_LIST_CHANGED_EVENT = 'backend-event-mcplistchanged';
+
+export type MCPListChangedKind = 'tools' | 'prompts' | 'resources';
+
+export interface MCPCatalogDiff {
+  added: string[];
+  removed: string[];
+  changed: string[];
+}
+
+export interface MCPListChangedEvent {
+  serverId: string;
+  serverName: string;
+  kind: MCPListChangedKind;
+  diff: MCPCatalogDiff;
+}
+
+export const MCP_SERVER_READINESS_EVENT = 'backend-event-mcpserverreadiness';
+
+export type MCPServerReadiness = 'pending' | 'starting' | 'ready' | 'failed';
+
+export interface MCPServerReadinessEvent {
+  serverId: string;
+  serverName: string;
+  readiness: MCPServerReadiness;
+  toolCount?: number;
+  error?: string;
+}
+
+export interface MCPInitializationSummary {
+  ready: string[];
+  failed: string[];
+  /** Servers still starting in the background when startup stopped waiting. */
+  pending: string[];
+  timedOut: boolean;
+}
+
+export interface ApproveMCPCapabilityChangesRequest {
+  serverId: string;
+}
+
+export type MCPPresetParameterKind = 'text' | 'secret' | 'path' | 'url';
+
+export interface MCPPresetParameter {
+  key: string;
+  label: string;
+  description?: string;
+  kind: MCPPresetParameterKind;
+  required: boolean;
+  default?: string;
+  placeholder?: string;
+}
+
+export interface MCPServerPreset {
+  id: string;
+  name: string;
+  description: string;
+  category: string;
+  homepage?: string;
+  command?: string;
+  args?: string[];
+  url?: string;
+  env?: Record<string, string>;
+  headers?: Record<string, string>;
+  parameters: MCPPresetParameter[];
+}
+
+export interface MCPServerPresetCatalog {
+  version: number;
+  presets: MCPServerPreset[];
+}
+
+export interface MCPServerPresetRequest {
+  presetId: string;
+  values: Record<string, string>;
+}
+
+export interface MCPPresetFieldError {
+  key: string;
+  message: string;
+}
+
+export interface MCPServerPresetValidation {
+  valid: boolean;
+  errors: MCPPresetFieldError[];
+  config?: Record<string, unknown>;
+  command?: string;
+  commandAvailable?: boolean;
+  commandSource?: 'system' | 'managed';
+  commandResolvedPath?: string;
+}
+
+export type MCPTraceDirection = 'outgoing' | 'incoming';
+
+/** One redacted JSON-RPC frame captured by the protocol inspector. */
+export interface MCPTraceFrame {
+  sequence: number;
+  timestampMs: number;
+  direction: MCPTraceDirection;
+  method?: string;
+  frame: unknown;
+}
+
+export interface MCPTraceSnapshot {
+  enabled: boolean;
+  capacity: number;
+  droppedFrames: number;
+  frames: MCPTraceFrame[];
+}
+
+export interface SetMCPTraceEnabledRequest {
+  serverId: string;
+  enabled: boolean;
+}
+
+export interface ExportMCPTraceRequest {
+  serverId: string;
+  targetPath: string;
+}
+
+export interface ExportMCPTraceResponse {
+  path: string;
+  frames: number;
+}
+
+export type MCPFailureKind =
+  | 'transport'
+  | 'timeout'
+  | 'protocol'
+  | 'auth'
+  | 'server'
+  | 'config'
+  | 'notFound'
+  | 'internal';
+
+/** Structured failure returned by MCP commands. */
+export interface MCPFailure {
+  kind: MCPFailureKind;
+  message: string;
+  /** JSON-RPC error code, present when `kind` is `server`. */
+  code?: number;
+  data?: unknown;
+}
+
+const MCP_FAILURE_KINDS: ReadonlySet<string> = new Set<MCPFailureKind>([
+  'transport',
+  'timeout',
+  'protocol',
+  'auth',
+  'server',
+  'config',
+  'notFound',
+  'internal',
+]);
+
+function asMCPFailure(value: unknown): MCPFailure | null {
+  if (!value || typeof value !== 'object') return null;
+  const candidate = value as Partial<MCPFailure>;
+  if (typeof candidate.kind !== 'string' || !MCP_FAILURE_KINDS.has(candidate.kind)) return null;
+  if (typeof candidate.message !== 'string') return null;
+  return candidate as MCPFailure;
+}
+
+/** MCP failure kind implied by a generic error code, for errors raised before reaching a server. */
+const MCP_FAILURE_KIND_BY_CODE: Partial<Record<CommandErrorCode, MCPFailureKind>> = {
+  io: 'transport',
+  network: 'transport',
+  timeout: 'timeout',
+  protocol: 'protocol',
+  unauthorized: 'auth',
+  rejected: 'server',
+  configuration: 'config',
+  invalid_input: 'config',
+  not_found: 'notFound',
+};
+
+/** Extracts the structured MCP failure from an error thrown by an MCP command. */
+export function getMCPFailure(error: unknown): MCPFailure | null {
+  const commandError = getCommandError(error);
+  if (commandError) {
+    return (
+      asMCPFailure(commandError.details) ?? {
+        kind: MCP_FAILURE_KIND_BY_CODE[commandError.code] ?? 'internal',
+        message: commandError.message,
+      }
+    );
+  }
+  return (
+    asMCPFailure(error) ??
+    asMCPFailure((error as { details?: { originalError?: unknown } } | null)?.details?.originalError)
+  );
+}
+
 export class MCPAPI {
 
-  static async initializeServers(): Promise<void> {
+  static async initializeServers(): Promise<MCPInitializationSummary> {
     return api.invoke('initialize_mcp_servers');
   }
 
@@ -296,6 +645,11 @@ export class MCPAPI {
     return api.invoke('read_mcp_resource', { request });
   }
 
+  /** Reads resources and converts them to model-ready text and images within a token budget. */
+  static async attachResources(request: AttachMCPResourcesRequest): Promise<MCPResourceAttachment[]> {
+    return api.invoke('attach_mcp_resources', { request });
+  }
+
   static async listPrompts(request: ListMCPPromptsRequest): Promise<MCPPrompt[]> {
     return api.invoke('list_mcp_prompts', { request });
   }
@@ -309,6 +663,48 @@ export class MCPAPI {
     return api.invoke('get_runtime_capabilities');
   }
 
+  static async diagnoseRuntimeCommand(command: string): Promise<CommandDiagnosis> {
+    return api.invoke('diagnose_runtime_command', { request: { command } }, { timeout: 60000 });
+  }
+
+  static async getRuntimeUsage(): Promise<RuntimeCommandUsage[]> {
+    return api.invoke('get_runtime_usage');
+  }
+
+  static async getRuntimeProvisioningPlan(): Promise<RuntimeProvisioningPlan> {
+    return api.invoke('get_runtime_provisioning_plan');
+  }
+
+  /** Cancelling keeps partial downloads, so a later install resumes them. */
+  static async installRuntimeComponents(
+    components: string[],
+    options: RuntimeInstallOptions = {}
+  ): Promise<RuntimeProvisioningResult> {
+    return api.invoke(
+      'install_runtime_components',
+      { request: { components, ...options } },
+      // A deferred or throttled install can take hours.
+      { timeout: (options.deferUntilIdle || options.maxBytesPerSec ? 12 * 60 : 30) * 60 * 1000 }
+    );
+  }
+
+  static async cancelRuntimeProvisioning(): Promise<boolean> {
+    return api.invoke('cancel_runtime_provisioning');
+  }
+
+  /** With `operationId`, first replays that install's progress this page missed. */
+  static onRuntimeProvisioningProgress(
+    callback: (progress: RuntimeProvisioningProgress) => void,
+    operationId?: string
+  ): () => void {
+    if (operationId) {
+      return eventReplayAPI.listenWithReplay(RUNTIME_PROVISIONING_PROGRESS_EVENT, callback, {
+        scope: operationId,
+      });
+    }
+    return api.listen(RUNTIME_PROVISIONING_PROGRESS_EVENT, callback);
+  }
+
    
   static async startServer(serverId: string): Promise<void> {
     return api.invoke('start_mcp_server', { serverId });
@@ -360,17 +756,42 @@ export class MCPAPI {
 
   /**
    * Forward JSON-RPC message from MCP App iframe to the MCP server (tools/call, resources/read, ping).
+   * `bitfun/*` methods are answered by the host for the widget named by `instanceId`:
+   * widget state (`bitfun/widgetState/*`), scratch files (`bitfun/files/read|write|delete|list`)
+   * and user-mediated pickers (`bitfun/files/pickOpen|pickSave`).
    * Request must include serverId plus JSON-RPC fields (method, params, id) - backend expects flattened shape.
    * Returns the JSON-RPC response to postMessage back to the iframe.
    * Note: Backend uses #[serde(flatten)], so response fields are at top level.
    */
   static async sendMCPAppMessage(request: {
     serverId: string;
+    instanceId?: string;
     [key: string]: unknown;
   }): Promise<Record<string, unknown>> {
     return api.invoke('send_mcp_app_message', { request });
   }
 
+  /**
+   * Register a rendered MCP App widget. Returns the saved instance (and its
+   * state) when the same tool call was opened before, including in a previous run.
+   */
+  static async openMCPAppInstance(request: OpenMCPAppInstanceRequest): Promise<MCPAppInstance> {
+    return api.invoke('open_mcp_app_instance', { request });
+  }
+
+  static async updateMCPAppState(instanceId: string, state: unknown): Promise<MCPAppInstance> {
+    return api.invoke('update_mcp_app_state', { request: { instanceId, state } });
+  }
+
+  static async closeMCPAppInstance(instanceId: string): Promise<boolean> {
+    return api.invoke('close_mcp_app_instance', { request: { instanceId } });
+  }
+
+  /** Widgets open in this or the previous run, most recently used first. */
+  static async listActiveMCPApps(): Promise<MCPAppInstance[]> {
+    return api.invoke('list_active_mcp_apps');
+  }
+
   static async submitMCPInteractionResponse(
     request: SubmitMCPInteractionResponseRequest
   ): Promise<void> {
@@ -408,6 +829,59 @@ export class MCPAPI {
   static async cancelRemoteOAuth(request: CancelMCPRemoteOAuthRequest): Promise<void> {
     return api.invoke('cancel_mcp_remote_oauth', { request });
   }
+
+  static async getCapabilityChanges(): Promise<MCPCapabilityChange[]> {
+    return api.invoke('get_mcp_capability_changes');
+  }
+
+  static async approveCapabilityChanges(
+    request: ApproveMCPCapabilityChangesRequest
+  ): Promise<void> {
+    return api.invoke('approve_mcp_capability_changes', { request });
+  }
+
+  /**
+   * Subscribe to catalog updates applied after a server's list-changed notification.
+   */
+  static onListChanged(callback: (event: MCPListChangedEvent) => void): () => void {
+    return api.listen(MCP_LIST_CHANGED_EVENT, callback);
+  }
+
+  static async getStartupReadiness(): Promise<Record<string, MCPServerReadiness>> {
+    return api.invoke('get_mcp_startup_readiness');
+  }
+
+  static onServerReadiness(callback: (event: MCPServerReadinessEvent) => void): () => void {
+    return api.listen(MCP_SERVER_READINESS_EVENT, callback);
+  }
+
+  static async setTraceEnabled(request: SetMCPTraceEnabledRequest): Promise<void> {
+    return api.invoke('set_mcp_trace_enabled', { request });
+  }
+
+  static async getTrace(serverId: string): Promise<MCPTraceSnapshot> {
+    return api.invoke('get_mcp_trace', { serverId });
+  }
+
+  /** Writes the server's redacted trace to `targetPath` as JSON. */
+  static async exportTrace(request: ExportMCPTraceRequest): Promise<ExportMCPTraceResponse> {
+    return api.invoke('export_mcp_trace', { request });
+  }
+
+  static async getServerPresets(): Promise<MCPServerPresetCatalog> {
+    return api.invoke('get_mcp_server_presets');
+  }
+
+  static async validateServerPreset(
+    request: MCPServerPresetRequest
+  ): Promise<MCPServerPresetValidation> {
+    return api.invoke('validate_mcp_server_preset', { request });
+  }
+
+  /** Adds a preset to the user MCP config; returns the new server id. */
+  static async installServerPreset(request: MCPServerPresetRequest): Promise<string> {
+    return api.invoke('install_mcp_server_preset', { request });
+  }
 }
 
 export default MCPAPI;
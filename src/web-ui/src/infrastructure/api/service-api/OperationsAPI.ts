@@ -0,0 +1,38 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type OperationKind = 'skill_market_download' | 'skill_market_preview' | 'runtime_install' | 'project_scaffold';
+
+export interface OperationInfo {
+  id: string;
+  kind: OperationKind;
+  label: string;
+  startedAtMs: number;
+  cancelRequested: boolean;
+}
+
+/** ID to pass as `operationId` to a cancellable command. */
+export function createOperationId(): string {
+  return crypto.randomUUID();
+}
+
+export class OperationsAPI {
+  /** Resolves to `false` when the operation already finished. */
+  async cancelOperation(operationId: string): Promise<boolean> {
+    try {
+      return await api.invoke<boolean>('cancel_operation', { operationId });
+    } catch (error) {
+      throw createTauriCommandError('cancel_operation', error, { operationId });
+    }
+  }
+
+  async listOperations(): Promise<OperationInfo[]> {
+    try {
+      return await api.invoke<OperationInfo[]>('list_operations');
+    } catch (error) {
+      throw createTauriCommandError('list_operations', error);
+    }
+  }
+}
+
+export const operationsAPI = new OperationsAPI();
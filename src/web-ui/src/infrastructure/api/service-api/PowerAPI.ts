@@ -0,0 +1,43 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type PowerSource = 'ac' | 'battery' | 'unknown';
+export type BackgroundWork = 'indexing' | 'scheduled_jobs' | 'runtime_downloads';
+
+export interface PowerSnapshot {
+  powerSource: PowerSource;
+  batteryPercent?: number;
+  /** System power saving mode or a low battery; pauses background work. */
+  batterySaver: boolean;
+  idleSecs?: number;
+  /** Unknown idle time never counts as idle. */
+  userIdle: boolean;
+  /** Background work currently held back. */
+  deferred: BackgroundWork[];
+  checkedAtMs?: number;
+}
+
+export class PowerAPI {
+  async getStatus(): Promise<PowerSnapshot> {
+    try {
+      return await api.invoke<PowerSnapshot>('get_power_status');
+    } catch (error) {
+      throw createTauriCommandError('get_power_status', error);
+    }
+  }
+
+  /** Probes the platform immediately instead of waiting for the next interval. */
+  async refresh(): Promise<PowerSnapshot> {
+    try {
+      return await api.invoke<PowerSnapshot>('refresh_power_status');
+    } catch (error) {
+      throw createTauriCommandError('refresh_power_status', error);
+    }
+  }
+
+  onPowerStateChanged(callback: (snapshot: PowerSnapshot) => void): () => void {
+    return api.listen<PowerSnapshot>('power_state_changed', callback);
+  }
+}
+
+export const powerAPI = new PowerAPI();
@@ -0,0 +1,98 @@
+import { api } from './ApiClient';
+import { eventReplayAPI } from './EventReplayAPI';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export const PROJECT_SCAFFOLD_PROGRESS_EVENT = 'project://scaffold-progress';
+
+export type ProjectTemplateSource = 'builtin' | 'user';
+
+export interface PostCreateCommand {
+  command: string;
+  args: string[];
+}
+
+export interface ProjectTemplateSummary {
+  id: string;
+  source: ProjectTemplateSource;
+  /** Absent for built-ins; their names come from `newProject.templates.<id>`. */
+  name?: string;
+  description?: string;
+  postCreate: PostCreateCommand[];
+}
+
+export interface ProjectTemplateCatalog {
+  templates: ProjectTemplateSummary[];
+  invalid: { dirName: string; error: string }[];
+}
+
+export type ScaffoldPhase = 'writing_files' | 'running_command' | 'completed';
+
+export interface ScaffoldProgress {
+  phase: ScaffoldPhase;
+  completed: number;
+  total: number;
+  /** File or command being processed. */
+  current?: string;
+}
+
+export interface PostCreateCommandResult {
+  commandLine: string;
+  /** `false` when the program is not installed. */
+  ran: boolean;
+  success: boolean;
+  exitCode?: number;
+  error?: string;
+}
+
+export interface ScaffoldResult {
+  projectPath: string;
+  templateId: string;
+  filesWritten: number;
+  /** Failed commands leave the project in place; show them to the user. */
+  commands: PostCreateCommandResult[];
+}
+
+export interface NewProjectFromTemplateRequest {
+  parentPath: string;
+  projectName: string;
+  templateId: string;
+  /** Lets `cancel_operation` stop the scaffold and remove the new directory. */
+  operationId?: string;
+}
+
+export class ProjectTemplateAPI {
+  async listTemplates(): Promise<ProjectTemplateCatalog> {
+    try {
+      return await api.invoke<ProjectTemplateCatalog>('list_project_templates');
+    } catch (error) {
+      throw createTauriCommandError('list_project_templates', error);
+    }
+  }
+
+  async newProjectFromTemplate(request: NewProjectFromTemplateRequest): Promise<ScaffoldResult> {
+    try {
+      return await api.invoke<ScaffoldResult>(
+        'new_project_from_template',
+        { request },
+        // Post-create commands such as dependency installs can be slow.
+        { timeout: 15 * 60 * 1000 }
+      );
+    } catch (error) {
+      throw createTauriCommandError('new_project_from_template', error, {
+        templateId: request.templateId,
+      });
+    }
+  }
+
+  /** With `operationId`, first replays progress this page missed. */
+  onScaffoldProgress(callback: (progress: ScaffoldProgress) => void, operationId?: string): () => void {
+    if (operationId) {
+      return eventReplayAPI.listenWithReplay(PROJECT_SCAFFOLD_PROGRESS_EVENT, callback, {
+        scope: operationId,
+      });
+    }
+    return api.listen(PROJECT_SCAFFOLD_PROGRESS_EVENT, callback);
+  }
+}
+
+export const projectTemplateAPI = new ProjectTemplateAPI();
@@ -13,6 +13,7 @@ export type ReviewDecision = 'approved' | 'changes_requested' | 'commented' | 'p
 export type ReviewFileStatus = 'added' | 'modified' | 'deleted' | 'renamed';
 export type ReviewPlatformDetailSection = 'overview' | 'ci' | 'files' | 'commits' | 'reviews';
 export type ReviewEvidenceCompleteness = 'complete' | 'partial';
+export type ReviewIssueStateFilter = 'open' | 'closed' | 'all';
 
 export interface ReviewPlatformAccount {
   id: string;
@@ -281,6 +282,83 @@ export interface ReviewPlatformPullRequestCiLogRequest extends ReviewPlatformPul
   ciItemName: string;
 }
 
+export interface ReviewPlatformIssueSummary {
+  issueId: string;
+  title: string;
+  state: string;
+  author?: string | null;
+  labels: string[];
+  comments: number;
+  webUrl: string;
+  createdAt?: string | null;
+  updatedAt?: string | null;
+}
+
+export interface ReviewPlatformIssuePage {
+  remoteId: string;
+  items: ReviewPlatformIssueSummary[];
+  pagination: ReviewPlatformPagination;
+}
+
+export interface ReviewPlatformReviewComments {
+  pullRequest: ReviewPlatformPullRequest;
+  threads: ReviewPlatformThread[];
+  /** Markdown digest of the comments, ready to hand to the agent. */
+  context: string;
+  truncated: boolean;
+}
+
+export interface ReviewPlatformBranchResult {
+  branchName: string;
+  headRevision: string;
+  remoteName?: string | null;
+  message: string;
+}
+
+export interface ReviewPlatformActionResult {
+  success: boolean;
+  message: string;
+  webUrl?: string | null;
+  pullRequest?: ReviewPlatformPullRequest | null;
+  thread?: ReviewPlatformThread | null;
+}
+
+export interface ReviewPlatformListIssuesRequest {
+  repositoryPath: string;
+  remoteId?: string | null;
+  state?: ReviewIssueStateFilter;
+  page?: number;
+  perPage?: number;
+}
+
+export interface ReviewPlatformReviewCommentsRequest extends ReviewPlatformPullRequestDetailRequest {
+  includeResolved?: boolean;
+}
+
+export interface ReviewPlatformCreateBranchRequest {
+  repositoryPath: string;
+  branchName: string;
+  startPoint?: string | null;
+  checkout?: boolean | null;
+}
+
+export interface ReviewPlatformPushBranchRequest {
+  repositoryPath: string;
+  remoteId?: string | null;
+  branchName?: string | null;
+  setUpstream?: boolean | null;
+}
+
+export interface ReviewPlatformCreatePullRequestRequest {
+  repositoryPath: string;
+  remoteId?: string | null;
+  title: string;
+  sourceBranch: string;
+  targetBranch: string;
+  body?: string | null;
+  draft?: boolean | null;
+}
+
 export interface ReviewPlatformUpdateAuthTokenRequest {
   platform: ReviewPlatformKind;
   host: string;
@@ -460,6 +538,85 @@ export class ReviewPlatformAPI {
     }
   }
 
+  async listIssues(request: ReviewPlatformListIssuesRequest): Promise<ReviewPlatformIssuePage> {
+    try {
+      return await api.invoke('review_platform_list_issues', { request });
+    } catch (error) {
+      log.error('Failed to list review platform issues', {
+        repositoryPath: request.repositoryPath,
+        remoteId: request.remoteId,
+        state: request.state,
+        error,
+      });
+      throw createTauriCommandError('review_platform_list_issues', error, request);
+    }
+  }
+
+  async getReviewComments(
+    request: ReviewPlatformReviewCommentsRequest,
+  ): Promise<ReviewPlatformReviewComments> {
+    try {
+      return await api.invoke('review_platform_get_review_comments', { request });
+    } catch (error) {
+      log.error('Failed to load review platform review comments', {
+        repositoryPath: request.repositoryPath,
+        remoteId: request.remoteId,
+        pullRequestId: request.pullRequestId,
+        error,
+      });
+      throw createTauriCommandError('review_platform_get_review_comments', error, request);
+    }
+  }
+
+  async createBranch(request: ReviewPlatformCreateBranchRequest): Promise<ReviewPlatformBranchResult> {
+    try {
+      return await api.invoke('review_platform_create_branch', { request });
+    } catch (error) {
+      log.error('Failed to create branch', {
+        repositoryPath: request.repositoryPath,
+        branchName: request.branchName,
+        error,
+      });
+      throw createTauriCommandError('review_platform_create_branch', error, request);
+    }
+  }
+
+  async pushBranch(request: ReviewPlatformPushBranchRequest): Promise<ReviewPlatformBranchResult> {
+    try {
+      return await api.invoke('review_platform_push_branch', { request });
+    } catch (error) {
+      log.error('Failed to push branch', {
+        repositoryPath: request.repositoryPath,
+        remoteId: request.remoteId,
+        branchName: request.branchName,
+        error,
+      });
+      throw createTauriCommandError('review_platform_push_branch', error, request);
+    }
+  }
+
+  async createPullRequest(
+    request: ReviewPlatformCreatePullRequestRequest,
+  ): Promise<ReviewPlatformActionResult> {
+    try {
+      return await api.invoke('review_platform_create_pull_request', { request });
+    } catch (error) {
+      log.error('Failed to create pull request', {
+        repositoryPath: request.repositoryPath,
+        remoteId: request.remoteId,
+        sourceBranch: request.sourceBranch,
+        targetBranch: request.targetBranch,
+        error,
+      });
+      throw createTauriCommandError('review_platform_create_pull_request', error, {
+        repositoryPath: request.repositoryPath,
+        remoteId: request.remoteId,
+        sourceBranch: request.sourceBranch,
+        targetBranch: request.targetBranch,
+      });
+    }
+  }
+
   async updateAuthToken(request: ReviewPlatformUpdateAuthTokenRequest): Promise<void> {
     try {
       await api.invoke('review_platform_update_auth_token', { request });
@@ -0,0 +1,76 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+import type { ArtifactRecord } from './ArtifactAPI';
+
+export interface CaptureDisplay {
+  id: number;
+  /** 1-based position in the system display list. */
+  index: number;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+  scaleFactor: number;
+  isPrimary: boolean;
+}
+
+/** Area in global logical coordinates, as reported by `listDisplays`. */
+export interface CaptureRect {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+export interface CaptureWindowRequest {
+  sessionId: string;
+  taskId?: string;
+  /** BitFun window label; the main window when omitted. */
+  windowLabel?: string;
+}
+
+export interface CaptureRegionRequest {
+  sessionId: string;
+  taskId?: string;
+  /** The display showing the main window when omitted. */
+  displayId?: number;
+  /** The whole display when omitted. */
+  region?: CaptureRect;
+}
+
+export interface ScreenCaptureResult {
+  /** `null` when the user declined the capture prompt. */
+  artifact: ArtifactRecord | null;
+  width: number;
+  height: number;
+}
+
+export class ScreenCaptureAPI {
+  async listDisplays(): Promise<CaptureDisplay[]> {
+    try {
+      return await api.invoke<CaptureDisplay[]>('list_capture_displays');
+    } catch (error) {
+      throw createTauriCommandError('list_capture_displays', error);
+    }
+  }
+
+  /** Asks the user first, then stores the capture as a session artifact. */
+  async captureWindow(request: CaptureWindowRequest): Promise<ScreenCaptureResult> {
+    try {
+      return await api.invoke<ScreenCaptureResult>('capture_window', { request });
+    } catch (error) {
+      throw createTauriCommandError('capture_window', error, request);
+    }
+  }
+
+  /** Asks the user first, then stores the capture as a session artifact. */
+  async captureRegion(request: CaptureRegionRequest): Promise<ScreenCaptureResult> {
+    try {
+      return await api.invoke<ScreenCaptureResult>('capture_region', { request });
+    } catch (error) {
+      throw createTauriCommandError('capture_region', error, request);
+    }
+  }
+}
+
+export const screenCaptureAPI = new ScreenCaptureAPI();
@@ -0,0 +1,77 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export interface SemanticSearchHit {
+  /** Workspace-relative, `/`-separated. */
+  path: string;
+  /** 1-based, inclusive. */
+  startLine: number;
+  endLine: number;
+  /** Cosine similarity; higher is closer. */
+  score: number;
+  content: string;
+}
+
+export interface SemanticSearchResult {
+  hits: SemanticSearchHit[];
+  chunksSearched: number;
+  filesEmbedded: number;
+}
+
+export interface SemanticIndexReport {
+  filesScanned: number;
+  filesEmbedded: number;
+  filesRemoved: number;
+  chunksEmbedded: number;
+  truncated: boolean;
+  elapsedMs: number;
+}
+
+export interface SemanticIndexStatus {
+  workspacePath: string;
+  modelId?: string | null;
+  filesIndexed: number;
+  chunksIndexed: number;
+  pendingFiles: number;
+  indexing: boolean;
+}
+
+export class SemanticSearchAPI {
+  async search(workspacePath: string, query: string, k?: number): Promise<SemanticSearchResult> {
+    const request = { workspacePath, query, k };
+    try {
+      return await api.invoke<SemanticSearchResult>('semantic_search', { request });
+    } catch (error) {
+      throw createTauriCommandError('semantic_search', error, request);
+    }
+  }
+
+  async indexWorkspace(workspacePath: string): Promise<SemanticIndexReport> {
+    const request = { workspacePath };
+    try {
+      return await api.invoke<SemanticIndexReport>('semantic_index_workspace', { request });
+    } catch (error) {
+      throw createTauriCommandError('semantic_index_workspace', error, request);
+    }
+  }
+
+  async getStatus(workspacePath: string): Promise<SemanticIndexStatus> {
+    const request = { workspacePath };
+    try {
+      return await api.invoke<SemanticIndexStatus>('get_semantic_index_status', { request });
+    } catch (error) {
+      throw createTauriCommandError('get_semantic_index_status', error, request);
+    }
+  }
+
+  async clearIndex(workspacePath: string): Promise<void> {
+    const request = { workspacePath };
+    try {
+      await api.invoke<void>('clear_semantic_index', { request });
+    } catch (error) {
+      throw createTauriCommandError('clear_semantic_index', error, request);
+    }
+  }
+}
+
+export const semanticSearchAPI = new SemanticSearchAPI();
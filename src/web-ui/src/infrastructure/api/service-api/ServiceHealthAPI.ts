@@ -0,0 +1,34 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type ServiceKind = 'mcp' | 'skills' | 'ai' | 'runtime' | 'file_watcher';
+
+export type ServiceStatus = 'initialized' | 'degraded' | 'failed';
+
+export interface ServiceHealth {
+  service: ServiceKind;
+  status: ServiceStatus;
+  reason?: string;
+  updatedAtMs: number;
+}
+
+export interface ServiceHealthSnapshot {
+  /** Services that have reported so far. */
+  services: ServiceHealth[];
+}
+
+export class ServiceHealthAPI {
+  async getServiceHealth(): Promise<ServiceHealthSnapshot> {
+    try {
+      return await api.invoke<ServiceHealthSnapshot>('get_service_health');
+    } catch (error) {
+      throw createTauriCommandError('get_service_health', error);
+    }
+  }
+
+  onServiceHealthChanged(callback: (snapshot: ServiceHealthSnapshot) => void): () => void {
+    return api.listen<ServiceHealthSnapshot>('service_health_changed', callback);
+  }
+}
+
+export const serviceHealthAPI = new ServiceHealthAPI();
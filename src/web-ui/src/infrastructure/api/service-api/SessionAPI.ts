@@ -38,6 +38,61 @@ export interface SessionReferenceCandidate {
   lastActivityAt: number;
 }
 
+export type SessionSearchField = 'title' | 'user_message' | 'assistant_text' | 'tool_call';
+
+export interface SessionSearchHit {
+  sessionId: string;
+  sessionName: string;
+  turnIndex?: number | null;
+  field: SessionSearchField;
+  snippet: string;
+  lastActiveAt: number;
+}
+
+export type SessionExportFormat = 'markdown' | 'json';
+
+export interface ExportSessionResult {
+  path: string;
+  bytes: number;
+}
+
+export type SessionReportFormat = 'markdown' | 'html' | 'pdf';
+
+/** `summary` keeps each request and its final answer; `transcript` keeps everything. */
+export type SessionReportTemplate = 'transcript' | 'summary';
+
+export interface SessionReportOptions {
+  template?: SessionReportTemplate;
+  /** Report on one task (dialog turn) instead of the whole session. */
+  turnIndex?: number;
+  includeToolCalls?: boolean;
+  includeDiffs?: boolean;
+  includeArtifacts?: boolean;
+  redactSecrets?: boolean;
+}
+
+export interface ExportSessionReportResult {
+  path: string;
+  bytes: number;
+  redactionCount: number;
+}
+
+export interface ExportReplayBundleResult {
+  path: string;
+  invocations: number;
+}
+
+export interface ToolReplayStatus {
+  recorded: number;
+  replayed: number;
+  diverged: number;
+}
+
+export interface SessionRetentionPlan {
+  archive: string[];
+  delete: string[];
+}
+
 export interface SessionUsageReportRequest {
   sessionId: string;
   workspacePath: string;
@@ -510,6 +565,133 @@ export class SessionAPI {
       throw createTauriCommandError('delete_all_archived_sessions', error, { workspacePath });
     }
   }
+
+  async searchSessionHistory(
+    workspacePath: string,
+    query: string,
+    limit?: number,
+    remoteConnectionId?: string,
+    remoteSshHost?: string
+  ): Promise<SessionSearchHit[]> {
+    try {
+      return await api.invoke('search_session_history', {
+        request: {
+          workspace_path: workspacePath,
+          query,
+          ...(limit !== undefined ? { limit } : {}),
+          ...remoteSessionFields(remoteConnectionId, remoteSshHost),
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('search_session_history', error, { workspacePath });
+    }
+  }
+
+  async exportSession(
+    sessionId: string,
+    workspacePath: string,
+    format: SessionExportFormat,
+    targetPath: string,
+    remoteConnectionId?: string,
+    remoteSshHost?: string
+  ): Promise<ExportSessionResult> {
+    try {
+      return await api.invoke('export_session', {
+        request: {
+          session_id: sessionId,
+          workspace_path: workspacePath,
+          format,
+          target_path: targetPath,
+          ...remoteSessionFields(remoteConnectionId, remoteSshHost),
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('export_session', error, { sessionId, workspacePath });
+    }
+  }
+
+  async exportSessionReport(
+    sessionId: string,
+    workspacePath: string,
+    format: SessionReportFormat,
+    targetPath: string,
+    options: SessionReportOptions = {},
+    remoteConnectionId?: string,
+    remoteSshHost?: string
+  ): Promise<ExportSessionReportResult> {
+    try {
+      return await api.invoke('export_session_report', {
+        request: {
+          session_id: sessionId,
+          workspace_path: workspacePath,
+          format,
+          options,
+          target_path: targetPath,
+          ...remoteSessionFields(remoteConnectionId, remoteSshHost),
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('export_session_report', error, { sessionId, workspacePath });
+    }
+  }
+
+  async exportReplayBundle(
+    sessionId: string,
+    workspacePath: string,
+    targetPath: string,
+    remoteConnectionId?: string,
+    remoteSshHost?: string
+  ): Promise<ExportReplayBundleResult> {
+    try {
+      return await api.invoke('export_replay_bundle', {
+        request: {
+          session_id: sessionId,
+          workspace_path: workspacePath,
+          target_path: targetPath,
+          ...remoteSessionFields(remoteConnectionId, remoteSshHost),
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('export_replay_bundle', error, { sessionId, workspacePath });
+    }
+  }
+
+  async startToolReplay(sessionId: string, bundlePath: string): Promise<ToolReplayStatus> {
+    try {
+      return await api.invoke('start_tool_replay', {
+        request: { session_id: sessionId, bundle_path: bundlePath }
+      });
+    } catch (error) {
+      throw createTauriCommandError('start_tool_replay', error, { sessionId });
+    }
+  }
+
+  async stopToolReplay(sessionId: string): Promise<ToolReplayStatus | null> {
+    try {
+      return await api.invoke('stop_tool_replay', {
+        request: { session_id: sessionId }
+      });
+    } catch (error) {
+      throw createTauriCommandError('stop_tool_replay', error, { sessionId });
+    }
+  }
+
+  async applySessionRetention(
+    workspacePath: string,
+    remoteConnectionId?: string,
+    remoteSshHost?: string
+  ): Promise<SessionRetentionPlan> {
+    try {
+      return await api.invoke('apply_session_retention', {
+        request: {
+          workspace_path: workspacePath,
+          ...remoteSessionFields(remoteConnectionId, remoteSshHost),
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('apply_session_retention', error, { workspacePath });
+    }
+  }
 }
 
 export const sessionAPI = new SessionAPI();
@@ -0,0 +1,86 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type SidecarPluginPermission = 'workspace_read' | 'workspace_write';
+
+export type SidecarPluginStatus = 'disabled' | 'idle' | 'running' | 'restarting' | 'failed';
+
+export interface SidecarPluginCommandSpec {
+  name: string;
+  description?: string | null;
+}
+
+export interface SidecarPluginInfo {
+  id: string;
+  name: string;
+  version: string;
+  description?: string | null;
+  directory: string;
+  enabled: boolean;
+  status: SidecarPluginStatus;
+  permissions: SidecarPluginPermission[];
+  grantedPermissions: SidecarPluginPermission[];
+  tools: string[];
+  contextProviders: string[];
+  commands: SidecarPluginCommandSpec[];
+  crashCount: number;
+  lastError?: string | null;
+}
+
+export interface SidecarPluginIssue {
+  directory: string;
+  message: string;
+}
+
+export interface SidecarPluginList {
+  pluginsDir: string;
+  plugins: SidecarPluginInfo[];
+  issues: SidecarPluginIssue[];
+}
+
+export class SidecarPluginAPI {
+  async listPlugins(): Promise<SidecarPluginList> {
+    try {
+      return await api.invoke<SidecarPluginList>('list_sidecar_plugins');
+    } catch (error) {
+      throw createTauriCommandError('list_sidecar_plugins', error);
+    }
+  }
+
+  /** Rescans the plugins folder and restarts enabled plugins. */
+  async reloadPlugins(): Promise<SidecarPluginList> {
+    try {
+      return await api.invoke<SidecarPluginList>('reload_sidecar_plugins');
+    } catch (error) {
+      throw createTauriCommandError('reload_sidecar_plugins', error);
+    }
+  }
+
+  /**
+   * Enabling may wait for the user to approve the plugin's permissions; a
+   * denied prompt rejects with a `cancelled` error.
+   */
+  async setPluginEnabled(pluginId: string, enabled: boolean): Promise<SidecarPluginInfo> {
+    const request = { pluginId, enabled };
+    try {
+      return await api.invoke<SidecarPluginInfo>('set_sidecar_plugin_enabled', { request });
+    } catch (error) {
+      throw createTauriCommandError('set_sidecar_plugin_enabled', error, request);
+    }
+  }
+
+  async invokeCommand<T = unknown>(
+    pluginId: string,
+    command: string,
+    args: unknown = {}
+  ): Promise<T> {
+    const request = { pluginId, command, arguments: args };
+    try {
+      return await api.invoke<T>('invoke_sidecar_plugin_command', { request });
+    } catch (error) {
+      throw createTauriCommandError('invoke_sidecar_plugin_command', error, { pluginId, command });
+    }
+  }
+}
+
+export const sidecarPluginAPI = new SidecarPluginAPI();
@@ -57,6 +57,18 @@ export interface SessionFileDiffStats {
   changeKind: 'create' | 'modify' | 'delete';
 }
 
+/** Workspace state before a turn's first edit; restoring it undoes that turn and later ones. */
+export interface TurnCheckpoint {
+  sessionId: string;
+  turnIndex: number;
+  createdAtMs: number;
+  files: string[];
+  operationCount: number;
+  linesAdded: number;
+  linesRemoved: number;
+  toolNames: string[];
+}
+
 export interface GetSessionModificationsRequest {
   sessionId: string;
 }
@@ -483,6 +495,44 @@ export class SnapshotAPI {
     }
   }
 
+  async listCheckpoints(sessionId: string, workspacePath?: string): Promise<TurnCheckpoint[]> {
+    try {
+      const resolvedWorkspacePath = requireSessionWorkspacePath(sessionId, workspacePath);
+      return await api.invoke<TurnCheckpoint[]>('list_checkpoints', {
+        request: {
+          session_id: sessionId,
+          workspacePath: resolvedWorkspacePath,
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('list_checkpoints', error, { sessionId, workspacePath });
+    }
+  }
+
+  /** Restores workspace files to a checkpoint without deleting conversation turns. */
+  async restoreCheckpoint(
+    sessionId: string,
+    turnIndex: number,
+    workspacePath?: string,
+  ): Promise<string[]> {
+    try {
+      const resolvedWorkspacePath = requireSessionWorkspacePath(sessionId, workspacePath);
+      return await api.invoke<string[]>('restore_checkpoint', {
+        request: {
+          session_id: sessionId,
+          turn_index: turnIndex,
+          workspacePath: resolvedWorkspacePath,
+        }
+      });
+    } catch (error) {
+      throw createTauriCommandError('restore_checkpoint', error, {
+        sessionId,
+        turnIndex,
+        workspacePath,
+      });
+    }
+  }
+
    
   async rollbackEntireSession(
     sessionId: string,
@@ -7,6 +7,12 @@ export const DEFAULT_SPEECH_SAMPLE_RATE = 16000;
 export const DEFAULT_MAX_RECORDING_SECONDS = 60;
 export const SPEECH_MODEL_PROGRESS_EVENT = 'speech://model-download-progress';
 export const SPEECH_MODEL_STATUS_CHANGED_EVENT = 'speech://model-status-changed';
+export const SPEECH_PARTIAL_TRANSCRIPT_EVENT = 'speech://partial-transcript';
+export const SPEECH_TTS_STATE_EVENT = 'speech://tts-state';
+
+export type SpeechInputProvider = 'local' | 'whisper_cpp' | 'cloud';
+export type TextToSpeechProvider = 'system' | 'cloud';
+export type TextToSpeechUtteranceState = 'started' | 'finished' | 'stopped' | 'failed';
 
 export type SpeechModelInstallState =
   | 'not_installed' | 'downloading' | 'installed' | 'verifying'
@@ -37,6 +43,7 @@ export interface SpeechModelStatus {
 export interface SpeechListModelsResponse { models: SpeechModelStatus[]; }
 export interface SpeechModelProgressEvent { status: SpeechModelStatus; }
 export interface SpeechStartInputSessionRequest {
+  provider?: SpeechInputProvider;
   modelId?: string | null;
   language?: string | null;
   sampleRate?: number | null;
@@ -44,6 +51,7 @@ export interface SpeechStartInputSessionRequest {
 }
 export interface SpeechInputSession {
   sessionId: string;
+  provider: SpeechInputProvider;
   modelId: string;
   language: string;
   sampleRate: number;
@@ -54,12 +62,56 @@ export interface SpeechAppendAudioChunkResponse {
   receivedSeconds: number;
   limitReached: boolean;
 }
+export interface SpeechPartialTranscriptEvent {
+  sessionId: string;
+  text: string;
+  audioDurationSeconds: number;
+}
+export interface SpeechWhisperCppModel {
+  modelId: string;
+  path: string;
+  sizeBytes: number;
+}
+export interface SpeechWhisperCppStatus {
+  binaryPath?: string | null;
+  modelsDir: string;
+  models: SpeechWhisperCppModel[];
+}
 export interface SpeechTranscriptionResult {
   text: string;
   language: string;
   durationMs: number;
   audioDurationSeconds: number;
 }
+export interface TextToSpeechVoice {
+  id: string;
+  name: string;
+  language?: string | null;
+}
+export interface TextToSpeechListVoicesResponse {
+  provider: TextToSpeechProvider;
+  voices: TextToSpeechVoice[];
+}
+export interface TextToSpeechSpeakRequest {
+  text: string;
+  voice?: string | null;
+  language?: string | null;
+}
+export interface TextToSpeechAudioClip {
+  mimeType: string;
+  dataBase64: string;
+}
+export interface TextToSpeechSpeakResult {
+  utteranceId: string;
+  provider: TextToSpeechProvider;
+  voice?: string | null;
+  audio?: TextToSpeechAudioClip | null;
+}
+export interface TextToSpeechStateEvent {
+  utteranceId: string;
+  state: TextToSpeechUtteranceState;
+  error?: string | null;
+}
 
 export class SpeechAPI {
   async listModels() {
@@ -87,6 +139,11 @@ export class SpeechAPI {
     catch (error) { throw createTauriCommandError('speech_verify_model', error, { modelId }); }
   }
 
+  async getWhisperCppStatus() {
+    try { return await api.invoke<SpeechWhisperCppStatus>('speech_get_whisper_cpp_status', {}); }
+    catch (error) { throw createTauriCommandError('speech_get_whisper_cpp_status', error); }
+  }
+
   async startInputSession(request: SpeechStartInputSessionRequest = {}) {
     try { return await api.invoke<SpeechInputSession>('speech_start_input_session', { request }); }
     catch (error) { throw createTauriCommandError('speech_start_input_session', error, request); }
@@ -109,6 +166,21 @@ export class SpeechAPI {
     catch (error) { throw createTauriCommandError('speech_cancel_input_session', error, { sessionId }); }
   }
 
+  async listVoices() {
+    try { return await api.invoke<TextToSpeechListVoicesResponse>('speech_list_voices', {}); }
+    catch (error) { throw createTauriCommandError('speech_list_voices', error); }
+  }
+
+  async speak(request: TextToSpeechSpeakRequest) {
+    try { return await api.invoke<TextToSpeechSpeakResult>('speech_speak', { request }, { timeout: 120000 }); }
+    catch (error) { throw createTauriCommandError('speech_speak', error, { language: request.language }); }
+  }
+
+  async stopSpeaking() {
+    try { return await api.invoke<string | null>('speech_stop_speaking', {}); }
+    catch (error) { throw createTauriCommandError('speech_stop_speaking', error); }
+  }
+
   onModelProgress(callback: (event: SpeechModelProgressEvent) => void): () => void {
     return api.listen(SPEECH_MODEL_PROGRESS_EVENT, callback);
   }
@@ -116,6 +188,14 @@ export class SpeechAPI {
   onModelStatusChanged(callback: (status: SpeechModelStatus) => void): () => void {
     return api.listen(SPEECH_MODEL_STATUS_CHANGED_EVENT, callback);
   }
+
+  onPartialTranscript(callback: (event: SpeechPartialTranscriptEvent) => void): () => void {
+    return api.listen(SPEECH_PARTIAL_TRANSCRIPT_EVENT, callback);
+  }
+
+  onSpeechState(callback: (event: TextToSpeechStateEvent) => void): () => void {
+    return api.listen(SPEECH_TTS_STATE_EVENT, callback);
+  }
 }
 
 export const speechAPI = new SpeechAPI();
@@ -24,6 +24,27 @@ export interface ToggleMainWindowFullscreenResponse {
   isMaximized: boolean;
 }
 
+/** Matches desktop `first_launch::FirstLaunchHandoff` (camelCase). */
+export interface FirstLaunchContext {
+  schemaVersion: number;
+  installerVersion: string;
+  installedAtMs?: number | null;
+  installPath: string;
+  components: string[];
+  appLanguage?: string | null;
+  themePreference?: string | null;
+  /** A model was set up in the installer. */
+  modelConfigured: boolean;
+}
+
+/** Matches core `app_paths::DataLocation` (camelCase). */
+export interface DataLocation {
+  userRoot?: string | null;
+  /** Moved to on the next restart. */
+  pendingRoot?: string | null;
+  lastError?: string | null;
+}
+
 /** Close-button behavior values (matches `app.close_button_behavior` config key). */
 export type CloseBehavior = 'quit' | 'minimize_to_tray' | 'ask';
 
@@ -61,6 +82,15 @@ export class SystemAPI {
     }
   }
 
+  /** Desktop only: installer handoff for the first launch after an install, otherwise null. */
+  async getFirstLaunchContext(): Promise<FirstLaunchContext | null> {
+    try {
+      return await api.invoke('get_first_launch_context');
+    } catch (error) {
+      throw createTauriCommandError('get_first_launch_context', error);
+    }
+  }
+
   /** Desktop only: download and install update after user confirms (calls updater again). */
   async installUpdate(): Promise<void> {
     try {
@@ -83,6 +113,26 @@ export class SystemAPI {
     }
   }
 
+  /** Desktop only: where user data is stored. */
+  async getDataLocation(): Promise<DataLocation> {
+    try {
+      return await api.invoke('get_data_location');
+    } catch (error) {
+      throw createTauriCommandError('get_data_location', error);
+    }
+  }
+
+  /** Desktop only: move user data to `path` on the next restart (see `restartApp`). */
+  async relocateDataDir(path: string): Promise<DataLocation> {
+    try {
+      return await api.invoke('relocate_data_dir', {
+        request: { path }
+      });
+    } catch (error) {
+      throw createTauriCommandError('relocate_data_dir', error);
+    }
+  }
+
    
   async openExternal(url: string): Promise<void> {
     try {
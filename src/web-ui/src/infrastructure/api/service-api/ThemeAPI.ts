@@ -0,0 +1,97 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+/** A user theme file: flat color tokens keyed by camelCase name, e.g. `bgPrimary`. */
+export interface UserThemeDefinition {
+  id: string;
+  name: string;
+  type: 'dark' | 'light';
+  description?: string;
+  colors: Record<string, string>;
+}
+
+export interface ThemeCatalogEntry {
+  id: string;
+  source: 'builtin' | 'user';
+  /** Absent for built-ins, whose names are localized by the frontend. */
+  name?: string;
+  type?: 'dark' | 'light';
+}
+
+export interface InvalidUserTheme {
+  fileName: string;
+  error: string;
+}
+
+export interface ThemeCatalog {
+  themes: ThemeCatalogEntry[];
+  invalid: InvalidUserTheme[];
+}
+
+export interface ThemeUpdatedEvent {
+  themeId: string;
+  /** Full token set when the active theme is a user theme. */
+  userTheme?: UserThemeDefinition | null;
+}
+
+export class ThemeAPI {
+  async listThemes(): Promise<ThemeCatalog> {
+    try {
+      return await api.invoke<ThemeCatalog>('list_themes');
+    } catch (error) {
+      throw createTauriCommandError('list_themes', error);
+    }
+  }
+
+  async getUserTheme(themeId: string): Promise<UserThemeDefinition | null> {
+    const request = { themeId };
+    try {
+      return await api.invoke<UserThemeDefinition | null>('get_user_theme', { request });
+    } catch (error) {
+      throw createTauriCommandError('get_user_theme', error, request);
+    }
+  }
+
+  async saveUserTheme(theme: UserThemeDefinition): Promise<void> {
+    const request = { theme };
+    try {
+      await api.invoke<void>('save_user_theme', { request });
+    } catch (error) {
+      throw createTauriCommandError('save_user_theme', error, { themeId: theme.id });
+    }
+  }
+
+  async deleteUserTheme(themeId: string): Promise<boolean> {
+    const request = { themeId };
+    try {
+      return await api.invoke<boolean>('delete_user_theme', { request });
+    } catch (error) {
+      throw createTauriCommandError('delete_user_theme', error, request);
+    }
+  }
+
+  /** The active theme id; the default theme when the saved one no longer exists. */
+  async getCurrentTheme(): Promise<string> {
+    try {
+      return await api.invoke<string>('get_current_theme');
+    } catch (error) {
+      throw createTauriCommandError('get_current_theme', error);
+    }
+  }
+
+  async setTheme(themeId: string): Promise<void> {
+    const request = { themeId };
+    try {
+      await api.invoke<void>('set_theme', { request });
+    } catch (error) {
+      throw createTauriCommandError('set_theme', error, request);
+    }
+  }
+
+  /** Fired in every window after the active theme changes, from any surface. */
+  onThemeUpdated(callback: (event: ThemeUpdatedEvent) => void): () => void {
+    return api.listen<ThemeUpdatedEvent>('bitfun_theme_updated', callback);
+  }
+}
+
+export const themeAPI = new ThemeAPI();
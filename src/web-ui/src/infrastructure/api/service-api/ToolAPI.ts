@@ -5,6 +5,8 @@ import { createTauriCommandError } from '../errors/TauriCommandError';
 import type {
   ExecuteToolRequest,
   GetToolInfoRequest,
+  SearchToolsRequest,
+  ToolSearchMatch,
   ValidateToolInputRequest
 } from './tauri-commands';
 import { createLogger } from '@/shared/utils/logger';
@@ -35,6 +37,15 @@ export class ToolAPI {
   }
 
    
+  async searchTools(request: SearchToolsRequest): Promise<ToolSearchMatch[]> {
+    try {
+      return await api.invoke('search_tools', { request });
+    } catch (error) {
+      throw createTauriCommandError('search_tools', error, request);
+    }
+  }
+
+   
   async validateToolInput(request: ValidateToolInputRequest): Promise<any> {
     try {
       return await api.invoke('validate_tool_input', { 
@@ -0,0 +1,69 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type SecondaryWindowKind = 'log_viewer' | 'terminal';
+
+/** Physical pixels; bounds are the restored size when maximized. */
+export interface WindowGeometry {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+  maximized: boolean;
+  fullscreen: boolean;
+}
+
+export interface SecondaryWindowInfo {
+  kind: SecondaryWindowKind;
+  label: string;
+  visible: boolean;
+  focused: boolean;
+  geometry?: WindowGeometry | null;
+}
+
+export class WindowAPI {
+  /**
+   * Opens the window, or focuses it when already open. `query` is passed to
+   * the window's page only when it is created.
+   */
+  async openSecondaryWindow(
+    kind: SecondaryWindowKind,
+    query: Record<string, string> = {}
+  ): Promise<SecondaryWindowInfo> {
+    const request = { kind, query };
+    try {
+      return await api.invoke<SecondaryWindowInfo>('open_secondary_window', { request });
+    } catch (error) {
+      throw createTauriCommandError('open_secondary_window', error, { kind });
+    }
+  }
+
+  async closeSecondaryWindow(kind: SecondaryWindowKind): Promise<void> {
+    const request = { kind };
+    try {
+      await api.invoke<void>('close_secondary_window', { request });
+    } catch (error) {
+      throw createTauriCommandError('close_secondary_window', error, request);
+    }
+  }
+
+  async listSecondaryWindows(): Promise<SecondaryWindowInfo[]> {
+    try {
+      return await api.invoke<SecondaryWindowInfo[]>('list_secondary_windows');
+    } catch (error) {
+      throw createTauriCommandError('list_secondary_windows', error);
+    }
+  }
+
+  /** Forgets saved geometry for one secondary window, or for every window when omitted. */
+  async resetWindowLayout(kind?: SecondaryWindowKind): Promise<void> {
+    const request = { kind: kind ?? null };
+    try {
+      await api.invoke<void>('reset_window_layout', { request });
+    } catch (error) {
+      throw createTauriCommandError('reset_window_layout', error, request);
+    }
+  }
+}
+
+export const windowAPI = new WindowAPI();
@@ -1,5 +1,5 @@
 import { beforeEach, describe, expect, it, vi } from 'vitest';
-import { workspaceAPI } from './WorkspaceAPI';
+import { isTrashUnavailableError, workspaceAPI } from './WorkspaceAPI';
 
 const invokeMock = vi.hoisted(() => vi.fn());
 
@@ -31,4 +31,21 @@ describe('WorkspaceAPI', () => {
       },
     });
   });
+
+  it('reports a missing system trash so the caller can offer a permanent delete', async () => {
+    invokeMock.mockRejectedValueOnce({
+      code: 'not_implemented',
+      message: 'Failed to delete file: The system trash is unavailable for /mnt/share/a.txt',
+      retryable: false,
+    });
+
+    const error = await workspaceAPI.deleteFile('/mnt/share/a.txt').catch((e: unknown) => e);
+    expect(isTrashUnavailableError(error)).toBe(true);
+
+    await workspaceAPI.deleteFile('/mnt/share/a.txt', undefined, true);
+    expect(invokeMock).toHaveBeenLastCalledWith('delete_file', {
+      request: { path: '/mnt/share/a.txt', remoteConnectionId: undefined, permanent: true },
+    });
+    expect(isTrashUnavailableError(new Error('Failed to delete file: permission denied'))).toBe(false);
+  });
 });
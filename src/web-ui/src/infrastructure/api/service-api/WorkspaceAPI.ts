@@ -1,7 +1,7 @@
  
 
 import { api } from './ApiClient';
-import { createTauriCommandError } from '../errors/TauriCommandError';
+import { createTauriCommandError, getCommandError } from '../errors/TauriCommandError';
 import type {
   ExplorerChildrenPageDto,
   ExplorerNodeDto,
@@ -172,6 +172,17 @@ function mapWorkspaceSearchIndexTaskHandle(
   };
 }
 
+/** Ripgrep-style globs relative to the search root; excludes apply on top of .gitignore. */
+export interface ContentSearchGlobFilters {
+  includeGlobs?: string[];
+  excludeGlobs?: string[];
+}
+
+/** True when a local delete failed only because the system trash is unavailable. */
+export function isTrashUnavailableError(error: unknown): boolean {
+  return getCommandError(error)?.code === 'not_implemented';
+}
+
 export class WorkspaceAPI {
    
   async openWorkspace(path: string): Promise<WorkspaceInfo> {
@@ -273,14 +284,17 @@ export class WorkspaceAPI {
     }
   }
 
-   
-  async deleteFile(path: string, remoteConnectionId?: string): Promise<void> {
+  /**
+   * Moves a local file to the system trash. Where no trash exists this fails
+   * (see `isTrashUnavailableError`) unless `permanent` is set.
+   */
+  async deleteFile(path: string, remoteConnectionId?: string, permanent: boolean = false): Promise<void> {
     try {
       await api.invoke('delete_file', {
-        request: { path, remoteConnectionId }
+        request: { path, remoteConnectionId, permanent }
       });
     } catch (error) {
-      throw createTauriCommandError('delete_file', error, { path });
+      throw createTauriCommandError('delete_file', error, { path, permanent });
     }
   }
 
@@ -295,14 +309,19 @@ export class WorkspaceAPI {
     }
   }
 
-   
-  async deleteDirectory(path: string, recursive: boolean = true, remoteConnectionId?: string): Promise<void> {
+  /** Trash behavior matches `deleteFile`. */
+  async deleteDirectory(
+    path: string,
+    recursive: boolean = true,
+    remoteConnectionId?: string,
+    permanent: boolean = false
+  ): Promise<void> {
     try {
       await api.invoke('delete_directory', {
-        request: { path, recursive, remoteConnectionId }
+        request: { path, recursive, remoteConnectionId, permanent }
       });
     } catch (error) {
-      throw createTauriCommandError('delete_directory', error, { path, recursive });
+      throw createTauriCommandError('delete_directory', error, { path, recursive, permanent });
     }
   }
 
@@ -487,6 +506,8 @@ export class WorkspaceAPI {
       wholeWord: boolean;
       maxResults?: number;
       includeDirectories?: boolean;
+      includeGlobs?: string[];
+      excludeGlobs?: string[];
     },
     callbacks: FileSearchStreamCallbacks = {},
     signal?: AbortSignal
@@ -809,7 +830,8 @@ export class WorkspaceAPI {
     wholeWord: boolean = false,
     searchIdOrSignal?: string | AbortSignal,
     maxResults?: number,
-    signal?: AbortSignal
+    signal?: AbortSignal,
+    globFilters: ContentSearchGlobFilters = {}
   ): Promise<FileSearchResponse> {
     const effectiveSignal = searchIdOrSignal instanceof AbortSignal ? searchIdOrSignal : signal;
     const effectiveSearchId =
@@ -825,6 +847,8 @@ export class WorkspaceAPI {
           useRegex,
           wholeWord,
           maxResults,
+          includeGlobs: globFilters.includeGlobs ?? [],
+          excludeGlobs: globFilters.excludeGlobs ?? [],
         } 
       });
 
@@ -855,7 +879,8 @@ export class WorkspaceAPI {
     searchIdOrSignal?: string | AbortSignal,
     maxResults?: number,
     callbacks: FileSearchStreamCallbacks = {},
-    signal?: AbortSignal
+    signal?: AbortSignal,
+    globFilters: ContentSearchGlobFilters = {}
   ): Promise<FileSearchCompleteEvent> {
     const effectiveSignal = searchIdOrSignal instanceof AbortSignal ? searchIdOrSignal : signal;
     const effectiveSearchId =
@@ -870,7 +895,8 @@ export class WorkspaceAPI {
         wholeWord,
         effectiveSearchId,
         maxResults,
-        effectiveSignal
+        effectiveSignal,
+        globFilters
       );
       const groupedResults = groupSearchResultsByFile(response.results);
       const event: FileSearchCompleteEvent = {
@@ -902,6 +928,8 @@ export class WorkspaceAPI {
         useRegex,
         wholeWord,
         maxResults,
+        includeGlobs: globFilters.includeGlobs ?? [],
+        excludeGlobs: globFilters.excludeGlobs ?? [],
       },
       callbacks,
       effectiveSignal
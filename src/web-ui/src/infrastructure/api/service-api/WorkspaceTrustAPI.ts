@@ -0,0 +1,65 @@
+import { api } from './ApiClient';
+import { createTauriCommandError } from '../errors/TauriCommandError';
+
+export type WorkspaceTrustDecision = 'trusted' | 'restricted';
+export type WorkspaceTrustState = WorkspaceTrustDecision | 'unknown';
+
+export interface WorkspaceTrustInfo {
+  path: string;
+  state: WorkspaceTrustState;
+  decidedBy?: string | null;
+}
+
+export interface WorkspaceTrustRecord {
+  path: string;
+  decision: WorkspaceTrustDecision;
+  decidedAtMs: number;
+}
+
+export class WorkspaceTrustAPI {
+  async getTrust(path: string): Promise<WorkspaceTrustInfo> {
+    const request = { path };
+    try {
+      return await api.invoke<WorkspaceTrustInfo>('get_workspace_trust', { request });
+    } catch (error) {
+      throw createTauriCommandError('get_workspace_trust', error, request);
+    }
+  }
+
+  async setTrust(path: string, decision: WorkspaceTrustDecision): Promise<WorkspaceTrustInfo> {
+    const request = { path, decision };
+    try {
+      return await api.invoke<WorkspaceTrustInfo>('set_workspace_trust', { request });
+    } catch (error) {
+      throw createTauriCommandError('set_workspace_trust', error, request);
+    }
+  }
+
+  async listDecisions(): Promise<WorkspaceTrustRecord[]> {
+    try {
+      return await api.invoke<WorkspaceTrustRecord[]>('list_workspace_trust');
+    } catch (error) {
+      throw createTauriCommandError('list_workspace_trust', error);
+    }
+  }
+
+  async forgetDecision(path: string): Promise<boolean> {
+    const request = { path };
+    try {
+      return await api.invoke<boolean>('forget_workspace_trust', { request });
+    } catch (error) {
+      throw createTauriCommandError('forget_workspace_trust', error, request);
+    }
+  }
+
+  /** Fired when an opened folder has no trust decision yet. */
+  onTrustRequired(callback: (info: WorkspaceTrustInfo) => void): () => void {
+    return api.listen<WorkspaceTrustInfo>('workspace_trust_required', callback);
+  }
+
+  onTrustChanged(callback: (info: WorkspaceTrustInfo) => void): () => void {
+    return api.listen<WorkspaceTrustInfo>('workspace_trust_changed', callback);
+  }
+}
+
+export const workspaceTrustAPI = new WorkspaceTrustAPI();
@@ -87,6 +87,24 @@ export interface GetToolInfoRequest {
   toolName: string;
 }
 
+export interface SearchToolsRequest {
+  query: string;
+  limit?: number;
+  workspacePath?: string;
+}
+
+export type ToolSearchSource = 'builtin' | 'mcp' | 'skill';
+
+export interface ToolSearchMatch {
+  name: string;
+  title?: string;
+  description: string;
+  source: ToolSearchSource;
+  namespace?: string;
+  deferred: boolean;
+  score: number;
+}
+
 export interface ExecuteToolRequest {
   toolName: string;
   parameters: any;
@@ -12,7 +12,8 @@ import { configManager } from '../services/ConfigManager';
 import { getCapabilitiesByCategory, resolveModelCategory } from '../services/modelCategory';
 import { allocateModelConfigId, PROVIDER_TEMPLATES, getModelDisplayName, getProviderDisplayName, getProviderTemplateId } from '../services/modelConfigs';
 import { DEFAULT_REASONING_MODE, getEffectiveReasoningMode, supportsAnthropicAdaptive, supportsAnthropicReasoning, supportsAnthropicThinkingBudget, supportsDeepSeekReasoningEffort, supportsResponsesReasoning } from '../utils/reasoning';
-import { aiApi, systemAPI } from '@/infrastructure/api';
+import { aiApi, credentialHealthAPI, systemAPI } from '@/infrastructure/api';
+import type { ModelCredentialStatus } from '@/infrastructure/api/service-api/CredentialHealthAPI';
 import type { SubscriptionAccount } from '@/infrastructure/api/service-api/AIApi';
 import type { SubscriptionProvider } from '../types';
 import { useNotification } from '@/shared/notification-system';
@@ -420,6 +421,7 @@ const AIModelConfig: React.FC = () => {
   const [showApiKey, setShowApiKey] = useState(false);
   const [testingConfigs, setTestingConfigs] = useState<Record<string, boolean>>({});
   const [testResults, setTestResults] = useState<Record<string, { success: boolean; message: string } | null>>({});
+  const [rejectedCredentials, setRejectedCredentials] = useState<Record<string, ModelCredentialStatus>>({});
   const [expandedIds, setExpandedIds] = useState<Set<string>>(new Set());
   const notification = useNotification();
   
@@ -633,6 +635,21 @@ const AIModelConfig: React.FC = () => {
     refreshSubscriptionAccounts();
   }, [refreshSubscriptionAccounts]);
 
+  useEffect(() => {
+    const applySnapshot = (models: ModelCredentialStatus[]) => {
+      setRejectedCredentials(
+        Object.fromEntries(
+          models.filter(status => status.state === 'rejected').map(status => [status.modelId, status])
+        )
+      );
+    };
+    credentialHealthAPI
+      .getStatus()
+      .then(snapshot => applySnapshot(snapshot.models))
+      .catch(error => log.warn('Failed to load credential health', { error: String(error) }));
+    return credentialHealthAPI.onCredentialHealthChanged(snapshot => applySnapshot(snapshot.models));
+  }, []);
+
   useEffect(() => {
     if (!subscriptionLoginPanel || subscriptionLoginPanel.status !== 'pending') return;
     setSubscriptionLoginClock(Date.now());
@@ -2804,6 +2821,7 @@ const AIModelConfig: React.FC = () => {
   const renderModelCollectionItem = (config: AIModelConfigType) => {
     const isExpanded = expandedIds.has(config.id || '');
     const testResult = config.id ? testResults[config.id] : null;
+    const rejectedCredential = config.id ? rejectedCredentials[config.id] : undefined;
     const isTesting = config.id ? !!testingConfigs[config.id] : false;
     const providerDisplayName = getProviderDisplayName(config);
     const modelDisplayName = getModelDisplayName(config);
@@ -2825,6 +2843,15 @@ const AIModelConfig: React.FC = () => {
             title={testResult.message}
           />
         )}
+        {rejectedCredential && !testResult && (
+          <span
+            data-testid="settings-model-credential-status"
+            data-config-id={config.id || ''}
+            data-status="rejected"
+            className="bitfun-ai-model-config__status-dot is-error"
+            title={t('messages.credentialRejected', { error: rejectedCredential.error ?? '' })}
+          />
+        )}
       </>
     );
 
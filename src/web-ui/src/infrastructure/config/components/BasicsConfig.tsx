@@ -27,6 +27,7 @@ import {
   ConfigPageSection,
   ConfigPageRow,
 } from './common';
+import { RuntimeCommandDiagnostics } from './RuntimeCommandDiagnostics';
 import { configManager } from '../services/ConfigManager';
 import { createLogger } from '@/shared/utils/logger';
 import type {
@@ -361,7 +362,7 @@ function BasicsLoggingSection() {
     try {
       setExportingDiagnostics(true);
       const result = await configAPI.exportDiagnosticsBundle();
-      showMessage('success', t('logging.messages.diagnosticsExported'));
+      showMessage('success', result.summary ?? t('logging.messages.diagnosticsExported'));
       await workspaceAPI.revealInExplorer(result.bundlePath);
     } catch (error) {
       log.error('Failed to export diagnostics bundle', { error });
@@ -878,6 +879,7 @@ const BasicsConfig: React.FC = () => {
         <BasicsAutoUpdateSection />
         <BasicsWindowBehaviorSection />
         <BasicsLoggingSection />
+        <RuntimeCommandDiagnostics />
         <BasicsTerminalSection />
         <BasicsNotificationsSection />
       </ConfigPageContent>
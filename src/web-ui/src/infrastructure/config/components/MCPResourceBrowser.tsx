@@ -68,6 +68,15 @@ export const MCPResourceBrowser: React.FC<MCPResourceBrowserProps> = ({ serverId
     loadResources();
   }, [serverId, loadResources]);
 
+  useEffect(() => {
+    if (!serverId) return;
+    return MCPAPI.onListChanged((event) => {
+      if (event.serverId === serverId && event.kind === 'resources') {
+        void loadResources();
+      }
+    });
+  }, [serverId, loadResources]);
+
   useEffect(() => {
     filterResources();
   }, [filterResources]);
@@ -0,0 +1,180 @@
+/**
+ * McpPresetCatalog — built-in MCP server presets with a small install form.
+ * Installing adds the rendered entry to the user mcp.json.
+ */
+
+import React, { useCallback, useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Plus } from 'lucide-react';
+import { Button, Input, Modal } from '@/component-library';
+import { useNotification } from '@/shared/notification-system';
+import { createLogger } from '@/shared/utils/logger';
+import {
+  MCPAPI,
+  type MCPServerPreset,
+  type MCPServerPresetValidation,
+} from '../../api/service-api/MCPAPI';
+import { ConfigCollectionItem, ConfigPageSection } from './common';
+
+const log = createLogger('McpPresetCatalog');
+
+export interface McpPresetCatalogProps {
+  /** Called with the new server id after a preset was added to mcp.json. */
+  onInstalled: (serverId: string) => void;
+}
+
+function initialValues(preset: MCPServerPreset): Record<string, string> {
+  return Object.fromEntries(
+    preset.parameters.map((parameter) => [parameter.key, parameter.default ?? ''])
+  );
+}
+
+const McpPresetCatalog: React.FC<McpPresetCatalogProps> = ({ onInstalled }) => {
+  const { t } = useTranslation('settings/mcp');
+  const notification = useNotification();
+  const [presets, setPresets] = useState<MCPServerPreset[]>([]);
+  const [activePreset, setActivePreset] = useState<MCPServerPreset | null>(null);
+  const [values, setValues] = useState<Record<string, string>>({});
+  const [validation, setValidation] = useState<MCPServerPresetValidation | null>(null);
+  const [installing, setInstalling] = useState(false);
+
+  const loadPresets = useCallback(async () => {
+    try {
+      const catalog = await MCPAPI.getServerPresets();
+      setPresets(catalog.presets);
+    } catch (error) {
+      log.warn('Failed to load MCP server presets', { error });
+      setPresets([]);
+    }
+  }, []);
+
+  useEffect(() => {
+    void loadPresets();
+  }, [loadPresets]);
+
+  const openPreset = (preset: MCPServerPreset) => {
+    setActivePreset(preset);
+    setValues(initialValues(preset));
+    setValidation(null);
+  };
+
+  const closePreset = () => {
+    if (installing) return;
+    setActivePreset(null);
+    setValidation(null);
+  };
+
+  const fieldError = (key: string) =>
+    validation?.errors.find((error) => error.key === key)?.message;
+
+  const handleInstall = async () => {
+    if (!activePreset) return;
+    const request = { presetId: activePreset.id, values };
+    setInstalling(true);
+    try {
+      const result = await MCPAPI.validateServerPreset(request);
+      setValidation(result);
+      if (!result.valid) return;
+
+      const serverId = await MCPAPI.installServerPreset(request);
+      notification.success(t('presets.installed', { name: activePreset.name }), {
+        title: t('presets.title'),
+        duration: 3000,
+      });
+      if (result.command && result.commandAvailable === false) {
+        notification.warning(t('presets.commandMissing', { command: result.command }), {
+          title: t('presets.title'),
+          duration: 8000,
+        });
+      }
+      setActivePreset(null);
+      setValidation(null);
+      onInstalled(serverId);
+    } catch (error) {
+      notification.error(error instanceof Error ? error.message : String(error), {
+        title: t('presets.installFailed'),
+        duration: 5000,
+      });
+    } finally {
+      setInstalling(false);
+    }
+  };
+
+  if (presets.length === 0) return null;
+
+  return (
+    <ConfigPageSection title={t('presets.title')} description={t('presets.description')}>
+      {presets.map((preset) => (
+        <ConfigCollectionItem
+          key={preset.id}
+          label={preset.name}
+          control={
+            <Button variant="secondary" size="small" onClick={() => openPreset(preset)}>
+              <Plus size={14} />
+              {t('presets.add')}
+            </Button>
+          }
+          details={
+            <div className="bitfun-mcp-tools__json-editor-header">
+              <p className="bitfun-mcp-tools__json-hint">{preset.description}</p>
+              <p className="bitfun-mcp-tools__json-hint">
+                {preset.command
+                  ? [preset.command, ...(preset.args ?? [])].join(' ')
+                  : preset.url}
+              </p>
+            </div>
+          }
+        />
+      ))}
+
+      <Modal
+        isOpen={!!activePreset}
+        onClose={closePreset}
+        title={activePreset ? t('presets.modalTitle', { name: activePreset.name }) : ''}
+        size="medium"
+        showCloseButton={!installing}
+      >
+        {activePreset && (
+          <div className="bitfun-mcp-tools__json-editor">
+            <p className="bitfun-mcp-tools__json-hint">{activePreset.description}</p>
+            {activePreset.parameters.length === 0 && (
+              <p className="bitfun-mcp-tools__json-hint">{t('presets.noParameters')}</p>
+            )}
+            {activePreset.parameters.map((parameter) => (
+              <Input
+                key={parameter.key}
+                label={parameter.required ? `${parameter.label} *` : parameter.label}
+                hint={parameter.description}
+                type={parameter.kind === 'secret' ? 'password' : 'text'}
+                value={values[parameter.key] ?? ''}
+                placeholder={parameter.placeholder}
+                onChange={(e) =>
+                  setValues((prev) => ({ ...prev, [parameter.key]: e.target.value }))
+                }
+                error={!!fieldError(parameter.key)}
+                errorMessage={fieldError(parameter.key)}
+                inputSize="small"
+                spellCheck={false}
+              />
+            ))}
+            {validation?.command && validation.commandAvailable === false && (
+              <p className="bitfun-mcp-tools__json-hint">
+                {t('presets.commandMissing', { command: validation.command })}
+              </p>
+            )}
+            <div className="bitfun-mcp-tools__json-actions">
+              <Button variant="secondary" onClick={closePreset} disabled={installing}>
+                {t('actions.cancel')}
+              </Button>
+              <Button variant="primary" onClick={handleInstall} isLoading={installing}>
+                {t('presets.install')}
+              </Button>
+            </div>
+          </div>
+        )}
+      </Modal>
+    </ConfigPageSection>
+  );
+};
+
+export default McpPresetCatalog;
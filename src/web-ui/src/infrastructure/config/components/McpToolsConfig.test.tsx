@@ -44,6 +44,9 @@ vi.mock('../../api/service-api/SystemAPI', () => ({ systemAPI: {} }));
 vi.mock('./ExternalMcpOverview', () => ({
   default: () => <div data-testid="external-mcp-overview" />,
 }));
+vi.mock('./McpPresetCatalog', () => ({
+  default: () => <div data-testid="mcp-preset-catalog" />,
+}));
 
 describe('McpToolsConfig remote behavior', () => {
   let container: HTMLDivElement;
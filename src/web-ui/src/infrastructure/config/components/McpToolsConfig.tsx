@@ -17,6 +17,8 @@ import {
   MinusCircle,
   KeyRound,
   Trash2,
+  Activity,
+  Download,
 } from 'lucide-react';
 import { Button, Textarea, IconButton, Modal, ToolProcessingDots } from '@/component-library';
 import {
@@ -30,13 +32,17 @@ import { useNotification } from '@/shared/notification-system';
 import { createLogger } from '@/shared/utils/logger';
 import { usePeerDeviceModeOptional } from '@/infrastructure/peer-device/peerDeviceContextState';
 import { isTauriRuntime } from '@/infrastructure/runtime';
+import { commandErrorMessage } from '@/infrastructure/i18n/core/commandErrorMessage';
 import {
+  getMCPFailure,
   MCPAPI,
+  MCPFailureKind,
   MCPRemoteOAuthSessionSnapshot,
   MCPServerInfo,
 } from '../../api/service-api/MCPAPI';
 import { systemAPI } from '../../api/service-api/SystemAPI';
 import ExternalMcpOverview from './ExternalMcpOverview';
+import McpPresetCatalog from './McpPresetCatalog';
 import './McpToolsConfig.scss';
 
 const log = createLogger('McpToolsConfig');
@@ -56,9 +62,33 @@ function createErrorClassifier(t: (key: string, options?: any) => any) {
     return suggestions.map((s) => String(s));
   };
 
+  // Failure kinds with a specific recovery path. Config, not-found and internal
+  // failures fall through to the message patterns below, which are more precise.
+  const failureKinds: Partial<Record<MCPFailureKind, { title: string; suggestions: string }>> = {
+    transport: { title: 'errors.serverUnavailable', suggestions: 'errors.suggestions.transport' },
+    timeout: { title: 'errors.requestTimedOut', suggestions: 'errors.suggestions.timeout' },
+    protocol: { title: 'errors.protocolError', suggestions: 'errors.suggestions.protocol' },
+    auth: { title: 'errors.authRequired', suggestions: 'errors.suggestions.auth' },
+    server: { title: 'errors.serverRejected', suggestions: 'errors.suggestions.server' },
+  };
+
   return function classifyError(error: unknown, context: string = 'operation'): ErrorInfo {
+    const failure = getMCPFailure(error);
+    const failureKind = failure ? failureKinds[failure.kind] : undefined;
+    if (failure && failureKind)
+      return {
+        title: t(failureKind.title),
+        message:
+          failure.kind === 'server' && failure.code !== undefined
+            ? t('errors.serverRejectedMessage', { code: failure.code, message: failure.message })
+            : failure.message,
+        duration: 10000,
+        suggestions: getSuggestions(failureKind.suggestions),
+      };
+
     let errorMessage = t('errors.unknownError');
-    if (error instanceof Error) errorMessage = error.message;
+    if (failure) errorMessage = failure.message;
+    else if (error instanceof Error) errorMessage = error.message;
     else if (typeof error === 'string') errorMessage = error;
 
     const normalizedMessage = errorMessage.toLowerCase();
@@ -191,6 +221,7 @@ const McpToolsConfig: React.FC = () => {
   const [oauthSession, setOauthSession] = useState<MCPRemoteOAuthSessionSnapshot | null>(null);
   const [oauthStarting, setOauthStarting] = useState(false);
   const [oauthCancelling, setOauthCancelling] = useState(false);
+  const [tracingServerIds, setTracingServerIds] = useState<Set<string>>(() => new Set());
   const [jsonLintError, setJsonLintError] = useState<{
     message: string;
     line?: number;
@@ -399,6 +430,16 @@ const McpToolsConfig: React.FC = () => {
     void loadJsonConfig();
   }, [desktopConfigAvailable, loadJsonConfig, loadServers]);
 
+  // Servers start concurrently and settle one by one; refresh as each does.
+  useEffect(() => {
+    if (!desktopConfigAvailable) return;
+    return MCPAPI.onServerReadiness((event) => {
+      if (event.readiness === 'ready' || event.readiness === 'failed') {
+        void loadServers();
+      }
+    });
+  }, [desktopConfigAvailable, loadServers]);
+
   useEffect(() => {
     return () => {
       if (oauthPollTimerRef.current !== null) {
@@ -654,6 +695,21 @@ const McpToolsConfig: React.FC = () => {
     );
   };
 
+  const handlePresetInstalled = async (serverId: string) => {
+    const capabilityEpoch = currentCapabilityEpoch();
+    if (capabilityEpoch === null) return;
+    try {
+      await MCPAPI.initializeServersNonDestructive();
+    } catch (error) {
+      log.warn('Failed to start installed MCP preset', { serverId, error });
+    }
+    if (!capabilityIsCurrent(capabilityEpoch)) return;
+    await loadServers();
+    if (capabilityIsCurrent(capabilityEpoch)) {
+      await loadJsonConfig();
+    }
+  };
+
   const handleStartServer = async (server: MCPServerInfo) => {
     const capabilityEpoch = currentCapabilityEpoch();
     if (capabilityEpoch === null) return;
@@ -679,10 +735,10 @@ const McpToolsConfig: React.FC = () => {
           void startRemoteOAuthFlow(server);
         }
       }
+      const localizedMessage = await commandErrorMessage(error);
       notification.error(
-        tMcp('messages.startFailed', { serverId }) +
-          ': ' +
-          getErrorMessage(error),
+        localizedMessage ??
+          tMcp('messages.startFailed', { serverId }) + ': ' + getErrorMessage(error),
         { title: tMcp('notifications.startFailed'), duration: 5000 }
       );
     }
@@ -710,6 +766,46 @@ const McpToolsConfig: React.FC = () => {
     }
   };
 
+  const handleToggleTrace = async (serverId: string) => {
+    const enabled = !tracingServerIds.has(serverId);
+    try {
+      await MCPAPI.setTraceEnabled({ serverId, enabled });
+      setTracingServerIds((prev) => {
+        const next = new Set(prev);
+        if (enabled) next.add(serverId);
+        else next.delete(serverId);
+        return next;
+      });
+    } catch (error) {
+      notification.error(classifyError(error, 'trace').message, {
+        title: tMcp('notifications.traceFailed'),
+        duration: 5000,
+      });
+    }
+  };
+
+  const handleExportTrace = async (serverId: string) => {
+    try {
+      const { save } = await import('@tauri-apps/plugin-dialog');
+      const targetPath = await save({
+        title: tMcp('actions.exportTrace'),
+        defaultPath: `mcp-trace-${serverId}.json`,
+        filters: [{ name: 'JSON', extensions: ['json'] }],
+      });
+      if (!targetPath) return;
+      const result = await MCPAPI.exportTrace({ serverId, targetPath });
+      notification.success(
+        tMcp('messages.traceExported', { count: result.frames, path: result.path }),
+        { title: tMcp('notifications.traceExported'), duration: 4000 }
+      );
+    } catch (error) {
+      notification.error(classifyError(error, 'trace').message, {
+        title: tMcp('notifications.traceFailed'),
+        duration: 5000,
+      });
+    }
+  };
+
   const handleRestartServer = async (server: MCPServerInfo) => {
     const capabilityEpoch = currentCapabilityEpoch();
     if (capabilityEpoch === null) return;
@@ -735,10 +831,10 @@ const McpToolsConfig: React.FC = () => {
           void startRemoteOAuthFlow(server);
         }
       }
+      const localizedMessage = await commandErrorMessage(error);
       notification.error(
-        tMcp('messages.restartFailed', { serverId }) +
-          ': ' +
-          getErrorMessage(error),
+        localizedMessage ??
+          tMcp('messages.restartFailed', { serverId }) + ': ' + getErrorMessage(error),
         { title: tMcp('notifications.restartFailed'), duration: 5000 }
       );
     }
@@ -1180,6 +1276,32 @@ const McpToolsConfig: React.FC = () => {
       >
         <RefreshCw size={14} />
       </IconButton>
+      {desktopConfigAvailable && (
+        <IconButton
+          size="small"
+          variant={tracingServerIds.has(server.id) ? 'primary' : 'ghost'}
+          onClick={() => handleToggleTrace(server.id)}
+          tooltip={
+            tracingServerIds.has(server.id) ? tMcp('actions.stopTrace') : tMcp('actions.startTrace')
+          }
+          aria-label={
+            tracingServerIds.has(server.id) ? tMcp('actions.stopTrace') : tMcp('actions.startTrace')
+          }
+        >
+          <Activity size={14} />
+        </IconButton>
+      )}
+      {desktopConfigAvailable && tracingServerIds.has(server.id) && (
+        <IconButton
+          size="small"
+          variant="ghost"
+          onClick={() => handleExportTrace(server.id)}
+          tooltip={tMcp('actions.exportTrace')}
+          aria-label={tMcp('actions.exportTrace')}
+        >
+          <Download size={14} />
+        </IconButton>
+      )}
     </>
   );
 
@@ -1242,6 +1364,20 @@ const McpToolsConfig: React.FC = () => {
                 </span>
               </div>
             )}
+            {server.tlsMode && (
+              <div className="bitfun-mcp-tools__server-detail-item">
+                <span className="bitfun-mcp-tools__server-detail-label">
+                  {tMcp('server.tls')}:
+                </span>
+                <span
+                  className={`bitfun-mcp-tools__server-detail-value${
+                    server.tlsMode === 'insecure' ? ' is-warning' : ''
+                  }`}
+                >
+                  {tMcp(`server.tlsMode.${server.tlsMode}`)}
+                </span>
+              </div>
+            )}
           </>
         )}
         {!isCommandDrivenServer(server) ? null : (
@@ -1410,6 +1546,10 @@ const McpToolsConfig: React.FC = () => {
             ))}
         </ConfigPageSection>
 
+        {desktopConfigAvailable && (
+          <McpPresetCatalog onInstalled={(serverId) => void handlePresetInstalled(serverId)} />
+        )}
+
         <ExternalMcpOverview />
       </ConfigPageContent>
       <Modal
@@ -0,0 +1,186 @@
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Search } from 'lucide-react';
+import { Alert, Button, Input, Select, Tag, type SelectOption } from '@/component-library';
+import {
+  MCPAPI,
+  type CommandDiagnosis,
+  type CommandMatchOrigin,
+} from '@/infrastructure/api/service-api/MCPAPI';
+import { createLogger } from '@/shared/utils/logger';
+import { configManager } from '../services/ConfigManager';
+import type { RuntimeResolutionPolicy } from '../types';
+import { ConfigPageRow, ConfigPageSection } from './common';
+
+const log = createLogger('RuntimeCommandDiagnostics');
+
+const RESOLUTION_POLICIES: RuntimeResolutionPolicy[] = ['prefer_system', 'prefer_managed', 'managed_only'];
+const DEFAULT_POLICY_PATH = 'app.runtime_resolution.default_policy';
+
+const ORIGIN_COLORS: Record<CommandMatchOrigin, 'gray' | 'blue' | 'green'> = {
+  systemPath: 'gray',
+  platformPath: 'blue',
+  managed: 'green',
+};
+
+export function RuntimeCommandDiagnostics() {
+  const { t } = useTranslation('settings/basics');
+  const [command, setCommand] = useState('node');
+  const [diagnosing, setDiagnosing] = useState(false);
+  const [diagnosis, setDiagnosis] = useState<CommandDiagnosis | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [defaultPolicy, setDefaultPolicy] = useState<RuntimeResolutionPolicy>('prefer_system');
+  const [savingPolicy, setSavingPolicy] = useState(false);
+
+  useEffect(() => {
+    let cancelled = false;
+    configManager
+      .getConfig<RuntimeResolutionPolicy>(DEFAULT_POLICY_PATH)
+      .then((policy) => {
+        if (!cancelled && policy) setDefaultPolicy(policy);
+      })
+      .catch((loadError) => {
+        log.warn('Failed to load runtime resolution policy', { error: loadError });
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const policyOptions = useMemo<SelectOption[]>(
+    () =>
+      RESOLUTION_POLICIES.map((policy) => ({
+        value: policy,
+        label: t(`runtimeDiagnostics.policies.${policy}`),
+      })),
+    [t]
+  );
+
+  const handlePolicyChange = useCallback(
+    async (policy: RuntimeResolutionPolicy) => {
+      const previous = defaultPolicy;
+      setDefaultPolicy(policy);
+      setSavingPolicy(true);
+      try {
+        await configManager.setConfig(DEFAULT_POLICY_PATH, policy);
+      } catch (saveError) {
+        log.error('Failed to update runtime resolution policy', { policy, error: saveError });
+        setDefaultPolicy(previous);
+        setError(t('runtimeDiagnostics.messages.policyFailed'));
+      } finally {
+        setSavingPolicy(false);
+      }
+    },
+    [defaultPolicy, t]
+  );
+
+  const handleDiagnose = useCallback(async () => {
+    const trimmed = command.trim();
+    if (!trimmed) return;
+    setDiagnosing(true);
+    setError(null);
+    try {
+      setDiagnosis(await MCPAPI.diagnoseRuntimeCommand(trimmed));
+    } catch (diagnoseError) {
+      log.error('Failed to diagnose runtime command', { command: trimmed, error: diagnoseError });
+      setDiagnosis(null);
+      setError(t('runtimeDiagnostics.messages.failed'));
+    } finally {
+      setDiagnosing(false);
+    }
+  }, [command, t]);
+
+  return (
+    <ConfigPageSection
+      title={t('runtimeDiagnostics.sections.title')}
+      description={t('runtimeDiagnostics.sections.hint')}
+    >
+      <ConfigPageRow
+        label={t('runtimeDiagnostics.policy.label')}
+        description={t('runtimeDiagnostics.policy.description')}
+        align="center"
+      >
+        <Select
+          value={defaultPolicy}
+          onChange={(value) => {
+            void handlePolicyChange(value as RuntimeResolutionPolicy);
+          }}
+          options={policyOptions}
+          disabled={savingPolicy}
+          size="small"
+        />
+      </ConfigPageRow>
+
+      <ConfigPageRow
+        label={t('runtimeDiagnostics.command.label')}
+        description={t('runtimeDiagnostics.command.description')}
+        align="center"
+      >
+        <div className="bitfun-runtime-diagnostics__query">
+          <Input
+            value={command}
+            onChange={(e) => setCommand(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === 'Enter') void handleDiagnose();
+            }}
+            placeholder={t('runtimeDiagnostics.command.placeholder')}
+            inputSize="small"
+          />
+          <Button
+            type="button"
+            variant="secondary"
+            size="small"
+            onClick={() => {
+              void handleDiagnose();
+            }}
+            isLoading={diagnosing}
+            disabled={diagnosing || !command.trim()}
+          >
+            <Search size={14} />
+            {t('runtimeDiagnostics.actions.diagnose')}
+          </Button>
+        </div>
+      </ConfigPageRow>
+
+      {error ? <Alert type="error" message={error} /> : null}
+
+      {diagnosis ? (
+        <div className="bitfun-runtime-diagnostics__result">
+          {diagnosis.matches.length > 0 ? (
+            <ol className="bitfun-runtime-diagnostics__matches">
+              {diagnosis.matches.map((match) => (
+                <li
+                  key={match.path}
+                  className={`bitfun-runtime-diagnostics__match${match.active ? ' is-active' : ''}`}
+                >
+                  <Tag size="small" color={ORIGIN_COLORS[match.origin]}>
+                    {t(`runtimeDiagnostics.origins.${match.origin}`)}
+                  </Tag>
+                  <span className="bitfun-runtime-diagnostics__path">{match.path}</span>
+                  <span className="bitfun-runtime-diagnostics__version">
+                    {match.version ?? t('runtimeDiagnostics.unknownVersion')}
+                  </span>
+                  {match.active ? (
+                    <Tag size="small" color="purple">
+                      {t('runtimeDiagnostics.active')}
+                    </Tag>
+                  ) : null}
+                </li>
+              ))}
+            </ol>
+          ) : null}
+          {diagnosis.conflicts.length === 0 && diagnosis.matches.length > 0 ? (
+            <Alert type="success" message={t('runtimeDiagnostics.messages.noConflicts')} />
+          ) : null}
+          {diagnosis.suggestions.map((suggestion) => (
+            <Alert
+              key={suggestion}
+              type={diagnosis.matches.length === 0 ? 'error' : 'warning'}
+              message={suggestion}
+            />
+          ))}
+        </div>
+      ) : null}
+    </ConfigPageSection>
+  );
+}
@@ -83,6 +83,7 @@ type ToolPermissionMode = 'ask' | 'auto' | 'full_access';
 
 const DEFAULT_SUBAGENT_BATCH_EXECUTION_POLICY: SubagentBatchExecutionPolicy = 'force_parallel';
 const DEFAULT_SUBAGENT_MAX_CONCURRENCY = 5;
+const DEFAULT_SUBAGENT_MAX_DEPTH = 1;
 
 function normalizeSubagentBatchExecutionPolicy(value: unknown): SubagentBatchExecutionPolicy {
   return value === 'force_parallel' || value === 'serial' || value === 'safe_only'
@@ -120,6 +121,7 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
   const [companionPetListExpanded, setCompanionPetListExpanded] = useState(false);
   const [enableDeferredToolLoading, setEnableDeferredToolLoading] = useState(true);
   const [subagentMaxConcurrency, setSubagentMaxConcurrency] = useState(DEFAULT_SUBAGENT_MAX_CONCURRENCY);
+  const [subagentMaxDepth, setSubagentMaxDepth] = useState(DEFAULT_SUBAGENT_MAX_DEPTH);
   const [executionTimeout, setExecutionTimeout] = useState('');
   const [subagentBatchExecutionPolicy, setSubagentBatchExecutionPolicy] =
     useState<SubagentBatchExecutionPolicy>(DEFAULT_SUBAGENT_BATCH_EXECUTION_POLICY);
@@ -225,6 +227,7 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
         loadedSettings,
         deferredToolLoadingEnabled,
         loadedSubagentMaxConcurrency,
+        loadedSubagentMaxDepth,
         execTimeout,
         loadedSubagentBatchExecutionPolicy,
         debugConfigData,
@@ -236,6 +239,7 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
         aiExperienceConfigService.getSettingsAsync(),
         configManager.getConfig<boolean>('ai.enable_deferred_tool_loading'),
         configManager.getConfig<number | null>('ai.subagent_max_concurrency'),
+        configManager.getConfig<number | null>('ai.subagent_max_depth'),
         configManager.getConfig<number | null>('ai.tool_execution_timeout_secs'),
         configManager.getConfig<SubagentBatchExecutionPolicy>('ai.subagent_batch_execution_policy'),
         configManager.getConfig<DebugModeConfig>('ai.debug_mode_config'),
@@ -251,6 +255,7 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
       setSubagentMaxConcurrency(loadedSubagentMaxConcurrency != null
         ? loadedSubagentMaxConcurrency
         : DEFAULT_SUBAGENT_MAX_CONCURRENCY);
+      setSubagentMaxDepth(loadedSubagentMaxDepth ?? DEFAULT_SUBAGENT_MAX_DEPTH);
       setExecutionTimeout(execTimeout != null ? String(execTimeout) : '');
       setSubagentBatchExecutionPolicy(normalizeSubagentBatchExecutionPolicy(loadedSubagentBatchExecutionPolicy));
       if (debugConfigData) setDebugConfig(debugConfigData);
@@ -552,6 +557,17 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
     }
   };
 
+  const handleSubagentMaxDepthChange = async (value: number) => {
+    if (Number.isNaN(value) || value < 1) return;
+    setSubagentMaxDepth(value);
+    try {
+      await configManager.setConfig('ai.subagent_max_depth', value);
+    } catch (error) {
+      log.error('Failed to save subagent_max_depth', error);
+      notificationService.error(tTools('messages.saveFailed'));
+    }
+  };
+
   const handleComputerUseEnabledChange = async (checked: boolean) => {
     setComputerUseBusy(true);
     setComputerUseEnabled(checked);
@@ -1203,6 +1219,27 @@ const SessionSettingsPanels: React.FC<SessionSettingsPanelsProps> = ({ variant }
               />
             </div>
           </ConfigPageRow>
+          <ConfigPageRow
+            label={(
+              <span className="bitfun-func-agent-config__inline-label">
+                <span>{tTools('config.subagentMaxDepth')}</span>
+              </span>
+            )}
+            description={tTools('config.subagentMaxDepthDesc')}
+            align="center"
+          >
+            <div className="bitfun-func-agent-config__row-control">
+              <NumberInput
+                value={subagentMaxDepth}
+                onChange={(val) => void handleSubagentMaxDepthChange(val)}
+                min={1}
+                max={5}
+                step={1}
+                size="small"
+                variant="compact"
+              />
+            </div>
+          </ConfigPageRow>
         </ConfigPageSection>
 
         <ConfigPageSection
@@ -15,11 +15,17 @@ import {
   getSkillSourceLabel,
 } from '../skillSourcePresentation';
 import { open } from '@tauri-apps/plugin-dialog';
+import { commandErrorMessage } from '@/infrastructure/i18n/core/commandErrorMessage';
 import { createLogger } from '@/shared/utils/logger';
 import './SkillsConfig.scss';
 
 const log = createLogger('SkillsConfig');
 
+/** Backend catalog text when the command sent one, else the raw error message. */
+async function skillErrorText(err: unknown): Promise<string> {
+  return (await commandErrorMessage(err)) ?? (err instanceof Error ? err.message : String(err));
+}
+
 const SkillsConfig: React.FC = () => {
   const { t } = useTranslation('settings/skills');
   const [showAddForm, setShowAddForm] = useState(false);
@@ -139,7 +145,7 @@ const SkillsConfig: React.FC = () => {
       resetForm();
       await loadSkills(true);
     } catch (err) {
-      notification.error(t('messages.addFailed', { error: err instanceof Error ? err.message : String(err) }));
+      notification.error(t('messages.addFailed', { error: await skillErrorText(err) }));
     } finally {
       setIsAdding(false);
     }
@@ -159,7 +165,7 @@ const SkillsConfig: React.FC = () => {
       notification.success(t('messages.deleteSuccess', { name: skill.name }));
       await loadSkills(true);
     } catch (err) {
-      notification.error(t('messages.deleteFailed', { error: err instanceof Error ? err.message : String(err) }));
+      notification.error(t('messages.deleteFailed', { error: await skillErrorText(err) }));
     } finally {
       setDeleteConfirm({ show: false, skill: null });
     }
@@ -183,7 +189,7 @@ const SkillsConfig: React.FC = () => {
       notification.success(t('messages.marketDownloadSuccess', { name: installedName }));
       await loadSkills(true);
     } catch (err) {
-      notification.error(t('messages.marketDownloadFailed', { error: err instanceof Error ? err.message : String(err) }));
+      notification.error(t('messages.marketDownloadFailed', { error: await skillErrorText(err) }));
     } finally {
       setDownloadingPackage(null);
     }
@@ -295,10 +301,10 @@ const SkillsConfig: React.FC = () => {
       <>
         <span className="bitfun-collection-item__badge">
           {isRemote
-            ? skill.level === 'user'
+            ? skill.level !== 'project'
               ? t('list.item.localUser')
               : t('list.item.remoteProject')
-            : skill.level === 'user'
+            : skill.level !== 'project'
               ? t('list.item.user')
               : t('list.item.project')}
         </span>
@@ -610,7 +616,7 @@ const SkillsConfig: React.FC = () => {
     );
   }
 
-  const userSkills = skills.filter(s => s.level === 'user');
+  const userSkills = skills.filter(s => s.level !== 'project');
   const projectSkills = skills.filter(s => s.level === 'project');
 
   return (
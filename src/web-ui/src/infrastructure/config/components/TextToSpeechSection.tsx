@@ -0,0 +1,278 @@
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { Square, Volume2 } from 'lucide-react';
+import { useTranslation } from 'react-i18next';
+import { Button, Select, Switch, type SelectOption } from '@/component-library';
+import { speechAPI, type TextToSpeechVoice } from '@/infrastructure/api';
+import { speakText, stopSpeaking, subscribeReadAloud } from '@/infrastructure/speech/readAloud';
+import { createLogger } from '@/shared/utils/logger';
+import { configManager } from '../services/ConfigManager';
+import { getProviderDisplayName } from '../services/modelConfigs';
+import type { AIModelConfig, TextToSpeechSettings } from '../types';
+import {
+  ConfigPageMessage,
+  ConfigPageRow,
+  ConfigPageSection,
+} from './common';
+
+const log = createLogger('TextToSpeechSection');
+
+/** Languages offered a voice override; `default` covers everything else. */
+const VOICE_LANGUAGES = ['default', 'en', 'zh'];
+
+interface TextToSpeechSectionProps {
+  settings: TextToSpeechSettings;
+  onChange: (patch: Partial<TextToSpeechSettings>) => Promise<void>;
+}
+
+function normalizeSelectValue(value: string | number | (string | number)[]): string {
+  return String(Array.isArray(value) ? (value[0] ?? '') : value);
+}
+
+function clampRate(value: number): number {
+  if (!Number.isFinite(value)) {
+    return 1;
+  }
+  return Math.min(2, Math.max(0.5, Math.round(value * 10) / 10));
+}
+
+function voiceMatchesLanguage(voice: TextToSpeechVoice, language: string): boolean {
+  if (language === 'default' || !voice.language) {
+    return true;
+  }
+  return voice.language.toLowerCase().split(/[-_]/)[0] === language;
+}
+
+export function TextToSpeechSection({ settings, onChange }: TextToSpeechSectionProps) {
+  const { t } = useTranslation('settings/voice-input');
+  const [voices, setVoices] = useState<TextToSpeechVoice[]>([]);
+  const [voicesError, setVoicesError] = useState<string | null>(null);
+  const [endpointModels, setEndpointModels] = useState<AIModelConfig[]>([]);
+  const [speaking, setSpeaking] = useState(false);
+
+  useEffect(() => subscribeReadAloud(setSpeaking), []);
+
+  useEffect(() => {
+    let cancelled = false;
+    void configManager.getConfig<AIModelConfig[]>('ai.models').then(models => {
+      if (!cancelled) {
+        setEndpointModels((models || []).filter(model => !!model.enabled && !!model.base_url));
+      }
+    }).catch(error => {
+      log.warn('Failed to load AI models for cloud speech', { error });
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  useEffect(() => {
+    if (!settings.enabled) {
+      return undefined;
+    }
+    let cancelled = false;
+    setVoicesError(null);
+    speechAPI.listVoices().then(response => {
+      if (!cancelled) {
+        setVoices(response.voices);
+      }
+    }).catch(error => {
+      log.warn('Failed to list text-to-speech voices', { error });
+      if (!cancelled) {
+        setVoices([]);
+        setVoicesError(t('textToSpeech.messages.voicesFailed'));
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [settings.enabled, settings.provider, settings.cloud_model_config_id, t]);
+
+  const providerOptions = useMemo<SelectOption[]>(() => [
+    { label: t('textToSpeech.provider.system'), value: 'system' },
+    { label: t('textToSpeech.provider.cloud'), value: 'cloud' },
+  ], [t]);
+  const endpointOptions = useMemo<SelectOption[]>(() => endpointModels.map(model => ({
+    label: `${getProviderDisplayName(model)} / ${model.model_name}`,
+    value: model.id || '',
+  })), [endpointModels]);
+
+  const voiceOptionsFor = useCallback((language: string): SelectOption[] => [
+    { label: t('textToSpeech.voices.automatic'), value: '' },
+    ...voices
+      .filter(voice => voiceMatchesLanguage(voice, language))
+      .map(voice => ({
+        label: voice.language ? `${voice.name} (${voice.language})` : voice.name,
+        value: voice.id,
+      })),
+  ], [t, voices]);
+
+  const handlePreview = useCallback(async () => {
+    if (speaking) {
+      await stopSpeaking().catch(error => log.warn('Failed to stop speech preview', { error }));
+      return;
+    }
+    try {
+      await speakText(t('textToSpeech.preview.sample'));
+    } catch (error) {
+      log.error('Failed to preview text-to-speech', { error });
+      setVoicesError(t('textToSpeech.messages.previewFailed'));
+    }
+  }, [speaking, t]);
+
+  return (
+    <ConfigPageSection
+      title={t('sections.textToSpeech')}
+      description={t('textToSpeech.description')}
+    >
+      <ConfigPageRow
+        label={t('textToSpeech.enabled.label')}
+        description={t('textToSpeech.enabled.description')}
+        align="center"
+      >
+        <Switch
+          checked={settings.enabled}
+          onChange={(event) => void onChange({ enabled: event.target.checked })}
+          size="small"
+        />
+      </ConfigPageRow>
+
+      {settings.enabled ? (
+        <>
+          <ConfigPageRow
+            label={t('textToSpeech.readTaskSummaries.label')}
+            description={t('textToSpeech.readTaskSummaries.description')}
+            align="center"
+          >
+            <Switch
+              checked={settings.read_task_summaries}
+              onChange={(event) => void onChange({ read_task_summaries: event.target.checked })}
+              size="small"
+            />
+          </ConfigPageRow>
+
+          <ConfigPageRow
+            label={t('textToSpeech.readNotifications.label')}
+            description={t('textToSpeech.readNotifications.description')}
+            align="center"
+          >
+            <Switch
+              checked={settings.read_notifications}
+              onChange={(event) => void onChange({ read_notifications: event.target.checked })}
+              size="small"
+            />
+          </ConfigPageRow>
+
+          <ConfigPageRow
+            label={t('textToSpeech.provider.label')}
+            description={t('textToSpeech.provider.description')}
+            align="center"
+          >
+            <Select
+              value={settings.provider}
+              onChange={(value) => void onChange({
+                provider: normalizeSelectValue(value) === 'cloud' ? 'cloud' : 'system',
+                voices: {},
+              })}
+              options={providerOptions}
+              size="small"
+              className="voice-input-config__select"
+            />
+          </ConfigPageRow>
+
+          {settings.provider === 'cloud' ? (
+            <>
+              <ConfigPageRow
+                label={t('textToSpeech.endpoint.label')}
+                description={t('textToSpeech.endpoint.description')}
+                align="center"
+              >
+                <Select
+                  value={settings.cloud_model_config_id}
+                  onChange={(value) => void onChange({ cloud_model_config_id: normalizeSelectValue(value) })}
+                  options={endpointOptions}
+                  placeholder={t('textToSpeech.endpoint.placeholder')}
+                  disabled={endpointOptions.length === 0}
+                  size="small"
+                  className="voice-input-config__model-select"
+                />
+              </ConfigPageRow>
+
+              <ConfigPageRow
+                label={t('textToSpeech.modelName.label')}
+                description={t('textToSpeech.modelName.description')}
+                align="center"
+                wide
+              >
+                <input
+                  className="voice-input-config__text-input"
+                  defaultValue={settings.cloud_model_name}
+                  onBlur={(event) => {
+                    const modelName = event.target.value.trim();
+                    if (modelName && modelName !== settings.cloud_model_name) {
+                      void onChange({ cloud_model_name: modelName });
+                    }
+                  }}
+                  placeholder="gpt-4o-mini-tts"
+                />
+              </ConfigPageRow>
+            </>
+          ) : null}
+
+          <ConfigPageRow
+            label={t('textToSpeech.rate.label')}
+            description={t('textToSpeech.rate.description')}
+            align="center"
+          >
+            <input
+              className="voice-input-config__number-input"
+              type="number"
+              min={0.5}
+              max={2}
+              step={0.1}
+              value={settings.rate}
+              onChange={(event) => void onChange({ rate: clampRate(Number(event.target.value)) })}
+              aria-label={t('textToSpeech.rate.label')}
+            />
+          </ConfigPageRow>
+
+          {VOICE_LANGUAGES.map(language => (
+            <ConfigPageRow
+              key={language}
+              label={t(`textToSpeech.voices.${language}`)}
+              description={language === 'default' ? t('textToSpeech.voices.description') : undefined}
+              align="center"
+            >
+              <Select
+                value={settings.voices[language] ?? ''}
+                onChange={(value) => {
+                  const voice = normalizeSelectValue(value);
+                  const nextVoices = { ...settings.voices };
+                  if (voice) {
+                    nextVoices[language] = voice;
+                  } else {
+                    delete nextVoices[language];
+                  }
+                  void onChange({ voices: nextVoices });
+                }}
+                options={voiceOptionsFor(language)}
+                size="small"
+                className="voice-input-config__model-select"
+              />
+            </ConfigPageRow>
+          ))}
+
+          {voicesError ? (
+            <ConfigPageMessage message={{ type: 'warning', text: voicesError }} />
+          ) : null}
+
+          <div className="voice-input-config__cloud-actions">
+            <Button variant="secondary" size="small" onClick={() => void handlePreview()}>
+              {speaking ? <Square size={14} /> : <Volume2 size={14} />}
+              {speaking ? t('textToSpeech.preview.stop') : t('textToSpeech.preview.start')}
+            </Button>
+          </div>
+        </>
+      ) : null}
+    </ConfigPageSection>
+  );
+}
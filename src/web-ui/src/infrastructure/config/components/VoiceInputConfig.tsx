@@ -16,8 +16,10 @@ import {
   LOCAL_SENSEVOICE_SMALL_INT8_MODEL_ID,
   speechAPI,
   workspaceAPI,
+  type SpeechInputProvider,
   type SpeechModelInstallState,
   type SpeechModelStatus,
+  type SpeechWhisperCppStatus,
 } from '@/infrastructure/api';
 import { notificationService } from '@/shared/notification-system';
 import { createLogger } from '@/shared/utils/logger';
@@ -29,7 +31,13 @@ import {
   aiExperienceConfigService,
   type AIExperienceSettings,
 } from '../services/AIExperienceConfigService';
-import type { AIModelConfig, DefaultModelsConfig, VoiceInputSettings } from '../types';
+import type {
+  AIModelConfig,
+  DefaultModelsConfig,
+  TextToSpeechSettings,
+  VoiceInputSettings,
+} from '../types';
+import { TextToSpeechSection } from './TextToSpeechSection';
 import { VoiceInputDiagnostics } from './VoiceInputDiagnostics';
 import {
   ConfigPageContent,
@@ -56,7 +64,7 @@ const MODEL_RESOURCE_HINT_KEYS: Record<string, string> = {
   [LOCAL_QWEN3_ASR_0_6B_INT8_MODEL_ID]: 'model.resourceHints.qwen3',
 };
 
-type VoiceInputProvider = 'local' | 'cloud';
+type VoiceInputProvider = SpeechInputProvider;
 type CloudSpeechProviderPreset = 'qwen' | 'custom';
 
 interface CloudSpeechDraft {
@@ -76,6 +84,10 @@ function hasHttpUrlScheme(value: string): boolean {
   return /^https?:\/\//i.test(value.trim());
 }
 
+function resolveVoiceInputProvider(provider?: string): VoiceInputProvider {
+  return provider === 'cloud' || provider === 'whisper_cpp' ? provider : 'local';
+}
+
 function resolveTranscriptionRequestUrl(baseUrl: string): string {
   const trimmed = trimTrailingSlashes(baseUrl);
   if (trimmed.endsWith('/audio/transcriptions')) {
@@ -164,6 +176,7 @@ const VoiceInputConfig: React.FC = () => {
   } = useAIExperienceSettings();
   const [models, setModels] = useState<SpeechModelStatus[]>([]);
   const [cloudModels, setCloudModels] = useState<AIModelConfig[]>([]);
+  const [whisperCpp, setWhisperCpp] = useState<SpeechWhisperCppStatus | null>(null);
   const [defaultModels, setDefaultModels] = useState<DefaultModelsConfig>({});
   const [cloudDraft, setCloudDraft] = useState<CloudSpeechDraft>(createDefaultCloudSpeechDraft);
   const [loading, setLoading] = useState(speechRuntimeSupported);
@@ -171,13 +184,17 @@ const VoiceInputConfig: React.FC = () => {
   const cancelDownloadRequestedRef = useRef<Set<string>>(new Set());
 
   const voiceInput = settings?.voice_input;
-  const selectedProvider: VoiceInputProvider = voiceInput?.provider === 'cloud' ? 'cloud' : 'local';
+  const selectedProvider = resolveVoiceInputProvider(voiceInput?.provider);
   const selectedLocalModelId = selectedProvider === 'local'
     ? (voiceInput?.model_id || DEFAULT_LOCAL_VOICE_MODEL_ID)
     : DEFAULT_LOCAL_VOICE_MODEL_ID;
   const selectedCloudModelId = selectedProvider === 'cloud'
     ? voiceInput?.model_id
     : defaultModels.speech_recognition;
+  const whisperCppModels = whisperCpp?.models ?? [];
+  const selectedWhisperCppModelId = selectedProvider === 'whisper_cpp'
+    ? (voiceInput?.model_id || whisperCppModels[0]?.modelId || '')
+    : (whisperCppModels[0]?.modelId || '');
   const selectedModel = useMemo(
     () => models.find(item => item.modelId === selectedLocalModelId) ?? models[0],
     [models, selectedLocalModelId],
@@ -194,6 +211,7 @@ const VoiceInputConfig: React.FC = () => {
   );
   const providerOptions = useMemo<SelectOption[]>(() => [
     { label: t('composer.provider.local'), value: 'local' },
+    { label: t('composer.provider.whisperCpp'), value: 'whisper_cpp' },
     { label: t('composer.provider.cloud'), value: 'cloud' },
   ], [t]);
   const cloudPresetOptions = useMemo<SelectOption[]>(() => [
@@ -205,6 +223,10 @@ const VoiceInputConfig: React.FC = () => {
     value: item.modelId,
     disabled: item.state !== 'installed',
   })), [models]);
+  const whisperCppModelOptions = useMemo<SelectOption[]>(() => (whisperCpp?.models ?? []).map(model => ({
+    label: model.modelId,
+    value: model.modelId,
+  })), [whisperCpp]);
   const cloudModelOptions = useMemo<SelectOption[]>(() => cloudModels.map(model => ({
     label: `${getProviderDisplayName(model)} / ${model.model_name}`,
     value: model.id || '',
@@ -230,12 +252,17 @@ const VoiceInputConfig: React.FC = () => {
     }
     try {
       setLoading(true);
-      const [modelResponse, aiModels, defaultModelsConfig] = await Promise.all([
+      const [modelResponse, aiModels, defaultModelsConfig, whisperCppStatus] = await Promise.all([
         speechAPI.listModels(),
         configManager.getConfig<AIModelConfig[]>('ai.models'),
         configManager.getConfig<DefaultModelsConfig>('ai.default_models'),
+        speechAPI.getWhisperCppStatus().catch(error => {
+          log.warn('Failed to load whisper.cpp status', { error });
+          return null;
+        }),
       ]);
       setModels(modelResponse.models);
+      setWhisperCpp(whisperCppStatus);
       setCloudModels((aiModels || []).filter(model => {
         const capabilities = Array.isArray(model.capabilities) ? model.capabilities : [];
         return !!model.enabled && (
@@ -308,6 +335,25 @@ const VoiceInputConfig: React.FC = () => {
     }
   }, [settings, t]);
 
+  const updateTextToSpeech = useCallback(async (patch: Partial<TextToSpeechSettings>) => {
+    if (!settings) {
+      notificationService.error(t('messages.loadFailed'));
+      return;
+    }
+    try {
+      await aiExperienceConfigService.saveSettings({
+        ...settings,
+        text_to_speech: {
+          ...settings.text_to_speech,
+          ...patch,
+        },
+      });
+    } catch (error) {
+      log.error('Failed to save text-to-speech settings', { error });
+      notificationService.error(t('messages.saveFailed'));
+    }
+  }, [settings, t]);
+
   const updateModelStatus = useCallback((status: SpeechModelStatus) => {
     setModels(previous => previous.map(item =>
       item.modelId === status.modelId ? status : item
@@ -565,6 +611,13 @@ const VoiceInputConfig: React.FC = () => {
                   });
                   return;
                 }
+                if (provider === 'whisper_cpp') {
+                  void updateVoiceInput({
+                    provider: 'whisper_cpp',
+                    model_id: whisperCppModels[0]?.modelId || '',
+                  });
+                  return;
+                }
                 void updateVoiceInput({
                   provider: 'local',
                   model_id: selectedLocalModelUsable
@@ -582,25 +635,47 @@ const VoiceInputConfig: React.FC = () => {
             label={t('composer.model.label')}
             description={selectedProvider === 'cloud'
               ? t('composer.model.cloudActiveDescription')
-              : t('composer.model.localDescription')}
+              : selectedProvider === 'whisper_cpp'
+                ? t('composer.model.whisperCppDescription', { path: whisperCpp?.modelsDir ?? '' })
+                : t('composer.model.localDescription')}
             align="center"
           >
             <Select
               value={selectedProvider === 'cloud'
                 ? (selectedCloudModel?.id || '')
-                : (selectedModel?.modelId ?? selectedLocalModelId)}
+                : selectedProvider === 'whisper_cpp'
+                  ? selectedWhisperCppModelId
+                  : (selectedModel?.modelId ?? selectedLocalModelId)}
               onChange={(value) => updateVoiceInput({
                 provider: selectedProvider,
                 model_id: normalizeSelectValue(value),
               })}
-              options={selectedProvider === 'cloud' ? cloudModelOptions : localModelOptions}
-              placeholder={selectedProvider === 'cloud' ? t('composer.model.cloudPlaceholder') : undefined}
-              disabled={selectedProvider === 'cloud' && cloudModelOptions.length === 0}
+              options={selectedProvider === 'cloud'
+                ? cloudModelOptions
+                : selectedProvider === 'whisper_cpp' ? whisperCppModelOptions : localModelOptions}
+              placeholder={selectedProvider === 'cloud'
+                ? t('composer.model.cloudPlaceholder')
+                : selectedProvider === 'whisper_cpp' ? t('composer.model.whisperCppPlaceholder') : undefined}
+              disabled={
+                (selectedProvider === 'cloud' && cloudModelOptions.length === 0) ||
+                (selectedProvider === 'whisper_cpp' && whisperCppModelOptions.length === 0)
+              }
               size="small"
               className="voice-input-config__model-select"
             />
           </ConfigPageRow>
 
+          {selectedProvider === 'whisper_cpp' ? (
+            <ConfigPageMessage
+              message={whisperCpp?.binaryPath
+                ? { type: 'info', text: t('composer.whisperCpp.ready', { path: whisperCpp.binaryPath }) }
+                : {
+                  type: 'warning',
+                  text: t('composer.whisperCpp.binaryMissing', { path: whisperCpp?.modelsDir ?? '' }),
+                }}
+            />
+          ) : null}
+
           <ConfigPageRow
             label={t('composer.language.label')}
             description={t('composer.language.description')}
@@ -754,6 +829,11 @@ const VoiceInputConfig: React.FC = () => {
           }}
         />
 
+        <TextToSpeechSection
+          settings={settings.text_to_speech}
+          onChange={updateTextToSpeech}
+        />
+
         <ConfigPageSection
           title={t('sections.model')}
           titleSuffix={selectedModel ? (
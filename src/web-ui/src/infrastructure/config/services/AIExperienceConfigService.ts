@@ -4,7 +4,7 @@ import { configManager } from './ConfigManager';
 import { DEFAULT_AGENT_COMPANION_PET } from './AgentCompanionPetService';
 import { configAPI } from '@/infrastructure/api/service-api/ConfigAPI';
 import { createLogger } from '@/shared/utils/logger';
-import type { VoiceInputSettings } from '../types';
+import type { TextToSpeechSettings, VoiceInputSettings } from '../types';
 
 const log = createLogger('AIExperienceConfig');
 
@@ -29,6 +29,8 @@ export interface AIExperienceSettings {
   enable_workspace_search: boolean;
   /** Local speech-to-text settings for the chat composer. */
   voice_input: VoiceInputSettings;
+  /** Text-to-speech settings for reading results aloud. */
+  text_to_speech: TextToSpeechSettings;
   /** User-defined quick actions shown in the post-coding actions menu. */
   quick_actions?: QuickAction[];
 }
@@ -77,6 +79,16 @@ const defaultSettings: AIExperienceSettings = {
     max_recording_seconds: 60,
     microphone_device_id: '',
   },
+  text_to_speech: {
+    enabled: false,
+    provider: 'system',
+    cloud_model_config_id: '',
+    cloud_model_name: 'gpt-4o-mini-tts',
+    rate: 1,
+    voices: {},
+    read_task_summaries: true,
+    read_notifications: false,
+  },
   quick_actions: DEFAULT_QUICK_ACTIONS,
 };
 
@@ -88,6 +100,10 @@ function normalizeSettings(settings: AIExperienceSettings | null | undefined): A
       ...defaultSettings.voice_input,
       ...settings?.voice_input,
     },
+    text_to_speech: {
+      ...defaultSettings.text_to_speech,
+      ...settings?.text_to_speech,
+    },
   };
   // Legacy configs used null to mean the built-in SVG panda. Panda is now the default preset.
   if (!merged.agent_companion_pet) {
@@ -74,6 +74,33 @@ export interface AppConfig {
   ai_experience: AIExperienceConfig;
   user_tool_groups?: UserToolGroupsConfig;
   user_skill_groups?: UserSkillGroupsConfig;
+  runtime_resolution?: RuntimeResolutionSettings;
+  power?: PowerSettings;
+  downloads?: DownloadSettings;
+}
+
+export interface DownloadSettings {
+  /** Combined cap for large downloads in bytes per second; `0` is unlimited. */
+  max_bytes_per_sec: number;
+}
+
+export interface PowerSettings {
+  pause_indexing_on_battery_saver: boolean;
+  pause_scheduled_jobs_on_battery_saver: boolean;
+  /** Downloads requested with "install when idle" wait for the user to be idle. */
+  defer_downloads_until_idle: boolean;
+  /** Seconds without keyboard or mouse input before the user counts as idle. */
+  idle_threshold_secs: number;
+  /** Battery level that counts as battery saver; `0` disables the check. */
+  low_battery_percent: number;
+}
+
+export type RuntimeResolutionPolicy = 'prefer_system' | 'prefer_managed' | 'managed_only';
+
+export interface RuntimeResolutionSettings {
+  default_policy: RuntimeResolutionPolicy;
+  /** Per-command overrides keyed by command name, e.g. `python3`. */
+  commands: Record<string, RuntimeResolutionPolicy>;
 }
 
 export interface UserToolGroupsConfig {
@@ -162,6 +189,8 @@ export interface AIExperienceConfig {
   enable_workspace_search: boolean;
   /** Local speech-to-text settings for the chat composer. */
   voice_input: VoiceInputSettings;
+  /** Text-to-speech settings for reading results aloud. */
+  text_to_speech: TextToSpeechSettings;
   /** User-defined quick actions shown in the post-coding actions menu. */
   quick_actions?: Array<{ id: string; label: string; prompt: string; enabled: boolean }>;
 }
@@ -175,6 +204,19 @@ export interface VoiceInputSettings {
   microphone_device_id: string;
 }
 
+export interface TextToSpeechSettings {
+  enabled: boolean;
+  provider: 'system' | 'cloud';
+  /** AI model config whose endpoint and key are used for cloud speech. */
+  cloud_model_config_id: string;
+  cloud_model_name: string;
+  rate: number;
+  /** Voice id per language tag; the `default` entry applies otherwise. */
+  voices: Record<string, string>;
+  read_task_summaries: boolean;
+  read_notifications: boolean;
+}
+
 export type ModelCapability =
   | 'text_chat'
   | 'function_calling'
@@ -248,6 +290,15 @@ export interface AIModelConfig {
   thinking_budget_tokens?: number;
   /** Authentication source. Defaults to inline `api_key`. */
   auth?: AuthConfig;
+  /** Client-side request throttling. Unset fields are unlimited. */
+  rate_limit?: ModelRateLimitConfig;
+}
+
+export interface ModelRateLimitConfig {
+  requests_per_minute?: number | null;
+  /** Charged from an estimate of the request input size. */
+  tokens_per_minute?: number | null;
+  max_concurrent_requests?: number | null;
 }
 
 /** Subscription provider for in-app OAuth auth. */
@@ -270,6 +321,7 @@ export interface DefaultModelsConfig {
   fast?: string | null;
   image_understanding?: string | null;
   speech_recognition?: string | null;
+  embedding?: string | null;
 }
 
 export type SubagentModelSelection =
@@ -303,10 +355,131 @@ export interface AIConfig {
   stream_idle_timeout_secs?: number | null;
   stream_ttft_timeout_secs?: number | null;
   tool_execution_timeout_secs?: number | null;
+  /** Token budget per tool result sent to the model; `null` keeps built-in limits. */
+  tool_result_max_tokens?: number | null;
   allow_tool_json_repair?: boolean;
   subagent_batch_execution_policy?: 'safe_only' | 'force_parallel' | 'serial';
+  /** Most tool calls from one model turn that run at the same time. */
+  max_parallel_tool_calls?: number;
+  /** Subagent levels allowed below a top-level session; 1 blocks nested delegation. */
+  subagent_max_depth?: number;
   computer_use_enabled?: boolean;
   browser_control_preferred_browser?: string;
+  /** Cap on model requests in flight across all models; `null` means unlimited. */
+  max_concurrent_requests?: number | null;
+  /** Per-provider overrides for agent prompt context providers, keyed by provider id. */
+  context_providers?: Record<string, ContextProviderSettings>;
+  web_fetch?: WebFetchSettings;
+  /** Overridable per workspace in `.bitfun/config/secret_redaction.json`. */
+  secret_redaction?: SecretRedactionSettings;
+  write_sandbox?: WriteSandboxSettings;
+  hooks?: HooksSettings;
+  edit_transaction?: EditTransactionSettings;
+  tool_exposure_budget?: ToolExposureBudgetSettings;
+  skill_models?: SkillModelSettings;
+  response_cache?: ResponseCacheSettings;
+}
+
+export interface ContextProviderSettings {
+  enabled?: boolean | null;
+  /** Approximate token cap for the provider's section. */
+  token_budget?: number | null;
+}
+
+export interface WebFetchSettings {
+  /** Domains the WebFetch tool may read, subdomains included; empty allows any. */
+  allowed_domains: string[];
+  max_response_bytes: number;
+  timeout_secs: number;
+  /** How long fetched pages are reused; 0 disables the cache. */
+  cache_ttl_secs: number;
+  respect_robots_txt: boolean;
+}
+
+/** Redaction of secrets in content sent to model providers. */
+export interface SecretRedactionSettings {
+  enabled: boolean;
+  /** Exact values never redacted. */
+  allowlist: string[];
+  /** Extra regexes; the first capture group is redacted when present. */
+  custom_patterns: string[];
+}
+
+/** Where agent file writes may land, by workspace trust level. */
+export interface WriteSandboxSettings {
+  enabled: boolean;
+  /** Absolute directories writable in addition to the workspace root. */
+  extra_write_paths: string[];
+  trusted: WriteSandboxLevel;
+  /** Applies to restricted and undecided workspaces. */
+  restricted: WriteSandboxLevel;
+}
+
+export interface WriteSandboxLevel {
+  /** Reject writes outside the allowed directories instead of asking. */
+  confine_to_workspace: boolean;
+  /** Ask before writing `.git/config`, CI definitions or lockfiles. */
+  approve_sensitive_files: boolean;
+}
+
+/** Commands run on agent lifecycle events with the event as JSON on stdin. */
+export interface HooksSettings {
+  enabled: boolean;
+  hooks: HookDefinition[];
+}
+
+export type HookEvent =
+  | 'pre_tool_call'
+  | 'post_tool_call'
+  | 'post_edit'
+  | 'session_start'
+  | 'session_end';
+
+export interface HookDefinition {
+  event: HookEvent;
+  /** Tool name pattern with `*` wildcards and `|` alternatives; empty matches every tool. */
+  matcher?: string;
+  command: string;
+  args?: string[];
+  timeout_secs?: number;
+  enabled?: boolean;
+}
+
+/** Checks run before a multi-file EditTransaction is kept. */
+export interface EditTransactionSettings {
+  /** Reject transactions that introduce parse errors. */
+  check_syntax: boolean;
+  /** Build or test program run from the workspace root; empty disables it. */
+  validation_command: string;
+  validation_args: string[];
+  validation_timeout_secs: number;
+}
+
+export interface ToolExposureBudgetSettings {
+  /** Send only the most relevant tools each turn; needs deferred tool loading. */
+  enabled: boolean;
+  max_direct_tools: number;
+  /** Deferred tools named in the prompt; the rest are found with SearchTools. */
+  max_listed_deferred_tools: number;
+  /** Tools always sent with full schemas. */
+  pinned_tools: string[];
+}
+
+/** User policy over the `model` field of SKILL.md front matter. */
+export interface SkillModelSettings {
+  /** When false, skills never change the session model. */
+  enabled: boolean;
+  /** Selector used instead of the skill's preference, keyed by skill name; `inherit` ignores it. */
+  overrides: Record<string, string>;
+}
+
+/** Reuse of responses to repeated utility requests (titles, commit messages). */
+export interface ResponseCacheSettings {
+  enabled: boolean;
+  /** How long a cached response is reused. */
+  ttl_secs: number;
+  /** Least recently used responses are dropped beyond this count. */
+  max_entries: number;
 }
 
 export interface StoredAgentProfileConfigItem {
@@ -337,7 +510,8 @@ export interface SkillInfo {
   name: string;
   description: string;
   path: string;
-  level: SkillLevel;
+  /** `builtin` skills ship with BitFun and are read-only; duplicate one to the user level to edit it. */
+  level: SkillLevel | 'builtin';
   sourceSlot: string;
   /** Provider-neutral ecosystem identity shared by related discovery slots. */
   sourceId?: string;
@@ -350,6 +524,27 @@ export interface SkillInfo {
   isShadowed?: boolean;
   /** Key of the skill that shadows this one (if any). */
   shadowedByKey?: string | null;
+  /** Languages with a SKILL.<lang>.md variant next to the default SKILL.md. */
+  languages?: string[];
+  /** `min_bitfun_version` and `platforms` from the SKILL.md front matter. */
+  compatibility?: SkillCompatibility;
+  /** Set when the skill needs a newer BitFun or another OS; it is disabled in every mode. */
+  incompatibleReason?: string;
+  /** Arguments the skill accepts through the Skill tool, from `parameters` in the front matter. */
+  parameters?: SkillParameter[];
+}
+
+export interface SkillParameter {
+  name: string;
+  type: 'string' | 'number' | 'integer' | 'boolean' | 'array' | 'object';
+  description?: string;
+  required: boolean;
+}
+
+export interface SkillCompatibility {
+  minBitfunVersion?: string;
+  /** `windows`, `macos` or `linux`; absent means any platform. */
+  platforms?: string[];
 }
 
 export interface ModeSkillInfo extends SkillInfo {
@@ -369,7 +564,8 @@ export interface ModeSkillInfo extends SkillInfo {
     | 'builtin_policy_enabled'
     | 'builtin_policy_disabled'
     | 'enabled_by_user_override'
-    | 'disabled_by_user_override';
+    | 'disabled_by_user_override'
+    | 'incompatible';
 }
 
 export interface SkillMarketItem {
@@ -387,6 +583,50 @@ export interface SkillMarketDownloadResult {
   level: SkillLevel;
   installedSkills: string[];
   output: string;
+  /** Pass to undoSkillInstall to remove what this install created. */
+  installId?: string;
+  /** Installed skills that are disabled because they target a newer BitFun or another OS. */
+  warnings: string[];
+}
+
+export interface SkillBundleExportResult {
+  path: string;
+  skillName: string;
+  /** Referenced files from next to the skill folder that were bundled too. */
+  externalAssets: string[];
+  /** References that do not resolve to a file; left out of the bundle. */
+  missingAssets: string[];
+}
+
+export interface SkillInstallUndoResult {
+  installId: string;
+  package: string;
+  removedPaths: string[];
+}
+
+export interface SkillMarketPreviewSkill {
+  name: string;
+  description?: string;
+  /** Folder the skill would be written to. */
+  targetPath: string;
+  /** Whether targetPath already exists and would be overwritten. */
+  exists: boolean;
+  fileCount: number;
+  sizeBytes: number;
+  /** Script files, relative to the skill folder. */
+  scripts: string[];
+  /** Why the skill would be disabled after installing, if it would be. */
+  incompatibleReason?: string | null;
+}
+
+export interface SkillMarketInstallPreview {
+  package: string;
+  level: SkillLevel;
+  skills: SkillMarketPreviewSkill[];
+  totalSizeBytes: number;
+  includesScripts: boolean;
+  /** True when a skill targets a newer BitFun or another OS. */
+  hasIncompatibleSkills: boolean;
 }
 
 export interface DebugModeConfig {
@@ -586,6 +826,16 @@ export interface WorkspaceConfig {
   include_patterns: string[];
   file_associations: Record<string, string>;
   search_exclude_patterns: string[];
+  /** Language servers keyed by LSP language id; overrides installed plugins. */
+  language_servers?: Record<string, LanguageServerSettings>;
+}
+
+export interface LanguageServerSettings {
+  command: string;
+  args?: string[];
+  env?: Record<string, string>;
+  file_extensions?: string[];
+  enabled?: boolean;
 }
 
 export interface IConfigManager {
@@ -697,6 +947,10 @@ export interface UnexpectedExitInfo {
 
 export interface DiagnosticsBundleInfo {
   bundlePath: string;
+  sizeBytes: number;
+  logSessionCount: number;
+  /** One-line description rendered in the configured locale. */
+  summary?: string;
 }
 
 export interface DefaultModels {
@@ -704,6 +958,7 @@ export interface DefaultModels {
   fast: string | null;
   image_understanding?: string | null;
   speech_recognition?: string | null;
+  embedding?: string | null;
 }
 
 export type OptionalCapabilityModels = Record<string, never>;
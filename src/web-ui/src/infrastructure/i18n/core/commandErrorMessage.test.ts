@@ -0,0 +1,56 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest';
+
+const localizeMessages = vi.fn();
+const getCurrentLocale = vi.fn();
+
+vi.mock('@/infrastructure/api/service-api/I18nAPI', () => ({ i18nAPI: { localizeMessages } }));
+vi.mock('./I18nService', () => ({ i18nService: { getCurrentLocale } }));
+
+import { commandErrorMessage } from './commandErrorMessage';
+
+const startFailed = {
+  code: 'network',
+  message: 'Failed to start MCP server github: connection refused',
+  retryable: true,
+  localized: {
+    id: 'mcp-server-start-failed',
+    params: { server: 'github', reason: 'connection refused' },
+    text: 'Failed to start MCP server github: connection refused',
+    locale: 'en-US',
+  },
+};
+
+describe('commandErrorMessage', () => {
+  beforeEach(() => {
+    localizeMessages.mockReset();
+    getCurrentLocale.mockReturnValue('en-US');
+  });
+
+  it('uses the catalog text when it is already in the UI language', async () => {
+    await expect(commandErrorMessage(startFailed)).resolves.toBe(startFailed.localized.text);
+    expect(localizeMessages).not.toHaveBeenCalled();
+  });
+
+  it('renders the message again after a language switch', async () => {
+    getCurrentLocale.mockReturnValue('zh-CN');
+    localizeMessages.mockResolvedValue([{ ...startFailed.localized, text: '启动 MCP 服务器 github 失败', locale: 'zh-CN' }]);
+
+    await expect(commandErrorMessage(startFailed)).resolves.toBe('启动 MCP 服务器 github 失败');
+    expect(localizeMessages).toHaveBeenCalledWith(
+      [{ id: 'mcp-server-start-failed', params: startFailed.localized.params }],
+      'zh-CN'
+    );
+  });
+
+  it('falls back to the original text when rendering fails', async () => {
+    getCurrentLocale.mockReturnValue('zh-CN');
+    localizeMessages.mockRejectedValue(new Error('ipc closed'));
+
+    await expect(commandErrorMessage(startFailed)).resolves.toBe(startFailed.localized.text);
+  });
+
+  it('returns null for errors without a catalog message', async () => {
+    await expect(commandErrorMessage({ code: 'io', message: 'disk full', retryable: false })).resolves.toBeNull();
+    await expect(commandErrorMessage(new Error('plain'))).resolves.toBeNull();
+  });
+});
@@ -0,0 +1,30 @@
+import { getCommandError } from '@/infrastructure/api/errors/TauriCommandError';
+import { i18nAPI } from '@/infrastructure/api/service-api/I18nAPI';
+import { createLogger } from '@/shared/utils/logger';
+import { i18nService } from './I18nService';
+
+const log = createLogger('commandErrorMessage');
+
+/**
+ * Text of a command error rendered from the backend message catalog, in the
+ * current UI language. Returns `null` when the error carries no catalog
+ * message, so callers keep their own wording.
+ */
+export async function commandErrorMessage(error: unknown): Promise<string | null> {
+  const localized = getCommandError(error)?.localized;
+  if (!localized) return null;
+
+  const locale = i18nService.getCurrentLocale();
+  if (localized.locale === locale) return localized.text;
+
+  try {
+    const [rendered] = await i18nAPI.localizeMessages(
+      [{ id: localized.id, params: localized.params }],
+      locale
+    );
+    return rendered?.text ?? localized.text;
+  } catch (renderError) {
+    log.warn('Failed to render command error in the UI language', { id: localized.id, renderError });
+    return localized.text;
+  }
+}
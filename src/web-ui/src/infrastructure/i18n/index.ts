@@ -4,6 +4,7 @@
 
 // Core service
 export { I18nService, i18nService } from './core/I18nService';
+export { commandErrorMessage } from './core/commandErrorMessage';
 
 // Provider
 export { I18nProvider } from './providers';
@@ -0,0 +1,118 @@
+import { speechAPI } from '@/infrastructure/api/service-api/SpeechAPI';
+import type { TextToSpeechSpeakResult } from '@/infrastructure/api/service-api/SpeechAPI';
+import { aiExperienceConfigService } from '@/infrastructure/config/services/AIExperienceConfigService';
+import { i18nService } from '@/infrastructure/i18n';
+import { createLogger } from '@/shared/utils/logger';
+
+const log = createLogger('readAloud');
+
+export type ReadAloudSource = 'task_summary' | 'notification';
+
+type ReadAloudListener = (speaking: boolean) => void;
+
+interface ActiveUtterance {
+  utteranceId: string;
+  /** Set for cloud voices, which the webview plays itself. */
+  audio: HTMLAudioElement | null;
+}
+
+let active: ActiveUtterance | null = null;
+// Bumped on every speak/stop so a slow request cannot start playing after
+// something newer has replaced it.
+let generation = 0;
+let unlistenState: (() => void) | null = null;
+const listeners = new Set<ReadAloudListener>();
+
+function setActive(next: ActiveUtterance | null): void {
+  active = next;
+  listeners.forEach(listener => listener(next !== null));
+}
+
+function stopPlayback(): void {
+  if (active?.audio) {
+    active.audio.pause();
+    active.audio.removeAttribute('src');
+  }
+  if (active) {
+    setActive(null);
+  }
+}
+
+function ensureStateListener(): void {
+  if (unlistenState) {
+    return;
+  }
+  unlistenState = speechAPI.onSpeechState(event => {
+    if (event.utteranceId !== active?.utteranceId || event.state === 'started') {
+      return;
+    }
+    if (event.state === 'failed') {
+      log.warn('System speech failed', { utteranceId: event.utteranceId, error: event.error });
+    }
+    setActive(null);
+  });
+}
+
+/** Notifies `listener` whenever read-aloud starts or stops. */
+export function subscribeReadAloud(listener: ReadAloudListener): () => void {
+  ensureStateListener();
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+/** Speaks `text` right away, interrupting anything already being read. */
+export async function speakText(
+  text: string,
+  language: string = i18nService.getCurrentLocale(),
+): Promise<TextToSpeechSpeakResult | null> {
+  ensureStateListener();
+  stopPlayback();
+  const requestGeneration = ++generation;
+  const result = await speechAPI.speak({ text, language });
+  if (requestGeneration !== generation) {
+    return null;
+  }
+
+  if (!result.audio) {
+    setActive({ utteranceId: result.utteranceId, audio: null });
+    return result;
+  }
+  const audio = new Audio(`data:${result.audio.mimeType};base64,${result.audio.dataBase64}`);
+  const release = () => {
+    if (active?.audio === audio) {
+      setActive(null);
+    }
+  };
+  audio.addEventListener('ended', release);
+  audio.addEventListener('error', release);
+  setActive({ utteranceId: result.utteranceId, audio });
+  await audio.play();
+  return result;
+}
+
+export async function stopSpeaking(): Promise<void> {
+  generation += 1;
+  stopPlayback();
+  await speechAPI.stopSpeaking();
+}
+
+/**
+ * Reads `text` aloud if text-to-speech is enabled for `source`. Failures are
+ * logged rather than thrown so callers can fire and forget.
+ */
+export async function readAloud(text: string, source: ReadAloudSource): Promise<void> {
+  try {
+    const settings = (await aiExperienceConfigService.getSettingsAsync()).text_to_speech;
+    const sourceEnabled = source === 'task_summary'
+      ? settings.read_task_summaries
+      : settings.read_notifications;
+    if (!settings.enabled || !sourceEnabled || !text.trim()) {
+      return;
+    }
+    await speakText(text);
+  } catch (error) {
+    log.warn('Failed to read text aloud', { source, error });
+  }
+}
@@ -1,13 +1,14 @@
 import { JSDOM } from 'jsdom';
 import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
 
-import { configAPI } from '@/infrastructure/api';
+import { configAPI, themeAPI, type UserThemeDefinition } from '@/infrastructure/api';
 import { bitfunDarkTheme, bitfunLightTheme } from '../presets';
 import {
   PLUGIN_THEME_COLOR_KEYS,
   createPluginThemeColorProjection,
 } from '../pluginThemeProjection';
 import { SYSTEM_THEME_ID, type ThemeConfig } from '../types';
+import { USER_THEME_COLOR_TOKENS, toUserThemeDefinition } from '../utils/userThemeTokens';
 import { ThemeService } from './ThemeService';
 
 function expectThemeError(
@@ -36,6 +37,14 @@ function expectNoRetiredThemeAuthoringKeys(theme: ThemeConfig) {
   expect(components?.windowControls).toBeUndefined();
 }
 
+function expectOnlyUserThemeTokens(theme: UserThemeDefinition | undefined) {
+  expect(theme).toBeDefined();
+  const tokens = new Set<string>(USER_THEME_COLOR_TOKENS.map(([token]) => token));
+  Object.keys(theme?.colors ?? {}).forEach(token => {
+    expect(tokens.has(token)).toBe(true);
+  });
+}
+
 function createThemeWithRetiredAuthoringKeys(id: string, name: string): ThemeConfig {
   return {
     ...bitfunDarkTheme,
@@ -86,8 +95,42 @@ vi.mock('@/infrastructure/api', () => ({
     getConfig: vi.fn(),
     setConfig: vi.fn().mockResolvedValue(undefined),
   },
+  themeAPI: {
+    listThemes: vi.fn(),
+    getUserTheme: vi.fn(),
+    saveUserTheme: vi.fn(),
+    deleteUserTheme: vi.fn(),
+    getCurrentTheme: vi.fn(),
+    setTheme: vi.fn(),
+    onThemeUpdated: vi.fn(),
+  },
 }));
 
+/** Backs the mocked theme registry commands with an in-memory set of theme files. */
+function mockThemeRegistry(
+  currentThemeId = '',
+  userThemes: UserThemeDefinition[] = [],
+): Map<string, UserThemeDefinition> {
+  const files = new Map(userThemes.map(theme => [theme.id, theme]));
+  vi.mocked(themeAPI.getCurrentTheme).mockResolvedValue(currentThemeId);
+  vi.mocked(themeAPI.listThemes).mockImplementation(async () => ({
+    themes: Array.from(files.keys()).map(id => ({ id, source: 'user' as const })),
+    invalid: [],
+  }));
+  vi.mocked(themeAPI.getUserTheme).mockImplementation(async id => files.get(id) ?? null);
+  vi.mocked(themeAPI.saveUserTheme).mockImplementation(async theme => {
+    files.set(theme.id, theme);
+  });
+  vi.mocked(themeAPI.deleteUserTheme).mockImplementation(async id => files.delete(id));
+  return files;
+}
+
+function savedUserTheme(themeId: string): UserThemeDefinition | undefined {
+  return vi.mocked(themeAPI.saveUserTheme).mock.calls
+    .map(([theme]) => theme)
+    .find(theme => theme.id === themeId);
+}
+
 vi.mock('../integrations/MonacoThemeSync', () => ({
   monacoThemeSync: {
     syncTheme: vi.fn(),
@@ -126,6 +169,9 @@ describe('ThemeService runtime theme tokens', () => {
     delete bootstrapGlobals.__BITFUN_BOOTSTRAP_THEME_SELECTION__;
     vi.mocked(configAPI.getConfig).mockResolvedValue(undefined);
     vi.mocked(configAPI.setConfig).mockResolvedValue(undefined);
+    vi.mocked(themeAPI.setTheme).mockResolvedValue(undefined);
+    vi.mocked(themeAPI.onThemeUpdated).mockReturnValue(() => {});
+    mockThemeRegistry();
   });
 
   afterEach(() => {
@@ -236,7 +282,7 @@ describe('ThemeService runtime theme tokens', () => {
     expect(rootStyle.getPropertyValue('--color-overlay-black-30')).toBe('rgba(0, 0, 0, 0.3)');
   });
 
-  it('initializes from bootstrap theme selection without reading or writing themes.current', async () => {
+  it('initializes from bootstrap theme selection without reading or writing the current theme', async () => {
     bootstrapGlobals.__BITFUN_BOOTSTRAP_THEME_ID__ = 'bitfun-slate';
     bootstrapGlobals.__BITFUN_BOOTSTRAP_THEME_SELECTION__ = 'bitfun-slate';
     const service = new ThemeService();
@@ -246,14 +292,8 @@ describe('ThemeService runtime theme tokens', () => {
     expect(service.getCurrentThemeId()).toBe('bitfun-slate');
     expect(document.documentElement.getAttribute('data-theme')).toBe('bitfun-slate');
     expect(configAPI.getConfig).not.toHaveBeenCalled();
-    expect(configAPI.getConfig).not.toHaveBeenCalledWith(
-      'themes.current',
-      expect.anything(),
-    );
-    expect(configAPI.setConfig).not.toHaveBeenCalledWith(
-      'themes.current',
-      expect.anything(),
-    );
+    expect(themeAPI.getCurrentTheme).not.toHaveBeenCalled();
+    expect(themeAPI.setTheme).not.toHaveBeenCalled();
   });
 
   it('loads custom themes on demand after initialization and deduplicates repeated loads', async () => {
@@ -265,6 +305,7 @@ describe('ThemeService runtime theme tokens', () => {
     await service.ensureUserThemesLoaded();
     await service.ensureUserThemesLoaded();
 
+    expect(themeAPI.listThemes).toHaveBeenCalledTimes(1);
     expect(configAPI.getConfig).toHaveBeenCalledTimes(1);
     expect(configAPI.getConfig).toHaveBeenCalledWith(
       'themes',
@@ -272,23 +313,16 @@ describe('ThemeService runtime theme tokens', () => {
     );
   });
 
-  it('falls back to config lookup when bootstrap theme selection is unavailable', async () => {
+  it('falls back to the theme registry when bootstrap theme selection is unavailable', async () => {
     bootstrapGlobals.__BITFUN_BOOTSTRAP_THEME_ID__ = 'bitfun-light';
-    vi.mocked(configAPI.getConfig).mockImplementation(async (key: string) => {
-      if (key === 'themes.current') {
-        return 'bitfun-slate';
-      }
-      return undefined;
-    });
+    mockThemeRegistry('bitfun-slate');
     const service = new ThemeService();
 
     await service.initialize();
 
     expect(service.getCurrentThemeId()).toBe('bitfun-slate');
-    expect(configAPI.getConfig).toHaveBeenCalledWith(
-      'themes.current',
-      expect.objectContaining({ skipRetryOnNotFound: true }),
-    );
+    expect(themeAPI.getCurrentTheme).toHaveBeenCalledTimes(1);
+    expect(configAPI.getConfig).not.toHaveBeenCalledWith('themes.current', expect.anything());
   });
 
   it('applies saved custom theme during initialization when bootstrap cannot provide it', async () => {
@@ -304,15 +338,7 @@ describe('ThemeService runtime theme tokens', () => {
         },
       },
     };
-    vi.mocked(configAPI.getConfig).mockImplementation(async (key: string) => {
-      if (key === 'themes.current') {
-        return 'custom-ocean';
-      }
-      if (key === 'themes') {
-        return { custom: [customTheme] };
-      }
-      return undefined;
-    });
+    mockThemeRegistry('custom-ocean', [toUserThemeDefinition(customTheme)]);
     const service = new ThemeService();
 
     await service.initialize();
@@ -322,29 +348,52 @@ describe('ThemeService runtime theme tokens', () => {
     expect(service.getResolvedThemeId()).toBe('custom-ocean');
     expect(document.documentElement.getAttribute('data-theme')).toBe('custom-ocean');
     expect(document.documentElement.style.getPropertyValue('--color-bg-primary')).toBe('#001122');
-    expect(configAPI.getConfig).toHaveBeenCalledWith(
-      'themes',
-      expect.objectContaining({ skipRetryOnNotFound: true }),
-    );
-    expect(vi.mocked(configAPI.getConfig).mock.calls.filter(([key]) => key === 'themes')).toHaveLength(1);
-    expect(configAPI.setConfig).not.toHaveBeenCalledWith('themes.current', 'custom-ocean');
+    expect(themeAPI.listThemes).toHaveBeenCalledTimes(1);
+    expect(themeAPI.getUserTheme).toHaveBeenCalledWith('custom-ocean');
+    expect(themeAPI.setTheme).not.toHaveBeenCalled();
   });
 
   it('does not persist the theme selection again during initialization', async () => {
-    vi.mocked(configAPI.getConfig).mockImplementation(async (key: string) => {
-      if (key === 'themes.current') {
-        return 'bitfun-slate';
-      }
-      return undefined;
-    });
+    mockThemeRegistry('bitfun-slate');
     const service = new ThemeService();
 
     await service.initialize();
 
-    expect(configAPI.setConfig).not.toHaveBeenCalledWith(
-      'themes.current',
-      expect.anything(),
-    );
+    expect(themeAPI.setTheme).not.toHaveBeenCalled();
+  });
+
+  it('persists theme selections through the theme registry', async () => {
+    const service = new ThemeService();
+
+    await service.applyTheme('bitfun-slate');
+    await service.applyTheme('bitfun-slate');
+
+    expect(themeAPI.setTheme).toHaveBeenCalledTimes(1);
+    expect(themeAPI.setTheme).toHaveBeenCalledWith('bitfun-slate');
+    expect(configAPI.setConfig).not.toHaveBeenCalled();
+  });
+
+  it('applies theme changes broadcast by the backend without persisting them again', async () => {
+    const service = new ThemeService();
+    await service.initialize();
+    const onThemeUpdated = vi.mocked(themeAPI.onThemeUpdated).mock.calls[0]?.[0];
+    expect(onThemeUpdated).toBeDefined();
+    const userTheme = toUserThemeDefinition({
+      ...bitfunDarkTheme,
+      id: 'custom-remote',
+      name: 'Remote Custom',
+      colors: {
+        ...bitfunDarkTheme.colors,
+        background: { ...bitfunDarkTheme.colors.background, primary: '#0b1e2d' },
+      },
+    });
+
+    onThemeUpdated?.({ themeId: 'custom-remote', userTheme });
+    await vi.waitFor(() => expect(service.getCurrentThemeId()).toBe('custom-remote'));
+
+    expect(document.documentElement.style.getPropertyValue('--color-bg-primary')).toBe('#0b1e2d');
+    await service.applyTheme('custom-remote');
+    expect(themeAPI.setTheme).not.toHaveBeenCalled();
   });
 
   it('validates the core theme schema instead of only root fields', () => {
@@ -417,10 +466,8 @@ describe('ThemeService runtime theme tokens', () => {
         },
       },
     } as unknown as ThemeConfig;
+    mockThemeRegistry('custom-partial');
     vi.mocked(configAPI.getConfig).mockImplementation(async (key: string) => {
-      if (key === 'themes.current') {
-        return 'custom-partial';
-      }
       if (key === 'themes') {
         return { custom: [partialCustomTheme] };
       }
@@ -442,7 +489,8 @@ describe('ThemeService runtime theme tokens', () => {
     expect(document.documentElement.style.getPropertyValue('--color-bg-secondary')).toBe(
       bitfunLightTheme.colors.background.secondary,
     );
-    expect(configAPI.setConfig).not.toHaveBeenCalledWith('themes.custom', expect.anything());
+    expect(savedUserTheme('custom-partial')?.colors.bgPrimary).toBe('#101820');
+    expect(configAPI.setConfig).toHaveBeenCalledWith('themes.custom', []);
   });
 
   it('does not inject non-contract dynamic keys from custom themes', () => {
@@ -566,7 +614,7 @@ describe('ThemeService runtime theme tokens', () => {
     expect(document.documentElement.getAttribute('data-window-control-close-hover-override')).toBeNull();
   });
 
-  it('skips invalid persisted custom themes before they reach preview or runtime injection', async () => {
+  it('skips invalid user themes before they reach preview or runtime injection', async () => {
     const invalidCustomTheme = {
       ...bitfunLightTheme,
       id: 'custom-broken',
@@ -579,15 +627,7 @@ describe('ThemeService runtime theme tokens', () => {
         },
       },
     };
-    vi.mocked(configAPI.getConfig).mockImplementation(async (key: string) => {
-      if (key === 'themes.current') {
-        return 'custom-broken';
-      }
-      if (key === 'themes') {
-        return { custom: [invalidCustomTheme] };
-      }
-      return undefined;
-    });
+    mockThemeRegistry('custom-broken', [toUserThemeDefinition(invalidCustomTheme)]);
     const service = new ThemeService();
 
     await service.initialize();
@@ -595,7 +635,26 @@ describe('ThemeService runtime theme tokens', () => {
     expect(service.getTheme('custom-broken')).toBeUndefined();
     expect(service.getCurrentThemeId()).toBe(SYSTEM_THEME_ID);
     expect(document.documentElement.getAttribute('data-theme')).not.toBe('custom-broken');
-    expect(configAPI.setConfig).not.toHaveBeenCalledWith('themes.custom', expect.anything());
+  });
+
+  it('drops invalid legacy custom themes instead of migrating them', async () => {
+    const invalidLegacyTheme = {
+      ...bitfunLightTheme,
+      id: 'custom-legacy-broken',
+      name: 'Broken Legacy',
+      colors: {
+        ...bitfunLightTheme.colors,
+        text: { ...bitfunLightTheme.colors.text, primary: 'invalid' },
+      },
+    };
+    vi.mocked(configAPI.getConfig).mockResolvedValue({ custom: [invalidLegacyTheme] });
+    const service = new ThemeService();
+
+    await service.ensureUserThemesLoaded();
+
+    expect(service.getTheme('custom-legacy-broken')).toBeUndefined();
+    expect(themeAPI.saveUserTheme).not.toHaveBeenCalled();
+    expect(configAPI.setConfig).toHaveBeenCalledWith('themes.custom', []);
   });
 
   it('persists registered custom themes only after schema normalization succeeds', async () => {
@@ -623,19 +682,15 @@ describe('ThemeService runtime theme tokens', () => {
     expect(normalized?.colors.background.primary).toBe('#04080f');
     expect(normalized?.colors.background.secondary).toBe(bitfunDarkTheme.colors.background.secondary);
     expect(normalized?.effects.radius.base).toBe(bitfunDarkTheme.effects.radius.base);
-    expect(configAPI.setConfig).toHaveBeenCalledWith(
-      'themes.custom',
-      expect.arrayContaining([
-        expect.objectContaining({
-          id: 'custom-registered',
-          colors: expect.objectContaining({
-            background: expect.objectContaining({
-              primary: '#04080f',
-              secondary: bitfunDarkTheme.colors.background.secondary,
-            }),
-          }),
+    expect(themeAPI.saveUserTheme).toHaveBeenCalledWith(
+      expect.objectContaining({
+        id: 'custom-registered',
+        type: 'dark',
+        colors: expect.objectContaining({
+          bgPrimary: '#04080f',
+          bgSecondary: bitfunDarkTheme.colors.background.secondary,
         }),
-      ]),
+      }),
     );
 
     await expect(
@@ -652,6 +707,7 @@ describe('ThemeService runtime theme tokens', () => {
       }),
     ).rejects.toThrow(/Invalid theme/);
     expect(service.getTheme('custom-invalid-register')).toBeUndefined();
+    expect(savedUserTheme('custom-invalid-register')).toBeUndefined();
 
     await expect(
       service.registerTheme({
@@ -715,15 +771,7 @@ describe('ThemeService runtime theme tokens', () => {
     expect(normalized.colors.git.added).toBe(bitfunDarkTheme.colors.git.added);
     expectNoNonContractGitColorKeys(normalized.colors.git);
 
-    const persistedThemes = vi.mocked(configAPI.setConfig).mock.calls.find(([key]) => key === 'themes.custom')?.[1] as
-      | ThemeConfig[]
-      | undefined;
-    const persistedTheme = persistedThemes?.find(theme => theme.id === 'custom-legacy-git-bg');
-    expect(persistedTheme).toBeDefined();
-    if (!persistedTheme) {
-      throw new Error('Expected custom legacy git theme to be persisted');
-    }
-    expectNoNonContractGitColorKeys(persistedTheme.colors.git);
+    expectOnlyUserThemeTokens(savedUserTheme('custom-legacy-git-bg'));
 
     const exported = service.exportTheme('custom-legacy-git-bg');
     expect(exported).not.toBeNull();
@@ -750,15 +798,7 @@ describe('ThemeService runtime theme tokens', () => {
     expect(normalized.colors.accent[700]).toBe(bitfunDarkTheme.colors.accent[700]);
     expectNoRetiredThemeAuthoringKeys(normalized);
 
-    const persistedThemes = vi.mocked(configAPI.setConfig).mock.calls.find(([key]) => key === 'themes.custom')?.[1] as
-      | ThemeConfig[]
-      | undefined;
-    const persistedTheme = persistedThemes?.find(theme => theme.id === 'custom-retired-authoring');
-    expect(persistedTheme).toBeDefined();
-    if (!persistedTheme) {
-      throw new Error('Expected custom theme with retired keys to be persisted');
-    }
-    expectNoRetiredThemeAuthoringKeys(persistedTheme);
+    expectOnlyUserThemeTokens(savedUserTheme('custom-retired-authoring'));
 
     const exported = service.exportTheme('custom-retired-authoring');
     expect(exported).not.toBeNull();
@@ -768,6 +808,17 @@ describe('ThemeService runtime theme tokens', () => {
     expectNoRetiredThemeAuthoringKeys(exported.theme);
   });
 
+  it('keeps legacy custom themes that could not be saved for the next start', async () => {
+    const legacyTheme = { ...bitfunDarkTheme, id: 'custom-unsaved', name: 'Unsaved' };
+    vi.mocked(configAPI.getConfig).mockResolvedValue({ custom: [legacyTheme] });
+    vi.mocked(themeAPI.saveUserTheme).mockRejectedValue(new Error('disk full'));
+    const service = new ThemeService();
+
+    await service.ensureUserThemesLoaded();
+
+    expect(configAPI.setConfig).toHaveBeenCalledWith('themes.custom', [legacyTheme]);
+  });
+
   it('migrates persisted custom themes with retired authoring keys on load', async () => {
     const retiredAuthoringTheme = createThemeWithRetiredAuthoringKeys(
       'custom-loaded-retired-authoring',
@@ -785,16 +836,8 @@ describe('ThemeService runtime theme tokens', () => {
     }
     expectNoRetiredThemeAuthoringKeys(normalized);
 
-    const migratedThemes = vi.mocked(configAPI.setConfig).mock.calls.find(([key]) => key === 'themes.custom')?.[1] as
-      | ThemeConfig[]
-      | undefined;
-    expect(migratedThemes).toHaveLength(1);
-    const migratedTheme = migratedThemes?.[0];
-    expect(migratedTheme).toBeDefined();
-    if (!migratedTheme) {
-      throw new Error('Expected custom theme with retired keys to be migrated');
-    }
-    expectNoRetiredThemeAuthoringKeys(migratedTheme);
+    expectOnlyUserThemeTokens(savedUserTheme('custom-loaded-retired-authoring'));
+    expect(configAPI.setConfig).toHaveBeenCalledWith('themes.custom', []);
   });
 
   it('migrates persisted custom themes with non-contract git color keys on load', async () => {
@@ -828,18 +871,10 @@ describe('ThemeService runtime theme tokens', () => {
     expect(normalized.colors.git as unknown as Record<string, unknown>).not.toHaveProperty('addedBgHover');
     expect(normalized.colors.git as unknown as Record<string, unknown>).not.toHaveProperty('stagedBorder');
 
-    const migratedThemes = vi.mocked(configAPI.setConfig).mock.calls.find(([key]) => key === 'themes.custom')?.[1] as
-      | ThemeConfig[]
-      | undefined;
-    expect(migratedThemes).toHaveLength(1);
-    const migratedGitColors = migratedThemes?.[0]?.colors.git as unknown as Record<string, unknown> | undefined;
-    expect(migratedGitColors).toBeDefined();
-    if (!migratedGitColors) {
-      throw new Error('Expected migrated theme to keep git colors');
-    }
-    expect(migratedGitColors).not.toHaveProperty('changesBg');
-    expect(migratedGitColors).not.toHaveProperty('addedBgHover');
-    expect(migratedGitColors).not.toHaveProperty('stagedBorder');
+    const migratedTheme = savedUserTheme('custom-loaded-legacy-git');
+    expectOnlyUserThemeTokens(migratedTheme);
+    expect(migratedTheme?.colors.gitAdded).toBe(bitfunDarkTheme.colors.git.added);
+    expect(configAPI.setConfig).toHaveBeenCalledWith('themes.custom', []);
   });
 
   it('projects normalized custom themes through the compact plugin color boundary', async () => {
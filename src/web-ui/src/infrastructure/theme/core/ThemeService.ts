@@ -15,7 +15,13 @@ import {
 } from '../types';
 import { builtinThemes, getSystemPreferredDefaultThemeId } from '../presets';
 import { themeValidator } from '../utils/ThemeValidator';
-import { configAPI } from '@/infrastructure/api';
+import { fromUserThemeDefinition, toUserThemeDefinition } from '../utils/userThemeTokens';
+import {
+  configAPI,
+  themeAPI,
+  type ThemeUpdatedEvent,
+  type UserThemeDefinition,
+} from '@/infrastructure/api';
 import { monacoThemeSync } from '../integrations/MonacoThemeSync';
 import { createLogger } from '@/shared/utils/logger';
 
@@ -228,6 +234,7 @@ export class ThemeService {
   private userThemesLoaded = false;
   private userThemesLoadPromise: Promise<void> | null = null;
   private pendingUserThemeSelection: ThemeId | null = null;
+  private themeUpdateCleanup: (() => void) | null = null;
 
   constructor() {
     this.initializeBuiltinThemes();
@@ -247,6 +254,7 @@ export class ThemeService {
   async initialize(): Promise<void> {
     if (this.initialized) return;
     this.initialized = true;
+    this.subscribeToThemeUpdates();
     try {
       const bootstrapSelection = this.getBootstrapThemeSelection();
       if (bootstrapSelection) {
@@ -336,55 +344,101 @@ export class ThemeService {
 
 
   private async loadUserThemes(): Promise<void> {
+    await this.migrateLegacyCustomThemes();
+    try {
+      const catalog = await themeAPI.listThemes();
+      catalog.invalid.forEach(item => {
+        log.warn('Skipped invalid user theme file', item);
+      });
+      const userThemeIds = catalog.themes
+        .filter(entry => entry.source === 'user')
+        .map(entry => entry.id);
+      let loadedCount = 0;
+      for (const themeId of userThemeIds) {
+        const definition = await themeAPI.getUserTheme(themeId).catch(error => {
+          log.warn('Failed to load user theme', { id: themeId, error });
+          return null;
+        });
+        if (definition && this.addUserTheme(definition)) {
+          loadedCount += 1;
+        }
+      }
+      log.info('Loaded user themes', { count: loadedCount, skipped: userThemeIds.length - loadedCount });
+    } catch (error) {
+      log.warn('Failed to list user themes', error);
+    }
+  }
+
+
+  /**
+   * Moves themes that older versions kept in `themes.custom` into theme
+   * registry files. Entries that could not be saved stay for the next start.
+   */
+  private async migrateLegacyCustomThemes(): Promise<void> {
+    let legacyThemes: ThemeConfig[] | undefined;
     try {
       // Read the whole themes section so missing optional `custom` does not surface
       // as an expected backend error during startup.
       const themesConfig = await configAPI.getConfig('themes', {
         skipRetryOnNotFound: true,
       }) as { custom?: ThemeConfig[] } | undefined;
-      const themes = themesConfig?.custom;
-
-      if (Array.isArray(themes) && themes.length > 0) {
-        let loadedCount = 0;
-        let migratedThemeKeys = false;
-        const persistedThemes = [...themes];
-        themes.forEach((theme, index) => {
-          try {
-            const normalizedTheme = this.normalizeCustomTheme(theme);
-            this.themes.set(normalizedTheme.id, normalizedTheme);
-            loadedCount += 1;
-            if (hasNonContractThemeKeys(theme)) {
-              persistedThemes[index] = normalizedTheme;
-              migratedThemeKeys = true;
-            }
-          } catch (error) {
-            log.warn('Skipped invalid user theme', {
-              id: theme?.id,
-              error: error instanceof Error ? error.message : String(error),
-            });
-          }
+      legacyThemes = themesConfig?.custom;
+    } catch (_error) {
+      return;
+    }
+    if (!Array.isArray(legacyThemes) || legacyThemes.length === 0) {
+      return;
+    }
+
+    const remaining: ThemeConfig[] = [];
+    for (const theme of legacyThemes) {
+      let normalizedTheme: ThemeConfig;
+      try {
+        normalizedTheme = this.normalizeCustomTheme(theme);
+      } catch (error) {
+        log.warn('Dropped invalid legacy custom theme', {
+          id: theme?.id,
+          error: error instanceof Error ? error.message : String(error),
         });
-        log.info('Loaded user themes', { count: loadedCount, skipped: themes.length - loadedCount });
-        if (migratedThemeKeys) {
-          try {
-            await configAPI.setConfig('themes.custom', persistedThemes);
-          } catch (error) {
-            log.warn('Failed to migrate custom theme keys', error);
-          }
-        }
+        continue;
       }
-    } catch (_error) {
+      try {
+        await themeAPI.saveUserTheme(toUserThemeDefinition(normalizedTheme));
+      } catch (error) {
+        log.warn('Failed to migrate legacy custom theme', { id: theme.id, error });
+        remaining.push(theme);
+      }
+    }
+    log.info('Migrated legacy custom themes', {
+      count: legacyThemes.length - remaining.length,
+      remaining: remaining.length,
+    });
+    try {
+      await configAPI.setConfig('themes.custom', remaining);
+    } catch (error) {
+      log.warn('Failed to clear migrated legacy custom themes', error);
+    }
+  }
+
 
+  private addUserTheme(definition: UserThemeDefinition): ThemeConfig | null {
+    try {
+      const theme = this.normalizeCustomTheme(fromUserThemeDefinition(definition) as ThemeConfig);
+      this.themes.set(theme.id, theme);
+      return theme;
+    } catch (error) {
+      log.warn('Skipped invalid user theme', {
+        id: definition.id,
+        error: error instanceof Error ? error.message : String(error),
+      });
+      return null;
     }
   }
 
 
   private async loadThemeSelection(): Promise<ThemeSelectionId | null> {
     try {
-
-      const raw = await configAPI.getConfig('themes.current', {
-        skipRetryOnNotFound: true
-      }) as string | undefined;
+      const raw = await themeAPI.getCurrentTheme();
 
       if (raw === SYSTEM_THEME_ID) {
         return SYSTEM_THEME_ID;
@@ -396,6 +450,43 @@ export class ThemeService {
   }
 
 
+  /** Applies theme changes made from other windows or surfaces. */
+  private subscribeToThemeUpdates(): void {
+    if (this.themeUpdateCleanup) {
+      return;
+    }
+    try {
+      this.themeUpdateCleanup = themeAPI.onThemeUpdated(event => {
+        void this.handleThemeUpdated(event);
+      });
+    } catch (error) {
+      log.warn('Failed to subscribe to theme updates', error);
+    }
+  }
+
+  private async handleThemeUpdated(event: ThemeUpdatedEvent): Promise<void> {
+    if (event.userTheme && !this.addUserTheme(event.userTheme)) {
+      return;
+    }
+    if (event.themeId !== SYSTEM_THEME_ID && !this.themes.has(event.themeId)) {
+      log.warn('Updated theme is not available in this window', { id: event.themeId });
+      return;
+    }
+    try {
+      if (event.themeId !== this.themeSelection) {
+        await this.applyThemeSelection(event.themeId, { persist: false });
+      } else if (event.userTheme) {
+        // Same selection, but the theme file may have been edited.
+        await this.applyResolvedTheme(event.themeId);
+      }
+      this.lastSavedSelection = event.themeId;
+    } catch (error) {
+      log.warn('Failed to apply updated theme', { id: event.themeId, error });
+    }
+  }
+
+
+
 
 
   private normalizeCustomTheme(theme: ThemeConfig): ThemeConfig {
@@ -444,10 +535,10 @@ export class ThemeService {
       log.warn('Theme already exists, will override', { id: theme.id });
     }
 
+    await themeAPI.saveUserTheme(toUserThemeDefinition(normalizedTheme));
     this.themes.set(normalizedTheme.id, normalizedTheme);
     this.emitEvent('theme:register', normalizedTheme.id, normalizedTheme);
     log.info('Theme registered', { id: normalizedTheme.id, name: normalizedTheme.name });
-    await this.saveUserThemes();
   }
 
 
@@ -475,7 +566,9 @@ export class ThemeService {
     log.info('Theme unregistered', { id: themeId, name: theme.name });
 
 
-    this.saveUserThemes();
+    themeAPI.deleteUserTheme(themeId).catch(error => {
+      log.warn('Failed to delete user theme', { id: themeId, error });
+    });
 
     return true;
   }
@@ -894,7 +987,7 @@ export class ThemeService {
     }
     this.lastSavedSelection = selection;
     try {
-      await configAPI.setConfig('themes.current', selection);
+      await themeAPI.setTheme(selection);
     } catch (error) {
       this.lastSavedSelection = undefined;
       log.warn('Failed to save current theme ID', error);
@@ -902,20 +995,6 @@ export class ThemeService {
   }
 
 
-  private async saveUserThemes(): Promise<void> {
-    try {
-      const userThemes = Array.from(this.themes.values()).filter(
-          theme => !builtinThemes.some(t => t.id === theme.id)
-      );
-      await configAPI.setConfig('themes.custom', userThemes);
-    } catch (error) {
-      log.warn('Failed to save user themes', error);
-    }
-  }
-
-
-
-
   exportTheme(themeId: ThemeId): ThemeExport | null {
     const theme = this.themes.get(themeId);
     if (!theme) {
@@ -0,0 +1,40 @@
+import { describe, expect, it } from 'vitest';
+
+import { bitfunDarkTheme } from '../presets';
+import {
+  USER_THEME_COLOR_TOKENS,
+  fromUserThemeDefinition,
+  toUserThemeDefinition,
+} from './userThemeTokens';
+
+describe('userThemeTokens', () => {
+  it('flattens every mapped color and keeps the registry-required tokens', () => {
+    const definition = toUserThemeDefinition({ ...bitfunDarkTheme, id: 'ocean', name: 'Ocean' });
+
+    expect(definition).toMatchObject({ id: 'ocean', name: 'Ocean', type: 'dark' });
+    expect(definition.colors.bgPrimary).toBe(bitfunDarkTheme.colors.background.primary);
+    expect(definition.colors.accentColor).toBe(bitfunDarkTheme.colors.accent[500]);
+    expect(definition.colors.gitStaged).toBe(bitfunDarkTheme.colors.git.staged);
+    for (const token of ['bgPrimary', 'bgSecondary', 'bgScene', 'textPrimary', 'textMuted', 'accentColor']) {
+      expect(definition.colors).toHaveProperty(token);
+    }
+  });
+
+  it('round-trips mapped colors and ignores unknown tokens', () => {
+    const definition = toUserThemeDefinition({ ...bitfunDarkTheme, id: 'ocean', name: 'Ocean' });
+    const theme = fromUserThemeDefinition({
+      ...definition,
+      colors: { ...definition.colors, bgPrimary: '#0b1e2d', unknownToken: '#ffffff' },
+    });
+
+    expect(theme.colors?.background.primary).toBe('#0b1e2d');
+    expect(theme.colors?.accent[500]).toBe(bitfunDarkTheme.colors.accent[500]);
+    expect(theme.colors?.semantic.errorBorder).toBe(bitfunDarkTheme.colors.semantic.errorBorder);
+    expect(JSON.stringify(theme)).not.toContain('unknownToken');
+  });
+
+  it('maps each token name once', () => {
+    const tokens = USER_THEME_COLOR_TOKENS.map(([token]) => token);
+    expect(new Set(tokens).size).toBe(tokens.length);
+  });
+});
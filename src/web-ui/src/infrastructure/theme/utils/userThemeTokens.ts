@@ -0,0 +1,106 @@
+import type { UserThemeDefinition } from '@/infrastructure/api/service-api/ThemeAPI';
+import type { ThemeConfig } from '../types';
+
+/**
+ * Theme registry token name -> path under `ThemeConfig.colors`.
+ *
+ * User theme files only carry colors; effects, motion and typography come from
+ * the built-in base theme of the same type. The first six tokens are the ones
+ * the registry requires.
+ */
+export const USER_THEME_COLOR_TOKENS = [
+  ['bgPrimary', 'background', 'primary'],
+  ['bgSecondary', 'background', 'secondary'],
+  ['bgScene', 'background', 'scene'],
+  ['textPrimary', 'text', 'primary'],
+  ['textMuted', 'text', 'muted'],
+  ['accentColor', 'accent', '500'],
+  ['bgTertiary', 'background', 'tertiary'],
+  ['bgElevated', 'background', 'elevated'],
+  ['bgWorkbench', 'background', 'workbench'],
+  ['textSecondary', 'text', 'secondary'],
+  ['textDisabled', 'text', 'disabled'],
+  ['accent50', 'accent', '50'],
+  ['accent100', 'accent', '100'],
+  ['accent200', 'accent', '200'],
+  ['accent300', 'accent', '300'],
+  ['accent400', 'accent', '400'],
+  ['accent600', 'accent', '600'],
+  ['accent700', 'accent', '700'],
+  ['purple100', 'purple', '100'],
+  ['purple200', 'purple', '200'],
+  ['purple500', 'purple', '500'],
+  ['purple600', 'purple', '600'],
+  ['success', 'semantic', 'success'],
+  ['successBg', 'semantic', 'successBg'],
+  ['successBorder', 'semantic', 'successBorder'],
+  ['warning', 'semantic', 'warning'],
+  ['warningBg', 'semantic', 'warningBg'],
+  ['warningBorder', 'semantic', 'warningBorder'],
+  ['error', 'semantic', 'error'],
+  ['errorBg', 'semantic', 'errorBg'],
+  ['errorBorder', 'semantic', 'errorBorder'],
+  ['info', 'semantic', 'info'],
+  ['infoBg', 'semantic', 'infoBg'],
+  ['infoBorder', 'semantic', 'infoBorder'],
+  ['borderSubtle', 'border', 'subtle'],
+  ['borderBase', 'border', 'base'],
+  ['borderMedium', 'border', 'medium'],
+  ['borderStrong', 'border', 'strong'],
+  ['borderProminent', 'border', 'prominent'],
+  ['elementSubtle', 'element', 'subtle'],
+  ['elementSoft', 'element', 'soft'],
+  ['elementBase', 'element', 'base'],
+  ['elementMedium', 'element', 'medium'],
+  ['elementStrong', 'element', 'strong'],
+  ['gitBranch', 'git', 'branch'],
+  ['gitBranchBg', 'git', 'branchBg'],
+  ['gitChanges', 'git', 'changes'],
+  ['gitAdded', 'git', 'added'],
+  ['gitDeleted', 'git', 'deleted'],
+  ['gitStaged', 'git', 'staged'],
+  ['scrollbarThumb', 'scrollbar', 'thumb'],
+  ['scrollbarThumbHover', 'scrollbar', 'thumbHover'],
+] as const;
+
+type ColorGroups = Record<string, Record<string, string> | undefined>;
+
+/** Flattens a theme's colors into a registry definition. */
+export function toUserThemeDefinition(theme: ThemeConfig): UserThemeDefinition {
+  const groups = theme.colors as unknown as ColorGroups;
+  const colors: Record<string, string> = {};
+  USER_THEME_COLOR_TOKENS.forEach(([token, group, key]) => {
+    const value = groups[group]?.[key];
+    if (typeof value === 'string') {
+      colors[token] = value;
+    }
+  });
+  return {
+    id: theme.id,
+    name: theme.name,
+    type: theme.type,
+    ...(theme.description ? { description: theme.description } : {}),
+    colors,
+  };
+}
+
+/**
+ * Expands a registry definition into a partial theme. Unknown tokens are
+ * ignored; the caller fills the rest from a built-in base theme.
+ */
+export function fromUserThemeDefinition(definition: UserThemeDefinition): Partial<ThemeConfig> {
+  const groups: Record<string, Record<string, string>> = {};
+  USER_THEME_COLOR_TOKENS.forEach(([token, group, key]) => {
+    const value = definition.colors[token];
+    if (typeof value === 'string') {
+      groups[group] = { ...groups[group], [key]: value };
+    }
+  });
+  return {
+    id: definition.id,
+    name: definition.name,
+    type: definition.type,
+    ...(definition.description ? { description: definition.description } : {}),
+    colors: groups as unknown as ThemeConfig['colors'],
+  };
+}
@@ -299,6 +299,12 @@ async function initializeAfterRender(): Promise<void> {
       const { registerNotificationContextMenu } = await import('./shared/notification-system');
       registerNotificationContextMenu();
     })(),
+    (async () => {
+      const { initOpenFilesContextSync } = await import(
+        './app/components/panels/content-canvas/stores/openFilesContextSync'
+      );
+      initOpenFilesContextSync();
+    })(),
   ]);
 
   initResults.forEach((result, index) => {
@@ -311,6 +317,7 @@ async function initializeAfterRender(): Promise<void> {
       'RecommendationProviders',
       'Tools',
       'ContextMenu',
+      'OpenFilesContextSync',
     ];
     if (result.status === 'rejected') {
       log.warn('Initialization failed', { module: names[index], error: result.reason });
@@ -252,6 +252,10 @@ export class DragManager {
         return context.refValue;
       case 'url':
         return context.url;
+      case 'mcp-resource':
+        return context.content;
+      case 'attachment':
+        return context.path;
       default:
         return '';
     }
@@ -166,7 +166,13 @@ export const useContextStore = create<ContextState>()(
         },
         
         partialize: (state: any) => ({ 
-          contexts: state.contexts.filter((ctx: any) => ctx.type !== 'image' && ctx.type !== 'pull-request')
+          contexts: state.contexts.filter(
+            (ctx: any) =>
+              ctx.type !== 'image' &&
+              ctx.type !== 'pull-request' &&
+              ctx.type !== 'mcp-resource' &&
+              ctx.type !== 'attachment'
+          )
         })
       } as any
     ),
@@ -6,6 +6,8 @@
  * like files, snippets, diagrams, and URLs in a transportable form (e.g. drag-and-drop,
  * context menus, clipboard).
  */
+import type { AttachmentKind } from '@/infrastructure/api/service-api/AttachmentAPI';
+
 export interface BaseContext {
   id: string;
   timestamp: number;
@@ -27,7 +29,9 @@ export type ContextItem =
   | TerminalCommandContext
   | GitRefContext
   | URLContext
-  | WebElementContext;
+  | WebElementContext
+  | MCPResourceContext
+  | AttachmentContext;
 
 export interface FileContext extends BaseContext {
   type: 'file';
@@ -150,6 +154,32 @@ export interface WebElementContext extends BaseContext {
   sourceUrl?: string;
 }
 
+/** An MCP resource converted to text when it was attached. */
+export interface MCPResourceContext extends BaseContext {
+  type: 'mcp-resource';
+  serverId: string;
+  serverName: string;
+  uri: string;
+  label: string;
+  mimeType?: string;
+  content: string;
+  estimatedTokens: number;
+  /** Part of the resource was cut or left out to fit the token budget. */
+  truncated: boolean;
+}
+
+/** A dropped or pasted file stored by the session attachment service. */
+export interface AttachmentContext extends BaseContext {
+  type: 'attachment';
+  attachmentId: string;
+  name: string;
+  kind: AttachmentKind;
+  mimeType: string;
+  sizeBytes: number;
+  /** Absolute path of the stored copy. */
+  path: string;
+}
+
 /**
  * Convenience alias for the discriminant used by `ContextItem`.
  */
@@ -224,6 +254,14 @@ export function isWebElementContext(context: ContextItem): context is WebElement
   return context.type === 'web-element';
 }
 
+export function isMCPResourceContext(context: ContextItem): context is MCPResourceContext {
+  return context.type === 'mcp-resource';
+}
+
+export function isAttachmentContext(context: ContextItem): context is AttachmentContext {
+  return context.type === 'attachment';
+}
+
  
 export function isContextOfType<T extends ContextType>(
   context: ContextItem,
@@ -5,7 +5,8 @@ export type ConnectionTestMessageCode =
   | 'image_input_check_failed'
   | 'tls_or_certificate_issue'
   | 'proxy_issue'
-  | 'network_issue';
+  | 'network_issue'
+  | 'authentication_failed';
 
 const MESSAGE_KEY_BY_CODE: Record<ConnectionTestMessageCode, string> = {
   tool_calls_not_detected: 'messages.connectionTestMessages.toolCallsNotDetected',
@@ -13,6 +14,7 @@ const MESSAGE_KEY_BY_CODE: Record<ConnectionTestMessageCode, string> = {
   tls_or_certificate_issue: 'messages.connectionTestMessages.tlsOrCertificateIssue',
   proxy_issue: 'messages.connectionTestMessages.proxyIssue',
   network_issue: 'messages.connectionTestMessages.networkIssue',
+  authentication_failed: 'messages.connectionTestMessages.authenticationFailed',
 };
 
 export function translateConnectionTestMessage(
@@ -25,6 +25,9 @@ export function formatContextForPrompt(context: ContextItem): string {
       ].filter(line => line !== '').join('\n');
     case 'image':
       return '';
+    case 'attachment':
+      // Sent by id; the backend resolves the stored copy for the agent.
+      return '';
     case 'terminal-command':
       return `[Command: ${context.command}]`;
     case 'mermaid-node':
@@ -35,6 +38,15 @@ export function formatContextForPrompt(context: ContextItem): string {
       return `[Git Ref: ${context.refValue}]`;
     case 'url':
       return `[URL: ${context.url}]`;
+    case 'mcp-resource':
+      return [
+        `[MCP Resource: ${context.label}]`,
+        `Server: ${context.serverName}`,
+        `URI: ${context.uri}`,
+        context.mimeType ? `MIME type: ${context.mimeType}` : '',
+        '',
+        context.content,
+      ].filter(line => line !== '').join('\n');
     case 'web-element': {
       const isCanvasElement = typeof context.metadata?.artifactReference === 'string';
       const label = typeof context.metadata?.label === 'string' ? context.metadata.label : `<${context.tagName}>`;